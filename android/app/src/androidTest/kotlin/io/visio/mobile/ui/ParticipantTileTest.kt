@@ -8,6 +8,7 @@ import org.junit.Rule
 import org.junit.Test
 import uniffi.visio.ConnectionQuality
 import uniffi.visio.ParticipantInfo
+import uniffi.visio.Presence
 
 class ParticipantTileTest {
     @get:Rule
@@ -28,6 +29,7 @@ class ParticipantTileTest {
         hasVideo = hasVideo,
         videoTrackSid = videoTrackSid,
         connectionQuality = ConnectionQuality.GOOD,
+        presence = Presence.AVAILABLE,
     )
 
     @Test
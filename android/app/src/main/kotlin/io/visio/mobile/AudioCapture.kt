@@ -91,8 +91,10 @@ class AudioCapture {
                         buffer.position(0)
                         buffer.limit(read * 2)
 
+                        // read() returns once the last sample was captured
+                        val captureNs = System.nanoTime() - read * 1_000_000_000L / SAMPLE_RATE
                         NativeVideo.nativePushAudioFrame(
-                            buffer, read, SAMPLE_RATE, CHANNELS,
+                            buffer, read, SAMPLE_RATE, CHANNELS, captureNs,
                         )
                     }
                 }
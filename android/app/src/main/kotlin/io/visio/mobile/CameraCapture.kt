@@ -3,17 +3,24 @@ package io.visio.mobile
 import android.annotation.SuppressLint
 import android.content.Context
 import android.graphics.ImageFormat
+import android.graphics.Rect
 import android.hardware.camera2.CameraCaptureSession
 import android.hardware.camera2.CameraCharacteristics
 import android.hardware.camera2.CameraDevice
 import android.hardware.camera2.CameraManager
 import android.hardware.camera2.CaptureRequest
+import android.hardware.camera2.params.MeteringRectangle
 import android.hardware.display.DisplayManager
 import android.media.ImageReader
 import android.os.Handler
 import android.os.HandlerThread
+import android.os.SystemClock
 import android.util.Log
+import android.util.Range
+import android.util.Size
 import android.view.Display
+import uniffi.visio.CameraCommand
+import uniffi.visio.CaptureConstraints
 
 /**
  * Captures camera frames via Camera2 API and pushes them into the
@@ -37,11 +44,31 @@ class CameraCapture(private val context: Context) {
     private var handler: Handler? = null
     @Volatile private var sensorOrientation: Int = 0
     @Volatile private var isFrontCamera: Boolean = false
+    // Sensor timestamps use elapsedRealtime (REALTIME source) on most devices
+    @Volatile private var realtimeTimestamps: Boolean = false
+    @Volatile private var currentCameraId: String? = null
     @Volatile private var running = false
+
+    // Torch, zoom and focus requested through VisioClient camera commands
+    @Volatile private var torchEnabled = false
+    @Volatile private var zoomFactor = 1f
+    @Volatile private var focusPoint: Pair<Float, Float>? = null
+    @Volatile private var lastRotation = 0
+
+    // Target format from VisioClient; null captures at WIDTH x HEIGHT
+    @Volatile private var constraints: CaptureConstraints? = null
     private val displayManager = context.getSystemService(Context.DISPLAY_SERVICE) as DisplayManager
 
+    /** Sensor timestamp converted to the System.nanoTime() clock. */
+    private fun captureTimestampNs(sensorTimestampNs: Long): Long =
+        if (realtimeTimestamps) {
+            sensorTimestampNs - (SystemClock.elapsedRealtimeNanos() - System.nanoTime())
+        } else {
+            sensorTimestampNs
+        }
+
     @SuppressLint("MissingPermission") // Caller must check CAMERA permission first
-    fun start() {
+    fun start(preferredCameraId: String? = null) {
         if (running) return
         running = true
 
@@ -51,7 +78,11 @@ class CameraCapture(private val context: Context) {
         handler = Handler(thread.looper)
 
         val cameraManager = context.getSystemService(Context.CAMERA_SERVICE) as CameraManager
-        val cameraId = findFrontCamera(cameraManager) ?: findBackCamera(cameraManager)
+        // Preferred camera if still present, else front, else back
+        val cameraId =
+            preferredCameraId?.takeIf { it in cameraManager.cameraIdList }
+                ?: findFrontCamera(cameraManager)
+                ?: findBackCamera(cameraManager)
         if (cameraId == null) {
             Log.e(TAG, "No camera found")
             running = false
@@ -61,11 +92,16 @@ class CameraCapture(private val context: Context) {
         val chars = cameraManager.getCameraCharacteristics(cameraId)
         sensorOrientation = chars.get(CameraCharacteristics.SENSOR_ORIENTATION) ?: 0
         isFrontCamera = chars.get(CameraCharacteristics.LENS_FACING) == CameraCharacteristics.LENS_FACING_FRONT
+        realtimeTimestamps =
+            chars.get(CameraCharacteristics.SENSOR_INFO_TIMESTAMP_SOURCE) ==
+            CameraCharacteristics.SENSOR_INFO_TIMESTAMP_SOURCE_REALTIME
+        currentCameraId = cameraId
         Log.i(TAG, "Camera $cameraId: sensorOrientation=$sensorOrientation, front=$isFrontCamera")
 
         // ImageReader receives YUV_420_888 frames
+        val size = captureSize(chars)
         val newReader =
-            ImageReader.newInstance(WIDTH, HEIGHT, ImageFormat.YUV_420_888, MAX_IMAGES).apply {
+            ImageReader.newInstance(size.width, size.height, ImageFormat.YUV_420_888, MAX_IMAGES).apply {
                 setOnImageAvailableListener({ reader ->
                     val image =
                         reader.acquireLatestImage() ?: run {
@@ -90,6 +126,7 @@ class CameraCapture(private val context: Context) {
                             } else {
                                 (sensorOrientation - displayDegrees + 360) % 360
                             }
+                        lastRotation = rotation
 
                         NativeVideo.nativePushCameraFrame(
                             yPlane.buffer,
@@ -103,6 +140,7 @@ class CameraCapture(private val context: Context) {
                             image.width,
                             image.height,
                             rotation,
+                            captureTimestampNs(image.timestamp),
                         )
                     } finally {
                         image.close()
@@ -190,11 +228,17 @@ class CameraCapture(private val context: Context) {
         val chars = cameraManager.getCameraCharacteristics(newId)
         sensorOrientation = chars.get(CameraCharacteristics.SENSOR_ORIENTATION) ?: 0
         isFrontCamera = chars.get(CameraCharacteristics.LENS_FACING) == CameraCharacteristics.LENS_FACING_FRONT
+        realtimeTimestamps =
+            chars.get(CameraCharacteristics.SENSOR_INFO_TIMESTAMP_SOURCE) ==
+            CameraCharacteristics.SENSOR_INFO_TIMESTAMP_SOURCE_REALTIME
+        currentCameraId = newId
+        focusPoint = null
         Log.i(TAG, "Switching to camera $newId: sensorOrientation=$sensorOrientation, front=$isFrontCamera")
 
         // Recreate ImageReader
+        val size = captureSize(chars)
         val newReader =
-            ImageReader.newInstance(WIDTH, HEIGHT, ImageFormat.YUV_420_888, MAX_IMAGES).apply {
+            ImageReader.newInstance(size.width, size.height, ImageFormat.YUV_420_888, MAX_IMAGES).apply {
                 setOnImageAvailableListener({ reader ->
                     val image = reader.acquireLatestImage() ?: return@setOnImageAvailableListener
                     try {
@@ -212,11 +256,13 @@ class CameraCapture(private val context: Context) {
                             } else {
                                 (sensorOrientation - displayDegrees + 360) % 360
                             }
+                        lastRotation = rotation
                         NativeVideo.nativePushCameraFrame(
                             yPlane.buffer, uPlane.buffer, vPlane.buffer,
                             yPlane.rowStride, uPlane.rowStride, vPlane.rowStride,
                             uPlane.pixelStride, vPlane.pixelStride,
                             image.width, image.height, rotation,
+                            captureTimestampNs(image.timestamp),
                         )
                     } finally {
                         image.close()
@@ -253,9 +299,52 @@ class CameraCapture(private val context: Context) {
         )
     }
 
+    /**
+     * Apply a torch, zoom or focus command. Commands received before the
+     * session is configured are applied when it starts.
+     */
+    fun apply(command: CameraCommand) {
+        when (command) {
+            is CameraCommand.SetTorch -> torchEnabled = command.enabled
+            is CameraCommand.SetZoom -> zoomFactor = command.factor
+            is CameraCommand.SetFocusPoint -> focusPoint = command.x to command.y
+        }
+        handler?.post { updateRepeatingRequest() }
+    }
+
+    /**
+     * Apply new capture constraints. Frame rate and low-light mode change
+     * in place; returns true if the resolution changed, which takes a
+     * capture restart.
+     */
+    fun setConstraints(newConstraints: CaptureConstraints): Boolean {
+        val old = constraints
+        constraints = newConstraints
+        if (running && old != null && (old.width != newConstraints.width || old.height != newConstraints.height)) {
+            return true
+        }
+        handler?.post { updateRepeatingRequest() }
+        return false
+    }
+
+    /** Largest YUV output size within the constraints. */
+    private fun captureSize(chars: CameraCharacteristics): Size {
+        val c = constraints ?: return Size(WIDTH, HEIGHT)
+        val sizes =
+            chars.get(CameraCharacteristics.SCALER_STREAM_CONFIGURATION_MAP)
+                ?.getOutputSizes(ImageFormat.YUV_420_888)
+                .orEmpty()
+        return sizes
+            .filter { it.width <= c.width.toInt() && it.height <= c.height.toInt() }
+            .maxByOrNull { it.width * it.height }
+            ?: Size(WIDTH, HEIGHT)
+    }
+
     /** Returns true if currently using front camera. */
     fun isFront(): Boolean = isFrontCamera
 
+    fun cameraId(): String? = currentCameraId
+
     @Suppress("DEPRECATION")
     private fun createCaptureSession(camera: CameraDevice) {
         val reader = synchronized(lock) { imageReader } ?: return
@@ -270,14 +359,7 @@ class CameraCapture(private val context: Context) {
                         return
                     }
                     synchronized(lock) { captureSession = session }
-
-                    val request =
-                        camera.createCaptureRequest(CameraDevice.TEMPLATE_PREVIEW).apply {
-                            addTarget(surface)
-                            set(CaptureRequest.CONTROL_AF_MODE, CaptureRequest.CONTROL_AF_MODE_CONTINUOUS_VIDEO)
-                        }.build()
-
-                    session.setRepeatingRequest(request, null, handler)
+                    updateRepeatingRequest()
                     Log.i(TAG, "Camera capture session started")
                 }
 
@@ -289,6 +371,81 @@ class CameraCapture(private val context: Context) {
         )
     }
 
+    /** (Re)issue the preview request with the current torch, zoom and focus. */
+    private fun updateRepeatingRequest() {
+        val (camera, session, reader) =
+            synchronized(lock) { Triple(cameraDevice, captureSession, imageReader) }
+        if (camera == null || session == null || reader == null) return
+        val cameraManager = context.getSystemService(Context.CAMERA_SERVICE) as CameraManager
+        val chars = cameraManager.getCameraCharacteristics(camera.id)
+
+        val request =
+            camera.createCaptureRequest(CameraDevice.TEMPLATE_PREVIEW).apply {
+                addTarget(reader.surface)
+                set(CaptureRequest.CONTROL_AF_MODE, CaptureRequest.CONTROL_AF_MODE_CONTINUOUS_VIDEO)
+                if (torchEnabled && chars.get(CameraCharacteristics.FLASH_INFO_AVAILABLE) == true) {
+                    set(CaptureRequest.FLASH_MODE, CaptureRequest.FLASH_MODE_TORCH)
+                }
+                constraints?.let { c ->
+                    // Highest rate within max_fps; in low-light mode, the range
+                    // that lets the rate drop furthest for longer exposures
+                    val ranges = chars.get(CameraCharacteristics.CONTROL_AE_AVAILABLE_TARGET_FPS_RANGES).orEmpty()
+                    val range =
+                        ranges
+                            .filter { it.upper <= c.maxFps.toInt() }
+                            .maxWithOrNull(
+                                compareBy<Range<Int>>({ it.upper }, { if (c.lowLightBoost) -it.lower else it.lower }),
+                            )
+                    range?.let { set(CaptureRequest.CONTROL_AE_TARGET_FPS_RANGE, it) }
+                }
+                val active = chars.get(CameraCharacteristics.SENSOR_INFO_ACTIVE_ARRAY_SIZE) ?: return@apply
+                val maxZoom = chars.get(CameraCharacteristics.SCALER_AVAILABLE_MAX_DIGITAL_ZOOM) ?: 1f
+                val zoom = zoomFactor.coerceIn(1f, maxZoom)
+                val cropW = (active.width() / zoom).toInt()
+                val cropH = (active.height() / zoom).toInt()
+                val crop =
+                    Rect(
+                        active.centerX() - cropW / 2,
+                        active.centerY() - cropH / 2,
+                        active.centerX() + cropW / 2,
+                        active.centerY() + cropH / 2,
+                    )
+                set(CaptureRequest.SCALER_CROP_REGION, crop)
+
+                val (x, y) = focusPoint ?: return@apply
+                if ((chars.get(CameraCharacteristics.CONTROL_MAX_REGIONS_AF) ?: 0) == 0) return@apply
+                // The point is given on the upright frame: undo the frame rotation
+                val (sx, sy) =
+                    when (lastRotation) {
+                        90 -> y to 1f - x
+                        180 -> 1f - x to 1f - y
+                        270 -> 1f - y to x
+                        else -> x to y
+                    }
+                val size = minOf(crop.width(), crop.height()) / 10
+                val cx = crop.left + (sx * crop.width()).toInt()
+                val cy = crop.top + (sy * crop.height()).toInt()
+                val region =
+                    MeteringRectangle(
+                        (cx - size / 2).coerceIn(crop.left, crop.right - size),
+                        (cy - size / 2).coerceIn(crop.top, crop.bottom - size),
+                        size,
+                        size,
+                        MeteringRectangle.METERING_WEIGHT_MAX,
+                    )
+                set(CaptureRequest.CONTROL_AF_REGIONS, arrayOf(region))
+                if ((chars.get(CameraCharacteristics.CONTROL_MAX_REGIONS_AE) ?: 0) > 0) {
+                    set(CaptureRequest.CONTROL_AE_REGIONS, arrayOf(region))
+                }
+            }.build()
+
+        try {
+            session.setRepeatingRequest(request, null, handler)
+        } catch (e: Exception) {
+            Log.e(TAG, "Failed to update capture request", e)
+        }
+    }
+
     private fun findFrontCamera(manager: CameraManager): String? {
         return manager.cameraIdList.firstOrNull { id ->
             val chars = manager.getCameraCharacteristics(id)
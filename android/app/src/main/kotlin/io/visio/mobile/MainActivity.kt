@@ -8,6 +8,7 @@ import android.content.IntentFilter
 import android.os.Build
 import android.os.Bundle
 import android.util.Log
+import android.view.KeyEvent
 import android.util.Rational
 import androidx.activity.ComponentActivity
 import androidx.activity.compose.setContent
@@ -22,20 +23,22 @@ import kotlinx.coroutines.CoroutineScope
 import kotlinx.coroutines.Dispatchers
 import kotlinx.coroutines.launch
 import uniffi.visio.ConnectionState
+import uniffi.visio.DeepLink
+import uniffi.visio.MediaButton
+import uniffi.visio.parseDeepLink
 
 class MainActivity : ComponentActivity() {
-    private fun parseDeepLink(intent: Intent?): String? {
-        val uri = intent?.data ?: return null
-        if (uri.scheme != "visio") return null
-        val host = uri.host ?: return null
-        val slug = uri.path?.trimStart('/') ?: return null
-        if (host.isBlank() || slug.isBlank()) return null
-
-        val instances = VisioManager.client.getMeetInstances()
-        return if (instances.contains(host)) {
-            "https://$host/$slug"
-        } else {
-            null
+    private fun handleDeepLink(intent: Intent?) {
+        val uri = intent?.data ?: return
+        when (val link = parseDeepLink(uri.toString())) {
+            is DeepLink.JoinRoom -> {
+                val instances = VisioManager.client.getMeetInstances()
+                if (instances.contains(link.instance)) {
+                    VisioManager.pendingDeepLink = link
+                }
+            }
+            is DeepLink.OpenSettings -> VisioManager.pendingOpenSettings = true
+            null -> {}
         }
     }
 
@@ -77,7 +80,7 @@ class MainActivity : ComponentActivity() {
         enableEdgeToEdge()
         super.onCreate(savedInstanceState)
 
-        parseDeepLink(intent)?.let { VisioManager.pendingDeepLink = it }
+        handleDeepLink(intent)
 
         val filter =
             IntentFilter().apply {
@@ -105,7 +108,7 @@ class MainActivity : ComponentActivity() {
 
     override fun onNewIntent(intent: Intent) {
         super.onNewIntent(intent)
-        parseDeepLink(intent)?.let { VisioManager.pendingDeepLink = it }
+        handleDeepLink(intent)
     }
 
     override fun onPause() {
@@ -129,6 +132,23 @@ class MainActivity : ComponentActivity() {
         }
     }
 
+    override fun onKeyDown(
+        keyCode: Int,
+        event: KeyEvent,
+    ): Boolean {
+        val button =
+            when (keyCode) {
+                KeyEvent.KEYCODE_HEADSETHOOK, KeyEvent.KEYCODE_MEDIA_PLAY_PAUSE -> MediaButton.PLAY_PAUSE
+                KeyEvent.KEYCODE_ENDCALL -> MediaButton.HANGUP
+                else -> return super.onKeyDown(keyCode, event)
+            }
+        if (VisioManager.connectionState.value is ConnectionState.Disconnected) {
+            return super.onKeyDown(keyCode, event)
+        }
+        VisioManager.handleMediaButton(button)
+        return true
+    }
+
     override fun onUserLeaveHint() {
         super.onUserLeaveHint()
         val state = VisioManager.connectionState.value
@@ -15,6 +15,16 @@ object NativeVideo {
 
     external fun detachSurface(trackSid: String)
 
+    /**
+     * Swap the surface of a running renderer without restarting its video
+     * stream (no lost keyframe, no black frame). Returns false if no
+     * renderer is running for the track; use [attachSurface] then.
+     */
+    external fun replaceSurface(
+        trackSid: String,
+        surface: Surface,
+    ): Boolean
+
     /**
      * Push a YUV_420_888 camera frame into the LiveKit NativeVideoSource.
      * Called from CameraCapture's ImageReader callback.
@@ -22,6 +32,7 @@ object NativeVideo {
      * The ByteBuffers must be direct buffers pointing to the Y, U, V planes.
      * pixelStride indicates the byte spacing between consecutive pixel values
      * in each plane (1 for planar I420, 2 for semi-planar NV12/NV21).
+     * timestampNs is the capture time on the System.nanoTime() clock.
      */
     external fun nativePushCameraFrame(
         y: ByteBuffer,
@@ -35,6 +46,7 @@ object NativeVideo {
         width: Int,
         height: Int,
         rotation: Int,
+        timestampNs: Long,
     )
 
     /**
@@ -50,12 +62,14 @@ object NativeVideo {
      * @param numSamples Total number of samples in the buffer
      * @param sampleRate Sample rate in Hz (48000)
      * @param numChannels Number of audio channels (1 = mono)
+     * @param timestampNs Capture time of the first sample (System.nanoTime() clock)
      */
     external fun nativePushAudioFrame(
         data: ByteBuffer,
         numSamples: Int,
         sampleRate: Int,
         numChannels: Int,
+        timestampNs: Long,
     )
 
     /**
@@ -22,10 +22,17 @@ class VideoSurfaceView(
         width: Int,
         height: Int,
     ) {
-        Log.d(TAG, "surfaceCreated track=$trackSid ${width}x$height, attaching surface")
         val s = Surface(texture)
         surface = s
-        NativeVideo.attachSurface(trackSid, s)
+        // Another view still renders this track (e.g. recreated on rotation):
+        // take over its renderer instead of restarting the stream.
+        val previousOwner = owners.put(trackSid, this)
+        if (previousOwner != null && NativeVideo.replaceSurface(trackSid, s)) {
+            Log.d(TAG, "surfaceCreated track=$trackSid ${width}x$height, replaced surface")
+        } else {
+            Log.d(TAG, "surfaceCreated track=$trackSid ${width}x$height, attaching surface")
+            NativeVideo.attachSurface(trackSid, s)
+        }
     }
 
     override fun onSurfaceTextureSizeChanged(
@@ -37,8 +44,13 @@ class VideoSurfaceView(
     }
 
     override fun onSurfaceTextureDestroyed(texture: SurfaceTexture): Boolean {
-        Log.d(TAG, "surfaceDestroyed track=$trackSid, detaching surface")
-        NativeVideo.detachSurface(trackSid)
+        if (owners[trackSid] === this) {
+            Log.d(TAG, "surfaceDestroyed track=$trackSid, detaching surface")
+            owners.remove(trackSid)
+            NativeVideo.detachSurface(trackSid)
+        } else {
+            Log.d(TAG, "surfaceDestroyed track=$trackSid, renderer already moved to a new view")
+        }
         surface?.release()
         surface = null
         return true
@@ -50,5 +62,8 @@ class VideoSurfaceView(
 
     companion object {
         private const val TAG = "VideoSurfaceView"
+
+        // View currently owning the renderer of each track (main thread only).
+        private val owners = mutableMapOf<String, VideoSurfaceView>()
     }
 }
@@ -3,6 +3,7 @@ package io.visio.mobile
 import android.app.Application
 import android.util.Log
 import io.visio.mobile.ui.i18n.Strings
+import uniffi.visio.selfTest
 
 class VisioApplication : Application() {
     companion object {
@@ -22,6 +23,10 @@ class VisioApplication : Application() {
         } catch (e: UnsatisfiedLinkError) {
             Log.e("Visio", "nativeInitWebrtc failed: ${e.message}")
         }
+        val report = selfTest(filesDir.absolutePath)
+        if (report.hasProblem) {
+            Log.e("Visio", "Startup self test failed: $report")
+        }
         Strings.init(this)
         VisioManager.initialize(applicationContext)
     }
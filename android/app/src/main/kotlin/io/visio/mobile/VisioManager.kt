@@ -1,11 +1,14 @@
 package io.visio.mobile
 
+import android.Manifest
 import android.content.Context
+import android.content.pm.PackageManager
 import android.media.AudioDeviceInfo
 import android.media.AudioManager
 import android.os.Build
 import android.os.PowerManager
 import android.util.Log
+import androidx.core.content.ContextCompat
 import androidx.compose.runtime.getValue
 import androidx.compose.runtime.mutableStateOf
 import androidx.compose.runtime.setValue
@@ -13,16 +16,42 @@ import kotlinx.coroutines.CoroutineScope
 import kotlinx.coroutines.Dispatchers
 import kotlinx.coroutines.SupervisorJob
 import kotlinx.coroutines.cancel
+import kotlinx.coroutines.flow.MutableSharedFlow
 import kotlinx.coroutines.flow.MutableStateFlow
+import kotlinx.coroutines.flow.SharedFlow
 import kotlinx.coroutines.flow.StateFlow
+import kotlinx.coroutines.flow.asSharedFlow
 import kotlinx.coroutines.flow.asStateFlow
+import kotlinx.coroutines.flow.update
 import kotlinx.coroutines.launch
+import kotlinx.coroutines.withContext
+import uniffi.visio.CallSummary
+import uniffi.visio.CameraCommand
+import uniffi.visio.CameraCommandListener
+import uniffi.visio.CaptureConstraints
 import uniffi.visio.ChatMessage
+import uniffi.visio.ConnectStep
 import uniffi.visio.ConnectionState
+import uniffi.visio.DeepLink
+import uniffi.visio.DeviceCheckReport
+import uniffi.visio.DevicePermission
+import uniffi.visio.FeatureFlags
+import uniffi.visio.LocalRecordingOptions
+import uniffi.visio.LocalPermissions
+import uniffi.visio.MediaButton
 import uniffi.visio.ParticipantInfo
+import uniffi.visio.PermissionState
+import uniffi.visio.Poll
+import uniffi.visio.PowerMode
+import uniffi.visio.Presence
+import uniffi.visio.QualityReductionReason
+import uniffi.visio.RecordingMode
+import uniffi.visio.RecordingStatus
+import uniffi.visio.ThermalState
 import uniffi.visio.VisioClient
 import uniffi.visio.VisioEvent
 import uniffi.visio.VisioEventListener
+import uniffi.visio.WhiteboardSession
 
 object VisioManager : VisioEventListener {
     // Library loaded and WebRTC initialized by VisioApplication.onCreate()
@@ -55,10 +84,89 @@ object VisioManager : VisioEventListener {
     private val _activeSpeakers = MutableStateFlow<List<String>>(emptyList())
     val activeSpeakers: StateFlow<List<String>> = _activeSpeakers.asStateFlow()
 
+    private val _layoutOrder = MutableStateFlow<List<String>>(emptyList())
+    val layoutOrder: StateFlow<List<String>> = _layoutOrder.asStateFlow()
+
+    // Track shown large in speaker view (null: avatar of spotlightParticipant)
+    private val _spotlightParticipant = MutableStateFlow<String?>(null)
+    val spotlightParticipant: StateFlow<String?> = _spotlightParticipant.asStateFlow()
+    private val _spotlightTrack = MutableStateFlow<String?>(null)
+    val spotlightTrack: StateFlow<String?> = _spotlightTrack.asStateFlow()
+
+    // Video tracks that stopped delivering frames (show a spinner on the tile)
+    private val _stalledTracks = MutableStateFlow<Set<String>>(emptySet())
+    val stalledTracks: StateFlow<Set<String>> = _stalledTracks.asStateFlow()
+
+    // What the local participant may do in the room (null when disconnected)
+    private val _localPermissions = MutableStateFlow<LocalPermissions?>(null)
+    val localPermissions: StateFlow<LocalPermissions?> = _localPermissions.asStateFlow()
+
+    // Knocking on a restricted room, waiting for the host to let us in
+    private val _waitingForHost = MutableStateFlow(false)
+    val waitingForHost: StateFlow<Boolean> = _waitingForHost.asStateFlow()
+
+    // Features of the Meet instance (hide buttons of unsupported ones)
+    private val _featureFlags = MutableStateFlow<FeatureFlags?>(null)
+    val featureFlags: StateFlow<FeatureFlags?> = _featureFlags.asStateFlow()
+
+    // The instance offers a collaborative whiteboard for the current room
+    private val _whiteboardAvailable = MutableStateFlow(false)
+    val whiteboardAvailable: StateFlow<Boolean> = _whiteboardAvailable.asStateFlow()
+
+    // Current room only admits participants through the lobby
+    private val _roomLocked = MutableStateFlow(false)
+    val roomLocked: StateFlow<Boolean> = _roomLocked.asStateFlow()
+
+    // Cloud recording of the current room
+    private val _recordingStatus = MutableStateFlow(RecordingStatus.STOPPED)
+    val recordingStatus: StateFlow<RecordingStatus> = _recordingStatus.asStateFlow()
+
+    // Target format of the camera capture, lowered on a poor connection
+    private val _captureConstraints = MutableStateFlow<CaptureConstraints?>(null)
+    val captureConstraints: StateFlow<CaptureConstraints?> = _captureConstraints.asStateFlow()
+
+    // Battery saver: lower capture and rendering rates, no self-view
+    private val _powerMode = MutableStateFlow(PowerMode.NORMAL)
+    val powerMode: StateFlow<PowerMode> = _powerMode.asStateFlow()
+
+    // Why video quality is currently lowered (null when it is not)
+    private val _qualityReduction = MutableStateFlow<QualityReductionReason?>(null)
+    val qualityReduction: StateFlow<QualityReductionReason?> = _qualityReduction.asStateFlow()
+
+    // OS permissions as reported to the core, for permission badges
+    private val _devicePermissions =
+        MutableStateFlow<Map<DevicePermission, PermissionState>>(emptyMap())
+    val devicePermissions: StateFlow<Map<DevicePermission, PermissionState>> =
+        _devicePermissions.asStateFlow()
+
+    // Duration of the running local recording (null when not recording)
+    private val _localRecordingMs = MutableStateFlow<Long?>(null)
+    val localRecordingMs: StateFlow<Long?> = _localRecordingMs.asStateFlow()
+
+    // Fires when the host refused our entry request
+    private val _entryDenied = MutableSharedFlow<Unit>(extraBufferCapacity = 1)
+    val entryDenied: SharedFlow<Unit> = _entryDenied.asSharedFlow()
+
     // Hand raise: map of participant_sid -> queue position (0 = not raised)
     private val _handRaisedMap = MutableStateFlow<Map<String, Int>>(emptyMap())
     val handRaisedMap: StateFlow<Map<String, Int>> = _handRaisedMap.asStateFlow()
 
+    // Presence advertised by the local participant (remote ones are in ParticipantInfo)
+    private val _presence = MutableStateFlow(Presence.AVAILABLE)
+    val presence: StateFlow<Presence> = _presence.asStateFlow()
+
+    // Bad-network fallback in effect: the core turned the camera off
+    private val _qualityFallback = MutableStateFlow(false)
+    val qualityFallback: StateFlow<Boolean> = _qualityFallback.asStateFlow()
+
+    // Summary of the last call, for the post-call rating screen
+    private val _lastCallSummary = MutableStateFlow<CallSummary?>(null)
+    val lastCallSummary: StateFlow<CallSummary?> = _lastCallSummary.asStateFlow()
+
+    // App-level attributes per participant_sid, as reported by the core
+    private val _participantAttributes = MutableStateFlow<Map<String, Map<String, String>>>(emptyMap())
+    val participantAttributes: StateFlow<Map<String, Map<String, String>>> = _participantAttributes.asStateFlow()
+
     // Unread chat message count
     private val _unreadCount = MutableStateFlow(0)
     val unreadCount: StateFlow<Int> = _unreadCount.asStateFlow()
@@ -72,8 +180,37 @@ object VisioManager : VisioEventListener {
     private val _reactions = MutableStateFlow<List<ReactionData>>(emptyList())
     val reactions: StateFlow<List<ReactionData>> = _reactions.asStateFlow()
 
-    // Deep link: pre-fill room URL on HomeScreen
-    var pendingDeepLink: String? by mutableStateOf(null)
+    // Polls of the room, oldest first
+    private val _polls = MutableStateFlow<List<Poll>>(emptyList())
+    val polls: StateFlow<List<Poll>> = _polls.asStateFlow()
+
+    // Custom app messages on topics subscribed with subscribeDataTopic()
+    private val _dataMessages = MutableSharedFlow<VisioEvent.DataMessageReceived>(extraBufferCapacity = 64)
+    val dataMessages: SharedFlow<VisioEvent.DataMessageReceived> = _dataMessages.asSharedFlow()
+
+    // Fires when the local hand was lowered because the user kept speaking
+    private val _handAutoLowered = MutableSharedFlow<Unit>(extraBufferCapacity = 1)
+    val handAutoLowered: SharedFlow<Unit> = _handAutoLowered.asSharedFlow()
+
+    // Minutes left before the meeting's scheduled end once warned, 0 when over
+    private val _meetingMinutesLeft = MutableStateFlow<Int?>(null)
+    val meetingMinutesLeft: StateFlow<Int?> = _meetingMinutesLeft.asStateFlow()
+
+    // Idle checks: alone in the room, or no activity for a while (see IdleMonitor)
+    private val _aloneInRoom = MutableStateFlow(false)
+    val aloneInRoom: StateFlow<Boolean> = _aloneInRoom.asStateFlow()
+    private val _inactivityPrompt = MutableStateFlow(false)
+    val inactivityPrompt: StateFlow<Boolean> = _inactivityPrompt.asStateFlow()
+
+    // Last completed connection step (null when idle)
+    private val _connectStep = MutableStateFlow<ConnectStep?>(null)
+    val connectStep: StateFlow<ConnectStep?> = _connectStep.asStateFlow()
+
+    // Deep link: pre-fill room URL (and display name) on HomeScreen
+    var pendingDeepLink: DeepLink.JoinRoom? by mutableStateOf(null)
+
+    // Deep link: open the settings screen
+    var pendingOpenSettings by mutableStateOf(false)
 
     // Observable state for language, theme, display name
     var currentLang by mutableStateOf("fr")
@@ -91,6 +228,13 @@ object VisioManager : VisioEventListener {
         val dataDir = context.filesDir.absolutePath
         _client = VisioClient(dataDir)
         _client.addListener(this)
+        _client.addCameraCommandListener(
+            object : CameraCommandListener {
+                override fun onCameraCommand(command: CameraCommand) {
+                    cameraCapture?.apply(command)
+                }
+            },
+        )
         // Load persisted settings
         try {
             val settings = _client.getSettings()
@@ -113,9 +257,56 @@ object VisioManager : VisioEventListener {
         } catch (e: Exception) {
             Log.e("VisioManager", "Failed to load blur model", e)
         }
+        // Step video quality down as the device heats up
+        if (Build.VERSION.SDK_INT >= Build.VERSION_CODES.Q) {
+            val pm = appContext.getSystemService(Context.POWER_SERVICE) as PowerManager
+            pm.addThermalStatusListener { status ->
+                _client.reportThermalState(thermalState(status))
+            }
+        }
+        refreshPermissions()
         initialized = true
     }
 
+    /**
+     * Report the current permissions to the core. Unlike iOS, Android does
+     * not prompt on first use: a permission not granted yet is reported as
+     * denied until the user grants it (reportPermission).
+     */
+    fun refreshPermissions() {
+        val permissions =
+            buildMap {
+                put(DevicePermission.CAMERA, Manifest.permission.CAMERA)
+                put(DevicePermission.MICROPHONE, Manifest.permission.RECORD_AUDIO)
+                if (Build.VERSION.SDK_INT >= Build.VERSION_CODES.TIRAMISU) {
+                    put(DevicePermission.NOTIFICATIONS, Manifest.permission.POST_NOTIFICATIONS)
+                }
+            }
+        for ((permission, name) in permissions) {
+            val granted = ContextCompat.checkSelfPermission(appContext, name) == PackageManager.PERMISSION_GRANTED
+            reportPermission(permission, granted)
+        }
+    }
+
+    /** Report the result of a permission prompt to the core. */
+    fun reportPermission(
+        permission: DevicePermission,
+        granted: Boolean,
+    ) {
+        _client.setPermissionState(
+            permission,
+            if (granted) PermissionState.GRANTED else PermissionState.DENIED,
+        )
+    }
+
+    private fun thermalState(status: Int): ThermalState =
+        when (status) {
+            PowerManager.THERMAL_STATUS_NONE -> ThermalState.NOMINAL
+            PowerManager.THERMAL_STATUS_LIGHT -> ThermalState.FAIR
+            PowerManager.THERMAL_STATUS_MODERATE -> ThermalState.SERIOUS
+            else -> ThermalState.CRITICAL
+        }
+
     fun setTheme(theme: String) {
         currentTheme = theme
         scope.launch { client.setTheme(theme) }
@@ -136,7 +327,15 @@ object VisioManager : VisioEventListener {
      */
     fun startCameraCapture() {
         if (cameraCapture != null) return
-        cameraCapture = CameraCapture(appContext).also { it.start() }
+        val preferred = _client.getSettings().preferredCameraId
+        val controls = _client.getCameraControlState()
+        cameraCapture =
+            CameraCapture(appContext).also {
+                it.apply(CameraCommand.SetTorch(controls.torchEnabled))
+                it.apply(CameraCommand.SetZoom(controls.zoomFactor))
+                it.setConstraints(_client.getCaptureConstraints())
+                it.start(preferred)
+            }
     }
 
     /**
@@ -149,16 +348,154 @@ object VisioManager : VisioEventListener {
 
     fun switchCamera(useFront: Boolean) {
         cameraCapture?.switchCamera(useFront)
+        cameraCapture?.cameraId()?.let { _client.setPreferredCameraId(it) }
     }
 
     fun isFrontCamera(): Boolean = cameraCapture?.isFront() ?: true
 
+    fun setTorchEnabled(enabled: Boolean) {
+        _client.setTorchEnabled(enabled)
+    }
+
+    fun setZoom(factor: Float) {
+        _client.setZoom(factor)
+    }
+
+    private fun upsertPoll(poll: Poll) {
+        _polls.value =
+            if (_polls.value.any { it.id == poll.id }) {
+                _polls.value.map { if (it.id == poll.id) poll else it }
+            } else {
+                _polls.value + poll
+            }
+    }
+
+    fun createPoll(
+        question: String,
+        options: List<String>,
+    ) {
+        scope.launch {
+            try {
+                _client.createPoll(question, options)
+            } catch (e: Exception) {
+                Log.e("VisioManager", "Failed to create poll", e)
+            }
+        }
+    }
+
+    fun votePoll(
+        pollId: String,
+        option: Int,
+    ) {
+        scope.launch {
+            try {
+                _client.votePoll(pollId, option.toUInt())
+            } catch (e: Exception) {
+                Log.e("VisioManager", "Failed to vote in poll $pollId", e)
+            }
+        }
+    }
+
+    fun closePoll(pollId: String) {
+        scope.launch {
+            try {
+                _client.closePoll(pollId)
+            } catch (e: Exception) {
+                Log.e("VisioManager", "Failed to close poll $pollId", e)
+            }
+        }
+    }
+
+    fun setPresence(presence: Presence) {
+        scope.launch {
+            try {
+                _client.setPresence(presence)
+            } catch (e: Exception) {
+                Log.e("VisioManager", "Failed to set presence $presence", e)
+            }
+        }
+    }
+
+    fun setLocalAttributes(attributes: Map<String, String>) {
+        scope.launch {
+            try {
+                _client.setLocalAttributes(attributes)
+            } catch (e: Exception) {
+                Log.e("VisioManager", "Failed to set attributes ${attributes.keys}", e)
+            }
+        }
+    }
+
+    fun publishData(
+        topic: String,
+        payload: ByteArray,
+        reliable: Boolean = true,
+    ) {
+        scope.launch {
+            try {
+                _client.publishData(topic, payload, reliable)
+            } catch (e: Exception) {
+                Log.e("VisioManager", "Failed to publish data on $topic", e)
+            }
+        }
+    }
+
+    fun subscribeDataTopic(topic: String) {
+        _client.subscribeDataTopic(topic)
+    }
+
+    fun unsubscribeDataTopic(topic: String) {
+        _client.unsubscribeDataTopic(topic)
+    }
+
+    /** Let the camera lower its frame rate for better exposure in dim light. */
+    fun setLowLightBoost(enabled: Boolean) {
+        _client.setLowLightBoost(enabled)
+    }
+
+    /** Switch battery saver mode on or off for all rooms and renderers. */
+    fun setPowerMode(mode: PowerMode) {
+        _client.setPowerMode(mode)
+    }
+
+    /** Focus on a point of the self-view, normalized to 0..1. */
+    fun setFocusPoint(
+        x: Float,
+        y: Float,
+    ) {
+        _client.setFocusPoint(x, y)
+    }
+
+    /**
+     * Forward a headset button press. The core debounces it, toggles the
+     * mic or hangs up, and reports it with MediaButtonHandled.
+     */
+    fun handleMediaButton(button: MediaButton) {
+        scope.launch {
+            try {
+                _client.handleMediaButton(button)
+            } catch (e: Exception) {
+                Log.e("VISIO", "Media button failed: ${e.message}")
+            }
+        }
+    }
+
+    /**
+     * Answer the inactivity prompt: the user is still there.
+     */
+    fun stillHere() {
+        _inactivityPrompt.value = false
+        _client.reportActivity()
+    }
+
     /**
      * Start AudioRecord capture. Call after setMicrophoneEnabled(true) succeeds.
      */
     fun startAudioCapture() {
         if (audioCapture != null) return
         audioCapture = AudioCapture().also { it.start() }
+        findAudioDevice(_client.getSettings().preferredMicrophoneId, AudioManager.GET_DEVICES_INPUTS)
+            ?.let { audioCapture?.setPreferredDevice(it) }
     }
 
     /**
@@ -185,6 +522,8 @@ object VisioManager : VisioEventListener {
                 acquire(4 * 60 * 60 * 1000L) // 4-hour timeout as safety net
             }
         audioPlayout = AudioPlayout().also { it.start() }
+        findAudioDevice(_client.getSettings().preferredSpeakerId, AudioManager.GET_DEVICES_OUTPUTS)
+            ?.let { audioPlayout?.setPreferredDevice(it) }
     }
 
     /**
@@ -205,6 +544,7 @@ object VisioManager : VisioEventListener {
      * Route audio input to a specific device.
      */
     fun setAudioInputDevice(device: AudioDeviceInfo) {
+        _client.setPreferredMicrophoneId(device.id.toString())
         audioCapture?.setPreferredDevice(device)
         if (Build.VERSION.SDK_INT >= Build.VERSION_CODES.S) {
             val am = appContext.getSystemService(Context.AUDIO_SERVICE) as AudioManager
@@ -216,6 +556,7 @@ object VisioManager : VisioEventListener {
      * Route audio output to a specific device.
      */
     fun setAudioOutputDevice(device: AudioDeviceInfo) {
+        _client.setPreferredSpeakerId(device.id.toString())
         audioPlayout?.setPreferredDevice(device)
         if (Build.VERSION.SDK_INT >= Build.VERSION_CODES.S) {
             val am = appContext.getSystemService(Context.AUDIO_SERVICE) as AudioManager
@@ -223,6 +564,16 @@ object VisioManager : VisioEventListener {
         }
     }
 
+    /**
+     * Connected audio device with the persisted [id], or null when unset or
+     * unplugged (the system default is used then).
+     */
+    private fun findAudioDevice(id: String?, flags: Int): AudioDeviceInfo? {
+        if (id == null) return null
+        val am = appContext.getSystemService(Context.AUDIO_SERVICE) as AudioManager
+        return am.getDevices(flags).firstOrNull { it.id.toString() == id }
+    }
+
     /**
      * Full teardown: stop captures, playout, cancel pending coroutines, disconnect.
      */
@@ -230,6 +581,125 @@ object VisioManager : VisioEventListener {
         scope.launch { client.sendReaction(emoji) }
     }
 
+    fun inviteParticipants(emails: List<String>) {
+        scope.launch {
+            try {
+                client.inviteParticipants(emails)
+            } catch (e: Exception) {
+                Log.e("VISIO", "Invitation failed: ${e.message}")
+            }
+        }
+    }
+
+    /**
+     * Pre-join device check: capture briefly from the selected microphone (and camera
+     * with checkCamera) and report a silent mic, a camera sending no frames or a
+     * speaker that cannot be opened. Needs the RECORD_AUDIO (and CAMERA) permission.
+     * Captures already running for a call are left as they are.
+     */
+    suspend fun runDeviceCheck(checkCamera: Boolean): DeviceCheckReport =
+        withContext(Dispatchers.IO) {
+            val speakerError =
+                try {
+                    AudioPlayout().apply {
+                        start()
+                        stop()
+                    }
+                    null
+                } catch (e: Exception) {
+                    e.message ?: "audio output failed"
+                }
+            val startedMic = audioCapture == null
+            val startedCamera = checkCamera && cameraCapture == null
+            if (startedMic) startAudioCapture()
+            if (startedCamera) startCameraCapture()
+            try {
+                _client.runDeviceCheck(1500u, speakerError, checkCamera)
+            } finally {
+                if (startedMic) stopAudioCapture()
+                if (startedCamera) stopCameraCapture()
+            }
+        }
+
+    /** URL and credentials to open the whiteboard in a WebView, or null if unavailable. */
+    suspend fun getWhiteboardSession(): WhiteboardSession? =
+        withContext(Dispatchers.IO) {
+            try {
+                client.getWhiteboardSession()
+            } catch (e: Exception) {
+                Log.e("VISIO", "Whiteboard session failed: ${e.message}")
+                null
+            }
+        }
+
+    fun setRoomLocked(locked: Boolean) {
+        scope.launch {
+            try {
+                client.lockRoom(locked)
+            } catch (e: Exception) {
+                Log.e("VISIO", "Room lock failed: ${e.message}")
+            }
+        }
+    }
+
+    fun startRecording(mode: RecordingMode) {
+        scope.launch {
+            try {
+                client.startRecording(mode)
+            } catch (e: Exception) {
+                Log.e("VISIO", "Start recording failed: ${e.message}")
+            }
+        }
+    }
+
+    /** Record the call audio to a WAV file in the app's recordings dir. */
+    fun startLocalRecording() {
+        scope.launch {
+            try {
+                val dir = java.io.File(appContext.filesDir, "recordings")
+                val file = java.io.File(dir, "visio-${System.currentTimeMillis()}.wav")
+                client.startLocalRecording(file.absolutePath, LocalRecordingOptions(maxSizeBytes = null))
+                _localRecordingMs.value = 0L
+            } catch (e: Exception) {
+                Log.e("VISIO", "Local recording failed: ${e.message}")
+            }
+        }
+    }
+
+    fun stopLocalRecording() {
+        scope.launch {
+            try {
+                client.stopLocalRecording()
+            } catch (e: Exception) {
+                Log.e("VISIO", "Stop local recording failed: ${e.message}")
+            }
+            _localRecordingMs.value = null
+        }
+    }
+
+    /** Save the last frame of a video track as PNG; returns the file, or null. */
+    fun saveSnapshot(trackSid: String): java.io.File? {
+        val dir = java.io.File(appContext.filesDir, "snapshots").apply { mkdirs() }
+        val file = java.io.File(dir, "visio-${System.currentTimeMillis()}.png")
+        return try {
+            client.saveSnapshot(trackSid, file.absolutePath)
+            file
+        } catch (e: Exception) {
+            Log.e("VISIO", "Snapshot failed: ${e.message}")
+            null
+        }
+    }
+
+    fun stopRecording() {
+        scope.launch {
+            try {
+                client.stopRecording()
+            } catch (e: Exception) {
+                Log.e("VISIO", "Stop recording failed: ${e.message}")
+            }
+        }
+    }
+
     fun disconnect() {
         stopCameraCapture()
         stopAudioCapture()
@@ -305,9 +775,26 @@ object VisioManager : VisioEventListener {
                         CallForegroundService.start(appContext)
                     }
                     is ConnectionState.Disconnected -> {
+                        _connectStep.value = null
+                        _stalledTracks.value = emptySet()
+                        _localPermissions.value = null
+                        _waitingForHost.value = false
+                        _roomLocked.value = false
+                        _recordingStatus.value = RecordingStatus.STOPPED
+                        _captureConstraints.value = null
+                        _polls.value = emptyList()
+                        _localRecordingMs.value = null
+                        _featureFlags.value = null
+                        _whiteboardAvailable.value = false
                         _handRaisedMap.value = emptyMap()
                         _unreadCount.value = 0
                         _isHandRaised.value = false
+                        _presence.value = Presence.AVAILABLE
+                        _participantAttributes.value = emptyMap()
+                        _qualityFallback.value = false
+                        _meetingMinutesLeft.value = null
+                        _aloneInRoom.value = false
+                        _inactivityPrompt.value = false
                         CallForegroundService.stop(appContext)
                     }
                     else -> {}
@@ -322,6 +809,10 @@ object VisioManager : VisioEventListener {
                 val sid = event.participantSid
                 _handRaisedMap.value = _handRaisedMap.value.minus(sid)
             }
+            is VisioEvent.ParticipantBatchUpdate -> {
+                refreshParticipants()
+                _handRaisedMap.value = _handRaisedMap.value.minus(event.left.toSet())
+            }
             is VisioEvent.TrackMuted -> {
                 refreshParticipants()
             }
@@ -331,9 +822,39 @@ object VisioManager : VisioEventListener {
             is VisioEvent.ActiveSpeakersChanged -> {
                 _activeSpeakers.value = event.participantSids
             }
+            is VisioEvent.LayoutOrderChanged -> {
+                _layoutOrder.value = event.participantSids
+            }
+            is VisioEvent.SpotlightChanged -> {
+                _spotlightParticipant.value = event.participantSid
+                _spotlightTrack.value = event.trackSid
+            }
             is VisioEvent.ConnectionQualityChanged -> {
                 refreshParticipants()
             }
+            is VisioEvent.PresenceChanged -> {
+                refreshParticipants()
+                _presence.value = _client.presence()
+            }
+            is VisioEvent.QualityPolicyTriggered -> {
+                _qualityFallback.value = event.active
+                if (event.camera) {
+                    // Follow the camera the core turned off or back on
+                    scope.launch {
+                        if (event.active) stopCameraCapture() else startCameraCapture()
+                        refreshParticipants()
+                    }
+                }
+            }
+            is VisioEvent.CallEnded -> {
+                _lastCallSummary.value = event.summary
+            }
+            is VisioEvent.ParticipantAttributesChanged -> {
+                val sid = event.participantSid
+                // Empty values are removed keys.
+                val merged = (_participantAttributes.value[sid].orEmpty() + event.changed).filterValues { it.isNotEmpty() }
+                _participantAttributes.value = _participantAttributes.value.plus(sid to merged)
+            }
             is VisioEvent.ChatMessageReceived -> {
                 refreshChatMessages()
             }
@@ -364,8 +885,110 @@ object VisioManager : VisioEventListener {
             }
             is VisioEvent.TrackUnsubscribed -> {
                 Log.d("VISIO", "TrackUnsubscribed: trackSid=${event.trackSid}")
+                _stalledTracks.value = _stalledTracks.value - event.trackSid
+                refreshParticipants()
+            }
+            is VisioEvent.TrackStalled -> {
+                _stalledTracks.value = _stalledTracks.value + event.trackSid
+            }
+            is VisioEvent.TrackResumed -> {
+                _stalledTracks.value = _stalledTracks.value - event.trackSid
+            }
+            is VisioEvent.PermissionsChanged -> {
+                _localPermissions.value = event.permissions
+            }
+            is VisioEvent.WaitingForHost -> {
+                _waitingForHost.value = true
+            }
+            is VisioEvent.EntryGranted -> {
+                _waitingForHost.value = false
+            }
+            is VisioEvent.EntryDenied -> {
+                _waitingForHost.value = false
+                _entryDenied.tryEmit(Unit)
+            }
+            is VisioEvent.FeatureFlagsChanged -> {
+                _featureFlags.value = event.flags
+            }
+            is VisioEvent.RoomLockedChanged -> {
+                _roomLocked.value = event.locked
+            }
+            is VisioEvent.RecordingStatusChanged -> {
+                _recordingStatus.value = event.status
+            }
+            is VisioEvent.SourceReady -> {
+                // Capture pushes into the core registry, which now points
+                // at the new publication: nothing to re-wire here.
+                Log.d("VISIO", "capture source ready: ${event.source} #${event.handle}")
+            }
+            is VisioEvent.CaptureConstraintsChanged -> {
+                _captureConstraints.value = event.constraints
+                if (cameraCapture?.setConstraints(event.constraints) == true) {
+                    // New resolution: restart capture off the Rust callback thread
+                    scope.launch {
+                        stopCameraCapture()
+                        startCameraCapture()
+                    }
+                }
+            }
+            is VisioEvent.PollStarted -> upsertPoll(event.poll)
+            is VisioEvent.PollUpdated -> upsertPoll(event.poll)
+            is VisioEvent.PollEnded -> upsertPoll(event.poll)
+            is VisioEvent.WhiteboardAvailable -> _whiteboardAvailable.value = true
+            is VisioEvent.PowerModeChanged -> {
+                _powerMode.value = event.mode
+                // The self-view tile is hidden in battery saver mode
                 refreshParticipants()
             }
+            is VisioEvent.QualityReduced -> _qualityReduction.value = event.reason
+            is VisioEvent.QualityRestored -> _qualityReduction.value = null
+            is VisioEvent.DevicePermissionChanged ->
+                _devicePermissions.update { it + (event.permission to event.state) }
+            is VisioEvent.DataMessageReceived -> {
+                _dataMessages.tryEmit(event)
+            }
+            is VisioEvent.LocalRecordingProgress -> {
+                if (_localRecordingMs.value != null) {
+                    _localRecordingMs.value = event.durationMs.toLong()
+                }
+            }
+            is VisioEvent.RestreamStatusChanged -> {
+                // Restreaming needs ffmpeg: only the desktop app starts it.
+            }
+            is VisioEvent.SettingsChanged -> {
+                // Changed elsewhere (e.g. a deep link): refresh observable settings
+                val settings = _client.getSettings()
+                if ("language" in event.keys) currentLang = settings.language ?: "fr"
+                if ("theme" in event.keys) currentTheme = settings.theme ?: "light"
+                if ("display_name" in event.keys) displayName = settings.displayName ?: ""
+            }
+            is VisioEvent.MediaButtonHandled -> {
+                // Off the core's call stack: these call back into the client.
+                scope.launch {
+                    when (event.button) {
+                        MediaButton.PLAY_PAUSE ->
+                            if (_client.isMicrophoneEnabled()) startAudioCapture() else stopAudioCapture()
+                        MediaButton.HANGUP -> disconnect()
+                    }
+                }
+            }
+            is VisioEvent.MeetingEndingSoon -> {
+                _meetingMinutesLeft.value = event.minutesLeft.toInt()
+            }
+            is VisioEvent.MeetingEnded -> {
+                _meetingMinutesLeft.value = 0
+            }
+            is VisioEvent.AloneInRoom -> {
+                // Off the core's call stack: disconnect calls back into the client.
+                if (event.autoLeave) scope.launch { disconnect() } else _aloneInRoom.value = true
+            }
+            is VisioEvent.InactivityDetected -> {
+                _inactivityPrompt.value = true
+            }
+            is VisioEvent.SystemCallChanged -> {
+                // Calls are not reported to ConnectionService yet: the
+                // foreground service keeps the call alive instead.
+            }
             is VisioEvent.ReactionReceived -> {
                 val reaction = ReactionData(
                     id = reactionIdCounter++,
@@ -385,6 +1008,19 @@ object VisioManager : VisioEventListener {
                     }
                 }
             }
+            is VisioEvent.HandAutoLowered -> {
+                _isHandRaised.value = false
+                _handAutoLowered.tryEmit(Unit)
+            }
+            is VisioEvent.ConnectProgress -> {
+                _connectStep.value = event.step
+            }
+            is VisioEvent.RoomSwitched -> {
+                _chatMessages.value = emptyList()
+                _handRaisedMap.value = emptyMap()
+                _isHandRaised.value = false
+                _reactions.value = emptyList()
+            }
         }
     }
 }
@@ -1,11 +1,13 @@
 package io.visio.mobile.navigation
 
 import androidx.compose.runtime.Composable
+import androidx.compose.runtime.LaunchedEffect
 import androidx.navigation.NavType
 import androidx.navigation.compose.NavHost
 import androidx.navigation.compose.composable
 import androidx.navigation.compose.rememberNavController
 import androidx.navigation.navArgument
+import io.visio.mobile.VisioManager
 import io.visio.mobile.ui.CallScreen
 import io.visio.mobile.ui.ChatScreen
 import io.visio.mobile.ui.HomeScreen
@@ -17,6 +19,13 @@ import java.net.URLEncoder
 fun AppNavigation() {
     val navController = rememberNavController()
 
+    LaunchedEffect(VisioManager.pendingOpenSettings) {
+        if (VisioManager.pendingOpenSettings) {
+            VisioManager.pendingOpenSettings = false
+            navController.navigate("settings")
+        }
+    }
+
     NavHost(navController = navController, startDestination = "home") {
         composable("home") {
             HomeScreen(
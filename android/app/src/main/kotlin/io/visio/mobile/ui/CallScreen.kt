@@ -80,6 +80,7 @@ import kotlinx.coroutines.delay
 import kotlinx.coroutines.launch
 import kotlinx.coroutines.withContext
 import uniffi.visio.ConnectionState
+import uniffi.visio.DevicePermission
 import uniffi.visio.ParticipantInfo
 import kotlin.math.absoluteValue
 
@@ -119,6 +120,7 @@ fun CallScreen(
     val handRaisedMap by VisioManager.handRaisedMap.collectAsState()
     val unreadCount by VisioManager.unreadCount.collectAsState()
     val isHandRaised by VisioManager.isHandRaised.collectAsState()
+    val qualityFallback by VisioManager.qualityFallback.collectAsState()
 
     val context = LocalContext.current
     val lang = VisioManager.currentLang
@@ -145,6 +147,7 @@ fun CallScreen(
         rememberLauncherForActivityResult(
             ActivityResultContracts.RequestPermission(),
         ) { granted ->
+            VisioManager.reportPermission(DevicePermission.MICROPHONE, granted)
             if (granted) {
                 coroutineScope.launch(Dispatchers.IO) {
                     try {
@@ -163,6 +166,7 @@ fun CallScreen(
         rememberLauncherForActivityResult(
             ActivityResultContracts.RequestPermission(),
         ) { granted ->
+            VisioManager.reportPermission(DevicePermission.CAMERA, granted)
             if (granted) {
                 coroutineScope.launch(Dispatchers.IO) {
                     try {
@@ -202,19 +206,15 @@ fun CallScreen(
                 return@withContext
             }
 
-            val settings =
-                try {
-                    VisioManager.client.getSettings()
-                } catch (e: Exception) {
-                    errorMessage = "Failed to load settings: ${e.message}"
-                    return@withContext
-                }
-
+            // The core applies the mic/camera-on-join settings while
+            // connecting, skipping devices without permission.
+            VisioManager.refreshPermissions()
             val user = username.ifBlank { null }
             try {
                 VisioManager.client.connect(roomUrl, user)
             } catch (e: Exception) {
-                errorMessage = "Connection failed: ${e.message}"
+                Log.e(TAG, "Connection failed", e)
+                errorMessage = Strings.error(e, lang)
                 return@withContext
             }
 
@@ -225,40 +225,23 @@ fun CallScreen(
                 return@withContext
             }
 
-            // Apply mic-on-join setting (only if permission already granted)
-            if (settings.micEnabledOnJoin) {
-                val hasMicPerm =
-                    ContextCompat.checkSelfPermission(
-                        context, Manifest.permission.RECORD_AUDIO,
-                    ) == PackageManager.PERMISSION_GRANTED
-                if (hasMicPerm) {
-                    try {
-                        VisioManager.client.setMicrophoneEnabled(true)
-                        VisioManager.startAudioCapture()
-                    } catch (e: Exception) {
-                        Log.e(TAG, "Failed to enable microphone on join", e)
-                    }
-                }
-            }
+            // Feed the tracks published on join
             micEnabled = VisioManager.client.isMicrophoneEnabled()
-
-            // Apply camera-on-join setting (only if permission already granted)
-            if (settings.cameraEnabledOnJoin) {
-                val hasCamPerm =
-                    ContextCompat.checkSelfPermission(
-                        context, Manifest.permission.CAMERA,
-                    ) == PackageManager.PERMISSION_GRANTED
-                if (hasCamPerm) {
-                    try {
-                        VisioManager.client.setCameraEnabled(true)
-                        VisioManager.startCameraCapture()
-                        VisioManager.refreshParticipantsPublic()
-                    } catch (e: Exception) {
-                        Log.e(TAG, "Failed to enable camera on join", e)
-                    }
-                }
+            if (micEnabled) {
+                VisioManager.startAudioCapture()
             }
             cameraEnabled = VisioManager.client.isCameraEnabled()
+            if (cameraEnabled) {
+                VisioManager.startCameraCapture()
+                VisioManager.refreshParticipantsPublic()
+            }
+        }
+    }
+
+    // The bad-network fallback turns the camera off and back on
+    LaunchedEffect(qualityFallback) {
+        if (VisioManager.connectionState.value is ConnectionState.Connected) {
+            cameraEnabled = withContext(Dispatchers.IO) { VisioManager.client.isCameraEnabled() }
         }
     }
 
@@ -435,6 +418,8 @@ fun CallScreen(
                                 context, Manifest.permission.RECORD_AUDIO,
                             ) == PackageManager.PERMISSION_GRANTED
                         if (hasPermission) {
+                            // May have been granted from the system settings
+                            VisioManager.reportPermission(DevicePermission.MICROPHONE, true)
                             coroutineScope.launch(Dispatchers.IO) {
                                 try {
                                     VisioManager.client.setMicrophoneEnabled(true)
@@ -471,6 +456,8 @@ fun CallScreen(
                                 context, Manifest.permission.CAMERA,
                             ) == PackageManager.PERMISSION_GRANTED
                         if (hasPermission) {
+                            // May have been granted from the system settings
+                            VisioManager.reportPermission(DevicePermission.CAMERA, true)
                             coroutineScope.launch(Dispatchers.IO) {
                                 try {
                                     VisioManager.client.setCameraEnabled(true)
@@ -42,9 +42,7 @@ import io.visio.mobile.R
 import io.visio.mobile.VisioManager
 import io.visio.mobile.ui.i18n.Strings
 import io.visio.mobile.ui.theme.VisioColors
-import kotlinx.coroutines.Dispatchers
 import kotlinx.coroutines.delay
-import kotlinx.coroutines.withContext
 import uniffi.visio.RoomValidationResult
 
 private const val TAG = "HomeScreen"
@@ -75,7 +73,8 @@ fun HomeScreen(
     LaunchedEffect(VisioManager.pendingDeepLink) {
         val link = VisioManager.pendingDeepLink
         if (link != null) {
-            roomUrl = link
+            roomUrl = link.meetUrl
+            link.displayName?.let { username = it }
             VisioManager.pendingDeepLink = null
         }
     }
@@ -106,9 +105,7 @@ fun HomeScreen(
             var foundValid = false
             for (url in urlsToTry) {
                 val result =
-                    withContext(Dispatchers.IO) {
-                        VisioManager.client.validateRoom(url, username.trim().ifEmpty { null })
-                    }
+                    VisioManager.client.validateRoomAsync(url, username.trim().ifEmpty { null })
                 when (result) {
                     is RoomValidationResult.Valid -> {
                         roomStatus = "valid"
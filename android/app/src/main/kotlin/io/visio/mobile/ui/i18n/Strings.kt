@@ -3,6 +3,7 @@ package io.visio.mobile.ui.i18n
 import android.content.Context
 import android.util.Log
 import org.json.JSONObject
+import uniffi.visio.VisioException
 
 object Strings {
     private var translations: MutableMap<String, Map<String, String>> = mutableMapOf()
@@ -29,6 +30,29 @@ object Strings {
         return translations[lang]?.get(key) ?: translations["en"]?.get(key) ?: key
     }
 
+    /** Message for users of an error raised by the core; details belong in the log. */
+    fun error(
+        e: Exception,
+        lang: String,
+    ): String {
+        val key =
+            when (e) {
+                is VisioException.Connection -> e.userMessageKey
+                is VisioException.Room -> e.userMessageKey
+                is VisioException.Auth -> e.userMessageKey
+                is VisioException.Http -> e.userMessageKey
+                is VisioException.InvalidUrl -> e.userMessageKey
+                is VisioException.Timeout -> e.userMessageKey
+                is VisioException.Unreachable -> e.userMessageKey
+                is VisioException.PermissionDenied -> e.userMessageKey
+                is VisioException.PublishNotAllowed -> e.userMessageKey
+                is VisioException.MessageTooLarge -> e.userMessageKey
+                is VisioException.Generic -> e.userMessageKey
+                else -> "error.generic"
+            }
+        return t(key, lang)
+    }
+
     fun detectSystemLang(): String {
         val sysLang = java.util.Locale.getDefault().language
         return if (sysLang in supportedLangs) sysLang else "en"
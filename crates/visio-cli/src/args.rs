@@ -0,0 +1,215 @@
+//! Command-line argument parsing.
+//!
+//! Kept dependency-free on purpose: the flag set is small and the
+//! binary is mostly driven by scripts and load-test harnesses.
+
+use std::time::Duration;
+
+pub const USAGE: &str = "\
+Usage: visio-cli [OPTIONS] <MEET_URL>
+       visio-cli [OPTIONS] --livekit-url <URL> --token <TOKEN>
+
+Options:
+  -n, --name <NAME>        Display name used when requesting a token
+      --livekit-url <URL>  Connect directly to a LiveKit server (skips the Meet API)
+      --token <TOKEN>      Access token for --livekit-url
+      --mic                Publish a synthetic tone as microphone on join
+      --camera             Publish a synthetic test pattern as camera on join
+      --chat <TEXT>        Send a chat message once connected (repeatable)
+      --duration <SECS>    Leave the room after SECS seconds
+      --connect-timeout <SECS>
+                           Give up if the Meet API or LiveKit does not answer within SECS
+      --no-stdin           Do not read interactive commands from stdin
+  -h, --help               Print this help
+
+Interactive commands (one per line on stdin):
+  chat <text> | mic on|off | camera on|off | hand up|down
+  react <emoji> | participants | quit";
+
+/// Where to connect.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Target {
+    /// Meet URL or room slug, resolved through the Meet API.
+    Meet(String),
+    /// Direct LiveKit connection.
+    LiveKit { url: String, token: String },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Args {
+    pub target: Target,
+    pub name: Option<String>,
+    pub mic: bool,
+    pub camera: bool,
+    pub chat: Vec<String>,
+    pub duration: Option<Duration>,
+    pub connect_timeout: Option<Duration>,
+    pub read_stdin: bool,
+}
+
+/// Result of parsing: either arguments to run with, or a help request.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Parsed {
+    Run(Args),
+    Help,
+}
+
+/// Parse arguments (excluding the program name).
+pub fn parse<I>(args: I) -> Result<Parsed, String>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut iter = args.into_iter();
+    let mut meet_url = None;
+    let mut livekit_url = None;
+    let mut token = None;
+    let mut name = None;
+    let mut mic = false;
+    let mut camera = false;
+    let mut chat = Vec::new();
+    let mut duration = None;
+    let mut connect_timeout = None;
+    let mut read_stdin = true;
+
+    while let Some(arg) = iter.next() {
+        let mut value = |flag: &str| {
+            iter.next()
+                .ok_or_else(|| format!("missing value for {flag}"))
+        };
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Parsed::Help),
+            "-n" | "--name" => name = Some(value(&arg)?),
+            "--livekit-url" => livekit_url = Some(value(&arg)?),
+            "--token" => token = Some(value(&arg)?),
+            "--mic" => mic = true,
+            "--camera" => camera = true,
+            "--chat" => chat.push(value(&arg)?),
+            "--duration" => {
+                let raw = value(&arg)?;
+                let secs: u64 = raw
+                    .parse()
+                    .map_err(|_| format!("invalid --duration: {raw}"))?;
+                duration = Some(Duration::from_secs(secs));
+            }
+            "--connect-timeout" => {
+                let raw = value(&arg)?;
+                let secs: u64 = raw
+                    .parse()
+                    .ok()
+                    .filter(|s| *s > 0)
+                    .ok_or_else(|| format!("invalid --connect-timeout: {raw}"))?;
+                connect_timeout = Some(Duration::from_secs(secs));
+            }
+            "--no-stdin" => read_stdin = false,
+            s if s.starts_with('-') => return Err(format!("unknown option: {s}")),
+            _ => {
+                if meet_url.replace(arg).is_some() {
+                    return Err("only one meet URL may be given".into());
+                }
+            }
+        }
+    }
+
+    let target = match (meet_url, livekit_url, token) {
+        (Some(url), None, None) => Target::Meet(url),
+        (None, Some(url), Some(token)) => Target::LiveKit { url, token },
+        (None, Some(_), None) => return Err("--livekit-url requires --token".into()),
+        (None, None, Some(_)) => return Err("--token requires --livekit-url".into()),
+        (Some(_), _, _) => {
+            return Err("a meet URL cannot be combined with --livekit-url/--token".into());
+        }
+        (None, None, None) => return Err("missing meet URL".into()),
+    };
+
+    Ok(Parsed::Run(Args {
+        target,
+        name,
+        mic,
+        camera,
+        chat,
+        duration,
+        connect_timeout,
+        read_stdin,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(args: &[&str]) -> Result<Args, String> {
+        match parse(args.iter().map(|s| s.to_string()))? {
+            Parsed::Run(a) => Ok(a),
+            Parsed::Help => Err("help".into()),
+        }
+    }
+
+    #[test]
+    fn parses_meet_url_with_flags() {
+        let args = run(&[
+            "https://meet.example.com/abc-defg-hij",
+            "--name",
+            "bot",
+            "--mic",
+            "--camera",
+            "--chat",
+            "hello",
+            "--chat",
+            "world",
+            "--duration",
+            "30",
+            "--connect-timeout",
+            "5",
+            "--no-stdin",
+        ])
+        .unwrap();
+        assert_eq!(
+            args.target,
+            Target::Meet("https://meet.example.com/abc-defg-hij".into())
+        );
+        assert_eq!(args.name.as_deref(), Some("bot"));
+        assert!(args.mic && args.camera);
+        assert_eq!(args.chat, vec!["hello", "world"]);
+        assert_eq!(args.duration, Some(Duration::from_secs(30)));
+        assert_eq!(args.connect_timeout, Some(Duration::from_secs(5)));
+        assert!(!args.read_stdin);
+    }
+
+    #[test]
+    fn parses_direct_livekit_target() {
+        let args = run(&["--livekit-url", "ws://localhost:7880", "--token", "t"]).unwrap();
+        assert_eq!(
+            args.target,
+            Target::LiveKit {
+                url: "ws://localhost:7880".into(),
+                token: "t".into()
+            }
+        );
+        assert!(args.read_stdin);
+    }
+
+    #[test]
+    fn rejects_incomplete_or_conflicting_targets() {
+        assert!(run(&[]).is_err());
+        assert!(run(&["--livekit-url", "ws://x"]).is_err());
+        assert!(run(&["--token", "t"]).is_err());
+        assert!(run(&["https://a/b", "--livekit-url", "ws://x", "--token", "t"]).is_err());
+        assert!(run(&["https://a/b", "https://a/c"]).is_err());
+    }
+
+    #[test]
+    fn rejects_bad_values() {
+        assert!(run(&["https://a/b", "--duration", "soon"]).is_err());
+        assert!(run(&["https://a/b", "--connect-timeout", "0"]).is_err());
+        assert!(run(&["https://a/b", "--name"]).is_err());
+        assert!(run(&["https://a/b", "--bogus"]).is_err());
+    }
+
+    #[test]
+    fn help_short_circuits() {
+        assert_eq!(
+            parse(["--help".to_string()].into_iter()).unwrap(),
+            Parsed::Help
+        );
+    }
+}
@@ -0,0 +1,521 @@
+//! JSON-lines event output.
+//!
+//! Every `VisioEvent` becomes one JSON object on stdout, tagged with an
+//! `event` field and a millisecond timestamp, so test harnesses can
+//! consume the stream with `jq` or a line-oriented parser.
+
+use std::io::Write;
+
+use serde_json::{Value, json};
+use visio_core::{
+    CallDirection, ConnectStep, ConnectionQuality, ConnectionState, DevicePermission,
+    PermissionState, Poll, PowerMode, Presence, QualityReductionReason, RecordingStatus,
+    RestreamState, SystemCallState, ThermalState, TrackKind, TrackSource, VisioEvent,
+    VisioEventListener,
+};
+
+fn source_to_str(source: &TrackSource) -> &'static str {
+    match source {
+        TrackSource::Microphone => "microphone",
+        TrackSource::Camera => "camera",
+        TrackSource::ScreenShare => "screen_share",
+        TrackSource::ScreenShareAudio => "screen_share_audio",
+        TrackSource::Unknown => "unknown",
+    }
+}
+
+fn quality_to_str(quality: &ConnectionQuality) -> &'static str {
+    match quality {
+        ConnectionQuality::Excellent => "excellent",
+        ConnectionQuality::Good => "good",
+        ConnectionQuality::Poor => "poor",
+        ConnectionQuality::Lost => "lost",
+    }
+}
+
+fn presence_to_str(presence: Presence) -> &'static str {
+    match presence {
+        Presence::Available => "available",
+        Presence::Away => "away",
+        Presence::BeRightBack => "brb",
+        Presence::DoNotDisturb => "dnd",
+    }
+}
+
+fn power_mode_to_str(mode: PowerMode) -> &'static str {
+    match mode {
+        PowerMode::Normal => "normal",
+        PowerMode::Saver => "saver",
+    }
+}
+
+fn thermal_state_to_str(state: ThermalState) -> &'static str {
+    match state {
+        ThermalState::Nominal => "nominal",
+        ThermalState::Fair => "fair",
+        ThermalState::Serious => "serious",
+        ThermalState::Critical => "critical",
+    }
+}
+
+fn device_permission_to_str(permission: DevicePermission) -> &'static str {
+    match permission {
+        DevicePermission::Camera => "camera",
+        DevicePermission::Microphone => "microphone",
+        DevicePermission::ScreenCapture => "screen_capture",
+        DevicePermission::Notifications => "notifications",
+    }
+}
+
+fn permission_state_to_str(state: PermissionState) -> &'static str {
+    match state {
+        PermissionState::Unknown => "unknown",
+        PermissionState::Granted => "granted",
+        PermissionState::Denied => "denied",
+    }
+}
+
+fn call_direction_to_str(direction: CallDirection) -> &'static str {
+    match direction {
+        CallDirection::Incoming => "incoming",
+        CallDirection::Outgoing => "outgoing",
+    }
+}
+
+fn system_call_state_to_str(state: SystemCallState) -> &'static str {
+    match state {
+        SystemCallState::Connecting => "connecting",
+        SystemCallState::Connected => "connected",
+        SystemCallState::EndedLocally => "ended_locally",
+        SystemCallState::EndedRemotely => "ended_remotely",
+        SystemCallState::Failed => "failed",
+    }
+}
+
+fn step_to_str(step: &ConnectStep) -> &'static str {
+    match step {
+        ConnectStep::TokenRequested => "token_requested",
+        ConnectStep::TokenReceived => "token_received",
+        ConnectStep::SignalConnected => "signal_connected",
+        ConnectStep::MediaConnected => "media_connected",
+    }
+}
+
+fn recording_status_to_str(status: &RecordingStatus) -> &'static str {
+    match status {
+        RecordingStatus::Stopped => "stopped",
+        RecordingStatus::Starting => "starting",
+        RecordingStatus::Recording => "recording",
+        RecordingStatus::Stopping => "stopping",
+    }
+}
+
+fn restream_state_to_str(state: &RestreamState) -> &'static str {
+    match state {
+        RestreamState::Stopped => "stopped",
+        RestreamState::Connecting => "connecting",
+        RestreamState::Live => "live",
+        RestreamState::Failed => "failed",
+    }
+}
+
+fn poll_to_json(event: &str, poll: &Poll) -> Value {
+    json!({
+        "event": event,
+        "id": poll.id,
+        "question": poll.question,
+        "options": poll.options,
+        "counts": poll.counts,
+        "creator_sid": poll.creator_sid,
+        "local_vote": poll.local_vote,
+        "ended": poll.ended,
+    })
+}
+
+/// Convert a core event into its JSON-lines representation.
+pub fn event_to_json(event: &VisioEvent) -> Value {
+    match event {
+        VisioEvent::ConnectionStateChanged(state) => {
+            let (name, attempt) = match state {
+                ConnectionState::Disconnected => ("disconnected", None),
+                ConnectionState::Connecting => ("connecting", None),
+                ConnectionState::Connected => ("connected", None),
+                ConnectionState::Reconnecting { attempt } => ("reconnecting", Some(*attempt)),
+            };
+            json!({"event": "connection_state_changed", "state": name, "attempt": attempt})
+        }
+        VisioEvent::ParticipantJoined(info) => json!({
+            "event": "participant_joined",
+            "sid": info.sid,
+            "identity": info.identity,
+            "name": info.name,
+            "presence": presence_to_str(info.presence),
+        }),
+        VisioEvent::ParticipantLeft(sid) => json!({"event": "participant_left", "sid": sid}),
+        VisioEvent::TrackSubscribed(info) => json!({
+            "event": "track_subscribed",
+            "sid": info.sid,
+            "participant_sid": info.participant_sid,
+            "kind": match info.kind {
+                TrackKind::Audio => "audio",
+                TrackKind::Video => "video",
+            },
+            "source": source_to_str(&info.source),
+        }),
+        VisioEvent::TrackUnsubscribed(sid) => json!({"event": "track_unsubscribed", "sid": sid}),
+        VisioEvent::TrackMuted {
+            participant_sid,
+            source,
+        } => json!({
+            "event": "track_muted",
+            "participant_sid": participant_sid,
+            "source": source_to_str(source),
+        }),
+        VisioEvent::TrackUnmuted {
+            participant_sid,
+            source,
+        } => json!({
+            "event": "track_unmuted",
+            "participant_sid": participant_sid,
+            "source": source_to_str(source),
+        }),
+        VisioEvent::ActiveSpeakersChanged(sids) => {
+            json!({"event": "active_speakers_changed", "sids": sids})
+        }
+        VisioEvent::LayoutOrderChanged(sids) => {
+            json!({"event": "layout_order_changed", "sids": sids})
+        }
+        VisioEvent::ConnectionQualityChanged {
+            participant_sid,
+            quality,
+        } => json!({
+            "event": "connection_quality_changed",
+            "participant_sid": participant_sid,
+            "quality": quality_to_str(quality),
+        }),
+        VisioEvent::ChatMessageReceived(msg) => json!({
+            "event": "chat_message_received",
+            "id": msg.id,
+            "sender_sid": msg.sender_sid,
+            "sender_name": msg.sender_name,
+            "text": msg.text,
+            "timestamp_ms": msg.timestamp_ms,
+            "kind": msg.kind,
+        }),
+        VisioEvent::HandRaisedChanged {
+            participant_sid,
+            raised,
+            position,
+        } => json!({
+            "event": "hand_raised_changed",
+            "participant_sid": participant_sid,
+            "raised": raised,
+            "position": position,
+        }),
+        VisioEvent::UnreadCountChanged(count) => {
+            json!({"event": "unread_count_changed", "count": count})
+        }
+        VisioEvent::ReactionReceived {
+            participant_sid,
+            participant_name,
+            emoji,
+        } => json!({
+            "event": "reaction_received",
+            "participant_sid": participant_sid,
+            "participant_name": participant_name,
+            "emoji": emoji,
+        }),
+        VisioEvent::HandAutoLowered => json!({"event": "hand_auto_lowered"}),
+        VisioEvent::ConnectionLost => json!({"event": "connection_lost"}),
+        VisioEvent::RoomSwitched { meet_url } => {
+            json!({"event": "room_switched", "meet_url": meet_url})
+        }
+        VisioEvent::ParticipantBatchUpdate {
+            joined,
+            left,
+            joined_count,
+            left_count,
+        } => json!({
+            "event": "participant_batch_update",
+            "joined": joined
+                .iter()
+                .map(|p| json!({"sid": p.sid, "identity": p.identity, "name": p.name}))
+                .collect::<Vec<_>>(),
+            "left": left,
+            "joined_count": joined_count,
+            "left_count": left_count,
+        }),
+        VisioEvent::ConnectProgress(step) => {
+            json!({"event": "connect_progress", "step": step_to_str(step)})
+        }
+        VisioEvent::TrackStalled { track_sid } => {
+            json!({"event": "track_stalled", "track_sid": track_sid})
+        }
+        VisioEvent::TrackResumed { track_sid } => {
+            json!({"event": "track_resumed", "track_sid": track_sid})
+        }
+        VisioEvent::PermissionsChanged(perms) => json!({
+            "event": "permissions_changed",
+            "access_level": perms.access_level,
+            "can_publish": perms.can_publish,
+            "can_subscribe": perms.can_subscribe,
+            "can_publish_data": perms.can_publish_data,
+            "can_publish_sources": perms.can_publish_sources,
+            "is_admin": perms.is_admin,
+        }),
+        VisioEvent::WaitingForHost => json!({"event": "waiting_for_host"}),
+        VisioEvent::EntryGranted => json!({"event": "entry_granted"}),
+        VisioEvent::EntryDenied => json!({"event": "entry_denied"}),
+        VisioEvent::RoomLockedChanged(locked) => {
+            json!({"event": "room_locked_changed", "locked": locked})
+        }
+        VisioEvent::FeatureFlagsChanged(flags) => json!({
+            "event": "feature_flags_changed",
+            "chat": flags.chat,
+            "reactions": flags.reactions,
+            "recording": flags.recording,
+            "transcription": flags.transcription,
+            "dial_in": flags.dial_in,
+            "subtitles": flags.subtitles,
+            "whiteboard": flags.whiteboard,
+            "watermark": flags.watermark,
+        }),
+        VisioEvent::RecordingStatusChanged(status) => json!({
+            "event": "recording_status_changed",
+            "status": recording_status_to_str(status),
+        }),
+        VisioEvent::LocalRecordingProgress {
+            duration_ms,
+            size_bytes,
+        } => json!({
+            "event": "local_recording_progress",
+            "duration_ms": duration_ms,
+            "size_bytes": size_bytes,
+        }),
+        VisioEvent::RestreamStatusChanged(status) => json!({
+            "event": "restream_status_changed",
+            "state": restream_state_to_str(&status.state),
+            "video_bitrate_kbps": status.video_bitrate_kbps,
+            "error": status.error,
+        }),
+        VisioEvent::SourceReady { source, handle } => json!({
+            "event": "source_ready",
+            "source": source_to_str(source),
+            "handle": handle,
+        }),
+        VisioEvent::CaptureConstraintsChanged(constraints) => json!({
+            "event": "capture_constraints_changed",
+            "width": constraints.width,
+            "height": constraints.height,
+            "max_fps": constraints.max_fps,
+            "low_light_boost": constraints.low_light_boost,
+        }),
+        VisioEvent::DataMessageReceived {
+            topic,
+            sender_sid,
+            payload,
+        } => json!({
+            "event": "data_message_received",
+            "topic": topic,
+            "sender_sid": sender_sid,
+            "payload": payload,
+        }),
+        VisioEvent::PollStarted(poll) => poll_to_json("poll_started", poll),
+        VisioEvent::PollUpdated(poll) => poll_to_json("poll_updated", poll),
+        VisioEvent::PollEnded(poll) => poll_to_json("poll_ended", poll),
+        VisioEvent::WhiteboardAvailable => json!({"event": "whiteboard_available"}),
+        VisioEvent::PowerModeChanged(mode) => json!({
+            "event": "power_mode_changed",
+            "mode": power_mode_to_str(*mode),
+        }),
+        VisioEvent::QualityReduced {
+            reason,
+            constraints,
+        } => {
+            let reason = match reason {
+                QualityReductionReason::Thermal(state) => json!({
+                    "kind": "thermal",
+                    "thermal_state": thermal_state_to_str(*state),
+                }),
+            };
+            json!({
+                "event": "quality_reduced",
+                "reason": reason,
+                "width": constraints.width,
+                "height": constraints.height,
+                "max_fps": constraints.max_fps,
+            })
+        }
+        VisioEvent::QualityRestored => json!({"event": "quality_restored"}),
+        VisioEvent::DevicePermissionChanged { permission, state } => json!({
+            "event": "device_permission_changed",
+            "permission": device_permission_to_str(*permission),
+            "state": permission_state_to_str(*state),
+        }),
+        VisioEvent::SettingsChanged { keys } => json!({"event": "settings_changed", "keys": keys}),
+        VisioEvent::SystemCallChanged(call) => json!({
+            "event": "system_call_changed",
+            "call_id": call.call_id,
+            "room": call.room,
+            "display_name": call.display_name,
+            "direction": call_direction_to_str(call.direction),
+            "state": system_call_state_to_str(call.state),
+        }),
+        VisioEvent::MediaButtonHandled(button) => {
+            json!({"event": "media_button_handled", "button": button.as_str()})
+        }
+        VisioEvent::MeetingEndingSoon { minutes_left } => {
+            json!({"event": "meeting_ending_soon", "minutes_left": minutes_left})
+        }
+        VisioEvent::MeetingEnded => json!({"event": "meeting_ended"}),
+        VisioEvent::AloneInRoom {
+            minutes,
+            auto_leave,
+        } => json!({
+            "event": "alone_in_room",
+            "minutes": minutes,
+            "auto_leave": auto_leave,
+        }),
+        VisioEvent::InactivityDetected { minutes } => {
+            json!({"event": "inactivity_detected", "minutes": minutes})
+        }
+        VisioEvent::SpotlightChanged {
+            participant_sid,
+            track_sid,
+        } => json!({
+            "event": "spotlight_changed",
+            "participant_sid": participant_sid,
+            "track_sid": track_sid,
+        }),
+        VisioEvent::PresenceChanged {
+            participant_sid,
+            presence,
+        } => json!({
+            "event": "presence_changed",
+            "participant_sid": participant_sid,
+            "presence": presence_to_str(*presence),
+        }),
+        VisioEvent::ParticipantAttributesChanged {
+            participant_sid,
+            changed,
+        } => json!({
+            "event": "participant_attributes_changed",
+            "participant_sid": participant_sid,
+            "changed": changed,
+        }),
+        VisioEvent::QualityPolicyTriggered {
+            active,
+            camera,
+            remote_video,
+        } => json!({
+            "event": "quality_policy_triggered",
+            "active": active,
+            "camera": camera,
+            "remote_video": remote_video,
+        }),
+        VisioEvent::CallEnded(summary) => json!({
+            "event": "call_ended",
+            "duration_secs": summary.duration_secs,
+            "peak_participants": summary.peak_participants,
+            "reconnect_count": summary.reconnect_count,
+            "average_quality": summary.average_quality.as_ref().map(quality_to_str),
+            "error_count": summary.error_count,
+        }),
+        VisioEvent::VideoPrivacyChanged { enabled } => {
+            json!({"event": "video_privacy_changed", "enabled": enabled})
+        }
+        VisioEvent::ChatUnreadChanged(unread) => json!({
+            "event": "chat_unread_changed",
+            "first_unread_id": unread.first_unread_id,
+            "normal": unread.normal,
+            "mention": unread.mention,
+            "system": unread.system,
+        }),
+    }
+}
+
+/// Print a JSON value as a single line on stdout, stamped with the wall clock.
+pub fn print_line(mut value: Value) {
+    if let Some(obj) = value.as_object_mut() {
+        let ts = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or(0);
+        obj.insert("ts_ms".into(), ts.into());
+    }
+    let mut out = std::io::stdout().lock();
+    let _ = writeln!(out, "{value}");
+    let _ = out.flush();
+}
+
+/// Listener that writes every event to stdout as JSON lines.
+pub struct JsonLinesListener;
+
+impl VisioEventListener for JsonLinesListener {
+    fn on_event(&self, event: VisioEvent) {
+        print_line(event_to_json(&event));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use visio_core::{ChatMessage, ChatMessageKind, ParticipantInfo, Presence};
+
+    #[test]
+    fn connection_state_includes_attempt() {
+        let v = event_to_json(&VisioEvent::ConnectionStateChanged(
+            ConnectionState::Reconnecting { attempt: 3 },
+        ));
+        assert_eq!(v["event"], "connection_state_changed");
+        assert_eq!(v["state"], "reconnecting");
+        assert_eq!(v["attempt"], 3);
+
+        let v = event_to_json(&VisioEvent::ConnectionStateChanged(
+            ConnectionState::Connected,
+        ));
+        assert!(v["attempt"].is_null());
+    }
+
+    #[test]
+    fn participant_joined_fields() {
+        let v = event_to_json(&VisioEvent::ParticipantJoined(ParticipantInfo {
+            sid: "PA_1".into(),
+            identity: "alice".into(),
+            name: Some("Alice".into()),
+            is_muted: false,
+            has_video: false,
+            video_track_sid: None,
+            connection_quality: ConnectionQuality::Good,
+            presence: Presence::DoNotDisturb,
+        }));
+        assert_eq!(v["event"], "participant_joined");
+        assert_eq!(v["sid"], "PA_1");
+        assert_eq!(v["name"], "Alice");
+        assert_eq!(v["presence"], "dnd");
+    }
+
+    #[test]
+    fn chat_message_is_single_line() {
+        let v = event_to_json(&VisioEvent::ChatMessageReceived(ChatMessage {
+            id: "m1".into(),
+            sender_sid: "PA_1".into(),
+            sender_name: "Alice".into(),
+            text: "multi\nline".into(),
+            timestamp_ms: 42,
+            kind: ChatMessageKind::Text,
+        }));
+        assert_eq!(v["text"], "multi\nline");
+        assert_eq!(v["kind"], "text");
+        assert!(!v.to_string().contains('\n'));
+    }
+
+    #[test]
+    fn room_switched_carries_url() {
+        let v = event_to_json(&VisioEvent::RoomSwitched {
+            meet_url: "https://meet.example.com/abc-defg-hij".into(),
+        });
+        assert_eq!(v["event"], "room_switched");
+        assert_eq!(v["meet_url"], "https://meet.example.com/abc-defg-hij");
+    }
+}
@@ -0,0 +1,238 @@
+//! Headless Visio client.
+//!
+//! Joins a room through visio-core without any UI shell, optionally
+//! publishes synthetic audio/video, and prints every core event as a
+//! JSON line on stdout. Intended for integration testing and server
+//! load generation. Logs go to stderr so stdout stays machine-readable.
+
+mod args;
+mod events;
+
+use std::sync::Arc;
+
+use serde_json::json;
+use tokio::io::{AsyncBufReadExt, BufReader};
+use visio_core::{ConnectTimeouts, JoinOptions, MeetingControls, RoomManager, TestMediaOptions};
+
+use crate::args::{Parsed, Target};
+use crate::events::{JsonLinesListener, print_line};
+
+/// A parsed interactive command.
+#[derive(Debug, PartialEq, Eq)]
+enum Command {
+    Chat(String),
+    Mic(bool),
+    Camera(bool),
+    Hand(bool),
+    React(String),
+    Participants,
+    Quit,
+}
+
+fn parse_on_off(arg: &str) -> Result<bool, String> {
+    match arg {
+        "on" => Ok(true),
+        "off" => Ok(false),
+        other => Err(format!("expected on|off, got {other:?}")),
+    }
+}
+
+fn parse_command(line: &str) -> Result<Option<Command>, String> {
+    let line = line.trim();
+    if line.is_empty() {
+        return Ok(None);
+    }
+    let (cmd, rest) = line.split_once(' ').unwrap_or((line, ""));
+    let rest = rest.trim();
+    let command = match cmd {
+        "chat" if !rest.is_empty() => Command::Chat(rest.to_string()),
+        "mic" => Command::Mic(parse_on_off(rest)?),
+        "camera" => Command::Camera(parse_on_off(rest)?),
+        "hand" => match rest {
+            "up" => Command::Hand(true),
+            "down" => Command::Hand(false),
+            other => return Err(format!("expected up|down, got {other:?}")),
+        },
+        "react" if !rest.is_empty() => Command::React(rest.to_string()),
+        "participants" => Command::Participants,
+        "quit" | "exit" => Command::Quit,
+        _ => return Err(format!("unknown command: {line}")),
+    };
+    Ok(Some(command))
+}
+
+/// Execute one command. Returns `false` when the client should leave.
+async fn execute(
+    room: &RoomManager,
+    controls: &MeetingControls,
+    chat: &visio_core::ChatService,
+    command: Command,
+) -> bool {
+    let result = match command {
+        Command::Chat(text) => chat.send_message(&text).await.map(|_| ()),
+        Command::Mic(on) => controls.set_microphone_enabled(on).await,
+        Command::Camera(on) => controls.set_camera_enabled(on).await,
+        Command::Hand(true) => room.raise_hand().await,
+        Command::Hand(false) => room.lower_hand().await,
+        Command::React(emoji) => room.send_reaction(&emoji).await,
+        Command::Participants => {
+            let list: Vec<_> = room
+                .participants()
+                .await
+                .into_iter()
+                .map(|p| json!({"sid": p.sid, "identity": p.identity, "name": p.name}))
+                .collect();
+            print_line(json!({"event": "participants", "participants": list}));
+            Ok(())
+        }
+        Command::Quit => return false,
+    };
+    if let Err(e) = result {
+        print_line(json!({"event": "error", "message": e.to_string()}));
+    }
+    true
+}
+
+#[tokio::main]
+async fn main() {
+    tracing_subscriber::fmt()
+        .with_writer(std::io::stderr)
+        .with_env_filter(
+            tracing_subscriber::EnvFilter::try_from_default_env()
+                .unwrap_or_else(|_| "visio_core=info,visio_cli=info".parse().unwrap()),
+        )
+        .init();
+
+    let args = match args::parse(std::env::args().skip(1)) {
+        Ok(Parsed::Run(args)) => args,
+        Ok(Parsed::Help) => {
+            println!("{}", args::USAGE);
+            return;
+        }
+        Err(e) => {
+            eprintln!("error: {e}\n\n{}", args::USAGE);
+            std::process::exit(2);
+        }
+    };
+
+    let room = RoomManager::new();
+    room.add_listener(Arc::new(JsonLinesListener));
+    let controls = room.controls();
+    // Every published track is fed by a test pattern / tone generator.
+    controls
+        .set_test_media(TestMediaOptions::audio_video())
+        .await;
+    let chat = room.chat();
+    if let Some(timeout) = args.connect_timeout {
+        room.set_connect_timeouts(ConnectTimeouts {
+            meet_api: timeout,
+            livekit: timeout,
+        })
+        .await;
+    }
+
+    let connected = match &args.target {
+        Target::Meet(url) => {
+            let options = JoinOptions {
+                username: args.name.clone(),
+                ..JoinOptions::default()
+            };
+            room.connect(url, &options).await
+        }
+        Target::LiveKit { url, token } => room.connect_with_token(url, token).await,
+    };
+    if let Err(e) = connected {
+        print_line(json!({"event": "error", "message": e.to_string()}));
+        std::process::exit(1);
+    }
+
+    let mut startup = Vec::new();
+    if args.mic {
+        startup.push(Command::Mic(true));
+    }
+    if args.camera {
+        startup.push(Command::Camera(true));
+    }
+    startup.extend(args.chat.iter().cloned().map(Command::Chat));
+    for command in startup {
+        execute(&room, &controls, &chat, command).await;
+    }
+
+    let deadline = async {
+        match args.duration {
+            Some(d) => tokio::time::sleep(d).await,
+            None => std::future::pending().await,
+        }
+    };
+    tokio::pin!(deadline);
+
+    let mut stdin = args
+        .read_stdin
+        .then(|| BufReader::new(tokio::io::stdin()).lines());
+
+    loop {
+        tokio::select! {
+            _ = &mut deadline => break,
+            _ = tokio::signal::ctrl_c() => break,
+            line = async {
+                match stdin.as_mut() {
+                    Some(lines) => lines.next_line().await,
+                    None => std::future::pending().await,
+                }
+            } => {
+                let line = match line {
+                    Ok(Some(line)) => line,
+                    // EOF: keep running until the deadline / Ctrl-C.
+                    Ok(None) | Err(_) => {
+                        stdin = None;
+                        continue;
+                    }
+                };
+                match parse_command(&line) {
+                    Ok(Some(command)) => {
+                        if !execute(&room, &controls, &chat, command).await {
+                            break;
+                        }
+                    }
+                    Ok(None) => {}
+                    Err(e) => print_line(json!({"event": "error", "message": e})),
+                }
+            }
+        }
+    }
+
+    room.disconnect().await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_interactive_commands() {
+        assert_eq!(
+            parse_command("chat hello there"),
+            Ok(Some(Command::Chat("hello there".into())))
+        );
+        assert_eq!(parse_command("mic on"), Ok(Some(Command::Mic(true))));
+        assert_eq!(
+            parse_command("camera off"),
+            Ok(Some(Command::Camera(false)))
+        );
+        assert_eq!(parse_command("hand up"), Ok(Some(Command::Hand(true))));
+        assert_eq!(
+            parse_command("react 👍"),
+            Ok(Some(Command::React("👍".into())))
+        );
+        assert_eq!(parse_command("  quit  "), Ok(Some(Command::Quit)));
+        assert_eq!(parse_command("   "), Ok(None));
+    }
+
+    #[test]
+    fn rejects_malformed_commands() {
+        assert!(parse_command("chat").is_err());
+        assert!(parse_command("mic maybe").is_err());
+        assert!(parse_command("hand sideways").is_err());
+        assert!(parse_command("dance").is_err());
+    }
+}
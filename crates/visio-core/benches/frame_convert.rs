@@ -0,0 +1,42 @@
+//! NV12 → I420 conversion of the macOS camera capture path.
+//!
+//! Run with `cargo bench -p visio-core --bench frame_convert`.
+
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use visio_core::frame_convert::nv12_to_i420;
+
+const SIZES: [(&str, usize, usize); 3] = [
+    ("360p", 640, 360),
+    ("720p", 1280, 720),
+    ("1080p", 1920, 1080),
+];
+
+fn nv12(c: &mut Criterion) {
+    let mut group = c.benchmark_group("nv12_to_i420");
+    for (name, w, h) in SIZES {
+        let (cw, ch) = (w.div_ceil(2), h.div_ceil(2));
+        // Rows padded to 64 bytes, as CVPixelBuffer does.
+        let (y_stride, uv_stride) = (w.next_multiple_of(64), (cw * 2).next_multiple_of(64));
+        let y: Vec<u8> = (0..y_stride * h).map(|i| i as u8).collect();
+        let uv: Vec<u8> = (0..uv_stride * ch).map(|i| (i / 3) as u8).collect();
+        let (mut y_out, mut u_out, mut v_out) =
+            (vec![0; w * h], vec![0; cw * ch], vec![0; cw * ch]);
+
+        group.throughput(Throughput::Elements((w * h) as u64));
+        group.bench_function(BenchmarkId::from_parameter(name), |b| {
+            b.iter(|| {
+                nv12_to_i420(
+                    [&y, &uv],
+                    [y_stride, uv_stride],
+                    (w, h),
+                    [&mut y_out, &mut u_out, &mut v_out],
+                    [w, cw, cw],
+                )
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, nv12);
+criterion_main!(benches);
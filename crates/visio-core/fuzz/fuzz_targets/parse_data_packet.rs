@@ -0,0 +1,26 @@
+//! Data packets as received from the network: the first byte picks the
+//! topic, the rest is the payload.
+//!
+//! Run with `cargo +nightly fuzz run parse_data_packet` from
+//! `crates/visio-core`.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use visio_core::{IncomingPayload, parse_data_packet};
+
+fuzz_target!(|data: &[u8]| {
+    let Some((&selector, payload)) = data.split_first() else {
+        return;
+    };
+    let topic = match selector % 3 {
+        0 => None,
+        1 => Some("lk-chat-topic"),
+        _ => Some("visio.polls"),
+    };
+    match parse_data_packet(topic, payload) {
+        Ok(Some(IncomingPayload::Chat { text, .. })) => assert!(!text.is_empty()),
+        Ok(Some(IncomingPayload::Reaction { emoji })) => assert!(!emoji.is_empty()),
+        Ok(None) | Err(_) => {}
+    }
+});
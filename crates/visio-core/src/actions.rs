@@ -0,0 +1,40 @@
+//! Meeting actions triggered outside the meeting UI.
+//!
+//! Keyboard shortcuts, global hotkeys and similar inputs all do the same
+//! few things. They go through `RoomManager::dispatch_action`, so every
+//! shell toggles media, the hand and the chat panel the same way as its
+//! buttons, even when its window is not focused.
+
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MeetingAction {
+    ToggleMicrophone,
+    ToggleCamera,
+    /// Raise the hand, or lower it if raised.
+    ToggleHandRaise,
+    /// Mark the chat panel open or closed; the shell shows or hides it.
+    ToggleChat,
+    /// Microphone on while the key is held, off once released.
+    PushToTalk {
+        pressed: bool,
+    },
+}
+
+impl MeetingAction {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::ToggleMicrophone => "toggle_microphone",
+            Self::ToggleCamera => "toggle_camera",
+            Self::ToggleHandRaise => "toggle_hand_raise",
+            Self::ToggleChat => "toggle_chat",
+            Self::PushToTalk { .. } => "push_to_talk",
+        }
+    }
+}
+
+impl fmt::Display for MeetingAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -0,0 +1,213 @@
+//! App-level LiveKit participant attributes.
+//!
+//! Apps built on the core can share small key/value state (a status text,
+//! a role in a game, ...) through participant attributes without a change
+//! in the core for each feature. Changes from any participant, local one
+//! included, are delivered as `VisioEvent::ParticipantAttributesChanged`.
+//! Attributes used by the core itself (raised hands, presence) are neither
+//! writable nor reported here.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use livekit::prelude::*;
+use tokio::sync::Mutex;
+
+use crate::errors::VisioError;
+use crate::events::{EventEmitter, VisioEvent};
+use crate::presence::PRESENCE_ATTRIBUTE;
+
+/// Attributes managed by the core.
+const RESERVED_KEYS: &[&str] = &["handRaisedAt", PRESENCE_ATTRIBUTE];
+
+/// Longest attribute key accepted.
+pub const MAX_ATTRIBUTE_KEY_BYTES: usize = 64;
+
+/// Longest attribute value accepted.
+pub const MAX_ATTRIBUTE_VALUE_BYTES: usize = 1024;
+
+/// Largest update accepted by `set_local_attributes`, keys and values
+/// included, so attributes stay small in every participant update.
+pub const MAX_ATTRIBUTES_UPDATE_BYTES: usize = 8 * 1024;
+
+#[derive(Clone)]
+pub struct AttributesService {
+    room: Arc<Mutex<Option<Arc<Room>>>>,
+    emitter: EventEmitter,
+}
+
+impl AttributesService {
+    pub fn new(room: Arc<Mutex<Option<Arc<Room>>>>, emitter: EventEmitter) -> Self {
+        Self { room, emitter }
+    }
+
+    /// Set attributes of the local participant. Other keys are kept; an
+    /// empty value removes its key.
+    pub async fn set_local_attributes(
+        &self,
+        attributes: HashMap<String, String>,
+    ) -> Result<(), VisioError> {
+        check_attributes(&attributes)?;
+        let room = self.room.lock().await;
+        let room = room
+            .as_ref()
+            .ok_or_else(|| VisioError::Room("not connected".into()))?;
+        room.local_participant()
+            .set_attributes(attributes)
+            .await
+            .map_err(|e| VisioError::Room(format!("set attributes: {e}")))
+    }
+
+    /// App-level attributes of a participant, local or remote. `None` for
+    /// unknown participants.
+    pub async fn participant_attributes(
+        &self,
+        participant_sid: &str,
+    ) -> Option<HashMap<String, String>> {
+        let room = self.room.lock().await;
+        let room = room.as_ref()?;
+        let local = room.local_participant();
+        let attributes = if local.sid().to_string() == participant_sid {
+            local.attributes()
+        } else {
+            room.remote_participants()
+                .values()
+                .find(|p| p.sid().to_string() == participant_sid)?
+                .attributes()
+        };
+        Some(app_attributes(&attributes))
+    }
+
+    /// Report attribute changes, from the event loop.
+    pub(crate) fn handle_changed(
+        &self,
+        participant_sid: &str,
+        changed_attributes: &HashMap<String, String>,
+    ) {
+        let changed = app_attributes(changed_attributes);
+        if changed.is_empty() {
+            return;
+        }
+        self.emitter.emit(VisioEvent::ParticipantAttributesChanged {
+            participant_sid: participant_sid.to_string(),
+            changed,
+        });
+    }
+}
+
+fn app_attributes(attributes: &HashMap<String, String>) -> HashMap<String, String> {
+    attributes
+        .iter()
+        .filter(|(key, _)| !RESERVED_KEYS.contains(&key.as_str()))
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect()
+}
+
+fn check_attributes(attributes: &HashMap<String, String>) -> Result<(), VisioError> {
+    if attributes.is_empty() {
+        return Err(VisioError::Room("no attributes to set".into()));
+    }
+    let mut total = 0;
+    for (key, value) in attributes {
+        if key.is_empty() {
+            return Err(VisioError::Room("attribute key must not be empty".into()));
+        }
+        if RESERVED_KEYS.contains(&key.as_str()) {
+            return Err(VisioError::Room(format!("attribute {key} is reserved")));
+        }
+        if key.len() > MAX_ATTRIBUTE_KEY_BYTES {
+            return Err(VisioError::Room(format!(
+                "attribute key too long: {} bytes (max {MAX_ATTRIBUTE_KEY_BYTES})",
+                key.len()
+            )));
+        }
+        if value.len() > MAX_ATTRIBUTE_VALUE_BYTES {
+            return Err(VisioError::Room(format!(
+                "attribute {key} too large: {} bytes (max {MAX_ATTRIBUTE_VALUE_BYTES})",
+                value.len()
+            )));
+        }
+        total += key.len() + value.len();
+    }
+    if total > MAX_ATTRIBUTES_UPDATE_BYTES {
+        return Err(VisioError::Room(format!(
+            "attributes too large: {total} bytes (max {MAX_ATTRIBUTES_UPDATE_BYTES})"
+        )));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::VisioEventListener;
+
+    struct EventCapture {
+        events: std::sync::Mutex<Vec<VisioEvent>>,
+    }
+
+    impl VisioEventListener for EventCapture {
+        fn on_event(&self, event: VisioEvent) {
+            self.events.lock().unwrap().push(event);
+        }
+    }
+
+    fn attrs(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn validates_updates() {
+        let service = AttributesService::new(Arc::new(Mutex::new(None)), EventEmitter::new());
+        let rejected = |attributes: HashMap<String, String>, reason: &str| {
+            let err = check_attributes(&attributes);
+            assert!(
+                matches!(&err, Err(VisioError::Room(msg)) if msg.contains(reason)),
+                "{err:?}"
+            );
+        };
+        rejected(HashMap::new(), "no attributes");
+        rejected(attrs(&[("", "x")]), "must not be empty");
+        rejected(attrs(&[("presence", "away")]), "reserved");
+        rejected(attrs(&[("handRaisedAt", "")]), "reserved");
+        let long_key = "k".repeat(MAX_ATTRIBUTE_KEY_BYTES + 1);
+        rejected(attrs(&[(&long_key, "x")]), "key too long");
+        let long_value = "v".repeat(MAX_ATTRIBUTE_VALUE_BYTES + 1);
+        rejected(attrs(&[("status", &long_value)]), "status too large");
+        let value = "v".repeat(MAX_ATTRIBUTE_VALUE_BYTES);
+        let many: HashMap<String, String> = (0..10)
+            .map(|i| (format!("key{i}"), value.clone()))
+            .collect();
+        rejected(many, "attributes too large");
+
+        // Valid updates fail on the room, removals included.
+        let err = service
+            .set_local_attributes(attrs(&[("status", "busy"), ("role", "")]))
+            .await;
+        assert!(matches!(err, Err(VisioError::Room(msg)) if msg == "not connected"));
+        assert_eq!(service.participant_attributes("PA_1").await, None);
+    }
+
+    #[test]
+    fn reports_app_attributes_only() {
+        let emitter = EventEmitter::new();
+        let capture = Arc::new(EventCapture {
+            events: std::sync::Mutex::new(Vec::new()),
+        });
+        emitter.add_listener(capture.clone());
+        let service = AttributesService::new(Arc::new(Mutex::new(None)), emitter);
+
+        service.handle_changed("PA_1", &attrs(&[("handRaisedAt", "2024-01-01T00:00:00Z")]));
+        service.handle_changed("PA_1", &attrs(&[("presence", "dnd"), ("status", "busy")]));
+        let events = capture.events.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        assert!(matches!(
+            &events[0],
+            VisioEvent::ParticipantAttributesChanged { participant_sid, changed }
+                if participant_sid == "PA_1" && *changed == attrs(&[("status", "busy")])
+        ));
+    }
+}
@@ -0,0 +1,281 @@
+//! Ducking of remote audio while the local user speaks.
+//!
+//! On speakerphone, remote voices playing over one's own make both hard to
+//! follow. When ducking is enabled, a voice activity detector on the
+//! captured microphone signal lowers the playout gain while the local user
+//! speaks, and raises it back once they stop. Gain changes are ramped so
+//! they do not click.
+//!
+//! The detector runs on the raw capture, before echo cancellation, so the
+//! remote audio coming back through the microphone must not count as
+//! speech: capture only counts when it is clearly louder than what is
+//! being played out.
+
+use std::sync::{Mutex, OnceLock, TryLockError};
+use std::time::{Duration, Instant};
+
+/// Capture RMS above which a block may be speech (about -35 dBFS).
+const SPEECH_RMS: f32 = 580.0;
+
+/// Capture must be this many times louder than the playout to count as
+/// local speech rather than echo.
+const ECHO_MARGIN: f32 = 2.0;
+
+/// How long the gain stays down after the last speech block, so it does
+/// not pump between words.
+const HANGOVER: Duration = Duration::from_millis(400);
+
+/// Playout sample rate (`AudioPlayoutBuffer` holds 48 kHz mono).
+const PLAYOUT_RATE: f32 = 48_000.0;
+
+/// Time to ramp the gain down when speech starts, and back up after.
+const ATTACK_SECS: f32 = 0.05;
+const RELEASE_SECS: f32 = 0.3;
+
+/// Ducking behaviour, from the `audio_ducking_*` settings.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DuckingConfig {
+    pub enabled: bool,
+    /// Share of the remote volume removed while speaking, from 0.0 (no
+    /// change) to 1.0 (silence).
+    pub level: f32,
+}
+
+impl Default for DuckingConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            level: 0.5,
+        }
+    }
+}
+
+impl DuckingConfig {
+    /// Playout gain while the local user speaks.
+    fn ducked_gain(self) -> f32 {
+        1.0 - self.level.clamp(0.0, 1.0)
+    }
+}
+
+#[derive(Debug)]
+struct DuckerState {
+    config: DuckingConfig,
+    /// Current playout gain, ramped towards the target.
+    gain: f32,
+    last_speech: Option<Instant>,
+    /// RMS of the last playout block, before ducking.
+    playout_rms: f32,
+}
+
+/// Voice activity detection on the capture and gain on the playout. Fed by
+/// the capture paths and applied by `AudioPlayoutBuffer::pull_samples`
+/// through `audio_ducker()`.
+#[derive(Debug)]
+pub struct AudioDucker {
+    state: Mutex<DuckerState>,
+}
+
+impl Default for AudioDucker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AudioDucker {
+    pub fn new() -> Self {
+        Self {
+            state: Mutex::new(DuckerState {
+                config: DuckingConfig::default(),
+                gain: 1.0,
+                last_speech: None,
+                playout_rms: 0.0,
+            }),
+        }
+    }
+
+    pub fn config(&self) -> DuckingConfig {
+        self.lock().config
+    }
+
+    /// Takes effect on the next playout block; disabling ramps the gain
+    /// back up.
+    pub fn set_config(&self, config: DuckingConfig) {
+        self.lock().config = config;
+    }
+
+    /// Back to full volume with no recent speech, once no room is joined.
+    pub fn reset(&self) {
+        let mut state = self.lock();
+        state.gain = 1.0;
+        state.last_speech = None;
+        state.playout_rms = 0.0;
+    }
+
+    /// A block of captured microphone samples.
+    pub fn observe_capture(&self, samples: &[i16]) {
+        self.observe_capture_at(samples, Instant::now());
+    }
+
+    /// Apply the ducking gain to a block about to be played out.
+    ///
+    /// Called on the audio thread, so it never waits: while the state is
+    /// locked elsewhere, the block is played out unchanged.
+    pub fn apply(&self, out: &mut [i16]) {
+        self.apply_at(out, Instant::now());
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, DuckerState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn observe_capture_at(&self, samples: &[i16], now: Instant) {
+        let mut state = self.lock();
+        if !state.config.enabled {
+            return;
+        }
+        let level = rms(samples);
+        if level > SPEECH_RMS && level > state.playout_rms * ECHO_MARGIN {
+            state.last_speech = Some(now);
+        }
+    }
+
+    fn apply_at(&self, out: &mut [i16], now: Instant) {
+        let mut state = match self.state.try_lock() {
+            Ok(state) => state,
+            Err(TryLockError::Poisoned(e)) => e.into_inner(),
+            Err(TryLockError::WouldBlock) => return,
+        };
+        state.playout_rms = rms(out);
+        let speaking = state
+            .last_speech
+            .is_some_and(|at| now.saturating_duration_since(at) < HANGOVER);
+        let target = if state.config.enabled && speaking {
+            state.config.ducked_gain()
+        } else {
+            1.0
+        };
+        if state.gain == 1.0 && target == 1.0 {
+            return;
+        }
+        let ramp = if target < state.gain {
+            ATTACK_SECS
+        } else {
+            RELEASE_SECS
+        };
+        let step = 1.0 / (ramp * PLAYOUT_RATE);
+        let mut gain = state.gain;
+        for sample in out.iter_mut() {
+            gain = if gain > target {
+                (gain - step).max(target)
+            } else {
+                (gain + step).min(target)
+            };
+            *sample = (*sample as f32 * gain) as i16;
+        }
+        state.gain = gain;
+    }
+}
+
+fn rms(samples: &[i16]) -> f32 {
+    if samples.is_empty() {
+        return 0.0;
+    }
+    let sum: f64 = samples.iter().map(|&s| (s as f64) * (s as f64)).sum();
+    (sum / samples.len() as f64).sqrt() as f32
+}
+
+/// Process-wide ducker: there is one microphone and one audio output.
+pub fn audio_ducker() -> &'static AudioDucker {
+    static DUCKER: OnceLock<AudioDucker> = OnceLock::new();
+    DUCKER.get_or_init(AudioDucker::new)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 10 ms of playout.
+    fn block(value: i16) -> Vec<i16> {
+        vec![value; 480]
+    }
+
+    #[test]
+    fn ducks_while_speaking_with_ramps() {
+        let ducker = AudioDucker::new();
+        let t0 = Instant::now();
+        let ms = Duration::from_millis;
+
+        // Disabled: speech changes nothing.
+        ducker.observe_capture_at(&block(3000), t0);
+        let mut out = block(1000);
+        ducker.apply_at(&mut out, t0);
+        assert_eq!(out, block(1000));
+
+        ducker.set_config(DuckingConfig {
+            enabled: true,
+            level: 0.75,
+        });
+        ducker.observe_capture_at(&block(3000), t0);
+        // The gain ramps down over the attack, without a jump.
+        let mut out = block(1000);
+        ducker.apply_at(&mut out, t0);
+        assert!(out[0] > 990 && out[479] < out[0]);
+        for i in 1..5 {
+            out = block(1000);
+            ducker.apply_at(&mut out, t0 + ms(10 * i));
+        }
+        assert_eq!(out[479], 250);
+
+        // Held between words, then released slowly.
+        ducker.apply_at(&mut out, t0 + ms(300));
+        out = block(1000);
+        ducker.apply_at(&mut out, t0 + ms(500));
+        assert!(out[0] <= 251 && out[479] > 250 && out[479] < 400);
+        for i in 1..40 {
+            out = block(1000);
+            ducker.apply_at(&mut out, t0 + ms(500 + 10 * i));
+        }
+        assert_eq!(out, block(1000));
+    }
+
+    #[test]
+    fn ignores_quiet_capture_and_echo() {
+        let ducker = AudioDucker::new();
+        ducker.set_config(DuckingConfig {
+            enabled: true,
+            level: 1.0,
+        });
+        let t0 = Instant::now();
+
+        // Background noise.
+        ducker.observe_capture_at(&block(100), t0);
+        let mut out = block(4000);
+        ducker.apply_at(&mut out, t0);
+        assert_eq!(out, block(4000));
+
+        // The remote voice picked up by the microphone.
+        ducker.observe_capture_at(&block(3000), t0);
+        ducker.apply_at(&mut out, t0);
+        assert_eq!(out, block(4000));
+    }
+
+    #[test]
+    fn skips_a_block_while_locked() {
+        let ducker = AudioDucker::new();
+        ducker.set_config(DuckingConfig {
+            enabled: true,
+            level: 1.0,
+        });
+        let t0 = Instant::now();
+        ducker.observe_capture_at(&block(3000), t0);
+
+        let guard = ducker.lock();
+        let mut out = block(1000);
+        ducker.apply_at(&mut out, t0);
+        assert_eq!(out, block(1000));
+        drop(guard);
+
+        ducker.apply_at(&mut out, t0);
+        assert!(out[479] < 1000);
+    }
+}
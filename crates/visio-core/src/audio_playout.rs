@@ -1,5 +1,15 @@
 use std::collections::VecDeque;
-use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
+use std::sync::{Mutex, TryLockError};
+
+use crate::audio_ducking::audio_ducker;
+
+/// Receives a copy of every block handed to the audio output.
+pub type PlayoutTap = Box<dyn Fn(&[i16]) + Send + Sync>;
+
+/// Identifies a tap installed with `AudioPlayoutBuffer::add_tap`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TapId(u64);
 
 /// Thread-safe ring buffer for decoded remote audio PCM samples.
 ///
@@ -13,6 +23,13 @@ pub struct AudioPlayoutBuffer {
     buffer: Mutex<VecDeque<i16>>,
     /// Maximum number of i16 samples to store (2 seconds at 48kHz mono = 96_000).
     max_samples: usize,
+    /// See what the platform output plays (local recording, desktop
+    /// virtual microphone).
+    taps: Mutex<Vec<(TapId, PlayoutTap)>>,
+    next_tap: AtomicU64,
+    /// Output volume (0.0 to 1.0), as `f32` bits.
+    volume: AtomicU32,
+    muted: AtomicBool,
 }
 
 impl Default for AudioPlayoutBuffer {
@@ -28,6 +45,10 @@ impl AudioPlayoutBuffer {
         Self {
             buffer: Mutex::new(VecDeque::with_capacity(max_samples)),
             max_samples,
+            taps: Mutex::new(Vec::new()),
+            next_tap: AtomicU64::new(0),
+            volume: AtomicU32::new(1.0f32.to_bits()),
+            muted: AtomicBool::new(false),
         }
     }
 
@@ -35,7 +56,7 @@ impl AudioPlayoutBuffer {
     ///
     /// If the buffer would exceed max capacity, oldest samples are dropped.
     pub fn push_samples(&self, samples: &[i16]) {
-        let mut buf = self.buffer.lock().unwrap();
+        let mut buf = self.buffer.lock().unwrap_or_else(|e| e.into_inner());
         buf.extend(samples.iter().copied());
 
         // Drop oldest samples if we exceed capacity
@@ -50,7 +71,7 @@ impl AudioPlayoutBuffer {
     /// Returns the number of samples actually written. Unfilled positions
     /// in `out` are zeroed (silence).
     pub fn pull_samples(&self, out: &mut [i16]) -> usize {
-        let mut buf = self.buffer.lock().unwrap();
+        let mut buf = self.buffer.lock().unwrap_or_else(|e| e.into_inner());
         let available = buf.len().min(out.len());
 
         for (i, sample) in buf.drain(..available).enumerate() {
@@ -61,13 +82,88 @@ impl AudioPlayoutBuffer {
         for sample in out[available..].iter_mut() {
             *sample = 0;
         }
+        drop(buf);
+
+        // Lower remote audio while the local user speaks, if enabled.
+        audio_ducker().apply(out);
+
+        // Never wait on the audio thread: while a tap is being added or
+        // removed, this block is not tapped.
+        let taps = match self.taps.try_lock() {
+            Ok(taps) => Some(taps),
+            Err(TryLockError::Poisoned(e)) => Some(e.into_inner()),
+            Err(TryLockError::WouldBlock) => None,
+        };
+        for (_, tap) in taps.iter().flat_map(|taps| taps.iter()) {
+            tap(out);
+        }
+        drop(taps);
+
+        // After the taps: the output volume is the listener's choice and
+        // does not change what is recorded.
+        if self.muted.load(Ordering::Relaxed) {
+            out.fill(0);
+        } else {
+            let volume = self.volume();
+            if volume < 1.0 {
+                for sample in out.iter_mut() {
+                    *sample = (*sample as f32 * volume) as i16;
+                }
+            }
+        }
 
         available
     }
 
+    /// Output volume, from 0.0 (silent) to 1.0 (unchanged).
+    pub fn volume(&self) -> f32 {
+        f32::from_bits(self.volume.load(Ordering::Relaxed))
+    }
+
+    pub fn set_volume(&self, volume: f32) {
+        let volume = if volume.is_nan() {
+            1.0
+        } else {
+            volume.clamp(0.0, 1.0)
+        };
+        self.volume.store(volume.to_bits(), Ordering::Relaxed);
+    }
+
+    /// Whether all remote audio is silenced, whatever the volume.
+    pub fn is_muted(&self) -> bool {
+        self.muted.load(Ordering::Relaxed)
+    }
+
+    pub fn set_muted(&self, muted: bool) {
+        self.muted.store(muted, Ordering::Relaxed);
+    }
+
+    /// Install a tap called with each block pulled by the output, silence
+    /// included. It runs on the audio thread and must not block: copy the
+    /// block into a bounded queue, with `try_lock` or `try_send`, and drop
+    /// it if the queue is busy or full.
+    pub fn add_tap(&self, tap: PlayoutTap) -> TapId {
+        let id = TapId(self.next_tap.fetch_add(1, Ordering::Relaxed));
+        self.taps
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push((id, tap));
+        id
+    }
+
+    pub fn remove_tap(&self, id: TapId) {
+        self.taps
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .retain(|(tap_id, _)| *tap_id != id);
+    }
+
     /// Clear all buffered samples (e.g., on disconnect).
     pub fn clear(&self) {
-        self.buffer.lock().unwrap().clear();
+        self.buffer
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clear();
     }
 }
 
@@ -97,6 +193,10 @@ mod tests {
         let buf = AudioPlayoutBuffer {
             buffer: Mutex::new(VecDeque::with_capacity(4)),
             max_samples: 4,
+            taps: Mutex::new(Vec::new()),
+            next_tap: AtomicU64::new(0),
+            volume: AtomicU32::new(1.0f32.to_bits()),
+            muted: AtomicBool::new(false),
         };
 
         buf.push_samples(&[1, 2, 3, 4]);
@@ -117,6 +217,79 @@ mod tests {
         assert_eq!(out, vec![0, 0, 0]);
     }
 
+    #[test]
+    fn volume_and_mute_apply_after_the_tap() {
+        let buf = AudioPlayoutBuffer::new();
+        let tapped = std::sync::Arc::new(Mutex::new(Vec::new()));
+        let sink = tapped.clone();
+        buf.add_tap(Box::new(move |samples: &[i16]| {
+            sink.lock().unwrap().extend_from_slice(samples)
+        }));
+
+        buf.set_volume(0.5);
+        buf.push_samples(&[1000, -1000]);
+        let mut out = vec![0i16; 2];
+        buf.pull_samples(&mut out);
+        assert_eq!(out, vec![500, -500]);
+
+        buf.set_muted(true);
+        buf.push_samples(&[1000, -1000]);
+        buf.pull_samples(&mut out);
+        assert_eq!(out, vec![0, 0]);
+        assert_eq!(*tapped.lock().unwrap(), vec![1000, -1000, 1000, -1000]);
+
+        buf.set_muted(false);
+        buf.set_volume(3.0);
+        assert_eq!(buf.volume(), 1.0);
+    }
+
+    #[test]
+    fn removed_taps_stop_receiving_blocks() {
+        let buf = AudioPlayoutBuffer::new();
+        let counts = std::sync::Arc::new([AtomicU32::new(0), AtomicU32::new(0)]);
+        let ids = [0, 1].map(|i| {
+            let counts = counts.clone();
+            buf.add_tap(Box::new(move |_: &[i16]| {
+                counts[i].fetch_add(1, Ordering::Relaxed);
+            }))
+        });
+
+        let mut out = vec![0i16; 2];
+        buf.pull_samples(&mut out);
+        buf.remove_tap(ids[0]);
+        buf.pull_samples(&mut out);
+
+        assert_eq!(counts[0].load(Ordering::Relaxed), 1);
+        assert_eq!(counts[1].load(Ordering::Relaxed), 2);
+    }
+
+    #[test]
+    fn taps_never_block_or_fail_the_output() {
+        let buf = std::sync::Arc::new(AudioPlayoutBuffer::new());
+        let count = std::sync::Arc::new(AtomicU32::new(0));
+        let tapped = count.clone();
+        buf.add_tap(Box::new(move |_: &[i16]| {
+            tapped.fetch_add(1, Ordering::Relaxed);
+        }));
+        let mut out = vec![0i16; 2];
+
+        // Taps being changed: the block plays untapped.
+        let held = buf.taps.lock().unwrap();
+        buf.pull_samples(&mut out);
+        drop(held);
+        assert_eq!(count.load(Ordering::Relaxed), 0);
+
+        // A panic while the taps were locked does not stop the output.
+        let poisoner = buf.clone();
+        let _ = std::thread::spawn(move || {
+            let _taps = poisoner.taps.lock().unwrap();
+            panic!("poison the taps");
+        })
+        .join();
+        buf.pull_samples(&mut out);
+        assert_eq!(count.load(Ordering::Relaxed), 1);
+    }
+
     #[test]
     fn clear_empties_buffer() {
         let buf = AudioPlayoutBuffer::new();
@@ -1,10 +1,36 @@
 use crate::errors::VisioError;
+use crate::meet_api::{EntryRequest, HttpMeetApi, LOBBY_COOKIE_NAME, MeetApi, RoomRequest};
+use chrono::{DateTime, Utc};
 use serde::Deserialize;
+use std::time::Duration;
 
-/// Response from the Meet API.
+/// Room payload returned by the Meet API.
 #[derive(Debug, Deserialize)]
-struct MeetApiResponse {
-    livekit: LiveKitCredentials,
+struct MeetApiRoom {
+    #[serde(default)]
+    id: Option<String>,
+    /// Missing when the room is restricted and we were not let in yet.
+    #[serde(default)]
+    livekit: Option<LiveKitCredentials>,
+    #[serde(default)]
+    access_level: Option<String>,
+    /// Dial-in PIN, when telephony is enabled on the instance.
+    #[serde(default)]
+    pin_code: Option<String>,
+    /// RFC 3339 time at which the instance closes the room, if scheduled.
+    #[serde(default)]
+    end_time: Option<String>,
+    /// Longest a meeting may last on the instance, in seconds.
+    #[serde(default)]
+    max_duration: Option<u64>,
+}
+
+/// Lobby payload returned by `request-entry`.
+#[derive(Debug, Deserialize)]
+struct MeetApiEntry {
+    status: String,
+    #[serde(default)]
+    livekit: Option<LiveKitCredentials>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -20,6 +46,51 @@ pub struct TokenInfo {
     pub livekit_url: String,
     /// JWT access token
     pub token: String,
+    /// Room access level (`public`, `trusted`, `restricted`), if reported.
+    pub access_level: Option<String>,
+    /// Meet room id (used by the lobby and invitation endpoints).
+    pub room_id: Option<String>,
+    /// Dial-in PIN of the room, if telephony is enabled.
+    pub pin_code: Option<String>,
+    /// When the instance closes the room, if scheduled.
+    pub scheduled_end: Option<DateTime<Utc>>,
+    /// Longest a meeting may last, if the instance limits it.
+    pub max_duration: Option<Duration>,
+}
+
+impl LiveKitCredentials {
+    fn into_token_info(self, access_level: Option<String>, room_id: Option<String>) -> TokenInfo {
+        TokenInfo {
+            livekit_url: self
+                .url
+                .replace("https://", "wss://")
+                .replace("http://", "ws://"),
+            token: self.token,
+            access_level,
+            room_id,
+            pin_code: None,
+            scheduled_end: None,
+            max_duration: None,
+        }
+    }
+}
+
+/// Result of a room lookup.
+#[derive(Debug, Clone)]
+pub enum RoomAccess {
+    /// We may join right away.
+    Granted(TokenInfo),
+    /// The room is restricted: the host has to let us in. Send the request
+    /// with `AuthService::request_entry_with`.
+    LobbyRequired(EntryRequest),
+}
+
+/// Host decision on a lobby entry request.
+#[derive(Debug, Clone)]
+pub enum EntryStatus {
+    Waiting,
+    Granted(TokenInfo),
+    Denied,
 }
 
 /// Requests a LiveKit token from the Meet API.
@@ -37,55 +108,115 @@ impl AuthService {
         username: Option<&str>,
         session_cookie: Option<&str>,
     ) -> Result<TokenInfo, VisioError> {
-        let (instance, slug) = Self::parse_meet_url(meet_url)?;
+        Self::request_token_with(&HttpMeetApi::default(), meet_url, username, session_cookie).await
+    }
 
-        let mut api_url = format!("https://{}/api/v1.0/rooms/{}/", instance, slug);
-        if let Some(name) = username {
-            let encoded = urlencoding::encode(name);
-            api_url.push_str(&format!("?username={encoded}"));
+    /// Same as `request_token`, going through the given `MeetApi` transport.
+    ///
+    /// Restricted rooms that need the host's approval fail with
+    /// `VisioError::Auth`; use `lookup_room_with` to go through the lobby.
+    pub async fn request_token_with(
+        api: &dyn MeetApi,
+        meet_url: &str,
+        username: Option<&str>,
+        session_cookie: Option<&str>,
+    ) -> Result<TokenInfo, VisioError> {
+        match Self::lookup_room_with(api, meet_url, username, session_cookie).await? {
+            RoomAccess::Granted(info) => Ok(info),
+            RoomAccess::LobbyRequired(_) => Err(VisioError::Auth(
+                "room requires approval from the host".into(),
+            )),
         }
+    }
+
+    /// Look up a room: returns LiveKit credentials, or the lobby request
+    /// to send when the room is restricted.
+    pub async fn lookup_room_with(
+        api: &dyn MeetApi,
+        meet_url: &str,
+        username: Option<&str>,
+        session_cookie: Option<&str>,
+    ) -> Result<RoomAccess, VisioError> {
+        let (instance, slug) = Self::parse_meet_url(meet_url)?;
 
-        tracing::info!("requesting token from Meet API: {}", api_url);
+        let request = RoomRequest {
+            instance,
+            slug,
+            username: username.map(str::to_string),
+            session_cookie: session_cookie.map(str::to_string),
+        };
+        let resp = api.get_room(&request).await?;
+        Self::check_status(resp.status)?;
 
-        let client = reqwest::Client::builder()
-            .redirect(reqwest::redirect::Policy::none())
-            .build()
-            .map_err(|e| VisioError::Http(e.to_string()))?;
+        let data: MeetApiRoom = serde_json::from_str(&resp.body)
+            .map_err(|e| VisioError::Auth(format!("invalid Meet API response: {e}")))?;
 
-        let mut req = client.get(&api_url);
-        if let Some(cookie) = session_cookie {
-            req = req.header("Cookie", format!("sessionid={cookie}"));
+        match (data.livekit, data.access_level.as_deref(), data.id) {
+            (Some(livekit), _, room_id) => Ok(RoomAccess::Granted(TokenInfo {
+                pin_code: data.pin_code,
+                // An unreadable end time is ignored like a missing one.
+                scheduled_end: data
+                    .end_time
+                    .and_then(|t| DateTime::parse_from_rfc3339(&t).ok())
+                    .map(|t| t.with_timezone(&Utc)),
+                max_duration: data.max_duration.map(Duration::from_secs),
+                ..livekit.into_token_info(data.access_level, room_id)
+            })),
+            (None, Some("restricted"), Some(room_id)) => {
+                Ok(RoomAccess::LobbyRequired(EntryRequest {
+                    instance: request.instance,
+                    room_id,
+                    username: request.username,
+                    session_cookie: request.session_cookie,
+                    lobby_cookie: None,
+                }))
+            }
+            _ => Err(VisioError::Auth(
+                "invalid Meet API response: missing LiveKit credentials".into(),
+            )),
         }
+    }
 
-        let resp = req.send().await.map_err(|e| VisioError::Http(e.to_string()))?;
+    /// Send (or repeat) a lobby entry request. Keeps `request.lobby_cookie`
+    /// up to date so the next poll is recognized as the same participant.
+    pub async fn request_entry_with(
+        api: &dyn MeetApi,
+        request: &mut EntryRequest,
+    ) -> Result<EntryStatus, VisioError> {
+        let resp = api.request_entry(request).await?;
+        if let Some(cookie) = lobby_cookie(&resp.set_cookies) {
+            request.lobby_cookie = Some(cookie);
+        }
+        Self::check_status(resp.status)?;
 
-        if resp.status().is_redirection() || resp.status() == reqwest::StatusCode::UNAUTHORIZED {
+        let data: MeetApiEntry = serde_json::from_str(&resp.body)
+            .map_err(|e| VisioError::Auth(format!("invalid Meet API response: {e}")))?;
+        match (data.status.as_str(), data.livekit) {
+            ("waiting", _) => Ok(EntryStatus::Waiting),
+            ("denied", _) => Ok(EntryStatus::Denied),
+            ("accepted", Some(livekit)) => Ok(EntryStatus::Granted(
+                livekit.into_token_info(Some("restricted".into()), Some(request.room_id.clone())),
+            )),
+            (status, _) => Err(VisioError::Auth(format!(
+                "unexpected lobby status: {status}"
+            ))),
+        }
+    }
+
+    fn check_status(status: u16) -> Result<(), VisioError> {
+        let status = reqwest::StatusCode::from_u16(status)
+            .map_err(|_| VisioError::Auth(format!("Meet API returned status {status}")))?;
+
+        if status.is_redirection() || status == reqwest::StatusCode::UNAUTHORIZED {
             return Err(VisioError::AuthRequired);
         }
 
-        if !resp.status().is_success() {
+        if !status.is_success() {
             return Err(VisioError::Auth(format!(
-                "Meet API returned status {}",
-                resp.status()
+                "Meet API returned status {status}"
             )));
         }
-
-        let data: MeetApiResponse = resp
-            .json()
-            .await
-            .map_err(|e| VisioError::Auth(format!("invalid Meet API response: {e}")))?;
-
-        // Convert URL to WebSocket
-        let livekit_url = data
-            .livekit
-            .url
-            .replace("https://", "wss://")
-            .replace("http://", "ws://");
-
-        Ok(TokenInfo {
-            livekit_url,
-            token: data.livekit.token,
-        })
+        Ok(())
     }
 
     /// Extract and validate the room slug from user input.
@@ -122,6 +253,16 @@ impl AuthService {
         Self::request_token(meet_url, username, session_cookie).await
     }
 
+    /// Same as `validate_room`, going through the given `MeetApi` transport.
+    pub async fn validate_room_with(
+        api: &dyn MeetApi,
+        meet_url: &str,
+        username: Option<&str>,
+        session_cookie: Option<&str>,
+    ) -> Result<TokenInfo, VisioError> {
+        Self::request_token_with(api, meet_url, username, session_cookie).await
+    }
+
     /// Extract the Meet instance hostname from a room URL.
     pub fn parse_instance(meet_url: &str) -> Result<String, VisioError> {
         let (instance, _) = Self::parse_meet_url(meet_url)?;
@@ -129,7 +270,7 @@ impl AuthService {
     }
 
     /// Parse a Meet URL into (instance, room_slug).
-    fn parse_meet_url(url: &str) -> Result<(String, String), VisioError> {
+    pub(crate) fn parse_meet_url(url: &str) -> Result<(String, String), VisioError> {
         let url = url
             .trim()
             .trim_end_matches('/')
@@ -147,6 +288,15 @@ impl AuthService {
     }
 }
 
+/// Value of the lobby cookie among `Set-Cookie` headers.
+fn lobby_cookie(set_cookies: &[String]) -> Option<String> {
+    set_cookies.iter().find_map(|header| {
+        let pair = header.split(';').next()?;
+        let (name, value) = pair.split_once('=')?;
+        (name.trim() == LOBBY_COOKIE_NAME).then(|| value.trim().to_string())
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -205,4 +355,14 @@ mod tests {
         let slug = AuthService::extract_slug("https://meet.example.com/abc-defg-hij/").unwrap();
         assert_eq!(slug, "abc-defg-hij");
     }
+
+    #[test]
+    fn lobby_cookie_is_read_from_set_cookie_headers() {
+        let headers = vec![
+            "csrftoken=abc; Path=/".to_string(),
+            "lobbyParticipantId=7f3e; Path=/; HttpOnly".to_string(),
+        ];
+        assert_eq!(lobby_cookie(&headers).as_deref(), Some("7f3e"));
+        assert_eq!(lobby_cookie(&headers[..1]), None);
+    }
 }
@@ -0,0 +1,285 @@
+//! Loudness normalization of remote audio.
+//!
+//! Some participants sit close to a good microphone, others whisper into a
+//! laptop across the room; on a phone speaker the quiet ones get lost. When
+//! auto-gain is enabled, the decoded audio of each participant is brought
+//! towards a common loudness before it reaches the playout buffer.
+//!
+//! Loudness is approximated by the RMS level, averaged over a few seconds
+//! of speech (no K-weighting, so the target is close to, not exactly,
+//! LUFS). Silence is ignored, so the gain does not creep up between
+//! sentences, and gain changes are ramped. The gain reached for each
+//! participant is remembered by identity: after a reconnection or a
+//! resubscription their audio starts at the right level.
+
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+
+/// Blocks below this RMS (about -50 dBFS) are silence and left out of the
+/// loudness estimate.
+const SILENCE_RMS: f32 = 100.0;
+
+/// Averaging time of the loudness estimate.
+const LOUDNESS_SECS: f32 = 3.0;
+
+/// Gain bounds: at most +12 dB for quiet voices and -12 dB for loud ones.
+const MIN_GAIN: f32 = 0.25;
+const MAX_GAIN: f32 = 4.0;
+
+/// Time to ramp the gain by a factor of 2.
+const RAMP_SECS: f32 = 0.5;
+
+/// Remote audio sample rate (48 kHz mono, see `AudioPlayoutBuffer`).
+const SAMPLE_RATE: f32 = 48_000.0;
+
+/// Participants remembered at most; the oldest entries go first.
+const MAX_PARTICIPANTS: usize = 256;
+
+/// Auto-gain behaviour, from the `auto_gain_*` settings.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AutoGainConfig {
+    pub enabled: bool,
+    /// Loudness every participant is brought to, in dBFS (roughly LUFS).
+    pub target_lufs: f32,
+}
+
+impl Default for AutoGainConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            target_lufs: -20.0,
+        }
+    }
+}
+
+impl AutoGainConfig {
+    /// Target RMS in sample units.
+    fn target_rms(self) -> f32 {
+        i16::MAX as f32 * 10f32.powf(self.target_lufs.clamp(-40.0, -6.0) / 20.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ParticipantGain {
+    /// Averaged mean square of the speech blocks, `None` before the first.
+    mean_square: Option<f32>,
+    gain: f32,
+    /// Order of the last update, to forget the oldest participants.
+    last_used: u64,
+}
+
+#[derive(Debug, Default)]
+struct AutoGainState {
+    config: AutoGainConfig,
+    participants: HashMap<String, ParticipantGain>,
+    updates: u64,
+}
+
+/// Per-participant loudness normalization, applied by the audio stream of
+/// each remote track through `auto_gain()`.
+#[derive(Debug, Default)]
+pub struct AutoGain {
+    state: Mutex<AutoGainState>,
+}
+
+impl AutoGain {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn config(&self) -> AutoGainConfig {
+        self.lock().config
+    }
+
+    /// Takes effect on the next block. The remembered gains are kept when
+    /// disabling, for when it is enabled again.
+    pub fn set_config(&self, config: AutoGainConfig) {
+        self.lock().config = config;
+    }
+
+    /// Forget the remembered gains, once no room is joined.
+    pub fn reset(&self) {
+        let mut state = self.lock();
+        state.participants.clear();
+        state.updates = 0;
+    }
+
+    /// Gain currently applied to `identity`, `None` if they were never
+    /// heard with auto-gain enabled.
+    pub fn gain(&self, identity: &str) -> Option<f32> {
+        self.lock().participants.get(identity).map(|p| p.gain)
+    }
+
+    /// Normalize a block of decoded audio from `identity`. Does nothing
+    /// while auto-gain is disabled.
+    pub fn process(&self, identity: &str, samples: &mut [i16]) {
+        if samples.is_empty() {
+            return;
+        }
+        let mut state = self.lock();
+        let config = state.config;
+        if !config.enabled {
+            return;
+        }
+        state.updates += 1;
+        let last_used = state.updates;
+        if !state.participants.contains_key(identity)
+            && state.participants.len() >= MAX_PARTICIPANTS
+            && let Some(oldest) = state
+                .participants
+                .iter()
+                .min_by_key(|(_, p)| p.last_used)
+                .map(|(id, _)| id.clone())
+        {
+            state.participants.remove(&oldest);
+        }
+        let new = ParticipantGain {
+            mean_square: None,
+            gain: 1.0,
+            last_used,
+        };
+        let participant = state
+            .participants
+            .entry(identity.to_string())
+            .or_insert(new);
+        participant.last_used = last_used;
+
+        let block_secs = samples.len() as f32 / SAMPLE_RATE;
+        let square = mean_square(samples);
+        if square > SILENCE_RMS * SILENCE_RMS {
+            let weight = (block_secs / LOUDNESS_SECS).min(1.0);
+            participant.mean_square = Some(match participant.mean_square {
+                Some(ms) => ms + (square - ms) * weight,
+                None => square,
+            });
+        }
+        let Some(ms) = participant.mean_square else {
+            return;
+        };
+
+        // Never push the block into clipping.
+        let peak = samples.iter().map(|s| s.unsigned_abs()).max().unwrap_or(0);
+        let headroom = if peak == 0 {
+            MAX_GAIN
+        } else {
+            i16::MAX as f32 / peak as f32
+        };
+        let target = (config.target_rms() / ms.sqrt())
+            .clamp(MIN_GAIN, MAX_GAIN)
+            .min(headroom);
+
+        // The gain drops at once when a block would clip, and ramps otherwise.
+        let start = participant.gain.min(headroom);
+        let max_step = 2f32.powf(block_secs / RAMP_SECS);
+        let end = target.clamp(start / max_step, start * max_step);
+        participant.gain = end;
+        drop(state);
+
+        let n = samples.len() as f32;
+        for (i, sample) in samples.iter_mut().enumerate() {
+            let gain = start + (end - start) * (i + 1) as f32 / n;
+            *sample = (*sample as f32 * gain) as i16;
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, AutoGainState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+fn mean_square(samples: &[i16]) -> f32 {
+    let sum: f64 = samples.iter().map(|&s| (s as f64) * (s as f64)).sum();
+    (sum / samples.len() as f64) as f32
+}
+
+/// Process-wide auto-gain: there is one audio output.
+pub fn auto_gain() -> &'static AutoGain {
+    static AUTO_GAIN: OnceLock<AutoGain> = OnceLock::new();
+    AUTO_GAIN.get_or_init(AutoGain::new)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 10 ms of a square wave at `amplitude`.
+    fn block(amplitude: i16) -> Vec<i16> {
+        (0..480)
+            .map(|i| if i % 2 == 0 { amplitude } else { -amplitude })
+            .collect()
+    }
+
+    fn enabled() -> AutoGain {
+        let gain = AutoGain::new();
+        gain.set_config(AutoGainConfig {
+            enabled: true,
+            target_lufs: -20.0,
+        });
+        gain
+    }
+
+    #[test]
+    fn brings_participants_to_the_target() {
+        let gain = AutoGain::new();
+        let mut out = block(500);
+        gain.process("alice", &mut out);
+        assert_eq!(out, block(500));
+        assert_eq!(gain.gain("alice"), None);
+
+        // -20 dBFS is an amplitude of about 3277 for a square wave.
+        let gain = enabled();
+        for _ in 0..300 {
+            out = block(1000);
+            gain.process("quiet", &mut out);
+            out = block(10000);
+            gain.process("loud", &mut out);
+        }
+        let mut quiet = block(1000);
+        gain.process("quiet", &mut quiet);
+        let mut loud = block(10000);
+        gain.process("loud", &mut loud);
+        assert!((3100..3450).contains(&quiet[0]), "{}", quiet[0]);
+        assert!((3100..3450).contains(&loud[0]), "{}", loud[0]);
+    }
+
+    #[test]
+    fn ramps_and_remembers_the_gain_through_silence() {
+        let gain = enabled();
+        let mut out = block(1000);
+        gain.process("alice", &mut out);
+        // No jump on the first block.
+        assert!(out[0] <= 1010 && out[479].abs() > 1000);
+
+        for _ in 0..300 {
+            out = block(1000);
+            gain.process("alice", &mut out);
+        }
+        let reached = gain.gain("alice").unwrap();
+        assert!(reached > 3.0);
+
+        for _ in 0..100 {
+            out = block(10);
+            gain.process("alice", &mut out);
+        }
+        assert_eq!(gain.gain("alice"), Some(reached));
+
+        // Disabled: untouched, and the gain is still there afterwards.
+        gain.set_config(AutoGainConfig::default());
+        out = block(1000);
+        gain.process("alice", &mut out);
+        assert_eq!(out, block(1000));
+        assert_eq!(gain.gain("alice"), Some(reached));
+    }
+
+    #[test]
+    fn never_clips() {
+        let gain = enabled();
+        for _ in 0..300 {
+            gain.process("alice", &mut block(1000));
+        }
+        // A sudden shout at the gain of a quiet voice: louder, not clipped.
+        let mut out = block(30000);
+        gain.process("alice", &mut out);
+        assert!(out[0] > 30000 && out[0] == -out[1]);
+    }
+}
@@ -0,0 +1,194 @@
+//! Capture timestamps and audio/video skew.
+//!
+//! Capture paths stamp each frame with the time it was captured, on the
+//! platform monotonic clock: `CLOCK_MONOTONIC` on Linux and Android
+//! (`System.nanoTime()`), the host clock on Apple platforms
+//! (`mach_absolute_time`, which AVFoundation sample buffers use).
+//! WebRTC aligns these timestamps to its own clock, so only their
+//! spacing matters to it.
+//!
+//! `AvSyncMonitor` compares each capture timestamp with the time the
+//! frame reaches the core. The difference between the video and audio
+//! pipeline delays is the skew receivers will have to compensate for.
+
+use std::sync::{Mutex, OnceLock};
+
+/// Smoothing factor of the delay averages.
+const EWMA_ALPHA: f64 = 0.1;
+
+/// A stream that sent nothing for this long is no longer reported.
+const STALE_AFTER_US: i64 = 2_000_000;
+
+/// Delays above this are treated as a timestamp from another clock and
+/// ignored.
+const MAX_PLAUSIBLE_DELAY_US: i64 = 10_000_000;
+
+/// Current time on the capture clock, in microseconds.
+// The timespec fields are 32-bit on 32-bit Android targets.
+#[allow(clippy::unnecessary_cast)]
+pub fn monotonic_us() -> i64 {
+    #[cfg(unix)]
+    {
+        #[cfg(any(target_os = "macos", target_os = "ios"))]
+        let clock = libc::CLOCK_UPTIME_RAW;
+        #[cfg(not(any(target_os = "macos", target_os = "ios")))]
+        let clock = libc::CLOCK_MONOTONIC;
+        let mut ts = libc::timespec {
+            tv_sec: 0,
+            tv_nsec: 0,
+        };
+        // SAFETY: `ts` is a valid, writable timespec.
+        unsafe { libc::clock_gettime(clock, &mut ts) };
+        ts.tv_sec as i64 * 1_000_000 + ts.tv_nsec as i64 / 1_000
+    }
+    #[cfg(not(unix))]
+    {
+        static EPOCH: OnceLock<std::time::Instant> = OnceLock::new();
+        EPOCH
+            .get_or_init(std::time::Instant::now)
+            .elapsed()
+            .as_micros() as i64
+    }
+}
+
+/// Timestamp for a frame captured at `timestamp_ns` (capture clock,
+/// nanoseconds). Platforms that cannot tell pass 0: the frame is then
+/// stamped now.
+pub fn capture_timestamp_us(timestamp_ns: i64) -> i64 {
+    if timestamp_ns > 0 {
+        timestamp_ns / 1_000
+    } else {
+        monotonic_us()
+    }
+}
+
+/// Measured capture-to-core delays and their difference.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct AvSyncStats {
+    pub audio_delay_ms: Option<f64>,
+    pub video_delay_ms: Option<f64>,
+    /// Video delay minus audio delay: positive when video lags audio.
+    pub skew_ms: Option<f64>,
+}
+
+#[derive(Debug, Default)]
+struct DelayTracker {
+    avg_us: Option<f64>,
+    last_seen_us: i64,
+}
+
+impl DelayTracker {
+    fn record(&mut self, capture_us: i64, now_us: i64) {
+        let delay = now_us - capture_us;
+        if !(0..=MAX_PLAUSIBLE_DELAY_US).contains(&delay) {
+            return;
+        }
+        let delay = delay as f64;
+        self.avg_us = Some(match self.avg_us {
+            Some(avg) => avg + EWMA_ALPHA * (delay - avg),
+            None => delay,
+        });
+        self.last_seen_us = now_us;
+    }
+
+    fn delay_ms(&self, now_us: i64) -> Option<f64> {
+        self.avg_us
+            .filter(|_| now_us - self.last_seen_us <= STALE_AFTER_US)
+            .map(|us| us / 1_000.0)
+    }
+}
+
+/// Tracks local capture delays. Fed by the capture paths through
+/// `av_sync_monitor()`.
+#[derive(Debug, Default)]
+pub struct AvSyncMonitor {
+    audio: Mutex<DelayTracker>,
+    video: Mutex<DelayTracker>,
+}
+
+impl AvSyncMonitor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// An audio frame captured at `capture_us` reached the core.
+    pub fn record_audio(&self, capture_us: i64) {
+        self.record_audio_at(capture_us, monotonic_us());
+    }
+
+    /// A video frame captured at `capture_us` reached the core.
+    pub fn record_video(&self, capture_us: i64) {
+        self.record_video_at(capture_us, monotonic_us());
+    }
+
+    pub fn stats(&self) -> AvSyncStats {
+        self.stats_at(monotonic_us())
+    }
+
+    fn record_audio_at(&self, capture_us: i64, now_us: i64) {
+        self.audio
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .record(capture_us, now_us);
+    }
+
+    fn record_video_at(&self, capture_us: i64, now_us: i64) {
+        self.video
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .record(capture_us, now_us);
+    }
+
+    fn stats_at(&self, now_us: i64) -> AvSyncStats {
+        let audio_delay_ms = self
+            .audio
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .delay_ms(now_us);
+        let video_delay_ms = self
+            .video
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .delay_ms(now_us);
+        AvSyncStats {
+            audio_delay_ms,
+            video_delay_ms,
+            skew_ms: video_delay_ms.zip(audio_delay_ms).map(|(v, a)| v - a),
+        }
+    }
+}
+
+/// Process-wide monitor: capture paths are global (one camera, one mic).
+pub fn av_sync_monitor() -> &'static AvSyncMonitor {
+    static MONITOR: OnceLock<AvSyncMonitor> = OnceLock::new();
+    MONITOR.get_or_init(AvSyncMonitor::new)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn skew_is_video_delay_minus_audio_delay() {
+        let monitor = AvSyncMonitor::new();
+        assert_eq!(monitor.stats_at(0), AvSyncStats::default());
+
+        monitor.record_audio_at(1_000_000, 1_020_000);
+        monitor.record_video_at(1_000_000, 1_080_000);
+        // Timestamp from another clock: ignored.
+        monitor.record_video_at(-50_000_000, 1_080_000);
+        let stats = monitor.stats_at(1_100_000);
+        assert_eq!(stats.audio_delay_ms, Some(20.0));
+        assert_eq!(stats.video_delay_ms, Some(80.0));
+        assert_eq!(stats.skew_ms, Some(60.0));
+
+        // Camera stopped: no skew anymore.
+        monitor.record_audio_at(4_000_000, 4_020_000);
+        let stats = monitor.stats_at(4_100_000);
+        assert_eq!(stats.video_delay_ms, None);
+        assert_eq!(stats.skew_ms, None);
+
+        assert!(capture_timestamp_us(0) > 0);
+        assert_eq!(capture_timestamp_us(5_000_000), 5_000);
+    }
+}
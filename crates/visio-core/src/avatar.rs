@@ -0,0 +1,134 @@
+//! Placeholder images for participants without video.
+//!
+//! The shells draw a tile with the participant's initials on a color
+//! derived from their name when the camera is off. `generate_avatar`
+//! renders the same tile as a PNG, for the local preview and the snapshot
+//! APIs, so every shell gets the same picture. Initials and color follow
+//! the shells: the first letters of the first two words (or the first two
+//! letters of a single word), on hsl(sum of the code points mod 360, 50%,
+//! 35%). Initials use the built-in font of `VideoOverlay`, so letters
+//! outside Latin are drawn as `?`.
+
+use crate::errors::VisioError;
+use crate::overlay::{GLYPH_ADVANCE, GLYPH_H, GLYPH_W, glyphs};
+
+/// Smallest and largest image generated, in pixels.
+pub const MIN_AVATAR_SIZE: u32 = 16;
+pub const MAX_AVATAR_SIZE: u32 = 1024;
+
+/// Initials shown for `name`, `?` for a blank name.
+pub fn initials(name: &str) -> String {
+    let words: Vec<&str> = name.split_whitespace().collect();
+    let initials: String = match words.as_slice() {
+        [] => return "?".to_string(),
+        [word] => word.chars().take(2).collect(),
+        [first, second, ..] => first
+            .chars()
+            .take(1)
+            .chain(second.chars().take(1))
+            .collect(),
+    };
+    initials.to_uppercase()
+}
+
+/// Background color of the avatar of `name`, as RGB.
+pub fn avatar_color(name: &str) -> [u8; 3] {
+    let hue = name.chars().map(|c| c as u32).sum::<u32>() % 360;
+    hsl_to_rgb(hue as f32, 0.5, 0.35)
+}
+
+/// PNG image (RGB, `size` x `size` pixels) of the initials of `name` on
+/// its color. `size` is clamped between `MIN_AVATAR_SIZE` and
+/// `MAX_AVATAR_SIZE`.
+pub fn generate_avatar(name: &str, size: u32) -> Result<Vec<u8>, VisioError> {
+    let size = size.clamp(MIN_AVATAR_SIZE, MAX_AVATAR_SIZE) as usize;
+    let color = avatar_color(name);
+    let mut rgb: Vec<u8> = color.repeat(size * size);
+
+    // Initials about 2/5 of the tile high, centered.
+    let text = glyphs(&initials(name));
+    let scale = (size * 2 / 5 / GLYPH_H).max(1);
+    let text_w = (text.len() * GLYPH_ADVANCE - 1) * scale;
+    let text_h = GLYPH_H * scale;
+    let x0 = size.saturating_sub(text_w) / 2;
+    let y0 = size.saturating_sub(text_h) / 2;
+    for (i, glyph) in text.iter().enumerate() {
+        for (row, bits) in glyph.iter().enumerate() {
+            for col in 0..GLYPH_W {
+                if bits & (1 << (GLYPH_W - 1 - col)) == 0 {
+                    continue;
+                }
+                let gx = x0 + (i * GLYPH_ADVANCE + col) * scale;
+                let gy = y0 + row * scale;
+                for py in gy..(gy + scale).min(size) {
+                    for px in gx..(gx + scale).min(size) {
+                        let at = (py * size + px) * 3;
+                        rgb[at..at + 3].fill(u8::MAX);
+                    }
+                }
+            }
+        }
+    }
+
+    encode_png(&rgb, size as u32).map_err(|e| VisioError::Room(format!("encode avatar: {e}")))
+}
+
+fn hsl_to_rgb(hue: f32, saturation: f32, lightness: f32) -> [u8; 3] {
+    let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+    let h = hue / 60.0;
+    let x = chroma * (1.0 - (h % 2.0 - 1.0).abs());
+    let (r, g, b) = match h as u32 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let m = lightness - chroma / 2.0;
+    [r, g, b].map(|c| ((c + m) * 255.0).round() as u8)
+}
+
+fn encode_png(rgb: &[u8], size: u32) -> Result<Vec<u8>, png::EncodingError> {
+    let mut out = Vec::new();
+    let mut encoder = png::Encoder::new(&mut out, size, size);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.write_header()?.write_image_data(rgb)?;
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn initials_and_color_follow_the_shells() {
+        assert_eq!(initials("Ada Lovelace"), "AL");
+        assert_eq!(initials("  élodie  martin dupont"), "ÉM");
+        assert_eq!(initials("bob"), "BO");
+        assert_eq!(initials("   "), "?");
+
+        // "Ab": 65 + 98 = 163, hsl(163, 50%, 35%).
+        assert_eq!(avatar_color("Ab"), [45, 134, 109]);
+        assert_eq!(avatar_color(""), [134, 45, 45]);
+    }
+
+    #[test]
+    fn generates_a_square_png() {
+        let png = generate_avatar("Ada Lovelace", 128).unwrap();
+        let decoder = png::Decoder::new(png.as_slice());
+        let mut reader = decoder.read_info().unwrap();
+        let mut pixels = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut pixels).unwrap();
+        assert_eq!((info.width, info.height), (128, 128));
+
+        let color = avatar_color("Ada Lovelace");
+        assert_eq!(pixels[..3], color);
+        // White initials in the middle.
+        assert!(pixels.chunks_exact(3).any(|p| p == [255, 255, 255]));
+
+        let tiny = generate_avatar("Ada", 0).unwrap();
+        assert_eq!(&tiny[16..24], &[0, 0, 0, 16, 0, 0, 0, 16]);
+    }
+}
@@ -0,0 +1,289 @@
+//! Disk cache for avatars and room metadata.
+//!
+//! Entries live under `DataPaths::cache_dir`, one file per key. They
+//! expire after a fixed time and the oldest are dropped when a cache grows
+//! past its size limit. The cache is best effort: I/O errors are logged
+//! and treated as misses.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::auth::TokenInfo;
+use crate::data_paths::DataPaths;
+use crate::errors::VisioError;
+
+/// Avatars change rarely; a day keeps them reasonably fresh.
+const AVATAR_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+const AVATAR_MAX_BYTES: u64 = 20 * 1024 * 1024;
+const ROOM_INFO_TTL: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+const ROOM_INFO_MAX_BYTES: u64 = 1024 * 1024;
+/// Larger downloads are not avatars.
+const MAX_AVATAR_SIZE: usize = 2 * 1024 * 1024;
+
+/// A directory of cached blobs, bounded in age and total size.
+#[derive(Debug, Clone)]
+pub struct DiskCache {
+    dir: PathBuf,
+    ttl: Duration,
+    max_bytes: u64,
+}
+
+impl DiskCache {
+    pub fn new(dir: impl Into<PathBuf>, ttl: Duration, max_bytes: u64) -> Self {
+        Self {
+            dir: dir.into(),
+            ttl,
+            max_bytes,
+        }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Cached bytes for `key`, unless missing or expired.
+    pub fn get(&self, key: &str) -> Option<Vec<u8>> {
+        let path = self.entry_path(key);
+        let modified = fs::metadata(&path).and_then(|m| m.modified()).ok()?;
+        if is_expired(modified, self.ttl) {
+            let _ = fs::remove_file(&path);
+            return None;
+        }
+        fs::read(&path).ok()
+    }
+
+    /// Store `data` under `key`, then drop the oldest entries if the cache
+    /// went over its size limit.
+    pub fn put(&self, key: &str, data: &[u8]) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        // Write then rename so readers never see a partial entry.
+        let path = self.entry_path(key);
+        let tmp = path.with_extension("tmp");
+        fs::write(&tmp, data)?;
+        fs::rename(&tmp, &path)?;
+        self.prune()
+    }
+
+    pub fn remove(&self, key: &str) {
+        let _ = fs::remove_file(self.entry_path(key));
+    }
+
+    /// Delete every entry.
+    pub fn clear(&self) -> io::Result<()> {
+        match fs::remove_dir_all(&self.dir) {
+            Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+            _ => Ok(()),
+        }
+    }
+
+    /// Total size of the entries, in bytes.
+    pub fn size(&self) -> u64 {
+        self.entries().iter().map(|(_, len, _)| len).sum()
+    }
+
+    /// Entries are named by the SHA-256 of their key, so two keys never
+    /// share a file.
+    fn entry_path(&self, key: &str) -> PathBuf {
+        let name: String = Sha256::digest(key.as_bytes())
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect();
+        self.dir.join(name)
+    }
+
+    /// Entry files with their size and modification time.
+    fn entries(&self) -> Vec<(PathBuf, u64, SystemTime)> {
+        let Ok(dir) = fs::read_dir(&self.dir) else {
+            return Vec::new();
+        };
+        dir.filter_map(|entry| {
+            let entry = entry.ok()?;
+            let meta = entry.metadata().ok()?;
+            if !meta.is_file() {
+                return None;
+            }
+            Some((entry.path(), meta.len(), meta.modified().ok()?))
+        })
+        .collect()
+    }
+
+    fn prune(&self) -> io::Result<()> {
+        let mut entries = self.entries();
+        let mut total: u64 = entries.iter().map(|(_, len, _)| len).sum();
+        if total <= self.max_bytes {
+            return Ok(());
+        }
+        entries.sort_by_key(|(_, _, modified)| *modified);
+        for (path, len, _) in entries {
+            if total <= self.max_bytes {
+                break;
+            }
+            fs::remove_file(path)?;
+            total -= len;
+        }
+        Ok(())
+    }
+}
+
+fn is_expired(modified: SystemTime, ttl: Duration) -> bool {
+    SystemTime::now()
+        .duration_since(modified)
+        .is_ok_and(|age| age >= ttl)
+}
+
+/// Room metadata kept between sessions, without the LiveKit credentials.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RoomInfo {
+    pub room_id: Option<String>,
+    pub access_level: Option<String>,
+    pub pin_code: Option<String>,
+}
+
+impl From<&TokenInfo> for RoomInfo {
+    fn from(info: &TokenInfo) -> Self {
+        Self {
+            room_id: info.room_id.clone(),
+            access_level: info.access_level.clone(),
+            pin_code: info.pin_code.clone(),
+        }
+    }
+}
+
+/// The caches shared by the shells: participant avatars and Meet room info.
+#[derive(Debug, Clone)]
+pub struct CacheService {
+    avatars: DiskCache,
+    rooms: DiskCache,
+}
+
+impl CacheService {
+    pub fn new(paths: &DataPaths) -> Self {
+        let dir = paths.cache_dir();
+        Self {
+            avatars: DiskCache::new(dir.join("avatars"), AVATAR_TTL, AVATAR_MAX_BYTES),
+            rooms: DiskCache::new(dir.join("rooms"), ROOM_INFO_TTL, ROOM_INFO_MAX_BYTES),
+        }
+    }
+
+    /// Avatar image at `url` if cached, without touching the network.
+    pub fn cached_avatar(&self, url: &str) -> Option<Vec<u8>> {
+        self.avatars.get(url)
+    }
+
+    /// Avatar image at `url`, downloaded and cached on a miss.
+    pub async fn avatar(&self, url: &str) -> Result<Vec<u8>, VisioError> {
+        if let Some(bytes) = self.cached_avatar(url) {
+            return Ok(bytes);
+        }
+        let resp = reqwest::get(url)
+            .await
+            .map_err(|e| VisioError::Http(format!("avatar download failed: {e}")))?;
+        if !resp.status().is_success() {
+            return Err(VisioError::Http(format!(
+                "avatar download returned status {}",
+                resp.status()
+            )));
+        }
+        let bytes = resp
+            .bytes()
+            .await
+            .map_err(|e| VisioError::Http(format!("avatar download failed: {e}")))?;
+        if bytes.len() > MAX_AVATAR_SIZE {
+            return Err(VisioError::Http(format!(
+                "avatar too large: {} bytes",
+                bytes.len()
+            )));
+        }
+        if let Err(e) = self.avatars.put(url, &bytes) {
+            tracing::warn!("failed to cache avatar {url}: {e}");
+        }
+        Ok(bytes.to_vec())
+    }
+
+    /// Last known info of the room at `meet_url`.
+    pub fn room_info(&self, meet_url: &str) -> Option<RoomInfo> {
+        let bytes = self.rooms.get(&room_key(meet_url))?;
+        serde_json::from_slice(&bytes).ok()
+    }
+
+    /// Remember the info of a room we looked up.
+    pub fn store_room_info(&self, meet_url: &str, info: &RoomInfo) {
+        let Ok(bytes) = serde_json::to_vec(info) else {
+            return;
+        };
+        if let Err(e) = self.rooms.put(&room_key(meet_url), &bytes) {
+            tracing::warn!("failed to cache room info: {e}");
+        }
+    }
+
+    /// Delete all cached avatars and room info.
+    pub fn clear(&self) -> io::Result<()> {
+        self.avatars.clear()?;
+        self.rooms.clear()
+    }
+}
+
+/// The same room may be typed with or without scheme or trailing slash.
+fn room_key(meet_url: &str) -> String {
+    meet_url
+        .trim()
+        .trim_end_matches('/')
+        .trim_start_matches("https://")
+        .trim_start_matches("http://")
+        .to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entries_expire_and_stay_within_size() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = DiskCache::new(dir.path(), Duration::from_secs(60), 10);
+
+        assert_eq!(cache.get("a"), None);
+        cache.put("a", b"12345").unwrap();
+        assert_eq!(cache.get("a").as_deref(), Some(&b"12345"[..]));
+
+        // Going over 10 bytes drops the oldest entry.
+        std::thread::sleep(Duration::from_millis(20));
+        cache.put("b", b"6789012").unwrap();
+        assert_eq!(cache.get("a"), None);
+        assert_eq!(cache.get("b").as_deref(), Some(&b"6789012"[..]));
+        assert_eq!(cache.size(), 7);
+
+        let expired = DiskCache::new(dir.path(), Duration::ZERO, 10);
+        assert_eq!(expired.get("b"), None);
+        assert_eq!(cache.size(), 0);
+    }
+
+    #[test]
+    fn room_info_is_keyed_by_normalized_url() {
+        let dir = tempfile::tempdir().unwrap();
+        let service = CacheService::new(&DataPaths::new(dir.path()));
+        let info = RoomInfo {
+            room_id: Some("7f3e".into()),
+            access_level: Some("public".into()),
+            pin_code: None,
+        };
+
+        service.store_room_info("https://meet.example.com/abc-defg-hij/", &info);
+        assert_eq!(
+            service.room_info("meet.example.com/abc-defg-hij"),
+            Some(info)
+        );
+        assert_eq!(
+            service.cached_avatar("https://meet.example.com/a.png"),
+            None
+        );
+
+        service.clear().unwrap();
+        assert_eq!(service.room_info("meet.example.com/abc-defg-hij"), None);
+    }
+}
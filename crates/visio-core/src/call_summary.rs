@@ -0,0 +1,267 @@
+//! End-of-call summary.
+//!
+//! `CallSummaryTracker` follows the connection state machine of one room
+//! and, when a call ends, builds a `CallSummary`: how long it lasted, how
+//! many people were there at most, how often the connection dropped, the
+//! local connection quality over the call and how many errors were seen.
+//! The summary is emitted with `CallEnded` and kept until the next call,
+//! for the shells to show the post-call rating screen and attach it to
+//! feedback. Unlike `CallMetrics`, it is built for every call and never
+//! leaves the device on its own.
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+
+use crate::clock::Clock;
+use crate::events::{
+    ConnectionQuality, ConnectionState, EventEmitter, VisioEvent, VisioEventListener,
+};
+
+/// Summary of a finished call.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CallSummary {
+    /// End of the call, in milliseconds since the Unix epoch.
+    pub ended_at_ms: i64,
+    /// Time spent connected, from join to hang-up.
+    pub duration_secs: u64,
+    /// Most participants in the room at once, the local one included.
+    pub peak_participants: u32,
+    /// SDK reconnections plus connection losses.
+    pub reconnect_count: u32,
+    /// Local connection quality averaged over the call, `None` if the
+    /// server never reported it.
+    pub average_quality: Option<ConnectionQuality>,
+    /// Connection losses and stalled remote video tracks.
+    pub error_count: u32,
+}
+
+fn quality_score(quality: &ConnectionQuality) -> f64 {
+    match quality {
+        ConnectionQuality::Excellent => 3.0,
+        ConnectionQuality::Good => 2.0,
+        ConnectionQuality::Poor => 1.0,
+        ConnectionQuality::Lost => 0.0,
+    }
+}
+
+fn score_quality(score: f64) -> ConnectionQuality {
+    match score {
+        s if s >= 2.5 => ConnectionQuality::Excellent,
+        s if s >= 1.5 => ConnectionQuality::Good,
+        s if s >= 0.5 => ConnectionQuality::Poor,
+        _ => ConnectionQuality::Lost,
+    }
+}
+
+/// Local quality averaged over time.
+#[derive(Debug, Default)]
+struct QualityAverager {
+    current: Option<(ConnectionQuality, Instant)>,
+    weighted: f64,
+    total: Duration,
+}
+
+impl QualityAverager {
+    /// The quality is `quality` from `now` (`None`: no longer measured).
+    fn set(&mut self, quality: Option<ConnectionQuality>, now: Instant) {
+        if let Some((previous, since)) = self.current.take() {
+            let elapsed = now.saturating_duration_since(since);
+            self.weighted += quality_score(&previous) * elapsed.as_secs_f64();
+            self.total += elapsed;
+        }
+        self.current = quality.map(|q| (q, now));
+    }
+
+    fn average(&self) -> Option<ConnectionQuality> {
+        if self.total.is_zero() {
+            // Reported only at the very end: use that report.
+            return self.current.as_ref().map(|(q, _)| q.clone());
+        }
+        Some(score_quality(self.weighted / self.total.as_secs_f64()))
+    }
+}
+
+/// State of the current call.
+#[derive(Debug, Default)]
+struct SummaryState {
+    connected_since: Option<Instant>,
+    peak_participants: u32,
+    reconnects: u32,
+    sdk_reconnecting: bool,
+    errors: u32,
+    quality: QualityAverager,
+}
+
+impl SummaryState {
+    fn on_state(&mut self, connection: &ConnectionState, now: Instant) {
+        match connection {
+            ConnectionState::Connected => {
+                self.connected_since.get_or_insert(now);
+                self.sdk_reconnecting = false;
+            }
+            ConnectionState::Reconnecting { .. } => {
+                if self.connected_since.is_some() && !self.sdk_reconnecting {
+                    self.sdk_reconnecting = true;
+                    self.reconnects += 1;
+                }
+            }
+            ConnectionState::Connecting | ConnectionState::Disconnected => {}
+        }
+    }
+
+    /// Close the call; `None` if it never connected.
+    fn finish(&mut self, now: Instant, ended_at_ms: i64) -> Option<CallSummary> {
+        let mut state = std::mem::take(self);
+        let connected_since = state.connected_since?;
+        state.quality.set(None, now);
+        Some(CallSummary {
+            ended_at_ms,
+            duration_secs: now.duration_since(connected_since).as_secs(),
+            peak_participants: state.peak_participants.max(1),
+            reconnect_count: state.reconnects,
+            average_quality: state.quality.average(),
+            error_count: state.errors,
+        })
+    }
+}
+
+/// Builds the summary of each call of one room. Obtain it with
+/// `RoomManager::call_summary()`.
+#[derive(Clone)]
+pub struct CallSummaryTracker {
+    emitter: EventEmitter,
+    clock: Arc<dyn Clock>,
+    state: Arc<Mutex<SummaryState>>,
+    last: Arc<Mutex<Option<CallSummary>>>,
+}
+
+impl CallSummaryTracker {
+    pub(crate) fn new(emitter: EventEmitter, clock: Arc<dyn Clock>) -> Self {
+        Self {
+            emitter,
+            clock,
+            state: Arc::new(Mutex::new(SummaryState::default())),
+            last: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Summary of the last call that ended, if any.
+    pub fn last_summary(&self) -> Option<CallSummary> {
+        self.last.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// `remote_count` other participants are in the room, from the event
+    /// loop.
+    pub(crate) fn on_participant_count(&self, remote_count: usize) {
+        let count = remote_count as u32 + 1;
+        let mut state = self.state();
+        state.peak_participants = state.peak_participants.max(count);
+    }
+
+    /// Follow the local connection quality, from the event loop.
+    pub(crate) fn on_local_quality(&self, quality: &ConnectionQuality) {
+        let now = self.clock.now();
+        self.state().quality.set(Some(quality.clone()), now);
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, SummaryState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn finish(&self) {
+        let ended_at_ms = self.clock.utc_now().timestamp_millis();
+        let Some(summary) = self.state().finish(self.clock.now(), ended_at_ms) else {
+            return;
+        };
+        tracing::info!(?summary, "call ended");
+        *self.last.lock().unwrap_or_else(|e| e.into_inner()) = Some(summary.clone());
+        self.emitter.emit(VisioEvent::CallEnded(summary));
+    }
+}
+
+impl VisioEventListener for CallSummaryTracker {
+    fn on_event(&self, event: VisioEvent) {
+        match event {
+            VisioEvent::ConnectionStateChanged(ConnectionState::Disconnected) => self.finish(),
+            VisioEvent::ConnectionStateChanged(connection) => {
+                let now = self.clock.now();
+                self.state().on_state(&connection, now);
+            }
+            VisioEvent::ConnectionLost => {
+                let mut state = self.state();
+                if state.connected_since.is_some() {
+                    state.reconnects += 1;
+                    state.errors += 1;
+                }
+            }
+            VisioEvent::TrackStalled { .. } => self.state().errors += 1,
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::MockClock;
+
+    fn state(s: ConnectionState) -> VisioEvent {
+        VisioEvent::ConnectionStateChanged(s)
+    }
+
+    #[test]
+    fn summarizes_a_call() {
+        let clock = MockClock::new();
+        let emitter = EventEmitter::new();
+        let tracker = CallSummaryTracker::new(emitter.clone(), Arc::new(clock.clone()));
+        emitter.add_listener(Arc::new(tracker.clone()));
+
+        emitter.emit(state(ConnectionState::Connecting));
+        tracker.on_participant_count(2);
+        emitter.emit(state(ConnectionState::Connected));
+        tracker.on_local_quality(&ConnectionQuality::Excellent);
+        clock.advance(Duration::from_secs(60));
+        tracker.on_participant_count(4);
+        tracker.on_local_quality(&ConnectionQuality::Poor);
+        emitter.emit(state(ConnectionState::Reconnecting { attempt: 1 }));
+        emitter.emit(state(ConnectionState::Reconnecting { attempt: 2 }));
+        emitter.emit(state(ConnectionState::Connected));
+        emitter.emit(VisioEvent::TrackStalled {
+            track_sid: "TR_a".into(),
+        });
+        clock.advance(Duration::from_secs(20));
+        tracker.on_participant_count(1);
+        emitter.emit(state(ConnectionState::Disconnected));
+
+        let summary = tracker.last_summary().unwrap();
+        assert_eq!(summary.duration_secs, 80);
+        assert_eq!(summary.peak_participants, 5);
+        assert_eq!(summary.reconnect_count, 1);
+        assert_eq!(summary.error_count, 1);
+        // 60 s excellent, 20 s poor: 2.5 on average.
+        assert_eq!(summary.average_quality, Some(ConnectionQuality::Excellent));
+    }
+
+    #[test]
+    fn failed_joins_leave_the_last_summary() {
+        let clock = MockClock::new();
+        let emitter = EventEmitter::new();
+        let tracker = CallSummaryTracker::new(emitter.clone(), Arc::new(clock.clone()));
+        emitter.add_listener(Arc::new(tracker.clone()));
+
+        emitter.emit(state(ConnectionState::Connected));
+        emitter.emit(VisioEvent::ConnectionLost);
+        clock.advance(Duration::from_secs(5));
+        emitter.emit(state(ConnectionState::Disconnected));
+        let summary = tracker.last_summary().unwrap();
+        assert_eq!((summary.reconnect_count, summary.error_count), (1, 1));
+        assert_eq!(summary.peak_participants, 1);
+        assert_eq!(summary.average_quality, None);
+
+        emitter.emit(state(ConnectionState::Connecting));
+        emitter.emit(state(ConnectionState::Disconnected));
+        assert_eq!(tracker.last_summary(), Some(summary));
+    }
+}
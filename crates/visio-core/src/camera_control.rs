@@ -0,0 +1,174 @@
+//! Camera control commands (torch, zoom, focus).
+//!
+//! Capture stays platform-side: the core only validates the commands the
+//! UI issues and forwards them to the capture layers subscribed with
+//! `CameraControl::add_listener`. The last torch and zoom values are kept,
+//! so a capture layer that (re)starts can apply them to the new device.
+
+use std::sync::{Arc, Mutex, RwLock};
+
+/// Zoom factors are relative to the widest field of view of the device.
+const MIN_ZOOM: f32 = 1.0;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CameraCommand {
+    SetTorch {
+        enabled: bool,
+    },
+    SetZoom {
+        factor: f32,
+    },
+    /// Point in the frame to focus on, normalized to 0..1 from the top-left
+    /// corner of the upright image.
+    SetFocusPoint {
+        x: f32,
+        y: f32,
+    },
+}
+
+pub trait CameraCommandListener: Send + Sync {
+    fn on_camera_command(&self, command: CameraCommand);
+}
+
+/// Last requested torch and zoom values.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CameraControlState {
+    pub torch_enabled: bool,
+    pub zoom_factor: f32,
+}
+
+impl Default for CameraControlState {
+    fn default() -> Self {
+        Self {
+            torch_enabled: false,
+            zoom_factor: MIN_ZOOM,
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct CameraControl {
+    listeners: Arc<RwLock<Vec<Arc<dyn CameraCommandListener>>>>,
+    state: Arc<Mutex<CameraControlState>>,
+}
+
+impl CameraControl {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_listener(&self, listener: Arc<dyn CameraCommandListener>) {
+        self.listeners
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(listener);
+    }
+
+    pub fn state(&self) -> CameraControlState {
+        *self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    pub fn set_torch_enabled(&self, enabled: bool) {
+        self.state
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .torch_enabled = enabled;
+        self.send(CameraCommand::SetTorch { enabled });
+    }
+
+    /// Request a zoom factor. Values below 1 are raised to 1; capture layers
+    /// clamp to the maximum their device supports. Non-finite values are
+    /// ignored.
+    pub fn set_zoom(&self, factor: f32) {
+        if !factor.is_finite() {
+            return;
+        }
+        let factor = factor.max(MIN_ZOOM);
+        self.state
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .zoom_factor = factor;
+        self.send(CameraCommand::SetZoom { factor });
+    }
+
+    /// Request focus on the normalized point (`x`, `y`), clamped to the
+    /// frame. Non-finite values are ignored.
+    pub fn set_focus_point(&self, x: f32, y: f32) {
+        if !x.is_finite() || !y.is_finite() {
+            return;
+        }
+        self.send(CameraCommand::SetFocusPoint {
+            x: x.clamp(0.0, 1.0),
+            y: y.clamp(0.0, 1.0),
+        });
+    }
+
+    /// Back to torch off and no zoom, e.g. when the camera is turned off.
+    pub fn reset(&self) {
+        let state = std::mem::take(&mut *self.state.lock().unwrap_or_else(|e| e.into_inner()));
+        if state.torch_enabled {
+            self.send(CameraCommand::SetTorch { enabled: false });
+        }
+        if state.zoom_factor != MIN_ZOOM {
+            self.send(CameraCommand::SetZoom { factor: MIN_ZOOM });
+        }
+    }
+
+    fn send(&self, command: CameraCommand) {
+        let listeners = self.listeners.read().unwrap_or_else(|e| e.into_inner());
+        for listener in listeners.iter() {
+            listener.on_camera_command(command);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct CommandCapture {
+        commands: Mutex<Vec<CameraCommand>>,
+    }
+
+    impl CameraCommandListener for CommandCapture {
+        fn on_camera_command(&self, command: CameraCommand) {
+            self.commands.lock().unwrap().push(command);
+        }
+    }
+
+    #[test]
+    fn forwards_validated_commands_and_keeps_state() {
+        let control = CameraControl::new();
+        let capture = Arc::new(CommandCapture {
+            commands: Mutex::new(Vec::new()),
+        });
+        control.add_listener(capture.clone());
+
+        control.set_torch_enabled(true);
+        control.set_zoom(0.5);
+        control.set_zoom(f32::NAN);
+        control.set_focus_point(1.5, 0.25);
+        assert_eq!(
+            control.state(),
+            CameraControlState {
+                torch_enabled: true,
+                zoom_factor: 1.0,
+            }
+        );
+        control.set_zoom(2.0);
+        control.reset();
+
+        assert_eq!(control.state(), CameraControlState::default());
+        assert_eq!(
+            *capture.commands.lock().unwrap(),
+            vec![
+                CameraCommand::SetTorch { enabled: true },
+                CameraCommand::SetZoom { factor: 1.0 },
+                CameraCommand::SetFocusPoint { x: 1.0, y: 0.25 },
+                CameraCommand::SetZoom { factor: 2.0 },
+                CameraCommand::SetTorch { enabled: false },
+                CameraCommand::SetZoom { factor: 1.0 },
+            ]
+        );
+    }
+}
@@ -0,0 +1,276 @@
+//! Registry of the native sources fed by platform capture.
+//!
+//! A source is registered when `MeetingControls` publishes the microphone
+//! or camera track and released when the room disconnects, so the capture
+//! pipelines (JNI, C FFI) always push into the source of the live
+//! publication. Each registration gets a handle, reported to the shells
+//! with `VisioEvent::SourceReady`. With several rooms, the last published
+//! source of each kind receives the frames.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, OnceLock};
+
+use livekit::webrtc::audio_frame::AudioFrame;
+use livekit::webrtc::audio_source::native::NativeAudioSource;
+use livekit::webrtc::prelude::*;
+use livekit::webrtc::video_source::native::NativeVideoSource;
+
+use crate::frame_convert::I420Frame;
+
+/// A registered source and its handle.
+#[derive(Debug)]
+struct Slot<T> {
+    current: Option<(u64, T)>,
+}
+
+impl<T> Default for Slot<T> {
+    fn default() -> Self {
+        Self { current: None }
+    }
+}
+
+impl<T: Clone> Slot<T> {
+    /// Replace the source, returning the handle of the one replaced.
+    fn set(&mut self, handle: u64, source: T) -> Option<u64> {
+        self.current
+            .replace((handle, source))
+            .map(|(handle, _)| handle)
+    }
+
+    fn get(&self) -> Option<T> {
+        self.current.as_ref().map(|(_, source)| source.clone())
+    }
+
+    fn handle(&self) -> Option<u64> {
+        self.current.as_ref().map(|(handle, _)| *handle)
+    }
+
+    /// Drop the source if `handle` is still the registered one: a newer
+    /// publication (e.g. by another room) is left alone.
+    fn release(&mut self, handle: u64) -> bool {
+        if self.handle() != Some(handle) {
+            return false;
+        }
+        self.current = None;
+        true
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// The sources the platform capture pipelines push frames into.
+pub struct CaptureSources {
+    next_handle: AtomicU64,
+    audio: Mutex<Slot<NativeAudioSource>>,
+    video: Mutex<Slot<NativeVideoSource>>,
+}
+
+impl CaptureSources {
+    fn new() -> Self {
+        Self {
+            next_handle: AtomicU64::new(1),
+            audio: Mutex::new(Slot::default()),
+            video: Mutex::new(Slot::default()),
+        }
+    }
+
+    /// Source of the published microphone track, if any.
+    pub fn audio(&self) -> Option<NativeAudioSource> {
+        lock(&self.audio).get()
+    }
+
+    /// Source of the published camera track, if any.
+    pub fn video(&self) -> Option<NativeVideoSource> {
+        lock(&self.video).get()
+    }
+
+    fn register_audio(&self, source: NativeAudioSource) -> u64 {
+        let handle = self.next_handle.fetch_add(1, Ordering::Relaxed);
+        lock(&self.audio).set(handle, source);
+        handle
+    }
+
+    fn register_video(&self, source: NativeVideoSource) -> u64 {
+        let handle = self.next_handle.fetch_add(1, Ordering::Relaxed);
+        lock(&self.video).set(handle, source);
+        handle
+    }
+
+    fn release(&self, handle: u64) {
+        if lock(&self.audio).release(handle) || lock(&self.video).release(handle) {
+            tracing::info!("capture source {handle} released");
+        }
+    }
+}
+
+/// Process-wide registry: there is one microphone and one camera.
+pub fn capture_sources() -> &'static CaptureSources {
+    static SOURCES: OnceLock<CaptureSources> = OnceLock::new();
+    SOURCES.get_or_init(CaptureSources::new)
+}
+
+/// The sources published by one room, shared by the room and its
+/// controls. Registered in `capture_sources()` until released.
+#[derive(Clone, Default)]
+pub struct PublishedSources {
+    audio: Arc<Mutex<Slot<NativeAudioSource>>>,
+    video: Arc<Mutex<Slot<NativeVideoSource>>>,
+}
+
+impl PublishedSources {
+    /// Register the source of a new microphone publication, returning its
+    /// handle. Replaces the source of a previous publication.
+    pub(crate) fn publish_audio(&self, source: NativeAudioSource) -> u64 {
+        let registry = capture_sources();
+        let handle = registry.register_audio(source.clone());
+        if let Some(previous) = lock(&self.audio).set(handle, source) {
+            registry.release(previous);
+        }
+        handle
+    }
+
+    /// Register the source of a new camera publication, returning its
+    /// handle. Replaces the source of a previous publication.
+    pub(crate) fn publish_video(&self, source: NativeVideoSource) -> u64 {
+        let registry = capture_sources();
+        let handle = registry.register_video(source.clone());
+        if let Some(previous) = lock(&self.video).set(handle, source) {
+            registry.release(previous);
+        }
+        handle
+    }
+
+    pub fn audio(&self) -> Option<NativeAudioSource> {
+        lock(&self.audio).get()
+    }
+
+    pub fn video(&self) -> Option<NativeVideoSource> {
+        lock(&self.video).get()
+    }
+
+    /// Feed a captured frame to the camera source, rotated clockwise by
+    /// `rotation_degrees` (0, 90, 180 or 270). `false` without a camera
+    /// publication.
+    pub fn push_video(&self, frame: &I420Frame, rotation_degrees: u32, timestamp_us: i64) -> bool {
+        let Some(source) = self.video() else {
+            return false;
+        };
+        let mut buffer = I420Buffer::new(frame.width, frame.height);
+        let (sy, su, sv) = buffer.strides();
+        let (y, u, v) = buffer.data_mut();
+        let (w, cw) = (frame.width as usize, frame.chroma_width() as usize);
+        copy_rows(&frame.y, w, y, sy as usize);
+        copy_rows(&frame.u, cw, u, su as usize);
+        copy_rows(&frame.v, cw, v, sv as usize);
+        let rotation = match rotation_degrees {
+            90 => VideoRotation::VideoRotation90,
+            180 => VideoRotation::VideoRotation180,
+            270 => VideoRotation::VideoRotation270,
+            _ => VideoRotation::VideoRotation0,
+        };
+        source.capture_frame(&VideoFrame {
+            rotation,
+            timestamp_us,
+            buffer,
+        });
+        true
+    }
+
+    /// Feed interleaved PCM samples to the microphone source, which takes
+    /// mono, or stereo with the music preset: other channel counts are
+    /// converted (see `fit_channels`). `false` without a microphone
+    /// publication or if the source rejects the frame.
+    pub async fn push_audio(&self, samples: &[i16], sample_rate: u32, num_channels: u32) -> bool {
+        let Some(source) = self.audio() else {
+            return false;
+        };
+        if num_channels == 0 {
+            return false;
+        }
+        let channels = source.num_channels();
+        let data = fit_channels(samples, num_channels, channels);
+        let frame = AudioFrame {
+            samples_per_channel: data.len() as u32 / channels,
+            data: data.into(),
+            sample_rate,
+            num_channels: channels,
+        };
+        match source.capture_frame(&frame).await {
+            Ok(()) => true,
+            Err(e) => {
+                tracing::warn!("pushed audio frame rejected: {e}");
+                false
+            }
+        }
+    }
+
+    /// Unregister the sources of this room; its publications are gone.
+    pub(crate) fn release(&self) {
+        let registry = capture_sources();
+        for handle in [
+            lock(&self.audio).current.take().map(|(handle, _)| handle),
+            lock(&self.video).current.take().map(|(handle, _)| handle),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            registry.release(handle);
+        }
+    }
+}
+
+/// Convert interleaved PCM from `from` to `to` channels: mixed down to
+/// mono, mono duplicated to every channel, extra channels dropped.
+pub fn fit_channels(samples: &[i16], from: u32, to: u32) -> Vec<i16> {
+    let (from, to) = (from.max(1) as usize, to.max(1) as usize);
+    if from == to {
+        return samples.to_vec();
+    }
+    let mut out = Vec::with_capacity(samples.len() / from * to);
+    for frame in samples.chunks_exact(from) {
+        if to == 1 {
+            let sum: i32 = frame.iter().map(|&s| i32::from(s)).sum();
+            out.push((sum / from as i32) as i16);
+        } else {
+            out.extend((0..to).map(|c| frame[c.min(from - 1)]));
+        }
+    }
+    out
+}
+
+/// Copy `src`, packed `width` bytes per row, into `dst` rows of `stride`.
+fn copy_rows(src: &[u8], width: usize, dst: &mut [u8], stride: usize) {
+    for (row, line) in src.chunks_exact(width).enumerate() {
+        dst[row * stride..row * stride + width].copy_from_slice(line);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn releasing_a_stale_handle_keeps_the_newer_source() {
+        let mut slot = Slot::default();
+        assert_eq!(slot.set(1, "first room"), None);
+        assert_eq!(slot.set(2, "second room"), Some(1));
+
+        // The first room disconnects after the second one published.
+        assert!(!slot.release(1));
+        assert_eq!(slot.get(), Some("second room"));
+
+        assert!(slot.release(2));
+        assert_eq!(slot.get(), None);
+        assert_eq!(slot.handle(), None);
+    }
+
+    #[test]
+    fn fits_channel_counts() {
+        assert_eq!(fit_channels(&[100, 300, -10, 10], 2, 1), vec![200, 0]);
+        assert_eq!(fit_channels(&[1, 2], 1, 2), vec![1, 1, 2, 2]);
+        assert_eq!(fit_channels(&[1, 2, 3, 4, 5, 6], 3, 2), vec![1, 2, 4, 5]);
+        assert_eq!(fit_channels(&[1, 2], 1, 1), vec![1, 2]);
+    }
+}
@@ -1,11 +1,16 @@
-use livekit::data_stream::StreamTextOptions;
+use chrono::{DateTime, FixedOffset, Offset, Utc};
+use livekit::data_stream::{StreamTextOptions, StreamWriter};
 use livekit::prelude::*;
+use serde::{Deserialize, Serialize};
 use std::sync::Arc;
-use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use tokio::sync::Mutex;
 
+use crate::chat_format::{MessageSpan, message_body};
+use crate::chat_unread::{ChatUnread, UnreadTracker};
 use crate::errors::VisioError;
-use crate::events::{ChatMessage, EventEmitter, VisioEvent};
+use crate::events::{ChatMessage, ChatMessageKind, EventEmitter, VisioEvent};
+use crate::i18n;
 
 /// Shared message store between RoomManager event loop and ChatService.
 pub type MessageStore = Arc<Mutex<Vec<ChatMessage>>>;
@@ -13,13 +18,33 @@ pub type MessageStore = Arc<Mutex<Vec<ChatMessage>>>;
 /// The topic used by LiveKit Meet / LaSuite Meet for chat messages.
 const CHAT_TOPIC: &str = "lk.chat";
 
+/// Default limit on the UTF-8 size of a chat message, sent or received.
+pub const DEFAULT_MAX_MESSAGE_BYTES: usize = 64 * 1024;
+
+/// Size of the pieces a message is written in, well under the data
+/// channel packet limit once the stream headers are added.
+const STREAM_CHUNK_BYTES: usize = 8 * 1024;
+
+/// File format of `ChatService::export_chat`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChatExportFormat {
+    /// One `[time] sender: text` line per message.
+    Txt,
+    /// Array of messages, with the Unix time and the localized time.
+    Json,
+    /// RFC 4180, with a header row.
+    Csv,
+}
+
 /// Manages chat messaging via LiveKit data channels.
 pub struct ChatService {
     room: Arc<Mutex<Option<Arc<Room>>>>,
     emitter: EventEmitter,
     messages: MessageStore,
-    unread_count: Arc<AtomicU32>,
-    chat_open: Arc<AtomicBool>,
+    unread: UnreadTracker,
+    max_message_bytes: Arc<AtomicUsize>,
+    formatting: Arc<AtomicBool>,
 }
 
 impl ChatService {
@@ -27,18 +52,34 @@ impl ChatService {
         room: Arc<Mutex<Option<Arc<Room>>>>,
         emitter: EventEmitter,
         messages: MessageStore,
+        unread: UnreadTracker,
+        max_message_bytes: Arc<AtomicUsize>,
+        formatting: Arc<AtomicBool>,
     ) -> Self {
         Self {
             room,
             emitter,
             messages,
-            unread_count: Arc::new(AtomicU32::new(0)),
-            chat_open: Arc::new(AtomicBool::new(false)),
+            unread,
+            max_message_bytes,
+            formatting,
         }
     }
 
     /// Send a chat message to all participants using the Stream API (lk.chat topic).
+    ///
+    /// Long messages are written to the stream in chunks, which receivers
+    /// reassemble. Messages over the room's limit fail with
+    /// `MessageTooLarge` before anything is sent.
     pub async fn send_message(&self, text: &str) -> Result<ChatMessage, VisioError> {
+        let max = self.max_message_bytes.load(Ordering::Relaxed);
+        if text.len() > max {
+            return Err(VisioError::MessageTooLarge {
+                size: text.len(),
+                max,
+            });
+        }
+
         let room = self.room.lock().await;
         let room = room
             .as_ref()
@@ -51,10 +92,13 @@ impl ChatService {
             ..Default::default()
         };
 
-        let info = local
-            .send_text(text, options)
-            .await
-            .map_err(|e| VisioError::Room(format!("send chat: {e}")))?;
+        let send_error = |e| VisioError::Room(format!("send chat: {e}"));
+        let writer = local.stream_text(options).await.map_err(send_error)?;
+        let info = writer.info().clone();
+        for chunk in utf8_chunks(text, STREAM_CHUNK_BYTES) {
+            writer.write(chunk).await.map_err(send_error)?;
+        }
+        writer.close().await.map_err(send_error)?;
 
         let msg = ChatMessage {
             id: info.id,
@@ -62,6 +106,7 @@ impl ChatService {
             sender_name: local.name().to_string(),
             text: text.to_string(),
             timestamp_ms: info.timestamp.timestamp_millis() as u64,
+            kind: ChatMessageKind::Text,
         };
 
         self.messages.lock().await.push(msg.clone());
@@ -71,40 +116,271 @@ impl ChatService {
         Ok(msg)
     }
 
+    /// Body of a message as spans to draw, with emoji shortcodes, bold and
+    /// links resolved, or its sanitized text alone when formatting is off
+    /// (`RoomManager::set_chat_formatting`). See `chat_format`.
+    pub fn message_body(&self, text: &str) -> Vec<MessageSpan> {
+        message_body(text, self.formatting.load(Ordering::Relaxed))
+    }
+
     /// Get all messages in the current session.
     pub async fn messages(&self) -> Vec<ChatMessage> {
         self.messages.lock().await.clone()
     }
 
+    /// The messages of the session as a UTF-8 file in `format`, to save
+    /// the chat before leaving. Times are shown at `utc_offset_minutes`
+    /// from UTC in the date format of `language`.
+    pub async fn export_chat(
+        &self,
+        format: ChatExportFormat,
+        language: &str,
+        utc_offset_minutes: i32,
+    ) -> Vec<u8> {
+        let messages = self.messages.lock().await.clone();
+        export_messages(&messages, format, language, utc_offset_minutes).into_bytes()
+    }
+
     /// Handle an incoming chat message from the event loop.
     pub async fn handle_incoming(&self, msg: ChatMessage) {
         self.messages.lock().await.push(msg.clone());
         self.emitter.emit(VisioEvent::ChatMessageReceived(msg));
-
-        if !self.chat_open.load(Ordering::Relaxed) {
-            let count = self.unread_count.fetch_add(1, Ordering::Relaxed) + 1;
-            self.emitter.emit(VisioEvent::UnreadCountChanged(count));
-        }
     }
 
     /// Clear all messages (on disconnect).
     pub async fn clear(&self) {
         self.messages.lock().await.clear();
-        self.unread_count.store(0, Ordering::Relaxed);
+        self.unread.clear();
     }
 
     /// Mark the chat panel as open or closed.
-    /// When opened, resets the unread count to zero.
+    /// When opened, resets the unread counts to zero.
     pub fn set_chat_open(&self, open: bool) {
-        self.chat_open.store(open, Ordering::Relaxed);
-        if open {
-            self.unread_count.store(0, Ordering::Relaxed);
-            self.emitter.emit(VisioEvent::UnreadCountChanged(0));
-        }
+        self.unread.set_chat_open(open);
     }
 
-    /// Get the current unread message count.
+    /// Get the current unread message count, mentions included.
     pub fn unread_count(&self) -> u32 {
-        self.unread_count.load(Ordering::Relaxed)
+        self.unread.unread().total()
+    }
+
+    /// Unread messages by kind, and where the "new messages" divider goes.
+    pub fn unread(&self) -> ChatUnread {
+        self.unread.unread()
+    }
+
+    /// Acknowledge the messages up to `id`, included, typically the last
+    /// one on screen. Returns `false` if `id` is not unread.
+    pub fn mark_read_up_to(&self, id: &str) -> bool {
+        self.unread.mark_read_up_to(id)
+    }
+}
+
+fn export_messages(
+    messages: &[ChatMessage],
+    format: ChatExportFormat,
+    language: &str,
+    utc_offset_minutes: i32,
+) -> String {
+    let text = |key: &str| i18n::translate(language, key, &[]);
+    let offset =
+        FixedOffset::east_opt(utc_offset_minutes.saturating_mul(60)).unwrap_or_else(|| Utc.fix());
+    let date_format = text("chat.export.dateFormat");
+    let time = |msg: &ChatMessage| {
+        DateTime::from_timestamp_millis(msg.timestamp_ms as i64)
+            .unwrap_or_default()
+            .with_timezone(&offset)
+    };
+    // Messages of participants without a name are still attributed;
+    // system messages name the participant in their text.
+    let sender = |msg: &ChatMessage| {
+        if msg.kind != ChatMessageKind::Text {
+            String::new()
+        } else if msg.sender_name.trim().is_empty() {
+            msg.sender_sid.clone()
+        } else {
+            msg.sender_name.clone()
+        }
+    };
+
+    match format {
+        ChatExportFormat::Txt => messages
+            .iter()
+            .map(|msg| {
+                // Continuation lines are indented to keep one entry per
+                // message.
+                let body = msg.text.replace('\n', "\n    ");
+                let time = time(msg).format(&date_format);
+                match msg.kind {
+                    ChatMessageKind::Text => format!("[{time}] {}: {body}\n", sender(msg)),
+                    _ => format!("[{time}] {body}\n"),
+                }
+            })
+            .collect(),
+        ChatExportFormat::Json => {
+            let entries: Vec<_> = messages
+                .iter()
+                .map(|msg| {
+                    serde_json::json!({
+                        "id": msg.id,
+                        "sender_sid": msg.sender_sid,
+                        "sender_name": sender(msg),
+                        "text": msg.text,
+                        "timestamp_ms": msg.timestamp_ms,
+                        "time": time(msg).format(&date_format).to_string(),
+                        "kind": msg.kind,
+                    })
+                })
+                .collect();
+            serde_json::to_string_pretty(&entries).unwrap_or_default()
+        }
+        ChatExportFormat::Csv => {
+            let header = [
+                text("chat.export.time"),
+                text("chat.export.sender"),
+                text("chat.export.message"),
+            ];
+            std::iter::once(header)
+                .chain(messages.iter().map(|msg| {
+                    [
+                        time(msg).format(&date_format).to_string(),
+                        sender(msg),
+                        msg.text.clone(),
+                    ]
+                }))
+                .map(|row| row.map(|field| csv_field(&field)).join(",") + "\r\n")
+                .collect()
+        }
+    }
+}
+
+/// `field` quoted for CSV when it contains a separator, a quote or a line
+/// break.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Split `text` in pieces of at most `max` bytes without cutting a
+/// character in two. `max` must be at least 4 bytes.
+fn utf8_chunks(text: &str, max: usize) -> Vec<&str> {
+    let mut chunks = Vec::new();
+    let mut rest = text;
+    while rest.len() > max {
+        let mut end = max;
+        while !rest.is_char_boundary(end) {
+            end -= 1;
+        }
+        let (chunk, tail) = rest.split_at(end);
+        chunks.push(chunk);
+        rest = tail;
+    }
+    if !rest.is_empty() {
+        chunks.push(rest);
+    }
+    chunks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chunks_split_on_character_boundaries() {
+        assert!(utf8_chunks("", 4).is_empty());
+        assert_eq!(utf8_chunks("hello", 8), vec!["hello"]);
+        assert_eq!(utf8_chunks("abcdefgh", 4), vec!["abcd", "efgh"]);
+        // "é" is 2 bytes: never cut in the middle.
+        assert_eq!(utf8_chunks("aéé", 4), vec!["aé", "é"]);
+        let long = "🎉x".repeat(1000);
+        let chunks = utf8_chunks(&long, STREAM_CHUNK_BYTES);
+        assert!(chunks.iter().all(|c| c.len() <= STREAM_CHUNK_BYTES));
+        assert_eq!(chunks.concat(), long);
+    }
+
+    fn messages() -> Vec<ChatMessage> {
+        vec![
+            ChatMessage {
+                id: "1".into(),
+                sender_sid: "PA_1".into(),
+                sender_name: "Alice".into(),
+                text: "Hello, \"all\"\nsecond line".into(),
+                // 2024-03-01 09:05:07 UTC
+                timestamp_ms: 1_709_283_907_000,
+                kind: ChatMessageKind::Text,
+            },
+            ChatMessage {
+                id: "2".into(),
+                sender_sid: "PA_2".into(),
+                sender_name: String::new(),
+                text: "hi".into(),
+                timestamp_ms: 1_709_283_967_000,
+                kind: ChatMessageKind::Text,
+            },
+            ChatMessage {
+                id: "system-1".into(),
+                sender_sid: "PA_2".into(),
+                sender_name: "PA_2".into(),
+                text: "PA_2 left the meeting".into(),
+                timestamp_ms: 1_709_283_968_000,
+                kind: ChatMessageKind::ParticipantLeft,
+            },
+        ]
+    }
+
+    #[test]
+    fn exports_with_localized_times() {
+        let txt = export_messages(&messages(), ChatExportFormat::Txt, "fr", 60);
+        assert_eq!(
+            txt,
+            "[01/03/2024 10:05:07] Alice: Hello, \"all\"\n    second line\n\
+             [01/03/2024 10:06:07] PA_2: hi\n\
+             [01/03/2024 10:06:08] PA_2 left the meeting\n"
+        );
+
+        let csv = export_messages(&messages(), ChatExportFormat::Csv, "en", 0);
+        assert_eq!(
+            csv,
+            "Time,Sender,Message\r\n\
+             2024-03-01 09:05:07,Alice,\"Hello, \"\"all\"\"\nsecond line\"\r\n\
+             2024-03-01 09:06:07,PA_2,hi\r\n\
+             2024-03-01 09:06:08,,PA_2 left the meeting\r\n"
+        );
+
+        let json = export_messages(&messages(), ChatExportFormat::Json, "de", -300);
+        let entries: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(entries[0]["time"], "01.03.2024 04:05:07");
+        assert_eq!(entries[0]["timestamp_ms"], 1_709_283_907_000u64);
+        assert_eq!(entries[1]["sender_name"], "PA_2");
+        assert_eq!(entries[2]["kind"], "participant_left");
+        assert_eq!(export_messages(&[], ChatExportFormat::Txt, "en", 0), "");
+    }
+
+    #[tokio::test]
+    async fn oversized_messages_are_refused_before_sending() {
+        let max = Arc::new(AtomicUsize::new(DEFAULT_MAX_MESSAGE_BYTES));
+        let emitter = EventEmitter::new();
+        let chat = ChatService::new(
+            Arc::new(Mutex::new(None)),
+            emitter.clone(),
+            MessageStore::default(),
+            UnreadTracker::new(emitter),
+            max.clone(),
+            Arc::new(AtomicBool::new(true)),
+        );
+        max.store(4, Ordering::Relaxed);
+        let err = chat.send_message("hello").await;
+        assert!(matches!(
+            err,
+            Err(VisioError::MessageTooLarge { size: 5, max: 4 })
+        ));
+        // Within the limit, the room is checked next.
+        assert!(matches!(
+            chat.send_message("hi").await,
+            Err(VisioError::Room(_))
+        ));
     }
 }
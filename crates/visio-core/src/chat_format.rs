@@ -0,0 +1,391 @@
+//! Structured body of chat messages.
+//!
+//! Shells used to render the raw text, each with its own markdown and
+//! emoji handling. `message_body` turns the text into spans that every
+//! shell draws the same way: `:shortcode:` emoji are replaced, `**bold**`,
+//! `[label](url)` and bare `http(s)://` URLs become spans, and nothing else
+//! is interpreted. Spans are plain text: shells must not render them as
+//! HTML or markdown again.
+//!
+//! Control characters (but newlines and tabs) and bidirectional overrides,
+//! which can disguise a link or reorder the text, are removed. Links only
+//! use `http`, `https` or `mailto`; a markdown link to anything else stays
+//! literal text.
+
+use serde::Serialize;
+
+/// How a span is drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SpanKind {
+    Text,
+    Bold,
+    /// `text` opens `url`.
+    Link,
+}
+
+/// Piece of a message body, see `message_body`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MessageSpan {
+    pub kind: SpanKind,
+    pub text: String,
+    /// Set for `Link` spans only.
+    pub url: Option<String>,
+}
+
+impl MessageSpan {
+    fn new(kind: SpanKind, text: &str) -> Self {
+        Self {
+            kind,
+            text: replace_shortcodes(text),
+            url: None,
+        }
+    }
+}
+
+/// Shortcodes and their emoji, sorted for the binary search.
+const SHORTCODES: &[(&str, &str)] = &[
+    ("+1", "👍"),
+    ("-1", "👎"),
+    ("100", "💯"),
+    ("angry", "😠"),
+    ("blush", "😊"),
+    ("broken_heart", "💔"),
+    ("bulb", "💡"),
+    ("clap", "👏"),
+    ("coffee", "☕"),
+    ("cry", "😢"),
+    ("eyes", "👀"),
+    ("fire", "🔥"),
+    ("grin", "😁"),
+    ("heart", "❤️"),
+    ("heart_eyes", "😍"),
+    ("joy", "😂"),
+    ("laughing", "😆"),
+    ("muscle", "💪"),
+    ("neutral_face", "😐"),
+    ("ok_hand", "👌"),
+    ("partying_face", "🥳"),
+    ("pray", "🙏"),
+    ("raised_hand", "✋"),
+    ("rocket", "🚀"),
+    ("scream", "😱"),
+    ("see_no_evil", "🙈"),
+    ("slightly_smiling_face", "🙂"),
+    ("smile", "😄"),
+    ("smiley", "😃"),
+    ("sob", "😭"),
+    ("sparkles", "✨"),
+    ("star", "⭐"),
+    ("sunglasses", "😎"),
+    ("tada", "🎉"),
+    ("thinking", "🤔"),
+    ("thumbsdown", "👎"),
+    ("thumbsup", "👍"),
+    ("warning", "⚠️"),
+    ("wave", "👋"),
+    ("white_check_mark", "✅"),
+    ("wink", "😉"),
+    ("x", "❌"),
+    ("zap", "⚡"),
+];
+
+/// Emoji of `:name:`, if known.
+pub fn shortcode_emoji(name: &str) -> Option<&'static str> {
+    SHORTCODES
+        .binary_search_by(|(code, _)| (*code).cmp(name))
+        .ok()
+        .map(|i| SHORTCODES[i].1)
+}
+
+/// Spans of `text`. With `formatting` off, the sanitized text as a single
+/// span, for users who prefer to see what was typed.
+pub fn message_body(text: &str, formatting: bool) -> Vec<MessageSpan> {
+    let text = sanitize(text);
+    if text.is_empty() {
+        return Vec::new();
+    }
+    if !formatting {
+        return vec![MessageSpan {
+            kind: SpanKind::Text,
+            text,
+            url: None,
+        }];
+    }
+
+    let mut spans = Vec::new();
+    let mut plain = String::new();
+    let mut rest = text.as_str();
+    while let Some(c) = rest.chars().next() {
+        let at_word_start = plain
+            .chars()
+            .last()
+            .is_none_or(|p| p.is_whitespace() || p == '(');
+        let found = bold(rest)
+            .or_else(|| markdown_link(rest))
+            .or_else(|| at_word_start.then(|| bare_url(rest)).flatten());
+        match found {
+            Some((span, len)) => {
+                push_text(&mut spans, &plain);
+                plain.clear();
+                if span.kind == SpanKind::Text {
+                    // A refused link, kept as typed.
+                    push_text(&mut spans, &span.text);
+                } else {
+                    spans.push(span);
+                }
+                rest = &rest[len..];
+            }
+            None => {
+                plain.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+    }
+    push_text(&mut spans, &plain);
+    spans
+}
+
+/// Append `text` as a text span, merged into a text span before it.
+fn push_text(spans: &mut Vec<MessageSpan>, text: &str) {
+    if text.is_empty() {
+        return;
+    }
+    let text = replace_shortcodes(text);
+    match spans.last_mut() {
+        Some(last) if last.kind == SpanKind::Text => last.text.push_str(&text),
+        _ => spans.push(MessageSpan {
+            kind: SpanKind::Text,
+            text,
+            url: None,
+        }),
+    }
+}
+
+/// `**bold**` at the start of `s`, and its length.
+fn bold(s: &str) -> Option<(MessageSpan, usize)> {
+    let inner = s.strip_prefix("**")?;
+    let end = inner.find("**")?;
+    let text = &inner[..end];
+    if text.is_empty()
+        || text.starts_with(char::is_whitespace)
+        || text.ends_with(char::is_whitespace)
+    {
+        return None;
+    }
+    Some((MessageSpan::new(SpanKind::Bold, text), end + 4))
+}
+
+/// `[label](url)` at the start of `s`, and its length. A link to an
+/// unsafe URL is returned as a text span.
+fn markdown_link(s: &str) -> Option<(MessageSpan, usize)> {
+    let inner = s.strip_prefix('[')?;
+    let label_end = inner.find(']')?;
+    let label = &inner[..label_end];
+    let target = inner[label_end + 1..].strip_prefix('(')?;
+    // The URL may contain balanced parentheses, as on Wikipedia.
+    let mut depth = 0usize;
+    let url_end = target.find(|c| match c {
+        '(' => {
+            depth += 1;
+            false
+        }
+        ')' if depth == 0 => true,
+        ')' => {
+            depth -= 1;
+            false
+        }
+        _ => false,
+    })?;
+    let url = &target[..url_end];
+    if label.trim().is_empty()
+        || label.contains('\n')
+        || url.is_empty()
+        || url.contains(char::is_whitespace)
+    {
+        return None;
+    }
+    let len = 1 + label_end + 2 + url_end + 1;
+    if !is_safe_url(url) {
+        return Some((
+            MessageSpan {
+                kind: SpanKind::Text,
+                text: s[..len].to_string(),
+                url: None,
+            },
+            len,
+        ));
+    }
+    let mut span = MessageSpan::new(SpanKind::Link, label);
+    span.url = Some(url.to_string());
+    Some((span, len))
+}
+
+/// `http(s)://...` at the start of `s`, up to a space, without trailing
+/// punctuation, and its length.
+fn bare_url(s: &str) -> Option<(MessageSpan, usize)> {
+    let prefix = ["https://", "http://"]
+        .iter()
+        .find(|scheme| starts_with_ignore_case(s, scheme))?
+        .len();
+    let end = s
+        .find(|c: char| c.is_whitespace() || matches!(c, '<' | '>' | '"'))
+        .unwrap_or(s.len());
+    let mut url = &s[..end];
+    loop {
+        let trimmed = url.trim_end_matches(['.', ',', ';', ':', '!', '?', '\'']);
+        // A closing parenthesis belongs to the URL only if it opened one.
+        let trimmed = match trimmed.strip_suffix(')') {
+            Some(t) if t.matches('(').count() < trimmed.matches(')').count() => t,
+            _ => trimmed,
+        };
+        if trimmed.len() == url.len() {
+            break;
+        }
+        url = trimmed;
+    }
+    if url.len() <= prefix {
+        return None;
+    }
+    let span = MessageSpan {
+        kind: SpanKind::Link,
+        text: url.to_string(),
+        url: Some(url.to_string()),
+    };
+    Some((span, url.len()))
+}
+
+fn is_safe_url(url: &str) -> bool {
+    ["https://", "http://", "mailto:"]
+        .iter()
+        .any(|scheme| url.len() > scheme.len() && starts_with_ignore_case(url, scheme))
+}
+
+fn starts_with_ignore_case(s: &str, prefix: &str) -> bool {
+    s.as_bytes()
+        .get(..prefix.len())
+        .is_some_and(|start| start.eq_ignore_ascii_case(prefix.as_bytes()))
+}
+
+/// `text` without control characters (but newlines and tabs) and
+/// bidirectional controls.
+fn sanitize(text: &str) -> String {
+    text.chars()
+        .filter(|&c| {
+            (!c.is_control() || c == '\n' || c == '\t')
+                && !matches!(c, '\u{202A}'..='\u{202E}' | '\u{2066}'..='\u{2069}')
+        })
+        .collect()
+}
+
+/// `text` with the known `:shortcode:`s replaced.
+fn replace_shortcodes(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find(':') {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let emoji = after.find(':').and_then(|end| {
+            let name = &after[..end];
+            let valid = !name.is_empty()
+                && name
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '+' | '-'));
+            valid
+                .then(|| shortcode_emoji(name))
+                .flatten()
+                .map(|emoji| (emoji, end))
+        });
+        match emoji {
+            Some((emoji, end)) => {
+                out.push_str(emoji);
+                rest = &after[end + 1..];
+            }
+            None => {
+                out.push(':');
+                rest = after;
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn span(kind: SpanKind, text: &str, url: Option<&str>) -> MessageSpan {
+        MessageSpan {
+            kind,
+            text: text.into(),
+            url: url.map(Into::into),
+        }
+    }
+
+    #[test]
+    fn parses_bold_links_and_shortcodes() {
+        assert!(SHORTCODES.windows(2).all(|w| w[0].0 < w[1].0));
+
+        let body = message_body(
+            "**Done** :tada: see [the doc](https://example.org/a_(b)) or (https://meet.example/abc).",
+            true,
+        );
+        assert_eq!(
+            body,
+            [
+                span(SpanKind::Bold, "Done", None),
+                span(SpanKind::Text, " 🎉 see ", None),
+                span(SpanKind::Link, "the doc", Some("https://example.org/a_(b)")),
+                span(SpanKind::Text, " or (", None),
+                span(
+                    SpanKind::Link,
+                    "https://meet.example/abc",
+                    Some("https://meet.example/abc")
+                ),
+                span(SpanKind::Text, ").", None),
+            ]
+        );
+
+        // Not markup: left as typed.
+        assert_eq!(
+            message_body("a ** b ** at 10:30:00 :nope: 2*3", true),
+            [span(
+                SpanKind::Text,
+                "a ** b ** at 10:30:00 :nope: 2*3",
+                None
+            )]
+        );
+        assert_eq!(
+            message_body("x:+1::thumbsup:", true),
+            [span(SpanKind::Text, "x👍👍", None)]
+        );
+        assert!(message_body("", true).is_empty());
+    }
+
+    #[test]
+    fn refuses_unsafe_links_and_controls() {
+        assert_eq!(
+            message_body("[click](javascript:alert(1)) <b>hi</b>", true),
+            [span(
+                SpanKind::Text,
+                "[click](javascript:alert(1)) <b>hi</b>",
+                None
+            )]
+        );
+        // A right-to-left override would show "gpj.exe" as "exe.jpg".
+        assert_eq!(
+            message_body("photo\u{202E}gpj.exe\u{0007}\r\nok\t:)", true),
+            [span(SpanKind::Text, "photogpj.exe\nok\t:)", None)]
+        );
+        assert_eq!(
+            message_body("[mail](mailto:a@b.c)", true),
+            [span(SpanKind::Link, "mail", Some("mailto:a@b.c"))]
+        );
+        // Without formatting, sanitized text only.
+        assert_eq!(
+            message_body("**hi** :wave:\u{202E}", false),
+            [span(SpanKind::Text, "**hi** :wave:", None)]
+        );
+    }
+}
@@ -0,0 +1,425 @@
+//! Decoding of the chat and reaction packets sent by Meet web clients.
+//!
+//! Besides the text stream API, Meet web sends chat messages as JSON data
+//! packets on the legacy `lk-chat-topic`, and reactions as JSON packets
+//! without a topic. Both come straight from the network: they are decoded
+//! into explicit types within size limits, and anything else is rejected,
+//! logged and counted in `RejectedPayloads` rather than half-read.
+//!
+//! Chat messages, whether read from text streams, legacy packets or the
+//! native chat API, are also checked against the room's message size
+//! limit, shared with `ChatService`.
+//!
+//! Packets on other topics (polls, custom data messages) are not looked at.
+
+use serde::Deserialize;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+
+use crate::chat::DEFAULT_MAX_MESSAGE_BYTES;
+use crate::data_channel::MAX_PAYLOAD_BYTES;
+
+/// Topic of chat messages sent with the data packet API.
+pub(crate) const LEGACY_CHAT_TOPIC: &str = "lk-chat-topic";
+
+/// Largest packet decoded, the same limit as for sending.
+pub const MAX_INCOMING_PAYLOAD_BYTES: usize = MAX_PAYLOAD_BYTES;
+
+/// Longest message id accepted.
+const MAX_ID_BYTES: usize = 128;
+
+/// Longest reaction accepted (an emoji with modifiers and joiners).
+const MAX_EMOJI_BYTES: usize = 64;
+
+/// A chat message or reaction decoded from a data packet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IncomingPayload {
+    Chat {
+        id: String,
+        text: String,
+        timestamp_ms: u64,
+    },
+    Reaction {
+        emoji: String,
+    },
+}
+
+/// Why a packet was rejected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PayloadRejection {
+    /// Larger than `MAX_INCOMING_PAYLOAD_BYTES`.
+    Oversized(usize),
+    Malformed(String),
+}
+
+/// Packets and text streams rejected since the `RoomManager` was created.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RejectedPayloads {
+    pub oversized: u64,
+    pub malformed: u64,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LegacyChatWire {
+    #[serde(default)]
+    id: String,
+    message: String,
+    #[serde(default)]
+    timestamp: Option<serde_json::Number>,
+    /// Also sent over the text stream API, handled there.
+    #[serde(default)]
+    ignore_legacy: bool,
+}
+
+#[derive(Deserialize)]
+struct EnvelopeWire {
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default)]
+    data: serde_json::Value,
+}
+
+#[derive(Deserialize)]
+struct ReactionWire {
+    emoji: String,
+}
+
+/// Decode a data packet. `Ok(None)` for packets that are valid but not
+/// for us: other topics, other message types, empty chat messages and
+/// those flagged `ignoreLegacy`.
+pub fn parse_data_packet(
+    topic: Option<&str>,
+    payload: &[u8],
+) -> Result<Option<IncomingPayload>, PayloadRejection> {
+    let legacy_chat = match topic {
+        None => false,
+        Some(LEGACY_CHAT_TOPIC) => true,
+        Some(_) => return Ok(None),
+    };
+    if payload.len() > MAX_INCOMING_PAYLOAD_BYTES {
+        return Err(PayloadRejection::Oversized(payload.len()));
+    }
+    let malformed = |e: serde_json::Error| PayloadRejection::Malformed(e.to_string());
+    if legacy_chat {
+        let wire: LegacyChatWire = serde_json::from_slice(payload).map_err(malformed)?;
+        if wire.ignore_legacy || wire.message.is_empty() {
+            return Ok(None);
+        }
+        if wire.id.len() > MAX_ID_BYTES {
+            return Err(PayloadRejection::Malformed("message id too long".into()));
+        }
+        return Ok(Some(IncomingPayload::Chat {
+            id: wire.id,
+            text: wire.message,
+            timestamp_ms: wire.timestamp.and_then(|t| t.as_u64()).unwrap_or(0),
+        }));
+    }
+
+    let envelope: EnvelopeWire = serde_json::from_slice(payload).map_err(malformed)?;
+    if envelope.kind != "reactionReceived" {
+        return Ok(None);
+    }
+    let reaction = ReactionWire::deserialize(envelope.data).map_err(malformed)?;
+    if reaction.emoji.is_empty() || reaction.emoji.len() > MAX_EMOJI_BYTES {
+        return Err(PayloadRejection::Malformed(format!(
+            "invalid reaction of {} bytes",
+            reaction.emoji.len()
+        )));
+    }
+    Ok(Some(IncomingPayload::Reaction {
+        emoji: reaction.emoji,
+    }))
+}
+
+/// `parse_data_packet` with rejections logged and counted.
+#[derive(Clone)]
+pub struct PayloadGuard {
+    oversized: Arc<AtomicU64>,
+    malformed: Arc<AtomicU64>,
+    /// Limit on the text of received chat messages.
+    max_text_bytes: Arc<AtomicUsize>,
+}
+
+impl Default for PayloadGuard {
+    fn default() -> Self {
+        Self::new(Arc::new(AtomicUsize::new(DEFAULT_MAX_MESSAGE_BYTES)))
+    }
+}
+
+impl PayloadGuard {
+    pub(crate) fn new(max_text_bytes: Arc<AtomicUsize>) -> Self {
+        Self {
+            oversized: Arc::default(),
+            malformed: Arc::default(),
+            max_text_bytes,
+        }
+    }
+
+    pub(crate) fn parse(
+        &self,
+        topic: Option<&str>,
+        payload: &[u8],
+        sender_sid: &str,
+    ) -> Option<IncomingPayload> {
+        match parse_data_packet(topic, payload) {
+            Ok(Some(IncomingPayload::Chat { text, .. }))
+                if !self.accept_text(&text, sender_sid) =>
+            {
+                None
+            }
+            Ok(parsed) => parsed,
+            Err(PayloadRejection::Oversized(len)) => {
+                tracing::warn!("dropping oversized data packet from {sender_sid}: {len} bytes");
+                self.oversized.fetch_add(1, Ordering::Relaxed);
+                None
+            }
+            Err(PayloadRejection::Malformed(reason)) => {
+                tracing::warn!("dropping malformed data packet from {sender_sid}: {reason}");
+                self.malformed.fetch_add(1, Ordering::Relaxed);
+                None
+            }
+        }
+    }
+
+    /// Whether the text of a received chat message is within the size
+    /// limit. Longer ones are logged and counted as oversized.
+    pub(crate) fn accept_text(&self, text: &str, sender_identity: &str) -> bool {
+        let max = self.max_text_bytes.load(Ordering::Relaxed);
+        if text.len() <= max {
+            return true;
+        }
+        tracing::warn!(
+            "dropping oversized chat message from {sender_identity}: {} bytes (max {max})",
+            text.len()
+        );
+        self.oversized.fetch_add(1, Ordering::Relaxed);
+        false
+    }
+
+    pub fn rejections(&self) -> RejectedPayloads {
+        RejectedPayloads {
+            oversized: self.oversized.load(Ordering::Relaxed),
+            malformed: self.malformed.load(Ordering::Relaxed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use proptest::sample::{Index, select};
+
+    fn chat(payload: &str) -> Result<Option<IncomingPayload>, PayloadRejection> {
+        parse_data_packet(Some(LEGACY_CHAT_TOPIC), payload.as_bytes())
+    }
+
+    fn untopiced(payload: &str) -> Result<Option<IncomingPayload>, PayloadRejection> {
+        parse_data_packet(None, payload.as_bytes())
+    }
+
+    #[test]
+    fn decodes_meet_web_packets() {
+        assert_eq!(
+            chat(r#"{"id":"m1","message":"hello","timestamp":1700000000000}"#),
+            Ok(Some(IncomingPayload::Chat {
+                id: "m1".into(),
+                text: "hello".into(),
+                timestamp_ms: 1_700_000_000_000,
+            }))
+        );
+        // Fractional or negative timestamps are not fatal.
+        assert!(matches!(
+            chat(r#"{"message":"hi","timestamp":-1.5}"#),
+            Ok(Some(IncomingPayload::Chat {
+                timestamp_ms: 0,
+                ..
+            }))
+        ));
+        assert_eq!(chat(r#"{"message":"hi","ignoreLegacy":true}"#), Ok(None));
+        assert_eq!(chat(r#"{"message":""}"#), Ok(None));
+
+        assert_eq!(
+            untopiced(r#"{"type":"reactionReceived","data":{"emoji":"👍"}}"#),
+            Ok(Some(IncomingPayload::Reaction {
+                emoji: "👍".into()
+            }))
+        );
+        assert_eq!(untopiced(r#"{"type":"somethingElse"}"#), Ok(None));
+        assert_eq!(
+            parse_data_packet(Some("visio.polls"), b"\xff not json"),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_and_oversized_packets() {
+        let malformed = |r: Result<Option<IncomingPayload>, PayloadRejection>| {
+            matches!(r, Err(PayloadRejection::Malformed(_)))
+        };
+        assert!(malformed(chat("not json")));
+        assert!(malformed(chat(r#"{"id":"m1"}"#)));
+        assert!(malformed(chat(r#"{"message":42}"#)));
+        assert!(malformed(chat(r#"["message"]"#)));
+        assert!(malformed(chat(&format!(
+            r#"{{"id":"{}","message":"hi"}}"#,
+            "x".repeat(MAX_ID_BYTES + 1)
+        ))));
+        assert!(malformed(untopiced(r#"{"type":"reactionReceived"}"#)));
+        assert!(malformed(untopiced(
+            r#"{"type":"reactionReceived","data":{"emoji":""}}"#
+        )));
+
+        let huge = format!(
+            r#"{{"message":"{}"}}"#,
+            "x".repeat(MAX_INCOMING_PAYLOAD_BYTES)
+        );
+        assert_eq!(chat(&huge), Err(PayloadRejection::Oversized(huge.len())));
+
+        let guard = PayloadGuard::default();
+        assert!(
+            guard
+                .parse(Some(LEGACY_CHAT_TOPIC), huge.as_bytes(), "PA_1")
+                .is_none()
+        );
+        assert!(guard.parse(None, b"{", "PA_1").is_none());
+        assert!(guard.parse(None, b"{\"type\":\"other\"}", "PA_1").is_none());
+        assert!(guard.accept_text("hello", "alice"));
+        let long = "x".repeat(DEFAULT_MAX_MESSAGE_BYTES + 1);
+        assert!(!guard.accept_text(&long, "alice"));
+        assert_eq!(
+            guard.rejections(),
+            RejectedPayloads {
+                oversized: 2,
+                malformed: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn drops_legacy_chat_over_the_message_limit() {
+        let max = Arc::new(AtomicUsize::new(16));
+        let guard = PayloadGuard::new(max.clone());
+        let packet = |text: &str| format!(r#"{{"id":"m1","message":"{text}"}}"#);
+
+        let fits = packet(&"x".repeat(16));
+        assert!(matches!(
+            guard.parse(Some(LEGACY_CHAT_TOPIC), fits.as_bytes(), "PA_1"),
+            Some(IncomingPayload::Chat { .. })
+        ));
+        // Well under the packet limit, but over the message limit.
+        let long = packet(&"x".repeat(17));
+        assert!(
+            guard
+                .parse(Some(LEGACY_CHAT_TOPIC), long.as_bytes(), "PA_1")
+                .is_none()
+        );
+        assert_eq!(guard.rejections().oversized, 1);
+
+        // The limit is read on every packet.
+        max.store(32, Ordering::Relaxed);
+        assert!(
+            guard
+                .parse(Some(LEGACY_CHAT_TOPIC), long.as_bytes(), "PA_1")
+                .is_some()
+        );
+    }
+
+    /// Whatever is accepted respects the limits; only large packets are
+    /// rejected as oversized.
+    fn check_decoded(topic: Option<&str>, bytes: &[u8]) -> Result<(), TestCaseError> {
+        match parse_data_packet(topic, bytes) {
+            Ok(Some(IncomingPayload::Chat { id, text, .. })) => {
+                prop_assert!(!text.is_empty() && id.len() <= MAX_ID_BYTES);
+            }
+            Ok(Some(IncomingPayload::Reaction { emoji })) => {
+                prop_assert!(!emoji.is_empty() && emoji.len() <= MAX_EMOJI_BYTES);
+            }
+            Ok(None) | Err(PayloadRejection::Malformed(_)) => {}
+            Err(PayloadRejection::Oversized(len)) => {
+                prop_assert!(len > MAX_INCOMING_PAYLOAD_BYTES);
+            }
+        }
+        Ok(())
+    }
+
+    fn topics() -> impl Strategy<Value = Option<&'static str>> {
+        select(vec![None, Some(LEGACY_CHAT_TOPIC)])
+    }
+
+    /// Byte edits of a packet: remove, replace or insert.
+    #[derive(Debug, Clone)]
+    enum Edit {
+        Remove(Index),
+        Replace(Index, u8),
+        Insert(Index, u8),
+    }
+
+    fn edit() -> impl Strategy<Value = Edit> {
+        let byte =
+            select(b"{}[]\":,\\ 0123456789.-eE truefalsnl\xc3\xa9\xf0\x9f\x8e\x89\xff".to_vec());
+        prop_oneof![
+            any::<Index>().prop_map(Edit::Remove),
+            (any::<Index>(), byte.clone()).prop_map(|(i, b)| Edit::Replace(i, b)),
+            (any::<Index>(), byte).prop_map(|(i, b)| Edit::Insert(i, b)),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn random_packets_are_decoded_or_rejected(
+            topic in topics(),
+            bytes in prop::collection::vec(any::<u8>(), 0..256),
+        ) {
+            check_decoded(topic, &bytes)?;
+        }
+
+        #[test]
+        fn edited_packets_are_decoded_or_rejected(
+            topic in topics(),
+            packet in select(vec![
+                r#"{"id":"m1","message":"hello","timestamp":1700000000000}"#,
+                r#"{"type":"reactionReceived","data":{"emoji":"🎉"}}"#,
+            ]),
+            edits in prop::collection::vec(edit(), 1..5),
+        ) {
+            let mut bytes = packet.as_bytes().to_vec();
+            for edit in edits {
+                match edit {
+                    Edit::Remove(i) if !bytes.is_empty() => {
+                        bytes.remove(i.index(bytes.len()));
+                    }
+                    Edit::Replace(i, b) if !bytes.is_empty() => {
+                        let i = i.index(bytes.len());
+                        bytes[i] = b;
+                    }
+                    Edit::Insert(i, b) => bytes.insert(i.index(bytes.len() + 1), b),
+                    _ => {}
+                }
+            }
+            check_decoded(topic, &bytes)?;
+        }
+
+        #[test]
+        fn chat_packets_round_trip(
+            id in "\\PC{0,32}",
+            text in "\\PC{1,200}",
+            timestamp in any::<u64>(),
+        ) {
+            let packet = serde_json::json!({
+                "id": id,
+                "message": text,
+                "timestamp": timestamp,
+            });
+            prop_assert_eq!(
+                chat(&packet.to_string()),
+                Ok(Some(IncomingPayload::Chat {
+                    id,
+                    text,
+                    timestamp_ms: timestamp,
+                }))
+            );
+        }
+    }
+}
@@ -0,0 +1,369 @@
+//! Unread chat messages.
+//!
+//! Messages received while the chat panel is closed are unread, counted
+//! by kind: normal messages, messages mentioning the local participant
+//! (`@name`) and system messages. The badge (`UnreadCountChanged`) counts
+//! the first two.
+//!
+//! Opening the panel clears the counts but not the position of the "new
+//! messages" divider: `first_unread_id` stays on the first message not
+//! yet acknowledged until the UI calls `mark_read_up_to` with the last
+//! message it showed, or the local participant sends a message. The state
+//! is kept when the connection drops and comes back, and reset on leaving
+//! or switching rooms.
+
+use std::collections::{HashSet, VecDeque};
+use std::sync::{Arc, Mutex};
+
+use serde::Serialize;
+
+use crate::events::{ChatMessage, ChatMessageKind, EventEmitter, VisioEvent, VisioEventListener};
+
+/// Ids of the last messages remembered to drop duplicates. A message is
+/// delivered again soon after the first time (reconnection, replay), so
+/// the latest ones are enough.
+const MAX_SEEN: usize = 1000;
+
+/// Unread messages of the chat, see `ChatService::unread()`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ChatUnread {
+    /// Where to draw the "new messages" divider.
+    pub first_unread_id: Option<String>,
+    pub normal: u32,
+    pub mention: u32,
+    pub system: u32,
+}
+
+impl ChatUnread {
+    /// Count shown on the chat badge: system messages are left out.
+    pub fn total(&self) -> u32 {
+        self.normal + self.mention
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum UnreadKind {
+    Normal,
+    Mention,
+    System,
+}
+
+#[derive(Debug)]
+struct Pending {
+    id: String,
+    kind: UnreadKind,
+    /// Still counted: received since the panel was last opened.
+    counted: bool,
+}
+
+#[derive(Default)]
+struct UnreadState {
+    chat_open: bool,
+    local_sid: String,
+    local_name: String,
+    /// Messages not acknowledged yet, in arrival order.
+    pending: Vec<Pending>,
+    /// Last messages seen, so one delivered twice is counted once.
+    seen: HashSet<String>,
+    /// `seen` in arrival order, to forget the oldest past `MAX_SEEN`.
+    seen_order: VecDeque<String>,
+}
+
+impl UnreadState {
+    fn unread(&self) -> ChatUnread {
+        let mut unread = ChatUnread {
+            first_unread_id: self.pending.first().map(|p| p.id.clone()),
+            ..ChatUnread::default()
+        };
+        for pending in self.pending.iter().filter(|p| p.counted) {
+            match pending.kind {
+                UnreadKind::Normal => unread.normal += 1,
+                UnreadKind::Mention => unread.mention += 1,
+                UnreadKind::System => unread.system += 1,
+            }
+        }
+        unread
+    }
+
+    /// Remember `id`; `false` if it was already seen.
+    fn see(&mut self, id: &str) -> bool {
+        if !self.seen.insert(id.to_string()) {
+            return false;
+        }
+        self.seen_order.push_back(id.to_string());
+        if self.seen_order.len() > MAX_SEEN
+            && let Some(oldest) = self.seen_order.pop_front()
+        {
+            self.seen.remove(&oldest);
+        }
+        true
+    }
+}
+
+/// Unread tracking of a room, shared by `RoomManager` and its
+/// `ChatService`s.
+#[derive(Clone)]
+pub struct UnreadTracker {
+    emitter: EventEmitter,
+    state: Arc<Mutex<UnreadState>>,
+}
+
+impl UnreadTracker {
+    pub(crate) fn new(emitter: EventEmitter) -> Self {
+        Self {
+            emitter,
+            state: Arc::new(Mutex::new(UnreadState::default())),
+        }
+    }
+
+    /// The local participant: their messages are not unread, and `@name`
+    /// mentions them.
+    pub(crate) fn set_local(&self, sid: &str, name: &str) {
+        let mut state = self.lock();
+        state.local_sid = sid.to_string();
+        state.local_name = name.to_string();
+    }
+
+    pub fn is_chat_open(&self) -> bool {
+        self.lock().chat_open
+    }
+
+    /// Mark the chat panel as open or closed. Opening it clears the
+    /// counts; the divider stays until `mark_read_up_to`.
+    pub fn set_chat_open(&self, open: bool) {
+        self.update(|state| {
+            state.chat_open = open;
+            if open {
+                for pending in &mut state.pending {
+                    pending.counted = false;
+                }
+            }
+        });
+    }
+
+    pub fn unread(&self) -> ChatUnread {
+        self.lock().unread()
+    }
+
+    /// Acknowledge the messages up to `id`, included, e.g. the last one on
+    /// screen. Returns `false` if `id` is not an unread message.
+    pub fn mark_read_up_to(&self, id: &str) -> bool {
+        let mut found = false;
+        self.update(|state| {
+            if let Some(pos) = state.pending.iter().position(|p| p.id == id) {
+                state.pending.drain(..=pos);
+                found = true;
+            }
+        });
+        found
+    }
+
+    /// Forget everything, on leaving the room.
+    pub(crate) fn clear(&self) {
+        self.update(|state| {
+            state.pending.clear();
+            state.seen.clear();
+            state.seen_order.clear();
+        });
+    }
+
+    fn on_message(&self, msg: &ChatMessage) {
+        self.update(|state| {
+            if !state.see(&msg.id) {
+                return;
+            }
+            let local = !state.local_sid.is_empty() && msg.sender_sid == state.local_sid;
+            if local && msg.kind == ChatMessageKind::Text {
+                // Answering means the conversation was read.
+                state.pending.clear();
+                return;
+            }
+            if local || state.chat_open {
+                return;
+            }
+            let kind = if msg.kind != ChatMessageKind::Text {
+                UnreadKind::System
+            } else if mentions(&msg.text, &state.local_name) {
+                UnreadKind::Mention
+            } else {
+                UnreadKind::Normal
+            };
+            state.pending.push(Pending {
+                id: msg.id.clone(),
+                kind,
+                counted: true,
+            });
+        });
+    }
+
+    /// Apply `change`, then report what changed.
+    fn update(&self, change: impl FnOnce(&mut UnreadState)) {
+        let (before, after) = {
+            let mut state = self.lock();
+            let before = state.unread();
+            change(&mut state);
+            (before, state.unread())
+        };
+        if after.total() != before.total() {
+            self.emitter
+                .emit(VisioEvent::UnreadCountChanged(after.total()));
+        }
+        if after != before {
+            self.emitter.emit(VisioEvent::ChatUnreadChanged(after));
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, UnreadState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl VisioEventListener for UnreadTracker {
+    fn on_event(&self, event: VisioEvent) {
+        if let VisioEvent::ChatMessageReceived(msg) = event {
+            self.on_message(&msg);
+        }
+    }
+}
+
+/// Whether `text` mentions `name` as `@name`, ignoring case. The name
+/// must end a word: `@ada` does not mention "Ad".
+fn mentions(text: &str, name: &str) -> bool {
+    let name = name.trim();
+    if name.is_empty() {
+        return false;
+    }
+    let text = text.to_lowercase();
+    let needle = format!("@{}", name.to_lowercase());
+    text.match_indices(&needle).any(|(start, _)| {
+        text[start + needle.len()..]
+            .chars()
+            .next()
+            .is_none_or(|c| !c.is_alphanumeric() && c != '_')
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Capture(Mutex<Vec<VisioEvent>>);
+
+    impl VisioEventListener for Capture {
+        fn on_event(&self, event: VisioEvent) {
+            self.0.lock().unwrap().push(event);
+        }
+    }
+
+    fn message(id: &str, sender_sid: &str, text: &str, kind: ChatMessageKind) -> VisioEvent {
+        VisioEvent::ChatMessageReceived(ChatMessage {
+            id: id.into(),
+            sender_sid: sender_sid.into(),
+            sender_name: String::new(),
+            text: text.into(),
+            timestamp_ms: 0,
+            kind,
+        })
+    }
+
+    fn tracker() -> (EventEmitter, UnreadTracker) {
+        let emitter = EventEmitter::new();
+        let tracker = UnreadTracker::new(emitter.clone());
+        emitter.add_listener(Arc::new(tracker.clone()));
+        tracker.set_local("PA_me", "Ada Lovelace");
+        (emitter, tracker)
+    }
+
+    #[test]
+    fn counts_by_kind_and_keeps_the_divider() {
+        let (emitter, tracker) = tracker();
+        let capture = Arc::new(Capture(Mutex::new(Vec::new())));
+        emitter.add_listener(capture.clone());
+
+        emitter.emit(message("1", "PA_1", "hello", ChatMessageKind::Text));
+        emitter.emit(message(
+            "2",
+            "PA_1",
+            "hi @ada lovelace!",
+            ChatMessageKind::Text,
+        ));
+        emitter.emit(message(
+            "3",
+            "",
+            "joined",
+            ChatMessageKind::ParticipantJoined,
+        ));
+        // Delivered again after a reconnection.
+        emitter.emit(message("1", "PA_1", "hello", ChatMessageKind::Text));
+        // Own system messages are not unread.
+        emitter.emit(message("4", "PA_me", "", ChatMessageKind::HandRaised));
+
+        let unread = tracker.unread();
+        assert_eq!(unread.first_unread_id.as_deref(), Some("1"));
+        assert_eq!((unread.normal, unread.mention, unread.system), (1, 1, 1));
+        let badges: Vec<u32> = capture
+            .0
+            .lock()
+            .unwrap()
+            .iter()
+            .filter_map(|e| match e {
+                VisioEvent::UnreadCountChanged(n) => Some(*n),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(badges, [1, 2]);
+
+        // Opening clears the counts, the divider stays until acknowledged.
+        tracker.set_chat_open(true);
+        emitter.emit(message("5", "PA_1", "while open", ChatMessageKind::Text));
+        let unread = tracker.unread();
+        assert_eq!(unread.total() + unread.system, 0);
+        assert_eq!(unread.first_unread_id.as_deref(), Some("1"));
+        assert!(tracker.mark_read_up_to("2"));
+        assert_eq!(tracker.unread().first_unread_id.as_deref(), Some("3"));
+        assert!(!tracker.mark_read_up_to("5"));
+        assert!(tracker.mark_read_up_to("3"));
+        assert_eq!(tracker.unread(), ChatUnread::default());
+    }
+
+    #[test]
+    fn sending_acknowledges_everything() {
+        let (emitter, tracker) = tracker();
+        emitter.emit(message("1", "PA_1", "hello", ChatMessageKind::Text));
+        emitter.emit(message("2", "PA_1", "anyone?", ChatMessageKind::Text));
+        assert!(tracker.mark_read_up_to("1"));
+        assert_eq!(tracker.unread().normal, 1);
+
+        emitter.emit(message("3", "PA_me", "yes", ChatMessageKind::Text));
+        assert_eq!(tracker.unread(), ChatUnread::default());
+
+        emitter.emit(message("4", "PA_1", "great", ChatMessageKind::Text));
+        tracker.clear();
+        assert_eq!(tracker.unread(), ChatUnread::default());
+        // Forgotten on leaving: a new room may reuse ids.
+        emitter.emit(message("4", "PA_1", "great", ChatMessageKind::Text));
+        assert_eq!(tracker.unread().normal, 1);
+    }
+
+    #[test]
+    fn mentions_need_a_word_boundary() {
+        assert!(mentions("hi @Ada", "ada"));
+        assert!(mentions("@ada, @adam", "Ada"));
+        assert!(mentions("@adam @ada.", "ada"));
+        assert!(!mentions("hi @adam", "ada"));
+        assert!(!mentions("@ada_l", "ada"));
+        assert!(!mentions("hi ada", "ada"));
+    }
+
+    #[test]
+    fn seen_ids_are_capped() {
+        let (emitter, tracker) = tracker();
+        for i in 0..=MAX_SEEN {
+            emitter.emit(message(&i.to_string(), "PA_1", "hi", ChatMessageKind::Text));
+        }
+        let state = tracker.lock();
+        assert_eq!(state.seen.len(), MAX_SEEN);
+        assert_eq!(state.seen_order.len(), MAX_SEEN);
+        assert!(!state.seen.contains("0"));
+    }
+}
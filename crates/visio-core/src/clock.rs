@@ -0,0 +1,171 @@
+//! Time source for timers and measurements.
+//!
+//! Code that waits or measures durations goes through an `Arc<dyn Clock>`:
+//! `SystemClock` in production, `MockClock` in tests, where time only
+//! moves when the test calls `MockClock::advance`. Timer tests then run
+//! instantly and cannot flake on a loaded machine. `MockClock` is built
+//! for the crate's own tests and with the `test-util` feature.
+
+use chrono::{DateTime, Utc};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+#[cfg(any(test, feature = "test-util"))]
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+#[cfg(any(test, feature = "test-util"))]
+use tokio::sync::oneshot;
+
+pub type SleepFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+pub trait Clock: Send + Sync {
+    /// Monotonic time, for durations.
+    fn now(&self) -> Instant;
+    /// Wall clock time, for timestamps shared with other clients.
+    fn utc_now(&self) -> DateTime<Utc>;
+    /// Complete once `duration` has elapsed on this clock.
+    fn sleep(&self, duration: Duration) -> SleepFuture;
+}
+
+/// Real time, with tokio timers.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn utc_now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+
+    fn sleep(&self, duration: Duration) -> SleepFuture {
+        Box::pin(tokio::time::sleep(duration))
+    }
+}
+
+/// The clock used outside tests.
+pub fn system_clock() -> Arc<dyn Clock> {
+    Arc::new(SystemClock)
+}
+
+#[cfg(any(test, feature = "test-util"))]
+struct MockState {
+    now: Instant,
+    utc: DateTime<Utc>,
+    /// Deadline and waker of each pending `sleep`.
+    sleepers: Vec<(Instant, oneshot::Sender<()>)>,
+}
+
+/// Clock frozen at its creation time until `advance` is called.
+#[cfg(any(test, feature = "test-util"))]
+#[derive(Clone)]
+pub struct MockClock {
+    state: Arc<Mutex<MockState>>,
+}
+
+#[cfg(any(test, feature = "test-util"))]
+impl Default for MockClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(any(test, feature = "test-util"))]
+impl MockClock {
+    pub fn new() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(MockState {
+                now: Instant::now(),
+                utc: Utc::now(),
+                sleepers: Vec::new(),
+            })),
+        }
+    }
+
+    /// Move time forward and complete the sleeps that are due.
+    pub fn advance(&self, by: Duration) {
+        let due = {
+            let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+            state.now += by;
+            if let Ok(by) = chrono::TimeDelta::from_std(by) {
+                state.utc += by;
+            }
+            let now = state.now;
+            let (due, pending) = std::mem::take(&mut state.sleepers)
+                .into_iter()
+                .partition(|(deadline, _)| *deadline <= now);
+            state.sleepers = pending;
+            due
+        };
+        for (_, waker) in due {
+            let _ = waker.send(());
+        }
+    }
+
+    /// Sleeps not completed yet, to know when a spawned task is waiting.
+    pub fn pending_sleeps(&self) -> usize {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        // Forget sleeps whose future was dropped (e.g. aborted tasks).
+        state.sleepers.retain(|(_, waker)| !waker.is_closed());
+        state.sleepers.len()
+    }
+}
+
+#[cfg(any(test, feature = "test-util"))]
+impl Clock for MockClock {
+    fn now(&self) -> Instant {
+        self.state.lock().unwrap_or_else(|e| e.into_inner()).now
+    }
+
+    fn utc_now(&self) -> DateTime<Utc> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner()).utc
+    }
+
+    fn sleep(&self, duration: Duration) -> SleepFuture {
+        if duration.is_zero() {
+            return Box::pin(std::future::ready(()));
+        }
+        let (tx, rx) = oneshot::channel();
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let deadline = state.now + duration;
+        state.sleepers.push((deadline, tx));
+        Box::pin(async move {
+            let _ = rx.await;
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn mock_sleeps_complete_only_when_due() {
+        let clock = MockClock::new();
+        let (t0, utc0) = (clock.now(), clock.utc_now());
+        let short = tokio::spawn(clock.sleep(Duration::from_secs(1)));
+        let long = tokio::spawn(clock.sleep(Duration::from_secs(5)));
+        assert_eq!(clock.pending_sleeps(), 2);
+
+        clock.advance(Duration::from_millis(1500));
+        short.await.unwrap();
+        assert!(!long.is_finished());
+        assert_eq!(clock.pending_sleeps(), 1);
+
+        clock.advance(Duration::from_secs(4));
+        long.await.unwrap();
+        assert_eq!(clock.now() - t0, Duration::from_millis(5500));
+        assert_eq!((clock.utc_now() - utc0).num_milliseconds(), 5500);
+
+        clock.sleep(Duration::ZERO).await;
+    }
+
+    #[test]
+    fn dropped_sleeps_are_not_pending() {
+        let clock = MockClock::new();
+        drop(clock.sleep(Duration::from_secs(1)));
+        assert_eq!(clock.pending_sleeps(), 0);
+    }
+}
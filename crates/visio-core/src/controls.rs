@@ -1,74 +1,748 @@
-use livekit::options::TrackPublishOptions;
+use livekit::options::{
+    AudioEncoding, TrackPublishOptions, VideoCodec as LkVideoCodec, VideoEncoding,
+};
 use livekit::prelude::*;
 use livekit::track::TrackSource as LkTrackSource;
 use livekit::webrtc::audio_source::native::NativeAudioSource;
 use livekit::webrtc::prelude::*;
 use livekit::webrtc::video_source::native::NativeVideoSource;
+use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
+use crate::capture_sources::PublishedSources;
+use crate::device_permissions::{DevicePermission, DevicePermissions};
 use crate::errors::VisioError;
-use crate::events::{EventEmitter, VisioEvent};
+use crate::events::{ConnectionQuality, EventEmitter, TrackSource, VisioEvent};
+use crate::permissions::LocalPermissions;
+use crate::test_media::{self, TestMediaOptions, TestMediaTask};
+use crate::video_privacy::video_privacy;
 
 /// Audio source options matching v1 settings.
 const AUDIO_SAMPLE_RATE: u32 = 48_000;
 const AUDIO_CHANNELS: u32 = 1;
+const STEREO_AUDIO_CHANNELS: u32 = 2;
 const AUDIO_QUEUE_SIZE_MS: u32 = 100;
 
+/// Opus accepts 6 to 510 kbps.
+const MIN_AUDIO_BITRATE_BPS: u32 = 6_000;
+const MAX_AUDIO_BITRATE_BPS: u32 = 510_000;
+
+/// Bitrate of `AudioPublishOptions::low_bandwidth`: Opus switches to
+/// narrowband, still intelligible for speech.
+const LOW_BANDWIDTH_AUDIO_BITRATE_BPS: u32 = 16_000;
+
+/// Bitrate of `AudioPublishOptions::music`: transparent stereo Opus.
+const MUSIC_AUDIO_BITRATE_BPS: u32 = 128_000;
+
 /// Default video resolution.
 const VIDEO_WIDTH: u32 = 1280;
 const VIDEO_HEIGHT: u32 = 720;
 
+/// Resolution of the screen share source.
+const SCREEN_WIDTH: u32 = 1920;
+const SCREEN_HEIGHT: u32 = 1080;
+
+/// Encoding for `VideoContentHint::Detail`: sharp frames at a low rate.
+const DETAIL_ENCODING: VideoEncoding = VideoEncoding {
+    max_bitrate: 2_500_000,
+    max_framerate: 15.0,
+};
+
+/// What a published video track carries, like the WebRTC `contentHint`
+/// track attribute. Decides what the encoder sacrifices under congestion.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum VideoContentHint {
+    /// Camera video: keep the frame rate, lower the resolution.
+    #[default]
+    Motion,
+    /// Slides, documents, text: keep the resolution, lower the frame rate.
+    /// Published without simulcast, as low-resolution layers are unreadable.
+    Detail,
+}
+
+/// Codec the camera track is published with. Subscribers that cannot
+/// decode it get a VP8 backup layer from the SFU.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum VideoCodec {
+    /// Software encoded everywhere, the safe default.
+    #[default]
+    Vp8,
+    Vp9,
+    /// Hardware encoded on iOS and most Android devices: lower CPU and
+    /// battery use than VP8 at the same quality.
+    H264,
+    /// Best quality per bit, but only hardware encoded on recent devices;
+    /// elsewhere the software encoder is expensive.
+    Av1,
+}
+
+impl VideoCodec {
+    fn to_livekit(self) -> LkVideoCodec {
+        match self {
+            Self::Vp8 => LkVideoCodec::VP8,
+            Self::Vp9 => LkVideoCodec::VP9,
+            Self::H264 => LkVideoCodec::H264,
+            Self::Av1 => LkVideoCodec::AV1,
+        }
+    }
+}
+
+/// What the microphone is used for, selecting its processing and encoding.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AudioPreset {
+    /// Speech: echo cancellation, noise suppression and auto-gain, mono.
+    #[default]
+    Voice,
+    /// Music or instruments: no voice processing, which would pump and
+    /// cut notes, stereo at a high bitrate. Use with headphones, as
+    /// nothing cancels the echo.
+    Music,
+}
+
+impl AudioPreset {
+    /// Microphone encoding of this preset.
+    pub fn publish_options(self) -> AudioPublishOptions {
+        match self {
+            Self::Voice => AudioPublishOptions::default(),
+            Self::Music => AudioPublishOptions::music(),
+        }
+    }
+}
+
+/// How the microphone track is encoded (Opus). The defaults are LiveKit's:
+/// speech bitrate, with DTX and RED.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AudioPublishOptions {
+    /// Opus target bitrate in bits per second, clamped to 6-510 kbps.
+    /// `None` lets LiveKit pick its speech preset.
+    pub max_bitrate_bps: Option<u32>,
+    /// Discontinuous transmission: almost nothing is sent during silence.
+    pub dtx: bool,
+    /// Redundant encoding: each packet also carries the previous one,
+    /// which hides losses but about doubles the bitrate.
+    pub red: bool,
+    /// Two channels; the captured audio is mixed down to mono otherwise.
+    pub stereo: bool,
+    /// Echo cancellation, noise suppression and auto-gain on the capture.
+    pub voice_processing: bool,
+}
+
+impl Default for AudioPublishOptions {
+    fn default() -> Self {
+        Self {
+            max_bitrate_bps: None,
+            dtx: true,
+            red: true,
+            stereo: false,
+            voice_processing: true,
+        }
+    }
+}
+
+impl AudioPublishOptions {
+    /// Voice over 2G/3G links: 16 kbps narrowband Opus, DTX, no RED.
+    pub fn low_bandwidth() -> Self {
+        Self {
+            max_bitrate_bps: Some(LOW_BANDWIDTH_AUDIO_BITRATE_BPS),
+            dtx: true,
+            red: false,
+            ..Self::default()
+        }
+    }
+
+    /// Music or instruments: 128 kbps stereo without voice processing.
+    /// DTX would cut quiet passages, and RED is dropped to keep the
+    /// bitrate reasonable.
+    pub fn music() -> Self {
+        Self {
+            max_bitrate_bps: Some(MUSIC_AUDIO_BITRATE_BPS),
+            dtx: false,
+            red: false,
+            stereo: true,
+            voice_processing: false,
+        }
+    }
+
+    /// Preset these options belong to: voice unless voice processing is
+    /// off.
+    pub fn preset(&self) -> AudioPreset {
+        if self.voice_processing {
+            AudioPreset::Voice
+        } else {
+            AudioPreset::Music
+        }
+    }
+
+    /// A microphone source with the processing and channels of these
+    /// options. Changing either needs a new source.
+    fn new_source(&self) -> NativeAudioSource {
+        NativeAudioSource::new(
+            AudioSourceOptions {
+                echo_cancellation: self.voice_processing,
+                noise_suppression: self.voice_processing,
+                auto_gain_control: self.voice_processing,
+            },
+            AUDIO_SAMPLE_RATE,
+            if self.stereo {
+                STEREO_AUDIO_CHANNELS
+            } else {
+                AUDIO_CHANNELS
+            },
+            AUDIO_QUEUE_SIZE_MS,
+        )
+    }
+
+    fn publish_options(&self) -> TrackPublishOptions {
+        TrackPublishOptions {
+            source: LkTrackSource::Microphone,
+            audio_encoding: self.max_bitrate_bps.map(|bps| AudioEncoding {
+                max_bitrate: u64::from(bps.clamp(MIN_AUDIO_BITRATE_BPS, MAX_AUDIO_BITRATE_BPS)),
+            }),
+            dtx: self.dtx,
+            red: self.red,
+            ..Default::default()
+        }
+    }
+}
+
+/// How a track from `source` is published, so a track republished in
+/// another room (`RoomManager::switch_room`) keeps the encoding
+/// `MeetingControls` gave it: `audio` for the microphone, `codec` and
+/// `hint` for the camera. Screen shares always use the detail encoding and
+/// the music options.
+pub(crate) fn track_publish_options(
+    source: LkTrackSource,
+    audio: AudioPublishOptions,
+    codec: VideoCodec,
+    hint: VideoContentHint,
+) -> TrackPublishOptions {
+    match source {
+        LkTrackSource::Microphone => audio.publish_options(),
+        LkTrackSource::Camera => {
+            let detail = hint == VideoContentHint::Detail;
+            TrackPublishOptions {
+                source,
+                video_codec: codec.to_livekit(),
+                video_encoding: detail.then_some(DETAIL_ENCODING),
+                simulcast: !detail,
+                ..Default::default()
+            }
+        }
+        LkTrackSource::Screenshare => TrackPublishOptions {
+            source,
+            video_encoding: Some(DETAIL_ENCODING),
+            simulcast: false,
+            ..Default::default()
+        },
+        LkTrackSource::ScreenshareAudio => TrackPublishOptions {
+            source,
+            ..AudioPublishOptions::music().publish_options()
+        },
+        _ => TrackPublishOptions {
+            source,
+            ..Default::default()
+        },
+    }
+}
+
+/// How a screen share is published.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ScreenShareOptions {
+    /// Also publish the sound played by the computer, as a second audio
+    /// track flagged `ScreenShareAudio`.
+    pub system_audio: bool,
+}
+
+/// Sources of a screen share, fed by the platform screen and loopback
+/// audio capture.
+#[derive(Clone)]
+pub struct ScreenShareSources {
+    pub video: NativeVideoSource,
+    /// Present when `ScreenShareOptions::system_audio` was set.
+    pub audio: Option<NativeAudioSource>,
+}
+
+/// What platform camera capture should produce. Capture picks the closest
+/// supported format that does not exceed the target, instead of always
+/// capturing at the published 720p.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CaptureConstraints {
+    pub width: u32,
+    pub height: u32,
+    pub max_fps: u32,
+    /// Let the camera lower its frame rate for longer exposures in dim
+    /// light (set by the user).
+    pub low_light_boost: bool,
+}
+
+impl Default for CaptureConstraints {
+    fn default() -> Self {
+        Self {
+            width: VIDEO_WIDTH,
+            height: VIDEO_HEIGHT,
+            max_fps: 30,
+            low_light_boost: false,
+        }
+    }
+}
+
+impl CaptureConstraints {
+    /// Target for the local connection quality reported by the SFU: layers
+    /// it stops forwarding on a poor uplink are not worth capturing.
+    pub fn for_quality(quality: &ConnectionQuality, low_light_boost: bool) -> Self {
+        let (width, height, max_fps) = match quality {
+            ConnectionQuality::Excellent | ConnectionQuality::Good => {
+                (VIDEO_WIDTH, VIDEO_HEIGHT, 30)
+            }
+            ConnectionQuality::Poor => (640, 360, 15),
+            ConnectionQuality::Lost => (320, 180, 15),
+        };
+        Self {
+            width,
+            height,
+            max_fps,
+            low_light_boost,
+        }
+    }
+}
+
+/// Replace the shared constraints and emit `CaptureConstraintsChanged` if
+/// they differ.
+pub(crate) fn update_capture_constraints(
+    shared: &std::sync::Mutex<CaptureConstraints>,
+    emitter: &EventEmitter,
+    update: impl FnOnce(CaptureConstraints) -> CaptureConstraints,
+) {
+    let changed = {
+        let mut current = shared.lock().unwrap_or_else(|e| e.into_inner());
+        let next = update(*current);
+        (next != *current).then(|| {
+            *current = next;
+            next
+        })
+    };
+    if let Some(constraints) = changed {
+        tracing::info!("capture constraints changed: {constraints:?}");
+        emitter.emit(VisioEvent::CaptureConstraintsChanged(constraints));
+    }
+}
+
+/// Publish a microphone track reading from `source`.
+async fn publish_microphone(
+    local: &LocalParticipant,
+    source: NativeAudioSource,
+    options: TrackPublishOptions,
+) -> RoomResult<LocalTrackPublication> {
+    let track = LocalAudioTrack::create_audio_track("microphone", RtcAudioSource::Native(source));
+    local.publish_track(LocalTrack::Audio(track), options).await
+}
+
+/// Microphone/camera state requested while disconnected.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PendingMedia {
+    pub microphone: Option<bool>,
+    pub camera: Option<bool>,
+}
+
+impl PendingMedia {
+    pub fn is_empty(&self) -> bool {
+        self.microphone.is_none() && self.camera.is_none()
+    }
+}
+
+/// Queue of `PendingMedia`, shared by the room and its controls. Disabled
+/// by default: toggling media while disconnected fails.
+#[derive(Debug, Clone, Default)]
+pub struct PendingMediaQueue {
+    state: Arc<std::sync::Mutex<QueueState>>,
+}
+
+#[derive(Debug, Default)]
+struct QueueState {
+    enabled: bool,
+    pending: PendingMedia,
+}
+
+impl PendingMediaQueue {
+    fn lock(&self) -> std::sync::MutexGuard<'_, QueueState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Record a request with `update`, or `None` if queueing is disabled.
+    fn push(&self, update: impl FnOnce(&mut PendingMedia)) -> Option<()> {
+        let mut state = self.lock();
+        let QueueState { enabled, pending } = &mut *state;
+        enabled.then(|| update(pending))
+    }
+
+    /// Add `media` to the queue whether queueing is enabled or not.
+    /// Requests already queued win.
+    pub(crate) fn request(&self, media: PendingMedia) {
+        let pending = &mut self.lock().pending;
+        pending.microphone = pending.microphone.or(media.microphone);
+        pending.camera = pending.camera.or(media.camera);
+    }
+
+    fn take(&self) -> PendingMedia {
+        std::mem::take(&mut self.lock().pending)
+    }
+}
+
 /// Controls for local media (microphone, camera).
 ///
 /// Manages local track creation, publishing, and mute/unmute.
-/// Native UI shells feed captured audio/video frames into the
-/// sources exposed by this struct.
+/// Native UI shells feed captured audio/video frames into the sources
+/// registered in `capture_sources()` when tracks are published.
 pub struct MeetingControls {
     room: Arc<Mutex<Option<Arc<Room>>>>,
     emitter: EventEmitter,
     mic_enabled: Arc<Mutex<bool>>,
     camera_enabled: Arc<Mutex<bool>>,
-    audio_source: Arc<Mutex<Option<NativeAudioSource>>>,
-    video_source: Arc<Mutex<Option<NativeVideoSource>>>,
+    /// Sources of the published tracks, shared with the room, which
+    /// releases them on disconnect.
+    sources: PublishedSources,
+    /// Shared with the room, which republishes the camera on a switch.
+    video_content_hint: Arc<Mutex<VideoContentHint>>,
+    /// Shared with the room event loop, which follows connection quality.
+    capture_constraints: Arc<std::sync::Mutex<CaptureConstraints>>,
+    /// Shared with the room, whose own controls publish on connect.
+    audio_options: Arc<std::sync::Mutex<AudioPublishOptions>>,
+    /// Preferred codec of the camera track, shared with the room.
+    video_codec: Arc<std::sync::Mutex<VideoCodec>>,
+    /// Reported by the shell; denied devices are not published.
+    device_permissions: DevicePermissions,
+    /// Token grants of the current room; sources they exclude are not
+    /// published. `None` while disconnected.
+    local_permissions: Arc<Mutex<Option<LocalPermissions>>>,
+    /// Mic/camera toggles received while disconnected, applied on connect.
+    pending: PendingMediaQueue,
+    /// Synthetic generators used instead of device capture (tests, CLI).
+    test_media: Arc<Mutex<TestMediaOptions>>,
+    audio_generator: Arc<Mutex<Option<TestMediaTask>>>,
+    video_generator: Arc<Mutex<Option<TestMediaTask>>>,
 }
 
 impl MeetingControls {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         room: Arc<Mutex<Option<Arc<Room>>>>,
         emitter: EventEmitter,
+        mic_enabled: Arc<Mutex<bool>>,
         camera_enabled: Arc<Mutex<bool>>,
+        capture_constraints: Arc<std::sync::Mutex<CaptureConstraints>>,
+        audio_options: Arc<std::sync::Mutex<AudioPublishOptions>>,
+        video_codec: Arc<std::sync::Mutex<VideoCodec>>,
+        video_content_hint: Arc<Mutex<VideoContentHint>>,
+        device_permissions: DevicePermissions,
+        local_permissions: Arc<Mutex<Option<LocalPermissions>>>,
+        pending: PendingMediaQueue,
+        sources: PublishedSources,
     ) -> Self {
         Self {
             room,
             emitter,
-            mic_enabled: Arc::new(Mutex::new(false)),
+            mic_enabled,
             camera_enabled,
-            audio_source: Arc::new(Mutex::new(None)),
-            video_source: Arc::new(Mutex::new(None)),
+            sources,
+            video_content_hint,
+            capture_constraints,
+            audio_options,
+            video_codec,
+            device_permissions,
+            local_permissions,
+            pending,
+            test_media: Arc::new(Mutex::new(TestMediaOptions::default())),
+            audio_generator: Arc::new(Mutex::new(None)),
+            video_generator: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Record `set_microphone_enabled` / `set_camera_enabled` calls made
+    /// while disconnected instead of failing, and apply the last requested
+    /// state right after the room connects, like the mic/camera-on-join
+    /// settings. Disabling drops the queued requests.
+    pub fn set_queue_until_connected(&self, enabled: bool) {
+        let mut state = self.pending.lock();
+        state.enabled = enabled;
+        if !enabled {
+            state.pending = PendingMedia::default();
+        }
+    }
+
+    pub fn queue_until_connected(&self) -> bool {
+        self.pending.lock().enabled
+    }
+
+    /// Requests waiting for the room to connect.
+    pub fn pending_media(&self) -> PendingMedia {
+        self.pending.lock().pending
+    }
+
+    /// Apply the queued requests. Called by the room once connected; a
+    /// failure (e.g. `PermissionDenied`) does not prevent the other device
+    /// from being applied, and the first one is returned.
+    pub async fn apply_pending(&self) -> Result<(), VisioError> {
+        let pending = self.pending.take();
+        if pending.is_empty() {
+            return Ok(());
+        }
+        tracing::info!("applying media requested before connect: {pending:?}");
+        let microphone = match pending.microphone {
+            Some(enabled) => self.set_microphone_enabled(enabled).await,
+            None => Ok(()),
+        };
+        let camera = match pending.camera {
+            Some(enabled) => self.set_camera_enabled(enabled).await,
+            None => Ok(()),
+        };
+        microphone.and(camera)
+    }
+
+    /// Drive subsequently published tracks from synthetic sources.
+    ///
+    /// Takes effect on the next `publish_microphone` / `publish_camera`;
+    /// disabling a source here also stops its running generator.
+    pub async fn set_test_media(&self, options: TestMediaOptions) {
+        if options.audio.is_none() {
+            self.audio_generator.lock().await.take();
+        }
+        if options.video.is_none() {
+            self.video_generator.lock().await.take();
+        }
+        *self.test_media.lock().await = options;
+    }
+
+    /// Current synthetic media options.
+    pub async fn test_media(&self) -> TestMediaOptions {
+        self.test_media.lock().await.clone()
+    }
+
+    /// Set the content hint of the published video track.
+    ///
+    /// Takes effect on the next `publish_camera`.
+    pub async fn set_video_content_hint(&self, hint: VideoContentHint) {
+        *self.video_content_hint.lock().await = hint;
+    }
+
+    pub async fn video_content_hint(&self) -> VideoContentHint {
+        *self.video_content_hint.lock().await
+    }
+
+    /// Set the codec of the camera track, e.g. H264 for hardware encoding
+    /// on iOS. Takes effect on the next `publish_camera`.
+    pub fn set_video_codec(&self, codec: VideoCodec) {
+        *self.video_codec.lock().unwrap_or_else(|e| e.into_inner()) = codec;
+    }
+
+    pub fn video_codec(&self) -> VideoCodec {
+        *self.video_codec.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Current target for platform camera capture.
+    pub fn capture_constraints(&self) -> CaptureConstraints {
+        *self
+            .capture_constraints
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Allow the camera to trade frame rate for exposure in dim light.
+    /// Emits `CaptureConstraintsChanged` when the value changes.
+    pub fn set_low_light_boost(&self, enabled: bool) {
+        update_capture_constraints(&self.capture_constraints, &self.emitter, |c| {
+            CaptureConstraints {
+                low_light_boost: enabled,
+                ..c
+            }
+        });
+    }
+
+    /// Current encoding of the microphone track.
+    pub fn audio_publish_options(&self) -> AudioPublishOptions {
+        *self.audio_options.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Change how the microphone is encoded. A published microphone track
+    /// is republished, which remote participants hear as a short gap. A
+    /// change of channels or voice processing also replaces its source,
+    /// announced with `SourceReady`.
+    ///
+    /// On error the previous options are kept, and the previous track is
+    /// republished if the new one could not be.
+    pub async fn set_audio_publish_options(
+        &self,
+        options: AudioPublishOptions,
+    ) -> Result<(), VisioError> {
+        let previous = std::mem::replace(
+            &mut *self.audio_options.lock().unwrap_or_else(|e| e.into_inner()),
+            options,
+        );
+        if previous == options {
+            return Ok(());
+        }
+        tracing::info!("audio publish options: {options:?}");
+        let new_source = (previous.stereo, previous.voice_processing)
+            != (options.stereo, options.voice_processing);
+        let result = self.republish_microphone(previous, new_source).await;
+        if result.is_err() {
+            *self.audio_options.lock().unwrap_or_else(|e| e.into_inner()) = previous;
+        }
+        result
+    }
+
+    /// Current preset of the microphone.
+    pub fn audio_preset(&self) -> AudioPreset {
+        self.audio_publish_options().preset()
+    }
+
+    /// Switch the microphone between speech and music (see `AudioPreset`),
+    /// replacing any custom bitrate. See `set_audio_publish_options`.
+    pub async fn set_audio_preset(&self, preset: AudioPreset) -> Result<(), VisioError> {
+        self.set_audio_publish_options(preset.publish_options())
+            .await
+    }
+
+    /// Change the Opus bitrate of the microphone, in bits per second, e.g.
+    /// 16000 to stay in a voice call on a 2G/3G link. See
+    /// `set_audio_publish_options`.
+    pub async fn set_audio_bitrate(&self, bps: u32) -> Result<(), VisioError> {
+        let options = AudioPublishOptions {
+            max_bitrate_bps: Some(bps.clamp(MIN_AUDIO_BITRATE_BPS, MAX_AUDIO_BITRATE_BPS)),
+            ..self.audio_publish_options()
+        };
+        self.set_audio_publish_options(options).await
+    }
+
+    /// Replace the published microphone track with one using the current
+    /// `AudioPublishOptions`: WebRTC cannot change them on a live track.
+    /// Unless `new_source`, the source is kept, so platform capture goes
+    /// on uninterrupted. If the new track cannot be published, the
+    /// previous source is published again with the `previous` options.
+    async fn republish_microphone(
+        &self,
+        previous: AudioPublishOptions,
+        new_source: bool,
+    ) -> Result<(), VisioError> {
+        let Some(previous_source) = self.sources.audio() else {
+            return Ok(());
+        };
+        let room = self.room.lock().await;
+        let Some(room) = room.as_ref() else {
+            return Ok(());
+        };
+        let local = room.local_participant();
+        let Some(publication) = local
+            .track_publications()
+            .into_values()
+            .find(|p| p.source() == LkTrackSource::Microphone)
+        else {
+            return Ok(());
+        };
+        let muted = publication.is_muted();
+        local
+            .unpublish_track(&publication.sid())
+            .await
+            .map_err(|e| VisioError::Room(format!("unpublish audio: {e}")))?;
+
+        let options = self.audio_publish_options();
+        let source = if new_source {
+            options.new_source()
+        } else {
+            previous_source.clone()
+        };
+        let published = publish_microphone(local, source.clone(), options.publish_options()).await;
+        let publication = match published {
+            Ok(publication) => publication,
+            Err(e) => {
+                // Do not leave the call without a microphone.
+                let restored =
+                    publish_microphone(local, previous_source, previous.publish_options()).await;
+                match restored {
+                    Ok(restored) if muted => restored.mute(),
+                    Ok(_) => {}
+                    Err(restore_error) => {
+                        tracing::warn!("cannot restore the microphone track: {restore_error}")
+                    }
+                }
+                return Err(VisioError::Room(format!("publish audio: {e}")));
+            }
+        };
+        if muted {
+            publication.mute();
+        }
+        if new_source {
+            let handle = self.sources.publish_audio(source.clone());
+            if let Some(config) = self.test_media.lock().await.audio.clone() {
+                *self.audio_generator.lock().await =
+                    Some(test_media::spawn_audio(source.clone(), config));
+            }
+            self.emitter.emit(VisioEvent::SourceReady {
+                source: TrackSource::Microphone,
+                handle,
+            });
+        }
+        tracing::info!("microphone track republished");
+        Ok(())
+    }
+
+    /// Whether the token grants allow publishing from `source`, so UIs can
+    /// disable the matching button. Grants are only known once connected:
+    /// this is `true` before. `PermissionsChanged` signals updates.
+    pub async fn can_publish(&self, source: TrackSource) -> bool {
+        self.local_permissions
+            .lock()
+            .await
+            .as_ref()
+            .is_none_or(|perms| perms.can_publish_source(source))
+    }
+
+    /// `PublishNotAllowed` if the token grants exclude the source behind
+    /// `permission`, then `PermissionDenied` if the user denied access to
+    /// the device and no synthetic source replaces it.
+    async fn check_capture_permission(
+        &self,
+        permission: DevicePermission,
+    ) -> Result<(), VisioError> {
+        let source = match permission {
+            DevicePermission::Microphone => Some(TrackSource::Microphone),
+            DevicePermission::Camera => Some(TrackSource::Camera),
+            DevicePermission::ScreenCapture => Some(TrackSource::ScreenShare),
+            DevicePermission::Notifications => None,
+        };
+        if let Some(source) = source
+            && !self.can_publish(source.clone()).await
+        {
+            return Err(VisioError::PublishNotAllowed(source));
         }
+        let test_media = self.test_media.lock().await;
+        let synthetic = match permission {
+            DevicePermission::Microphone => test_media.audio.is_some(),
+            DevicePermission::Camera => test_media.video.is_some(),
+            DevicePermission::ScreenCapture | DevicePermission::Notifications => false,
+        };
+        if synthetic {
+            return Ok(());
+        }
+        self.device_permissions.check(permission)
     }
 
     /// Publish a microphone track to the room.
     ///
     /// Creates a NativeAudioSource and publishes an audio track.
     /// Returns the audio source so native code can feed PCM frames into it.
+    /// With synthetic audio enabled (`set_test_media`), a tone generator feeds it instead.
+    /// Fails with `PermissionDenied` if the microphone permission was denied.
     pub async fn publish_microphone(&self) -> Result<NativeAudioSource, VisioError> {
+        self.check_capture_permission(DevicePermission::Microphone)
+            .await?;
         let room = self.room.lock().await;
         let room = room
             .as_ref()
             .ok_or_else(|| VisioError::Room("not connected".into()))?;
 
-        let source = NativeAudioSource::new(
-            AudioSourceOptions {
-                echo_cancellation: true,
-                noise_suppression: true,
-                auto_gain_control: true,
-            },
-            AUDIO_SAMPLE_RATE,
-            AUDIO_CHANNELS,
-            AUDIO_QUEUE_SIZE_MS,
-        );
+        let source = self.audio_publish_options().new_source();
 
         let track = LocalAudioTrack::create_audio_track(
             "microphone",
@@ -78,18 +752,23 @@ impl MeetingControls {
         room.local_participant()
             .publish_track(
                 LocalTrack::Audio(track),
-                TrackPublishOptions {
-                    source: LkTrackSource::Microphone,
-                    ..Default::default()
-                },
+                self.audio_publish_options().publish_options(),
             )
             .await
             .map_err(|e| VisioError::Room(format!("publish audio: {e}")))?;
 
         *self.mic_enabled.lock().await = true;
-        *self.audio_source.lock().await = Some(source.clone());
+        let handle = self.sources.publish_audio(source.clone());
+        if let Some(config) = self.test_media.lock().await.audio.clone() {
+            *self.audio_generator.lock().await =
+                Some(test_media::spawn_audio(source.clone(), config));
+        }
 
         tracing::info!("microphone track published");
+        self.emitter.emit(VisioEvent::SourceReady {
+            source: crate::events::TrackSource::Microphone,
+            handle,
+        });
         self.emitter.emit(VisioEvent::TrackUnmuted {
             participant_sid: String::new(),
             source: crate::events::TrackSource::Microphone,
@@ -102,18 +781,25 @@ impl MeetingControls {
     ///
     /// Creates a NativeVideoSource and publishes a video track.
     /// Returns the video source so native code can feed captured frames into it.
+    /// With synthetic video enabled (`set_test_media`), a pattern generator feeds it instead.
+    /// Fails with `PermissionDenied` if the camera permission was denied.
     pub async fn publish_camera(&self) -> Result<NativeVideoSource, VisioError> {
+        self.check_capture_permission(DevicePermission::Camera)
+            .await?;
         let room = self.room.lock().await;
         let room = room
             .as_ref()
             .ok_or_else(|| VisioError::Room("not connected".into()))?;
 
+        let hint = *self.video_content_hint.lock().await;
+        let detail = hint == VideoContentHint::Detail;
+        // A screencast source makes WebRTC keep resolution over frame rate.
         let source = NativeVideoSource::new(
             VideoResolution {
                 width: VIDEO_WIDTH,
                 height: VIDEO_HEIGHT,
             },
-            false, // not a screencast
+            detail,
         );
 
         let track =
@@ -122,31 +808,171 @@ impl MeetingControls {
         room.local_participant()
             .publish_track(
                 LocalTrack::Video(track),
-                TrackPublishOptions {
-                    source: LkTrackSource::Camera,
-                    ..Default::default()
-                },
+                track_publish_options(
+                    LkTrackSource::Camera,
+                    self.audio_publish_options(),
+                    self.video_codec(),
+                    hint,
+                ),
             )
             .await
             .map_err(|e| VisioError::Room(format!("publish video: {e}")))?;
 
         *self.camera_enabled.lock().await = true;
-        *self.video_source.lock().await = Some(source.clone());
+        let handle = self.sources.publish_video(source.clone());
+        if let Some(config) = self.test_media.lock().await.video.clone() {
+            *self.video_generator.lock().await =
+                Some(test_media::spawn_video(source.clone(), config));
+        }
 
         tracing::info!("camera track published");
+        self.emitter.emit(VisioEvent::SourceReady {
+            source: crate::events::TrackSource::Camera,
+            handle,
+        });
+        self.emitter.emit(VisioEvent::TrackUnmuted {
+            participant_sid: String::new(),
+            source: crate::events::TrackSource::Camera,
+        });
+
         Ok(source)
     }
 
+    /// Publish a screen share: a screencast video track and, with
+    /// `system_audio`, the computer's sound as a second audio track. The
+    /// audio is published like `AudioPreset::Music`: stereo, high bitrate,
+    /// without voice processing. Returns the sources the platform capture
+    /// feeds.
+    ///
+    /// Fails with `PublishNotAllowed` or `PermissionDenied` for the screen
+    /// capture permission, or if already sharing.
+    pub async fn start_screen_share(
+        &self,
+        options: ScreenShareOptions,
+    ) -> Result<ScreenShareSources, VisioError> {
+        self.check_capture_permission(DevicePermission::ScreenCapture)
+            .await?;
+        if self.is_screen_sharing().await {
+            return Err(VisioError::Room("already sharing the screen".into()));
+        }
+        let room = self.room.lock().await;
+        let room = room
+            .as_ref()
+            .ok_or_else(|| VisioError::Room("not connected".into()))?;
+        let local = room.local_participant();
+
+        let video = NativeVideoSource::new(
+            VideoResolution {
+                width: SCREEN_WIDTH,
+                height: SCREEN_HEIGHT,
+            },
+            true,
+        );
+        let track =
+            LocalVideoTrack::create_video_track("screen", RtcVideoSource::Native(video.clone()));
+        local
+            .publish_track(
+                LocalTrack::Video(track),
+                track_publish_options(
+                    LkTrackSource::Screenshare,
+                    self.audio_publish_options(),
+                    self.video_codec(),
+                    VideoContentHint::Detail,
+                ),
+            )
+            .await
+            .map_err(|e| VisioError::Room(format!("publish screen share: {e}")))?;
+
+        let mut audio = None;
+        if options.system_audio {
+            let music = AudioPublishOptions::music();
+            let source = music.new_source();
+            let track = LocalAudioTrack::create_audio_track(
+                "screen_audio",
+                RtcAudioSource::Native(source.clone()),
+            );
+            let published = local
+                .publish_track(
+                    LocalTrack::Audio(track),
+                    track_publish_options(
+                        LkTrackSource::ScreenshareAudio,
+                        music,
+                        self.video_codec(),
+                        VideoContentHint::Detail,
+                    ),
+                )
+                .await;
+            if let Err(e) = published {
+                // Not half a share: take the video down too.
+                let _ = self.unpublish_screen_share(&local).await;
+                return Err(VisioError::Room(format!("publish screen audio: {e}")));
+            }
+            audio = Some(source);
+        }
+
+        tracing::info!(
+            "screen share published, system audio: {}",
+            options.system_audio
+        );
+        Ok(ScreenShareSources { video, audio })
+    }
+
+    /// Stop the screen share and its system audio, if any.
+    pub async fn stop_screen_share(&self) -> Result<(), VisioError> {
+        let room = self.room.lock().await;
+        let Some(room) = room.as_ref() else {
+            return Ok(());
+        };
+        self.unpublish_screen_share(&room.local_participant()).await
+    }
+
+    /// Whether a screen share is published.
+    pub async fn is_screen_sharing(&self) -> bool {
+        let room = self.room.lock().await;
+        room.as_ref().is_some_and(|room| {
+            room.local_participant()
+                .track_publications()
+                .values()
+                .any(|p| p.source() == LkTrackSource::Screenshare)
+        })
+    }
+
+    async fn unpublish_screen_share(&self, local: &LocalParticipant) -> Result<(), VisioError> {
+        for publication in local.track_publications().into_values() {
+            if matches!(
+                publication.source(),
+                LkTrackSource::Screenshare | LkTrackSource::ScreenshareAudio
+            ) {
+                local
+                    .unpublish_track(&publication.sid())
+                    .await
+                    .map_err(|e| VisioError::Room(format!("unpublish screen share: {e}")))?;
+            }
+        }
+        tracing::info!("screen share stopped");
+        Ok(())
+    }
+
     /// Toggle the microphone on/off.
     ///
     /// If enabling and no microphone track has been published yet,
-    /// automatically publishes one first.
+    /// automatically publishes one first. Enabling fails with
+    /// `PermissionDenied` if the microphone permission was denied.
+    /// Without a room, the request is queued if `set_queue_until_connected`
+    /// is on.
     pub async fn set_microphone_enabled(&self, enabled: bool) -> Result<(), VisioError> {
+        if enabled {
+            self.check_capture_permission(DevicePermission::Microphone)
+                .await?;
+        }
         {
             let room = self.room.lock().await;
-            let room = room
-                .as_ref()
-                .ok_or_else(|| VisioError::Room("not connected".into()))?;
+            let Some(room) = room.as_ref() else {
+                return self
+                    .pending
+                    .push(|p| p.microphone = Some(enabled))
+                    .ok_or_else(|| VisioError::Room("not connected".into()));
+            };
 
             let local = room.local_participant();
             let has_mic_track = local
@@ -182,13 +1008,23 @@ impl MeetingControls {
     /// Toggle the camera on/off.
     ///
     /// If enabling and no camera track has been published yet,
-    /// automatically publishes one first.
+    /// automatically publishes one first. Enabling fails with
+    /// `PermissionDenied` if the camera permission was denied.
+    /// Without a room, the request is queued if `set_queue_until_connected`
+    /// is on.
     pub async fn set_camera_enabled(&self, enabled: bool) -> Result<(), VisioError> {
+        if enabled {
+            self.check_capture_permission(DevicePermission::Camera)
+                .await?;
+        }
         {
             let room = self.room.lock().await;
-            let room = room
-                .as_ref()
-                .ok_or_else(|| VisioError::Room("not connected".into()))?;
+            let Some(room) = room.as_ref() else {
+                return self
+                    .pending
+                    .push(|p| p.camera = Some(enabled))
+                    .ok_or_else(|| VisioError::Room("not connected".into()));
+            };
 
             let local = room.local_participant();
             let has_camera_track = local
@@ -231,36 +1067,186 @@ impl MeetingControls {
         *self.camera_enabled.lock().await
     }
 
-    /// Get the audio source for feeding PCM frames from native capture.
+    /// Hide the outgoing video behind a blurred frame or a card (see
+    /// `VideoPrivacy`), or show it again. The camera track stays published
+    /// and unmuted, so nothing is renegotiated. Emits `VideoPrivacyChanged`
+    /// when the state changes.
+    pub fn set_video_privacy(&self, enabled: bool) {
+        if video_privacy().set_enabled(enabled) {
+            tracing::info!("video privacy: {enabled}");
+            self.emitter
+                .emit(VisioEvent::VideoPrivacyChanged { enabled });
+        }
+    }
+
+    pub fn is_video_privacy_enabled(&self) -> bool {
+        video_privacy().is_enabled()
+    }
+
+    /// Source of the microphone track published by this room.
     pub async fn audio_source(&self) -> Option<NativeAudioSource> {
-        self.audio_source.lock().await.clone()
+        self.sources.audio()
     }
 
-    /// Get the video source for feeding video frames from native capture.
+    /// Source of the camera track published by this room.
     pub async fn video_source(&self) -> Option<NativeVideoSource> {
-        self.video_source.lock().await.clone()
+        self.sources.video()
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::device_permissions::PermissionState;
     use crate::events::EventEmitter;
 
     fn make_controls() -> (MeetingControls, Arc<Mutex<bool>>) {
         let room = Arc::new(Mutex::new(None));
         let emitter = EventEmitter::new();
         let camera_enabled = Arc::new(Mutex::new(false));
-        let controls = MeetingControls::new(room, emitter, camera_enabled.clone());
+        let controls = MeetingControls::new(
+            room,
+            emitter.clone(),
+            Arc::new(Mutex::new(false)),
+            camera_enabled.clone(),
+            Arc::new(std::sync::Mutex::new(CaptureConstraints::default())),
+            Arc::new(std::sync::Mutex::new(AudioPublishOptions::default())),
+            Arc::new(std::sync::Mutex::new(VideoCodec::default())),
+            Arc::new(Mutex::new(VideoContentHint::default())),
+            DevicePermissions::new(emitter),
+            Arc::new(Mutex::new(None)),
+            PendingMediaQueue::default(),
+            PublishedSources::default(),
+        );
         (controls, camera_enabled)
     }
 
+    #[test]
+    fn capture_constraints_follow_quality_and_low_light() {
+        let emitter = EventEmitter::new();
+        let changes = Arc::new(std::sync::Mutex::new(Vec::new()));
+        struct Capture(Arc<std::sync::Mutex<Vec<CaptureConstraints>>>);
+        impl crate::events::VisioEventListener for Capture {
+            fn on_event(&self, event: VisioEvent) {
+                if let VisioEvent::CaptureConstraintsChanged(c) = event {
+                    self.0.lock().unwrap().push(c);
+                }
+            }
+        }
+        emitter.add_listener(Arc::new(Capture(changes.clone())));
+        let shared = Arc::new(std::sync::Mutex::new(CaptureConstraints::default()));
+        let controls = MeetingControls::new(
+            Arc::new(Mutex::new(None)),
+            emitter.clone(),
+            Arc::new(Mutex::new(false)),
+            Arc::new(Mutex::new(false)),
+            shared.clone(),
+            Arc::new(std::sync::Mutex::new(AudioPublishOptions::default())),
+            Arc::new(std::sync::Mutex::new(VideoCodec::default())),
+            Arc::new(Mutex::new(VideoContentHint::default())),
+            DevicePermissions::new(emitter.clone()),
+            Arc::new(Mutex::new(None)),
+            PendingMediaQueue::default(),
+            PublishedSources::default(),
+        );
+
+        controls.set_low_light_boost(true);
+        update_capture_constraints(&shared, &emitter, |c| {
+            CaptureConstraints::for_quality(&ConnectionQuality::Poor, c.low_light_boost)
+        });
+        // Unchanged: no event.
+        update_capture_constraints(&shared, &emitter, |c| {
+            CaptureConstraints::for_quality(&ConnectionQuality::Poor, c.low_light_boost)
+        });
+
+        let expected = CaptureConstraints {
+            width: 640,
+            height: 360,
+            max_fps: 15,
+            low_light_boost: true,
+        };
+        assert_eq!(controls.capture_constraints(), expected);
+        let changes = changes.lock().unwrap();
+        assert_eq!(changes.len(), 2);
+        assert!(changes[0].low_light_boost);
+        assert_eq!(changes[0].height, 720);
+        assert_eq!(changes[1], expected);
+    }
+
     #[tokio::test]
     async fn camera_enabled_initial_state() {
         let (controls, _) = make_controls();
         assert!(!controls.is_camera_enabled().await);
     }
 
+    #[tokio::test]
+    async fn video_content_hint_defaults_to_motion() {
+        let (controls, _) = make_controls();
+        assert_eq!(
+            controls.video_content_hint().await,
+            VideoContentHint::Motion
+        );
+        controls
+            .set_video_content_hint(VideoContentHint::Detail)
+            .await;
+        assert_eq!(
+            controls.video_content_hint().await,
+            VideoContentHint::Detail
+        );
+    }
+
+    #[tokio::test]
+    async fn audio_bitrate_is_clamped_to_opus_range() {
+        let (controls, _) = make_controls();
+        assert_eq!(
+            controls.audio_publish_options(),
+            AudioPublishOptions::default()
+        );
+        assert!(
+            controls
+                .audio_publish_options()
+                .publish_options()
+                .audio_encoding
+                .is_none()
+        );
+
+        // Not connected: only the preference changes.
+        controls.set_audio_bitrate(1_000).await.unwrap();
+        assert_eq!(
+            controls.audio_publish_options().max_bitrate_bps,
+            Some(6_000)
+        );
+        controls.set_audio_bitrate(16_000).await.unwrap();
+        assert!(controls.audio_publish_options().red);
+
+        let low = AudioPublishOptions::low_bandwidth().publish_options();
+        assert_eq!(low.audio_encoding.map(|e| e.max_bitrate), Some(16_000));
+        assert!(low.dtx && !low.red);
+    }
+
+    #[tokio::test]
+    async fn music_preset_drops_voice_processing() {
+        let (controls, _) = make_controls();
+        assert_eq!(controls.audio_preset(), AudioPreset::Voice);
+
+        controls.set_audio_preset(AudioPreset::Music).await.unwrap();
+        let options = controls.audio_publish_options();
+        assert_eq!(options.preset(), AudioPreset::Music);
+        assert!(options.stereo && !options.voice_processing);
+        let publish = options.publish_options();
+        assert_eq!(publish.audio_encoding.map(|e| e.max_bitrate), Some(128_000));
+        assert!(!publish.dtx);
+
+        // A custom bitrate keeps the preset.
+        controls.set_audio_bitrate(96_000).await.unwrap();
+        assert_eq!(controls.audio_preset(), AudioPreset::Music);
+        controls.set_audio_preset(AudioPreset::Voice).await.unwrap();
+        assert_eq!(
+            controls.audio_publish_options(),
+            AudioPublishOptions::default()
+        );
+    }
+
     #[tokio::test]
     async fn shared_camera_enabled_flag() {
         let (controls, camera_enabled) = make_controls();
@@ -291,6 +1277,106 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[tokio::test]
+    async fn denied_permission_blocks_publishing() {
+        let (controls, _) = make_controls();
+        let permissions = controls.device_permissions.clone();
+        permissions.set(DevicePermission::Camera, PermissionState::Denied);
+        permissions.set(DevicePermission::Microphone, PermissionState::Denied);
+
+        assert!(matches!(
+            controls.set_camera_enabled(true).await,
+            Err(VisioError::PermissionDenied(DevicePermission::Camera))
+        ));
+        assert!(matches!(
+            controls.publish_microphone().await,
+            Err(VisioError::PermissionDenied(DevicePermission::Microphone))
+        ));
+        // Turning a device off needs no permission.
+        assert!(matches!(
+            controls.set_microphone_enabled(false).await,
+            Err(VisioError::Room(_))
+        ));
+        // A synthetic source replaces the denied device.
+        controls
+            .set_test_media(TestMediaOptions::audio_video())
+            .await;
+        assert!(matches!(
+            controls.publish_camera().await,
+            Err(VisioError::Room(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn missing_grant_blocks_publishing() {
+        let (controls, _) = make_controls();
+        controls.set_queue_until_connected(true);
+        assert!(controls.can_publish(TrackSource::Camera).await);
+
+        let mut perms = LocalPermissions::from_token("jwt", None);
+        perms.can_publish_sources = vec!["microphone".into()];
+        *controls.local_permissions.lock().await = Some(perms);
+        assert!(!controls.can_publish(TrackSource::Camera).await);
+        assert!(controls.can_publish(TrackSource::Microphone).await);
+
+        assert!(matches!(
+            controls.set_camera_enabled(true).await,
+            Err(VisioError::PublishNotAllowed(TrackSource::Camera))
+        ));
+        // Synthetic media does not bypass the grants.
+        controls
+            .set_test_media(TestMediaOptions::audio_video())
+            .await;
+        assert!(matches!(
+            controls.publish_camera().await,
+            Err(VisioError::PublishNotAllowed(TrackSource::Camera))
+        ));
+        controls.set_microphone_enabled(true).await.unwrap();
+        assert!(controls.set_camera_enabled(false).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn queues_media_requests_until_connected() {
+        let (controls, _) = make_controls();
+        controls.set_queue_until_connected(true);
+
+        controls.set_camera_enabled(true).await.unwrap();
+        controls.set_microphone_enabled(true).await.unwrap();
+        controls.set_microphone_enabled(false).await.unwrap();
+        assert_eq!(
+            controls.pending_media(),
+            PendingMedia {
+                microphone: Some(false),
+                camera: Some(true),
+            }
+        );
+        assert!(!controls.is_camera_enabled().await);
+
+        // A denied permission still fails right away.
+        controls
+            .device_permissions
+            .set(DevicePermission::Microphone, PermissionState::Denied);
+        assert!(matches!(
+            controls.set_microphone_enabled(true).await,
+            Err(VisioError::PermissionDenied(_))
+        ));
+
+        controls.set_queue_until_connected(false);
+        assert!(controls.pending_media().is_empty());
+        assert!(controls.set_camera_enabled(true).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_media_options_roundtrip() {
+        let (controls, _) = make_controls();
+        assert_eq!(controls.test_media().await, TestMediaOptions::default());
+
+        controls
+            .set_test_media(TestMediaOptions::audio_video())
+            .await;
+        assert_eq!(controls.test_media().await, TestMediaOptions::audio_video());
+    }
+
     #[tokio::test]
     async fn mic_enabled_initial_state() {
         let (controls, _) = make_controls();
@@ -0,0 +1,186 @@
+//! Custom application messages over the LiveKit data channel.
+//!
+//! Apps built on the core (polls, whiteboard cursors, ...) publish raw
+//! bytes on a topic of their own and subscribe to the topics they handle.
+//! Messages on subscribed topics are delivered as
+//! `VisioEvent::DataMessageReceived`; other topics are ignored.
+//! Topics used by the core itself (chat, polls) cannot be published to.
+
+use std::collections::BTreeSet;
+use std::sync::Arc;
+
+use livekit::prelude::*;
+use tokio::sync::Mutex;
+
+use crate::errors::VisioError;
+use crate::events::{EventEmitter, VisioEvent};
+use crate::polls::POLLS_TOPIC;
+
+/// Topics reserved for the Meet protocol.
+const RESERVED_TOPICS: &[&str] = &["lk.chat", "lk-chat-topic", POLLS_TOPIC];
+
+/// Largest payload accepted by `publish`, below the SFU limit for a
+/// single data packet.
+pub const MAX_PAYLOAD_BYTES: usize = 15_000;
+
+#[derive(Clone)]
+pub struct DataChannelService {
+    room: Arc<Mutex<Option<Arc<Room>>>>,
+    emitter: EventEmitter,
+    subscriptions: Arc<std::sync::Mutex<BTreeSet<String>>>,
+}
+
+impl DataChannelService {
+    pub fn new(room: Arc<Mutex<Option<Arc<Room>>>>, emitter: EventEmitter) -> Self {
+        Self {
+            room,
+            emitter,
+            subscriptions: Arc::new(std::sync::Mutex::new(BTreeSet::new())),
+        }
+    }
+
+    /// Send `payload` to all participants on `topic`. Reliable messages are
+    /// retransmitted and ordered; lossy ones suit frequent updates such as
+    /// cursor positions.
+    pub async fn publish(
+        &self,
+        topic: &str,
+        payload: Vec<u8>,
+        reliable: bool,
+    ) -> Result<(), VisioError> {
+        check_topic(topic)?;
+        if payload.len() > MAX_PAYLOAD_BYTES {
+            return Err(VisioError::Room(format!(
+                "data message too large: {} bytes (max {MAX_PAYLOAD_BYTES})",
+                payload.len()
+            )));
+        }
+        self.publish_raw(topic, payload, reliable).await
+    }
+
+    /// Publish without the topic check, for the core's own protocols.
+    pub(crate) async fn publish_raw(
+        &self,
+        topic: &str,
+        payload: Vec<u8>,
+        reliable: bool,
+    ) -> Result<(), VisioError> {
+        let room = self.room.lock().await;
+        let room = room
+            .as_ref()
+            .ok_or_else(|| VisioError::Room("not connected".into()))?;
+
+        room.local_participant()
+            .publish_data(DataPacket {
+                payload,
+                topic: Some(topic.to_string()),
+                reliable,
+                ..Default::default()
+            })
+            .await
+            .map_err(|e| VisioError::Room(format!("publish data: {e}")))
+    }
+
+    /// Deliver messages received on `topic` as `DataMessageReceived` events.
+    pub fn subscribe(&self, topic: &str) -> Result<(), VisioError> {
+        check_topic(topic)?;
+        self.subscriptions
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(topic.to_string());
+        Ok(())
+    }
+
+    pub fn unsubscribe(&self, topic: &str) {
+        self.subscriptions
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(topic);
+    }
+
+    /// Subscribed topics, sorted.
+    pub fn subscriptions(&self) -> Vec<String> {
+        self.subscriptions
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .cloned()
+            .collect()
+    }
+
+    /// Called by the room event loop for every data packet with a topic.
+    pub(crate) fn handle_incoming(&self, topic: &str, payload: &[u8], sender_sid: &str) {
+        let subscribed = self
+            .subscriptions
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .contains(topic);
+        if subscribed {
+            self.emitter.emit(VisioEvent::DataMessageReceived {
+                topic: topic.to_string(),
+                sender_sid: sender_sid.to_string(),
+                payload: payload.to_vec(),
+            });
+        }
+    }
+}
+
+fn check_topic(topic: &str) -> Result<(), VisioError> {
+    if topic.is_empty() {
+        return Err(VisioError::Room("data topic must not be empty".into()));
+    }
+    if RESERVED_TOPICS.contains(&topic) {
+        return Err(VisioError::Room(format!("data topic {topic} is reserved")));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::VisioEventListener;
+
+    struct EventCapture {
+        events: std::sync::Mutex<Vec<VisioEvent>>,
+    }
+
+    impl VisioEventListener for EventCapture {
+        fn on_event(&self, event: VisioEvent) {
+            self.events.lock().unwrap().push(event);
+        }
+    }
+
+    #[tokio::test]
+    async fn delivers_subscribed_topics_only() {
+        let emitter = EventEmitter::new();
+        let capture = Arc::new(EventCapture {
+            events: std::sync::Mutex::new(Vec::new()),
+        });
+        emitter.add_listener(capture.clone());
+        let service = DataChannelService::new(Arc::new(Mutex::new(None)), emitter);
+
+        assert!(service.subscribe("lk.chat").is_err());
+        assert!(service.subscribe("").is_err());
+        service.subscribe("poll").unwrap();
+        service.subscribe("cursor").unwrap();
+        service.unsubscribe("cursor");
+        assert_eq!(service.subscriptions(), vec!["poll".to_string()]);
+
+        let err = service
+            .publish("poll", vec![0; MAX_PAYLOAD_BYTES + 1], true)
+            .await;
+        assert!(matches!(err, Err(VisioError::Room(msg)) if msg.contains("too large")));
+        let err = service.publish("poll", b"vote".to_vec(), true).await;
+        assert!(matches!(err, Err(VisioError::Room(msg)) if msg == "not connected"));
+
+        service.handle_incoming("poll", b"vote", "PA_1");
+        service.handle_incoming("cursor", b"1,2", "PA_1");
+        let events = capture.events.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        assert!(matches!(
+            &events[0],
+            VisioEvent::DataMessageReceived { topic, sender_sid, payload }
+                if topic == "poll" && sender_sid == "PA_1" && payload == b"vote"
+        ));
+    }
+}
@@ -0,0 +1,165 @@
+//! Where the client keeps its files.
+//!
+//! Everything lives under one root directory, chosen by the shell: the
+//! platform data directory by default, or another one (portable mode,
+//! `VISIO_DATA_DIR`). When the root changes, `DataPaths::migrate_from`
+//! brings the user's files over from the previous one.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const SETTINGS_FILE: &str = "settings.json";
+
+/// Layout of the data directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataPaths {
+    root: PathBuf,
+}
+
+impl DataPaths {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Persistent settings (see `SettingsStore`).
+    pub fn settings_file(&self) -> PathBuf {
+        self.root.join(SETTINGS_FILE)
+    }
+
+    /// Log files, safe to delete.
+    pub fn logs_dir(&self) -> PathBuf {
+        self.root.join("logs")
+    }
+
+    /// Local recordings made by the user.
+    pub fn recordings_dir(&self) -> PathBuf {
+        self.root.join("recordings")
+    }
+
+    /// Saved video snapshots.
+    pub fn snapshots_dir(&self) -> PathBuf {
+        self.root.join("snapshots")
+    }
+
+    /// Downloaded or derived data, safe to delete.
+    pub fn cache_dir(&self) -> PathBuf {
+        self.root.join("cache")
+    }
+
+    /// Create the root and its subdirectories.
+    pub fn create_dirs(&self) -> io::Result<()> {
+        for dir in [
+            self.root.clone(),
+            self.logs_dir(),
+            self.recordings_dir(),
+            self.snapshots_dir(),
+            self.cache_dir(),
+        ] {
+            fs::create_dir_all(dir)?;
+        }
+        Ok(())
+    }
+
+    /// Check that the root can be created and written to.
+    pub fn check_writable(&self) -> io::Result<()> {
+        fs::create_dir_all(&self.root)?;
+        let probe = self.root.join(".write-test");
+        fs::write(&probe, b"ok")?;
+        fs::remove_file(probe)
+    }
+
+    /// Copy the user's files (settings, recordings, snapshots) from
+    /// `previous` when this directory has no settings yet. Logs and cache
+    /// are not carried over; `previous` is left untouched. Returns whether
+    /// anything was migrated.
+    pub fn migrate_from(&self, previous: &DataPaths) -> io::Result<bool> {
+        if previous.root == self.root
+            || self.settings_file().exists()
+            || !previous.settings_file().exists()
+        {
+            return Ok(false);
+        }
+        fs::create_dir_all(&self.root)?;
+        fs::copy(previous.settings_file(), self.settings_file())?;
+        copy_dir(&previous.recordings_dir(), &self.recordings_dir())?;
+        copy_dir(&previous.snapshots_dir(), &self.snapshots_dir())?;
+        tracing::info!(
+            "migrated data from {} to {}",
+            previous.root.display(),
+            self.root.display()
+        );
+        Ok(true)
+    }
+}
+
+/// Copy the tree at `from` into `to`, keeping files already there.
+fn copy_dir(from: &Path, to: &Path) -> io::Result<()> {
+    if !from.is_dir() {
+        return Ok(());
+    }
+    fs::create_dir_all(to)?;
+    for entry in fs::read_dir(from)? {
+        let entry = entry?;
+        let target = to.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_dir(&entry.path(), &target)?;
+        } else if !target.exists() {
+            fs::copy(entry.path(), target)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checks_that_the_root_is_writable() {
+        let dir = tempfile::tempdir().unwrap();
+        let paths = DataPaths::new(dir.path().join("visio"));
+        paths.check_writable().unwrap();
+        assert!(paths.root().is_dir());
+        assert!(!paths.root().join(".write-test").exists());
+
+        // A file where the root should be.
+        let blocked = DataPaths::new(dir.path().join("visio").join("settings.json"));
+        fs::write(blocked.root(), b"{}").unwrap();
+        assert!(blocked.check_writable().is_err());
+    }
+
+    #[test]
+    fn migrates_user_files_once() {
+        let old_dir = tempfile::tempdir().unwrap();
+        let new_dir = tempfile::tempdir().unwrap();
+        let old = DataPaths::new(old_dir.path());
+        let new = DataPaths::new(new_dir.path().join("portable"));
+
+        // Nothing to migrate yet.
+        assert!(!new.migrate_from(&old).unwrap());
+
+        old.create_dirs().unwrap();
+        fs::write(old.settings_file(), r#"{"theme":"dark"}"#).unwrap();
+        fs::write(old.recordings_dir().join("call.wav"), b"RIFF").unwrap();
+        fs::write(old.cache_dir().join("model.bin"), b"cache").unwrap();
+
+        assert!(new.migrate_from(&old).unwrap());
+        assert_eq!(
+            fs::read_to_string(new.settings_file()).unwrap(),
+            r#"{"theme":"dark"}"#
+        );
+        assert!(new.recordings_dir().join("call.wav").exists());
+        assert!(!new.cache_dir().join("model.bin").exists());
+        assert!(old.settings_file().exists());
+
+        // The new directory has its own settings now.
+        fs::write(old.settings_file(), r#"{"theme":"light"}"#).unwrap();
+        assert!(!new.migrate_from(&old).unwrap());
+        assert!(!old.migrate_from(&old).unwrap());
+    }
+}
@@ -0,0 +1,143 @@
+//! Parsing of the links that open the app.
+//!
+//! Shells receive `visio://instance/slug` links from the OS, and users may
+//! paste or share plain Meet links (`https://instance/slug`). Both may
+//! carry a `name` query parameter to prefill the display name.
+//! `visio://settings` opens the settings screen. Whether the instance is
+//! one the user trusts is left to the shell (see `Settings::meet_instances`).
+
+/// What a link asks the app to do.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeepLink {
+    /// Join the room at `meet_url` (`https://instance/slug`).
+    JoinRoom {
+        meet_url: String,
+        instance: String,
+        slug: String,
+        display_name: Option<String>,
+    },
+    OpenSettings,
+}
+
+impl DeepLink {
+    /// Structured intent of `uri`, or `None` if it is not a link the app
+    /// handles.
+    pub fn parse(uri: &str) -> Option<Self> {
+        let uri = uri.trim();
+        let (scheme, rest) = uri.split_once("://")?;
+        let scheme = scheme.to_ascii_lowercase();
+        if !matches!(scheme.as_str(), "visio" | "https" | "http") {
+            return None;
+        }
+
+        let rest = rest.split('#').next().unwrap_or_default();
+        let (path, query) = match rest.split_once('?') {
+            Some((path, query)) => (path, Some(query)),
+            None => (rest, None),
+        };
+        let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+
+        if scheme == "visio" && segments == ["settings"] {
+            return Some(Self::OpenSettings);
+        }
+
+        let [host, slug] = segments.as_slice() else {
+            return None;
+        };
+        // `visio:///settings` leaves the host empty.
+        if path.starts_with('/') {
+            return None;
+        }
+        if !is_valid_host(host) || !is_valid_slug(slug) {
+            return None;
+        }
+
+        let instance = host.to_ascii_lowercase();
+        Some(Self::JoinRoom {
+            meet_url: format!("https://{instance}/{slug}"),
+            instance,
+            slug: slug.to_string(),
+            display_name: query.and_then(display_name),
+        })
+    }
+}
+
+fn is_valid_host(host: &str) -> bool {
+    !host.is_empty()
+        && host
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | ':'))
+}
+
+fn is_valid_slug(slug: &str) -> bool {
+    slug.chars()
+        .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_'))
+}
+
+/// Non-empty `name` parameter of a query string.
+fn display_name(query: &str) -> Option<String> {
+    query.split('&').find_map(|pair| {
+        let (key, value) = pair.split_once('=')?;
+        if key != "name" {
+            return None;
+        }
+        let value = value.replace('+', " ");
+        let value = urlencoding::decode(&value).ok()?;
+        let value = value.trim();
+        (!value.is_empty()).then(|| value.to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn join(instance: &str, slug: &str, display_name: Option<&str>) -> Option<DeepLink> {
+        Some(DeepLink::JoinRoom {
+            meet_url: format!("https://{instance}/{slug}"),
+            instance: instance.to_string(),
+            slug: slug.to_string(),
+            display_name: display_name.map(str::to_string),
+        })
+    }
+
+    #[test]
+    fn parses_room_links() {
+        assert_eq!(
+            DeepLink::parse("visio://meet.example.com/abc-defg-hij"),
+            join("meet.example.com", "abc-defg-hij", None)
+        );
+        assert_eq!(
+            DeepLink::parse("https://Meet.Example.com/abc-defg-hij/?name=Ana%C3%AFs+Dupont"),
+            join("meet.example.com", "abc-defg-hij", Some("Anaïs Dupont"))
+        );
+        assert_eq!(
+            DeepLink::parse(" http://localhost:8071/my_room?lang=fr&name=&x=1#top "),
+            join("localhost:8071", "my_room", None)
+        );
+        assert_eq!(
+            DeepLink::parse("VISIO://meet.example.com/abc-defg-hij?x=1&name=Bob"),
+            join("meet.example.com", "abc-defg-hij", Some("Bob"))
+        );
+    }
+
+    #[test]
+    fn parses_settings_and_rejects_other_links() {
+        assert_eq!(
+            DeepLink::parse("visio://settings"),
+            Some(DeepLink::OpenSettings)
+        );
+        assert_eq!(
+            DeepLink::parse("visio:///settings/"),
+            Some(DeepLink::OpenSettings)
+        );
+        assert_eq!(DeepLink::parse("https://settings"), None);
+        assert_eq!(DeepLink::parse("https://meet.example.com"), None);
+        assert_eq!(DeepLink::parse("https://meet.example.com/a/b"), None);
+        assert_eq!(DeepLink::parse("https://meet.example.com/a%20b"), None);
+        assert_eq!(DeepLink::parse("visio:///meet.example.com/abc"), None);
+        assert_eq!(DeepLink::parse("ftp://meet.example.com/abc"), None);
+        assert_eq!(DeepLink::parse("meet.example.com/abc"), None);
+        assert_eq!(DeepLink::parse(""), None);
+    }
+}
@@ -0,0 +1,199 @@
+//! Pre-call device health check.
+//!
+//! Before joining, the pre-join screen runs `run_device_check` while the
+//! shell captures from the selected microphone and camera: the capture
+//! paths report what they get (`DeviceCheck::observe_audio`,
+//! `DeviceCheck::observe_video_frame`) and the shell says whether the
+//! audio output opened. The report tells a working device from one that
+//! is silent, sends no frames or cannot be opened, for warnings like
+//! "your microphone seems silent".
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, OnceLock};
+use std::time::Duration;
+
+use crate::diagnostics::CheckResult;
+
+/// RMS level (0.0 to 1.0) under which the microphone counts as silent:
+/// -70 dBFS, below the noise floor of any live microphone.
+pub const SILENCE_LEVEL: f32 = 0.0003;
+
+/// How long `run_device_check` listens by default.
+pub const DEFAULT_CHECK_WINDOW: Duration = Duration::from_millis(1500);
+
+/// Result of a device check.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeviceCheckReport {
+    pub microphone: CheckResult,
+    /// Loudest RMS level heard, from 0.0 to 1.0.
+    pub microphone_level: f32,
+    pub camera: CheckResult,
+    pub camera_frames: u32,
+    pub speaker: CheckResult,
+}
+
+impl DeviceCheckReport {
+    /// Whether any checked device needs the user's attention.
+    pub fn has_problem(&self) -> bool {
+        self.microphone.is_failed() || self.camera.is_failed() || self.speaker.is_failed()
+    }
+}
+
+#[derive(Debug, Default)]
+struct Observed {
+    audio_samples: u64,
+    peak_level: f32,
+    video_frames: u32,
+}
+
+/// Collects what the capture paths produce while a check runs. Shared by
+/// all capture paths, see `device_check()`.
+#[derive(Debug, Default)]
+pub struct DeviceCheck {
+    active: AtomicBool,
+    observed: Mutex<Observed>,
+}
+
+impl DeviceCheck {
+    /// Start collecting, forgetting any previous check.
+    pub fn start(&self) {
+        *self.observed.lock().unwrap_or_else(|e| e.into_inner()) = Observed::default();
+        self.active.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.active.load(Ordering::Relaxed)
+    }
+
+    /// Feed a chunk of captured 16-bit PCM. Cheap no-op outside a check.
+    pub fn observe_audio(&self, pcm: &[i16]) {
+        if !self.is_active() || pcm.is_empty() {
+            return;
+        }
+        let level = rms(pcm);
+        let mut observed = self.observed.lock().unwrap_or_else(|e| e.into_inner());
+        observed.audio_samples += pcm.len() as u64;
+        observed.peak_level = observed.peak_level.max(level);
+    }
+
+    /// Count a captured camera frame. Cheap no-op outside a check.
+    pub fn observe_video_frame(&self) {
+        if !self.is_active() {
+            return;
+        }
+        self.observed
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .video_frames += 1;
+    }
+
+    /// Stop collecting and report. `speaker` is whether the shell could
+    /// open the audio output; the camera is skipped unless `check_camera`.
+    pub fn finish(&self, speaker: CheckResult, check_camera: bool) -> DeviceCheckReport {
+        self.active.store(false, Ordering::Relaxed);
+        let observed =
+            std::mem::take(&mut *self.observed.lock().unwrap_or_else(|e| e.into_inner()));
+        let microphone = if observed.audio_samples == 0 {
+            CheckResult::Failed("no audio captured".into())
+        } else if observed.peak_level < SILENCE_LEVEL {
+            CheckResult::Failed("microphone is silent".into())
+        } else {
+            CheckResult::Passed
+        };
+        let camera = if !check_camera {
+            CheckResult::Skipped
+        } else if observed.video_frames == 0 {
+            CheckResult::Failed("no camera frames".into())
+        } else {
+            CheckResult::Passed
+        };
+        DeviceCheckReport {
+            microphone,
+            microphone_level: observed.peak_level,
+            camera,
+            camera_frames: observed.video_frames,
+            speaker,
+        }
+    }
+}
+
+fn rms(pcm: &[i16]) -> f32 {
+    let sum: f64 = pcm
+        .iter()
+        .map(|&s| {
+            let s = f64::from(s) / 32768.0;
+            s * s
+        })
+        .sum();
+    (sum / pcm.len() as f64).sqrt() as f32
+}
+
+/// The process-wide device check fed by the capture paths.
+pub fn device_check() -> &'static DeviceCheck {
+    static CHECK: OnceLock<DeviceCheck> = OnceLock::new();
+    CHECK.get_or_init(DeviceCheck::default)
+}
+
+/// Collect capture output for `window` and report. The shell starts the
+/// microphone (and the camera with `check_camera`) before calling, and
+/// stops them after.
+pub async fn run_device_check(
+    window: Duration,
+    speaker: CheckResult,
+    check_camera: bool,
+) -> DeviceCheckReport {
+    device_check().start();
+    tokio::time::sleep(window).await;
+    device_check().finish(speaker, check_camera)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_silent_and_missing_devices() {
+        let check = DeviceCheck::default();
+        check.observe_audio(&[1000; 480]);
+        check.start();
+        check.observe_audio(&[0; 480]);
+        check.observe_audio(&[3, -2, 1, 0]);
+        let report = check.finish(CheckResult::Passed, true);
+        assert_eq!(
+            report.microphone,
+            CheckResult::Failed("microphone is silent".into())
+        );
+        assert_eq!(
+            report.camera,
+            CheckResult::Failed("no camera frames".into())
+        );
+        assert!(report.has_problem());
+
+        // Nothing is collected once finished.
+        check.observe_video_frame();
+        let report = check.finish(CheckResult::Skipped, false);
+        assert_eq!(
+            report.microphone,
+            CheckResult::Failed("no audio captured".into())
+        );
+        assert_eq!(report.camera, CheckResult::Skipped);
+        assert_eq!(report.camera_frames, 0);
+    }
+
+    #[test]
+    fn reports_working_devices() {
+        let check = DeviceCheck::default();
+        check.start();
+        let speech: Vec<i16> = (0..480)
+            .map(|i| if i % 2 == 0 { 3000 } else { -3000 })
+            .collect();
+        check.observe_audio(&speech);
+        check.observe_video_frame();
+        check.observe_video_frame();
+        let report = check.finish(CheckResult::Passed, true);
+        assert_eq!(report.microphone, CheckResult::Passed);
+        assert!((report.microphone_level - 3000.0 / 32768.0).abs() < 1e-4);
+        assert_eq!(report.camera_frames, 2);
+        assert!(!report.has_problem());
+    }
+}
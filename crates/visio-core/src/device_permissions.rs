@@ -0,0 +1,146 @@
+//! Operating system permissions for capture devices and notifications.
+//!
+//! Only the shells can ask the OS, so they report each permission as it
+//! becomes known (`DevicePermissions::set`). The core then refuses to
+//! publish a camera or microphone the user denied with a clear
+//! `VisioError::PermissionDenied`, instead of publishing a black or silent
+//! track, and emits `DevicePermissionChanged` for UI badges.
+//!
+//! Not to be confused with `LocalPermissions`, what the SFU allows the
+//! local participant to do.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use crate::errors::VisioError;
+use crate::events::{EventEmitter, VisioEvent};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DevicePermission {
+    Camera,
+    Microphone,
+    ScreenCapture,
+    Notifications,
+}
+
+impl DevicePermission {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Camera => "camera",
+            Self::Microphone => "microphone",
+            Self::ScreenCapture => "screen_capture",
+            Self::Notifications => "notifications",
+        }
+    }
+}
+
+impl fmt::Display for DevicePermission {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PermissionState {
+    /// Not reported yet, or not asked yet. Operations are attempted.
+    #[default]
+    Unknown,
+    Granted,
+    Denied,
+}
+
+/// Permission states reported by the shell, shared by the room and its
+/// `MeetingControls`.
+#[derive(Clone)]
+pub struct DevicePermissions {
+    states: Arc<Mutex<HashMap<DevicePermission, PermissionState>>>,
+    emitter: EventEmitter,
+}
+
+impl DevicePermissions {
+    pub fn new(emitter: EventEmitter) -> Self {
+        Self {
+            states: Arc::new(Mutex::new(HashMap::new())),
+            emitter,
+        }
+    }
+
+    /// Record `state` for `permission`. Emits `DevicePermissionChanged`
+    /// when it changes.
+    pub fn set(&self, permission: DevicePermission, state: PermissionState) {
+        let previous = self
+            .states
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(permission, state)
+            .unwrap_or_default();
+        if previous != state {
+            tracing::info!("{permission} permission: {previous:?} -> {state:?}");
+            self.emitter
+                .emit(VisioEvent::DevicePermissionChanged { permission, state });
+        }
+    }
+
+    pub fn get(&self, permission: DevicePermission) -> PermissionState {
+        self.states
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(&permission)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// `PermissionDenied` if the user denied `permission`.
+    pub fn check(&self, permission: DevicePermission) -> Result<(), VisioError> {
+        match self.get(permission) {
+            PermissionState::Denied => Err(VisioError::PermissionDenied(permission)),
+            PermissionState::Unknown | PermissionState::Granted => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tracks_states_and_emits_changes() {
+        let emitter = EventEmitter::new();
+        let changes = Arc::new(Mutex::new(Vec::new()));
+        struct Capture(Arc<Mutex<Vec<(DevicePermission, PermissionState)>>>);
+        impl crate::events::VisioEventListener for Capture {
+            fn on_event(&self, event: VisioEvent) {
+                if let VisioEvent::DevicePermissionChanged { permission, state } = event {
+                    self.0.lock().unwrap().push((permission, state));
+                }
+            }
+        }
+        emitter.add_listener(Arc::new(Capture(changes.clone())));
+        let permissions = DevicePermissions::new(emitter);
+
+        assert_eq!(
+            permissions.get(DevicePermission::Camera),
+            PermissionState::Unknown
+        );
+        assert!(permissions.check(DevicePermission::Camera).is_ok());
+
+        permissions.set(DevicePermission::Camera, PermissionState::Denied);
+        permissions.set(DevicePermission::Camera, PermissionState::Denied);
+        permissions.set(DevicePermission::Microphone, PermissionState::Granted);
+        // Reporting the default state is not a change.
+        permissions.set(DevicePermission::Notifications, PermissionState::Unknown);
+
+        let err = permissions.check(DevicePermission::Camera).unwrap_err();
+        assert_eq!(err.code(), "permission_denied");
+        assert_eq!(err.developer_detail().as_deref(), Some("camera"));
+        assert!(permissions.check(DevicePermission::Microphone).is_ok());
+        assert_eq!(
+            *changes.lock().unwrap(),
+            [
+                (DevicePermission::Camera, PermissionState::Denied),
+                (DevicePermission::Microphone, PermissionState::Granted),
+            ]
+        );
+    }
+}
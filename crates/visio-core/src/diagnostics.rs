@@ -0,0 +1,353 @@
+//! Connectivity diagnosis run after a failed connection.
+//!
+//! Probes the path to a host step by step — DNS resolution (IPv4 and
+//! IPv6), TCP reachability of each address family, TLS handshake and, for
+//! LiveKit URLs, the WebSocket upgrade — so a bare "connection failed"
+//! comes with the step that broke. When a step fails, a plain-HTTP probe
+//! that gets redirected to another host reveals a captive portal.
+
+use std::fmt;
+use std::net::{IpAddr, SocketAddr, TcpStream, ToSocketAddrs};
+use std::time::Duration;
+
+/// Upper bound for each probe.
+const STEP_TIMEOUT: Duration = Duration::from_secs(3);
+
+/// Outcome of one diagnosis step.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CheckResult {
+    Passed,
+    Failed(String),
+    /// Not run (an earlier step failed, or not applicable).
+    Skipped,
+}
+
+impl CheckResult {
+    pub fn is_passed(&self) -> bool {
+        *self == Self::Passed
+    }
+
+    pub fn is_failed(&self) -> bool {
+        matches!(self, Self::Failed(_))
+    }
+}
+
+impl fmt::Display for CheckResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Passed => write!(f, "ok"),
+            Self::Failed(reason) => write!(f, "failed ({reason})"),
+            Self::Skipped => write!(f, "skipped"),
+        }
+    }
+}
+
+/// Result of probing the network path to a host.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConnectivityDiagnosis {
+    pub host: String,
+    pub port: u16,
+    pub dns: CheckResult,
+    pub ipv4_addresses: Vec<String>,
+    pub ipv6_addresses: Vec<String>,
+    pub tcp_ipv4: CheckResult,
+    pub tcp_ipv6: CheckResult,
+    pub tls: CheckResult,
+    pub websocket: CheckResult,
+    /// A plain-HTTP request was redirected to another host.
+    pub captive_portal: bool,
+}
+
+impl ConnectivityDiagnosis {
+    fn new(host: String, port: u16) -> Self {
+        Self {
+            host,
+            port,
+            dns: CheckResult::Skipped,
+            ipv4_addresses: Vec::new(),
+            ipv6_addresses: Vec::new(),
+            tcp_ipv4: CheckResult::Skipped,
+            tcp_ipv6: CheckResult::Skipped,
+            tls: CheckResult::Skipped,
+            websocket: CheckResult::Skipped,
+            captive_portal: false,
+        }
+    }
+
+    /// Whether the diagnosis explains a failed connection. An unreachable
+    /// IPv6 path alone is not a problem as long as IPv4 works.
+    pub fn has_problem(&self) -> bool {
+        self.dns.is_failed()
+            || !(self.tcp_ipv4.is_passed() || self.tcp_ipv6.is_passed())
+            || self.tls.is_failed()
+            || self.websocket.is_failed()
+            || self.captive_portal
+    }
+}
+
+impl fmt::Display for ConnectivityDiagnosis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}: DNS {} ({} IPv4, {} IPv6), TCP IPv4 {}, TCP IPv6 {}, TLS {}, WebSocket {}",
+            self.host,
+            self.port,
+            self.dns,
+            self.ipv4_addresses.len(),
+            self.ipv6_addresses.len(),
+            self.tcp_ipv4,
+            self.tcp_ipv6,
+            self.tls,
+            self.websocket,
+        )?;
+        if self.captive_portal {
+            write!(f, ", captive portal suspected")?;
+        }
+        Ok(())
+    }
+}
+
+/// Diagnose the path to `url` (`https://`, `wss://` or `ws://`). The
+/// WebSocket step only runs for `ws`/`wss` URLs.
+pub async fn diagnose(url: &str) -> ConnectivityDiagnosis {
+    let url = if url.contains("://") {
+        url.to_string()
+    } else {
+        format!("https://{url}")
+    };
+    let Ok(parsed) = reqwest::Url::parse(&url) else {
+        let mut diagnosis = ConnectivityDiagnosis::new(url, 0);
+        diagnosis.dns = CheckResult::Failed("invalid URL".into());
+        return diagnosis;
+    };
+    let host = parsed
+        .host_str()
+        .unwrap_or_default()
+        .trim_matches(['[', ']'])
+        .to_string();
+    let port = parsed.port_or_known_default().unwrap_or(443);
+    let secure = matches!(parsed.scheme(), "https" | "wss");
+    let websocket = matches!(parsed.scheme(), "ws" | "wss");
+    let mut diagnosis = ConnectivityDiagnosis::new(host.clone(), port);
+
+    // DNS
+    let addrs = match resolve(&host, port).await {
+        Ok(addrs) if addrs.is_empty() => {
+            diagnosis.dns = CheckResult::Failed("no address".into());
+            return diagnosis;
+        }
+        Ok(addrs) => addrs,
+        Err(reason) => {
+            diagnosis.dns = CheckResult::Failed(reason);
+            return diagnosis;
+        }
+    };
+    let (v4, v6): (Vec<SocketAddr>, Vec<SocketAddr>) = addrs.iter().partition(|a| a.is_ipv4());
+    diagnosis.ipv4_addresses = v4.iter().map(|a| a.ip().to_string()).collect();
+    diagnosis.ipv6_addresses = v6.iter().map(|a| a.ip().to_string()).collect();
+    if unexpected_loopback(&host, &addrs) {
+        diagnosis.dns = CheckResult::Failed("resolves to a loopback address".into());
+        return diagnosis;
+    }
+    diagnosis.dns = CheckResult::Passed;
+
+    // TCP, per address family
+    if let Some(addr) = v4.first() {
+        diagnosis.tcp_ipv4 = tcp_connect(*addr).await;
+    }
+    if let Some(addr) = v6.first() {
+        diagnosis.tcp_ipv6 = tcp_connect(*addr).await;
+    }
+    if !(diagnosis.tcp_ipv4.is_passed() || diagnosis.tcp_ipv6.is_passed()) {
+        diagnosis.captive_portal = captive_portal(&host).await;
+        return diagnosis;
+    }
+
+    // TLS and WebSocket upgrade
+    let (tls, upgrade) = http_probe(&parsed, secure, websocket).await;
+    diagnosis.tls = tls;
+    diagnosis.websocket = upgrade;
+    if diagnosis.tls.is_failed() || diagnosis.websocket.is_failed() {
+        diagnosis.captive_portal = captive_portal(&host).await;
+    }
+    diagnosis
+}
+
+async fn resolve(host: &str, port: u16) -> Result<Vec<SocketAddr>, String> {
+    let target = (host.to_string(), port);
+    let lookup = tokio::task::spawn_blocking(move || {
+        target
+            .to_socket_addrs()
+            .map(|addrs| addrs.collect::<Vec<_>>())
+    });
+    match tokio::time::timeout(STEP_TIMEOUT, lookup).await {
+        Ok(Ok(Ok(addrs))) => Ok(addrs),
+        Ok(Ok(Err(e))) => Err(e.to_string()),
+        Ok(Err(e)) => Err(e.to_string()),
+        Err(_) => Err("timed out".into()),
+    }
+}
+
+/// A public host name that only resolves to loopback addresses points to
+/// a broken resolver or hosts file, not to the intended server.
+fn unexpected_loopback(host: &str, addrs: &[SocketAddr]) -> bool {
+    host != "localhost"
+        && host.parse::<IpAddr>().is_err()
+        && addrs.iter().all(|a| a.ip().is_loopback())
+}
+
+async fn tcp_connect(addr: SocketAddr) -> CheckResult {
+    let connect =
+        tokio::task::spawn_blocking(move || TcpStream::connect_timeout(&addr, STEP_TIMEOUT));
+    match connect.await {
+        Ok(Ok(_)) => CheckResult::Passed,
+        Ok(Err(e)) => CheckResult::Failed(e.to_string()),
+        Err(e) => CheckResult::Failed(e.to_string()),
+    }
+}
+
+/// One HTTP/1.1 request to the URL. For WebSocket URLs it is an upgrade
+/// request to LiveKit's `/rtc` endpoint.
+async fn http_probe(
+    url: &reqwest::Url,
+    secure: bool,
+    websocket: bool,
+) -> (CheckResult, CheckResult) {
+    let skipped_tls = |r: CheckResult| if secure { r } else { CheckResult::Skipped };
+    let client = match reqwest::Client::builder()
+        .http1_only()
+        .redirect(reqwest::redirect::Policy::none())
+        .timeout(STEP_TIMEOUT)
+        .build()
+    {
+        Ok(client) => client,
+        Err(e) => return (CheckResult::Failed(e.to_string()), CheckResult::Skipped),
+    };
+
+    let mut probe_url = url.clone();
+    let _ = probe_url.set_scheme(if secure { "https" } else { "http" });
+    if websocket {
+        probe_url.set_path("/rtc");
+    }
+    let mut request = client.get(probe_url);
+    if websocket {
+        request = request
+            .header("Connection", "Upgrade")
+            .header("Upgrade", "websocket")
+            .header("Sec-WebSocket-Version", "13")
+            .header("Sec-WebSocket-Key", "dmlzaW8tZGlhZ25vc2lzIQ==");
+    }
+
+    match request.send().await {
+        Ok(resp) => {
+            let upgrade = if websocket {
+                websocket_result(resp.status().as_u16())
+            } else {
+                CheckResult::Skipped
+            };
+            (skipped_tls(CheckResult::Passed), upgrade)
+        }
+        Err(e) if secure => (CheckResult::Failed(e.to_string()), CheckResult::Skipped),
+        Err(e) if websocket => (CheckResult::Skipped, CheckResult::Failed(e.to_string())),
+        Err(_) => (CheckResult::Skipped, CheckResult::Skipped),
+    }
+}
+
+/// LiveKit answers an upgrade without token with 401; anything but that
+/// or 101 means a proxy is in the way.
+fn websocket_result(status: u16) -> CheckResult {
+    match status {
+        101 | 401 => CheckResult::Passed,
+        status => CheckResult::Failed(format!("unexpected HTTP status {status}")),
+    }
+}
+
+/// Plain-HTTP request to the host: captive portals intercept it and
+/// redirect to their login page.
+async fn captive_portal(host: &str) -> bool {
+    let Ok(client) = reqwest::Client::builder()
+        .redirect(reqwest::redirect::Policy::none())
+        .timeout(STEP_TIMEOUT)
+        .build()
+    else {
+        return false;
+    };
+    let Ok(resp) = client.get(format!("http://{host}/")).send().await else {
+        return false;
+    };
+    resp.status().is_redirection()
+        && resp
+            .headers()
+            .get(reqwest::header::LOCATION)
+            .and_then(|l| l.to_str().ok())
+            .is_some_and(|location| is_foreign_redirect(host, location))
+}
+
+/// Whether `location` points to another host than `host` (a same-host
+/// redirect, e.g. to HTTPS, is normal).
+fn is_foreign_redirect(host: &str, location: &str) -> bool {
+    match reqwest::Url::parse(location) {
+        Ok(url) => url
+            .host_str()
+            .is_some_and(|h| !h.eq_ignore_ascii_case(host)),
+        // Relative redirect: same host.
+        Err(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn redirects_to_other_hosts_look_like_captive_portals() {
+        assert!(!is_foreign_redirect(
+            "meet.example.com",
+            "https://meet.example.com/"
+        ));
+        assert!(!is_foreign_redirect("meet.example.com", "/login"));
+        assert!(is_foreign_redirect(
+            "meet.example.com",
+            "http://portal.hotel.example/login?url=meet.example.com"
+        ));
+    }
+
+    #[test]
+    fn loopback_only_matters_for_public_names() {
+        let loopback = [
+            "127.0.0.1:443".parse().unwrap(),
+            "[::1]:443".parse().unwrap(),
+        ];
+        assert!(unexpected_loopback("meet.example.com", &loopback));
+        assert!(!unexpected_loopback("localhost", &loopback));
+        assert!(!unexpected_loopback("127.0.0.1", &loopback[..1]));
+    }
+
+    #[test]
+    fn ipv6_failure_alone_is_not_a_problem() {
+        let mut diagnosis = ConnectivityDiagnosis::new("lk.example.com".into(), 443);
+        diagnosis.dns = CheckResult::Passed;
+        diagnosis.tcp_ipv4 = CheckResult::Passed;
+        diagnosis.tcp_ipv6 = CheckResult::Failed("network unreachable".into());
+        diagnosis.tls = CheckResult::Passed;
+        diagnosis.websocket = websocket_result(401);
+        assert!(!diagnosis.has_problem());
+
+        diagnosis.websocket = websocket_result(200);
+        assert!(diagnosis.has_problem());
+        assert!(
+            diagnosis
+                .to_string()
+                .contains("WebSocket failed (unexpected HTTP status 200)")
+        );
+    }
+
+    #[tokio::test]
+    async fn unresolvable_host_fails_at_dns() {
+        let diagnosis = diagnose("wss://visio-diagnosis.invalid").await;
+        assert_eq!(diagnosis.port, 443);
+        assert!(diagnosis.dns.is_failed(), "{diagnosis}");
+        assert_eq!(diagnosis.tls, CheckResult::Skipped);
+        assert!(diagnosis.has_problem());
+    }
+}
@@ -1,5 +1,11 @@
 use thiserror::Error;
 
+use crate::device_permissions::DevicePermission;
+use crate::diagnostics::ConnectivityDiagnosis;
+use crate::events::TrackSource;
+use crate::i18n;
+use crate::permissions;
+
 #[derive(Debug, Error)]
 pub enum VisioError {
     #[error("connection failed: {0}")]
@@ -14,4 +20,136 @@ pub enum VisioError {
     Http(String),
     #[error("invalid URL: {0}")]
     InvalidUrl(String),
+    #[error("timed out: {0}")]
+    Timeout(String),
+    /// The user denied an operating system permission the operation needs.
+    #[error("{0} permission denied")]
+    PermissionDenied(DevicePermission),
+    /// The participant's token grants do not allow publishing from this
+    /// source (see `LocalPermissions`).
+    #[error("not allowed to publish {}", publish_grant(.0))]
+    PublishNotAllowed(TrackSource),
+    /// A chat message is longer than the room's limit (see
+    /// `RoomManager::set_max_chat_message_bytes`).
+    #[error("message too large: {size} bytes (max {max})")]
+    MessageTooLarge { size: usize, max: usize },
+    /// Connecting failed and the connectivity diagnosis run afterwards
+    /// found where the network path breaks.
+    #[error("connection failed: {message} ({diagnosis})")]
+    Unreachable {
+        message: String,
+        diagnosis: Box<ConnectivityDiagnosis>,
+    },
+}
+
+impl VisioError {
+    /// Stable identifier of the kind of error, for logs and shell logic.
+    pub fn code(&self) -> &'static str {
+        match self {
+            VisioError::Connection(_) => "connection",
+            VisioError::Room(_) => "room",
+            VisioError::Auth(_) => "auth",
+            VisioError::AuthRequired => "auth_required",
+            VisioError::Http(_) => "http",
+            VisioError::InvalidUrl(_) => "invalid_url",
+            VisioError::Timeout(_) => "timeout",
+            VisioError::PermissionDenied(_) => "permission_denied",
+            VisioError::PublishNotAllowed(_) => "publish_not_allowed",
+            VisioError::MessageTooLarge { .. } => "message_too_large",
+            VisioError::Unreachable { .. } => "unreachable",
+        }
+    }
+
+    /// Key of a message fit for users in the i18n catalogs.
+    pub fn user_message_key(&self) -> &'static str {
+        match self {
+            VisioError::Connection(_) => "error.connection",
+            VisioError::Room(_) => "error.room",
+            VisioError::Auth(_) => "error.auth",
+            VisioError::AuthRequired => "error.authRequired",
+            VisioError::Http(_) => "error.http",
+            VisioError::InvalidUrl(_) => "error.invalidUrl",
+            VisioError::Timeout(_) => "error.timeout",
+            VisioError::PermissionDenied(_) => "error.permissionDenied",
+            VisioError::PublishNotAllowed(_) => "error.publishNotAllowed",
+            VisioError::MessageTooLarge { .. } => "error.messageTooLarge",
+            VisioError::Unreachable { .. } => "error.unreachable",
+        }
+    }
+
+    /// Message for users in `language`.
+    pub fn user_message(&self, language: &str) -> String {
+        i18n::translate(language, self.user_message_key(), &[])
+    }
+
+    /// Technical detail (server responses, internal state) to log rather
+    /// than show.
+    pub fn developer_detail(&self) -> Option<String> {
+        match self {
+            VisioError::Connection(detail)
+            | VisioError::Room(detail)
+            | VisioError::Auth(detail)
+            | VisioError::Http(detail)
+            | VisioError::InvalidUrl(detail)
+            | VisioError::Timeout(detail) => Some(detail.clone()),
+            VisioError::AuthRequired => None,
+            VisioError::PermissionDenied(permission) => Some(permission.to_string()),
+            VisioError::PublishNotAllowed(source) => Some(publish_grant(source).to_string()),
+            VisioError::MessageTooLarge { size, max } => Some(format!("{size} bytes (max {max})")),
+            VisioError::Unreachable { message, diagnosis } => {
+                Some(format!("{message} ({diagnosis})"))
+            }
+        }
+    }
+}
+
+/// Missing grant behind `PublishNotAllowed`.
+fn publish_grant(source: &TrackSource) -> &'static str {
+    permissions::grant_name(source).unwrap_or("tracks")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_user_message_from_detail() {
+        let err = VisioError::Http("502 <html>Bad Gateway</html>".into());
+        assert_eq!(err.code(), "http");
+        assert_eq!(err.user_message("fr"), "Impossible de joindre le serveur.");
+        assert_eq!(
+            err.developer_detail().as_deref(),
+            Some("502 <html>Bad Gateway</html>")
+        );
+        assert_eq!(VisioError::AuthRequired.developer_detail(), None);
+        assert_eq!(
+            VisioError::AuthRequired.user_message("en"),
+            "You need to sign in to do this."
+        );
+    }
+
+    #[test]
+    fn publish_not_allowed_names_the_grant() {
+        let err = VisioError::PublishNotAllowed(TrackSource::Camera);
+        assert_eq!(err.code(), "publish_not_allowed");
+        assert_eq!(err.to_string(), "not allowed to publish camera");
+        assert_eq!(err.developer_detail().as_deref(), Some("camera"));
+    }
+
+    #[test]
+    fn message_too_large_reports_both_sizes() {
+        let err = VisioError::MessageTooLarge {
+            size: 70_000,
+            max: 65_536,
+        };
+        assert_eq!(err.code(), "message_too_large");
+        assert_eq!(
+            err.to_string(),
+            "message too large: 70000 bytes (max 65536)"
+        );
+        assert_eq!(
+            err.developer_detail().as_deref(),
+            Some("70000 bytes (max 65536)")
+        );
+    }
 }
@@ -1,5 +1,22 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 
+use serde::Serialize;
+
+use crate::call_summary::CallSummary;
+use crate::chat_unread::ChatUnread;
+use crate::controls::CaptureConstraints;
+use crate::device_permissions::{DevicePermission, PermissionState};
+use crate::features::FeatureFlags;
+use crate::media_buttons::MediaButton;
+use crate::permissions::LocalPermissions;
+use crate::polls::Poll;
+use crate::power::{PowerMode, QualityReductionReason};
+use crate::presence::Presence;
+use crate::recording::RecordingStatus;
+use crate::restream::RestreamStatus;
+use crate::system_call::SystemCall;
+
 /// Events emitted by the core to native UI listeners.
 #[derive(Debug, Clone)]
 pub enum VisioEvent {
@@ -28,6 +45,12 @@ pub enum VisioEvent {
         position: u32,
     },
     UnreadCountChanged(u32),
+    /// Unread messages by kind, or the first unread message, changed (see
+    /// `ChatService::unread()`).
+    ChatUnreadChanged(ChatUnread),
+    /// The local hand was lowered automatically because the participant
+    /// kept speaking (follows the corresponding `HandRaisedChanged`).
+    HandAutoLowered,
     /// A participant sent an animated reaction (emoji).
     ReactionReceived {
         participant_sid: String,
@@ -36,6 +59,181 @@ pub enum VisioEvent {
     },
     /// Connection lost unexpectedly — native UI should call reconnect().
     ConnectionLost,
+    /// `switch_room()` swapped rooms: state of the previous room (chat,
+    /// participants, tracks) has been cleared and the new room is live.
+    RoomSwitched {
+        meet_url: String,
+    },
+    /// Remote participant SIDs in display order (see `LayoutService`).
+    LayoutOrderChanged(Vec<String>),
+    /// Coalesced participant joins/leaves (see `JoinLeaveConfig`). In
+    /// count-only mode `joined` and `left` are empty.
+    ParticipantBatchUpdate {
+        joined: Vec<ParticipantInfo>,
+        left: Vec<String>,
+        joined_count: u32,
+        left_count: u32,
+    },
+    /// A step of `connect()` / `switch_room()` completed.
+    ConnectProgress(ConnectStep),
+    /// An unmuted remote video track stopped delivering frames; a
+    /// resubscribe is attempted (see `TrackRecovery`).
+    TrackStalled {
+        track_sid: String,
+    },
+    /// Frames arrived again on a track reported by `TrackStalled`.
+    TrackResumed {
+        track_sid: String,
+    },
+    /// Local participant permissions were set on connect or updated by
+    /// the server (see `RoomManager::local_permissions()`).
+    PermissionsChanged(LocalPermissions),
+    /// The room is restricted: an entry request was sent and `connect()`
+    /// waits for the host to answer.
+    WaitingForHost,
+    /// The host accepted the entry request; the connection proceeds.
+    EntryGranted,
+    /// The host refused the entry request; `connect()` fails.
+    EntryDenied,
+    /// The current room was locked (`true`) or unlocked (see
+    /// `ModerationService::lock_room`).
+    RoomLockedChanged(bool),
+    /// Features of the Meet instance were read after connecting (see
+    /// `RoomManager::feature_flags()`).
+    FeatureFlagsChanged(FeatureFlags),
+    /// The cloud recording of the room started, stopped or is changing
+    /// state (see `RecordingService`).
+    RecordingStatusChanged(RecordingStatus),
+    /// A local recording grew (about once per second) or was finalized
+    /// (see `LocalRecorder`).
+    LocalRecordingProgress {
+        duration_ms: u64,
+        size_bytes: u64,
+    },
+    /// A restream started, went live, failed or stopped (see
+    /// `Restreamer`).
+    RestreamStatusChanged(RestreamStatus),
+    /// A microphone or camera track was published; its source, identified
+    /// by `handle`, now receives the frames pushed by platform capture
+    /// (see `capture_sources()`).
+    SourceReady {
+        source: TrackSource,
+        handle: u64,
+    },
+    /// The target for platform camera capture changed, after a local
+    /// connection quality change or `MeetingControls::set_low_light_boost`.
+    CaptureConstraintsChanged(CaptureConstraints),
+    /// A data message arrived on a topic subscribed with
+    /// `DataChannelService::subscribe`.
+    DataMessageReceived {
+        topic: String,
+        sender_sid: String,
+        payload: Vec<u8>,
+    },
+    /// A poll was started, locally or by another participant (see
+    /// `PollsService`).
+    PollStarted(Poll),
+    /// Votes of a running poll changed.
+    PollUpdated(Poll),
+    /// The creator of a poll closed it.
+    PollEnded(Poll),
+    /// The instance offers a collaborative whiteboard for the room just
+    /// joined; open it with `RoomManager::whiteboard_session()`.
+    WhiteboardAvailable,
+    /// `RoomManager::set_power_mode` switched to this mode.
+    PowerModeChanged(PowerMode),
+    /// Video quality was lowered for `reason`; `constraints` is the new
+    /// capture target.
+    QualityReduced {
+        reason: QualityReductionReason,
+        constraints: CaptureConstraints,
+    },
+    /// The cause of the last `QualityReduced` went away.
+    QualityRestored,
+    /// The shell reported a new state for an operating system permission.
+    DevicePermissionChanged {
+        permission: DevicePermission,
+        state: PermissionState,
+    },
+    /// A `SettingsStore` setter changed these settings keys (serialized
+    /// field names, e.g. `language`).
+    SettingsChanged {
+        keys: Vec<String>,
+    },
+    /// The call reported with `RoomManager::report_call` started,
+    /// connected or ended, for the shells to update CallKit / ConnectionService.
+    SystemCallChanged(SystemCall),
+    /// A headset button press was handled (see
+    /// `RoomManager::handle_media_button`); shells sync their capture or
+    /// leave the call screen.
+    MediaButtonHandled(MediaButton),
+    /// The meeting has a scheduled end and reached a warning threshold
+    /// (see `meeting_timer::WARNING_MINUTES`).
+    MeetingEndingSoon {
+        minutes_left: u32,
+    },
+    /// The scheduled end of the meeting was reached; the server is closing
+    /// the room.
+    MeetingEnded,
+    /// Every other participant left `minutes` ago (see `IdleMonitor`).
+    /// With `auto_leave`, shells leave the room as for a hang-up.
+    AloneInRoom {
+        minutes: u32,
+        auto_leave: bool,
+    },
+    /// No one spoke and no track was published for `minutes`; shells ask
+    /// whether the user is still there (`RoomManager::report_activity`).
+    InactivityDetected {
+        minutes: u32,
+    },
+    /// The tile shown in speaker view changed (see `SpotlightService`).
+    /// `track_sid` is `None` when the participant has no video.
+    SpotlightChanged {
+        participant_sid: Option<String>,
+        track_sid: Option<String>,
+    },
+    /// A participant, local or remote, changed presence (see
+    /// `PresenceService`).
+    PresenceChanged {
+        participant_sid: String,
+        presence: Presence,
+    },
+    /// App-level attributes of a participant changed (see
+    /// `AttributesService`). An empty value means the key was removed.
+    ParticipantAttributesChanged {
+        participant_sid: String,
+        changed: HashMap<String, String>,
+    },
+    /// The bad-network fallback (see `QualityPolicy`) started (`active`)
+    /// or ended. `camera` and `remote_video` tell what it turned off, or
+    /// back on.
+    QualityPolicyTriggered {
+        active: bool,
+        camera: bool,
+        remote_video: bool,
+    },
+    /// A call ended; the summary is also kept as
+    /// `CallSummaryTracker::last_summary` until the next call ends.
+    CallEnded(CallSummary),
+    /// The outgoing video was hidden or shown again without unpublishing
+    /// it (see `MeetingControls::set_video_privacy`).
+    VideoPrivacyChanged {
+        enabled: bool,
+    },
+}
+
+/// Milestones of a connection attempt, in the order they are reached.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConnectStep {
+    /// The Meet API token request was sent.
+    TokenRequested,
+    /// The Meet API returned LiveKit credentials.
+    TokenReceived,
+    /// The LiveKit signalling session was joined and the primary
+    /// transport negotiated.
+    SignalConnected,
+    /// Room state is seeded and remote media can be received.
+    MediaConnected,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -55,9 +253,10 @@ pub struct ParticipantInfo {
     pub has_video: bool,
     pub video_track_sid: Option<String>,
     pub connection_quality: ConnectionQuality,
+    pub presence: Presence,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub enum ConnectionQuality {
     Excellent,
     Good,
@@ -84,16 +283,35 @@ pub enum TrackSource {
     Microphone,
     Camera,
     ScreenShare,
+    /// System audio published with a screen share.
+    ScreenShareAudio,
     Unknown,
 }
 
 #[derive(Debug, Clone)]
 pub struct ChatMessage {
     pub id: String,
+    /// For system messages, the participant the event is about (empty for
+    /// room events).
     pub sender_sid: String,
     pub sender_name: String,
+    /// For system messages, a localized description of the event.
     pub text: String,
     pub timestamp_ms: u64,
+    pub kind: ChatMessageKind,
+}
+
+/// Message typed by a participant, or system message generated by the
+/// core for a meeting event (see `SystemMessages`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ChatMessageKind {
+    #[default]
+    Text,
+    ParticipantJoined,
+    ParticipantLeft,
+    RecordingStarted,
+    HandRaised,
 }
 
 /// Trait for receiving events from the core.
@@ -130,10 +348,12 @@ impl EventEmitter {
     }
 
     pub fn emit(&self, event: VisioEvent) {
+        // Snapshot so listeners may emit follow-up events (LayoutService).
         let listeners = self
             .listeners
             .read()
-            .unwrap_or_else(|poisoned| poisoned.into_inner());
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone();
         for listener in listeners.iter() {
             listener.on_event(event.clone());
         }
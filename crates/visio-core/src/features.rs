@@ -0,0 +1,167 @@
+//! Features enabled by the Meet instance.
+//!
+//! La Suite Meet deployments differ in what they offer (recording,
+//! transcription, dial-in, whiteboard...). The instance configuration
+//! (`GET /api/v1.0/config/`) is read at connect time so UIs can hide the
+//! buttons of unsupported features. Chat and reactions are on unless the
+//! instance explicitly disables them.
+
+use serde::Deserialize;
+
+use crate::meet_api::MeetApi;
+
+/// What the current Meet instance supports. Read it with
+/// `RoomManager::feature_flags()`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeatureFlags {
+    pub chat: bool,
+    pub reactions: bool,
+    pub recording: bool,
+    pub transcription: bool,
+    pub dial_in: bool,
+    pub subtitles: bool,
+    /// Collaborative whiteboard, opened with `RoomManager::whiteboard_session()`.
+    pub whiteboard: bool,
+    /// Text the instance requires on outgoing video (see `VideoOverlay`).
+    pub watermark: Option<String>,
+}
+
+impl Default for FeatureFlags {
+    fn default() -> Self {
+        Self {
+            chat: true,
+            reactions: true,
+            recording: false,
+            transcription: false,
+            dial_in: false,
+            subtitles: false,
+            whiteboard: false,
+            watermark: None,
+        }
+    }
+}
+
+/// Subset of the instance configuration describing features.
+#[derive(Debug, Default, Deserialize)]
+struct FeaturesConfig {
+    #[serde(default)]
+    chat: Option<Toggle>,
+    #[serde(default)]
+    reactions: Option<Toggle>,
+    #[serde(default)]
+    recording: Option<RecordingConfig>,
+    #[serde(default)]
+    telephony: Option<Toggle>,
+    #[serde(default)]
+    subtitle: Option<Toggle>,
+    #[serde(default)]
+    whiteboard: Option<Toggle>,
+    #[serde(default)]
+    watermark: Option<WatermarkConfig>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct Toggle {
+    #[serde(default)]
+    enabled: bool,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct WatermarkConfig {
+    #[serde(default)]
+    enabled: bool,
+    #[serde(default)]
+    text: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct RecordingConfig {
+    #[serde(default)]
+    is_enabled: bool,
+    #[serde(default)]
+    available_modes: Vec<String>,
+}
+
+impl FeatureFlags {
+    /// Flags described by an instance configuration document.
+    pub fn from_config(json: &str) -> Result<Self, serde_json::Error> {
+        let config: FeaturesConfig = serde_json::from_str(json)?;
+        let recording = config.recording.unwrap_or_default();
+        let has_mode = |mode: &str| {
+            recording.is_enabled && recording.available_modes.iter().any(|m| m == mode)
+        };
+        Ok(Self {
+            chat: config.chat.is_none_or(|t| t.enabled),
+            reactions: config.reactions.is_none_or(|t| t.enabled),
+            recording: has_mode("screen_recording"),
+            transcription: has_mode("transcript"),
+            dial_in: config.telephony.is_some_and(|t| t.enabled),
+            subtitles: config.subtitle.is_some_and(|t| t.enabled),
+            whiteboard: config.whiteboard.is_some_and(|t| t.enabled),
+            watermark: config
+                .watermark
+                .filter(|w| w.enabled && !w.text.trim().is_empty())
+                .map(|w| w.text.trim().to_string()),
+        })
+    }
+
+    /// Fetch the flags of `instance`. Falls back to the defaults when the
+    /// configuration cannot be read.
+    pub async fn fetch_with(api: &dyn MeetApi, instance: &str) -> Self {
+        let body = match api.get_config(instance).await {
+            Ok(resp) if (200..300).contains(&resp.status) => resp.body,
+            Ok(resp) => {
+                tracing::warn!("Meet config returned status {}", resp.status);
+                return Self::default();
+            }
+            Err(e) => {
+                tracing::warn!("cannot fetch Meet config: {e}");
+                return Self::default();
+            }
+        };
+        Self::from_config(&body).unwrap_or_else(|e| {
+            tracing::warn!("invalid Meet config: {e}");
+            Self::default()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_features_from_instance_config() {
+        let flags = FeatureFlags::from_config(
+            r#"{
+                "recording": {"is_enabled": true, "available_modes": ["transcript"]},
+                "telephony": {"enabled": true, "phone_number": "+33 1 23 45 67 89"},
+                "subtitle": {"enabled": false},
+                "reactions": {"enabled": false},
+                "whiteboard": {"enabled": true},
+                "watermark": {"enabled": true, "text": " Internal use only "},
+                "analytics": {}
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            flags,
+            FeatureFlags {
+                chat: true,
+                reactions: false,
+                recording: false,
+                transcription: true,
+                dial_in: true,
+                subtitles: false,
+                whiteboard: true,
+                watermark: Some("Internal use only".into()),
+            }
+        );
+
+        assert_eq!(
+            FeatureFlags::from_config("{}").unwrap(),
+            FeatureFlags::default()
+        );
+        assert!(FeatureFlags::from_config("not json").is_err());
+    }
+}
@@ -0,0 +1,185 @@
+//! Call feedback for the "rate your call" screen.
+//!
+//! `FeedbackService::submit_feedback` sends the user's rating of the last
+//! call to its Meet instance. With the `feedback_diagnostics_enabled`
+//! setting on, the `CallSummary` of the call goes along, and the event
+//! journal too when the user asked to attach logs. With it off, only the
+//! rating and comments leave the device.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use tokio::sync::Mutex;
+
+use crate::auth::AuthService;
+use crate::call_summary::CallSummaryTracker;
+use crate::errors::VisioError;
+use crate::journal::EventJournal;
+use crate::meet_api::{FeedbackRequest, MeetApi};
+use crate::settings::Settings;
+
+/// Sends call ratings, shared with `RoomManager` (see
+/// `RoomManager::feedback()`).
+#[derive(Clone)]
+pub struct FeedbackService {
+    meet_api: Arc<dyn MeetApi>,
+    session_cookie: Arc<Mutex<Option<String>>>,
+    journal: EventJournal,
+    call_summary: CallSummaryTracker,
+    /// Instance of the last call, kept after leaving it.
+    instance: Arc<std::sync::Mutex<Option<String>>>,
+    diagnostics_enabled: Arc<AtomicBool>,
+}
+
+impl FeedbackService {
+    pub(crate) fn new(
+        meet_api: Arc<dyn MeetApi>,
+        session_cookie: Arc<Mutex<Option<String>>>,
+        journal: EventJournal,
+        call_summary: CallSummaryTracker,
+    ) -> Self {
+        Self {
+            meet_api,
+            session_cookie,
+            journal,
+            call_summary,
+            instance: Arc::new(std::sync::Mutex::new(None)),
+            diagnostics_enabled: Arc::new(AtomicBool::new(true)),
+        }
+    }
+
+    /// Follow the `feedback_diagnostics_enabled` privacy setting.
+    pub fn apply_settings(&self, settings: &Settings) {
+        self.diagnostics_enabled
+            .store(settings.feedback_diagnostics_enabled, Ordering::Relaxed);
+    }
+
+    /// A call to `meet_url` started: its instance receives the feedback.
+    pub(crate) fn remember_call(&self, meet_url: &str) {
+        if let Ok(instance) = AuthService::parse_instance(meet_url) {
+            *self.instance.lock().unwrap_or_else(|e| e.into_inner()) = Some(instance);
+        }
+    }
+
+    /// Rate the last call from 1 (bad) to 5 (excellent). `attach_logs`
+    /// adds the event journal, if the privacy settings allow it.
+    ///
+    /// Fails with `VisioError::Room` for a rating out of range, before any
+    /// call, or if the instance does not accept feedback.
+    pub async fn submit_feedback(
+        &self,
+        rating: u8,
+        comments: Option<String>,
+        attach_logs: bool,
+    ) -> Result<(), VisioError> {
+        if !(1..=5).contains(&rating) {
+            return Err(VisioError::Room(format!(
+                "rating must be between 1 and 5, got {rating}"
+            )));
+        }
+        let instance = self
+            .instance
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+            .ok_or_else(|| VisioError::Room("no call to rate".into()))?;
+        let diagnostics = self.diagnostics_enabled.load(Ordering::Relaxed);
+        let request = FeedbackRequest {
+            instance,
+            rating,
+            comments: comments
+                .map(|c| c.trim().to_string())
+                .filter(|c| !c.is_empty()),
+            call_summary: self
+                .call_summary
+                .last_summary()
+                .filter(|_| diagnostics)
+                .and_then(|summary| serde_json::to_value(summary).ok()),
+            logs: (diagnostics && attach_logs).then(|| self.journal.export()),
+            session_cookie: self.session_cookie.lock().await.clone(),
+        };
+        let resp = self.meet_api.submit_feedback(&request).await?;
+        match resp.status {
+            200..=299 => Ok(()),
+            401 | 403 => Err(VisioError::AuthRequired),
+            404 | 405 => Err(VisioError::Room(
+                "feedback is not supported by this instance".into(),
+            )),
+            status => Err(VisioError::Room(format!(
+                "feedback request failed with status {status}"
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::MockClock;
+    use crate::events::{ConnectionState, EventEmitter, VisioEvent};
+    use crate::meet_api::MockMeetApi;
+
+    fn service(api: Arc<MockMeetApi>) -> FeedbackService {
+        let clock = Arc::new(MockClock::new());
+        let emitter = EventEmitter::new();
+        let journal = EventJournal::new(clock.clone());
+        emitter.add_listener(Arc::new(journal.clone()));
+        let call_summary = CallSummaryTracker::new(emitter.clone(), clock);
+        emitter.add_listener(Arc::new(call_summary.clone()));
+        emitter.emit(VisioEvent::ConnectionStateChanged(
+            ConnectionState::Connected,
+        ));
+        emitter.emit(VisioEvent::ConnectionStateChanged(
+            ConnectionState::Disconnected,
+        ));
+
+        let service = FeedbackService::new(
+            api,
+            Arc::new(Mutex::new(Some("cookie".into()))),
+            journal,
+            call_summary,
+        );
+        service.remember_call("https://meet.example.com/abc-defg-hij");
+        service
+    }
+
+    #[tokio::test]
+    async fn attaches_diagnostics_when_allowed() {
+        let api = Arc::new(MockMeetApi::new());
+        api.push_response(201, "{}");
+        api.push_response(201, "{}");
+        let service = service(api.clone());
+
+        service
+            .submit_feedback(4, Some(" choppy audio ".into()), true)
+            .await
+            .unwrap();
+        service.apply_settings(&Settings {
+            feedback_diagnostics_enabled: false,
+            ..Settings::default()
+        });
+        service.submit_feedback(2, None, true).await.unwrap();
+
+        let requests = api.feedback_requests();
+        assert_eq!(requests[0].instance, "meet.example.com");
+        assert_eq!(requests[0].comments.as_deref(), Some("choppy audio"));
+        let summary = requests[0].call_summary.as_ref().unwrap();
+        assert_eq!(summary["peak_participants"], 1);
+        assert!(requests[0].logs.as_ref().unwrap().contains("Disconnected"));
+        assert_eq!(requests[1].session_cookie.as_deref(), Some("cookie"));
+        assert!(requests[1].call_summary.is_none() && requests[1].logs.is_none());
+    }
+
+    #[tokio::test]
+    async fn rejects_bad_ratings_and_unsupported_instances() {
+        let api = Arc::new(MockMeetApi::new());
+        api.push_response(404, "");
+        let service = service(api.clone());
+
+        assert!(service.submit_feedback(0, None, false).await.is_err());
+        assert!(service.submit_feedback(6, None, false).await.is_err());
+        assert!(api.feedback_requests().is_empty());
+        let err = service.submit_feedback(5, None, false).await.unwrap_err();
+        assert!(err.to_string().contains("not supported"));
+    }
+}
@@ -0,0 +1,324 @@
+//! Conversion of frames pushed by external capture backends to I420, and
+//! scaling of I420 frames for the outputs that need a fixed size.
+//!
+//! The platform-agnostic frame push API (`visio_push_video_frame`) accepts
+//! I420, NV12 and RGBA frames; WebRTC sources take I420. RGBA is converted
+//! with BT.601 full-range coefficients, like the background blur pipeline.
+
+/// Pixel layout of a pushed video frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PixelFormat {
+    /// Planar 4:2:0: Y, U and V planes.
+    I420,
+    /// Semi-planar 4:2:0: a Y plane and an interleaved UV plane.
+    Nv12,
+    /// Packed 8-bit R, G, B, A in a single plane.
+    Rgba,
+}
+
+impl PixelFormat {
+    /// Bytes each plane of a frame `height` rows high must hold with
+    /// `strides` (0 for unused planes), or `None` if a length overflows.
+    pub fn plane_lens(self, height: u32, strides: [u32; 3]) -> Option<[usize; 3]> {
+        let rows = height as usize;
+        let chroma_rows = rows.div_ceil(2);
+        let [s0, s1, s2] = strides.map(|s| s as usize);
+        Some(match self {
+            PixelFormat::I420 => [
+                s0.checked_mul(rows)?,
+                s1.checked_mul(chroma_rows)?,
+                s2.checked_mul(chroma_rows)?,
+            ],
+            PixelFormat::Nv12 => [s0.checked_mul(rows)?, s1.checked_mul(chroma_rows)?, 0],
+            PixelFormat::Rgba => [s0.checked_mul(rows)?, 0, 0],
+        })
+    }
+
+    /// Minimum stride of each plane for a frame `width` pixels wide, or
+    /// `None` if it overflows.
+    fn min_strides(self, width: u32) -> Option<[u32; 3]> {
+        let chroma = width.div_ceil(2);
+        Some(match self {
+            PixelFormat::I420 => [width, chroma, chroma],
+            PixelFormat::Nv12 => [width, chroma.checked_mul(2)?, 0],
+            PixelFormat::Rgba => [width.checked_mul(4)?, 0, 0],
+        })
+    }
+}
+
+/// A captured frame borrowed from the capture backend.
+#[derive(Debug, Clone, Copy)]
+pub struct RawVideoFrame<'a> {
+    pub format: PixelFormat,
+    pub width: u32,
+    pub height: u32,
+    /// Plane data, laid out as described by `PixelFormat::plane_lens`.
+    pub planes: [&'a [u8]; 3],
+    pub strides: [u32; 3],
+}
+
+/// A tightly packed I420 frame.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct I420Frame {
+    pub width: u32,
+    pub height: u32,
+    pub y: Vec<u8>,
+    pub u: Vec<u8>,
+    pub v: Vec<u8>,
+}
+
+impl I420Frame {
+    pub fn chroma_width(&self) -> u32 {
+        self.width.div_ceil(2)
+    }
+
+    pub fn chroma_height(&self) -> u32 {
+        self.height.div_ceil(2)
+    }
+}
+
+/// Convert `frame` to I420, or `None` if its size, strides or planes are
+/// inconsistent.
+pub fn to_i420(frame: &RawVideoFrame<'_>) -> Option<I420Frame> {
+    if frame.width == 0 || frame.height == 0 {
+        return None;
+    }
+    let min_strides = frame.format.min_strides(frame.width)?;
+    let lens = frame.format.plane_lens(frame.height, frame.strides)?;
+    for i in 0..3 {
+        if frame.strides[i] < min_strides[i] || frame.planes[i].len() < lens[i] {
+            return None;
+        }
+    }
+
+    let w = frame.width as usize;
+    let h = frame.height as usize;
+    let cw = w.div_ceil(2);
+    let ch = h.div_ceil(2);
+    let [s0, s1, s2] = frame.strides.map(|s| s as usize);
+    let [p0, p1, p2] = frame.planes;
+    let mut out = I420Frame {
+        width: frame.width,
+        height: frame.height,
+        y: vec![0; w * h],
+        u: vec![0; cw * ch],
+        v: vec![0; cw * ch],
+    };
+
+    match frame.format {
+        PixelFormat::I420 => {
+            copy_plane(p0, s0, &mut out.y, w, h);
+            copy_plane(p1, s1, &mut out.u, cw, ch);
+            copy_plane(p2, s2, &mut out.v, cw, ch);
+        }
+        PixelFormat::Nv12 => nv12_to_i420(
+            [p0, p1],
+            [s0, s1],
+            (w, h),
+            [&mut out.y, &mut out.u, &mut out.v],
+            [w, cw, cw],
+        ),
+        PixelFormat::Rgba => {
+            for row in 0..h {
+                for col in 0..w {
+                    let px = row * s0 + col * 4;
+                    let (r, g, b) = (p0[px] as f32, p0[px + 1] as f32, p0[px + 2] as f32);
+                    out.y[row * w + col] =
+                        (0.299 * r + 0.587 * g + 0.114 * b).clamp(0.0, 255.0) as u8;
+                    // Chroma from the top-left pixel of each 2x2 block.
+                    if row % 2 == 0 && col % 2 == 0 {
+                        let idx = (row / 2) * cw + col / 2;
+                        out.u[idx] =
+                            (-0.169 * r - 0.331 * g + 0.500 * b + 128.0).clamp(0.0, 255.0) as u8;
+                        out.v[idx] =
+                            (0.500 * r - 0.419 * g - 0.081 * b + 128.0).clamp(0.0, 255.0) as u8;
+                    }
+                }
+            }
+        }
+    }
+    Some(out)
+}
+
+/// Convert NV12 planes of `size` to I420 planes laid out with
+/// `out_strides`. The planes must hold the whole frame.
+pub fn nv12_to_i420(
+    planes: [&[u8]; 2],
+    strides: [usize; 2],
+    size: (usize, usize),
+    out: [&mut [u8]; 3],
+    out_strides: [usize; 3],
+) {
+    let (w, h) = size;
+    let (cw, ch) = (w.div_ceil(2), h.div_ceil(2));
+    let [y, uv] = planes;
+    let [y_out, u_out, v_out] = out;
+    for row in 0..h {
+        let dst = row * out_strides[0];
+        y_out[dst..dst + w].copy_from_slice(&y[row * strides[0]..row * strides[0] + w]);
+    }
+    for row in 0..ch {
+        let src = &uv[row * strides[1]..row * strides[1] + cw * 2];
+        let (u_row, v_row) = (row * out_strides[1], row * out_strides[2]);
+        for (col, pair) in src.chunks_exact(2).enumerate() {
+            u_out[u_row + col] = pair[0];
+            v_out[v_row + col] = pair[1];
+        }
+    }
+}
+
+/// Scale an I420 frame of `src_size` into a packed `dst_size` I420 frame
+/// in `out`, keeping its aspect ratio: the remaining area is black.
+/// Nearest-neighbour, which is enough for a preview-grade feed.
+pub fn scale_i420(
+    planes: [&[u8]; 3],
+    strides: [usize; 3],
+    src_size: (usize, usize),
+    dst_size: (usize, usize),
+    out: &mut Vec<u8>,
+) {
+    let (sw, sh) = src_size;
+    let (dw, dh) = dst_size;
+    let (dcw, dch) = (dw.div_ceil(2), dh.div_ceil(2));
+    out.clear();
+    out.resize(dw * dh, 0);
+    out.resize(dw * dh + 2 * dcw * dch, 128);
+    if sw == 0 || sh == 0 {
+        return;
+    }
+
+    // Largest even-sized rectangle with the source aspect ratio.
+    let (fw, fh) = if sw * dh > sh * dw {
+        (dw, (dw * sh / sw) & !1)
+    } else {
+        ((dh * sw / sh) & !1, dh)
+    };
+    let (x0, y0) = (((dw - fw) / 2) & !1, ((dh - fh) / 2) & !1);
+
+    let (y_out, chroma) = out.split_at_mut(dw * dh);
+    let (u_out, v_out) = chroma.split_at_mut(dcw * dch);
+    let copy = |src: &[u8], stride: usize, dst: &mut [u8], div: usize| {
+        let (src_w, src_h) = (sw.div_ceil(div), sh.div_ceil(div));
+        let (fit_w, fit_h) = (fw / div, fh / div);
+        let dst_w = dw.div_ceil(div);
+        for row in 0..fit_h {
+            let src_row = (row * src_h / fit_h).min(src_h - 1) * stride;
+            let dst_row = (y0 / div + row) * dst_w + x0 / div;
+            for col in 0..fit_w {
+                let src_col = (col * src_w / fit_w).min(src_w - 1);
+                dst[dst_row + col] = src[src_row + src_col];
+            }
+        }
+    };
+    copy(planes[0], strides[0], y_out, 1);
+    copy(planes[1], strides[1], u_out, 2);
+    copy(planes[2], strides[2], v_out, 2);
+}
+
+fn copy_plane(src: &[u8], stride: usize, dst: &mut [u8], width: usize, rows: usize) {
+    for row in 0..rows {
+        dst[row * width..(row + 1) * width]
+            .copy_from_slice(&src[row * stride..row * stride + width]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nv12_with_padding_is_deinterleaved() {
+        // 4x2 frame, rows padded to 6 bytes.
+        let y = [1, 2, 3, 4, 0, 0, 5, 6, 7, 8, 0, 0];
+        let uv = [10, 20, 11, 21, 0, 0];
+        let frame = RawVideoFrame {
+            format: PixelFormat::Nv12,
+            width: 4,
+            height: 2,
+            planes: [&y, &uv, &[]],
+            strides: [6, 6, 0],
+        };
+
+        let i420 = to_i420(&frame).unwrap();
+        assert_eq!(i420.y, [1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(i420.u, [10, 11]);
+        assert_eq!(i420.v, [20, 21]);
+    }
+
+    #[test]
+    fn rgba_white_and_black_map_to_neutral_chroma() {
+        let rgba = [255, 255, 255, 255, 0, 0, 0, 255];
+        let frame = RawVideoFrame {
+            format: PixelFormat::Rgba,
+            width: 2,
+            height: 1,
+            planes: [&rgba, &[], &[]],
+            strides: [8, 0, 0],
+        };
+
+        let i420 = to_i420(&frame).unwrap();
+        assert_eq!(i420.y, [255, 0]);
+        assert!((127..=128).contains(&i420.u[0]));
+        assert!((127..=128).contains(&i420.v[0]));
+        assert_eq!((i420.chroma_width(), i420.chroma_height()), (1, 1));
+    }
+
+    #[test]
+    fn short_planes_and_strides_are_rejected() {
+        let y = [0u8; 4];
+        let frame = RawVideoFrame {
+            format: PixelFormat::I420,
+            width: 2,
+            height: 2,
+            planes: [&y, &[0], &[]],
+            strides: [2, 1, 1],
+        };
+        assert_eq!(to_i420(&frame), None);
+
+        let narrow = RawVideoFrame {
+            strides: [1, 1, 1],
+            planes: [&y, &[0], &[0]],
+            ..frame
+        };
+        assert_eq!(to_i420(&narrow), None);
+    }
+
+    #[test]
+    fn overflowing_sizes_are_rejected() {
+        assert_eq!(PixelFormat::Rgba.min_strides(u32::MAX / 2), None);
+        assert_eq!(PixelFormat::Nv12.min_strides(u32::MAX), None);
+        if usize::BITS == 32 {
+            assert_eq!(PixelFormat::I420.plane_lens(u32::MAX, [u32::MAX; 3]), None);
+        }
+
+        let frame = RawVideoFrame {
+            format: PixelFormat::Rgba,
+            width: u32::MAX / 2,
+            height: 1,
+            planes: [&[0; 8], &[], &[]],
+            strides: [u32::MAX, 0, 0],
+        };
+        assert_eq!(to_i420(&frame), None);
+    }
+
+    #[test]
+    fn scale_i420_letterboxes_and_keeps_aspect_ratio() {
+        // 2x2 white frame into a 6x2 output: centred, black bars.
+        let (y, u, v) = ([255u8; 4], [90u8], [200u8]);
+        let mut out = Vec::new();
+        scale_i420([&y, &u, &v], [2, 1, 1], (2, 2), (6, 2), &mut out);
+
+        assert_eq!(out.len(), 6 * 2 + 2 * 3);
+        assert_eq!(&out[..12], &[0, 0, 255, 255, 0, 0, 0, 0, 255, 255, 0, 0]);
+        assert_eq!(&out[12..15], &[128, 90, 128]);
+        assert_eq!(&out[15..], &[128, 200, 128]);
+
+        // A 4x2 frame into 4x6: full width, bars above and below.
+        let y = [10, 20, 30, 40, 50, 60, 70, 80];
+        scale_i420([&y, &[1, 2], &[3, 4]], [4, 2, 2], (4, 2), (4, 6), &mut out);
+        assert_eq!(&out[..8], &[0; 8]);
+        assert_eq!(&out[8..16], &y);
+        assert_eq!(&out[16..24], &[0; 8]);
+        assert_eq!(&out[24..30], &[128, 128, 1, 2, 128, 128]);
+    }
+}
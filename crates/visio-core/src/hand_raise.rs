@@ -1,32 +1,61 @@
 use livekit::prelude::Room;
 use std::collections::{BTreeMap, HashMap};
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::Mutex;
 
+use crate::clock::Clock;
 use crate::errors::VisioError;
 use crate::events::{EventEmitter, VisioEvent};
 
+/// Auto-lower behaviour for the local participant's raised hand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AutoLowerConfig {
+    pub enabled: bool,
+    /// How long the participant must keep speaking before the hand is lowered.
+    pub delay: Duration,
+}
+
+impl Default for AutoLowerConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            delay: Duration::from_secs(3),
+        }
+    }
+}
+
 /// Manages hand-raise state using LiveKit participant attributes.
 ///
 /// Interoperable with LaSuite Meet: uses `{"handRaisedAt": "<ISO 8601>"}` attribute.
 /// Maintains a queue ordered by raise time (BTreeMap<timestamp, participant_sid>).
-/// Supports auto-lower: if the local participant speaks for 3 consecutive seconds
-/// with hand raised, the hand is automatically lowered.
+/// Supports auto-lower: if the local participant keeps speaking for
+/// `AutoLowerConfig::delay` with hand raised, the hand is automatically lowered.
 pub struct HandRaiseManager {
     room: Arc<Room>,
     emitter: EventEmitter,
     /// timestamp -> participant_sid, ordered by raise time
     raised_hands: Arc<Mutex<BTreeMap<i64, String>>>,
     auto_lower_handle: Arc<Mutex<Option<tokio::task::JoinHandle<()>>>>,
+    /// Shared with RoomManager so changes apply to the current call.
+    auto_lower: Arc<std::sync::Mutex<AutoLowerConfig>>,
+    clock: Arc<dyn Clock>,
 }
 
 impl HandRaiseManager {
-    pub fn new(room: Arc<Room>, emitter: EventEmitter) -> Self {
+    pub fn new(
+        room: Arc<Room>,
+        emitter: EventEmitter,
+        auto_lower: Arc<std::sync::Mutex<AutoLowerConfig>>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
         Self {
             room,
             emitter,
             raised_hands: Arc::new(Mutex::new(BTreeMap::new())),
             auto_lower_handle: Arc::new(Mutex::new(None)),
+            auto_lower,
+            clock,
         }
     }
 
@@ -35,7 +64,7 @@ impl HandRaiseManager {
     /// Sets the `handRaisedAt` participant attribute to the current ISO 8601 timestamp,
     /// matching the LaSuite Meet protocol for interoperability.
     pub async fn raise_hand(&self) -> Result<(), VisioError> {
-        let now = chrono::Utc::now();
+        let now = self.clock.utc_now();
         let iso_timestamp = now.to_rfc3339_opts(chrono::SecondsFormat::Millis, true);
         let epoch_ms = now.timestamp_millis();
         tracing::info!("raise_hand: setting handRaisedAt={iso_timestamp}");
@@ -158,19 +187,23 @@ impl HandRaiseManager {
 
     /// Update auto-lower state based on active speakers.
     ///
-    /// If the local participant is speaking AND has their hand raised,
-    /// starts a 3-second timer. If they are still speaking when the timer
-    /// fires, the hand is automatically lowered.
+    /// If auto-lower is enabled and the local participant is speaking AND
+    /// has their hand raised, starts a timer of `AutoLowerConfig::delay`.
+    /// If they are still speaking when the timer fires, the hand is
+    /// automatically lowered and `HandAutoLowered` is emitted.
     /// If the local participant stops speaking or their hand is not raised,
     /// any existing timer is cancelled.
     pub fn start_auto_lower(&self, active_speakers: Vec<String>) {
         let local_sid = self.room.local_participant().sid().to_string();
-        let is_speaking = active_speakers.contains(&local_sid);
+        let config = *self.auto_lower.lock().unwrap_or_else(|e| e.into_inner());
+        let is_speaking = config.enabled && active_speakers.contains(&local_sid);
 
         let raised_hands = self.raised_hands.clone();
         let auto_lower_handle = self.auto_lower_handle.clone();
+        let auto_lower = self.auto_lower.clone();
         let room = self.room.clone();
         let emitter = self.emitter.clone();
+        let clock = self.clock.clone();
 
         tokio::spawn(async move {
             // Cancel existing timer
@@ -192,22 +225,24 @@ impl HandRaiseManager {
                 return;
             }
 
-            // Start 3-second timer
+            // Start auto-lower timer
             let local_sid2 = local_sid.clone();
             let raised_hands2 = raised_hands.clone();
             let room2 = room.clone();
             let emitter2 = emitter.clone();
 
             let handle = tokio::spawn(async move {
-                tokio::time::sleep(std::time::Duration::from_secs(3)).await;
+                clock.sleep(config.delay).await;
 
-                // Re-check hand is still raised after timer
+                // Re-check hand is still raised (and auto-lower still
+                // enabled) after timer
                 let still_raised = {
                     let hands = raised_hands2.lock().await;
                     hands.values().any(|sid| sid == &local_sid2)
                 };
+                let enabled = auto_lower.lock().unwrap_or_else(|e| e.into_inner()).enabled;
 
-                if still_raised {
+                if still_raised && enabled {
                     // Auto-lower: set attribute and update local state
                     let _ = room2
                         .local_participant()
@@ -226,6 +261,7 @@ impl HandRaiseManager {
                         raised: false,
                         position: 0,
                     });
+                    emitter2.emit(VisioEvent::HandAutoLowered);
                 }
             });
 
@@ -0,0 +1,164 @@
+//! Retries of Meet API requests.
+//!
+//! Mobile networks drop requests, and a join should not fail because one
+//! HTTP round trip did. `HttpMeetApi` retries with exponential backoff
+//! after transport failures and `429`/`502`/`503`/`504` responses,
+//! honoring `Retry-After`. Requests with side effects (invitations,
+//! recording) are only retried when the server cannot have processed
+//! them: connection failures, `429` and `503`.
+
+use std::time::Duration;
+
+/// How `HttpMeetApi` retries failed requests.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Attempts after the first one.
+    pub max_retries: u32,
+    /// Wait before the first retry, doubled for each following one.
+    pub initial_backoff: Duration,
+    /// Longest wait between attempts. A `Retry-After` above it is not
+    /// honored: the response is returned as is.
+    pub max_backoff: Duration,
+    /// Timeout of each attempt.
+    pub request_timeout: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 2,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(5),
+            request_timeout: Duration::from_secs(15),
+        }
+    }
+}
+
+/// Outcome of an attempt that may deserve a retry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum AttemptFailure {
+    /// No connection could be made; the request was not sent.
+    Connect,
+    /// The request failed after it may have reached the server (timeout,
+    /// reset connection...).
+    Transport,
+    /// The server answered with `status`.
+    Status {
+        status: u16,
+        retry_after: Option<Duration>,
+    },
+}
+
+impl RetryPolicy {
+    /// No retries, only the per-request timeout.
+    pub fn no_retry() -> Self {
+        Self {
+            max_retries: 0,
+            ..Self::default()
+        }
+    }
+
+    /// Exponential backoff before retry number `retry` (0-based).
+    fn backoff(&self, retry: u32) -> Duration {
+        let factor = 1u32.checked_shl(retry).unwrap_or(u32::MAX);
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+
+    /// How long to wait before retry number `retry` after `failure`, or
+    /// `None` to give up. `idempotent` requests may be sent twice.
+    pub(crate) fn delay(
+        &self,
+        retry: u32,
+        idempotent: bool,
+        failure: AttemptFailure,
+    ) -> Option<Duration> {
+        if retry >= self.max_retries {
+            return None;
+        }
+        match failure {
+            AttemptFailure::Connect => Some(self.backoff(retry)),
+            AttemptFailure::Transport => idempotent.then(|| self.backoff(retry)),
+            AttemptFailure::Status {
+                status,
+                retry_after,
+            } => {
+                let retryable = match status {
+                    429 | 503 => true,
+                    502 | 504 => idempotent,
+                    _ => false,
+                };
+                if !retryable {
+                    return None;
+                }
+                match retry_after {
+                    Some(wait) if wait > self.max_backoff => None,
+                    Some(wait) => Some(wait),
+                    None => Some(self.backoff(retry)),
+                }
+            }
+        }
+    }
+}
+
+/// `Retry-After` given in seconds. HTTP dates are ignored.
+pub(crate) fn parse_retry_after(value: &str) -> Option<Duration> {
+    value.trim().parse().ok().map(Duration::from_secs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status(status: u16, retry_after: Option<u64>) -> AttemptFailure {
+        AttemptFailure::Status {
+            status,
+            retry_after: retry_after.map(Duration::from_secs),
+        }
+    }
+
+    #[test]
+    fn retries_with_backoff_and_retry_after() {
+        let policy = RetryPolicy {
+            max_retries: 4,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(3),
+            request_timeout: Duration::from_secs(10),
+        };
+        let ms = Duration::from_millis;
+
+        assert_eq!(
+            policy.delay(0, true, AttemptFailure::Connect),
+            Some(ms(500))
+        );
+        assert_eq!(
+            policy.delay(1, true, AttemptFailure::Transport),
+            Some(ms(1000))
+        );
+        assert_eq!(policy.delay(3, true, status(502, None)), Some(ms(3000)));
+        assert_eq!(policy.delay(4, true, AttemptFailure::Connect), None);
+
+        assert_eq!(policy.delay(0, true, status(429, Some(2))), Some(ms(2000)));
+        assert_eq!(policy.delay(0, true, status(429, Some(60))), None);
+        assert_eq!(policy.delay(0, true, status(404, None)), None);
+        assert_eq!(policy.delay(0, true, status(500, None)), None);
+
+        // Requests with side effects are not repeated once they may have
+        // been processed.
+        assert_eq!(
+            policy.delay(0, false, AttemptFailure::Connect),
+            Some(ms(500))
+        );
+        assert_eq!(policy.delay(0, false, AttemptFailure::Transport), None);
+        assert_eq!(policy.delay(0, false, status(504, None)), None);
+        assert_eq!(policy.delay(0, false, status(503, None)), Some(ms(500)));
+
+        assert_eq!(
+            RetryPolicy::no_retry().delay(0, true, AttemptFailure::Connect),
+            None
+        );
+        assert_eq!(parse_retry_after(" 7 "), Some(Duration::from_secs(7)));
+        assert_eq!(parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT"), None);
+    }
+}
@@ -0,0 +1,101 @@
+//! Translations of user-facing strings.
+//!
+//! The shells and the core share the catalogs in `i18n/<lang>.json`: flat
+//! maps from keys to strings with `{name}` placeholders. The core embeds
+//! them so strings it generates itself (invite text, error messages...)
+//! follow the language setting without a round trip through the shell.
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+/// Supported languages, English first (used for unsupported languages and
+/// missing keys).
+pub const SUPPORTED_LANGUAGES: &[&str] = &["en", "fr", "de", "es", "it", "nl"];
+
+const CATALOGS: &[(&str, &str)] = &[
+    ("en", include_str!("../../../i18n/en.json")),
+    ("fr", include_str!("../../../i18n/fr.json")),
+    ("de", include_str!("../../../i18n/de.json")),
+    ("es", include_str!("../../../i18n/es.json")),
+    ("it", include_str!("../../../i18n/it.json")),
+    ("nl", include_str!("../../../i18n/nl.json")),
+];
+
+type Catalog = HashMap<String, String>;
+
+fn catalogs() -> &'static HashMap<&'static str, Catalog> {
+    static CATALOGS_BY_LANG: OnceLock<HashMap<&'static str, Catalog>> = OnceLock::new();
+    CATALOGS_BY_LANG.get_or_init(|| {
+        CATALOGS
+            .iter()
+            .map(|(lang, json)| {
+                let catalog = serde_json::from_str(json).unwrap_or_else(|e| {
+                    tracing::error!("invalid i18n catalog {lang}: {e}");
+                    Catalog::new()
+                });
+                (*lang, catalog)
+            })
+            .collect()
+    })
+}
+
+/// Supported language matching `language` (`fr`, `fr-FR`, `fr_CA`...),
+/// English otherwise.
+pub fn normalize_language(language: &str) -> &'static str {
+    let code = language
+        .split(['-', '_'])
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    SUPPORTED_LANGUAGES
+        .iter()
+        .find(|lang| **lang == code)
+        .unwrap_or(&SUPPORTED_LANGUAGES[0])
+}
+
+/// String `key` in `language` with `{name}` placeholders replaced by the
+/// matching `args`. Falls back to English, then to the key itself.
+pub fn translate(language: &str, key: &str, args: &[(&str, &str)]) -> String {
+    let catalogs = catalogs();
+    let template = [normalize_language(language), SUPPORTED_LANGUAGES[0]]
+        .iter()
+        .find_map(|lang| catalogs.get(lang)?.get(key))
+        .map(String::as_str)
+        .unwrap_or(key);
+    args.iter()
+        .fold(template.to_string(), |text, (name, value)| {
+            text.replace(&format!("{{{name}}}"), value)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Keys of the strings generated by the core, which every catalog must
+    /// translate.
+    const CORE_KEY_PREFIXES: &[&str] = &["invite.", "error.", "chat.export.", "chat.system."];
+
+    #[test]
+    fn translates_with_fallbacks() {
+        assert_eq!(
+            translate("fr_CA", "deepLink.unknownInstance", &[("host", "x.org")]),
+            "Instance inconnue : x.org"
+        );
+        assert_eq!(translate("pt", "chat", &[]), "Chat");
+        assert_eq!(translate("en", "no.such.key", &[]), "no.such.key");
+        assert_eq!(normalize_language("NL-be"), "nl");
+
+        // Every catalog translates the strings generated by the core.
+        let en = &catalogs()["en"];
+        for lang in SUPPORTED_LANGUAGES {
+            let catalog = &catalogs()[lang];
+            let missing: Vec<_> = en
+                .keys()
+                .filter(|k| CORE_KEY_PREFIXES.iter().any(|p| k.starts_with(p)))
+                .filter(|k| !catalog.contains_key(*k))
+                .collect();
+            assert!(missing.is_empty(), "{lang} is missing {missing:?}");
+        }
+    }
+}
@@ -0,0 +1,293 @@
+//! Alone-in-room and inactivity detection.
+//!
+//! A forgotten call keeps the camera, microphone and battery busy. While
+//! connected, `IdleMonitor` checks the room periodically:
+//!
+//! - alone for `IdleConfig::alone_timeout` (every other participant
+//!   left): `AloneInRoom`, with `auto_leave` set when the shell should
+//!   leave the room as for a hang-up;
+//! - no one spoke and no track was published or unmuted for
+//!   `IdleConfig::inactivity_timeout`: `InactivityDetected`, for the
+//!   shell to ask whether the user is still there. Answering calls
+//!   `RoomManager::report_activity`.
+//!
+//! Each is emitted once until the situation changes (someone joins,
+//! activity resumes).
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use tokio::task::JoinHandle;
+
+use crate::events::{EventEmitter, VisioEvent, VisioEventListener};
+use crate::participants::ParticipantManager;
+
+/// How often the room is checked while connected.
+const CHECK_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Idle policy, from the `alone_*` and `inactivity_*` settings. A `None`
+/// timeout disables the check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IdleConfig {
+    pub alone_timeout: Option<Duration>,
+    pub auto_leave_when_alone: bool,
+    pub inactivity_timeout: Option<Duration>,
+}
+
+impl Default for IdleConfig {
+    fn default() -> Self {
+        Self {
+            alone_timeout: Some(Duration::from_secs(5 * 60)),
+            auto_leave_when_alone: false,
+            inactivity_timeout: Some(Duration::from_secs(60 * 60)),
+        }
+    }
+}
+
+/// Idle state of the current call, independent of timers.
+#[derive(Debug)]
+struct IdleTracker {
+    config: IdleConfig,
+    alone_since: Option<Instant>,
+    alone_reported: bool,
+    last_activity: Instant,
+    inactivity_reported: bool,
+}
+
+impl IdleTracker {
+    fn new(config: IdleConfig, now: Instant) -> Self {
+        Self {
+            config,
+            alone_since: None,
+            alone_reported: false,
+            last_activity: now,
+            inactivity_reported: false,
+        }
+    }
+
+    /// Joined a room: start counting from `now`.
+    fn restart(&mut self, now: Instant) {
+        *self = Self::new(self.config, now);
+    }
+
+    fn activity(&mut self, now: Instant) {
+        self.last_activity = now;
+        self.inactivity_reported = false;
+    }
+
+    /// Events due at `now`, with `remote_count` other participants.
+    fn check(&mut self, now: Instant, remote_count: usize) -> Vec<VisioEvent> {
+        let mut events = Vec::new();
+        if remote_count > 0 {
+            self.alone_since = None;
+            self.alone_reported = false;
+        } else {
+            let since = *self.alone_since.get_or_insert(now);
+            if let Some(timeout) = self.config.alone_timeout
+                && !self.alone_reported
+                && now.duration_since(since) >= timeout
+            {
+                self.alone_reported = true;
+                events.push(VisioEvent::AloneInRoom {
+                    minutes: whole_minutes(now.duration_since(since)),
+                    auto_leave: self.config.auto_leave_when_alone,
+                });
+            }
+        }
+        if let Some(timeout) = self.config.inactivity_timeout
+            && !self.inactivity_reported
+            && now.duration_since(self.last_activity) >= timeout
+        {
+            self.inactivity_reported = true;
+            events.push(VisioEvent::InactivityDetected {
+                minutes: whole_minutes(now.duration_since(self.last_activity)),
+            });
+        }
+        events
+    }
+}
+
+fn whole_minutes(d: Duration) -> u32 {
+    (d.as_secs() / 60) as u32
+}
+
+/// Runs the idle checks of the current call. Registered as a listener on
+/// the room emitter to see speaking and track activity.
+#[derive(Clone)]
+pub struct IdleMonitor {
+    tracker: Arc<Mutex<IdleTracker>>,
+    participants: Arc<tokio::sync::Mutex<ParticipantManager>>,
+    task: Arc<Mutex<Option<JoinHandle<()>>>>,
+    emitter: EventEmitter,
+}
+
+impl IdleMonitor {
+    pub fn new(
+        emitter: EventEmitter,
+        participants: Arc<tokio::sync::Mutex<ParticipantManager>>,
+    ) -> Self {
+        Self {
+            tracker: Arc::new(Mutex::new(IdleTracker::new(
+                IdleConfig::default(),
+                Instant::now(),
+            ))),
+            participants,
+            task: Arc::new(Mutex::new(None)),
+            emitter,
+        }
+    }
+
+    /// Change the policy. Takes effect at the next check, including for
+    /// an ongoing call.
+    pub fn set_config(&self, config: IdleConfig) {
+        self.tracker
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .config = config;
+    }
+
+    pub fn config(&self) -> IdleConfig {
+        self.tracker
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .config
+    }
+
+    /// The user showed they are there (e.g. answered the inactivity prompt).
+    pub fn activity(&self) {
+        self.tracker
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .activity(Instant::now());
+    }
+
+    /// Start checking the room just joined, replacing any previous
+    /// checks. Must be called from a tokio runtime.
+    pub(crate) fn start(&self) {
+        self.reset();
+        self.tracker
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .restart(Instant::now());
+        let monitor = self.clone();
+        let task = tokio::spawn(async move {
+            let mut interval = tokio::time::interval(CHECK_INTERVAL);
+            interval.tick().await;
+            loop {
+                interval.tick().await;
+                let remote_count = monitor.participants.lock().await.participant_count();
+                let events = monitor
+                    .tracker
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .check(Instant::now(), remote_count);
+                for event in events {
+                    monitor.emitter.emit(event);
+                }
+            }
+        });
+        *self.task.lock().unwrap_or_else(|e| e.into_inner()) = Some(task);
+    }
+
+    /// Stop checking (left the room).
+    pub(crate) fn reset(&self) {
+        if let Some(task) = self.task.lock().unwrap_or_else(|e| e.into_inner()).take() {
+            task.abort();
+        }
+    }
+}
+
+impl VisioEventListener for IdleMonitor {
+    fn on_event(&self, event: VisioEvent) {
+        let active = match &event {
+            // Participants are unknown until reconnected: the shell
+            // reconnects or leaves, and a new join restarts the checks.
+            VisioEvent::ConnectionLost => {
+                self.reset();
+                false
+            }
+            VisioEvent::ActiveSpeakersChanged(speakers) => !speakers.is_empty(),
+            VisioEvent::TrackSubscribed(_) | VisioEvent::TrackUnmuted { .. } => true,
+            _ => false,
+        };
+        if active {
+            self.activity();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn minutes(m: u64) -> Duration {
+        Duration::from_secs(m * 60)
+    }
+
+    #[test]
+    fn alone_is_reported_once_per_episode() {
+        let t0 = Instant::now();
+        let mut tracker = IdleTracker::new(
+            IdleConfig {
+                alone_timeout: Some(minutes(5)),
+                auto_leave_when_alone: true,
+                inactivity_timeout: None,
+            },
+            t0,
+        );
+        assert!(tracker.check(t0, 0).is_empty());
+        assert!(tracker.check(t0 + minutes(4), 0).is_empty());
+        let events = tracker.check(t0 + minutes(5), 0);
+        assert!(matches!(
+            events[..],
+            [VisioEvent::AloneInRoom {
+                minutes: 5,
+                auto_leave: true
+            }]
+        ));
+        assert!(tracker.check(t0 + minutes(6), 0).is_empty());
+
+        // Someone came back, then left again: counts from the new leave.
+        assert!(tracker.check(t0 + minutes(7), 1).is_empty());
+        assert!(tracker.check(t0 + minutes(8), 0).is_empty());
+        assert!(tracker.check(t0 + minutes(12), 0).is_empty());
+        assert_eq!(tracker.check(t0 + minutes(13), 0).len(), 1);
+    }
+
+    #[test]
+    fn inactivity_resets_on_activity() {
+        let t0 = Instant::now();
+        let mut tracker = IdleTracker::new(
+            IdleConfig {
+                alone_timeout: None,
+                auto_leave_when_alone: false,
+                inactivity_timeout: Some(minutes(30)),
+            },
+            t0,
+        );
+        tracker.activity(t0 + minutes(20));
+        assert!(tracker.check(t0 + minutes(40), 2).is_empty());
+        let events = tracker.check(t0 + minutes(51), 2);
+        assert!(matches!(
+            events[..],
+            [VisioEvent::InactivityDetected { minutes: 31 }]
+        ));
+        assert!(tracker.check(t0 + minutes(52), 2).is_empty());
+        tracker.activity(t0 + minutes(53));
+        assert_eq!(tracker.check(t0 + minutes(83), 2).len(), 1);
+    }
+
+    #[test]
+    fn disabled_checks_stay_silent() {
+        let t0 = Instant::now();
+        let mut tracker = IdleTracker::new(
+            IdleConfig {
+                alone_timeout: None,
+                auto_leave_when_alone: true,
+                inactivity_timeout: None,
+            },
+            t0,
+        );
+        assert!(tracker.check(t0 + minutes(600), 0).is_empty());
+    }
+}
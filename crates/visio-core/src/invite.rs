@@ -0,0 +1,215 @@
+//! Invitations: shareable room link, dial-in details and email invites.
+//!
+//! The link is built from the Meet URL of the room; dial-in details are
+//! only returned when the room has a PIN and the instance advertises a
+//! phone number in its configuration. `build_invite_text` formats them
+//! as a localized plain-text blurb to copy or share.
+
+use chrono::{DateTime, FixedOffset, Offset, Utc};
+use serde::Deserialize;
+
+use crate::auth::AuthService;
+use crate::errors::VisioError;
+use crate::i18n;
+use crate::meet_api::{InviteRequest, MeetApi};
+
+/// How to invite someone to the current room.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InviteInfo {
+    /// Shareable room link, e.g. `https://meet.example.com/abc-defg-hij`.
+    pub url: String,
+    pub dial_in: Option<DialInInfo>,
+}
+
+/// Phone access to the room.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DialInInfo {
+    pub phone_number: String,
+    pub pin_code: String,
+}
+
+/// Subset of `GET /api/v1.0/config/`.
+#[derive(Debug, Default, Deserialize)]
+struct MeetApiConfig {
+    #[serde(default)]
+    telephony: Option<TelephonyConfig>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct TelephonyConfig {
+    #[serde(default)]
+    enabled: bool,
+    #[serde(default)]
+    phone_number: Option<String>,
+}
+
+/// Localized plain-text invitation to the room described by `info`, one
+/// item per line: intro, meeting time, link and dial-in details.
+///
+/// The meeting time is only included when `starts_at_ms` (Unix time) is
+/// given; it is shown at `utc_offset_minutes` from UTC, usually the
+/// offset of the sender's time zone. Unsupported languages fall back to
+/// English.
+pub fn build_invite_text(
+    info: &InviteInfo,
+    language: &str,
+    starts_at_ms: Option<i64>,
+    utc_offset_minutes: i32,
+) -> String {
+    let text = |key: &str, args: &[(&str, &str)]| i18n::translate(language, key, args);
+    let mut lines = vec![text("invite.text.intro", &[])];
+    let offset =
+        FixedOffset::east_opt(utc_offset_minutes.saturating_mul(60)).unwrap_or_else(|| Utc.fix());
+    let starts_at = starts_at_ms
+        .and_then(DateTime::from_timestamp_millis)
+        .map(|t| t.with_timezone(&offset));
+    if let Some(starts_at) = starts_at {
+        let time = format!(
+            "{} (UTC{})",
+            starts_at.format(&text("invite.text.dateFormat", &[])),
+            starts_at.format("%:z")
+        );
+        lines.push(text("invite.text.when", &[("time", &time)]));
+    }
+    lines.push(text("invite.text.link", &[("url", &info.url)]));
+    if let Some(dial_in) = &info.dial_in {
+        lines.push(text(
+            "invite.text.dialIn",
+            &[("phone", &dial_in.phone_number), ("pin", &dial_in.pin_code)],
+        ));
+    }
+    lines.join("\n")
+}
+
+/// Builds invitation details and sends email invitations.
+pub struct InviteService;
+
+impl InviteService {
+    /// Invitation details for the room at `meet_url`. The instance
+    /// configuration is only fetched when the room has a dial-in PIN;
+    /// if it cannot be read the link is returned without dial-in details.
+    pub async fn get_invite_info_with(
+        api: &dyn MeetApi,
+        meet_url: &str,
+        pin_code: Option<&str>,
+    ) -> Result<InviteInfo, VisioError> {
+        let (instance, slug) = AuthService::parse_meet_url(meet_url)?;
+        let url = format!("https://{instance}/{slug}");
+
+        let dial_in = match pin_code {
+            Some(pin_code) => Self::phone_number(api, &instance)
+                .await
+                .map(|phone_number| DialInInfo {
+                    phone_number,
+                    pin_code: pin_code.to_string(),
+                }),
+            None => None,
+        };
+        Ok(InviteInfo { url, dial_in })
+    }
+
+    async fn phone_number(api: &dyn MeetApi, instance: &str) -> Option<String> {
+        let resp = match api.get_config(instance).await {
+            Ok(resp) if (200..300).contains(&resp.status) => resp,
+            Ok(resp) => {
+                tracing::warn!("Meet config returned status {}", resp.status);
+                return None;
+            }
+            Err(e) => {
+                tracing::warn!("cannot fetch Meet config: {e}");
+                return None;
+            }
+        };
+        let config: MeetApiConfig = serde_json::from_str(&resp.body)
+            .inspect_err(|e| tracing::warn!("invalid Meet config: {e}"))
+            .ok()?;
+        config
+            .telephony
+            .filter(|t| t.enabled)
+            .and_then(|t| t.phone_number)
+            .filter(|n| !n.is_empty())
+    }
+
+    /// Email an invitation to the room `room_id` on `instance`.
+    ///
+    /// Fails with `VisioError::Room` if the instance does not support
+    /// invitations.
+    pub async fn invite_participants_with(
+        api: &dyn MeetApi,
+        instance: &str,
+        room_id: &str,
+        emails: Vec<String>,
+        session_cookie: Option<&str>,
+    ) -> Result<(), VisioError> {
+        let emails: Vec<String> = emails
+            .into_iter()
+            .map(|e| e.trim().to_string())
+            .filter(|e| !e.is_empty())
+            .collect();
+        if let Some(invalid) = emails.iter().find(|e| !e.contains('@')) {
+            return Err(VisioError::Room(format!(
+                "invalid email address: {invalid}"
+            )));
+        }
+        if emails.is_empty() {
+            return Ok(());
+        }
+
+        let request = InviteRequest {
+            instance: instance.to_string(),
+            room_id: room_id.to_string(),
+            emails,
+            session_cookie: session_cookie.map(str::to_string),
+        };
+        let resp = api.invite(&request).await?;
+        match resp.status {
+            200..=299 => Ok(()),
+            401 | 403 => Err(VisioError::AuthRequired),
+            404 | 405 => Err(VisioError::Room(
+                "invitations are not supported by this instance".into(),
+            )),
+            status => Err(VisioError::Room(format!(
+                "invitation failed with status {status}"
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invite_text_is_localized() {
+        let info = InviteInfo {
+            url: "https://meet.example.com/abc-defg-hij".into(),
+            dial_in: Some(DialInInfo {
+                phone_number: "+33 1 23 45 67 89".into(),
+                pin_code: "123456".into(),
+            }),
+        };
+        let starts_at = DateTime::parse_from_rfc3339("2026-03-05T14:30:00+01:00")
+            .unwrap()
+            .timestamp_millis();
+        assert_eq!(
+            build_invite_text(&info, "fr-FR", Some(starts_at), 60),
+            "Vous êtes invité(e) à une réunion vidéo.\n\
+             Quand : 05/03/2026 14:30 (UTC+01:00)\n\
+             Rejoindre : https://meet.example.com/abc-defg-hij\n\
+             Par téléphone : +33 1 23 45 67 89 (code : 123456)"
+        );
+
+        let info = InviteInfo {
+            dial_in: None,
+            ..info
+        };
+        assert_eq!(
+            build_invite_text(&info, "pt", None, 0),
+            "You are invited to a video meeting.\n\
+             Join: https://meet.example.com/abc-defg-hij"
+        );
+        for lang in i18n::SUPPORTED_LANGUAGES {
+            assert!(build_invite_text(&info, lang, Some(starts_at), -300).contains(&info.url));
+        }
+    }
+}
@@ -0,0 +1,255 @@
+//! Coalescing of participant join/leave events.
+//!
+//! In large rooms, join/leave storms (a meeting starting, a server
+//! draining) produce one event per participant and overwhelm UI updates
+//! and join/leave chimes. When enabled, the coalescer buffers them for a
+//! short window and emits a single `ParticipantBatchUpdate` instead —
+//! play at most one chime per batch.
+//!
+//! Only event delivery is affected: `RoomManager::participants()` is
+//! always up to date.
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::events::{EventEmitter, ParticipantInfo, VisioEvent};
+
+/// How participant join/leave events are delivered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JoinLeaveMode {
+    /// One `ParticipantJoined` / `ParticipantLeft` per participant.
+    Individual,
+    /// One `ParticipantBatchUpdate` per window, with the full lists.
+    Batched,
+    /// One `ParticipantBatchUpdate` per window carrying counts only;
+    /// listeners refresh with `participants()`.
+    CountOnly,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JoinLeaveConfig {
+    pub mode: JoinLeaveMode,
+    /// Coalescing window, starting at the first buffered event.
+    pub window: Duration,
+}
+
+impl Default for JoinLeaveConfig {
+    fn default() -> Self {
+        Self {
+            mode: JoinLeaveMode::Individual,
+            window: Duration::from_millis(1000),
+        }
+    }
+}
+
+#[derive(Default)]
+struct Pending {
+    joined: Vec<ParticipantInfo>,
+    left: Vec<String>,
+    flush_scheduled: bool,
+}
+
+/// Routes join/leave notifications according to the current `JoinLeaveConfig`.
+#[derive(Clone)]
+pub struct JoinLeaveCoalescer {
+    emitter: EventEmitter,
+    config: Arc<Mutex<JoinLeaveConfig>>,
+    pending: Arc<Mutex<Pending>>,
+}
+
+impl JoinLeaveCoalescer {
+    pub fn new(emitter: EventEmitter) -> Self {
+        Self {
+            emitter,
+            config: Arc::new(Mutex::new(JoinLeaveConfig::default())),
+            pending: Arc::new(Mutex::new(Pending::default())),
+        }
+    }
+
+    pub fn config(&self) -> JoinLeaveConfig {
+        *self.config.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Change the delivery mode. Anything buffered so far is flushed first.
+    pub fn set_config(&self, config: JoinLeaveConfig) {
+        self.flush();
+        *self.config.lock().unwrap_or_else(|e| e.into_inner()) = config;
+    }
+
+    pub fn joined(&self, info: ParticipantInfo) {
+        let config = self.config();
+        if config.mode == JoinLeaveMode::Individual {
+            self.emitter.emit(VisioEvent::ParticipantJoined(info));
+            return;
+        }
+        self.pending
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .joined
+            .push(info);
+        self.schedule_flush(config.window);
+    }
+
+    pub fn left(&self, sid: String) {
+        let config = self.config();
+        if config.mode == JoinLeaveMode::Individual {
+            self.emitter.emit(VisioEvent::ParticipantLeft(sid));
+            return;
+        }
+        {
+            let mut pending = self.pending.lock().unwrap_or_else(|e| e.into_inner());
+            // Joined and left within the same window: nothing to report.
+            if let Some(pos) = pending.joined.iter().position(|p| p.sid == sid) {
+                pending.joined.remove(pos);
+            } else {
+                pending.left.push(sid);
+            }
+        }
+        self.schedule_flush(config.window);
+    }
+
+    fn schedule_flush(&self, window: Duration) {
+        {
+            let mut pending = self.pending.lock().unwrap_or_else(|e| e.into_inner());
+            if pending.flush_scheduled {
+                return;
+            }
+            pending.flush_scheduled = true;
+        }
+        let this = self.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(window).await;
+            this.flush();
+        });
+    }
+
+    /// Emit buffered joins/leaves now. Leaves are listed before joins are
+    /// applied, so a participant that left and rejoined appears in both.
+    pub fn flush(&self) {
+        let (joined, left) = {
+            let mut pending = self.pending.lock().unwrap_or_else(|e| e.into_inner());
+            pending.flush_scheduled = false;
+            (
+                std::mem::take(&mut pending.joined),
+                std::mem::take(&mut pending.left),
+            )
+        };
+        if joined.is_empty() && left.is_empty() {
+            return;
+        }
+        let (joined_count, left_count) = (joined.len() as u32, left.len() as u32);
+        let event = match self.config().mode {
+            JoinLeaveMode::CountOnly => VisioEvent::ParticipantBatchUpdate {
+                joined: Vec::new(),
+                left: Vec::new(),
+                joined_count,
+                left_count,
+            },
+            _ => VisioEvent::ParticipantBatchUpdate {
+                joined,
+                left,
+                joined_count,
+                left_count,
+            },
+        };
+        self.emitter.emit(event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::{ConnectionQuality, VisioEventListener};
+    use crate::presence::Presence;
+
+    struct Capture(Mutex<Vec<VisioEvent>>);
+
+    impl VisioEventListener for Capture {
+        fn on_event(&self, event: VisioEvent) {
+            self.0.lock().unwrap().push(event);
+        }
+    }
+
+    fn setup(mode: JoinLeaveMode) -> (JoinLeaveCoalescer, Arc<Capture>) {
+        let emitter = EventEmitter::new();
+        let capture = Arc::new(Capture(Mutex::new(Vec::new())));
+        emitter.add_listener(capture.clone());
+        let coalescer = JoinLeaveCoalescer::new(emitter);
+        coalescer.set_config(JoinLeaveConfig {
+            mode,
+            window: Duration::from_millis(20),
+        });
+        (coalescer, capture)
+    }
+
+    fn info(sid: &str) -> ParticipantInfo {
+        ParticipantInfo {
+            sid: sid.into(),
+            identity: sid.into(),
+            name: None,
+            is_muted: false,
+            has_video: false,
+            video_track_sid: None,
+            connection_quality: ConnectionQuality::Good,
+            presence: Presence::Available,
+        }
+    }
+
+    #[tokio::test]
+    async fn individual_mode_emits_immediately() {
+        let (c, capture) = setup(JoinLeaveMode::Individual);
+        c.joined(info("a"));
+        c.left("b".into());
+        let events = capture.0.lock().unwrap();
+        assert!(matches!(events[0], VisioEvent::ParticipantJoined(_)));
+        assert!(matches!(events[1], VisioEvent::ParticipantLeft(_)));
+    }
+
+    #[tokio::test]
+    async fn batched_mode_coalesces_within_window() {
+        let (c, capture) = setup(JoinLeaveMode::Batched);
+        c.joined(info("a"));
+        c.joined(info("b"));
+        c.left("c".into());
+        assert!(capture.0.lock().unwrap().is_empty());
+
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        let events = capture.0.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        match &events[0] {
+            VisioEvent::ParticipantBatchUpdate {
+                joined,
+                left,
+                joined_count,
+                left_count,
+            } => {
+                assert_eq!(joined.len(), 2);
+                assert_eq!(left, &vec!["c".to_string()]);
+                assert_eq!((*joined_count, *left_count), (2, 1));
+            }
+            other => panic!("expected batch, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn transient_participant_is_dropped_and_count_only_has_no_lists() {
+        let (c, capture) = setup(JoinLeaveMode::CountOnly);
+        c.joined(info("a"));
+        c.joined(info("b"));
+        c.left("a".into());
+        c.flush();
+        let events = capture.0.lock().unwrap();
+        match &events[0] {
+            VisioEvent::ParticipantBatchUpdate {
+                joined,
+                left,
+                joined_count,
+                left_count,
+            } => {
+                assert!(joined.is_empty() && left.is_empty());
+                assert_eq!((*joined_count, *left_count), (1, 0));
+            }
+            other => panic!("expected batch, got {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,148 @@
+//! How to join a room.
+//!
+//! `RoomManager::connect` takes everything that shapes a join in one
+//! `JoinOptions` value instead of a growing list of parameters and
+//! room-wide setters. Shells usually start from `JoinOptions::from_settings`
+//! and adjust it with the builder methods
+//! (`.username("Ana").camera_on(false)`).
+
+use serde::{Deserialize, Serialize};
+
+use crate::controls::PendingMedia;
+use crate::rtc_config::RtcConfigOverride;
+use crate::settings::Settings;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(default)]
+pub struct JoinOptions {
+    /// Name sent to the Meet API with the token request.
+    pub username: Option<String>,
+    /// Name shown to the other participants, set once joined when it
+    /// differs from `username`.
+    pub display_name: Option<String>,
+    /// Publish the microphone right after joining.
+    pub mic_on: bool,
+    /// Publish the camera right after joining (ignored with `audio_only`).
+    pub camera_on: bool,
+    /// Receive no remote video: video tracks are unsubscribed as soon as
+    /// the SFU subscribes them.
+    pub audio_only: bool,
+    /// Subscribe to remote tracks as they are published. Without it, no
+    /// remote media is received.
+    pub auto_subscribe: bool,
+    /// ICE configuration for this connection instead of the room-wide one
+    /// (`RoomManager::set_rtc_config`).
+    pub rtc_overrides: Option<RtcConfigOverride>,
+}
+
+impl Default for JoinOptions {
+    fn default() -> Self {
+        Self {
+            username: None,
+            display_name: None,
+            mic_on: false,
+            camera_on: false,
+            audio_only: false,
+            auto_subscribe: true,
+            rtc_overrides: None,
+        }
+    }
+}
+
+impl JoinOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Media from the mic/camera-on-join settings.
+    pub fn from_settings(settings: &Settings) -> Self {
+        Self {
+            mic_on: settings.mic_enabled_on_join,
+            camera_on: settings.camera_enabled_on_join,
+            ..Self::default()
+        }
+    }
+
+    pub fn username(mut self, username: impl Into<String>) -> Self {
+        self.username = Some(username.into());
+        self
+    }
+
+    pub fn display_name(mut self, display_name: impl Into<String>) -> Self {
+        self.display_name = Some(display_name.into());
+        self
+    }
+
+    pub fn mic_on(mut self, on: bool) -> Self {
+        self.mic_on = on;
+        self
+    }
+
+    pub fn camera_on(mut self, on: bool) -> Self {
+        self.camera_on = on;
+        self
+    }
+
+    pub fn audio_only(mut self, audio_only: bool) -> Self {
+        self.audio_only = audio_only;
+        self
+    }
+
+    pub fn auto_subscribe(mut self, auto_subscribe: bool) -> Self {
+        self.auto_subscribe = auto_subscribe;
+        self
+    }
+
+    pub fn rtc_overrides(mut self, overrides: RtcConfigOverride) -> Self {
+        self.rtc_overrides = Some(overrides);
+        self
+    }
+
+    /// Media to enable once connected.
+    pub(crate) fn media(&self) -> PendingMedia {
+        PendingMedia {
+            microphone: self.mic_on.then_some(true),
+            camera: (self.camera_on && !self.audio_only).then_some(true),
+        }
+    }
+
+    /// Name to set on the local participant once joined, if any.
+    pub(crate) fn participant_name(&self) -> Option<&str> {
+        self.display_name
+            .as_deref()
+            .filter(|name| Some(*name) != self.username.as_deref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_from_settings_and_derives_media() {
+        let settings = Settings {
+            camera_enabled_on_join: true,
+            ..Settings::default()
+        };
+        let options = JoinOptions::from_settings(&settings).username("ana");
+        assert_eq!(
+            options.media(),
+            PendingMedia {
+                microphone: Some(true),
+                camera: Some(true),
+            }
+        );
+        assert!(options.auto_subscribe);
+        assert_eq!(options.participant_name(), None);
+
+        // Audio-only joins never publish the camera.
+        let options = options.audio_only(true).display_name("Ana Dupont");
+        assert_eq!(options.media().camera, None);
+        assert_eq!(options.participant_name(), Some("Ana Dupont"));
+        assert_eq!(JoinOptions::new().media(), PendingMedia::default());
+
+        // Missing fields take their defaults.
+        let options: JoinOptions = serde_json::from_str(r#"{"mic_on": true}"#).unwrap();
+        assert_eq!(options, JoinOptions::new().mic_on(true));
+    }
+}
@@ -0,0 +1,249 @@
+//! Journal of room events, for debugging from user reports.
+//!
+//! Every `VisioEvent` emitted and every LiveKit `RoomEvent` received is
+//! recorded with its time, in the order the core saw them, so ordering
+//! bugs (an entry granted before the lobby was shown, a camera published
+//! before the room connected, ...) can be reconstructed from a report.
+//! The journal keeps the last `MAX_JOURNAL_ENTRIES` in memory and, once
+//! `EventJournal::set_file` is called, appends them to a JSON lines file
+//! rotated at `MAX_JOURNAL_FILE_BYTES`. Chat and data payloads are never
+//! recorded, only the kind of event.
+
+use std::collections::VecDeque;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use livekit::prelude::RoomEvent;
+use serde::Serialize;
+
+use crate::clock::Clock;
+use crate::events::{VisioEvent, VisioEventListener};
+
+/// Entries kept in memory.
+pub const MAX_JOURNAL_ENTRIES: usize = 2000;
+
+/// Size of the journal file before it is rotated to `<file>.1`.
+pub const MAX_JOURNAL_FILE_BYTES: u64 = 1024 * 1024;
+
+/// Longest description kept for an event.
+const MAX_DESCRIPTION_CHARS: usize = 500;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JournalSource {
+    /// Emitted by the core to the UI.
+    Visio,
+    /// Received from LiveKit.
+    Room,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct JournalEntry {
+    /// Wall clock time, in milliseconds since the Unix epoch.
+    pub timestamp_ms: i64,
+    pub source: JournalSource,
+    /// Debug representation of the event, truncated.
+    pub event: String,
+}
+
+struct JournalState {
+    entries: VecDeque<JournalEntry>,
+    file: Option<PathBuf>,
+}
+
+/// Bounded log of room events. Obtain it with `RoomManager::journal()`;
+/// it is kept across calls.
+#[derive(Clone)]
+pub struct EventJournal {
+    state: Arc<Mutex<JournalState>>,
+    clock: Arc<dyn Clock>,
+}
+
+impl EventJournal {
+    pub fn new(clock: Arc<dyn Clock>) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(JournalState {
+                entries: VecDeque::new(),
+                file: None,
+            })),
+            clock,
+        }
+    }
+
+    /// Also append entries to `path`, or stop with `None`. Its directory
+    /// is created if needed.
+    pub fn set_file(&self, path: Option<PathBuf>) {
+        if let Some(dir) = path.as_deref().and_then(Path::parent)
+            && let Err(e) = fs::create_dir_all(dir)
+        {
+            tracing::warn!("event journal {}: {e}", dir.display());
+        }
+        self.lock().file = path;
+    }
+
+    /// Entries in memory, oldest first.
+    pub fn entries(&self) -> Vec<JournalEntry> {
+        self.lock().entries.iter().cloned().collect()
+    }
+
+    /// Entries in memory as JSON lines, oldest first, to attach to a
+    /// report.
+    pub fn export(&self) -> String {
+        self.lock()
+            .entries
+            .iter()
+            .filter_map(|entry| serde_json::to_string(entry).ok())
+            .map(|line| line + "\n")
+            .collect()
+    }
+
+    pub fn clear(&self) {
+        self.lock().entries.clear();
+    }
+
+    /// Record an event received from LiveKit, from the event loop.
+    pub(crate) fn record_room_event(&self, event: &RoomEvent) {
+        let description = match event {
+            RoomEvent::ChatMessage { .. } => "ChatMessage".to_string(),
+            RoomEvent::DataReceived { topic, .. } => format!("DataReceived {{ topic: {topic:?} }}"),
+            RoomEvent::TextStreamOpened { topic, .. } => {
+                format!("TextStreamOpened {{ topic: {topic:?} }}")
+            }
+            event => format!("{event:?}"),
+        };
+        self.record(JournalSource::Room, description);
+    }
+
+    fn record(&self, source: JournalSource, description: String) {
+        let entry = JournalEntry {
+            timestamp_ms: self.clock.utc_now().timestamp_millis(),
+            source,
+            event: truncate(description),
+        };
+        let mut state = self.lock();
+        if let Some(path) = &state.file
+            && let Err(e) = append(path, &entry)
+        {
+            tracing::warn!("event journal {}: {e}", path.display());
+            state.file = None;
+        }
+        if state.entries.len() == MAX_JOURNAL_ENTRIES {
+            state.entries.pop_front();
+        }
+        state.entries.push_back(entry);
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, JournalState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl VisioEventListener for EventJournal {
+    fn on_event(&self, event: VisioEvent) {
+        let description = match &event {
+            VisioEvent::ChatMessageReceived(_) => "ChatMessageReceived".to_string(),
+            VisioEvent::DataMessageReceived { topic, .. } => {
+                format!("DataMessageReceived {{ topic: {topic:?} }}")
+            }
+            event => format!("{event:?}"),
+        };
+        self.record(JournalSource::Visio, description);
+    }
+}
+
+fn truncate(mut description: String) -> String {
+    if let Some((end, _)) = description.char_indices().nth(MAX_DESCRIPTION_CHARS) {
+        description.truncate(end);
+        description.push('…');
+    }
+    description
+}
+
+fn append(path: &Path, entry: &JournalEntry) -> std::io::Result<()> {
+    if fs::metadata(path).is_ok_and(|m| m.len() >= MAX_JOURNAL_FILE_BYTES) {
+        let mut rotated = path.as_os_str().to_owned();
+        rotated.push(".1");
+        fs::rename(path, rotated)?;
+    }
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    let line = serde_json::to_string(entry).map_err(std::io::Error::other)?;
+    writeln!(file, "{line}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::MockClock;
+    use crate::events::{ChatMessage, ChatMessageKind, ConnectionState};
+    use std::time::Duration;
+
+    #[test]
+    fn records_in_order_and_bounded() {
+        let clock = MockClock::new();
+        let journal = EventJournal::new(Arc::new(clock.clone()));
+        journal.on_event(VisioEvent::WaitingForHost);
+        clock.advance(Duration::from_millis(250));
+        journal.on_event(VisioEvent::ConnectionStateChanged(
+            ConnectionState::Connected,
+        ));
+
+        let entries = journal.entries();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].event, "WaitingForHost");
+        assert!(entries[1].event.starts_with("ConnectionStateChanged"));
+        assert_eq!(entries[1].timestamp_ms - entries[0].timestamp_ms, 250);
+        assert_eq!(entries[1].source, JournalSource::Visio);
+
+        for _ in 0..MAX_JOURNAL_ENTRIES {
+            journal.on_event(VisioEvent::EntryGranted);
+        }
+        let entries = journal.entries();
+        assert_eq!(entries.len(), MAX_JOURNAL_ENTRIES);
+        assert!(entries.iter().all(|e| e.event == "EntryGranted"));
+    }
+
+    #[test]
+    fn redacts_content_and_truncates() {
+        let journal = EventJournal::new(Arc::new(MockClock::new()));
+        journal.on_event(VisioEvent::ChatMessageReceived(ChatMessage {
+            id: "m1".into(),
+            sender_sid: "PA_1".into(),
+            sender_name: "Alice".into(),
+            text: "secret".into(),
+            timestamp_ms: 0,
+            kind: ChatMessageKind::Text,
+        }));
+        let speakers = vec!["x".repeat(MAX_DESCRIPTION_CHARS); 2];
+        journal.on_event(VisioEvent::ActiveSpeakersChanged(speakers));
+
+        let entries = journal.entries();
+        assert_eq!(entries[0].event, "ChatMessageReceived");
+        assert_eq!(entries[1].event.chars().count(), MAX_DESCRIPTION_CHARS + 1);
+        assert!(entries[1].event.ends_with('…'));
+    }
+
+    #[test]
+    fn exports_and_appends_json_lines() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("journal.jsonl");
+        let journal = EventJournal::new(Arc::new(MockClock::new()));
+        journal.set_file(Some(path.clone()));
+        journal.on_event(VisioEvent::WaitingForHost);
+        journal.on_event(VisioEvent::EntryGranted);
+
+        let export = journal.export();
+        assert_eq!(fs::read_to_string(&path).unwrap(), export);
+        let lines: Vec<serde_json::Value> = export
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[1]["source"], "visio");
+        assert_eq!(lines[1]["event"], "EntryGranted");
+
+        journal.clear();
+        assert!(journal.export().is_empty());
+    }
+}
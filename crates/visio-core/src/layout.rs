@@ -0,0 +1,273 @@
+//! Shared participant ordering for grid / filmstrip layouts.
+//!
+//! `LayoutService` listens to room events and keeps remote participants
+//! sorted as:
+//!
+//! 1. participants sharing their screen,
+//! 2. pinned participants, in pin order,
+//! 3. active speakers, loudest first,
+//! 4. everyone else, in join order.
+//!
+//! Each participant appears once, at its highest rank. A
+//! `LayoutOrderChanged` event is emitted whenever the order changes, so
+//! every platform renders the same order. The local self-view tile is
+//! not part of the order; UIs place it themselves.
+//!
+//! Join/leave tracking needs the participant lists, so it is not updated
+//! by count-only `ParticipantBatchUpdate`s.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use crate::events::{ConnectionState, EventEmitter, TrackSource, VisioEvent, VisioEventListener};
+
+#[derive(Default)]
+struct LayoutState {
+    /// Remote participants in join order.
+    joined: Vec<String>,
+    pinned: Vec<String>,
+    active_speakers: Vec<String>,
+    /// Screen share track SID -> participant SID.
+    screen_shares: HashMap<String, String>,
+    /// Last order reported to listeners.
+    order: Vec<String>,
+}
+
+impl LayoutState {
+    fn compute_order(&self) -> Vec<String> {
+        let sharers = self
+            .joined
+            .iter()
+            .filter(|sid| self.screen_shares.values().any(|s| s == *sid));
+        let ranked = sharers
+            .chain(self.pinned.iter())
+            .chain(self.active_speakers.iter())
+            .chain(self.joined.iter());
+
+        let mut order: Vec<String> = Vec::with_capacity(self.joined.len());
+        for sid in ranked {
+            if self.joined.contains(sid) && !order.contains(sid) {
+                order.push(sid.clone());
+            }
+        }
+        order
+    }
+
+    fn remove_participant(&mut self, sid: &str) {
+        self.joined.retain(|s| s != sid);
+        self.pinned.retain(|s| s != sid);
+        self.active_speakers.retain(|s| s != sid);
+        self.screen_shares.retain(|_, p| p != sid);
+    }
+
+    /// Apply an event. Returns whether the inputs of the order changed.
+    fn apply(&mut self, event: &VisioEvent) -> bool {
+        match event {
+            VisioEvent::ParticipantJoined(info) => {
+                if self.joined.contains(&info.sid) {
+                    return false;
+                }
+                self.joined.push(info.sid.clone());
+            }
+            VisioEvent::ParticipantLeft(sid) => self.remove_participant(sid),
+            VisioEvent::ParticipantBatchUpdate { joined, left, .. } => {
+                for sid in left {
+                    self.remove_participant(sid);
+                }
+                for info in joined {
+                    if !self.joined.contains(&info.sid) {
+                        self.joined.push(info.sid.clone());
+                    }
+                }
+            }
+            VisioEvent::ActiveSpeakersChanged(sids) => {
+                self.active_speakers = sids.clone();
+            }
+            VisioEvent::TrackSubscribed(info) if info.source == TrackSource::ScreenShare => {
+                self.screen_shares
+                    .insert(info.sid.clone(), info.participant_sid.clone());
+            }
+            VisioEvent::TrackUnsubscribed(track_sid) => {
+                if self.screen_shares.remove(track_sid).is_none() {
+                    return false;
+                }
+            }
+            VisioEvent::RoomSwitched { .. }
+            | VisioEvent::ConnectionStateChanged(ConnectionState::Disconnected) => {
+                *self = LayoutState {
+                    order: std::mem::take(&mut self.order),
+                    ..Default::default()
+                };
+            }
+            _ => return false,
+        }
+        true
+    }
+}
+
+/// Maintains the shared participant order. Obtain it with
+/// `RoomManager::layout()`.
+#[derive(Clone)]
+pub struct LayoutService {
+    emitter: EventEmitter,
+    state: Arc<Mutex<LayoutState>>,
+}
+
+impl LayoutService {
+    pub(crate) fn new(emitter: EventEmitter) -> Self {
+        Self {
+            emitter,
+            state: Arc::new(Mutex::new(LayoutState::default())),
+        }
+    }
+
+    /// Pin a participant so it is ranked right after screen sharers.
+    pub fn pin_participant(&self, sid: &str) {
+        self.update(|state| {
+            if state.pinned.iter().any(|s| s == sid) {
+                return false;
+            }
+            state.pinned.push(sid.to_string());
+            true
+        });
+    }
+
+    pub fn unpin_participant(&self, sid: &str) {
+        self.update(|state| {
+            let before = state.pinned.len();
+            state.pinned.retain(|s| s != sid);
+            state.pinned.len() != before
+        });
+    }
+
+    pub fn pinned_participants(&self) -> Vec<String> {
+        self.state
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .pinned
+            .clone()
+    }
+
+    /// Remote participant SIDs in display order.
+    pub fn ordered_participants(&self) -> Vec<String> {
+        self.state
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .order
+            .clone()
+    }
+
+    /// Run `f` on the state; if it reports a change, recompute the order
+    /// and emit `LayoutOrderChanged` when it differs from the last one.
+    fn update(&self, f: impl FnOnce(&mut LayoutState) -> bool) {
+        let changed = {
+            let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+            if !f(&mut state) {
+                return;
+            }
+            let order = state.compute_order();
+            if order == state.order {
+                None
+            } else {
+                state.order = order.clone();
+                Some(order)
+            }
+        };
+        if let Some(order) = changed {
+            self.emitter.emit(VisioEvent::LayoutOrderChanged(order));
+        }
+    }
+}
+
+impl VisioEventListener for LayoutService {
+    fn on_event(&self, event: VisioEvent) {
+        self.update(|state| state.apply(&event));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::{ConnectionQuality, ParticipantInfo, TrackInfo, TrackKind};
+    use crate::presence::Presence;
+
+    struct Capture(Mutex<Vec<Vec<String>>>);
+
+    impl VisioEventListener for Capture {
+        fn on_event(&self, event: VisioEvent) {
+            if let VisioEvent::LayoutOrderChanged(order) = event {
+                self.0.lock().unwrap().push(order);
+            }
+        }
+    }
+
+    fn setup() -> (EventEmitter, LayoutService, Arc<Capture>) {
+        let emitter = EventEmitter::new();
+        let layout = LayoutService::new(emitter.clone());
+        emitter.add_listener(Arc::new(layout.clone()));
+        let capture = Arc::new(Capture(Mutex::new(Vec::new())));
+        emitter.add_listener(capture.clone());
+        (emitter, layout, capture)
+    }
+
+    fn join(emitter: &EventEmitter, sid: &str) {
+        emitter.emit(VisioEvent::ParticipantJoined(ParticipantInfo {
+            sid: sid.into(),
+            identity: sid.into(),
+            name: None,
+            is_muted: false,
+            has_video: false,
+            video_track_sid: None,
+            connection_quality: ConnectionQuality::Good,
+            presence: Presence::Available,
+        }));
+    }
+
+    #[test]
+    fn ranks_screen_share_pins_speakers_then_join_order() {
+        let (emitter, layout, _) = setup();
+        for sid in ["a", "b", "c", "d"] {
+            join(&emitter, sid);
+        }
+        assert_eq!(layout.ordered_participants(), ["a", "b", "c", "d"]);
+
+        emitter.emit(VisioEvent::ActiveSpeakersChanged(vec!["d".into()]));
+        assert_eq!(layout.ordered_participants(), ["d", "a", "b", "c"]);
+
+        layout.pin_participant("c");
+        assert_eq!(layout.ordered_participants(), ["c", "d", "a", "b"]);
+
+        emitter.emit(VisioEvent::TrackSubscribed(TrackInfo {
+            sid: "TR_screen".into(),
+            participant_sid: "b".into(),
+            kind: TrackKind::Video,
+            source: TrackSource::ScreenShare,
+        }));
+        assert_eq!(layout.ordered_participants(), ["b", "c", "d", "a"]);
+
+        emitter.emit(VisioEvent::TrackUnsubscribed("TR_screen".into()));
+        layout.unpin_participant("c");
+        emitter.emit(VisioEvent::ActiveSpeakersChanged(Vec::new()));
+        assert_eq!(layout.ordered_participants(), ["a", "b", "c", "d"]);
+    }
+
+    #[test]
+    fn emits_only_on_change_and_forgets_departed_pins() {
+        let (emitter, layout, capture) = setup();
+        join(&emitter, "a");
+        join(&emitter, "b");
+        layout.pin_participant("b");
+        layout.pin_participant("b");
+        emitter.emit(VisioEvent::ActiveSpeakersChanged(vec!["b".into()]));
+        assert_eq!(capture.0.lock().unwrap().len(), 3);
+
+        emitter.emit(VisioEvent::ParticipantLeft("b".into()));
+        assert!(layout.pinned_participants().is_empty());
+        assert_eq!(layout.ordered_participants(), ["a"]);
+
+        emitter.emit(VisioEvent::ConnectionStateChanged(
+            ConnectionState::Disconnected,
+        ));
+        assert!(layout.ordered_participants().is_empty());
+    }
+}
@@ -3,27 +3,158 @@
 //! Pure Rust crate with no platform dependencies.
 //! Consumed by native UI shells via UniFFI bindings.
 
+pub mod actions;
+pub mod attributes;
+pub mod audio_ducking;
 pub mod audio_playout;
 pub mod auth;
+pub mod auto_gain;
+pub mod av_sync;
+pub mod avatar;
+pub mod cache;
+pub mod call_summary;
+pub mod camera_control;
+pub mod capture_sources;
 pub mod chat;
+pub mod chat_format;
+pub mod chat_payload;
+pub mod chat_unread;
+pub mod clock;
 pub mod controls;
+pub mod data_channel;
+pub mod data_paths;
+pub mod deep_link;
+pub mod device_check;
+pub mod device_permissions;
+pub mod diagnostics;
 pub mod errors;
 pub mod events;
+pub mod features;
+pub mod feedback;
+pub mod frame_convert;
 pub mod hand_raise;
+pub mod http_retry;
+pub mod i18n;
+pub mod idle;
+pub mod invite;
+pub mod join_leave;
+pub mod join_options;
+pub mod journal;
+pub mod layout;
+pub mod local_recording;
+pub mod media_buttons;
+pub mod meet_api;
+pub mod meeting_timer;
+pub mod metrics;
+pub mod moderation;
+pub mod overlay;
 pub mod participants;
+pub mod permissions;
+pub mod polls;
+pub mod power;
+pub mod presence;
+pub mod qr;
+pub mod quality_policy;
+pub mod recording;
+pub mod restream;
 pub mod room;
+pub mod rtc_config;
 pub mod settings;
+pub mod spotlight;
+pub mod stats;
+pub mod system_call;
+pub mod system_messages;
+pub mod test_media;
+pub mod tile_layout;
+pub mod track_recovery;
+pub mod video_privacy;
+pub mod visibility;
+pub mod whiteboard;
 
-pub use audio_playout::AudioPlayoutBuffer;
-pub use auth::{AuthService, TokenInfo};
-pub use chat::ChatService;
-pub use controls::MeetingControls;
+pub use actions::MeetingAction;
+pub use attributes::{
+    AttributesService, MAX_ATTRIBUTE_KEY_BYTES, MAX_ATTRIBUTE_VALUE_BYTES,
+    MAX_ATTRIBUTES_UPDATE_BYTES,
+};
+pub use audio_ducking::{AudioDucker, DuckingConfig};
+pub use audio_playout::{AudioPlayoutBuffer, TapId};
+pub use auth::{AuthService, EntryStatus, RoomAccess, TokenInfo};
+pub use auto_gain::{AutoGain, AutoGainConfig};
+pub use av_sync::{AvSyncMonitor, AvSyncStats};
+pub use avatar::generate_avatar;
+pub use cache::{CacheService, DiskCache, RoomInfo};
+pub use call_summary::{CallSummary, CallSummaryTracker};
+pub use camera_control::{CameraCommand, CameraCommandListener, CameraControl, CameraControlState};
+pub use capture_sources::{CaptureSources, PublishedSources, capture_sources};
+pub use chat::{ChatExportFormat, ChatService, DEFAULT_MAX_MESSAGE_BYTES};
+pub use chat_format::{MessageSpan, SpanKind, message_body};
+pub use chat_payload::{IncomingPayload, PayloadRejection, RejectedPayloads, parse_data_packet};
+pub use chat_unread::{ChatUnread, UnreadTracker};
+#[cfg(any(test, feature = "test-util"))]
+pub use clock::MockClock;
+pub use clock::{Clock, SystemClock, system_clock};
+pub use controls::{
+    AudioPreset, AudioPublishOptions, CaptureConstraints, MeetingControls, PendingMedia,
+    PendingMediaQueue, ScreenShareOptions, ScreenShareSources, VideoCodec, VideoContentHint,
+};
+pub use data_channel::DataChannelService;
+pub use data_paths::DataPaths;
+pub use deep_link::DeepLink;
+pub use device_check::{DeviceCheck, DeviceCheckReport};
+pub use device_permissions::{DevicePermission, DevicePermissions, PermissionState};
+pub use diagnostics::{CheckResult, ConnectivityDiagnosis};
 pub use errors::VisioError;
 pub use events::{
-    ChatMessage, ConnectionQuality, ConnectionState, EventEmitter, ParticipantInfo, TrackInfo,
-    TrackKind, TrackSource, VisioEvent, VisioEventListener,
+    ChatMessage, ChatMessageKind, ConnectStep, ConnectionQuality, ConnectionState, EventEmitter,
+    ParticipantInfo, TrackInfo, TrackKind, TrackSource, VisioEvent, VisioEventListener,
+};
+pub use features::FeatureFlags;
+pub use feedback::FeedbackService;
+pub use frame_convert::{I420Frame, PixelFormat, RawVideoFrame};
+pub use hand_raise::{AutoLowerConfig, HandRaiseManager};
+pub use http_retry::RetryPolicy;
+pub use idle::{IdleConfig, IdleMonitor};
+pub use invite::{DialInInfo, InviteInfo, InviteService, build_invite_text};
+pub use join_leave::{JoinLeaveCoalescer, JoinLeaveConfig, JoinLeaveMode};
+pub use join_options::JoinOptions;
+pub use journal::{
+    EventJournal, JournalEntry, JournalSource, MAX_JOURNAL_ENTRIES, MAX_JOURNAL_FILE_BYTES,
 };
-pub use hand_raise::HandRaiseManager;
-pub use participants::ParticipantManager;
-pub use room::RoomManager;
+pub use layout::LayoutService;
+pub use local_recording::{LocalRecorder, LocalRecordingFormat, LocalRecordingOptions};
+pub use media_buttons::{MediaButton, MediaButtonDebouncer};
+#[cfg(any(test, feature = "test-util"))]
+pub use meet_api::MockMeetApi;
+pub use meet_api::{
+    EntryRequest, FeedbackRequest, HttpMeetApi, InviteRequest, MeetApi, MeetApiResponse,
+    RecordingAction, RecordingRequest, RoomRequest, RoomUpdateRequest, WhiteboardRequest,
+};
+pub use meeting_timer::MeetingTimer;
+pub use metrics::{CallMetrics, HttpMetricsSink, MetricsReporter, MetricsSink, NegotiatedCodecs};
+pub use moderation::ModerationService;
+pub use overlay::{OverlayCorner, VideoOverlay, video_overlay};
+pub use participants::{DEFAULT_SPEAKER_HOLD, ParticipantManager};
+pub use permissions::LocalPermissions;
+pub use polls::{Poll, PollsService};
+pub use power::{PowerMode, PowerState, QualityReductionReason, ThermalState};
+pub use presence::{Presence, PresenceService};
+pub use qr::generate_room_qr;
+pub use quality_policy::{QualityPolicy, QualityPolicyConfig};
+pub use recording::{RecordingMode, RecordingService, RecordingStatus};
+pub use restream::{RestreamOptions, RestreamState, RestreamStatus, Restreamer};
+pub use room::{ConnectTimeouts, ReconnectPolicy, RoomManager};
+pub use rtc_config::{IceServerConfig, RtcConfigOverride};
 pub use settings::{Settings, SettingsStore};
+pub use spotlight::{Spotlight, SpotlightService};
+pub use stats::{
+    QUALITY_HISTORY_WINDOW, QUALITY_SAMPLE_INTERVAL, QualitySample, StatsCollector,
+    VideoReceiveStats,
+};
+pub use system_call::{CallDirection, SystemCall, SystemCallState, SystemCallTracker};
+pub use system_messages::SystemMessages;
+pub use test_media::{TestAudioConfig, TestMediaOptions, TestPattern, TestVideoConfig};
+pub use tile_layout::{LayoutMode, LayoutOptions, TileRect, compute_layout};
+pub use track_recovery::TrackRecovery;
+pub use video_privacy::{VideoPrivacy, VideoPrivacyStyle, video_privacy};
+pub use visibility::TrackVisibility;
+pub use whiteboard::{WhiteboardService, WhiteboardSession};
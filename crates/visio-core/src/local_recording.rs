@@ -0,0 +1,469 @@
+//! Local call recording to a file.
+//!
+//! `LocalRecorder` taps the audio playout buffer, so the file contains
+//! exactly what the local user hears (remote participants, 48 kHz mono).
+//!
+//! WAV files are written by the core itself, on every platform: a
+//! dedicated thread writes 16-bit PCM, and the tap only copies blocks into
+//! a bounded channel and drops them if the writer falls behind, so the
+//! audio output never blocks.
+//!
+//! The core ships no encoder, so MP4, WebM and audio-only Ogg recordings
+//! drive ffmpeg through the same `Session` as `Restreamer`, which makes
+//! them a desktop feature. MP4 and WebM also contain the video of the
+//! active speaker, which `RoomManager` switches like a restream's.
+
+use std::fs::File;
+use std::io::{BufWriter, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::mpsc;
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+use crate::audio_playout::{AudioPlayoutBuffer, TapId};
+use crate::errors::VisioError;
+use crate::events::{EventEmitter, VisioEvent};
+use crate::restream::{
+    Media, RestreamOptions, Session, SpeakerVideo, StateCallback, ffmpeg_input_args,
+};
+
+const SAMPLE_RATE: u32 = 48_000;
+const CHANNELS: u16 = 1;
+const BYTES_PER_SAMPLE: u16 = 2;
+const HEADER_LEN: u64 = 44;
+
+/// Blocks queued between the audio thread and the writer (a few seconds
+/// at usual output block sizes).
+const QUEUE_BLOCKS: usize = 512;
+
+/// Progress events are emitted once per second of recorded audio.
+const PROGRESS_INTERVAL_BYTES: u64 = SAMPLE_RATE as u64 * BYTES_PER_SAMPLE as u64;
+
+/// How often the size of an ffmpeg recording is reported.
+const PROGRESS_INTERVAL: Duration = Duration::from_secs(1);
+
+/// ffmpeg gets this long to write the end of the file once stopped.
+const FINISH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Container of a local recording.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LocalRecordingFormat {
+    /// 16-bit PCM audio, written without ffmpeg.
+    #[default]
+    Wav,
+    /// Opus audio, through ffmpeg.
+    Ogg,
+    /// H.264 video and AAC audio, through ffmpeg.
+    Mp4,
+    /// VP9 video and Opus audio, through ffmpeg.
+    WebM,
+}
+
+impl LocalRecordingFormat {
+    /// File extension, without the dot.
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Wav => "wav",
+            Self::Ogg => "ogg",
+            Self::Mp4 => "mp4",
+            Self::WebM => "webm",
+        }
+    }
+
+    pub fn has_video(self) -> bool {
+        matches!(self, Self::Mp4 | Self::WebM)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LocalRecordingOptions {
+    /// Stop writing once the file reaches this size. `None` records until
+    /// stopped.
+    pub max_size_bytes: Option<u64>,
+    pub format: LocalRecordingFormat,
+    /// Video size, frame rate, bitrates and ffmpeg executable of the
+    /// formats encoded by ffmpeg. WAV recordings ignore it.
+    pub encoding: RestreamOptions,
+}
+
+/// Thread writing the file.
+enum Writer {
+    Wav(JoinHandle<std::io::Result<u64>>),
+    Ffmpeg(FfmpegWriter),
+}
+
+/// ffmpeg encoding the recording, and the thread reporting its progress.
+struct FfmpegWriter {
+    media: Media,
+    session: Session,
+    /// Dropped to end the progress thread.
+    stop_progress: mpsc::Sender<()>,
+    progress: JoinHandle<()>,
+}
+
+/// A running local recording. Started with
+/// `RoomManager::start_local_recording`.
+pub struct LocalRecorder {
+    path: PathBuf,
+    playout: Arc<AudioPlayoutBuffer>,
+    tap: TapId,
+    writer: Option<Writer>,
+}
+
+impl LocalRecorder {
+    /// Create the file at `path` and start recording what `playout` plays,
+    /// in `options.format`.
+    pub fn start(
+        path: impl AsRef<Path>,
+        options: LocalRecordingOptions,
+        playout: Arc<AudioPlayoutBuffer>,
+        emitter: EventEmitter,
+    ) -> Result<Self, VisioError> {
+        match options.format {
+            LocalRecordingFormat::Wav => Self::start_wav(path, options, playout, emitter),
+            _ => Self::start_ffmpeg(path, options, playout, emitter),
+        }
+    }
+
+    fn start_wav(
+        path: impl AsRef<Path>,
+        options: LocalRecordingOptions,
+        playout: Arc<AudioPlayoutBuffer>,
+        emitter: EventEmitter,
+    ) -> Result<Self, VisioError> {
+        let path = path.as_ref().to_path_buf();
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent).map_err(|e| {
+                VisioError::Room(format!("cannot create {}: {e}", parent.display()))
+            })?;
+        }
+        let mut file = BufWriter::new(
+            File::create(&path)
+                .map_err(|e| VisioError::Room(format!("cannot create {}: {e}", path.display())))?,
+        );
+        write_wav_header(&mut file, 0)
+            .map_err(|e| VisioError::Room(format!("cannot write {}: {e}", path.display())))?;
+
+        let (tx, rx) = mpsc::sync_channel::<Vec<i16>>(QUEUE_BLOCKS);
+        let writer = std::thread::Builder::new()
+            .name("visio-local-recording".into())
+            .spawn(move || write_samples(file, rx, options, emitter))
+            .map_err(|e| VisioError::Room(format!("cannot start recording thread: {e}")))?;
+        let tap = playout.add_tap(Box::new(move |samples| {
+            // Full queue: drop the block rather than stall playback.
+            let _ = tx.try_send(samples.to_vec());
+        }));
+        tracing::info!("local recording started: {}", path.display());
+
+        Ok(Self {
+            path,
+            playout,
+            tap,
+            writer: Some(Writer::Wav(writer)),
+        })
+    }
+
+    fn start_ffmpeg(
+        path: impl AsRef<Path>,
+        options: LocalRecordingOptions,
+        playout: Arc<AudioPlayoutBuffer>,
+        emitter: EventEmitter,
+    ) -> Result<Self, VisioError> {
+        let path = path.as_ref().to_path_buf();
+        let mut encoding = options.encoding.clone();
+        if !options.format.has_video() {
+            // ffmpeg still reads the video input: keep it tiny.
+            (encoding.width, encoding.height, encoding.fps) = (16, 16, 1);
+        }
+        if encoding.width == 0 || encoding.height == 0 || encoding.video_bitrate_kbps == 0 {
+            return Err(VisioError::Room("invalid local recording options".into()));
+        }
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent).map_err(|e| {
+                VisioError::Room(format!("cannot create {}: {e}", parent.display()))
+            })?;
+        }
+
+        let media = Media::new(&encoding);
+        let on_state: StateCallback = Arc::new(|_, error| {
+            // ffmpeg also exits on its own at `max_size_bytes`.
+            if let Some(error) = error {
+                tracing::info!("local recording ended: {error}");
+            }
+        });
+        let args = |video_port, audio_port| {
+            Ok::<_, VisioError>(ffmpeg_args(
+                &path, &options, &encoding, video_port, audio_port,
+            ))
+        };
+        let session = Session::start(&encoding, &media, &args, on_state)?;
+
+        let (stop_progress, stopped) = mpsc::channel::<()>();
+        let progress = {
+            let path = path.clone();
+            let started = Instant::now();
+            std::thread::Builder::new()
+                .name("visio-local-recording".into())
+                .spawn(move || {
+                    let report = || {
+                        let size = std::fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+                        emitter.emit(VisioEvent::LocalRecordingProgress {
+                            duration_ms: started.elapsed().as_millis() as u64,
+                            size_bytes: size,
+                        });
+                    };
+                    while let Err(mpsc::RecvTimeoutError::Timeout) =
+                        stopped.recv_timeout(PROGRESS_INTERVAL)
+                    {
+                        report();
+                    }
+                    report();
+                })
+                .map_err(|e| VisioError::Room(format!("cannot start recording thread: {e}")))?
+        };
+        let tap = media.tap(&playout);
+        tracing::info!("local recording started: {}", path.display());
+
+        Ok(Self {
+            path,
+            playout,
+            tap,
+            writer: Some(Writer::Ffmpeg(FfmpegWriter {
+                media,
+                session,
+                stop_progress,
+                progress,
+            })),
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The recorded video, for `RoomManager` to follow the active speaker.
+    /// `None` for audio-only formats.
+    pub(crate) fn speaker_video(&self) -> Option<&Arc<SpeakerVideo>> {
+        match &self.writer {
+            Some(Writer::Ffmpeg(ffmpeg)) => Some(&ffmpeg.media.video),
+            _ => None,
+        }
+    }
+
+    /// Stop recording and finalize the file. Returns its size in bytes.
+    pub fn stop(mut self) -> Result<u64, VisioError> {
+        self.finish()
+    }
+
+    fn finish(&mut self) -> Result<u64, VisioError> {
+        // Dropping the tap closes the channel and ends the writer thread.
+        self.playout.remove_tap(self.tap);
+        let size = match self.writer.take() {
+            None => return Ok(0),
+            Some(Writer::Wav(writer)) => writer
+                .join()
+                .map_err(|_| VisioError::Room("recording thread panicked".into()))?
+                .map_err(|e| {
+                    VisioError::Room(format!("cannot write {}: {e}", self.path.display()))
+                })?,
+            Some(Writer::Ffmpeg(ffmpeg)) => {
+                ffmpeg.media.video.set_track(None);
+                ffmpeg.session.finish(FINISH_TIMEOUT);
+                drop(ffmpeg.stop_progress);
+                let _ = ffmpeg.progress.join();
+                std::fs::metadata(&self.path)
+                    .map_err(|e| {
+                        VisioError::Room(format!("cannot write {}: {e}", self.path.display()))
+                    })?
+                    .len()
+            }
+        };
+        tracing::info!(
+            "local recording stopped: {} ({size} bytes)",
+            self.path.display()
+        );
+        Ok(size)
+    }
+}
+
+impl Drop for LocalRecorder {
+    fn drop(&mut self) {
+        if self.writer.is_some()
+            && let Err(e) = self.finish()
+        {
+            tracing::warn!("local recording: {e}");
+        }
+    }
+}
+
+/// Command line for ffmpeg encoding its inputs to `path`.
+fn ffmpeg_args(
+    path: &Path,
+    options: &LocalRecordingOptions,
+    encoding: &RestreamOptions,
+    video_port: u16,
+    audio_port: u16,
+) -> Vec<String> {
+    let video_bitrate = format!("{}k", encoding.video_bitrate_kbps);
+    let audio_bitrate = format!("{}k", encoding.audio_bitrate_kbps);
+    let gop = (encoding.fps.max(1) * 2).to_string();
+    #[rustfmt::skip]
+    let output: &[&str] = match options.format {
+        LocalRecordingFormat::Mp4 => &[
+            "-c:v", "libx264", "-preset", "veryfast", "-b:v", &video_bitrate, "-g", &gop,
+            "-c:a", "aac", "-b:a", &audio_bitrate,
+            "-f", "mp4",
+        ],
+        LocalRecordingFormat::WebM => &[
+            "-c:v", "libvpx-vp9", "-deadline", "realtime", "-cpu-used", "8",
+            "-b:v", &video_bitrate, "-g", &gop,
+            "-c:a", "libopus", "-b:a", &audio_bitrate,
+            "-f", "webm",
+        ],
+        LocalRecordingFormat::Ogg | LocalRecordingFormat::Wav => &[
+            "-map", "1:a", "-c:a", "libopus", "-b:a", &audio_bitrate,
+            "-f", "ogg",
+        ],
+    };
+    let mut args = ffmpeg_input_args(encoding, video_port, audio_port);
+    args.extend(output.iter().map(|arg| arg.to_string()));
+    if let Some(max) = options.max_size_bytes {
+        args.extend(["-fs".to_string(), max.to_string()]);
+    }
+    args.extend(["-y".to_string(), path.to_string_lossy().into_owned()]);
+    args
+}
+
+/// Writer thread: append blocks until the tap is removed or the size limit
+/// is reached, then patch the header with the final sizes.
+fn write_samples(
+    mut file: BufWriter<File>,
+    rx: mpsc::Receiver<Vec<i16>>,
+    options: LocalRecordingOptions,
+    emitter: EventEmitter,
+) -> std::io::Result<u64> {
+    let max_data = options
+        .max_size_bytes
+        .map(|max| max.saturating_sub(HEADER_LEN))
+        .unwrap_or(u64::from(u32::MAX) - HEADER_LEN);
+    let mut data_len: u64 = 0;
+    let mut next_progress = PROGRESS_INTERVAL_BYTES;
+
+    while let Ok(block) = rx.recv() {
+        let room = (max_data - data_len) / u64::from(BYTES_PER_SAMPLE);
+        let take = block.len().min(room as usize);
+        for sample in &block[..take] {
+            file.write_all(&sample.to_le_bytes())?;
+        }
+        data_len += take as u64 * u64::from(BYTES_PER_SAMPLE);
+        if data_len >= next_progress {
+            next_progress = data_len + PROGRESS_INTERVAL_BYTES;
+            emit_progress(&emitter, data_len);
+        }
+        if take < block.len() {
+            tracing::info!("local recording reached its size limit");
+            break;
+        }
+    }
+
+    write_wav_header(&mut file, data_len as u32)?;
+    file.flush()?;
+    emit_progress(&emitter, data_len);
+    Ok(HEADER_LEN + data_len)
+}
+
+fn emit_progress(emitter: &EventEmitter, data_len: u64) {
+    let byte_rate = u64::from(SAMPLE_RATE) * u64::from(CHANNELS) * u64::from(BYTES_PER_SAMPLE);
+    emitter.emit(VisioEvent::LocalRecordingProgress {
+        duration_ms: data_len * 1000 / byte_rate,
+        size_bytes: HEADER_LEN + data_len,
+    });
+}
+
+/// Write (or rewrite, seeking back to the start) the 44-byte WAV header
+/// for `data_len` bytes of PCM data.
+fn write_wav_header<W: Write + Seek>(out: &mut W, data_len: u32) -> std::io::Result<()> {
+    let block_align = CHANNELS * BYTES_PER_SAMPLE;
+    let byte_rate = SAMPLE_RATE * u32::from(block_align);
+    out.seek(SeekFrom::Start(0))?;
+    out.write_all(b"RIFF")?;
+    out.write_all(&(36 + data_len).to_le_bytes())?;
+    out.write_all(b"WAVEfmt ")?;
+    out.write_all(&16u32.to_le_bytes())?;
+    out.write_all(&1u16.to_le_bytes())?; // PCM
+    out.write_all(&CHANNELS.to_le_bytes())?;
+    out.write_all(&SAMPLE_RATE.to_le_bytes())?;
+    out.write_all(&byte_rate.to_le_bytes())?;
+    out.write_all(&block_align.to_le_bytes())?;
+    out.write_all(&(BYTES_PER_SAMPLE * 8).to_le_bytes())?;
+    out.write_all(b"data")?;
+    out.write_all(&data_len.to_le_bytes())?;
+    out.seek(SeekFrom::End(0))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn records_pulled_audio_to_wav() {
+        let dir = std::env::temp_dir().join(format!("visio-rec-{}", std::process::id()));
+        let path = dir.join("call.wav");
+        let playout = Arc::new(AudioPlayoutBuffer::new());
+        let recorder = LocalRecorder::start(
+            &path,
+            LocalRecordingOptions {
+                max_size_bytes: Some(HEADER_LEN + 6),
+            },
+            playout.clone(),
+            EventEmitter::new(),
+        )
+        .unwrap();
+
+        playout.push_samples(&[1, -2]);
+        let mut out = [0i16; 4];
+        playout.pull_samples(&mut out);
+        // Past the size limit: dropped.
+        playout.pull_samples(&mut out);
+
+        let size = recorder.stop().unwrap();
+        let bytes = std::fs::read(&path).unwrap();
+        std::fs::remove_dir_all(&dir).ok();
+
+        assert_eq!(size, HEADER_LEN + 6);
+        assert_eq!(bytes.len() as u64, size);
+        assert_eq!(&bytes[0..4], b"RIFF");
+        assert_eq!(u32::from_le_bytes(bytes[40..44].try_into().unwrap()), 6);
+        assert_eq!(&bytes[44..], &[1, 0, 0xfe, 0xff, 0, 0]);
+    }
+
+    #[test]
+    fn ffmpeg_encodes_the_requested_container() {
+        let args = |format, max_size_bytes| {
+            let options = LocalRecordingOptions {
+                max_size_bytes,
+                format,
+                encoding: RestreamOptions::default(),
+            };
+            let path = Path::new("/data/call");
+            ffmpeg_args(path, &options, &options.encoding, 4000, 4001)
+        };
+
+        let mp4 = args(LocalRecordingFormat::Mp4, None);
+        assert!(mp4.windows(2).any(|w| w == ["-i", "tcp://127.0.0.1:4000"]));
+        assert!(mp4.windows(2).any(|w| w == ["-c:v", "libx264"]));
+        assert!(mp4.ends_with(&["-f".into(), "mp4".into(), "-y".into(), "/data/call".into()]));
+
+        let webm = args(LocalRecordingFormat::WebM, Some(1_000_000));
+        assert!(webm.windows(2).any(|w| w == ["-c:v", "libvpx-vp9"]));
+        assert!(webm.windows(2).any(|w| w == ["-fs", "1000000"]));
+
+        // Audio only: the video input is not mapped.
+        let ogg = args(LocalRecordingFormat::Ogg, None);
+        assert!(ogg.windows(2).any(|w| w == ["-map", "1:a"]));
+        assert!(!ogg.iter().any(|arg| arg == "-c:v"));
+        assert!(!LocalRecordingFormat::Ogg.has_video());
+    }
+}
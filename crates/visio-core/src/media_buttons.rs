@@ -0,0 +1,79 @@
+//! Bluetooth and wired headset buttons.
+//!
+//! Each platform reports headset buttons its own way (Android key events,
+//! iOS remote commands, desktop media keys). Shells translate them to a
+//! `MediaButton` and hand it to `RoomManager::handle_media_button`, so a
+//! press does the same everywhere: play/pause toggles the microphone and
+//! hang-up leaves the call. Headsets often deliver one press twice (key
+//! down and up, or once per audio route), hence the debounce.
+
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Repeats of the same button closer than this are one press.
+pub const MEDIA_BUTTON_DEBOUNCE: Duration = Duration::from_millis(400);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MediaButton {
+    /// Play/pause, or the single button of a wired headset: toggles the
+    /// microphone.
+    PlayPause,
+    /// Hang-up (end call) button: leaves the call.
+    Hangup,
+}
+
+impl MediaButton {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::PlayPause => "play_pause",
+            Self::Hangup => "hangup",
+        }
+    }
+}
+
+impl fmt::Display for MediaButton {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Drops repeated presses of the same button.
+#[derive(Clone, Default)]
+pub struct MediaButtonDebouncer {
+    last: Arc<Mutex<Option<(MediaButton, Instant)>>>,
+}
+
+impl MediaButtonDebouncer {
+    /// Whether a press of `button` at `now` should be handled.
+    pub fn accept(&self, button: MediaButton, now: Instant) -> bool {
+        let mut last = self.last.lock().unwrap_or_else(|e| e.into_inner());
+        let repeat = last.is_some_and(|(previous, at)| {
+            previous == button && now.saturating_duration_since(at) < MEDIA_BUTTON_DEBOUNCE
+        });
+        if !repeat {
+            *last = Some((button, now));
+        }
+        !repeat
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repeated_presses_are_debounced() {
+        let debouncer = MediaButtonDebouncer::default();
+        let t0 = Instant::now();
+        let ms = Duration::from_millis;
+
+        assert!(debouncer.accept(MediaButton::PlayPause, t0));
+        assert!(!debouncer.accept(MediaButton::PlayPause, t0 + ms(100)));
+        // The window runs from the last handled press.
+        assert!(!debouncer.accept(MediaButton::PlayPause, t0 + ms(350)));
+        assert!(debouncer.accept(MediaButton::PlayPause, t0 + ms(450)));
+        // Another button is not a repeat.
+        assert!(debouncer.accept(MediaButton::Hangup, t0 + ms(500)));
+    }
+}
@@ -0,0 +1,662 @@
+//! Transport abstraction for the La Suite Meet REST API.
+//!
+//! `AuthService` interprets Meet API responses (status codes, JSON body);
+//! the actual HTTP round-trip goes through a `MeetApi` implementation so
+//! RoomManager can be exercised offline with `MockMeetApi`, built for the
+//! crate's own tests and with the `test-util` feature.
+
+#[cfg(any(test, feature = "test-util"))]
+use std::collections::VecDeque;
+use std::future::Future;
+use std::pin::Pin;
+#[cfg(any(test, feature = "test-util"))]
+use std::sync::Mutex;
+
+use crate::diagnostics::{self, ConnectivityDiagnosis};
+use crate::errors::VisioError;
+use crate::http_retry::{self, AttemptFailure, RetryPolicy};
+
+/// Raw HTTP response from the Meet API.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MeetApiResponse {
+    pub status: u16,
+    pub body: String,
+    /// Raw `Set-Cookie` header values.
+    pub set_cookies: Vec<String>,
+}
+
+/// A room lookup request (`GET /api/v1.0/rooms/{slug}/`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoomRequest {
+    pub instance: String,
+    pub slug: String,
+    pub username: Option<String>,
+    pub session_cookie: Option<String>,
+}
+
+/// A lobby entry request for a restricted room
+/// (`POST /api/v1.0/rooms/{id}/request-entry/`).
+///
+/// The same request is sent again while waiting for the host;
+/// `lobby_cookie` identifies the waiting participant between polls.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntryRequest {
+    pub instance: String,
+    pub room_id: String,
+    pub username: Option<String>,
+    pub session_cookie: Option<String>,
+    pub lobby_cookie: Option<String>,
+}
+
+/// Email invitations to a room (`POST /api/v1.0/rooms/{id}/invite/`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InviteRequest {
+    pub instance: String,
+    pub room_id: String,
+    pub emails: Vec<String>,
+    pub session_cookie: Option<String>,
+}
+
+/// Change of a room's access level (`PATCH /api/v1.0/rooms/{id}/`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoomUpdateRequest {
+    pub instance: String,
+    pub room_id: String,
+    pub access_level: String,
+    pub session_cookie: Option<String>,
+}
+
+/// Start or stop the cloud recording of a room
+/// (`POST /api/v1.0/rooms/{id}/start-recording/` or `.../stop-recording/`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordingRequest {
+    pub instance: String,
+    pub room_id: String,
+    pub action: RecordingAction,
+    pub session_cookie: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RecordingAction {
+    /// Start recording; `mode` is `screen_recording` or `transcript`.
+    Start {
+        mode: String,
+    },
+    Stop,
+}
+
+/// Whiteboard session of a room (`GET /api/v1.0/rooms/{id}/whiteboard/`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WhiteboardRequest {
+    pub instance: String,
+    pub room_id: String,
+    pub session_cookie: Option<String>,
+}
+
+/// Rating of a call by the user (`POST /api/v1.0/feedback/`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeedbackRequest {
+    pub instance: String,
+    /// From 1 (bad) to 5 (excellent).
+    pub rating: u8,
+    pub comments: Option<String>,
+    /// `CallSummary` of the rated call, if shared.
+    pub call_summary: Option<serde_json::Value>,
+    /// Event journal as JSON lines, if shared.
+    pub logs: Option<String>,
+    pub session_cookie: Option<String>,
+}
+
+/// Cookie the Meet backend uses to recognize a waiting participant.
+pub const LOBBY_COOKIE_NAME: &str = "lobbyParticipantId";
+
+pub type MeetApiFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<MeetApiResponse, VisioError>> + Send + 'a>>;
+
+pub type DiagnosisFuture<'a> =
+    Pin<Box<dyn Future<Output = Option<ConnectivityDiagnosis>> + Send + 'a>>;
+
+/// Performs Meet API HTTP requests.
+///
+/// Transport failures are reported as `VisioError::Http`; any HTTP status,
+/// including errors, is returned as a `MeetApiResponse` for `AuthService`
+/// to interpret.
+pub trait MeetApi: Send + Sync {
+    fn get_room<'a>(&'a self, request: &'a RoomRequest) -> MeetApiFuture<'a>;
+
+    /// Ask the host of a restricted room to let us in. Transports that do
+    /// not support the lobby fail with `VisioError::Http`.
+    fn request_entry<'a>(&'a self, _request: &'a EntryRequest) -> MeetApiFuture<'a> {
+        Box::pin(async { Err(VisioError::Http("lobby not supported".into())) })
+    }
+
+    /// Instance configuration (`GET /api/v1.0/config/`).
+    fn get_config<'a>(&'a self, _instance: &'a str) -> MeetApiFuture<'a> {
+        Box::pin(async { Err(VisioError::Http("config not supported".into())) })
+    }
+
+    /// Send email invitations to a room.
+    fn invite<'a>(&'a self, _request: &'a InviteRequest) -> MeetApiFuture<'a> {
+        Box::pin(async { Err(VisioError::Http("invitations not supported".into())) })
+    }
+
+    /// Update room settings (owners and administrators only).
+    fn update_room<'a>(&'a self, _request: &'a RoomUpdateRequest) -> MeetApiFuture<'a> {
+        Box::pin(async { Err(VisioError::Http("room update not supported".into())) })
+    }
+
+    /// Start or stop the cloud recording of a room (owners and
+    /// administrators only).
+    fn update_recording<'a>(&'a self, _request: &'a RecordingRequest) -> MeetApiFuture<'a> {
+        Box::pin(async { Err(VisioError::Http("recording not supported".into())) })
+    }
+
+    /// URL and credentials of the collaborative whiteboard of a room.
+    fn get_whiteboard_session<'a>(&'a self, _request: &'a WhiteboardRequest) -> MeetApiFuture<'a> {
+        Box::pin(async { Err(VisioError::Http("whiteboard not supported".into())) })
+    }
+
+    /// Send the user's rating of a call.
+    fn submit_feedback<'a>(&'a self, _request: &'a FeedbackRequest) -> MeetApiFuture<'a> {
+        Box::pin(async { Err(VisioError::Http("feedback not supported".into())) })
+    }
+
+    /// Probe the network path to `url` after a failed connection. Returns
+    /// `None` when the transport cannot run a diagnosis.
+    fn diagnose_connectivity<'a>(&'a self, _url: &'a str) -> DiagnosisFuture<'a> {
+        Box::pin(async { None })
+    }
+}
+
+fn http_client() -> Result<reqwest::Client, VisioError> {
+    reqwest::Client::builder()
+        .redirect(reqwest::redirect::Policy::none())
+        .build()
+        .map_err(|e| VisioError::Http(e.to_string()))
+}
+
+async fn read_response(resp: reqwest::Response) -> Result<MeetApiResponse, VisioError> {
+    let status = resp.status().as_u16();
+    let set_cookies = resp
+        .headers()
+        .get_all(reqwest::header::SET_COOKIE)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .map(str::to_string)
+        .collect();
+    let body = resp
+        .text()
+        .await
+        .map_err(|e| VisioError::Http(e.to_string()))?;
+    Ok(MeetApiResponse {
+        status,
+        body,
+        set_cookies,
+    })
+}
+
+/// Default implementation backed by reqwest.
+///
+/// Failed requests are retried according to a `RetryPolicy` (see
+/// `http_retry`).
+#[derive(Debug, Clone, Default)]
+pub struct HttpMeetApi {
+    retry: RetryPolicy,
+}
+
+impl HttpMeetApi {
+    pub fn with_retry_policy(retry: RetryPolicy) -> Self {
+        Self { retry }
+    }
+
+    /// Send the request built by `build`, retrying it on transient
+    /// failures. `idempotent` requests may be sent twice.
+    async fn send(
+        &self,
+        idempotent: bool,
+        build: impl Fn(&reqwest::Client) -> reqwest::RequestBuilder,
+    ) -> Result<MeetApiResponse, VisioError> {
+        let client = http_client()?;
+        let mut retry = 0;
+        loop {
+            let result = build(&client)
+                .timeout(self.retry.request_timeout)
+                .send()
+                .await;
+            let failure = match &result {
+                Ok(resp) => AttemptFailure::Status {
+                    status: resp.status().as_u16(),
+                    retry_after: resp
+                        .headers()
+                        .get(reqwest::header::RETRY_AFTER)
+                        .and_then(|v| v.to_str().ok())
+                        .and_then(http_retry::parse_retry_after),
+                },
+                Err(e) if e.is_connect() => AttemptFailure::Connect,
+                Err(_) => AttemptFailure::Transport,
+            };
+            let Some(delay) = self.retry.delay(retry, idempotent, failure) else {
+                let resp = result.map_err(|e| VisioError::Http(e.to_string()))?;
+                return read_response(resp).await;
+            };
+            match &result {
+                Ok(resp) => tracing::warn!(
+                    "Meet API {} returned {}, retrying in {:?}",
+                    resp.url().path(),
+                    resp.status(),
+                    delay
+                ),
+                Err(e) => tracing::warn!("Meet API request failed: {e}, retrying in {delay:?}"),
+            }
+            drop(result);
+            tokio::time::sleep(delay).await;
+            retry += 1;
+        }
+    }
+}
+
+impl MeetApi for HttpMeetApi {
+    fn get_room<'a>(&'a self, request: &'a RoomRequest) -> MeetApiFuture<'a> {
+        Box::pin(async move {
+            let mut api_url = format!(
+                "https://{}/api/v1.0/rooms/{}/",
+                request.instance, request.slug
+            );
+            if let Some(name) = &request.username {
+                let encoded = urlencoding::encode(name);
+                api_url.push_str(&format!("?username={encoded}"));
+            }
+
+            tracing::info!("requesting token from Meet API: {}", api_url);
+
+            self.send(true, |client| {
+                let mut req = client.get(&api_url);
+                if let Some(cookie) = &request.session_cookie {
+                    req = req.header("Cookie", format!("sessionid={cookie}"));
+                }
+                req
+            })
+            .await
+        })
+    }
+
+    fn request_entry<'a>(&'a self, request: &'a EntryRequest) -> MeetApiFuture<'a> {
+        Box::pin(async move {
+            let api_url = format!(
+                "https://{}/api/v1.0/rooms/{}/request-entry/",
+                request.instance, request.room_id
+            );
+            tracing::debug!("requesting room entry: {}", api_url);
+
+            let cookies: Vec<String> = [
+                request
+                    .session_cookie
+                    .as_ref()
+                    .map(|c| format!("sessionid={c}")),
+                request
+                    .lobby_cookie
+                    .as_ref()
+                    .map(|c| format!("{LOBBY_COOKIE_NAME}={c}")),
+            ]
+            .into_iter()
+            .flatten()
+            .collect();
+
+            // Entry requests are polled anyway: sending one twice is harmless.
+            self.send(true, |client| {
+                let mut req = client
+                    .post(&api_url)
+                    .json(&serde_json::json!({ "username": request.username }));
+                if !cookies.is_empty() {
+                    req = req.header("Cookie", cookies.join("; "));
+                }
+                req
+            })
+            .await
+        })
+    }
+
+    fn get_config<'a>(&'a self, instance: &'a str) -> MeetApiFuture<'a> {
+        Box::pin(async move {
+            let api_url = format!("https://{instance}/api/v1.0/config/");
+            self.send(true, |client| client.get(&api_url)).await
+        })
+    }
+
+    fn invite<'a>(&'a self, request: &'a InviteRequest) -> MeetApiFuture<'a> {
+        Box::pin(async move {
+            let api_url = format!(
+                "https://{}/api/v1.0/rooms/{}/invite/",
+                request.instance, request.room_id
+            );
+            tracing::info!(
+                "inviting {} participant(s): {}",
+                request.emails.len(),
+                api_url
+            );
+
+            self.send(false, |client| {
+                let mut req = client
+                    .post(&api_url)
+                    .json(&serde_json::json!({ "emails": request.emails }));
+                if let Some(cookie) = &request.session_cookie {
+                    req = req.header("Cookie", format!("sessionid={cookie}"));
+                }
+                req
+            })
+            .await
+        })
+    }
+
+    fn update_room<'a>(&'a self, request: &'a RoomUpdateRequest) -> MeetApiFuture<'a> {
+        Box::pin(async move {
+            let api_url = format!(
+                "https://{}/api/v1.0/rooms/{}/",
+                request.instance, request.room_id
+            );
+            tracing::info!(
+                "setting room access level to {}: {}",
+                request.access_level,
+                api_url
+            );
+
+            self.send(true, |client| {
+                let mut req = client
+                    .patch(&api_url)
+                    .json(&serde_json::json!({ "access_level": request.access_level }));
+                if let Some(cookie) = &request.session_cookie {
+                    req = req.header("Cookie", format!("sessionid={cookie}"));
+                }
+                req
+            })
+            .await
+        })
+    }
+
+    fn update_recording<'a>(&'a self, request: &'a RecordingRequest) -> MeetApiFuture<'a> {
+        Box::pin(async move {
+            let (endpoint, body) = match &request.action {
+                RecordingAction::Start { mode } => {
+                    ("start-recording", serde_json::json!({ "mode": mode }))
+                }
+                RecordingAction::Stop => ("stop-recording", serde_json::json!({})),
+            };
+            let api_url = format!(
+                "https://{}/api/v1.0/rooms/{}/{}/",
+                request.instance, request.room_id, endpoint
+            );
+            tracing::info!("recording request: {}", api_url);
+
+            self.send(false, |client| {
+                let mut req = client.post(&api_url).json(&body);
+                if let Some(cookie) = &request.session_cookie {
+                    req = req.header("Cookie", format!("sessionid={cookie}"));
+                }
+                req
+            })
+            .await
+        })
+    }
+
+    fn get_whiteboard_session<'a>(&'a self, request: &'a WhiteboardRequest) -> MeetApiFuture<'a> {
+        Box::pin(async move {
+            let api_url = format!(
+                "https://{}/api/v1.0/rooms/{}/whiteboard/",
+                request.instance, request.room_id
+            );
+            tracing::info!("fetching whiteboard session: {}", api_url);
+
+            self.send(true, |client| {
+                let mut req = client.get(&api_url);
+                if let Some(cookie) = &request.session_cookie {
+                    req = req.header("Cookie", format!("sessionid={cookie}"));
+                }
+                req
+            })
+            .await
+        })
+    }
+
+    fn submit_feedback<'a>(&'a self, request: &'a FeedbackRequest) -> MeetApiFuture<'a> {
+        Box::pin(async move {
+            let api_url = format!("https://{}/api/v1.0/feedback/", request.instance);
+            tracing::info!("submitting call feedback: {}", api_url);
+
+            let body = serde_json::json!({
+                "rating": request.rating,
+                "comments": request.comments,
+                "call_summary": request.call_summary,
+                "logs": request.logs,
+                "platform": std::env::consts::OS,
+                "app_version": env!("CARGO_PKG_VERSION"),
+            });
+            self.send(false, |client| {
+                let mut req = client.post(&api_url).json(&body);
+                if let Some(cookie) = &request.session_cookie {
+                    req = req.header("Cookie", format!("sessionid={cookie}"));
+                }
+                req
+            })
+            .await
+        })
+    }
+
+    fn diagnose_connectivity<'a>(&'a self, url: &'a str) -> DiagnosisFuture<'a> {
+        Box::pin(async move { Some(diagnostics::diagnose(url).await) })
+    }
+}
+
+/// Scripted `MeetApi` for tests.
+///
+/// Responses are returned in the order they were queued; once the queue
+/// is empty every request fails with `VisioError::Http`. All requests are
+/// recorded for later assertions.
+#[cfg(any(test, feature = "test-util"))]
+#[derive(Debug, Default)]
+pub struct MockMeetApi {
+    responses: Mutex<VecDeque<Result<MeetApiResponse, VisioError>>>,
+    requests: Mutex<Vec<RoomRequest>>,
+    entry_requests: Mutex<Vec<EntryRequest>>,
+    invite_requests: Mutex<Vec<InviteRequest>>,
+    update_requests: Mutex<Vec<RoomUpdateRequest>>,
+    recording_requests: Mutex<Vec<RecordingRequest>>,
+    whiteboard_requests: Mutex<Vec<WhiteboardRequest>>,
+    feedback_requests: Mutex<Vec<FeedbackRequest>>,
+    diagnosis: Mutex<Option<ConnectivityDiagnosis>>,
+}
+
+#[cfg(any(test, feature = "test-util"))]
+impl MockMeetApi {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queue an HTTP response with the given status and body.
+    pub fn push_response(&self, status: u16, body: impl Into<String>) {
+        self.push_result(Ok(MeetApiResponse {
+            status,
+            body: body.into(),
+            set_cookies: Vec::new(),
+        }));
+    }
+
+    /// Queue a lobby answer (`waiting`, `accepted` or `denied`), optionally
+    /// setting the lobby cookie. Accepted answers carry LiveKit credentials.
+    pub fn push_entry_status(&self, status: &str, lobby_cookie: Option<&str>) {
+        let mut body = serde_json::json!({ "status": status, "livekit": null });
+        if status == "accepted" {
+            body["livekit"] = serde_json::json!({ "url": "https://lk", "token": "jwt-token" });
+        }
+        self.push_result(Ok(MeetApiResponse {
+            status: 200,
+            body: body.to_string(),
+            set_cookies: lobby_cookie
+                .map(|c| format!("{LOBBY_COOKIE_NAME}={c}; Path=/; HttpOnly"))
+                .into_iter()
+                .collect(),
+        }));
+    }
+
+    /// Queue a successful room response carrying LiveKit credentials.
+    pub fn push_token(&self, livekit_url: &str, token: &str) {
+        let body = serde_json::json!({
+            "livekit": { "url": livekit_url, "token": token }
+        });
+        self.push_response(200, body.to_string());
+    }
+
+    /// Queue a transport-level failure.
+    pub fn push_error(&self, error: VisioError) {
+        self.push_result(Err(error));
+    }
+
+    fn push_result(&self, result: Result<MeetApiResponse, VisioError>) {
+        self.responses
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push_back(result);
+    }
+
+    /// Requests received so far.
+    pub fn requests(&self) -> Vec<RoomRequest> {
+        self.requests
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// Lobby entry requests received so far.
+    pub fn entry_requests(&self) -> Vec<EntryRequest> {
+        self.entry_requests
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// Invitation requests received so far.
+    pub fn invite_requests(&self) -> Vec<InviteRequest> {
+        self.invite_requests
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// Room update requests received so far.
+    pub fn update_requests(&self) -> Vec<RoomUpdateRequest> {
+        self.update_requests
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// Recording requests received so far.
+    pub fn recording_requests(&self) -> Vec<RecordingRequest> {
+        self.recording_requests
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// Whiteboard session requests received so far.
+    pub fn whiteboard_requests(&self) -> Vec<WhiteboardRequest> {
+        self.whiteboard_requests
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// Feedback submissions received so far.
+    pub fn feedback_requests(&self) -> Vec<FeedbackRequest> {
+        self.feedback_requests
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// Diagnosis returned by `diagnose_connectivity` (none by default).
+    pub fn set_diagnosis(&self, diagnosis: ConnectivityDiagnosis) {
+        *self.diagnosis.lock().unwrap_or_else(|e| e.into_inner()) = Some(diagnosis);
+    }
+
+    fn next_response(&self) -> MeetApiFuture<'static> {
+        let next = self
+            .responses
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .pop_front()
+            .unwrap_or_else(|| Err(VisioError::Http("no mock response queued".into())));
+        Box::pin(async move { next })
+    }
+}
+
+#[cfg(any(test, feature = "test-util"))]
+impl MeetApi for MockMeetApi {
+    fn get_room<'a>(&'a self, request: &'a RoomRequest) -> MeetApiFuture<'a> {
+        self.requests
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(request.clone());
+        self.next_response()
+    }
+
+    fn request_entry<'a>(&'a self, request: &'a EntryRequest) -> MeetApiFuture<'a> {
+        self.entry_requests
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(request.clone());
+        self.next_response()
+    }
+
+    fn get_config<'a>(&'a self, _instance: &'a str) -> MeetApiFuture<'a> {
+        self.next_response()
+    }
+
+    fn invite<'a>(&'a self, request: &'a InviteRequest) -> MeetApiFuture<'a> {
+        self.invite_requests
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(request.clone());
+        self.next_response()
+    }
+
+    fn update_room<'a>(&'a self, request: &'a RoomUpdateRequest) -> MeetApiFuture<'a> {
+        self.update_requests
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(request.clone());
+        self.next_response()
+    }
+
+    fn update_recording<'a>(&'a self, request: &'a RecordingRequest) -> MeetApiFuture<'a> {
+        self.recording_requests
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(request.clone());
+        self.next_response()
+    }
+
+    fn get_whiteboard_session<'a>(&'a self, request: &'a WhiteboardRequest) -> MeetApiFuture<'a> {
+        self.whiteboard_requests
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(request.clone());
+        self.next_response()
+    }
+
+    fn submit_feedback<'a>(&'a self, request: &'a FeedbackRequest) -> MeetApiFuture<'a> {
+        self.feedback_requests
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(request.clone());
+        self.next_response()
+    }
+
+    fn diagnose_connectivity<'a>(&'a self, _url: &'a str) -> DiagnosisFuture<'a> {
+        let diagnosis = self
+            .diagnosis
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone();
+        Box::pin(async move { diagnosis })
+    }
+}
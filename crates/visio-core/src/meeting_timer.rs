@@ -0,0 +1,176 @@
+//! Scheduled end of the meeting.
+//!
+//! Some Meet instances close rooms at a scheduled time or after a maximum
+//! duration, and report it with the room (`TokenInfo::scheduled_end`,
+//! `TokenInfo::max_duration`). The core then counts down: it emits
+//! `MeetingEndingSoon` as each warning threshold passes and `MeetingEnded`
+//! at the deadline, so shells can warn users before the room closes. The
+//! server closes the room itself.
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use tokio::task::JoinHandle;
+
+use crate::auth::TokenInfo;
+use crate::events::{EventEmitter, VisioEvent};
+
+/// Minutes left at which `MeetingEndingSoon` is emitted, latest first.
+pub const WARNING_MINUTES: [u32; 3] = [10, 5, 1];
+
+fn minutes(m: u32) -> Duration {
+    Duration::from_secs(u64::from(m) * 60)
+}
+
+/// Counts down to the end of the meeting, if it has one.
+#[derive(Clone)]
+pub struct MeetingTimer {
+    deadline: Arc<Mutex<Option<Instant>>>,
+    task: Arc<Mutex<Option<JoinHandle<()>>>>,
+    emitter: EventEmitter,
+}
+
+impl MeetingTimer {
+    pub fn new(emitter: EventEmitter) -> Self {
+        Self {
+            deadline: Arc::new(Mutex::new(None)),
+            task: Arc::new(Mutex::new(None)),
+            emitter,
+        }
+    }
+
+    /// Deadline of `room` joined at `now` (wall clock `wall`): the earlier
+    /// of its scheduled end and its maximum duration, counted from the join.
+    pub fn deadline_for(room: &TokenInfo, now: Instant, wall: DateTime<Utc>) -> Option<Instant> {
+        let scheduled = room
+            .scheduled_end
+            .map(|end| now + (end - wall).to_std().unwrap_or_default());
+        let max = room.max_duration.map(|max| now + max);
+        match (scheduled, max) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        }
+    }
+
+    /// Start counting down for the room just joined, replacing any
+    /// previous countdown. Must be called from a tokio runtime.
+    pub(crate) fn start(&self, room: &TokenInfo) {
+        self.reset();
+        let now = Instant::now();
+        let Some(deadline) = Self::deadline_for(room, now, Utc::now()) else {
+            return;
+        };
+        tracing::info!(
+            "meeting ends in {}s",
+            deadline.saturating_duration_since(now).as_secs()
+        );
+        *self.deadline.lock().unwrap_or_else(|e| e.into_inner()) = Some(deadline);
+        let emitter = self.emitter.clone();
+        let task = tokio::spawn(async move {
+            for (wait, minutes_left) in warning_schedule(deadline.saturating_duration_since(now)) {
+                tokio::time::sleep_until((now + wait).into()).await;
+                emitter.emit(VisioEvent::MeetingEndingSoon { minutes_left });
+            }
+            tokio::time::sleep_until(deadline.into()).await;
+            emitter.emit(VisioEvent::MeetingEnded);
+        });
+        *self.task.lock().unwrap_or_else(|e| e.into_inner()) = Some(task);
+    }
+
+    /// Stop the countdown (left the room).
+    pub(crate) fn reset(&self) {
+        if let Some(task) = self.task.lock().unwrap_or_else(|e| e.into_inner()).take() {
+            task.abort();
+        }
+        *self.deadline.lock().unwrap_or_else(|e| e.into_inner()) = None;
+    }
+
+    /// Time left before the meeting ends, `None` when it has no end.
+    pub fn time_remaining(&self) -> Option<Duration> {
+        self.deadline
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .map(|deadline| deadline.saturating_duration_since(Instant::now()))
+    }
+}
+
+/// Warnings due with `remaining` time left: (wait from now, minutes left).
+/// Joining inside the warning window warns right away.
+fn warning_schedule(remaining: Duration) -> Vec<(Duration, u32)> {
+    let mut schedule: Vec<(Duration, u32)> = WARNING_MINUTES
+        .iter()
+        .filter_map(|&m| remaining.checked_sub(minutes(m)).map(|wait| (wait, m)))
+        .collect();
+    let joined_late = schedule.len() < WARNING_MINUTES.len()
+        && !remaining.is_zero()
+        && schedule.first().is_none_or(|(wait, _)| !wait.is_zero());
+    if joined_late {
+        let left = remaining.as_secs().div_ceil(60) as u32;
+        schedule.insert(0, (Duration::ZERO, left));
+    }
+    schedule
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn room(scheduled_end: Option<DateTime<Utc>>, max_duration: Option<Duration>) -> TokenInfo {
+        TokenInfo {
+            livekit_url: "wss://lk".into(),
+            token: "jwt".into(),
+            access_level: None,
+            room_id: None,
+            pin_code: None,
+            scheduled_end,
+            max_duration,
+        }
+    }
+
+    #[test]
+    fn deadline_is_the_earlier_limit() {
+        let (now, wall) = (Instant::now(), Utc::now());
+        let in_30 = wall + chrono::Duration::minutes(30);
+        assert_eq!(
+            MeetingTimer::deadline_for(&room(None, None), now, wall),
+            None
+        );
+        assert_eq!(
+            MeetingTimer::deadline_for(&room(Some(in_30), None), now, wall),
+            Some(now + minutes(30))
+        );
+        assert_eq!(
+            MeetingTimer::deadline_for(&room(Some(in_30), Some(minutes(20))), now, wall),
+            Some(now + minutes(20))
+        );
+        // Already over.
+        let past = wall - chrono::Duration::minutes(1);
+        assert_eq!(
+            MeetingTimer::deadline_for(&room(Some(past), None), now, wall),
+            Some(now)
+        );
+    }
+
+    #[test]
+    fn warnings_follow_the_thresholds() {
+        assert_eq!(
+            warning_schedule(minutes(60)),
+            [(minutes(50), 10), (minutes(55), 5), (minutes(59), 1)]
+        );
+        // Joined with 7 minutes left: warned at once.
+        assert_eq!(
+            warning_schedule(minutes(7)),
+            [(Duration::ZERO, 7), (minutes(2), 5), (minutes(6), 1)]
+        );
+        assert_eq!(
+            warning_schedule(minutes(5)),
+            [(Duration::ZERO, 5), (minutes(4), 1)]
+        );
+        assert_eq!(
+            warning_schedule(Duration::from_secs(30)),
+            [(Duration::ZERO, 1)]
+        );
+        assert!(warning_schedule(Duration::ZERO).is_empty());
+    }
+}
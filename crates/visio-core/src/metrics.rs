@@ -0,0 +1,471 @@
+//! Opt-in, anonymized call quality metrics.
+//!
+//! `MetricsReporter` follows the connection state machine of one room and,
+//! at the end of each call, hands a `CallMetrics` report to a pluggable
+//! `MetricsSink` (e.g. `HttpMetricsSink`, a JSON POST to a deployment's
+//! collector). Nothing is measured or sent unless a sink is set, which
+//! `apply_settings` only does when the user enabled metrics.
+//!
+//! Reports carry no identity, room or URL: only a random per-call id,
+//! the platform, the app version and the quality figures below.
+//!
+//! `codecs_in_use` reads the negotiated codecs from the same WebRTC
+//! stats, for `RoomManager::negotiated_codecs`.
+
+use livekit::prelude::Room;
+use livekit::webrtc::stats::RtcStats;
+use serde::Serialize;
+use std::collections::HashSet;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+
+use crate::clock::Clock;
+use crate::errors::VisioError;
+use crate::events::{ConnectionState, VisioEvent, VisioEventListener};
+use crate::settings::Settings;
+
+/// Interval between two packet loss samples during a call (see
+/// `PowerMode::stats_interval`).
+pub(crate) const SAMPLE_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Quality report for one call, sent when it ends.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CallMetrics {
+    /// Random id, unique per call.
+    pub session_id: String,
+    pub platform: String,
+    pub app_version: String,
+    /// From the start of `connect()` to the room being connected.
+    pub join_time_ms: Option<u64>,
+    /// Time spent connected, from join to hang-up.
+    pub duration_secs: u64,
+    /// SDK reconnections plus connection losses.
+    pub reconnect_count: u32,
+    /// Share of received packets lost over the call, in percent.
+    pub avg_packet_loss_pct: Option<f64>,
+}
+
+/// Codecs in use on the media streams of a call, as MIME types (e.g.
+/// "video/H264", "audio/opus").
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NegotiatedCodecs {
+    /// Codecs of the tracks this client publishes.
+    pub sent: Vec<String>,
+    /// Codecs of the subscribed remote tracks.
+    pub received: Vec<String>,
+}
+
+/// Codecs referenced by the RTP streams of one peer connection, sorted.
+/// Codecs offered in the SDP but not used by any stream are left out.
+pub(crate) fn codecs_in_use(stats: &[RtcStats]) -> Vec<String> {
+    let used: HashSet<&str> = stats
+        .iter()
+        .filter_map(|s| match s {
+            RtcStats::OutboundRtp(rtp) => Some(rtp.stream.codec_id.as_str()),
+            RtcStats::InboundRtp(rtp) => Some(rtp.stream.codec_id.as_str()),
+            _ => None,
+        })
+        .collect();
+    let mut codecs: Vec<String> = stats
+        .iter()
+        .filter_map(|s| match s {
+            RtcStats::Codec(c) if used.contains(c.rtc.id.as_str()) => {
+                Some(c.codec.mime_type.clone())
+            }
+            _ => None,
+        })
+        .collect();
+    codecs.sort();
+    codecs.dedup();
+    codecs
+}
+
+pub type MetricsFuture<'a> = Pin<Box<dyn Future<Output = Result<(), VisioError>> + Send + 'a>>;
+
+/// Destination of call reports.
+pub trait MetricsSink: Send + Sync {
+    fn report<'a>(&'a self, metrics: &'a CallMetrics) -> MetricsFuture<'a>;
+}
+
+/// Posts each report as JSON to `endpoint`.
+#[derive(Debug, Clone)]
+pub struct HttpMetricsSink {
+    endpoint: String,
+}
+
+impl HttpMetricsSink {
+    pub fn new(endpoint: impl Into<String>) -> Self {
+        Self {
+            endpoint: endpoint.into(),
+        }
+    }
+}
+
+impl MetricsSink for HttpMetricsSink {
+    fn report<'a>(&'a self, metrics: &'a CallMetrics) -> MetricsFuture<'a> {
+        Box::pin(async move {
+            let resp = reqwest::Client::new()
+                .post(&self.endpoint)
+                .json(metrics)
+                .send()
+                .await
+                .map_err(|e| VisioError::Http(e.to_string()))?;
+            if !resp.status().is_success() {
+                return Err(VisioError::Http(format!(
+                    "metrics endpoint returned {}",
+                    resp.status()
+                )));
+            }
+            Ok(())
+        })
+    }
+}
+
+/// Packet loss over successive cumulative samples. Counters going
+/// backwards (streams ending) restart the delta from zero.
+#[derive(Debug, Default)]
+struct LossAverager {
+    last: (u64, u64),
+    lost: u64,
+    total: u64,
+}
+
+impl LossAverager {
+    fn add_sample(&mut self, lost: u64, received: u64) {
+        let (last_lost, last_received) = self.last;
+        let (d_lost, d_received) = if lost >= last_lost && received >= last_received {
+            (lost - last_lost, received - last_received)
+        } else {
+            (lost, received)
+        };
+        self.lost += d_lost;
+        self.total += d_lost + d_received;
+        self.last = (lost, received);
+    }
+
+    fn average_pct(&self) -> Option<f64> {
+        (self.total > 0).then(|| self.lost as f64 * 100.0 / self.total as f64)
+    }
+}
+
+#[derive(Debug, Default, PartialEq)]
+enum Phase {
+    #[default]
+    Idle,
+    Joining {
+        since: Instant,
+    },
+    InCall,
+    /// Connection lost; the host may call `reconnect()`.
+    Lost,
+}
+
+#[derive(Default)]
+struct CallState {
+    phase: Phase,
+    join_time: Option<Duration>,
+    connected_since: Option<Instant>,
+    reconnects: u32,
+    sdk_reconnecting: bool,
+    loss: LossAverager,
+    sampler: Option<JoinHandle<()>>,
+}
+
+impl CallState {
+    fn finish(&mut self, now: Instant) -> Option<CallMetrics> {
+        if let Some(sampler) = self.sampler.take() {
+            sampler.abort();
+        }
+        let state = std::mem::take(self);
+        let connected_since = state.connected_since?;
+        Some(CallMetrics {
+            session_id: uuid::Uuid::new_v4().to_string(),
+            platform: std::env::consts::OS.to_string(),
+            app_version: env!("CARGO_PKG_VERSION").to_string(),
+            join_time_ms: state.join_time.map(|d| d.as_millis() as u64),
+            duration_secs: now.duration_since(connected_since).as_secs(),
+            reconnect_count: state.reconnects,
+            avg_packet_loss_pct: state.loss.average_pct(),
+        })
+    }
+}
+
+/// Collects call metrics for one room. Obtain it with
+/// `RoomManager::metrics()`.
+#[derive(Clone)]
+pub struct MetricsReporter {
+    room: Arc<Mutex<Option<Arc<Room>>>>,
+    sink: Arc<std::sync::Mutex<Option<Arc<dyn MetricsSink>>>>,
+    state: Arc<std::sync::Mutex<CallState>>,
+    sample_interval: Arc<std::sync::Mutex<Duration>>,
+    clock: Arc<dyn Clock>,
+}
+
+impl MetricsReporter {
+    pub(crate) fn new(room: Arc<Mutex<Option<Arc<Room>>>>, clock: Arc<dyn Clock>) -> Self {
+        Self {
+            room,
+            sink: Arc::new(std::sync::Mutex::new(None)),
+            state: Arc::new(std::sync::Mutex::new(CallState::default())),
+            sample_interval: Arc::new(std::sync::Mutex::new(SAMPLE_INTERVAL)),
+            clock,
+        }
+    }
+
+    /// Change the interval between packet loss samples, from the next one.
+    pub(crate) fn set_sample_interval(&self, interval: Duration) {
+        *self
+            .sample_interval
+            .lock()
+            .unwrap_or_else(|e| e.into_inner()) = interval;
+    }
+
+    /// Set where reports go; `None` disables metrics.
+    pub fn set_sink(&self, sink: Option<Arc<dyn MetricsSink>>) {
+        *self.sink.lock().unwrap_or_else(|e| e.into_inner()) = sink;
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.sink().is_some()
+    }
+
+    /// Enable HTTP reporting if the user opted in and an endpoint is set.
+    pub fn apply_settings(&self, settings: &Settings) {
+        let sink = settings
+            .metrics_endpoint
+            .as_deref()
+            .filter(|endpoint| settings.metrics_enabled && !endpoint.is_empty())
+            .map(|endpoint| Arc::new(HttpMetricsSink::new(endpoint)) as Arc<dyn MetricsSink>);
+        self.set_sink(sink);
+    }
+
+    fn sink(&self) -> Option<Arc<dyn MetricsSink>> {
+        self.sink.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    fn on_state(&self, connection: &ConnectionState) {
+        let now = self.clock.now();
+        let report = {
+            let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+            match connection {
+                ConnectionState::Connecting => {
+                    if state.phase == Phase::Idle {
+                        state.phase = Phase::Joining { since: now };
+                    }
+                    None
+                }
+                ConnectionState::Connected => {
+                    if let Phase::Joining { since } = state.phase {
+                        state.join_time = Some(now.duration_since(since));
+                        state.connected_since = Some(now);
+                        state.sampler = self.spawn_sampler();
+                    }
+                    state.phase = Phase::InCall;
+                    state.sdk_reconnecting = false;
+                    None
+                }
+                ConnectionState::Reconnecting { .. } => {
+                    if state.phase == Phase::InCall && !state.sdk_reconnecting {
+                        state.sdk_reconnecting = true;
+                        state.reconnects += 1;
+                    }
+                    None
+                }
+                ConnectionState::Disconnected => state.finish(now),
+            }
+        };
+        if let Some(report) = report {
+            self.send(report);
+        }
+    }
+
+    fn on_connection_lost(&self) {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        if state.phase == Phase::InCall {
+            state.phase = Phase::Lost;
+            state.reconnects += 1;
+        }
+    }
+
+    fn send(&self, report: CallMetrics) {
+        let (Some(sink), Ok(rt)) = (self.sink(), tokio::runtime::Handle::try_current()) else {
+            return;
+        };
+        rt.spawn(async move {
+            match sink.report(&report).await {
+                Ok(()) => tracing::debug!("call metrics reported"),
+                Err(e) => tracing::warn!("failed to report call metrics: {e}"),
+            }
+        });
+    }
+
+    /// Periodically sample subscriber packet loss while in a call.
+    fn spawn_sampler(&self) -> Option<JoinHandle<()>> {
+        if !self.is_enabled() {
+            return None;
+        }
+        let rt = tokio::runtime::Handle::try_current().ok()?;
+        let room = self.room.clone();
+        let state = self.state.clone();
+        let sample_interval = self.sample_interval.clone();
+        let clock = self.clock.clone();
+        Some(rt.spawn(async move {
+            let mut next_sample = clock.now();
+            loop {
+                clock
+                    .sleep(next_sample.saturating_duration_since(clock.now()))
+                    .await;
+                next_sample += *sample_interval.lock().unwrap_or_else(|e| e.into_inner());
+                let Some(room) = room.lock().await.clone() else {
+                    continue;
+                };
+                let Ok(stats) = room.get_stats().await else {
+                    continue;
+                };
+                let (lost, received) = stats
+                    .subscriber_stats
+                    .iter()
+                    .filter_map(|s| match s {
+                        RtcStats::InboundRtp(rtp) => Some((
+                            rtp.received.packets_lost.max(0) as u64,
+                            rtp.received.packets_received,
+                        )),
+                        _ => None,
+                    })
+                    .fold((0, 0), |(l, r), (dl, dr)| (l + dl, r + dr));
+                state
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .loss
+                    .add_sample(lost, received);
+            }
+        }))
+    }
+}
+
+impl VisioEventListener for MetricsReporter {
+    fn on_event(&self, event: VisioEvent) {
+        match event {
+            VisioEvent::ConnectionStateChanged(state) => self.on_state(&state),
+            VisioEvent::ConnectionLost => self.on_connection_lost(),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::{MockClock, system_clock};
+
+    #[derive(Default)]
+    struct CaptureSink(std::sync::Mutex<Vec<CallMetrics>>);
+
+    impl MetricsSink for CaptureSink {
+        fn report<'a>(&'a self, metrics: &'a CallMetrics) -> MetricsFuture<'a> {
+            self.0.lock().unwrap().push(metrics.clone());
+            Box::pin(async { Ok(()) })
+        }
+    }
+
+    fn state(s: ConnectionState) -> VisioEvent {
+        VisioEvent::ConnectionStateChanged(s)
+    }
+
+    #[test]
+    fn codecs_in_use_ignores_unused_codecs() {
+        use livekit::webrtc::stats::{CodecStats, OutboundRtpStats, dictionaries};
+
+        let codec = |id: &str, mime: &str| {
+            RtcStats::Codec(CodecStats {
+                rtc: dictionaries::RtcStats {
+                    id: id.into(),
+                    ..Default::default()
+                },
+                codec: dictionaries::CodecStats {
+                    mime_type: mime.into(),
+                    ..Default::default()
+                },
+            })
+        };
+        let outbound = |codec_id: &str| {
+            let mut rtp = OutboundRtpStats::default();
+            rtp.stream.codec_id = codec_id.into();
+            RtcStats::OutboundRtp(rtp)
+        };
+        let stats = vec![
+            codec("c1", "video/VP8"),
+            codec("c2", "video/H264"),
+            codec("c3", "audio/opus"),
+            outbound("c2"),
+            outbound("c3"),
+            // Simulcast layers share the codec.
+            outbound("c2"),
+        ];
+        assert_eq!(codecs_in_use(&stats), vec!["audio/opus", "video/H264"]);
+        assert!(codecs_in_use(&[]).is_empty());
+    }
+
+    #[test]
+    fn loss_average_survives_counter_resets() {
+        let mut loss = LossAverager::default();
+        assert_eq!(loss.average_pct(), None);
+        loss.add_sample(1, 99);
+        loss.add_sample(3, 197);
+        // A stream ended: counters restart from its successor's totals.
+        loss.add_sample(0, 100);
+        assert!((loss.average_pct().unwrap() - 1.0).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn reports_one_call_with_reconnects() {
+        let clock = MockClock::new();
+        let reporter = MetricsReporter::new(Arc::new(Mutex::new(None)), Arc::new(clock.clone()));
+        let sink = Arc::new(CaptureSink::default());
+        reporter.set_sink(Some(sink.clone()));
+
+        reporter.on_event(state(ConnectionState::Connecting));
+        clock.advance(Duration::from_millis(1250));
+        reporter.on_event(state(ConnectionState::Connected));
+        clock.advance(Duration::from_secs(90));
+        reporter.on_event(state(ConnectionState::Reconnecting { attempt: 1 }));
+        reporter.on_event(state(ConnectionState::Reconnecting { attempt: 2 }));
+        reporter.on_event(state(ConnectionState::Connected));
+        // Network loss, then the host's reconnect() succeeds.
+        reporter.on_event(VisioEvent::ConnectionLost);
+        reporter.on_event(state(ConnectionState::Reconnecting { attempt: 1 }));
+        reporter.on_event(state(ConnectionState::Connecting));
+        reporter.on_event(state(ConnectionState::Connected));
+        clock.advance(Duration::from_secs(30));
+        reporter.on_event(state(ConnectionState::Disconnected));
+        tokio::task::yield_now().await;
+
+        let reports = sink.0.lock().unwrap();
+        assert_eq!(reports.len(), 1);
+        assert_eq!(reports[0].reconnect_count, 2);
+        assert_eq!(reports[0].join_time_ms, Some(1250));
+        assert_eq!(reports[0].duration_secs, 120);
+        assert_eq!(reports[0].avg_packet_loss_pct, None);
+    }
+
+    #[tokio::test]
+    async fn failed_joins_and_disabled_metrics_report_nothing() {
+        let reporter = MetricsReporter::new(Arc::new(Mutex::new(None)), system_clock());
+        let sink = Arc::new(CaptureSink::default());
+        reporter.set_sink(Some(sink.clone()));
+        reporter.on_event(state(ConnectionState::Connecting));
+        reporter.on_event(state(ConnectionState::Disconnected));
+
+        reporter.apply_settings(&Settings::default());
+        assert!(!reporter.is_enabled());
+        reporter.on_event(state(ConnectionState::Connecting));
+        reporter.on_event(state(ConnectionState::Connected));
+        reporter.on_event(state(ConnectionState::Disconnected));
+        tokio::task::yield_now().await;
+        assert!(sink.0.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,212 @@
+//! Moderator actions on the current room.
+//!
+//! Locking a room sets its Meet access level to `restricted`: nobody joins
+//! directly anymore, latecomers have to knock and be let in from the lobby.
+//! Unlocking restores the access level the room had before.
+
+use std::sync::Arc;
+
+use tokio::sync::Mutex;
+
+use crate::auth::{AuthService, TokenInfo};
+use crate::errors::VisioError;
+use crate::events::{EventEmitter, VisioEvent};
+use crate::meet_api::{MeetApi, RoomUpdateRequest};
+
+/// Access level of a locked room.
+const LOCKED_ACCESS_LEVEL: &str = "restricted";
+
+/// Access level restored when the level before locking is unknown.
+const DEFAULT_ACCESS_LEVEL: &str = "public";
+
+/// Moderator actions, shared with `RoomManager` (see
+/// `RoomManager::moderation()`). The Meet API rejects them unless the
+/// local user owns or administers the room.
+#[derive(Clone)]
+pub struct ModerationService {
+    meet_api: Arc<dyn MeetApi>,
+    emitter: EventEmitter,
+    last_meet_url: Arc<Mutex<Option<String>>>,
+    session_cookie: Arc<Mutex<Option<String>>>,
+    meet_room: Arc<Mutex<Option<TokenInfo>>>,
+    /// Access level to restore on unlock.
+    unlocked_access_level: Arc<Mutex<Option<String>>>,
+}
+
+impl ModerationService {
+    pub(crate) fn new(
+        meet_api: Arc<dyn MeetApi>,
+        emitter: EventEmitter,
+        last_meet_url: Arc<Mutex<Option<String>>>,
+        session_cookie: Arc<Mutex<Option<String>>>,
+        meet_room: Arc<Mutex<Option<TokenInfo>>>,
+    ) -> Self {
+        Self {
+            meet_api,
+            emitter,
+            last_meet_url,
+            session_cookie,
+            meet_room,
+            unlocked_access_level: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Whether the current room only admits participants through the lobby.
+    pub async fn is_room_locked(&self) -> bool {
+        self.meet_room
+            .lock()
+            .await
+            .as_ref()
+            .and_then(|room| room.access_level.as_deref())
+            == Some(LOCKED_ACCESS_LEVEL)
+    }
+
+    /// Lock or unlock the current room. Emits `RoomLockedChanged` when the
+    /// state changed.
+    ///
+    /// Fails with `VisioError::AuthRequired` if the Meet API refuses the
+    /// change (not the room owner) and with `VisioError::Room` if the
+    /// instance does not support it.
+    pub async fn lock_room(&self, locked: bool) -> Result<(), VisioError> {
+        let meet_url = self
+            .last_meet_url
+            .lock()
+            .await
+            .clone()
+            .ok_or_else(|| VisioError::Room("not connected".into()))?;
+        let (room_id, current_level) = self
+            .meet_room
+            .lock()
+            .await
+            .as_ref()
+            .map(|room| (room.room_id.clone(), room.access_level.clone()))
+            .ok_or_else(|| VisioError::Room("not connected".into()))?;
+        let room_id = room_id
+            .ok_or_else(|| VisioError::Room("room id not reported by the Meet API".into()))?;
+        if (current_level.as_deref() == Some(LOCKED_ACCESS_LEVEL)) == locked {
+            return Ok(());
+        }
+
+        let access_level = if locked {
+            LOCKED_ACCESS_LEVEL.to_string()
+        } else {
+            self.unlocked_access_level
+                .lock()
+                .await
+                .clone()
+                .unwrap_or_else(|| DEFAULT_ACCESS_LEVEL.to_string())
+        };
+        let request = RoomUpdateRequest {
+            instance: AuthService::parse_instance(&meet_url)?,
+            room_id,
+            access_level: access_level.clone(),
+            session_cookie: self.session_cookie.lock().await.clone(),
+        };
+        let resp = self.meet_api.update_room(&request).await?;
+        match resp.status {
+            200..=299 => {}
+            401 | 403 => return Err(VisioError::AuthRequired),
+            404 | 405 => {
+                return Err(VisioError::Room(
+                    "room locking is not supported by this instance".into(),
+                ));
+            }
+            status => {
+                return Err(VisioError::Room(format!(
+                    "room update failed with status {status}"
+                )));
+            }
+        }
+
+        *self.unlocked_access_level.lock().await = if locked { current_level } else { None };
+        if let Some(room) = self.meet_room.lock().await.as_mut() {
+            room.access_level = Some(access_level);
+        }
+        tracing::info!("room {}", if locked { "locked" } else { "unlocked" });
+        self.emitter.emit(VisioEvent::RoomLockedChanged(locked));
+        Ok(())
+    }
+
+    /// Forget the level to restore (the current room changed).
+    pub(crate) async fn reset(&self) {
+        *self.unlocked_access_level.lock().await = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::VisioEventListener;
+    use crate::meet_api::MockMeetApi;
+
+    struct LockCapture(std::sync::Mutex<Vec<bool>>);
+
+    impl VisioEventListener for LockCapture {
+        fn on_event(&self, event: VisioEvent) {
+            if let VisioEvent::RoomLockedChanged(locked) = event {
+                self.0.lock().unwrap().push(locked);
+            }
+        }
+    }
+
+    fn connected_service(api: Arc<MockMeetApi>) -> (ModerationService, Arc<LockCapture>) {
+        let emitter = EventEmitter::new();
+        let capture = Arc::new(LockCapture(std::sync::Mutex::new(Vec::new())));
+        emitter.add_listener(capture.clone());
+        let room = TokenInfo {
+            livekit_url: "wss://lk".into(),
+            token: "jwt".into(),
+            access_level: Some("trusted".into()),
+            room_id: Some("room-uuid".into()),
+            pin_code: None,
+            scheduled_end: None,
+            max_duration: None,
+        };
+        let service = ModerationService::new(
+            api,
+            emitter,
+            Arc::new(Mutex::new(Some(
+                "https://meet.example.com/abc-defg-hij".into(),
+            ))),
+            Arc::new(Mutex::new(Some("cookie".into()))),
+            Arc::new(Mutex::new(Some(room))),
+        );
+        (service, capture)
+    }
+
+    #[tokio::test]
+    async fn lock_then_unlock_restores_access_level() {
+        let api = Arc::new(MockMeetApi::new());
+        api.push_response(200, "{}");
+        api.push_response(200, "{}");
+        let (service, capture) = connected_service(api.clone());
+
+        service.lock_room(true).await.unwrap();
+        assert!(service.is_room_locked().await);
+        // Already locked: no request.
+        service.lock_room(true).await.unwrap();
+        service.lock_room(false).await.unwrap();
+        assert!(!service.is_room_locked().await);
+
+        let requests = api.update_requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].instance, "meet.example.com");
+        assert_eq!(requests[0].room_id, "room-uuid");
+        assert_eq!(requests[0].access_level, "restricted");
+        assert_eq!(requests[0].session_cookie.as_deref(), Some("cookie"));
+        assert_eq!(requests[1].access_level, "trusted");
+        assert_eq!(*capture.0.lock().unwrap(), vec![true, false]);
+    }
+
+    #[tokio::test]
+    async fn refused_lock_leaves_room_unlocked() {
+        let api = Arc::new(MockMeetApi::new());
+        api.push_response(403, r#"{"detail":"forbidden"}"#);
+        let (service, capture) = connected_service(api);
+
+        let err = service.lock_room(true).await.unwrap_err();
+        assert!(matches!(err, VisioError::AuthRequired), "got {err:?}");
+        assert!(!service.is_room_locked().await);
+        assert!(capture.0.lock().unwrap().is_empty());
+    }
+}
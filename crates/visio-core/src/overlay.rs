@@ -0,0 +1,543 @@
+//! Watermark overlay on outgoing video.
+//!
+//! After background processing, each captured frame goes through
+//! `video_overlay()`, which composites a branding image and/or a line of
+//! text in a corner of the picture before it is published. The local text
+//! comes from the `video_overlay_*` settings; a watermark required by the
+//! Meet instance (`FeatureFlags::watermark`) replaces it for the calls on
+//! that instance. The image is set by the shell, from its own assets.
+//!
+//! The overlay is drawn upright as the receivers see the frame, whatever
+//! the rotation metadata of the capture. Text uses a built-in 5x7 font:
+//! capitals, digits and common punctuation, accents are dropped.
+
+use std::sync::{Mutex, OnceLock};
+
+use serde::{Deserialize, Serialize};
+
+use crate::errors::VisioError;
+
+/// Longest text drawn, in characters.
+const MAX_TEXT_CHARS: usize = 48;
+
+/// Largest image accepted, in pixels per side.
+const MAX_IMAGE_SIDE: u32 = 1024;
+
+/// Glyph size of the built-in font, and the advance between glyphs.
+pub(crate) const GLYPH_W: usize = 5;
+pub(crate) const GLYPH_H: usize = 7;
+pub(crate) const GLYPH_ADVANCE: usize = GLYPH_W + 1;
+
+/// Opacity of the text and of the box behind it, out of 255.
+const TEXT_ALPHA: u8 = 230;
+const BOX_ALPHA: u8 = 110;
+
+/// Corner of the picture the overlay is drawn in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OverlayCorner {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    #[default]
+    BottomRight,
+}
+
+/// Decoded RGBA image.
+#[derive(Debug, Clone)]
+struct RgbaImage {
+    width: usize,
+    height: usize,
+    rgba: Vec<u8>,
+}
+
+/// The overlay rendered for one frame size, in I420-ready YUV with alpha.
+#[derive(Debug)]
+struct Stamp {
+    /// Size of the upright picture it was rendered for.
+    frame: (usize, usize),
+    /// Position in the upright picture.
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+    /// Per pixel: Y, U, V, alpha.
+    yuva: Vec<[u8; 4]>,
+}
+
+#[derive(Debug, Default)]
+struct OverlayState {
+    text: Option<String>,
+    instance_text: Option<String>,
+    image: Option<RgbaImage>,
+    corner: OverlayCorner,
+    /// Rendered overlay, dropped whenever the configuration changes.
+    stamp: Option<Stamp>,
+}
+
+impl OverlayState {
+    fn text(&self) -> Option<&str> {
+        self.instance_text.as_deref().or(self.text.as_deref())
+    }
+
+    fn is_empty(&self) -> bool {
+        self.text().is_none() && self.image.is_none()
+    }
+}
+
+/// Overlay stage of the outgoing video pipeline, reached through
+/// `video_overlay()`.
+#[derive(Debug, Default)]
+pub struct VideoOverlay {
+    state: Mutex<OverlayState>,
+}
+
+impl VideoOverlay {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Local text and corner of the overlay, from the settings. Blank text
+    /// removes it.
+    pub fn set_text(&self, text: Option<String>, corner: OverlayCorner) {
+        let mut state = self.lock();
+        state.text = normalize_text(text);
+        state.corner = corner;
+        state.stamp = None;
+    }
+
+    /// Watermark required by the Meet instance, drawn instead of the local
+    /// text; `None` after leaving its calls.
+    pub fn set_instance_text(&self, text: Option<String>) {
+        let mut state = self.lock();
+        let text = normalize_text(text);
+        if state.instance_text != text {
+            state.instance_text = text;
+            state.stamp = None;
+        }
+    }
+
+    /// Branding image drawn above the text, as PNG; `None` removes it.
+    ///
+    /// Fails with `VisioError::Room` if the PNG cannot be decoded or is
+    /// larger than 1024 pixels per side.
+    pub fn set_image_png(&self, png: Option<&[u8]>) -> Result<(), VisioError> {
+        let image = png.map(decode_png).transpose()?;
+        let mut state = self.lock();
+        state.image = image;
+        state.stamp = None;
+        Ok(())
+    }
+
+    /// Whether frames are currently modified.
+    pub fn is_active(&self) -> bool {
+        !self.lock().is_empty()
+    }
+
+    /// Composite the overlay onto an I420 frame in place. `rotation` is
+    /// the clockwise rotation receivers apply to show the frame upright.
+    /// Returns `false` when there is nothing to draw.
+    #[allow(clippy::too_many_arguments)]
+    pub fn process_i420(
+        &self,
+        y: &mut [u8],
+        u: &mut [u8],
+        v: &mut [u8],
+        width: usize,
+        height: usize,
+        strides: [usize; 3],
+        rotation: u32,
+    ) -> bool {
+        let mut state = self.lock();
+        if state.is_empty() || width == 0 || height == 0 {
+            return false;
+        }
+        let rotation = rotation % 360;
+        let frame = if rotation == 90 || rotation == 270 {
+            (height, width)
+        } else {
+            (width, height)
+        };
+        if state.stamp.as_ref().is_none_or(|s| s.frame != frame) {
+            state.stamp = Some(render(&state, frame));
+        }
+        let Some(stamp) = state.stamp.as_ref() else {
+            return false;
+        };
+
+        for sy in 0..stamp.height {
+            for sx in 0..stamp.width {
+                let [luma, cb, cr, alpha] = stamp.yuva[sy * stamp.width + sx];
+                if alpha == 0 {
+                    continue;
+                }
+                let (bx, by) = to_buffer(stamp.x + sx, stamp.y + sy, width, height, rotation);
+                if let Some(p) = y.get_mut(by * strides[0] + bx) {
+                    *p = blend(*p, luma, alpha);
+                }
+                if bx % 2 == 0 && by % 2 == 0 {
+                    if let Some(p) = u.get_mut(by / 2 * strides[1] + bx / 2) {
+                        *p = blend(*p, cb, alpha);
+                    }
+                    if let Some(p) = v.get_mut(by / 2 * strides[2] + bx / 2) {
+                        *p = blend(*p, cr, alpha);
+                    }
+                }
+            }
+        }
+        true
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, OverlayState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Process-wide overlay: there is one camera.
+pub fn video_overlay() -> &'static VideoOverlay {
+    static OVERLAY: OnceLock<VideoOverlay> = OnceLock::new();
+    OVERLAY.get_or_init(VideoOverlay::new)
+}
+
+fn normalize_text(text: Option<String>) -> Option<String> {
+    text.map(|t| t.trim().chars().take(MAX_TEXT_CHARS).collect::<String>())
+        .filter(|t| !t.is_empty())
+}
+
+fn decode_png(bytes: &[u8]) -> Result<RgbaImage, VisioError> {
+    let invalid = |e: String| VisioError::Room(format!("invalid overlay image: {e}"));
+    let mut decoder = png::Decoder::new(bytes);
+    decoder.set_transformations(png::Transformations::normalize_to_color8());
+    let mut reader = decoder.read_info().map_err(|e| invalid(e.to_string()))?;
+    let info = reader.info();
+    if info.width > MAX_IMAGE_SIDE || info.height > MAX_IMAGE_SIDE {
+        return Err(invalid(format!(
+            "{}x{} is larger than {MAX_IMAGE_SIDE} pixels",
+            info.width, info.height
+        )));
+    }
+    let mut buf = vec![0; reader.output_buffer_size()];
+    let frame = reader
+        .next_frame(&mut buf)
+        .map_err(|e| invalid(e.to_string()))?;
+    let pixels = &buf[..frame.buffer_size()];
+    let rgba = match frame.color_type {
+        png::ColorType::Rgba => pixels.to_vec(),
+        png::ColorType::Rgb => pixels
+            .chunks_exact(3)
+            .flat_map(|p| [p[0], p[1], p[2], u8::MAX])
+            .collect(),
+        png::ColorType::GrayscaleAlpha => pixels
+            .chunks_exact(2)
+            .flat_map(|p| [p[0], p[0], p[0], p[1]])
+            .collect(),
+        png::ColorType::Grayscale => pixels.iter().flat_map(|&g| [g, g, g, u8::MAX]).collect(),
+        png::ColorType::Indexed => return Err(invalid("unexpanded palette".into())),
+    };
+    Ok(RgbaImage {
+        width: frame.width as usize,
+        height: frame.height as usize,
+        rgba,
+    })
+}
+
+/// Render the overlay for an upright picture of `frame` size.
+fn render(state: &OverlayState, frame: (usize, usize)) -> Stamp {
+    let (fw, fh) = frame;
+    // Text about 1/25 of the picture height: 4 pixels per dot at 720p.
+    let scale = (fh / 180).max(1);
+    let pad = 2 * scale;
+    let margin = fh / 30;
+
+    let text = state.text().map(glyphs).unwrap_or_default();
+    let text_w = if text.is_empty() {
+        0
+    } else {
+        (text.len() * GLYPH_ADVANCE - 1) * scale + 2 * pad
+    };
+    let text_h = if text.is_empty() {
+        0
+    } else {
+        GLYPH_H * scale + 2 * pad
+    };
+
+    // The image is at most 1/8 of the height and 1/3 of the width.
+    let image = state.image.as_ref().map(|img| {
+        let h = (fh / 8).max(1);
+        let w = (img.width * h / img.height.max(1)).clamp(1, (fw / 3).max(1));
+        (img, w, h)
+    });
+    let (image_w, image_h) = image.map_or((0, 0), |(_, w, h)| (w, h));
+    let gap = if image.is_some() && !text.is_empty() {
+        pad
+    } else {
+        0
+    };
+
+    let width = text_w.max(image_w).min(fw.saturating_sub(2 * margin));
+    let height = (image_h + gap + text_h).min(fh.saturating_sub(2 * margin));
+    let right = matches!(
+        state.corner,
+        OverlayCorner::TopRight | OverlayCorner::BottomRight
+    );
+    let bottom = matches!(
+        state.corner,
+        OverlayCorner::BottomLeft | OverlayCorner::BottomRight
+    );
+    let x = if right { fw - margin - width } else { margin };
+    let y = if bottom { fh - margin - height } else { margin };
+
+    let mut rgba = vec![[0u8; 4]; width * height];
+    let mut put = |px: usize, py: usize, color: [u8; 4]| {
+        if px < width && py < height {
+            rgba[py * width + px] = color;
+        }
+    };
+    // Left or right aligned within the stamp, like the corner.
+    let align = |w: usize| if right { width.saturating_sub(w) } else { 0 };
+
+    if let Some((img, w, h)) = image {
+        let ox = align(w);
+        for py in 0..h {
+            for px in 0..w {
+                let sx = px * img.width / w;
+                let sy = py * img.height / h;
+                let i = (sy * img.width + sx) * 4;
+                let p = &img.rgba[i..i + 4];
+                put(ox + px, py, [p[0], p[1], p[2], p[3]]);
+            }
+        }
+    }
+    if !text.is_empty() {
+        let ox = align(text_w);
+        let oy = image_h + gap;
+        for py in 0..text_h {
+            for px in 0..text_w {
+                put(ox + px, oy + py, [0, 0, 0, BOX_ALPHA]);
+            }
+        }
+        for (i, glyph) in text.iter().enumerate() {
+            for (row, bits) in glyph.iter().enumerate() {
+                for col in 0..GLYPH_W {
+                    if bits & (1 << (GLYPH_W - 1 - col)) == 0 {
+                        continue;
+                    }
+                    let gx = ox + pad + (i * GLYPH_ADVANCE + col) * scale;
+                    let gy = oy + pad + row * scale;
+                    for dy in 0..scale {
+                        for dx in 0..scale {
+                            put(gx + dx, gy + dy, [255, 255, 255, TEXT_ALPHA]);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    Stamp {
+        frame,
+        x,
+        y,
+        width,
+        height,
+        yuva: rgba.into_iter().map(rgba_to_yuva).collect(),
+    }
+}
+
+/// BT.601 limited range, like the camera frames.
+fn rgba_to_yuva([r, g, b, a]: [u8; 4]) -> [u8; 4] {
+    let (r, g, b) = (r as i32, g as i32, b as i32);
+    let y = ((66 * r + 129 * g + 25 * b + 128) >> 8) + 16;
+    let u = ((-38 * r - 74 * g + 112 * b + 128) >> 8) + 128;
+    let v = ((112 * r - 94 * g - 18 * b + 128) >> 8) + 128;
+    [y as u8, u as u8, v as u8, a]
+}
+
+fn blend(dst: u8, src: u8, alpha: u8) -> u8 {
+    let a = alpha as u32;
+    ((src as u32 * a + dst as u32 * (255 - a) + 127) / 255) as u8
+}
+
+/// Buffer pixel shown at (`x`, `y`) of the upright picture, for a buffer of
+/// `width` x `height` rotated clockwise by `rotation`.
+fn to_buffer(x: usize, y: usize, width: usize, height: usize, rotation: u32) -> (usize, usize) {
+    match rotation {
+        90 => (y, height - 1 - x),
+        180 => (width - 1 - x, height - 1 - y),
+        270 => (width - 1 - y, x),
+        _ => (x, y),
+    }
+}
+
+/// Glyphs of `text` in the built-in font.
+pub(crate) fn glyphs(text: &str) -> Vec<[u8; GLYPH_H]> {
+    text.chars()
+        .flat_map(char::to_uppercase)
+        .map(|c| glyph(fold_accent(c)))
+        .collect()
+}
+
+fn fold_accent(c: char) -> char {
+    match c {
+        'À' | 'Â' | 'Ä' | 'Á' => 'A',
+        'É' | 'È' | 'Ê' | 'Ë' => 'E',
+        'Î' | 'Ï' | 'Í' => 'I',
+        'Ô' | 'Ö' | 'Ó' => 'O',
+        'Ù' | 'Û' | 'Ü' | 'Ú' => 'U',
+        'Ç' => 'C',
+        'Ñ' => 'N',
+        c => c,
+    }
+}
+
+/// Rows of a 5x7 glyph, most significant bit on the left. Unknown
+/// characters are drawn as `?`.
+fn glyph(c: char) -> [u8; GLYPH_H] {
+    match c {
+        'A' => [0x0E, 0x11, 0x11, 0x1F, 0x11, 0x11, 0x11],
+        'B' => [0x1E, 0x11, 0x11, 0x1E, 0x11, 0x11, 0x1E],
+        'C' => [0x0E, 0x11, 0x10, 0x10, 0x10, 0x11, 0x0E],
+        'D' => [0x1E, 0x11, 0x11, 0x11, 0x11, 0x11, 0x1E],
+        'E' => [0x1F, 0x10, 0x10, 0x1E, 0x10, 0x10, 0x1F],
+        'F' => [0x1F, 0x10, 0x10, 0x1E, 0x10, 0x10, 0x10],
+        'G' => [0x0E, 0x11, 0x10, 0x17, 0x11, 0x11, 0x0F],
+        'H' => [0x11, 0x11, 0x11, 0x1F, 0x11, 0x11, 0x11],
+        'I' => [0x0E, 0x04, 0x04, 0x04, 0x04, 0x04, 0x0E],
+        'J' => [0x07, 0x02, 0x02, 0x02, 0x02, 0x12, 0x0C],
+        'K' => [0x11, 0x12, 0x14, 0x18, 0x14, 0x12, 0x11],
+        'L' => [0x10, 0x10, 0x10, 0x10, 0x10, 0x10, 0x1F],
+        'M' => [0x11, 0x1B, 0x15, 0x15, 0x11, 0x11, 0x11],
+        'N' => [0x11, 0x11, 0x19, 0x15, 0x13, 0x11, 0x11],
+        'O' => [0x0E, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0E],
+        'P' => [0x1E, 0x11, 0x11, 0x1E, 0x10, 0x10, 0x10],
+        'Q' => [0x0E, 0x11, 0x11, 0x11, 0x15, 0x12, 0x0D],
+        'R' => [0x1E, 0x11, 0x11, 0x1E, 0x14, 0x12, 0x11],
+        'S' => [0x0F, 0x10, 0x10, 0x0E, 0x01, 0x01, 0x1E],
+        'T' => [0x1F, 0x04, 0x04, 0x04, 0x04, 0x04, 0x04],
+        'U' => [0x11, 0x11, 0x11, 0x11, 0x11, 0x11, 0x0E],
+        'V' => [0x11, 0x11, 0x11, 0x11, 0x11, 0x0A, 0x04],
+        'W' => [0x11, 0x11, 0x11, 0x15, 0x15, 0x15, 0x0A],
+        'X' => [0x11, 0x11, 0x0A, 0x04, 0x0A, 0x11, 0x11],
+        'Y' => [0x11, 0x11, 0x11, 0x0A, 0x04, 0x04, 0x04],
+        'Z' => [0x1F, 0x01, 0x02, 0x04, 0x08, 0x10, 0x1F],
+        '0' => [0x0E, 0x11, 0x13, 0x15, 0x19, 0x11, 0x0E],
+        '1' => [0x04, 0x0C, 0x04, 0x04, 0x04, 0x04, 0x0E],
+        '2' => [0x0E, 0x11, 0x01, 0x02, 0x04, 0x08, 0x1F],
+        '3' => [0x1F, 0x02, 0x04, 0x02, 0x01, 0x11, 0x0E],
+        '4' => [0x02, 0x06, 0x0A, 0x12, 0x1F, 0x02, 0x02],
+        '5' => [0x1F, 0x10, 0x1E, 0x01, 0x01, 0x11, 0x0E],
+        '6' => [0x06, 0x08, 0x10, 0x1E, 0x11, 0x11, 0x0E],
+        '7' => [0x1F, 0x01, 0x02, 0x04, 0x08, 0x08, 0x08],
+        '8' => [0x0E, 0x11, 0x11, 0x0E, 0x11, 0x11, 0x0E],
+        '9' => [0x0E, 0x11, 0x11, 0x0F, 0x01, 0x02, 0x0C],
+        ' ' => [0x00; GLYPH_H],
+        '.' => [0x00, 0x00, 0x00, 0x00, 0x00, 0x0C, 0x0C],
+        ',' => [0x00, 0x00, 0x00, 0x00, 0x0C, 0x04, 0x08],
+        '-' => [0x00, 0x00, 0x00, 0x1F, 0x00, 0x00, 0x00],
+        '_' => [0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x1F],
+        ':' => [0x00, 0x0C, 0x0C, 0x00, 0x0C, 0x0C, 0x00],
+        '/' => [0x00, 0x01, 0x02, 0x04, 0x08, 0x10, 0x00],
+        '!' => [0x04, 0x04, 0x04, 0x04, 0x04, 0x00, 0x04],
+        '@' => [0x0E, 0x11, 0x01, 0x0D, 0x15, 0x15, 0x0E],
+        '#' => [0x0A, 0x0A, 0x1F, 0x0A, 0x1F, 0x0A, 0x0A],
+        '(' => [0x02, 0x04, 0x08, 0x08, 0x08, 0x04, 0x02],
+        ')' => [0x08, 0x04, 0x02, 0x02, 0x02, 0x04, 0x08],
+        '\'' => [0x04, 0x04, 0x08, 0x00, 0x00, 0x00, 0x00],
+        _ => [0x0E, 0x11, 0x01, 0x02, 0x04, 0x00, 0x04],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Black 720p frame, packed planes.
+    fn frame() -> (Vec<u8>, Vec<u8>, Vec<u8>) {
+        (
+            vec![16; 1280 * 720],
+            vec![128; 640 * 360],
+            vec![128; 640 * 360],
+        )
+    }
+
+    fn png(width: u32, height: u32, rgb: [u8; 3]) -> Vec<u8> {
+        let mut out = Vec::new();
+        let mut encoder = png::Encoder::new(&mut out, width, height);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let pixels: Vec<u8> = (0..width * height).flat_map(|_| rgb).collect();
+        encoder
+            .write_header()
+            .unwrap()
+            .write_image_data(&pixels)
+            .unwrap();
+        out
+    }
+
+    #[test]
+    fn draws_text_in_the_chosen_corner() {
+        let overlay = VideoOverlay::new();
+        let (mut y, mut u, mut v) = frame();
+        let strides = [1280, 640, 640];
+        assert!(!overlay.process_i420(&mut y, &mut u, &mut v, 1280, 720, strides, 0));
+        assert!(y.iter().all(|&p| p == 16));
+
+        overlay.set_text(Some("  Visio  ".into()), OverlayCorner::BottomRight);
+        assert!(overlay.process_i420(&mut y, &mut u, &mut v, 1280, 720, strides, 0));
+        let bright = |y: &[u8], x0: usize, x1: usize, y0: usize, y1: usize| {
+            (y0..y1).any(|row| (x0..x1).any(|col| y[row * 1280 + col] > 200))
+        };
+        assert!(bright(&y, 640, 1280, 360, 720));
+        assert!(!bright(&y, 0, 640, 0, 720));
+        assert!(!bright(&y, 0, 1280, 0, 360));
+
+        overlay.set_text(Some("   ".into()), OverlayCorner::TopLeft);
+        assert!(!overlay.is_active());
+    }
+
+    #[test]
+    fn instance_watermark_wins_and_follows_rotation() {
+        let overlay = VideoOverlay::new();
+        overlay.set_text(Some("local".into()), OverlayCorner::TopLeft);
+        overlay.set_instance_text(Some("CONFIDENTIEL".into()));
+        assert_eq!(overlay.lock().text(), Some("CONFIDENTIEL"));
+        overlay.set_instance_text(None);
+        assert_eq!(overlay.lock().text(), Some("local"));
+
+        // A portrait buffer rotated by 90°: the upright top-left corner is
+        // the bottom-left of the buffer.
+        let (mut y, mut u, mut v) = frame();
+        overlay.process_i420(&mut y, &mut u, &mut v, 720, 1280, [720, 360, 360], 90);
+        let bright = |rows: std::ops::Range<usize>| {
+            rows.into_iter()
+                .any(|r| y[r * 720..][..360].iter().any(|&p| p > 200))
+        };
+        assert!(bright(640..1280));
+        assert!(!bright(0..640));
+    }
+
+    #[test]
+    fn draws_png_images() {
+        let overlay = VideoOverlay::new();
+        assert!(overlay.set_image_png(Some(b"not a png")).is_err());
+        assert!(
+            overlay
+                .set_image_png(Some(&png(2048, 8, [0, 0, 0])))
+                .is_err()
+        );
+        overlay
+            .set_image_png(Some(&png(32, 16, [255, 255, 255])))
+            .unwrap();
+        assert!(overlay.is_active());
+
+        let (mut y, mut u, mut v) = frame();
+        overlay.process_i420(&mut y, &mut u, &mut v, 1280, 720, [1280, 640, 640], 0);
+        // 90 pixels high (1/8 of 720), bottom-right with a 24 pixel margin.
+        assert_eq!(y[(720 - 24 - 1) * 1280 + 1280 - 24 - 1], 235);
+        assert_eq!(y[(720 - 24 - 90) * 1280 + 1280 - 24 - 1], 235);
+        assert_eq!(y[(720 - 24 - 91) * 1280 + 1280 - 24 - 1], 16);
+
+        overlay.set_image_png(None).unwrap();
+        assert!(!overlay.is_active());
+    }
+}
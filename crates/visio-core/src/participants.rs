@@ -1,15 +1,35 @@
+use std::time::{Duration, Instant};
+
 use crate::events::ParticipantInfo;
 
 #[cfg(test)]
 use crate::events::ConnectionQuality;
+#[cfg(test)]
+use crate::presence::Presence;
+
+/// How long a speaker stays active after LiveKit stops reporting them.
+pub const DEFAULT_SPEAKER_HOLD: Duration = Duration::from_millis(1500);
 
 /// Manages the list of participants in a room.
 ///
 /// Updated by the room event loop. Read by native UI layers.
+///
+/// Active speakers are smoothed: LiveKit reports speaker changes at every
+/// pause in speech, which makes speaker highlights flicker. A participant
+/// stays in `active_speakers()` for `speaker_hold` after they were last
+/// reported, and `dominant_speaker()` only moves to someone else once the
+/// current one has been silent for that long.
 #[derive(Debug, Clone)]
 pub struct ParticipantManager {
     participants: Vec<ParticipantInfo>,
+    /// Smoothed speakers: currently reported ones loudest first, then held ones.
     active_speakers: Vec<String>,
+    /// Speakers as last reported by LiveKit.
+    raw_speakers: Vec<String>,
+    /// When each recently active speaker was last reported.
+    last_heard: Vec<(String, Instant)>,
+    dominant_speaker: Option<String>,
+    speaker_hold: Duration,
     local_sid: Option<String>,
 }
 
@@ -24,6 +44,10 @@ impl ParticipantManager {
         Self {
             participants: Vec::new(),
             active_speakers: Vec::new(),
+            raw_speakers: Vec::new(),
+            last_heard: Vec::new(),
+            dominant_speaker: None,
+            speaker_hold: DEFAULT_SPEAKER_HOLD,
             local_sid: None,
         }
     }
@@ -45,6 +69,11 @@ impl ParticipantManager {
     pub fn remove_participant(&mut self, sid: &str) {
         self.participants.retain(|p| p.sid != sid);
         self.active_speakers.retain(|s| s != sid);
+        self.raw_speakers.retain(|s| s != sid);
+        self.last_heard.retain(|(s, _)| s != sid);
+        if self.dominant_speaker.as_deref() == Some(sid) {
+            self.dominant_speaker = self.active_speakers.first().cloned();
+        }
     }
 
     pub fn participants(&self) -> &[ParticipantInfo] {
@@ -59,7 +88,12 @@ impl ParticipantManager {
         self.participants.iter_mut().find(|p| p.sid == sid)
     }
 
+    /// Set the smoothed speaker list directly, bypassing the hold time.
     pub fn set_active_speakers(&mut self, sids: Vec<String>) {
+        let now = Instant::now();
+        self.last_heard = sids.iter().map(|s| (s.clone(), now)).collect();
+        self.dominant_speaker = sids.first().cloned().or(self.dominant_speaker.take());
+        self.raw_speakers = sids.clone();
         self.active_speakers = sids;
     }
 
@@ -67,6 +101,73 @@ impl ParticipantManager {
         &self.active_speakers
     }
 
+    /// The participant a spotlight tile should show: the most recent main
+    /// speaker, kept while the room is silent.
+    pub fn dominant_speaker(&self) -> Option<&str> {
+        self.dominant_speaker.as_deref()
+    }
+
+    /// Hold time applied to speakers that stop being reported. Zero
+    /// disables smoothing.
+    pub fn set_speaker_hold(&mut self, hold: Duration) {
+        self.speaker_hold = hold;
+    }
+
+    pub fn speaker_hold(&self) -> Duration {
+        self.speaker_hold
+    }
+
+    /// Record a LiveKit speaker update (loudest first). Returns whether the
+    /// smoothed list changed.
+    pub fn update_active_speakers(&mut self, sids: Vec<String>, now: Instant) -> bool {
+        for sid in &sids {
+            match self.last_heard.iter_mut().find(|(s, _)| s == sid) {
+                Some(entry) => entry.1 = now,
+                None => self.last_heard.push((sid.clone(), now)),
+            }
+        }
+        self.raw_speakers = sids;
+        self.refresh_active_speakers(now)
+    }
+
+    /// Drop speakers whose hold time has expired. Returns whether the
+    /// smoothed list changed.
+    pub fn refresh_active_speakers(&mut self, now: Instant) -> bool {
+        let hold = self.speaker_hold;
+        let raw = &self.raw_speakers;
+        self.last_heard
+            .retain(|(sid, at)| raw.contains(sid) || now.duration_since(*at) < hold);
+
+        let held = self
+            .active_speakers
+            .iter()
+            .filter(|sid| !raw.contains(sid) && self.last_heard.iter().any(|(s, _)| s == *sid));
+        let active: Vec<String> = raw.iter().chain(held).cloned().collect();
+
+        let dominant_heard = self
+            .dominant_speaker
+            .as_ref()
+            .is_some_and(|d| self.last_heard.iter().any(|(s, _)| s == d));
+        if !dominant_heard && let Some(first) = active.first() {
+            self.dominant_speaker = Some(first.clone());
+        }
+
+        if active == self.active_speakers {
+            return false;
+        }
+        self.active_speakers = active;
+        true
+    }
+
+    /// When the next held speaker expires, if any.
+    pub fn next_speaker_expiry(&self) -> Option<Instant> {
+        self.last_heard
+            .iter()
+            .filter(|(sid, _)| !self.raw_speakers.contains(sid))
+            .map(|(_, at)| *at + self.speaker_hold)
+            .min()
+    }
+
     pub fn participant_count(&self) -> usize {
         self.participants.len()
     }
@@ -74,6 +175,9 @@ impl ParticipantManager {
     pub fn clear(&mut self) {
         self.participants.clear();
         self.active_speakers.clear();
+        self.raw_speakers.clear();
+        self.last_heard.clear();
+        self.dominant_speaker = None;
         self.local_sid = None;
     }
 }
@@ -91,6 +195,7 @@ mod tests {
             has_video: false,
             video_track_sid: None,
             connection_quality: ConnectionQuality::Good,
+            presence: Presence::Available,
         }
     }
 
@@ -132,6 +237,45 @@ mod tests {
         assert_eq!(mgr.active_speakers(), &["p1"]);
     }
 
+    #[test]
+    fn speakers_are_held_after_going_silent() {
+        let mut mgr = ParticipantManager::new();
+        mgr.set_speaker_hold(Duration::from_secs(1));
+        let t0 = Instant::now();
+        assert!(mgr.update_active_speakers(vec!["p1".into()], t0));
+        assert!(mgr.update_active_speakers(vec!["p2".into()], t0 + Duration::from_millis(200)));
+        // p1 paused but is still held, after the current speaker.
+        assert_eq!(mgr.active_speakers(), &["p2", "p1"]);
+        assert_eq!(mgr.dominant_speaker(), Some("p1"));
+        assert_eq!(mgr.next_speaker_expiry(), Some(t0 + Duration::from_secs(1)));
+
+        // p1 resumes within the hold: no change in membership.
+        let resumed = vec!["p2".into(), "p1".into()];
+        assert!(!mgr.update_active_speakers(resumed, t0 + Duration::from_millis(500)));
+        assert!(!mgr.update_active_speakers(vec!["p2".into()], t0 + Duration::from_millis(700)));
+
+        assert!(!mgr.refresh_active_speakers(t0 + Duration::from_millis(1400)));
+        assert_eq!(mgr.dominant_speaker(), Some("p1"));
+        assert!(mgr.refresh_active_speakers(t0 + Duration::from_millis(1600)));
+        assert_eq!(mgr.active_speakers(), &["p2"]);
+        assert_eq!(mgr.dominant_speaker(), Some("p2"));
+    }
+
+    #[test]
+    fn dominant_speaker_survives_silence_and_zero_hold_disables_smoothing() {
+        let mut mgr = ParticipantManager::new();
+        mgr.set_speaker_hold(Duration::ZERO);
+        let t0 = Instant::now();
+        mgr.update_active_speakers(vec!["p1".into()], t0);
+        assert!(mgr.update_active_speakers(Vec::new(), t0));
+        assert!(mgr.active_speakers().is_empty());
+        assert_eq!(mgr.dominant_speaker(), Some("p1"));
+        assert!(mgr.next_speaker_expiry().is_none());
+
+        mgr.remove_participant("p1");
+        assert!(mgr.dominant_speaker().is_none());
+    }
+
     #[test]
     fn clear_resets_everything() {
         let mut mgr = ParticipantManager::new();
@@ -141,6 +285,7 @@ mod tests {
         mgr.clear();
         assert_eq!(mgr.participant_count(), 0);
         assert!(mgr.active_speakers().is_empty());
+        assert!(mgr.dominant_speaker().is_none());
         assert!(mgr.local_sid().is_none());
     }
 
@@ -0,0 +1,152 @@
+//! What the local participant is allowed to do in the current room.
+//!
+//! The Meet API reports the room access level (`public`, `trusted`,
+//! `restricted`) and the LiveKit token carries the participant grants.
+//! Both are combined into `LocalPermissions` on connect; the SFU may later
+//! change the grants (e.g. a moderator revokes publishing), which updates
+//! the snapshot and emits `PermissionsChanged`.
+
+use livekit_api::access_token::{Claims, VideoGrants};
+
+use crate::events::TrackSource;
+
+/// Permissions of the local participant. Read it with
+/// `RoomManager::local_permissions()`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LocalPermissions {
+    /// Room access level reported by the Meet API, if known.
+    pub access_level: Option<String>,
+    pub can_publish: bool,
+    pub can_subscribe: bool,
+    pub can_publish_data: bool,
+    /// Sources that may be published (`camera`, `microphone`,
+    /// `screen_share`, `screen_share_audio`). Empty means any source.
+    pub can_publish_sources: Vec<String>,
+    pub can_update_metadata: bool,
+    /// Room admin grant (moderation actions).
+    pub is_admin: bool,
+}
+
+impl LocalPermissions {
+    /// Read the grants of a LiveKit access token. The signature is not
+    /// checked (the SFU does that); an unreadable token yields LiveKit's
+    /// default grants.
+    pub fn from_token(token: &str, access_level: Option<String>) -> Self {
+        let grants = match Claims::from_unverified(token) {
+            Ok(claims) => claims.video,
+            Err(e) => {
+                tracing::warn!("cannot read token grants: {e}");
+                VideoGrants::default()
+            }
+        };
+        Self {
+            access_level,
+            can_publish: grants.can_publish,
+            can_subscribe: grants.can_subscribe,
+            can_publish_data: grants.can_publish_data,
+            can_publish_sources: grants.can_publish_sources,
+            can_update_metadata: grants.can_update_own_metadata,
+            is_admin: grants.room_admin,
+        }
+    }
+
+    /// Whether a track from `source` may be published.
+    pub fn can_publish_source(&self, source: TrackSource) -> bool {
+        let Some(name) = grant_name(&source) else {
+            return self.can_publish;
+        };
+        self.can_publish
+            && (self.can_publish_sources.is_empty()
+                || self.can_publish_sources.iter().any(|s| s == name))
+    }
+
+    /// Apply a permission update from the SFU. `sources` are LiveKit
+    /// `TrackSource` protocol values. Returns whether anything changed.
+    pub(crate) fn apply_update(
+        &mut self,
+        can_publish: bool,
+        can_subscribe: bool,
+        can_publish_data: bool,
+        sources: &[i32],
+        can_update_metadata: bool,
+    ) -> bool {
+        let updated = Self {
+            can_publish,
+            can_subscribe,
+            can_publish_data,
+            can_publish_sources: sources
+                .iter()
+                .filter_map(|s| source_name(*s))
+                .map(str::to_string)
+                .collect(),
+            can_update_metadata,
+            ..self.clone()
+        };
+        if updated == *self {
+            return false;
+        }
+        *self = updated;
+        true
+    }
+}
+
+/// Name of `source` in the token's `canPublishSources` grant.
+pub fn grant_name(source: &TrackSource) -> Option<&'static str> {
+    match source {
+        TrackSource::Microphone => Some("microphone"),
+        TrackSource::Camera => Some("camera"),
+        TrackSource::ScreenShare => Some("screen_share"),
+        TrackSource::ScreenShareAudio => Some("screen_share_audio"),
+        TrackSource::Unknown => None,
+    }
+}
+
+/// Token grant name of a LiveKit `TrackSource` protocol value.
+fn source_name(source: i32) -> Option<&'static str> {
+    match source {
+        1 => Some("camera"),
+        2 => Some("microphone"),
+        3 => Some("screen_share"),
+        4 => Some("screen_share_audio"),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use livekit_api::access_token::AccessToken;
+
+    #[test]
+    fn reads_grants_from_token_and_applies_updates() {
+        let token = AccessToken::with_api_key("key", "secret")
+            .with_identity("alice")
+            .with_grants(VideoGrants {
+                room_join: true,
+                room: "abc-defg-hij".into(),
+                can_publish_sources: vec!["microphone".into()],
+                ..Default::default()
+            })
+            .to_jwt()
+            .unwrap();
+
+        let mut perms = LocalPermissions::from_token(&token, Some("restricted".into()));
+        assert_eq!(perms.access_level.as_deref(), Some("restricted"));
+        assert!(perms.can_subscribe && !perms.is_admin);
+        assert!(perms.can_publish_source(TrackSource::Microphone));
+        assert!(!perms.can_publish_source(TrackSource::Camera));
+
+        assert!(perms.apply_update(true, true, true, &[1, 2], false));
+        assert!(perms.can_publish_source(TrackSource::Camera));
+        assert!(!perms.apply_update(true, true, true, &[1, 2], false));
+        assert!(perms.apply_update(false, true, true, &[], false));
+        assert!(!perms.can_publish_source(TrackSource::Microphone));
+    }
+
+    #[test]
+    fn unreadable_token_uses_default_grants() {
+        let perms = LocalPermissions::from_token("not-a-jwt", None);
+        assert!(perms.can_publish && perms.can_subscribe && perms.can_publish_data);
+        assert!(perms.can_publish_sources.is_empty());
+    }
+}
@@ -0,0 +1,396 @@
+//! Polls over the data channel.
+//!
+//! LaSuite Meet has no poll protocol of its own, so Visio clients exchange
+//! JSON messages on the reserved `visio.polls` topic:
+//!
+//! - `{"type": "pollState", "poll": {id, question, options, votes, ended}}`
+//!   announces a poll (sent by its creator when it starts and again when a
+//!   participant joins, so late joiners catch up; `votes` maps participant
+//!   SIDs to option indexes);
+//! - `{"type": "pollVote", "pollId": .., "option": ..}`: a participant
+//!   voted (a new vote replaces the previous one);
+//! - `{"type": "pollEnded", "pollId": ..}`: the creator closed the poll.
+//!
+//! Every client tallies the votes itself. Senders are identified by the
+//! SFU, not by the payload, so votes and closes cannot be spoofed.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use livekit::prelude::*;
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+
+use crate::data_channel::DataChannelService;
+use crate::errors::VisioError;
+use crate::events::{EventEmitter, VisioEvent};
+
+pub(crate) const POLLS_TOPIC: &str = "visio.polls";
+
+const MAX_OPTIONS: usize = 10;
+
+/// A poll as shown to the local participant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Poll {
+    pub id: String,
+    pub question: String,
+    pub options: Vec<String>,
+    /// Votes per option, in the order of `options`.
+    pub counts: Vec<u32>,
+    pub creator_sid: String,
+    /// Option the local participant voted for.
+    pub local_vote: Option<u32>,
+    pub ended: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct PollState {
+    id: String,
+    question: String,
+    options: Vec<String>,
+    #[serde(default)]
+    votes: BTreeMap<String, u32>,
+    #[serde(default)]
+    ended: bool,
+    /// Set from the sender of the announcement.
+    #[serde(skip)]
+    creator_sid: String,
+}
+
+impl PollState {
+    fn to_poll(&self, local_sid: &str) -> Poll {
+        let mut counts = vec![0; self.options.len()];
+        for &option in self.votes.values() {
+            if let Some(count) = counts.get_mut(option as usize) {
+                *count += 1;
+            }
+        }
+        Poll {
+            id: self.id.clone(),
+            question: self.question.clone(),
+            options: self.options.clone(),
+            counts,
+            creator_sid: self.creator_sid.clone(),
+            local_vote: self.votes.get(local_sid).copied(),
+            ended: self.ended,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type")]
+enum PollMessage {
+    #[serde(rename = "pollState")]
+    State { poll: PollState },
+    #[serde(rename = "pollVote", rename_all = "camelCase")]
+    Vote { poll_id: String, option: u32 },
+    #[serde(rename = "pollEnded", rename_all = "camelCase")]
+    Ended { poll_id: String },
+}
+
+#[derive(Clone)]
+pub struct PollsService {
+    room: Arc<Mutex<Option<Arc<Room>>>>,
+    data_channel: DataChannelService,
+    emitter: EventEmitter,
+    /// Polls of the current room, oldest first.
+    polls: Arc<std::sync::Mutex<Vec<PollState>>>,
+}
+
+impl PollsService {
+    pub fn new(
+        room: Arc<Mutex<Option<Arc<Room>>>>,
+        data_channel: DataChannelService,
+        emitter: EventEmitter,
+    ) -> Self {
+        Self {
+            room,
+            data_channel,
+            emitter,
+            polls: Arc::new(std::sync::Mutex::new(Vec::new())),
+        }
+    }
+
+    /// Polls of the current room, oldest first.
+    pub async fn polls(&self) -> Vec<Poll> {
+        let local_sid = self.local_sid().await.unwrap_or_default();
+        self.lock().iter().map(|p| p.to_poll(&local_sid)).collect()
+    }
+
+    /// Start a poll with 2 to 10 options.
+    pub async fn create_poll(
+        &self,
+        question: &str,
+        options: Vec<String>,
+    ) -> Result<Poll, VisioError> {
+        let question = question.trim();
+        if question.is_empty() {
+            return Err(VisioError::Room("poll question must not be empty".into()));
+        }
+        let options: Vec<String> = options.iter().map(|o| o.trim().to_string()).collect();
+        if !(2..=MAX_OPTIONS).contains(&options.len()) || options.iter().any(|o| o.is_empty()) {
+            return Err(VisioError::Room(format!(
+                "a poll needs 2 to {MAX_OPTIONS} non-empty options"
+            )));
+        }
+        let local_sid = self.local_sid().await?;
+        let state = PollState {
+            id: uuid::Uuid::new_v4().to_string(),
+            question: question.to_string(),
+            options,
+            votes: BTreeMap::new(),
+            ended: false,
+            creator_sid: local_sid.clone(),
+        };
+        self.send(&PollMessage::State {
+            poll: state.clone(),
+        })
+        .await?;
+
+        let poll = state.to_poll(&local_sid);
+        self.lock().push(state);
+        self.emitter.emit(VisioEvent::PollStarted(poll.clone()));
+        Ok(poll)
+    }
+
+    /// Vote for `option` (index into the poll options), replacing any
+    /// previous vote.
+    pub async fn vote(&self, poll_id: &str, option: u32) -> Result<Poll, VisioError> {
+        let local_sid = self.local_sid().await?;
+        {
+            let polls = self.lock();
+            let state = find(&polls, poll_id)?;
+            if state.ended {
+                return Err(VisioError::Room("poll has ended".into()));
+            }
+            if option as usize >= state.options.len() {
+                return Err(VisioError::Room(format!("invalid poll option: {option}")));
+            }
+        }
+        self.send(&PollMessage::Vote {
+            poll_id: poll_id.to_string(),
+            option,
+        })
+        .await?;
+        self.update(poll_id, &local_sid, |state| {
+            state.votes.insert(local_sid.clone(), option);
+        })
+    }
+
+    /// End a poll created by the local participant.
+    pub async fn close_poll(&self, poll_id: &str) -> Result<Poll, VisioError> {
+        let local_sid = self.local_sid().await?;
+        if find(&self.lock(), poll_id)?.creator_sid != local_sid {
+            return Err(VisioError::Room(
+                "only the poll creator can close it".into(),
+            ));
+        }
+        self.send(&PollMessage::Ended {
+            poll_id: poll_id.to_string(),
+        })
+        .await?;
+        self.update(poll_id, &local_sid, |state| state.ended = true)
+    }
+
+    /// Called by the room event loop for messages on the polls topic.
+    pub(crate) async fn handle_incoming(&self, payload: &[u8], sender_sid: &str) {
+        let message = match serde_json::from_slice::<PollMessage>(payload) {
+            Ok(message) => message,
+            Err(e) => {
+                tracing::warn!("invalid poll message from {sender_sid}: {e}");
+                return;
+            }
+        };
+        let local_sid = self.local_sid().await.unwrap_or_default();
+        if let Some(event) = self.apply(message, sender_sid, &local_sid) {
+            self.emitter.emit(event);
+        }
+    }
+
+    /// Re-announce the polls created locally, for participants who joined
+    /// after they started.
+    pub(crate) async fn announce_local_polls(&self) {
+        let Ok(local_sid) = self.local_sid().await else {
+            return;
+        };
+        let own: Vec<PollState> = self
+            .lock()
+            .iter()
+            .filter(|p| p.creator_sid == local_sid)
+            .cloned()
+            .collect();
+        for poll in own {
+            if let Err(e) = self.send(&PollMessage::State { poll }).await {
+                tracing::warn!("poll announce failed: {e}");
+            }
+        }
+    }
+
+    /// Forget the polls of the previous room.
+    pub(crate) fn reset(&self) {
+        self.lock().clear();
+    }
+
+    /// Apply a received message; returns the event to emit, if any.
+    fn apply(&self, message: PollMessage, sender_sid: &str, local_sid: &str) -> Option<VisioEvent> {
+        let mut polls = self.lock();
+        match message {
+            PollMessage::State { mut poll } => {
+                poll.creator_sid = sender_sid.to_string();
+                if let Some(known) = polls.iter_mut().find(|p| p.id == poll.id) {
+                    if known.creator_sid != sender_sid {
+                        return None;
+                    }
+                    // Keep the votes seen locally, add those we missed.
+                    for (sid, option) in poll.votes {
+                        known.votes.entry(sid).or_insert(option);
+                    }
+                    known.ended |= poll.ended;
+                    return Some(VisioEvent::PollUpdated(known.to_poll(local_sid)));
+                }
+                let event = if poll.ended {
+                    VisioEvent::PollEnded(poll.to_poll(local_sid))
+                } else {
+                    VisioEvent::PollStarted(poll.to_poll(local_sid))
+                };
+                polls.push(poll);
+                Some(event)
+            }
+            PollMessage::Vote { poll_id, option } => {
+                let state = polls.iter_mut().find(|p| p.id == poll_id)?;
+                if state.ended || option as usize >= state.options.len() {
+                    return None;
+                }
+                state.votes.insert(sender_sid.to_string(), option);
+                Some(VisioEvent::PollUpdated(state.to_poll(local_sid)))
+            }
+            PollMessage::Ended { poll_id } => {
+                let state = polls.iter_mut().find(|p| p.id == poll_id)?;
+                if state.creator_sid != sender_sid || state.ended {
+                    return None;
+                }
+                state.ended = true;
+                Some(VisioEvent::PollEnded(state.to_poll(local_sid)))
+            }
+        }
+    }
+
+    /// Apply a local change and emit the matching event.
+    fn update(
+        &self,
+        poll_id: &str,
+        local_sid: &str,
+        change: impl FnOnce(&mut PollState),
+    ) -> Result<Poll, VisioError> {
+        let poll = {
+            let mut polls = self.lock();
+            let state = polls
+                .iter_mut()
+                .find(|p| p.id == poll_id)
+                .ok_or_else(|| VisioError::Room(format!("unknown poll: {poll_id}")))?;
+            change(state);
+            state.to_poll(local_sid)
+        };
+        self.emitter.emit(if poll.ended {
+            VisioEvent::PollEnded(poll.clone())
+        } else {
+            VisioEvent::PollUpdated(poll.clone())
+        });
+        Ok(poll)
+    }
+
+    async fn send(&self, message: &PollMessage) -> Result<(), VisioError> {
+        let payload = serde_json::to_vec(message)
+            .map_err(|e| VisioError::Room(format!("encode poll message: {e}")))?;
+        self.data_channel
+            .publish_raw(POLLS_TOPIC, payload, true)
+            .await
+    }
+
+    async fn local_sid(&self) -> Result<String, VisioError> {
+        let room = self.room.lock().await;
+        let room = room
+            .as_ref()
+            .ok_or_else(|| VisioError::Room("not connected".into()))?;
+        Ok(room.local_participant().sid().to_string())
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<PollState>> {
+        self.polls.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+fn find<'a>(polls: &'a [PollState], poll_id: &str) -> Result<&'a PollState, VisioError> {
+    polls
+        .iter()
+        .find(|p| p.id == poll_id)
+        .ok_or_else(|| VisioError::Room(format!("unknown poll: {poll_id}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn service() -> PollsService {
+        let room = Arc::new(Mutex::new(None));
+        let emitter = EventEmitter::new();
+        let data_channel = DataChannelService::new(room.clone(), emitter.clone());
+        PollsService::new(room, data_channel, emitter)
+    }
+
+    fn message(json: serde_json::Value) -> PollMessage {
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn tallies_votes_from_remote_messages() {
+        let polls = service();
+        let start = message(serde_json::json!({
+            "type": "pollState",
+            "poll": {"id": "p1", "question": "Lunch?", "options": ["Pizza", "Sushi"]},
+        }));
+        let Some(VisioEvent::PollStarted(poll)) = polls.apply(start, "PA_host", "PA_me") else {
+            panic!("expected PollStarted");
+        };
+        assert_eq!(poll.creator_sid, "PA_host");
+        assert_eq!(poll.counts, vec![0, 0]);
+
+        let vote = |sid: &str, option: u32| {
+            polls.apply(
+                message(serde_json::json!({"type": "pollVote", "pollId": "p1", "option": option})),
+                sid,
+                "PA_me",
+            )
+        };
+        vote("PA_a", 0);
+        vote("PA_me", 1);
+        // A new vote replaces the previous one; out-of-range options are ignored.
+        vote("PA_a", 1);
+        assert!(vote("PA_a", 7).is_none());
+        let Some(VisioEvent::PollUpdated(poll)) = vote("PA_b", 0) else {
+            panic!("expected PollUpdated");
+        };
+        assert_eq!(poll.counts, vec![1, 2]);
+        assert_eq!(poll.local_vote, Some(1));
+
+        // Only the creator can end the poll.
+        let end = || message(serde_json::json!({"type": "pollEnded", "pollId": "p1"}));
+        assert!(polls.apply(end(), "PA_a", "PA_me").is_none());
+        let Some(VisioEvent::PollEnded(poll)) = polls.apply(end(), "PA_host", "PA_me") else {
+            panic!("expected PollEnded");
+        };
+        assert!(poll.ended);
+        assert!(vote("PA_c", 0).is_none());
+    }
+
+    #[tokio::test]
+    async fn local_actions_validate_input() {
+        let polls = service();
+        let err = polls.create_poll(" ", vec!["a".into(), "b".into()]).await;
+        assert!(matches!(err, Err(VisioError::Room(msg)) if msg.contains("question")));
+        let err = polls.create_poll("Q?", vec!["a".into()]).await;
+        assert!(matches!(err, Err(VisioError::Room(msg)) if msg.contains("options")));
+        let err = polls.create_poll("Q?", vec!["a".into(), "b".into()]).await;
+        assert!(matches!(err, Err(VisioError::Room(msg)) if msg == "not connected"));
+    }
+}
@@ -0,0 +1,207 @@
+//! Battery saver and thermal throttling.
+//!
+//! Saving power during a call takes several knobs: capture resolution and
+//! frame rate, rendering rate, stats polling and the self-view. Shells set
+//! a `PowerMode` once (`RoomManager::set_power_mode`) and the core derives
+//! each of them from it, instead of every platform orchestrating its own.
+//!
+//! Shells also forward the device thermal state from platform APIs
+//! (`RoomManager::report_thermal_state`); capture and rendering step down
+//! as the device heats up. `PowerState` combines both: the stricter limit
+//! wins.
+
+use std::time::Duration;
+
+use crate::controls::CaptureConstraints;
+use crate::metrics::SAMPLE_INTERVAL;
+
+/// Capture target (width, height, fps) in battery saver mode.
+const SAVER_CAPTURE: (u32, u32, u32) = (640, 360, 15);
+
+/// Highest rendering frame rate in battery saver mode.
+const SAVER_RENDER_FPS: u32 = 15;
+
+/// Interval between two stats samples in battery saver mode.
+const SAVER_STATS_INTERVAL: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PowerMode {
+    #[default]
+    Normal,
+    /// Lower publish resolution and frame rate, render less often, poll
+    /// stats less often and hide the self-view.
+    Saver,
+}
+
+impl PowerMode {
+    /// `constraints` capped to what this mode allows.
+    pub fn limit_capture(self, constraints: CaptureConstraints) -> CaptureConstraints {
+        match self {
+            Self::Normal => constraints,
+            Self::Saver => cap(constraints, SAVER_CAPTURE),
+        }
+    }
+
+    /// Highest frame rate renderers should draw at, `None` for no limit.
+    pub fn max_render_fps(self) -> Option<u32> {
+        match self {
+            Self::Normal => None,
+            Self::Saver => Some(SAVER_RENDER_FPS),
+        }
+    }
+
+    /// Interval between two call stats samples.
+    pub fn stats_interval(self) -> Duration {
+        match self {
+            Self::Normal => SAMPLE_INTERVAL,
+            Self::Saver => SAVER_STATS_INTERVAL,
+        }
+    }
+
+    /// Whether the local camera preview is rendered.
+    pub fn self_view_enabled(self) -> bool {
+        self == Self::Normal
+    }
+}
+
+/// Thermal state of the device, as reported by the platform (iOS
+/// `ProcessInfo.ThermalState`, Android `PowerManager` thermal status).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ThermalState {
+    #[default]
+    Nominal,
+    Fair,
+    Serious,
+    Critical,
+}
+
+impl ThermalState {
+    /// Capture (width, height, fps) and rendering frame rate allowed in
+    /// this state, `None` for no limit.
+    fn limits(self) -> Option<((u32, u32, u32), u32)> {
+        match self {
+            Self::Nominal => None,
+            Self::Fair => Some(((960, 540, 24), 24)),
+            Self::Serious => Some(((640, 360, 15), 15)),
+            Self::Critical => Some(((320, 180, 10), 10)),
+        }
+    }
+
+    /// `constraints` capped to what this state allows.
+    pub fn limit_capture(self, constraints: CaptureConstraints) -> CaptureConstraints {
+        match self.limits() {
+            Some((capture, _)) => cap(constraints, capture),
+            None => constraints,
+        }
+    }
+
+    /// Highest frame rate renderers should draw at, `None` for no limit.
+    pub fn max_render_fps(self) -> Option<u32> {
+        self.limits().map(|(_, fps)| fps)
+    }
+}
+
+/// Power mode and thermal state, which together limit capture and
+/// rendering.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PowerState {
+    pub mode: PowerMode,
+    pub thermal: ThermalState,
+}
+
+impl PowerState {
+    pub fn limit_capture(self, constraints: CaptureConstraints) -> CaptureConstraints {
+        self.thermal
+            .limit_capture(self.mode.limit_capture(constraints))
+    }
+
+    pub fn max_render_fps(self) -> Option<u32> {
+        match (self.mode.max_render_fps(), self.thermal.max_render_fps()) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        }
+    }
+}
+
+/// Why video quality was lowered, carried by `VisioEvent::QualityReduced`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QualityReductionReason {
+    /// The device is hot: capture and rendering were stepped down.
+    Thermal(ThermalState),
+}
+
+fn cap(
+    constraints: CaptureConstraints,
+    (width, height, max_fps): (u32, u32, u32),
+) -> CaptureConstraints {
+    CaptureConstraints {
+        width: constraints.width.min(width),
+        height: constraints.height.min(height),
+        max_fps: constraints.max_fps.min(max_fps),
+        ..constraints
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::ConnectionQuality;
+
+    #[test]
+    fn saver_caps_capture_and_rendering() {
+        let hd = CaptureConstraints::for_quality(&ConnectionQuality::Good, true);
+        assert_eq!(PowerMode::Normal.limit_capture(hd), hd);
+        assert_eq!(
+            PowerMode::Saver.limit_capture(hd),
+            CaptureConstraints {
+                width: 640,
+                height: 360,
+                max_fps: 15,
+                low_light_boost: true,
+            }
+        );
+        // Already below the cap on a poor uplink.
+        let lost = CaptureConstraints::for_quality(&ConnectionQuality::Lost, false);
+        assert_eq!(PowerMode::Saver.limit_capture(lost), lost);
+
+        assert_eq!(PowerMode::Normal.max_render_fps(), None);
+        assert_eq!(PowerMode::Saver.max_render_fps(), Some(15));
+        assert!(PowerMode::Saver.stats_interval() > PowerMode::Normal.stats_interval());
+        assert!(!PowerMode::Saver.self_view_enabled());
+    }
+
+    #[test]
+    fn thermal_state_steps_down_progressively() {
+        let hd = CaptureConstraints::default();
+        let steps = [
+            ThermalState::Nominal,
+            ThermalState::Fair,
+            ThermalState::Serious,
+            ThermalState::Critical,
+        ];
+        let captures: Vec<_> = steps
+            .iter()
+            .map(|s| {
+                let c = s.limit_capture(hd);
+                (c.width, c.max_fps)
+            })
+            .collect();
+        assert_eq!(captures, [(1280, 30), (960, 24), (640, 15), (320, 10)]);
+        assert_eq!(ThermalState::Nominal.max_render_fps(), None);
+        assert_eq!(ThermalState::Critical.max_render_fps(), Some(10));
+
+        // The stricter of power mode and thermal state wins.
+        let state = PowerState {
+            mode: PowerMode::Saver,
+            thermal: ThermalState::Fair,
+        };
+        assert_eq!(state.limit_capture(hd).width, 640);
+        assert_eq!(state.max_render_fps(), Some(15));
+        let state = PowerState {
+            mode: PowerMode::Normal,
+            thermal: ThermalState::Critical,
+        };
+        assert_eq!(state.max_render_fps(), Some(10));
+        assert_eq!(PowerState::default().max_render_fps(), None);
+    }
+}
@@ -0,0 +1,242 @@
+//! Presence of participants: available, away, be right back or do not
+//! disturb.
+//!
+//! Presence is shared through the `presence` participant attribute, the
+//! way raised hands use `handRaisedAt`: `"away"`, `"brb"` or `"dnd"`, and
+//! an empty or missing attribute for available. Unknown values read as
+//! available, so clients that never set the attribute (Meet web) show as
+//! such. Remote presence is kept in `ParticipantInfo::presence` and
+//! changes are reported with `PresenceChanged`.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use livekit::prelude::*;
+use tokio::sync::Mutex;
+
+use crate::errors::VisioError;
+use crate::events::{EventEmitter, VisioEvent};
+use crate::participants::ParticipantManager;
+
+pub(crate) const PRESENCE_ATTRIBUTE: &str = "presence";
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Presence {
+    #[default]
+    Available,
+    Away,
+    BeRightBack,
+    DoNotDisturb,
+}
+
+impl Presence {
+    /// Value of the `presence` attribute.
+    pub fn as_attribute(self) -> &'static str {
+        match self {
+            Presence::Available => "",
+            Presence::Away => "away",
+            Presence::BeRightBack => "brb",
+            Presence::DoNotDisturb => "dnd",
+        }
+    }
+
+    pub fn from_attribute(value: &str) -> Self {
+        match value {
+            "away" => Presence::Away,
+            "brb" => Presence::BeRightBack,
+            "dnd" => Presence::DoNotDisturb,
+            _ => Presence::Available,
+        }
+    }
+
+    /// Presence advertised in a participant's attributes.
+    pub(crate) fn of(attributes: &HashMap<String, String>) -> Self {
+        attributes
+            .get(PRESENCE_ATTRIBUTE)
+            .map_or(Presence::Available, |v| Self::from_attribute(v))
+    }
+}
+
+/// Presence of the local participant and tracking of remote ones. Obtain
+/// it with `RoomManager::presence()`.
+#[derive(Clone)]
+pub struct PresenceService {
+    room: Arc<Mutex<Option<Arc<Room>>>>,
+    emitter: EventEmitter,
+    participants: Arc<Mutex<ParticipantManager>>,
+    local: Arc<std::sync::Mutex<Presence>>,
+}
+
+impl PresenceService {
+    pub fn new(
+        room: Arc<Mutex<Option<Arc<Room>>>>,
+        emitter: EventEmitter,
+        participants: Arc<Mutex<ParticipantManager>>,
+    ) -> Self {
+        Self {
+            room,
+            emitter,
+            participants,
+            local: Arc::new(std::sync::Mutex::new(Presence::Available)),
+        }
+    }
+
+    /// Presence of the local participant.
+    pub fn presence(&self) -> Presence {
+        *self.local.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Presence of a remote participant, `Available` for unknown ones.
+    pub async fn participant_presence(&self, participant_sid: &str) -> Presence {
+        self.participants
+            .lock()
+            .await
+            .participant(participant_sid)
+            .map_or(Presence::Available, |p| p.presence)
+    }
+
+    /// Advertise the local participant's presence to the room.
+    pub async fn set_presence(&self, presence: Presence) -> Result<(), VisioError> {
+        let room = self.room.lock().await.clone();
+        let room = room.ok_or_else(|| VisioError::Room("not connected".into()))?;
+        let local = room.local_participant();
+        local
+            .set_attributes(HashMap::from([(
+                PRESENCE_ATTRIBUTE.to_string(),
+                presence.as_attribute().to_string(),
+            )]))
+            .await
+            .map_err(|e| VisioError::Room(format!("set presence: {e}")))?;
+
+        let previous = std::mem::replace(
+            &mut *self.local.lock().unwrap_or_else(|e| e.into_inner()),
+            presence,
+        );
+        if previous != presence {
+            self.emitter.emit(VisioEvent::PresenceChanged {
+                participant_sid: local.sid().to_string(),
+                presence,
+            });
+        }
+        Ok(())
+    }
+
+    /// Apply a remote participant's attribute change, from the event loop.
+    pub(crate) async fn handle_participant_attributes(
+        &self,
+        participant_sid: &str,
+        changed_attributes: &HashMap<String, String>,
+    ) {
+        let Some(value) = changed_attributes.get(PRESENCE_ATTRIBUTE) else {
+            return;
+        };
+        let presence = Presence::from_attribute(value);
+        {
+            let mut pm = self.participants.lock().await;
+            let Some(info) = pm.participant_mut(participant_sid) else {
+                return;
+            };
+            if info.presence == presence {
+                return;
+            }
+            info.presence = presence;
+        }
+        self.emitter.emit(VisioEvent::PresenceChanged {
+            participant_sid: participant_sid.to_string(),
+            presence,
+        });
+    }
+
+    /// Forget the local presence when leaving the room.
+    pub(crate) fn reset(&self) {
+        *self.local.lock().unwrap_or_else(|e| e.into_inner()) = Presence::Available;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::{ConnectionQuality, ParticipantInfo, VisioEventListener};
+
+    struct Collector(std::sync::Mutex<Vec<(String, Presence)>>);
+
+    impl VisioEventListener for Collector {
+        fn on_event(&self, event: VisioEvent) {
+            if let VisioEvent::PresenceChanged {
+                participant_sid,
+                presence,
+            } = event
+            {
+                self.0.lock().unwrap().push((participant_sid, presence));
+            }
+        }
+    }
+
+    #[test]
+    fn attribute_values_round_trip() {
+        for presence in [
+            Presence::Available,
+            Presence::Away,
+            Presence::BeRightBack,
+            Presence::DoNotDisturb,
+        ] {
+            assert_eq!(Presence::from_attribute(presence.as_attribute()), presence);
+        }
+        assert_eq!(Presence::from_attribute("lunch"), Presence::Available);
+        assert_eq!(Presence::of(&HashMap::new()), Presence::Available);
+    }
+
+    #[tokio::test]
+    async fn remote_changes_update_participants_and_emit() {
+        let emitter = EventEmitter::new();
+        let collector = Arc::new(Collector(std::sync::Mutex::new(Vec::new())));
+        emitter.add_listener(collector.clone());
+        let participants = Arc::new(Mutex::new(ParticipantManager::new()));
+        participants.lock().await.add_participant(ParticipantInfo {
+            sid: "PA_1".into(),
+            identity: "alice".into(),
+            name: None,
+            is_muted: false,
+            has_video: false,
+            video_track_sid: None,
+            connection_quality: ConnectionQuality::Good,
+            presence: Presence::Available,
+        });
+        let service = PresenceService::new(Arc::new(Mutex::new(None)), emitter, participants);
+        let attrs = |value: &str| HashMap::from([(PRESENCE_ATTRIBUTE.to_string(), value.into())]);
+
+        service
+            .handle_participant_attributes("PA_1", &attrs("dnd"))
+            .await;
+        // Repeated values, other attributes and unknown participants are ignored.
+        service
+            .handle_participant_attributes("PA_1", &attrs("dnd"))
+            .await;
+        let hand = HashMap::from([("handRaisedAt".to_string(), "x".to_string())]);
+        service.handle_participant_attributes("PA_1", &hand).await;
+        service
+            .handle_participant_attributes("PA_2", &attrs("away"))
+            .await;
+        assert_eq!(
+            service.participant_presence("PA_1").await,
+            Presence::DoNotDisturb
+        );
+
+        service
+            .handle_participant_attributes("PA_1", &attrs(""))
+            .await;
+        assert_eq!(
+            *collector.0.lock().unwrap(),
+            vec![
+                ("PA_1".to_string(), Presence::DoNotDisturb),
+                ("PA_1".to_string(), Presence::Available),
+            ]
+        );
+
+        assert!(matches!(
+            service.set_presence(Presence::Away).await,
+            Err(VisioError::Room(_))
+        ));
+        assert_eq!(service.presence(), Presence::Available);
+    }
+}
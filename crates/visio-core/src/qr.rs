@@ -0,0 +1,91 @@
+//! QR codes for room invites.
+//!
+//! Shells render the PNG returned by `generate_room_qr` as is, so none of
+//! them needs a QR library of its own.
+
+use qrcode::{Color, EcLevel, QrCode};
+
+use crate::errors::VisioError;
+
+/// Blank modules around the code, as required by the QR specification.
+const QUIET_ZONE: usize = 4;
+
+/// Largest image generated, in pixels.
+pub const MAX_QR_SIZE: u32 = 2048;
+
+/// PNG image (grayscale, `size` x `size` pixels) of a QR code encoding `url`.
+///
+/// Modules are drawn with a whole number of pixels and the code is
+/// centered, so the quiet zone may be slightly wider than four modules.
+/// `size` is raised to the smallest size able to hold the code and capped
+/// at `MAX_QR_SIZE`.
+pub fn generate_room_qr(url: &str, size: u32) -> Result<Vec<u8>, VisioError> {
+    let url = url.trim();
+    if url.is_empty() {
+        return Err(VisioError::InvalidUrl("empty room URL".into()));
+    }
+    let code = QrCode::with_error_correction_level(url, EcLevel::M)
+        .map_err(|e| VisioError::InvalidUrl(format!("cannot encode URL as QR code: {e}")))?;
+
+    let width = code.width();
+    let modules = width + 2 * QUIET_ZONE;
+    let size = (size.min(MAX_QR_SIZE) as usize).max(modules);
+    let scale = size / modules;
+    let offset = (size - width * scale) / 2;
+
+    let colors = code.to_colors();
+    let mut pixels = vec![u8::MAX; size * size];
+    for (i, color) in colors.iter().enumerate() {
+        if *color == Color::Light {
+            continue;
+        }
+        let (x, y) = (i % width, i / width);
+        for row in 0..scale {
+            let start = (offset + y * scale + row) * size + offset + x * scale;
+            pixels[start..start + scale].fill(0);
+        }
+    }
+
+    encode_png(&pixels, size as u32).map_err(|e| VisioError::Room(format!("encode QR code: {e}")))
+}
+
+fn encode_png(luma: &[u8], size: u32) -> Result<Vec<u8>, png::EncodingError> {
+    let mut out = Vec::new();
+    let mut encoder = png::Encoder::new(&mut out, size, size);
+    encoder.set_color(png::ColorType::Grayscale);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.write_header()?.write_image_data(luma)?;
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn png_size(png: &[u8]) -> (u32, u32) {
+        // IHDR is the first chunk, right after the 8-byte signature.
+        let w = u32::from_be_bytes(png[16..20].try_into().unwrap());
+        let h = u32::from_be_bytes(png[20..24].try_into().unwrap());
+        (w, h)
+    }
+
+    #[test]
+    fn generates_png_of_requested_size() {
+        let png = generate_room_qr("https://meet.example.com/abc-defg-hij", 256).unwrap();
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(png_size(&png), (256, 256));
+
+        let tiny = generate_room_qr("https://meet.example.com/abc-defg-hij", 1).unwrap();
+        let (w, h) = png_size(&tiny);
+        assert_eq!(w, h);
+        assert!(w > 1 && w < 64);
+        let huge = generate_room_qr("https://meet.example.com/abc-defg-hij", 100_000).unwrap();
+        assert_eq!(png_size(&huge), (MAX_QR_SIZE, MAX_QR_SIZE));
+
+        assert!(matches!(
+            generate_room_qr("  ", 256),
+            Err(VisioError::InvalidUrl(_))
+        ));
+        assert!(generate_room_qr(&"x".repeat(5000), 256).is_err());
+    }
+}
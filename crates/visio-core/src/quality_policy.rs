@@ -0,0 +1,395 @@
+//! Automatic fallback on a bad network.
+//!
+//! When the local connection quality stays `Poor` or `Lost` for
+//! `QualityPolicyConfig::delay`, `QualityPolicy` turns the camera off and,
+//! with `pause_remote_video`, stops receiving remote video as in an
+//! audio-only join. Once the quality has been `Good` or better for as
+//! long, it turns back on what it turned off. Both steps are reported
+//! with `QualityPolicyTriggered`, for the shell to update its camera
+//! state and tell the user why.
+
+use std::collections::HashSet;
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
+
+use livekit::prelude::{RemoteTrackPublication, Room};
+use livekit::track::TrackKind;
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+
+use crate::clock::Clock;
+use crate::controls::MeetingControls;
+use crate::events::{ConnectionQuality, EventEmitter, VisioEvent};
+
+/// Fallback policy, from the `quality_policy_*` settings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QualityPolicyConfig {
+    pub enabled: bool,
+    /// How long the quality must stay bad before the fallback, and good
+    /// before the restore.
+    pub delay: Duration,
+    /// Also unsubscribe from remote video during the fallback.
+    pub pause_remote_video: bool,
+}
+
+impl Default for QualityPolicyConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            delay: Duration::from_secs(10),
+            pause_remote_video: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Step {
+    Fallback,
+    Restore,
+}
+
+/// What the fallback turned off.
+#[derive(Debug, Default)]
+struct Fallback {
+    camera: bool,
+    remote_video: bool,
+    /// Remote video tracks unsubscribed by the policy.
+    paused_tracks: HashSet<String>,
+}
+
+/// Policy state of the current call, independent of timers.
+#[derive(Debug, Default)]
+struct PolicyTracker {
+    config: QualityPolicyConfig,
+    /// When the quality became bad (no fallback yet) or good again
+    /// (fallback active).
+    since: Option<Instant>,
+    fallback: Option<Fallback>,
+}
+
+impl PolicyTracker {
+    /// Follow a local quality change. Returns the delay after which
+    /// `due` should be checked, if a step may become due.
+    fn on_quality(&mut self, quality: &ConnectionQuality, now: Instant) -> Option<Duration> {
+        let bad = matches!(quality, ConnectionQuality::Poor | ConnectionQuality::Lost);
+        // Counting toward the fallback while bad, toward the restore
+        // while good.
+        let counting = if self.fallback.is_some() {
+            !bad
+        } else {
+            bad && self.config.enabled
+        };
+        if !counting {
+            self.since = None;
+            return None;
+        }
+        if self.since.is_some() {
+            return None;
+        }
+        self.since = Some(now);
+        Some(self.config.delay)
+    }
+
+    /// The step to take at `now`, if one is due.
+    fn due(&mut self, now: Instant) -> Option<Step> {
+        let since = self.since?;
+        if now.duration_since(since) < self.config.delay {
+            return None;
+        }
+        self.since = None;
+        Some(if self.fallback.is_some() {
+            Step::Restore
+        } else {
+            Step::Fallback
+        })
+    }
+}
+
+/// Applies the fallback policy to one room. Fed with the local quality by
+/// the room event loop.
+#[derive(Clone)]
+pub struct QualityPolicy {
+    room: Arc<Mutex<Option<Arc<Room>>>>,
+    emitter: EventEmitter,
+    clock: Arc<dyn Clock>,
+    /// The room's controls, set once the room manager is built.
+    controls: Arc<OnceLock<MeetingControls>>,
+    tracker: Arc<std::sync::Mutex<PolicyTracker>>,
+    timer: Arc<std::sync::Mutex<Option<JoinHandle<()>>>>,
+}
+
+impl QualityPolicy {
+    pub(crate) fn new(
+        room: Arc<Mutex<Option<Arc<Room>>>>,
+        emitter: EventEmitter,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self {
+            room,
+            emitter,
+            clock,
+            controls: Arc::new(OnceLock::new()),
+            tracker: Arc::new(std::sync::Mutex::new(PolicyTracker::default())),
+            timer: Arc::new(std::sync::Mutex::new(None)),
+        }
+    }
+
+    pub(crate) fn set_controls(&self, controls: MeetingControls) {
+        let _ = self.controls.set(controls);
+    }
+
+    /// Change the policy. An active fallback is still restored when the
+    /// quality recovers, even once disabled.
+    pub fn set_config(&self, config: QualityPolicyConfig) {
+        self.tracker().config = config;
+    }
+
+    pub fn config(&self) -> QualityPolicyConfig {
+        self.tracker().config
+    }
+
+    /// Whether the fallback is in effect.
+    pub fn is_active(&self) -> bool {
+        self.tracker().fallback.is_some()
+    }
+
+    fn tracker(&self) -> std::sync::MutexGuard<'_, PolicyTracker> {
+        self.tracker.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Follow the local connection quality, from the event loop.
+    pub(crate) fn on_local_quality(&self, quality: &ConnectionQuality) {
+        let now = self.clock.now();
+        let mut tracker = self.tracker();
+        let wait = tracker.on_quality(quality, now);
+        if tracker.since.is_none() {
+            self.cancel_timer();
+        }
+        let (Some(wait), Ok(rt)) = (wait, tokio::runtime::Handle::try_current()) else {
+            return;
+        };
+        let policy = self.clone();
+        let timer = rt.spawn(async move {
+            policy.clock.sleep(wait).await;
+            let Some(step) = policy.tracker().due(policy.clock.now()) else {
+                return;
+            };
+            // Detached: a quality change must not abort a step halfway.
+            tokio::spawn(async move {
+                match step {
+                    Step::Fallback => policy.fall_back().await,
+                    Step::Restore => policy.restore().await,
+                }
+            });
+        });
+        if let Some(previous) = self
+            .timer
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .replace(timer)
+        {
+            previous.abort();
+        }
+    }
+
+    /// Unsubscribe a newly subscribed remote video track while the
+    /// fallback pauses remote video. Returns whether it was paused.
+    pub(crate) fn pause_if_active(&self, publication: &RemoteTrackPublication) -> bool {
+        if publication.kind() != TrackKind::Video {
+            return false;
+        }
+        let mut tracker = self.tracker();
+        let Some(fallback) = tracker.fallback.as_mut().filter(|f| f.remote_video) else {
+            return false;
+        };
+        publication.set_subscribed(false);
+        fallback.paused_tracks.insert(publication.sid().to_string());
+        true
+    }
+
+    /// Forget the fallback when leaving the room.
+    pub(crate) fn reset(&self) {
+        self.cancel_timer();
+        let mut tracker = self.tracker();
+        tracker.since = None;
+        tracker.fallback = None;
+    }
+
+    fn cancel_timer(&self) {
+        if let Some(timer) = self.timer.lock().unwrap_or_else(|e| e.into_inner()).take() {
+            timer.abort();
+        }
+    }
+
+    async fn fall_back(&self) {
+        let Some(controls) = self.controls.get() else {
+            return;
+        };
+        let mut fallback = Fallback::default();
+        if controls.is_camera_enabled().await {
+            match controls.set_camera_enabled(false).await {
+                Ok(()) => fallback.camera = true,
+                Err(e) => tracing::warn!("quality policy: cannot disable camera: {e}"),
+            }
+        }
+        if self.config().pause_remote_video
+            && let Some(room) = self.room.lock().await.clone()
+        {
+            fallback.remote_video = true;
+            for participant in room.remote_participants().values() {
+                for publication in participant.track_publications().values() {
+                    if publication.kind() == TrackKind::Video && publication.is_subscribed() {
+                        publication.set_subscribed(false);
+                        fallback.paused_tracks.insert(publication.sid().to_string());
+                    }
+                }
+            }
+        }
+        let (camera, remote_video) = (fallback.camera, fallback.remote_video);
+        self.tracker().fallback = Some(fallback);
+        tracing::info!("quality policy: fallback (camera: {camera}, remote video: {remote_video})");
+        self.emitter.emit(VisioEvent::QualityPolicyTriggered {
+            active: true,
+            camera,
+            remote_video,
+        });
+    }
+
+    async fn restore(&self) {
+        let Some(fallback) = self.tracker().fallback.take() else {
+            return;
+        };
+        if fallback.camera
+            && let Some(controls) = self.controls.get()
+            && let Err(e) = controls.set_camera_enabled(true).await
+        {
+            tracing::warn!("quality policy: cannot restore camera: {e}");
+        }
+        if !fallback.paused_tracks.is_empty()
+            && let Some(room) = self.room.lock().await.clone()
+        {
+            for participant in room.remote_participants().values() {
+                for (sid, publication) in participant.track_publications() {
+                    if fallback.paused_tracks.contains(&sid.to_string()) {
+                        publication.set_subscribed(true);
+                    }
+                }
+            }
+        }
+        tracing::info!("quality policy: restored");
+        self.emitter.emit(VisioEvent::QualityPolicyTriggered {
+            active: false,
+            camera: fallback.camera,
+            remote_video: fallback.remote_video,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::MockClock;
+    use crate::events::VisioEventListener;
+    use crate::meet_api::MockMeetApi;
+    use crate::room::RoomManager;
+
+    const POOR: ConnectionQuality = ConnectionQuality::Poor;
+    const GOOD: ConnectionQuality = ConnectionQuality::Good;
+
+    fn secs(s: u64) -> Duration {
+        Duration::from_secs(s)
+    }
+
+    #[test]
+    fn falls_back_after_a_sustained_bad_quality() {
+        let t0 = Instant::now();
+        let mut tracker = PolicyTracker::default();
+        assert_eq!(tracker.on_quality(&POOR, t0), Some(secs(10)));
+        // Still bad: the first timer keeps counting.
+        assert_eq!(
+            tracker.on_quality(&ConnectionQuality::Lost, t0 + secs(3)),
+            None
+        );
+        assert_eq!(tracker.due(t0 + secs(9)), None);
+        assert_eq!(tracker.due(t0 + secs(10)), Some(Step::Fallback));
+
+        // A short recovery does not restore.
+        tracker.fallback = Some(Fallback::default());
+        assert_eq!(tracker.on_quality(&GOOD, t0 + secs(20)), Some(secs(10)));
+        assert_eq!(tracker.on_quality(&POOR, t0 + secs(25)), None);
+        assert_eq!(tracker.due(t0 + secs(30)), None);
+        assert_eq!(
+            tracker.on_quality(&ConnectionQuality::Excellent, t0 + secs(31)),
+            Some(secs(10))
+        );
+        assert_eq!(tracker.due(t0 + secs(41)), Some(Step::Restore));
+    }
+
+    #[test]
+    fn brief_drops_and_disabled_policy_do_nothing() {
+        let t0 = Instant::now();
+        let mut tracker = PolicyTracker::default();
+        tracker.on_quality(&POOR, t0);
+        tracker.on_quality(&GOOD, t0 + secs(5));
+        assert_eq!(tracker.due(t0 + secs(10)), None);
+
+        tracker.config.enabled = false;
+        assert_eq!(tracker.on_quality(&POOR, t0 + secs(20)), None);
+        assert_eq!(tracker.due(t0 + secs(60)), None);
+    }
+
+    struct Triggers(std::sync::Mutex<Vec<(bool, bool)>>);
+
+    impl VisioEventListener for Triggers {
+        fn on_event(&self, event: VisioEvent) {
+            if let VisioEvent::QualityPolicyTriggered { active, camera, .. } = event {
+                self.0.lock().unwrap().push((active, camera));
+            }
+        }
+    }
+
+    async fn settle() {
+        for _ in 0..10 {
+            tokio::task::yield_now().await;
+        }
+    }
+
+    #[tokio::test]
+    async fn timers_follow_the_clock() {
+        let clock = MockClock::new();
+        let manager =
+            RoomManager::with_clock(Arc::new(MockMeetApi::new()), Arc::new(clock.clone()));
+        let triggers = Arc::new(Triggers(std::sync::Mutex::new(Vec::new())));
+        manager.add_listener(triggers.clone());
+        let policy = manager.quality_policy();
+
+        // Recovered before the delay, then left the room: nothing happens.
+        policy.on_local_quality(&POOR);
+        settle().await;
+        assert_eq!(clock.pending_sleeps(), 1);
+        policy.on_local_quality(&GOOD);
+        settle().await;
+        assert_eq!(clock.pending_sleeps(), 0);
+        policy.on_local_quality(&POOR);
+        settle().await;
+        policy.reset();
+        settle().await;
+        assert_eq!(clock.pending_sleeps(), 0);
+
+        policy.on_local_quality(&POOR);
+        settle().await;
+        clock.advance(secs(10));
+        settle().await;
+        assert!(policy.is_active());
+        policy.on_local_quality(&GOOD);
+        settle().await;
+        clock.advance(secs(10));
+        settle().await;
+        assert!(!policy.is_active());
+        // The camera was off: the policy had nothing to turn off.
+        assert_eq!(
+            *triggers.0.lock().unwrap(),
+            vec![(true, false), (false, false)]
+        );
+    }
+}
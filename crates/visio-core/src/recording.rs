@@ -0,0 +1,306 @@
+//! Cloud recording of the current room.
+//!
+//! Recording runs server side: the Meet API starts a LiveKit egress
+//! (screen recording or transcript) and LiveKit reports it to every
+//! participant through the room's `active_recording` flag. Only room
+//! owners and administrators may start or stop it.
+
+use std::sync::Arc;
+
+use tokio::sync::Mutex;
+
+use crate::auth::{AuthService, TokenInfo};
+use crate::errors::VisioError;
+use crate::events::{EventEmitter, VisioEvent};
+use crate::meet_api::{MeetApi, RecordingAction, RecordingRequest};
+use crate::permissions::LocalPermissions;
+
+/// What the egress produces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordingMode {
+    /// Video recording of the meeting.
+    ScreenRecording,
+    /// Speech-to-text transcript.
+    Transcript,
+}
+
+impl RecordingMode {
+    /// Name used by the Meet API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RecordingMode::ScreenRecording => "screen_recording",
+            RecordingMode::Transcript => "transcript",
+        }
+    }
+}
+
+/// Recording state of the current room.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RecordingStatus {
+    #[default]
+    Stopped,
+    /// Start requested, waiting for LiveKit to report the recording.
+    Starting,
+    Recording,
+    /// Stop requested, waiting for LiveKit to report the end.
+    Stopping,
+}
+
+/// Starts and stops the cloud recording, shared with `RoomManager` (see
+/// `RoomManager::recording()`).
+#[derive(Clone)]
+pub struct RecordingService {
+    meet_api: Arc<dyn MeetApi>,
+    emitter: EventEmitter,
+    last_meet_url: Arc<Mutex<Option<String>>>,
+    session_cookie: Arc<Mutex<Option<String>>>,
+    meet_room: Arc<Mutex<Option<TokenInfo>>>,
+    local_permissions: Arc<Mutex<Option<LocalPermissions>>>,
+    status: Arc<std::sync::Mutex<RecordingStatus>>,
+}
+
+impl RecordingService {
+    pub(crate) fn new(
+        meet_api: Arc<dyn MeetApi>,
+        emitter: EventEmitter,
+        last_meet_url: Arc<Mutex<Option<String>>>,
+        session_cookie: Arc<Mutex<Option<String>>>,
+        meet_room: Arc<Mutex<Option<TokenInfo>>>,
+        local_permissions: Arc<Mutex<Option<LocalPermissions>>>,
+    ) -> Self {
+        Self {
+            meet_api,
+            emitter,
+            last_meet_url,
+            session_cookie,
+            meet_room,
+            local_permissions,
+            status: Arc::new(std::sync::Mutex::new(RecordingStatus::Stopped)),
+        }
+    }
+
+    /// Current recording state.
+    pub fn status(&self) -> RecordingStatus {
+        *self.status.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Ask the Meet API to start recording. The status becomes `Starting`
+    /// until LiveKit reports the recording as active.
+    ///
+    /// Fails with `VisioError::AuthRequired` if the token or the Meet API
+    /// does not allow it, and with `VisioError::Room` if the instance does
+    /// not support recording or a recording is already running.
+    pub async fn start_recording(&self, mode: RecordingMode) -> Result<(), VisioError> {
+        if self.status() != RecordingStatus::Stopped {
+            return Err(VisioError::Room("recording already in progress".into()));
+        }
+        self.check_permission().await?;
+        let previous = self.set_status(RecordingStatus::Starting);
+        let action = RecordingAction::Start {
+            mode: mode.as_str().to_string(),
+        };
+        self.send(action).await.inspect_err(|_| {
+            self.set_status(previous);
+        })
+    }
+
+    /// Ask the Meet API to stop recording. The status becomes `Stopping`
+    /// until LiveKit reports the end of the recording.
+    pub async fn stop_recording(&self) -> Result<(), VisioError> {
+        if self.status() == RecordingStatus::Stopped {
+            return Ok(());
+        }
+        self.check_permission().await?;
+        let previous = self.set_status(RecordingStatus::Stopping);
+        self.send(RecordingAction::Stop).await.inspect_err(|_| {
+            self.set_status(previous);
+        })
+    }
+
+    /// LiveKit reported whether the room is being recorded.
+    pub(crate) fn set_active(&self, active: bool) {
+        let status = if active {
+            RecordingStatus::Recording
+        } else {
+            RecordingStatus::Stopped
+        };
+        self.set_status(status);
+    }
+
+    /// Forget the state of the previous room.
+    pub(crate) fn reset(&self) {
+        *self.status.lock().unwrap_or_else(|e| e.into_inner()) = RecordingStatus::Stopped;
+    }
+
+    /// Refuse early when the token grants are known and do not include
+    /// room administration.
+    async fn check_permission(&self) -> Result<(), VisioError> {
+        match self.local_permissions.lock().await.as_ref() {
+            Some(perms) if !perms.is_admin => Err(VisioError::AuthRequired),
+            _ => Ok(()),
+        }
+    }
+
+    async fn send(&self, action: RecordingAction) -> Result<(), VisioError> {
+        let meet_url = self
+            .last_meet_url
+            .lock()
+            .await
+            .clone()
+            .ok_or_else(|| VisioError::Room("not connected".into()))?;
+        let room_id = self
+            .meet_room
+            .lock()
+            .await
+            .as_ref()
+            .and_then(|room| room.room_id.clone())
+            .ok_or_else(|| VisioError::Room("room id not reported by the Meet API".into()))?;
+        let request = RecordingRequest {
+            instance: AuthService::parse_instance(&meet_url)?,
+            room_id,
+            action,
+            session_cookie: self.session_cookie.lock().await.clone(),
+        };
+        let resp = self.meet_api.update_recording(&request).await?;
+        match resp.status {
+            200..=299 => Ok(()),
+            401 | 403 => Err(VisioError::AuthRequired),
+            404 | 405 => Err(VisioError::Room(
+                "recording is not supported by this instance".into(),
+            )),
+            status => Err(VisioError::Room(format!(
+                "recording request failed with status {status}"
+            ))),
+        }
+    }
+
+    /// Store `status` and emit `RecordingStatusChanged` if it changed.
+    /// Returns the previous status.
+    fn set_status(&self, status: RecordingStatus) -> RecordingStatus {
+        let previous = std::mem::replace(
+            &mut *self.status.lock().unwrap_or_else(|e| e.into_inner()),
+            status,
+        );
+        if previous != status {
+            tracing::info!("recording status: {status:?}");
+            self.emitter
+                .emit(VisioEvent::RecordingStatusChanged(status));
+        }
+        previous
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::VisioEventListener;
+    use crate::meet_api::MockMeetApi;
+
+    struct StatusCapture(std::sync::Mutex<Vec<RecordingStatus>>);
+
+    impl VisioEventListener for StatusCapture {
+        fn on_event(&self, event: VisioEvent) {
+            if let VisioEvent::RecordingStatusChanged(status) = event {
+                self.0.lock().unwrap().push(status);
+            }
+        }
+    }
+
+    fn connected_service(
+        api: Arc<MockMeetApi>,
+        is_admin: bool,
+    ) -> (RecordingService, Arc<StatusCapture>) {
+        let emitter = EventEmitter::new();
+        let capture = Arc::new(StatusCapture(std::sync::Mutex::new(Vec::new())));
+        emitter.add_listener(capture.clone());
+        let room = TokenInfo {
+            livekit_url: "wss://lk".into(),
+            token: "jwt".into(),
+            access_level: Some("public".into()),
+            room_id: Some("room-uuid".into()),
+            pin_code: None,
+            scheduled_end: None,
+            max_duration: None,
+        };
+        let mut perms = LocalPermissions::from_token("jwt", None);
+        perms.is_admin = is_admin;
+        let service = RecordingService::new(
+            api,
+            emitter,
+            Arc::new(Mutex::new(Some(
+                "https://meet.example.com/abc-defg-hij".into(),
+            ))),
+            Arc::new(Mutex::new(Some("cookie".into()))),
+            Arc::new(Mutex::new(Some(room))),
+            Arc::new(Mutex::new(Some(perms))),
+        );
+        (service, capture)
+    }
+
+    #[tokio::test]
+    async fn start_and_stop_follow_livekit_state() {
+        let api = Arc::new(MockMeetApi::new());
+        api.push_response(201, "{}");
+        api.push_response(200, "{}");
+        let (service, capture) = connected_service(api.clone(), true);
+
+        service
+            .start_recording(RecordingMode::Transcript)
+            .await
+            .unwrap();
+        assert_eq!(service.status(), RecordingStatus::Starting);
+        service.set_active(true);
+        service.stop_recording().await.unwrap();
+        assert_eq!(service.status(), RecordingStatus::Stopping);
+        service.set_active(false);
+
+        let requests = api.recording_requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].instance, "meet.example.com");
+        assert_eq!(requests[0].room_id, "room-uuid");
+        assert_eq!(
+            requests[0].action,
+            RecordingAction::Start {
+                mode: "transcript".into()
+            }
+        );
+        assert_eq!(requests[0].session_cookie.as_deref(), Some("cookie"));
+        assert_eq!(requests[1].action, RecordingAction::Stop);
+        assert_eq!(
+            *capture.0.lock().unwrap(),
+            vec![
+                RecordingStatus::Starting,
+                RecordingStatus::Recording,
+                RecordingStatus::Stopping,
+                RecordingStatus::Stopped,
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn refused_start_reverts_to_stopped() {
+        let api = Arc::new(MockMeetApi::new());
+        api.push_response(404, "");
+        let (service, capture) = connected_service(api.clone(), true);
+
+        let err = service
+            .start_recording(RecordingMode::ScreenRecording)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, VisioError::Room(_)), "got {err:?}");
+        assert_eq!(service.status(), RecordingStatus::Stopped);
+        assert_eq!(
+            *capture.0.lock().unwrap(),
+            vec![RecordingStatus::Starting, RecordingStatus::Stopped]
+        );
+
+        // Without the admin grant no request is sent.
+        let (service, _) = connected_service(api.clone(), false);
+        let err = service
+            .start_recording(RecordingMode::ScreenRecording)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, VisioError::AuthRequired), "got {err:?}");
+        assert_eq!(api.recording_requests().len(), 1);
+    }
+}
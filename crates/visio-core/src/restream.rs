@@ -0,0 +1,684 @@
+//! Restreaming of the meeting to an RTMP, RTSP or SRT endpoint, e.g. for
+//! an overflow room or a media server on the local network.
+//!
+//! The core ships no video encoder, so `Restreamer` drives an `ffmpeg`
+//! process, which makes restreaming a desktop feature. ffmpeg reads two
+//! raw inputs over loopback TCP: the mixed room audio, tapped from the
+//! playout buffer like `LocalRecorder`, and the video of the active
+//! speaker, scaled to a fixed size. Both are paced by the wall
+//! clock: missing audio is padded with silence and the last video frame
+//! is repeated, so ffmpeg sees constant-rate inputs. `LocalRecorder`
+//! uses the same `Session` to encode MP4, WebM and Ogg files.
+//!
+//! ffmpeg cannot change its bitrate on the fly: `set_video_bitrate`
+//! restarts it, which the endpoint sees as a short reconnection.
+
+use std::collections::VecDeque;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{Ipv4Addr, TcpListener, TcpStream};
+use std::process::{Child, Command, Stdio};
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, TryLockError};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+use futures_util::StreamExt;
+use livekit::track::RemoteVideoTrack;
+use livekit::webrtc::video_stream::native::NativeVideoStream;
+
+use crate::audio_playout::{AudioPlayoutBuffer, TapId};
+use crate::errors::VisioError;
+use crate::events::{EventEmitter, ParticipantInfo, VisioEvent};
+use crate::frame_convert::scale_i420;
+
+const SAMPLE_RATE: u32 = 48_000;
+
+/// At most one second of audio waits for ffmpeg; older samples are dropped.
+const AUDIO_QUEUE_MAX: usize = SAMPLE_RATE as usize;
+
+/// How often queued audio is sent to ffmpeg.
+const AUDIO_WRITE_INTERVAL: Duration = Duration::from_millis(20);
+
+/// ffmpeg must connect to its inputs within this time after starting.
+const INPUT_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RestreamOptions {
+    /// Size of the streamed video; remote frames are letterboxed into it.
+    pub width: u32,
+    pub height: u32,
+    pub fps: u32,
+    pub video_bitrate_kbps: u32,
+    pub audio_bitrate_kbps: u32,
+    /// ffmpeg executable; `None` looks it up on `PATH`.
+    pub ffmpeg_path: Option<String>,
+}
+
+impl Default for RestreamOptions {
+    fn default() -> Self {
+        Self {
+            width: 1280,
+            height: 720,
+            fps: 30,
+            video_bitrate_kbps: 2500,
+            audio_bitrate_kbps: 128,
+            ffmpeg_path: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RestreamState {
+    #[default]
+    Stopped,
+    /// ffmpeg is starting, or restarting after a bitrate change.
+    Connecting,
+    /// ffmpeg reads the room audio and video.
+    Live,
+    /// ffmpeg exited on its own; see `RestreamStatus::error`.
+    Failed,
+}
+
+/// State of the restream, reported with `VisioEvent::RestreamStatusChanged`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RestreamStatus {
+    pub state: RestreamState,
+    pub video_bitrate_kbps: u32,
+    /// Last line ffmpeg printed before failing.
+    pub error: Option<String>,
+}
+
+/// The video track to restream: the dominant speaker's camera, or else the
+/// first remote participant with video.
+pub fn restream_track_sid(
+    participants: &[ParticipantInfo],
+    dominant_speaker: Option<&str>,
+    local_sid: Option<&str>,
+) -> Option<String> {
+    let remote_video = |p: &&ParticipantInfo| {
+        Some(p.sid.as_str()) != local_sid && p.has_video && p.video_track_sid.is_some()
+    };
+    participants
+        .iter()
+        .filter(remote_video)
+        .find(|p| Some(p.sid.as_str()) == dominant_speaker)
+        .or_else(|| participants.iter().find(remote_video))
+        .and_then(|p| p.video_track_sid.clone())
+}
+
+/// ffmpeg arguments reading raw video from `video_port` and raw audio
+/// from `audio_port`, as fed by `Session`.
+pub(crate) fn ffmpeg_input_args(
+    options: &RestreamOptions,
+    video_port: u16,
+    audio_port: u16,
+) -> Vec<String> {
+    let size = format!("{}x{}", options.width, options.height);
+    let rate = options.fps.max(1).to_string();
+    let video_input = format!("tcp://127.0.0.1:{video_port}");
+    let audio_input = format!("tcp://127.0.0.1:{audio_port}");
+    let sample_rate = SAMPLE_RATE.to_string();
+    #[rustfmt::skip]
+    let args: &[&str] = &[
+        "-hide_banner", "-loglevel", "error",
+        "-f", "rawvideo", "-pix_fmt", "yuv420p", "-s", &size, "-framerate", &rate,
+        "-i", &video_input,
+        "-f", "s16le", "-ar", &sample_rate, "-ac", "1",
+        "-i", &audio_input,
+    ];
+    args.iter().map(|arg| arg.to_string()).collect()
+}
+
+/// Command line for ffmpeg streaming its inputs to `url`. The container
+/// follows the URL scheme.
+fn ffmpeg_args(
+    url: &str,
+    options: &RestreamOptions,
+    video_port: u16,
+    audio_port: u16,
+) -> Result<Vec<String>, VisioError> {
+    let scheme = url
+        .split_once("://")
+        .map(|(scheme, _)| scheme.to_lowercase());
+    let output: &[&str] = match scheme.as_deref() {
+        Some("rtmp" | "rtmps") => &["-f", "flv"],
+        Some("rtsp" | "rtsps") => &["-f", "rtsp", "-rtsp_transport", "tcp"],
+        Some("srt" | "udp") => &["-f", "mpegts"],
+        _ => {
+            return Err(VisioError::InvalidUrl(format!(
+                "unsupported restream URL: {url}"
+            )));
+        }
+    };
+    let gop = (options.fps.max(1) * 2).to_string();
+    let video_bitrate = format!("{}k", options.video_bitrate_kbps);
+    let buffer_size = format!("{}k", options.video_bitrate_kbps * 2);
+    let audio_bitrate = format!("{}k", options.audio_bitrate_kbps);
+    #[rustfmt::skip]
+    let encoding: &[&str] = &[
+        "-c:v", "libx264", "-preset", "veryfast", "-tune", "zerolatency",
+        "-b:v", &video_bitrate, "-maxrate", &video_bitrate, "-bufsize", &buffer_size,
+        "-g", &gop,
+        "-c:a", "aac", "-b:a", &audio_bitrate,
+    ];
+    let mut args = ffmpeg_input_args(options, video_port, audio_port);
+    args.extend(encoding.iter().map(|arg| arg.to_string()));
+    args.extend(output.iter().map(|arg| arg.to_string()));
+    args.push(url.to_string());
+    Ok(args)
+}
+
+/// Samples due for ffmpeg from `queue`, padded with silence to `due`.
+fn take_due(queue: &mut VecDeque<i16>, due: usize) -> Vec<i16> {
+    let available = due.min(queue.len());
+    let mut block: Vec<i16> = queue.drain(..available).collect();
+    block.resize(due, 0);
+    block
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Current status, shared with the ffmpeg threads.
+struct StatusCell {
+    status: Mutex<RestreamStatus>,
+    emitter: EventEmitter,
+}
+
+impl StatusCell {
+    fn set(&self, state: RestreamState, error: Option<String>) {
+        let status = {
+            let mut status = lock(&self.status);
+            status.state = state;
+            status.error = error;
+            status.clone()
+        };
+        self.emitter.emit(VisioEvent::RestreamStatusChanged(status));
+    }
+}
+
+/// Called by `Session` when ffmpeg goes live or exits on its own.
+pub(crate) type StateCallback = Arc<dyn Fn(RestreamState, Option<String>) + Send + Sync>;
+
+/// Video of one remote track, scaled into the fixed-size frame that
+/// `Session` repeats to ffmpeg. Black until a track is set.
+pub(crate) struct SpeakerVideo {
+    /// Latest video frame, packed I420 at the output size.
+    frame: Arc<Mutex<Vec<u8>>>,
+    size: (usize, usize),
+    /// SID of the track and the task copying its frames.
+    track: Mutex<Option<(String, tokio::task::JoinHandle<()>)>>,
+}
+
+impl SpeakerVideo {
+    fn new(width: u32, height: u32) -> Self {
+        let size = (width as usize, height as usize);
+        let mut frame = Vec::new();
+        scale_i420([&[], &[], &[]], [0; 3], (0, 0), size, &mut frame);
+        Self {
+            frame: Arc::new(Mutex::new(frame)),
+            size,
+            track: Mutex::new(None),
+        }
+    }
+
+    pub(crate) fn track_sid(&self) -> Option<String> {
+        lock(&self.track).as_ref().map(|(sid, _)| sid.clone())
+    }
+
+    /// Copy the frames of `track` (with its SID), or black with `None`.
+    pub(crate) fn set_track(&self, track: Option<(String, RemoteVideoTrack)>) {
+        let mut current = lock(&self.track);
+        if let Some((_, task)) = current.take() {
+            task.abort();
+        }
+        let size = self.size;
+        let Some((sid, track)) = track else {
+            let mut frame = lock(&self.frame);
+            scale_i420([&[], &[], &[]], [0; 3], (0, 0), size, &mut frame);
+            return;
+        };
+        tracing::info!("ffmpeg video switched to track {sid}");
+        let frame = self.frame.clone();
+        let task = tokio::spawn(async move {
+            let mut stream = NativeVideoStream::new(track.rtc_track());
+            let mut out = Vec::new();
+            while let Some(video_frame) = stream.next().await {
+                let i420 = video_frame.buffer.to_i420();
+                let (y, u, v) = i420.data();
+                let (sy, su, sv) = i420.strides();
+                scale_i420(
+                    [y, u, v],
+                    [sy as usize, su as usize, sv as usize],
+                    (i420.width() as usize, i420.height() as usize),
+                    size,
+                    &mut out,
+                );
+                std::mem::swap(&mut *lock(&frame), &mut out);
+            }
+        });
+        *current = Some((sid, task));
+    }
+}
+
+impl Drop for SpeakerVideo {
+    fn drop(&mut self) {
+        if let Some((_, task)) = lock(&self.track).take() {
+            task.abort();
+        }
+    }
+}
+
+/// Media fed to ffmpeg, kept across restarts.
+pub(crate) struct Media {
+    pub(crate) video: Arc<SpeakerVideo>,
+    audio: Arc<Mutex<VecDeque<i16>>>,
+}
+
+impl Media {
+    /// Black video at the size of `options` and no audio yet.
+    pub(crate) fn new(options: &RestreamOptions) -> Self {
+        Self {
+            video: Arc::new(SpeakerVideo::new(options.width, options.height)),
+            audio: Arc::new(Mutex::new(VecDeque::with_capacity(AUDIO_QUEUE_MAX))),
+        }
+    }
+
+    /// Queue what `playout` plays for the audio input.
+    pub(crate) fn tap(&self, playout: &AudioPlayoutBuffer) -> TapId {
+        let queue = self.audio.clone();
+        playout.add_tap(Box::new(move |samples| {
+            // Never wait on the audio thread: drop the block while the
+            // reader holds the queue.
+            let mut queue = match queue.try_lock() {
+                Ok(queue) => queue,
+                Err(TryLockError::Poisoned(e)) => e.into_inner(),
+                Err(TryLockError::WouldBlock) => return,
+            };
+            queue.extend(samples.iter().copied());
+            let excess = queue.len().saturating_sub(AUDIO_QUEUE_MAX);
+            queue.drain(..excess);
+        }))
+    }
+}
+
+/// One ffmpeg process and the threads feeding it.
+pub(crate) struct Session {
+    stop: Arc<AtomicBool>,
+    child: Arc<Mutex<Child>>,
+    threads: Vec<JoinHandle<()>>,
+}
+
+impl Session {
+    /// Start ffmpeg with the arguments `args` returns for the video and
+    /// audio input ports.
+    pub(crate) fn start(
+        options: &RestreamOptions,
+        media: &Media,
+        args: &dyn Fn(u16, u16) -> Result<Vec<String>, VisioError>,
+        on_state: StateCallback,
+    ) -> Result<Self, VisioError> {
+        let bind = || {
+            let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0))?;
+            listener.set_nonblocking(true)?;
+            let port = listener.local_addr()?.port();
+            Ok::<_, std::io::Error>((listener, port))
+        };
+        let ((video_listener, video_port), (audio_listener, audio_port)) = bind()
+            .and_then(|video| Ok((video, bind()?)))
+            .map_err(|e| VisioError::Room(format!("cannot open restream inputs: {e}")))?;
+        let args = args(video_port, audio_port)?;
+
+        let program = options.ffmpeg_path.as_deref().unwrap_or("ffmpeg");
+        let mut child = Command::new(program)
+            .args(&args)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| VisioError::Room(format!("cannot start {program}: {e}")))?;
+        let stderr = child.stderr.take();
+        let child = Arc::new(Mutex::new(child));
+        let stop = Arc::new(AtomicBool::new(false));
+        let connected = Arc::new(AtomicU8::new(0));
+
+        // Each input reports Live once both are connected.
+        let accept = {
+            let (stop, child, on_state) = (stop.clone(), child.clone(), on_state.clone());
+            move |listener: TcpListener| -> Option<TcpStream> {
+                let deadline = Instant::now() + INPUT_CONNECT_TIMEOUT;
+                while !stop.load(Ordering::Relaxed) {
+                    match listener.accept() {
+                        Ok((stream, _)) => {
+                            stream.set_nonblocking(false).ok()?;
+                            if connected.fetch_add(1, Ordering::Relaxed) == 1 {
+                                on_state(RestreamState::Live, None);
+                            }
+                            return Some(stream);
+                        }
+                        Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                            if Instant::now() >= deadline {
+                                tracing::warn!("restream: ffmpeg did not connect to its inputs");
+                                let _ = lock(&child).kill();
+                                return None;
+                            }
+                            std::thread::sleep(Duration::from_millis(50));
+                        }
+                        Err(e) => {
+                            tracing::warn!("restream: cannot accept ffmpeg input: {e}");
+                            return None;
+                        }
+                    }
+                }
+                None
+            }
+        };
+
+        let spawn = |name: &str, body: Box<dyn FnOnce() + Send>| {
+            std::thread::Builder::new()
+                .name(name.into())
+                .spawn(body)
+                .map_err(|e| VisioError::Room(format!("cannot start restream thread: {e}")))
+        };
+        let mut threads = Vec::new();
+
+        let video = {
+            let (accept, stop) = (accept.clone(), stop.clone());
+            let frame = media.video.frame.clone();
+            let interval = Duration::from_secs(1) / options.fps.max(1);
+            move || {
+                let Some(mut conn) = accept(video_listener) else {
+                    return;
+                };
+                let mut next = Instant::now();
+                while !stop.load(Ordering::Relaxed) {
+                    let data = lock(&frame).clone();
+                    if conn.write_all(&data).is_err() {
+                        break;
+                    }
+                    next += interval;
+                    match next.checked_duration_since(Instant::now()) {
+                        Some(wait) => std::thread::sleep(wait),
+                        None => next = Instant::now(),
+                    }
+                }
+            }
+        };
+        threads.push(spawn("visio-restream-video", Box::new(video))?);
+
+        let audio = {
+            let (stop, queue) = (stop.clone(), media.audio.clone());
+            move || {
+                let Some(mut conn) = accept(audio_listener) else {
+                    return;
+                };
+                let start = Instant::now();
+                let mut written: u64 = 0;
+                while !stop.load(Ordering::Relaxed) {
+                    std::thread::sleep(AUDIO_WRITE_INTERVAL);
+                    let elapsed = start.elapsed().as_micros() as u64;
+                    let due = (elapsed * u64::from(SAMPLE_RATE) / 1_000_000 - written) as usize;
+                    let block = take_due(&mut lock(&queue), due);
+                    let bytes: Vec<u8> = block.iter().flat_map(|s| s.to_le_bytes()).collect();
+                    if conn.write_all(&bytes).is_err() {
+                        break;
+                    }
+                    written += due as u64;
+                }
+            }
+        };
+        threads.push(spawn("visio-restream-audio", Box::new(audio))?);
+
+        let monitor = {
+            let (stop, child) = (stop.clone(), child.clone());
+            move || {
+                // stderr closes when ffmpeg exits.
+                let mut last_line = None;
+                if let Some(stderr) = stderr {
+                    for line in BufReader::new(stderr).lines().map_while(Result::ok) {
+                        if !line.trim().is_empty() {
+                            tracing::warn!("ffmpeg: {line}");
+                            last_line = Some(line);
+                        }
+                    }
+                }
+                let exit = lock(&child).wait();
+                if stop.load(Ordering::Relaxed) {
+                    return;
+                }
+                let error = match (last_line, exit) {
+                    (Some(line), _) => line,
+                    (None, Ok(exit)) => format!("ffmpeg exited with {exit}"),
+                    (None, Err(e)) => format!("ffmpeg: {e}"),
+                };
+                on_state(RestreamState::Failed, Some(error));
+            }
+        };
+        threads.push(spawn("visio-restream-monitor", Box::new(monitor))?);
+
+        Ok(Self {
+            stop,
+            child,
+            threads,
+        })
+    }
+
+    /// Close the inputs and give ffmpeg up to `timeout` to finish its
+    /// output, e.g. the index of a file, before it is killed.
+    pub(crate) fn finish(self, timeout: Duration) {
+        // The writers see the flag and drop their connections.
+        self.stop.store(true, Ordering::Relaxed);
+        let deadline = Instant::now() + timeout;
+        while Instant::now() < deadline {
+            match lock(&self.child).try_wait() {
+                Ok(None) => std::thread::sleep(Duration::from_millis(50)),
+                _ => break,
+            }
+        }
+    }
+}
+
+impl Drop for Session {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        // Killing ffmpeg closes the inputs, which ends the writers.
+        let _ = lock(&self.child).kill();
+        for thread in self.threads.drain(..) {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// A running restream. Started with `RoomManager::start_restream`, which
+/// switches the video to the active speaker.
+pub struct Restreamer {
+    url: String,
+    options: Mutex<RestreamOptions>,
+    media: Media,
+    playout: Arc<AudioPlayoutBuffer>,
+    tap: TapId,
+    session: Mutex<Option<Session>>,
+    status: Arc<StatusCell>,
+}
+
+impl Restreamer {
+    /// Start ffmpeg streaming what `playout` plays to `url`, with black
+    /// video until `set_video_track` is called.
+    pub fn start(
+        url: &str,
+        options: RestreamOptions,
+        playout: Arc<AudioPlayoutBuffer>,
+        emitter: EventEmitter,
+    ) -> Result<Self, VisioError> {
+        if options.width == 0 || options.height == 0 || options.video_bitrate_kbps == 0 {
+            return Err(VisioError::Room("invalid restream options".into()));
+        }
+        let media = Media::new(&options);
+        let status = Arc::new(StatusCell {
+            status: Mutex::new(RestreamStatus {
+                state: RestreamState::Connecting,
+                video_bitrate_kbps: options.video_bitrate_kbps,
+                error: None,
+            }),
+            emitter,
+        });
+        let session = Self::start_session(url, &options, &media, &status)?;
+        status.set(RestreamState::Connecting, None);
+        let tap = media.tap(&playout);
+        tracing::info!("restream started to {url}");
+
+        Ok(Self {
+            url: url.to_string(),
+            options: Mutex::new(options),
+            media,
+            playout,
+            tap,
+            session: Mutex::new(Some(session)),
+            status,
+        })
+    }
+
+    fn start_session(
+        url: &str,
+        options: &RestreamOptions,
+        media: &Media,
+        status: &Arc<StatusCell>,
+    ) -> Result<Session, VisioError> {
+        let status = status.clone();
+        let on_state: StateCallback = Arc::new(move |state, error| {
+            if let Some(error) = &error {
+                tracing::warn!("restream failed: {error}");
+            }
+            status.set(state, error);
+        });
+        let args = |video_port, audio_port| ffmpeg_args(url, options, video_port, audio_port);
+        Session::start(options, media, &args, on_state)
+    }
+
+    pub fn status(&self) -> RestreamStatus {
+        lock(&self.status.status).clone()
+    }
+
+    /// SID of the restreamed video track, if any.
+    pub fn video_track_sid(&self) -> Option<String> {
+        self.media.video.track_sid()
+    }
+
+    /// Restream `track` (with its SID), or black video with `None`.
+    pub fn set_video_track(&self, track: Option<(String, RemoteVideoTrack)>) {
+        self.media.video.set_track(track);
+    }
+
+    /// The restreamed video, for `RoomManager` to follow the active
+    /// speaker.
+    pub(crate) fn speaker_video(&self) -> &Arc<SpeakerVideo> {
+        &self.media.video
+    }
+
+    /// Restart ffmpeg with a new video bitrate.
+    pub fn set_video_bitrate(&self, kbps: u32) -> Result<(), VisioError> {
+        if kbps == 0 {
+            return Err(VisioError::Room("invalid restream bitrate".into()));
+        }
+        let options = {
+            let mut options = lock(&self.options);
+            options.video_bitrate_kbps = kbps;
+            options.clone()
+        };
+        let mut session = lock(&self.session);
+        // Stop the old process first: the endpoint takes one publisher.
+        session.take();
+        lock(&self.status.status).video_bitrate_kbps = kbps;
+        self.status.set(RestreamState::Connecting, None);
+        match Self::start_session(&self.url, &options, &self.media, &self.status) {
+            Ok(started) => {
+                *session = Some(started);
+                Ok(())
+            }
+            Err(e) => {
+                self.status.set(RestreamState::Failed, Some(e.to_string()));
+                Err(e)
+            }
+        }
+    }
+}
+
+impl Drop for Restreamer {
+    fn drop(&mut self) {
+        self.playout.remove_tap(self.tap);
+        self.media.video.set_track(None);
+        lock(&self.session).take();
+        self.status.set(RestreamState::Stopped, None);
+        tracing::info!("restream to {} stopped", self.url);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::ConnectionQuality;
+    use crate::presence::Presence;
+
+    fn participant(sid: &str, video: Option<&str>) -> ParticipantInfo {
+        ParticipantInfo {
+            sid: sid.into(),
+            identity: sid.into(),
+            name: None,
+            is_muted: false,
+            has_video: video.is_some(),
+            video_track_sid: video.map(str::to_string),
+            connection_quality: ConnectionQuality::Good,
+            presence: Presence::Available,
+        }
+    }
+
+    #[test]
+    fn restreams_the_dominant_speaker_or_first_remote_video() {
+        let participants = [
+            participant("local", Some("local-camera")),
+            participant("alice", Some("TR_alice")),
+            participant("bob", None),
+            participant("carol", Some("TR_carol")),
+        ];
+        let pick = |dominant| restream_track_sid(&participants, dominant, Some("local"));
+
+        assert_eq!(pick(Some("carol")).as_deref(), Some("TR_carol"));
+        // Speakers without video, or the local user, fall back.
+        assert_eq!(pick(Some("bob")).as_deref(), Some("TR_alice"));
+        assert_eq!(pick(Some("local")).as_deref(), Some("TR_alice"));
+        assert_eq!(pick(None).as_deref(), Some("TR_alice"));
+        assert_eq!(
+            restream_track_sid(&participants[..1], None, Some("local")),
+            None
+        );
+    }
+
+    #[test]
+    fn ffmpeg_output_follows_the_url_scheme() {
+        let options = RestreamOptions::default();
+        let args = ffmpeg_args("rtmp://live.example.com/app/key", &options, 4000, 4001).unwrap();
+        assert!(args.ends_with(&[
+            "-f".into(),
+            "flv".into(),
+            "rtmp://live.example.com/app/key".into()
+        ]));
+        assert!(args.windows(2).any(|w| w == ["-i", "tcp://127.0.0.1:4000"]));
+        assert!(args.windows(2).any(|w| w == ["-i", "tcp://127.0.0.1:4001"]));
+        assert!(args.windows(2).any(|w| w == ["-b:v", "2500k"]));
+        assert!(args.windows(2).any(|w| w == ["-s", "1280x720"]));
+
+        let rtsp = ffmpeg_args("RTSP://192.168.1.10/overflow", &options, 1, 2).unwrap();
+        assert!(rtsp.windows(2).any(|w| w == ["-rtsp_transport", "tcp"]));
+        assert!(ffmpeg_args("https://example.com/", &options, 1, 2).is_err());
+    }
+
+    #[test]
+    fn due_audio_is_padded_with_silence() {
+        let mut queue: VecDeque<i16> = (1..=5).collect();
+        assert_eq!(take_due(&mut queue, 3), [1, 2, 3]);
+        assert_eq!(take_due(&mut queue, 4), [4, 5, 0, 0]);
+        assert!(queue.is_empty());
+        assert!(take_due(&mut queue, 0).is_empty());
+    }
+}
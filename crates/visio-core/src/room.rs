@@ -1,24 +1,135 @@
 use futures_util::StreamExt;
+use livekit::DisconnectReason;
 use livekit::data_stream::StreamReader;
+use livekit::options::TrackPublishOptions;
 use livekit::participant::ConnectionQuality as LkConnectionQuality;
-use livekit::prelude::{DataPacket, RemoteParticipant, Room, RoomEvent, RoomOptions};
+use livekit::prelude::{
+    DataPacket, LocalAudioTrack, LocalTrack, LocalVideoTrack, Participant, RemoteParticipant, Room,
+    RoomEvent, RoomOptions,
+};
 use livekit::track::{RemoteVideoTrack, TrackKind as LkTrackKind, TrackSource as LkTrackSource};
 use livekit::webrtc::audio_stream::native::NativeAudioStream;
 use std::collections::HashMap;
-use std::sync::Arc;
-use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
-use tokio::sync::Mutex;
-
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Weak};
+use std::time::{Duration, Instant};
+use tokio::sync::{Mutex, mpsc, oneshot};
+
+use crate::actions::MeetingAction;
+use crate::attributes::AttributesService;
+use crate::audio_ducking::audio_ducker;
 use crate::audio_playout::AudioPlayoutBuffer;
-use crate::auth::AuthService;
-use crate::chat::MessageStore;
+use crate::auth::{AuthService, EntryStatus, RoomAccess, TokenInfo};
+use crate::auto_gain::auto_gain;
+use crate::call_summary::CallSummaryTracker;
+use crate::capture_sources::PublishedSources;
+use crate::chat::{DEFAULT_MAX_MESSAGE_BYTES, MessageStore};
+use crate::chat_payload::{IncomingPayload, PayloadGuard, RejectedPayloads};
+use crate::chat_unread::{ChatUnread, UnreadTracker};
+use crate::clock::{Clock, system_clock};
+use crate::controls::{
+    AudioPreset, AudioPublishOptions, CaptureConstraints, PendingMedia, PendingMediaQueue,
+    VideoCodec, VideoContentHint, track_publish_options, update_capture_constraints,
+};
+use crate::data_channel::DataChannelService;
+use crate::device_permissions::DevicePermissions;
 use crate::errors::VisioError;
 use crate::events::{
-    ChatMessage, ConnectionQuality, ConnectionState, EventEmitter, ParticipantInfo, TrackInfo,
-    TrackKind, TrackSource, VisioEvent, VisioEventListener,
+    ChatMessage, ChatMessageKind, ConnectStep, ConnectionQuality, ConnectionState, EventEmitter,
+    ParticipantInfo, TrackInfo, TrackKind, TrackSource, VisioEvent, VisioEventListener,
 };
-use crate::hand_raise::HandRaiseManager;
+use crate::features::FeatureFlags;
+use crate::feedback::FeedbackService;
+use crate::hand_raise::{AutoLowerConfig, HandRaiseManager};
+use crate::idle::{IdleConfig, IdleMonitor};
+use crate::invite::{InviteInfo, InviteService};
+use crate::join_leave::{JoinLeaveCoalescer, JoinLeaveConfig};
+use crate::join_options::JoinOptions;
+use crate::journal::EventJournal;
+use crate::layout::LayoutService;
+use crate::local_recording::{LocalRecorder, LocalRecordingOptions};
+use crate::media_buttons::{MediaButton, MediaButtonDebouncer};
+use crate::meet_api::{EntryRequest, HttpMeetApi, MeetApi};
+use crate::meeting_timer::MeetingTimer;
+use crate::metrics::{MetricsReporter, NegotiatedCodecs, codecs_in_use};
+use crate::moderation::ModerationService;
+use crate::overlay::video_overlay;
 use crate::participants::ParticipantManager;
+use crate::permissions::LocalPermissions;
+use crate::polls::{POLLS_TOPIC, PollsService};
+use crate::power::{PowerMode, PowerState, QualityReductionReason, ThermalState};
+use crate::presence::{Presence, PresenceService};
+use crate::quality_policy::{QualityPolicy, QualityPolicyConfig};
+use crate::recording::RecordingService;
+use crate::restream::{
+    RestreamOptions, RestreamStatus, Restreamer, SpeakerVideo, restream_track_sid,
+};
+use crate::rtc_config::RtcConfigOverride;
+use crate::spotlight::SpotlightService;
+use crate::stats::StatsCollector;
+use crate::system_call::{CallDirection, SystemCall, SystemCallState, SystemCallTracker};
+use crate::system_messages::SystemMessages;
+use crate::track_recovery::TrackRecovery;
+use crate::video_privacy::video_privacy;
+use crate::visibility::TrackVisibility;
+use crate::whiteboard::{WhiteboardService, WhiteboardSession};
+
+/// Pause between two lobby polls while waiting for the host.
+const ENTRY_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// How long to wait for the host to answer a lobby entry request.
+const ENTRY_WAIT_TIMEOUT: Duration = Duration::from_secs(600);
+
+/// How often a restream or a local recording with video checks whether
+/// the active speaker changed.
+const RESTREAM_FOLLOW_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Upper bounds for the network steps of `connect()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConnectTimeouts {
+    /// Meet API token request.
+    pub meet_api: Duration,
+    /// LiveKit signalling join and transport negotiation.
+    pub livekit: Duration,
+}
+
+impl Default for ConnectTimeouts {
+    fn default() -> Self {
+        Self {
+            meet_api: Duration::from_secs(10),
+            livekit: Duration::from_secs(15),
+        }
+    }
+}
+
+/// Exponential backoff of `RoomManager::reconnect()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReconnectPolicy {
+    pub max_attempts: u32,
+    /// Wait after the first failed attempt, doubled after each failure.
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 10,
+            base_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(30),
+        }
+    }
+}
+
+impl ReconnectPolicy {
+    /// Wait after failed attempt number `attempt` (from 1).
+    pub fn delay(&self, attempt: u32) -> Duration {
+        2u32.checked_pow(attempt.saturating_sub(1))
+            .and_then(|factor| self.base_delay.checked_mul(factor))
+            .unwrap_or(self.max_delay)
+            .min(self.max_delay)
+    }
+}
 
 /// Manages the lifecycle of a LiveKit room connection.
 pub struct RoomManager {
@@ -30,17 +141,139 @@ pub struct RoomManager {
     messages: MessageStore,
     playout_buffer: Arc<AudioPlayoutBuffer>,
     hand_raise: Arc<Mutex<Option<HandRaiseManager>>>,
+    hand_auto_lower: Arc<std::sync::Mutex<AutoLowerConfig>>,
+    /// Shared with every MeetingControls, so toggles read the state the
+    /// other instances set.
+    mic_enabled: Arc<Mutex<bool>>,
     /// Shared with MeetingControls so local_participant_info() reads the
     /// authoritative camera state without depending on LiveKit publication
     /// mute-state timing.
     camera_enabled: Arc<Mutex<bool>>,
     /// Stored connection info for application-level reconnection.
     last_meet_url: Arc<Mutex<Option<String>>>,
-    last_username: Arc<Mutex<Option<String>>>,
+    last_join_options: Arc<Mutex<JoinOptions>>,
     session_cookie: Arc<Mutex<Option<String>>>,
-    /// Chat unread tracking (shared with event loop).
-    chat_open: Arc<AtomicBool>,
-    unread_count: Arc<AtomicU32>,
+    /// Meet API answer for the current room (room id, dial-in PIN).
+    meet_room: Arc<Mutex<Option<TokenInfo>>>,
+    /// Chat unread tracking, fed by `ChatMessageReceived`.
+    unread: UnreadTracker,
+    /// Meet API transport used for token requests.
+    meet_api: Arc<dyn MeetApi>,
+    connect_timeouts: Arc<Mutex<ConnectTimeouts>>,
+    reconnect_policy: Arc<Mutex<ReconnectPolicy>>,
+    /// Time source of timers and call metrics (a `MockClock` in tests).
+    clock: Arc<dyn Clock>,
+    rtc_config: Arc<std::sync::Mutex<RtcConfigOverride>>,
+    join_leave: JoinLeaveCoalescer,
+    layout: LayoutService,
+    spotlight: SpotlightService,
+    track_recovery: TrackRecovery,
+    metrics: MetricsReporter,
+    stats: StatsCollector,
+    moderation: ModerationService,
+    recording: RecordingService,
+    data_channel: DataChannelService,
+    polls: PollsService,
+    presence: PresenceService,
+    attributes: AttributesService,
+    /// Camera and remote video fallback on a bad network.
+    quality_policy: QualityPolicy,
+    /// Subscription priority from the tiles on screen.
+    visibility: TrackVisibility,
+    /// Every event seen, for debugging from user reports.
+    journal: EventJournal,
+    /// Summary of the current call, emitted when it ends.
+    call_summary: CallSummaryTracker,
+    feedback: FeedbackService,
+    /// Target for platform camera capture (see `MeetingControls`).
+    capture_constraints: Arc<std::sync::Mutex<CaptureConstraints>>,
+    /// Encoding of the microphone track, kept across reconnections.
+    audio_options: Arc<std::sync::Mutex<AudioPublishOptions>>,
+    /// Preferred codec of the camera track, kept across reconnections.
+    video_codec: Arc<std::sync::Mutex<VideoCodec>>,
+    /// Content hint of the camera track, kept across room switches.
+    video_content_hint: Arc<Mutex<VideoContentHint>>,
+    /// Caps capture constraints, self-view and stats sampling.
+    power: Arc<std::sync::Mutex<PowerState>>,
+    /// Operating system permissions reported by the shell.
+    device_permissions: DevicePermissions,
+    /// Media toggled while disconnected (see `MeetingControls`).
+    pending_media: PendingMediaQueue,
+    /// Sources of the local tracks, registered for platform capture.
+    published_sources: PublishedSources,
+    /// Decodes chat and reaction data packets, counting rejected ones.
+    payload_guard: PayloadGuard,
+    /// Size limit of chat messages, shared by the chat service and the
+    /// payload guard.
+    max_chat_message_bytes: Arc<AtomicUsize>,
+    /// Whether chat message bodies are formatted, see
+    /// `ChatService::message_body`.
+    chat_formatting: Arc<AtomicBool>,
+    local_recorder: Arc<std::sync::Mutex<Option<LocalRecorder>>>,
+    restreamer: Arc<std::sync::Mutex<Option<Arc<Restreamer>>>>,
+    /// Features of the Meet instance of the current room.
+    feature_flags: Arc<Mutex<FeatureFlags>>,
+    /// Local participant permissions; `None` while disconnected.
+    local_permissions: Arc<Mutex<Option<LocalPermissions>>>,
+    /// Call reported to the OS call UI (CallKit / ConnectionService).
+    system_call: SystemCallTracker,
+    /// Meeting events posted to the chat.
+    system_messages: SystemMessages,
+    media_buttons: MediaButtonDebouncer,
+    /// Countdown to the scheduled end of the meeting, if any.
+    meeting_timer: MeetingTimer,
+    /// Alone-in-room and inactivity checks.
+    idle: IdleMonitor,
+    /// Detaches the running event loop from shared state (room switch).
+    event_loop_cancel: Arc<Mutex<Option<oneshot::Sender<()>>>>,
+}
+
+/// State of the current session, torn down by `disconnect` and by the
+/// event loop when the room drops.
+#[derive(Clone)]
+struct SessionState {
+    emitter: EventEmitter,
+    mic_enabled: Arc<Mutex<bool>>,
+    participants: Arc<Mutex<ParticipantManager>>,
+    subscribed_tracks: Arc<Mutex<HashMap<String, RemoteVideoTrack>>>,
+    messages: MessageStore,
+    unread: UnreadTracker,
+    playout_buffer: Arc<AudioPlayoutBuffer>,
+    published_sources: PublishedSources,
+    local_permissions: Arc<Mutex<Option<LocalPermissions>>>,
+    meet_room: Arc<Mutex<Option<TokenInfo>>>,
+    meeting_timer: MeetingTimer,
+    idle: IdleMonitor,
+    moderation: ModerationService,
+    recording: RecordingService,
+    polls: PollsService,
+    presence: PresenceService,
+    quality_policy: QualityPolicy,
+    visibility: TrackVisibility,
+    local_recorder: Arc<std::sync::Mutex<Option<LocalRecorder>>>,
+    restreamer: Arc<std::sync::Mutex<Option<Arc<Restreamer>>>>,
+    feature_flags: Arc<Mutex<FeatureFlags>>,
+    hand_raise: Arc<Mutex<Option<HandRaiseManager>>>,
+}
+
+/// What the room event loop works on: the session, plus the services it
+/// feeds that outlive a session.
+struct EventLoopContext {
+    session: SessionState,
+    connection_state: Arc<Mutex<ConnectionState>>,
+    room_ref: Arc<Mutex<Option<Arc<Room>>>>,
+    last_meet_url: Arc<Mutex<Option<String>>>,
+    join_leave: JoinLeaveCoalescer,
+    capture_constraints: Arc<std::sync::Mutex<CaptureConstraints>>,
+    power: Arc<std::sync::Mutex<PowerState>>,
+    data_channel: DataChannelService,
+    attributes: AttributesService,
+    journal: EventJournal,
+    call_summary: CallSummaryTracker,
+    system_call: SystemCallTracker,
+    system_messages: SystemMessages,
+    payload_guard: PayloadGuard,
+    clock: Arc<dyn Clock>,
 }
 
 impl Default for RoomManager {
@@ -51,22 +284,140 @@ impl Default for RoomManager {
 
 impl RoomManager {
     pub fn new() -> Self {
-        Self {
-            room: Arc::new(Mutex::new(None)),
-            emitter: EventEmitter::new(),
-            participants: Arc::new(Mutex::new(ParticipantManager::new())),
+        Self::with_meet_api(Arc::new(HttpMeetApi::default()))
+    }
+
+    /// Create a RoomManager that requests tokens through `meet_api`
+    /// (e.g. a `MockMeetApi` in tests).
+    pub fn with_meet_api(meet_api: Arc<dyn MeetApi>) -> Self {
+        Self::with_clock(meet_api, system_clock())
+    }
+
+    /// Like `with_meet_api`, with timers driven by `clock` (e.g. a
+    /// `MockClock` in tests).
+    pub fn with_clock(meet_api: Arc<dyn MeetApi>, clock: Arc<dyn Clock>) -> Self {
+        let emitter = EventEmitter::new();
+        let layout = LayoutService::new(emitter.clone());
+        emitter.add_listener(Arc::new(layout.clone()));
+        let spotlight = SpotlightService::new(emitter.clone(), layout.clone());
+        emitter.add_listener(Arc::new(spotlight.clone()));
+        let room = Arc::new(Mutex::new(None));
+        let subscribed_tracks = Arc::new(Mutex::new(HashMap::new()));
+        let track_recovery =
+            TrackRecovery::new(room.clone(), emitter.clone(), subscribed_tracks.clone());
+        let metrics = MetricsReporter::new(room.clone(), clock.clone());
+        emitter.add_listener(Arc::new(metrics.clone()));
+        let stats = StatsCollector::new(room.clone(), clock.clone());
+        emitter.add_listener(Arc::new(stats.clone()));
+        let system_call = SystemCallTracker::new(emitter.clone());
+        emitter.add_listener(Arc::new(system_call.clone()));
+        let messages = MessageStore::default();
+        let system_messages = SystemMessages::new(messages.clone(), emitter.clone(), clock.clone());
+        emitter.add_listener(Arc::new(system_messages.clone()));
+        let unread = UnreadTracker::new(emitter.clone());
+        emitter.add_listener(Arc::new(unread.clone()));
+        let participants = Arc::new(Mutex::new(ParticipantManager::new()));
+        let idle = IdleMonitor::new(emitter.clone(), participants.clone());
+        emitter.add_listener(Arc::new(idle.clone()));
+        let last_meet_url = Arc::new(Mutex::new(None));
+        let session_cookie = Arc::new(Mutex::new(None));
+        let meet_room = Arc::new(Mutex::new(None));
+        let moderation = ModerationService::new(
+            meet_api.clone(),
+            emitter.clone(),
+            last_meet_url.clone(),
+            session_cookie.clone(),
+            meet_room.clone(),
+        );
+        let local_permissions = Arc::new(Mutex::new(None));
+        let recording = RecordingService::new(
+            meet_api.clone(),
+            emitter.clone(),
+            last_meet_url.clone(),
+            session_cookie.clone(),
+            meet_room.clone(),
+            local_permissions.clone(),
+        );
+        let data_channel = DataChannelService::new(room.clone(), emitter.clone());
+        let polls = PollsService::new(room.clone(), data_channel.clone(), emitter.clone());
+        let presence = PresenceService::new(room.clone(), emitter.clone(), participants.clone());
+        let attributes = AttributesService::new(room.clone(), emitter.clone());
+        let quality_policy = QualityPolicy::new(room.clone(), emitter.clone(), clock.clone());
+        let visibility = TrackVisibility::new(room.clone());
+        let journal = EventJournal::new(clock.clone());
+        emitter.add_listener(Arc::new(journal.clone()));
+        let call_summary = CallSummaryTracker::new(emitter.clone(), clock.clone());
+        emitter.add_listener(Arc::new(call_summary.clone()));
+        let feedback = FeedbackService::new(
+            meet_api.clone(),
+            session_cookie.clone(),
+            journal.clone(),
+            call_summary.clone(),
+        );
+        let max_chat_message_bytes = Arc::new(AtomicUsize::new(DEFAULT_MAX_MESSAGE_BYTES));
+        let manager = Self {
+            room,
+            join_leave: JoinLeaveCoalescer::new(emitter.clone()),
+            layout,
+            spotlight,
+            track_recovery,
+            metrics,
+            stats,
+            moderation,
+            recording,
+            data_channel,
+            polls,
+            presence,
+            attributes,
+            quality_policy,
+            visibility,
+            journal,
+            call_summary,
+            feedback,
+            capture_constraints: Arc::new(std::sync::Mutex::new(CaptureConstraints::default())),
+            audio_options: Arc::new(std::sync::Mutex::new(AudioPublishOptions::default())),
+            video_codec: Arc::new(std::sync::Mutex::new(VideoCodec::default())),
+            video_content_hint: Arc::new(Mutex::new(VideoContentHint::default())),
+            power: Arc::new(std::sync::Mutex::new(PowerState::default())),
+            device_permissions: DevicePermissions::new(emitter.clone()),
+            pending_media: PendingMediaQueue::default(),
+            published_sources: PublishedSources::default(),
+            payload_guard: PayloadGuard::new(max_chat_message_bytes.clone()),
+            max_chat_message_bytes,
+            chat_formatting: Arc::new(AtomicBool::new(true)),
+            meeting_timer: MeetingTimer::new(emitter.clone()),
+            local_recorder: Arc::new(std::sync::Mutex::new(None)),
+            restreamer: Arc::new(std::sync::Mutex::new(None)),
+            emitter,
+            participants,
             connection_state: Arc::new(Mutex::new(ConnectionState::Disconnected)),
-            subscribed_tracks: Arc::new(Mutex::new(HashMap::new())),
-            messages: Arc::new(Mutex::new(Vec::new())),
+            subscribed_tracks,
+            messages,
             playout_buffer: Arc::new(AudioPlayoutBuffer::new()),
             hand_raise: Arc::new(Mutex::new(None)),
+            hand_auto_lower: Arc::new(std::sync::Mutex::new(AutoLowerConfig::default())),
+            mic_enabled: Arc::new(Mutex::new(false)),
             camera_enabled: Arc::new(Mutex::new(false)),
-            last_meet_url: Arc::new(Mutex::new(None)),
-            last_username: Arc::new(Mutex::new(None)),
-            session_cookie: Arc::new(Mutex::new(None)),
-            chat_open: Arc::new(AtomicBool::new(false)),
-            unread_count: Arc::new(AtomicU32::new(0)),
-        }
+            last_meet_url,
+            last_join_options: Arc::new(Mutex::new(JoinOptions::default())),
+            session_cookie,
+            meet_room,
+            unread,
+            meet_api,
+            connect_timeouts: Arc::new(Mutex::new(ConnectTimeouts::default())),
+            reconnect_policy: Arc::new(Mutex::new(ReconnectPolicy::default())),
+            clock,
+            rtc_config: Arc::new(std::sync::Mutex::new(RtcConfigOverride::default())),
+            feature_flags: Arc::new(Mutex::new(FeatureFlags::default())),
+            local_permissions,
+            system_call,
+            system_messages,
+            media_buttons: MediaButtonDebouncer::default(),
+            idle,
+            event_loop_cancel: Arc::new(Mutex::new(None)),
+        };
+        manager.quality_policy.set_controls(manager.controls());
+        manager
     }
 
     /// Get a reference to the audio playout buffer.
@@ -77,6 +428,25 @@ impl RoomManager {
         self.playout_buffer.clone()
     }
 
+    /// Volume of all remote audio, from 0.0 to 1.0, applied before the
+    /// samples reach the platform output.
+    pub fn set_output_volume(&self, volume: f32) {
+        self.playout_buffer.set_volume(volume);
+    }
+
+    pub fn output_volume(&self) -> f32 {
+        self.playout_buffer.volume()
+    }
+
+    /// Silence all remote audio ("speaker off"), keeping the volume.
+    pub fn set_output_muted(&self, muted: bool) {
+        self.playout_buffer.set_muted(muted);
+    }
+
+    pub fn is_output_muted(&self) -> bool {
+        self.playout_buffer.is_muted()
+    }
+
     /// Register a listener for room events.
     pub fn add_listener(&self, listener: Arc<dyn VisioEventListener>) {
         self.emitter.add_listener(listener);
@@ -87,32 +457,304 @@ impl RoomManager {
         crate::controls::MeetingControls::new(
             self.room.clone(),
             self.emitter.clone(),
+            self.mic_enabled.clone(),
             self.camera_enabled.clone(),
+            self.capture_constraints.clone(),
+            self.audio_options.clone(),
+            self.video_codec.clone(),
+            self.video_content_hint.clone(),
+            self.device_permissions.clone(),
+            self.local_permissions.clone(),
+            self.pending_media.clone(),
+            self.published_sources.clone(),
         )
     }
 
+    /// Sources of the local tracks published in this room, for capture
+    /// backends that push frames themselves.
+    pub fn published_sources(&self) -> PublishedSources {
+        self.published_sources.clone()
+    }
+
+    /// Chat and reaction packets dropped as oversized or malformed.
+    pub fn rejected_payloads(&self) -> RejectedPayloads {
+        self.payload_guard.rejections()
+    }
+
+    /// Limit on the UTF-8 size of chat messages, sent or received.
+    pub fn max_chat_message_bytes(&self) -> usize {
+        self.max_chat_message_bytes.load(Ordering::Relaxed)
+    }
+
+    /// Change the chat message size limit (`DEFAULT_MAX_MESSAGE_BYTES` by
+    /// default). Longer messages fail to send with `MessageTooLarge` and
+    /// are dropped on receipt.
+    pub fn set_max_chat_message_bytes(&self, max: usize) {
+        self.max_chat_message_bytes.store(max, Ordering::Relaxed);
+    }
+
+    /// Whether `ChatService::message_body` formats messages (the default)
+    /// or returns their text as typed.
+    pub fn chat_formatting(&self) -> bool {
+        self.chat_formatting.load(Ordering::Relaxed)
+    }
+
+    pub fn set_chat_formatting(&self, enabled: bool) {
+        self.chat_formatting.store(enabled, Ordering::Relaxed);
+    }
+
     /// Create a ChatService bound to this room.
     pub fn chat(&self) -> crate::chat::ChatService {
         crate::chat::ChatService::new(
             self.room.clone(),
             self.emitter.clone(),
             self.messages.clone(),
+            self.unread.clone(),
+            self.max_chat_message_bytes.clone(),
+            self.chat_formatting.clone(),
         )
     }
 
+    /// Shared participant ordering service for this room.
+    pub fn layout(&self) -> LayoutService {
+        self.layout.clone()
+    }
+
+    /// Shared speaker view spotlight for this room.
+    pub fn spotlight(&self) -> SpotlightService {
+        self.spotlight.clone()
+    }
+
+    /// Meeting events posted to the chat of this room.
+    pub fn system_messages(&self) -> SystemMessages {
+        self.system_messages.clone()
+    }
+
+    /// Opt-in call metrics for this room (disabled until a sink is set).
+    pub fn metrics(&self) -> MetricsReporter {
+        self.metrics.clone()
+    }
+
+    pub fn call_summary(&self) -> CallSummaryTracker {
+        self.call_summary.clone()
+    }
+
+    pub fn feedback(&self) -> FeedbackService {
+        self.feedback.clone()
+    }
+
+    /// Quality history of the published and subscribed tracks.
+    pub fn stats(&self) -> StatsCollector {
+        self.stats.clone()
+    }
+
+    /// Moderator actions (room lock) on the current room.
+    pub fn moderation(&self) -> ModerationService {
+        self.moderation.clone()
+    }
+
+    /// Cloud recording of the current room.
+    pub fn recording(&self) -> RecordingService {
+        self.recording.clone()
+    }
+
+    /// Custom application messages on the current room.
+    pub fn data_channel(&self) -> DataChannelService {
+        self.data_channel.clone()
+    }
+
+    /// Polls of the current room.
+    pub fn polls(&self) -> PollsService {
+        self.polls.clone()
+    }
+
+    /// Presence of the local participant and of remote ones.
+    pub fn presence(&self) -> PresenceService {
+        self.presence.clone()
+    }
+
+    /// App-level participant attributes of the current room.
+    pub fn attributes(&self) -> AttributesService {
+        self.attributes.clone()
+    }
+
+    /// Record what the local user hears to a file at `path`, in
+    /// `options.format` (see `LocalRecorder`). MP4 and WebM recordings
+    /// also contain the active speaker's video.
+    ///
+    /// Fails with `VisioError::Room` if a local recording is already
+    /// running, the file cannot be created or ffmpeg cannot be started.
+    pub fn start_local_recording(
+        &self,
+        path: &str,
+        options: LocalRecordingOptions,
+    ) -> Result<(), VisioError> {
+        let mut recorder = self
+            .local_recorder
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        if recorder.is_some() {
+            return Err(VisioError::Room("local recording already running".into()));
+        }
+        let started = LocalRecorder::start(
+            path,
+            options,
+            self.playout_buffer.clone(),
+            self.emitter.clone(),
+        )?;
+        if let Some(video) = started.speaker_video() {
+            self.follow_active_speaker(Arc::downgrade(video));
+        }
+        *recorder = Some(started);
+        Ok(())
+    }
+
+    /// Stop the local recording and return the file size in bytes.
+    pub fn stop_local_recording(&self) -> Result<u64, VisioError> {
+        let recorder = self
+            .local_recorder
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .take()
+            .ok_or_else(|| VisioError::Room("no local recording running".into()))?;
+        recorder.stop()
+    }
+
+    /// Whether a local recording is running.
+    pub fn is_local_recording(&self) -> bool {
+        self.local_recorder
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .is_some()
+    }
+
+    /// Restream the room audio and the active speaker's video to `url`
+    /// through ffmpeg (see `Restreamer`). Status changes follow as
+    /// `RestreamStatusChanged` events.
+    ///
+    /// Fails if a restream is already running, the URL scheme is not
+    /// supported or ffmpeg cannot be started.
+    pub fn start_restream(&self, url: &str, options: RestreamOptions) -> Result<(), VisioError> {
+        let mut slot = self.restreamer.lock().unwrap_or_else(|e| e.into_inner());
+        if slot.is_some() {
+            return Err(VisioError::Room("restream already running".into()));
+        }
+        let restreamer = Arc::new(Restreamer::start(
+            url,
+            options,
+            self.playout_buffer.clone(),
+            self.emitter.clone(),
+        )?);
+
+        self.follow_active_speaker(Arc::downgrade(restreamer.speaker_video()));
+        *slot = Some(restreamer);
+        Ok(())
+    }
+
+    /// Switch `video` to the active speaker's camera until it is dropped.
+    fn follow_active_speaker(&self, video: Weak<SpeakerVideo>) {
+        let participants = self.participants.clone();
+        let subscribed_tracks = self.subscribed_tracks.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(RESTREAM_FOLLOW_INTERVAL);
+            loop {
+                interval.tick().await;
+                let Some(video) = video.upgrade() else {
+                    break;
+                };
+                let sid = {
+                    let participants = participants.lock().await;
+                    restream_track_sid(
+                        participants.participants(),
+                        participants.dominant_speaker(),
+                        participants.local_sid(),
+                    )
+                };
+                if sid == video.track_sid() {
+                    continue;
+                }
+                let track = match sid {
+                    Some(sid) => {
+                        let track = subscribed_tracks.lock().await.get(&sid).cloned();
+                        track.map(|track| (sid, track))
+                    }
+                    None => None,
+                };
+                video.set_track(track);
+            }
+        });
+    }
+
+    /// Stop the restream.
+    pub fn stop_restream(&self) -> Result<(), VisioError> {
+        let restreamer = self
+            .restreamer
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .take();
+        match restreamer {
+            Some(_) => Ok(()),
+            None => Err(VisioError::Room("no restream running".into())),
+        }
+    }
+
+    /// Change the video bitrate of the running restream. ffmpeg restarts,
+    /// so the endpoint briefly loses the stream.
+    pub fn set_restream_bitrate(&self, video_bitrate_kbps: u32) -> Result<(), VisioError> {
+        let restreamer = self
+            .restreamer
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+            .ok_or_else(|| VisioError::Room("no restream running".into()))?;
+        restreamer.set_video_bitrate(video_bitrate_kbps)
+    }
+
+    pub fn restream_status(&self) -> RestreamStatus {
+        self.restreamer
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .as_ref()
+            .map(|restreamer| restreamer.status())
+            .unwrap_or_default()
+    }
+
+    /// Handler for stalled video tracks, fed by the video renderers.
+    pub fn track_recovery(&self) -> TrackRecovery {
+        self.track_recovery.clone()
+    }
+
     /// Mark the chat panel as open or closed.
-    /// When opened, resets the unread count to zero.
+    /// When opened, resets the unread counts to zero.
     pub fn set_chat_open(&self, open: bool) {
-        self.chat_open.store(open, Ordering::Relaxed);
-        if open {
-            self.unread_count.store(0, Ordering::Relaxed);
-            self.emitter.emit(VisioEvent::UnreadCountChanged(0));
-        }
+        self.unread.set_chat_open(open);
+    }
+
+    /// Whether the chat panel is marked open.
+    pub fn is_chat_open(&self) -> bool {
+        self.unread.is_chat_open()
     }
 
-    /// Get the current unread message count.
+    /// Get the current unread message count, mentions included.
     pub fn unread_count(&self) -> u32 {
-        self.unread_count.load(Ordering::Relaxed)
+        self.unread.unread().total()
+    }
+
+    /// Unread messages by kind, and the first unread message.
+    pub fn chat_unread(&self) -> ChatUnread {
+        self.unread.unread()
+    }
+
+    /// Acknowledge the chat messages up to `id`, included. Returns `false`
+    /// if `id` is not unread.
+    pub fn mark_chat_read_up_to(&self, id: &str) -> bool {
+        self.unread.mark_read_up_to(id)
+    }
+
+    /// What the local participant may do in the current room, or `None`
+    /// when not connected.
+    pub async fn local_permissions(&self) -> Option<LocalPermissions> {
+        self.local_permissions.lock().await.clone()
     }
 
     /// Get current connection state.
@@ -143,6 +785,7 @@ impl RoomManager {
         // publication mute state, which may lag behind the actual user intent
         // (pub_.mute() is async and needs server ACK before is_muted() updates).
         let has_video = *self.camera_enabled.lock().await;
+        let self_view = self.power_mode().self_view_enabled();
         let is_muted = local
             .track_publications()
             .values()
@@ -157,20 +800,42 @@ impl RoomManager {
             name,
             is_muted,
             has_video,
-            video_track_sid: if has_video {
+            video_track_sid: if has_video && self_view {
                 Some("local-camera".to_string())
             } else {
                 None
             },
             connection_quality: ConnectionQuality::Excellent,
+            presence: self.presence.presence(),
         })
     }
 
-    /// Get current active speakers.
+    /// Get current active speakers, smoothed by the speaker hold time.
     pub async fn active_speakers(&self) -> Vec<String> {
         self.participants.lock().await.active_speakers().to_vec()
     }
 
+    /// Participant to show in a spotlight tile. Only changes once the
+    /// current dominant speaker has been silent for the hold time, and
+    /// always alongside an `ActiveSpeakersChanged` event.
+    pub async fn dominant_speaker(&self) -> Option<String> {
+        self.participants
+            .lock()
+            .await
+            .dominant_speaker()
+            .map(str::to_string)
+    }
+
+    /// How long a speaker stays active after they stop talking.
+    /// `Duration::ZERO` reports LiveKit's speaker updates unsmoothed.
+    pub async fn set_active_speaker_hold(&self, hold: Duration) {
+        self.participants.lock().await.set_speaker_hold(hold);
+    }
+
+    pub async fn active_speaker_hold(&self) -> Duration {
+        self.participants.lock().await.speaker_hold()
+    }
+
     /// Get a subscribed remote video track by its SID.
     ///
     /// Returns `None` if the track is not currently subscribed.
@@ -188,27 +853,244 @@ impl RoomManager {
             .collect()
     }
 
+    /// Codecs negotiated for the published and subscribed tracks, read
+    /// from the WebRTC stats: shows whether a preferred `VideoCodec` was
+    /// actually used.
+    pub async fn negotiated_codecs(&self) -> Result<NegotiatedCodecs, VisioError> {
+        let room = self
+            .room
+            .lock()
+            .await
+            .clone()
+            .ok_or_else(|| VisioError::Room("not connected".into()))?;
+        let stats = room
+            .get_stats()
+            .await
+            .map_err(|e| VisioError::Room(format!("get stats: {e}")))?;
+        Ok(NegotiatedCodecs {
+            sent: codecs_in_use(&stats.publisher_stats),
+            received: codecs_in_use(&stats.subscriber_stats),
+        })
+    }
+
     /// Set a session cookie for authenticated Meet instances.
     pub async fn set_session_cookie(&self, cookie: Option<String>) {
         *self.session_cookie.lock().await = cookie;
     }
 
+    /// Set the timeouts applied by subsequent connection attempts.
+    pub async fn set_connect_timeouts(&self, timeouts: ConnectTimeouts) {
+        *self.connect_timeouts.lock().await = timeouts;
+    }
+
+    /// Set the backoff used by subsequent `reconnect()` calls.
+    pub async fn set_reconnect_policy(&self, policy: ReconnectPolicy) {
+        *self.reconnect_policy.lock().await = policy;
+    }
+
+    /// Configure automatic lowering of the local hand while speaking.
+    /// Takes effect immediately, including for an ongoing call.
+    pub fn set_hand_auto_lower(&self, config: AutoLowerConfig) {
+        *self
+            .hand_auto_lower
+            .lock()
+            .unwrap_or_else(|e| e.into_inner()) = config;
+    }
+
+    pub fn hand_auto_lower(&self) -> AutoLowerConfig {
+        *self
+            .hand_auto_lower
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Configure alone-in-room and inactivity detection. Takes effect at
+    /// the next check, including for an ongoing call.
+    pub fn set_idle_config(&self, config: IdleConfig) {
+        self.idle.set_config(config);
+    }
+
+    pub fn idle_config(&self) -> IdleConfig {
+        self.idle.config()
+    }
+
+    /// Configure the camera and remote video fallback on a bad network.
+    /// Takes effect at the next quality change.
+    pub fn set_quality_policy_config(&self, config: QualityPolicyConfig) {
+        self.quality_policy.set_config(config);
+    }
+
+    pub fn quality_policy(&self) -> QualityPolicy {
+        self.quality_policy.clone()
+    }
+
+    pub fn visibility(&self) -> TrackVisibility {
+        self.visibility.clone()
+    }
+
+    pub fn journal(&self) -> EventJournal {
+        self.journal.clone()
+    }
+
+    /// The user is still there (answered `InactivityDetected`): restart
+    /// the inactivity countdown.
+    pub fn report_activity(&self) {
+        self.idle.activity();
+    }
+
+    /// Configure how participant join/leave events are delivered.
+    pub fn set_join_leave_config(&self, config: JoinLeaveConfig) {
+        self.join_leave.set_config(config);
+    }
+
+    pub fn join_leave_config(&self) -> JoinLeaveConfig {
+        self.join_leave.config()
+    }
+
+    /// Switch between normal and battery saver operation (see `PowerMode`).
+    /// Takes effect immediately: capture constraints are recomputed and
+    /// `PowerModeChanged` is emitted. Renderers are process-wide and left
+    /// to the caller.
+    pub async fn set_power_mode(&self, mode: PowerMode) {
+        if self.update_power(|p| p.mode = mode).await.is_none() {
+            return;
+        }
+        tracing::info!("power mode: {mode:?}");
+        self.metrics.set_sample_interval(mode.stats_interval());
+        self.emitter.emit(VisioEvent::PowerModeChanged(mode));
+    }
+
+    pub fn power_mode(&self) -> PowerMode {
+        self.power_state().mode
+    }
+
+    /// Thermal state of the device, forwarded by the shell from platform
+    /// APIs. Capture steps down as the device heats up (see
+    /// `ThermalState`); `QualityReduced` explains each step down and
+    /// `QualityRestored` is emitted back at `Nominal`. Renderers are
+    /// process-wide and left to the caller.
+    pub async fn report_thermal_state(&self, thermal: ThermalState) {
+        let Some(previous) = self.update_power(|p| p.thermal = thermal).await else {
+            return;
+        };
+        tracing::info!("thermal state: {:?} -> {thermal:?}", previous.thermal);
+        if thermal == ThermalState::Nominal {
+            self.emitter.emit(VisioEvent::QualityRestored);
+        } else {
+            self.emitter.emit(VisioEvent::QualityReduced {
+                reason: QualityReductionReason::Thermal(thermal),
+                constraints: self.controls().capture_constraints(),
+            });
+        }
+    }
+
+    /// Power mode and thermal state currently applied.
+    pub fn power_state(&self) -> PowerState {
+        *self.power.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Apply `update` to the power state and, if it changed, recompute the
+    /// capture constraints. Returns the previous state on change.
+    async fn update_power(&self, update: impl FnOnce(&mut PowerState)) -> Option<PowerState> {
+        let (previous, power) = {
+            let mut power = self.power.lock().unwrap_or_else(|e| e.into_inner());
+            let previous = *power;
+            update(&mut power);
+            (previous, *power)
+        };
+        if previous == power {
+            return None;
+        }
+        let quality = match self.room.lock().await.as_ref() {
+            Some(room) => connection_quality(room.local_participant().connection_quality()),
+            None => ConnectionQuality::Excellent,
+        };
+        update_capture_constraints(&self.capture_constraints, &self.emitter, |c| {
+            power.limit_capture(CaptureConstraints::for_quality(&quality, c.low_light_boost))
+        });
+        Some(previous)
+    }
+
+    /// Enable `media` right after the next connection succeeds
+    /// (`connect` does it for `JoinOptions::mic_on` / `camera_on` once
+    /// joined). Toggles
+    /// queued with `MeetingControls::set_queue_until_connected` win. Media
+    /// the user denied is skipped (`PermissionDenied` is logged).
+    pub fn request_media_on_connect(&self, media: PendingMedia) {
+        self.pending_media.request(media);
+    }
+
+    /// Operating system permissions, reported by the shell as they become
+    /// known (see `DevicePermissions`).
+    pub fn device_permissions(&self) -> DevicePermissions {
+        self.device_permissions.clone()
+    }
+
+    /// Set the microphone preset of the next publication, e.g. from the
+    /// settings. `MeetingControls::set_audio_preset` also changes a
+    /// published track.
+    pub fn set_audio_preset(&self, preset: AudioPreset) {
+        *self.audio_options.lock().unwrap_or_else(|e| e.into_inner()) = preset.publish_options();
+    }
+
+    /// Set the ICE servers / relay policy used by subsequent connections.
+    pub fn set_rtc_config(&self, config: RtcConfigOverride) {
+        *self.rtc_config.lock().unwrap_or_else(|e| e.into_inner()) = config;
+    }
+
+    pub fn rtc_config(&self) -> RtcConfigOverride {
+        self.rtc_config
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    /// Get the current connection timeouts.
+    pub async fn connect_timeouts(&self) -> ConnectTimeouts {
+        *self.connect_timeouts.lock().await
+    }
+
     /// Connect to a room using the Meet API.
     ///
-    /// Calls the Meet API to get a token, then connects to the LiveKit room.
-    pub async fn connect(&self, meet_url: &str, username: Option<&str>) -> Result<(), VisioError> {
+    /// Calls the Meet API to get a token, then connects to the LiveKit room
+    /// as described by `options` (see `JoinOptions`).
+    pub async fn connect(&self, meet_url: &str, options: &JoinOptions) -> Result<(), VisioError> {
         // Store connection info for potential reconnection
         *self.last_meet_url.lock().await = Some(meet_url.to_string());
-        *self.last_username.lock().await = username.map(|s| s.to_string());
+        *self.last_join_options.lock().await = options.clone();
+        self.feedback.remember_call(meet_url);
+        let username = options.username.as_deref();
 
         self.set_connection_state(ConnectionState::Connecting).await;
 
-        let cookie = self.session_cookie.lock().await;
-        let token_info =
-            AuthService::request_token(meet_url, username, cookie.as_deref()).await?;
+        let token_info = match self.fetch_token(meet_url, username).await {
+            Ok(info) => info,
+            Err(e) => {
+                let e = match AuthService::parse_meet_url(meet_url) {
+                    Ok((instance, _)) => {
+                        self.diagnose_failure(&format!("https://{instance}/"), e)
+                            .await
+                    }
+                    Err(_) => e,
+                };
+                self.set_connection_state(ConnectionState::Disconnected)
+                    .await;
+                return Err(e);
+            }
+        };
 
-        self.connect_with_token(&token_info.livekit_url, &token_info.token)
-            .await
+        self.join(
+            &token_info.livekit_url,
+            &token_info.token,
+            token_info.access_level.clone(),
+            options,
+        )
+        .await?;
+        self.meeting_timer.start(&token_info);
+        *self.meet_room.lock().await = Some(token_info);
+        self.moderation.reset().await;
+        self.refresh_feature_flags(meet_url);
+        Ok(())
     }
 
     /// Connect directly with a LiveKit URL and token (useful for testing).
@@ -216,25 +1098,275 @@ impl RoomManager {
         &self,
         livekit_url: &str,
         token: &str,
+    ) -> Result<(), VisioError> {
+        self.join(livekit_url, token, None, &JoinOptions::default())
+            .await
+    }
+
+    async fn join(
+        &self,
+        livekit_url: &str,
+        token: &str,
+        access_level: Option<String>,
+        options: &JoinOptions,
     ) -> Result<(), VisioError> {
         self.set_connection_state(ConnectionState::Connecting).await;
 
+        let (room, events) = match self.open_room(livekit_url, token, options).await {
+            Ok(opened) => opened,
+            Err(e) => {
+                let e = self.diagnose_failure(livekit_url, e).await;
+                self.set_connection_state(ConnectionState::Disconnected)
+                    .await;
+                return Err(e);
+            }
+        };
+        if let Some(name) = options.participant_name()
+            && let Err(e) = room.local_participant().set_name(name.to_string()).await
+        {
+            tracing::warn!("display name not set: {e}");
+        }
+        self.install_room(room, LocalPermissions::from_token(token, access_level))
+            .await;
+
+        // Update state to connected
+        self.set_connection_state(ConnectionState::Connected).await;
+
+        self.spawn_event_loop(events, options.audio_only).await;
+
+        // Media toggled before connecting; failures do not fail the join.
+        // Queued only now, so a failed join does not leave the options'
+        // media waiting for the next connection.
+        self.request_media_on_connect(options.media());
+        if let Err(e) = self.controls().apply_pending().await {
+            tracing::warn!("media requested before connect not applied: {e}");
+        }
+
+        Ok(())
+    }
+
+    /// Turn a network-level connect failure into
+    /// `VisioError::Unreachable` when a connectivity diagnosis of `url`
+    /// finds the problem. Other errors, and failures the diagnosis cannot
+    /// explain, are returned unchanged.
+    async fn diagnose_failure(&self, url: &str, error: VisioError) -> VisioError {
+        if !matches!(
+            error,
+            VisioError::Connection(_) | VisioError::Http(_) | VisioError::Timeout(_)
+        ) {
+            return error;
+        }
+        match self.meet_api.diagnose_connectivity(url).await {
+            Some(diagnosis) if diagnosis.has_problem() => {
+                tracing::warn!("connectivity diagnosis: {diagnosis}");
+                VisioError::Unreachable {
+                    message: error.to_string(),
+                    diagnosis: Box::new(diagnosis),
+                }
+            }
+            Some(diagnosis) => {
+                tracing::info!("connectivity diagnosis found no problem: {diagnosis}");
+                error
+            }
+            None => error,
+        }
+    }
+
+    /// Switch to another room, keeping the current one up until the new
+    /// connection is established.
+    ///
+    /// The token is fetched and the new LiveKit connection negotiated while
+    /// the current room is still live; local microphone/camera tracks are
+    /// republished from the same sources. Listeners see `ParticipantLeft` /
+    /// `TrackUnsubscribed` for the old room, `RoomSwitched`, then the new
+    /// room's participants — the connection state stays `Connected`.
+    /// If anything fails before the swap, the current room is left untouched.
+    /// Without a current room this is equivalent to `connect()`.
+    pub async fn switch_room(
+        &self,
+        meet_url: &str,
+        username: Option<&str>,
+    ) -> Result<(), VisioError> {
+        let mut options = self.last_join_options.lock().await.clone();
+        options.username = username.map(|s| s.to_string());
+        let Some(old_room) = self.room.lock().await.clone() else {
+            return self.connect(meet_url, &options).await;
+        };
+
+        let token_info = self.fetch_token(meet_url, username).await?;
+        let (new_room, events) = self
+            .open_room(&token_info.livekit_url, &token_info.token, &options)
+            .await?;
+
+        self.republish_local_tracks(&old_room, &new_room).await;
+
+        // Detach the old event loop so the old room's Disconnected event
+        // does not clear the state we are about to install.
+        if let Some(cancel) = self.event_loop_cancel.lock().await.take() {
+            let _ = cancel.send(());
+        }
+        *self.room.lock().await = None;
+
+        let old_participants: Vec<String> = {
+            let pm = self.participants.lock().await;
+            pm.participants().iter().map(|p| p.sid.clone()).collect()
+        };
+        // The local tracks were republished: their sources stay.
+        Self::reset_session_state(&self.session_state()).await;
+        for sid in old_participants {
+            self.join_leave.left(sid);
+        }
+        // Deliver the old room's departures before RoomSwitched.
+        self.join_leave.flush();
+
+        *self.last_meet_url.lock().await = Some(meet_url.to_string());
+        self.feedback.remember_call(meet_url);
+        let audio_only = options.audio_only;
+        *self.last_join_options.lock().await = options;
+        self.emitter.emit(VisioEvent::RoomSwitched {
+            meet_url: meet_url.to_string(),
+        });
+
+        let permissions =
+            LocalPermissions::from_token(&token_info.token, token_info.access_level.clone());
+        self.meeting_timer.start(&token_info);
+        *self.meet_room.lock().await = Some(token_info);
+        self.refresh_feature_flags(meet_url);
+        self.install_room(new_room, permissions).await;
+        self.spawn_event_loop(events, audio_only).await;
+
+        tokio::spawn(async move {
+            if let Err(e) = old_room.close().await {
+                tracing::warn!("error closing previous room: {e}");
+            }
+        });
+
+        tracing::info!("switched room to {meet_url}");
+        Ok(())
+    }
+
+    /// Request LiveKit credentials from the Meet API, each request bounded
+    /// by `ConnectTimeouts::meet_api`. Restricted rooms go through the
+    /// lobby first (see `wait_for_entry`).
+    async fn fetch_token(
+        &self,
+        meet_url: &str,
+        username: Option<&str>,
+    ) -> Result<TokenInfo, VisioError> {
+        let timeout = self.connect_timeouts.lock().await.meet_api;
+        let cookie = self.session_cookie.lock().await.clone();
+
+        self.emitter
+            .emit(VisioEvent::ConnectProgress(ConnectStep::TokenRequested));
+        let request = AuthService::lookup_room_with(
+            self.meet_api.as_ref(),
+            meet_url,
+            username,
+            cookie.as_deref(),
+        );
+        let access = tokio::time::timeout(timeout, request).await.map_err(|_| {
+            VisioError::Timeout(format!("Meet API did not respond within {timeout:?}"))
+        })??;
+        let token_info = match access {
+            RoomAccess::Granted(info) => info,
+            RoomAccess::LobbyRequired(entry) => self.wait_for_entry(entry, timeout).await?,
+        };
+        self.emitter
+            .emit(VisioEvent::ConnectProgress(ConnectStep::TokenReceived));
+
+        Ok(token_info)
+    }
+
+    /// Knock on a restricted room: emits `WaitingForHost`, then polls the
+    /// lobby until the host lets us in (`EntryGranted`) or refuses
+    /// (`EntryDenied`). `disconnect()` abandons the request.
+    async fn wait_for_entry(
+        &self,
+        mut entry: EntryRequest,
+        timeout: Duration,
+    ) -> Result<TokenInfo, VisioError> {
+        tracing::info!(
+            "room {} is restricted, asking the host to let us in",
+            entry.room_id
+        );
+        self.emitter.emit(VisioEvent::WaitingForHost);
+        let deadline = self.clock.now() + ENTRY_WAIT_TIMEOUT;
+
+        loop {
+            if self.last_meet_url.lock().await.is_none() {
+                return Err(VisioError::Connection("entry request cancelled".into()));
+            }
+            let request = AuthService::request_entry_with(self.meet_api.as_ref(), &mut entry);
+            let status = tokio::time::timeout(timeout, request).await.map_err(|_| {
+                VisioError::Timeout(format!("Meet API did not respond within {timeout:?}"))
+            })??;
+            match status {
+                EntryStatus::Granted(info) => {
+                    tracing::info!("host let us into room {}", entry.room_id);
+                    self.emitter.emit(VisioEvent::EntryGranted);
+                    return Ok(info);
+                }
+                EntryStatus::Denied => {
+                    tracing::info!("host denied entry to room {}", entry.room_id);
+                    self.emitter.emit(VisioEvent::EntryDenied);
+                    return Err(VisioError::Auth("entry denied by the host".into()));
+                }
+                EntryStatus::Waiting => {}
+            }
+            if self.clock.now() >= deadline {
+                return Err(VisioError::Timeout(
+                    "the host did not answer the entry request".into(),
+                ));
+            }
+            self.clock.sleep(ENTRY_POLL_INTERVAL).await;
+        }
+    }
+
+    /// Connect to LiveKit, bounded by `ConnectTimeouts::livekit`.
+    async fn open_room(
+        &self,
+        livekit_url: &str,
+        token: &str,
+        join_options: &JoinOptions,
+    ) -> Result<(Arc<Room>, mpsc::UnboundedReceiver<RoomEvent>), VisioError> {
+        let timeout = self.connect_timeouts.lock().await.livekit;
         let mut options = RoomOptions::default();
-        options.auto_subscribe = true;
+        options.auto_subscribe = join_options.auto_subscribe;
         options.adaptive_stream = true;
         options.dynacast = true;
+        options.connect_timeout = timeout;
+        match &join_options.rtc_overrides {
+            Some(overrides) => overrides.apply(&mut options.rtc_config),
+            None => self.rtc_config().apply(&mut options.rtc_config),
+        }
 
-        let (room, events) = Room::connect(livekit_url, token, options)
-            .await
-            .map_err(|e| VisioError::Connection(e.to_string()))?;
-
-        let room = Arc::new(room);
+        // Room::connect returns once the join response is received and the
+        // primary peer connection is up; the outer timeout also bounds the
+        // retries LiveKit performs internally.
+        let (room, events) =
+            tokio::time::timeout(timeout, Room::connect(livekit_url, token, options))
+                .await
+                .map_err(|_| {
+                    VisioError::Timeout(format!("LiveKit did not connect within {timeout:?}"))
+                })?
+                .map_err(|e| VisioError::Connection(e.to_string()))?;
+        self.emitter
+            .emit(VisioEvent::ConnectProgress(ConnectStep::SignalConnected));
+
+        Ok((Arc::new(room), events))
+    }
 
+    /// Make `room` the current room: seed participants, store the
+    /// reference and permissions, and set up hand raise handling.
+    async fn install_room(&self, room: Arc<Room>, mut permissions: LocalPermissions) {
         // Store local participant SID
         {
             let local = room.local_participant();
             let mut pm = self.participants.lock().await;
             pm.set_local_sid(local.sid().to_string());
+            let (sid, name) = (local.sid().to_string(), local.name().to_string());
+            self.system_messages.set_local(&sid, &name);
+            self.unread.set_local(&sid, &name);
         }
 
         // Seed existing remote participants
@@ -243,62 +1375,126 @@ impl RoomManager {
             for (_, participant) in room.remote_participants() {
                 let info = Self::remote_participant_to_info(&participant);
                 pm.add_participant(info.clone());
-                self.emitter.emit(VisioEvent::ParticipantJoined(info));
+                self.join_leave.joined(info);
             }
+            self.call_summary
+                .on_participant_count(pm.participants().len());
         }
 
+        // The join response carries the grants actually applied by the SFU.
+        if let Some(p) = room.local_participant().permission() {
+            permissions.apply_update(
+                p.can_publish,
+                p.can_subscribe,
+                p.can_publish_data,
+                &p.can_publish_sources,
+                p.can_update_metadata,
+            );
+        }
+        *self.local_permissions.lock().await = Some(permissions.clone());
+        self.emitter
+            .emit(VisioEvent::PermissionsChanged(permissions));
+        self.recording.set_active(room.active_recording());
+
         // Store room reference
         *self.room.lock().await = Some(room.clone());
 
-        // Initialize HandRaiseManager now that we have a room
-        {
-            let hm = HandRaiseManager::new(room.clone(), self.emitter.clone());
-            *self.hand_raise.lock().await = Some(hm);
+        // Initialize HandRaiseManager now that we have a room
+        {
+            let hm = HandRaiseManager::new(
+                room.clone(),
+                self.emitter.clone(),
+                self.hand_auto_lower.clone(),
+                self.clock.clone(),
+            );
+            *self.hand_raise.lock().await = Some(hm);
+        }
+        self.idle.start();
+
+        self.emitter
+            .emit(VisioEvent::ConnectProgress(ConnectStep::MediaConnected));
+    }
+
+    /// Publish the local tracks of `from` on `to`, reusing the same native
+    /// sources so platform capture keeps feeding them. Failures are logged:
+    /// the switch proceeds and the user can re-enable the device.
+    async fn republish_local_tracks(&self, from: &Room, to: &Room) {
+        for (_, publication) in from.local_participant().track_publications() {
+            let Some(track) = publication.track() else {
+                continue;
+            };
+            let muted = publication.is_muted();
+            let new_track = match track {
+                LocalTrack::Audio(t) => LocalTrack::Audio(LocalAudioTrack::create_audio_track(
+                    &t.name(),
+                    t.rtc_source(),
+                )),
+                LocalTrack::Video(t) => LocalTrack::Video(LocalVideoTrack::create_video_track(
+                    &t.name(),
+                    t.rtc_source(),
+                )),
+            };
+            let options = self.republish_options(publication.source()).await;
+            match to
+                .local_participant()
+                .publish_track(new_track, options)
+                .await
+            {
+                Ok(new_pub) => {
+                    if muted {
+                        new_pub.mute();
+                    }
+                }
+                Err(e) => tracing::warn!("republish {:?} failed: {e}", publication.source()),
+            }
         }
+    }
 
-        // Update state to connected
-        self.set_connection_state(ConnectionState::Connected).await;
+    /// Options of a track republished by `switch_room`: the ones
+    /// `MeetingControls` published it with.
+    async fn republish_options(&self, source: LkTrackSource) -> TrackPublishOptions {
+        let audio = *self.audio_options.lock().unwrap_or_else(|e| e.into_inner());
+        let codec = *self.video_codec.lock().unwrap_or_else(|e| e.into_inner());
+        let hint = *self.video_content_hint.lock().await;
+        track_publish_options(source, audio, codec, hint)
+    }
 
-        // Spawn event loop
-        let emitter = self.emitter.clone();
-        let participants = self.participants.clone();
-        let connection_state = self.connection_state.clone();
-        let room_ref = self.room.clone();
-        let subscribed_tracks = self.subscribed_tracks.clone();
-        let messages = self.messages.clone();
-        let playout_buffer = self.playout_buffer.clone();
-        let hand_raise = self.hand_raise.clone();
-        let last_meet_url = self.last_meet_url.clone();
-        let chat_open = self.chat_open.clone();
-        let unread_count = self.unread_count.clone();
+    /// Run the room event loop. With `audio_only`, remote video is
+    /// unsubscribed as soon as it is subscribed.
+    async fn spawn_event_loop(&self, events: mpsc::UnboundedReceiver<RoomEvent>, audio_only: bool) {
+        let (cancel_tx, cancel_rx) = oneshot::channel();
+        *self.event_loop_cancel.lock().await = Some(cancel_tx);
+
+        let context = EventLoopContext {
+            session: self.session_state(),
+            connection_state: self.connection_state.clone(),
+            room_ref: self.room.clone(),
+            last_meet_url: self.last_meet_url.clone(),
+            join_leave: self.join_leave.clone(),
+            capture_constraints: self.capture_constraints.clone(),
+            power: self.power.clone(),
+            data_channel: self.data_channel.clone(),
+            attributes: self.attributes.clone(),
+            journal: self.journal.clone(),
+            call_summary: self.call_summary.clone(),
+            system_call: self.system_call.clone(),
+            system_messages: self.system_messages.clone(),
+            payload_guard: self.payload_guard.clone(),
+            clock: self.clock.clone(),
+        };
 
         tokio::spawn(async move {
-            Self::event_loop(
-                events,
-                emitter,
-                participants,
-                connection_state,
-                room_ref,
-                subscribed_tracks,
-                messages,
-                playout_buffer,
-                hand_raise,
-                last_meet_url,
-                chat_open,
-                unread_count,
-            )
-            .await;
+            Self::event_loop(events, cancel_rx, context, audio_only).await;
         });
-
-        Ok(())
     }
 
     /// Disconnect from the current room.
     pub async fn disconnect(&self) {
+        self.system_call.end(SystemCallState::EndedLocally);
         // Clear reconnection info BEFORE closing — so the event loop
         // knows this disconnect is intentional.
         *self.last_meet_url.lock().await = None;
-        *self.last_username.lock().await = None;
+        *self.last_join_options.lock().await = JoinOptions::default();
 
         let room = self.room.lock().await.take();
         if let Some(room) = room
@@ -306,16 +1502,136 @@ impl RoomManager {
         {
             tracing::warn!("error closing room: {e}");
         }
-        self.participants.lock().await.clear();
-        self.subscribed_tracks.lock().await.clear();
-        self.messages.lock().await.clear();
-        self.playout_buffer.clear();
+        let session = self.session_state();
+        Self::reset_session_state(&session).await;
+        Self::release_local_tracks(&session).await;
+        self.set_connection_state(ConnectionState::Disconnected)
+            .await;
+    }
+
+    fn session_state(&self) -> SessionState {
+        SessionState {
+            emitter: self.emitter.clone(),
+            mic_enabled: self.mic_enabled.clone(),
+            participants: self.participants.clone(),
+            subscribed_tracks: self.subscribed_tracks.clone(),
+            messages: self.messages.clone(),
+            unread: self.unread.clone(),
+            playout_buffer: self.playout_buffer.clone(),
+            published_sources: self.published_sources.clone(),
+            local_permissions: self.local_permissions.clone(),
+            meet_room: self.meet_room.clone(),
+            meeting_timer: self.meeting_timer.clone(),
+            idle: self.idle.clone(),
+            moderation: self.moderation.clone(),
+            recording: self.recording.clone(),
+            polls: self.polls.clone(),
+            presence: self.presence.clone(),
+            quality_policy: self.quality_policy.clone(),
+            visibility: self.visibility.clone(),
+            local_recorder: self.local_recorder.clone(),
+            restreamer: self.restreamer.clone(),
+            feature_flags: self.feature_flags.clone(),
+            hand_raise: self.hand_raise.clone(),
+        }
+    }
+
+    /// Tear down the state bound to the connection: participants, remote
+    /// tracks, playout and hand raise. Enough when the connection drops,
+    /// since `reconnect` rebuilds it.
+    async fn reset_transport_state(session: &SessionState) {
+        session.participants.lock().await.clear();
+        Self::unsubscribe_all(&session.subscribed_tracks, &session.emitter).await;
+        session.playout_buffer.clear();
         // Clear hand raise state
-        if let Some(hm) = self.hand_raise.lock().await.take() {
+        if let Some(hm) = session.hand_raise.lock().await.take() {
             hm.clear().await;
         }
-        self.set_connection_state(ConnectionState::Disconnected)
-            .await;
+    }
+
+    /// Release the sources of the local tracks once the room that
+    /// published them is gone. `switch_room` keeps them: it republishes
+    /// the tracks in the new room.
+    async fn release_local_tracks(session: &SessionState) {
+        session.published_sources.release();
+        *session.mic_enabled.lock().await = false;
+    }
+
+    /// Tear down the whole session on leaving it: the connection state,
+    /// then chat, meeting services, local recording and restream.
+    async fn reset_session_state(session: &SessionState) {
+        Self::reset_transport_state(session).await;
+        session.messages.lock().await.clear();
+        session.unread.clear();
+        *session.local_permissions.lock().await = None;
+        *session.meet_room.lock().await = None;
+        session.meeting_timer.reset();
+        session.idle.reset();
+        session.moderation.reset().await;
+        session.recording.reset();
+        session.polls.reset();
+        session.presence.reset();
+        session.quality_policy.reset();
+        session.visibility.reset();
+        let recorder = session
+            .local_recorder
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .take();
+        if let Some(recorder) = recorder
+            && let Err(e) = recorder.stop()
+        {
+            tracing::warn!("local recording: {e}");
+        }
+        session
+            .restreamer
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .take();
+        *session.feature_flags.lock().await = FeatureFlags::default();
+    }
+
+    /// Reset the media state every room of the process shares: the
+    /// instance watermark, the privacy mode of the camera, the auto-gain
+    /// levels and the ducking. `disconnect` leaves it alone since other
+    /// rooms may still be joined; call this once the last one is left.
+    pub fn reset_shared_media(&self) {
+        video_overlay().set_instance_text(None);
+        if video_privacy().set_enabled(false) {
+            self.emitter
+                .emit(VisioEvent::VideoPrivacyChanged { enabled: false });
+        }
+        auto_gain().reset();
+        audio_ducker().reset();
+    }
+
+    /// Report the call for the OS call UI when joining `room`, shown as
+    /// `display_name`. Its changes follow as `SystemCallChanged` events.
+    pub async fn report_call(
+        &self,
+        room: &str,
+        display_name: &str,
+        direction: CallDirection,
+    ) -> SystemCall {
+        let connected = *self.connection_state.lock().await == ConnectionState::Connected;
+        self.system_call
+            .report(room, display_name, direction, connected)
+    }
+
+    /// Time left before the meeting's scheduled end, `None` when the
+    /// instance reported no end for the room.
+    pub fn time_remaining(&self) -> Option<Duration> {
+        self.meeting_timer.time_remaining()
+    }
+
+    /// The call reported with `report_call`, until it ends.
+    pub fn system_call(&self) -> Option<SystemCall> {
+        self.system_call.current()
+    }
+
+    /// End the reported call from the OS call UI: leaves the room.
+    pub async fn end_call(&self) {
+        self.disconnect().await;
     }
 
     /// Raise the local participant's hand.
@@ -336,6 +1652,63 @@ impl RoomManager {
             .await
     }
 
+    /// Perform `action` as the matching meeting control would.
+    pub async fn dispatch_action(&self, action: MeetingAction) -> Result<(), VisioError> {
+        tracing::info!("dispatching {action}");
+        let controls = self.controls();
+        match action {
+            // A request queued while disconnected is the state to flip.
+            MeetingAction::ToggleMicrophone => {
+                let enabled = match controls.pending_media().microphone {
+                    Some(enabled) => enabled,
+                    None => controls.is_microphone_enabled().await,
+                };
+                controls.set_microphone_enabled(!enabled).await
+            }
+            MeetingAction::ToggleCamera => {
+                let enabled = match controls.pending_media().camera {
+                    Some(enabled) => enabled,
+                    None => controls.is_camera_enabled().await,
+                };
+                controls.set_camera_enabled(!enabled).await
+            }
+            MeetingAction::ToggleHandRaise => {
+                if self.is_hand_raised().await {
+                    self.lower_hand().await
+                } else {
+                    self.raise_hand().await
+                }
+            }
+            MeetingAction::ToggleChat => {
+                self.set_chat_open(!self.is_chat_open());
+                Ok(())
+            }
+            MeetingAction::PushToTalk { pressed } => controls.set_microphone_enabled(pressed).await,
+        }
+    }
+
+    /// Handle a headset button press: play/pause toggles the microphone,
+    /// hang-up leaves the call. Repeats within `MEDIA_BUTTON_DEBOUNCE` and
+    /// presses outside a call are ignored. Returns whether the press was
+    /// handled; handled presses emit `MediaButtonHandled`.
+    pub async fn handle_media_button(&self, button: MediaButton) -> Result<bool, VisioError> {
+        if *self.connection_state.lock().await == ConnectionState::Disconnected
+            || !self.media_buttons.accept(button, self.clock.now())
+        {
+            return Ok(false);
+        }
+        tracing::info!("media button: {button}");
+        match button {
+            MediaButton::PlayPause => {
+                self.dispatch_action(MeetingAction::ToggleMicrophone)
+                    .await?
+            }
+            MediaButton::Hangup => self.end_call().await,
+        }
+        self.emitter.emit(VisioEvent::MediaButtonHandled(button));
+        Ok(true)
+    }
+
     /// Send an animated reaction visible to all participants.
     ///
     /// The payload matches the Meet web client protocol:
@@ -364,6 +1737,120 @@ impl RoomManager {
         Ok(())
     }
 
+    /// Shareable link of the current room, with dial-in details when the
+    /// instance provides them.
+    pub async fn invite_info(&self) -> Result<InviteInfo, VisioError> {
+        let meet_url = self
+            .last_meet_url
+            .lock()
+            .await
+            .clone()
+            .ok_or_else(|| VisioError::Room("not connected".into()))?;
+        let pin_code = self
+            .meet_room
+            .lock()
+            .await
+            .as_ref()
+            .and_then(|room| room.pin_code.clone());
+        InviteService::get_invite_info_with(self.meet_api.as_ref(), &meet_url, pin_code.as_deref())
+            .await
+    }
+
+    /// Features of the current Meet instance (defaults until read after
+    /// connecting).
+    pub async fn feature_flags(&self) -> FeatureFlags {
+        self.feature_flags.lock().await.clone()
+    }
+
+    /// Read the feature flags of the instance hosting `meet_url` in the
+    /// background; emits `FeatureFlagsChanged` if they differ from the
+    /// current ones.
+    fn refresh_feature_flags(&self, meet_url: &str) {
+        let Ok(instance) = AuthService::parse_instance(meet_url) else {
+            return;
+        };
+        let meet_api = self.meet_api.clone();
+        let connect_timeouts = self.connect_timeouts.clone();
+        let feature_flags = self.feature_flags.clone();
+        let emitter = self.emitter.clone();
+        tokio::spawn(async move {
+            let timeout = connect_timeouts.lock().await.meet_api;
+            let fetch = FeatureFlags::fetch_with(meet_api.as_ref(), &instance);
+            let fetched = tokio::time::timeout(timeout, fetch)
+                .await
+                .unwrap_or_default();
+            let changed = {
+                let mut current = feature_flags.lock().await;
+                let changed = *current != fetched;
+                *current = fetched.clone();
+                changed
+            };
+            let whiteboard = fetched.whiteboard;
+            video_overlay().set_instance_text(fetched.watermark.clone());
+            if changed {
+                tracing::info!("feature flags of {instance}: {fetched:?}");
+                emitter.emit(VisioEvent::FeatureFlagsChanged(fetched));
+            }
+            if whiteboard {
+                emitter.emit(VisioEvent::WhiteboardAvailable);
+            }
+        });
+    }
+
+    /// URL and credentials of the whiteboard of the current room, to open
+    /// in a webview. Fails with `VisioError::Room` if the instance has no
+    /// whiteboard.
+    pub async fn whiteboard_session(&self) -> Result<WhiteboardSession, VisioError> {
+        let meet_url = self
+            .last_meet_url
+            .lock()
+            .await
+            .clone()
+            .ok_or_else(|| VisioError::Room("not connected".into()))?;
+        let room_id = self
+            .meet_room
+            .lock()
+            .await
+            .as_ref()
+            .and_then(|room| room.room_id.clone())
+            .ok_or_else(|| VisioError::Room("room id not reported by the Meet API".into()))?;
+        let session_cookie = self.session_cookie.lock().await.clone();
+        WhiteboardService::get_session_with(
+            self.meet_api.as_ref(),
+            &AuthService::parse_instance(&meet_url)?,
+            &room_id,
+            session_cookie.as_deref(),
+        )
+        .await
+    }
+
+    /// Email an invitation to the current room, if the instance supports it.
+    pub async fn invite_participants(&self, emails: Vec<String>) -> Result<(), VisioError> {
+        let meet_url = self
+            .last_meet_url
+            .lock()
+            .await
+            .clone()
+            .ok_or_else(|| VisioError::Room("not connected".into()))?;
+        let room_id = self
+            .meet_room
+            .lock()
+            .await
+            .as_ref()
+            .and_then(|room| room.room_id.clone())
+            .ok_or_else(|| VisioError::Room("room id not reported by the Meet API".into()))?;
+        let instance = AuthService::parse_instance(&meet_url)?;
+        let cookie = self.session_cookie.lock().await.clone();
+        InviteService::invite_participants_with(
+            self.meet_api.as_ref(),
+            &instance,
+            &room_id,
+            emails,
+            cookie.as_deref(),
+        )
+        .await
+    }
+
     /// Check if the local participant's hand is currently raised.
     pub async fn is_hand_raised(&self) -> bool {
         let hm = self.hand_raise.lock().await;
@@ -376,7 +1863,7 @@ impl RoomManager {
     /// Get stored connection info for reconnection.
     pub async fn last_connection_info(&self) -> Option<(String, Option<String>)> {
         let url = self.last_meet_url.lock().await.clone();
-        let username = self.last_username.lock().await.clone();
+        let username = self.last_join_options.lock().await.username.clone();
         url.map(|u| (u, username))
     }
 
@@ -384,14 +1871,21 @@ impl RoomManager {
     ///
     /// Called by native UI when ConnectionLost is received.
     pub async fn reconnect(&self) -> Result<(), VisioError> {
-        let (meet_url, username) = self
+        let (meet_url, _) = self
             .last_connection_info()
             .await
             .ok_or_else(|| VisioError::Connection("no previous connection info".into()))?;
+        // Mic/camera-on-join only applies to the first join.
+        let options = self
+            .last_join_options
+            .lock()
+            .await
+            .clone()
+            .mic_on(false)
+            .camera_on(false);
 
-        let max_attempts: u32 = 10;
-        let base_delay = std::time::Duration::from_secs(1);
-        let max_delay = std::time::Duration::from_secs(30);
+        let policy = *self.reconnect_policy.lock().await;
+        let max_attempts = policy.max_attempts;
 
         for attempt in 1..=max_attempts {
             self.set_connection_state(ConnectionState::Reconnecting { attempt })
@@ -399,7 +1893,7 @@ impl RoomManager {
 
             tracing::info!("reconnection attempt {attempt}/{max_attempts}");
 
-            match self.connect(&meet_url, username.as_deref()).await {
+            match self.connect(&meet_url, &options).await {
                 Ok(()) => {
                     tracing::info!("reconnection successful on attempt {attempt}");
                     return Ok(());
@@ -407,19 +1901,17 @@ impl RoomManager {
                 Err(e) => {
                     tracing::warn!("reconnection attempt {attempt}/{max_attempts} failed: {e}");
                     if attempt < max_attempts {
-                        let delay = base_delay
-                            .checked_mul(2u32.pow(attempt - 1))
-                            .unwrap_or(max_delay)
-                            .min(max_delay);
-                        tokio::time::sleep(delay).await;
+                        self.clock.sleep(policy.delay(attempt)).await;
                     }
                 }
             }
         }
 
         // All attempts failed — clear connection info and report disconnect
+        self.system_call.end(SystemCallState::Failed);
+        Self::reset_session_state(&self.session_state()).await;
         *self.last_meet_url.lock().await = None;
-        *self.last_username.lock().await = None;
+        *self.last_join_options.lock().await = JoinOptions::default();
         self.set_connection_state(ConnectionState::Disconnected)
             .await;
         Err(VisioError::Connection(
@@ -432,11 +1924,24 @@ impl RoomManager {
         self.emitter.emit(VisioEvent::ConnectionStateChanged(state));
     }
 
+    /// Whether the server closed the session on purpose, so the call is
+    /// over rather than dropped.
+    fn ended_by_server(reason: DisconnectReason) -> bool {
+        matches!(
+            reason,
+            DisconnectReason::ParticipantRemoved
+                | DisconnectReason::RoomDeleted
+                | DisconnectReason::RoomClosed
+                | DisconnectReason::DuplicateIdentity
+        )
+    }
+
     fn lk_source_to_visio(source: LkTrackSource) -> TrackSource {
         match source {
             LkTrackSource::Microphone => TrackSource::Microphone,
             LkTrackSource::Camera => TrackSource::Camera,
             LkTrackSource::Screenshare => TrackSource::ScreenShare,
+            LkTrackSource::ScreenshareAudio => TrackSource::ScreenShareAudio,
             _ => TrackSource::Unknown,
         }
     }
@@ -466,29 +1971,71 @@ impl RoomManager {
             has_video: false,
             video_track_sid: None,
             connection_quality: ConnectionQuality::Good,
+            presence: Presence::of(&p.attributes()),
         }
     }
 
-    #[allow(clippy::too_many_arguments)]
     async fn event_loop(
-        mut events: tokio::sync::mpsc::UnboundedReceiver<RoomEvent>,
-        emitter: EventEmitter,
-        participants: Arc<Mutex<ParticipantManager>>,
-        connection_state: Arc<Mutex<ConnectionState>>,
-        room_ref: Arc<Mutex<Option<Arc<Room>>>>,
-        subscribed_tracks: Arc<Mutex<HashMap<String, RemoteVideoTrack>>>,
-        messages: MessageStore,
-        playout_buffer: Arc<AudioPlayoutBuffer>,
-        hand_raise: Arc<Mutex<Option<HandRaiseManager>>>,
-        last_meet_url: Arc<Mutex<Option<String>>>,
-        chat_open: Arc<AtomicBool>,
-        unread_count: Arc<AtomicU32>,
+        mut events: mpsc::UnboundedReceiver<RoomEvent>,
+        mut cancel: oneshot::Receiver<()>,
+        context: EventLoopContext,
+        audio_only: bool,
     ) {
+        let EventLoopContext {
+            session,
+            connection_state,
+            room_ref,
+            last_meet_url,
+            join_leave,
+            capture_constraints,
+            power,
+            data_channel,
+            attributes,
+            journal,
+            call_summary,
+            system_call,
+            system_messages,
+            payload_guard,
+            clock,
+        } = context;
+        let SessionState {
+            emitter,
+            participants,
+            subscribed_tracks,
+            messages,
+            playout_buffer,
+            hand_raise,
+            local_permissions,
+            recording,
+            polls,
+            presence,
+            quality_policy,
+            visibility,
+            ..
+        } = session.clone();
         let mut reconnect_attempt: u32 = 0;
         // Track active audio stream tasks so they get cancelled on disconnect
         let mut audio_stream_tasks: HashMap<String, tokio::task::JoinHandle<()>> = HashMap::new();
-
-        while let Some(event) = events.recv().await {
+        // Re-emits the smoothed speaker list as held speakers expire.
+        let mut speaker_expiry: Option<tokio::task::JoinHandle<()>> = None;
+
+        loop {
+            let event = tokio::select! {
+                // Detached (room switch or a newer loop took over): the
+                // shared state now belongs to another room — leave it alone.
+                _ = &mut cancel => {
+                    for (_, handle) in audio_stream_tasks.drain() {
+                        handle.abort();
+                    }
+                    tracing::info!("room event loop detached");
+                    break;
+                }
+                event = events.recv() => match event {
+                    Some(event) => event,
+                    None => break,
+                },
+            };
+            journal.record_room_event(&event);
             match event {
                 RoomEvent::Connected { .. } => {
                     reconnect_attempt = 0;
@@ -523,13 +2070,13 @@ impl RoomManager {
                     let is_intentional = last_meet_url.lock().await.is_none();
 
                     *connection_state.lock().await = ConnectionState::Disconnected;
-                    participants.lock().await.clear();
-                    subscribed_tracks.lock().await.clear();
-                    messages.lock().await.clear();
-                    playout_buffer.clear();
-                    if let Some(hm) = hand_raise.lock().await.take() {
-                        hm.clear().await;
+                    // A lost connection keeps the session for `reconnect`.
+                    if is_intentional {
+                        Self::reset_session_state(&session).await;
+                    } else {
+                        Self::reset_transport_state(&session).await;
                     }
+                    Self::release_local_tracks(&session).await;
                     for (sid, handle) in audio_stream_tasks.drain() {
                         handle.abort();
                         tracing::info!("audio playout stream aborted on disconnect: {sid}");
@@ -541,6 +2088,9 @@ impl RoomManager {
                             ConnectionState::Disconnected,
                         ));
                     } else {
+                        if Self::ended_by_server(reason) {
+                            system_call.end(SystemCallState::EndedRemotely);
+                        }
                         // Network loss — emit ConnectionLost so native UI
                         // can trigger reconnect().
                         emitter.emit(VisioEvent::ConnectionLost);
@@ -550,14 +2100,34 @@ impl RoomManager {
 
                 RoomEvent::ParticipantConnected(participant) => {
                     let info = Self::remote_participant_to_info(&participant);
-                    participants.lock().await.add_participant(info.clone());
-                    emitter.emit(VisioEvent::ParticipantJoined(info));
+                    {
+                        let mut pm = participants.lock().await;
+                        pm.add_participant(info.clone());
+                        call_summary.on_participant_count(pm.participants().len());
+                    }
+                    system_messages.participant_joined(&info);
+                    join_leave.joined(info);
+                    // Late joiner: catch them up on our polls.
+                    let polls = polls.clone();
+                    tokio::spawn(async move { polls.announce_local_polls().await });
                 }
 
                 RoomEvent::ParticipantDisconnected(participant) => {
                     let sid = participant.sid().to_string();
                     participants.lock().await.remove_participant(&sid);
-                    emitter.emit(VisioEvent::ParticipantLeft(sid));
+                    // An abrupt leave may never deliver `TrackUnsubscribed`:
+                    // release the participant's tracks now.
+                    for track_sid in participant.track_publications().keys() {
+                        let track_sid = track_sid.to_string();
+                        if let Some(handle) = audio_stream_tasks.remove(&track_sid) {
+                            handle.abort();
+                        }
+                        if subscribed_tracks.lock().await.remove(&track_sid).is_some() {
+                            emitter.emit(VisioEvent::TrackUnsubscribed(track_sid));
+                        }
+                    }
+                    system_messages.participant_left(&sid);
+                    join_leave.left(sid);
                 }
 
                 RoomEvent::TrackSubscribed {
@@ -565,6 +2135,14 @@ impl RoomManager {
                     publication,
                     participant,
                 } => {
+                    if audio_only && publication.kind() == LkTrackKind::Video {
+                        publication.set_subscribed(false);
+                        continue;
+                    }
+                    if quality_policy.pause_if_active(&publication) {
+                        continue;
+                    }
+                    visibility.on_subscribed(&publication);
                     let source = Self::lk_source_to_visio(publication.source());
                     let track_kind = match publication.kind() {
                         LkTrackKind::Audio => TrackKind::Audio,
@@ -595,7 +2173,8 @@ impl RoomManager {
                     }
 
                     // Start audio playout: create NativeAudioStream and feed
-                    // decoded PCM frames into the shared playout buffer.
+                    // decoded PCM frames, normalized per participant, into
+                    // the shared playout buffer.
                     if track_kind == TrackKind::Audio
                         && let livekit::track::RemoteTrack::Audio(audio_track) = &track
                     {
@@ -606,9 +2185,13 @@ impl RoomManager {
                         );
                         let buf = playout_buffer.clone();
                         let sid = track_sid.clone();
+                        let identity = participant.identity().to_string();
                         let handle = tokio::spawn(async move {
                             tracing::info!("audio playout stream started for track {sid}");
-                            while let Some(frame) = audio_stream.next().await {
+                            while let Some(mut frame) = audio_stream.next().await {
+                                if auto_gain().config().enabled {
+                                    auto_gain().process(&identity, frame.data.to_mut());
+                                }
                                 buf.push_samples(&frame.data);
                             }
                             tracing::info!("audio playout stream ended for track {sid}");
@@ -635,13 +2218,16 @@ impl RoomManager {
                     let is_video = publication.kind() == LkTrackKind::Video;
                     let is_audio = publication.kind() == LkTrackKind::Audio;
 
+                    // Video tracks already released (participant left) were
+                    // reported then.
+                    let mut released = false;
                     if is_video {
                         let mut pm = participants.lock().await;
                         if let Some(p) = pm.participant_mut(&psid) {
                             p.has_video = false;
                             p.video_track_sid = None;
                         }
-                        subscribed_tracks.lock().await.remove(&track_sid);
+                        released = subscribed_tracks.lock().await.remove(&track_sid).is_none();
                     }
 
                     if is_audio && let Some(handle) = audio_stream_tasks.remove(&track_sid) {
@@ -649,7 +2235,9 @@ impl RoomManager {
                         tracing::info!("audio playout stream aborted for track {track_sid}");
                     }
 
-                    emitter.emit(VisioEvent::TrackUnsubscribed(track_sid));
+                    if !released {
+                        emitter.emit(VisioEvent::TrackUnsubscribed(track_sid));
+                    }
                 }
 
                 RoomEvent::TrackMuted {
@@ -707,12 +2295,34 @@ impl RoomManager {
 
                 RoomEvent::ActiveSpeakersChanged { speakers } => {
                     let sids: Vec<String> = speakers.iter().map(|p| p.sid().to_string()).collect();
-                    participants.lock().await.set_active_speakers(sids.clone());
-                    // Auto-lower hand if local participant is speaking with hand raised
+                    // Auto-lower hand if local participant is speaking with hand raised.
+                    // Uses the raw list: it is about speaking right now.
                     if let Some(hm) = hand_raise.lock().await.as_ref() {
                         hm.start_auto_lower(sids.clone());
                     }
-                    emitter.emit(VisioEvent::ActiveSpeakersChanged(sids));
+                    let (changed, active, expiry) = {
+                        let mut pm = participants.lock().await;
+                        let changed = pm.update_active_speakers(sids, clock.now());
+                        (
+                            changed,
+                            pm.active_speakers().to_vec(),
+                            pm.next_speaker_expiry(),
+                        )
+                    };
+                    if changed {
+                        emitter.emit(VisioEvent::ActiveSpeakersChanged(active));
+                    }
+                    if let Some(task) = speaker_expiry.take() {
+                        task.abort();
+                    }
+                    if let Some(deadline) = expiry {
+                        speaker_expiry = Some(Self::spawn_speaker_expiry(
+                            participants.clone(),
+                            emitter.clone(),
+                            clock.clone(),
+                            deadline,
+                        ));
+                    }
                 }
 
                 RoomEvent::ParticipantAttributesChanged {
@@ -720,23 +2330,53 @@ impl RoomManager {
                     changed_attributes,
                 } => {
                     let psid = participant.sid().to_string();
+                    attributes.handle_changed(&psid, &changed_attributes);
+                    // The local presence is reported by `set_presence`.
+                    if matches!(participant, Participant::Remote(_)) {
+                        presence
+                            .handle_participant_attributes(&psid, &changed_attributes)
+                            .await;
+                    }
                     if let Some(hm) = hand_raise.lock().await.as_ref() {
                         hm.handle_participant_attributes(psid, &changed_attributes)
                             .await;
                     }
                 }
 
+                RoomEvent::ParticipantPermissionChanged {
+                    participant: Participant::Local(_),
+                    permission: Some(p),
+                } => {
+                    let updated = {
+                        let mut perms = local_permissions.lock().await;
+                        perms.as_mut().and_then(|perms| {
+                            perms
+                                .apply_update(
+                                    p.can_publish,
+                                    p.can_subscribe,
+                                    p.can_publish_data,
+                                    &p.can_publish_sources,
+                                    p.can_update_metadata,
+                                )
+                                .then(|| perms.clone())
+                        })
+                    };
+                    if let Some(perms) = updated {
+                        tracing::info!("local permissions changed: {perms:?}");
+                        emitter.emit(VisioEvent::PermissionsChanged(perms));
+                    }
+                }
+
+                RoomEvent::RoomUpdated { room } => {
+                    recording.set_active(room.active_recording);
+                }
+
                 RoomEvent::ConnectionQualityChanged {
                     quality,
                     participant,
                 } => {
                     let psid = participant.sid().to_string();
-                    let q = match quality {
-                        LkConnectionQuality::Excellent => ConnectionQuality::Excellent,
-                        LkConnectionQuality::Good => ConnectionQuality::Good,
-                        LkConnectionQuality::Poor => ConnectionQuality::Poor,
-                        LkConnectionQuality::Lost => ConnectionQuality::Lost,
-                    };
+                    let q = connection_quality(quality);
 
                     {
                         let mut pm = participants.lock().await;
@@ -745,6 +2385,18 @@ impl RoomManager {
                         }
                     }
 
+                    if matches!(participant, Participant::Local(_)) {
+                        let power = *power.lock().unwrap_or_else(|e| e.into_inner());
+                        update_capture_constraints(&capture_constraints, &emitter, |c| {
+                            power.limit_capture(CaptureConstraints::for_quality(
+                                &q,
+                                c.low_light_boost,
+                            ))
+                        });
+                        quality_policy.on_local_quality(&q);
+                        call_summary.on_local_quality(&q);
+                    }
+
                     emitter.emit(VisioEvent::ConnectionQualityChanged {
                         participant_sid: psid,
                         quality: q,
@@ -756,15 +2408,18 @@ impl RoomManager {
                     participant,
                     ..
                 } => {
+                    let sender_sid = participant
+                        .as_ref()
+                        .map(|p| p.sid().to_string())
+                        .unwrap_or_default();
+                    if !payload_guard.accept_text(&message.message, &sender_sid) {
+                        continue;
+                    }
                     tracing::info!(
                         "ChatMessage received: id={} text={}",
                         message.id,
                         message.message
                     );
-                    let sender_sid = participant
-                        .as_ref()
-                        .map(|p| p.sid().to_string())
-                        .unwrap_or_default();
                     let sender_name = participant
                         .as_ref()
                         .map(|p| p.name().to_string())
@@ -776,6 +2431,7 @@ impl RoomManager {
                         sender_name,
                         text: message.message,
                         timestamp_ms: message.timestamp as u64,
+                        kind: ChatMessageKind::Text,
                     };
                     messages.lock().await.push(msg.clone());
                     emitter.emit(VisioEvent::ChatMessageReceived(msg));
@@ -791,8 +2447,7 @@ impl RoomManager {
                         let emitter = emitter.clone();
                         let room_ref = room_ref.clone();
                         let identity = participant_identity.to_string();
-                        let chat_open = chat_open.clone();
-                        let unread_count = unread_count.clone();
+                        let payload_guard = payload_guard.clone();
 
                         tokio::spawn(async move {
                             let reader = reader.take();
@@ -805,6 +2460,7 @@ impl RoomManager {
                             let timestamp_ms = reader.info().timestamp.timestamp_millis() as u64;
 
                             match reader.read_all().await {
+                                Ok(text) if !payload_guard.accept_text(&text, &identity) => {}
                                 Ok(text) => {
                                     // Look up participant name from room
                                     let sender_name = {
@@ -825,6 +2481,7 @@ impl RoomManager {
                                         sender_name,
                                         text,
                                         timestamp_ms,
+                                        kind: ChatMessageKind::Text,
                                     };
                                     tracing::info!(
                                         "Chat via TextStream: from={} text={}",
@@ -833,10 +2490,6 @@ impl RoomManager {
                                     );
                                     messages.lock().await.push(msg.clone());
                                     emitter.emit(VisioEvent::ChatMessageReceived(msg));
-                                    if !chat_open.load(Ordering::Relaxed) {
-                                        let count = unread_count.fetch_add(1, Ordering::Relaxed) + 1;
-                                        emitter.emit(VisioEvent::UnreadCountChanged(count));
-                                    }
                                 }
                                 Err(e) => {
                                     tracing::warn!("Failed to read chat text stream: {e}");
@@ -864,60 +2517,46 @@ impl RoomManager {
                         payload.len()
                     );
 
-                    // Handle reactions from Meet web client (no topic, reliable data)
-                    if let Ok(text) = std::str::from_utf8(&payload)
-                        && let Ok(json) = serde_json::from_str::<serde_json::Value>(text)
-                        && json["type"].as_str() == Some("reactionReceived")
-                    {
-                        if let Some(emoji) = json["data"]["emoji"].as_str() {
-                            let sender_name = participant
-                                .as_ref()
-                                .map(|p| p.name().to_string())
-                                .unwrap_or_default();
+                    match topic.as_deref() {
+                        Some(POLLS_TOPIC) => polls.handle_incoming(&payload, &psid).await,
+                        Some(topic) => data_channel.handle_incoming(topic, &payload, &psid),
+                        None => {}
+                    }
+
+                    // Reactions (no topic) and legacy chat messages from Meet
+                    // web. New clients send chat over both the Stream API and
+                    // "lk-chat-topic" with "ignoreLegacy", already handled in
+                    // TextStreamOpened.
+                    let sender_name = participant
+                        .as_ref()
+                        .map(|p| p.name().to_string())
+                        .unwrap_or_default();
+                    match payload_guard.parse(topic.as_deref(), &payload, &psid) {
+                        Some(IncomingPayload::Reaction { emoji }) => {
                             emitter.emit(VisioEvent::ReactionReceived {
-                                participant_sid: psid.clone(),
+                                participant_sid: psid,
                                 participant_name: sender_name,
-                                emoji: emoji.to_string(),
+                                emoji,
                             });
                         }
-                        continue;
-                    }
-
-                    // Legacy fallback: chat messages via DataReceived with topic "lk-chat-topic"
-                    // New clients send both Stream + legacy; "ignoreLegacy" flag means
-                    // the TextStreamOpened handler already processed it.
-                    if topic_str == "lk-chat-topic"
-                        && let Ok(text) = std::str::from_utf8(&payload)
-                        && let Ok(json) = serde_json::from_str::<serde_json::Value>(text)
-                    {
-                        // Skip if sender uses Stream API (we handle it in TextStreamOpened)
-                        if json["ignoreLegacy"].as_bool() == Some(true) {
-                            tracing::debug!("Skipping legacy DataReceived (ignoreLegacy=true)");
-                            continue;
-                        }
-
-                        let sender_name = participant
-                            .as_ref()
-                            .map(|p| p.name().to_string())
-                            .unwrap_or_default();
-
-                        let msg = ChatMessage {
-                            id: json["id"].as_str().unwrap_or("").to_string(),
-                            sender_sid: psid.clone(),
-                            sender_name,
-                            text: json["message"].as_str().unwrap_or("").to_string(),
-                            timestamp_ms: json["timestamp"].as_u64().unwrap_or(0),
-                        };
-
-                        if !msg.text.is_empty() {
+                        Some(IncomingPayload::Chat {
+                            id,
+                            text,
+                            timestamp_ms,
+                        }) => {
+                            let msg = ChatMessage {
+                                id,
+                                sender_sid: psid.clone(),
+                                sender_name,
+                                text,
+                                timestamp_ms,
+                                kind: ChatMessageKind::Text,
+                            };
                             tracing::info!("Chat via DataReceived: from={psid} text={}", msg.text);
                             messages.lock().await.push(msg.clone());
                             emitter.emit(VisioEvent::ChatMessageReceived(msg));
-                            if !chat_open.load(Ordering::Relaxed) {
-                                let count = unread_count.fetch_add(1, Ordering::Relaxed) + 1;
-                                emitter.emit(VisioEvent::UnreadCountChanged(count));
-                            }
                         }
+                        None => {}
                     }
                 }
 
@@ -927,8 +2566,74 @@ impl RoomManager {
             }
         }
 
+        if let Some(task) = speaker_expiry {
+            task.abort();
+        }
+        // The room closed without a `Disconnected` event.
+        for (_, handle) in audio_stream_tasks.drain() {
+            handle.abort();
+        }
         tracing::info!("room event loop ended");
     }
+
+    /// Forget every subscribed video track, reporting each with
+    /// `TrackUnsubscribed` so the UI stops its renderer.
+    async fn unsubscribe_all(
+        subscribed_tracks: &Mutex<HashMap<String, RemoteVideoTrack>>,
+        emitter: &EventEmitter,
+    ) {
+        let sids: Vec<String> = subscribed_tracks
+            .lock()
+            .await
+            .drain()
+            .map(|(sid, _)| sid)
+            .collect();
+        for sid in sids {
+            emitter.emit(VisioEvent::TrackUnsubscribed(sid));
+        }
+    }
+
+    /// Drop held speakers once their hold time expires, emitting the
+    /// smoothed list whenever it changes.
+    fn spawn_speaker_expiry(
+        participants: Arc<Mutex<ParticipantManager>>,
+        emitter: EventEmitter,
+        clock: Arc<dyn Clock>,
+        mut deadline: Instant,
+    ) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            loop {
+                clock
+                    .sleep(deadline.saturating_duration_since(clock.now()))
+                    .await;
+                let (changed, active, next) = {
+                    let mut pm = participants.lock().await;
+                    let changed = pm.refresh_active_speakers(clock.now());
+                    (
+                        changed,
+                        pm.active_speakers().to_vec(),
+                        pm.next_speaker_expiry(),
+                    )
+                };
+                if changed {
+                    emitter.emit(VisioEvent::ActiveSpeakersChanged(active));
+                }
+                match next {
+                    Some(next) => deadline = next,
+                    None => break,
+                }
+            }
+        })
+    }
+}
+
+pub(crate) fn connection_quality(quality: LkConnectionQuality) -> ConnectionQuality {
+    match quality {
+        LkConnectionQuality::Excellent => ConnectionQuality::Excellent,
+        LkConnectionQuality::Good => ConnectionQuality::Good,
+        LkConnectionQuality::Poor => ConnectionQuality::Poor,
+        LkConnectionQuality::Lost => ConnectionQuality::Lost,
+    }
 }
 
 #[cfg(test)]
@@ -957,6 +2662,66 @@ mod tests {
         assert!(controls.is_camera_enabled().await);
     }
 
+    #[tokio::test]
+    async fn toggle_microphone_flips_the_shared_state() {
+        let rm = RoomManager::new();
+        rm.controls().set_queue_until_connected(true);
+
+        rm.dispatch_action(MeetingAction::ToggleMicrophone)
+            .await
+            .unwrap();
+        assert_eq!(rm.controls().pending_media().microphone, Some(true));
+        rm.dispatch_action(MeetingAction::ToggleMicrophone)
+            .await
+            .unwrap();
+        assert_eq!(rm.controls().pending_media().microphone, Some(false));
+
+        // A published, unmuted mic is seen by every controls instance.
+        rm.controls().set_queue_until_connected(false);
+        rm.controls().set_queue_until_connected(true);
+        *rm.mic_enabled.lock().await = true;
+        assert!(rm.controls().is_microphone_enabled().await);
+        rm.dispatch_action(MeetingAction::ToggleMicrophone)
+            .await
+            .unwrap();
+        assert_eq!(rm.controls().pending_media().microphone, Some(false));
+    }
+
+    #[tokio::test]
+    async fn switched_tracks_keep_their_publish_options() {
+        let rm = RoomManager::new();
+        let controls = rm.controls();
+        controls.set_audio_preset(AudioPreset::Music).await.unwrap();
+        controls.set_video_codec(VideoCodec::H264);
+        controls
+            .set_video_content_hint(VideoContentHint::Detail)
+            .await;
+
+        // What `switch_room` republishes with.
+        let mic = rm.republish_options(LkTrackSource::Microphone).await;
+        assert!(!mic.dtx && !mic.red);
+        assert_eq!(mic.audio_encoding.map(|e| e.max_bitrate), Some(128_000));
+        let camera = rm.republish_options(LkTrackSource::Camera).await;
+        assert!(matches!(camera.source, LkTrackSource::Camera));
+        assert!(matches!(
+            camera.video_codec,
+            livekit::options::VideoCodec::H264
+        ));
+        assert!(!camera.simulcast);
+        assert_eq!(
+            camera.video_encoding.map(|e| e.max_bitrate),
+            Some(2_500_000)
+        );
+    }
+
+    #[test]
+    fn reconnect_delay_doubles_up_to_the_cap() {
+        let policy = ReconnectPolicy::default();
+        let delays: Vec<u64> = (1..=7).map(|a| policy.delay(a).as_secs()).collect();
+        assert_eq!(delays, vec![1, 2, 4, 8, 16, 30, 30]);
+        assert_eq!(policy.delay(64), policy.max_delay);
+    }
+
     #[tokio::test]
     async fn initial_connection_state_is_disconnected() {
         let rm = RoomManager::new();
@@ -970,4 +2735,237 @@ mod tests {
         let participants = rm.participants().await;
         assert!(participants.is_empty());
     }
+
+    #[tokio::test]
+    async fn session_teardown_resets_the_meeting_state() {
+        let rm = RoomManager::new();
+        let flags = FeatureFlags {
+            whiteboard: !FeatureFlags::default().whiteboard,
+            ..FeatureFlags::default()
+        };
+        *rm.feature_flags.lock().await = flags.clone();
+        rm.emitter
+            .emit(VisioEvent::ChatMessageReceived(ChatMessage {
+                id: "m1".into(),
+                sender_sid: "PA_1".into(),
+                sender_name: "Ada".into(),
+                text: "hello".into(),
+                timestamp_ms: 0,
+                kind: ChatMessageKind::Text,
+            }));
+        assert_eq!(rm.chat_unread().normal, 1);
+
+        // A lost connection keeps the session for `reconnect`.
+        RoomManager::reset_transport_state(&rm.session_state()).await;
+        assert_eq!(rm.feature_flags().await, flags);
+        assert_eq!(rm.chat_unread().normal, 1);
+
+        RoomManager::reset_session_state(&rm.session_state()).await;
+        assert_eq!(rm.feature_flags().await, FeatureFlags::default());
+        assert_eq!(rm.chat_unread(), ChatUnread::default());
+        assert!(rm.local_permissions().await.is_none());
+        assert!(rm.time_remaining().is_none());
+    }
+
+    #[tokio::test]
+    async fn dispatches_actions_to_controls() {
+        let rm = RoomManager::new();
+        rm.dispatch_action(MeetingAction::ToggleChat).await.unwrap();
+        assert!(rm.is_chat_open());
+        rm.dispatch_action(MeetingAction::ToggleChat).await.unwrap();
+        assert!(!rm.is_chat_open());
+
+        assert!(
+            rm.dispatch_action(MeetingAction::ToggleHandRaise)
+                .await
+                .is_err()
+        );
+
+        let controls = rm.controls();
+        controls.set_queue_until_connected(true);
+        rm.dispatch_action(MeetingAction::ToggleMicrophone)
+            .await
+            .unwrap();
+        rm.dispatch_action(MeetingAction::PushToTalk { pressed: false })
+            .await
+            .unwrap();
+        assert_eq!(controls.pending_media().microphone, Some(false));
+    }
+
+    #[tokio::test]
+    async fn media_buttons_toggle_the_microphone_and_hang_up() {
+        use crate::clock::MockClock;
+        use crate::media_buttons::MEDIA_BUTTON_DEBOUNCE;
+        use crate::meet_api::MockMeetApi;
+
+        let clock = Arc::new(MockClock::new());
+        let rm = RoomManager::with_clock(Arc::new(MockMeetApi::new()), clock.clone());
+        assert!(
+            !rm.handle_media_button(MediaButton::PlayPause)
+                .await
+                .unwrap()
+        );
+
+        rm.set_connection_state(ConnectionState::Connected).await;
+        *rm.mic_enabled.lock().await = true;
+        let controls = rm.controls();
+        controls.set_queue_until_connected(true);
+        assert!(
+            rm.handle_media_button(MediaButton::PlayPause)
+                .await
+                .unwrap()
+        );
+        // The same press delivered twice.
+        assert!(
+            !rm.handle_media_button(MediaButton::PlayPause)
+                .await
+                .unwrap()
+        );
+        assert_eq!(controls.pending_media().microphone, Some(false));
+
+        clock.advance(MEDIA_BUTTON_DEBOUNCE);
+        assert!(
+            rm.handle_media_button(MediaButton::PlayPause)
+                .await
+                .unwrap()
+        );
+        assert_eq!(controls.pending_media().microphone, Some(true));
+
+        assert!(rm.handle_media_button(MediaButton::Hangup).await.unwrap());
+        assert_eq!(rm.connection_state().await, ConnectionState::Disconnected);
+    }
+
+    #[tokio::test]
+    async fn entry_requests_are_polled_on_the_clock() {
+        use crate::clock::MockClock;
+        use crate::meet_api::MockMeetApi;
+
+        let api = Arc::new(MockMeetApi::new());
+        api.push_response(
+            200,
+            r#"{"id":"room-uuid","slug":"abc-defg-hij","access_level":"restricted"}"#,
+        );
+        api.push_entry_status("waiting", Some("lobby-1"));
+        api.push_entry_status("denied", None);
+        let clock = Arc::new(MockClock::new());
+        let rm = Arc::new(RoomManager::with_clock(api.clone(), clock.clone()));
+
+        let connect = tokio::spawn({
+            let rm = rm.clone();
+            async move {
+                rm.connect("https://meet.example.com/abc-defg-hij", &JoinOptions::new())
+                    .await
+            }
+        });
+        while clock.pending_sleeps() == 0 {
+            tokio::task::yield_now().await;
+        }
+        assert_eq!(api.entry_requests().len(), 1);
+
+        // No real second passes: the poll waits for the clock.
+        clock.advance(ENTRY_POLL_INTERVAL);
+        let err = connect.await.unwrap().unwrap_err();
+        assert!(matches!(err, VisioError::Auth(_)), "got {err:?}");
+        assert_eq!(api.entry_requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn media_on_join_yields_to_queued_toggles() {
+        let rm = RoomManager::new();
+        let controls = rm.controls();
+        controls.set_queue_until_connected(true);
+        controls.set_camera_enabled(false).await.unwrap();
+
+        let options = JoinOptions::new().mic_on(true).camera_on(true);
+        rm.request_media_on_connect(options.media());
+        assert_eq!(
+            controls.pending_media(),
+            PendingMedia {
+                microphone: Some(true),
+                camera: Some(false),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn failed_join_does_not_queue_media() {
+        use crate::meet_api::MockMeetApi;
+
+        let api = Arc::new(MockMeetApi::new());
+        // Nothing listens there: the LiveKit connection is refused.
+        api.push_token("http://127.0.0.1:1", "jwt-token");
+        let rm = RoomManager::with_meet_api(api);
+
+        let options = JoinOptions::new().mic_on(true).camera_on(true);
+        let meet_url = "https://meet.example.com/abc-defg-hij";
+        assert!(rm.connect(meet_url, &options).await.is_err());
+        assert!(rm.controls().pending_media().is_empty());
+    }
+
+    #[tokio::test]
+    async fn power_mode_caps_capture_and_emits_once() {
+        struct Capture(std::sync::Mutex<Vec<PowerMode>>);
+        impl VisioEventListener for Capture {
+            fn on_event(&self, event: VisioEvent) {
+                if let VisioEvent::PowerModeChanged(mode) = event {
+                    self.0.lock().unwrap().push(mode);
+                }
+            }
+        }
+        let rm = RoomManager::new();
+        let capture = Arc::new(Capture(std::sync::Mutex::new(Vec::new())));
+        rm.add_listener(capture.clone());
+
+        rm.set_power_mode(PowerMode::Saver).await;
+        rm.set_power_mode(PowerMode::Saver).await;
+        assert_eq!(rm.power_mode(), PowerMode::Saver);
+        let constraints = rm.controls().capture_constraints();
+        assert_eq!((constraints.width, constraints.max_fps), (640, 15));
+
+        rm.set_power_mode(PowerMode::Normal).await;
+        assert_eq!(
+            rm.controls().capture_constraints(),
+            CaptureConstraints::default()
+        );
+        assert_eq!(
+            *capture.0.lock().unwrap(),
+            [PowerMode::Saver, PowerMode::Normal]
+        );
+    }
+
+    #[tokio::test]
+    async fn thermal_state_reduces_and_restores_quality() {
+        struct Capture(std::sync::Mutex<Vec<String>>);
+        impl VisioEventListener for Capture {
+            fn on_event(&self, event: VisioEvent) {
+                match event {
+                    VisioEvent::QualityReduced {
+                        reason,
+                        constraints,
+                    } => self
+                        .0
+                        .lock()
+                        .unwrap()
+                        .push(format!("{reason:?} {}", constraints.width)),
+                    VisioEvent::QualityRestored => self.0.lock().unwrap().push("restored".into()),
+                    _ => {}
+                }
+            }
+        }
+        let rm = RoomManager::new();
+        let capture = Arc::new(Capture(std::sync::Mutex::new(Vec::new())));
+        rm.add_listener(capture.clone());
+
+        rm.report_thermal_state(ThermalState::Serious).await;
+        rm.report_thermal_state(ThermalState::Serious).await;
+        rm.report_thermal_state(ThermalState::Critical).await;
+        rm.set_power_mode(PowerMode::Saver).await;
+        rm.report_thermal_state(ThermalState::Nominal).await;
+        // Battery saver still applies.
+        assert_eq!(rm.controls().capture_constraints().width, 640);
+        assert_eq!(
+            *capture.0.lock().unwrap(),
+            ["Thermal(Serious) 640", "Thermal(Critical) 320", "restored"]
+        );
+    }
 }
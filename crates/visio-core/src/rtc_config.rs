@@ -0,0 +1,105 @@
+//! ICE configuration overrides.
+//!
+//! By default LiveKit uses the STUN/TURN servers sent by the SFU in the
+//! join response. Some enterprise networks only let media through a
+//! specific TURN relay; `RtcConfigOverride` replaces the server list and
+//! can force relay-only transport. It is stored in `Settings` and applied
+//! by `RoomManager` to every subsequent connection.
+
+use livekit::webrtc::peer_connection_factory::{IceServer, IceTransportsType, RtcConfiguration};
+use serde::{Deserialize, Serialize};
+
+use crate::errors::VisioError;
+
+/// A STUN or TURN server.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct IceServerConfig {
+    /// `stun:`, `turn:` or `turns:` URLs.
+    pub urls: Vec<String>,
+    #[serde(default)]
+    pub username: String,
+    #[serde(default)]
+    pub credential: String,
+}
+
+/// ICE settings applied on top of those provided by the SFU.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct RtcConfigOverride {
+    /// Servers used instead of the SFU-provided ones. Empty keeps the
+    /// SFU defaults.
+    #[serde(default)]
+    pub ice_servers: Vec<IceServerConfig>,
+    /// Only use relayed (TURN) candidates.
+    #[serde(default)]
+    pub relay_only: bool,
+}
+
+impl RtcConfigOverride {
+    /// Check that every server URL uses a STUN/TURN scheme.
+    pub fn validate(&self) -> Result<(), VisioError> {
+        for url in self.ice_servers.iter().flat_map(|s| &s.urls) {
+            if !["stun:", "turn:", "turns:"]
+                .iter()
+                .any(|scheme| url.starts_with(scheme))
+            {
+                return Err(VisioError::InvalidUrl(format!(
+                    "not a STUN/TURN URL: '{url}'"
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    pub(crate) fn apply(&self, config: &mut RtcConfiguration) {
+        if !self.ice_servers.is_empty() {
+            config.ice_servers = self
+                .ice_servers
+                .iter()
+                .map(|s| IceServer {
+                    urls: s.urls.clone(),
+                    username: s.username.clone(),
+                    password: s.credential.clone(),
+                })
+                .collect();
+        }
+        if self.relay_only {
+            config.ice_transport_type = IceTransportsType::Relay;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn override_replaces_servers_and_forces_relay() {
+        let mut config = RtcConfiguration::default();
+        RtcConfigOverride::default().apply(&mut config);
+        assert!(config.ice_servers.is_empty());
+        assert_eq!(config.ice_transport_type, IceTransportsType::All);
+
+        let over = RtcConfigOverride {
+            ice_servers: vec![IceServerConfig {
+                urls: vec!["turns:turn.example.com:443?transport=tcp".into()],
+                username: "user".into(),
+                credential: "secret".into(),
+            }],
+            relay_only: true,
+        };
+        over.validate().unwrap();
+        over.apply(&mut config);
+        assert_eq!(config.ice_servers.len(), 1);
+        assert_eq!(config.ice_servers[0].password, "secret");
+        assert_eq!(config.ice_transport_type, IceTransportsType::Relay);
+
+        let invalid = RtcConfigOverride {
+            ice_servers: vec![IceServerConfig {
+                urls: vec!["https://turn.example.com".into()],
+                ..Default::default()
+            }],
+            relay_only: false,
+        };
+        assert!(invalid.validate().is_err());
+    }
+}
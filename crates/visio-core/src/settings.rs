@@ -1,8 +1,22 @@
+use std::collections::HashMap;
 use std::path::PathBuf;
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 use serde::{Deserialize, Serialize};
 
+use crate::audio_ducking::DuckingConfig;
+use crate::auto_gain::AutoGainConfig;
+use crate::controls::AudioPreset;
+use crate::data_paths::DataPaths;
+use crate::events::{EventEmitter, VisioEvent, VisioEventListener};
+use crate::hand_raise::AutoLowerConfig;
+use crate::idle::IdleConfig;
+use crate::overlay::OverlayCorner;
+use crate::quality_policy::QualityPolicyConfig;
+use crate::rtc_config::RtcConfigOverride;
+use crate::video_privacy::VideoPrivacyStyle;
+
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct Settings {
     #[serde(default)]
@@ -25,6 +39,77 @@ pub struct Settings {
     pub notification_message_received: bool,
     #[serde(default = "default_background_mode")]
     pub background_mode: String,
+    /// Text drawn on the outgoing video (see `VideoOverlay`), unless the
+    /// instance requires its own watermark.
+    #[serde(default)]
+    pub video_overlay_text: Option<String>,
+    #[serde(default)]
+    pub video_overlay_corner: OverlayCorner,
+    /// What replaces the outgoing video in privacy mode (see
+    /// `VideoPrivacy`).
+    #[serde(default)]
+    pub video_privacy_style: VideoPrivacyStyle,
+    #[serde(default = "default_true")]
+    pub hand_auto_lower_enabled: bool,
+    #[serde(default = "default_hand_auto_lower_delay_secs")]
+    pub hand_auto_lower_delay_secs: u32,
+    /// Opt-in anonymized call metrics (see `MetricsReporter`).
+    #[serde(default)]
+    pub metrics_enabled: bool,
+    #[serde(default)]
+    pub metrics_endpoint: Option<String>,
+    /// Let call feedback carry the call summary and, on request, the
+    /// event journal (see `FeedbackService`).
+    #[serde(default = "default_true")]
+    pub feedback_diagnostics_enabled: bool,
+    /// Custom STUN/TURN servers and relay policy.
+    #[serde(default)]
+    pub rtc_config: RtcConfigOverride,
+    /// Preferred devices, as platform device identifiers. Shells fall back
+    /// to the system default when the device is no longer present.
+    #[serde(default)]
+    pub preferred_microphone_id: Option<String>,
+    #[serde(default)]
+    pub preferred_camera_id: Option<String>,
+    #[serde(default)]
+    pub preferred_speaker_id: Option<String>,
+    /// Lower remote audio while the local user speaks (see
+    /// `AudioDucker`), by `audio_ducking_level` (0.0 to 1.0).
+    #[serde(default)]
+    pub audio_ducking_enabled: bool,
+    #[serde(default = "default_audio_ducking_level")]
+    pub audio_ducking_level: f32,
+    /// Bring every remote participant to the same loudness (see
+    /// `AutoGain`), around `auto_gain_target_lufs`.
+    #[serde(default)]
+    pub auto_gain_enabled: bool,
+    #[serde(default = "default_auto_gain_target_lufs")]
+    pub auto_gain_target_lufs: f32,
+    /// Microphone processing and encoding (see `AudioPreset`).
+    #[serde(default)]
+    pub audio_preset: AudioPreset,
+    /// Idle policy (see `IdleMonitor`): minutes alone in the room before
+    /// `AloneInRoom`, and without any activity before
+    /// `InactivityDetected`. 0 disables the check.
+    #[serde(default = "default_alone_timeout_mins")]
+    pub alone_timeout_mins: u32,
+    #[serde(default)]
+    pub auto_leave_when_alone: bool,
+    #[serde(default = "default_inactivity_timeout_mins")]
+    pub inactivity_timeout_mins: u32,
+    /// Bad-network fallback (see `QualityPolicy`): seconds of poor
+    /// quality before the camera, and remote video with
+    /// `quality_policy_pause_remote_video`, are turned off.
+    #[serde(default = "default_true")]
+    pub quality_policy_enabled: bool,
+    #[serde(default = "default_quality_policy_delay_secs")]
+    pub quality_policy_delay_secs: u32,
+    #[serde(default)]
+    pub quality_policy_pause_remote_video: bool,
+    /// Free-form preferences owned by the platform shells, persisted
+    /// without a schema change in core.
+    #[serde(default)]
+    pub extras: HashMap<String, String>,
 }
 
 fn default_meet_instances() -> Vec<String> {
@@ -43,6 +128,30 @@ fn default_true() -> bool {
     true
 }
 
+fn default_hand_auto_lower_delay_secs() -> u32 {
+    3
+}
+
+fn default_audio_ducking_level() -> f32 {
+    DuckingConfig::default().level
+}
+
+fn default_auto_gain_target_lufs() -> f32 {
+    AutoGainConfig::default().target_lufs
+}
+
+fn default_alone_timeout_mins() -> u32 {
+    5
+}
+
+fn default_inactivity_timeout_mins() -> u32 {
+    60
+}
+
+fn default_quality_policy_delay_secs() -> u32 {
+    QualityPolicyConfig::default().delay.as_secs() as u32
+}
+
 impl Default for Settings {
     fn default() -> Self {
         Self {
@@ -56,52 +165,125 @@ impl Default for Settings {
             notification_hand_raised: true,
             notification_message_received: true,
             background_mode: "off".to_string(),
+            video_overlay_text: None,
+            video_overlay_corner: OverlayCorner::BottomRight,
+            video_privacy_style: VideoPrivacyStyle::Blur,
+            hand_auto_lower_enabled: true,
+            hand_auto_lower_delay_secs: default_hand_auto_lower_delay_secs(),
+            metrics_enabled: false,
+            metrics_endpoint: None,
+            feedback_diagnostics_enabled: true,
+            rtc_config: RtcConfigOverride::default(),
+            preferred_microphone_id: None,
+            preferred_camera_id: None,
+            preferred_speaker_id: None,
+            audio_ducking_enabled: false,
+            audio_ducking_level: default_audio_ducking_level(),
+            auto_gain_enabled: false,
+            auto_gain_target_lufs: default_auto_gain_target_lufs(),
+            audio_preset: AudioPreset::Voice,
+            alone_timeout_mins: default_alone_timeout_mins(),
+            auto_leave_when_alone: false,
+            inactivity_timeout_mins: default_inactivity_timeout_mins(),
+            quality_policy_enabled: true,
+            quality_policy_delay_secs: default_quality_policy_delay_secs(),
+            quality_policy_pause_remote_video: false,
+            extras: HashMap::new(),
         }
     }
 }
 
+impl Settings {
+    /// Hand auto-lower behaviour described by these settings.
+    pub fn hand_auto_lower(&self) -> AutoLowerConfig {
+        AutoLowerConfig {
+            enabled: self.hand_auto_lower_enabled,
+            delay: Duration::from_secs(self.hand_auto_lower_delay_secs as u64),
+        }
+    }
+
+    /// Audio ducking behaviour described by these settings.
+    pub fn audio_ducking(&self) -> DuckingConfig {
+        DuckingConfig {
+            enabled: self.audio_ducking_enabled,
+            level: self.audio_ducking_level,
+        }
+    }
+
+    /// Playout loudness normalization described by these settings.
+    pub fn auto_gain(&self) -> AutoGainConfig {
+        AutoGainConfig {
+            enabled: self.auto_gain_enabled,
+            target_lufs: self.auto_gain_target_lufs,
+        }
+    }
+
+    /// Idle policy described by these settings.
+    pub fn idle(&self) -> IdleConfig {
+        let minutes = |m: u32| (m > 0).then(|| Duration::from_secs(u64::from(m) * 60));
+        IdleConfig {
+            alone_timeout: minutes(self.alone_timeout_mins),
+            auto_leave_when_alone: self.auto_leave_when_alone,
+            inactivity_timeout: minutes(self.inactivity_timeout_mins),
+        }
+    }
+
+    /// Bad-network fallback described by these settings.
+    pub fn quality_policy(&self) -> QualityPolicyConfig {
+        QualityPolicyConfig {
+            enabled: self.quality_policy_enabled,
+            delay: Duration::from_secs(u64::from(self.quality_policy_delay_secs)),
+            pause_remote_video: self.quality_policy_pause_remote_video,
+        }
+    }
+}
+
+/// Persistent settings. Every setter saves the file and, when a value
+/// actually changed, emits `VisioEvent::SettingsChanged` with the changed
+/// keys so other windows and platforms can refresh.
 pub struct SettingsStore {
     settings: Mutex<Settings>,
     file_path: PathBuf,
+    emitter: EventEmitter,
 }
 
 impl SettingsStore {
     pub fn new(data_dir: &str) -> Self {
-        let file_path = PathBuf::from(data_dir).join("settings.json");
+        let file_path = DataPaths::new(data_dir).settings_file();
         let settings = Self::load(&file_path);
         Self {
             settings: Mutex::new(settings),
             file_path,
+            emitter: EventEmitter::new(),
         }
     }
 
+    pub fn add_listener(&self, listener: Arc<dyn VisioEventListener>) {
+        self.emitter.add_listener(listener);
+    }
+
     pub fn get(&self) -> Settings {
         self.settings.lock().unwrap_or_else(|e| e.into_inner()).clone()
     }
 
     pub fn set_display_name(&self, name: Option<String>) {
-        self.settings.lock().unwrap_or_else(|e| e.into_inner()).display_name = name;
-        self.save();
+        self.update(|s| s.display_name = name);
     }
 
     pub fn set_language(&self, lang: Option<String>) {
-        self.settings.lock().unwrap_or_else(|e| e.into_inner()).language = lang;
-        self.save();
+        self.update(|s| s.language = lang);
     }
 
     pub fn set_mic_enabled_on_join(&self, enabled: bool) {
-        self.settings.lock().unwrap_or_else(|e| e.into_inner()).mic_enabled_on_join = enabled;
-        self.save();
+        self.update(|s| s.mic_enabled_on_join = enabled);
     }
 
     pub fn set_camera_enabled_on_join(&self, enabled: bool) {
-        self.settings.lock().unwrap_or_else(|e| e.into_inner()).camera_enabled_on_join = enabled;
-        self.save();
+        self.update(|s| s.camera_enabled_on_join = enabled);
     }
 
     pub fn set_theme(&self, theme: String) {
-        self.settings.lock().unwrap_or_else(|e| e.into_inner()).theme = theme;
-        self.save();
+        self.update(|s| s.theme = theme);
     }
 
     pub fn get_meet_instances(&self) -> Vec<String> {
@@ -109,23 +291,109 @@ impl SettingsStore {
     }
 
     pub fn set_meet_instances(&self, instances: Vec<String>) {
-        self.settings.lock().unwrap_or_else(|e| e.into_inner()).meet_instances = instances;
-        self.save();
+        self.update(|s| s.meet_instances = instances);
     }
 
     pub fn set_notification_participant_join(&self, enabled: bool) {
-        self.settings.lock().unwrap_or_else(|e| e.into_inner()).notification_participant_join = enabled;
-        self.save();
+        self.update(|s| s.notification_participant_join = enabled);
     }
 
     pub fn set_notification_hand_raised(&self, enabled: bool) {
-        self.settings.lock().unwrap_or_else(|e| e.into_inner()).notification_hand_raised = enabled;
-        self.save();
+        self.update(|s| s.notification_hand_raised = enabled);
     }
 
     pub fn set_notification_message_received(&self, enabled: bool) {
-        self.settings.lock().unwrap_or_else(|e| e.into_inner()).notification_message_received = enabled;
-        self.save();
+        self.update(|s| s.notification_message_received = enabled);
+    }
+
+    pub fn set_hand_auto_lower(&self, enabled: bool, delay_secs: u32) {
+        self.update(|s| {
+            s.hand_auto_lower_enabled = enabled;
+            s.hand_auto_lower_delay_secs = delay_secs;
+        });
+    }
+
+    pub fn set_metrics(&self, enabled: bool, endpoint: Option<String>) {
+        self.update(|s| {
+            s.metrics_enabled = enabled;
+            s.metrics_endpoint = endpoint;
+        });
+    }
+
+    pub fn set_feedback_diagnostics_enabled(&self, enabled: bool) {
+        self.update(|s| s.feedback_diagnostics_enabled = enabled);
+    }
+
+    pub fn set_audio_ducking(&self, enabled: bool, level: f32) {
+        self.update(|s| {
+            s.audio_ducking_enabled = enabled;
+            s.audio_ducking_level = level.clamp(0.0, 1.0);
+        });
+    }
+
+    /// `target_lufs` is kept between -40 and -6.
+    pub fn set_auto_gain(&self, enabled: bool, target_lufs: f32) {
+        self.update(|s| {
+            s.auto_gain_enabled = enabled;
+            s.auto_gain_target_lufs = target_lufs.clamp(-40.0, -6.0);
+        });
+    }
+
+    pub fn set_audio_preset(&self, preset: AudioPreset) {
+        self.update(|s| s.audio_preset = preset);
+    }
+
+    pub fn set_idle(
+        &self,
+        alone_timeout_mins: u32,
+        auto_leave_when_alone: bool,
+        inactivity_timeout_mins: u32,
+    ) {
+        self.update(|s| {
+            s.alone_timeout_mins = alone_timeout_mins;
+            s.auto_leave_when_alone = auto_leave_when_alone;
+            s.inactivity_timeout_mins = inactivity_timeout_mins;
+        });
+    }
+
+    pub fn set_quality_policy(&self, enabled: bool, delay_secs: u32, pause_remote_video: bool) {
+        self.update(|s| {
+            s.quality_policy_enabled = enabled;
+            s.quality_policy_delay_secs = delay_secs;
+            s.quality_policy_pause_remote_video = pause_remote_video;
+        });
+    }
+
+    pub fn set_rtc_config(&self, config: RtcConfigOverride) {
+        self.update(|s| s.rtc_config = config);
+    }
+
+    pub fn set_preferred_microphone_id(&self, id: Option<String>) {
+        self.update(|s| s.preferred_microphone_id = id);
+    }
+
+    pub fn set_preferred_camera_id(&self, id: Option<String>) {
+        self.update(|s| s.preferred_camera_id = id);
+    }
+
+    pub fn set_preferred_speaker_id(&self, id: Option<String>) {
+        self.update(|s| s.preferred_speaker_id = id);
+    }
+
+    pub fn get_extra(&self, key: &str) -> Option<String> {
+        self.settings.lock().unwrap_or_else(|e| e.into_inner()).extras.get(key).cloned()
+    }
+
+    /// Set an extra preference; `None` removes it.
+    pub fn set_extra(&self, key: &str, value: Option<String>) {
+        self.update(|s| match value {
+            Some(value) => {
+                s.extras.insert(key.to_string(), value);
+            }
+            None => {
+                s.extras.remove(key);
+            }
+        });
     }
 
     pub fn get_background_mode(&self) -> String {
@@ -133,8 +401,34 @@ impl SettingsStore {
     }
 
     pub fn set_background_mode(&self, mode: String) {
-        self.settings.lock().unwrap_or_else(|e| e.into_inner()).background_mode = mode;
+        self.update(|s| s.background_mode = mode);
+    }
+
+    pub fn set_video_privacy_style(&self, style: VideoPrivacyStyle) {
+        self.update(|s| s.video_privacy_style = style);
+    }
+
+    pub fn set_video_overlay(&self, text: Option<String>, corner: OverlayCorner) {
+        self.update(|s| {
+            s.video_overlay_text = text.filter(|t| !t.trim().is_empty());
+            s.video_overlay_corner = corner;
+        });
+    }
+
+    /// Apply `f`, save, and emit `SettingsChanged` for the keys whose
+    /// value changed.
+    fn update(&self, f: impl FnOnce(&mut Settings)) {
+        let changed_keys = {
+            let mut settings = self.settings.lock().unwrap_or_else(|e| e.into_inner());
+            let before = serde_json::to_value(&*settings).unwrap_or_default();
+            f(&mut settings);
+            let after = serde_json::to_value(&*settings).unwrap_or_default();
+            changed_keys(&before, &after)
+        };
         self.save();
+        if !changed_keys.is_empty() {
+            self.emitter.emit(VisioEvent::SettingsChanged { keys: changed_keys });
+        }
     }
 
     fn save(&self) {
@@ -155,6 +449,18 @@ impl SettingsStore {
     }
 }
 
+/// Top-level keys of the serialized settings that differ.
+fn changed_keys(before: &serde_json::Value, after: &serde_json::Value) -> Vec<String> {
+    let (Some(before), Some(after)) = (before.as_object(), after.as_object()) else {
+        return Vec::new();
+    };
+    after
+        .iter()
+        .filter(|(key, value)| before.get(*key) != Some(*value))
+        .map(|(key, _)| key.clone())
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -365,4 +671,202 @@ mod tests {
             vec!["meet.numerique.gouv.fr".to_string()]
         );
     }
+
+    #[test]
+    fn test_preferred_devices_persist() {
+        let dir = temp_dir();
+        let path = dir.path().to_str().unwrap();
+        {
+            let store = SettingsStore::new(path);
+            assert_eq!(store.get().preferred_microphone_id, None);
+            store.set_preferred_microphone_id(Some("USB Audio".to_string()));
+            store.set_preferred_camera_id(Some("0x1420000005ac8600".to_string()));
+            store.set_preferred_speaker_id(Some("Headphones".to_string()));
+            store.set_preferred_speaker_id(None);
+        }
+        let s = SettingsStore::new(path).get();
+        assert_eq!(s.preferred_microphone_id.as_deref(), Some("USB Audio"));
+        assert_eq!(s.preferred_camera_id.as_deref(), Some("0x1420000005ac8600"));
+        assert_eq!(s.preferred_speaker_id, None);
+    }
+
+    #[test]
+    fn test_extras_persist_and_theme_round_trips() {
+        let dir = temp_dir();
+        let path = dir.path().to_str().unwrap();
+        {
+            let store = SettingsStore::new(path);
+            assert_eq!(store.get_extra("android.pip_enabled"), None);
+            store.set_extra("android.pip_enabled", Some("true".to_string()));
+            store.set_extra("desktop.window_width", Some("1280".to_string()));
+            store.set_extra("desktop.window_width", None);
+            store.set_theme("dark".to_string());
+        }
+        let store = SettingsStore::new(path);
+        assert_eq!(store.get_extra("android.pip_enabled").as_deref(), Some("true"));
+        assert_eq!(store.get_extra("desktop.window_width"), None);
+        assert_eq!(store.get().theme, "dark");
+    }
+
+    struct KeysCapture(Mutex<Vec<Vec<String>>>);
+
+    impl VisioEventListener for KeysCapture {
+        fn on_event(&self, event: VisioEvent) {
+            if let VisioEvent::SettingsChanged { keys } = event {
+                self.0.lock().unwrap().push(keys);
+            }
+        }
+    }
+
+    #[test]
+    fn test_setters_emit_changed_keys() {
+        let dir = temp_dir();
+        let store = SettingsStore::new(dir.path().to_str().unwrap());
+        let capture = Arc::new(KeysCapture(Mutex::new(Vec::new())));
+        store.add_listener(capture.clone());
+
+        store.set_language(Some("de".to_string()));
+        store.set_language(Some("de".to_string()));
+        store.set_hand_auto_lower(true, 7);
+        store.set_metrics(true, None);
+
+        assert_eq!(
+            *capture.0.lock().unwrap(),
+            vec![
+                vec!["language".to_string()],
+                vec!["hand_auto_lower_delay_secs".to_string()],
+                vec!["metrics_enabled".to_string()],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_configs_from_settings() {
+        let defaults = Settings::default();
+        assert_eq!(defaults.hand_auto_lower(), AutoLowerConfig::default());
+        assert_eq!(defaults.idle(), IdleConfig::default());
+        assert_eq!(defaults.quality_policy(), QualityPolicyConfig::default());
+        assert_eq!(defaults.audio_ducking(), DuckingConfig::default());
+        assert_eq!(defaults.auto_gain(), AutoGainConfig::default());
+
+        let s = Settings {
+            hand_auto_lower_delay_secs: 10,
+            alone_timeout_mins: 0,
+            inactivity_timeout_mins: 90,
+            quality_policy_delay_secs: 30,
+            ..Settings::default()
+        };
+        assert_eq!(s.hand_auto_lower().delay, Duration::from_secs(10));
+        assert_eq!(s.idle().alone_timeout, None);
+        assert_eq!(
+            s.idle().inactivity_timeout,
+            Some(Duration::from_secs(90 * 60))
+        );
+        assert_eq!(s.quality_policy().delay, Duration::from_secs(30));
+    }
+
+    /// A setter, and the fields it is expected to change.
+    type RoundTrip = (&'static str, fn(&SettingsStore), fn(&mut Settings));
+
+    #[test]
+    fn test_setters_persist() {
+        let cases: &[RoundTrip] = &[
+            (
+                "hand_auto_lower",
+                |store| store.set_hand_auto_lower(false, 10),
+                |s| {
+                    s.hand_auto_lower_enabled = false;
+                    s.hand_auto_lower_delay_secs = 10;
+                },
+            ),
+            (
+                "idle",
+                |store| store.set_idle(0, true, 90),
+                |s| {
+                    s.alone_timeout_mins = 0;
+                    s.auto_leave_when_alone = true;
+                    s.inactivity_timeout_mins = 90;
+                },
+            ),
+            (
+                "feedback_diagnostics",
+                |store| store.set_feedback_diagnostics_enabled(false),
+                |s| s.feedback_diagnostics_enabled = false,
+            ),
+            (
+                "quality_policy",
+                |store| store.set_quality_policy(false, 30, true),
+                |s| {
+                    s.quality_policy_enabled = false;
+                    s.quality_policy_delay_secs = 30;
+                    s.quality_policy_pause_remote_video = true;
+                },
+            ),
+            (
+                "audio_ducking",
+                |store| store.set_audio_ducking(true, 0.5),
+                |s| {
+                    s.audio_ducking_enabled = true;
+                    s.audio_ducking_level = 0.5;
+                },
+            ),
+            (
+                "auto_gain",
+                |store| store.set_auto_gain(true, -16.0),
+                |s| {
+                    s.auto_gain_enabled = true;
+                    s.auto_gain_target_lufs = -16.0;
+                },
+            ),
+            (
+                "audio_preset",
+                |store| store.set_audio_preset(AudioPreset::Music),
+                |s| s.audio_preset = AudioPreset::Music,
+            ),
+            (
+                "video_overlay",
+                |store| store.set_video_overlay(Some("Visio".into()), OverlayCorner::TopLeft),
+                |s| {
+                    s.video_overlay_text = Some("Visio".into());
+                    s.video_overlay_corner = OverlayCorner::TopLeft;
+                },
+            ),
+            (
+                "video_privacy_style",
+                |store| store.set_video_privacy_style(VideoPrivacyStyle::Card),
+                |s| s.video_privacy_style = VideoPrivacyStyle::Card,
+            ),
+        ];
+        for (name, set, expect) in cases {
+            let dir = temp_dir();
+            let path = dir.path().to_str().unwrap();
+            set(&SettingsStore::new(path));
+            let mut expected = Settings::default();
+            expect(&mut expected);
+            assert_eq!(SettingsStore::new(path).get(), expected, "{name}");
+        }
+    }
+
+    #[test]
+    fn test_audio_levels_are_clamped() {
+        let dir = temp_dir();
+        let store = SettingsStore::new(dir.path().to_str().unwrap());
+        store.set_audio_ducking(true, 1.5);
+        assert_eq!(store.get().audio_ducking_level, 1.0);
+        store.set_audio_ducking(true, -0.5);
+        assert_eq!(store.get().audio_ducking_level, 0.0);
+        store.set_auto_gain(true, 0.0);
+        assert_eq!(store.get().auto_gain_target_lufs, -6.0);
+        store.set_auto_gain(true, -60.0);
+        assert_eq!(store.get().auto_gain_target_lufs, -40.0);
+    }
+
+    #[test]
+    fn test_blank_video_overlay_text_is_cleared() {
+        let dir = temp_dir();
+        let store = SettingsStore::new(dir.path().to_str().unwrap());
+        store.set_video_overlay(Some("Visio".into()), OverlayCorner::TopLeft);
+        store.set_video_overlay(Some("  ".into()), OverlayCorner::TopLeft);
+        assert_eq!(store.get().video_overlay_text, None);
+    }
 }
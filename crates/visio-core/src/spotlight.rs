@@ -0,0 +1,302 @@
+//! Shared choice of the spotlight tile for speaker view.
+//!
+//! `SpotlightService` listens to room events and picks the remote
+//! participant shown large, by priority:
+//!
+//! 1. the latest screen share,
+//! 2. the dominant speaker,
+//! 3. the first pinned participant (see `LayoutService`),
+//! 4. the previous spotlight, or else the first participant to join.
+//!
+//! A speaker keeps the spotlight for as long as they stay in the smoothed
+//! active speaker list (see `ParticipantManager`), even when someone else
+//! is momentarily louder, so the tile does not flip at every interjection.
+//! A `SpotlightChanged` event is emitted whenever the participant or the
+//! track changes, so every shell shows the same tile and renderers can
+//! subscribe to the track that is actually displayed.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use crate::events::{ConnectionState, EventEmitter, TrackSource, VisioEvent, VisioEventListener};
+use crate::layout::LayoutService;
+
+/// The tile shown in speaker view.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Spotlight {
+    /// Remote participant shown, `None` when alone in the room.
+    pub participant_sid: Option<String>,
+    /// Screen share or camera track to render; `None` shows the
+    /// participant's avatar.
+    pub track_sid: Option<String>,
+}
+
+#[derive(Default)]
+struct SpotlightState {
+    /// Remote participants in join order.
+    joined: Vec<String>,
+    /// Smoothed active speakers, loudest first (may include the local
+    /// participant, never spotlighted).
+    active_speakers: Vec<String>,
+    /// Screen share track SID and participant SID, oldest first.
+    screen_shares: Vec<(String, String)>,
+    /// Participant SID -> camera track SID.
+    cameras: HashMap<String, String>,
+    /// Last spotlight reported to listeners.
+    current: Spotlight,
+}
+
+impl SpotlightState {
+    fn compute(&self, pinned: &[String]) -> Spotlight {
+        if let Some((track_sid, participant_sid)) = self.screen_shares.last() {
+            return Spotlight {
+                participant_sid: Some(participant_sid.clone()),
+                track_sid: Some(track_sid.clone()),
+            };
+        }
+        let is_remote = |sid: &String| self.joined.contains(sid);
+        let current = self
+            .current
+            .participant_sid
+            .as_ref()
+            .filter(|s| is_remote(s));
+        let speaker = current
+            .filter(|s| self.active_speakers.contains(s))
+            .or_else(|| self.active_speakers.iter().find(|s| is_remote(s)));
+        let participant = speaker
+            .or_else(|| pinned.iter().find(|s| is_remote(s)))
+            .or(current)
+            .or_else(|| self.joined.first());
+        Spotlight {
+            participant_sid: participant.cloned(),
+            track_sid: participant.and_then(|sid| self.cameras.get(sid).cloned()),
+        }
+    }
+
+    fn remove_participant(&mut self, sid: &str) {
+        self.joined.retain(|s| s != sid);
+        self.active_speakers.retain(|s| s != sid);
+        self.screen_shares.retain(|(_, p)| p != sid);
+        self.cameras.remove(sid);
+    }
+
+    /// Apply an event. Returns whether the inputs of the spotlight changed.
+    fn apply(&mut self, event: &VisioEvent) -> bool {
+        match event {
+            VisioEvent::ParticipantJoined(info) => {
+                if self.joined.contains(&info.sid) {
+                    return false;
+                }
+                self.joined.push(info.sid.clone());
+            }
+            VisioEvent::ParticipantLeft(sid) => self.remove_participant(sid),
+            VisioEvent::ParticipantBatchUpdate { joined, left, .. } => {
+                for sid in left {
+                    self.remove_participant(sid);
+                }
+                for info in joined {
+                    if !self.joined.contains(&info.sid) {
+                        self.joined.push(info.sid.clone());
+                    }
+                }
+            }
+            VisioEvent::ActiveSpeakersChanged(sids) => {
+                self.active_speakers = sids.clone();
+            }
+            VisioEvent::TrackSubscribed(info) => match info.source {
+                TrackSource::ScreenShare => {
+                    self.screen_shares.retain(|(t, _)| *t != info.sid);
+                    self.screen_shares
+                        .push((info.sid.clone(), info.participant_sid.clone()));
+                }
+                TrackSource::Camera => {
+                    self.cameras
+                        .insert(info.participant_sid.clone(), info.sid.clone());
+                }
+                _ => return false,
+            },
+            VisioEvent::TrackUnsubscribed(track_sid) => {
+                let shares = self.screen_shares.len();
+                let cameras = self.cameras.len();
+                self.screen_shares.retain(|(t, _)| t != track_sid);
+                self.cameras.retain(|_, t| t != track_sid);
+                if shares == self.screen_shares.len() && cameras == self.cameras.len() {
+                    return false;
+                }
+            }
+            // Pins live in the layout, whose order changes with them.
+            VisioEvent::LayoutOrderChanged(_) => {}
+            VisioEvent::RoomSwitched { .. }
+            | VisioEvent::ConnectionStateChanged(ConnectionState::Disconnected) => {
+                *self = SpotlightState {
+                    current: std::mem::take(&mut self.current),
+                    ..Default::default()
+                };
+            }
+            _ => return false,
+        }
+        true
+    }
+}
+
+/// Maintains the spotlight tile. Obtain it with `RoomManager::spotlight()`.
+#[derive(Clone)]
+pub struct SpotlightService {
+    emitter: EventEmitter,
+    layout: LayoutService,
+    state: Arc<Mutex<SpotlightState>>,
+}
+
+impl SpotlightService {
+    pub(crate) fn new(emitter: EventEmitter, layout: LayoutService) -> Self {
+        Self {
+            emitter,
+            layout,
+            state: Arc::new(Mutex::new(SpotlightState::default())),
+        }
+    }
+
+    /// The tile speaker view should show.
+    pub fn spotlight(&self) -> Spotlight {
+        self.state
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .current
+            .clone()
+    }
+
+    fn update(&self, event: &VisioEvent) {
+        let pinned = self.layout.pinned_participants();
+        let changed = {
+            let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+            if !state.apply(event) {
+                return;
+            }
+            let spotlight = state.compute(&pinned);
+            if spotlight == state.current {
+                None
+            } else {
+                state.current = spotlight.clone();
+                Some(spotlight)
+            }
+        };
+        if let Some(spotlight) = changed {
+            self.emitter.emit(VisioEvent::SpotlightChanged {
+                participant_sid: spotlight.participant_sid,
+                track_sid: spotlight.track_sid,
+            });
+        }
+    }
+}
+
+impl VisioEventListener for SpotlightService {
+    fn on_event(&self, event: VisioEvent) {
+        self.update(&event);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::{ConnectionQuality, ParticipantInfo, TrackInfo, TrackKind};
+    use crate::presence::Presence;
+
+    fn setup() -> (EventEmitter, LayoutService, SpotlightService) {
+        let emitter = EventEmitter::new();
+        let layout = LayoutService::new(emitter.clone());
+        let spotlight = SpotlightService::new(emitter.clone(), layout.clone());
+        emitter.add_listener(Arc::new(layout.clone()));
+        emitter.add_listener(Arc::new(spotlight.clone()));
+        (emitter, layout, spotlight)
+    }
+
+    fn join(emitter: &EventEmitter, sid: &str) {
+        emitter.emit(VisioEvent::ParticipantJoined(ParticipantInfo {
+            sid: sid.into(),
+            identity: sid.into(),
+            name: None,
+            is_muted: false,
+            has_video: false,
+            video_track_sid: None,
+            connection_quality: ConnectionQuality::Good,
+            presence: Presence::Available,
+        }));
+    }
+
+    fn subscribe(emitter: &EventEmitter, track: &str, participant: &str, source: TrackSource) {
+        emitter.emit(VisioEvent::TrackSubscribed(TrackInfo {
+            sid: track.into(),
+            participant_sid: participant.into(),
+            kind: TrackKind::Video,
+            source,
+        }));
+    }
+
+    fn speakers(emitter: &EventEmitter, sids: &[&str]) {
+        emitter.emit(VisioEvent::ActiveSpeakersChanged(
+            sids.iter().map(|s| s.to_string()).collect(),
+        ));
+    }
+
+    fn shown(spotlight: &SpotlightService) -> (Option<String>, Option<String>) {
+        let s = spotlight.spotlight();
+        (s.participant_sid, s.track_sid)
+    }
+
+    fn some(participant: &str, track: Option<&str>) -> (Option<String>, Option<String>) {
+        (Some(participant.into()), track.map(str::to_string))
+    }
+
+    #[test]
+    fn screen_share_beats_speaker_beats_pin() {
+        let (emitter, layout, spotlight) = setup();
+        assert_eq!(spotlight.spotlight(), Spotlight::default());
+        for sid in ["a", "b", "c"] {
+            join(&emitter, sid);
+        }
+        subscribe(&emitter, "TR_cam_b", "b", TrackSource::Camera);
+        assert_eq!(shown(&spotlight), some("a", None));
+
+        layout.pin_participant("c");
+        speakers(&emitter, &["b"]);
+        assert_eq!(shown(&spotlight), some("b", Some("TR_cam_b")));
+
+        subscribe(&emitter, "TR_screen", "c", TrackSource::ScreenShare);
+        assert_eq!(shown(&spotlight), some("c", Some("TR_screen")));
+
+        emitter.emit(VisioEvent::TrackUnsubscribed("TR_screen".into()));
+        assert_eq!(shown(&spotlight), some("b", Some("TR_cam_b")));
+
+        // The room goes silent: the pin takes over.
+        speakers(&emitter, &[]);
+        assert_eq!(shown(&spotlight), some("c", None));
+    }
+
+    #[test]
+    fn speaker_keeps_spotlight_while_active() {
+        let (emitter, _, spotlight) = setup();
+        for sid in ["a", "b"] {
+            join(&emitter, sid);
+        }
+        // The local participant is never spotlighted.
+        speakers(&emitter, &["local", "a"]);
+        assert_eq!(shown(&spotlight), some("a", None));
+
+        // "b" is louder for a moment, "a" is still held as active.
+        speakers(&emitter, &["b", "a"]);
+        assert_eq!(shown(&spotlight), some("a", None));
+        speakers(&emitter, &["b"]);
+        assert_eq!(shown(&spotlight), some("b", None));
+
+        // Silence without pins keeps the last speaker.
+        speakers(&emitter, &[]);
+        assert_eq!(shown(&spotlight), some("b", None));
+
+        emitter.emit(VisioEvent::ParticipantLeft("b".into()));
+        assert_eq!(shown(&spotlight), some("a", None));
+        emitter.emit(VisioEvent::ConnectionStateChanged(
+            ConnectionState::Disconnected,
+        ));
+        assert_eq!(spotlight.spotlight(), Spotlight::default());
+    }
+}
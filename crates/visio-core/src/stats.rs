@@ -0,0 +1,510 @@
+//! Per-track quality history, for in-call quality graphs.
+//!
+//! While connected, `StatsCollector` reads the WebRTC stats every second
+//! and keeps, for each published or subscribed track, the bitrate and
+//! packet loss since the previous read along with the connection quality
+//! of the track's participant. Samples older than `QUALITY_HISTORY_WINDOW`
+//! are dropped, so UIs can draw a sparkline of the last minutes without
+//! keeping state of their own. The same read gives the resolution, frame
+//! rate and decoding figures of each subscribed video track.
+
+use livekit::prelude::Room;
+use livekit::webrtc::stats::RtcStats;
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+
+use crate::clock::Clock;
+use crate::events::{ConnectionQuality, ConnectionState, VisioEvent, VisioEventListener};
+use crate::room::connection_quality;
+
+/// Interval between two quality samples.
+pub const QUALITY_SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
+
+/// How far back the history of a track goes.
+pub const QUALITY_HISTORY_WINDOW: Duration = Duration::from_secs(5 * 60);
+
+/// Quality of one track over one sample interval.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QualitySample {
+    /// Unix time of the sample, in milliseconds.
+    pub timestamp_ms: u64,
+    /// Media bitrate since the previous sample, all simulcast layers.
+    pub bitrate_kbps: u32,
+    /// Share of packets lost since the previous sample, in percent.
+    pub packet_loss_pct: f64,
+    /// Connection quality of the track's participant.
+    pub quality: ConnectionQuality,
+}
+
+/// Latest receive figures of a subscribed video track.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct VideoReceiveStats {
+    /// Resolution of the last decoded frame.
+    pub width: u32,
+    pub height: u32,
+    /// Frames decoded per second.
+    pub fps: f64,
+    /// Average time spent decoding a frame.
+    pub avg_decode_ms: f64,
+    /// Times the video froze since the subscription.
+    pub freeze_count: u32,
+}
+
+impl VideoReceiveStats {
+    /// Whether the video is received in HD (720p or more), for badges.
+    pub fn is_hd(&self) -> bool {
+        self.width.min(self.height) >= 720
+    }
+}
+
+/// Cumulative RTP counters of one track.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct Counters {
+    bytes: u64,
+    /// Packets sent, or expected by the receiver (received plus lost).
+    packets: u64,
+    lost: u64,
+}
+
+/// Counters of subscribed tracks, by WebRTC track id.
+fn inbound_counters(stats: &[RtcStats]) -> HashMap<String, Counters> {
+    let mut tracks: HashMap<String, Counters> = HashMap::new();
+    for s in stats {
+        if let RtcStats::InboundRtp(rtp) = s {
+            let lost = rtp.received.packets_lost.max(0) as u64;
+            let c = tracks
+                .entry(rtp.inbound.track_identifier.clone())
+                .or_default();
+            c.bytes += rtp.inbound.bytes_received;
+            c.packets += rtp.received.packets_received + lost;
+            c.lost += lost;
+        }
+    }
+    tracks
+}
+
+/// Receive figures of subscribed video tracks, by WebRTC track id.
+fn video_receive_stats(stats: &[RtcStats]) -> HashMap<String, VideoReceiveStats> {
+    stats
+        .iter()
+        .filter_map(|s| match s {
+            RtcStats::InboundRtp(rtp) if rtp.stream.kind == "video" => {
+                let v = &rtp.inbound;
+                let avg_decode_ms = if v.frames_decoded > 0 {
+                    v.total_decode_time * 1000.0 / v.frames_decoded as f64
+                } else {
+                    0.0
+                };
+                Some((
+                    v.track_identifier.clone(),
+                    VideoReceiveStats {
+                        width: v.frame_width,
+                        height: v.frame_height,
+                        fps: v.frames_per_second,
+                        avg_decode_ms,
+                        freeze_count: v.freeze_count,
+                    },
+                ))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Counters of published tracks, by WebRTC track id. Losses come from
+/// the receiver reports of each outbound stream.
+fn outbound_counters(stats: &[RtcStats]) -> HashMap<String, Counters> {
+    let sources: HashMap<&str, &str> = stats
+        .iter()
+        .filter_map(|s| match s {
+            RtcStats::MediaSource(m) => {
+                Some((m.rtc.id.as_str(), m.source.track_identifier.as_str()))
+            }
+            _ => None,
+        })
+        .collect();
+    let lost: HashMap<&str, u64> = stats
+        .iter()
+        .filter_map(|s| match s {
+            RtcStats::RemoteInboundRtp(r) => Some((
+                r.remote_inbound.local_id.as_str(),
+                r.received.packets_lost.max(0) as u64,
+            )),
+            _ => None,
+        })
+        .collect();
+    let mut tracks: HashMap<String, Counters> = HashMap::new();
+    for s in stats {
+        if let RtcStats::OutboundRtp(rtp) = s {
+            let Some(track_id) = sources.get(rtp.outbound.media_source_id.as_str()) else {
+                continue;
+            };
+            let c = tracks.entry(track_id.to_string()).or_default();
+            c.bytes += rtp.sent.bytes_sent;
+            c.packets += rtp.sent.packets_sent;
+            c.lost += lost.get(rtp.rtc.id.as_str()).copied().unwrap_or(0);
+        }
+    }
+    tracks
+}
+
+/// Sid and participant quality of each track of the room, by WebRTC
+/// track id.
+fn room_tracks(room: &Room) -> HashMap<String, (String, ConnectionQuality)> {
+    let mut tracks = HashMap::new();
+    let local = room.local_participant();
+    let quality = connection_quality(local.connection_quality());
+    for publication in local.track_publications().values() {
+        if let Some(track) = publication.track() {
+            tracks.insert(
+                track.rtc_track().id(),
+                (publication.sid().to_string(), quality.clone()),
+            );
+        }
+    }
+    for participant in room.remote_participants().values() {
+        let quality = connection_quality(participant.connection_quality());
+        for publication in participant.track_publications().values() {
+            if let Some(track) = publication.track() {
+                tracks.insert(
+                    track.rtc_track().id(),
+                    (publication.sid().to_string(), quality.clone()),
+                );
+            }
+        }
+    }
+    tracks
+}
+
+struct TrackHistory {
+    last: Counters,
+    last_at: Instant,
+    samples: VecDeque<QualitySample>,
+}
+
+#[derive(Default)]
+struct History {
+    tracks: HashMap<String, TrackHistory>,
+    /// Latest receive figures of subscribed video tracks, by sid.
+    video: HashMap<String, VideoReceiveStats>,
+}
+
+impl History {
+    /// Add a sample from the counters of one read. The first read of a
+    /// track, and counters going backwards (stream restarted), only set
+    /// the baseline.
+    fn record(
+        &mut self,
+        track_sid: &str,
+        counters: Counters,
+        quality: ConnectionQuality,
+        at: Instant,
+        timestamp_ms: u64,
+    ) {
+        let Some(track) = self.tracks.get_mut(track_sid) else {
+            self.tracks.insert(
+                track_sid.to_string(),
+                TrackHistory {
+                    last: counters,
+                    last_at: at,
+                    samples: VecDeque::new(),
+                },
+            );
+            return;
+        };
+        let last = std::mem::replace(&mut track.last, counters);
+        let elapsed = at.duration_since(std::mem::replace(&mut track.last_at, at));
+        let restarted = counters.bytes < last.bytes
+            || counters.packets < last.packets
+            || counters.lost < last.lost;
+        if restarted || elapsed.is_zero() {
+            return;
+        }
+        let (packets, lost) = (counters.packets - last.packets, counters.lost - last.lost);
+        let bits = (counters.bytes - last.bytes) * 8;
+        track.samples.push_back(QualitySample {
+            timestamp_ms,
+            bitrate_kbps: (bits as f64 / elapsed.as_secs_f64() / 1000.0).round() as u32,
+            packet_loss_pct: if packets > 0 {
+                lost.min(packets) as f64 * 100.0 / packets as f64
+            } else {
+                0.0
+            },
+            quality,
+        });
+        let window_ms = QUALITY_HISTORY_WINDOW.as_millis() as u64;
+        while track
+            .samples
+            .front()
+            .is_some_and(|s| s.timestamp_ms + window_ms < timestamp_ms)
+        {
+            track.samples.pop_front();
+        }
+    }
+
+    /// Forget tracks no longer published or subscribed.
+    fn retain(&mut self, track_sids: &[String]) {
+        self.tracks.retain(|sid, _| track_sids.contains(sid));
+        self.video.retain(|sid, _| track_sids.contains(sid));
+    }
+}
+
+/// Quality history of the tracks of one room. Obtain it with
+/// `RoomManager::stats()`.
+#[derive(Clone)]
+pub struct StatsCollector {
+    room: Arc<Mutex<Option<Arc<Room>>>>,
+    history: Arc<std::sync::Mutex<History>>,
+    sampler: Arc<std::sync::Mutex<Option<JoinHandle<()>>>>,
+    clock: Arc<dyn Clock>,
+}
+
+impl StatsCollector {
+    pub(crate) fn new(room: Arc<Mutex<Option<Arc<Room>>>>, clock: Arc<dyn Clock>) -> Self {
+        Self {
+            room,
+            history: Arc::new(std::sync::Mutex::new(History::default())),
+            sampler: Arc::new(std::sync::Mutex::new(None)),
+            clock,
+        }
+    }
+
+    /// Samples of a track over the last `QUALITY_HISTORY_WINDOW`, oldest
+    /// first. Empty for unknown tracks.
+    pub fn quality_history(&self, track_sid: &str) -> Vec<QualitySample> {
+        self.history
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .tracks
+            .get(track_sid)
+            .map(|t| t.samples.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Latest receive figures of a subscribed video track, `None` before
+    /// its first stats read.
+    pub fn video_stats(&self, track_sid: &str) -> Option<VideoReceiveStats> {
+        self.history
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .video
+            .get(track_sid)
+            .cloned()
+    }
+
+    fn on_state(&self, state: &ConnectionState) {
+        let mut sampler = self.sampler.lock().unwrap_or_else(|e| e.into_inner());
+        match state {
+            ConnectionState::Connected if sampler.is_none() => {
+                *sampler = self.spawn_sampler();
+            }
+            ConnectionState::Disconnected => {
+                if let Some(sampler) = sampler.take() {
+                    sampler.abort();
+                }
+                *self.history.lock().unwrap_or_else(|e| e.into_inner()) = History::default();
+            }
+            _ => {}
+        }
+    }
+
+    /// Read the stats of every track each `QUALITY_SAMPLE_INTERVAL`.
+    fn spawn_sampler(&self) -> Option<JoinHandle<()>> {
+        let rt = tokio::runtime::Handle::try_current().ok()?;
+        let room = self.room.clone();
+        let history = self.history.clone();
+        let clock = self.clock.clone();
+        Some(rt.spawn(async move {
+            let mut next_sample = clock.now();
+            loop {
+                clock
+                    .sleep(next_sample.saturating_duration_since(clock.now()))
+                    .await;
+                next_sample += QUALITY_SAMPLE_INTERVAL;
+                let Some(room) = room.lock().await.clone() else {
+                    continue;
+                };
+                let Ok(stats) = room.get_stats().await else {
+                    continue;
+                };
+                let mut counters = outbound_counters(&stats.publisher_stats);
+                counters.extend(inbound_counters(&stats.subscriber_stats));
+                let video = video_receive_stats(&stats.subscriber_stats);
+                let tracks = room_tracks(&room);
+                let (at, timestamp_ms) = (clock.now(), clock.utc_now().timestamp_millis() as u64);
+
+                let mut history = history.lock().unwrap_or_else(|e| e.into_inner());
+                let mut seen = Vec::new();
+                for (track_id, c) in counters {
+                    let Some((sid, quality)) = tracks.get(&track_id) else {
+                        continue;
+                    };
+                    history.record(sid, c, quality.clone(), at, timestamp_ms);
+                    if let Some(v) = video.get(&track_id) {
+                        history.video.insert(sid.clone(), v.clone());
+                    }
+                    seen.push(sid.clone());
+                }
+                history.retain(&seen);
+            }
+        }))
+    }
+}
+
+impl VisioEventListener for StatsCollector {
+    fn on_event(&self, event: VisioEvent) {
+        if let VisioEvent::ConnectionStateChanged(state) = event {
+            self.on_state(&state);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::system_clock;
+
+    fn counters(bytes: u64, packets: u64, lost: u64) -> Counters {
+        Counters {
+            bytes,
+            packets,
+            lost,
+        }
+    }
+
+    #[test]
+    fn inbound_counters_sum_per_track() {
+        use livekit::webrtc::stats::InboundRtpStats;
+
+        let inbound = |track: &str, bytes: u64, received: u64, lost: i64| {
+            let mut rtp = InboundRtpStats::default();
+            rtp.inbound.track_identifier = track.into();
+            rtp.inbound.bytes_received = bytes;
+            rtp.received.packets_received = received;
+            rtp.received.packets_lost = lost;
+            RtcStats::InboundRtp(rtp)
+        };
+        let tracks = inbound_counters(&[
+            inbound("a", 1000, 90, 10),
+            inbound("a", 500, 10, -1),
+            inbound("b", 10, 1, 0),
+        ]);
+        assert_eq!(tracks["a"], counters(1500, 110, 10));
+        assert_eq!(tracks["b"], counters(10, 1, 0));
+    }
+
+    #[test]
+    fn video_stats_come_from_video_streams() {
+        use livekit::webrtc::stats::InboundRtpStats;
+
+        let mut video = InboundRtpStats::default();
+        video.stream.kind = "video".into();
+        video.inbound.track_identifier = "v".into();
+        video.inbound.frame_width = 1280;
+        video.inbound.frame_height = 720;
+        video.inbound.frames_per_second = 30.0;
+        video.inbound.frames_decoded = 300;
+        video.inbound.total_decode_time = 1.5;
+        video.inbound.freeze_count = 2;
+        let mut audio = InboundRtpStats::default();
+        audio.stream.kind = "audio".into();
+        audio.inbound.track_identifier = "a".into();
+
+        let stats =
+            video_receive_stats(&[RtcStats::InboundRtp(video), RtcStats::InboundRtp(audio)]);
+        assert_eq!(stats.len(), 1);
+        let v = &stats["v"];
+        assert_eq!((v.width, v.height, v.freeze_count), (1280, 720, 2));
+        assert!((v.avg_decode_ms - 5.0).abs() < 1e-9);
+        assert!(v.is_hd());
+        let sd = VideoReceiveStats {
+            width: 640,
+            height: 360,
+            ..v.clone()
+        };
+        assert!(!sd.is_hd());
+    }
+
+    #[test]
+    fn history_keeps_a_rolling_window() {
+        let mut history = History::default();
+        let t0 = Instant::now();
+        let at = |secs: u64| t0 + Duration::from_secs(secs);
+        let ms = |secs: u64| 1_000_000 + secs * 1000;
+
+        history.record(
+            "TR_1",
+            counters(0, 0, 0),
+            ConnectionQuality::Good,
+            at(0),
+            ms(0),
+        );
+        assert!(history.tracks["TR_1"].samples.is_empty());
+        // 125 kB and 5 lost packets out of 100 in two seconds.
+        history.record(
+            "TR_1",
+            counters(125_000, 100, 5),
+            ConnectionQuality::Poor,
+            at(2),
+            ms(2),
+        );
+        let sample = &history.tracks["TR_1"].samples[0];
+        assert_eq!(sample.bitrate_kbps, 500);
+        assert!((sample.packet_loss_pct - 5.0).abs() < 1e-9);
+        assert_eq!(sample.quality, ConnectionQuality::Poor);
+
+        // Restarted streams only reset the baseline.
+        history.record(
+            "TR_1",
+            counters(10, 1, 0),
+            ConnectionQuality::Good,
+            at(3),
+            ms(3),
+        );
+        assert_eq!(history.tracks["TR_1"].samples.len(), 1);
+
+        let window = QUALITY_HISTORY_WINDOW.as_secs();
+        for secs in 4..window + 10 {
+            history.record(
+                "TR_1",
+                counters(10 + secs, 1 + secs, 0),
+                ConnectionQuality::Good,
+                at(secs),
+                ms(secs),
+            );
+        }
+        let samples = &history.tracks["TR_1"].samples;
+        assert_eq!(samples.front().unwrap().timestamp_ms, ms(9));
+        assert_eq!(samples.len() as u64, window + 1);
+
+        history.retain(&[]);
+        assert!(history.tracks.is_empty());
+    }
+
+    #[tokio::test]
+    async fn history_is_cleared_on_disconnect() {
+        let collector = StatsCollector::new(Arc::new(Mutex::new(None)), system_clock());
+        let t0 = Instant::now();
+        {
+            let mut history = collector.history.lock().unwrap();
+            history.record("TR_1", counters(0, 0, 0), ConnectionQuality::Good, t0, 0);
+            history.record(
+                "TR_1",
+                counters(1000, 10, 0),
+                ConnectionQuality::Good,
+                t0 + QUALITY_SAMPLE_INTERVAL,
+                1000,
+            );
+        }
+        assert_eq!(collector.quality_history("TR_1").len(), 1);
+        assert!(collector.quality_history("TR_2").is_empty());
+
+        collector.on_event(VisioEvent::ConnectionStateChanged(
+            ConnectionState::Disconnected,
+        ));
+        assert!(collector.quality_history("TR_1").is_empty());
+    }
+}
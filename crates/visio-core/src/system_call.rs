@@ -0,0 +1,220 @@
+//! Call lifecycle for the operating system call UI.
+//!
+//! CallKit on iOS and ConnectionService on Android treat a meeting as a
+//! real call: it shows in the system call UI, holds the audio focus and
+//! gets Bluetooth headset routing. Shells report the call when they join
+//! (`RoomManager::report_call`) and bridge `SystemCallChanged` events to
+//! the OS: the core follows the room connection and tells them when the
+//! call connected and how it ended.
+//!
+//! Once connected, the call survives `ConnectionLost` while the shell
+//! reconnects; it only fails when `RoomManager::reconnect` gives up.
+
+use std::sync::{Arc, Mutex};
+
+use crate::events::{ConnectionState, EventEmitter, VisioEvent, VisioEventListener};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CallDirection {
+    /// Joined from an invitation or a ring (CallKit incoming call).
+    Incoming,
+    /// Joined by the user.
+    Outgoing,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SystemCallState {
+    Connecting,
+    Connected,
+    /// The local user left (`RoomManager::disconnect` or `end_call`).
+    EndedLocally,
+    /// The server ended the call: removed from the room, room closed or
+    /// deleted, or the same identity joined elsewhere.
+    EndedRemotely,
+    /// The call could not connect, or the connection was lost for good.
+    Failed,
+}
+
+impl SystemCallState {
+    pub fn is_ended(self) -> bool {
+        !matches!(self, Self::Connecting | Self::Connected)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SystemCall {
+    /// UUID identifying the call to the OS (CallKit requires one).
+    pub call_id: String,
+    /// Meet URL or room name, the handle shown by the call UI.
+    pub room: String,
+    pub display_name: String,
+    pub direction: CallDirection,
+    pub state: SystemCallState,
+}
+
+/// The call reported to the OS, if any. Registered as a listener on the
+/// room emitter to follow the connection state.
+#[derive(Clone)]
+pub struct SystemCallTracker {
+    call: Arc<Mutex<Option<SystemCall>>>,
+    emitter: EventEmitter,
+}
+
+impl SystemCallTracker {
+    pub fn new(emitter: EventEmitter) -> Self {
+        Self {
+            call: Arc::new(Mutex::new(None)),
+            emitter,
+        }
+    }
+
+    /// Start tracking a new call, replacing (and failing) any previous one.
+    /// `connected` when the room is already connected.
+    pub fn report(
+        &self,
+        room: &str,
+        display_name: &str,
+        direction: CallDirection,
+        connected: bool,
+    ) -> SystemCall {
+        self.end(SystemCallState::Failed);
+        let call = SystemCall {
+            call_id: uuid::Uuid::new_v4().to_string(),
+            room: room.to_string(),
+            display_name: display_name.to_string(),
+            direction,
+            state: if connected {
+                SystemCallState::Connected
+            } else {
+                SystemCallState::Connecting
+            },
+        };
+        *self.call.lock().unwrap_or_else(|e| e.into_inner()) = Some(call.clone());
+        self.emitter
+            .emit(VisioEvent::SystemCallChanged(call.clone()));
+        call
+    }
+
+    pub fn current(&self) -> Option<SystemCall> {
+        self.call.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// End the current call with `state`, if there is one.
+    pub fn end(&self, state: SystemCallState) {
+        debug_assert!(state.is_ended());
+        let ended = self
+            .call
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .take()
+            .map(|call| SystemCall { state, ..call });
+        if let Some(call) = ended {
+            tracing::info!("system call {} ended: {state:?}", call.call_id);
+            self.emitter.emit(VisioEvent::SystemCallChanged(call));
+        }
+    }
+
+    fn state(&self) -> Option<SystemCallState> {
+        self.call
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .as_ref()
+            .map(|call| call.state)
+    }
+
+    fn connected(&self) {
+        let connected = {
+            let mut guard = self.call.lock().unwrap_or_else(|e| e.into_inner());
+            match guard.as_mut() {
+                Some(call) if call.state == SystemCallState::Connecting => {
+                    call.state = SystemCallState::Connected;
+                    Some(call.clone())
+                }
+                _ => None,
+            }
+        };
+        if let Some(call) = connected {
+            self.emitter.emit(VisioEvent::SystemCallChanged(call));
+        }
+    }
+}
+
+impl VisioEventListener for SystemCallTracker {
+    fn on_event(&self, event: VisioEvent) {
+        match event {
+            VisioEvent::ConnectionStateChanged(ConnectionState::Connected) => self.connected(),
+            // A join that did not go through. Leaving on purpose ends the
+            // call before the room reports the disconnection.
+            VisioEvent::ConnectionStateChanged(ConnectionState::Disconnected)
+                if self.state() == Some(SystemCallState::Connecting) =>
+            {
+                self.end(SystemCallState::Failed)
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Capture(Mutex<Vec<SystemCallState>>);
+
+    impl VisioEventListener for Capture {
+        fn on_event(&self, event: VisioEvent) {
+            if let VisioEvent::SystemCallChanged(call) = event {
+                self.0.lock().unwrap().push(call.state);
+            }
+        }
+    }
+
+    #[test]
+    fn follows_the_connection_until_the_call_ends() {
+        let emitter = EventEmitter::new();
+        let tracker = SystemCallTracker::new(emitter.clone());
+        emitter.add_listener(Arc::new(tracker.clone()));
+        let capture = Arc::new(Capture(Mutex::new(Vec::new())));
+        emitter.add_listener(capture.clone());
+
+        let call = tracker.report("abc-defg-hij", "Ana", CallDirection::Outgoing, false);
+        assert_eq!(call.state, SystemCallState::Connecting);
+        assert!(uuid::Uuid::parse_str(&call.call_id).is_ok());
+        emitter.emit(VisioEvent::ConnectionStateChanged(
+            ConnectionState::Connected,
+        ));
+        // Already connected: no new event.
+        emitter.emit(VisioEvent::ConnectionStateChanged(
+            ConnectionState::Connected,
+        ));
+        assert_eq!(tracker.current().unwrap().state, SystemCallState::Connected);
+        // A failed reconnection attempt does not end a connected call.
+        emitter.emit(VisioEvent::ConnectionStateChanged(
+            ConnectionState::Disconnected,
+        ));
+        assert_eq!(tracker.current().unwrap().state, SystemCallState::Connected);
+
+        tracker.end(SystemCallState::EndedLocally);
+        emitter.emit(VisioEvent::ConnectionStateChanged(
+            ConnectionState::Disconnected,
+        ));
+        assert_eq!(tracker.current(), None);
+
+        // A join that never connects fails.
+        tracker.report("abc-defg-hij", "Ana", CallDirection::Incoming, false);
+        emitter.emit(VisioEvent::ConnectionStateChanged(
+            ConnectionState::Disconnected,
+        ));
+
+        assert_eq!(
+            *capture.0.lock().unwrap(),
+            [
+                SystemCallState::Connecting,
+                SystemCallState::Connected,
+                SystemCallState::EndedLocally,
+                SystemCallState::Connecting,
+                SystemCallState::Failed,
+            ]
+        );
+    }
+}
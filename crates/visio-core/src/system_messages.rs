@@ -0,0 +1,247 @@
+//! Meeting events in the chat.
+//!
+//! Joins, departures, the start of a recording and raised hands are added
+//! to the chat as system messages (`ChatMessage` with a `kind` other than
+//! `Text`), so every shell interleaves them with the conversation the same
+//! way. Their text is localized in the `language` setting; shells that
+//! render their own strings use the kind and the sender name.
+//!
+//! Participants already in the room when joining are not announced: only
+//! joins and departures during the call are (`RoomManager` reports them,
+//! see `participant_joined` and `participant_left`).
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use crate::chat::MessageStore;
+use crate::clock::Clock;
+use crate::events::{
+    ChatMessage, ChatMessageKind, ConnectionState, EventEmitter, ParticipantInfo, VisioEvent,
+    VisioEventListener,
+};
+use crate::i18n;
+use crate::recording::RecordingStatus;
+use crate::settings::Settings;
+
+#[derive(Default)]
+struct State {
+    language: Option<String>,
+    /// Participant SID -> display name, for events that only carry the SID.
+    names: HashMap<String, String>,
+}
+
+/// Adds system messages to the chat of a room. Obtain it with
+/// `RoomManager::system_messages()`.
+#[derive(Clone)]
+pub struct SystemMessages {
+    messages: MessageStore,
+    emitter: EventEmitter,
+    clock: Arc<dyn Clock>,
+    state: Arc<Mutex<State>>,
+}
+
+impl SystemMessages {
+    pub(crate) fn new(
+        messages: MessageStore,
+        emitter: EventEmitter,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self {
+            messages,
+            emitter,
+            clock,
+            state: Arc::new(Mutex::new(State::default())),
+        }
+    }
+
+    /// Follow the `language` setting, for the next messages.
+    pub fn apply_settings(&self, settings: &Settings) {
+        self.lock().language = settings.language.clone();
+    }
+
+    /// Name shown for the local participant, e.g. when they raise their
+    /// hand.
+    pub(crate) fn set_local(&self, sid: &str, name: &str) {
+        self.remember(sid, name);
+    }
+
+    /// A participant joined during the call.
+    pub(crate) fn participant_joined(&self, info: &ParticipantInfo) {
+        let name = info.name.clone().unwrap_or_else(|| info.identity.clone());
+        self.remember(&info.sid, &name);
+        let key = "chat.system.participantJoined";
+        self.post(ChatMessageKind::ParticipantJoined, key, &info.sid, &name);
+    }
+
+    /// A participant left during the call.
+    pub(crate) fn participant_left(&self, sid: &str) {
+        let name = self
+            .lock()
+            .names
+            .remove(sid)
+            .unwrap_or_else(|| sid.to_string());
+        let key = "chat.system.participantLeft";
+        self.post(ChatMessageKind::ParticipantLeft, key, sid, &name);
+    }
+
+    fn remember(&self, sid: &str, name: &str) {
+        self.lock().names.insert(sid.to_string(), name.to_string());
+    }
+
+    /// Add a message of `kind` about `sid`, with the text `key`.
+    fn post(&self, kind: ChatMessageKind, key: &str, sid: &str, name: &str) {
+        let language = self.lock().language.clone().unwrap_or_default();
+        let msg = ChatMessage {
+            id: format!("system-{}", uuid::Uuid::new_v4()),
+            sender_sid: sid.to_string(),
+            sender_name: name.to_string(),
+            text: i18n::translate(&language, key, &[("name", name)]),
+            timestamp_ms: self.clock.utc_now().timestamp_millis() as u64,
+            kind,
+        };
+        // Events are delivered synchronously, sometimes while the event
+        // loop holds the store: append later rather than block.
+        match self.messages.try_lock() {
+            Ok(mut messages) => messages.push(msg.clone()),
+            Err(_) => {
+                let messages = self.messages.clone();
+                let msg = msg.clone();
+                if let Ok(rt) = tokio::runtime::Handle::try_current() {
+                    rt.spawn(async move { messages.lock().await.push(msg) });
+                }
+            }
+        }
+        self.emitter.emit(VisioEvent::ChatMessageReceived(msg));
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl VisioEventListener for SystemMessages {
+    fn on_event(&self, event: VisioEvent) {
+        match event {
+            // Names of the participants already there, and of those who
+            // joined while join/leave events are batched.
+            VisioEvent::ParticipantJoined(info) => {
+                let name = info.name.unwrap_or(info.identity);
+                self.remember(&info.sid, &name);
+            }
+            VisioEvent::ParticipantBatchUpdate { joined, .. } => {
+                for info in joined {
+                    let name = info.name.unwrap_or(info.identity);
+                    self.remember(&info.sid, &name);
+                }
+            }
+            VisioEvent::RecordingStatusChanged(RecordingStatus::Recording) => {
+                let key = "chat.system.recordingStarted";
+                self.post(ChatMessageKind::RecordingStarted, key, "", "");
+            }
+            VisioEvent::HandRaisedChanged {
+                participant_sid,
+                raised: true,
+                ..
+            } => {
+                let name = self.lock().names.get(&participant_sid).cloned();
+                let name = name.unwrap_or_else(|| participant_sid.clone());
+                let key = "chat.system.handRaised";
+                self.post(ChatMessageKind::HandRaised, key, &participant_sid, &name);
+            }
+            VisioEvent::RoomSwitched { .. }
+            | VisioEvent::ConnectionStateChanged(ConnectionState::Disconnected) => {
+                self.lock().names.clear();
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::MockClock;
+    use crate::events::ConnectionQuality;
+    use crate::presence::Presence;
+
+    struct Capture(Mutex<Vec<ChatMessage>>);
+
+    impl VisioEventListener for Capture {
+        fn on_event(&self, event: VisioEvent) {
+            if let VisioEvent::ChatMessageReceived(msg) = event {
+                self.0.lock().unwrap().push(msg);
+            }
+        }
+    }
+
+    fn participant(sid: &str, name: Option<&str>) -> ParticipantInfo {
+        ParticipantInfo {
+            sid: sid.into(),
+            identity: format!("{sid}-identity"),
+            name: name.map(Into::into),
+            is_muted: false,
+            has_video: false,
+            video_track_sid: None,
+            connection_quality: ConnectionQuality::Good,
+            presence: Presence::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn posts_localized_meeting_events() {
+        let emitter = EventEmitter::new();
+        let store = MessageStore::default();
+        let system =
+            SystemMessages::new(store.clone(), emitter.clone(), Arc::new(MockClock::new()));
+        emitter.add_listener(Arc::new(system.clone()));
+        let capture = Arc::new(Capture(Mutex::new(Vec::new())));
+        emitter.add_listener(capture.clone());
+
+        // Already in the room: remembered, not announced.
+        emitter.emit(VisioEvent::ParticipantJoined(participant(
+            "PA_1",
+            Some("Alice"),
+        )));
+        emitter.emit(VisioEvent::HandRaisedChanged {
+            participant_sid: "PA_1".into(),
+            raised: true,
+            position: 1,
+        });
+        emitter.emit(VisioEvent::HandRaisedChanged {
+            participant_sid: "PA_1".into(),
+            raised: false,
+            position: 0,
+        });
+        system.apply_settings(&Settings {
+            language: Some("fr".into()),
+            ..Settings::default()
+        });
+        system.participant_joined(&participant("PA_2", None));
+        emitter.emit(VisioEvent::RecordingStatusChanged(
+            RecordingStatus::Starting,
+        ));
+        emitter.emit(VisioEvent::RecordingStatusChanged(
+            RecordingStatus::Recording,
+        ));
+        system.participant_left("PA_1");
+
+        let posted = capture.0.lock().unwrap().clone();
+        let kinds: Vec<_> = posted.iter().map(|m| m.kind).collect();
+        assert_eq!(
+            kinds,
+            [
+                ChatMessageKind::HandRaised,
+                ChatMessageKind::ParticipantJoined,
+                ChatMessageKind::RecordingStarted,
+                ChatMessageKind::ParticipantLeft,
+            ]
+        );
+        assert_eq!(posted[0].sender_name, "Alice");
+        assert_eq!(posted[0].text, "Alice raised their hand");
+        assert_eq!(posted[1].text, "PA_2-identity a rejoint la réunion");
+        assert_eq!(posted[2].sender_sid, "");
+        assert_eq!(posted[3].sender_name, "Alice");
+        assert!(posted[0].id.starts_with("system-"));
+        assert_eq!(store.lock().await.len(), 4);
+    }
+}
@@ -0,0 +1,286 @@
+//! Synthetic media sources for automated tests and headless clients.
+//!
+//! When enabled on `MeetingControls`, publishing the camera or microphone
+//! also spawns a generator task that feeds colour bars / a moving box or a
+//! sine tone into the freshly created native source. This exercises the
+//! full publish path without any capture device.
+
+use std::f32::consts::TAU;
+use std::time::Duration;
+
+use livekit::webrtc::audio_frame::AudioFrame;
+use livekit::webrtc::audio_source::native::NativeAudioSource;
+use livekit::webrtc::prelude::*;
+use livekit::webrtc::video_source::native::NativeVideoSource;
+use tokio::task::JoinHandle;
+
+/// Synthetic audio is generated at the same rate the microphone source uses.
+const AUDIO_SAMPLE_RATE: u32 = 48_000;
+const AUDIO_FRAME_MS: u32 = 10;
+
+/// SMPTE-style colour bars as BT.601 (Y, U, V).
+const BARS: [(u8, u8, u8); 7] = [
+    (235, 128, 128), // white
+    (210, 16, 146),  // yellow
+    (170, 166, 16),  // cyan
+    (145, 54, 34),   // green
+    (106, 202, 222), // magenta
+    (81, 90, 240),   // red
+    (41, 240, 110),  // blue
+];
+
+/// What the synthetic video source draws.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TestPattern {
+    /// Static vertical colour bars.
+    ColorBars,
+    /// Colour bars with a black box sweeping left to right.
+    MovingBox,
+}
+
+/// Synthetic camera configuration.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TestVideoConfig {
+    pub width: u32,
+    pub height: u32,
+    pub fps: u32,
+    pub pattern: TestPattern,
+}
+
+impl Default for TestVideoConfig {
+    fn default() -> Self {
+        Self {
+            width: 640,
+            height: 360,
+            fps: 15,
+            pattern: TestPattern::MovingBox,
+        }
+    }
+}
+
+/// Synthetic microphone configuration.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TestAudioConfig {
+    pub frequency_hz: f32,
+    /// Peak amplitude in 0.0..=1.0 of full scale.
+    pub amplitude: f32,
+}
+
+impl Default for TestAudioConfig {
+    fn default() -> Self {
+        Self {
+            frequency_hz: 440.0,
+            amplitude: 0.2,
+        }
+    }
+}
+
+/// Which local sources should be driven by synthetic generators.
+///
+/// `None` keeps the normal behaviour: native code feeds captured frames.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TestMediaOptions {
+    pub video: Option<TestVideoConfig>,
+    pub audio: Option<TestAudioConfig>,
+}
+
+impl TestMediaOptions {
+    /// Both synthetic camera and microphone with default settings.
+    pub fn audio_video() -> Self {
+        Self {
+            video: Some(TestVideoConfig::default()),
+            audio: Some(TestAudioConfig::default()),
+        }
+    }
+}
+
+/// A running generator task. Aborted when dropped.
+pub struct TestMediaTask {
+    handle: JoinHandle<()>,
+}
+
+impl Drop for TestMediaTask {
+    fn drop(&mut self) {
+        self.handle.abort();
+    }
+}
+
+/// Fill I420 planes with the configured pattern for `frame_index`.
+#[allow(clippy::too_many_arguments)]
+pub fn draw_test_pattern(
+    y: &mut [u8],
+    u: &mut [u8],
+    v: &mut [u8],
+    width: usize,
+    height: usize,
+    stride_y: usize,
+    stride_uv: usize,
+    pattern: TestPattern,
+    fps: u32,
+    frame_index: u64,
+) {
+    let bar_w = width.div_ceil(BARS.len()).max(1);
+    for row in 0..height {
+        for col in 0..width {
+            y[row * stride_y + col] = BARS[col / bar_w].0;
+        }
+    }
+    for row in 0..height / 2 {
+        for col in 0..width / 2 {
+            let (_, cu, cv) = BARS[(col * 2) / bar_w];
+            u[row * stride_uv + col] = cu;
+            v[row * stride_uv + col] = cv;
+        }
+    }
+
+    if pattern == TestPattern::MovingBox {
+        // One full sweep every four seconds regardless of fps.
+        let box_size = height / 4;
+        let travel = width.saturating_sub(box_size).max(1);
+        let sweep_frames = (fps.max(1) as u64) * 4;
+        let x0 = ((frame_index % sweep_frames) as usize * travel) / sweep_frames as usize;
+        let y0 = (height - box_size) / 2;
+        for row in y0..y0 + box_size {
+            for col in x0..(x0 + box_size).min(width) {
+                y[row * stride_y + col] = 16;
+            }
+        }
+    }
+}
+
+/// Fill `out` with a sine tone, continuing from `phase` (radians).
+/// Returns the phase for the next frame so consecutive frames are seamless.
+pub fn fill_tone(out: &mut [i16], config: &TestAudioConfig, phase: f32) -> f32 {
+    let step = TAU * config.frequency_hz / AUDIO_SAMPLE_RATE as f32;
+    let amplitude = config.amplitude.clamp(0.0, 1.0) * i16::MAX as f32;
+    let mut p = phase;
+    for s in out.iter_mut() {
+        *s = (p.sin() * amplitude) as i16;
+        p = (p + step) % TAU;
+    }
+    p
+}
+
+/// Spawn a task pushing test-pattern frames into `source`.
+pub fn spawn_video(source: NativeVideoSource, config: TestVideoConfig) -> TestMediaTask {
+    let handle = tokio::spawn(async move {
+        let period = Duration::from_micros(1_000_000 / config.fps.max(1) as u64);
+        let mut ticker = tokio::time::interval(period);
+        let mut frame_index = 0u64;
+        loop {
+            ticker.tick().await;
+            let mut i420 = I420Buffer::new(config.width, config.height);
+            let (sy, su, _) = i420.strides();
+            let (y, u, v) = i420.data_mut();
+            draw_test_pattern(
+                y,
+                u,
+                v,
+                config.width as usize,
+                config.height as usize,
+                sy as usize,
+                su as usize,
+                config.pattern,
+                config.fps,
+                frame_index,
+            );
+            let frame = VideoFrame {
+                rotation: VideoRotation::VideoRotation0,
+                timestamp_us: crate::av_sync::monotonic_us(),
+                buffer: i420,
+            };
+            source.capture_frame(&frame);
+            frame_index += 1;
+        }
+    });
+    tracing::info!("synthetic video source started");
+    TestMediaTask { handle }
+}
+
+/// Spawn a task pushing a continuous sine tone into `source`.
+pub fn spawn_audio(source: NativeAudioSource, config: TestAudioConfig) -> TestMediaTask {
+    let handle = tokio::spawn(async move {
+        let samples = (AUDIO_SAMPLE_RATE * AUDIO_FRAME_MS / 1000) as usize;
+        let mut buf = vec![0i16; samples];
+        let mut phase = 0.0f32;
+        loop {
+            phase = fill_tone(&mut buf, &config, phase);
+            let frame = AudioFrame {
+                data: buf.clone().into(),
+                sample_rate: AUDIO_SAMPLE_RATE,
+                num_channels: 1,
+                samples_per_channel: samples as u32,
+            };
+            // capture_frame paces itself against the source queue.
+            if let Err(e) = source.capture_frame(&frame).await {
+                tracing::warn!("synthetic audio capture_frame failed: {e}");
+                tokio::time::sleep(Duration::from_millis(AUDIO_FRAME_MS as u64)).await;
+            }
+        }
+    });
+    tracing::info!("synthetic audio source started");
+    TestMediaTask { handle }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn draw(pattern: TestPattern, frame_index: u64) -> Vec<u8> {
+        let (w, h) = (64usize, 32usize);
+        let mut y = vec![0u8; w * h];
+        let mut u = vec![0u8; w * h / 4];
+        let mut v = vec![0u8; w * h / 4];
+        draw_test_pattern(
+            &mut y,
+            &mut u,
+            &mut v,
+            w,
+            h,
+            w,
+            w / 2,
+            pattern,
+            15,
+            frame_index,
+        );
+        y
+    }
+
+    #[test]
+    fn moving_box_changes_between_frames() {
+        let a = draw(TestPattern::MovingBox, 0);
+        let b = draw(TestPattern::MovingBox, 10);
+        assert_ne!(a, b);
+        // Top row is never covered by the box.
+        assert_eq!(a[0], BARS[0].0);
+        assert_eq!(a[63], BARS[BARS.len() - 1].0);
+    }
+
+    #[test]
+    fn color_bars_are_static() {
+        assert_eq!(
+            draw(TestPattern::ColorBars, 0),
+            draw(TestPattern::ColorBars, 42)
+        );
+    }
+
+    #[test]
+    fn tone_is_continuous_and_bounded() {
+        let config = TestAudioConfig::default();
+        let mut a = vec![0i16; 480];
+        let mut b = vec![0i16; 480];
+        let phase = fill_tone(&mut a, &config, 0.0);
+        fill_tone(&mut b, &config, phase);
+        let peak = a.iter().map(|s| s.unsigned_abs()).max().unwrap();
+        assert!(peak > 6000 && peak <= (0.2 * i16::MAX as f32) as u16 + 1);
+        assert!((a[479] as i32 - b[0] as i32).abs() < 1000);
+    }
+
+    #[test]
+    fn options_default_to_real_capture() {
+        let opts = TestMediaOptions::default();
+        assert!(opts.video.is_none() && opts.audio.is_none());
+        let av = TestMediaOptions::audio_video();
+        assert!(av.video.is_some() && av.audio.is_some());
+    }
+}
@@ -0,0 +1,323 @@
+//! Tile geometry for the call screen.
+//!
+//! `compute_layout` places participant tiles in a container, so Android,
+//! iOS and desktop draw the same grid instead of each keeping a layout
+//! engine of its own. The order of the tiles comes from `LayoutService`
+//! and the speaker from `SpotlightService`; this module only does the
+//! geometry.
+//!
+//! Coordinates are in the container's units (dp, points or CSS pixels),
+//! with the origin at its top-left corner.
+
+/// Width / height of a tile when `LayoutOptions::tile_aspect_ratio` is
+/// not a positive number.
+pub const DEFAULT_TILE_ASPECT_RATIO: f32 = 16.0 / 9.0;
+
+/// Share of the container height given to the filmstrip in speaker mode.
+const FILMSTRIP_SHARE: f32 = 0.2;
+
+/// Share of the container width given to the sidebar.
+const SIDEBAR_SHARE: f32 = 0.25;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LayoutMode {
+    /// Equal tiles, as large as the container allows.
+    #[default]
+    Grid,
+    /// The spotlight tile above a filmstrip of the others.
+    Speaker,
+    /// The spotlight tile beside a column of the others. Portrait
+    /// containers use the speaker layout instead.
+    Sidebar,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LayoutOptions {
+    pub mode: LayoutMode,
+    pub width: f32,
+    pub height: f32,
+    /// Tiles in display order, e.g. `LayoutService::ordered_participants`
+    /// plus the self-view.
+    pub participant_sids: Vec<String>,
+    /// Tile shown large in speaker and sidebar modes, usually the
+    /// `SpotlightChanged` participant. Defaults to the first tile.
+    pub spotlight_sid: Option<String>,
+    /// Width / height of a tile (see `DEFAULT_TILE_ASPECT_RATIO`).
+    pub tile_aspect_ratio: f32,
+    /// Space between tiles.
+    pub gap: f32,
+}
+
+impl Default for LayoutOptions {
+    fn default() -> Self {
+        Self {
+            mode: LayoutMode::Grid,
+            width: 0.0,
+            height: 0.0,
+            participant_sids: Vec::new(),
+            spotlight_sid: None,
+            tile_aspect_ratio: DEFAULT_TILE_ASPECT_RATIO,
+            gap: 8.0,
+        }
+    }
+}
+
+/// Position and size of a participant tile.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TileRect {
+    pub participant_sid: String,
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+#[derive(Clone, Copy)]
+struct Area {
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+}
+
+/// Tiles for `options`, in the order of `participant_sids` except that in
+/// speaker and sidebar modes the spotlight tile comes first.
+///
+/// Grid tiles keep the tile aspect ratio and are centered, the last row
+/// included. Filmstrip and sidebar tiles that do not fit extend past the
+/// right or bottom edge; shells make that strip scrollable.
+pub fn compute_layout(options: &LayoutOptions) -> Vec<TileRect> {
+    let valid = |v: f32| v.is_finite() && v > 0.0;
+    if options.participant_sids.is_empty() || !valid(options.width) || !valid(options.height) {
+        return Vec::new();
+    }
+    let aspect = if valid(options.tile_aspect_ratio) {
+        options.tile_aspect_ratio
+    } else {
+        DEFAULT_TILE_ASPECT_RATIO
+    };
+    let gap = if options.gap.is_finite() {
+        options.gap.max(0.0)
+    } else {
+        0.0
+    };
+    let container = Area {
+        x: 0.0,
+        y: 0.0,
+        width: options.width,
+        height: options.height,
+    };
+
+    let sids = &options.participant_sids;
+    if options.mode == LayoutMode::Grid || sids.len() == 1 {
+        return grid(sids, container, aspect, gap);
+    }
+
+    let main = options
+        .spotlight_sid
+        .as_ref()
+        .filter(|sid| sids.contains(sid))
+        .unwrap_or(&sids[0]);
+    let others: Vec<String> = sids.iter().filter(|s| *s != main).cloned().collect();
+    let sidebar = options.mode == LayoutMode::Sidebar && options.width >= options.height;
+
+    let mut tiles = Vec::with_capacity(sids.len());
+    if sidebar {
+        let column_width = options.width * SIDEBAR_SHARE;
+        let stage = Area {
+            width: (options.width - column_width - gap).max(0.0),
+            ..container
+        };
+        tiles.push(fit(main, stage, aspect));
+        let column = Area {
+            x: options.width - column_width,
+            width: column_width,
+            ..container
+        };
+        tiles.extend(column_tiles(&others, column, aspect, gap));
+    } else {
+        let strip_height = options.height * FILMSTRIP_SHARE;
+        let stage = Area {
+            height: (options.height - strip_height - gap).max(0.0),
+            ..container
+        };
+        tiles.push(fit(main, stage, aspect));
+        let strip = Area {
+            y: options.height - strip_height,
+            height: strip_height,
+            ..container
+        };
+        tiles.extend(row_tiles(&others, strip, aspect, gap));
+    }
+    tiles
+}
+
+/// Largest tile of ratio `aspect` centered in `area`.
+fn fit(sid: &str, area: Area, aspect: f32) -> TileRect {
+    let width = area.width.min(area.height * aspect);
+    let height = width / aspect;
+    TileRect {
+        participant_sid: sid.to_string(),
+        x: area.x + (area.width - width) / 2.0,
+        y: area.y + (area.height - height) / 2.0,
+        width,
+        height,
+    }
+}
+
+/// Equal tiles with the column count giving the largest tiles.
+fn grid(sids: &[String], area: Area, aspect: f32, gap: f32) -> Vec<TileRect> {
+    let count = sids.len();
+    let tile_width = |columns: usize| {
+        let rows = count.div_ceil(columns);
+        let cell_width = (area.width - gap * (columns - 1) as f32) / columns as f32;
+        let cell_height = (area.height - gap * (rows - 1) as f32) / rows as f32;
+        cell_width.min(cell_height * aspect).max(0.0)
+    };
+    let mut columns = 1;
+    for candidate in 2..=count {
+        if tile_width(candidate) > tile_width(columns) {
+            columns = candidate;
+        }
+    }
+
+    let width = tile_width(columns);
+    let height = width / aspect;
+    let rows = count.div_ceil(columns);
+    let grid_height = rows as f32 * height + (rows - 1) as f32 * gap;
+    let top = area.y + (area.height - grid_height) / 2.0;
+
+    sids.iter()
+        .enumerate()
+        .map(|(i, sid)| {
+            let (row, column) = (i / columns, i % columns);
+            let in_row = columns.min(count - row * columns);
+            let row_width = in_row as f32 * width + (in_row - 1) as f32 * gap;
+            let left = area.x + (area.width - row_width) / 2.0;
+            TileRect {
+                participant_sid: sid.clone(),
+                x: left + column as f32 * (width + gap),
+                y: top + row as f32 * (height + gap),
+                width,
+                height,
+            }
+        })
+        .collect()
+}
+
+/// Tiles filling the height of `area`, left to right, centered when they fit.
+fn row_tiles(sids: &[String], area: Area, aspect: f32, gap: f32) -> Vec<TileRect> {
+    let height = area.height;
+    let width = height * aspect;
+    let total = sids.len() as f32 * (width + gap) - gap;
+    let left = area.x + ((area.width - total) / 2.0).max(0.0);
+    sids.iter()
+        .enumerate()
+        .map(|(i, sid)| TileRect {
+            participant_sid: sid.clone(),
+            x: left + i as f32 * (width + gap),
+            y: area.y,
+            width,
+            height,
+        })
+        .collect()
+}
+
+/// Tiles filling the width of `area`, top to bottom, centered when they fit.
+fn column_tiles(sids: &[String], area: Area, aspect: f32, gap: f32) -> Vec<TileRect> {
+    let width = area.width;
+    let height = width / aspect;
+    let total = sids.len() as f32 * (height + gap) - gap;
+    let top = area.y + ((area.height - total) / 2.0).max(0.0);
+    sids.iter()
+        .enumerate()
+        .map(|(i, sid)| TileRect {
+            participant_sid: sid.clone(),
+            x: area.x,
+            y: top + i as f32 * (height + gap),
+            width,
+            height,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(mode: LayoutMode, width: f32, height: f32, count: usize) -> LayoutOptions {
+        LayoutOptions {
+            mode,
+            width,
+            height,
+            participant_sids: (0..count).map(|i| format!("p{i}")).collect(),
+            gap: 0.0,
+            ..Default::default()
+        }
+    }
+
+    fn assert_rect(tile: &TileRect, sid: &str, rect: [f32; 4]) {
+        let actual = [tile.x, tile.y, tile.width, tile.height];
+        assert_eq!(tile.participant_sid, sid);
+        for (a, e) in actual.iter().zip(rect) {
+            assert!((a - e).abs() < 0.01, "{sid}: {actual:?} != {rect:?}");
+        }
+    }
+
+    #[test]
+    fn grid_picks_columns_for_the_container_shape() {
+        let tiles = compute_layout(&options(LayoutMode::Grid, 1600.0, 900.0, 4));
+        assert_rect(&tiles[0], "p0", [0.0, 0.0, 800.0, 450.0]);
+        assert_rect(&tiles[3], "p3", [800.0, 450.0, 800.0, 450.0]);
+
+        // An incomplete last row is centered.
+        let tiles = compute_layout(&options(LayoutMode::Grid, 1600.0, 900.0, 3));
+        assert_rect(&tiles[2], "p2", [400.0, 450.0, 800.0, 450.0]);
+
+        // Portrait phones stack tiles.
+        let tiles = compute_layout(&options(LayoutMode::Grid, 360.0, 800.0, 2));
+        assert_rect(&tiles[0], "p0", [0.0, 197.5, 360.0, 202.5]);
+        assert_rect(&tiles[1], "p1", [0.0, 400.0, 360.0, 202.5]);
+
+        let mut with_gap = options(LayoutMode::Grid, 1610.0, 910.0, 4);
+        with_gap.gap = 10.0;
+        let tiles = compute_layout(&with_gap);
+        assert_rect(&tiles[3], "p3", [810.0, 460.0, 800.0, 450.0]);
+    }
+
+    #[test]
+    fn speaker_and_sidebar_put_the_spotlight_first() {
+        let mut speaker = options(LayoutMode::Speaker, 1000.0, 1000.0, 3);
+        speaker.spotlight_sid = Some("p1".into());
+        let tiles = compute_layout(&speaker);
+        // Stage 1000x800, filmstrip of 200-high tiles below it.
+        assert_rect(&tiles[0], "p1", [0.0, 118.75, 1000.0, 562.5]);
+        assert_rect(&tiles[1], "p0", [144.44, 800.0, 355.56, 200.0]);
+        assert_rect(&tiles[2], "p2", [500.0, 800.0, 355.56, 200.0]);
+
+        // Unknown spotlight: the first tile.
+        let mut sidebar = options(LayoutMode::Sidebar, 1600.0, 900.0, 2);
+        sidebar.spotlight_sid = Some("gone".into());
+        let tiles = compute_layout(&sidebar);
+        assert_rect(&tiles[0], "p0", [0.0, 112.5, 1200.0, 675.0]);
+        assert_rect(&tiles[1], "p1", [1200.0, 337.5, 400.0, 225.0]);
+
+        // Portrait sidebar falls back to the filmstrip.
+        sidebar.width = 900.0;
+        sidebar.height = 1600.0;
+        let tiles = compute_layout(&sidebar);
+        assert_eq!(tiles[1].y, 1280.0);
+    }
+
+    #[test]
+    fn degenerate_inputs() {
+        assert!(compute_layout(&options(LayoutMode::Grid, 1600.0, 900.0, 0)).is_empty());
+        assert!(compute_layout(&options(LayoutMode::Grid, 0.0, 900.0, 2)).is_empty());
+        assert!(compute_layout(&options(LayoutMode::Grid, f32::NAN, 900.0, 2)).is_empty());
+
+        // A lone participant fills the container in every mode.
+        let mut alone = options(LayoutMode::Speaker, 1600.0, 900.0, 1);
+        alone.tile_aspect_ratio = 0.0;
+        assert_rect(&compute_layout(&alone)[0], "p0", [0.0, 0.0, 1600.0, 900.0]);
+    }
+}
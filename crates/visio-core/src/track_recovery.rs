@@ -0,0 +1,154 @@
+//! Recovery of stalled remote video tracks.
+//!
+//! Frame delivery is watched by the platform renderers (visio-video), which
+//! report a track that went silent without being muted and report it again
+//! once frames resume. `TrackRecovery` turns these reports into
+//! `TrackStalled` / `TrackResumed` events and resubscribes the stalled
+//! track, which makes the SFU restart the stream. Resubscribing emits the
+//! usual `TrackUnsubscribed` / `TrackSubscribed` pair, so renderers are
+//! restarted the same way as for any new track.
+
+use livekit::prelude::Room;
+use livekit::track::RemoteVideoTrack;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+
+use crate::events::{EventEmitter, VisioEvent};
+
+/// Minimum time between two resubscribes of the same track, so a sender
+/// that stopped producing frames is not resubscribed in a loop.
+const RESUBSCRIBE_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Pause between unsubscribing and subscribing again.
+const RESUBSCRIBE_DELAY: Duration = Duration::from_millis(250);
+
+/// Remembers when each track was last resubscribed.
+#[derive(Default)]
+struct ResubscribeThrottle {
+    last_attempt: HashMap<String, Instant>,
+}
+
+impl ResubscribeThrottle {
+    /// Whether `track_sid` may be resubscribed at `now`; records the attempt.
+    fn try_attempt(&mut self, track_sid: &str, now: Instant) -> bool {
+        if let Some(last) = self.last_attempt.get(track_sid)
+            && now.duration_since(*last) < RESUBSCRIBE_INTERVAL
+        {
+            return false;
+        }
+        self.last_attempt.insert(track_sid.to_string(), now);
+        true
+    }
+
+    fn forget(&mut self, track_sid: &str) {
+        self.last_attempt.remove(track_sid);
+    }
+}
+
+/// Handles stall reports for the tracks of one room. Obtain it with
+/// `RoomManager::track_recovery()`.
+#[derive(Clone)]
+pub struct TrackRecovery {
+    room: Arc<Mutex<Option<Arc<Room>>>>,
+    emitter: EventEmitter,
+    subscribed_tracks: Arc<Mutex<HashMap<String, RemoteVideoTrack>>>,
+    throttle: Arc<std::sync::Mutex<ResubscribeThrottle>>,
+}
+
+impl TrackRecovery {
+    pub(crate) fn new(
+        room: Arc<Mutex<Option<Arc<Room>>>>,
+        emitter: EventEmitter,
+        subscribed_tracks: Arc<Mutex<HashMap<String, RemoteVideoTrack>>>,
+    ) -> Self {
+        Self {
+            room,
+            emitter,
+            subscribed_tracks,
+            throttle: Arc::new(std::sync::Mutex::new(ResubscribeThrottle::default())),
+        }
+    }
+
+    /// No frames arrived on `track_sid` for the stall timeout. Emits
+    /// `TrackStalled` and resubscribes the track (at most once every 30 s).
+    ///
+    /// Returns `false` if the track is not subscribed in this room.
+    pub async fn track_stalled(&self, track_sid: &str) -> bool {
+        if !self.subscribed_tracks.lock().await.contains_key(track_sid) {
+            return false;
+        }
+        tracing::warn!("video track {track_sid} stalled");
+        self.emitter.emit(VisioEvent::TrackStalled {
+            track_sid: track_sid.to_string(),
+        });
+
+        let allowed = self
+            .throttle
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .try_attempt(track_sid, Instant::now());
+        if allowed {
+            self.resubscribe(track_sid).await;
+        }
+        true
+    }
+
+    /// Frames arrived again on a track previously reported as stalled.
+    /// Emits `TrackResumed`.
+    ///
+    /// Returns `false` if the track is not subscribed in this room.
+    pub async fn track_resumed(&self, track_sid: &str) -> bool {
+        if !self.subscribed_tracks.lock().await.contains_key(track_sid) {
+            return false;
+        }
+        tracing::info!("video track {track_sid} resumed");
+        self.throttle
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .forget(track_sid);
+        self.emitter.emit(VisioEvent::TrackResumed {
+            track_sid: track_sid.to_string(),
+        });
+        true
+    }
+
+    async fn resubscribe(&self, track_sid: &str) {
+        let Some(room) = self.room.lock().await.clone() else {
+            return;
+        };
+        let publication = room.remote_participants().values().find_map(|p| {
+            p.track_publications()
+                .into_values()
+                .find(|publication| publication.sid().as_str() == track_sid)
+        });
+        let Some(publication) = publication else {
+            tracing::warn!("cannot resubscribe {track_sid}: publication not found");
+            return;
+        };
+        tracing::info!("resubscribing stalled video track {track_sid}");
+        publication.set_subscribed(false);
+        tokio::time::sleep(RESUBSCRIBE_DELAY).await;
+        publication.set_subscribed(true);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resubscribes_at_most_once_per_interval() {
+        let mut throttle = ResubscribeThrottle::default();
+        let t0 = Instant::now();
+        assert!(throttle.try_attempt("TR_a", t0));
+        assert!(!throttle.try_attempt("TR_a", t0 + Duration::from_secs(10)));
+        assert!(throttle.try_attempt("TR_b", t0 + Duration::from_secs(10)));
+        assert!(throttle.try_attempt("TR_a", t0 + RESUBSCRIBE_INTERVAL));
+
+        // Recovered tracks may be resubscribed again right away.
+        throttle.forget("TR_b");
+        assert!(throttle.try_attempt("TR_b", t0 + Duration::from_secs(11)));
+    }
+}
@@ -0,0 +1,251 @@
+//! Privacy mode of the outgoing video.
+//!
+//! Muting the camera stops the track: receivers drop the picture and, on
+//! unmute, wait for the track to resume and for a new keyframe. For a
+//! quick "hide me" the camera keeps running and the track stays
+//! published, while `video_privacy()` replaces every captured frame: with a
+//! heavily blurred copy of the frame captured when privacy was turned on,
+//! or with a plain card, depending on the `video_privacy_style` setting.
+//! Turn it on and off with `MeetingControls::set_video_privacy`, which
+//! emits `VideoPrivacyChanged`.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, OnceLock};
+
+use serde::{Deserialize, Serialize};
+
+/// Downscale factor of the blur: details smaller than this are gone.
+const BLUR_FACTOR: usize = 24;
+
+/// Luma of the plain card, a dark gray.
+const CARD_LUMA: u8 = 48;
+
+/// What is sent instead of the camera while privacy is on.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VideoPrivacyStyle {
+    /// The frame captured when privacy was turned on, blurred and frozen.
+    #[default]
+    Blur,
+    /// A plain dark card.
+    Card,
+}
+
+/// Replacement planes, packed (stride = width).
+#[derive(Debug)]
+struct Frozen {
+    width: usize,
+    height: usize,
+    y: Vec<u8>,
+    u: Vec<u8>,
+    v: Vec<u8>,
+}
+
+/// Privacy stage of the outgoing video pipeline, reached through
+/// `video_privacy()`.
+#[derive(Debug, Default)]
+pub struct VideoPrivacy {
+    enabled: AtomicBool,
+    style: Mutex<VideoPrivacyStyle>,
+    /// Built from the first frame after privacy was turned on.
+    frozen: Mutex<Option<Frozen>>,
+}
+
+impl VideoPrivacy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::Relaxed)
+    }
+
+    /// Returns whether the state changed.
+    pub fn set_enabled(&self, enabled: bool) -> bool {
+        let changed = self.enabled.swap(enabled, Ordering::Relaxed) != enabled;
+        if changed {
+            *self.frozen() = None;
+        }
+        changed
+    }
+
+    pub fn style(&self) -> VideoPrivacyStyle {
+        *self.style.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Takes effect on the next frame.
+    pub fn set_style(&self, style: VideoPrivacyStyle) {
+        *self.style.lock().unwrap_or_else(|e| e.into_inner()) = style;
+        *self.frozen() = None;
+    }
+
+    /// Replace an I420 frame in place while privacy is on. Returns `false`
+    /// and leaves the frame alone otherwise.
+    pub fn process_i420(
+        &self,
+        y: &mut [u8],
+        u: &mut [u8],
+        v: &mut [u8],
+        width: usize,
+        height: usize,
+        strides: [usize; 3],
+    ) -> bool {
+        if !self.is_enabled() || width == 0 || height == 0 {
+            return false;
+        }
+        let mut frozen = self.frozen();
+        // A new size (rotation, camera switch) needs a new replacement; the
+        // current frame is only ever sent blurred.
+        if frozen
+            .as_ref()
+            .is_none_or(|f| f.width != width || f.height != height)
+        {
+            let planes = [&*y, &*u, &*v];
+            *frozen = Some(match self.style() {
+                VideoPrivacyStyle::Blur => blur(planes, width, height, strides),
+                VideoPrivacyStyle::Card => card(width, height),
+            });
+        }
+        let Some(frozen) = frozen.as_ref() else {
+            return false;
+        };
+
+        let (cw, ch) = (width.div_ceil(2), height.div_ceil(2));
+        copy_plane(&frozen.y, width, height, y, strides[0]);
+        copy_plane(&frozen.u, cw, ch, u, strides[1]);
+        copy_plane(&frozen.v, cw, ch, v, strides[2]);
+        true
+    }
+
+    fn frozen(&self) -> std::sync::MutexGuard<'_, Option<Frozen>> {
+        self.frozen.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Process-wide privacy mode: there is one camera.
+pub fn video_privacy() -> &'static VideoPrivacy {
+    static PRIVACY: OnceLock<VideoPrivacy> = OnceLock::new();
+    PRIVACY.get_or_init(VideoPrivacy::new)
+}
+
+fn card(width: usize, height: usize) -> Frozen {
+    let chroma = width.div_ceil(2) * height.div_ceil(2);
+    Frozen {
+        width,
+        height,
+        y: vec![CARD_LUMA; width * height],
+        u: vec![128; chroma],
+        v: vec![128; chroma],
+    }
+}
+
+fn blur(planes: [&[u8]; 3], width: usize, height: usize, strides: [usize; 3]) -> Frozen {
+    let (cw, ch) = (width.div_ceil(2), height.div_ceil(2));
+    Frozen {
+        width,
+        height,
+        y: blur_plane(planes[0], width, height, strides[0], BLUR_FACTOR),
+        u: blur_plane(planes[1], cw, ch, strides[1], BLUR_FACTOR / 2),
+        v: blur_plane(planes[2], cw, ch, strides[2], BLUR_FACTOR / 2),
+    }
+}
+
+/// Average `factor`-sized blocks, then scale back up with bilinear
+/// interpolation: a strong blur for a fraction of the cost of a wide
+/// kernel. Returns a packed plane.
+fn blur_plane(src: &[u8], w: usize, h: usize, stride: usize, factor: usize) -> Vec<u8> {
+    let (sw, sh) = (w.div_ceil(factor), h.div_ceil(factor));
+    let mut small = vec![0f32; sw * sh];
+    for by in 0..sh {
+        for bx in 0..sw {
+            let (x0, y0) = (bx * factor, by * factor);
+            let (x1, y1) = ((x0 + factor).min(w), (y0 + factor).min(h));
+            let mut sum = 0u32;
+            for row in y0..y1 {
+                let line = &src[row * stride..];
+                sum += line[x0..x1].iter().map(|&p| p as u32).sum::<u32>();
+            }
+            small[by * sw + bx] = sum as f32 / ((x1 - x0) * (y1 - y0)) as f32;
+        }
+    }
+
+    let mut out = vec![0u8; w * h];
+    for row in 0..h {
+        // Position in the small plane, from block centers.
+        let fy = ((row as f32 + 0.5) / factor as f32 - 0.5).clamp(0.0, (sh - 1) as f32);
+        let (y0, ty) = (fy as usize, fy.fract());
+        let y1 = (y0 + 1).min(sh - 1);
+        for col in 0..w {
+            let fx = ((col as f32 + 0.5) / factor as f32 - 0.5).clamp(0.0, (sw - 1) as f32);
+            let (x0, tx) = (fx as usize, fx.fract());
+            let x1 = (x0 + 1).min(sw - 1);
+            let top = small[y0 * sw + x0] * (1.0 - tx) + small[y0 * sw + x1] * tx;
+            let bottom = small[y1 * sw + x0] * (1.0 - tx) + small[y1 * sw + x1] * tx;
+            out[row * w + col] = (top * (1.0 - ty) + bottom * ty + 0.5) as u8;
+        }
+    }
+    out
+}
+
+fn copy_plane(src: &[u8], w: usize, h: usize, dst: &mut [u8], stride: usize) {
+    for row in 0..h {
+        let start = row * stride;
+        if let Some(line) = dst.get_mut(start..start + w) {
+            line.copy_from_slice(&src[row * w..][..w]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 64x48 frame with a sharp vertical edge and padded strides.
+    fn frame() -> (Vec<u8>, Vec<u8>, Vec<u8>) {
+        let y = (0..80 * 48)
+            .map(|i| if i % 80 < 32 { 16 } else { 235 })
+            .collect();
+        (y, vec![90; 40 * 24], vec![160; 40 * 24])
+    }
+
+    #[test]
+    fn blurs_and_freezes_the_frame() {
+        let privacy = VideoPrivacy::new();
+        let (mut y, mut u, mut v) = frame();
+        let strides = [80, 40, 40];
+        assert!(!privacy.process_i420(&mut y, &mut u, &mut v, 64, 48, strides));
+        assert_eq!(y, frame().0);
+
+        assert!(privacy.set_enabled(true));
+        assert!(!privacy.set_enabled(true));
+        assert!(privacy.process_i420(&mut y, &mut u, &mut v, 64, 48, strides));
+        // The edge is smeared, flat chroma stays flat, padding is untouched.
+        let row = &y[24 * 80..][..64];
+        assert!(row[31] > 40 && row[32] < 210, "{row:?}");
+        assert!(row[0] < row[31] && row[32] < row[63]);
+        assert!(u[..32].iter().all(|&p| p == 90));
+        assert_eq!(y[24 * 80 + 70], 235);
+
+        // Later frames get the frozen picture, whatever the camera sees.
+        let blurred = y.clone();
+        let (mut y2, mut u2, mut v2) = (vec![255; 80 * 48], u.clone(), v.clone());
+        privacy.process_i420(&mut y2, &mut u2, &mut v2, 64, 48, strides);
+        assert_eq!(y2[..64], blurred[..64]);
+
+        assert!(privacy.set_enabled(false));
+        let (mut y3, mut u3, mut v3) = frame();
+        assert!(!privacy.process_i420(&mut y3, &mut u3, &mut v3, 64, 48, strides));
+        assert_eq!(y3, frame().0);
+    }
+
+    #[test]
+    fn card_style_sends_a_plain_frame() {
+        let privacy = VideoPrivacy::new();
+        privacy.set_style(VideoPrivacyStyle::Card);
+        privacy.set_enabled(true);
+        let (mut y, mut u, mut v) = frame();
+        privacy.process_i420(&mut y, &mut u, &mut v, 64, 48, [80, 40, 40]);
+        assert!(y[..64].iter().all(|&p| p == CARD_LUMA));
+        assert!(u[..32].iter().chain(&v[..32]).all(|&p| p == 128));
+    }
+}
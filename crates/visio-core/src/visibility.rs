@@ -0,0 +1,117 @@
+//! Subscription priority from the tiles on screen.
+//!
+//! The shell reports which remote video tracks are visible with
+//! `TrackVisibility::set_visible_tracks`. Visible tracks are received at
+//! the highest simulcast layer; hidden ones stay subscribed but are
+//! disabled, so the SFU stops forwarding them and nothing is decoded for
+//! off-screen tiles. Tracks subscribed later get the same treatment.
+//! Until the shell reports anything, every track is visible.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use livekit::prelude::{RemoteTrackPublication, Room};
+use livekit::proto::VideoQuality;
+use livekit::track::TrackKind;
+use tokio::sync::Mutex;
+
+/// Visible remote video tracks of one room. Obtain it with
+/// `RoomManager::visibility()`.
+#[derive(Clone)]
+pub struct TrackVisibility {
+    room: Arc<Mutex<Option<Arc<Room>>>>,
+    /// `None` until the shell reports its tiles.
+    visible: Arc<std::sync::Mutex<Option<HashSet<String>>>>,
+}
+
+impl TrackVisibility {
+    pub(crate) fn new(room: Arc<Mutex<Option<Arc<Room>>>>) -> Self {
+        Self {
+            room,
+            visible: Arc::new(std::sync::Mutex::new(None)),
+        }
+    }
+
+    /// Replace the set of visible remote video tracks and update every
+    /// subscription. Returns the hidden tracks, whose renderers the shell
+    /// may pause.
+    pub async fn set_visible_tracks(&self, track_sids: Vec<String>) -> Vec<String> {
+        let visible: HashSet<String> = track_sids.into_iter().collect();
+        *self.lock() = Some(visible.clone());
+
+        let Some(room) = self.room.lock().await.clone() else {
+            return Vec::new();
+        };
+        let mut hidden = Vec::new();
+        for participant in room.remote_participants().values() {
+            for (sid, publication) in participant.track_publications() {
+                if publication.kind() != TrackKind::Video {
+                    continue;
+                }
+                let sid = sid.to_string();
+                let is_visible = visible.contains(&sid);
+                apply(&publication, is_visible);
+                if !is_visible {
+                    hidden.push(sid);
+                }
+            }
+        }
+        tracing::debug!(
+            visible = visible.len(),
+            hidden = hidden.len(),
+            "track visibility updated"
+        );
+        hidden
+    }
+
+    /// Whether `track_sid` is on screen. Every track is until the shell
+    /// reports its tiles.
+    pub fn is_visible(&self, track_sid: &str) -> bool {
+        self.lock()
+            .as_ref()
+            .is_none_or(|visible| visible.contains(track_sid))
+    }
+
+    /// Apply the reported visibility to a newly subscribed track.
+    pub(crate) fn on_subscribed(&self, publication: &RemoteTrackPublication) {
+        if publication.kind() == TrackKind::Video && self.lock().is_some() {
+            apply(publication, self.is_visible(publication.sid().as_str()));
+        }
+    }
+
+    /// Forget the tiles when leaving the room.
+    pub(crate) fn reset(&self) {
+        *self.lock() = None;
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Option<HashSet<String>>> {
+        self.visible.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+fn apply(publication: &RemoteTrackPublication, visible: bool) {
+    publication.set_enabled(visible);
+    if visible {
+        publication.set_video_quality(VideoQuality::High);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn everything_is_visible_until_reported() {
+        let visibility = TrackVisibility::new(Arc::new(Mutex::new(None)));
+        assert!(visibility.is_visible("TR_a"));
+
+        // No room yet: nothing to pause, but the tiles are kept.
+        let hidden = visibility.set_visible_tracks(vec!["TR_a".into()]).await;
+        assert!(hidden.is_empty());
+        assert!(visibility.is_visible("TR_a"));
+        assert!(!visibility.is_visible("TR_b"));
+
+        visibility.reset();
+        assert!(visibility.is_visible("TR_b"));
+    }
+}
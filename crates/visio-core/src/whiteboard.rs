@@ -0,0 +1,123 @@
+//! Collaborative whiteboard of the current room.
+//!
+//! The whiteboard is a web application hosted by the instance. The Meet
+//! API hands out its URL and, when the whiteboard does not rely on the
+//! Meet session cookie, a short-lived token; shells open it in a webview.
+//! Instances advertise it with the `whiteboard` feature flag, and
+//! `VisioEvent::WhiteboardAvailable` is emitted after connecting to one.
+
+use serde::Deserialize;
+
+use crate::errors::VisioError;
+use crate::meet_api::{MeetApi, WhiteboardRequest};
+
+/// Where and how to open the whiteboard of a room.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WhiteboardSession {
+    /// Absolute URL of the whiteboard.
+    pub url: String,
+    /// Token to pass to the whiteboard, if the instance issues one.
+    pub token: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct WhiteboardResponse {
+    url: String,
+    #[serde(default)]
+    token: Option<String>,
+}
+
+/// Fetches whiteboard sessions from the Meet API.
+pub struct WhiteboardService;
+
+impl WhiteboardService {
+    /// Whiteboard session of the room `room_id` on `instance`. URLs
+    /// relative to the instance are made absolute.
+    ///
+    /// Fails with `VisioError::Room` if the instance has no whiteboard.
+    pub async fn get_session_with(
+        api: &dyn MeetApi,
+        instance: &str,
+        room_id: &str,
+        session_cookie: Option<&str>,
+    ) -> Result<WhiteboardSession, VisioError> {
+        let request = WhiteboardRequest {
+            instance: instance.to_string(),
+            room_id: room_id.to_string(),
+            session_cookie: session_cookie.map(str::to_string),
+        };
+        let resp = api.get_whiteboard_session(&request).await?;
+        match resp.status {
+            200..=299 => {}
+            401 | 403 => return Err(VisioError::AuthRequired),
+            404 | 405 => {
+                return Err(VisioError::Room(
+                    "whiteboard is not supported by this instance".into(),
+                ));
+            }
+            status => {
+                return Err(VisioError::Room(format!(
+                    "whiteboard request failed with status {status}"
+                )));
+            }
+        }
+
+        let body: WhiteboardResponse = serde_json::from_str(&resp.body)
+            .map_err(|e| VisioError::Http(format!("invalid whiteboard response: {e}")))?;
+        let url = if body.url.starts_with('/') {
+            format!("https://{instance}{}", body.url)
+        } else {
+            body.url
+        };
+        Ok(WhiteboardSession {
+            url,
+            token: body.token.filter(|t| !t.is_empty()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::meet_api::MockMeetApi;
+
+    #[tokio::test]
+    async fn resolves_session_and_maps_errors() {
+        let api = MockMeetApi::new();
+        api.push_response(
+            200,
+            r#"{"url": "/whiteboard/room-uuid", "token": "wb-jwt"}"#,
+        );
+        api.push_response(
+            200,
+            r#"{"url": "https://board.example.com/r/1", "token": ""}"#,
+        );
+        api.push_response(404, "");
+
+        let session =
+            WhiteboardService::get_session_with(&api, "meet.example.com", "room-uuid", Some("c"))
+                .await
+                .unwrap();
+        assert_eq!(
+            session,
+            WhiteboardSession {
+                url: "https://meet.example.com/whiteboard/room-uuid".into(),
+                token: Some("wb-jwt".into()),
+            }
+        );
+        let session =
+            WhiteboardService::get_session_with(&api, "meet.example.com", "room-uuid", None)
+                .await
+                .unwrap();
+        assert_eq!(session.url, "https://board.example.com/r/1");
+        assert_eq!(session.token, None);
+        let err =
+            WhiteboardService::get_session_with(&api, "meet.example.com", "room-uuid", None).await;
+        assert!(matches!(err, Err(VisioError::Room(msg)) if msg.contains("not supported")));
+
+        let requests = api.whiteboard_requests();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[0].room_id, "room-uuid");
+        assert_eq!(requests[0].session_cookie.as_deref(), Some("c"));
+    }
+}
@@ -14,7 +14,10 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use livekit_api::access_token::{AccessToken, VideoGrants};
-use visio_core::{ConnectionState, RoomManager, VisioEvent, VisioEventListener};
+use visio_core::{
+    ConnectionState, MockMeetApi, RoomManager, TestMediaOptions, TrackKind, VisioEvent,
+    VisioEventListener,
+};
 
 fn livekit_url() -> String {
     std::env::var("LIVEKIT_URL").unwrap_or_else(|_| "ws://localhost:7880".to_string())
@@ -99,8 +102,7 @@ async fn test_connect_and_disconnect() {
                 .states
                 .lock()
                 .unwrap()
-                .iter()
-                .any(|s| *s == ConnectionState::Disconnected)
+                .contains(&ConnectionState::Disconnected)
         },
         Duration::from_secs(5),
     )
@@ -200,3 +202,100 @@ async fn test_mute_unmute_propagation() {
     rm1.disconnect().await;
     rm2.disconnect().await;
 }
+
+#[tokio::test]
+async fn test_switch_room_drops_the_old_room_polls() {
+    let room1 = format!("test-switch-{}", uuid::Uuid::new_v4());
+    let room2 = format!("test-switch-{}", uuid::Uuid::new_v4());
+    let token1 = make_token("alice", "Alice", &room1);
+    let token2 = make_token("alice", "Alice", &room2);
+    let api = Arc::new(MockMeetApi::new());
+    let rm = RoomManager::with_meet_api(api.clone());
+
+    rm.connect_with_token(&livekit_url(), &token1)
+        .await
+        .expect("connect failed");
+    rm.polls()
+        .create_poll("Lunch?", vec!["Pizza".into(), "Sushi".into()])
+        .await
+        .expect("create poll");
+    assert_eq!(rm.polls().polls().await.len(), 1);
+
+    api.push_response(
+        200,
+        format!(
+            r#"{{"livekit":{{"url":"{}","token":"{token2}"}}}}"#,
+            livekit_url()
+        ),
+    );
+    rm.switch_room("https://meet.example.com/abc-defg-hij", None)
+        .await
+        .expect("switch failed");
+    assert!(rm.polls().polls().await.is_empty());
+
+    rm.disconnect().await;
+}
+
+/// Listener that records subscribed video track SIDs.
+struct VideoTrackCapture {
+    tracks: std::sync::Mutex<Vec<String>>,
+}
+
+impl VisioEventListener for VideoTrackCapture {
+    fn on_event(&self, event: VisioEvent) {
+        if let VisioEvent::TrackSubscribed(info) = event
+            && info.kind == TrackKind::Video
+        {
+            self.tracks.lock().unwrap().push(info.sid);
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_synthetic_camera_is_received() {
+    let room_name = format!("test-synth-{}", uuid::Uuid::new_v4());
+    let token1 = make_token("alice", "Alice", &room_name);
+    let token2 = make_token("bob", "Bob", &room_name);
+    let url = livekit_url();
+
+    let rm1 = RoomManager::new();
+    let rm2 = RoomManager::new();
+    let capture = Arc::new(VideoTrackCapture {
+        tracks: std::sync::Mutex::new(Vec::new()),
+    });
+    rm2.add_listener(capture.clone());
+
+    let controls1 = rm1.controls();
+    controls1
+        .set_test_media(TestMediaOptions::audio_video())
+        .await;
+
+    rm1.connect_with_token(&url, &token1)
+        .await
+        .expect("connect rm1");
+    rm2.connect_with_token(&url, &token2)
+        .await
+        .expect("connect rm2");
+
+    controls1
+        .publish_camera()
+        .await
+        .expect("publish synthetic camera");
+    controls1
+        .publish_microphone()
+        .await
+        .expect("publish synthetic microphone");
+
+    let subscribed = wait_for(
+        || !capture.tracks.lock().unwrap().is_empty(),
+        Duration::from_secs(10),
+    )
+    .await;
+    assert!(
+        subscribed,
+        "bob should subscribe to alice's synthetic camera"
+    );
+
+    rm1.disconnect().await;
+    rm2.disconnect().await;
+}
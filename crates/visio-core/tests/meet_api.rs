@@ -0,0 +1,502 @@
+//! Offline tests for the Meet API token flow using `MockMeetApi`.
+//!
+//! No network or LiveKit server required:
+//! ```sh
+//! cargo test -p visio-core --test meet_api
+//! ```
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use visio_core::meet_api::MeetApiFuture;
+use visio_core::{
+    AuthService, CheckResult, ConnectStep, ConnectTimeouts, ConnectionState, ConnectivityDiagnosis,
+    DialInInfo, EntryStatus, FeatureFlags, InviteService, JoinOptions, MeetApi, MockClock,
+    MockMeetApi, ReconnectPolicy, RoomAccess, RoomManager, RoomRequest, VisioError, VisioEvent,
+    VisioEventListener,
+};
+
+const MEET_URL: &str = "https://meet.example.com/abc-defg-hij";
+
+/// Room lookup answer for a restricted room we were not let into yet.
+const RESTRICTED_ROOM: &str =
+    r#"{"id":"room-uuid","slug":"abc-defg-hij","access_level":"restricted"}"#;
+
+/// Listener that captures connection state changes.
+struct StateCapture {
+    states: std::sync::Mutex<Vec<ConnectionState>>,
+}
+
+impl VisioEventListener for StateCapture {
+    fn on_event(&self, event: VisioEvent) {
+        if let VisioEvent::ConnectionStateChanged(state) = event {
+            self.states.lock().unwrap().push(state);
+        }
+    }
+}
+
+#[tokio::test]
+async fn token_request_returns_websocket_url() {
+    let api = MockMeetApi::new();
+    api.push_token("https://livekit.example.com", "jwt-token");
+
+    let info = AuthService::request_token_with(&api, MEET_URL, Some("Jean Dupont"), Some("abc"))
+        .await
+        .expect("token");
+    assert_eq!(info.livekit_url, "wss://livekit.example.com");
+    assert_eq!(info.token, "jwt-token");
+    assert_eq!(info.access_level, None);
+
+    let requests = api.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].instance, "meet.example.com");
+    assert_eq!(requests[0].slug, "abc-defg-hij");
+    assert_eq!(requests[0].username.as_deref(), Some("Jean Dupont"));
+    assert_eq!(requests[0].session_cookie.as_deref(), Some("abc"));
+}
+
+#[tokio::test]
+async fn token_request_reads_room_access_level() {
+    let api = MockMeetApi::new();
+    api.push_response(
+        200,
+        r#"{"access_level":"restricted","livekit":{"url":"https://lk","token":"t"}}"#,
+    );
+
+    let info = AuthService::request_token_with(&api, MEET_URL, None, None)
+        .await
+        .expect("token");
+    assert_eq!(info.access_level.as_deref(), Some("restricted"));
+    assert_eq!(info.scheduled_end, None);
+    assert_eq!(info.max_duration, None);
+}
+
+#[tokio::test]
+async fn token_request_reads_meeting_end() {
+    let api = MockMeetApi::new();
+    api.push_response(
+        200,
+        r#"{"end_time":"2026-03-02T10:30:00+01:00","max_duration":3600,"livekit":{"url":"https://lk","token":"t"}}"#,
+    );
+
+    let info = AuthService::request_token_with(&api, MEET_URL, None, None)
+        .await
+        .expect("token");
+    assert_eq!(
+        info.scheduled_end.map(|t| t.to_rfc3339()).as_deref(),
+        Some("2026-03-02T09:30:00+00:00")
+    );
+    assert_eq!(info.max_duration, Some(Duration::from_secs(3600)));
+}
+
+#[tokio::test]
+async fn room_not_found_is_auth_error() {
+    let api = MockMeetApi::new();
+    api.push_response(404, r#"{"detail":"Not found."}"#);
+
+    let err = AuthService::validate_room_with(&api, MEET_URL, None, None)
+        .await
+        .unwrap_err();
+    match err {
+        VisioError::Auth(msg) => assert!(msg.contains("404"), "got: {msg}"),
+        other => panic!("expected Auth error, got {other:?}"),
+    }
+}
+
+#[tokio::test]
+async fn unauthorized_and_redirect_require_login() {
+    let api = MockMeetApi::new();
+    api.push_response(401, "");
+    api.push_response(302, "");
+
+    for _ in 0..2 {
+        let err = AuthService::request_token_with(&api, MEET_URL, None, None)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, VisioError::AuthRequired), "got {err:?}");
+    }
+}
+
+#[tokio::test]
+async fn malformed_responses_are_rejected() {
+    let api = MockMeetApi::new();
+    api.push_response(200, "<html>maintenance</html>");
+    api.push_response(200, r#"{"livekit":{"url":"https://lk"}}"#);
+    api.push_response(200, r#"{"id":"room"}"#);
+
+    for _ in 0..3 {
+        let err = AuthService::request_token_with(&api, MEET_URL, None, None)
+            .await
+            .unwrap_err();
+        match err {
+            VisioError::Auth(msg) => {
+                assert!(msg.contains("invalid Meet API response"), "got: {msg}")
+            }
+            other => panic!("expected Auth error, got {other:?}"),
+        }
+    }
+}
+
+#[tokio::test]
+async fn transport_errors_are_propagated() {
+    let api = MockMeetApi::new();
+    api.push_error(VisioError::Http("connection refused".into()));
+
+    let err = AuthService::request_token_with(&api, MEET_URL, None, None)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, VisioError::Http(_)), "got {err:?}");
+}
+
+#[tokio::test]
+async fn invalid_url_never_reaches_the_api() {
+    let api = MockMeetApi::new();
+    let err = AuthService::request_token_with(&api, "not-a-url", None, None)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, VisioError::InvalidUrl(_)), "got {err:?}");
+    assert!(api.requests().is_empty());
+}
+
+#[tokio::test]
+async fn connect_token_error_returns_to_disconnected() {
+    let api = Arc::new(MockMeetApi::new());
+    api.push_response(500, "internal error");
+
+    let rm = RoomManager::with_meet_api(api.clone());
+    let capture = Arc::new(StateCapture {
+        states: std::sync::Mutex::new(Vec::new()),
+    });
+    rm.add_listener(capture.clone());
+    rm.set_session_cookie(Some("cookie".into())).await;
+
+    let err = rm
+        .connect(MEET_URL, &JoinOptions::new().username("bot"))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, VisioError::Auth(_)), "got {err:?}");
+    assert_eq!(rm.connection_state().await, ConnectionState::Disconnected);
+    assert_eq!(
+        *capture.states.lock().unwrap(),
+        vec![ConnectionState::Connecting, ConnectionState::Disconnected]
+    );
+
+    let requests = api.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].username.as_deref(), Some("bot"));
+    assert_eq!(requests[0].session_cookie.as_deref(), Some("cookie"));
+}
+
+#[tokio::test]
+async fn switch_room_without_current_room_connects() {
+    let api = Arc::new(MockMeetApi::new());
+    api.push_error(VisioError::Http("connection refused".into()));
+
+    let rm = RoomManager::with_meet_api(api.clone());
+    let capture = Arc::new(StateCapture {
+        states: std::sync::Mutex::new(Vec::new()),
+    });
+    rm.add_listener(capture.clone());
+
+    let err = rm.switch_room(MEET_URL, None).await.unwrap_err();
+    assert!(matches!(err, VisioError::Http(_)), "got {err:?}");
+    assert_eq!(
+        *capture.states.lock().unwrap(),
+        vec![ConnectionState::Connecting, ConnectionState::Disconnected]
+    );
+    assert_eq!(api.requests().len(), 1);
+}
+
+#[tokio::test]
+async fn connect_failure_carries_connectivity_diagnosis() {
+    let api = Arc::new(MockMeetApi::new());
+    api.push_error(VisioError::Http("error sending request".into()));
+    api.set_diagnosis(ConnectivityDiagnosis {
+        host: "meet.example.com".into(),
+        port: 443,
+        dns: CheckResult::Failed("resolves to a loopback address".into()),
+        ipv4_addresses: vec!["127.0.0.1".into()],
+        ipv6_addresses: vec![],
+        tcp_ipv4: CheckResult::Skipped,
+        tcp_ipv6: CheckResult::Skipped,
+        tls: CheckResult::Skipped,
+        websocket: CheckResult::Skipped,
+        captive_portal: false,
+    });
+
+    let rm = RoomManager::with_meet_api(api.clone());
+    let err = rm.connect(MEET_URL, &JoinOptions::new()).await.unwrap_err();
+    match err {
+        VisioError::Unreachable { message, diagnosis } => {
+            assert!(message.contains("error sending request"), "{message}");
+            assert_eq!(diagnosis.host, "meet.example.com");
+            assert!(diagnosis.dns.is_failed());
+        }
+        err => panic!("expected Unreachable, got {err:?}"),
+    }
+    assert_eq!(rm.connection_state().await, ConnectionState::Disconnected);
+}
+
+/// Meet API that never answers.
+struct HangingMeetApi;
+
+impl MeetApi for HangingMeetApi {
+    fn get_room<'a>(&'a self, _request: &'a RoomRequest) -> MeetApiFuture<'a> {
+        Box::pin(std::future::pending())
+    }
+}
+
+/// Listener that captures connection progress steps.
+struct ProgressCapture {
+    steps: std::sync::Mutex<Vec<ConnectStep>>,
+}
+
+impl VisioEventListener for ProgressCapture {
+    fn on_event(&self, event: VisioEvent) {
+        if let VisioEvent::ConnectProgress(step) = event {
+            self.steps.lock().unwrap().push(step);
+        }
+    }
+}
+
+#[tokio::test]
+async fn meet_api_timeout_returns_to_disconnected() {
+    let rm = RoomManager::with_meet_api(Arc::new(HangingMeetApi));
+    rm.set_connect_timeouts(ConnectTimeouts {
+        meet_api: Duration::from_millis(50),
+        ..Default::default()
+    })
+    .await;
+    let progress = Arc::new(ProgressCapture {
+        steps: std::sync::Mutex::new(Vec::new()),
+    });
+    rm.add_listener(progress.clone());
+
+    let err = rm.connect(MEET_URL, &JoinOptions::new()).await.unwrap_err();
+    assert!(matches!(err, VisioError::Timeout(_)), "got {err:?}");
+    assert_eq!(rm.connection_state().await, ConnectionState::Disconnected);
+    assert_eq!(
+        *progress.steps.lock().unwrap(),
+        vec![ConnectStep::TokenRequested]
+    );
+}
+
+#[tokio::test]
+async fn reconnect_backs_off_on_the_clock() {
+    let api = Arc::new(MockMeetApi::new());
+    let clock = MockClock::new();
+    let rm = RoomManager::with_clock(api.clone(), Arc::new(clock.clone()));
+    rm.set_reconnect_policy(ReconnectPolicy {
+        max_attempts: 3,
+        ..Default::default()
+    })
+    .await;
+    // No response is queued: every token request fails.
+    rm.connect(MEET_URL, &JoinOptions::new()).await.unwrap_err();
+    let capture = Arc::new(StateCapture {
+        states: std::sync::Mutex::new(Vec::new()),
+    });
+    rm.add_listener(capture.clone());
+
+    // Failed attempts 1 and 2 wait 1 s then 2 s, on the mock clock only.
+    let drive = async {
+        for wait in [1, 2] {
+            while clock.pending_sleeps() == 0 {
+                tokio::task::yield_now().await;
+            }
+            clock.advance(Duration::from_secs(wait));
+        }
+    };
+    let (result, ()) = tokio::join!(rm.reconnect(), drive);
+
+    let err = result.unwrap_err();
+    assert!(matches!(err, VisioError::Connection(_)), "got {err:?}");
+    assert_eq!(api.requests().len(), 4);
+    let states = capture.states.lock().unwrap();
+    let attempts: Vec<u32> = states
+        .iter()
+        .filter_map(|s| match s {
+            ConnectionState::Reconnecting { attempt } => Some(*attempt),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(attempts, vec![1, 2, 3]);
+    assert_eq!(states.last(), Some(&ConnectionState::Disconnected));
+}
+
+#[tokio::test]
+async fn restricted_room_goes_through_the_lobby() {
+    let api = MockMeetApi::new();
+    api.push_response(200, RESTRICTED_ROOM);
+    api.push_entry_status("waiting", Some("lobby-1"));
+    api.push_entry_status("accepted", None);
+
+    let access = AuthService::lookup_room_with(&api, MEET_URL, Some("Jean"), Some("abc"))
+        .await
+        .expect("lookup");
+    let RoomAccess::LobbyRequired(mut entry) = access else {
+        panic!("expected the lobby, got {access:?}");
+    };
+    assert_eq!(entry.instance, "meet.example.com");
+    assert_eq!(entry.room_id, "room-uuid");
+
+    let status = AuthService::request_entry_with(&api, &mut entry)
+        .await
+        .expect("entry");
+    assert!(matches!(status, EntryStatus::Waiting), "got {status:?}");
+    assert_eq!(entry.lobby_cookie.as_deref(), Some("lobby-1"));
+
+    let status = AuthService::request_entry_with(&api, &mut entry)
+        .await
+        .expect("entry");
+    let EntryStatus::Granted(info) = status else {
+        panic!("expected entry, got {status:?}");
+    };
+    assert_eq!(info.livekit_url, "wss://lk");
+    assert_eq!(info.access_level.as_deref(), Some("restricted"));
+
+    // The second poll is sent as the same waiting participant.
+    let entries = api.entry_requests();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].lobby_cookie, None);
+    assert_eq!(entries[1].lobby_cookie.as_deref(), Some("lobby-1"));
+    assert_eq!(entries[1].username.as_deref(), Some("Jean"));
+    assert_eq!(entries[1].session_cookie.as_deref(), Some("abc"));
+
+    // Without the lobby flow, a restricted room is an error.
+    api.push_response(200, RESTRICTED_ROOM);
+    let err = AuthService::request_token_with(&api, MEET_URL, None, None)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, VisioError::Auth(_)), "got {err:?}");
+}
+
+/// Listener that captures lobby events.
+struct LobbyCapture {
+    events: std::sync::Mutex<Vec<&'static str>>,
+}
+
+impl VisioEventListener for LobbyCapture {
+    fn on_event(&self, event: VisioEvent) {
+        let name = match event {
+            VisioEvent::WaitingForHost => "waiting",
+            VisioEvent::EntryGranted => "granted",
+            VisioEvent::EntryDenied => "denied",
+            _ => return,
+        };
+        self.events.lock().unwrap().push(name);
+    }
+}
+
+#[tokio::test]
+async fn connect_fails_when_host_denies_entry() {
+    let api = Arc::new(MockMeetApi::new());
+    api.push_response(200, RESTRICTED_ROOM);
+    api.push_entry_status("denied", None);
+
+    let rm = RoomManager::with_meet_api(api.clone());
+    let capture = Arc::new(LobbyCapture {
+        events: std::sync::Mutex::new(Vec::new()),
+    });
+    rm.add_listener(capture.clone());
+
+    let err = rm
+        .connect(MEET_URL, &JoinOptions::new().username("bot"))
+        .await
+        .unwrap_err();
+    assert!(matches!(err, VisioError::Auth(_)), "got {err:?}");
+    assert_eq!(rm.connection_state().await, ConnectionState::Disconnected);
+    assert_eq!(*capture.events.lock().unwrap(), vec!["waiting", "denied"]);
+    assert_eq!(api.entry_requests()[0].username.as_deref(), Some("bot"));
+}
+
+#[tokio::test]
+async fn invite_info_includes_dial_in_when_available() {
+    let api = MockMeetApi::new();
+    api.push_response(
+        200,
+        r#"{"telephony":{"enabled":true,"phone_number":"+33 1 23 45 67 89"}}"#,
+    );
+
+    let info =
+        InviteService::get_invite_info_with(&api, "meet.example.com/abc-defg-hij/", Some("123456"))
+            .await
+            .expect("invite info");
+    assert_eq!(info.url, "https://meet.example.com/abc-defg-hij");
+    assert_eq!(
+        info.dial_in,
+        Some(DialInInfo {
+            phone_number: "+33 1 23 45 67 89".into(),
+            pin_code: "123456".into(),
+        })
+    );
+
+    // Config failures only drop the dial-in details.
+    api.push_response(500, "internal error");
+    let info = InviteService::get_invite_info_with(&api, MEET_URL, Some("123456"))
+        .await
+        .expect("invite info");
+    assert_eq!(info.dial_in, None);
+}
+
+#[tokio::test]
+async fn invite_participants_posts_emails() {
+    let api = MockMeetApi::new();
+    api.push_response(200, "{}");
+    api.push_response(404, r#"{"detail":"Not found."}"#);
+
+    let emails = vec![" alice@example.com ".to_string(), String::new()];
+    InviteService::invite_participants_with(
+        &api,
+        "meet.example.com",
+        "room-uuid",
+        emails,
+        Some("abc"),
+    )
+    .await
+    .expect("invite");
+    let requests = api.invite_requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].room_id, "room-uuid");
+    assert_eq!(requests[0].emails, vec!["alice@example.com"]);
+    assert_eq!(requests[0].session_cookie.as_deref(), Some("abc"));
+
+    let err = InviteService::invite_participants_with(
+        &api,
+        "meet.example.com",
+        "room-uuid",
+        vec!["bob@example.com".into()],
+        None,
+    )
+    .await
+    .unwrap_err();
+    assert!(matches!(err, VisioError::Room(_)), "got {err:?}");
+
+    let err = InviteService::invite_participants_with(
+        &api,
+        "meet.example.com",
+        "room-uuid",
+        vec!["not-an-email".into()],
+        None,
+    )
+    .await
+    .unwrap_err();
+    assert!(matches!(err, VisioError::Room(_)), "got {err:?}");
+    assert_eq!(api.invite_requests().len(), 2);
+}
+
+#[tokio::test]
+async fn feature_flags_fall_back_to_defaults() {
+    let api = MockMeetApi::new();
+    api.push_response(
+        200,
+        r#"{"recording":{"is_enabled":true,"available_modes":["screen_recording"]}}"#,
+    );
+    api.push_response(500, "internal error");
+
+    let flags = FeatureFlags::fetch_with(&api, "meet.example.com").await;
+    assert!(flags.recording && flags.chat && !flags.transcription);
+
+    let flags = FeatureFlags::fetch_with(&api, "meet.example.com").await;
+    assert_eq!(flags, FeatureFlags::default());
+}
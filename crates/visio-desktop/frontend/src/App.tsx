@@ -70,6 +70,30 @@ interface Settings {
   theme: string;
 }
 
+interface CheckResult {
+  status: "passed" | "failed" | "skipped";
+  reason?: string;
+}
+
+interface DeviceCheckReport {
+  microphone: CheckResult;
+  microphoneLevel: number;
+  camera: CheckResult;
+  cameraFrames: number;
+  speaker: CheckResult;
+  hasProblem: boolean;
+}
+
+type DeepLink =
+  | {
+      kind: "joinRoom";
+      meetUrl: string;
+      instance: string;
+      slug: string;
+      displayName: string | null;
+    }
+  | { kind: "openSettings" };
+
 interface ReactionData {
   id: number;
   participantSid: string;
@@ -292,6 +316,8 @@ function HomeView({
   const [joining, setJoining] = useState(false);
   const [roomStatus, setRoomStatus] = useState<"idle" | "checking" | "valid" | "not_found" | "auth_required" | "authenticating" | "error">("idle");
   const [meetInstances, setMeetInstances] = useState<string[]>([]);
+  const [deviceCheck, setDeviceCheck] = useState<DeviceCheckReport | null>(null);
+  const [checkingDevices, setCheckingDevices] = useState(false);
 
   useEffect(() => {
     invoke<string[]>("get_meet_instances").then(setMeetInstances).catch(() => {});
@@ -397,6 +423,17 @@ function HomeView({
     if (e.key === "Enter") handleJoin();
   };
 
+  const handleDeviceCheck = async () => {
+    setCheckingDevices(true);
+    try {
+      setDeviceCheck(await invoke<DeviceCheckReport>("run_device_check", { checkCamera: true }));
+    } catch (e) {
+      setError(String(e));
+    } finally {
+      setCheckingDevices(false);
+    }
+  };
+
   return (
     <div id="home" className="section active">
       <button className="settings-gear" onClick={onOpenSettings}>
@@ -445,6 +482,21 @@ function HomeView({
             {joining ? t("home.connecting") : t("home.join")}
           </button>
         )}
+        <button className="btn btn-secondary" disabled={checkingDevices} onClick={handleDeviceCheck}>
+          {checkingDevices ? t("home.deviceCheck.running") : t("home.deviceCheck")}
+        </button>
+        {deviceCheck && !deviceCheck.hasProblem && (
+          <div className="room-status valid">{t("home.deviceCheck.ok")}</div>
+        )}
+        {deviceCheck?.microphone.status === "failed" && (
+          <div className="room-status error">{t("home.deviceCheck.micSilent")}</div>
+        )}
+        {deviceCheck?.camera.status === "failed" && (
+          <div className="room-status error">{t("home.deviceCheck.noCamera")}</div>
+        )}
+        {deviceCheck?.speaker.status === "failed" && (
+          <div className="room-status error">{t("home.deviceCheck.noSpeaker")}</div>
+        )}
         <div className="error-msg">{error}</div>
       </div>
     </div>
@@ -1315,6 +1367,11 @@ function SettingsModal({
 export default function App() {
   const [view, setView] = useState<View>("home");
   const [connectionState, setConnectionState] = useState("disconnected");
+  // Minutes left before the meeting's scheduled end, once warned.
+  const [meetingMinutesLeft, setMeetingMinutesLeft] = useState<number | null>(null);
+  // Idle checks of the core: alone in the room, or no activity for a while.
+  const [aloneInRoom, setAloneInRoom] = useState(false);
+  const [inactivityPrompt, setInactivityPrompt] = useState(false);
   const [participants, setParticipants] = useState<Participant[]>([]);
   const [localParticipant, setLocalParticipant] = useState<Participant | null>(null);
   const [messages, setMessages] = useState<ChatMessage[]>([]);
@@ -1374,24 +1431,23 @@ export default function App() {
   useEffect(() => {
     const unlisten = onOpenUrl((urls: string[]) => {
       if (urls.length === 0) return;
-      const url = urls[0];
-      try {
-        const parsed = new URL(url);
-        if (parsed.protocol !== "visio:") return;
-        const host = parsed.hostname;
-        const slug = parsed.pathname.replace(/^\//, "");
-        if (!host || !slug) return;
-
+      invoke<DeepLink | null>("parse_deep_link", { uri: urls[0] }).then((link) => {
+        if (!link) return;
+        if (link.kind === "openSettings") {
+          setShowSettings(true);
+          return;
+        }
         invoke<string[]>("get_meet_instances").then((instances) => {
-          if (instances.includes(host)) {
+          if (instances.includes(link.instance)) {
             setView("home");
-            setDeepLinkUrl(`https://${host}/${slug}`);
+            setDeepLinkUrl(link.meetUrl);
+            if (link.displayName) setDisplayName(link.displayName);
             setDeepLinkError(null);
           } else {
-            setDeepLinkError(t("deepLink.unknownInstance").replace("{host}", host));
+            setDeepLinkError(t("deepLink.unknownInstance").replace("{host}", link.instance));
           }
         });
-      } catch { /* ignore malformed URLs */ }
+      });
     });
     return () => { unlisten.then((fn) => fn()); };
   }, []);
@@ -1520,6 +1576,7 @@ export default function App() {
     let unlistenHand: UnlistenFn | null = null;
     let unlistenUnread: UnlistenFn | null = null;
     let unlistenSpeakers: UnlistenFn | null = null;
+    let unlistenAction: UnlistenFn | null = null;
 
     listen<{ participantSid: string; raised: boolean; position: number }>(
       "hand-raised-changed",
@@ -1554,17 +1611,42 @@ export default function App() {
       unlistenSpeakers = fn;
     });
 
+    // Global shortcuts act outside the UI: follow the resulting state.
+    listen<{ micEnabled: boolean; cameraEnabled: boolean; handRaised: boolean; chatOpen: boolean }>(
+      "meeting-action",
+      (event) => {
+        const { micEnabled, cameraEnabled, handRaised, chatOpen } = event.payload;
+        setMicEnabled(micEnabled);
+        setCamEnabled(cameraEnabled);
+        setIsHandRaised(handRaised);
+        setShowChat(chatOpen);
+      }
+    ).then((fn) => {
+      unlistenAction = fn;
+    });
+
     return () => {
       if (unlistenHand) unlistenHand();
       if (unlistenUnread) unlistenUnread();
       if (unlistenSpeakers) unlistenSpeakers();
+      if (unlistenAction) unlistenAction();
     };
   }, [view]);
 
   // ---- Handlers -----------------------------------------------------------
-  const handleJoin = (meetUrl: string) => {
+  const handleJoin = async (meetUrl: string) => {
     setCurrentMeetUrl(meetUrl);
     setView("call");
+    // The core applied the mic/camera-on-join settings while connecting.
+    try {
+      const media = await invoke<{ micEnabled: boolean; cameraEnabled: boolean }>(
+        "get_media_state"
+      );
+      setMicEnabled(media.micEnabled);
+      setCamEnabled(media.cameraEnabled);
+    } catch (e) {
+      console.error("get_media_state error:", e);
+    }
   };
 
   const handleToggleMic = async () => {
@@ -1611,8 +1693,137 @@ export default function App() {
     setActiveSpeakers([]);
     setLocalParticipant(null);
     setCurrentMeetUrl("");
+    setMeetingMinutesLeft(null);
+    setAloneInRoom(false);
+    setInactivityPrompt(false);
+  };
+
+  // Scheduled end of the meeting: warn, then show it is over.
+  useEffect(() => {
+    if (view === "home") return;
+    let unlistenSoon: UnlistenFn | null = null;
+    let unlistenEnded: UnlistenFn | null = null;
+    listen<number>("meeting-ending-soon", (event) => {
+      setMeetingMinutesLeft(event.payload);
+    }).then((fn) => {
+      unlistenSoon = fn;
+    });
+    listen("meeting-ended", () => {
+      setMeetingMinutesLeft(0);
+    }).then((fn) => {
+      unlistenEnded = fn;
+    });
+    return () => {
+      if (unlistenSoon) unlistenSoon();
+      if (unlistenEnded) unlistenEnded();
+    };
+  }, [view]);
+
+  // Alone or inactive for a while: leave when the policy says so, or ask.
+  useEffect(() => {
+    if (view === "home") return;
+    let unlistenAlone: UnlistenFn | null = null;
+    let unlistenInactive: UnlistenFn | null = null;
+    listen<{ minutes: number; autoLeave: boolean }>("alone-in-room", (event) => {
+      if (event.payload.autoLeave) {
+        handleHangUp();
+      } else {
+        setAloneInRoom(true);
+      }
+    }).then((fn) => {
+      unlistenAlone = fn;
+    });
+    listen<number>("inactivity-detected", () => {
+      setInactivityPrompt(true);
+    }).then((fn) => {
+      unlistenInactive = fn;
+    });
+    return () => {
+      if (unlistenAlone) unlistenAlone();
+      if (unlistenInactive) unlistenInactive();
+    };
+  }, [view]);
+
+  useEffect(() => {
+    if (participants.length > 0) setAloneInRoom(false);
+  }, [participants]);
+
+  const handleStillHere = () => {
+    setInactivityPrompt(false);
+    invoke("report_activity").catch((e) => console.error("report_activity error:", e));
   };
 
+  // "Leave" from the tray menu hangs up like the leave button.
+  useEffect(() => {
+    if (view === "home") return;
+    let unlisten: UnlistenFn | null = null;
+    listen("tray-leave", () => {
+      handleHangUp();
+    }).then((fn) => {
+      unlisten = fn;
+    });
+    return () => {
+      if (unlisten) unlisten();
+    };
+  }, [view]);
+
+  // Headset and media keys: the core toggles the mic or hangs up.
+  useEffect(() => {
+    if (view === "home") return;
+    const press = (button: string) => () => {
+      invoke("handle_media_button", { button }).catch((e) =>
+        console.error("handle_media_button error:", e),
+      );
+    };
+    const handlers: [string, () => void][] = [
+      ["play", press("playPause")],
+      ["pause", press("playPause")],
+      ["hangup", press("hangup")],
+    ];
+    for (const [action, handler] of handlers) {
+      try {
+        navigator.mediaSession?.setActionHandler(action as MediaSessionAction, handler);
+      } catch {
+        // Action not supported by this webview.
+      }
+    }
+    let unlisten: UnlistenFn | null = null;
+    listen<string>("media-button-handled", (event) => {
+      if (event.payload === "hangup") handleHangUp();
+    }).then((fn) => {
+      unlisten = fn;
+    });
+    return () => {
+      for (const [action] of handlers) {
+        try {
+          navigator.mediaSession?.setActionHandler(action as MediaSessionAction, null);
+        } catch {
+          // Action not supported by this webview.
+        }
+      }
+      if (unlisten) unlisten();
+    };
+  }, [view]);
+
+  // Clicking a chat notification opens the chat panel.
+  useEffect(() => {
+    if (view === "home") return;
+    let unlisten: UnlistenFn | null = null;
+    listen<{ kind: string }>("notification-opened", (event) => {
+      if (event.payload.kind !== "chat") return;
+      setShowChat(true);
+      setUnreadCount(0);
+      invoke("set_chat_open", { open: true }).catch((e) =>
+        console.error("set_chat_open error:", e),
+      );
+    }).then((fn) => {
+      unlisten = fn;
+    });
+    return () => {
+      if (unlisten) unlisten();
+    };
+  }, [view]);
+
   const handleToggleHandRaise = async () => {
     try {
       if (isHandRaised) {
@@ -1652,6 +1863,19 @@ export default function App() {
         <header>
           <h1>{t("app.title")}</h1>
           <StatusBadge state={connectionState} />
+          {meetingMinutesLeft !== null && (
+            <span className="status-badge ending-soon">
+              {meetingMinutesLeft > 0
+                ? t("meeting.endingSoon").replace("{minutes}", String(meetingMinutesLeft))
+                : t("meeting.ended")}
+            </span>
+          )}
+          {aloneInRoom && <span className="status-badge">{t("idle.alone")}</span>}
+          {inactivityPrompt && (
+            <button className="status-badge ending-soon" onClick={handleStillHere}>
+              {t("idle.stillHere")}
+            </button>
+          )}
         </header>
       )}
       <main>
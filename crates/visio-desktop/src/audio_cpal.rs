@@ -6,9 +6,8 @@ use livekit::webrtc::audio_frame::AudioFrame;
 use livekit::webrtc::audio_source::native::NativeAudioSource;
 use visio_core::AudioPlayoutBuffer;
 
-/// Internal sample rate used by LiveKit (48kHz mono i16).
+/// Internal sample rate used by LiveKit (48kHz i16).
 const LK_SAMPLE_RATE: u32 = 48_000;
-const LK_CHANNELS: u32 = 1;
 
 // cpal::Stream is !Send + !Sync due to platform internals, but it is safe
 // to hold in Tauri state — we never move the stream across threads, we just
@@ -25,13 +24,98 @@ pub struct CpalAudioPlayout {
     _stream: SendSyncStream,
 }
 
+// ---------------------------------------------------------------------------
+// Device selection
+// ---------------------------------------------------------------------------
+
+/// Names of the available input and output devices. cpal has no stable
+/// device identifier; the name is what `Settings::preferred_*_id` stores.
+pub fn device_names() -> (Vec<String>, Vec<String>) {
+    let host = cpal::default_host();
+    let inputs = host
+        .input_devices()
+        .map(|devices| devices.filter_map(|d| d.name().ok()).collect())
+        .unwrap_or_default();
+    let outputs = host
+        .output_devices()
+        .map(|devices| devices.filter_map(|d| d.name().ok()).collect())
+        .unwrap_or_default();
+    (inputs, outputs)
+}
+
+/// The device named `preferred`, or the host default when it is unset or
+/// no longer present.
+fn select_device(
+    devices: Result<impl Iterator<Item = cpal::Device>, cpal::DevicesError>,
+    preferred: Option<&str>,
+    default: Option<cpal::Device>,
+) -> Option<cpal::Device> {
+    let Some(preferred) = preferred else {
+        return default;
+    };
+    let candidates = devices
+        .map(|devices| {
+            devices
+                .filter_map(|d| d.name().ok().map(|name| (name, d)))
+                .collect()
+        })
+        .unwrap_or_default();
+    match find_by_name(candidates, preferred) {
+        Some(device) => Some(device),
+        None => {
+            tracing::warn!("audio device {preferred:?} not found, using the default device");
+            default
+        }
+    }
+}
+
+fn find_by_name<T>(candidates: Vec<(String, T)>, name: &str) -> Option<T> {
+    candidates
+        .into_iter()
+        .find(|(candidate, _)| candidate == name)
+        .map(|(_, device)| device)
+}
+
 impl CpalAudioPlayout {
-    pub fn start(playout_buffer: Arc<AudioPlayoutBuffer>) -> Result<Self, String> {
+    /// Start playout on the output device named `preferred_device`, or
+    /// the default output device.
+    pub fn start(
+        playout_buffer: Arc<AudioPlayoutBuffer>,
+        preferred_device: Option<&str>,
+    ) -> Result<Self, String> {
         let host = cpal::default_host();
-        let device = host
-            .default_output_device()
-            .ok_or("no output audio device available")?;
+        let device = select_device(
+            host.output_devices(),
+            preferred_device,
+            host.default_output_device(),
+        )
+        .ok_or("no output audio device available")?;
+        Self::open(device, move |buf| playout_buffer.pull_samples(buf))
+    }
+
+    /// Play the 48 kHz mono samples produced by `pull` on the output
+    /// device named `device_name`, without falling back to another device.
+    pub fn start_with(
+        device_name: &str,
+        pull: impl FnMut(&mut [i16]) + Send + 'static,
+    ) -> Result<Self, String> {
+        let candidates = cpal::default_host()
+            .output_devices()
+            .map(|devices| {
+                devices
+                    .filter_map(|d| d.name().ok().map(|name| (name, d)))
+                    .collect()
+            })
+            .unwrap_or_default();
+        let device = find_by_name(candidates, device_name)
+            .ok_or_else(|| format!("output device {device_name:?} not found"))?;
+        Self::open(device, pull)
+    }
 
+    fn open(
+        device: cpal::Device,
+        mut pull: impl FnMut(&mut [i16]) + Send + 'static,
+    ) -> Result<Self, String> {
         let default_cfg = device
             .default_output_config()
             .map_err(|e| format!("default output config: {e}"))?;
@@ -67,7 +151,7 @@ impl CpalAudioPlayout {
                     let lk_samples = lk_samples.max(1);
 
                     let mut buf = vec![0i16; lk_samples];
-                    playout_buffer.pull_samples(&mut buf);
+                    pull(&mut buf);
 
                     // Resample 48kHz → device rate using linear interpolation
                     let resampled = if device_sr == LK_SAMPLE_RATE {
@@ -101,7 +185,7 @@ impl CpalAudioPlayout {
 }
 
 // ---------------------------------------------------------------------------
-// Capture — microphone → NativeAudioSource
+// Capture — microphone or system audio → NativeAudioSource
 // ---------------------------------------------------------------------------
 
 pub struct CpalAudioCapture {
@@ -110,12 +194,53 @@ pub struct CpalAudioCapture {
 }
 
 impl CpalAudioCapture {
-    pub fn start(audio_source: NativeAudioSource) -> Result<Self, String> {
+    /// Start capturing from the input device named `preferred_device`, or
+    /// the default input device.
+    pub fn start(
+        audio_source: NativeAudioSource,
+        preferred_device: Option<&str>,
+    ) -> Result<Self, String> {
+        let host = cpal::default_host();
+        let device = select_device(
+            host.input_devices(),
+            preferred_device,
+            host.default_input_device(),
+        )
+        .ok_or("no input audio device available")?;
+        Self::open(device, audio_source, true)
+    }
+
+    /// Capture what the computer plays, for the system audio of a screen
+    /// share. WASAPI records the default output device in loopback; on
+    /// Linux, the PulseAudio/PipeWire monitor of the output is an input
+    /// device. macOS needs ScreenCaptureKit, which cpal does not offer.
+    pub fn start_loopback(audio_source: NativeAudioSource) -> Result<Self, String> {
         let host = cpal::default_host();
-        let device = host
-            .default_input_device()
-            .ok_or("no input audio device available")?;
+        #[cfg(target_os = "windows")]
+        let device = host.default_output_device();
+        #[cfg(target_os = "linux")]
+        let device = host.input_devices().ok().and_then(|mut devices| {
+            devices.find(|d| {
+                d.name()
+                    .is_ok_and(|name| name.to_lowercase().contains("monitor"))
+            })
+        });
+        #[cfg(not(any(target_os = "windows", target_os = "linux")))]
+        let device: Option<cpal::Device> = {
+            let _ = &host;
+            return Err("system audio capture is not supported on this platform".into());
+        };
+        let device = device.ok_or("no system audio loopback device available")?;
+        Self::open(device, audio_source, false)
+    }
 
+    /// Feed `audio_source` from `device`. Only the `microphone` feeds the
+    /// ducking, device check and A/V sync monitors.
+    fn open(
+        device: cpal::Device,
+        audio_source: NativeAudioSource,
+        microphone: bool,
+    ) -> Result<Self, String> {
         let default_cfg = device
             .default_input_config()
             .map_err(|e| format!("default input config: {e}"))?;
@@ -147,13 +272,30 @@ impl CpalAudioCapture {
         let stream = device
             .build_input_stream(
                 &config,
-                move |data: &[f32], _: &cpal::InputCallbackInfo| {
+                move |data: &[f32], info: &cpal::InputCallbackInfo| {
                     if !running_flag.load(Ordering::Relaxed) {
                         return;
                     }
 
                     let device_frames = data.len() / device_ch as usize;
 
+                    if microphone {
+                        // Capture time of the first sample: the host reports how
+                        // long ago it was captured, else assume one buffer ago.
+                        let timestamp = info.timestamp();
+                        let age = timestamp
+                            .callback
+                            .duration_since(&timestamp.capture)
+                            .unwrap_or_else(|| {
+                                std::time::Duration::from_micros(
+                                    device_frames as u64 * 1_000_000 / device_sr as u64,
+                                )
+                            });
+                        visio_core::av_sync::av_sync_monitor().record_audio(
+                            visio_core::av_sync::monotonic_us() - age.as_micros() as i64,
+                        );
+                    }
+
                     // Resample to 48kHz mono i16
                     let lk_frames = if device_sr == LK_SAMPLE_RATE {
                         device_frames
@@ -162,29 +304,41 @@ impl CpalAudioCapture {
                     };
                     let lk_frames = lk_frames.max(1);
 
-                    // Mix multichannel to mono
-                    let mono = if device_ch == 1 {
-                        data.to_vec()
-                    } else {
-                        mix_to_mono(data, device_ch as usize)
-                    };
-
-                    // Convert f32 mono to i16
-                    let mono_i16: Vec<i16> = mono.iter()
-                        .map(|&s| (s * 32767.0).clamp(-32768.0, 32767.0) as i16)
+                    // One plane per source channel: the mono mix, or the
+                    // device channels for a stereo source (music preset,
+                    // system audio).
+                    let channels = audio_source.num_channels().max(1) as usize;
+                    let planes: Vec<Vec<i16>> = (0..channels)
+                        .map(|c| {
+                            let plane = if channels == 1 {
+                                mix_to_mono(data, device_ch as usize)
+                            } else {
+                                extract_channel(data, device_ch as usize, c)
+                            };
+
+                            // Convert f32 to i16
+                            let plane: Vec<i16> = plane
+                                .iter()
+                                .map(|&s| (s * 32767.0).clamp(-32768.0, 32767.0) as i16)
+                                .collect();
+
+                            // Resample device rate → 48kHz using linear interpolation
+                            if device_sr == LK_SAMPLE_RATE {
+                                plane
+                            } else {
+                                linear_resample(&plane, lk_frames)
+                            }
+                        })
                         .collect();
-
-                    // Resample device rate → 48kHz using linear interpolation
-                    let pcm = if device_sr == LK_SAMPLE_RATE {
-                        mono_i16
-                    } else {
-                        linear_resample(&mono_i16, lk_frames)
-                    };
+                    if microphone {
+                        visio_core::audio_ducking::audio_ducker().observe_capture(&planes[0]);
+                        visio_core::device_check::device_check().observe_audio(&planes[0]);
+                    }
 
                     let frame = AudioFrame {
-                        data: pcm.into(),
+                        data: interleave(&planes).into(),
                         sample_rate: LK_SAMPLE_RATE,
-                        num_channels: LK_CHANNELS,
+                        num_channels: channels as u32,
                         samples_per_channel: lk_frames as u32,
                     };
 
@@ -257,10 +411,44 @@ fn mix_to_mono(data: &[f32], channels: usize) -> Vec<f32> {
     mono
 }
 
+/// Channel `index` of f32 interleaved audio; the last channel when the
+/// device has fewer.
+fn extract_channel(data: &[f32], channels: usize, index: usize) -> Vec<f32> {
+    if channels == 0 {
+        return Vec::new();
+    }
+    let index = index.min(channels - 1);
+    data.chunks_exact(channels)
+        .map(|frame| frame[index])
+        .collect()
+}
+
+/// Interleave equally long planes into one buffer.
+fn interleave(planes: &[Vec<i16>]) -> Vec<i16> {
+    let frames = planes.first().map_or(0, Vec::len);
+    let mut out = Vec::with_capacity(frames * planes.len());
+    for f in 0..frames {
+        out.extend(planes.iter().map(|plane| plane[f]));
+    }
+    out
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn find_by_name_matches_exactly() {
+        let candidates = || {
+            vec![
+                ("Built-in Microphone".to_string(), 1),
+                ("USB Audio".to_string(), 2),
+            ]
+        };
+        assert_eq!(find_by_name(candidates(), "USB Audio"), Some(2));
+        assert_eq!(find_by_name(candidates(), "USB"), None);
+    }
+
     #[test]
     fn resample_same_length() {
         let input: Vec<i16> = vec![0, 100, 200, 300, 400];
@@ -316,4 +504,17 @@ mod tests {
         let mono = mix_to_mono(&[], 2);
         assert!(mono.is_empty());
     }
+
+    #[test]
+    fn extract_channel_duplicates_mono() {
+        let stereo = vec![1.0f32, 2.0, 3.0, 4.0];
+        assert_eq!(extract_channel(&stereo, 2, 1), vec![2.0, 4.0]);
+        assert_eq!(extract_channel(&[5.0, 6.0], 1, 1), vec![5.0, 6.0]);
+    }
+
+    #[test]
+    fn interleave_planes() {
+        assert_eq!(interleave(&[vec![1, 3], vec![2, 4]]), vec![1, 2, 3, 4]);
+        assert!(interleave(&[]).is_empty());
+    }
 }
@@ -1,27 +1,50 @@
 //! macOS camera capture using AVFoundation.
 //!
-//! Opens the default camera, forces NV12 pixel format, converts
+//! Opens the preferred camera (falling back to the default one), forces NV12 pixel format, converts
 //! NV12 → I420, and feeds frames into a LiveKit NativeVideoSource.
 //! Also emits self-view frames through the visio-video desktop callback.
 
 use std::ffi::{c_char, c_void};
-use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
 
 use livekit::webrtc::prelude::*;
 use livekit::webrtc::video_source::native::NativeVideoSource;
 
 use objc2::rc::Retained;
 use objc2::runtime::{AnyClass, AnyObject, Bool, NSObject};
-use objc2::{define_class, msg_send, ClassType};
+use objc2::{ClassType, define_class, msg_send};
 
 // ---------------------------------------------------------------------------
 // CoreMedia / CoreVideo C FFI
 // ---------------------------------------------------------------------------
 
+/// CoreMedia `CMTime`.
+#[repr(C)]
+struct CMTime {
+    value: i64,
+    timescale: i32,
+    flags: u32,
+    epoch: i64,
+}
+
+/// `kCMTimeFlags_Valid`
+const CM_TIME_FLAGS_VALID: u32 = 1;
+
 #[link(name = "CoreMedia", kind = "framework")]
 unsafe extern "C" {
     fn CMSampleBufferGetImageBuffer(sbuf: *const c_void) -> *const c_void;
+    fn CMSampleBufferGetPresentationTimeStamp(sbuf: *const c_void) -> CMTime;
+}
+
+/// Capture time of a sample buffer on the host clock, in nanoseconds
+/// (0 if unknown).
+fn presentation_time_ns(sample_buffer: *const c_void) -> i64 {
+    let pts = unsafe { CMSampleBufferGetPresentationTimeStamp(sample_buffer) };
+    if pts.flags & CM_TIME_FLAGS_VALID == 0 || pts.timescale <= 0 {
+        return 0;
+    }
+    (pts.value as i128 * 1_000_000_000 / pts.timescale as i128) as i64
 }
 
 #[link(name = "CoreVideo", kind = "framework")]
@@ -119,34 +142,19 @@ fn process_camera_frame(sample_buffer: *const c_void) {
 
     let strides = i420.strides();
     let (y_dst, u_dst, v_dst) = i420.data_mut();
-
-    // Copy Y plane
-    for row in 0..h {
-        let src_start = row * y_stride;
-        let dst_start = row * strides.0 as usize;
-        let src_slice =
-            unsafe { std::slice::from_raw_parts(y_ptr.add(src_start), w) };
-        y_dst[dst_start..dst_start + w].copy_from_slice(src_slice);
-    }
-
-    // Deinterleave UV plane into U and V
-    let chroma_h = h / 2;
-    let chroma_w = w / 2;
-    for row in 0..chroma_h {
-        let src_row = unsafe {
-            std::slice::from_raw_parts(uv_ptr.add(row * uv_stride), chroma_w * 2)
-        };
-        let dst_row_offset = row * strides.1 as usize;
-        for col in 0..chroma_w {
-            u_dst[dst_row_offset + col] = src_row[col * 2];
-            v_dst[dst_row_offset + col] = src_row[col * 2 + 1];
-        }
-    }
-
-    // Release mutable borrows before immutable use
-    let _ = y_dst;
-    let _ = u_dst;
-    let _ = v_dst;
+    let (y_src, uv_src) = unsafe {
+        (
+            std::slice::from_raw_parts(y_ptr, y_stride * h),
+            std::slice::from_raw_parts(uv_ptr, uv_stride * h.div_ceil(2)),
+        )
+    };
+    visio_core::frame_convert::nv12_to_i420(
+        [y_src, uv_src],
+        [y_stride, uv_stride],
+        (w, h),
+        [y_dst, u_dst, v_dst],
+        [strides.0 as usize, strides.1 as usize, strides.2 as usize],
+    );
 
     unsafe { CVPixelBufferUnlockBaseAddress(pxbuf, 1) };
 
@@ -154,17 +162,44 @@ fn process_camera_frame(sample_buffer: *const c_void) {
     {
         let (y_data, u_data, v_data) = i420.data_mut();
         visio_ffi::blur::BlurProcessor::process_i420(
-            y_data, u_data, v_data,
-            w, h,
-            strides.0 as usize, strides.1 as usize, strides.2 as usize,
+            y_data,
+            u_data,
+            v_data,
+            w,
+            h,
+            strides.0 as usize,
+            strides.1 as usize,
+            strides.2 as usize,
             0, // Desktop camera frames have no rotation metadata
         );
+        visio_core::video_privacy().process_i420(
+            y_data,
+            u_data,
+            v_data,
+            w,
+            h,
+            [strides.0 as usize, strides.1 as usize, strides.2 as usize],
+        );
+        visio_core::video_overlay().process_i420(
+            y_data,
+            u_data,
+            v_data,
+            w,
+            h,
+            [strides.0 as usize, strides.1 as usize, strides.2 as usize],
+            0,
+        );
     }
 
+    visio_core::device_check::device_check().observe_video_frame();
+
     // Feed frame into LiveKit
+    let timestamp_us =
+        visio_core::av_sync::capture_timestamp_us(presentation_time_ns(sample_buffer));
+    visio_core::av_sync::av_sync_monitor().record_video(timestamp_us);
     let frame = VideoFrame {
         rotation: VideoRotation::VideoRotation0,
-        timestamp_us: 0,
+        timestamp_us,
         buffer: i420,
     };
     state.video_source.capture_frame(&frame);
@@ -217,8 +252,10 @@ pub struct MacCameraCapture {
 unsafe impl Send for MacCameraCapture {}
 
 impl MacCameraCapture {
-    /// Start capturing from the default camera and feeding frames into `source`.
-    pub fn start(source: NativeVideoSource) -> Result<Self, String> {
+    /// Start capturing from the camera with unique ID `preferred_id`, or
+    /// the default camera when it is unset or unplugged, and feed frames
+    /// into `source`.
+    pub fn start(source: NativeVideoSource, preferred_id: Option<&str>) -> Result<Self, String> {
         // Store the source in global state for the delegate callback
         {
             let mut state = CAMERA_STATE.lock().unwrap();
@@ -228,44 +265,53 @@ impl MacCameraCapture {
             });
         }
 
-        unsafe { Self::start_avfoundation() }
+        unsafe { Self::start_avfoundation(preferred_id) }
     }
 
-    unsafe fn start_avfoundation() -> Result<Self, String> {
+    unsafe fn start_avfoundation(preferred_id: Option<&str>) -> Result<Self, String> {
         // --- Create session ---
-        let session_cls = AnyClass::get(c"AVCaptureSession")
-            .ok_or("AVCaptureSession class not found")?;
+        let session_cls =
+            AnyClass::get(c"AVCaptureSession").ok_or("AVCaptureSession class not found")?;
         let session: Retained<AnyObject> = unsafe { msg_send![session_cls, new] };
 
         // Set session preset
-        let _: () = unsafe {
-            msg_send![&*session, setSessionPreset: AVCaptureSessionPresetHigh]
-        };
+        let _: () = unsafe { msg_send![&*session, setSessionPreset: AVCaptureSessionPresetHigh] };
 
         // --- Find camera device ---
-        let device_cls = AnyClass::get(c"AVCaptureDevice")
-            .ok_or("AVCaptureDevice class not found")?;
-        let device_ptr: *mut AnyObject = unsafe {
-            msg_send![device_cls, defaultDeviceWithMediaType: AVMediaTypeVideo]
-        };
+        let device_cls =
+            AnyClass::get(c"AVCaptureDevice").ok_or("AVCaptureDevice class not found")?;
+        let mut device_ptr: *mut AnyObject = std::ptr::null_mut();
+        if let Some(id) = preferred_id {
+            let id = std::ffi::CString::new(id).map_err(|e| e.to_string())?;
+            let string_cls = AnyClass::get(c"NSString").unwrap();
+            let unique_id: Retained<AnyObject> =
+                unsafe { msg_send![string_cls, stringWithUTF8String: id.as_ptr()] };
+            device_ptr = unsafe { msg_send![device_cls, deviceWithUniqueID: &*unique_id] };
+            if device_ptr.is_null() {
+                tracing::warn!("preferred camera {id:?} not found, using the default camera");
+            }
+        }
+        if device_ptr.is_null() {
+            device_ptr =
+                unsafe { msg_send![device_cls, defaultDeviceWithMediaType: AVMediaTypeVideo] };
+        }
         if device_ptr.is_null() {
             return Err("No camera device found".into());
         }
-        let device = unsafe { Retained::retain(device_ptr) }
-            .ok_or("Failed to retain camera device")?;
+        let device =
+            unsafe { Retained::retain(device_ptr) }.ok_or("Failed to retain camera device")?;
 
         // --- Create device input ---
-        let input_cls = AnyClass::get(c"AVCaptureDeviceInput")
-            .ok_or("AVCaptureDeviceInput class not found")?;
+        let input_cls =
+            AnyClass::get(c"AVCaptureDeviceInput").ok_or("AVCaptureDeviceInput class not found")?;
         let mut error_ptr: *mut AnyObject = std::ptr::null_mut();
-        let input_ptr: *mut AnyObject = unsafe {
-            msg_send![input_cls, deviceInputWithDevice: &*device, error: &mut error_ptr]
-        };
+        let input_ptr: *mut AnyObject =
+            unsafe { msg_send![input_cls, deviceInputWithDevice: &*device, error: &mut error_ptr] };
         if input_ptr.is_null() {
             return Err("Failed to create camera input".into());
         }
-        let input = unsafe { Retained::retain(input_ptr) }
-            .ok_or("Failed to retain camera input")?;
+        let input =
+            unsafe { Retained::retain(input_ptr) }.ok_or("Failed to retain camera input")?;
 
         // --- Create video data output ---
         let output_cls = AnyClass::get(c"AVCaptureVideoDataOutput")
@@ -274,58 +320,41 @@ impl MacCameraCapture {
 
         // Force NV12 pixel format via videoSettings dictionary
         let nsnumber_cls = AnyClass::get(c"NSNumber").unwrap();
-        let format_num: Retained<AnyObject> = unsafe {
-            msg_send![nsnumber_cls, numberWithUnsignedInt: PIXEL_FORMAT_NV12]
-        };
+        let format_num: Retained<AnyObject> =
+            unsafe { msg_send![nsnumber_cls, numberWithUnsignedInt: PIXEL_FORMAT_NV12] };
 
         // kCVPixelBufferPixelFormatTypeKey = "PixelFormatType"
         let key_bytes = c"PixelFormatType";
         let key_cls = AnyClass::get(c"NSString").unwrap();
-        let format_key: Retained<AnyObject> = unsafe {
-            msg_send![key_cls, stringWithUTF8String: key_bytes.as_ptr()]
-        };
+        let format_key: Retained<AnyObject> =
+            unsafe { msg_send![key_cls, stringWithUTF8String: key_bytes.as_ptr()] };
 
         let dict_cls = AnyClass::get(c"NSDictionary").unwrap();
         let video_settings: Retained<AnyObject> = unsafe {
             msg_send![dict_cls, dictionaryWithObject: &*format_num, forKey: &*format_key]
         };
-        let _: () = unsafe {
-            msg_send![&*output, setVideoSettings: &*video_settings]
-        };
+        let _: () = unsafe { msg_send![&*output, setVideoSettings: &*video_settings] };
 
         // Discard late frames
-        let _: () = unsafe {
-            msg_send![&*output, setAlwaysDiscardsLateVideoFrames: Bool::YES]
-        };
+        let _: () = unsafe { msg_send![&*output, setAlwaysDiscardsLateVideoFrames: Bool::YES] };
 
         // --- Create delegate and dispatch queue ---
-        let delegate: Retained<VisioCameraDelegate> = unsafe {
-            msg_send![VisioCameraDelegate::class(), new]
-        };
+        let delegate: Retained<VisioCameraDelegate> =
+            unsafe { msg_send![VisioCameraDelegate::class(), new] };
 
-        let queue = unsafe {
-            dispatch_queue_create(
-                c"io.visio.camera".as_ptr(),
-                std::ptr::null(),
-            )
-        };
+        let queue = unsafe { dispatch_queue_create(c"io.visio.camera".as_ptr(), std::ptr::null()) };
 
-        let _: () = unsafe {
-            msg_send![&*output, setSampleBufferDelegate: &*delegate, queue: queue]
-        };
+        let _: () =
+            unsafe { msg_send![&*output, setSampleBufferDelegate: &*delegate, queue: queue] };
 
         // --- Add input and output to session ---
-        let can_add_input: Bool = unsafe {
-            msg_send![&*session, canAddInput: &*input]
-        };
+        let can_add_input: Bool = unsafe { msg_send![&*session, canAddInput: &*input] };
         if !can_add_input.as_bool() {
             return Err("Cannot add camera input to session".into());
         }
         let _: () = unsafe { msg_send![&*session, addInput: &*input] };
 
-        let can_add_output: Bool = unsafe {
-            msg_send![&*session, canAddOutput: &*output]
-        };
+        let can_add_output: Bool = unsafe { msg_send![&*session, canAddOutput: &*output] };
         if !can_add_output.as_bool() {
             return Err("Cannot add video output to session".into());
         }
@@ -1,15 +1,25 @@
+use std::collections::HashMap;
 use std::sync::{Arc, OnceLock};
 use tokio::sync::Mutex;
 
 use tauri::{AppHandle, Emitter, Listener, Manager};
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, ShortcutState};
 use visio_core::{
-    ChatService, MeetingControls, RoomManager, SettingsStore, TrackInfo, TrackKind, TrackSource,
+    AudioPreset, AudioPublishOptions, ChatExportFormat, ChatService, ConnectStep, DevicePermission,
+    LayoutMode, LayoutOptions, LocalRecordingFormat, LocalRecordingOptions, MediaButton,
+    MeetingAction, MeetingControls, OverlayCorner, PermissionState, PowerMode, Presence,
+    QualityReductionReason, RecordingMode, RecordingStatus, RestreamOptions, RestreamState,
+    RestreamStatus, RoomManager, ScreenShareOptions, SettingsStore, ThermalState, TrackInfo,
+    TrackKind, TrackRecovery, TrackSource, VideoCodec, VideoContentHint, VideoPrivacyStyle,
     VisioEvent, VisioEventListener,
 };
 
+mod audio_cpal;
 #[cfg(target_os = "macos")]
 mod camera_macos;
-mod audio_cpal;
+mod notifications;
+mod tray;
+mod virtual_devices;
 
 // ---------------------------------------------------------------------------
 // Global AppHandle for the C video callback
@@ -31,7 +41,9 @@ unsafe extern "C" fn on_desktop_frame(
     let sid = unsafe { std::ffi::CStr::from_ptr(track_sid) };
     let Ok(sid_str) = sid.to_str() else { return };
     let b64 = unsafe { std::slice::from_raw_parts(data, data_len) };
-    let Ok(b64_str) = std::str::from_utf8(b64) else { return };
+    let Ok(b64_str) = std::str::from_utf8(b64) else {
+        return;
+    };
 
     let _ = app.emit(
         "video-frame",
@@ -57,6 +69,63 @@ struct VisioState {
     camera_capture: std::sync::Mutex<Option<camera_macos::MacCameraCapture>>,
     _audio_playout: audio_cpal::CpalAudioPlayout,
     audio_capture: std::sync::Mutex<Option<audio_cpal::CpalAudioCapture>>,
+    /// System audio loopback of the screen share.
+    screen_audio_capture: std::sync::Mutex<Option<audio_cpal::CpalAudioCapture>>,
+    virtual_devices: std::sync::Mutex<Option<virtual_devices::VirtualDevices>>,
+}
+
+/// Per-user data directory, used unless `RunOptions::data_dir` is set.
+fn default_data_dir() -> std::path::PathBuf {
+    dirs::data_dir()
+        .unwrap_or_else(|| std::path::PathBuf::from("."))
+        .join("io.visio.desktop")
+}
+
+static DATA_PATHS: OnceLock<visio_core::DataPaths> = OnceLock::new();
+
+/// Data directory of this run (settings, recordings, snapshots).
+fn data_paths() -> &'static visio_core::DataPaths {
+    DATA_PATHS.get_or_init(|| visio_core::DataPaths::new(default_data_dir()))
+}
+
+/// Launch options, from the command line and the environment.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RunOptions {
+    /// Data directory replacing the per-user one.
+    pub data_dir: Option<std::path::PathBuf>,
+}
+
+impl RunOptions {
+    /// `--data-dir <dir>`, then `--portable` (a `visio-data` directory next
+    /// to the executable, e.g. on a USB stick), then `VISIO_DATA_DIR`.
+    /// Other arguments (deep links) are left to the plugins.
+    pub fn from_env() -> Self {
+        Self::parse(std::env::args().skip(1), std::env::var_os("VISIO_DATA_DIR"))
+    }
+
+    fn parse(mut args: impl Iterator<Item = String>, env_dir: Option<std::ffi::OsString>) -> Self {
+        let mut portable = false;
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--data-dir" => {
+                    if let Some(dir) = args.next() {
+                        return Self {
+                            data_dir: Some(dir.into()),
+                        };
+                    }
+                }
+                "--portable" => portable = true,
+                _ => {}
+            }
+        }
+        let portable_dir = portable
+            .then(|| std::env::current_exe().ok())
+            .flatten()
+            .and_then(|exe| exe.parent().map(|dir| dir.join("visio-data")));
+        Self {
+            data_dir: portable_dir.or_else(|| env_dir.map(Into::into)),
+        }
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -72,12 +141,88 @@ fn source_to_str(source: &TrackSource) -> &'static str {
         TrackSource::Microphone => "microphone",
         TrackSource::Camera => "camera",
         TrackSource::ScreenShare => "screen_share",
+        TrackSource::ScreenShareAudio => "screen_share_audio",
         TrackSource::Unknown => "unknown",
     }
 }
 
+fn recording_status_to_str(status: RecordingStatus) -> &'static str {
+    match status {
+        RecordingStatus::Stopped => "stopped",
+        RecordingStatus::Starting => "starting",
+        RecordingStatus::Recording => "recording",
+        RecordingStatus::Stopping => "stopping",
+    }
+}
+
+fn restream_status_to_json(status: &RestreamStatus) -> serde_json::Value {
+    let state = match status.state {
+        RestreamState::Stopped => "stopped",
+        RestreamState::Connecting => "connecting",
+        RestreamState::Live => "live",
+        RestreamState::Failed => "failed",
+    };
+    serde_json::json!({
+        "state": state,
+        "videoBitrateKbps": status.video_bitrate_kbps,
+        "error": status.error,
+    })
+}
+
+fn connect_step_to_str(step: ConnectStep) -> &'static str {
+    match step {
+        ConnectStep::TokenRequested => "token_requested",
+        ConnectStep::TokenReceived => "token_received",
+        ConnectStep::SignalConnected => "signal_connected",
+        ConnectStep::MediaConnected => "media_connected",
+    }
+}
+
+fn permissions_to_json(perms: &visio_core::LocalPermissions) -> serde_json::Value {
+    serde_json::json!({
+        "accessLevel": perms.access_level,
+        "canPublish": perms.can_publish,
+        "canSubscribe": perms.can_subscribe,
+        "canPublishData": perms.can_publish_data,
+        "canPublishSources": perms.can_publish_sources,
+        "canUpdateMetadata": perms.can_update_metadata,
+        "isAdmin": perms.is_admin,
+        "canPublishMicrophone": perms.can_publish_source(TrackSource::Microphone),
+        "canPublishCamera": perms.can_publish_source(TrackSource::Camera),
+        "canPublishScreenShare": perms.can_publish_source(TrackSource::ScreenShare),
+    })
+}
+
+fn feature_flags_to_json(flags: &visio_core::FeatureFlags) -> serde_json::Value {
+    serde_json::json!({
+        "chat": flags.chat,
+        "reactions": flags.reactions,
+        "recording": flags.recording,
+        "transcription": flags.transcription,
+        "dialIn": flags.dial_in,
+        "subtitles": flags.subtitles,
+        "whiteboard": flags.whiteboard,
+        "watermark": flags.watermark,
+    })
+}
+
+fn poll_to_json(poll: &visio_core::Poll) -> serde_json::Value {
+    serde_json::json!({
+        "id": poll.id,
+        "question": poll.question,
+        "options": poll.options,
+        "counts": poll.counts,
+        "creatorSid": poll.creator_sid,
+        "localVote": poll.local_vote,
+        "ended": poll.ended,
+    })
+}
+
 impl VisioEventListener for DesktopEventListener {
     fn on_event(&self, event: VisioEvent) {
+        if let Some(app) = APP_HANDLE.get() {
+            notifications::on_event(app, &event);
+        }
         match event {
             VisioEvent::ConnectionStateChanged(state) => {
                 let name = match &state {
@@ -89,6 +234,7 @@ impl VisioEventListener for DesktopEventListener {
                 tracing::info!("connection state changed: {name}");
                 if let Some(app) = APP_HANDLE.get() {
                     let _ = app.emit("connection-state-changed", name);
+                    tray::refresh(app);
                 }
             }
             VisioEvent::ParticipantJoined(info) => {
@@ -126,6 +272,7 @@ impl VisioEventListener for DesktopEventListener {
                             video_track,
                             std::ptr::null_mut(),
                             None,
+                            Some(stream_health_handler(rm.track_recovery())),
                         );
                     }
                 });
@@ -147,6 +294,9 @@ impl VisioEventListener for DesktopEventListener {
                             "source": source_to_str(&source),
                         }),
                     );
+                    if source == TrackSource::Microphone {
+                        tray::refresh(app);
+                    }
                 }
             }
             VisioEvent::TrackUnmuted {
@@ -161,6 +311,9 @@ impl VisioEventListener for DesktopEventListener {
                             "source": source_to_str(&source),
                         }),
                     );
+                    if source == TrackSource::Microphone {
+                        tray::refresh(app);
+                    }
                 }
             }
             VisioEvent::HandRaisedChanged {
@@ -187,11 +340,21 @@ impl VisioEventListener for DesktopEventListener {
                     let _ = app.emit("unread-count-changed", count);
                 }
             }
+            VisioEvent::HandAutoLowered => {
+                if let Some(app) = APP_HANDLE.get() {
+                    let _ = app.emit("hand-auto-lowered", ());
+                }
+            }
             VisioEvent::ActiveSpeakersChanged(sids) => {
                 if let Some(app) = APP_HANDLE.get() {
                     let _ = app.emit("active-speakers-changed", &sids);
                 }
             }
+            VisioEvent::LayoutOrderChanged(sids) => {
+                if let Some(app) = APP_HANDLE.get() {
+                    let _ = app.emit("layout-order-changed", &sids);
+                }
+            }
             VisioEvent::ConnectionQualityChanged {
                 participant_sid,
                 quality,
@@ -216,6 +379,7 @@ impl VisioEventListener for DesktopEventListener {
                             "senderName": msg.sender_name,
                             "text": msg.text,
                             "timestampMs": msg.timestamp_ms,
+                            "kind": msg.kind,
                         }),
                     );
                 }
@@ -249,10 +413,354 @@ impl VisioEventListener for DesktopEventListener {
                     }
                 });
             }
+            VisioEvent::RoomSwitched { meet_url } => {
+                if let Some(app) = APP_HANDLE.get() {
+                    let _ = app.emit("room-switched", serde_json::json!({ "meetUrl": meet_url }));
+                }
+            }
+            VisioEvent::ParticipantBatchUpdate {
+                joined,
+                left,
+                joined_count,
+                left_count,
+            } => {
+                if let Some(app) = APP_HANDLE.get() {
+                    let joined: Vec<_> = joined
+                        .iter()
+                        .map(|p| {
+                            serde_json::json!({
+                                "sid": p.sid,
+                                "identity": p.identity,
+                                "name": p.name,
+                            })
+                        })
+                        .collect();
+                    let _ = app.emit(
+                        "participant-batch-update",
+                        serde_json::json!({
+                            "joined": joined,
+                            "left": left,
+                            "joinedCount": joined_count,
+                            "leftCount": left_count,
+                        }),
+                    );
+                }
+            }
+            VisioEvent::ConnectProgress(step) => {
+                if let Some(app) = APP_HANDLE.get() {
+                    let _ = app.emit("connect-progress", connect_step_to_str(step));
+                }
+            }
+            VisioEvent::TrackStalled { track_sid } => {
+                if let Some(app) = APP_HANDLE.get() {
+                    let _ = app.emit("track-stalled", &track_sid);
+                }
+            }
+            VisioEvent::TrackResumed { track_sid } => {
+                if let Some(app) = APP_HANDLE.get() {
+                    let _ = app.emit("track-resumed", &track_sid);
+                }
+            }
+            VisioEvent::PermissionsChanged(perms) => {
+                if let Some(app) = APP_HANDLE.get() {
+                    let _ = app.emit("permissions-changed", permissions_to_json(&perms));
+                }
+            }
+            VisioEvent::WaitingForHost => {
+                if let Some(app) = APP_HANDLE.get() {
+                    let _ = app.emit("waiting-for-host", ());
+                }
+            }
+            VisioEvent::EntryGranted => {
+                if let Some(app) = APP_HANDLE.get() {
+                    let _ = app.emit("entry-granted", ());
+                }
+            }
+            VisioEvent::EntryDenied => {
+                if let Some(app) = APP_HANDLE.get() {
+                    let _ = app.emit("entry-denied", ());
+                }
+            }
+            VisioEvent::RoomLockedChanged(locked) => {
+                if let Some(app) = APP_HANDLE.get() {
+                    let _ = app.emit("room-locked-changed", locked);
+                }
+            }
+            VisioEvent::FeatureFlagsChanged(flags) => {
+                if let Some(app) = APP_HANDLE.get() {
+                    let _ = app.emit("feature-flags-changed", feature_flags_to_json(&flags));
+                }
+            }
+            VisioEvent::RecordingStatusChanged(status) => {
+                if let Some(app) = APP_HANDLE.get() {
+                    let _ = app.emit("recording-status-changed", recording_status_to_str(status));
+                }
+            }
+            VisioEvent::LocalRecordingProgress {
+                duration_ms,
+                size_bytes,
+            } => {
+                if let Some(app) = APP_HANDLE.get() {
+                    let _ = app.emit(
+                        "local-recording-progress",
+                        serde_json::json!({
+                            "durationMs": duration_ms,
+                            "sizeBytes": size_bytes,
+                        }),
+                    );
+                }
+            }
+            VisioEvent::RestreamStatusChanged(status) => {
+                if let Some(app) = APP_HANDLE.get() {
+                    let _ = app.emit("restream-status-changed", restream_status_to_json(&status));
+                }
+            }
+            VisioEvent::SourceReady { source, handle } => {
+                if let Some(app) = APP_HANDLE.get() {
+                    let _ = app.emit(
+                        "source-ready",
+                        serde_json::json!({
+                            "source": source_to_str(&source),
+                            "handle": handle,
+                        }),
+                    );
+                }
+            }
+            VisioEvent::CaptureConstraintsChanged(constraints) => {
+                if let Some(app) = APP_HANDLE.get() {
+                    let _ = app.emit(
+                        "capture-constraints-changed",
+                        serde_json::json!({
+                            "width": constraints.width,
+                            "height": constraints.height,
+                            "maxFps": constraints.max_fps,
+                            "lowLightBoost": constraints.low_light_boost,
+                        }),
+                    );
+                }
+            }
+            VisioEvent::DataMessageReceived {
+                topic,
+                sender_sid,
+                payload,
+            } => {
+                if let Some(app) = APP_HANDLE.get() {
+                    let _ = app.emit(
+                        "data-message-received",
+                        serde_json::json!({
+                            "topic": topic,
+                            "senderSid": sender_sid,
+                            "payload": payload,
+                        }),
+                    );
+                }
+            }
+            VisioEvent::PollStarted(poll) => {
+                if let Some(app) = APP_HANDLE.get() {
+                    let _ = app.emit("poll-started", poll_to_json(&poll));
+                }
+            }
+            VisioEvent::PollUpdated(poll) => {
+                if let Some(app) = APP_HANDLE.get() {
+                    let _ = app.emit("poll-updated", poll_to_json(&poll));
+                }
+            }
+            VisioEvent::PollEnded(poll) => {
+                if let Some(app) = APP_HANDLE.get() {
+                    let _ = app.emit("poll-ended", poll_to_json(&poll));
+                }
+            }
+            VisioEvent::WhiteboardAvailable => {
+                if let Some(app) = APP_HANDLE.get() {
+                    let _ = app.emit("whiteboard-available", ());
+                }
+            }
+            VisioEvent::PowerModeChanged(mode) => {
+                if let Some(app) = APP_HANDLE.get() {
+                    let _ = app.emit("power-mode-changed", power_mode_to_str(mode));
+                }
+            }
+            VisioEvent::QualityReduced {
+                reason,
+                constraints,
+            } => {
+                let reason = match reason {
+                    QualityReductionReason::Thermal(state) => serde_json::json!({
+                        "kind": "thermal",
+                        "thermalState": thermal_state_to_str(state),
+                    }),
+                };
+                if let Some(app) = APP_HANDLE.get() {
+                    let _ = app.emit(
+                        "quality-reduced",
+                        serde_json::json!({
+                            "reason": reason,
+                            "width": constraints.width,
+                            "height": constraints.height,
+                            "maxFps": constraints.max_fps,
+                        }),
+                    );
+                }
+            }
+            VisioEvent::QualityRestored => {
+                if let Some(app) = APP_HANDLE.get() {
+                    let _ = app.emit("quality-restored", ());
+                }
+            }
+            VisioEvent::DevicePermissionChanged { permission, state } => {
+                if let Some(app) = APP_HANDLE.get() {
+                    let _ = app.emit(
+                        "device-permission-changed",
+                        serde_json::json!({
+                            "permission": device_permission_to_str(permission),
+                            "state": permission_state_to_str(state),
+                        }),
+                    );
+                }
+            }
+            VisioEvent::SettingsChanged { keys } => {
+                // Payload: changed keys with their new values.
+                if let Some(app) = APP_HANDLE.get()
+                    && let Some(state) = app.try_state::<VisioState>()
+                {
+                    let settings = serde_json::to_value(state.settings.get()).unwrap_or_default();
+                    let changed: serde_json::Map<String, serde_json::Value> = keys
+                        .into_iter()
+                        .map(|key| {
+                            let value = settings.get(&key).cloned().unwrap_or_default();
+                            (key, value)
+                        })
+                        .collect();
+                    let _ = app.emit("settings-changed", changed);
+                }
+            }
+            // No OS call UI on desktop: calls are never reported.
+            VisioEvent::SystemCallChanged(_) => {}
+            VisioEvent::MediaButtonHandled(button) => {
+                if let Some(app) = APP_HANDLE.get() {
+                    let _ = app.emit("media-button-handled", media_button_to_str(button));
+                }
+            }
+            VisioEvent::MeetingEndingSoon { minutes_left } => {
+                if let Some(app) = APP_HANDLE.get() {
+                    let _ = app.emit("meeting-ending-soon", minutes_left);
+                }
+            }
+            VisioEvent::MeetingEnded => {
+                if let Some(app) = APP_HANDLE.get() {
+                    let _ = app.emit("meeting-ended", ());
+                }
+            }
+            VisioEvent::AloneInRoom {
+                minutes,
+                auto_leave,
+            } => {
+                if let Some(app) = APP_HANDLE.get() {
+                    let _ = app.emit(
+                        "alone-in-room",
+                        serde_json::json!({ "minutes": minutes, "autoLeave": auto_leave }),
+                    );
+                }
+            }
+            VisioEvent::InactivityDetected { minutes } => {
+                if let Some(app) = APP_HANDLE.get() {
+                    let _ = app.emit("inactivity-detected", minutes);
+                }
+            }
+            VisioEvent::SpotlightChanged {
+                participant_sid,
+                track_sid,
+            } => {
+                if let Some(app) = APP_HANDLE.get() {
+                    let _ = app.emit(
+                        "spotlight-changed",
+                        serde_json::json!({
+                            "participantSid": participant_sid,
+                            "trackSid": track_sid,
+                        }),
+                    );
+                }
+            }
+            VisioEvent::PresenceChanged {
+                participant_sid,
+                presence,
+            } => {
+                if let Some(app) = APP_HANDLE.get() {
+                    let _ = app.emit(
+                        "presence-changed",
+                        serde_json::json!({
+                            "participantSid": participant_sid,
+                            "presence": presence_to_str(presence),
+                        }),
+                    );
+                }
+            }
+            VisioEvent::ParticipantAttributesChanged {
+                participant_sid,
+                changed,
+            } => {
+                if let Some(app) = APP_HANDLE.get() {
+                    let _ = app.emit(
+                        "participant-attributes-changed",
+                        serde_json::json!({
+                            "participantSid": participant_sid,
+                            "changed": changed,
+                        }),
+                    );
+                }
+            }
+            VisioEvent::QualityPolicyTriggered {
+                active,
+                camera,
+                remote_video,
+            } => {
+                if let Some(app) = APP_HANDLE.get() {
+                    let _ = app.emit(
+                        "quality-policy-triggered",
+                        serde_json::json!({
+                            "active": active,
+                            "camera": camera,
+                            "remoteVideo": remote_video,
+                        }),
+                    );
+                }
+            }
+            VisioEvent::CallEnded(summary) => {
+                if let Some(app) = APP_HANDLE.get() {
+                    let _ = app.emit("call-ended", call_summary_to_json(&summary));
+                }
+            }
+            VisioEvent::VideoPrivacyChanged { enabled } => {
+                if let Some(app) = APP_HANDLE.get() {
+                    let _ = app.emit(
+                        "video-privacy-changed",
+                        serde_json::json!({ "enabled": enabled }),
+                    );
+                }
+            }
+            VisioEvent::ChatUnreadChanged(unread) => {
+                if let Some(app) = APP_HANDLE.get() {
+                    let _ = app.emit("chat-unread-changed", chat_unread_to_json(&unread));
+                }
+            }
         }
     }
 }
 
+/// Forward renderer stall reports to the room's `TrackRecovery`.
+fn stream_health_handler(recovery: TrackRecovery) -> visio_video::StreamHealthHandler {
+    Arc::new(move |track_sid, health| {
+        let recovery = recovery.clone();
+        let sid = track_sid.to_string();
+        tokio::spawn(async move {
+            match health {
+                visio_video::StreamHealth::Stalled => recovery.track_stalled(&sid).await,
+                visio_video::StreamHealth::Resumed => recovery.track_resumed(&sid).await,
+            };
+        });
+    })
+}
+
 // ---------------------------------------------------------------------------
 // Tauri commands
 // ---------------------------------------------------------------------------
@@ -284,9 +792,44 @@ async fn connect(
     state: tauri::State<'_, VisioState>,
     meet_url: String,
     username: Option<String>,
+    options: Option<visio_core::JoinOptions>,
+) -> Result<(), String> {
+    // Without explicit options, join with the mic/camera-on-join settings.
+    let mut options =
+        options.unwrap_or_else(|| visio_core::JoinOptions::from_settings(&state.settings.get()));
+    if username.is_some() {
+        options.username = username;
+    }
+    let room = state.room.lock().await;
+    // The core enables the requested media once connected; capture follows.
+    room.connect(&meet_url, &options)
+        .await
+        .map_err(|e| e.to_string())?;
+    drop(room);
+
+    let controls = state.controls.lock().await;
+    sync_captures(&state, &controls).await
+}
+
+/// Microphone and camera state, e.g. after joining with the
+/// mic/camera-on-join settings.
+#[tauri::command]
+async fn get_media_state(state: tauri::State<'_, VisioState>) -> Result<serde_json::Value, String> {
+    let controls = state.controls.lock().await;
+    Ok(serde_json::json!({
+        "micEnabled": controls.is_microphone_enabled().await,
+        "cameraEnabled": controls.is_camera_enabled().await,
+    }))
+}
+
+#[tauri::command]
+async fn switch_room(
+    state: tauri::State<'_, VisioState>,
+    meet_url: String,
+    username: Option<String>,
 ) -> Result<(), String> {
     let room = state.room.lock().await;
-    room.connect(&meet_url, username.as_deref())
+    room.switch_room(&meet_url, username.as_deref())
         .await
         .map_err(|e| e.to_string())
 }
@@ -295,6 +838,8 @@ async fn connect(
 async fn disconnect(state: tauri::State<'_, VisioState>) -> Result<(), String> {
     let room = state.room.lock().await;
     room.disconnect().await;
+    // The only room: nothing else uses the shared media state.
+    room.reset_shared_media();
     Ok(())
 }
 
@@ -312,12 +857,32 @@ async fn get_connection_state(state: tauri::State<'_, VisioState>) -> Result<Str
 }
 
 #[tauri::command]
-async fn get_participants(
+async fn get_local_permissions(
     state: tauri::State<'_, VisioState>,
-) -> Result<Vec<serde_json::Value>, String> {
+) -> Result<Option<serde_json::Value>, String> {
     let room = state.room.lock().await;
-    let participants = room.participants().await;
-    let result: Vec<serde_json::Value> = participants
+    Ok(room
+        .local_permissions()
+        .await
+        .as_ref()
+        .map(permissions_to_json))
+}
+
+#[tauri::command]
+async fn get_feature_flags(
+    state: tauri::State<'_, VisioState>,
+) -> Result<serde_json::Value, String> {
+    let room = state.room.lock().await;
+    Ok(feature_flags_to_json(&room.feature_flags().await))
+}
+
+#[tauri::command]
+async fn get_participants(
+    state: tauri::State<'_, VisioState>,
+) -> Result<Vec<serde_json::Value>, String> {
+    let room = state.room.lock().await;
+    let participants = room.participants().await;
+    let result: Vec<serde_json::Value> = participants
         .into_iter()
         .map(|p| {
             serde_json::json!({
@@ -328,6 +893,7 @@ async fn get_participants(
                 "has_video": p.has_video,
                 "video_track_sid": p.video_track_sid,
                 "connection_quality": format!("{:?}", p.connection_quality),
+                "presence": presence_to_str(p.presence),
             })
         })
         .collect();
@@ -349,299 +915,1864 @@ async fn get_local_participant(
             "has_video": p.has_video,
             "video_track_sid": p.video_track_sid,
             "connection_quality": format!("{:?}", p.connection_quality),
+            "presence": presence_to_str(p.presence),
+        })
+    }))
+}
+
+#[tauri::command]
+async fn get_video_tracks(state: tauri::State<'_, VisioState>) -> Result<Vec<String>, String> {
+    let room = state.room.lock().await;
+    let sids = room.video_track_sids().await;
+    Ok(sids)
+}
+
+#[tauri::command]
+async fn toggle_mic(state: tauri::State<'_, VisioState>, enabled: bool) -> Result<(), String> {
+    let controls = state.controls.lock().await;
+    controls
+        .set_microphone_enabled(enabled)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if enabled {
+        start_audio_capture(&state, &controls).await?;
+    } else {
+        // Stop capture
+        let mut cap = state
+            .audio_capture
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        if let Some(capture) = cap.take() {
+            capture.stop();
+        }
+    }
+
+    Ok(())
+}
+
+/// Start or stop the native captures to match the microphone and camera
+/// state, after it changed without going through `toggle_mic` /
+/// `toggle_camera` (join settings, shortcuts).
+async fn sync_captures(state: &VisioState, controls: &MeetingControls) -> Result<(), String> {
+    if controls.is_microphone_enabled().await {
+        start_audio_capture(state, controls).await?;
+    } else if let Some(capture) = state
+        .audio_capture
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .take()
+    {
+        capture.stop();
+    }
+    #[cfg(target_os = "macos")]
+    if controls.is_camera_enabled().await {
+        let running = state
+            .camera_capture
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .is_some();
+        if !running && let Some(source) = controls.video_source().await {
+            start_camera_capture(state, source)?;
+        }
+    } else if let Some(mut capture) = state
+        .camera_capture
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .take()
+    {
+        capture.stop();
+    }
+    Ok(())
+}
+
+/// Feed the published microphone source from cpal, unless already running.
+async fn start_audio_capture(state: &VisioState, controls: &MeetingControls) -> Result<(), String> {
+    let already_running = state
+        .audio_capture
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .is_some();
+    if !already_running && let Some(source) = controls.audio_source().await {
+        let preferred = state.settings.get().preferred_microphone_id;
+        let capture = audio_cpal::CpalAudioCapture::start(source, preferred.as_deref())
+            .map_err(|e| format!("audio capture: {e}"))?;
+        *state
+            .audio_capture
+            .lock()
+            .unwrap_or_else(|e| e.into_inner()) = Some(capture);
+    }
+    Ok(())
+}
+
+#[cfg(target_os = "macos")]
+fn start_camera_capture(
+    state: &VisioState,
+    source: livekit::webrtc::video_source::native::NativeVideoSource,
+) -> Result<(), String> {
+    let preferred = state.settings.get().preferred_camera_id;
+    let capture = camera_macos::MacCameraCapture::start(source, preferred.as_deref())
+        .map_err(|e| format!("camera capture: {e}"))?;
+    *state
+        .camera_capture
+        .lock()
+        .unwrap_or_else(|e| e.into_inner()) = Some(capture);
+    Ok(())
+}
+
+fn check_result_json(result: &visio_core::CheckResult) -> serde_json::Value {
+    match result {
+        visio_core::CheckResult::Passed => serde_json::json!({ "status": "passed" }),
+        visio_core::CheckResult::Failed(reason) => {
+            serde_json::json!({ "status": "failed", "reason": reason })
+        }
+        visio_core::CheckResult::Skipped => serde_json::json!({ "status": "skipped" }),
+    }
+}
+
+/// Pre-join device check: listen to the selected microphone (and camera
+/// with `check_camera`, macOS only) for a moment and report silent or
+/// dead devices, and whether the audio output opens. Captures already
+/// running for a call are used as they are.
+#[tauri::command]
+async fn run_device_check(
+    state: tauri::State<'_, VisioState>,
+    check_camera: bool,
+) -> Result<serde_json::Value, String> {
+    use livekit::webrtc::audio_source::AudioSourceOptions;
+    use livekit::webrtc::audio_source::native::NativeAudioSource;
+
+    let settings = state.settings.get();
+    let speaker = match audio_cpal::CpalAudioPlayout::start(
+        Arc::new(visio_core::AudioPlayoutBuffer::new()),
+        settings.preferred_speaker_id.as_deref(),
+    ) {
+        Ok(_) => visio_core::CheckResult::Passed,
+        Err(e) => visio_core::CheckResult::Failed(e),
+    };
+
+    let mut mic_error = None;
+    let mic_running = state
+        .audio_capture
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .is_some();
+    let mic = if mic_running {
+        None
+    } else {
+        let source = NativeAudioSource::new(AudioSourceOptions::default(), 48_000, 1, 100);
+        audio_cpal::CpalAudioCapture::start(source, settings.preferred_microphone_id.as_deref())
+            .map_err(|e| mic_error = Some(e))
+            .ok()
+    };
+
+    #[cfg(target_os = "macos")]
+    let mut camera = None;
+    #[cfg(target_os = "macos")]
+    let mut camera_error = None;
+    #[cfg(target_os = "macos")]
+    if check_camera
+        && state
+            .camera_capture
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .is_none()
+    {
+        use livekit::webrtc::video_source::VideoResolution;
+        use livekit::webrtc::video_source::native::NativeVideoSource;
+        let source = NativeVideoSource::new(
+            VideoResolution {
+                width: 1280,
+                height: 720,
+            },
+            false,
+        );
+        match camera_macos::MacCameraCapture::start(source, settings.preferred_camera_id.as_deref())
+        {
+            Ok(capture) => camera = Some(capture),
+            Err(e) => camera_error = Some(e),
+        }
+    }
+    // No camera capture on other desktops yet.
+    #[cfg(not(target_os = "macos"))]
+    let check_camera = {
+        let _ = check_camera;
+        false
+    };
+
+    let mut report = visio_core::device_check::run_device_check(
+        visio_core::device_check::DEFAULT_CHECK_WINDOW,
+        speaker,
+        check_camera,
+    )
+    .await;
+
+    if let Some(capture) = mic {
+        capture.stop();
+    }
+    if let Some(e) = mic_error {
+        report.microphone = visio_core::CheckResult::Failed(e);
+    }
+    #[cfg(target_os = "macos")]
+    {
+        if let Some(mut capture) = camera {
+            capture.stop();
+        }
+        if let Some(e) = camera_error {
+            report.camera = visio_core::CheckResult::Failed(e);
+        }
+    }
+
+    Ok(serde_json::json!({
+        "microphone": check_result_json(&report.microphone),
+        "microphoneLevel": report.microphone_level,
+        "camera": check_result_json(&report.camera),
+        "cameraFrames": report.camera_frames,
+        "speaker": check_result_json(&report.speaker),
+        "hasProblem": report.has_problem(),
+    }))
+}
+
+#[tauri::command]
+async fn toggle_camera(state: tauri::State<'_, VisioState>, enabled: bool) -> Result<(), String> {
+    let controls = state.controls.lock().await;
+    if enabled {
+        // Publish camera track if not yet published
+        if controls.video_source().await.is_none() {
+            #[cfg_attr(not(target_os = "macos"), allow(unused_variables))]
+            let source = controls.publish_camera().await.map_err(|e| e.to_string())?;
+            tracing::info!("camera track published via toggle_camera");
+
+            // Start native camera capture
+            #[cfg(target_os = "macos")]
+            start_camera_capture(&state, source)?;
+        }
+    } else {
+        // Stop camera capture when disabling
+        #[cfg(target_os = "macos")]
+        {
+            let mut cam = state
+                .camera_capture
+                .lock()
+                .unwrap_or_else(|e| e.into_inner());
+            if let Some(mut capture) = cam.take() {
+                capture.stop();
+            }
+        }
+    }
+    controls
+        .set_camera_enabled(enabled)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Share the screen, with what the computer plays when `system_audio` is
+/// set (Windows and Linux; the share fails elsewhere).
+#[tauri::command]
+async fn start_screen_share(
+    state: tauri::State<'_, VisioState>,
+    system_audio: bool,
+) -> Result<(), String> {
+    let controls = state.controls.lock().await;
+    let sources = controls
+        .start_screen_share(ScreenShareOptions { system_audio })
+        .await
+        .map_err(|e| e.to_string())?;
+    if let Some(audio) = sources.audio {
+        match audio_cpal::CpalAudioCapture::start_loopback(audio) {
+            Ok(capture) => {
+                *state
+                    .screen_audio_capture
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner()) = Some(capture);
+            }
+            Err(e) => {
+                let _ = controls.stop_screen_share().await;
+                return Err(format!("system audio capture: {e}"));
+            }
+        }
+    }
+    Ok(())
+}
+
+#[tauri::command]
+async fn stop_screen_share(state: tauri::State<'_, VisioState>) -> Result<(), String> {
+    if let Some(capture) = state
+        .screen_audio_capture
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .take()
+    {
+        capture.stop();
+    }
+    let controls = state.controls.lock().await;
+    controls
+        .stop_screen_share()
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Hide the outgoing video behind a blurred frame or a card, keeping the
+/// camera track published.
+#[tauri::command]
+async fn set_video_privacy(
+    state: tauri::State<'_, VisioState>,
+    enabled: bool,
+) -> Result<(), String> {
+    state.controls.lock().await.set_video_privacy(enabled);
+    Ok(())
+}
+
+/// What replaces the outgoing video in privacy mode: "blur" or "card".
+#[tauri::command]
+fn set_video_privacy_style(
+    state: tauri::State<'_, VisioState>,
+    style: String,
+) -> Result<(), String> {
+    let style = match style.as_str() {
+        "blur" => VideoPrivacyStyle::Blur,
+        "card" => VideoPrivacyStyle::Card,
+        other => return Err(format!("unknown privacy style: {other}")),
+    };
+    state.settings.set_video_privacy_style(style);
+    visio_core::video_privacy().set_style(style);
+    Ok(())
+}
+
+/// `hint` is "motion" (camera) or "detail" (slides, documents).
+#[tauri::command]
+async fn set_video_content_hint(
+    state: tauri::State<'_, VisioState>,
+    hint: String,
+) -> Result<(), String> {
+    let hint = match hint.as_str() {
+        "motion" => VideoContentHint::Motion,
+        "detail" => VideoContentHint::Detail,
+        other => return Err(format!("unknown content hint: {other}")),
+    };
+    state
+        .controls
+        .lock()
+        .await
+        .set_video_content_hint(hint)
+        .await;
+    Ok(())
+}
+
+/// `codec` is "vp8", "vp9", "h264" or "av1". Applies to the next
+/// published camera track.
+#[tauri::command]
+async fn set_video_codec(state: tauri::State<'_, VisioState>, codec: String) -> Result<(), String> {
+    let codec = match codec.as_str() {
+        "vp8" => VideoCodec::Vp8,
+        "vp9" => VideoCodec::Vp9,
+        "h264" => VideoCodec::H264,
+        "av1" => VideoCodec::Av1,
+        other => return Err(format!("unknown video codec: {other}")),
+    };
+    state.controls.lock().await.set_video_codec(codec);
+    Ok(())
+}
+
+/// MIME types of the codecs used by published and subscribed tracks.
+#[tauri::command]
+async fn get_negotiated_codecs(
+    state: tauri::State<'_, VisioState>,
+) -> Result<serde_json::Value, String> {
+    let room = state.room.lock().await;
+    let codecs = room.negotiated_codecs().await.map_err(|e| e.to_string())?;
+    Ok(serde_json::json!({
+        "sent": codecs.sent,
+        "received": codecs.received,
+    }))
+}
+
+/// Last minutes of bitrate, packet loss and quality of a track, oldest
+/// first, for the quality graph.
+#[tauri::command]
+async fn get_quality_history(
+    state: tauri::State<'_, VisioState>,
+    track_sid: String,
+) -> Result<serde_json::Value, String> {
+    let room = state.room.lock().await;
+    let samples: Vec<serde_json::Value> = room
+        .stats()
+        .quality_history(&track_sid)
+        .into_iter()
+        .map(|s| {
+            serde_json::json!({
+                "timestampMs": s.timestamp_ms,
+                "bitrateKbps": s.bitrate_kbps,
+                "packetLossPct": s.packet_loss_pct,
+                "quality": format!("{:?}", s.quality),
+            })
         })
+        .collect();
+    Ok(serde_json::json!(samples))
+}
+
+fn call_summary_to_json(summary: &visio_core::CallSummary) -> serde_json::Value {
+    serde_json::json!({
+        "endedAtMs": summary.ended_at_ms,
+        "durationSecs": summary.duration_secs,
+        "peakParticipants": summary.peak_participants,
+        "reconnectCount": summary.reconnect_count,
+        "averageQuality": summary.average_quality.as_ref().map(|q| format!("{q:?}")),
+        "errorCount": summary.error_count,
+    })
+}
+
+/// Summary of the last call, for the post-call rating screen.
+#[tauri::command]
+async fn get_last_call_summary(
+    state: tauri::State<'_, VisioState>,
+) -> Result<Option<serde_json::Value>, String> {
+    let summary = state.room.lock().await.call_summary().last_summary();
+    Ok(summary.as_ref().map(call_summary_to_json))
+}
+
+/// Rate the last call from 1 to 5, from the post-call rating screen.
+#[tauri::command]
+async fn submit_feedback(
+    state: tauri::State<'_, VisioState>,
+    rating: u8,
+    comments: Option<String>,
+    attach_logs: bool,
+) -> Result<(), String> {
+    let feedback = state.room.lock().await.feedback();
+    feedback
+        .submit_feedback(rating, comments, attach_logs)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Resolution, frame rate, decode time and freezes of a remote video
+/// track, for the HD/SD badge and the stats overlay.
+#[tauri::command]
+async fn get_video_stats(
+    state: tauri::State<'_, VisioState>,
+    track_sid: String,
+) -> Result<Option<serde_json::Value>, String> {
+    let received = state.room.lock().await.stats().video_stats(&track_sid);
+    let rendered = visio_video::renderer_stats(&track_sid);
+    if received.is_none() && rendered.is_none() {
+        return Ok(None);
+    }
+    let received = received.unwrap_or_default();
+    let rendered = rendered.unwrap_or_default();
+    Ok(Some(serde_json::json!({
+        "width": received.width,
+        "height": received.height,
+        "isHd": received.is_hd(),
+        "fps": received.fps,
+        "avgDecodeMs": received.avg_decode_ms,
+        "freezeCount": received.freeze_count,
+        "renderFps": rendered.fps,
+        "framesDropped": rendered.frames_dropped,
+    })))
+}
+
+/// Recent room events as JSON lines, to attach to a bug report.
+#[tauri::command]
+async fn export_journal(state: tauri::State<'_, VisioState>) -> Result<String, String> {
+    Ok(state.room.lock().await.journal().export())
+}
+
+#[tauri::command]
+async fn get_capture_constraints(
+    state: tauri::State<'_, VisioState>,
+) -> Result<serde_json::Value, String> {
+    let c = state.controls.lock().await.capture_constraints();
+    Ok(serde_json::json!({
+        "width": c.width,
+        "height": c.height,
+        "max_fps": c.max_fps,
+        "low_light_boost": c.low_light_boost,
+    }))
+}
+
+#[tauri::command]
+async fn set_low_light_boost(
+    state: tauri::State<'_, VisioState>,
+    enabled: bool,
+) -> Result<(), String> {
+    state.controls.lock().await.set_low_light_boost(enabled);
+    Ok(())
+}
+
+#[tauri::command]
+async fn get_audio_publish_options(
+    state: tauri::State<'_, VisioState>,
+) -> Result<serde_json::Value, String> {
+    let o = state.controls.lock().await.audio_publish_options();
+    Ok(serde_json::json!({
+        "max_bitrate_bps": o.max_bitrate_bps,
+        "dtx": o.dtx,
+        "red": o.red,
+        "stereo": o.stereo,
+        "voice_processing": o.voice_processing,
     }))
 }
 
+/// Switch the microphone between speech ("voice") and music ("music"),
+/// and remember the choice.
+#[tauri::command]
+async fn set_audio_preset(
+    state: tauri::State<'_, VisioState>,
+    preset: String,
+) -> Result<(), String> {
+    let preset = match preset.as_str() {
+        "voice" => AudioPreset::Voice,
+        "music" => AudioPreset::Music,
+        other => return Err(format!("unknown audio preset: {other}")),
+    };
+    state.settings.set_audio_preset(preset);
+    let controls = state.controls.lock().await;
+    controls
+        .set_audio_preset(preset)
+        .await
+        .map_err(|e| e.to_string())?;
+    // The preset may have replaced the microphone source: capture into the
+    // new one.
+    if let Some(capture) = state
+        .audio_capture
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .take()
+    {
+        capture.stop();
+        start_audio_capture(&state, &controls).await?;
+    }
+    Ok(())
+}
+
+/// Opus bitrate of the microphone, in bits per second.
+#[tauri::command]
+async fn set_audio_bitrate(state: tauri::State<'_, VisioState>, bps: u32) -> Result<(), String> {
+    let controls = state.controls.lock().await;
+    controls
+        .set_audio_bitrate(bps)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Switch the microphone to 16 kbps narrowband Opus for 2G/3G links, or
+/// back to the default encoding.
+#[tauri::command]
+async fn set_low_bandwidth_audio(
+    state: tauri::State<'_, VisioState>,
+    enabled: bool,
+) -> Result<(), String> {
+    let options = if enabled {
+        AudioPublishOptions::low_bandwidth()
+    } else {
+        AudioPublishOptions::default()
+    };
+    let controls = state.controls.lock().await;
+    controls
+        .set_audio_publish_options(options)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+fn power_mode_to_str(mode: PowerMode) -> &'static str {
+    match mode {
+        PowerMode::Normal => "normal",
+        PowerMode::Saver => "saver",
+    }
+}
+
+/// `mode` is "normal" or "saver" (lower capture and rendering rates, no
+/// self-view).
+#[tauri::command]
+async fn set_output_volume(state: tauri::State<'_, VisioState>, volume: f32) -> Result<(), String> {
+    state.room.lock().await.set_output_volume(volume);
+    Ok(())
+}
+
+/// Silence all remote audio ("speaker off"), keeping the volume.
+#[tauri::command]
+async fn set_output_muted(state: tauri::State<'_, VisioState>, muted: bool) -> Result<(), String> {
+    state.room.lock().await.set_output_muted(muted);
+    Ok(())
+}
+
+#[tauri::command]
+async fn set_power_mode(state: tauri::State<'_, VisioState>, mode: String) -> Result<(), String> {
+    let mode = match mode.as_str() {
+        "normal" => PowerMode::Normal,
+        "saver" => PowerMode::Saver,
+        other => return Err(format!("unknown power mode: {other}")),
+    };
+    let room = state.room.lock().await;
+    room.set_power_mode(mode).await;
+    apply_renderer_limits(&room);
+    Ok(())
+}
+
+fn thermal_state_to_str(state: ThermalState) -> &'static str {
+    match state {
+        ThermalState::Nominal => "nominal",
+        ThermalState::Fair => "fair",
+        ThermalState::Serious => "serious",
+        ThermalState::Critical => "critical",
+    }
+}
+
+/// `state` is "nominal", "fair", "serious" or "critical".
+#[tauri::command]
+async fn report_thermal_state(
+    state: tauri::State<'_, VisioState>,
+    thermal_state: String,
+) -> Result<(), String> {
+    let thermal = match thermal_state.as_str() {
+        "nominal" => ThermalState::Nominal,
+        "fair" => ThermalState::Fair,
+        "serious" => ThermalState::Serious,
+        "critical" => ThermalState::Critical,
+        other => return Err(format!("unknown thermal state: {other}")),
+    };
+    let room = state.room.lock().await;
+    room.report_thermal_state(thermal).await;
+    apply_renderer_limits(&room);
+    Ok(())
+}
+
+/// Renderers are process-wide: follow the power state of the room.
+fn apply_renderer_limits(room: &RoomManager) {
+    let power = room.power_state();
+    visio_video::set_max_render_fps(power.max_render_fps());
+    visio_video::set_self_view_enabled(power.mode.self_view_enabled());
+}
+
+#[tauri::command]
+async fn get_power_mode(state: tauri::State<'_, VisioState>) -> Result<String, String> {
+    Ok(power_mode_to_str(state.room.lock().await.power_mode()).to_string())
+}
+
+fn device_permission_to_str(permission: DevicePermission) -> &'static str {
+    match permission {
+        DevicePermission::Camera => "camera",
+        DevicePermission::Microphone => "microphone",
+        DevicePermission::ScreenCapture => "screenCapture",
+        DevicePermission::Notifications => "notifications",
+    }
+}
+
+fn permission_state_to_str(state: PermissionState) -> &'static str {
+    match state {
+        PermissionState::Unknown => "unknown",
+        PermissionState::Granted => "granted",
+        PermissionState::Denied => "denied",
+    }
+}
+
+/// Report a permission state learned by the frontend (Permissions API,
+/// `getUserMedia` failures). `permission` is "camera", "microphone",
+/// "screenCapture" or "notifications"; `permission_state` is "unknown",
+/// "granted" or "denied".
+#[tauri::command]
+async fn set_permission_state(
+    state: tauri::State<'_, VisioState>,
+    permission: String,
+    permission_state: String,
+) -> Result<(), String> {
+    let permission = match permission.as_str() {
+        "camera" => DevicePermission::Camera,
+        "microphone" => DevicePermission::Microphone,
+        "screenCapture" => DevicePermission::ScreenCapture,
+        "notifications" => DevicePermission::Notifications,
+        other => return Err(format!("unknown permission: {other}")),
+    };
+    let permission_state = match permission_state.as_str() {
+        "unknown" => PermissionState::Unknown,
+        "granted" => PermissionState::Granted,
+        "denied" => PermissionState::Denied,
+        other => return Err(format!("unknown permission state: {other}")),
+    };
+    state
+        .room
+        .lock()
+        .await
+        .device_permissions()
+        .set(permission, permission_state);
+    Ok(())
+}
+
+#[tauri::command]
+async fn send_chat(
+    state: tauri::State<'_, VisioState>,
+    text: String,
+) -> Result<serde_json::Value, String> {
+    let chat = state.chat.lock().await;
+    let msg = chat.send_message(&text).await.map_err(|e| e.to_string())?;
+    Ok(serde_json::json!({
+        "id": msg.id,
+        "sender_sid": msg.sender_sid,
+        "sender_name": msg.sender_name,
+        "text": msg.text,
+        "timestamp_ms": msg.timestamp_ms,
+        "kind": msg.kind,
+    }))
+}
+
+#[tauri::command]
+async fn get_messages(
+    state: tauri::State<'_, VisioState>,
+) -> Result<Vec<serde_json::Value>, String> {
+    let chat = state.chat.lock().await;
+    let messages = chat.messages().await;
+    let result: Vec<serde_json::Value> = messages
+        .into_iter()
+        .map(|m| {
+            serde_json::json!({
+                "id": m.id,
+                "sender_sid": m.sender_sid,
+                "sender_name": m.sender_name,
+                "text": m.text,
+                "timestamp_ms": m.timestamp_ms,
+                "kind": m.kind,
+            })
+        })
+        .collect();
+    Ok(result)
+}
+
+/// Spans of a message to draw as text, see `ChatService::message_body`.
+#[tauri::command]
+async fn get_message_body(
+    state: tauri::State<'_, VisioState>,
+    text: String,
+) -> Result<Vec<visio_core::MessageSpan>, String> {
+    Ok(state.chat.lock().await.message_body(&text))
+}
+
+#[tauri::command]
+async fn set_chat_formatting(
+    state: tauri::State<'_, VisioState>,
+    enabled: bool,
+) -> Result<(), String> {
+    state.room.lock().await.set_chat_formatting(enabled);
+    Ok(())
+}
+
+/// `format` is "txt", "json" or "csv".
+fn parse_chat_export_format(format: &str) -> Result<ChatExportFormat, String> {
+    match format {
+        "txt" => Ok(ChatExportFormat::Txt),
+        "json" => Ok(ChatExportFormat::Json),
+        "csv" => Ok(ChatExportFormat::Csv),
+        other => Err(format!("unknown chat export format: {other}")),
+    }
+}
+
+/// The chat of the session as a file, delivered as raw bytes.
+#[tauri::command]
+async fn export_chat(
+    state: tauri::State<'_, VisioState>,
+    format: String,
+    language: String,
+    utc_offset_minutes: i32,
+) -> Result<tauri::ipc::Response, String> {
+    let format = parse_chat_export_format(&format)?;
+    let chat = state.chat.lock().await;
+    let bytes = chat
+        .export_chat(format, &language, utc_offset_minutes)
+        .await;
+    Ok(tauri::ipc::Response::new(bytes))
+}
+
+/// Write the chat of the session to `path`, e.g. picked in a save dialog.
+#[tauri::command]
+async fn save_chat_export(
+    state: tauri::State<'_, VisioState>,
+    format: String,
+    language: String,
+    utc_offset_minutes: i32,
+    path: String,
+) -> Result<(), String> {
+    let format = parse_chat_export_format(&format)?;
+    let chat = state.chat.lock().await;
+    let bytes = chat
+        .export_chat(format, &language, utc_offset_minutes)
+        .await;
+    std::fs::write(&path, bytes).map_err(|e| format!("cannot write {path}: {e}"))
+}
+
+#[tauri::command]
+fn get_translations(app: AppHandle, lang: String) -> Result<serde_json::Value, String> {
+    let supported = ["en", "fr", "de", "es", "it", "nl"];
+    let lang = if supported.contains(&lang.as_str()) {
+        lang
+    } else {
+        "en".to_string()
+    };
+
+    let resource_path = app
+        .path()
+        .resource_dir()
+        .map_err(|e| format!("resource dir: {e}"))?
+        .join("i18n")
+        .join(format!("{lang}.json"));
+
+    let content = std::fs::read_to_string(&resource_path).map_err(|e| {
+        tracing::warn!("failed to load i18n/{lang}.json from {resource_path:?}: {e}");
+        format!("i18n file not found: {lang}.json")
+    })?;
+
+    serde_json::from_str(&content).map_err(|e| format!("invalid i18n JSON: {e}"))
+}
+
+/// String `key` of the shared catalogs in the language setting, with
+/// `{name}` placeholders replaced by `args`.
+#[tauri::command]
+fn translate(
+    state: tauri::State<'_, VisioState>,
+    key: String,
+    args: std::collections::HashMap<String, String>,
+) -> String {
+    let language = state.settings.get().language.unwrap_or_default();
+    let args: Vec<(&str, &str)> = args.iter().map(|(k, v)| (k.as_str(), v.as_str())).collect();
+    visio_core::i18n::translate(&language, &key, &args)
+}
+
+#[tauri::command]
+fn get_system_language() -> String {
+    sys_locale::get_locale()
+        .and_then(|l| l.split(['-', '_']).next().map(String::from))
+        .unwrap_or_else(|| "en".to_string())
+}
+
+#[tauri::command]
+fn get_settings(state: tauri::State<'_, VisioState>) -> Result<serde_json::Value, String> {
+    let s = state.settings.get();
+    Ok(serde_json::json!({
+        "display_name": s.display_name,
+        "language": s.language,
+        "mic_enabled_on_join": s.mic_enabled_on_join,
+        "camera_enabled_on_join": s.camera_enabled_on_join,
+        "theme": s.theme,
+        "video_overlay_text": s.video_overlay_text,
+        "video_overlay_corner": s.video_overlay_corner,
+        "video_privacy_style": s.video_privacy_style,
+        "hand_auto_lower_enabled": s.hand_auto_lower_enabled,
+        "hand_auto_lower_delay_secs": s.hand_auto_lower_delay_secs,
+        "metrics_enabled": s.metrics_enabled,
+        "metrics_endpoint": s.metrics_endpoint,
+        "feedback_diagnostics_enabled": s.feedback_diagnostics_enabled,
+        "rtc_config": s.rtc_config,
+        "preferred_microphone_id": s.preferred_microphone_id,
+        "preferred_camera_id": s.preferred_camera_id,
+        "preferred_speaker_id": s.preferred_speaker_id,
+        "audio_ducking_enabled": s.audio_ducking_enabled,
+        "audio_ducking_level": s.audio_ducking_level,
+        "auto_gain_enabled": s.auto_gain_enabled,
+        "auto_gain_target_lufs": s.auto_gain_target_lufs,
+        "audio_preset": s.audio_preset,
+        "alone_timeout_mins": s.alone_timeout_mins,
+        "auto_leave_when_alone": s.auto_leave_when_alone,
+        "inactivity_timeout_mins": s.inactivity_timeout_mins,
+        "quality_policy_enabled": s.quality_policy_enabled,
+        "quality_policy_delay_secs": s.quality_policy_delay_secs,
+        "quality_policy_pause_remote_video": s.quality_policy_pause_remote_video,
+        "extras": s.extras,
+    }))
+}
+
+#[tauri::command]
+fn set_display_name(
+    state: tauri::State<'_, VisioState>,
+    name: Option<String>,
+) -> Result<(), String> {
+    if let Some(ref n) = name {
+        let trimmed = n.trim();
+        if trimmed.is_empty() || trimmed.len() > 100 {
+            return Err("display name must be 1-100 characters".into());
+        }
+    }
+    state.settings.set_display_name(name);
+    Ok(())
+}
+
+#[tauri::command]
+async fn set_language(
+    state: tauri::State<'_, VisioState>,
+    lang: Option<String>,
+) -> Result<(), String> {
+    let supported = ["en", "fr", "de", "es", "it", "nl"];
+    if let Some(ref l) = lang
+        && !supported.contains(&l.as_str())
+    {
+        return Err(format!("unsupported language: {l}"));
+    }
+    state.settings.set_language(lang);
+    let room = state.room.lock().await;
+    room.system_messages().apply_settings(&state.settings.get());
+    Ok(())
+}
+
+#[tauri::command]
+fn set_mic_enabled_on_join(state: tauri::State<'_, VisioState>, enabled: bool) {
+    state.settings.set_mic_enabled_on_join(enabled);
+}
+
+#[tauri::command]
+fn set_camera_enabled_on_join(state: tauri::State<'_, VisioState>, enabled: bool) {
+    state.settings.set_camera_enabled_on_join(enabled);
+}
+
+#[tauri::command]
+fn set_theme(state: tauri::State<'_, VisioState>, theme: String) -> Result<(), String> {
+    let valid = ["light", "dark", "system"];
+    if !valid.contains(&theme.as_str()) {
+        return Err(format!("invalid theme: {theme}"));
+    }
+    state.settings.set_theme(theme);
+    Ok(())
+}
+
+#[tauri::command]
+async fn set_hand_auto_lower(
+    state: tauri::State<'_, VisioState>,
+    enabled: bool,
+    delay_secs: u32,
+) -> Result<(), String> {
+    if delay_secs == 0 {
+        return Err("auto-lower delay must be at least 1 second".into());
+    }
+    state.settings.set_hand_auto_lower(enabled, delay_secs);
+    let room = state.room.lock().await;
+    room.set_hand_auto_lower(state.settings.get().hand_auto_lower());
+    Ok(())
+}
+
+/// Minutes alone before "alone-in-room" and without activity before
+/// "inactivity-detected"; 0 disables either check.
+#[tauri::command]
+async fn set_idle_policy(
+    state: tauri::State<'_, VisioState>,
+    alone_timeout_mins: u32,
+    auto_leave_when_alone: bool,
+    inactivity_timeout_mins: u32,
+) -> Result<(), String> {
+    state.settings.set_idle(
+        alone_timeout_mins,
+        auto_leave_when_alone,
+        inactivity_timeout_mins,
+    );
+    let room = state.room.lock().await;
+    room.set_idle_config(state.settings.get().idle());
+    Ok(())
+}
+
+/// Seconds of poor quality before the camera, and remote video with
+/// `pause_remote_video`, are turned off until the quality recovers.
+#[tauri::command]
+async fn set_quality_policy(
+    state: tauri::State<'_, VisioState>,
+    enabled: bool,
+    delay_secs: u32,
+    pause_remote_video: bool,
+) -> Result<(), String> {
+    state
+        .settings
+        .set_quality_policy(enabled, delay_secs, pause_remote_video);
+    let room = state.room.lock().await;
+    room.set_quality_policy_config(state.settings.get().quality_policy());
+    Ok(())
+}
+
+/// The user answered "inactivity-detected": still there.
+#[tauri::command]
+async fn report_activity(state: tauri::State<'_, VisioState>) -> Result<(), String> {
+    state.room.lock().await.report_activity();
+    Ok(())
+}
+
+#[tauri::command]
+fn set_audio_ducking(
+    state: tauri::State<'_, VisioState>,
+    enabled: bool,
+    level: f32,
+) -> Result<(), String> {
+    state.settings.set_audio_ducking(enabled, level);
+    visio_core::audio_ducking::audio_ducker().set_config(state.settings.get().audio_ducking());
+    Ok(())
+}
+
+#[tauri::command]
+fn set_auto_gain(
+    state: tauri::State<'_, VisioState>,
+    enabled: bool,
+    target_lufs: f32,
+) -> Result<(), String> {
+    state.settings.set_auto_gain(enabled, target_lufs);
+    visio_core::auto_gain::auto_gain().set_config(state.settings.get().auto_gain());
+    Ok(())
+}
+
+#[tauri::command]
+async fn set_metrics(
+    state: tauri::State<'_, VisioState>,
+    enabled: bool,
+    endpoint: Option<String>,
+) -> Result<(), String> {
+    state.settings.set_metrics(enabled, endpoint);
+    let room = state.room.lock().await;
+    room.metrics().apply_settings(&state.settings.get());
+    Ok(())
+}
+
+#[tauri::command]
+async fn set_feedback_diagnostics_enabled(
+    state: tauri::State<'_, VisioState>,
+    enabled: bool,
+) -> Result<(), String> {
+    state.settings.set_feedback_diagnostics_enabled(enabled);
+    let room = state.room.lock().await;
+    room.feedback().apply_settings(&state.settings.get());
+    Ok(())
+}
+
+#[tauri::command]
+async fn set_rtc_config(
+    state: tauri::State<'_, VisioState>,
+    config: visio_core::RtcConfigOverride,
+) -> Result<(), String> {
+    config.validate().map_err(|e| e.to_string())?;
+    state.settings.set_rtc_config(config.clone());
+    let room = state.room.lock().await;
+    room.set_rtc_config(config);
+    Ok(())
+}
+
+#[tauri::command]
+fn get_extra(state: tauri::State<'_, VisioState>, key: String) -> Option<String> {
+    state.settings.get_extra(&key)
+}
+
+#[tauri::command]
+fn set_extra(state: tauri::State<'_, VisioState>, key: String, value: Option<String>) {
+    state.settings.set_extra(&key, value);
+}
+
+#[tauri::command]
+fn list_audio_devices() -> serde_json::Value {
+    let (inputs, outputs) = audio_cpal::device_names();
+    serde_json::json!({ "inputs": inputs, "outputs": outputs })
+}
+
+/// Takes effect the next time the microphone is enabled.
+#[tauri::command]
+fn set_preferred_microphone_id(state: tauri::State<'_, VisioState>, id: Option<String>) {
+    state.settings.set_preferred_microphone_id(id);
+}
+
+/// Takes effect the next time the camera is enabled.
+#[tauri::command]
+fn set_preferred_camera_id(state: tauri::State<'_, VisioState>, id: Option<String>) {
+    state.settings.set_preferred_camera_id(id);
+}
+
+/// Takes effect on the next start of the app.
+#[tauri::command]
+fn set_preferred_speaker_id(state: tauri::State<'_, VisioState>, id: Option<String>) {
+    state.settings.set_preferred_speaker_id(id);
+}
+
+#[tauri::command]
+fn get_meet_instances(state: tauri::State<'_, VisioState>) -> Result<Vec<String>, String> {
+    Ok(state.settings.get_meet_instances())
+}
+
+/// Structured intent of a deep link, or null if the app does not handle it.
+#[tauri::command]
+fn parse_deep_link(uri: String) -> Option<serde_json::Value> {
+    let link = visio_core::DeepLink::parse(&uri)?;
+    Some(match link {
+        visio_core::DeepLink::JoinRoom {
+            meet_url,
+            instance,
+            slug,
+            display_name,
+        } => serde_json::json!({
+            "kind": "joinRoom",
+            "meetUrl": meet_url,
+            "instance": instance,
+            "slug": slug,
+            "displayName": display_name,
+        }),
+        visio_core::DeepLink::OpenSettings => serde_json::json!({ "kind": "openSettings" }),
+    })
+}
+
+#[tauri::command]
+fn set_meet_instances(state: tauri::State<'_, VisioState>, instances: Vec<String>) {
+    state.settings.set_meet_instances(instances);
+}
+
+/// Remote video tracks whose tiles are on screen; the others are paused.
+#[tauri::command]
+async fn set_visible_tracks(
+    state: tauri::State<'_, VisioState>,
+    track_sids: Vec<String>,
+) -> Result<(), String> {
+    let visibility = state.room.lock().await.visibility();
+    let hidden = visibility.set_visible_tracks(track_sids.clone()).await;
+    for sid in &track_sids {
+        visio_video::set_renderer_paused(sid, false);
+    }
+    for sid in &hidden {
+        visio_video::set_renderer_paused(sid, true);
+    }
+    Ok(())
+}
+
+#[tauri::command]
+fn get_renderer_stats(track_sid: String) -> Option<serde_json::Value> {
+    visio_video::renderer_stats(&track_sid).map(|s| {
+        serde_json::json!({
+            "frames_received": s.frames_received,
+            "frames_rendered": s.frames_rendered,
+            "frames_dropped": s.frames_dropped,
+            "avg_render_ms": s.avg_render_ms,
+            "fps": s.fps,
+        })
+    })
+}
+
+/// Local capture delays and audio/video skew (positive: video lags).
+#[tauri::command]
+fn get_av_sync_stats() -> serde_json::Value {
+    let s = visio_core::av_sync::av_sync_monitor().stats();
+    serde_json::json!({
+        "audio_delay_ms": s.audio_delay_ms,
+        "video_delay_ms": s.video_delay_ms,
+        "skew_ms": s.skew_ms,
+    })
+}
+
+/// Save the last frame of `track_sid` as PNG to `path`, by default a
+/// timestamped file in the data directory. Returns the path of the file.
+#[tauri::command]
+fn save_snapshot(track_sid: String, path: Option<String>) -> Result<String, String> {
+    let png = visio_video::capture_snapshot(&track_sid)
+        .ok_or_else(|| format!("no frame available for track {track_sid}"))?;
+    let path = match path {
+        Some(path) => std::path::PathBuf::from(path),
+        None => {
+            let secs = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or_default();
+            data_paths()
+                .snapshots_dir()
+                .join(format!("visio-{secs}.png"))
+        }
+    };
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+    std::fs::write(&path, png).map_err(|e| e.to_string())?;
+    Ok(path.to_string_lossy().into_owned())
+}
+
+#[tauri::command]
+async fn get_dominant_speaker(
+    state: tauri::State<'_, VisioState>,
+) -> Result<Option<String>, String> {
+    let room = state.room.lock().await;
+    Ok(room.dominant_speaker().await)
+}
+
+#[tauri::command]
+async fn get_layout_order(state: tauri::State<'_, VisioState>) -> Result<Vec<String>, String> {
+    let room = state.room.lock().await;
+    Ok(room.layout().ordered_participants())
+}
+
+#[tauri::command]
+async fn pin_participant(
+    state: tauri::State<'_, VisioState>,
+    participant_sid: String,
+) -> Result<(), String> {
+    let room = state.room.lock().await;
+    room.layout().pin_participant(&participant_sid);
+    Ok(())
+}
+
+#[tauri::command]
+async fn unpin_participant(
+    state: tauri::State<'_, VisioState>,
+    participant_sid: String,
+) -> Result<(), String> {
+    let room = state.room.lock().await;
+    room.layout().unpin_participant(&participant_sid);
+    Ok(())
+}
+
+#[tauri::command]
+async fn raise_hand(state: tauri::State<'_, VisioState>) -> Result<(), String> {
+    tracing::info!("Tauri command: raise_hand");
+    let room = state.room.lock().await;
+    room.raise_hand().await.map_err(|e| {
+        tracing::error!("raise_hand command failed: {e}");
+        e.to_string()
+    })
+}
+
+#[tauri::command]
+async fn lower_hand(state: tauri::State<'_, VisioState>) -> Result<(), String> {
+    tracing::info!("Tauri command: lower_hand");
+    let room = state.room.lock().await;
+    room.lower_hand().await.map_err(|e| {
+        tracing::error!("lower_hand command failed: {e}");
+        e.to_string()
+    })
+}
+
+fn presence_to_str(presence: Presence) -> &'static str {
+    match presence {
+        Presence::Available => "available",
+        Presence::Away => "away",
+        Presence::BeRightBack => "brb",
+        Presence::DoNotDisturb => "dnd",
+    }
+}
+
+/// `presence` is "available", "away", "brb" or "dnd".
+#[tauri::command]
+async fn set_presence(state: tauri::State<'_, VisioState>, presence: String) -> Result<(), String> {
+    let presence = match presence.as_str() {
+        "available" => Presence::Available,
+        "away" => Presence::Away,
+        "brb" => Presence::BeRightBack,
+        "dnd" => Presence::DoNotDisturb,
+        other => return Err(format!("unknown presence: {other}")),
+    };
+    let room = state.room.lock().await;
+    room.presence()
+        .set_presence(presence)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+async fn set_local_attributes(
+    state: tauri::State<'_, VisioState>,
+    attributes: HashMap<String, String>,
+) -> Result<(), String> {
+    let room = state.room.lock().await;
+    room.attributes()
+        .set_local_attributes(attributes)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+async fn get_participant_attributes(
+    state: tauri::State<'_, VisioState>,
+    participant_sid: String,
+) -> Result<Option<HashMap<String, String>>, String> {
+    let room = state.room.lock().await;
+    Ok(room
+        .attributes()
+        .participant_attributes(&participant_sid)
+        .await)
+}
+
+#[tauri::command]
+async fn is_hand_raised(state: tauri::State<'_, VisioState>) -> Result<bool, String> {
+    let room = state.room.lock().await;
+    Ok(room.is_hand_raised().await)
+}
+
+#[tauri::command]
+async fn set_chat_open(state: tauri::State<'_, VisioState>, open: bool) -> Result<(), String> {
+    let chat = state.chat.lock().await;
+    chat.set_chat_open(open);
+    // The room tracks it too, for unread counts and the toggleChat action.
+    state.room.lock().await.set_chat_open(open);
+    Ok(())
+}
+
+fn chat_unread_to_json(unread: &visio_core::ChatUnread) -> serde_json::Value {
+    serde_json::json!({
+        "firstUnreadId": unread.first_unread_id,
+        "normal": unread.normal,
+        "mention": unread.mention,
+        "system": unread.system,
+        "total": unread.total(),
+    })
+}
+
+#[tauri::command]
+async fn get_chat_unread(state: tauri::State<'_, VisioState>) -> Result<serde_json::Value, String> {
+    let unread = state.room.lock().await.chat_unread();
+    Ok(chat_unread_to_json(&unread))
+}
+
+/// Acknowledge the messages up to `id`, included, e.g. the last one on
+/// screen: the "new messages" divider moves past them.
+#[tauri::command]
+async fn mark_chat_read_up_to(
+    state: tauri::State<'_, VisioState>,
+    id: String,
+) -> Result<bool, String> {
+    Ok(state.room.lock().await.mark_chat_read_up_to(&id))
+}
+
+/// Action bound to `name` for a key `state`: toggles fire on press,
+/// push-to-talk on press and release.
+fn shortcut_action(name: &str, state: ShortcutState) -> Result<Option<MeetingAction>, String> {
+    let pressed = state == ShortcutState::Pressed;
+    let action = match name {
+        "pushToTalk" => return Ok(Some(MeetingAction::PushToTalk { pressed })),
+        "toggleMic" => MeetingAction::ToggleMicrophone,
+        "toggleCamera" => MeetingAction::ToggleCamera,
+        "toggleHand" => MeetingAction::ToggleHandRaise,
+        "toggleChat" => MeetingAction::ToggleChat,
+        other => return Err(format!("unknown action: {other}")),
+    };
+    Ok(pressed.then_some(action))
+}
+
+fn action_to_str(action: MeetingAction) -> &'static str {
+    match action {
+        MeetingAction::ToggleMicrophone => "toggleMic",
+        MeetingAction::ToggleCamera => "toggleCamera",
+        MeetingAction::ToggleHandRaise => "toggleHand",
+        MeetingAction::ToggleChat => "toggleChat",
+        MeetingAction::PushToTalk { .. } => "pushToTalk",
+    }
+}
+
+/// Dispatch `action` to the core, keep the captures in sync and tell the
+/// frontend the resulting state.
+async fn run_action(
+    app: &AppHandle,
+    state: &VisioState,
+    action: MeetingAction,
+) -> Result<(), String> {
+    state
+        .room
+        .lock()
+        .await
+        .dispatch_action(action)
+        .await
+        .map_err(|e| e.to_string())?;
+    action_done(app, state, action).await
+}
+
+/// Sync the captures after `action` and emit "meeting-action".
+async fn action_done(
+    app: &AppHandle,
+    state: &VisioState,
+    action: MeetingAction,
+) -> Result<(), String> {
+    let (hand_raised, chat_open) = {
+        let room = state.room.lock().await;
+        (room.is_hand_raised().await, room.is_chat_open())
+    };
+    let controls = state.controls.lock().await;
+    sync_captures(state, &controls).await?;
+    let _ = app.emit(
+        "meeting-action",
+        serde_json::json!({
+            "action": action_to_str(action),
+            "micEnabled": controls.is_microphone_enabled().await,
+            "cameraEnabled": controls.is_camera_enabled().await,
+            "handRaised": hand_raised,
+            "chatOpen": chat_open,
+        }),
+    );
+    Ok(())
+}
+
+/// `action` is "toggleMic", "toggleCamera", "toggleHand", "toggleChat" or
+/// "pushToTalk" (with `pressed`, default true).
+#[tauri::command]
+async fn dispatch_action(
+    app: AppHandle,
+    state: tauri::State<'_, VisioState>,
+    action: String,
+    pressed: Option<bool>,
+) -> Result<(), String> {
+    let key = if pressed.unwrap_or(true) {
+        ShortcutState::Pressed
+    } else {
+        ShortcutState::Released
+    };
+    match shortcut_action(&action, key)? {
+        Some(action) => run_action(&app, &state, action).await,
+        None => Ok(()),
+    }
+}
+
+fn media_button_to_str(button: MediaButton) -> &'static str {
+    match button {
+        MediaButton::PlayPause => "playPause",
+        MediaButton::Hangup => "hangup",
+    }
+}
+
+/// Forward a headset or media key press: `button` is "playPause" (toggles
+/// the microphone) or "hangup". Returns whether it was handled; a handled
+/// hang-up emits "media-button-handled" for the frontend to leave the call.
+#[tauri::command]
+async fn handle_media_button(
+    app: AppHandle,
+    state: tauri::State<'_, VisioState>,
+    button: String,
+) -> Result<bool, String> {
+    let button = match button.as_str() {
+        "playPause" => MediaButton::PlayPause,
+        "hangup" => MediaButton::Hangup,
+        other => return Err(format!("unknown media button: {other}")),
+    };
+    let handled = state
+        .room
+        .lock()
+        .await
+        .handle_media_button(button)
+        .await
+        .map_err(|e| e.to_string())?;
+    if handled && button == MediaButton::PlayPause {
+        action_done(&app, &state, MeetingAction::ToggleMicrophone).await?;
+    }
+    Ok(handled)
+}
+
+/// Milliseconds left before the meeting's scheduled end, null when it has
+/// none.
+#[tauri::command]
+async fn get_time_remaining(state: tauri::State<'_, VisioState>) -> Result<Option<u64>, String> {
+    let room = state.room.lock().await;
+    Ok(room.time_remaining().map(|left| left.as_millis() as u64))
+}
+
+/// Replace the global shortcuts with `shortcuts`, a map from accelerators
+/// ("CmdOrCtrl+Shift+M") to action names (see `dispatch_action`). They
+/// work while the window is unfocused.
+#[tauri::command]
+fn register_shortcuts(app: AppHandle, shortcuts: HashMap<String, String>) -> Result<(), String> {
+    for action in shortcuts.values() {
+        shortcut_action(action, ShortcutState::Pressed)?;
+    }
+    let global_shortcut = app.global_shortcut();
+    global_shortcut
+        .unregister_all()
+        .map_err(|e| e.to_string())?;
+    for (accelerator, action) in shortcuts {
+        global_shortcut
+            .on_shortcut(accelerator.as_str(), move |app, _shortcut, event| {
+                let Ok(Some(action)) = shortcut_action(&action, event.state) else {
+                    return;
+                };
+                let app = app.clone();
+                tauri::async_runtime::spawn(async move {
+                    let state = app.state::<VisioState>();
+                    if let Err(e) = run_action(&app, &state, action).await {
+                        tracing::warn!("shortcut {action} failed: {e}");
+                    }
+                });
+            })
+            .map_err(|e| format!("{accelerator}: {e}"))?;
+    }
+    Ok(())
+}
+
+#[tauri::command]
+async fn send_reaction(state: tauri::State<'_, VisioState>, emoji: String) -> Result<(), String> {
+    let room = state.room.lock().await;
+    room.send_reaction(&emoji).await.map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+async fn publish_data(
+    state: tauri::State<'_, VisioState>,
+    topic: String,
+    payload: Vec<u8>,
+    reliable: bool,
+) -> Result<(), String> {
+    let data_channel = state.room.lock().await.data_channel();
+    data_channel
+        .publish(&topic, payload, reliable)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+async fn subscribe_data_topic(
+    state: tauri::State<'_, VisioState>,
+    topic: String,
+) -> Result<(), String> {
+    let data_channel = state.room.lock().await.data_channel();
+    data_channel.subscribe(&topic).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+async fn unsubscribe_data_topic(
+    state: tauri::State<'_, VisioState>,
+    topic: String,
+) -> Result<(), String> {
+    state.room.lock().await.data_channel().unsubscribe(&topic);
+    Ok(())
+}
+
+#[tauri::command]
+async fn create_poll(
+    state: tauri::State<'_, VisioState>,
+    question: String,
+    options: Vec<String>,
+) -> Result<serde_json::Value, String> {
+    let polls = state.room.lock().await.polls();
+    let poll = polls
+        .create_poll(&question, options)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(poll_to_json(&poll))
+}
+
 #[tauri::command]
-async fn get_video_tracks(
+async fn vote_poll(
     state: tauri::State<'_, VisioState>,
-) -> Result<Vec<String>, String> {
-    let room = state.room.lock().await;
-    let sids = room.video_track_sids().await;
-    Ok(sids)
+    poll_id: String,
+    option: u32,
+) -> Result<serde_json::Value, String> {
+    let polls = state.room.lock().await.polls();
+    let poll = polls
+        .vote(&poll_id, option)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(poll_to_json(&poll))
 }
 
 #[tauri::command]
-async fn toggle_mic(
+async fn close_poll(
     state: tauri::State<'_, VisioState>,
-    enabled: bool,
-) -> Result<(), String> {
-    let controls = state.controls.lock().await;
-    controls
-        .set_microphone_enabled(enabled)
+    poll_id: String,
+) -> Result<serde_json::Value, String> {
+    let polls = state.room.lock().await.polls();
+    let poll = polls
+        .close_poll(&poll_id)
         .await
         .map_err(|e| e.to_string())?;
-
-    if enabled {
-        // Start capture if not already running
-        let already_running = state.audio_capture.lock().unwrap_or_else(|e| e.into_inner()).is_some();
-        if !already_running {
-            if let Some(source) = controls.audio_source().await {
-                let capture = audio_cpal::CpalAudioCapture::start(source)
-                    .map_err(|e| format!("audio capture: {e}"))?;
-                *state.audio_capture.lock().unwrap_or_else(|e| e.into_inner()) = Some(capture);
-            }
-        }
-    } else {
-        // Stop capture
-        let mut cap = state.audio_capture.lock().unwrap_or_else(|e| e.into_inner());
-        if let Some(capture) = cap.take() {
-            capture.stop();
-        }
-    }
-
-    Ok(())
+    Ok(poll_to_json(&poll))
 }
 
 #[tauri::command]
-async fn toggle_camera(
-    state: tauri::State<'_, VisioState>,
-    enabled: bool,
-) -> Result<(), String> {
-    let controls = state.controls.lock().await;
-    if enabled {
-        // Publish camera track if not yet published
-        if controls.video_source().await.is_none() {
-            let source = controls
-                .publish_camera()
-                .await
-                .map_err(|e| e.to_string())?;
-            tracing::info!("camera track published via toggle_camera");
+async fn get_polls(state: tauri::State<'_, VisioState>) -> Result<Vec<serde_json::Value>, String> {
+    let polls = state.room.lock().await.polls();
+    Ok(polls.polls().await.iter().map(poll_to_json).collect())
+}
 
-            // Start native camera capture
-            #[cfg(target_os = "macos")]
-            {
-                let capture = camera_macos::MacCameraCapture::start(source)
-                    .map_err(|e| format!("camera capture: {e}"))?;
-                let mut cam = state.camera_capture.lock().unwrap_or_else(|e| e.into_inner());
-                *cam = Some(capture);
-            }
-        }
-    } else {
-        // Stop camera capture when disabling
-        #[cfg(target_os = "macos")]
-        {
-            let mut cam = state.camera_capture.lock().unwrap_or_else(|e| e.into_inner());
-            if let Some(mut capture) = cam.take() {
-                capture.stop();
-            }
-        }
-    }
-    controls
-        .set_camera_enabled(enabled)
-        .await
-        .map_err(|e| e.to_string())
+#[tauri::command]
+async fn get_invite_info(state: tauri::State<'_, VisioState>) -> Result<serde_json::Value, String> {
+    let room = state.room.lock().await;
+    let info = room.invite_info().await.map_err(|e| e.to_string())?;
+    Ok(serde_json::json!({
+        "url": info.url,
+        "dialIn": info.dial_in.map(|d| serde_json::json!({
+            "phoneNumber": d.phone_number,
+            "pinCode": d.pin_code,
+        })),
+    }))
 }
 
 #[tauri::command]
-async fn send_chat(
+async fn get_whiteboard_session(
     state: tauri::State<'_, VisioState>,
-    text: String,
 ) -> Result<serde_json::Value, String> {
-    let chat = state.chat.lock().await;
-    let msg = chat.send_message(&text).await.map_err(|e| e.to_string())?;
+    let room = state.room.lock().await;
+    let session = room.whiteboard_session().await.map_err(|e| e.to_string())?;
     Ok(serde_json::json!({
-        "id": msg.id,
-        "sender_sid": msg.sender_sid,
-        "sender_name": msg.sender_name,
-        "text": msg.text,
-        "timestamp_ms": msg.timestamp_ms,
+        "url": session.url,
+        "token": session.token,
     }))
 }
 
+/// Localized invitation to the current room, to copy to the clipboard.
 #[tauri::command]
-async fn get_messages(
+async fn get_invite_text(
     state: tauri::State<'_, VisioState>,
+    language: String,
+    starts_at_ms: Option<i64>,
+    utc_offset_minutes: i32,
+) -> Result<String, String> {
+    let room = state.room.lock().await;
+    let info = room.invite_info().await.map_err(|e| e.to_string())?;
+    Ok(visio_core::build_invite_text(
+        &info,
+        &language,
+        starts_at_ms,
+        utc_offset_minutes,
+    ))
+}
+
+/// Tile rectangles for the call screen; `mode` is "grid", "speaker" or
+/// "sidebar".
+#[tauri::command]
+fn compute_layout(
+    mode: String,
+    width: f32,
+    height: f32,
+    participant_sids: Vec<String>,
+    spotlight_sid: Option<String>,
+    tile_aspect_ratio: Option<f32>,
+    gap: Option<f32>,
 ) -> Result<Vec<serde_json::Value>, String> {
-    let chat = state.chat.lock().await;
-    let messages = chat.messages().await;
-    let result: Vec<serde_json::Value> = messages
+    let mode = match mode.as_str() {
+        "grid" => LayoutMode::Grid,
+        "speaker" => LayoutMode::Speaker,
+        "sidebar" => LayoutMode::Sidebar,
+        other => return Err(format!("unknown layout mode: {other}")),
+    };
+    let defaults = LayoutOptions::default();
+    let options = LayoutOptions {
+        mode,
+        width,
+        height,
+        participant_sids,
+        spotlight_sid,
+        tile_aspect_ratio: tile_aspect_ratio.unwrap_or(defaults.tile_aspect_ratio),
+        gap: gap.unwrap_or(defaults.gap),
+    };
+    Ok(visio_core::compute_layout(&options)
         .into_iter()
-        .map(|m| {
+        .map(|t| {
             serde_json::json!({
-                "id": m.id,
-                "sender_sid": m.sender_sid,
-                "sender_name": m.sender_name,
-                "text": m.text,
-                "timestamp_ms": m.timestamp_ms,
+                "participantSid": t.participant_sid,
+                "x": t.x,
+                "y": t.y,
+                "width": t.width,
+                "height": t.height,
             })
         })
-        .collect();
-    Ok(result)
+        .collect())
 }
 
+/// PNG image of a QR code for the room link, delivered as raw bytes.
 #[tauri::command]
-fn get_translations(
-    app: AppHandle,
-    lang: String,
-) -> Result<serde_json::Value, String> {
-    let supported = ["en", "fr", "de", "es", "it", "nl"];
-    let lang = if supported.contains(&lang.as_str()) {
-        lang
-    } else {
-        "en".to_string()
-    };
-
-    let resource_path = app
-        .path()
-        .resource_dir()
-        .map_err(|e| format!("resource dir: {e}"))?
-        .join("i18n")
-        .join(format!("{lang}.json"));
-
-    let content = std::fs::read_to_string(&resource_path).map_err(|e| {
-        tracing::warn!("failed to load i18n/{lang}.json from {resource_path:?}: {e}");
-        format!("i18n file not found: {lang}.json")
-    })?;
+fn generate_room_qr(url: String, size: u32) -> Result<tauri::ipc::Response, String> {
+    visio_core::generate_room_qr(&url, size)
+        .map(tauri::ipc::Response::new)
+        .map_err(|e| e.to_string())
+}
 
-    serde_json::from_str(&content)
-        .map_err(|e| format!("invalid i18n JSON: {e}"))
+/// PNG placeholder for a participant without video (initials on their
+/// color), delivered as raw bytes.
+#[tauri::command]
+fn generate_avatar(name: String, size: u32) -> Result<tauri::ipc::Response, String> {
+    visio_core::generate_avatar(&name, size)
+        .map(tauri::ipc::Response::new)
+        .map_err(|e| e.to_string())
 }
 
 #[tauri::command]
-fn get_system_language() -> String {
-    sys_locale::get_locale()
-        .and_then(|l| l.split(['-', '_']).next().map(String::from))
-        .unwrap_or_else(|| "en".to_string())
+async fn invite_participants(
+    state: tauri::State<'_, VisioState>,
+    emails: Vec<String>,
+) -> Result<(), String> {
+    let room = state.room.lock().await;
+    room.invite_participants(emails)
+        .await
+        .map_err(|e| e.to_string())
 }
 
 #[tauri::command]
-fn get_settings(state: tauri::State<'_, VisioState>) -> Result<serde_json::Value, String> {
-    let s = state.settings.get();
-    Ok(serde_json::json!({
-        "display_name": s.display_name,
-        "language": s.language,
-        "mic_enabled_on_join": s.mic_enabled_on_join,
-        "camera_enabled_on_join": s.camera_enabled_on_join,
-        "theme": s.theme,
-    }))
+async fn lock_room(state: tauri::State<'_, VisioState>, locked: bool) -> Result<(), String> {
+    let room = state.room.lock().await;
+    room.moderation()
+        .lock_room(locked)
+        .await
+        .map_err(|e| e.to_string())
 }
 
 #[tauri::command]
-fn set_display_name(
-    app: AppHandle,
-    state: tauri::State<'_, VisioState>,
-    name: Option<String>,
-) -> Result<(), String> {
-    if let Some(ref n) = name {
-        let trimmed = n.trim();
-        if trimmed.is_empty() || trimmed.len() > 100 {
-            return Err("display name must be 1-100 characters".into());
-        }
-    }
-    state.settings.set_display_name(name.clone());
-    let _ = app.emit("settings-changed", serde_json::json!({"display_name": name}));
-    Ok(())
+async fn is_room_locked(state: tauri::State<'_, VisioState>) -> Result<bool, String> {
+    let room = state.room.lock().await;
+    Ok(room.moderation().is_room_locked().await)
 }
 
+/// `mode` is "screen_recording" or "transcript".
 #[tauri::command]
-fn set_language(
-    app: AppHandle,
-    state: tauri::State<'_, VisioState>,
-    lang: Option<String>,
-) -> Result<(), String> {
-    let supported = ["en", "fr", "de", "es", "it", "nl"];
-    if let Some(ref l) = lang {
-        if !supported.contains(&l.as_str()) {
-            return Err(format!("unsupported language: {l}"));
-        }
-    }
-    state.settings.set_language(lang.clone());
-    let _ = app.emit("settings-changed", serde_json::json!({"language": lang}));
-    Ok(())
+async fn start_recording(state: tauri::State<'_, VisioState>, mode: String) -> Result<(), String> {
+    let mode = match mode.as_str() {
+        "screen_recording" => RecordingMode::ScreenRecording,
+        "transcript" => RecordingMode::Transcript,
+        other => return Err(format!("unknown recording mode: {other}")),
+    };
+    let room = state.room.lock().await;
+    room.recording()
+        .start_recording(mode)
+        .await
+        .map_err(|e| e.to_string())
 }
 
 #[tauri::command]
-fn set_mic_enabled_on_join(
-    app: AppHandle,
-    state: tauri::State<'_, VisioState>,
-    enabled: bool,
-) {
-    state.settings.set_mic_enabled_on_join(enabled);
-    let _ = app.emit("settings-changed", serde_json::json!({"mic_enabled_on_join": enabled}));
+async fn stop_recording(state: tauri::State<'_, VisioState>) -> Result<(), String> {
+    let room = state.room.lock().await;
+    room.recording()
+        .stop_recording()
+        .await
+        .map_err(|e| e.to_string())
 }
 
 #[tauri::command]
-fn set_camera_enabled_on_join(
-    app: AppHandle,
-    state: tauri::State<'_, VisioState>,
-    enabled: bool,
-) {
-    state.settings.set_camera_enabled_on_join(enabled);
-    let _ = app.emit("settings-changed", serde_json::json!({"camera_enabled_on_join": enabled}));
+async fn get_recording_status(state: tauri::State<'_, VisioState>) -> Result<String, String> {
+    let room = state.room.lock().await;
+    Ok(recording_status_to_str(room.recording().status()).to_string())
 }
 
+/// Record the call to `path`, by default a timestamped file in the data
+/// directory. `format` is "wav" (the default), "ogg", "mp4" or "webm";
+/// all but WAV need ffmpeg, and MP4 and WebM include the active speaker's
+/// video. Returns the path of the file.
 #[tauri::command]
-fn set_theme(
-    app: AppHandle,
+async fn start_local_recording(
     state: tauri::State<'_, VisioState>,
-    theme: String,
-) -> Result<(), String> {
-    let valid = ["light", "dark", "system"];
-    if !valid.contains(&theme.as_str()) {
-        return Err(format!("invalid theme: {theme}"));
-    }
-    state.settings.set_theme(theme.clone());
-    let _ = app.emit("settings-changed", serde_json::json!({"theme": theme}));
-    Ok(())
+    path: Option<String>,
+    format: Option<String>,
+    max_size_bytes: Option<u64>,
+) -> Result<String, String> {
+    let format = match format.as_deref().unwrap_or("wav") {
+        "wav" => LocalRecordingFormat::Wav,
+        "ogg" => LocalRecordingFormat::Ogg,
+        "mp4" => LocalRecordingFormat::Mp4,
+        "webm" => LocalRecordingFormat::WebM,
+        other => return Err(format!("unknown recording format: {other}")),
+    };
+    let path = path.unwrap_or_else(|| {
+        let secs = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+        data_paths()
+            .recordings_dir()
+            .join(format!("visio-{secs}.{}", format.extension()))
+            .to_string_lossy()
+            .into_owned()
+    });
+    let room = state.room.lock().await;
+    let options = LocalRecordingOptions {
+        max_size_bytes,
+        format,
+        ..Default::default()
+    };
+    room.start_local_recording(&path, options)
+        .map_err(|e| e.to_string())?;
+    Ok(path)
 }
 
 #[tauri::command]
-fn get_meet_instances(state: tauri::State<'_, VisioState>) -> Result<Vec<String>, String> {
-    Ok(state.settings.get_meet_instances())
+async fn stop_local_recording(state: tauri::State<'_, VisioState>) -> Result<u64, String> {
+    let room = state.room.lock().await;
+    room.stop_local_recording().map_err(|e| e.to_string())
 }
 
+/// Restream the meeting to an RTMP, RTSP or SRT `url` through ffmpeg,
+/// which must be installed. Progress follows as
+/// `restream-status-changed` events.
 #[tauri::command]
-fn set_meet_instances(state: tauri::State<'_, VisioState>, instances: Vec<String>) {
-    state.settings.set_meet_instances(instances);
+async fn start_restream(
+    state: tauri::State<'_, VisioState>,
+    url: String,
+    video_bitrate_kbps: Option<u32>,
+    audio_bitrate_kbps: Option<u32>,
+    fps: Option<u32>,
+) -> Result<(), String> {
+    let defaults = RestreamOptions::default();
+    let options = RestreamOptions {
+        video_bitrate_kbps: video_bitrate_kbps.unwrap_or(defaults.video_bitrate_kbps),
+        audio_bitrate_kbps: audio_bitrate_kbps.unwrap_or(defaults.audio_bitrate_kbps),
+        fps: fps.unwrap_or(defaults.fps),
+        ..defaults
+    };
+    let room = state.room.lock().await;
+    room.start_restream(&url, options)
+        .map_err(|e| e.to_string())
 }
 
 #[tauri::command]
-async fn raise_hand(state: tauri::State<'_, VisioState>) -> Result<(), String> {
-    tracing::info!("Tauri command: raise_hand");
+async fn stop_restream(state: tauri::State<'_, VisioState>) -> Result<(), String> {
     let room = state.room.lock().await;
-    room.raise_hand().await.map_err(|e| {
-        tracing::error!("raise_hand command failed: {e}");
-        e.to_string()
-    })
+    room.stop_restream().map_err(|e| e.to_string())
 }
 
 #[tauri::command]
-async fn lower_hand(state: tauri::State<'_, VisioState>) -> Result<(), String> {
-    tracing::info!("Tauri command: lower_hand");
+async fn set_restream_bitrate(
+    state: tauri::State<'_, VisioState>,
+    video_bitrate_kbps: u32,
+) -> Result<(), String> {
     let room = state.room.lock().await;
-    room.lower_hand().await.map_err(|e| {
-        tracing::error!("lower_hand command failed: {e}");
-        e.to_string()
-    })
+    room.set_restream_bitrate(video_bitrate_kbps)
+        .map_err(|e| e.to_string())
 }
 
 #[tauri::command]
-async fn is_hand_raised(state: tauri::State<'_, VisioState>) -> Result<bool, String> {
+async fn get_restream_status(
+    state: tauri::State<'_, VisioState>,
+) -> Result<serde_json::Value, String> {
     let room = state.room.lock().await;
-    Ok(room.is_hand_raised().await)
+    Ok(restream_status_to_json(&room.restream_status()))
 }
 
+/// Expose the room audio on a loopback output device and the video of
+/// `track_sid` (by default the first remote video) on a v4l2loopback
+/// device, for OBS or another app. Devices are picked automatically unless
+/// named. Returns the devices in use.
 #[tauri::command]
-async fn set_chat_open(state: tauri::State<'_, VisioState>, open: bool) -> Result<(), String> {
-    let chat = state.chat.lock().await;
-    chat.set_chat_open(open);
-    Ok(())
+async fn start_virtual_devices(
+    state: tauri::State<'_, VisioState>,
+    audio_device: Option<String>,
+    video_device: Option<String>,
+    track_sid: Option<String>,
+) -> Result<serde_json::Value, String> {
+    // Release the devices before opening them again.
+    state
+        .virtual_devices
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .take();
+
+    let room = state.room.lock().await;
+    let track_sid = match track_sid {
+        Some(sid) => Some(sid),
+        None => room.video_track_sids().await.into_iter().next(),
+    };
+    let track = match &track_sid {
+        Some(sid) => room.get_video_track(sid).await,
+        None => None,
+    };
+    let devices = virtual_devices::VirtualDevices::start(
+        room.playout_buffer(),
+        audio_device.as_deref(),
+        track,
+        video_device.as_deref(),
+    )?;
+    let result = serde_json::json!({
+        "audioDevice": devices.audio_device(),
+        "videoDevice": devices.video_device(),
+    });
+    *state
+        .virtual_devices
+        .lock()
+        .unwrap_or_else(|e| e.into_inner()) = Some(devices);
+    Ok(result)
 }
 
 #[tauri::command]
-async fn send_reaction(state: tauri::State<'_, VisioState>, emoji: String) -> Result<(), String> {
-    let room = state.room.lock().await;
-    room.send_reaction(&emoji).await.map_err(|e| e.to_string())
+fn stop_virtual_devices(state: tauri::State<'_, VisioState>) {
+    state
+        .virtual_devices
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .take();
 }
 
 #[tauri::command]
-fn set_background_mode(
-    state: tauri::State<'_, VisioState>,
-    app: AppHandle,
-    mode: String,
-) -> Result<(), String> {
+fn set_background_mode(state: tauri::State<'_, VisioState>, mode: String) -> Result<(), String> {
     // Validate mode
     if mode != "off" && mode != "blur" && !mode.starts_with("image:") {
         return Err("Invalid background mode".into());
@@ -661,7 +2792,6 @@ fn set_background_mode(
     visio_ffi::blur::BlurProcessor::set_mode(bg_mode);
     // Persist
     state.settings.set_background_mode(mode);
-    let _ = app.emit("settings-changed", ());
     Ok(())
 }
 
@@ -681,32 +2811,100 @@ fn load_background_image(id: u8, jpeg_path: String) -> Result<(), String> {
     visio_ffi::blur::BlurProcessor::load_replacement_image(id, &jpeg_bytes, 640, 480)
 }
 
+/// Text drawn on the outgoing video; `corner` is "top_left", "top_right",
+/// "bottom_left" or "bottom_right". Blank text removes it.
+#[tauri::command]
+fn set_video_overlay(
+    state: tauri::State<'_, VisioState>,
+    text: Option<String>,
+    corner: String,
+) -> Result<(), String> {
+    let corner = match corner.as_str() {
+        "top_left" => OverlayCorner::TopLeft,
+        "top_right" => OverlayCorner::TopRight,
+        "bottom_left" => OverlayCorner::BottomLeft,
+        "bottom_right" => OverlayCorner::BottomRight,
+        other => return Err(format!("unknown overlay corner: {other}")),
+    };
+    state.settings.set_video_overlay(text, corner);
+    let s = state.settings.get();
+    visio_core::video_overlay().set_text(s.video_overlay_text, s.video_overlay_corner);
+    Ok(())
+}
+
+/// Branding image drawn on the outgoing video, from a PNG file; `None`
+/// removes it.
+#[tauri::command]
+fn set_video_overlay_image(png_path: Option<String>) -> Result<(), String> {
+    let png = png_path
+        .map(|path| std::fs::read(&path).map_err(|e| e.to_string()))
+        .transpose()?;
+    visio_core::video_overlay()
+        .set_image_png(png.as_deref())
+        .map_err(|e| e.to_string())
+}
+
 // ---------------------------------------------------------------------------
 // Entry point
 // ---------------------------------------------------------------------------
 
-pub fn run() {
+pub fn run(options: RunOptions) {
     tracing_subscriber::fmt()
         .with_env_filter(
             tracing_subscriber::EnvFilter::try_from_default_env().unwrap_or_else(|_| {
-                "visio_core=info,visio_video=info,visio_desktop=info".parse().unwrap()
+                "visio_core=info,visio_video=info,visio_desktop=info"
+                    .parse()
+                    .unwrap()
             }),
         )
         .init();
 
-    let data_dir = dirs::data_dir()
-        .unwrap_or_else(|| std::path::PathBuf::from("."))
-        .join("io.visio.desktop");
-    std::fs::create_dir_all(&data_dir).ok();
-    let settings = SettingsStore::new(data_dir.to_str().unwrap());
+    let paths = DATA_PATHS.get_or_init(|| {
+        visio_core::DataPaths::new(options.data_dir.clone().unwrap_or_else(default_data_dir))
+    });
+    if options.data_dir.is_some()
+        && let Err(e) = paths.migrate_from(&visio_core::DataPaths::new(default_data_dir()))
+    {
+        tracing::warn!("data migration failed: {e}");
+    }
+    if let Err(e) = paths.create_dirs() {
+        tracing::warn!(
+            "cannot create data directory {}: {e}",
+            paths.root().display()
+        );
+    }
+    tracing::info!("data directory: {}", paths.root().display());
+    let settings = SettingsStore::new(&paths.root().to_string_lossy());
 
     let room_manager = RoomManager::new();
+    room_manager.set_hand_auto_lower(settings.get().hand_auto_lower());
+    room_manager.set_idle_config(settings.get().idle());
+    room_manager.set_quality_policy_config(settings.get().quality_policy());
+    room_manager.metrics().apply_settings(&settings.get());
+    room_manager.feedback().apply_settings(&settings.get());
+    room_manager
+        .system_messages()
+        .apply_settings(&settings.get());
+    room_manager.set_rtc_config(settings.get().rtc_config);
+    room_manager
+        .journal()
+        .set_file(Some(paths.logs_dir().join("journal.jsonl")));
+    visio_core::audio_ducking::audio_ducker().set_config(settings.get().audio_ducking());
+    visio_core::auto_gain::auto_gain().set_config(settings.get().auto_gain());
+    visio_core::video_overlay().set_text(
+        settings.get().video_overlay_text,
+        settings.get().video_overlay_corner,
+    );
+    visio_core::video_privacy().set_style(settings.get().video_privacy_style);
+    room_manager.set_audio_preset(settings.get().audio_preset);
     let playout_buffer = room_manager.playout_buffer();
     let controls = room_manager.controls();
     let chat = room_manager.chat();
 
-    let audio_playout = audio_cpal::CpalAudioPlayout::start(playout_buffer)
-        .expect("failed to start audio playout");
+    let preferred_speaker = settings.get().preferred_speaker_id;
+    let audio_playout =
+        audio_cpal::CpalAudioPlayout::start(playout_buffer, preferred_speaker.as_deref())
+            .expect("failed to start audio playout");
 
     let room_arc = Arc::new(Mutex::new(room_manager));
 
@@ -715,6 +2913,7 @@ pub fn run() {
         let listener = Arc::new(DesktopEventListener {
             room: room_arc.clone(),
         });
+        settings.add_listener(listener.clone());
         // We need to add the listener while we can still access room_manager
         // But room_manager is now behind Arc<Mutex>. We'll do it via block_on.
         let rt = tokio::runtime::Runtime::new().unwrap();
@@ -735,11 +2934,24 @@ pub fn run() {
         camera_capture: std::sync::Mutex::new(None),
         _audio_playout: audio_playout,
         audio_capture: std::sync::Mutex::new(None),
+        screen_audio_capture: std::sync::Mutex::new(None),
+        virtual_devices: std::sync::Mutex::new(None),
     };
 
     tauri::Builder::default()
+        // One client per user: a second launch (e.g. opening a join link)
+        // hands its deep link to the running instance — delivered through
+        // the deep-link plugin — and exits instead of opening the audio
+        // devices a second time. Must be the first plugin registered.
+        .plugin(tauri_plugin_single_instance::init(|app, argv, _cwd| {
+            tracing::info!("second instance started with {argv:?}, raising this one");
+            tray::show_main_window(app);
+        }))
         .plugin(tauri_plugin_deep_link::init())
+        .plugin(tauri_plugin_global_shortcut::Builder::new().build())
+        .plugin(tauri_plugin_notification::init())
         .manage(state)
+        .manage(notifications::Notifier::new())
         .setup(|app| {
             // Store handle globally for the C video callback
             let _ = APP_HANDLE.set(app.handle().clone());
@@ -759,23 +2971,41 @@ pub fn run() {
                 tracing::info!("Deep link received (Rust): {:?}", event.payload());
             });
 
+            tray::init(app.handle())?;
+
             Ok(())
         })
         .on_window_event(|window, event| {
+            if let tauri::WindowEvent::Focused(focused) = event {
+                notifications::focus_changed(window.app_handle(), *focused);
+            }
             if let tauri::WindowEvent::CloseRequested { .. } = event {
                 tracing::info!("window close requested, disconnecting gracefully");
                 let state: tauri::State<'_, VisioState> = window.state();
                 let room = state.room.clone();
                 // Stop audio/camera capture before disconnect
                 {
-                    let mut cap = state.audio_capture.lock().unwrap_or_else(|e| e.into_inner());
+                    let mut cap = state
+                        .audio_capture
+                        .lock()
+                        .unwrap_or_else(|e| e.into_inner());
                     if let Some(capture) = cap.take() {
                         capture.stop();
                     }
+                    let mut screen = state
+                        .screen_audio_capture
+                        .lock()
+                        .unwrap_or_else(|e| e.into_inner());
+                    if let Some(capture) = screen.take() {
+                        capture.stop();
+                    }
                 }
                 #[cfg(target_os = "macos")]
                 {
-                    let mut cam = state.camera_capture.lock().unwrap_or_else(|e| e.into_inner());
+                    let mut cam = state
+                        .camera_capture
+                        .lock()
+                        .unwrap_or_else(|e| e.into_inner());
                     if let Some(mut capture) = cam.take() {
                         capture.stop();
                     }
@@ -791,35 +3021,155 @@ pub fn run() {
         .invoke_handler(tauri::generate_handler![
             validate_room,
             connect,
+            switch_room,
             disconnect,
             get_connection_state,
+            get_local_permissions,
+            get_feature_flags,
             get_participants,
             get_local_participant,
             get_video_tracks,
             toggle_mic,
             toggle_camera,
+            start_screen_share,
+            stop_screen_share,
+            set_video_privacy,
+            set_video_privacy_style,
             send_chat,
             get_messages,
+            get_message_body,
+            set_chat_formatting,
+            export_chat,
+            save_chat_export,
             get_translations,
             get_system_language,
+            translate,
             get_settings,
             set_display_name,
             set_language,
             set_mic_enabled_on_join,
             set_camera_enabled_on_join,
             set_theme,
+            set_hand_auto_lower,
+            set_audio_ducking,
+            set_auto_gain,
+            set_idle_policy,
+            set_quality_policy,
+            run_device_check,
+            report_activity,
+            set_metrics,
+            set_feedback_diagnostics_enabled,
+            set_rtc_config,
+            get_extra,
+            set_extra,
+            list_audio_devices,
+            set_preferred_microphone_id,
+            set_preferred_camera_id,
+            set_preferred_speaker_id,
             get_meet_instances,
+            parse_deep_link,
             set_meet_instances,
+            get_dominant_speaker,
+            set_visible_tracks,
+            get_renderer_stats,
+            save_snapshot,
+            get_av_sync_stats,
+            set_video_content_hint,
+            set_video_codec,
+            get_negotiated_codecs,
+            get_quality_history,
+            get_video_stats,
+            get_last_call_summary,
+            submit_feedback,
+            export_journal,
+            get_capture_constraints,
+            set_low_light_boost,
+            get_audio_publish_options,
+            set_audio_bitrate,
+            set_low_bandwidth_audio,
+            set_audio_preset,
+            set_power_mode,
+            set_output_volume,
+            set_output_muted,
+            dispatch_action,
+            handle_media_button,
+            get_time_remaining,
+            register_shortcuts,
+            get_power_mode,
+            report_thermal_state,
+            set_permission_state,
+            get_media_state,
+            get_layout_order,
+            pin_participant,
+            unpin_participant,
             raise_hand,
             lower_hand,
             is_hand_raised,
+            set_presence,
+            set_local_attributes,
+            get_participant_attributes,
             set_chat_open,
+            get_chat_unread,
+            mark_chat_read_up_to,
             send_reaction,
+            publish_data,
+            subscribe_data_topic,
+            unsubscribe_data_topic,
+            create_poll,
+            vote_poll,
+            close_poll,
+            get_polls,
+            get_invite_info,
+            get_whiteboard_session,
+            get_invite_text,
+            generate_room_qr,
+            generate_avatar,
+            compute_layout,
+            invite_participants,
+            lock_room,
+            is_room_locked,
+            start_recording,
+            stop_recording,
+            get_recording_status,
+            start_local_recording,
+            stop_local_recording,
+            start_virtual_devices,
+            stop_virtual_devices,
+            start_restream,
+            stop_restream,
+            set_restream_bitrate,
+            get_restream_status,
             set_background_mode,
             get_background_mode,
             load_blur_model,
             load_background_image,
+            set_video_overlay,
+            set_video_overlay_image,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str], env_dir: Option<&str>) -> RunOptions {
+        RunOptions::parse(args.iter().map(|a| a.to_string()), env_dir.map(Into::into))
+    }
+
+    #[test]
+    fn run_options_choose_the_data_dir() {
+        assert_eq!(parse(&[], None), RunOptions::default());
+        assert_eq!(
+            parse(&["visio://join/abc"], Some("/env")).data_dir,
+            Some("/env".into())
+        );
+        assert_eq!(
+            parse(&["--data-dir", "/stick/data"], Some("/env")).data_dir,
+            Some("/stick/data".into())
+        );
+        let portable = parse(&["--portable"], Some("/env")).data_dir.unwrap();
+        assert!(portable.ends_with("visio-data"));
+    }
+}
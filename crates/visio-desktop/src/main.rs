@@ -1,5 +1,5 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 fn main() {
-    visio_desktop::run();
+    visio_desktop::run(visio_desktop::RunOptions::from_env());
 }
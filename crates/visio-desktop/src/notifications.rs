@@ -0,0 +1,196 @@
+//! Native notifications while the window is in the background.
+//!
+//! When the main window is not focused, chat messages, raised hands and
+//! participants joining post an OS notification, each kind behind its
+//! `notification_*` setting. Desktop notification APIs do not report
+//! clicks, but clicking a notification brings the app forward: the window
+//! regaining focus shortly after a notification counts as a click on it,
+//! and a chat notification then asks the frontend to open the chat panel
+//! (`notification-opened`).
+
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+use tauri::{AppHandle, Emitter, Manager};
+use tauri_plugin_notification::NotificationExt;
+use visio_core::{Settings, VisioEvent};
+
+use crate::VisioState;
+
+/// How long after a notification regaining focus counts as a click on it.
+const CLICK_THROUGH: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NotificationKind {
+    Chat,
+    HandRaised,
+    ParticipantJoined,
+}
+
+impl NotificationKind {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Chat => "chat",
+            Self::HandRaised => "handRaised",
+            Self::ParticipantJoined => "participantJoined",
+        }
+    }
+
+    fn enabled(self, settings: &Settings) -> bool {
+        match self {
+            Self::Chat => settings.notification_message_received,
+            Self::HandRaised => settings.notification_hand_raised,
+            Self::ParticipantJoined => settings.notification_participant_join,
+        }
+    }
+}
+
+/// Window focus and the last notification posted, managed as app state.
+pub(crate) struct Notifier {
+    focused: AtomicBool,
+    last: Mutex<Option<(NotificationKind, Instant)>>,
+}
+
+impl Notifier {
+    pub(crate) fn new() -> Self {
+        Self {
+            focused: AtomicBool::new(true),
+            last: Mutex::new(None),
+        }
+    }
+
+    fn record(&self, kind: NotificationKind, at: Instant) {
+        *self.last.lock().unwrap_or_else(|e| e.into_inner()) = Some((kind, at));
+    }
+
+    /// The notification a focus gain at `now` is a click on, if any.
+    fn take_clicked(&self, now: Instant) -> Option<NotificationKind> {
+        self.last
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .take()
+            .filter(|(_, at)| now.saturating_duration_since(*at) < CLICK_THROUGH)
+            .map(|(kind, _)| kind)
+    }
+}
+
+/// Track the main window focus. Call from `on_window_event`.
+pub(crate) fn focus_changed(app: &AppHandle, focused: bool) {
+    let Some(notifier) = app.try_state::<Notifier>() else {
+        return;
+    };
+    notifier.focused.store(focused, Ordering::Relaxed);
+    if focused && let Some(kind) = notifier.take_clicked(Instant::now()) {
+        let _ = app.emit(
+            "notification-opened",
+            serde_json::json!({ "kind": kind.as_str() }),
+        );
+    }
+}
+
+/// Post a notification for `event` if it is one we notify about.
+pub(crate) fn on_event(app: &AppHandle, event: &VisioEvent) {
+    match event {
+        VisioEvent::ChatMessageReceived(msg) => {
+            post(app, NotificationKind::Chat, &msg.sender_name, &msg.text);
+        }
+        VisioEvent::ParticipantJoined(info) => {
+            let name = info.name.as_deref().unwrap_or(&info.identity);
+            let body = tr(app, "notify.participantJoined", name);
+            post(app, NotificationKind::ParticipantJoined, &title(app), &body);
+        }
+        VisioEvent::HandRaisedChanged {
+            participant_sid,
+            raised: true,
+            ..
+        } => {
+            // Only the name is missing from the event: look it up, and skip
+            // our own hand.
+            let app = app.clone();
+            let sid = participant_sid.clone();
+            tauri::async_runtime::spawn(async move {
+                let state = app.state::<VisioState>();
+                let name = {
+                    let rm = state.room.lock().await;
+                    let local = rm.local_participant_info().await;
+                    if local.is_some_and(|p| p.sid == sid) {
+                        return;
+                    }
+                    rm.participants()
+                        .await
+                        .into_iter()
+                        .find(|p| p.sid == sid)
+                        .map(|p| p.name.unwrap_or(p.identity))
+                        .unwrap_or(sid)
+                };
+                let body = tr(&app, "notify.handRaised", &name);
+                post(&app, NotificationKind::HandRaised, &title(&app), &body);
+            });
+        }
+        _ => {}
+    }
+}
+
+fn post(app: &AppHandle, kind: NotificationKind, title: &str, body: &str) {
+    let (Some(notifier), Some(state)) =
+        (app.try_state::<Notifier>(), app.try_state::<VisioState>())
+    else {
+        return;
+    };
+    if notifier.focused.load(Ordering::Relaxed) || !kind.enabled(&state.settings.get()) {
+        return;
+    }
+    match app.notification().builder().title(title).body(body).show() {
+        Ok(()) => notifier.record(kind, Instant::now()),
+        Err(e) => tracing::warn!("{} notification failed: {e}", kind.as_str()),
+    }
+}
+
+fn language(app: &AppHandle) -> String {
+    app.state::<VisioState>()
+        .settings
+        .get()
+        .language
+        .unwrap_or_default()
+}
+
+fn title(app: &AppHandle) -> String {
+    visio_core::i18n::translate(&language(app), "app.title", &[])
+}
+
+fn tr(app: &AppHandle, key: &str, name: &str) -> String {
+    visio_core::i18n::translate(&language(app), key, &[("name", name)])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn focus_soon_after_a_notification_is_a_click() {
+        let notifier = Notifier::new();
+        let t0 = Instant::now();
+        assert_eq!(notifier.take_clicked(t0), None);
+
+        notifier.record(NotificationKind::ParticipantJoined, t0);
+        notifier.record(NotificationKind::Chat, t0);
+        assert_eq!(
+            notifier.take_clicked(t0 + Duration::from_secs(5)),
+            Some(NotificationKind::Chat)
+        );
+        // Taken once.
+        assert_eq!(notifier.take_clicked(t0 + Duration::from_secs(6)), None);
+
+        // Coming back much later is not a click.
+        notifier.record(NotificationKind::Chat, t0);
+        assert_eq!(notifier.take_clicked(t0 + CLICK_THROUGH), None);
+
+        let settings = Settings {
+            notification_hand_raised: false,
+            ..Settings::default()
+        };
+        assert!(NotificationKind::Chat.enabled(&settings));
+        assert!(!NotificationKind::HandRaised.enabled(&settings));
+    }
+}
@@ -0,0 +1,222 @@
+//! System tray icon: call status, microphone state and quick actions.
+//!
+//! The tooltip and status line follow the connection state, and the icon
+//! gets a red badge while the microphone is muted in a call. The menu
+//! mutes/unmutes, leaves the call and brings the window back. State is
+//! re-read from the core on the events that may change it (see
+//! `refresh`).
+
+use tauri::image::Image;
+use tauri::menu::{Menu, MenuItem, PredefinedMenuItem};
+use tauri::tray::TrayIconBuilder;
+use tauri::{AppHandle, Emitter, Manager, Wry};
+use visio_core::{ConnectionState, MeetingAction};
+
+use crate::VisioState;
+
+const TRAY_ID: &str = "visio";
+
+/// Menu items whose text follows the call state.
+struct TrayMenu {
+    status: MenuItem<Wry>,
+    mute: MenuItem<Wry>,
+    leave: MenuItem<Wry>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct TrayStatus {
+    connection: &'static str,
+    in_call: bool,
+    mic_enabled: bool,
+}
+
+fn tr(state: &VisioState, key: &str) -> String {
+    let language = state.settings.get().language.unwrap_or_default();
+    visio_core::i18n::translate(&language, key, &[])
+}
+
+/// Bring the main window to the front, restoring it if minimized or hidden.
+pub(crate) fn show_main_window(app: &AppHandle) {
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.unminimize();
+        let _ = window.show();
+        let _ = window.set_focus();
+    }
+}
+
+/// Create the tray icon. Call once from `setup`.
+pub(crate) fn init(app: &AppHandle) -> tauri::Result<()> {
+    let state = app.state::<VisioState>();
+    let status = MenuItem::with_id(
+        app,
+        "status",
+        tr(&state, "status.disconnected"),
+        false,
+        None::<&str>,
+    )?;
+    let mute = MenuItem::with_id(
+        app,
+        "toggle-mic",
+        tr(&state, "control.mute"),
+        false,
+        None::<&str>,
+    )?;
+    let leave = MenuItem::with_id(
+        app,
+        "leave",
+        tr(&state, "control.leave"),
+        false,
+        None::<&str>,
+    )?;
+    let show = MenuItem::with_id(app, "show", tr(&state, "tray.show"), true, None::<&str>)?;
+    let quit = MenuItem::with_id(app, "quit", tr(&state, "tray.quit"), true, None::<&str>)?;
+    let menu = Menu::with_items(
+        app,
+        &[
+            &status,
+            &PredefinedMenuItem::separator(app)?,
+            &mute,
+            &leave,
+            &PredefinedMenuItem::separator(app)?,
+            &show,
+            &quit,
+        ],
+    )?;
+
+    let mut builder = TrayIconBuilder::with_id(TRAY_ID)
+        .menu(&menu)
+        .tooltip(tr(&state, "app.title"))
+        .on_menu_event(|app, event| match event.id().as_ref() {
+            "toggle-mic" => {
+                let app = app.clone();
+                tauri::async_runtime::spawn(async move {
+                    let state = app.state::<VisioState>();
+                    if let Err(e) =
+                        crate::run_action(&app, &state, MeetingAction::ToggleMicrophone).await
+                    {
+                        tracing::warn!("tray mute toggle failed: {e}");
+                    }
+                });
+            }
+            // The frontend hangs up as its own leave button does.
+            "leave" => {
+                let _ = app.emit("tray-leave", ());
+            }
+            "show" => show_main_window(app),
+            "quit" => app.exit(0),
+            _ => {}
+        });
+    if let Some(icon) = app.default_window_icon() {
+        builder = builder.icon(icon.clone());
+    }
+    builder.build(app)?;
+    app.manage(TrayMenu {
+        status,
+        mute,
+        leave,
+    });
+    Ok(())
+}
+
+/// Re-read the connection and microphone state and update the tray.
+pub(crate) fn refresh(app: &AppHandle) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let state = app.state::<VisioState>();
+        let connection = state.room.lock().await.connection_state().await;
+        let mic_enabled = state.controls.lock().await.is_microphone_enabled().await;
+        let status = TrayStatus {
+            connection: match connection {
+                ConnectionState::Disconnected => "status.disconnected",
+                ConnectionState::Connecting => "status.connecting",
+                ConnectionState::Connected => "status.connected",
+                ConnectionState::Reconnecting { .. } => "status.reconnecting",
+            },
+            in_call: connection != ConnectionState::Disconnected,
+            mic_enabled,
+        };
+        if let Err(e) = apply(&app, &state, status) {
+            tracing::warn!("tray update failed: {e}");
+        }
+    });
+}
+
+fn apply(app: &AppHandle, state: &VisioState, status: TrayStatus) -> tauri::Result<()> {
+    let (Some(tray), Some(menu)) = (app.tray_by_id(TRAY_ID), app.try_state::<TrayMenu>()) else {
+        return Ok(());
+    };
+    let muted = status.in_call && !status.mic_enabled;
+
+    let mut tooltip = format!(
+        "{} – {}",
+        tr(state, "app.title"),
+        tr(state, status.connection)
+    );
+    if muted {
+        tooltip = format!("{tooltip} ({})", tr(state, "accessibility.muted"));
+    }
+    tray.set_tooltip(Some(tooltip))?;
+
+    menu.status.set_text(tr(state, status.connection))?;
+    let mute_key = if status.mic_enabled {
+        "control.mute"
+    } else {
+        "control.unmute"
+    };
+    menu.mute.set_text(tr(state, mute_key))?;
+    menu.mute.set_enabled(status.in_call)?;
+    menu.leave.set_enabled(status.in_call)?;
+
+    if let Some(icon) = app.default_window_icon() {
+        let icon = if muted {
+            Image::new_owned(
+                with_badge(icon.rgba(), icon.width(), icon.height()),
+                icon.width(),
+                icon.height(),
+            )
+        } else {
+            icon.clone()
+        };
+        tray.set_icon(Some(icon))?;
+    }
+    Ok(())
+}
+
+/// `rgba` (`width` x `height`) with a red dot in the bottom-right corner.
+fn with_badge(rgba: &[u8], width: u32, height: u32) -> Vec<u8> {
+    let mut out = rgba.to_vec();
+    let radius = (width.min(height) / 4).max(1) as i64;
+    let (cx, cy) = (width as i64 - radius - 1, height as i64 - radius - 1);
+    for y in (cy - radius).max(0)..=cy + radius {
+        for x in (cx - radius).max(0)..=cx + radius {
+            if (x - cx).pow(2) + (y - cy).pow(2) > radius.pow(2) {
+                continue;
+            }
+            let i = ((y * width as i64 + x) * 4) as usize;
+            if let Some(pixel) = out.get_mut(i..i + 4) {
+                pixel.copy_from_slice(&[0xE0, 0x1B, 0x24, 0xFF]);
+            }
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn badge_marks_the_bottom_right_corner() {
+        let (w, h) = (16u32, 16u32);
+        let icon = vec![0u8; (w * h * 4) as usize];
+        let badged = with_badge(&icon, w, h);
+        let pixel = |x: u32, y: u32| {
+            let i = ((y * w + x) * 4) as usize;
+            &badged[i..i + 4]
+        };
+        assert_eq!(pixel(11, 11), [0xE0, 0x1B, 0x24, 0xFF]);
+        assert_eq!(pixel(0, 0), [0, 0, 0, 0]);
+        assert_eq!(pixel(15, 15), [0, 0, 0, 0]);
+        assert_eq!(badged.len(), icon.len());
+    }
+}
@@ -0,0 +1,304 @@
+//! Virtual camera and microphone: the received room audio and video sent
+//! to loopback devices, so a meeting can be piped into OBS or another app.
+//!
+//! Audio is played on a loopback output device (BlackHole, VB-Audio Cable,
+//! a PulseAudio null sink...) which other apps record as a microphone.
+//! Video is written to a v4l2loopback device, so the virtual camera is
+//! Linux-only. Neither driver ships with the app: the user installs one.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex, TryLockError};
+
+use livekit::track::RemoteVideoTrack;
+use visio_core::{AudioPlayoutBuffer, TapId};
+
+use crate::audio_cpal;
+
+/// Keep at most 200 ms of audio queued for the loopback device; older
+/// samples are dropped if it consumes slower than the room plays.
+const AUDIO_QUEUE_MAX: usize = 48_000 / 5;
+
+/// Names of common loopback audio drivers, matched case-insensitively.
+const AUDIO_HINTS: &[&str] = &["blackhole", "cable input", "vb-audio", "loopback", "null"];
+
+/// Names v4l2loopback gives its devices by default or in common setups.
+#[cfg(target_os = "linux")]
+const CAMERA_HINTS: &[&str] = &["dummy video device", "loopback", "virtual"];
+
+/// Frame size written to the virtual camera; remote frames are scaled to it.
+#[cfg(target_os = "linux")]
+const CAMERA_WIDTH: usize = 1280;
+#[cfg(target_os = "linux")]
+const CAMERA_HEIGHT: usize = 720;
+
+/// The candidate to use among `(id, label)` pairs: the one whose id or
+/// label is `requested`, or else the first label matching one of `hints`.
+fn pick_device<'a>(
+    candidates: &'a [(String, String)],
+    requested: Option<&str>,
+    hints: &[&str],
+) -> Option<&'a str> {
+    let found = match requested {
+        Some(requested) => candidates
+            .iter()
+            .find(|(id, label)| id == requested || label == requested),
+        None => candidates.iter().find(|(_, label)| {
+            let label = label.to_lowercase();
+            hints.iter().any(|hint| label.contains(hint))
+        }),
+    };
+    found.map(|(id, _)| id.as_str())
+}
+
+// ---------------------------------------------------------------------------
+// Virtual microphone — room audio → loopback output device
+// ---------------------------------------------------------------------------
+
+struct VirtualMicrophone {
+    device: String,
+    playout: Arc<AudioPlayoutBuffer>,
+    tap: TapId,
+    _output: audio_cpal::CpalAudioPlayout,
+}
+
+impl VirtualMicrophone {
+    fn start(playout: Arc<AudioPlayoutBuffer>, requested: Option<&str>) -> Result<Self, String> {
+        let outputs: Vec<(String, String)> = audio_cpal::device_names()
+            .1
+            .into_iter()
+            .map(|name| (name.clone(), name))
+            .collect();
+        let device = pick_device(&outputs, requested, AUDIO_HINTS)
+            .ok_or("no loopback audio device found")?
+            .to_string();
+
+        // The tap sees what the speakers play, before the output volume.
+        let queue = Arc::new(Mutex::new(VecDeque::with_capacity(AUDIO_QUEUE_MAX)));
+        let sink = queue.clone();
+        let tap = playout.add_tap(Box::new(move |samples| {
+            // Never wait on the audio thread: drop the block while the
+            // reader holds the queue.
+            let mut queue = match sink.try_lock() {
+                Ok(queue) => queue,
+                Err(TryLockError::Poisoned(e)) => e.into_inner(),
+                Err(TryLockError::WouldBlock) => return,
+            };
+            queue.extend(samples.iter().copied());
+            let excess = queue.len().saturating_sub(AUDIO_QUEUE_MAX);
+            queue.drain(..excess);
+        }));
+        let output = audio_cpal::CpalAudioPlayout::start_with(&device, move |out| {
+            let mut queue = queue.lock().unwrap_or_else(|e| e.into_inner());
+            for sample in out.iter_mut() {
+                *sample = queue.pop_front().unwrap_or(0);
+            }
+        });
+        let output = match output {
+            Ok(output) => output,
+            Err(e) => {
+                playout.remove_tap(tap);
+                return Err(e);
+            }
+        };
+        tracing::info!("virtual microphone started on {device:?}");
+
+        Ok(Self {
+            device,
+            playout,
+            tap,
+            _output: output,
+        })
+    }
+}
+
+impl Drop for VirtualMicrophone {
+    fn drop(&mut self) {
+        self.playout.remove_tap(self.tap);
+        tracing::info!("virtual microphone stopped");
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Virtual camera — remote video track → v4l2loopback
+// ---------------------------------------------------------------------------
+
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+struct VirtualCamera {
+    device: String,
+    task: tokio::task::JoinHandle<()>,
+}
+
+impl VirtualCamera {
+    /// Write the frames of `track` to the v4l2loopback device `requested`
+    /// (a path such as `/dev/video10`, or a device name), or the first one
+    /// found. The device must accept YU12 1280x720, e.g. after
+    /// `v4l2loopback-ctl set-caps /dev/video10 YU12:1280x720`.
+    #[cfg(target_os = "linux")]
+    fn start(track: RemoteVideoTrack, requested: Option<&str>) -> Result<Self, String> {
+        use futures_util::StreamExt;
+        use livekit::webrtc::video_stream::native::NativeVideoStream;
+        use std::io::Write;
+        use visio_core::frame_convert::scale_i420;
+
+        let device = pick_device(&v4l2_devices(), requested, CAMERA_HINTS)
+            .ok_or("no v4l2loopback device found")?
+            .to_string();
+        let mut file = std::fs::OpenOptions::new()
+            .write(true)
+            .open(&device)
+            .map_err(|e| format!("cannot open {device}: {e}"))?;
+
+        let path = device.clone();
+        let task = tokio::spawn(async move {
+            let mut stream = NativeVideoStream::new(track.rtc_track());
+            let mut out = Vec::new();
+            while let Some(frame) = stream.next().await {
+                let i420 = frame.buffer.to_i420();
+                let (y, u, v) = i420.data();
+                let (sy, su, sv) = i420.strides();
+                scale_i420(
+                    [y, u, v],
+                    [sy as usize, su as usize, sv as usize],
+                    (i420.width() as usize, i420.height() as usize),
+                    (CAMERA_WIDTH, CAMERA_HEIGHT),
+                    &mut out,
+                );
+                if let Err(e) = file.write_all(&out) {
+                    tracing::warn!("virtual camera: cannot write to {path}: {e}");
+                    break;
+                }
+            }
+            tracing::info!("virtual camera stream ended");
+        });
+        tracing::info!("virtual camera started on {device}");
+
+        Ok(Self { device, task })
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn start(_track: RemoteVideoTrack, _requested: Option<&str>) -> Result<Self, String> {
+        Err("the virtual camera needs v4l2loopback and is only available on Linux".into())
+    }
+}
+
+impl Drop for VirtualCamera {
+    fn drop(&mut self) {
+        self.task.abort();
+        tracing::info!("virtual camera stopped");
+    }
+}
+
+/// `(path, name)` of the video4linux devices.
+#[cfg(target_os = "linux")]
+fn v4l2_devices() -> Vec<(String, String)> {
+    let Ok(dir) = std::fs::read_dir("/sys/class/video4linux") else {
+        return Vec::new();
+    };
+    let mut devices: Vec<(String, String)> = dir
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            let name = std::fs::read_to_string(entry.path().join("name")).ok()?;
+            let path = format!("/dev/{}", entry.file_name().to_string_lossy());
+            Some((path, name.trim().to_string()))
+        })
+        .collect();
+    devices.sort();
+    devices
+}
+
+// ---------------------------------------------------------------------------
+// Public API
+// ---------------------------------------------------------------------------
+
+/// The running virtual devices; dropping it stops them.
+pub struct VirtualDevices {
+    microphone: Option<VirtualMicrophone>,
+    camera: Option<VirtualCamera>,
+}
+
+impl VirtualDevices {
+    /// Start the virtual microphone, and the virtual camera when `track`
+    /// is given. Either may be unavailable; fails only if neither starts.
+    pub fn start(
+        playout: Arc<AudioPlayoutBuffer>,
+        audio_device: Option<&str>,
+        track: Option<RemoteVideoTrack>,
+        video_device: Option<&str>,
+    ) -> Result<Self, String> {
+        let microphone = VirtualMicrophone::start(playout, audio_device);
+        let camera = match track {
+            Some(track) => VirtualCamera::start(track, video_device),
+            None => Err("no remote video to send".into()),
+        };
+        match (microphone, camera) {
+            (Err(audio), Err(video)) => Err(format!("{audio}; {video}")),
+            (microphone, camera) => {
+                if let Err(e) = &microphone {
+                    tracing::warn!("virtual microphone unavailable: {e}");
+                }
+                if let Err(e) = &camera {
+                    tracing::warn!("virtual camera unavailable: {e}");
+                }
+                Ok(Self {
+                    microphone: microphone.ok(),
+                    camera: camera.ok(),
+                })
+            }
+        }
+    }
+
+    /// Output device the room audio is played on.
+    pub fn audio_device(&self) -> Option<&str> {
+        self.microphone.as_ref().map(|m| m.device.as_str())
+    }
+
+    /// Device the remote video is written to.
+    pub fn video_device(&self) -> Option<&str> {
+        self.camera.as_ref().map(|c| c.device.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn named(names: &[&str]) -> Vec<(String, String)> {
+        names
+            .iter()
+            .map(|n| (n.to_string(), n.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn pick_device_prefers_the_requested_one() {
+        let outputs = named(&[
+            "MacBook Speakers",
+            "BlackHole 2ch",
+            "CABLE Input (VB-Audio)",
+        ]);
+        assert_eq!(
+            pick_device(&outputs, None, AUDIO_HINTS),
+            Some("BlackHole 2ch")
+        );
+        assert_eq!(
+            pick_device(&outputs, Some("CABLE Input (VB-Audio)"), AUDIO_HINTS),
+            Some("CABLE Input (VB-Audio)")
+        );
+        assert_eq!(pick_device(&outputs, Some("Headset"), AUDIO_HINTS), None);
+        assert_eq!(pick_device(&named(&["Speakers"]), None, AUDIO_HINTS), None);
+
+        // Cameras are requested by path or by name.
+        let cameras = vec![(
+            "/dev/video10".to_string(),
+            "Dummy video device (0x0000)".to_string(),
+        )];
+        assert_eq!(
+            pick_device(&cameras, Some("/dev/video10"), &[]),
+            Some("/dev/video10")
+        );
+        assert_eq!(
+            pick_device(&cameras, Some("Dummy video device (0x0000)"), &[]),
+            Some("/dev/video10")
+        );
+    }
+}
@@ -91,11 +91,13 @@ impl BlurProcessor {
         // Check if cache is already valid
         {
             let cache = REPLACEMENT_CACHE.lock().ok()?;
-            if let Some(ref r) = *cache {
-                if r.id == id && r.width == frame_w && r.height == frame_h && r.rotation == rotation
-                {
-                    return Some(());
-                }
+            if let Some(ref r) = *cache
+                && r.id == id
+                && r.width == frame_w
+                && r.height == frame_h
+                && r.rotation == rotation
+            {
+                return Some(());
             }
         }
 
@@ -140,6 +142,7 @@ impl BlurProcessor {
     ///
     /// Returns `true` if the frame was modified, `false` if mode is Off or
     /// the model is not loaded.
+    #[allow(clippy::too_many_arguments)]
     pub fn process_i420(
         y: &mut [u8],
         u: &mut [u8],
@@ -2,19 +2,36 @@
 //!
 //! Provides a VisioClient object that wraps RoomManager, MeetingControls,
 //! and ChatService into a single FFI-safe interface.
+//!
+//! A client holds one or more rooms keyed by room id. The legacy
+//! single-room API operates on the default room (`DEFAULT_ROOM_ID`)
+//! unless a `room_id` is passed; `join()` opens additional rooms.
+
+// UniFFI errors cross the boundary by value, so `VisioError::Unreachable`
+// cannot box its diagnosis.
+#![allow(clippy::result_large_err)]
 
-use std::sync::{Arc, Mutex as StdMutex};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex as StdMutex, RwLock};
 use visio_core::{
     self,
     events::{
-        ChatMessage as CoreChatMessage, ConnectionQuality as CoreConnectionQuality,
+        ChatMessage as CoreChatMessage, ChatMessageKind as CoreChatMessageKind,
+        ConnectStep as CoreConnectStep, ConnectionQuality as CoreConnectionQuality,
         ConnectionState as CoreConnectionState, ParticipantInfo as CoreParticipantInfo,
         TrackInfo as CoreTrackInfo, TrackKind as CoreTrackKind, TrackSource as CoreTrackSource,
         VisioEvent as CoreVisioEvent,
     },
+    permissions::LocalPermissions as CoreLocalPermissions,
+    recording::{RecordingMode as CoreRecordingMode, RecordingStatus as CoreRecordingStatus},
+    restream::{RestreamState as CoreRestreamState, RestreamStatus as CoreRestreamStatus},
 };
 
 pub mod blur;
+mod logging;
+
+pub use logging::{set_file_logging, set_log_level, set_log_targets};
 
 uniffi::include_scaffolding!("visio");
 
@@ -34,15 +51,22 @@ pub extern "C" fn Java_io_visio_mobile_VisioApplication_nativeInitWebrtc(
     // Get JavaVM from JNIEnv
     let env = unsafe { jni::JNIEnv::from_raw(env as *mut jni::sys::JNIEnv) }
         .expect("nativeInitWebrtc: invalid JNIEnv");
-    let jvm = env.get_java_vm().expect("nativeInitWebrtc: failed to get JavaVM");
+    let jvm = env
+        .get_java_vm()
+        .expect("nativeInitWebrtc: failed to get JavaVM");
 
     libwebrtc::android::initialize_android(&jvm);
 
     // Prevent Drop from calling DestroyJavaVM
     std::mem::forget(jvm);
+    WEBRTC_INITIALIZED.store(true, Ordering::Release);
     visio_log("VISIO FFI: WebRTC initialized successfully");
 }
 
+/// Set once `nativeInitWebrtc` completed; checked by `self_test`.
+#[cfg(target_os = "android")]
+static WEBRTC_INITIALIZED: AtomicBool = AtomicBool::new(false);
+
 // ── Android logcat helper ────────────────────────────────────────────
 
 /// Write a message to logcat on Android, or stderr on other platforms.
@@ -51,10 +75,16 @@ fn visio_log(msg: &str) {
     {
         use std::ffi::CString;
         unsafe extern "C" {
-            fn __android_log_write(prio: i32, tag: *const std::ffi::c_char, text: *const std::ffi::c_char) -> i32;
+            fn __android_log_write(
+                prio: i32,
+                tag: *const std::ffi::c_char,
+                text: *const std::ffi::c_char,
+            ) -> i32;
         }
         let text = CString::new(msg).unwrap_or_else(|_| c"(invalid utf8)".into());
-        unsafe { __android_log_write(4 /* INFO */, c"VISIO_FFI".as_ptr(), text.as_ptr()); }
+        unsafe {
+            __android_log_write(4 /* INFO */, c"VISIO_FFI".as_ptr(), text.as_ptr());
+        }
     }
     #[cfg(target_os = "ios")]
     {
@@ -64,7 +94,9 @@ fn visio_log(msg: &str) {
             fn syslog(priority: i32, message: *const std::ffi::c_char, ...);
         }
         let text = CString::new(msg).unwrap_or_else(|_| c"(invalid utf8)".into());
-        unsafe { syslog(6 /* LOG_INFO */, text.as_ptr()); }
+        unsafe {
+            syslog(6 /* LOG_INFO */, text.as_ptr());
+        }
     }
     #[cfg(not(any(target_os = "android", target_os = "ios")))]
     eprintln!("{msg}");
@@ -73,19 +105,80 @@ fn visio_log(msg: &str) {
 // ── Namespace functions ──────────────────────────────────────────────
 
 /// Initialize tracing/logging. Call once from the host before using VisioClient.
-/// On Android, stderr goes to logcat for debuggable builds.
+/// On Android, logs go to logcat; the filter can be changed later with
+/// `set_log_level` / `set_log_targets`.
 fn init_logging() {
-    use std::sync::Once;
-    static INIT: Once = Once::new();
-    INIT.call_once(|| {
-        tracing_subscriber::fmt()
-            .with_env_filter(
-                tracing_subscriber::EnvFilter::try_from_default_env()
-                    .unwrap_or_else(|_| "visio_core=debug,visio_ffi=debug,visio_video=info".parse().unwrap()),
-            )
-            .with_ansi(false)
-            .init();
-    });
+    logging::init();
+}
+
+/// Structured intent of a `visio://` or Meet link, or `None` if the app
+/// does not handle it.
+fn parse_deep_link(uri: String) -> Option<DeepLink> {
+    visio_core::DeepLink::parse(&uri).map(DeepLink::from)
+}
+
+/// Localized plain-text invitation to copy or share. The meeting time is
+/// included when `starts_at_ms` is given, at `utc_offset_minutes` from UTC.
+fn build_invite_text(
+    info: InviteInfo,
+    language: String,
+    starts_at_ms: Option<i64>,
+    utc_offset_minutes: i32,
+) -> String {
+    visio_core::build_invite_text(&info.into(), &language, starts_at_ms, utc_offset_minutes)
+}
+
+/// Check the startup sequence before creating a `VisioClient`: WebRTC
+/// initialized (Android: `nativeInitWebrtc` called after `loadLibrary`),
+/// an async runtime can be created and `data_dir` is writable.
+fn self_test(data_dir: String) -> SelfTestReport {
+    #[cfg(target_os = "android")]
+    let webrtc = if WEBRTC_INITIALIZED.load(Ordering::Acquire) {
+        visio_core::CheckResult::Passed
+    } else {
+        visio_core::CheckResult::Failed("nativeInitWebrtc was not called".into())
+    };
+    // Other platforms initialize WebRTC on first use.
+    #[cfg(not(target_os = "android"))]
+    let webrtc = visio_core::CheckResult::Skipped;
+
+    let runtime = match tokio::runtime::Runtime::new() {
+        Ok(_) => visio_core::CheckResult::Passed,
+        Err(e) => visio_core::CheckResult::Failed(e.to_string()),
+    };
+    let data_dir = match visio_core::DataPaths::new(&data_dir).check_writable() {
+        Ok(()) => visio_core::CheckResult::Passed,
+        Err(e) => visio_core::CheckResult::Failed(format!("{data_dir}: {e}")),
+    };
+
+    let has_problem = webrtc.is_failed() || runtime.is_failed() || data_dir.is_failed();
+    let report = SelfTestReport {
+        webrtc: webrtc.into(),
+        runtime: runtime.into(),
+        data_dir: data_dir.into(),
+        has_problem,
+    };
+    visio_log(&format!("VISIO FFI: self test: {report:?}"));
+    report
+}
+
+/// PNG image of a QR code for the room link `url`, `size` pixels wide.
+fn generate_room_qr(url: String, size: u32) -> Result<Vec<u8>, VisioError> {
+    visio_core::generate_room_qr(&url, size).map_err(VisioError::from)
+}
+
+/// PNG placeholder of `name` for a participant without video: their
+/// initials on their color, `size` pixels wide.
+fn generate_avatar(name: String, size: u32) -> Result<Vec<u8>, VisioError> {
+    visio_core::generate_avatar(&name, size).map_err(VisioError::from)
+}
+
+/// Tile rectangles for the call screen (see `visio_core::tile_layout`).
+fn compute_layout(options: LayoutOptions) -> Vec<TileRect> {
+    visio_core::compute_layout(&options.into())
+        .into_iter()
+        .map(TileRect::from)
+        .collect()
 }
 
 // ── FFI-safe type conversions ──────────────────────────────────────────
@@ -109,6 +202,25 @@ impl From<CoreConnectionState> for ConnectionState {
     }
 }
 
+#[derive(Debug, Clone)]
+pub enum ConnectStep {
+    TokenRequested,
+    TokenReceived,
+    SignalConnected,
+    MediaConnected,
+}
+
+impl From<CoreConnectStep> for ConnectStep {
+    fn from(s: CoreConnectStep) -> Self {
+        match s {
+            CoreConnectStep::TokenRequested => Self::TokenRequested,
+            CoreConnectStep::TokenReceived => Self::TokenReceived,
+            CoreConnectStep::SignalConnected => Self::SignalConnected,
+            CoreConnectStep::MediaConnected => Self::MediaConnected,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum ConnectionQuality {
     Excellent,
@@ -148,6 +260,7 @@ pub enum TrackSource {
     Microphone,
     Camera,
     ScreenShare,
+    ScreenShareAudio,
     Unknown,
 }
 
@@ -157,6 +270,7 @@ impl From<CoreTrackSource> for TrackSource {
             CoreTrackSource::Microphone => Self::Microphone,
             CoreTrackSource::Camera => Self::Camera,
             CoreTrackSource::ScreenShare => Self::ScreenShare,
+            CoreTrackSource::ScreenShareAudio => Self::ScreenShareAudio,
             CoreTrackSource::Unknown => Self::Unknown,
         }
     }
@@ -171,6 +285,7 @@ pub struct ParticipantInfo {
     pub has_video: bool,
     pub video_track_sid: Option<String>,
     pub connection_quality: ConnectionQuality,
+    pub presence: Presence,
 }
 
 impl From<CoreParticipantInfo> for ParticipantInfo {
@@ -183,6 +298,202 @@ impl From<CoreParticipantInfo> for ParticipantInfo {
             has_video: p.has_video,
             video_track_sid: p.video_track_sid,
             connection_quality: p.connection_quality.into(),
+            presence: p.presence.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LocalPermissions {
+    pub access_level: Option<String>,
+    pub can_publish: bool,
+    pub can_subscribe: bool,
+    pub can_publish_data: bool,
+    pub can_publish_sources: Vec<String>,
+    pub can_update_metadata: bool,
+    pub is_admin: bool,
+    /// Per-source publish grants, to enable or disable the buttons.
+    pub can_publish_microphone: bool,
+    pub can_publish_camera: bool,
+    pub can_publish_screen_share: bool,
+}
+
+impl From<CoreLocalPermissions> for LocalPermissions {
+    fn from(p: CoreLocalPermissions) -> Self {
+        Self {
+            can_publish_microphone: p.can_publish_source(CoreTrackSource::Microphone),
+            can_publish_camera: p.can_publish_source(CoreTrackSource::Camera),
+            can_publish_screen_share: p.can_publish_source(CoreTrackSource::ScreenShare),
+            access_level: p.access_level,
+            can_publish: p.can_publish,
+            can_subscribe: p.can_subscribe,
+            can_publish_data: p.can_publish_data,
+            can_publish_sources: p.can_publish_sources,
+            can_update_metadata: p.can_update_metadata,
+            is_admin: p.is_admin,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FeatureFlags {
+    pub chat: bool,
+    pub reactions: bool,
+    pub recording: bool,
+    pub transcription: bool,
+    pub dial_in: bool,
+    pub subtitles: bool,
+    pub whiteboard: bool,
+    pub watermark: Option<String>,
+}
+
+impl From<visio_core::FeatureFlags> for FeatureFlags {
+    fn from(f: visio_core::FeatureFlags) -> Self {
+        Self {
+            chat: f.chat,
+            reactions: f.reactions,
+            recording: f.recording,
+            transcription: f.transcription,
+            dial_in: f.dial_in,
+            subtitles: f.subtitles,
+            whiteboard: f.whiteboard,
+            watermark: f.watermark,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum RecordingMode {
+    ScreenRecording,
+    Transcript,
+}
+
+impl From<RecordingMode> for CoreRecordingMode {
+    fn from(m: RecordingMode) -> Self {
+        match m {
+            RecordingMode::ScreenRecording => Self::ScreenRecording,
+            RecordingMode::Transcript => Self::Transcript,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum RecordingStatus {
+    Stopped,
+    Starting,
+    Recording,
+    Stopping,
+}
+
+impl From<CoreRecordingStatus> for RecordingStatus {
+    fn from(s: CoreRecordingStatus) -> Self {
+        match s {
+            CoreRecordingStatus::Stopped => Self::Stopped,
+            CoreRecordingStatus::Starting => Self::Starting,
+            CoreRecordingStatus::Recording => Self::Recording,
+            CoreRecordingStatus::Stopping => Self::Stopping,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LocalRecordingOptions {
+    pub max_size_bytes: Option<u64>,
+}
+
+// Mobile platforms have no ffmpeg: local recordings are WAV files.
+impl From<LocalRecordingOptions> for visio_core::LocalRecordingOptions {
+    fn from(o: LocalRecordingOptions) -> Self {
+        Self {
+            max_size_bytes: o.max_size_bytes,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum RestreamState {
+    Stopped,
+    Connecting,
+    Live,
+    Failed,
+}
+
+impl From<CoreRestreamState> for RestreamState {
+    fn from(s: CoreRestreamState) -> Self {
+        match s {
+            CoreRestreamState::Stopped => Self::Stopped,
+            CoreRestreamState::Connecting => Self::Connecting,
+            CoreRestreamState::Live => Self::Live,
+            CoreRestreamState::Failed => Self::Failed,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RestreamStatus {
+    pub state: RestreamState,
+    pub video_bitrate_kbps: u32,
+    pub error: Option<String>,
+}
+
+impl From<CoreRestreamStatus> for RestreamStatus {
+    fn from(s: CoreRestreamStatus) -> Self {
+        Self {
+            state: s.state.into(),
+            video_bitrate_kbps: s.video_bitrate_kbps,
+            error: s.error,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DialInInfo {
+    pub phone_number: String,
+    pub pin_code: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct InviteInfo {
+    pub url: String,
+    pub dial_in: Option<DialInInfo>,
+}
+
+impl From<visio_core::InviteInfo> for InviteInfo {
+    fn from(i: visio_core::InviteInfo) -> Self {
+        Self {
+            url: i.url,
+            dial_in: i.dial_in.map(|d| DialInInfo {
+                phone_number: d.phone_number,
+                pin_code: d.pin_code,
+            }),
+        }
+    }
+}
+
+impl From<InviteInfo> for visio_core::InviteInfo {
+    fn from(i: InviteInfo) -> Self {
+        Self {
+            url: i.url,
+            dial_in: i.dial_in.map(|d| visio_core::DialInInfo {
+                phone_number: d.phone_number,
+                pin_code: d.pin_code,
+            }),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct WhiteboardSession {
+    pub url: String,
+    pub token: Option<String>,
+}
+
+impl From<visio_core::WhiteboardSession> for WhiteboardSession {
+    fn from(s: visio_core::WhiteboardSession) -> Self {
+        Self {
+            url: s.url,
+            token: s.token,
         }
     }
 }
@@ -213,6 +524,7 @@ pub struct ChatMessage {
     pub sender_name: String,
     pub text: String,
     pub timestamp_ms: u64,
+    pub kind: ChatMessageKind,
 }
 
 impl From<CoreChatMessage> for ChatMessage {
@@ -223,6 +535,184 @@ impl From<CoreChatMessage> for ChatMessage {
             sender_name: m.sender_name,
             text: m.text,
             timestamp_ms: m.timestamp_ms,
+            kind: m.kind.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChatMessageKind {
+    Text,
+    ParticipantJoined,
+    ParticipantLeft,
+    RecordingStarted,
+    HandRaised,
+}
+
+impl From<CoreChatMessageKind> for ChatMessageKind {
+    fn from(k: CoreChatMessageKind) -> Self {
+        match k {
+            CoreChatMessageKind::Text => Self::Text,
+            CoreChatMessageKind::ParticipantJoined => Self::ParticipantJoined,
+            CoreChatMessageKind::ParticipantLeft => Self::ParticipantLeft,
+            CoreChatMessageKind::RecordingStarted => Self::RecordingStarted,
+            CoreChatMessageKind::HandRaised => Self::HandRaised,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChatUnread {
+    pub first_unread_id: Option<String>,
+    pub normal: u32,
+    pub mention: u32,
+    pub system: u32,
+}
+
+impl From<visio_core::ChatUnread> for ChatUnread {
+    fn from(u: visio_core::ChatUnread) -> Self {
+        Self {
+            first_unread_id: u.first_unread_id,
+            normal: u.normal,
+            mention: u.mention,
+            system: u.system,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChatExportFormat {
+    Txt,
+    Json,
+    Csv,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpanKind {
+    Text,
+    Bold,
+    Link,
+}
+
+impl From<visio_core::SpanKind> for SpanKind {
+    fn from(k: visio_core::SpanKind) -> Self {
+        match k {
+            visio_core::SpanKind::Text => Self::Text,
+            visio_core::SpanKind::Bold => Self::Bold,
+            visio_core::SpanKind::Link => Self::Link,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MessageSpan {
+    pub kind: SpanKind,
+    pub text: String,
+    pub url: Option<String>,
+}
+
+impl From<visio_core::MessageSpan> for MessageSpan {
+    fn from(s: visio_core::MessageSpan) -> Self {
+        Self {
+            kind: s.kind.into(),
+            text: s.text,
+            url: s.url,
+        }
+    }
+}
+
+impl From<ChatExportFormat> for visio_core::ChatExportFormat {
+    fn from(f: ChatExportFormat) -> Self {
+        match f {
+            ChatExportFormat::Txt => Self::Txt,
+            ChatExportFormat::Json => Self::Json,
+            ChatExportFormat::Csv => Self::Csv,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct IceServerConfig {
+    pub urls: Vec<String>,
+    pub username: String,
+    pub credential: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct RtcConfigOverride {
+    pub ice_servers: Vec<IceServerConfig>,
+    pub relay_only: bool,
+}
+
+impl From<visio_core::RtcConfigOverride> for RtcConfigOverride {
+    fn from(c: visio_core::RtcConfigOverride) -> Self {
+        Self {
+            ice_servers: c
+                .ice_servers
+                .into_iter()
+                .map(|s| IceServerConfig {
+                    urls: s.urls,
+                    username: s.username,
+                    credential: s.credential,
+                })
+                .collect(),
+            relay_only: c.relay_only,
+        }
+    }
+}
+
+impl From<RtcConfigOverride> for visio_core::RtcConfigOverride {
+    fn from(c: RtcConfigOverride) -> Self {
+        Self {
+            ice_servers: c
+                .ice_servers
+                .into_iter()
+                .map(|s| visio_core::IceServerConfig {
+                    urls: s.urls,
+                    username: s.username,
+                    credential: s.credential,
+                })
+                .collect(),
+            relay_only: c.relay_only,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct JoinOptions {
+    pub username: Option<String>,
+    pub display_name: Option<String>,
+    pub mic_on: bool,
+    pub camera_on: bool,
+    pub audio_only: bool,
+    pub auto_subscribe: bool,
+    pub rtc_overrides: Option<RtcConfigOverride>,
+}
+
+impl From<visio_core::JoinOptions> for JoinOptions {
+    fn from(o: visio_core::JoinOptions) -> Self {
+        Self {
+            username: o.username,
+            display_name: o.display_name,
+            mic_on: o.mic_on,
+            camera_on: o.camera_on,
+            audio_only: o.audio_only,
+            auto_subscribe: o.auto_subscribe,
+            rtc_overrides: o.rtc_overrides.map(RtcConfigOverride::from),
+        }
+    }
+}
+
+impl From<JoinOptions> for visio_core::JoinOptions {
+    fn from(o: JoinOptions) -> Self {
+        Self {
+            username: o.username,
+            display_name: o.display_name,
+            mic_on: o.mic_on,
+            camera_on: o.camera_on,
+            audio_only: o.audio_only,
+            auto_subscribe: o.auto_subscribe,
+            rtc_overrides: o.rtc_overrides.map(visio_core::RtcConfigOverride::from),
         }
     }
 }
@@ -238,6 +728,30 @@ pub struct Settings {
     pub notification_participant_join: bool,
     pub notification_hand_raised: bool,
     pub notification_message_received: bool,
+    pub video_overlay_text: Option<String>,
+    pub video_overlay_corner: OverlayCorner,
+    pub video_privacy_style: VideoPrivacyStyle,
+    pub hand_auto_lower_enabled: bool,
+    pub hand_auto_lower_delay_secs: u32,
+    pub metrics_enabled: bool,
+    pub metrics_endpoint: Option<String>,
+    pub feedback_diagnostics_enabled: bool,
+    pub rtc_config: RtcConfigOverride,
+    pub preferred_microphone_id: Option<String>,
+    pub preferred_camera_id: Option<String>,
+    pub preferred_speaker_id: Option<String>,
+    pub audio_ducking_enabled: bool,
+    pub audio_ducking_level: f32,
+    pub auto_gain_enabled: bool,
+    pub auto_gain_target_lufs: f32,
+    pub audio_preset: AudioPreset,
+    pub alone_timeout_mins: u32,
+    pub auto_leave_when_alone: bool,
+    pub inactivity_timeout_mins: u32,
+    pub quality_policy_enabled: bool,
+    pub quality_policy_delay_secs: u32,
+    pub quality_policy_pause_remote_video: bool,
+    pub extras: HashMap<String, String>,
 }
 
 impl From<visio_core::Settings> for Settings {
@@ -252,155 +766,1796 @@ impl From<visio_core::Settings> for Settings {
             notification_participant_join: s.notification_participant_join,
             notification_hand_raised: s.notification_hand_raised,
             notification_message_received: s.notification_message_received,
+            video_overlay_text: s.video_overlay_text,
+            video_overlay_corner: s.video_overlay_corner.into(),
+            video_privacy_style: s.video_privacy_style.into(),
+            hand_auto_lower_enabled: s.hand_auto_lower_enabled,
+            hand_auto_lower_delay_secs: s.hand_auto_lower_delay_secs,
+            metrics_enabled: s.metrics_enabled,
+            metrics_endpoint: s.metrics_endpoint,
+            feedback_diagnostics_enabled: s.feedback_diagnostics_enabled,
+            rtc_config: s.rtc_config.into(),
+            preferred_microphone_id: s.preferred_microphone_id,
+            preferred_camera_id: s.preferred_camera_id,
+            preferred_speaker_id: s.preferred_speaker_id,
+            audio_ducking_enabled: s.audio_ducking_enabled,
+            audio_ducking_level: s.audio_ducking_level,
+            auto_gain_enabled: s.auto_gain_enabled,
+            auto_gain_target_lufs: s.auto_gain_target_lufs,
+            audio_preset: s.audio_preset.into(),
+            alone_timeout_mins: s.alone_timeout_mins,
+            auto_leave_when_alone: s.auto_leave_when_alone,
+            inactivity_timeout_mins: s.inactivity_timeout_mins,
+            quality_policy_enabled: s.quality_policy_enabled,
+            quality_policy_delay_secs: s.quality_policy_delay_secs,
+            quality_policy_pause_remote_video: s.quality_policy_pause_remote_video,
+            extras: s.extras,
         }
     }
 }
 
 #[derive(Debug, Clone)]
-pub enum RoomValidationResult {
-    Valid { livekit_url: String, token: String },
-    NotFound,
-    InvalidFormat { message: String },
-    NetworkError { message: String },
+pub struct ConnectTimeouts {
+    pub meet_api_ms: u64,
+    pub livekit_ms: u64,
 }
 
-#[derive(Debug, Clone)]
-pub enum VisioEvent {
-    ConnectionStateChanged { state: ConnectionState },
-    ParticipantJoined { info: ParticipantInfo },
-    ParticipantLeft { participant_sid: String },
-    TrackSubscribed { info: TrackInfo },
-    TrackUnsubscribed { track_sid: String },
-    TrackMuted { participant_sid: String, source: TrackSource },
-    TrackUnmuted { participant_sid: String, source: TrackSource },
-    ActiveSpeakersChanged { participant_sids: Vec<String> },
-    ConnectionQualityChanged { participant_sid: String, quality: ConnectionQuality },
-    ChatMessageReceived { message: ChatMessage },
-    HandRaisedChanged { participant_sid: String, raised: bool, position: u32 },
-    UnreadCountChanged { count: u32 },
-    ReactionReceived { participant_sid: String, participant_name: String, emoji: String },
-    ConnectionLost,
+impl From<visio_core::ConnectTimeouts> for ConnectTimeouts {
+    fn from(t: visio_core::ConnectTimeouts) -> Self {
+        Self {
+            meet_api_ms: t.meet_api.as_millis() as u64,
+            livekit_ms: t.livekit.as_millis() as u64,
+        }
+    }
 }
 
-impl From<CoreVisioEvent> for VisioEvent {
-    fn from(e: CoreVisioEvent) -> Self {
-        match e {
-            CoreVisioEvent::ConnectionStateChanged(s) => {
-                Self::ConnectionStateChanged { state: s.into() }
-            }
-            CoreVisioEvent::ParticipantJoined(p) => {
-                Self::ParticipantJoined { info: p.into() }
-            }
-            CoreVisioEvent::ParticipantLeft(sid) => {
-                Self::ParticipantLeft { participant_sid: sid }
-            }
-            CoreVisioEvent::TrackSubscribed(t) => {
-                Self::TrackSubscribed { info: t.into() }
-            }
-            CoreVisioEvent::TrackUnsubscribed(sid) => {
-                Self::TrackUnsubscribed { track_sid: sid }
-            }
-            CoreVisioEvent::TrackMuted { participant_sid, source } => {
-                Self::TrackMuted { participant_sid, source: source.into() }
-            }
-            CoreVisioEvent::TrackUnmuted { participant_sid, source } => {
-                Self::TrackUnmuted { participant_sid, source: source.into() }
-            }
-            CoreVisioEvent::ActiveSpeakersChanged(sids) => {
-                Self::ActiveSpeakersChanged { participant_sids: sids }
-            }
-            CoreVisioEvent::ConnectionQualityChanged { participant_sid, quality } => {
-                Self::ConnectionQualityChanged { participant_sid, quality: quality.into() }
-            }
-            CoreVisioEvent::ChatMessageReceived(m) => {
-                Self::ChatMessageReceived { message: m.into() }
-            }
-            CoreVisioEvent::HandRaisedChanged { participant_sid, raised, position } => {
-                Self::HandRaisedChanged { participant_sid, raised, position }
-            }
-            CoreVisioEvent::UnreadCountChanged(count) => {
-                Self::UnreadCountChanged { count }
-            }
-            CoreVisioEvent::ReactionReceived { participant_sid, participant_name, emoji } => {
-                Self::ReactionReceived { participant_sid, participant_name, emoji }
-            }
-            CoreVisioEvent::ConnectionLost => Self::ConnectionLost,
+impl From<ConnectTimeouts> for visio_core::ConnectTimeouts {
+    fn from(t: ConnectTimeouts) -> Self {
+        Self {
+            meet_api: std::time::Duration::from_millis(t.meet_api_ms),
+            livekit: std::time::Duration::from_millis(t.livekit_ms),
         }
     }
 }
 
-// ── Error conversion ──────────────────────────────────────────────────
+#[derive(Debug, Clone)]
+pub enum JoinLeaveMode {
+    Individual,
+    Batched,
+    CountOnly,
+}
 
-#[derive(Debug, thiserror::Error)]
-pub enum VisioError {
-    #[error("Connection error: {msg}")]
-    Connection { msg: String },
-    #[error("Room error: {msg}")]
-    Room { msg: String },
-    #[error("Auth error: {msg}")]
-    Auth { msg: String },
-    #[error("HTTP error: {msg}")]
-    Http { msg: String },
-    #[error("Invalid URL: {msg}")]
-    InvalidUrl { msg: String },
-    #[error("{msg}")]
-    Generic { msg: String },
+#[derive(Debug, Clone)]
+pub struct JoinLeaveConfig {
+    pub mode: JoinLeaveMode,
+    pub window_ms: u64,
 }
 
-impl From<visio_core::VisioError> for VisioError {
-    fn from(e: visio_core::VisioError) -> Self {
-        tracing::error!("VisioError: {e}");
-        match e {
-            visio_core::VisioError::Connection(msg) => Self::Connection { msg },
-            visio_core::VisioError::Room(msg) => Self::Room { msg },
-            visio_core::VisioError::Auth(msg) => Self::Auth { msg },
-            visio_core::VisioError::Http(msg) => Self::Http { msg },
-            visio_core::VisioError::InvalidUrl(msg) => Self::InvalidUrl { msg },
-            visio_core::VisioError::AuthRequired => Self::Auth { msg: "authentication required".to_string() },
+impl From<visio_core::JoinLeaveConfig> for JoinLeaveConfig {
+    fn from(c: visio_core::JoinLeaveConfig) -> Self {
+        Self {
+            mode: match c.mode {
+                visio_core::JoinLeaveMode::Individual => JoinLeaveMode::Individual,
+                visio_core::JoinLeaveMode::Batched => JoinLeaveMode::Batched,
+                visio_core::JoinLeaveMode::CountOnly => JoinLeaveMode::CountOnly,
+            },
+            window_ms: c.window.as_millis() as u64,
         }
     }
 }
 
-// ── Callback interface ────────────────────────────────────────────────
-
-pub trait VisioEventListener: Send + Sync {
-    fn on_event(&self, event: VisioEvent);
+impl From<JoinLeaveConfig> for visio_core::JoinLeaveConfig {
+    fn from(c: JoinLeaveConfig) -> Self {
+        Self {
+            mode: match c.mode {
+                JoinLeaveMode::Individual => visio_core::JoinLeaveMode::Individual,
+                JoinLeaveMode::Batched => visio_core::JoinLeaveMode::Batched,
+                JoinLeaveMode::CountOnly => visio_core::JoinLeaveMode::CountOnly,
+            },
+            window: std::time::Duration::from_millis(c.window_ms),
+        }
+    }
 }
 
-// ── Bridge listener: FFI callback → core listener ─────────────────────
-
-struct BridgeListener {
-    ffi_listener: Arc<dyn VisioEventListener>,
+#[derive(Debug, Clone)]
+pub struct RendererStats {
+    pub frames_received: u64,
+    pub frames_rendered: u64,
+    pub frames_dropped: u64,
+    pub avg_render_ms: f64,
+    pub fps: f64,
 }
 
-impl visio_core::VisioEventListener for BridgeListener {
-    fn on_event(&self, event: CoreVisioEvent) {
-        self.ffi_listener.on_event(event.into());
+impl From<visio_video::RendererStats> for RendererStats {
+    fn from(s: visio_video::RendererStats) -> Self {
+        Self {
+            frames_received: s.frames_received,
+            frames_rendered: s.frames_rendered,
+            frames_dropped: s.frames_dropped,
+            avg_render_ms: s.avg_render_ms,
+            fps: s.fps,
+        }
     }
 }
 
-// ── VisioClient: main FFI object ──────────────────────────────────────
+#[derive(Debug, Clone)]
+pub struct AvSyncStats {
+    pub audio_delay_ms: Option<f64>,
+    pub video_delay_ms: Option<f64>,
+    pub skew_ms: Option<f64>,
+}
 
-pub struct VisioClient {
-    room_manager: visio_core::RoomManager,
-    controls: visio_core::MeetingControls,
-    chat: visio_core::ChatService,
-    settings: visio_core::SettingsStore,
-    rt: tokio::runtime::Runtime,
+impl From<visio_core::AvSyncStats> for AvSyncStats {
+    fn from(s: visio_core::AvSyncStats) -> Self {
+        Self {
+            audio_delay_ms: s.audio_delay_ms,
+            video_delay_ms: s.video_delay_ms,
+            skew_ms: s.skew_ms,
+        }
+    }
 }
 
-impl VisioClient {
+#[derive(Debug, Clone)]
+pub struct Poll {
+    pub id: String,
+    pub question: String,
+    pub options: Vec<String>,
+    pub counts: Vec<u32>,
+    pub creator_sid: String,
+    pub local_vote: Option<u32>,
+    pub ended: bool,
+}
+
+impl From<visio_core::Poll> for Poll {
+    fn from(p: visio_core::Poll) -> Self {
+        Self {
+            id: p.id,
+            question: p.question,
+            options: p.options,
+            counts: p.counts,
+            creator_sid: p.creator_sid,
+            local_vote: p.local_vote,
+            ended: p.ended,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PendingMedia {
+    pub microphone: Option<bool>,
+    pub camera: Option<bool>,
+}
+
+impl From<visio_core::PendingMedia> for PendingMedia {
+    fn from(p: visio_core::PendingMedia) -> Self {
+        Self {
+            microphone: p.microphone,
+            camera: p.camera,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CaptureConstraints {
+    pub width: u32,
+    pub height: u32,
+    pub max_fps: u32,
+    pub low_light_boost: bool,
+}
+
+impl From<visio_core::CaptureConstraints> for CaptureConstraints {
+    fn from(c: visio_core::CaptureConstraints) -> Self {
+        Self {
+            width: c.width,
+            height: c.height,
+            max_fps: c.max_fps,
+            low_light_boost: c.low_light_boost,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AudioPublishOptions {
+    pub max_bitrate_bps: Option<u32>,
+    pub dtx: bool,
+    pub red: bool,
+    pub stereo: bool,
+    pub voice_processing: bool,
+}
+
+impl From<visio_core::AudioPublishOptions> for AudioPublishOptions {
+    fn from(o: visio_core::AudioPublishOptions) -> Self {
+        Self {
+            max_bitrate_bps: o.max_bitrate_bps,
+            dtx: o.dtx,
+            red: o.red,
+            stereo: o.stereo,
+            voice_processing: o.voice_processing,
+        }
+    }
+}
+
+impl From<AudioPublishOptions> for visio_core::AudioPublishOptions {
+    fn from(o: AudioPublishOptions) -> Self {
+        Self {
+            max_bitrate_bps: o.max_bitrate_bps,
+            dtx: o.dtx,
+            red: o.red,
+            stereo: o.stereo,
+            voice_processing: o.voice_processing,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AudioPreset {
+    Voice,
+    Music,
+}
+
+impl From<visio_core::AudioPreset> for AudioPreset {
+    fn from(p: visio_core::AudioPreset) -> Self {
+        match p {
+            visio_core::AudioPreset::Voice => Self::Voice,
+            visio_core::AudioPreset::Music => Self::Music,
+        }
+    }
+}
+
+impl From<AudioPreset> for visio_core::AudioPreset {
+    fn from(p: AudioPreset) -> Self {
+        match p {
+            AudioPreset::Voice => Self::Voice,
+            AudioPreset::Music => Self::Music,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverlayCorner {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+impl From<visio_core::OverlayCorner> for OverlayCorner {
+    fn from(c: visio_core::OverlayCorner) -> Self {
+        match c {
+            visio_core::OverlayCorner::TopLeft => Self::TopLeft,
+            visio_core::OverlayCorner::TopRight => Self::TopRight,
+            visio_core::OverlayCorner::BottomLeft => Self::BottomLeft,
+            visio_core::OverlayCorner::BottomRight => Self::BottomRight,
+        }
+    }
+}
+
+impl From<OverlayCorner> for visio_core::OverlayCorner {
+    fn from(c: OverlayCorner) -> Self {
+        match c {
+            OverlayCorner::TopLeft => Self::TopLeft,
+            OverlayCorner::TopRight => Self::TopRight,
+            OverlayCorner::BottomLeft => Self::BottomLeft,
+            OverlayCorner::BottomRight => Self::BottomRight,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VideoPrivacyStyle {
+    Blur,
+    Card,
+}
+
+impl From<visio_core::VideoPrivacyStyle> for VideoPrivacyStyle {
+    fn from(s: visio_core::VideoPrivacyStyle) -> Self {
+        match s {
+            visio_core::VideoPrivacyStyle::Blur => Self::Blur,
+            visio_core::VideoPrivacyStyle::Card => Self::Card,
+        }
+    }
+}
+
+impl From<VideoPrivacyStyle> for visio_core::VideoPrivacyStyle {
+    fn from(s: VideoPrivacyStyle) -> Self {
+        match s {
+            VideoPrivacyStyle::Blur => Self::Blur,
+            VideoPrivacyStyle::Card => Self::Card,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum CameraCommand {
+    SetTorch { enabled: bool },
+    SetZoom { factor: f32 },
+    SetFocusPoint { x: f32, y: f32 },
+}
+
+impl From<visio_core::CameraCommand> for CameraCommand {
+    fn from(c: visio_core::CameraCommand) -> Self {
+        match c {
+            visio_core::CameraCommand::SetTorch { enabled } => Self::SetTorch { enabled },
+            visio_core::CameraCommand::SetZoom { factor } => Self::SetZoom { factor },
+            visio_core::CameraCommand::SetFocusPoint { x, y } => Self::SetFocusPoint { x, y },
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum DeepLink {
+    JoinRoom {
+        meet_url: String,
+        instance: String,
+        slug: String,
+        display_name: Option<String>,
+    },
+    OpenSettings,
+}
+
+impl From<visio_core::DeepLink> for DeepLink {
+    fn from(l: visio_core::DeepLink) -> Self {
+        match l {
+            visio_core::DeepLink::JoinRoom {
+                meet_url,
+                instance,
+                slug,
+                display_name,
+            } => Self::JoinRoom {
+                meet_url,
+                instance,
+                slug,
+                display_name,
+            },
+            visio_core::DeepLink::OpenSettings => Self::OpenSettings,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CameraControlState {
+    pub torch_enabled: bool,
+    pub zoom_factor: f32,
+}
+
+impl From<visio_core::CameraControlState> for CameraControlState {
+    fn from(s: visio_core::CameraControlState) -> Self {
+        Self {
+            torch_enabled: s.torch_enabled,
+            zoom_factor: s.zoom_factor,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum VideoContentHint {
+    Motion,
+    Detail,
+}
+
+impl From<visio_core::VideoContentHint> for VideoContentHint {
+    fn from(h: visio_core::VideoContentHint) -> Self {
+        match h {
+            visio_core::VideoContentHint::Motion => Self::Motion,
+            visio_core::VideoContentHint::Detail => Self::Detail,
+        }
+    }
+}
+
+impl From<VideoContentHint> for visio_core::VideoContentHint {
+    fn from(h: VideoContentHint) -> Self {
+        match h {
+            VideoContentHint::Motion => Self::Motion,
+            VideoContentHint::Detail => Self::Detail,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VideoCodec {
+    Vp8,
+    Vp9,
+    H264,
+    Av1,
+}
+
+impl From<visio_core::VideoCodec> for VideoCodec {
+    fn from(c: visio_core::VideoCodec) -> Self {
+        match c {
+            visio_core::VideoCodec::Vp8 => Self::Vp8,
+            visio_core::VideoCodec::Vp9 => Self::Vp9,
+            visio_core::VideoCodec::H264 => Self::H264,
+            visio_core::VideoCodec::Av1 => Self::Av1,
+        }
+    }
+}
+
+impl From<VideoCodec> for visio_core::VideoCodec {
+    fn from(c: VideoCodec) -> Self {
+        match c {
+            VideoCodec::Vp8 => Self::Vp8,
+            VideoCodec::Vp9 => Self::Vp9,
+            VideoCodec::H264 => Self::H264,
+            VideoCodec::Av1 => Self::Av1,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NegotiatedCodecs {
+    pub sent: Vec<String>,
+    pub received: Vec<String>,
+}
+
+impl From<visio_core::NegotiatedCodecs> for NegotiatedCodecs {
+    fn from(c: visio_core::NegotiatedCodecs) -> Self {
+        Self {
+            sent: c.sent,
+            received: c.received,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct QualitySample {
+    pub timestamp_ms: u64,
+    pub bitrate_kbps: u32,
+    pub packet_loss_pct: f64,
+    pub quality: ConnectionQuality,
+}
+
+impl From<visio_core::QualitySample> for QualitySample {
+    fn from(s: visio_core::QualitySample) -> Self {
+        Self {
+            timestamp_ms: s.timestamp_ms,
+            bitrate_kbps: s.bitrate_kbps,
+            packet_loss_pct: s.packet_loss_pct,
+            quality: s.quality.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CallSummary {
+    pub ended_at_ms: i64,
+    pub duration_secs: u64,
+    pub peak_participants: u32,
+    pub reconnect_count: u32,
+    pub average_quality: Option<ConnectionQuality>,
+    pub error_count: u32,
+}
+
+impl From<visio_core::CallSummary> for CallSummary {
+    fn from(s: visio_core::CallSummary) -> Self {
+        Self {
+            ended_at_ms: s.ended_at_ms,
+            duration_secs: s.duration_secs,
+            peak_participants: s.peak_participants,
+            reconnect_count: s.reconnect_count,
+            average_quality: s.average_quality.map(Into::into),
+            error_count: s.error_count,
+        }
+    }
+}
+
+/// Receive figures of a remote video track, from the RTC stats and the
+/// renderer.
+#[derive(Debug, Clone)]
+pub struct VideoStats {
+    pub width: u32,
+    pub height: u32,
+    pub is_hd: bool,
+    pub fps: f64,
+    pub avg_decode_ms: f64,
+    pub freeze_count: u32,
+    /// Frames drawn per second, 0 without a running renderer.
+    pub render_fps: f64,
+    pub frames_dropped: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LayoutMode {
+    Grid,
+    Speaker,
+    Sidebar,
+}
+
+impl From<LayoutMode> for visio_core::LayoutMode {
+    fn from(m: LayoutMode) -> Self {
+        match m {
+            LayoutMode::Grid => Self::Grid,
+            LayoutMode::Speaker => Self::Speaker,
+            LayoutMode::Sidebar => Self::Sidebar,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LayoutOptions {
+    pub mode: LayoutMode,
+    pub width: f32,
+    pub height: f32,
+    pub participant_sids: Vec<String>,
+    pub spotlight_sid: Option<String>,
+    pub tile_aspect_ratio: f32,
+    pub gap: f32,
+}
+
+impl From<LayoutOptions> for visio_core::LayoutOptions {
+    fn from(o: LayoutOptions) -> Self {
+        Self {
+            mode: o.mode.into(),
+            width: o.width,
+            height: o.height,
+            participant_sids: o.participant_sids,
+            spotlight_sid: o.spotlight_sid,
+            tile_aspect_ratio: o.tile_aspect_ratio,
+            gap: o.gap,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TileRect {
+    pub participant_sid: String,
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl From<visio_core::TileRect> for TileRect {
+    fn from(t: visio_core::TileRect) -> Self {
+        Self {
+            participant_sid: t.participant_sid,
+            x: t.x,
+            y: t.y,
+            width: t.width,
+            height: t.height,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PowerMode {
+    Normal,
+    Saver,
+}
+
+impl From<visio_core::PowerMode> for PowerMode {
+    fn from(m: visio_core::PowerMode) -> Self {
+        match m {
+            visio_core::PowerMode::Normal => Self::Normal,
+            visio_core::PowerMode::Saver => Self::Saver,
+        }
+    }
+}
+
+impl From<PowerMode> for visio_core::PowerMode {
+    fn from(m: PowerMode) -> Self {
+        match m {
+            PowerMode::Normal => Self::Normal,
+            PowerMode::Saver => Self::Saver,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Presence {
+    Available,
+    Away,
+    BeRightBack,
+    DoNotDisturb,
+}
+
+impl From<visio_core::Presence> for Presence {
+    fn from(p: visio_core::Presence) -> Self {
+        match p {
+            visio_core::Presence::Available => Self::Available,
+            visio_core::Presence::Away => Self::Away,
+            visio_core::Presence::BeRightBack => Self::BeRightBack,
+            visio_core::Presence::DoNotDisturb => Self::DoNotDisturb,
+        }
+    }
+}
+
+impl From<Presence> for visio_core::Presence {
+    fn from(p: Presence) -> Self {
+        match p {
+            Presence::Available => Self::Available,
+            Presence::Away => Self::Away,
+            Presence::BeRightBack => Self::BeRightBack,
+            Presence::DoNotDisturb => Self::DoNotDisturb,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThermalState {
+    Nominal,
+    Fair,
+    Serious,
+    Critical,
+}
+
+impl From<visio_core::ThermalState> for ThermalState {
+    fn from(s: visio_core::ThermalState) -> Self {
+        match s {
+            visio_core::ThermalState::Nominal => Self::Nominal,
+            visio_core::ThermalState::Fair => Self::Fair,
+            visio_core::ThermalState::Serious => Self::Serious,
+            visio_core::ThermalState::Critical => Self::Critical,
+        }
+    }
+}
+
+impl From<ThermalState> for visio_core::ThermalState {
+    fn from(s: ThermalState) -> Self {
+        match s {
+            ThermalState::Nominal => Self::Nominal,
+            ThermalState::Fair => Self::Fair,
+            ThermalState::Serious => Self::Serious,
+            ThermalState::Critical => Self::Critical,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DevicePermission {
+    Camera,
+    Microphone,
+    ScreenCapture,
+    Notifications,
+}
+
+impl From<visio_core::DevicePermission> for DevicePermission {
+    fn from(p: visio_core::DevicePermission) -> Self {
+        match p {
+            visio_core::DevicePermission::Camera => Self::Camera,
+            visio_core::DevicePermission::Microphone => Self::Microphone,
+            visio_core::DevicePermission::ScreenCapture => Self::ScreenCapture,
+            visio_core::DevicePermission::Notifications => Self::Notifications,
+        }
+    }
+}
+
+impl From<DevicePermission> for visio_core::DevicePermission {
+    fn from(p: DevicePermission) -> Self {
+        match p {
+            DevicePermission::Camera => Self::Camera,
+            DevicePermission::Microphone => Self::Microphone,
+            DevicePermission::ScreenCapture => Self::ScreenCapture,
+            DevicePermission::Notifications => Self::Notifications,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PermissionState {
+    Unknown,
+    Granted,
+    Denied,
+}
+
+impl From<visio_core::PermissionState> for PermissionState {
+    fn from(s: visio_core::PermissionState) -> Self {
+        match s {
+            visio_core::PermissionState::Unknown => Self::Unknown,
+            visio_core::PermissionState::Granted => Self::Granted,
+            visio_core::PermissionState::Denied => Self::Denied,
+        }
+    }
+}
+
+impl From<PermissionState> for visio_core::PermissionState {
+    fn from(s: PermissionState) -> Self {
+        match s {
+            PermissionState::Unknown => Self::Unknown,
+            PermissionState::Granted => Self::Granted,
+            PermissionState::Denied => Self::Denied,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CallDirection {
+    Incoming,
+    Outgoing,
+}
+
+impl From<CallDirection> for visio_core::CallDirection {
+    fn from(d: CallDirection) -> Self {
+        match d {
+            CallDirection::Incoming => Self::Incoming,
+            CallDirection::Outgoing => Self::Outgoing,
+        }
+    }
+}
+
+impl From<visio_core::CallDirection> for CallDirection {
+    fn from(d: visio_core::CallDirection) -> Self {
+        match d {
+            visio_core::CallDirection::Incoming => Self::Incoming,
+            visio_core::CallDirection::Outgoing => Self::Outgoing,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SystemCallState {
+    Connecting,
+    Connected,
+    EndedLocally,
+    EndedRemotely,
+    Failed,
+}
+
+impl From<visio_core::SystemCallState> for SystemCallState {
+    fn from(s: visio_core::SystemCallState) -> Self {
+        match s {
+            visio_core::SystemCallState::Connecting => Self::Connecting,
+            visio_core::SystemCallState::Connected => Self::Connected,
+            visio_core::SystemCallState::EndedLocally => Self::EndedLocally,
+            visio_core::SystemCallState::EndedRemotely => Self::EndedRemotely,
+            visio_core::SystemCallState::Failed => Self::Failed,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SystemCall {
+    pub call_id: String,
+    pub room: String,
+    pub display_name: String,
+    pub direction: CallDirection,
+    pub state: SystemCallState,
+}
+
+impl From<visio_core::SystemCall> for SystemCall {
+    fn from(c: visio_core::SystemCall) -> Self {
+        Self {
+            call_id: c.call_id,
+            room: c.room,
+            display_name: c.display_name,
+            direction: c.direction.into(),
+            state: c.state.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MediaButton {
+    PlayPause,
+    Hangup,
+}
+
+impl From<MediaButton> for visio_core::MediaButton {
+    fn from(b: MediaButton) -> Self {
+        match b {
+            MediaButton::PlayPause => Self::PlayPause,
+            MediaButton::Hangup => Self::Hangup,
+        }
+    }
+}
+
+impl From<visio_core::MediaButton> for MediaButton {
+    fn from(b: visio_core::MediaButton) -> Self {
+        match b {
+            visio_core::MediaButton::PlayPause => Self::PlayPause,
+            visio_core::MediaButton::Hangup => Self::Hangup,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum QualityReductionReason {
+    Thermal { state: ThermalState },
+}
+
+impl From<visio_core::QualityReductionReason> for QualityReductionReason {
+    fn from(r: visio_core::QualityReductionReason) -> Self {
+        match r {
+            visio_core::QualityReductionReason::Thermal(state) => Self::Thermal {
+                state: state.into(),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum RoomValidationResult {
+    Valid { livekit_url: String, token: String },
+    NotFound,
+    InvalidFormat { message: String },
+    NetworkError { message: String },
+}
+
+#[derive(Debug, Clone)]
+pub enum VisioEvent {
+    ConnectionStateChanged {
+        state: ConnectionState,
+    },
+    ParticipantJoined {
+        info: ParticipantInfo,
+    },
+    ParticipantLeft {
+        participant_sid: String,
+    },
+    TrackSubscribed {
+        info: TrackInfo,
+    },
+    TrackUnsubscribed {
+        track_sid: String,
+    },
+    TrackMuted {
+        participant_sid: String,
+        source: TrackSource,
+    },
+    TrackUnmuted {
+        participant_sid: String,
+        source: TrackSource,
+    },
+    ActiveSpeakersChanged {
+        participant_sids: Vec<String>,
+    },
+    LayoutOrderChanged {
+        participant_sids: Vec<String>,
+    },
+    ConnectionQualityChanged {
+        participant_sid: String,
+        quality: ConnectionQuality,
+    },
+    ChatMessageReceived {
+        message: ChatMessage,
+    },
+    HandRaisedChanged {
+        participant_sid: String,
+        raised: bool,
+        position: u32,
+    },
+    UnreadCountChanged {
+        count: u32,
+    },
+    HandAutoLowered,
+    ReactionReceived {
+        participant_sid: String,
+        participant_name: String,
+        emoji: String,
+    },
+    ConnectionLost,
+    RoomSwitched {
+        meet_url: String,
+    },
+    ConnectProgress {
+        step: ConnectStep,
+    },
+    ParticipantBatchUpdate {
+        joined: Vec<ParticipantInfo>,
+        left: Vec<String>,
+        joined_count: u32,
+        left_count: u32,
+    },
+    TrackStalled {
+        track_sid: String,
+    },
+    TrackResumed {
+        track_sid: String,
+    },
+    PermissionsChanged {
+        permissions: LocalPermissions,
+    },
+    WaitingForHost,
+    EntryGranted,
+    EntryDenied,
+    RoomLockedChanged {
+        locked: bool,
+    },
+    FeatureFlagsChanged {
+        flags: FeatureFlags,
+    },
+    RecordingStatusChanged {
+        status: RecordingStatus,
+    },
+    LocalRecordingProgress {
+        duration_ms: u64,
+        size_bytes: u64,
+    },
+    RestreamStatusChanged {
+        status: RestreamStatus,
+    },
+    SourceReady {
+        source: TrackSource,
+        handle: u64,
+    },
+    CaptureConstraintsChanged {
+        constraints: CaptureConstraints,
+    },
+    DataMessageReceived {
+        topic: String,
+        sender_sid: String,
+        payload: Vec<u8>,
+    },
+    PollStarted {
+        poll: Poll,
+    },
+    PollUpdated {
+        poll: Poll,
+    },
+    PollEnded {
+        poll: Poll,
+    },
+    WhiteboardAvailable,
+    PowerModeChanged {
+        mode: PowerMode,
+    },
+    QualityReduced {
+        reason: QualityReductionReason,
+        constraints: CaptureConstraints,
+    },
+    QualityRestored,
+    DevicePermissionChanged {
+        permission: DevicePermission,
+        state: PermissionState,
+    },
+    SettingsChanged {
+        keys: Vec<String>,
+    },
+    SystemCallChanged {
+        call: SystemCall,
+    },
+    MediaButtonHandled {
+        button: MediaButton,
+    },
+    MeetingEndingSoon {
+        minutes_left: u32,
+    },
+    MeetingEnded,
+    AloneInRoom {
+        minutes: u32,
+        auto_leave: bool,
+    },
+    InactivityDetected {
+        minutes: u32,
+    },
+    SpotlightChanged {
+        participant_sid: Option<String>,
+        track_sid: Option<String>,
+    },
+    PresenceChanged {
+        participant_sid: String,
+        presence: Presence,
+    },
+    ParticipantAttributesChanged {
+        participant_sid: String,
+        changed: HashMap<String, String>,
+    },
+    QualityPolicyTriggered {
+        active: bool,
+        camera: bool,
+        remote_video: bool,
+    },
+    CallEnded {
+        summary: CallSummary,
+    },
+    VideoPrivacyChanged {
+        enabled: bool,
+    },
+    ChatUnreadChanged {
+        unread: ChatUnread,
+    },
+}
+
+impl From<CoreVisioEvent> for VisioEvent {
+    fn from(e: CoreVisioEvent) -> Self {
+        match e {
+            CoreVisioEvent::ConnectionStateChanged(s) => {
+                Self::ConnectionStateChanged { state: s.into() }
+            }
+            CoreVisioEvent::ParticipantJoined(p) => Self::ParticipantJoined { info: p.into() },
+            CoreVisioEvent::ParticipantLeft(sid) => Self::ParticipantLeft {
+                participant_sid: sid,
+            },
+            CoreVisioEvent::TrackSubscribed(t) => Self::TrackSubscribed { info: t.into() },
+            CoreVisioEvent::TrackUnsubscribed(sid) => Self::TrackUnsubscribed { track_sid: sid },
+            CoreVisioEvent::TrackMuted {
+                participant_sid,
+                source,
+            } => Self::TrackMuted {
+                participant_sid,
+                source: source.into(),
+            },
+            CoreVisioEvent::TrackUnmuted {
+                participant_sid,
+                source,
+            } => Self::TrackUnmuted {
+                participant_sid,
+                source: source.into(),
+            },
+            CoreVisioEvent::ActiveSpeakersChanged(sids) => Self::ActiveSpeakersChanged {
+                participant_sids: sids,
+            },
+            CoreVisioEvent::LayoutOrderChanged(sids) => Self::LayoutOrderChanged {
+                participant_sids: sids,
+            },
+            CoreVisioEvent::ConnectionQualityChanged {
+                participant_sid,
+                quality,
+            } => Self::ConnectionQualityChanged {
+                participant_sid,
+                quality: quality.into(),
+            },
+            CoreVisioEvent::ChatMessageReceived(m) => {
+                Self::ChatMessageReceived { message: m.into() }
+            }
+            CoreVisioEvent::HandRaisedChanged {
+                participant_sid,
+                raised,
+                position,
+            } => Self::HandRaisedChanged {
+                participant_sid,
+                raised,
+                position,
+            },
+            CoreVisioEvent::UnreadCountChanged(count) => Self::UnreadCountChanged { count },
+            CoreVisioEvent::HandAutoLowered => Self::HandAutoLowered,
+            CoreVisioEvent::ReactionReceived {
+                participant_sid,
+                participant_name,
+                emoji,
+            } => Self::ReactionReceived {
+                participant_sid,
+                participant_name,
+                emoji,
+            },
+            CoreVisioEvent::ConnectionLost => Self::ConnectionLost,
+            CoreVisioEvent::RoomSwitched { meet_url } => Self::RoomSwitched { meet_url },
+            CoreVisioEvent::ConnectProgress(step) => Self::ConnectProgress { step: step.into() },
+            CoreVisioEvent::ParticipantBatchUpdate {
+                joined,
+                left,
+                joined_count,
+                left_count,
+            } => Self::ParticipantBatchUpdate {
+                joined: joined.into_iter().map(ParticipantInfo::from).collect(),
+                left,
+                joined_count,
+                left_count,
+            },
+            CoreVisioEvent::TrackStalled { track_sid } => Self::TrackStalled { track_sid },
+            CoreVisioEvent::TrackResumed { track_sid } => Self::TrackResumed { track_sid },
+            CoreVisioEvent::PermissionsChanged(p) => Self::PermissionsChanged {
+                permissions: p.into(),
+            },
+            CoreVisioEvent::WaitingForHost => Self::WaitingForHost,
+            CoreVisioEvent::EntryGranted => Self::EntryGranted,
+            CoreVisioEvent::EntryDenied => Self::EntryDenied,
+            CoreVisioEvent::RoomLockedChanged(locked) => Self::RoomLockedChanged { locked },
+            CoreVisioEvent::FeatureFlagsChanged(flags) => Self::FeatureFlagsChanged {
+                flags: flags.into(),
+            },
+            CoreVisioEvent::RecordingStatusChanged(status) => Self::RecordingStatusChanged {
+                status: status.into(),
+            },
+            CoreVisioEvent::LocalRecordingProgress {
+                duration_ms,
+                size_bytes,
+            } => Self::LocalRecordingProgress {
+                duration_ms,
+                size_bytes,
+            },
+            CoreVisioEvent::RestreamStatusChanged(status) => Self::RestreamStatusChanged {
+                status: status.into(),
+            },
+            CoreVisioEvent::SourceReady { source, handle } => Self::SourceReady {
+                source: source.into(),
+                handle,
+            },
+            CoreVisioEvent::CaptureConstraintsChanged(constraints) => {
+                Self::CaptureConstraintsChanged {
+                    constraints: constraints.into(),
+                }
+            }
+            CoreVisioEvent::DataMessageReceived {
+                topic,
+                sender_sid,
+                payload,
+            } => Self::DataMessageReceived {
+                topic,
+                sender_sid,
+                payload,
+            },
+            CoreVisioEvent::PollStarted(poll) => Self::PollStarted { poll: poll.into() },
+            CoreVisioEvent::PollUpdated(poll) => Self::PollUpdated { poll: poll.into() },
+            CoreVisioEvent::PollEnded(poll) => Self::PollEnded { poll: poll.into() },
+            CoreVisioEvent::WhiteboardAvailable => Self::WhiteboardAvailable,
+            CoreVisioEvent::PowerModeChanged(mode) => Self::PowerModeChanged { mode: mode.into() },
+            CoreVisioEvent::QualityReduced {
+                reason,
+                constraints,
+            } => Self::QualityReduced {
+                reason: reason.into(),
+                constraints: constraints.into(),
+            },
+            CoreVisioEvent::QualityRestored => Self::QualityRestored,
+            CoreVisioEvent::DevicePermissionChanged { permission, state } => {
+                Self::DevicePermissionChanged {
+                    permission: permission.into(),
+                    state: state.into(),
+                }
+            }
+            CoreVisioEvent::SettingsChanged { keys } => Self::SettingsChanged { keys },
+            CoreVisioEvent::SystemCallChanged(call) => {
+                Self::SystemCallChanged { call: call.into() }
+            }
+            CoreVisioEvent::MediaButtonHandled(button) => Self::MediaButtonHandled {
+                button: button.into(),
+            },
+            CoreVisioEvent::MeetingEndingSoon { minutes_left } => {
+                Self::MeetingEndingSoon { minutes_left }
+            }
+            CoreVisioEvent::MeetingEnded => Self::MeetingEnded,
+            CoreVisioEvent::AloneInRoom {
+                minutes,
+                auto_leave,
+            } => Self::AloneInRoom {
+                minutes,
+                auto_leave,
+            },
+            CoreVisioEvent::InactivityDetected { minutes } => Self::InactivityDetected { minutes },
+            CoreVisioEvent::SpotlightChanged {
+                participant_sid,
+                track_sid,
+            } => Self::SpotlightChanged {
+                participant_sid,
+                track_sid,
+            },
+            CoreVisioEvent::PresenceChanged {
+                participant_sid,
+                presence,
+            } => Self::PresenceChanged {
+                participant_sid,
+                presence: presence.into(),
+            },
+            CoreVisioEvent::ParticipantAttributesChanged {
+                participant_sid,
+                changed,
+            } => Self::ParticipantAttributesChanged {
+                participant_sid,
+                changed,
+            },
+            CoreVisioEvent::QualityPolicyTriggered {
+                active,
+                camera,
+                remote_video,
+            } => Self::QualityPolicyTriggered {
+                active,
+                camera,
+                remote_video,
+            },
+            CoreVisioEvent::CallEnded(summary) => Self::CallEnded {
+                summary: summary.into(),
+            },
+            CoreVisioEvent::VideoPrivacyChanged { enabled } => {
+                Self::VideoPrivacyChanged { enabled }
+            }
+            CoreVisioEvent::ChatUnreadChanged(unread) => Self::ChatUnreadChanged {
+                unread: unread.into(),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum CheckResult {
+    Passed,
+    Failed { reason: String },
+    Skipped,
+}
+
+impl From<visio_core::CheckResult> for CheckResult {
+    fn from(r: visio_core::CheckResult) -> Self {
+        match r {
+            visio_core::CheckResult::Passed => Self::Passed,
+            visio_core::CheckResult::Failed(reason) => Self::Failed { reason },
+            visio_core::CheckResult::Skipped => Self::Skipped,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ConnectivityDiagnosis {
+    pub host: String,
+    pub port: u16,
+    pub dns: CheckResult,
+    pub ipv4_addresses: Vec<String>,
+    pub ipv6_addresses: Vec<String>,
+    pub tcp_ipv4: CheckResult,
+    pub tcp_ipv6: CheckResult,
+    pub tls: CheckResult,
+    pub websocket: CheckResult,
+    pub captive_portal: bool,
+}
+
+impl From<visio_core::ConnectivityDiagnosis> for ConnectivityDiagnosis {
+    fn from(d: visio_core::ConnectivityDiagnosis) -> Self {
+        Self {
+            host: d.host,
+            port: d.port,
+            dns: d.dns.into(),
+            ipv4_addresses: d.ipv4_addresses,
+            ipv6_addresses: d.ipv6_addresses,
+            tcp_ipv4: d.tcp_ipv4.into(),
+            tcp_ipv6: d.tcp_ipv6.into(),
+            tls: d.tls.into(),
+            websocket: d.websocket.into(),
+            captive_portal: d.captive_portal,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DeviceCheckReport {
+    pub microphone: CheckResult,
+    pub microphone_level: f32,
+    pub camera: CheckResult,
+    pub camera_frames: u32,
+    pub speaker: CheckResult,
+    pub has_problem: bool,
+}
+
+impl From<visio_core::DeviceCheckReport> for DeviceCheckReport {
+    fn from(r: visio_core::DeviceCheckReport) -> Self {
+        Self {
+            has_problem: r.has_problem(),
+            microphone: r.microphone.into(),
+            microphone_level: r.microphone_level,
+            camera: r.camera.into(),
+            camera_frames: r.camera_frames,
+            speaker: r.speaker.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SelfTestReport {
+    pub webrtc: CheckResult,
+    pub runtime: CheckResult,
+    pub data_dir: CheckResult,
+    pub has_problem: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct RoomInfo {
+    pub room_id: Option<String>,
+    pub access_level: Option<String>,
+    pub pin_code: Option<String>,
+}
+
+impl From<visio_core::RoomInfo> for RoomInfo {
+    fn from(r: visio_core::RoomInfo) -> Self {
+        Self {
+            room_id: r.room_id,
+            access_level: r.access_level,
+            pin_code: r.pin_code,
+        }
+    }
+}
+
+// ── Error conversion ──────────────────────────────────────────────────
+
+/// Errors carry a stable `code`, the i18n key of a message for users and,
+/// when available, technical detail for logs. The display text (the
+/// exception message on the shells) is the detail, or the key without one.
+#[derive(Debug, thiserror::Error)]
+pub enum VisioError {
+    #[error("Connection error: {}", describe(user_message_key, developer_detail))]
+    Connection {
+        code: String,
+        user_message_key: String,
+        developer_detail: Option<String>,
+    },
+    #[error("Room error: {}", describe(user_message_key, developer_detail))]
+    Room {
+        code: String,
+        user_message_key: String,
+        developer_detail: Option<String>,
+    },
+    #[error("Auth error: {}", describe(user_message_key, developer_detail))]
+    Auth {
+        code: String,
+        user_message_key: String,
+        developer_detail: Option<String>,
+    },
+    #[error("HTTP error: {}", describe(user_message_key, developer_detail))]
+    Http {
+        code: String,
+        user_message_key: String,
+        developer_detail: Option<String>,
+    },
+    #[error("Invalid URL: {}", describe(user_message_key, developer_detail))]
+    InvalidUrl {
+        code: String,
+        user_message_key: String,
+        developer_detail: Option<String>,
+    },
+    #[error("Timed out: {}", describe(user_message_key, developer_detail))]
+    Timeout {
+        code: String,
+        user_message_key: String,
+        developer_detail: Option<String>,
+    },
+    #[error("Unreachable: {}", describe(user_message_key, developer_detail))]
+    Unreachable {
+        code: String,
+        user_message_key: String,
+        developer_detail: Option<String>,
+        diagnosis: ConnectivityDiagnosis,
+    },
+    #[error("Permission denied: {}", describe(user_message_key, developer_detail))]
+    PermissionDenied {
+        code: String,
+        user_message_key: String,
+        developer_detail: Option<String>,
+        permission: DevicePermission,
+    },
+    #[error("Not allowed: {}", describe(user_message_key, developer_detail))]
+    PublishNotAllowed {
+        code: String,
+        user_message_key: String,
+        developer_detail: Option<String>,
+        track_source: TrackSource,
+    },
+    #[error("Message too large: {}", describe(user_message_key, developer_detail))]
+    MessageTooLarge {
+        code: String,
+        user_message_key: String,
+        developer_detail: Option<String>,
+        size: u64,
+        max: u64,
+    },
+    #[error("{}", describe(user_message_key, developer_detail))]
+    Generic {
+        code: String,
+        user_message_key: String,
+        developer_detail: Option<String>,
+    },
+}
+
+fn describe<'a>(user_message_key: &'a str, developer_detail: &'a Option<String>) -> &'a str {
+    developer_detail.as_deref().unwrap_or(user_message_key)
+}
+
+impl VisioError {
+    /// Error raised by the bindings themselves (logging, blur images...).
+    fn generic(detail: impl Into<String>) -> Self {
+        Self::Generic {
+            code: "generic".to_string(),
+            user_message_key: "error.generic".to_string(),
+            developer_detail: Some(detail.into()),
+        }
+    }
+}
+
+impl From<visio_core::VisioError> for VisioError {
+    fn from(e: visio_core::VisioError) -> Self {
+        tracing::error!("VisioError: {e}");
+        let code = e.code().to_string();
+        let user_message_key = e.user_message_key().to_string();
+        let developer_detail = e.developer_detail();
+        match e {
+            visio_core::VisioError::Connection(_) => Self::Connection {
+                code,
+                user_message_key,
+                developer_detail,
+            },
+            visio_core::VisioError::Room(_) => Self::Room {
+                code,
+                user_message_key,
+                developer_detail,
+            },
+            visio_core::VisioError::Auth(_) | visio_core::VisioError::AuthRequired => Self::Auth {
+                code,
+                user_message_key,
+                developer_detail,
+            },
+            visio_core::VisioError::Http(_) => Self::Http {
+                code,
+                user_message_key,
+                developer_detail,
+            },
+            visio_core::VisioError::InvalidUrl(_) => Self::InvalidUrl {
+                code,
+                user_message_key,
+                developer_detail,
+            },
+            visio_core::VisioError::Timeout(_) => Self::Timeout {
+                code,
+                user_message_key,
+                developer_detail,
+            },
+            visio_core::VisioError::Unreachable { diagnosis, .. } => Self::Unreachable {
+                code,
+                user_message_key,
+                developer_detail,
+                diagnosis: (*diagnosis).into(),
+            },
+            visio_core::VisioError::PermissionDenied(permission) => Self::PermissionDenied {
+                code,
+                user_message_key,
+                developer_detail,
+                permission: permission.into(),
+            },
+            visio_core::VisioError::PublishNotAllowed(source) => Self::PublishNotAllowed {
+                code,
+                user_message_key,
+                developer_detail,
+                track_source: source.into(),
+            },
+            visio_core::VisioError::MessageTooLarge { size, max } => Self::MessageTooLarge {
+                code,
+                user_message_key,
+                developer_detail,
+                size: size as u64,
+                max: max as u64,
+            },
+        }
+    }
+}
+
+// ── Callback interface ────────────────────────────────────────────────
+
+pub trait VisioEventListener: Send + Sync {
+    fn on_event(&self, event: VisioEvent);
+}
+
+// ── Bridge listener: FFI callback → core listener ─────────────────────
+
+struct BridgeListener {
+    ffi_listener: Arc<dyn VisioEventListener>,
+}
+
+impl visio_core::VisioEventListener for BridgeListener {
+    fn on_event(&self, event: CoreVisioEvent) {
+        self.ffi_listener.on_event(event.into());
+    }
+}
+
+/// Callback interface implemented by the platform camera capture.
+pub trait CameraCommandListener: Send + Sync {
+    fn on_camera_command(&self, command: CameraCommand);
+}
+
+struct CameraCommandBridge {
+    ffi_listener: Arc<dyn CameraCommandListener>,
+}
+
+impl visio_core::CameraCommandListener for CameraCommandBridge {
+    fn on_camera_command(&self, command: visio_core::CameraCommand) {
+        self.ffi_listener.on_camera_command(command.into());
+    }
+}
+
+/// Callback interface receiving events from every room, tagged with the room id.
+pub trait RoomEventListener: Send + Sync {
+    fn on_room_event(&self, room_id: String, event: VisioEvent);
+}
+
+/// Fans one room's core events out to all registered room listeners.
+struct RoomBridgeListener {
+    room_id: String,
+    listeners: Arc<RwLock<Vec<Arc<dyn RoomEventListener>>>>,
+}
+
+impl visio_core::VisioEventListener for RoomBridgeListener {
+    fn on_event(&self, event: CoreVisioEvent) {
+        let listeners = self.listeners.read().unwrap_or_else(|e| e.into_inner());
+        for listener in listeners.iter() {
+            listener.on_room_event(self.room_id.clone(), event.clone().into());
+        }
+    }
+}
+
+/// Stops the renderer of every track the core reports unsubscribed,
+/// including those of participants who left abruptly, without waiting for
+/// the platform view to detach.
+struct RendererCleanup;
+
+impl visio_core::VisioEventListener for RendererCleanup {
+    fn on_event(&self, event: CoreVisioEvent) {
+        if let CoreVisioEvent::TrackUnsubscribed(track_sid) = event {
+            visio_video::stop_track_renderer(&track_sid);
+        }
+    }
+}
+
+// ── Rooms ─────────────────────────────────────────────────────────────
+
+/// Id of the room created with the client, used when no room id is given.
+pub const DEFAULT_ROOM_ID: &str = "default";
+
+/// One room connection and the services bound to it.
+/// Whether one of `rooms` is connected or connecting.
+async fn any_room_joined(rooms: Vec<Arc<RoomSlot>>) -> bool {
+    for slot in rooms {
+        if slot.room_manager.connection_state().await != CoreConnectionState::Disconnected {
+            return true;
+        }
+    }
+    false
+}
+
+struct RoomSlot {
+    room_manager: visio_core::RoomManager,
+    controls: visio_core::MeetingControls,
+    chat: visio_core::ChatService,
+}
+
+impl RoomSlot {
+    fn new(room_id: &str, room_listeners: &Arc<RwLock<Vec<Arc<dyn RoomEventListener>>>>) -> Self {
+        let room_manager = visio_core::RoomManager::new();
+        room_manager.add_listener(Arc::new(RoomBridgeListener {
+            room_id: room_id.to_string(),
+            listeners: room_listeners.clone(),
+        }));
+        room_manager.add_listener(Arc::new(RendererCleanup));
+        let controls = room_manager.controls();
+        let chat = room_manager.chat();
+        Self {
+            room_manager,
+            controls,
+            chat,
+        }
+    }
+
+    /// Apply the settings every room follows, to the default room and to
+    /// each room opened with `join()`.
+    fn configure(
+        &self,
+        room_id: &str,
+        settings: &visio_core::Settings,
+        data_paths: &visio_core::DataPaths,
+    ) {
+        let room_manager = &self.room_manager;
+        room_manager.set_hand_auto_lower(settings.hand_auto_lower());
+        room_manager.set_idle_config(settings.idle());
+        room_manager.set_quality_policy_config(settings.quality_policy());
+        room_manager.metrics().apply_settings(settings);
+        room_manager.feedback().apply_settings(settings);
+        room_manager.system_messages().apply_settings(settings);
+        room_manager.set_rtc_config(settings.rtc_config.clone());
+        room_manager.set_audio_preset(settings.audio_preset);
+        room_manager
+            .journal()
+            .set_file(Some(journal_file(data_paths, room_id)));
+    }
+}
+
+fn unknown_room(room_id: &str) -> VisioError {
+    visio_core::VisioError::Room(format!("unknown room: {room_id}")).into()
+}
+
+async fn validate_room_result(
+    url: String,
+    username: Option<String>,
+    cache: visio_core::CacheService,
+) -> RoomValidationResult {
+    if let Err(e) = visio_core::AuthService::extract_slug(&url) {
+        return RoomValidationResult::InvalidFormat {
+            message: e.to_string(),
+        };
+    }
+    match visio_core::AuthService::validate_room(&url, username.as_deref(), None).await {
+        Ok(token_info) => {
+            cache.store_room_info(&url, &visio_core::RoomInfo::from(&token_info));
+            RoomValidationResult::Valid {
+                livekit_url: token_info.livekit_url,
+                token: token_info.token,
+            }
+        }
+        Err(visio_core::VisioError::Auth(msg)) if msg.contains("404") => {
+            RoomValidationResult::NotFound
+        }
+        Err(e) => RoomValidationResult::NetworkError {
+            message: e.to_string(),
+        },
+    }
+}
+
+// ── Video renderer lookup ─────────────────────────────────────────────
+
+type RoomMap = Arc<StdMutex<HashMap<String, Arc<RoomSlot>>>>;
+
+/// What the renderer entry points need from a client: its rooms and its
+/// runtime. Shared with the JNI registry so a JNI call never reaches a
+/// client that was dropped.
+struct VideoContext {
+    rooms: RoomMap,
+    rt: tokio::runtime::Handle,
+    tasks: Arc<ClientTasks>,
+}
+
+impl VideoContext {
+    /// Look up a subscribed video track in any room and start a renderer on
+    /// the surface returned by `surface`. The surface is only requested if
+    /// the track exists. Returns whether a renderer was started.
+    fn start_renderer(
+        &self,
+        track_sid: &str,
+        surface: impl FnOnce() -> *mut std::ffi::c_void,
+    ) -> bool {
+        let rooms: Vec<_> = self
+            .rooms
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .values()
+            .cloned()
+            .collect();
+        let found = self.rt.block_on(async {
+            for room in &rooms {
+                if let Some(track) = room.room_manager.get_video_track(track_sid).await {
+                    return Some((track, room.room_manager.track_recovery()));
+                }
+            }
+            None
+        });
+        match found {
+            Some((video_track, recovery)) => {
+                visio_video::start_track_renderer(
+                    track_sid.to_string(),
+                    video_track,
+                    surface(),
+                    Some(self.rt.clone()),
+                    Some(self.stream_health_handler(recovery)),
+                );
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Forward renderer stall reports to the room's `TrackRecovery`, on the
+    /// client runtime.
+    fn stream_health_handler(
+        &self,
+        recovery: visio_core::TrackRecovery,
+    ) -> visio_video::StreamHealthHandler {
+        let (rt, tasks) = (self.rt.clone(), self.tasks.clone());
+        Arc::new(move |track_sid, health| {
+            let recovery = recovery.clone();
+            let sid = track_sid.to_string();
+            tasks.spawn(&rt, async move {
+                match health {
+                    visio_video::StreamHealth::Stalled => recovery.track_stalled(&sid).await,
+                    visio_video::StreamHealth::Resumed => recovery.track_resumed(&sid).await,
+                };
+            });
+        })
+    }
+}
+
+/// Tasks the client spawns itself, aborted and awaited by `shutdown()`.
+#[derive(Default)]
+struct ClientTasks(StdMutex<tokio::task::JoinSet<()>>);
+
+impl ClientTasks {
+    fn spawn(
+        &self,
+        rt: &tokio::runtime::Handle,
+        task: impl std::future::Future<Output = ()> + Send + 'static,
+    ) {
+        let mut set = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        // Reap finished tasks so the set only holds running ones.
+        while set.try_join_next().is_some() {}
+        set.spawn_on(task, rt);
+    }
+
+    /// Abort the running tasks and wait until they are gone.
+    async fn shutdown(&self) {
+        let mut set = std::mem::take(&mut *self.0.lock().unwrap_or_else(|e| e.into_inner()));
+        set.shutdown().await;
+    }
+}
+
+/// Live clients by id, for the JNI video functions which cannot be handed
+/// a client. Weak: the registry never keeps a client's rooms alive.
+static VIDEO_CLIENTS: std::sync::LazyLock<StdMutex<HashMap<u64, std::sync::Weak<VideoContext>>>> =
+    std::sync::LazyLock::new(|| StdMutex::new(HashMap::new()));
+
+/// Id of the client whose rooms `attachSurface` looks tracks up in (the
+/// last one connected), 0 for none.
+static ACTIVE_VIDEO_CLIENT: AtomicU64 = AtomicU64::new(0);
+
+static NEXT_CLIENT_ID: AtomicU64 = AtomicU64::new(1);
+
+fn register_video_client(id: u64, context: &Arc<VideoContext>) {
+    VIDEO_CLIENTS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(id, Arc::downgrade(context));
+}
+
+fn unregister_video_client(id: u64) {
+    let _ = ACTIVE_VIDEO_CLIENT.compare_exchange(id, 0, Ordering::AcqRel, Ordering::Acquire);
+    VIDEO_CLIENTS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .remove(&id);
+}
+
+/// The active client's video context, if that client is still alive.
+fn active_video_client() -> Option<Arc<VideoContext>> {
+    let id = ACTIVE_VIDEO_CLIENT.load(Ordering::Acquire);
+    if id == 0 {
+        return None;
+    }
+    VIDEO_CLIENTS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(&id)
+        .and_then(std::sync::Weak::upgrade)
+}
+
+// ── VisioClient: main FFI object ──────────────────────────────────────
+
+pub struct VisioClient {
+    client_id: u64,
+    rooms: RoomMap,
+    video: Arc<VideoContext>,
+    room_listeners: Arc<RwLock<Vec<Arc<dyn RoomEventListener>>>>,
+    next_room_id: AtomicU64,
+    /// Applied to every room, including those opened later with `join()`.
+    connect_timeouts: StdMutex<visio_core::ConnectTimeouts>,
+    join_leave: StdMutex<visio_core::JoinLeaveConfig>,
+    speaker_hold: StdMutex<std::time::Duration>,
+    power: StdMutex<visio_core::PowerState>,
+    device_permissions:
+        StdMutex<HashMap<visio_core::DevicePermission, visio_core::PermissionState>>,
+    queue_media: AtomicBool,
+    output_volume: StdMutex<f32>,
+    output_muted: AtomicBool,
+    settings: visio_core::SettingsStore,
+    cache: visio_core::CacheService,
+    data_paths: visio_core::DataPaths,
+    camera_control: visio_core::CameraControl,
+    /// Set by `shutdown()`.
+    shut_down: AtomicBool,
+    tasks: Arc<ClientTasks>,
+    rt: tokio::runtime::Handle,
+    /// Owner of `rt`, shut down by `shutdown()`.
+    runtime: StdMutex<Option<tokio::runtime::Runtime>>,
+}
+
+/// How long `shutdown()` waits for the tasks of the rooms to finish.
+const SHUTDOWN_DRAIN_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(2);
+
+/// Event journal file of a room, one per room so rooms do not interleave.
+fn journal_file(paths: &visio_core::DataPaths, room_id: &str) -> std::path::PathBuf {
+    paths.logs_dir().join(format!("journal-{room_id}.jsonl"))
+}
+
+impl VisioClient {
     pub fn new(data_dir: String) -> Self {
         visio_log("VISIO FFI: VisioClient::new() called");
         let rt = tokio::runtime::Runtime::new().expect("failed to create tokio runtime");
         visio_log("VISIO FFI: tokio runtime created successfully");
         let settings = visio_core::SettingsStore::new(&data_dir);
-        let room_manager = visio_core::RoomManager::new();
+        let data_paths = visio_core::DataPaths::new(&data_dir);
+        let cache = visio_core::CacheService::new(&data_paths);
+        let room_listeners = Arc::new(RwLock::new(Vec::new()));
+        let default_room = RoomSlot::new(DEFAULT_ROOM_ID, &room_listeners);
+        default_room.configure(DEFAULT_ROOM_ID, &settings.get(), &data_paths);
+        visio_core::audio_ducking::audio_ducker().set_config(settings.get().audio_ducking());
+        visio_core::auto_gain::auto_gain().set_config(settings.get().auto_gain());
+        visio_core::video_overlay().set_text(
+            settings.get().video_overlay_text,
+            settings.get().video_overlay_corner,
+        );
+        visio_core::video_privacy().set_style(settings.get().video_privacy_style);
 
         // Store playout buffer for Android JNI audio pull
         #[cfg(target_os = "android")]
         {
-            let buf = room_manager.playout_buffer();
+            let buf = default_room.room_manager.playout_buffer();
             *PLAYOUT_BUFFER.lock().unwrap() = Some(buf);
             visio_log("VISIO FFI: playout buffer stored for Android audio output");
         }
@@ -408,48 +2563,277 @@ impl VisioClient {
         // Store playout buffer for iOS C FFI audio pull
         #[cfg(target_os = "ios")]
         {
-            let buf = room_manager.playout_buffer();
+            let buf = default_room.room_manager.playout_buffer();
             *PLAYOUT_BUFFER_IOS.lock().unwrap() = Some(buf);
             visio_log("VISIO FFI: playout buffer stored for iOS audio output");
         }
 
-        let controls = room_manager.controls();
-        let chat = room_manager.chat();
+        let mut rooms = HashMap::new();
+        rooms.insert(DEFAULT_ROOM_ID.to_string(), Arc::new(default_room));
+        let rooms = Arc::new(StdMutex::new(rooms));
+        let tasks = Arc::new(ClientTasks::default());
+        let video = Arc::new(VideoContext {
+            rooms: rooms.clone(),
+            rt: rt.handle().clone(),
+            tasks: tasks.clone(),
+        });
+        let client_id = NEXT_CLIENT_ID.fetch_add(1, Ordering::Relaxed);
+        register_video_client(client_id, &video);
 
         visio_log("VISIO FFI: VisioClient::new() completed");
         Self {
-            room_manager,
-            controls,
-            chat,
+            client_id,
+            rooms,
+            video,
+            room_listeners,
+            next_room_id: AtomicU64::new(1),
+            connect_timeouts: StdMutex::new(visio_core::ConnectTimeouts::default()),
+            join_leave: StdMutex::new(visio_core::JoinLeaveConfig::default()),
+            speaker_hold: StdMutex::new(visio_core::DEFAULT_SPEAKER_HOLD),
+            power: StdMutex::new(visio_core::PowerState::default()),
+            device_permissions: StdMutex::new(HashMap::new()),
+            queue_media: AtomicBool::new(false),
+            output_volume: StdMutex::new(1.0),
+            output_muted: AtomicBool::new(false),
             settings,
-            rt,
+            cache,
+            data_paths,
+            camera_control: visio_core::CameraControl::new(),
+            shut_down: AtomicBool::new(false),
+            tasks,
+            rt: rt.handle().clone(),
+            runtime: StdMutex::new(Some(rt)),
+        }
+    }
+
+    /// Resolve a room id (default room when `None`).
+    fn room(&self, room_id: Option<&str>) -> Option<Arc<RoomSlot>> {
+        let id = room_id.unwrap_or(DEFAULT_ROOM_ID);
+        self.rooms
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(id)
+            .cloned()
+    }
+
+    fn room_or_err(&self, room_id: Option<&str>) -> Result<Arc<RoomSlot>, VisioError> {
+        self.room(room_id)
+            .ok_or_else(|| unknown_room(room_id.unwrap_or(DEFAULT_ROOM_ID)))
+    }
+
+    fn all_rooms(&self) -> Vec<Arc<RoomSlot>> {
+        self.rooms
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .values()
+            .cloned()
+            .collect()
+    }
+
+    /// Open an additional room and connect to it. Returns the new room id.
+    ///
+    /// Events for the new room are delivered to room listeners
+    /// (`add_room_listener`) only. Remote audio of additional rooms is not
+    /// routed to the platform playout, which stays bound to the default room.
+    pub fn join(&self, meet_url: String, username: Option<String>) -> Result<String, VisioError> {
+        if self.shut_down.load(Ordering::Acquire) {
+            return Err(visio_core::VisioError::Room("client is shut down".into()).into());
+        }
+        let room_id = format!("room-{}", self.next_room_id.fetch_add(1, Ordering::Relaxed));
+        let slot = Arc::new(RoomSlot::new(&room_id, &self.room_listeners));
+        let timeouts = *self
+            .connect_timeouts
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        self.rt
+            .block_on(slot.room_manager.set_connect_timeouts(timeouts));
+        let join_leave = *self.join_leave.lock().unwrap_or_else(|e| e.into_inner());
+        slot.room_manager.set_join_leave_config(join_leave);
+        let hold = *self.speaker_hold.lock().unwrap_or_else(|e| e.into_inner());
+        self.rt
+            .block_on(slot.room_manager.set_active_speaker_hold(hold));
+        let power = *self.power.lock().unwrap_or_else(|e| e.into_inner());
+        self.rt
+            .block_on(slot.room_manager.set_power_mode(power.mode));
+        self.rt
+            .block_on(slot.room_manager.report_thermal_state(power.thermal));
+        let device_permissions = slot.room_manager.device_permissions();
+        for (permission, state) in self
+            .device_permissions
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+        {
+            device_permissions.set(*permission, *state);
+        }
+        slot.controls
+            .set_queue_until_connected(self.queue_media.load(Ordering::Relaxed));
+        slot.room_manager
+            .set_output_volume(*self.output_volume.lock().unwrap_or_else(|e| e.into_inner()));
+        slot.room_manager
+            .set_output_muted(self.output_muted.load(Ordering::Relaxed));
+        slot.configure(&room_id, &self.settings.get(), &self.data_paths);
+        self.rooms
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(room_id.clone(), slot);
+
+        match self.connect(meet_url, username, Some(room_id.clone())) {
+            Ok(()) => Ok(room_id),
+            Err(e) => {
+                self.rooms
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .remove(&room_id);
+                Err(e)
+            }
+        }
+    }
+
+    /// Disconnect a room opened with `join()` and forget it.
+    /// Leaving the default room only disconnects it.
+    pub fn leave(&self, room_id: String) -> Result<(), VisioError> {
+        if room_id == DEFAULT_ROOM_ID {
+            self.disconnect(None);
+            return Ok(());
+        }
+        let slot = self
+            .rooms
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(&room_id)
+            .ok_or_else(|| unknown_room(&room_id))?;
+        self.rt.block_on(slot.room_manager.disconnect());
+        self.reset_shared_media_if_idle(&slot);
+        Ok(())
+    }
+
+    /// Reset the media state shared by the rooms (see
+    /// `RoomManager::reset_shared_media`) once `left` was the last room
+    /// joined.
+    fn reset_shared_media_if_idle(&self, left: &RoomSlot) {
+        if !self.rt.block_on(any_room_joined(self.all_rooms())) {
+            left.room_manager.reset_shared_media();
+        }
+    }
+
+    /// Release everything the client holds before the host drops it, e.g.
+    /// when Android recreates the Activity: leaves every room, stops the
+    /// video renderers, clears the platform audio and preview hooks, aborts
+    /// the tasks the client spawned and shuts its runtime down, giving the
+    /// tasks of the rooms up to `SHUTDOWN_DRAIN_TIMEOUT`. Rooms are gone
+    /// afterwards, so later calls fail or do nothing. Idempotent.
+    pub fn shutdown(&self) {
+        if self.shut_down.swap(true, Ordering::AcqRel) {
+            return;
         }
+        visio_log("VISIO FFI: shutting down client");
+        self.before_disconnect(None);
+        let rooms: HashMap<String, Arc<RoomSlot>> =
+            std::mem::take(&mut *self.rooms.lock().unwrap_or_else(|e| e.into_inner()));
+        for slot in rooms.values() {
+            self.rt.block_on(slot.room_manager.disconnect());
+        }
+        if let Some(slot) = rooms.values().next() {
+            slot.room_manager.reset_shared_media();
+        }
+        visio_video::stop_all_renderers();
+        unregister_video_client(self.client_id);
+
+        // Platform playout pulls from the default room's buffer.
+        let playout = rooms
+            .get(DEFAULT_ROOM_ID)
+            .map(|slot| slot.room_manager.playout_buffer());
+        #[cfg(target_os = "android")]
+        release_playout_buffer(&PLAYOUT_BUFFER, playout.as_ref());
+        #[cfg(target_os = "ios")]
+        release_playout_buffer(&PLAYOUT_BUFFER_IOS, playout.as_ref());
+        drop(playout);
+
+        self.rt.block_on(self.tasks.shutdown());
+        let runtime = self
+            .runtime
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .take();
+        if let Some(runtime) = runtime {
+            runtime.shutdown_timeout(SHUTDOWN_DRAIN_TIMEOUT);
+        }
+        visio_log("VISIO FFI: client shut down");
+    }
+
+    /// Ids of all rooms held by this client, default room first.
+    pub fn room_ids(&self) -> Vec<String> {
+        let mut ids: Vec<String> = self
+            .rooms
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .keys()
+            .filter(|id| id.as_str() != DEFAULT_ROOM_ID)
+            .cloned()
+            .collect();
+        ids.sort();
+        ids.insert(0, DEFAULT_ROOM_ID.to_string());
+        ids
     }
 
-    pub fn connect(&self, meet_url: String, username: Option<String>) -> Result<(), VisioError> {
+    /// Register a listener receiving events from all rooms, tagged with the room id.
+    pub fn add_room_listener(&self, listener: Box<dyn RoomEventListener>) {
+        self.room_listeners
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(Arc::from(listener));
+    }
+
+    pub fn connect(
+        &self,
+        meet_url: String,
+        username: Option<String>,
+        room_id: Option<String>,
+    ) -> Result<(), VisioError> {
+        let options = JoinOptions {
+            username,
+            ..self.join_options()
+        };
+        self.connect_with_options(meet_url, options, room_id)
+    }
+
+    /// Options `connect()` joins with: the mic/camera-on-join settings.
+    pub fn join_options(&self) -> JoinOptions {
+        visio_core::JoinOptions::from_settings(&self.settings.get()).into()
+    }
+
+    pub fn connect_with_options(
+        &self,
+        meet_url: String,
+        options: JoinOptions,
+        room_id: Option<String>,
+    ) -> Result<(), VisioError> {
         visio_log(&format!("VISIO FFI: connect() entered, url={meet_url}"));
+        let room = self.room_or_err(room_id.as_deref())?;
+        // Media requested by the options is enabled by the core once connected.
+        let options = visio_core::JoinOptions::from(options);
 
         // Wrap in catch_unwind to prevent panics from crossing FFI boundary (UB → SIGSEGV).
         let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
             visio_log("VISIO FFI: about to call block_on");
             let res = self.rt.block_on(async {
                 visio_log("VISIO FFI: inside block_on async block");
-                self.room_manager
-                    .connect(&meet_url, username.as_deref())
+                room.room_manager
+                    .connect(&meet_url, &options)
                     .await
                     .map_err(VisioError::from)
             });
-            visio_log(&format!("VISIO FFI: block_on completed, success={}", res.is_ok()));
+            visio_log(&format!(
+                "VISIO FFI: block_on completed, success={}",
+                res.is_ok()
+            ));
             res
         }));
 
         match result {
             Ok(Ok(())) => {
-                // Store self pointer for JNI video attach/detach
-                #[cfg(target_os = "android")]
-                {
-                    *CLIENT_FOR_VIDEO.lock().unwrap() = self as *const VisioClient as usize;
-                }
+                self.after_connect();
                 Ok(())
             }
             Ok(Err(e)) => Err(e),
@@ -462,125 +2846,675 @@ impl VisioClient {
                     "unknown panic".to_string()
                 };
                 visio_log(&format!("VISIO FFI: connect() PANIC caught: {msg}"));
-                Err(VisioError::Connection { msg: format!("panic in connect: {msg}") })
+                Err(visio_core::VisioError::Connection(format!("panic in connect: {msg}")).into())
             }
         }
     }
 
-    pub fn disconnect(&self) {
-        // Clear the client pointer BEFORE disconnecting so no JNI call
-        // can dereference a stale pointer while teardown is in progress.
+    pub fn disconnect(&self, room_id: Option<String>) {
+        let Some(room) = self.room(room_id.as_deref()) else {
+            return;
+        };
+        self.before_disconnect(room_id.as_deref());
+        self.rt.block_on(room.room_manager.disconnect());
+        self.reset_shared_media_if_idle(&room);
+    }
+
+    /// Report the call the user started, for the OS call UI (CallKit
+    /// `CXStartCallAction`, ConnectionService outgoing connection). Its
+    /// changes follow as `SystemCallChanged` events.
+    pub fn report_outgoing_call(
+        &self,
+        room: String,
+        display_name: String,
+        room_id: Option<String>,
+    ) -> Result<SystemCall, VisioError> {
+        self.report_call(
+            &room,
+            &display_name,
+            visio_core::CallDirection::Outgoing,
+            room_id,
+        )
+    }
+
+    /// Report a call joined from an invitation or a ring (CallKit
+    /// `reportNewIncomingCall`, ConnectionService incoming connection).
+    pub fn report_incoming_call(
+        &self,
+        room: String,
+        display_name: String,
+        room_id: Option<String>,
+    ) -> Result<SystemCall, VisioError> {
+        self.report_call(
+            &room,
+            &display_name,
+            visio_core::CallDirection::Incoming,
+            room_id,
+        )
+    }
+
+    fn report_call(
+        &self,
+        room: &str,
+        display_name: &str,
+        direction: visio_core::CallDirection,
+        room_id: Option<String>,
+    ) -> Result<SystemCall, VisioError> {
+        let slot = self.room_or_err(room_id.as_deref())?;
+        let call = self
+            .rt
+            .block_on(slot.room_manager.report_call(room, display_name, direction));
+        Ok(call.into())
+    }
+
+    /// The OS ended the call (hang-up from the lock screen, Bluetooth
+    /// headset or system call UI): leave the room.
+    pub fn end_call(&self, room_id: Option<String>) {
+        self.disconnect(room_id);
+    }
+
+    /// Forward a Bluetooth or wired headset button press. Returns whether
+    /// it was handled (not a repeat, and in a call); handled presses emit
+    /// `MediaButtonHandled`.
+    pub fn handle_media_button(
+        &self,
+        button: MediaButton,
+        room_id: Option<String>,
+    ) -> Result<bool, VisioError> {
+        let slot = self.room_or_err(room_id.as_deref())?;
+        if button == MediaButton::Hangup {
+            self.before_disconnect(room_id.as_deref());
+        }
+        Ok(self
+            .rt
+            .block_on(slot.room_manager.handle_media_button(button.into()))?)
+    }
+
+    /// The reported call, until it ends.
+    pub fn system_call(&self, room_id: Option<String>) -> Option<SystemCall> {
+        self.room(room_id.as_deref())?
+            .room_manager
+            .system_call()
+            .map(Into::into)
+    }
+
+    /// Milliseconds left before the meeting's scheduled end, `None` when
+    /// it has none (or not in a room).
+    pub fn get_time_remaining(&self, room_id: Option<String>) -> Option<u64> {
+        self.room(room_id.as_deref())?
+            .room_manager
+            .time_remaining()
+            .map(|left| left.as_millis() as u64)
+    }
+
+    /// Store self pointer for JNI video attach/detach.
+    fn after_connect(&self) {
+        ACTIVE_VIDEO_CLIENT.store(self.client_id, Ordering::Release);
+    }
+
+    /// Stop routing JNI surface attachments to this client BEFORE
+    /// disconnecting, so none races with the teardown.
+    fn before_disconnect(&self, room_id: Option<&str>) {
+        if room_id.unwrap_or(DEFAULT_ROOM_ID) != DEFAULT_ROOM_ID {
+            return;
+        }
+        let _ = ACTIVE_VIDEO_CLIENT.compare_exchange(
+            self.client_id,
+            0,
+            Ordering::AcqRel,
+            Ordering::Acquire,
+        );
         #[cfg(target_os = "android")]
         {
-            *CLIENT_FOR_VIDEO.lock().unwrap() = 0;
             // Release the local preview surface (detachSurface is a no-op for
             // local-camera to avoid a recomposition race, so we clean up here).
             LOCAL_PREVIEW_SURFACE.lock().unwrap().take();
         }
-        self.rt.block_on(self.room_manager.disconnect());
     }
 
-    pub fn reconnect(&self) -> Result<(), VisioError> {
-        self.rt
-            .block_on(self.room_manager.reconnect())
-            .map_err(Into::into)
+    /// Set the Meet API / LiveKit connection timeouts for all rooms.
+    pub fn set_connect_timeouts(&self, timeouts: ConnectTimeouts) {
+        let timeouts = visio_core::ConnectTimeouts::from(timeouts);
+        *self
+            .connect_timeouts
+            .lock()
+            .unwrap_or_else(|e| e.into_inner()) = timeouts;
+        for room in self.all_rooms() {
+            self.rt
+                .block_on(room.room_manager.set_connect_timeouts(timeouts));
+        }
+    }
+
+    pub fn connect_timeouts(&self) -> ConnectTimeouts {
+        (*self
+            .connect_timeouts
+            .lock()
+            .unwrap_or_else(|e| e.into_inner()))
+        .into()
+    }
+
+    /// Configure participant join/leave event coalescing for all rooms.
+    pub fn set_join_leave_config(&self, config: JoinLeaveConfig) {
+        let config = visio_core::JoinLeaveConfig::from(config);
+        *self.join_leave.lock().unwrap_or_else(|e| e.into_inner()) = config;
+        for room in self.all_rooms() {
+            room.room_manager.set_join_leave_config(config);
+        }
+    }
+
+    pub fn join_leave_config(&self) -> JoinLeaveConfig {
+        (*self.join_leave.lock().unwrap_or_else(|e| e.into_inner())).into()
+    }
+
+    /// Set how long speakers stay active after they stop talking, for all
+    /// rooms. 0 disables smoothing.
+    pub fn set_active_speaker_hold(&self, hold_ms: u64) {
+        let hold = std::time::Duration::from_millis(hold_ms);
+        *self.speaker_hold.lock().unwrap_or_else(|e| e.into_inner()) = hold;
+        for room in self.all_rooms() {
+            self.rt
+                .block_on(room.room_manager.set_active_speaker_hold(hold));
+        }
+    }
+
+    pub fn active_speaker_hold_ms(&self) -> u64 {
+        self.speaker_hold
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .as_millis() as u64
+    }
+
+    /// Set the volume of all remote audio (0.0 to 1.0), for all rooms.
+    pub fn set_output_volume(&self, volume: f32) {
+        *self.output_volume.lock().unwrap_or_else(|e| e.into_inner()) = volume;
+        for room in self.all_rooms() {
+            room.room_manager.set_output_volume(volume);
+        }
+    }
+
+    /// Volume applied to the default room, as clamped by the core.
+    pub fn output_volume(&self) -> f32 {
+        self.room(None)
+            .map(|room| room.room_manager.output_volume())
+            .unwrap_or(1.0)
+    }
+
+    /// Silence all remote audio ("speaker off") in all rooms, keeping the
+    /// volume.
+    pub fn set_output_muted(&self, muted: bool) {
+        self.output_muted.store(muted, Ordering::Relaxed);
+        for room in self.all_rooms() {
+            room.room_manager.set_output_muted(muted);
+        }
+    }
+
+    pub fn is_output_muted(&self) -> bool {
+        self.output_muted.load(Ordering::Relaxed)
+    }
+
+    /// Switch all rooms and video renderers between normal and battery
+    /// saver operation. Each room emits `PowerModeChanged`.
+    pub fn set_power_mode(&self, mode: PowerMode) {
+        let mode = visio_core::PowerMode::from(mode);
+        self.update_renderer_limits(|p| p.mode = mode);
+        for room in self.all_rooms() {
+            self.rt.block_on(room.room_manager.set_power_mode(mode));
+        }
+    }
+
+    pub fn power_mode(&self) -> PowerMode {
+        self.power
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .mode
+            .into()
+    }
+
+    /// Forward the device thermal state from platform APIs. Capture and
+    /// rendering step down as the device heats up; rooms emit
+    /// `QualityReduced` and, back at `Nominal`, `QualityRestored`.
+    pub fn report_thermal_state(&self, state: ThermalState) {
+        let thermal = visio_core::ThermalState::from(state);
+        self.update_renderer_limits(|p| p.thermal = thermal);
+        for room in self.all_rooms() {
+            self.rt
+                .block_on(room.room_manager.report_thermal_state(thermal));
+        }
+    }
+
+    /// Report an operating system permission as the shell learns it
+    /// (launch, permission prompt result, return from system settings).
+    /// Rooms refuse to publish a denied camera or microphone with
+    /// `PermissionDenied` and emit `DevicePermissionChanged`.
+    pub fn set_permission_state(&self, permission: DevicePermission, state: PermissionState) {
+        let (permission, state) = (permission.into(), state.into());
+        self.device_permissions
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(permission, state);
+        for room in self.all_rooms() {
+            room.room_manager
+                .device_permissions()
+                .set(permission, state);
+        }
+    }
+
+    pub fn permission_state(&self, permission: DevicePermission) -> PermissionState {
+        self.device_permissions
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(&permission.into())
+            .copied()
+            .unwrap_or_default()
+            .into()
+    }
+
+    /// Update the client power state and apply it to the (process-wide)
+    /// video renderers.
+    fn update_renderer_limits(&self, update: impl FnOnce(&mut visio_core::PowerState)) {
+        let power = {
+            let mut power = self.power.lock().unwrap_or_else(|e| e.into_inner());
+            update(&mut power);
+            *power
+        };
+        visio_video::set_max_render_fps(power.max_render_fps());
+        visio_video::set_self_view_enabled(power.mode.self_view_enabled());
+    }
+
+    /// Move a connected room to another meeting without a disconnected gap.
+    pub fn switch_room(
+        &self,
+        meet_url: String,
+        username: Option<String>,
+        room_id: Option<String>,
+    ) -> Result<(), VisioError> {
+        let room = self.room_or_err(room_id.as_deref())?;
+        self.rt
+            .block_on(
+                room.room_manager
+                    .switch_room(&meet_url, username.as_deref()),
+            )
+            .map_err(Into::into)
+    }
+
+    pub fn reconnect(&self, room_id: Option<String>) -> Result<(), VisioError> {
+        let room = self.room_or_err(room_id.as_deref())?;
+        self.rt
+            .block_on(room.room_manager.reconnect())
+            .map_err(Into::into)
+    }
+
+    pub fn connection_state(&self, room_id: Option<String>) -> ConnectionState {
+        match self.room(room_id.as_deref()) {
+            Some(room) => self
+                .rt
+                .block_on(room.room_manager.connection_state())
+                .into(),
+            None => ConnectionState::Disconnected,
+        }
+    }
+
+    /// What the local participant may do in the room, or `None` when not
+    /// connected.
+    pub fn get_local_permissions(&self, room_id: Option<String>) -> Option<LocalPermissions> {
+        let room = self.room(room_id.as_deref())?;
+        self.rt
+            .block_on(room.room_manager.local_permissions())
+            .map(LocalPermissions::from)
+    }
+
+    pub fn get_feature_flags(&self, room_id: Option<String>) -> FeatureFlags {
+        self.room(room_id.as_deref())
+            .map(|room| self.rt.block_on(room.room_manager.feature_flags()))
+            .unwrap_or_default()
+            .into()
+    }
+
+    pub fn participants(&self, room_id: Option<String>) -> Vec<ParticipantInfo> {
+        let Some(room) = self.room(room_id.as_deref()) else {
+            return Vec::new();
+        };
+        self.rt
+            .block_on(room.room_manager.participants())
+            .into_iter()
+            .map(ParticipantInfo::from)
+            .collect()
+    }
+
+    pub fn active_speakers(&self, room_id: Option<String>) -> Vec<String> {
+        match self.room(room_id.as_deref()) {
+            Some(room) => self.rt.block_on(room.room_manager.active_speakers()),
+            None => Vec::new(),
+        }
+    }
+
+    /// Participant for the spotlight tile; stable across short pauses.
+    pub fn dominant_speaker(&self, room_id: Option<String>) -> Option<String> {
+        self.room(room_id.as_deref())
+            .and_then(|room| self.rt.block_on(room.room_manager.dominant_speaker()))
+    }
+
+    /// Remote participant SIDs in shared display order.
+    pub fn layout_order(&self, room_id: Option<String>) -> Vec<String> {
+        self.room(room_id.as_deref())
+            .map(|room| room.room_manager.layout().ordered_participants())
+            .unwrap_or_default()
+    }
+
+    pub fn pin_participant(&self, participant_sid: String, room_id: Option<String>) {
+        if let Some(room) = self.room(room_id.as_deref()) {
+            room.room_manager.layout().pin_participant(&participant_sid);
+        }
+    }
+
+    pub fn unpin_participant(&self, participant_sid: String, room_id: Option<String>) {
+        if let Some(room) = self.room(room_id.as_deref()) {
+            room.room_manager
+                .layout()
+                .unpin_participant(&participant_sid);
+        }
+    }
+
+    pub fn pinned_participants(&self, room_id: Option<String>) -> Vec<String> {
+        self.room(room_id.as_deref())
+            .map(|room| room.room_manager.layout().pinned_participants())
+            .unwrap_or_default()
+    }
+
+    pub fn set_microphone_enabled(
+        &self,
+        enabled: bool,
+        room_id: Option<String>,
+    ) -> Result<(), VisioError> {
+        let room = self.room_or_err(room_id.as_deref())?;
+        self.rt.block_on(async {
+            room.controls
+                .set_microphone_enabled(enabled)
+                .await
+                .map_err(VisioError::from)
+        })
+    }
+
+    pub fn set_camera_enabled(
+        &self,
+        enabled: bool,
+        room_id: Option<String>,
+    ) -> Result<(), VisioError> {
+        let room = self.room_or_err(room_id.as_deref())?;
+        self.rt.block_on(async {
+            room.controls
+                .set_camera_enabled(enabled)
+                .await
+                .map_err(VisioError::from)?;
+
+            if !enabled {
+                self.camera_control.reset();
+            }
+            Ok(())
+        })
+    }
+
+    pub fn is_microphone_enabled(&self, room_id: Option<String>) -> bool {
+        self.room(room_id.as_deref())
+            .is_some_and(|room| self.rt.block_on(room.controls.is_microphone_enabled()))
+    }
+
+    pub fn is_camera_enabled(&self, room_id: Option<String>) -> bool {
+        self.room(room_id.as_deref())
+            .is_some_and(|room| self.rt.block_on(room.controls.is_camera_enabled()))
+    }
+
+    /// Hide the outgoing video behind a blurred frame or a card, keeping
+    /// the camera track published. Emits `VideoPrivacyChanged`.
+    pub fn set_video_privacy(
+        &self,
+        enabled: bool,
+        room_id: Option<String>,
+    ) -> Result<(), VisioError> {
+        let room = self.room_or_err(room_id.as_deref())?;
+        room.controls.set_video_privacy(enabled);
+        Ok(())
+    }
+
+    pub fn is_video_privacy_enabled(&self) -> bool {
+        visio_core::video_privacy().is_enabled()
+    }
+
+    /// What replaces the outgoing video in privacy mode. Persisted.
+    pub fn set_video_privacy_style(&self, style: VideoPrivacyStyle) {
+        self.settings.set_video_privacy_style(style.into());
+        visio_core::video_privacy().set_style(style.into());
+    }
+
+    /// Queue microphone/camera toggles made before a room connects instead
+    /// of failing with "not connected"; each room applies the last
+    /// requested state right after connecting. Applies to all rooms.
+    pub fn set_queue_media_until_connected(&self, enabled: bool) {
+        self.queue_media.store(enabled, Ordering::Relaxed);
+        for room in self.all_rooms() {
+            room.controls.set_queue_until_connected(enabled);
+        }
+    }
+
+    pub fn queue_media_until_connected(&self) -> bool {
+        self.queue_media.load(Ordering::Relaxed)
+    }
+
+    /// Microphone/camera toggles waiting for the room to connect.
+    pub fn pending_media(&self, room_id: Option<String>) -> PendingMedia {
+        self.room(room_id.as_deref())
+            .map(|room| room.controls.pending_media())
+            .unwrap_or_default()
+            .into()
+    }
+
+    /// Content hint for the next published camera track. Use `Detail` when
+    /// the camera points at slides or documents.
+    pub fn set_video_content_hint(&self, hint: VideoContentHint, room_id: Option<String>) {
+        if let Some(room) = self.room(room_id.as_deref()) {
+            self.rt
+                .block_on(room.controls.set_video_content_hint(hint.into()));
+        }
+    }
+
+    pub fn video_content_hint(&self, room_id: Option<String>) -> VideoContentHint {
+        match self.room(room_id.as_deref()) {
+            Some(room) => self.rt.block_on(room.controls.video_content_hint()).into(),
+            None => VideoContentHint::Motion,
+        }
+    }
+
+    /// Codec of the next published camera track. H264 is hardware
+    /// encoded on iOS and most Android devices.
+    pub fn set_video_codec(&self, codec: VideoCodec, room_id: Option<String>) {
+        if let Some(room) = self.room(room_id.as_deref()) {
+            room.controls.set_video_codec(codec.into());
+        }
+    }
+
+    pub fn video_codec(&self, room_id: Option<String>) -> VideoCodec {
+        match self.room(room_id.as_deref()) {
+            Some(room) => room.controls.video_codec().into(),
+            None => VideoCodec::Vp8,
+        }
+    }
+
+    /// Codecs actually used by the published and subscribed tracks.
+    pub fn get_negotiated_codecs(
+        &self,
+        room_id: Option<String>,
+    ) -> Result<NegotiatedCodecs, VisioError> {
+        let room = self.room_or_err(room_id.as_deref())?;
+        self.rt
+            .block_on(room.room_manager.negotiated_codecs())
+            .map(NegotiatedCodecs::from)
+            .map_err(VisioError::from)
+    }
+
+    /// Bitrate, packet loss and quality of a track over the last minutes,
+    /// one sample per second, oldest first.
+    pub fn get_quality_history(
+        &self,
+        track_sid: String,
+        room_id: Option<String>,
+    ) -> Vec<QualitySample> {
+        match self.room(room_id.as_deref()) {
+            Some(room) => room
+                .room_manager
+                .stats()
+                .quality_history(&track_sid)
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            None => Vec::new(),
+        }
     }
 
-    pub fn connection_state(&self) -> ConnectionState {
-        self.rt.block_on(self.room_manager.connection_state()).into()
+    /// Summary of the last call that ended in the room, for the post-call
+    /// rating screen. Rooms left with `leave` are gone: use the summary of
+    /// their `CallEnded` event.
+    pub fn get_last_call_summary(&self, room_id: Option<String>) -> Option<CallSummary> {
+        self.room(room_id.as_deref())?
+            .room_manager
+            .call_summary()
+            .last_summary()
+            .map(Into::into)
     }
 
-    pub fn participants(&self) -> Vec<ParticipantInfo> {
+    /// Rate the last call from 1 to 5 for its Meet instance. The call
+    /// summary, and the event journal with `attach_logs`, go along only if
+    /// feedback diagnostics are enabled in the settings.
+    pub fn submit_feedback(
+        &self,
+        rating: u8,
+        comments: Option<String>,
+        attach_logs: bool,
+        room_id: Option<String>,
+    ) -> Result<(), VisioError> {
+        let room = self.room_or_err(room_id.as_deref())?;
         self.rt
-            .block_on(self.room_manager.participants())
-            .into_iter()
-            .map(ParticipantInfo::from)
-            .collect()
+            .block_on(
+                room.room_manager
+                    .feedback()
+                    .submit_feedback(rating, comments, attach_logs),
+            )
+            .map_err(VisioError::from)
     }
 
-    pub fn active_speakers(&self) -> Vec<String> {
-        self.rt.block_on(self.room_manager.active_speakers())
+    /// Resolution, frame rate, decode time and freezes of a remote video
+    /// track, `None` until it has been received.
+    pub fn get_video_stats(
+        &self,
+        track_sid: String,
+        room_id: Option<String>,
+    ) -> Option<VideoStats> {
+        let received = self
+            .room(room_id.as_deref())
+            .and_then(|room| room.room_manager.stats().video_stats(&track_sid));
+        let rendered = visio_video::renderer_stats(&track_sid);
+        if received.is_none() && rendered.is_none() {
+            return None;
+        }
+        let received = received.unwrap_or_default();
+        let rendered = rendered.unwrap_or_default();
+        Some(VideoStats {
+            width: received.width,
+            height: received.height,
+            is_hd: received.is_hd(),
+            fps: received.fps,
+            avg_decode_ms: received.avg_decode_ms,
+            freeze_count: received.freeze_count,
+            render_fps: rendered.fps,
+            frames_dropped: rendered.frames_dropped,
+        })
     }
 
-    pub fn set_microphone_enabled(&self, enabled: bool) -> Result<(), VisioError> {
-        self.rt.block_on(async {
-            self.controls
-                .set_microphone_enabled(enabled)
-                .await
-                .map_err(VisioError::from)?;
+    /// Recent room events as JSON lines, oldest first, to attach to a bug
+    /// report (see `visio_core::EventJournal`).
+    pub fn export_journal(&self, room_id: Option<String>) -> String {
+        match self.room(room_id.as_deref()) {
+            Some(room) => room.room_manager.journal().export(),
+            None => String::new(),
+        }
+    }
 
-            #[cfg(target_os = "android")]
-            {
-                let mut guard = AUDIO_SOURCE.lock().unwrap();
-                if enabled {
-                    if let Some(source) = self.controls.audio_source().await {
-                        visio_log("VISIO FFI: audio source stored for JNI pipeline");
-                        *guard = Some(source);
-                    }
-                } else {
-                    visio_log("VISIO FFI: audio source cleared");
-                    *guard = None;
-                }
-            }
+    /// Target resolution, frame rate and low-light mode for camera capture.
+    pub fn get_capture_constraints(&self, room_id: Option<String>) -> CaptureConstraints {
+        match self.room(room_id.as_deref()) {
+            Some(room) => room.controls.capture_constraints().into(),
+            None => visio_core::CaptureConstraints::default().into(),
+        }
+    }
 
-            Ok(())
-        })
+    pub fn set_low_light_boost(&self, enabled: bool, room_id: Option<String>) {
+        if let Some(room) = self.room(room_id.as_deref()) {
+            room.controls.set_low_light_boost(enabled);
+        }
     }
 
-    pub fn set_camera_enabled(&self, enabled: bool) -> Result<(), VisioError> {
-        self.rt.block_on(async {
-            self.controls
-                .set_camera_enabled(enabled)
-                .await
-                .map_err(VisioError::from)?;
+    /// Opus encoding of the microphone track. A published track is
+    /// republished with the new options.
+    pub fn set_audio_publish_options(
+        &self,
+        options: AudioPublishOptions,
+        room_id: Option<String>,
+    ) -> Result<(), VisioError> {
+        let room = self.room_or_err(room_id.as_deref())?;
+        self.rt
+            .block_on(room.controls.set_audio_publish_options(options.into()))
+            .map_err(VisioError::from)
+    }
 
-            // On Android, store/clear the video source for the Camera2 → JNI pipeline
-            #[cfg(target_os = "android")]
-            {
-                let mut guard = CAMERA_SOURCE.lock().unwrap();
-                if enabled {
-                    if let Some(source) = self.controls.video_source().await {
-                        visio_log("VISIO FFI: camera source stored for JNI pipeline");
-                        *guard = Some(source);
-                    } else {
-                        visio_log("VISIO FFI: ERROR — video_source() returned None, CAMERA_SOURCE not set!");
-                    }
-                } else {
-                    visio_log("VISIO FFI: camera source cleared");
-                    *guard = None;
-                }
-            }
+    pub fn audio_publish_options(&self, room_id: Option<String>) -> AudioPublishOptions {
+        match self.room(room_id.as_deref()) {
+            Some(room) => room.controls.audio_publish_options().into(),
+            None => visio_core::AudioPublishOptions::default().into(),
+        }
+    }
 
-            // On iOS, store/clear the video source for the AVCaptureSession → C FFI pipeline
-            #[cfg(target_os = "ios")]
-            {
-                let mut guard = CAMERA_SOURCE_IOS.lock().unwrap();
-                if enabled {
-                    if let Some(source) = self.controls.video_source().await {
-                        visio_log("VISIO FFI: camera source stored for iOS capture pipeline");
-                        *guard = Some(source);
-                    }
-                } else {
-                    visio_log("VISIO FFI: camera source cleared");
-                    *guard = None;
-                }
-            }
+    /// Options for voice calls over 2G/3G: 16 kbps narrowband Opus.
+    pub fn low_bandwidth_audio_options(&self) -> AudioPublishOptions {
+        visio_core::AudioPublishOptions::low_bandwidth().into()
+    }
 
-            Ok(())
-        })
+    /// Persist the microphone preset (speech or music) and apply it to the
+    /// room. With `Music`, the microphone source is replaced: platform
+    /// capture must follow the new `SourceReady` handle.
+    pub fn set_audio_preset(
+        &self,
+        preset: AudioPreset,
+        room_id: Option<String>,
+    ) -> Result<(), VisioError> {
+        let room = self.room_or_err(room_id.as_deref())?;
+        self.settings.set_audio_preset(preset.into());
+        self.rt
+            .block_on(room.controls.set_audio_preset(preset.into()))
+            .map_err(VisioError::from)
     }
 
-    pub fn is_microphone_enabled(&self) -> bool {
-        self.rt.block_on(self.controls.is_microphone_enabled())
+    pub fn audio_preset(&self, room_id: Option<String>) -> AudioPreset {
+        match self.room(room_id.as_deref()) {
+            Some(room) => room.controls.audio_preset().into(),
+            None => self.settings.get().audio_preset.into(),
+        }
     }
 
-    pub fn is_camera_enabled(&self) -> bool {
-        self.rt.block_on(self.controls.is_camera_enabled())
+    /// Opus bitrate of the microphone in bits per second (6000-510000).
+    pub fn set_audio_bitrate(&self, bps: u32, room_id: Option<String>) -> Result<(), VisioError> {
+        let room = self.room_or_err(room_id.as_deref())?;
+        self.rt
+            .block_on(room.controls.set_audio_bitrate(bps))
+            .map_err(VisioError::from)
     }
 
-    pub fn send_chat_message(&self, text: String) -> Result<ChatMessage, VisioError> {
+    pub fn send_chat_message(
+        &self,
+        text: String,
+        room_id: Option<String>,
+    ) -> Result<ChatMessage, VisioError> {
+        let room = self.room_or_err(room_id.as_deref())?;
         self.rt.block_on(async {
-            self.chat
+            room.chat
                 .send_message(&text)
                 .await
                 .map(ChatMessage::from)
@@ -588,19 +3522,75 @@ impl VisioClient {
         })
     }
 
-    pub fn chat_messages(&self) -> Vec<ChatMessage> {
+    pub fn chat_messages(&self, room_id: Option<String>) -> Vec<ChatMessage> {
+        let Some(room) = self.room(room_id.as_deref()) else {
+            return Vec::new();
+        };
         self.rt
-            .block_on(self.chat.messages())
+            .block_on(room.chat.messages())
             .into_iter()
             .map(ChatMessage::from)
             .collect()
     }
 
+    /// Body of a chat message as spans to draw as plain text: emoji
+    /// shortcodes, bold and safe links resolved, unless formatting is off.
+    pub fn chat_message_body(&self, text: String, room_id: Option<String>) -> Vec<MessageSpan> {
+        let spans = match self.room(room_id.as_deref()) {
+            Some(room) => room.chat.message_body(&text),
+            None => visio_core::message_body(&text, true),
+        };
+        spans.into_iter().map(MessageSpan::from).collect()
+    }
+
+    /// Turn chat formatting off to get message bodies as typed.
+    pub fn set_chat_formatting(&self, enabled: bool, room_id: Option<String>) {
+        if let Some(room) = self.room(room_id.as_deref()) {
+            room.room_manager.set_chat_formatting(enabled);
+        }
+    }
+
+    /// The chat of the session as a file in `format`, with times at
+    /// `utc_offset_minutes` from UTC in the date format of `language`.
+    pub fn export_chat(
+        &self,
+        format: ChatExportFormat,
+        language: String,
+        utc_offset_minutes: i32,
+        room_id: Option<String>,
+    ) -> Result<Vec<u8>, VisioError> {
+        let room = self.room_or_err(room_id.as_deref())?;
+        let export = room
+            .chat
+            .export_chat(format.into(), &language, utc_offset_minutes);
+        Ok(self.rt.block_on(export))
+    }
+
+    /// Write the chat export (see `export_chat`) to `path`.
+    pub fn save_chat_export(
+        &self,
+        format: ChatExportFormat,
+        language: String,
+        utc_offset_minutes: i32,
+        path: String,
+        room_id: Option<String>,
+    ) -> Result<(), VisioError> {
+        let bytes = self.export_chat(format, language, utc_offset_minutes, room_id)?;
+        std::fs::write(&path, bytes)
+            .map_err(|e| visio_core::VisioError::Room(format!("cannot write {path}: {e}")).into())
+    }
+
+    /// Register a listener for events of the default room. Rooms opened
+    /// with `join()` only report to room listeners (`add_room_listener`),
+    /// which get every room's events with its id.
     pub fn add_listener(&self, listener: Box<dyn VisioEventListener>) {
         let bridge = Arc::new(BridgeListener {
             ffi_listener: Arc::from(listener),
         });
-        self.room_manager.add_listener(bridge);
+        self.settings.add_listener(bridge.clone());
+        if let Some(room) = self.room(None) {
+            room.room_manager.add_listener(bridge);
+        }
     }
 
     pub fn get_settings(&self) -> Settings {
@@ -611,8 +3601,24 @@ impl VisioClient {
         self.settings.set_display_name(name);
     }
 
+    /// Persist the language and apply it to the system messages of all
+    /// rooms.
     pub fn set_language(&self, lang: Option<String>) {
         self.settings.set_language(lang);
+        let settings = self.settings.get();
+        for room in self.all_rooms() {
+            room.room_manager
+                .system_messages()
+                .apply_settings(&settings);
+        }
+    }
+
+    /// String `key` of the shared catalogs in the language setting (English
+    /// when none is set), with `{name}` placeholders replaced by `args`.
+    pub fn translate(&self, key: String, args: HashMap<String, String>) -> String {
+        let language = self.settings.get().language.unwrap_or_default();
+        let args: Vec<(&str, &str)> = args.iter().map(|(k, v)| (k.as_str(), v.as_str())).collect();
+        visio_core::i18n::translate(&language, &key, &args)
     }
 
     pub fn set_mic_enabled_on_join(&self, enabled: bool) {
@@ -627,6 +3633,26 @@ impl VisioClient {
         self.settings.set_theme(theme);
     }
 
+    pub fn set_preferred_microphone_id(&self, id: Option<String>) {
+        self.settings.set_preferred_microphone_id(id);
+    }
+
+    pub fn set_preferred_camera_id(&self, id: Option<String>) {
+        self.settings.set_preferred_camera_id(id);
+    }
+
+    pub fn set_preferred_speaker_id(&self, id: Option<String>) {
+        self.settings.set_preferred_speaker_id(id);
+    }
+
+    pub fn get_extra(&self, key: String) -> Option<String> {
+        self.settings.get_extra(&key)
+    }
+
+    pub fn set_extra(&self, key: String, value: Option<String>) {
+        self.settings.set_extra(&key, value);
+    }
+
     pub fn get_meet_instances(&self) -> Vec<String> {
         self.settings.get_meet_instances()
     }
@@ -635,74 +3661,669 @@ impl VisioClient {
         self.settings.set_meet_instances(instances);
     }
 
-    pub fn set_notification_participant_join(&self, enabled: bool) {
-        self.settings.set_notification_participant_join(enabled);
+    pub fn set_notification_participant_join(&self, enabled: bool) {
+        self.settings.set_notification_participant_join(enabled);
+    }
+
+    pub fn set_notification_hand_raised(&self, enabled: bool) {
+        self.settings.set_notification_hand_raised(enabled);
+    }
+
+    pub fn set_notification_message_received(&self, enabled: bool) {
+        self.settings.set_notification_message_received(enabled);
+    }
+
+    /// Persist the hand auto-lower settings and apply them to all rooms.
+    pub fn set_hand_auto_lower(&self, enabled: bool, delay_secs: u32) {
+        self.settings.set_hand_auto_lower(enabled, delay_secs);
+        let config = self.settings.get().hand_auto_lower();
+        for room in self.all_rooms() {
+            room.room_manager.set_hand_auto_lower(config);
+        }
+    }
+
+    /// Pre-join device check: listen to the capture paths for `window_ms`
+    /// and report a silent microphone or a camera sending no frames.
+    /// Start the microphone (and the camera with `check_camera`) before
+    /// calling. `speaker_error` is why the audio output failed to open,
+    /// if it did. Blocks for `window_ms`.
+    pub fn run_device_check(
+        &self,
+        window_ms: u32,
+        speaker_error: Option<String>,
+        check_camera: bool,
+    ) -> DeviceCheckReport {
+        let speaker = match speaker_error {
+            Some(reason) => visio_core::CheckResult::Failed(reason),
+            None => visio_core::CheckResult::Passed,
+        };
+        self.rt
+            .block_on(visio_core::device_check::run_device_check(
+                std::time::Duration::from_millis(u64::from(window_ms)),
+                speaker,
+                check_camera,
+            ))
+            .into()
+    }
+
+    /// Feed microphone samples (48 kHz mono PCM) to a running device check,
+    /// for platforms whose capture does not go through the core (iOS).
+    pub fn push_device_check_audio(&self, samples: Vec<i16>) {
+        visio_core::device_check::device_check().observe_audio(&samples);
+    }
+
+    /// Persist the idle policy and apply it to all rooms: minutes alone
+    /// before `AloneInRoom` (0 disables), whether to leave then, and
+    /// minutes without activity before `InactivityDetected` (0 disables).
+    pub fn set_idle_policy(
+        &self,
+        alone_timeout_mins: u32,
+        auto_leave_when_alone: bool,
+        inactivity_timeout_mins: u32,
+    ) {
+        self.settings.set_idle(
+            alone_timeout_mins,
+            auto_leave_when_alone,
+            inactivity_timeout_mins,
+        );
+        let config = self.settings.get().idle();
+        for room in self.all_rooms() {
+            room.room_manager.set_idle_config(config);
+        }
+    }
+
+    /// Persist the bad-network fallback and apply it to all rooms: after
+    /// `delay_secs` of poor quality the camera is turned off, and remote
+    /// video too with `pause_remote_video`, until the quality recovers.
+    pub fn set_quality_policy(&self, enabled: bool, delay_secs: u32, pause_remote_video: bool) {
+        self.settings
+            .set_quality_policy(enabled, delay_secs, pause_remote_video);
+        let config = self.settings.get().quality_policy();
+        for room in self.all_rooms() {
+            room.room_manager.set_quality_policy_config(config);
+        }
+    }
+
+    /// The user is still there (answered `InactivityDetected`).
+    pub fn report_activity(&self, room_id: Option<String>) {
+        if let Some(slot) = self.room(room_id.as_deref()) {
+            slot.room_manager.report_activity();
+        }
+    }
+
+    /// Persist the audio ducking settings and apply them. `level` is the
+    /// share of the remote volume removed while the local user speaks.
+    pub fn set_audio_ducking(&self, enabled: bool, level: f32) {
+        self.settings.set_audio_ducking(enabled, level);
+        visio_core::audio_ducking::audio_ducker().set_config(self.settings.get().audio_ducking());
+    }
+
+    /// Persist the playout loudness normalization and apply it. Remote
+    /// participants are brought to about `target_lufs` (-40 to -6).
+    pub fn set_auto_gain(&self, enabled: bool, target_lufs: f32) {
+        self.settings.set_auto_gain(enabled, target_lufs);
+        visio_core::auto_gain::auto_gain().set_config(self.settings.get().auto_gain());
+    }
+
+    /// Persist the metrics opt-in and apply it to all rooms. Anonymized
+    /// call reports are POSTed to `endpoint` only while `enabled`.
+    pub fn set_metrics(&self, enabled: bool, endpoint: Option<String>) {
+        self.settings.set_metrics(enabled, endpoint);
+        let settings = self.settings.get();
+        for room in self.all_rooms() {
+            room.room_manager.metrics().apply_settings(&settings);
+        }
+    }
+
+    /// Persist whether feedback may carry the call summary and logs, and
+    /// apply it to all rooms.
+    pub fn set_feedback_diagnostics_enabled(&self, enabled: bool) {
+        self.settings.set_feedback_diagnostics_enabled(enabled);
+        let settings = self.settings.get();
+        for room in self.all_rooms() {
+            room.room_manager.feedback().apply_settings(&settings);
+        }
+    }
+
+    /// Persist custom STUN/TURN servers and the relay-only policy; applied
+    /// by the next connection of every room.
+    pub fn set_rtc_config(&self, config: RtcConfigOverride) -> Result<(), VisioError> {
+        let config = visio_core::RtcConfigOverride::from(config);
+        config.validate()?;
+        self.settings.set_rtc_config(config.clone());
+        for room in self.all_rooms() {
+            room.room_manager.set_rtc_config(config.clone());
+        }
+        Ok(())
+    }
+
+    pub fn raise_hand(&self, room_id: Option<String>) -> Result<(), VisioError> {
+        let room = self.room_or_err(room_id.as_deref())?;
+        self.rt
+            .block_on(room.room_manager.raise_hand())
+            .map_err(VisioError::from)
+    }
+
+    pub fn lower_hand(&self, room_id: Option<String>) -> Result<(), VisioError> {
+        let room = self.room_or_err(room_id.as_deref())?;
+        self.rt
+            .block_on(room.room_manager.lower_hand())
+            .map_err(VisioError::from)
+    }
+
+    pub fn set_presence(
+        &self,
+        presence: Presence,
+        room_id: Option<String>,
+    ) -> Result<(), VisioError> {
+        let room = self.room_or_err(room_id.as_deref())?;
+        self.rt
+            .block_on(room.room_manager.presence().set_presence(presence.into()))
+            .map_err(VisioError::from)
+    }
+
+    pub fn presence(&self, room_id: Option<String>) -> Presence {
+        self.room(room_id.as_deref())
+            .map_or(Presence::Available, |room| {
+                room.room_manager.presence().presence().into()
+            })
+    }
+
+    pub fn is_hand_raised(&self, room_id: Option<String>) -> bool {
+        self.room(room_id.as_deref())
+            .is_some_and(|room| self.rt.block_on(room.room_manager.is_hand_raised()))
+    }
+
+    pub fn send_reaction(&self, emoji: String, room_id: Option<String>) -> Result<(), VisioError> {
+        let room = self.room_or_err(room_id.as_deref())?;
+        self.rt
+            .block_on(room.room_manager.send_reaction(&emoji))
+            .map_err(VisioError::from)
+    }
+
+    /// Send a custom app message to all participants on `topic`.
+    pub fn publish_data(
+        &self,
+        topic: String,
+        payload: Vec<u8>,
+        reliable: bool,
+        room_id: Option<String>,
+    ) -> Result<(), VisioError> {
+        let room = self.room_or_err(room_id.as_deref())?;
+        self.rt
+            .block_on(
+                room.room_manager
+                    .data_channel()
+                    .publish(&topic, payload, reliable),
+            )
+            .map_err(VisioError::from)
+    }
+
+    /// Set app-level attributes of the local participant; an empty value
+    /// removes its key. Changes are reported as
+    /// `ParticipantAttributesChanged` events.
+    pub fn set_local_attributes(
+        &self,
+        attributes: HashMap<String, String>,
+        room_id: Option<String>,
+    ) -> Result<(), VisioError> {
+        let room = self.room_or_err(room_id.as_deref())?;
+        let service = room.room_manager.attributes();
+        self.rt
+            .block_on(service.set_local_attributes(attributes))
+            .map_err(VisioError::from)
+    }
+
+    pub fn get_participant_attributes(
+        &self,
+        participant_sid: String,
+        room_id: Option<String>,
+    ) -> Option<HashMap<String, String>> {
+        let room = self.room(room_id.as_deref())?;
+        let service = room.room_manager.attributes();
+        self.rt
+            .block_on(service.participant_attributes(&participant_sid))
+    }
+
+    /// Receive messages on `topic` as `DataMessageReceived` events.
+    pub fn subscribe_data_topic(
+        &self,
+        topic: String,
+        room_id: Option<String>,
+    ) -> Result<(), VisioError> {
+        let room = self.room_or_err(room_id.as_deref())?;
+        room.room_manager
+            .data_channel()
+            .subscribe(&topic)
+            .map_err(VisioError::from)
+    }
+
+    pub fn unsubscribe_data_topic(&self, topic: String, room_id: Option<String>) {
+        if let Some(room) = self.room(room_id.as_deref()) {
+            room.room_manager.data_channel().unsubscribe(&topic);
+        }
+    }
+
+    /// Start a poll with 2 to 10 options.
+    pub fn create_poll(
+        &self,
+        question: String,
+        options: Vec<String>,
+        room_id: Option<String>,
+    ) -> Result<Poll, VisioError> {
+        let room = self.room_or_err(room_id.as_deref())?;
+        self.rt
+            .block_on(room.room_manager.polls().create_poll(&question, options))
+            .map(Poll::from)
+            .map_err(VisioError::from)
+    }
+
+    /// Vote for option index `option`, replacing any previous vote.
+    pub fn vote_poll(
+        &self,
+        poll_id: String,
+        option: u32,
+        room_id: Option<String>,
+    ) -> Result<Poll, VisioError> {
+        let room = self.room_or_err(room_id.as_deref())?;
+        self.rt
+            .block_on(room.room_manager.polls().vote(&poll_id, option))
+            .map(Poll::from)
+            .map_err(VisioError::from)
+    }
+
+    /// End a poll created by the local participant.
+    pub fn close_poll(&self, poll_id: String, room_id: Option<String>) -> Result<Poll, VisioError> {
+        let room = self.room_or_err(room_id.as_deref())?;
+        self.rt
+            .block_on(room.room_manager.polls().close_poll(&poll_id))
+            .map(Poll::from)
+            .map_err(VisioError::from)
+    }
+
+    pub fn polls(&self, room_id: Option<String>) -> Vec<Poll> {
+        let Some(room) = self.room(room_id.as_deref()) else {
+            return Vec::new();
+        };
+        self.rt
+            .block_on(room.room_manager.polls().polls())
+            .into_iter()
+            .map(Poll::from)
+            .collect()
+    }
+
+    /// Shareable link and dial-in details of the room.
+    pub fn get_invite_info(&self, room_id: Option<String>) -> Result<InviteInfo, VisioError> {
+        let room = self.room_or_err(room_id.as_deref())?;
+        self.rt
+            .block_on(room.room_manager.invite_info())
+            .map(InviteInfo::from)
+            .map_err(VisioError::from)
+    }
+
+    /// URL and credentials of the room's collaborative whiteboard.
+    pub fn get_whiteboard_session(
+        &self,
+        room_id: Option<String>,
+    ) -> Result<WhiteboardSession, VisioError> {
+        let room = self.room_or_err(room_id.as_deref())?;
+        self.rt
+            .block_on(room.room_manager.whiteboard_session())
+            .map(WhiteboardSession::from)
+            .map_err(VisioError::from)
+    }
+
+    /// Email an invitation to the room, if the instance supports it.
+    pub fn invite_participants(
+        &self,
+        emails: Vec<String>,
+        room_id: Option<String>,
+    ) -> Result<(), VisioError> {
+        let room = self.room_or_err(room_id.as_deref())?;
+        self.rt
+            .block_on(room.room_manager.invite_participants(emails))
+            .map_err(VisioError::from)
+    }
+
+    pub fn lock_room(&self, locked: bool, room_id: Option<String>) -> Result<(), VisioError> {
+        let room = self.room_or_err(room_id.as_deref())?;
+        self.rt
+            .block_on(room.room_manager.moderation().lock_room(locked))
+            .map_err(VisioError::from)
+    }
+
+    pub fn is_room_locked(&self, room_id: Option<String>) -> bool {
+        self.room(room_id.as_deref()).is_some_and(|room| {
+            self.rt
+                .block_on(room.room_manager.moderation().is_room_locked())
+        })
+    }
+
+    /// Start the cloud recording (room owners and administrators only).
+    pub fn start_recording(
+        &self,
+        mode: RecordingMode,
+        room_id: Option<String>,
+    ) -> Result<(), VisioError> {
+        let room = self.room_or_err(room_id.as_deref())?;
+        self.rt
+            .block_on(room.room_manager.recording().start_recording(mode.into()))
+            .map_err(VisioError::from)
+    }
+
+    pub fn stop_recording(&self, room_id: Option<String>) -> Result<(), VisioError> {
+        let room = self.room_or_err(room_id.as_deref())?;
+        self.rt
+            .block_on(room.room_manager.recording().stop_recording())
+            .map_err(VisioError::from)
+    }
+
+    /// Record the call audio (what the user hears) to a WAV file at `path`.
+    pub fn start_local_recording(
+        &self,
+        path: String,
+        options: LocalRecordingOptions,
+        room_id: Option<String>,
+    ) -> Result<(), VisioError> {
+        let room = self.room_or_err(room_id.as_deref())?;
+        room.room_manager
+            .start_local_recording(&path, options.into())
+            .map_err(VisioError::from)
+    }
+
+    /// Stop the local recording; returns the file size in bytes.
+    pub fn stop_local_recording(&self, room_id: Option<String>) -> Result<u64, VisioError> {
+        let room = self.room_or_err(room_id.as_deref())?;
+        room.room_manager
+            .stop_local_recording()
+            .map_err(VisioError::from)
+    }
+
+    pub fn get_recording_status(&self, room_id: Option<String>) -> RecordingStatus {
+        self.room(room_id.as_deref())
+            .map(|room| room.room_manager.recording().status().into())
+            .unwrap_or(RecordingStatus::Stopped)
+    }
+
+    pub fn set_chat_open(&self, open: bool, room_id: Option<String>) {
+        if let Some(room) = self.room(room_id.as_deref()) {
+            room.room_manager.set_chat_open(open);
+        }
+    }
+
+    pub fn set_max_chat_message_bytes(&self, max_bytes: u64, room_id: Option<String>) {
+        if let Some(room) = self.room(room_id.as_deref()) {
+            room.room_manager
+                .set_max_chat_message_bytes(usize::try_from(max_bytes).unwrap_or(usize::MAX));
+        }
+    }
+
+    pub fn unread_count(&self, room_id: Option<String>) -> u32 {
+        self.room(room_id.as_deref())
+            .map_or(0, |room| room.room_manager.unread_count())
+    }
+
+    /// Unread messages by kind, and where to draw the "new messages"
+    /// divider.
+    pub fn chat_unread(&self, room_id: Option<String>) -> ChatUnread {
+        self.room(room_id.as_deref())
+            .map(|room| room.room_manager.chat_unread())
+            .unwrap_or_default()
+            .into()
+    }
+
+    /// Acknowledge the chat messages up to `id`, included, e.g. the last
+    /// one shown. Returns `false` if `id` is not unread.
+    pub fn mark_chat_read_up_to(&self, id: String, room_id: Option<String>) -> bool {
+        self.room(room_id.as_deref())
+            .is_some_and(|room| room.room_manager.mark_chat_read_up_to(&id))
+    }
+
+    pub fn validate_room(&self, url: String, username: Option<String>) -> RoomValidationResult {
+        self.rt
+            .block_on(validate_room_result(url, username, self.cache.clone()))
+    }
+
+    /// Avatar image at `url` if it is in the disk cache. Does not download.
+    pub fn get_cached_avatar(&self, url: String) -> Option<Vec<u8>> {
+        self.cache.cached_avatar(&url)
+    }
+
+    /// Avatar image at `url`, from the disk cache or downloaded (and
+    /// cached) on a miss. Blocks while downloading.
+    pub fn fetch_avatar(&self, url: String) -> Result<Vec<u8>, VisioError> {
+        self.rt
+            .block_on(self.cache.avatar(&url))
+            .map_err(VisioError::from)
+    }
+
+    /// Last known info of the room at `meet_url`, recorded by
+    /// `validate_room`.
+    pub fn get_cached_room_info(&self, meet_url: String) -> Option<RoomInfo> {
+        self.cache.room_info(&meet_url).map(RoomInfo::from)
+    }
+
+    /// Delete cached avatars and room info.
+    pub fn clear_cache(&self) {
+        if let Err(e) = self.cache.clear() {
+            visio_log(&format!("VISIO FFI: failed to clear cache: {e}"));
+        }
+    }
+
+    // ── Async variants ────────────────────────────────────────────────
+    //
+    // Exposed as suspend (Kotlin) / async (Swift) functions. The foreign
+    // executor polls these futures without a tokio reactor, so the actual
+    // work is spawned on the client runtime and only the JoinHandle is
+    // awaited here.
+
+    async fn run<T: Send + 'static>(
+        &self,
+        fut: impl std::future::Future<Output = T> + Send + 'static,
+    ) -> Result<T, VisioError> {
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        self.tasks.spawn(&self.rt, async move {
+            let _ = tx.send(fut.await);
+        });
+        // Dropped unsent if the task panicked or `shutdown()` aborted it.
+        rx.await.map_err(|_| {
+            visio_log("VISIO FFI: async task failed");
+            visio_core::VisioError::Connection("task failed".into()).into()
+        })
+    }
+
+    pub async fn connect_async(
+        &self,
+        meet_url: String,
+        username: Option<String>,
+        room_id: Option<String>,
+    ) -> Result<(), VisioError> {
+        let options = JoinOptions {
+            username,
+            ..self.join_options()
+        };
+        self.connect_with_options_async(meet_url, options, room_id)
+            .await
+    }
+
+    pub async fn connect_with_options_async(
+        &self,
+        meet_url: String,
+        options: JoinOptions,
+        room_id: Option<String>,
+    ) -> Result<(), VisioError> {
+        let room = self.room_or_err(room_id.as_deref())?;
+        let options = visio_core::JoinOptions::from(options);
+        self.run(async move {
+            room.room_manager.connect(&meet_url, &options).await?;
+            Ok::<_, visio_core::VisioError>(())
+        })
+        .await?
+        .map_err(VisioError::from)?;
+        self.after_connect();
+        Ok(())
+    }
+
+    pub async fn disconnect_async(&self, room_id: Option<String>) {
+        let Some(room) = self.room(room_id.as_deref()) else {
+            return;
+        };
+        self.before_disconnect(room_id.as_deref());
+        let rooms = self.all_rooms();
+        let _ = self
+            .run(async move {
+                room.room_manager.disconnect().await;
+                if !any_room_joined(rooms).await {
+                    room.room_manager.reset_shared_media();
+                }
+            })
+            .await;
+    }
+
+    pub async fn send_chat_message_async(
+        &self,
+        text: String,
+        room_id: Option<String>,
+    ) -> Result<ChatMessage, VisioError> {
+        let room = self.room_or_err(room_id.as_deref())?;
+        self.run(async move { room.chat.send_message(&text).await })
+            .await?
+            .map(ChatMessage::from)
+            .map_err(VisioError::from)
+    }
+
+    pub async fn validate_room_async(
+        &self,
+        url: String,
+        username: Option<String>,
+    ) -> RoomValidationResult {
+        self.run(validate_room_result(url, username, self.cache.clone()))
+            .await
+            .unwrap_or_else(|e| RoomValidationResult::NetworkError {
+                message: e.to_string(),
+            })
+    }
+
+    pub fn start_video_renderer(&self, track_sid: String) {
+        if self.video.start_renderer(&track_sid, std::ptr::null_mut) {
+            visio_log(&format!(
+                "VISIO FFI: started video renderer for {track_sid}"
+            ));
+        } else {
+            visio_log(&format!("VISIO FFI: no video track found for {track_sid}"));
+        }
+    }
+
+    pub fn stop_video_renderer(&self, track_sid: String) {
+        visio_log(&format!(
+            "VISIO FFI: stopping video renderer for {track_sid}"
+        ));
+        visio_video::stop_track_renderer(&track_sid);
     }
 
-    pub fn set_notification_hand_raised(&self, enabled: bool) {
-        self.settings.set_notification_hand_raised(enabled);
+    /// Remote video tracks whose tiles are on screen. They are received
+    /// at the best quality; the others are paused, stream and renderer,
+    /// until they are reported visible again.
+    pub fn set_visible_tracks(&self, track_sids: Vec<String>, room_id: Option<String>) {
+        let Some(room) = self.room(room_id.as_deref()) else {
+            return;
+        };
+        let hidden = self.rt.block_on(
+            room.room_manager
+                .visibility()
+                .set_visible_tracks(track_sids.clone()),
+        );
+        for sid in &track_sids {
+            visio_video::set_renderer_paused(sid, false);
+        }
+        for sid in &hidden {
+            visio_video::set_renderer_paused(sid, true);
+        }
     }
 
-    pub fn set_notification_message_received(&self, enabled: bool) {
-        self.settings.set_notification_message_received(enabled);
+    /// Frame statistics of the running renderer for `track_sid`.
+    pub fn get_renderer_stats(&self, track_sid: String) -> Option<RendererStats> {
+        visio_video::renderer_stats(&track_sid).map(Into::into)
     }
 
-    pub fn raise_hand(&self) -> Result<(), VisioError> {
-        self.rt.block_on(self.room_manager.raise_hand())
-            .map_err(VisioError::from)
+    /// Local capture delays and audio/video skew.
+    pub fn get_av_sync_stats(&self) -> AvSyncStats {
+        visio_core::av_sync::av_sync_monitor().stats().into()
     }
 
-    pub fn lower_hand(&self) -> Result<(), VisioError> {
-        self.rt.block_on(self.room_manager.lower_hand())
-            .map_err(VisioError::from)
+    /// Register the platform camera capture for torch, zoom and focus
+    /// commands.
+    pub fn add_camera_command_listener(&self, listener: Box<dyn CameraCommandListener>) {
+        self.camera_control
+            .add_listener(Arc::new(CameraCommandBridge {
+                ffi_listener: Arc::from(listener),
+            }));
     }
 
-    pub fn is_hand_raised(&self) -> bool {
-        self.rt.block_on(self.room_manager.is_hand_raised())
+    pub fn set_torch_enabled(&self, enabled: bool) {
+        self.camera_control.set_torch_enabled(enabled);
     }
 
-    pub fn send_reaction(&self, emoji: String) -> Result<(), VisioError> {
-        self.rt.block_on(self.room_manager.send_reaction(&emoji))
-            .map_err(VisioError::from)
+    pub fn set_zoom(&self, factor: f32) {
+        self.camera_control.set_zoom(factor);
     }
 
-    pub fn set_chat_open(&self, open: bool) {
-        self.room_manager.set_chat_open(open);
+    /// Focus on the point (`x`, `y`) of the local preview, normalized to 0..1.
+    pub fn set_focus_point(&self, x: f32, y: f32) {
+        self.camera_control.set_focus_point(x, y);
     }
 
-    pub fn unread_count(&self) -> u32 {
-        self.room_manager.unread_count()
+    /// Last requested torch and zoom values, for a capture that (re)starts.
+    pub fn get_camera_control_state(&self) -> CameraControlState {
+        self.camera_control.state().into()
     }
 
-    pub fn validate_room(&self, url: String, username: Option<String>) -> RoomValidationResult {
-        if let Err(e) = visio_core::AuthService::extract_slug(&url) {
-            return RoomValidationResult::InvalidFormat { message: e.to_string() };
-        }
-        match self.rt.block_on(visio_core::AuthService::validate_room(&url, username.as_deref(), None)) {
-            Ok(token_info) => RoomValidationResult::Valid {
-                livekit_url: token_info.livekit_url,
-                token: token_info.token,
-            },
-            Err(visio_core::VisioError::Auth(msg)) if msg.contains("404") => {
-                RoomValidationResult::NotFound
-            }
-            Err(e) => RoomValidationResult::NetworkError { message: e.to_string() },
-        }
+    /// PNG of the last frame received for `track_sid`.
+    pub fn capture_snapshot(&self, track_sid: String) -> Result<Vec<u8>, VisioError> {
+        visio_video::capture_snapshot(&track_sid).ok_or_else(|| {
+            visio_core::VisioError::Room(format!("no frame available for track {track_sid}")).into()
+        })
     }
 
-    pub fn start_video_renderer(&self, track_sid: String) {
-        let track = self.rt.block_on(self.room_manager.get_video_track(&track_sid));
-        if let Some(video_track) = track {
-            visio_log(&format!("VISIO FFI: starting video renderer for {track_sid}"));
-            visio_video::start_track_renderer(track_sid, video_track, std::ptr::null_mut(), Some(self.rt.handle().clone()));
-        } else {
-            visio_log(&format!("VISIO FFI: no video track found for {track_sid}"));
+    /// PNG of `participant_sid` as shown on their tile: the last frame of
+    /// their camera, or their avatar (`generate_avatar`, `size` pixels wide)
+    /// when it is off.
+    pub fn participant_snapshot(
+        &self,
+        participant_sid: String,
+        size: u32,
+        room_id: Option<String>,
+    ) -> Result<Vec<u8>, VisioError> {
+        let room = self.room_or_err(room_id.as_deref())?;
+        let manager = &room.room_manager;
+        let participant = self
+            .rt
+            .block_on(async {
+                let mut all = manager.participants().await;
+                all.extend(manager.local_participant_info().await);
+                all
+            })
+            .into_iter()
+            .find(|p| p.sid == participant_sid)
+            .ok_or_else(|| {
+                visio_core::VisioError::Room(format!("unknown participant {participant_sid}"))
+            })?;
+        if let Some(png) = participant
+            .video_track_sid
+            .filter(|_| participant.has_video)
+            .and_then(|sid| visio_video::capture_snapshot(&sid))
+        {
+            return Ok(png);
         }
+        let name = participant.name.unwrap_or(participant.identity);
+        visio_core::generate_avatar(&name, size).map_err(VisioError::from)
     }
 
-    pub fn stop_video_renderer(&self, track_sid: String) {
-        visio_log(&format!("VISIO FFI: stopping video renderer for {track_sid}"));
-        visio_video::stop_track_renderer(&track_sid);
+    /// Write the PNG of the last frame received for `track_sid` to `path`.
+    pub fn save_snapshot(&self, track_sid: String, path: String) -> Result<(), VisioError> {
+        let png = self.capture_snapshot(track_sid)?;
+        std::fs::write(&path, png)
+            .map_err(|e| visio_core::VisioError::Room(format!("cannot write {path}: {e}")).into())
     }
 
     pub fn set_background_mode(&self, mode: String) {
@@ -730,43 +4351,72 @@ impl VisioClient {
 
     pub fn load_background_image(&self, id: u8, jpeg_path: String) -> Result<(), VisioError> {
         let jpeg_bytes = std::fs::read(&jpeg_path)
-            .map_err(|e| VisioError::Generic { msg: format!("Failed to read image: {e}") })?;
+            .map_err(|e| VisioError::generic(format!("Failed to read image: {e}")))?;
         // Use 640x480 as default target — will be re-loaded at actual frame dimensions if needed
         blur::BlurProcessor::load_replacement_image(id, &jpeg_bytes, 640, 480)
-            .map_err(|e| VisioError::Generic { msg: e })
+            .map_err(VisioError::generic)
+    }
+
+    /// Text drawn on the outgoing video, in `corner`; `None` or blank
+    /// removes it. Persisted. A watermark required by the instance takes
+    /// its place during its calls.
+    pub fn set_video_overlay(&self, text: Option<String>, corner: OverlayCorner) {
+        self.settings.set_video_overlay(text, corner.into());
+        let settings = self.settings.get();
+        visio_core::video_overlay()
+            .set_text(settings.video_overlay_text, settings.video_overlay_corner);
+    }
+
+    /// Branding image drawn on the outgoing video, from a PNG file; `None`
+    /// removes it.
+    pub fn set_video_overlay_image(&self, png_path: Option<String>) -> Result<(), VisioError> {
+        let png = png_path
+            .map(|path| {
+                std::fs::read(&path)
+                    .map_err(|e| VisioError::generic(format!("Failed to read image: {e}")))
+            })
+            .transpose()?;
+        visio_core::video_overlay()
+            .set_image_png(png.as_deref())
+            .map_err(VisioError::from)
     }
 
     pub fn load_blur_model(&self, model_path: String) -> Result<(), VisioError> {
-        blur::model::load_model(std::path::Path::new(&model_path))
-            .map_err(|e| VisioError::Generic { msg: e })
+        blur::model::load_model(std::path::Path::new(&model_path)).map_err(VisioError::generic)
+    }
+}
+
+/// Clear a platform playout hook if it still holds `buffer`, so a client
+/// created since keeps its own.
+#[cfg(any(target_os = "android", target_os = "ios"))]
+fn release_playout_buffer(
+    hook: &StdMutex<Option<Arc<visio_core::AudioPlayoutBuffer>>>,
+    buffer: Option<&Arc<visio_core::AudioPlayoutBuffer>>,
+) {
+    let mut hook = hook.lock().unwrap_or_else(|e| e.into_inner());
+    if let (Some(current), Some(buffer)) = (hook.as_ref(), buffer)
+        && Arc::ptr_eq(current, buffer)
+    {
+        *hook = None;
+    }
+}
+
+impl Drop for VisioClient {
+    fn drop(&mut self) {
+        unregister_video_client(self.client_id);
     }
 }
 
-// ── Global camera video source (for Android Camera2 → Rust pipeline) ─
+// ── Android capture and playout pipelines (Camera2 / AudioRecord → Rust) ─
 
 #[cfg(target_os = "android")]
 use livekit::webrtc::prelude::*;
-#[cfg(target_os = "android")]
-use livekit::webrtc::video_source::native::NativeVideoSource;
-#[cfg(target_os = "android")]
-use livekit::webrtc::audio_source::native::NativeAudioSource;
 
 /// Stores the AudioPlayoutBuffer from RoomManager so the Android AudioPlayout
 /// Kotlin class can pull decoded remote audio via JNI.
 #[cfg(target_os = "android")]
 static PLAYOUT_BUFFER: StdMutex<Option<Arc<visio_core::AudioPlayoutBuffer>>> = StdMutex::new(None);
 
-/// Global VisioClient pointer (as usize) for JNI video attach/detach.
-/// Set in `connect()` so the JNI attachSurface can look up video tracks.
-#[cfg(target_os = "android")]
-static CLIENT_FOR_VIDEO: StdMutex<usize> = StdMutex::new(0);
-
-/// Stores the NativeVideoSource after `set_camera_enabled(true)` publishes
-/// the camera track. The Android CameraCapture Kotlin class pushes YUV frames
-/// into this source via JNI → `visio_push_camera_frame()`.
-#[cfg(target_os = "android")]
-static CAMERA_SOURCE: StdMutex<Option<NativeVideoSource>> = StdMutex::new(None);
-
 /// RAII wrapper around `ANativeWindow*` that calls `ANativeWindow_release` on drop.
 ///
 /// Prevents leaks and double-frees on error paths in JNI surface management.
@@ -816,12 +4466,6 @@ unsafe impl Send for NativeWindowHandle {}
 #[cfg(target_os = "android")]
 static LOCAL_PREVIEW_SURFACE: StdMutex<Option<NativeWindowHandle>> = StdMutex::new(None);
 
-/// Stores the NativeAudioSource after `set_microphone_enabled(true)` publishes
-/// the audio track. The Android AudioCapture Kotlin class pushes PCM frames
-/// into this source via JNI → `nativePushAudioFrame()`.
-#[cfg(target_os = "android")]
-static AUDIO_SOURCE: StdMutex<Option<NativeAudioSource>> = StdMutex::new(None);
-
 /// Dedicated tokio runtime for async audio capture_frame calls.
 #[cfg(target_os = "android")]
 static AUDIO_RT: std::sync::OnceLock<tokio::runtime::Runtime> = std::sync::OnceLock::new();
@@ -861,26 +4505,28 @@ pub unsafe extern "C" fn Java_io_visio_mobile_NativeVideo_nativePushCameraFrame(
     width: jni::sys::jint,
     height: jni::sys::jint,
     rotation_degrees: jni::sys::jint,
+    timestamp_ns: jni::sys::jlong,
 ) {
-    let guard = CAMERA_SOURCE.lock().unwrap();
-    let Some(source) = guard.as_ref() else {
-        visio_log("VISIO FFI: CAMERA_SOURCE is None — discarding frame");
+    // Counted even without a source: the pre-join device check runs
+    // before any room publishes the camera.
+    visio_core::device_check::device_check().observe_video_frame();
+    let Some(source) = visio_core::capture_sources().video() else {
+        visio_log("VISIO FFI: no camera source — discarding frame");
         return;
     };
 
     // Get direct buffer addresses from ByteBuffer objects
-    let Ok(jni_env) = (unsafe { jni::JNIEnv::from_raw(env) }) else { return };
-
-    let y_ptr = unsafe {
-        jni_env.get_direct_buffer_address(&jni::objects::JByteBuffer::from_raw(y_buf))
-    };
-    let u_ptr = unsafe {
-        jni_env.get_direct_buffer_address(&jni::objects::JByteBuffer::from_raw(u_buf))
-    };
-    let v_ptr = unsafe {
-        jni_env.get_direct_buffer_address(&jni::objects::JByteBuffer::from_raw(v_buf))
+    let Ok(jni_env) = (unsafe { jni::JNIEnv::from_raw(env) }) else {
+        return;
     };
 
+    let y_ptr =
+        unsafe { jni_env.get_direct_buffer_address(&jni::objects::JByteBuffer::from_raw(y_buf)) };
+    let u_ptr =
+        unsafe { jni_env.get_direct_buffer_address(&jni::objects::JByteBuffer::from_raw(u_buf)) };
+    let v_ptr =
+        unsafe { jni_env.get_direct_buffer_address(&jni::objects::JByteBuffer::from_raw(v_buf)) };
+
     let (Ok(y_ptr), Ok(u_ptr), Ok(v_ptr)) = (y_ptr, u_ptr, v_ptr) else {
         visio_log("VISIO FFI: failed to get direct buffer addresses from ByteBuffers");
         return;
@@ -942,9 +4588,31 @@ pub unsafe extern "C" fn Java_io_visio_mobile_NativeVideo_nativePushCameraFrame(
         let strides = i420.strides();
         let (y_data, u_data, v_data) = i420.data_mut();
         blur::BlurProcessor::process_i420(
-            y_data, u_data, v_data,
-            w as usize, h as usize,
-            strides.0 as usize, strides.1 as usize, strides.2 as usize,
+            y_data,
+            u_data,
+            v_data,
+            w as usize,
+            h as usize,
+            strides.0 as usize,
+            strides.1 as usize,
+            strides.2 as usize,
+            rotation_degrees as u32,
+        );
+        visio_core::video_privacy().process_i420(
+            y_data,
+            u_data,
+            v_data,
+            w as usize,
+            h as usize,
+            [strides.0 as usize, strides.1 as usize, strides.2 as usize],
+        );
+        visio_core::video_overlay().process_i420(
+            y_data,
+            u_data,
+            v_data,
+            w as usize,
+            h as usize,
+            [strides.0 as usize, strides.1 as usize, strides.2 as usize],
             rotation_degrees as u32,
         );
     }
@@ -961,7 +4629,9 @@ pub unsafe extern "C" fn Java_io_visio_mobile_NativeVideo_nativePushCameraFrame(
     // release the ANativeWindow while we are writing to it (prevents SIGSEGV).
     {
         let guard = LOCAL_PREVIEW_SURFACE.lock().unwrap();
-        if let Some(ref handle) = *guard {
+        if let Some(ref handle) = *guard
+            && visio_video::self_view_enabled()
+        {
             visio_video::render_i420_to_surface(
                 &i420,
                 handle.as_ptr() as *mut std::ffi::c_void,
@@ -972,28 +4642,28 @@ pub unsafe extern "C" fn Java_io_visio_mobile_NativeVideo_nativePushCameraFrame(
         drop(guard);
     }
 
+    let timestamp_us = visio_core::av_sync::capture_timestamp_us(timestamp_ns);
+    visio_core::av_sync::av_sync_monitor().record_video(timestamp_us);
     let frame = VideoFrame {
         rotation,
-        timestamp_us: 0,
+        timestamp_us,
         buffer: i420,
     };
     source.capture_frame(&frame);
-    drop(guard);
 
     // Prevent Drop from calling DestroyJavaVM
     std::mem::forget(jni_env);
 }
 
-/// Clear the global camera source (called when camera is disabled).
+/// Camera capture stopped. The source stays registered while the camera
+/// track is published, so a restarted capture feeds it again.
 #[cfg(target_os = "android")]
 #[unsafe(no_mangle)]
 pub extern "C" fn Java_io_visio_mobile_NativeVideo_nativeStopCameraCapture(
     _env: *mut jni::sys::JNIEnv,
     _class: jni::sys::jobject,
 ) {
-    visio_log("VISIO FFI: nativeStopCameraCapture — clearing camera source");
-    let mut guard = CAMERA_SOURCE.lock().unwrap();
-    *guard = None;
+    visio_log("VISIO FFI: nativeStopCameraCapture");
 }
 
 // ── JNI: audio capture pipeline ──────────────────────────────────────
@@ -1016,28 +4686,45 @@ pub unsafe extern "C" fn Java_io_visio_mobile_NativeVideo_nativePushAudioFrame(
     num_samples: jni::sys::jint,
     sample_rate: jni::sys::jint,
     num_channels: jni::sys::jint,
+    timestamp_ns: jni::sys::jlong,
 ) {
-    let guard = AUDIO_SOURCE.lock().unwrap();
-    let Some(source) = guard.as_ref() else {
+    let device_check = visio_core::device_check::device_check();
+    let source = visio_core::capture_sources().audio();
+    // The pre-join device check listens before any room publishes the mic.
+    if source.is_none() && !device_check.is_active() {
         return;
-    };
-    let source = source.clone();
-    drop(guard);
+    }
 
-    let Ok(jni_env) = (unsafe { jni::JNIEnv::from_raw(env) }) else { return };
+    let Ok(jni_env) = (unsafe { jni::JNIEnv::from_raw(env) }) else {
+        return;
+    };
     let ptr = unsafe {
         jni_env.get_direct_buffer_address(&jni::objects::JByteBuffer::from_raw(data_buf))
     };
-    let Ok(ptr) = ptr else { return; };
+    let Ok(ptr) = ptr else {
+        return;
+    };
 
     let sample_count = num_samples as usize;
     let pcm_data = unsafe { std::slice::from_raw_parts(ptr as *const i16, sample_count) };
+    device_check.observe_audio(pcm_data);
+    let Some(source) = source else {
+        std::mem::forget(jni_env);
+        return;
+    };
 
+    visio_core::av_sync::av_sync_monitor()
+        .record_audio(visio_core::av_sync::capture_timestamp_us(timestamp_ns));
+    visio_core::audio_ducking::audio_ducker().observe_capture(pcm_data);
+
+    // The music preset publishes a stereo source.
+    let channels = source.num_channels();
+    let data = visio_core::capture_sources::fit_channels(pcm_data, num_channels as u32, channels);
     let frame = AudioFrame {
-        data: pcm_data.into(),
+        samples_per_channel: data.len() as u32 / channels,
+        data: data.into(),
         sample_rate: sample_rate as u32,
-        num_channels: num_channels as u32,
-        samples_per_channel: sample_count as u32 / num_channels as u32,
+        num_channels: channels,
     };
 
     // capture_frame is async — run on dedicated single-thread runtime
@@ -1046,16 +4733,15 @@ pub unsafe extern "C" fn Java_io_visio_mobile_NativeVideo_nativePushAudioFrame(
     std::mem::forget(jni_env);
 }
 
-/// Clear the global audio source (called when mic capture stops).
+/// Microphone capture stopped. The source stays registered while the
+/// microphone track is published.
 #[cfg(target_os = "android")]
 #[unsafe(no_mangle)]
 pub extern "C" fn Java_io_visio_mobile_NativeVideo_nativeStopAudioCapture(
     _env: *mut jni::sys::JNIEnv,
     _class: jni::sys::jobject,
 ) {
-    visio_log("VISIO FFI: nativeStopAudioCapture — clearing audio source");
-    let mut guard = AUDIO_SOURCE.lock().unwrap();
-    *guard = None;
+    visio_log("VISIO FFI: nativeStopAudioCapture");
 }
 
 // ── JNI: audio playout pipeline (remote audio → speakers) ───────────
@@ -1083,9 +4769,12 @@ pub unsafe extern "C" fn Java_io_visio_mobile_NativeVideo_nativePullAudioPlaybac
     let playout = playout.clone();
     drop(guard);
 
-    let Ok(mut jni_env) = (unsafe { jni::JNIEnv::from_raw(env) }) else { return 0 };
+    let Ok(mut jni_env) = (unsafe { jni::JNIEnv::from_raw(env) }) else {
+        return 0;
+    };
 
-    let len = jni_env.get_array_length(&unsafe { jni::objects::JShortArray::from_raw(buffer) })
+    let len = jni_env
+        .get_array_length(&unsafe { jni::objects::JShortArray::from_raw(buffer) })
         .unwrap_or(0) as usize;
     if len == 0 {
         std::mem::forget(jni_env);
@@ -1110,13 +4799,8 @@ pub unsafe extern "C" fn Java_io_visio_mobile_NativeVideo_nativePullAudioPlaybac
 /// Stores the AudioPlayoutBuffer from RoomManager so the iOS AudioPlayout
 /// Swift class can pull decoded remote audio via C FFI.
 #[cfg(target_os = "ios")]
-static PLAYOUT_BUFFER_IOS: StdMutex<Option<Arc<visio_core::AudioPlayoutBuffer>>> = StdMutex::new(None);
-
-/// Stores the NativeVideoSource after `set_camera_enabled(true)` publishes
-/// the camera track. The iOS CameraCapture Swift class pushes I420 frames
-/// into this source via C FFI → `visio_push_ios_camera_frame()`.
-#[cfg(target_os = "ios")]
-static CAMERA_SOURCE_IOS: StdMutex<Option<livekit::webrtc::video_source::native::NativeVideoSource>> = StdMutex::new(None);
+static PLAYOUT_BUFFER_IOS: StdMutex<Option<Arc<visio_core::AudioPlayoutBuffer>>> =
+    StdMutex::new(None);
 
 /// Pull decoded remote audio samples from the playout buffer.
 ///
@@ -1130,7 +4814,9 @@ static CAMERA_SOURCE_IOS: StdMutex<Option<livekit::webrtc::video_source::native:
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn visio_pull_audio_playback(buffer: *mut i16, capacity: u32) -> i32 {
     let guard = PLAYOUT_BUFFER_IOS.lock().unwrap();
-    let Some(playout) = guard.as_ref() else { return 0 };
+    let Some(playout) = guard.as_ref() else {
+        return 0;
+    };
     let playout = playout.clone();
     drop(guard);
 
@@ -1139,34 +4825,40 @@ pub unsafe extern "C" fn visio_pull_audio_playback(buffer: *mut i16, capacity: u
 }
 
 /// Push an I420 video frame from the iOS camera into the LiveKit NativeVideoSource.
+/// `timestamp_ns` is the capture time on the host clock (0 if unknown).
 ///
 /// # Safety
 /// All pointers must be valid for the given dimensions and strides.
 #[cfg(target_os = "ios")]
 #[unsafe(no_mangle)]
 pub unsafe extern "C" fn visio_push_ios_camera_frame(
-    y_ptr: *const u8, y_stride: u32,
-    u_ptr: *const u8, u_stride: u32,
-    v_ptr: *const u8, v_stride: u32,
-    width: u32, height: u32,
+    y_ptr: *const u8,
+    y_stride: u32,
+    u_ptr: *const u8,
+    u_stride: u32,
+    v_ptr: *const u8,
+    v_stride: u32,
+    width: u32,
+    height: u32,
+    timestamp_ns: i64,
 ) {
     use livekit::webrtc::prelude::*;
     use std::sync::atomic::{AtomicU64, Ordering};
     static IOS_FRAME_COUNT: AtomicU64 = AtomicU64::new(0);
 
-    // Clone source and drop guard immediately (same pattern as visio_pull_audio_playback).
-    let source = {
-        let guard = CAMERA_SOURCE_IOS.lock().unwrap();
-        match guard.as_ref() {
-            Some(s) => s.clone(),
-            None => {
-                let n = IOS_FRAME_COUNT.fetch_add(1, Ordering::Relaxed);
-                if n % 30 == 0 {
-                    visio_log(&format!("visio_push_ios_camera_frame: no source (frame #{})", n));
-                }
-                return;
-            },
+    // Counted even without a source: the pre-join device check runs
+    // before any room publishes the camera.
+    visio_core::device_check::device_check().observe_video_frame();
+
+    let Some(source) = visio_core::capture_sources().video() else {
+        let n = IOS_FRAME_COUNT.fetch_add(1, Ordering::Relaxed);
+        if n % 30 == 0 {
+            visio_log(&format!(
+                "visio_push_ios_camera_frame: no source (frame #{})",
+                n
+            ));
         }
+        return;
     };
 
     let n = IOS_FRAME_COUNT.fetch_add(1, Ordering::Relaxed);
@@ -1193,13 +4885,15 @@ pub unsafe extern "C" fn visio_push_ios_camera_frame(
     }
     // Copy U plane
     for row in 0..chroma_h {
-        let src = unsafe { std::slice::from_raw_parts(u_ptr.add(row * u_stride as usize), chroma_w) };
+        let src =
+            unsafe { std::slice::from_raw_parts(u_ptr.add(row * u_stride as usize), chroma_w) };
         let dst_start = row * strides.1 as usize;
         u_dst[dst_start..dst_start + chroma_w].copy_from_slice(src);
     }
     // Copy V plane
     for row in 0..chroma_h {
-        let src = unsafe { std::slice::from_raw_parts(v_ptr.add(row * v_stride as usize), chroma_w) };
+        let src =
+            unsafe { std::slice::from_raw_parts(v_ptr.add(row * v_stride as usize), chroma_w) };
         let dst_start = row * strides.2 as usize;
         v_dst[dst_start..dst_start + chroma_w].copy_from_slice(src);
     }
@@ -1209,21 +4903,218 @@ pub unsafe extern "C" fn visio_push_ios_camera_frame(
         let strides = i420.strides();
         let (y_data, u_data, v_data) = i420.data_mut();
         blur::BlurProcessor::process_i420(
-            y_data, u_data, v_data,
-            width as usize, height as usize,
-            strides.0 as usize, strides.1 as usize, strides.2 as usize,
+            y_data,
+            u_data,
+            v_data,
+            width as usize,
+            height as usize,
+            strides.0 as usize,
+            strides.1 as usize,
+            strides.2 as usize,
             0, // iOS frames are pre-rotated by AVCaptureConnection
         );
+        visio_core::video_privacy().process_i420(
+            y_data,
+            u_data,
+            v_data,
+            width as usize,
+            height as usize,
+            [strides.0 as usize, strides.1 as usize, strides.2 as usize],
+        );
+        visio_core::video_overlay().process_i420(
+            y_data,
+            u_data,
+            v_data,
+            width as usize,
+            height as usize,
+            [strides.0 as usize, strides.1 as usize, strides.2 as usize],
+            0,
+        );
     }
 
+    let timestamp_us = visio_core::av_sync::capture_timestamp_us(timestamp_ns);
+    visio_core::av_sync::av_sync_monitor().record_video(timestamp_us);
     let frame = VideoFrame {
         rotation: VideoRotation::VideoRotation0,
-        timestamp_us: 0,
+        timestamp_us,
         buffer: i420,
     };
     source.capture_frame(&frame);
 }
 
+// ── C FFI: frame push for external capture backends ─────────────────
+//
+// Platform-agnostic entry points for capture that does not go through the
+// Android/iOS pipelines (V4L2 helpers, virtual cameras, ...). Frames go to
+// the sources published by the client's default room, like the built-in
+// capture. Return codes: 0 pushed, -1 invalid arguments, -2 nothing
+// published (no room, or the device is off).
+
+/// `format` of `visio_push_video_frame`: Y, U, V planes.
+pub const VISIO_PIXEL_FORMAT_I420: u32 = 0;
+/// `format` of `visio_push_video_frame`: Y plane, interleaved UV plane.
+pub const VISIO_PIXEL_FORMAT_NV12: u32 = 1;
+/// `format` of `visio_push_video_frame`: one plane of packed RGBA.
+pub const VISIO_PIXEL_FORMAT_RGBA: u32 = 2;
+
+/// Push a captured video frame into the published camera source.
+///
+/// `planes` and `strides` hold three entries; the planes unused by
+/// `format` may be null. The frame is converted to I420, then background
+/// blur applies as for the built-in camera. `rotation_degrees` (0, 90,
+/// 180, 270) is the clockwise rotation to display the frame upright;
+/// `timestamp_ns` is the capture time on the host clock (0 if unknown).
+///
+/// # Safety
+/// - `client_ptr` must point to a live `VisioClient`.
+/// - `planes` and `strides` must point to arrays of 3 elements.
+/// - Each plane used by `format` must hold `stride * rows` bytes, where
+///   rows is `height` for the first plane and `ceil(height / 2)` for the
+///   chroma planes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn visio_push_video_frame(
+    client_ptr: *const VisioClient,
+    format: u32,
+    width: u32,
+    height: u32,
+    planes: *const *const u8,
+    strides: *const u32,
+    rotation_degrees: u32,
+    timestamp_ns: i64,
+) -> i32 {
+    if client_ptr.is_null() || planes.is_null() || strides.is_null() {
+        return -1;
+    }
+    let format = match format {
+        VISIO_PIXEL_FORMAT_I420 => visio_core::PixelFormat::I420,
+        VISIO_PIXEL_FORMAT_NV12 => visio_core::PixelFormat::Nv12,
+        VISIO_PIXEL_FORMAT_RGBA => visio_core::PixelFormat::Rgba,
+        _ => return -1,
+    };
+    let client = unsafe { &*client_ptr };
+    let strides = unsafe { *strides.cast::<[u32; 3]>() };
+    let planes = unsafe { *planes.cast::<[*const u8; 3]>() };
+    let Some(lens) = format.plane_lens(height, strides) else {
+        return -1;
+    };
+    let mut slices: [&[u8]; 3] = [&[]; 3];
+    for ((slice, &ptr), &len) in slices.iter_mut().zip(&planes).zip(&lens) {
+        if len == 0 {
+            continue;
+        }
+        if ptr.is_null() {
+            return -1;
+        }
+        *slice = unsafe { std::slice::from_raw_parts(ptr, len) };
+    }
+
+    // Counted even without a source: the pre-join device check runs
+    // before any room publishes the camera.
+    visio_core::device_check::device_check().observe_video_frame();
+    let Some(room) = client.room(None) else {
+        return -2;
+    };
+    let sources = room.room_manager.published_sources();
+    if sources.video().is_none() {
+        return -2;
+    }
+
+    let raw = visio_core::RawVideoFrame {
+        format,
+        width,
+        height,
+        planes: slices,
+        strides,
+    };
+    let Some(mut frame) = visio_core::frame_convert::to_i420(&raw) else {
+        return -1;
+    };
+    let cw = frame.chroma_width() as usize;
+    blur::BlurProcessor::process_i420(
+        &mut frame.y,
+        &mut frame.u,
+        &mut frame.v,
+        width as usize,
+        height as usize,
+        width as usize,
+        cw,
+        cw,
+        rotation_degrees,
+    );
+    visio_core::video_privacy().process_i420(
+        &mut frame.y,
+        &mut frame.u,
+        &mut frame.v,
+        width as usize,
+        height as usize,
+        [width as usize, cw, cw],
+    );
+    visio_core::video_overlay().process_i420(
+        &mut frame.y,
+        &mut frame.u,
+        &mut frame.v,
+        width as usize,
+        height as usize,
+        [width as usize, cw, cw],
+        rotation_degrees,
+    );
+
+    let timestamp_us = visio_core::av_sync::capture_timestamp_us(timestamp_ns);
+    visio_core::av_sync::av_sync_monitor().record_video(timestamp_us);
+    if sources.push_video(&frame, rotation_degrees, timestamp_us) {
+        0
+    } else {
+        -2
+    }
+}
+
+/// Push interleaved 16-bit PCM samples into the published microphone
+/// source. The source takes 48 kHz; several channels are mixed down to
+/// mono. `timestamp_ns` is the capture time on the host clock (0 if
+/// unknown).
+///
+/// # Safety
+/// - `client_ptr` must point to a live `VisioClient`.
+/// - `samples` must hold `samples_per_channel * num_channels` values.
+/// - Must not be called from a tokio runtime thread.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn visio_push_audio_frame(
+    client_ptr: *const VisioClient,
+    samples: *const i16,
+    samples_per_channel: u32,
+    sample_rate: u32,
+    num_channels: u32,
+    timestamp_ns: i64,
+) -> i32 {
+    if client_ptr.is_null() || samples.is_null() || num_channels == 0 {
+        return -1;
+    }
+    let client = unsafe { &*client_ptr };
+    let len = samples_per_channel as usize * num_channels as usize;
+    let pcm = unsafe { std::slice::from_raw_parts(samples, len) };
+
+    visio_core::device_check::device_check().observe_audio(pcm);
+    let Some(room) = client.room(None) else {
+        return -2;
+    };
+    let sources = room.room_manager.published_sources();
+    if sources.audio().is_none() {
+        return -2;
+    }
+
+    visio_core::av_sync::av_sync_monitor()
+        .record_audio(visio_core::av_sync::capture_timestamp_us(timestamp_ns));
+    visio_core::audio_ducking::audio_ducker().observe_capture(pcm);
+    if client
+        .rt
+        .block_on(sources.push_audio(pcm, sample_rate, num_channels))
+    {
+        0
+    } else {
+        -2
+    }
+}
+
 // ── C FFI: video attach / detach ─────────────────────────────────────
 
 /// Attach a native surface for video rendering.
@@ -1260,19 +5151,12 @@ pub unsafe extern "C" fn visio_attach_video_surface(
         Err(_) => return -1,
     };
 
-    // Look up the track from the room manager
-    let track = client
-        .rt
-        .block_on(client.room_manager.get_video_track(&sid_str));
-    match track {
-        Some(video_track) => {
-            visio_video::start_track_renderer(sid_str, video_track, surface, Some(client.rt.handle().clone()));
-            0
-        }
-        None => {
-            tracing::warn!("no video track found for SID {sid_str}");
-            -2
-        }
+    // Look up the track in the client's rooms
+    if client.video.start_renderer(&sid_str, || surface) {
+        0
+    } else {
+        tracing::warn!("no video track found for SID {sid_str}");
+        -2
     }
 }
 
@@ -1283,9 +5167,7 @@ pub unsafe extern "C" fn visio_attach_video_surface(
 ///
 /// Returns 0 on success, -1 on invalid arguments.
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn visio_detach_video_surface(
-    track_sid: *const std::ffi::c_char,
-) -> i32 {
+pub unsafe extern "C" fn visio_detach_video_surface(track_sid: *const std::ffi::c_char) -> i32 {
     if track_sid.is_null() {
         return -1;
     }
@@ -1327,9 +5209,8 @@ pub unsafe extern "C" fn Java_io_visio_mobile_NativeVideo_attachSurface(
 
     // Get ANativeWindow from Surface
     let surface = unsafe { JObject::from_raw(surface_obj) };
-    let native_window = unsafe {
-        ndk_sys::ANativeWindow_fromSurface(env as *mut _, surface.as_raw() as *mut _)
-    };
+    let native_window =
+        unsafe { ndk_sys::ANativeWindow_fromSurface(env as *mut _, surface.as_raw() as *mut _) };
     if native_window.is_null() {
         visio_log("VISIO JNI: ANativeWindow_fromSurface returned null");
         return;
@@ -1350,36 +5231,27 @@ pub unsafe extern "C" fn Java_io_visio_mobile_NativeVideo_attachSurface(
     }
 
     // Remote tracks: look up the subscribed video track and start a renderer.
-    let client_addr = *CLIENT_FOR_VIDEO.lock().unwrap();
-    if client_addr == 0 {
-        visio_log("VISIO JNI: no client pointer stored, cannot attach surface");
+    let Some(video) = active_video_client() else {
+        visio_log("VISIO JNI: no connected client, cannot attach surface");
         // window_handle is dropped here → ANativeWindow_release called automatically
         return;
-    }
-
-    let client = unsafe { &*(client_addr as *const VisioClient) };
-    visio_log("VISIO JNI: about to block_on get_video_track");
-    let track = client
-        .rt
-        .block_on(client.room_manager.get_video_track(&track_sid));
-    visio_log(&format!("VISIO JNI: block_on done, track found={}", track.is_some()));
-
-    match track {
-        Some(video_track) => {
-            visio_log(&format!("VISIO JNI: calling start_track_renderer for {track_sid}"));
-            // Transfer ownership — start_track_renderer/frame_loop holds the surface.
-            visio_video::start_track_renderer(
-                track_sid.clone(),
-                video_track,
-                window_handle.into_raw() as *mut std::ffi::c_void,
-                Some(client.rt.handle().clone()),
-            );
-            visio_log(&format!("VISIO JNI: start_track_renderer returned for {track_sid}"));
-        }
-        None => {
-            visio_log(&format!("VISIO JNI: no video track found for {track_sid}"));
-            // window_handle is dropped here → ANativeWindow_release called automatically
-        }
+    };
+    visio_log("VISIO JNI: looking up video track");
+    // Transfer ownership only if the track exists — start_track_renderer/frame_loop
+    // then holds the surface. Otherwise window_handle is dropped at the end of
+    // this function → ANativeWindow_release called automatically.
+    let mut window_handle = Some(window_handle);
+    let started = video.start_renderer(&track_sid, || {
+        window_handle.take().map_or(std::ptr::null_mut(), |h| {
+            h.into_raw() as *mut std::ffi::c_void
+        })
+    });
+    if started {
+        visio_log(&format!(
+            "VISIO JNI: start_track_renderer returned for {track_sid}"
+        ));
+    } else {
+        visio_log(&format!("VISIO JNI: no video track found for {track_sid}"));
     }
 }
 
@@ -1413,13 +5285,68 @@ pub unsafe extern "C" fn Java_io_visio_mobile_NativeVideo_detachSurface(
     // The old ANativeWindow is released automatically by the RAII wrapper when
     // attachSurface replaces it.  Final cleanup happens in disconnect().
     if track_sid == "local-camera" {
-        visio_log("VISIO JNI: detachSurface(local-camera) — skipped (surface replaced on next attach)");
+        visio_log(
+            "VISIO JNI: detachSurface(local-camera) — skipped (surface replaced on next attach)",
+        );
         return;
     }
 
     visio_video::stop_track_renderer(&track_sid);
 }
 
+/// JNI: NativeVideo.replaceSurface(trackSid: String, surface: Surface): Boolean
+/// Swaps the surface of a running renderer (e.g. after rotation) without
+/// restarting its video stream. Returns false if no renderer is running
+/// for the track; the caller should then use attachSurface.
+#[cfg(target_os = "android")]
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn Java_io_visio_mobile_NativeVideo_replaceSurface(
+    env: *mut jni::sys::JNIEnv,
+    _class: jni::sys::jobject,
+    track_sid_jstr: jni::sys::jstring,
+    surface_obj: jni::sys::jobject,
+) -> jni::sys::jboolean {
+    use jni::objects::{JObject, JString};
+
+    let mut jni_env = match unsafe { jni::JNIEnv::from_raw(env) } {
+        Ok(e) => e,
+        Err(_) => return jni::sys::JNI_FALSE,
+    };
+
+    let jstr = unsafe { JString::from_raw(track_sid_jstr) };
+    let track_sid: String = match jni_env.get_string(&jstr) {
+        Ok(s) => s.into(),
+        Err(_) => return jni::sys::JNI_FALSE,
+    };
+
+    let surface = unsafe { JObject::from_raw(surface_obj) };
+    let native_window =
+        unsafe { ndk_sys::ANativeWindow_fromSurface(env as *mut _, surface.as_raw() as *mut _) };
+    if native_window.is_null() {
+        visio_log("VISIO JNI: ANativeWindow_fromSurface returned null");
+        return jni::sys::JNI_FALSE;
+    }
+    let window_handle = unsafe { NativeWindowHandle::from_raw(native_window) };
+
+    match visio_video::replace_surface(&track_sid, window_handle.as_ptr() as *mut std::ffi::c_void)
+    {
+        Some(previous) => {
+            // The renderer now owns the new window; the one it held goes
+            // back to us and is released here.
+            let _ = window_handle.into_raw();
+            if !previous.is_null() {
+                drop(unsafe {
+                    NativeWindowHandle::from_raw(previous as *mut ndk_sys::ANativeWindow)
+                });
+            }
+            visio_log(&format!("VISIO JNI: replaceSurface track={track_sid}"));
+            jni::sys::JNI_TRUE
+        }
+        // window_handle is dropped here → ANativeWindow_release called automatically
+        None => jni::sys::JNI_FALSE,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1433,6 +5360,7 @@ mod tests {
         let result = client.connect(
             "https://meet.linagora.com/test-desktop-debug".to_string(),
             Some("desktop-test".to_string()),
+            None,
         );
 
         match &result {
@@ -1443,6 +5371,255 @@ mod tests {
         eprintln!("TEST: no crash - connect() returned normally");
     }
 
+    fn test_client(name: &str) -> VisioClient {
+        let dir = std::env::temp_dir().join(format!("visio-test-{name}"));
+        VisioClient::new(dir.to_str().unwrap().to_string())
+    }
+
+    #[test]
+    fn test_async_variants_run_without_tokio_reactor() {
+        let client = test_client("async");
+        // Foreign executors poll without a reactor; mimic that with a bare
+        // current-thread runtime (no IO/time drivers enabled).
+        let executor = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+
+        let result = executor.block_on(client.validate_room_async("not a url".into(), None));
+        assert!(matches!(result, RoomValidationResult::InvalidFormat { .. }));
+
+        let err = executor
+            .block_on(client.connect_async("not a url".into(), None, None))
+            .unwrap_err();
+        assert!(matches!(err, VisioError::InvalidUrl { .. }), "got {err:?}");
+
+        let err = executor
+            .block_on(client.send_chat_message_async("hi".into(), Some("nope".into())))
+            .unwrap_err();
+        assert!(matches!(err, VisioError::Room { .. }));
+
+        executor.block_on(client.disconnect_async(None));
+        assert!(matches!(
+            client.connection_state(None),
+            ConnectionState::Disconnected
+        ));
+    }
+
+    #[test]
+    fn test_default_room_only_initially() {
+        let client = test_client("rooms-default");
+        assert_eq!(client.room_ids(), vec![DEFAULT_ROOM_ID.to_string()]);
+        assert!(matches!(
+            client.connection_state(None),
+            ConnectionState::Disconnected
+        ));
+        assert!(matches!(
+            client.connection_state(Some(DEFAULT_ROOM_ID.to_string())),
+            ConnectionState::Disconnected
+        ));
+    }
+
+    #[test]
+    fn test_system_call_lifecycle() {
+        let client = test_client("system-call");
+        let call = client
+            .report_outgoing_call("abc-defg-hij".to_string(), "Ana".to_string(), None)
+            .unwrap();
+        assert_eq!(call.direction, CallDirection::Outgoing);
+        assert_eq!(call.state, SystemCallState::Connecting);
+        assert_eq!(client.system_call(None).unwrap().call_id, call.call_id);
+
+        client.end_call(None);
+        assert!(client.system_call(None).is_none());
+        assert!(matches!(
+            client.report_incoming_call(
+                "x".to_string(),
+                "Ana".to_string(),
+                Some("room-42".to_string())
+            ),
+            Err(VisioError::Room { .. })
+        ));
+    }
+
+    #[test]
+    fn test_device_check_report() {
+        let client = test_client("device-check");
+        let report = client.run_device_check(0, Some("no output device".to_string()), false);
+        assert!(matches!(report.microphone, CheckResult::Failed { .. }));
+        assert!(matches!(report.camera, CheckResult::Skipped));
+        assert!(
+            matches!(report.speaker, CheckResult::Failed { ref reason } if reason == "no output device")
+        );
+        assert!(report.has_problem);
+    }
+
+    #[test]
+    fn test_self_test_reports_unwritable_data_dir() {
+        let dir = std::env::temp_dir().join("visio-test-self-test");
+        let report = self_test(dir.to_str().unwrap().to_string());
+        assert!(matches!(report.runtime, CheckResult::Passed));
+        assert!(matches!(report.data_dir, CheckResult::Passed));
+        assert!(!report.has_problem);
+
+        let file = dir.join("not-a-dir");
+        std::fs::write(&file, b"").unwrap();
+        let report = self_test(file.to_str().unwrap().to_string());
+        assert!(matches!(report.data_dir, CheckResult::Failed { .. }));
+        assert!(report.has_problem);
+    }
+
+    #[test]
+    fn test_video_registry_never_outlives_client() {
+        let first = test_client("video-registry-1");
+        let second = test_client("video-registry-2");
+
+        first.after_connect();
+        assert!(Arc::ptr_eq(&active_video_client().unwrap(), &first.video));
+
+        // Disconnecting another client leaves the active one alone.
+        second.before_disconnect(None);
+        assert!(active_video_client().is_some());
+
+        second.after_connect();
+        drop(first);
+        assert!(Arc::ptr_eq(&active_video_client().unwrap(), &second.video));
+
+        // A context still held by a JNI call keeps working after the drop,
+        // but the registry no longer hands it out.
+        let held = active_video_client().unwrap();
+        drop(second);
+        assert!(active_video_client().is_none());
+        assert!(!held.start_renderer("TR_missing", std::ptr::null_mut));
+    }
+
+    #[test]
+    fn test_shutdown_is_idempotent() {
+        let client = test_client("shutdown");
+        client.after_connect();
+        client.shutdown();
+
+        let registered = VIDEO_CLIENTS
+            .lock()
+            .unwrap()
+            .contains_key(&client.client_id);
+        assert!(!registered);
+        assert!(client.room(None).is_none());
+        let url = "https://meet.example.com/abc-defg-hij".to_string();
+        assert!(client.connect(url.clone(), None, None).is_err());
+        assert!(client.join(url, None).is_err());
+
+        // Again, and with the usual calls after it, before the drop.
+        client.shutdown();
+        client.disconnect(None);
+        drop(client);
+    }
+
+    #[test]
+    fn test_shutdown_awaits_client_tasks() {
+        struct Flag(Arc<AtomicBool>);
+        impl Drop for Flag {
+            fn drop(&mut self) {
+                self.0.store(true, Ordering::SeqCst);
+            }
+        }
+
+        let client = test_client("shutdown-tasks");
+        let dropped = Arc::new(AtomicBool::new(false));
+        let flag = Flag(dropped.clone());
+        client.tasks.spawn(&client.rt, async move {
+            let _flag = flag;
+            std::future::pending::<()>().await;
+        });
+        client.shutdown();
+        assert!(dropped.load(Ordering::SeqCst));
+        assert!(client.runtime.lock().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_cache_accessors_miss_when_empty() {
+        let client = test_client("cache");
+        client.clear_cache();
+        assert!(
+            client
+                .get_cached_avatar("https://meet.example.com/avatar.png".to_string())
+                .is_none()
+        );
+        assert!(
+            client
+                .get_cached_room_info("meet.example.com/abc-defg-hij".to_string())
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_join_failure_does_not_leak_room() {
+        let client = test_client("rooms-join-fail");
+        let result = client.join("not a meet url".to_string(), None);
+        assert!(matches!(result, Err(VisioError::InvalidUrl { .. })));
+        assert_eq!(client.room_ids(), vec![DEFAULT_ROOM_ID.to_string()]);
+    }
+
+    #[test]
+    fn test_unknown_room_is_rejected() {
+        let client = test_client("rooms-unknown");
+        let unknown = Some("room-42".to_string());
+        assert!(matches!(
+            client.leave("room-42".to_string()),
+            Err(VisioError::Room { .. })
+        ));
+        assert!(matches!(
+            client.send_chat_message("hi".to_string(), unknown.clone()),
+            Err(VisioError::Room { .. })
+        ));
+        assert!(matches!(
+            client.raise_hand(unknown.clone()),
+            Err(VisioError::Room { .. })
+        ));
+        assert!(client.participants(unknown.clone()).is_empty());
+        assert!(!client.is_microphone_enabled(unknown.clone()));
+        assert_eq!(client.unread_count(unknown), 0);
+        // Leaving the default room is allowed and keeps it.
+        assert!(client.leave(DEFAULT_ROOM_ID.to_string()).is_ok());
+        assert_eq!(client.room_ids(), vec![DEFAULT_ROOM_ID.to_string()]);
+    }
+
+    struct RoomEventCapture {
+        events: StdMutex<Vec<(String, VisioEvent)>>,
+    }
+
+    impl RoomEventListener for RoomEventCapture {
+        fn on_room_event(&self, room_id: String, event: VisioEvent) {
+            self.events.lock().unwrap().push((room_id, event));
+        }
+    }
+
+    #[test]
+    fn test_room_events_carry_room_id() {
+        let listeners: Arc<RwLock<Vec<Arc<dyn RoomEventListener>>>> =
+            Arc::new(RwLock::new(Vec::new()));
+        let capture = Arc::new(RoomEventCapture {
+            events: StdMutex::new(Vec::new()),
+        });
+        listeners.write().unwrap().push(capture.clone());
+
+        let bridge = RoomBridgeListener {
+            room_id: "room-7".to_string(),
+            listeners,
+        };
+        visio_core::VisioEventListener::on_event(
+            &bridge,
+            CoreVisioEvent::ParticipantLeft("PA_1".into()),
+        );
+
+        let events = capture.events.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].0, "room-7");
+        assert!(matches!(
+            &events[0].1,
+            VisioEvent::ParticipantLeft { participant_sid } if participant_sid == "PA_1"
+        ));
+    }
+
     #[test]
     fn test_block_on_works() {
         let rt = tokio::runtime::Runtime::new().unwrap();
@@ -0,0 +1,323 @@
+//! Tracing setup for the mobile hosts.
+//!
+//! The filter is installed behind a reload handle so hosts can change the
+//! log level or the full filter at runtime (`set_log_level`,
+//! `set_log_targets`). Output goes to logcat on Android — one tag per
+//! crate (`VISIO_CORE`, `VISIO_FFI`, `VISIO_VIDEO`), matching the direct
+//! `__android_log_write` calls — and to stderr elsewhere. Logs can also be
+//! written to a size-rotated file in the app data dir (`set_file_logging`).
+
+use std::fs::{File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::{Mutex, Once, OnceLock};
+
+use tracing_subscriber::filter::{EnvFilter, LevelFilter, filter_fn};
+use tracing_subscriber::fmt::MakeWriter;
+use tracing_subscriber::prelude::*;
+use tracing_subscriber::{Registry, reload};
+
+use crate::VisioError;
+
+/// Filter used when `RUST_LOG` is not set.
+const DEFAULT_FILTER: &str = "visio_core=debug,visio_ffi=debug,visio_video=info";
+
+/// Targets affected by `set_log_level`.
+const VISIO_TARGETS: [&str; 3] = ["visio_core", "visio_ffi", "visio_video"];
+
+/// Log file name inside `<data_dir>/logs`.
+const LOG_FILE_NAME: &str = "visio.log";
+/// Size at which the log file is rotated.
+const MAX_FILE_BYTES: u64 = 5 * 1024 * 1024;
+/// Rotated files kept next to the current one (`visio.log.1` is the newest).
+const MAX_ROTATED_FILES: usize = 3;
+
+static FILTER: OnceLock<reload::Handle<EnvFilter, Registry>> = OnceLock::new();
+static LOG_FILE: Mutex<Option<RotatingFile>> = Mutex::new(None);
+
+fn log_file() -> std::sync::MutexGuard<'static, Option<RotatingFile>> {
+    LOG_FILE.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Install the global subscriber. Idempotent; if another subscriber is
+/// already installed (e.g. by a desktop host), it is left in place.
+pub(crate) fn init() {
+    static INIT: Once = Once::new();
+    INIT.call_once(|| {
+        let filter =
+            EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(DEFAULT_FILTER));
+        let (filter, handle) = reload::Layer::new(filter);
+
+        #[cfg(target_os = "android")]
+        let console_writer = logcat::Logcat;
+        #[cfg(not(target_os = "android"))]
+        let console_writer = io::stderr;
+
+        let console = tracing_subscriber::fmt::layer()
+            .with_ansi(false)
+            .with_writer(console_writer);
+        let file = tracing_subscriber::fmt::layer()
+            .with_ansi(false)
+            .with_writer(FileWriter)
+            .with_filter(filter_fn(|_| log_file().is_some()));
+
+        if tracing_subscriber::registry()
+            .with(filter)
+            .with(console)
+            .with(file)
+            .try_init()
+            .is_ok()
+        {
+            let _ = FILTER.set(handle);
+        }
+    });
+}
+
+fn reload_filter(filter: EnvFilter) -> Result<(), VisioError> {
+    init();
+    let handle = FILTER
+        .get()
+        .ok_or_else(|| VisioError::generic("logging is managed by the host"))?;
+    handle
+        .reload(filter)
+        .map_err(|e| VisioError::generic(e.to_string()))
+}
+
+/// Directives setting every Visio crate to `level`.
+fn level_directives(level: &str) -> Result<String, VisioError> {
+    let level = LevelFilter::from_str(level.trim())
+        .map_err(|_| VisioError::generic(format!("invalid log level: {level}")))?;
+    let level = level.to_string().to_lowercase();
+    Ok(VISIO_TARGETS
+        .iter()
+        .map(|target| format!("{target}={level}"))
+        .collect::<Vec<_>>()
+        .join(","))
+}
+
+/// Set the level ("trace", "debug", "info", "warn", "error" or "off") of
+/// all Visio crates, replacing the current filter.
+pub fn set_log_level(level: String) -> Result<(), VisioError> {
+    reload_filter(EnvFilter::new(level_directives(&level)?))
+}
+
+/// Replace the filter with `RUST_LOG`-style directives, e.g.
+/// `"visio_core=debug,livekit=info"`.
+pub fn set_log_targets(filter: String) -> Result<(), VisioError> {
+    let filter = EnvFilter::try_new(&filter)
+        .map_err(|e| VisioError::generic(format!("invalid log filter: {e}")))?;
+    reload_filter(filter)
+}
+
+/// Also write logs to `<data_dir>/logs/visio.log`, rotated at 5 MB with
+/// three older files kept. `None` stops file logging.
+pub fn set_file_logging(data_dir: Option<String>) -> Result<(), VisioError> {
+    init();
+    let file = match data_dir {
+        Some(dir) => {
+            let dir = Path::new(&dir).join("logs");
+            Some(RotatingFile::open(&dir, MAX_FILE_BYTES).map_err(|e| {
+                VisioError::generic(format!("cannot open log file in {}: {e}", dir.display()))
+            })?)
+        }
+        None => None,
+    };
+    *log_file() = file;
+    Ok(())
+}
+
+// ── Rotating file ───────────────────────────────────────────────────
+
+struct RotatingFile {
+    path: PathBuf,
+    file: File,
+    written: u64,
+    max_bytes: u64,
+}
+
+impl RotatingFile {
+    fn open(dir: &Path, max_bytes: u64) -> io::Result<Self> {
+        std::fs::create_dir_all(dir)?;
+        let path = dir.join(LOG_FILE_NAME);
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        let written = file.metadata()?.len();
+        Ok(Self {
+            path,
+            file,
+            written,
+            max_bytes,
+        })
+    }
+
+    fn rotated_path(&self, index: usize) -> PathBuf {
+        self.path.with_extension(format!("log.{index}"))
+    }
+
+    /// Shift `visio.log.N` to `visio.log.N+1` (dropping the oldest) and
+    /// start a fresh `visio.log`.
+    fn rotate(&mut self) -> io::Result<()> {
+        for index in (1..MAX_ROTATED_FILES).rev() {
+            let _ = std::fs::rename(self.rotated_path(index), self.rotated_path(index + 1));
+        }
+        std::fs::rename(&self.path, self.rotated_path(1))?;
+        self.file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(true)
+            .open(&self.path)?;
+        self.written = 0;
+        Ok(())
+    }
+
+    fn write_record(&mut self, buf: &[u8]) -> io::Result<()> {
+        if self.written > 0 && self.written + buf.len() as u64 > self.max_bytes {
+            self.rotate()?;
+        }
+        self.file.write_all(buf)?;
+        self.written += buf.len() as u64;
+        Ok(())
+    }
+}
+
+/// Writer handed to the file layer; one record per formatted event.
+struct FileWriter;
+
+impl<'a> MakeWriter<'a> for FileWriter {
+    type Writer = FileWriter;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        FileWriter
+    }
+}
+
+impl Write for FileWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if let Some(file) = log_file().as_mut() {
+            file.write_record(buf)?;
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+// ── Logcat ──────────────────────────────────────────────────────────
+
+#[cfg(target_os = "android")]
+mod logcat {
+    use std::ffi::CString;
+    use std::io::{self, Write};
+
+    use tracing::{Level, Metadata};
+    use tracing_subscriber::fmt::MakeWriter;
+
+    unsafe extern "C" {
+        fn __android_log_write(
+            prio: i32,
+            tag: *const std::ffi::c_char,
+            text: *const std::ffi::c_char,
+        ) -> i32;
+    }
+
+    /// Sends each formatted event to logcat, tagged after its crate.
+    pub(super) struct Logcat;
+
+    /// Buffers one event and writes it to logcat on drop.
+    pub(super) struct LogcatLine {
+        tag: CString,
+        prio: i32,
+        buf: Vec<u8>,
+    }
+
+    impl<'a> MakeWriter<'a> for Logcat {
+        type Writer = LogcatLine;
+
+        fn make_writer(&'a self) -> Self::Writer {
+            LogcatLine {
+                tag: c"VISIO".into(),
+                prio: 4,
+                buf: Vec::new(),
+            }
+        }
+
+        fn make_writer_for(&'a self, meta: &Metadata<'_>) -> Self::Writer {
+            let krate = meta.target().split("::").next().unwrap_or("visio");
+            let tag = CString::new(krate.to_uppercase()).unwrap_or_else(|_| c"VISIO".into());
+            let prio = match *meta.level() {
+                Level::TRACE => 2,
+                Level::DEBUG => 3,
+                Level::INFO => 4,
+                Level::WARN => 5,
+                Level::ERROR => 6,
+            };
+            LogcatLine {
+                tag,
+                prio,
+                buf: Vec::new(),
+            }
+        }
+    }
+
+    impl Write for LogcatLine {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.buf.extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl Drop for LogcatLine {
+        fn drop(&mut self) {
+            while self.buf.last() == Some(&b'\n') {
+                self.buf.pop();
+            }
+            if self.buf.is_empty() {
+                return;
+            }
+            self.buf.retain(|&b| b != 0);
+            let text = CString::new(std::mem::take(&mut self.buf)).unwrap_or_default();
+            unsafe {
+                __android_log_write(self.prio, self.tag.as_ptr(), text.as_ptr());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn level_sets_every_visio_target() {
+        assert_eq!(
+            level_directives("DEBUG").unwrap(),
+            "visio_core=debug,visio_ffi=debug,visio_video=debug"
+        );
+        assert!(level_directives("loud").is_err());
+        assert!(set_log_targets("visio_core=[".into()).is_err());
+    }
+
+    #[test]
+    fn file_rotates_and_keeps_a_bounded_history() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut file = RotatingFile::open(dir.path(), 10).unwrap();
+        for record in ["aaaaaa\n", "bbbbbb\n", "cccccc\n", "dddddd\n", "eeeeee\n"] {
+            file.write_record(record.as_bytes()).unwrap();
+        }
+        let read = |name: &str| std::fs::read_to_string(dir.path().join(name)).unwrap();
+        assert_eq!(read("visio.log"), "eeeeee\n");
+        assert_eq!(read("visio.log.1"), "dddddd\n");
+        assert_eq!(read("visio.log.3"), "bbbbbb\n");
+        assert!(!dir.path().join("visio.log.4").exists());
+
+        // Reopening appends to the current file.
+        let reopened = RotatingFile::open(dir.path(), 10).unwrap();
+        assert_eq!(reopened.written, 7);
+    }
+}
@@ -0,0 +1,74 @@
+//! CPU render paths: I420 → RGBA (Android surface fallback) and
+//! I420 → JPEG (desktop).
+//!
+//! Run with `cargo bench -p visio-video --bench render`.
+
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use visio_video::convert::i420_to_rgba;
+
+const SIZES: [(&str, usize, usize); 3] = [
+    ("360p", 640, 360),
+    ("720p", 1280, 720),
+    ("1080p", 1920, 1080),
+];
+
+/// Packed I420 planes with gradients, so the JPEG encoder has detail to
+/// compress.
+fn test_frame(w: usize, h: usize) -> [Vec<u8>; 3] {
+    let (cw, ch) = (w.div_ceil(2), h.div_ceil(2));
+    let y = (0..w * h).map(|i| ((i % w) * 255 / w) as u8).collect();
+    let u = (0..cw * ch).map(|i| ((i / cw) * 255 / ch) as u8).collect();
+    let v = (0..cw * ch)
+        .map(|i| ((i % cw + i / cw) % 256) as u8)
+        .collect();
+    [y, u, v]
+}
+
+fn rgba(c: &mut Criterion) {
+    let mut group = c.benchmark_group("i420_to_rgba");
+    for (name, w, h) in SIZES {
+        let [y, u, v] = test_frame(w, h);
+        let cw = w.div_ceil(2);
+        // Same-size surface: every pixel is converted.
+        let mut surface = vec![0; w * h * 4];
+
+        group.throughput(Throughput::Elements((w * h) as u64));
+        group.bench_function(BenchmarkId::from_parameter(name), |b| {
+            b.iter(|| i420_to_rgba([&y, &u, &v], [w, cw, cw], (w, h), &mut surface, w, (w, h)))
+        });
+    }
+    group.finish();
+}
+
+#[cfg(any(target_os = "macos", target_os = "linux", target_os = "windows"))]
+fn jpeg(c: &mut Criterion) {
+    use visio_video::RenderProfile;
+    use visio_video::convert::i420_to_jpeg;
+
+    let mut group = c.benchmark_group("i420_to_jpeg");
+    for (name, w, h) in SIZES {
+        let [y, u, v] = test_frame(w, h);
+        let cw = w.div_ceil(2) as u32;
+        let quality = RenderProfile::CAMERA.jpeg_quality;
+
+        group.throughput(Throughput::Elements((w * h) as u64));
+        group.bench_function(BenchmarkId::from_parameter(name), |b| {
+            b.iter(|| {
+                i420_to_jpeg(
+                    [&y, &u, &v],
+                    [w as u32, cw, cw],
+                    w as u32,
+                    h as u32,
+                    quality,
+                )
+            })
+        });
+    }
+    group.finish();
+}
+
+#[cfg(any(target_os = "macos", target_os = "linux", target_os = "windows"))]
+criterion_group!(benches, rgba, jpeg);
+#[cfg(not(any(target_os = "macos", target_os = "linux", target_os = "windows")))]
+criterion_group!(benches, rgba);
+criterion_main!(benches);
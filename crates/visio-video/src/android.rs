@@ -2,9 +2,11 @@
 //!
 //! The native (Kotlin) side obtains an `ANativeWindow*` from its
 //! `SurfaceView` / `SurfaceTexture` via JNI and passes the raw pointer
-//! through `start_track_renderer`.  This module locks the window buffer,
-//! converts the incoming I420 video frame to RGBA, writes the pixels,
-//! and posts the result.  The `SurfaceView` takes care of display.
+//! through `start_track_renderer`.  Remote frames go through the GLES
+//! renderer (`android_gl`) when EGL is available.  Otherwise this module
+//! locks the window buffer, converts the incoming I420 video frame to
+//! RGBA, writes the pixels, and posts the result.  The `SurfaceView`
+//! takes care of display.
 
 use std::ffi::c_void;
 
@@ -12,6 +14,81 @@ use livekit::webrtc::prelude::BoxVideoFrame;
 use livekit::webrtc::video_frame::I420Buffer;
 use livekit::webrtc::video_frame::VideoBuffer;
 
+use crate::android_gl::{GlRenderer, Plane};
+
+/// Per-track renderer: GPU first, CPU fallback.
+///
+/// The GLES renderer is created on the first frame. If EGL setup or any
+/// draw fails, it is dropped (releasing the window) and the track stays
+/// on the CPU path for the rest of its lifetime.
+pub(crate) struct AndroidRenderer {
+    gl: Option<GlRenderer>,
+    gl_failed: bool,
+}
+
+impl AndroidRenderer {
+    pub(crate) fn new() -> Self {
+        Self {
+            gl: None,
+            gl_failed: false,
+        }
+    }
+
+    pub(crate) fn render(
+        &mut self,
+        frame: &BoxVideoFrame,
+        surface: *mut c_void,
+        track_sid: &str,
+        allow_downscale: bool,
+    ) {
+        if !self.gl_failed {
+            if self.gl.is_none() {
+                self.gl = GlRenderer::new(surface);
+                if self.gl.is_none() {
+                    tracing::warn!(
+                        "GPU renderer unavailable for track {track_sid}, using CPU path"
+                    );
+                    self.gl_failed = true;
+                }
+            }
+            if let Some(gl) = self.gl.as_mut() {
+                let i420 = frame.buffer.to_i420();
+                let (width, height) = (i420.width(), i420.height());
+                let (chroma_w, chroma_h) = (width.div_ceil(2), height.div_ceil(2));
+                let (y_data, u_data, v_data) = i420.data();
+                let (stride_y, stride_u, stride_v) = i420.strides();
+                let planes = [
+                    Plane {
+                        data: y_data,
+                        stride: stride_y,
+                        width,
+                        height,
+                    },
+                    Plane {
+                        data: u_data,
+                        stride: stride_u,
+                        width: chroma_w,
+                        height: chroma_h,
+                    },
+                    Plane {
+                        data: v_data,
+                        stride: stride_v,
+                        width: chroma_w,
+                        height: chroma_h,
+                    },
+                ];
+                if gl.render(planes) {
+                    return;
+                }
+                tracing::warn!("GPU render failed for track {track_sid}, falling back to CPU path");
+                self.gl = None;
+                self.gl_failed = true;
+            }
+        }
+        render_frame(frame, surface, track_sid, allow_downscale);
+    }
+}
+
 /// Render raw I420 planes to an ANativeWindow surface with rotation and mirror.
 ///
 /// Used for local camera self-view: the I420 buffer is already constructed
@@ -67,11 +144,8 @@ pub fn render_i420_to_surface(
         }
 
         let mut native_buf = std::mem::MaybeUninit::<ndk_sys::ANativeWindow_Buffer>::uninit();
-        let lock_result = ndk_sys::ANativeWindow_lock(
-            window,
-            native_buf.as_mut_ptr(),
-            std::ptr::null_mut(),
-        );
+        let lock_result =
+            ndk_sys::ANativeWindow_lock(window, native_buf.as_mut_ptr(), std::ptr::null_mut());
         if lock_result != 0 {
             return;
         }
@@ -149,6 +223,9 @@ pub fn render_i420_to_surface(
 /// * `frame`     — the video frame from the LiveKit NativeVideoStream
 /// * `surface`   — an `ANativeWindow*` obtained via `ANativeWindow_fromSurface()`
 /// * `track_sid` — identifies which track this frame belongs to (for logging)
+/// * `allow_downscale` — when `false` (screen shares), a video larger than
+///   the surface is drawn 1:1 into an enlarged buffer that the compositor
+///   scales down with filtering, instead of dropping pixels here
 ///
 /// # Safety contract (upheld by caller)
 /// `surface` must be a valid, non-null `ANativeWindow*` that remains alive for
@@ -157,6 +234,7 @@ pub(crate) fn render_frame(
     frame: &BoxVideoFrame,
     surface: *mut c_void,
     _track_sid: &str,
+    allow_downscale: bool,
 ) {
     let buffer = &frame.buffer;
     let width = buffer.width() as usize;
@@ -178,12 +256,24 @@ pub(crate) fn render_frame(
 
     unsafe {
         // Use the surface's actual dimensions for letterboxing.
-        let surf_w = ndk_sys::ANativeWindow_getWidth(window) as usize;
-        let surf_h = ndk_sys::ANativeWindow_getHeight(window) as usize;
-        if surf_w == 0 || surf_h == 0 {
+        let view_w = ndk_sys::ANativeWindow_getWidth(window) as usize;
+        let view_h = ndk_sys::ANativeWindow_getHeight(window) as usize;
+        if view_w == 0 || view_h == 0 {
             return;
         }
 
+        // Keep the view's aspect ratio, but grow the buffer so the whole
+        // video fits without downscaling when that is not allowed.
+        let (surf_w, surf_h) = if !allow_downscale && (width > view_w || height > view_h) {
+            let s = (width as f64 / view_w as f64).max(height as f64 / view_h as f64);
+            (
+                ((view_w as f64 * s).ceil() as usize).max(width),
+                ((view_h as f64 * s).ceil() as usize).max(height),
+            )
+        } else {
+            (view_w, view_h)
+        };
+
         let result = ndk_sys::ANativeWindow_setBuffersGeometry(
             window,
             surf_w as i32,
@@ -197,11 +287,8 @@ pub(crate) fn render_frame(
 
         // Lock the surface buffer for writing.
         let mut native_buf = std::mem::MaybeUninit::<ndk_sys::ANativeWindow_Buffer>::uninit();
-        let lock_result = ndk_sys::ANativeWindow_lock(
-            window,
-            native_buf.as_mut_ptr(),
-            std::ptr::null_mut(),
-        );
+        let lock_result =
+            ndk_sys::ANativeWindow_lock(window, native_buf.as_mut_ptr(), std::ptr::null_mut());
         if lock_result != 0 {
             tracing::warn!("ANativeWindow_lock failed: {lock_result}");
             return;
@@ -217,50 +304,15 @@ pub(crate) fn render_frame(
             return;
         }
 
-        // Clear to opaque black.
-        let pixels = bits as *mut u32;
-        for i in 0..(surf_h * dst_stride) {
-            *pixels.add(i) = 0xFF000000u32;
-        }
-
-        // Fit video inside surface preserving aspect ratio (letterbox).
-        let scale = (surf_w as f64 / width as f64).min(surf_h as f64 / height as f64);
-        let render_w = (width as f64 * scale) as usize;
-        let render_h = (height as f64 * scale) as usize;
-        let off_x = (surf_w - render_w) / 2;
-        let off_y = (surf_h - render_h) / 2;
-
-        // ---------------------------------------------------------------
-        // I420 → RGBA conversion (BT.601 full-range) with letterbox
-        // ---------------------------------------------------------------
-        for out_row in 0..render_h {
-            for out_col in 0..render_w {
-                // Nearest-neighbour scale to source coordinates.
-                let src_row = out_row * height / render_h;
-                let src_col = out_col * width / render_w;
-
-                let y_idx = src_row * y_stride + src_col;
-                let u_idx = (src_row / 2) * u_stride + (src_col / 2);
-                let v_idx = (src_row / 2) * v_stride + (src_col / 2);
-
-                let y = y_data[y_idx] as f32;
-                let u = u_data[u_idx] as f32 - 128.0;
-                let v = v_data[v_idx] as f32 - 128.0;
-
-                let r = (y + 1.402 * v).clamp(0.0, 255.0) as u8;
-                let g = (y - 0.344136 * u - 0.714136 * v).clamp(0.0, 255.0) as u8;
-                let b = (y + 1.772 * u).clamp(0.0, 255.0) as u8;
-
-                let dx = out_col + off_x;
-                let dy = out_row + off_y;
-                let out_offset = (dy * dst_stride + dx) * 4;
-                debug_assert!(out_offset + 3 < surf_h * dst_stride * 4);
-                *bits.add(out_offset) = r;
-                *bits.add(out_offset + 1) = g;
-                *bits.add(out_offset + 2) = b;
-                *bits.add(out_offset + 3) = 255;
-            }
-        }
+        let pixels = std::slice::from_raw_parts_mut(bits, surf_h * dst_stride * 4);
+        crate::convert::i420_to_rgba(
+            [y_data, u_data, v_data],
+            [y_stride, u_stride, v_stride],
+            (width, height),
+            pixels,
+            dst_stride,
+            (surf_w, surf_h),
+        );
 
         ndk_sys::ANativeWindow_unlockAndPost(window);
     }
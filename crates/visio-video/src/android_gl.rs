@@ -0,0 +1,527 @@
+//! Android GPU video renderer — EGL + OpenGL ES 2.0 on an ANativeWindow.
+//!
+//! Uploads the Y, U and V planes as three luminance textures and converts
+//! to RGB in a fragment shader (BT.601 full-range, same as the CPU path).
+//! Scaling and letterboxing are done by the GPU with linear filtering, so
+//! no per-pixel work happens on the CPU and no window buffer is locked.
+//!
+//! EGL and GLES are called through raw bindings to the system `libEGL` and
+//! `libGLESv2`. The EGL context is made current for the duration of each
+//! `render` call and released afterwards, because the frame loop is a
+//! tokio task and may run on a different worker thread for every frame.
+
+use std::ffi::{c_char, c_void};
+
+type EGLDisplay = *mut c_void;
+type EGLConfig = *mut c_void;
+type EGLSurface = *mut c_void;
+type EGLContext = *mut c_void;
+type EGLBoolean = u32;
+type EGLint = i32;
+
+const EGL_TRUE: EGLBoolean = 1;
+const EGL_NONE: EGLint = 0x3038;
+const EGL_RED_SIZE: EGLint = 0x3024;
+const EGL_GREEN_SIZE: EGLint = 0x3023;
+const EGL_BLUE_SIZE: EGLint = 0x3022;
+const EGL_SURFACE_TYPE: EGLint = 0x3033;
+const EGL_WINDOW_BIT: EGLint = 0x0004;
+const EGL_RENDERABLE_TYPE: EGLint = 0x3040;
+const EGL_OPENGL_ES2_BIT: EGLint = 0x0004;
+const EGL_CONTEXT_CLIENT_VERSION: EGLint = 0x3098;
+const EGL_WIDTH: EGLint = 0x3057;
+const EGL_HEIGHT: EGLint = 0x3056;
+
+#[link(name = "EGL")]
+unsafe extern "C" {
+    fn eglGetDisplay(display_id: *mut c_void) -> EGLDisplay;
+    fn eglInitialize(dpy: EGLDisplay, major: *mut EGLint, minor: *mut EGLint) -> EGLBoolean;
+    fn eglChooseConfig(
+        dpy: EGLDisplay,
+        attrib_list: *const EGLint,
+        configs: *mut EGLConfig,
+        config_size: EGLint,
+        num_config: *mut EGLint,
+    ) -> EGLBoolean;
+    fn eglCreateWindowSurface(
+        dpy: EGLDisplay,
+        config: EGLConfig,
+        win: *mut c_void,
+        attrib_list: *const EGLint,
+    ) -> EGLSurface;
+    fn eglCreateContext(
+        dpy: EGLDisplay,
+        config: EGLConfig,
+        share_context: EGLContext,
+        attrib_list: *const EGLint,
+    ) -> EGLContext;
+    fn eglMakeCurrent(
+        dpy: EGLDisplay,
+        draw: EGLSurface,
+        read: EGLSurface,
+        ctx: EGLContext,
+    ) -> EGLBoolean;
+    fn eglSwapBuffers(dpy: EGLDisplay, surface: EGLSurface) -> EGLBoolean;
+    fn eglQuerySurface(
+        dpy: EGLDisplay,
+        surface: EGLSurface,
+        attribute: EGLint,
+        value: *mut EGLint,
+    ) -> EGLBoolean;
+    fn eglDestroySurface(dpy: EGLDisplay, surface: EGLSurface) -> EGLBoolean;
+    fn eglDestroyContext(dpy: EGLDisplay, ctx: EGLContext) -> EGLBoolean;
+    fn eglGetError() -> EGLint;
+}
+
+type GLenum = u32;
+type GLuint = u32;
+type GLint = i32;
+
+const GL_NO_ERROR: GLenum = 0;
+const GL_TEXTURE_2D: GLenum = 0x0DE1;
+const GL_TEXTURE0: GLenum = 0x84C0;
+const GL_LUMINANCE: GLenum = 0x1909;
+const GL_UNSIGNED_BYTE: GLenum = 0x1401;
+const GL_FLOAT: GLenum = 0x1406;
+const GL_TEXTURE_MIN_FILTER: GLenum = 0x2801;
+const GL_TEXTURE_MAG_FILTER: GLenum = 0x2800;
+const GL_TEXTURE_WRAP_S: GLenum = 0x2802;
+const GL_TEXTURE_WRAP_T: GLenum = 0x2803;
+const GL_LINEAR: GLint = 0x2601;
+const GL_CLAMP_TO_EDGE: GLint = 0x812F;
+const GL_UNPACK_ALIGNMENT: GLenum = 0x0CF5;
+const GL_COLOR_BUFFER_BIT: GLenum = 0x4000;
+const GL_TRIANGLE_STRIP: GLenum = 0x0005;
+const GL_VERTEX_SHADER: GLenum = 0x8B31;
+const GL_FRAGMENT_SHADER: GLenum = 0x8B30;
+const GL_COMPILE_STATUS: GLenum = 0x8B81;
+const GL_LINK_STATUS: GLenum = 0x8B82;
+
+#[link(name = "GLESv2")]
+unsafe extern "C" {
+    fn glCreateShader(kind: GLenum) -> GLuint;
+    fn glShaderSource(
+        shader: GLuint,
+        count: GLint,
+        string: *const *const c_char,
+        length: *const GLint,
+    );
+    fn glCompileShader(shader: GLuint);
+    fn glGetShaderiv(shader: GLuint, pname: GLenum, params: *mut GLint);
+    fn glDeleteShader(shader: GLuint);
+    fn glCreateProgram() -> GLuint;
+    fn glAttachShader(program: GLuint, shader: GLuint);
+    fn glLinkProgram(program: GLuint);
+    fn glGetProgramiv(program: GLuint, pname: GLenum, params: *mut GLint);
+    fn glDeleteProgram(program: GLuint);
+    fn glUseProgram(program: GLuint);
+    fn glGetAttribLocation(program: GLuint, name: *const c_char) -> GLint;
+    fn glGetUniformLocation(program: GLuint, name: *const c_char) -> GLint;
+    fn glUniform1i(location: GLint, v0: GLint);
+    fn glUniform3f(location: GLint, v0: f32, v1: f32, v2: f32);
+    fn glGenTextures(n: GLint, textures: *mut GLuint);
+    fn glDeleteTextures(n: GLint, textures: *const GLuint);
+    fn glBindTexture(target: GLenum, texture: GLuint);
+    fn glActiveTexture(texture: GLenum);
+    fn glTexParameteri(target: GLenum, pname: GLenum, param: GLint);
+    #[allow(clippy::too_many_arguments)]
+    fn glTexImage2D(
+        target: GLenum,
+        level: GLint,
+        internalformat: GLint,
+        width: GLint,
+        height: GLint,
+        border: GLint,
+        format: GLenum,
+        kind: GLenum,
+        pixels: *const c_void,
+    );
+    #[allow(clippy::too_many_arguments)]
+    fn glTexSubImage2D(
+        target: GLenum,
+        level: GLint,
+        xoffset: GLint,
+        yoffset: GLint,
+        width: GLint,
+        height: GLint,
+        format: GLenum,
+        kind: GLenum,
+        pixels: *const c_void,
+    );
+    fn glPixelStorei(pname: GLenum, param: GLint);
+    fn glViewport(x: GLint, y: GLint, width: GLint, height: GLint);
+    fn glClearColor(r: f32, g: f32, b: f32, a: f32);
+    fn glClear(mask: GLenum);
+    fn glVertexAttribPointer(
+        index: GLuint,
+        size: GLint,
+        kind: GLenum,
+        normalized: u8,
+        stride: GLint,
+        pointer: *const c_void,
+    );
+    fn glEnableVertexAttribArray(index: GLuint);
+    fn glDrawArrays(mode: GLenum, first: GLint, count: GLint);
+    fn glGetError() -> GLenum;
+}
+
+const VERTEX_SHADER: &std::ffi::CStr = c"
+attribute vec2 a_position;
+attribute vec2 a_texcoord;
+varying vec2 v_texcoord;
+void main() {
+    gl_Position = vec4(a_position, 0.0, 1.0);
+    v_texcoord = a_texcoord;
+}
+";
+
+/// `u_crop` maps [0, 1] to the visible part of each plane: textures are
+/// uploaded at stride width since GLES 2 has no `GL_UNPACK_ROW_LENGTH`.
+const FRAGMENT_SHADER: &std::ffi::CStr = c"
+precision mediump float;
+varying vec2 v_texcoord;
+uniform sampler2D u_y;
+uniform sampler2D u_u;
+uniform sampler2D u_v;
+uniform vec3 u_crop;
+void main() {
+    float y = texture2D(u_y, vec2(v_texcoord.x * u_crop.x, v_texcoord.y)).r;
+    float u = texture2D(u_u, vec2(v_texcoord.x * u_crop.y, v_texcoord.y)).r - 0.5;
+    float v = texture2D(u_v, vec2(v_texcoord.x * u_crop.z, v_texcoord.y)).r - 0.5;
+    gl_FragColor = vec4(
+        clamp(y + 1.402 * v, 0.0, 1.0),
+        clamp(y - 0.344136 * u - 0.714136 * v, 0.0, 1.0),
+        clamp(y + 1.772 * u, 0.0, 1.0),
+        1.0);
+}
+";
+
+/// Full-screen quad as a triangle strip; texture row 0 is the top of the frame.
+const POSITIONS: [f32; 8] = [-1.0, -1.0, 1.0, -1.0, -1.0, 1.0, 1.0, 1.0];
+const TEXCOORDS: [f32; 8] = [0.0, 1.0, 1.0, 1.0, 0.0, 0.0, 1.0, 0.0];
+
+/// One I420 plane to upload.
+pub(crate) struct Plane<'a> {
+    pub data: &'a [u8],
+    pub stride: u32,
+    /// Visible width in pixels (<= stride).
+    pub width: u32,
+    pub height: u32,
+}
+
+pub(crate) struct GlRenderer {
+    display: EGLDisplay,
+    surface: EGLSurface,
+    context: EGLContext,
+    program: GLuint,
+    textures: [GLuint; 3],
+    /// (stride, height) each texture was last allocated with.
+    texture_sizes: [(u32, u32); 3],
+    a_position: GLuint,
+    a_texcoord: GLuint,
+    u_crop: GLint,
+}
+
+// SAFETY: The renderer is owned by a single frame loop task and is only
+// used from one thread at a time; the EGL context is released at the end
+// of every `render` call so the task may resume on another thread.
+unsafe impl Send for GlRenderer {}
+
+impl GlRenderer {
+    /// Create an EGL window surface and GLES 2 context on `window`
+    /// (an `ANativeWindow*`). Returns `None` if any step fails; the window
+    /// is then left untouched for the CPU path.
+    pub(crate) fn new(window: *mut c_void) -> Option<Self> {
+        unsafe {
+            let display = eglGetDisplay(std::ptr::null_mut());
+            if display.is_null()
+                || eglInitialize(display, std::ptr::null_mut(), std::ptr::null_mut()) != EGL_TRUE
+            {
+                tracing::warn!("EGL display unavailable (error {:#x})", eglGetError());
+                return None;
+            }
+
+            let config_attribs = [
+                EGL_RED_SIZE,
+                8,
+                EGL_GREEN_SIZE,
+                8,
+                EGL_BLUE_SIZE,
+                8,
+                EGL_SURFACE_TYPE,
+                EGL_WINDOW_BIT,
+                EGL_RENDERABLE_TYPE,
+                EGL_OPENGL_ES2_BIT,
+                EGL_NONE,
+            ];
+            let mut config: EGLConfig = std::ptr::null_mut();
+            let mut num_configs = 0;
+            if eglChooseConfig(
+                display,
+                config_attribs.as_ptr(),
+                &mut config,
+                1,
+                &mut num_configs,
+            ) != EGL_TRUE
+                || num_configs == 0
+            {
+                tracing::warn!("no suitable EGL config (error {:#x})", eglGetError());
+                return None;
+            }
+
+            let surface = eglCreateWindowSurface(display, config, window, [EGL_NONE].as_ptr());
+            if surface.is_null() {
+                tracing::warn!("eglCreateWindowSurface failed (error {:#x})", eglGetError());
+                return None;
+            }
+
+            let context_attribs = [EGL_CONTEXT_CLIENT_VERSION, 2, EGL_NONE];
+            let context = eglCreateContext(
+                display,
+                config,
+                std::ptr::null_mut(),
+                context_attribs.as_ptr(),
+            );
+            if context.is_null() {
+                tracing::warn!("eglCreateContext failed (error {:#x})", eglGetError());
+                eglDestroySurface(display, surface);
+                return None;
+            }
+
+            let mut renderer = Self {
+                display,
+                surface,
+                context,
+                program: 0,
+                textures: [0; 3],
+                texture_sizes: [(0, 0); 3],
+                a_position: 0,
+                a_texcoord: 0,
+                u_crop: -1,
+            };
+            // Dropping `renderer` on failure destroys the context and surface.
+            if eglMakeCurrent(display, surface, surface, context) != EGL_TRUE {
+                tracing::warn!("eglMakeCurrent failed (error {:#x})", eglGetError());
+                return None;
+            }
+            let ok = renderer.init_gl();
+            renderer.release_current();
+            ok.then_some(renderer)
+        }
+    }
+
+    /// Compile the shaders and create the plane textures.
+    /// The context must be current.
+    unsafe fn init_gl(&mut self) -> bool {
+        unsafe {
+            let Some(vs) = compile_shader(GL_VERTEX_SHADER, VERTEX_SHADER) else {
+                return false;
+            };
+            let Some(fs) = compile_shader(GL_FRAGMENT_SHADER, FRAGMENT_SHADER) else {
+                glDeleteShader(vs);
+                return false;
+            };
+            let program = glCreateProgram();
+            glAttachShader(program, vs);
+            glAttachShader(program, fs);
+            glLinkProgram(program);
+            glDeleteShader(vs);
+            glDeleteShader(fs);
+            let mut linked = 0;
+            glGetProgramiv(program, GL_LINK_STATUS, &mut linked);
+            if linked == 0 {
+                tracing::warn!("GLES program link failed");
+                glDeleteProgram(program);
+                return false;
+            }
+            self.program = program;
+
+            let a_position = glGetAttribLocation(program, c"a_position".as_ptr());
+            let a_texcoord = glGetAttribLocation(program, c"a_texcoord".as_ptr());
+            if a_position < 0 || a_texcoord < 0 {
+                return false;
+            }
+            self.a_position = a_position as GLuint;
+            self.a_texcoord = a_texcoord as GLuint;
+            self.u_crop = glGetUniformLocation(program, c"u_crop".as_ptr());
+
+            glUseProgram(program);
+            glGenTextures(3, self.textures.as_mut_ptr());
+            let samplers = [c"u_y", c"u_u", c"u_v"];
+            for (unit, (&texture, name)) in self.textures.iter().zip(samplers).enumerate() {
+                glActiveTexture(GL_TEXTURE0 + unit as GLenum);
+                glBindTexture(GL_TEXTURE_2D, texture);
+                glTexParameteri(GL_TEXTURE_2D, GL_TEXTURE_MIN_FILTER, GL_LINEAR);
+                glTexParameteri(GL_TEXTURE_2D, GL_TEXTURE_MAG_FILTER, GL_LINEAR);
+                glTexParameteri(GL_TEXTURE_2D, GL_TEXTURE_WRAP_S, GL_CLAMP_TO_EDGE);
+                glTexParameteri(GL_TEXTURE_2D, GL_TEXTURE_WRAP_T, GL_CLAMP_TO_EDGE);
+                glUniform1i(glGetUniformLocation(program, name.as_ptr()), unit as GLint);
+            }
+            glPixelStorei(GL_UNPACK_ALIGNMENT, 1);
+            glGetError() == GL_NO_ERROR
+        }
+    }
+
+    /// Draw one I420 frame (Y, U, V planes) letterboxed into the surface.
+    ///
+    /// Returns `false` on any EGL/GL error; the caller should drop the
+    /// renderer and fall back to the CPU path.
+    pub(crate) fn render(&mut self, planes: [Plane<'_>; 3]) -> bool {
+        let (width, height) = (planes[0].width, planes[0].height);
+        if width == 0 || height == 0 {
+            return true;
+        }
+        unsafe {
+            if eglMakeCurrent(self.display, self.surface, self.surface, self.context) != EGL_TRUE {
+                tracing::warn!("eglMakeCurrent failed (error {:#x})", eglGetError());
+                return false;
+            }
+            let ok = self.draw(&planes, width, height);
+            self.release_current();
+            ok
+        }
+    }
+
+    /// The context must be current.
+    unsafe fn draw(&mut self, planes: &[Plane<'_>; 3], width: u32, height: u32) -> bool {
+        unsafe {
+            let (mut surf_w, mut surf_h) = (0, 0);
+            eglQuerySurface(self.display, self.surface, EGL_WIDTH, &mut surf_w);
+            eglQuerySurface(self.display, self.surface, EGL_HEIGHT, &mut surf_h);
+            if surf_w <= 0 || surf_h <= 0 {
+                return true;
+            }
+
+            glUseProgram(self.program);
+            for (unit, plane) in planes.iter().enumerate() {
+                glActiveTexture(GL_TEXTURE0 + unit as GLenum);
+                glBindTexture(GL_TEXTURE_2D, self.textures[unit]);
+                let size = (plane.stride, plane.height);
+                if self.texture_sizes[unit] == size {
+                    glTexSubImage2D(
+                        GL_TEXTURE_2D,
+                        0,
+                        0,
+                        0,
+                        plane.stride as GLint,
+                        plane.height as GLint,
+                        GL_LUMINANCE,
+                        GL_UNSIGNED_BYTE,
+                        plane.data.as_ptr() as *const c_void,
+                    );
+                } else {
+                    glTexImage2D(
+                        GL_TEXTURE_2D,
+                        0,
+                        GL_LUMINANCE as GLint,
+                        plane.stride as GLint,
+                        plane.height as GLint,
+                        0,
+                        GL_LUMINANCE,
+                        GL_UNSIGNED_BYTE,
+                        plane.data.as_ptr() as *const c_void,
+                    );
+                    self.texture_sizes[unit] = size;
+                }
+            }
+            glUniform3f(
+                self.u_crop,
+                planes[0].width as f32 / planes[0].stride as f32,
+                planes[1].width as f32 / planes[1].stride as f32,
+                planes[2].width as f32 / planes[2].stride as f32,
+            );
+
+            // Fit video inside surface preserving aspect ratio (letterbox).
+            glClearColor(0.0, 0.0, 0.0, 1.0);
+            glClear(GL_COLOR_BUFFER_BIT);
+            let scale = (surf_w as f64 / width as f64).min(surf_h as f64 / height as f64);
+            let render_w = (width as f64 * scale) as GLint;
+            let render_h = (height as f64 * scale) as GLint;
+            glViewport(
+                (surf_w - render_w) / 2,
+                (surf_h - render_h) / 2,
+                render_w,
+                render_h,
+            );
+
+            glVertexAttribPointer(
+                self.a_position,
+                2,
+                GL_FLOAT,
+                0,
+                0,
+                POSITIONS.as_ptr() as *const c_void,
+            );
+            glEnableVertexAttribArray(self.a_position);
+            glVertexAttribPointer(
+                self.a_texcoord,
+                2,
+                GL_FLOAT,
+                0,
+                0,
+                TEXCOORDS.as_ptr() as *const c_void,
+            );
+            glEnableVertexAttribArray(self.a_texcoord);
+            glDrawArrays(GL_TRIANGLE_STRIP, 0, 4);
+
+            let gl_error = glGetError();
+            if gl_error != GL_NO_ERROR {
+                tracing::warn!("GLES draw failed (error {gl_error:#x})");
+                return false;
+            }
+            if eglSwapBuffers(self.display, self.surface) != EGL_TRUE {
+                tracing::warn!("eglSwapBuffers failed (error {:#x})", eglGetError());
+                return false;
+            }
+            true
+        }
+    }
+
+    fn release_current(&self) {
+        unsafe {
+            eglMakeCurrent(
+                self.display,
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+            );
+        }
+    }
+}
+
+impl Drop for GlRenderer {
+    fn drop(&mut self) {
+        unsafe {
+            if eglMakeCurrent(self.display, self.surface, self.surface, self.context) == EGL_TRUE {
+                if self.textures[0] != 0 {
+                    glDeleteTextures(3, self.textures.as_ptr());
+                }
+                if self.program != 0 {
+                    glDeleteProgram(self.program);
+                }
+                self.release_current();
+            }
+            // Destroying the surface disconnects the window from EGL so the
+            // CPU path can lock it again. The display is shared and stays up.
+            eglDestroyContext(self.display, self.context);
+            eglDestroySurface(self.display, self.surface);
+        }
+    }
+}
+
+unsafe fn compile_shader(kind: GLenum, source: &std::ffi::CStr) -> Option<GLuint> {
+    unsafe {
+        let shader = glCreateShader(kind);
+        let ptr = source.as_ptr();
+        glShaderSource(shader, 1, &ptr, std::ptr::null());
+        glCompileShader(shader);
+        let mut compiled = 0;
+        glGetShaderiv(shader, GL_COMPILE_STATUS, &mut compiled);
+        if compiled == 0 {
+            tracing::warn!("GLES shader compile failed (kind {kind:#x})");
+            glDeleteShader(shader);
+            return None;
+        }
+        Some(shader)
+    }
+}
@@ -0,0 +1,99 @@
+//! Pixel conversions of the CPU render paths.
+//!
+//! Kept apart from the platform renderers so they can be benchmarked
+//! (`cargo bench -p visio-video`) on any host.
+
+/// Convert I420 planes of `src_size` to RGBA (BT.601 full range) in
+/// `dst`, a `dst_size` buffer with rows `dst_stride` pixels apart. The
+/// video is scaled (nearest neighbour) to fit, keeping its aspect ratio;
+/// the rest of `dst` is opaque black.
+pub fn i420_to_rgba(
+    planes: [&[u8]; 3],
+    strides: [usize; 3],
+    src_size: (usize, usize),
+    dst: &mut [u8],
+    dst_stride: usize,
+    dst_size: (usize, usize),
+) {
+    let (width, height) = src_size;
+    let (surf_w, surf_h) = dst_size;
+    let [y_data, u_data, v_data] = planes;
+    let [y_stride, u_stride, v_stride] = strides;
+
+    for pixel in dst[..surf_h * dst_stride * 4].chunks_exact_mut(4) {
+        pixel.copy_from_slice(&[0, 0, 0, 255]);
+    }
+    if width == 0 || height == 0 {
+        return;
+    }
+
+    // Fit video inside surface preserving aspect ratio (letterbox).
+    let scale = (surf_w as f64 / width as f64).min(surf_h as f64 / height as f64);
+    let render_w = (width as f64 * scale) as usize;
+    let render_h = (height as f64 * scale) as usize;
+    let off_x = (surf_w - render_w) / 2;
+    let off_y = (surf_h - render_h) / 2;
+
+    for out_row in 0..render_h {
+        let src_row = out_row * height / render_h;
+        let row_start = ((out_row + off_y) * dst_stride + off_x) * 4;
+        let out = &mut dst[row_start..row_start + render_w * 4];
+        for (out_col, pixel) in out.chunks_exact_mut(4).enumerate() {
+            let src_col = out_col * width / render_w;
+
+            let y = y_data[src_row * y_stride + src_col] as f32;
+            let u = u_data[(src_row / 2) * u_stride + src_col / 2] as f32 - 128.0;
+            let v = v_data[(src_row / 2) * v_stride + src_col / 2] as f32 - 128.0;
+
+            pixel[0] = (y + 1.402 * v).clamp(0.0, 255.0) as u8;
+            pixel[1] = (y - 0.344136 * u - 0.714136 * v).clamp(0.0, 255.0) as u8;
+            pixel[2] = (y + 1.772 * u).clamp(0.0, 255.0) as u8;
+            pixel[3] = 255;
+        }
+    }
+}
+
+/// Encode I420 planes as a JPEG of `quality` (1-100), or `None` if the
+/// encoder fails.
+#[cfg(any(target_os = "macos", target_os = "linux", target_os = "windows"))]
+pub fn i420_to_jpeg(
+    planes: [&[u8]; 3],
+    strides: [u32; 3],
+    width: u32,
+    height: u32,
+    quality: u8,
+) -> Option<Vec<u8>> {
+    use image::codecs::jpeg::JpegEncoder;
+    use image::{ImageBuffer, Rgb};
+
+    let [y_data, u_data, v_data] = planes;
+    let [stride_y, stride_u, stride_v] = strides;
+    let rgb = crate::snapshot::i420_to_rgb(
+        y_data, stride_y, u_data, stride_u, v_data, stride_v, width, height,
+    );
+    let img = ImageBuffer::<Rgb<u8>, Vec<u8>>::from_raw(width, height, rgb)?;
+
+    let mut jpeg = Vec::with_capacity(width as usize * height as usize / 4);
+    JpegEncoder::new_with_quality(&mut jpeg, quality)
+        .encode_image(&img)
+        .ok()?;
+    Some(jpeg)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn letterboxes_rgba() {
+        // 2x2 grey video in a 4x2 surface with 5-pixel rows.
+        let grey = [128u8; 4];
+        let mut dst = vec![7u8; 5 * 2 * 4];
+        let planes = [&grey[..], &grey, &grey];
+        i420_to_rgba(planes, [2, 1, 1], (2, 2), &mut dst, 5, (4, 2));
+
+        let (black, video) = ([0, 0, 0, 255], [128, 128, 128, 255]);
+        let row: Vec<[u8; 4]> = dst[..16].chunks(4).map(|p| p.try_into().unwrap()).collect();
+        assert_eq!(row, [black, video, video, black]);
+    }
+}
@@ -6,10 +6,10 @@
 use std::ffi::c_void;
 use std::sync::OnceLock;
 
-use image::codecs::jpeg::JpegEncoder;
-use image::{ImageBuffer, Rgb};
 use livekit::webrtc::prelude::{BoxVideoFrame, VideoBuffer};
 
+use crate::RenderProfile;
+
 /// Callback type: (track_sid, base64_data, data_len, width, height, user_data)
 type FrameCallback = unsafe extern "C" fn(
     track_sid: *const std::ffi::c_char,
@@ -49,6 +49,7 @@ pub unsafe extern "C" fn visio_video_set_desktop_callback(
 }
 
 /// Encode I420 planes to JPEG base64 and deliver via the registered callback.
+#[allow(clippy::too_many_arguments)]
 fn encode_and_deliver(
     y_data: &[u8],
     stride_y: u32,
@@ -59,51 +60,24 @@ fn encode_and_deliver(
     width: u32,
     height: u32,
     track_sid: &str,
+    quality: u8,
 ) {
     let Some(cb) = CALLBACK.get() else {
         tracing::warn!("desktop render: no callback registered");
         return;
     };
 
-    let w = width as usize;
-    let h = height as usize;
-
-    // I420 → RGB conversion (BT.601)
-    let mut rgb = vec![0u8; w * h * 3];
-
-    for row in 0..h {
-        for col in 0..w {
-            let y_idx = row * stride_y as usize + col;
-            let u_idx = (row / 2) * stride_u as usize + (col / 2);
-            let v_idx = (row / 2) * stride_v as usize + (col / 2);
-
-            let y = y_data[y_idx] as f32;
-            let u = u_data[u_idx] as f32 - 128.0;
-            let v = v_data[v_idx] as f32 - 128.0;
-
-            let r = (y + 1.402 * v).clamp(0.0, 255.0) as u8;
-            let g = (y - 0.344136 * u - 0.714136 * v).clamp(0.0, 255.0) as u8;
-            let b = (y + 1.772 * u).clamp(0.0, 255.0) as u8;
-
-            let out_idx = (row * w + col) * 3;
-            rgb[out_idx] = r;
-            rgb[out_idx + 1] = g;
-            rgb[out_idx + 2] = b;
-        }
-    }
-
-    // Encode as JPEG (quality 60 — good balance of size vs. quality).
-    let Some(img) = ImageBuffer::<Rgb<u8>, Vec<u8>>::from_raw(width, height, rgb) else {
-        tracing::warn!("buffer size mismatch for track {track_sid}");
-        return;
-    };
-
-    let mut jpeg_buf = Vec::with_capacity(w * h / 4);
-    let mut encoder = JpegEncoder::new_with_quality(&mut jpeg_buf, 60);
-    if encoder.encode_image(&img).is_err() {
+    // Encode as JPEG at the track's render profile quality.
+    let Some(jpeg_buf) = crate::convert::i420_to_jpeg(
+        [y_data, u_data, v_data],
+        [stride_y, stride_u, stride_v],
+        width,
+        height,
+        quality,
+    ) else {
         tracing::warn!("JPEG encode failed for track {track_sid}");
         return;
-    }
+    };
 
     // Base64 encode
     use base64::Engine;
@@ -131,6 +105,7 @@ pub(crate) fn render_frame(
     frame: &BoxVideoFrame,
     _surface: *mut c_void,
     track_sid: &str,
+    quality: u8,
 ) {
     let buffer = &frame.buffer;
     let width = buffer.width();
@@ -143,7 +118,7 @@ pub(crate) fn render_frame(
 
     encode_and_deliver(
         y_data, stride_y, u_data, stride_u, v_data, stride_v,
-        width, height, track_sid,
+        width, height, track_sid, quality,
     );
 }
 
@@ -154,6 +129,9 @@ pub fn render_local_i420(
     i420: &livekit::webrtc::prelude::I420Buffer,
     track_sid: &str,
 ) {
+    if !crate::self_view_enabled() {
+        return;
+    }
     let width = i420.width();
     let height = i420.height();
     let (y_data, u_data, v_data) = i420.data();
@@ -161,6 +139,6 @@ pub fn render_local_i420(
 
     encode_and_deliver(
         y_data, stride_y, u_data, stride_u, v_data, stride_v,
-        width, height, track_sid,
+        width, height, track_sid, RenderProfile::CAMERA.jpeg_quality,
     );
 }
@@ -2,6 +2,12 @@
 //!
 //! Swift side creates CVPixelBuffer from the planes and displays
 //! on an AVSampleBufferDisplayLayer for GPU-accelerated rendering.
+//!
+//! Alternatively, Swift registers a pixel buffer provider
+//! (`visio_video_set_ios_pixel_buffer_provider`): Rust then writes NV12
+//! straight into a locked buffer taken from a `CVPixelBufferPool`, which
+//! saves one full-frame copy per frame. The plane callback remains the
+//! fallback when the provider has no buffer to offer.
 
 use std::ffi::c_void;
 use std::sync::OnceLock;
@@ -36,6 +42,51 @@ unsafe impl Sync for IosCallbackInfo {}
 
 static IOS_CALLBACK: OnceLock<IosCallbackInfo> = OnceLock::new();
 
+/// Destination planes of a locked NV12 (bi-planar 4:2:0) pixel buffer,
+/// filled in by the provider's acquire callback.
+#[repr(C)]
+pub struct VisioNv12Buffer {
+    pub y_ptr: *mut u8,
+    pub y_stride: u32,
+    pub uv_ptr: *mut u8,
+    pub uv_stride: u32,
+    /// Opaque provider handle (e.g. a retained CVPixelBuffer), passed back
+    /// to the submit callback.
+    pub handle: *mut c_void,
+}
+
+/// Acquire: (width, height, track_sid, user_data, out) -> whether `out` was filled.
+/// The buffer must be at least `width` x `height` and stay locked until submit.
+type IosAcquireBuffer = unsafe extern "C" fn(
+    width: u32,
+    height: u32,
+    track_sid: *const std::ffi::c_char,
+    user_data: *mut c_void,
+    out: *mut VisioNv12Buffer,
+) -> bool;
+
+/// Submit: (handle, track_sid, written, user_data). Unlock the buffer and
+/// display it if `written`; otherwise just release it.
+type IosSubmitBuffer = unsafe extern "C" fn(
+    handle: *mut c_void,
+    track_sid: *const std::ffi::c_char,
+    written: bool,
+    user_data: *mut c_void,
+);
+
+struct IosBufferProvider {
+    acquire: IosAcquireBuffer,
+    submit: IosSubmitBuffer,
+    user_data: *mut c_void,
+}
+
+// SAFETY: Same contract as `IosCallbackInfo`: registered once at startup,
+// valid for the application's lifetime, synchronised on the Swift side.
+unsafe impl Send for IosBufferProvider {}
+unsafe impl Sync for IosBufferProvider {}
+
+static IOS_BUFFER_PROVIDER: OnceLock<IosBufferProvider> = OnceLock::new();
+
 /// Register a frame callback from Swift.
 ///
 /// # Safety
@@ -53,19 +104,79 @@ pub unsafe extern "C" fn visio_video_set_ios_callback(
     });
 }
 
+/// Register a pixel buffer provider from Swift.
+///
+/// # Safety
+/// - `acquire` and `submit` must point to valid functions with the
+///   `IosAcquireBuffer` / `IosSubmitBuffer` signatures.
+/// - Buffers returned by `acquire` must stay locked and writable until
+///   the matching `submit` call.
+/// - `user_data` must remain valid for the application's lifetime.
+/// - This function should be called at most once, before any frames arrive.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn visio_video_set_ios_pixel_buffer_provider(
+    acquire: IosAcquireBuffer,
+    submit: IosSubmitBuffer,
+    user_data: *mut c_void,
+) {
+    let _ = IOS_BUFFER_PROVIDER.set(IosBufferProvider {
+        acquire,
+        submit,
+        user_data,
+    });
+}
+
+/// Copy I420 planes into NV12 destination planes (Y copied row by row,
+/// U and V interleaved). Both are video range, so no value conversion.
+///
+/// # Safety
+/// `dst` planes must hold `height` rows of `y_stride` bytes and
+/// `height.div_ceil(2)` rows of `uv_stride` bytes.
+#[allow(clippy::too_many_arguments)]
+unsafe fn write_nv12(
+    y_data: &[u8],
+    stride_y: usize,
+    u_data: &[u8],
+    stride_u: usize,
+    v_data: &[u8],
+    stride_v: usize,
+    width: usize,
+    height: usize,
+    dst: &VisioNv12Buffer,
+) {
+    let (dst_y_stride, dst_uv_stride) = (dst.y_stride as usize, dst.uv_stride as usize);
+    for row in 0..height {
+        let src = &y_data[row * stride_y..row * stride_y + width];
+        unsafe {
+            std::ptr::copy_nonoverlapping(src.as_ptr(), dst.y_ptr.add(row * dst_y_stride), width);
+        }
+    }
+
+    let (chroma_w, chroma_h) = (width.div_ceil(2), height.div_ceil(2));
+    for row in 0..chroma_h {
+        let u_row = &u_data[row * stride_u..row * stride_u + chroma_w];
+        let v_row = &v_data[row * stride_v..row * stride_v + chroma_w];
+        let dst_row = unsafe {
+            std::slice::from_raw_parts_mut(dst.uv_ptr.add(row * dst_uv_stride), chroma_w * 2)
+        };
+        for (col, (u, v)) in u_row.iter().zip(v_row).enumerate() {
+            dst_row[col * 2] = *u;
+            dst_row[col * 2 + 1] = *v;
+        }
+    }
+}
+
 /// Render a single I420 frame by passing plane pointers to the iOS callback.
 ///
 /// The Swift callback receives raw Y/U/V plane pointers and strides so it can
 /// create a CVPixelBuffer (or copy into one from a pool) and enqueue it on an
 /// AVSampleBufferDisplayLayer for GPU-accelerated YUV-to-RGB conversion.
-pub(crate) fn render_frame(
-    frame: &BoxVideoFrame,
-    _surface: *mut c_void,
-    track_sid: &str,
-) {
-    let Some(cb) = IOS_CALLBACK.get() else {
+pub(crate) fn render_frame(frame: &BoxVideoFrame, _surface: *mut c_void, track_sid: &str) {
+    let provider = IOS_BUFFER_PROVIDER.get();
+    let callback = IOS_CALLBACK.get();
+    if provider.is_none() && callback.is_none() {
         return;
-    };
+    }
 
     let buffer = &frame.buffer;
     let width = buffer.width();
@@ -86,6 +197,57 @@ pub(crate) fn render_frame(
         Err(_) => return, // track_sid contained a null byte — skip frame
     };
 
+    // Preferred path: write NV12 directly into a pooled pixel buffer.
+    if let Some(provider) = provider {
+        let mut dst = VisioNv12Buffer {
+            y_ptr: std::ptr::null_mut(),
+            y_stride: 0,
+            uv_ptr: std::ptr::null_mut(),
+            uv_stride: 0,
+            handle: std::ptr::null_mut(),
+        };
+        let acquired = unsafe {
+            (provider.acquire)(
+                width,
+                height,
+                sid_cstr.as_ptr(),
+                provider.user_data,
+                &mut dst,
+            )
+        };
+        let (w, h) = (width as usize, height as usize);
+        if acquired
+            && !dst.y_ptr.is_null()
+            && !dst.uv_ptr.is_null()
+            && dst.y_stride as usize >= w
+            && dst.uv_stride as usize >= w.div_ceil(2) * 2
+        {
+            unsafe {
+                write_nv12(
+                    y_data,
+                    stride_y as usize,
+                    u_data,
+                    stride_u as usize,
+                    v_data,
+                    stride_v as usize,
+                    w,
+                    h,
+                    &dst,
+                );
+                (provider.submit)(dst.handle, sid_cstr.as_ptr(), true, provider.user_data);
+            }
+            return;
+        }
+        if acquired {
+            // Unusable buffer: release it and use the plane callback.
+            unsafe { (provider.submit)(dst.handle, sid_cstr.as_ptr(), false, provider.user_data) };
+        }
+    }
+
+    let Some(cb) = callback else {
+        return;
+    };
+
     unsafe {
         (cb.callback)(
             width,
@@ -5,11 +5,14 @@
 //! This crate bypasses UniFFI for zero-copy performance.
 
 use std::collections::HashMap;
-use std::ffi::{c_char, c_void, CStr};
-use std::sync::{Mutex, OnceLock};
+use std::ffi::{CStr, c_char, c_void};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
 
-use futures_util::StreamExt;
+use futures_util::{FutureExt, Stream, StreamExt};
 use livekit::prelude::*;
+use livekit::webrtc::prelude::BoxVideoFrame;
 use livekit::webrtc::video_stream::native::NativeVideoStream;
 use tokio::runtime::{Handle, Runtime};
 use tokio::sync::watch;
@@ -18,6 +21,9 @@ use tokio::task::JoinHandle;
 #[cfg(target_os = "android")]
 mod android;
 
+#[cfg(target_os = "android")]
+mod android_gl;
+
 #[cfg(target_os = "android")]
 pub use android::render_i420_to_surface;
 
@@ -26,7 +32,13 @@ fn android_log(msg: &str) {
     use std::ffi::CString;
     let text = CString::new(msg).unwrap_or_else(|_| c"(invalid)".into());
     unsafe {
-        unsafe extern "C" { fn __android_log_write(prio: i32, tag: *const std::ffi::c_char, text: *const std::ffi::c_char) -> i32; }
+        unsafe extern "C" {
+            fn __android_log_write(
+                prio: i32,
+                tag: *const std::ffi::c_char,
+                text: *const std::ffi::c_char,
+            ) -> i32;
+        }
         __android_log_write(4, c"VISIO_VIDEO".as_ptr(), text.as_ptr());
     }
 }
@@ -34,6 +46,13 @@ fn android_log(msg: &str) {
 #[cfg(target_os = "ios")]
 mod ios;
 
+pub mod convert;
+mod snapshot;
+mod stats;
+
+pub use stats::RendererStats;
+use stats::StatsRecorder;
+
 #[cfg(any(target_os = "macos", target_os = "linux", target_os = "windows"))]
 mod desktop;
 
@@ -60,6 +79,122 @@ struct SurfacePtr(*mut c_void);
 // valid until `stop_track_renderer` / `visio_video_detach_surface` is called.
 unsafe impl Send for SurfacePtr {}
 
+/// Surface a frame loop draws to, swappable with `replace_surface`.
+///
+/// The frame loop holds the lock while rendering, so once the swap returns
+/// the previous surface is no longer drawn to. `generation` is bumped on
+/// every swap so per-surface renderer state (Android EGL) is rebuilt.
+struct SurfaceSlot {
+    surface: SurfacePtr,
+    generation: u64,
+}
+
+// ---------------------------------------------------------------------------
+// Render profiles
+// ---------------------------------------------------------------------------
+
+/// Per-track rendering trade-offs, picked from the track source.
+///
+/// Camera video favours CPU use. Screen shares carry text and fine detail,
+/// so they are rendered at every frame with a higher JPEG quality on
+/// desktop, and never downscaled on the CPU on Android's fallback path
+/// (the compositor scales the full-resolution buffer instead). The Android
+/// GLES path and iOS always scale full-resolution planes on the GPU.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RenderProfile {
+    /// Desktop: render one frame out of `frame_divisor`.
+    pub frame_divisor: u64,
+    /// Desktop: JPEG quality (1-100).
+    pub jpeg_quality: u8,
+    /// Android: allow nearest-neighbour downscaling to the surface size.
+    pub allow_downscale: bool,
+}
+
+impl RenderProfile {
+    /// ~10 fps at 30 fps input, quality 60 — good balance of size vs. quality.
+    pub const CAMERA: Self = Self {
+        frame_divisor: 3,
+        jpeg_quality: 60,
+        allow_downscale: true,
+    };
+
+    /// Screen shares are published at low frame rates: keep every frame.
+    pub const SCREEN_SHARE: Self = Self {
+        frame_divisor: 1,
+        jpeg_quality: 85,
+        allow_downscale: false,
+    };
+
+    pub fn for_source(source: TrackSource) -> Self {
+        match source {
+            TrackSource::Screenshare => Self::SCREEN_SHARE,
+            _ => Self::CAMERA,
+        }
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Power saving
+// ---------------------------------------------------------------------------
+
+/// Highest frame rate drawn by renderers, 0 for no limit.
+static MAX_RENDER_FPS: AtomicU64 = AtomicU64::new(0);
+
+static SELF_VIEW_ENABLED: AtomicBool = AtomicBool::new(true);
+
+/// Limit the frame rate drawn by all renderers, running ones included.
+/// Frames in excess are counted as dropped. `None` removes the limit.
+pub fn set_max_render_fps(fps: Option<u32>) {
+    MAX_RENDER_FPS.store(fps.map_or(0, u64::from), Ordering::Relaxed);
+}
+
+/// Shortest time between two rendered frames, if rendering is limited.
+fn min_render_interval() -> Option<Duration> {
+    match MAX_RENDER_FPS.load(Ordering::Relaxed) {
+        0 => None,
+        fps => Some(Duration::from_micros(1_000_000 / fps)),
+    }
+}
+
+/// Enable or disable drawing of the local camera preview.
+pub fn set_self_view_enabled(enabled: bool) {
+    SELF_VIEW_ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+pub fn self_view_enabled() -> bool {
+    SELF_VIEW_ENABLED.load(Ordering::Relaxed)
+}
+
+// ---------------------------------------------------------------------------
+// Stall watchdog
+// ---------------------------------------------------------------------------
+
+/// Default time without frames after which an unmuted track is stalled.
+pub const DEFAULT_STALL_TIMEOUT: Duration = Duration::from_secs(5);
+
+static STALL_TIMEOUT_MS: AtomicU64 = AtomicU64::new(DEFAULT_STALL_TIMEOUT.as_millis() as u64);
+
+/// Change the stall timeout of renderers started from now on.
+pub fn set_stall_timeout(timeout: Duration) {
+    STALL_TIMEOUT_MS.store(timeout.as_millis() as u64, Ordering::Relaxed);
+}
+
+pub fn stall_timeout() -> Duration {
+    Duration::from_millis(STALL_TIMEOUT_MS.load(Ordering::Relaxed))
+}
+
+/// Frame delivery transitions reported by the watchdog.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StreamHealth {
+    /// No frame for the stall timeout while the track is not muted.
+    Stalled,
+    /// A frame arrived after `Stalled`.
+    Resumed,
+}
+
+/// Called from the frame loop with the track SID; must not block.
+pub type StreamHealthHandler = Arc<dyn Fn(&str, StreamHealth) + Send + Sync>;
+
 // ---------------------------------------------------------------------------
 // Global state
 // ---------------------------------------------------------------------------
@@ -68,6 +203,12 @@ unsafe impl Send for SurfacePtr {}
 struct TrackRenderer {
     cancel_tx: watch::Sender<bool>,
     _handle: JoinHandle<()>,
+    stats: Arc<StatsRecorder>,
+    surface: Arc<Mutex<SurfaceSlot>>,
+    /// Most recent frame, kept for snapshots.
+    last_frame: Arc<Mutex<Option<BoxVideoFrame>>>,
+    /// Off-screen tile: frames are kept but not drawn.
+    paused: Arc<AtomicBool>,
 }
 
 /// Registry of active track renderers, keyed by track SID.
@@ -105,26 +246,51 @@ fn runtime() -> &'static Runtime {
 /// Otherwise it falls back to visio-video's internal runtime. Callers should
 /// pass the application runtime handle to avoid cross-runtime issues (e.g.
 /// on Android where NativeVideoStream may not yield frames on a separate runtime).
+///
+/// `on_health` is told when the stream stalls and when it resumes, so the
+/// caller can try to recover (e.g. resubscribe the track).
 pub fn start_track_renderer(
     track_sid: String,
     track: RemoteVideoTrack,
     surface: *mut c_void,
     rt_handle: Option<Handle>,
+    on_health: Option<StreamHealthHandler>,
 ) {
     // If there is already a renderer for this track, stop it first.
     stop_track_renderer(&track_sid);
 
     let (cancel_tx, cancel_rx) = watch::channel(false);
     let sid = track_sid.clone();
+    let stats = Arc::new(StatsRecorder::default());
+    let surface = Arc::new(Mutex::new(SurfaceSlot {
+        surface: SurfacePtr(surface),
+        generation: 0,
+    }));
+    let last_frame = Arc::new(Mutex::new(None));
+    let paused = Arc::new(AtomicBool::new(false));
+    let task = frame_loop(
+        sid,
+        track,
+        surface.clone(),
+        last_frame.clone(),
+        paused.clone(),
+        cancel_rx,
+        stats.clone(),
+        on_health,
+    );
 
     let handle = match rt_handle {
-        Some(h) => h.spawn(frame_loop(sid, track, SurfacePtr(surface), cancel_rx)),
-        None => runtime().spawn(frame_loop(sid, track, SurfacePtr(surface), cancel_rx)),
+        Some(h) => h.spawn(task),
+        None => runtime().spawn(task),
     };
 
     let renderer = TrackRenderer {
         cancel_tx,
         _handle: handle,
+        stats,
+        surface,
+        last_frame,
+        paused,
     };
 
     renderers()
@@ -146,36 +312,160 @@ pub fn stop_track_renderer(track_sid: &str) {
     }
 }
 
+/// Stop every renderer, e.g. when the client shuts down.
+pub fn stop_all_renderers() {
+    let stopped: Vec<_> = renderers()
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .drain()
+        .collect();
+    for (track_sid, renderer) in stopped {
+        let _ = renderer.cancel_tx.send(true);
+        tracing::info!(track_sid, "renderer stopped");
+    }
+}
+
+/// Point the running renderer for `track_sid` at `new_surface` without
+/// restarting it, e.g. when a rotation recreates the platform surface.
+/// The video stream is kept, so no keyframe is lost and nothing flashes
+/// black.
+///
+/// Waits for an in-flight frame to finish. Returns the previous surface,
+/// whose ownership goes back to the caller (e.g. to release it), or `None`
+/// if no renderer is running for `track_sid`; `new_surface` is then left
+/// untouched and the caller should start a renderer instead.
+pub fn replace_surface(track_sid: &str, new_surface: *mut c_void) -> Option<*mut c_void> {
+    let slot = renderers()
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(track_sid)
+        .map(|renderer| renderer.surface.clone())?;
+    let mut slot = slot.lock().unwrap_or_else(|e| e.into_inner());
+    slot.generation += 1;
+    let previous = std::mem::replace(&mut slot.surface, SurfacePtr(new_surface));
+    tracing::info!(
+        track_sid,
+        generation = slot.generation,
+        "renderer surface replaced"
+    );
+    Some(previous.0)
+}
+
+/// Stop or resume drawing for `track_sid`, e.g. when its tile scrolls off
+/// screen. A paused renderer keeps its stream and surface, and its stall
+/// watchdog is suspended. Returns `false` if no renderer is running for
+/// the track.
+pub fn set_renderer_paused(track_sid: &str, paused: bool) -> bool {
+    let renderers = renderers().lock().unwrap_or_else(|e| e.into_inner());
+    let Some(renderer) = renderers.get(track_sid) else {
+        return false;
+    };
+    if renderer.paused.swap(paused, Ordering::Relaxed) != paused {
+        tracing::info!(track_sid, paused, "renderer pause changed");
+    }
+    true
+}
+
+/// Frame statistics of the running renderer for `track_sid`, if any.
+pub fn renderer_stats(track_sid: &str) -> Option<RendererStats> {
+    renderers()
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(track_sid)
+        .map(|renderer| renderer.stats.snapshot())
+}
+
+/// PNG of the last frame received by the renderer for `track_sid`, e.g. a
+/// slide of a screen share. `None` if no renderer is running for the
+/// track or no frame arrived yet.
+pub fn capture_snapshot(track_sid: &str) -> Option<Vec<u8>> {
+    let last_frame = renderers()
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(track_sid)
+        .map(|renderer| renderer.last_frame.clone())?;
+    let last_frame = last_frame.lock().unwrap_or_else(|e| e.into_inner());
+    let frame = last_frame.as_ref()?;
+    snapshot::frame_to_png(frame)
+        .inspect_err(|e| tracing::warn!(track_sid, "snapshot encoding failed: {e}"))
+        .ok()
+}
+
 // ---------------------------------------------------------------------------
 // Frame loop
 // ---------------------------------------------------------------------------
 
+/// Latest-frame-wins: drain every frame already queued behind `first` and
+/// return the most recent one with the number of frames it superseded.
+///
+/// NativeVideoStream queues frames without bound, so a renderer slower
+/// than the stream would otherwise fall further and further behind.
+fn take_latest<S>(stream: &mut S, first: S::Item) -> (S::Item, u64)
+where
+    S: Stream + Unpin,
+{
+    let mut latest = first;
+    let mut superseded = 0;
+    while let Some(Some(next)) = stream.next().now_or_never() {
+        latest = next;
+        superseded += 1;
+    }
+    (latest, superseded)
+}
+
 async fn frame_loop(
     track_sid: String,
     track: RemoteVideoTrack,
-    surface: SurfacePtr,
+    surface: Arc<Mutex<SurfaceSlot>>,
+    last_frame: Arc<Mutex<Option<BoxVideoFrame>>>,
+    paused: Arc<AtomicBool>,
     mut cancel_rx: watch::Receiver<bool>,
+    stats: Arc<StatsRecorder>,
+    on_health: Option<StreamHealthHandler>,
 ) {
     #[cfg(target_os = "android")]
-    android_log(&format!("VISIO VIDEO: frame_loop started for track={track_sid}, enabled={}, muted={}",
-        track.is_enabled(), track.is_muted()));
-    tracing::info!(track_sid = %track_sid, "frame_loop started");
+    android_log(&format!(
+        "VISIO VIDEO: frame_loop started for track={track_sid}, enabled={}, muted={}",
+        track.is_enabled(),
+        track.is_muted()
+    ));
+    let profile = RenderProfile::for_source(track.source());
+    tracing::info!(track_sid = %track_sid, ?profile, "frame_loop started");
 
     let rtc_track = track.rtc_track();
     #[cfg(target_os = "android")]
-    android_log(&format!("VISIO VIDEO: creating NativeVideoStream for track={track_sid}"));
+    android_log(&format!(
+        "VISIO VIDEO: creating NativeVideoStream for track={track_sid}"
+    ));
     let mut stream = NativeVideoStream::new(rtc_track);
     #[cfg(target_os = "android")]
-    android_log(&format!("VISIO VIDEO: NativeVideoStream created, waiting for frames track={track_sid}"));
+    android_log(&format!(
+        "VISIO VIDEO: NativeVideoStream created, waiting for frames track={track_sid}"
+    ));
 
     #[cfg(target_os = "android")]
     let mut android_frame_count: u64 = 0;
     #[cfg(target_os = "android")]
     let mut android_poll_count: u64 = 0;
+    #[cfg(target_os = "android")]
+    let mut android_renderer = android::AndroidRenderer::new();
+    #[cfg(target_os = "android")]
+    let mut android_surface_generation: u64 = 0;
 
-    // Desktop: only render every Nth frame to save CPU.
+    // Desktop: only render one frame in N (camera) to save CPU.
     #[cfg(any(target_os = "macos", target_os = "linux", target_os = "windows"))]
     let mut frame_count: u64 = 0;
+    #[cfg(any(target_os = "macos", target_os = "linux", target_os = "windows"))]
+    let mut next_render_at: u64 = profile.frame_divisor;
+
+    // Watchdog: muted and paused tracks legitimately carry no frames, so
+    // only an unmuted track silent for `stall_timeout` is reported, once
+    // per stall.
+    let stall_timeout = stall_timeout();
+    let mut stall_deadline = tokio::time::Instant::now() + stall_timeout;
+    let mut stalled = false;
+    // Power saving: see `set_max_render_fps`.
+    let mut last_render: Option<Instant> = None;
 
     loop {
         tokio::select! {
@@ -192,37 +482,91 @@ async fn frame_loop(
                     android_log(&format!("VISIO VIDEO: still waiting for frames track={track_sid} (poll #{android_poll_count}, got {android_frame_count} frames so far)"));
                 }
             }
+            _ = tokio::time::sleep_until(stall_deadline), if !stalled => {
+                if track.is_muted() || paused.load(Ordering::Relaxed) {
+                    stall_deadline = tokio::time::Instant::now() + stall_timeout;
+                } else {
+                    stalled = true;
+                    tracing::warn!(track_sid = %track_sid, timeout_ms = stall_timeout.as_millis() as u64, "video stream stalled");
+                    if let Some(on_health) = &on_health {
+                        on_health(&track_sid, StreamHealth::Stalled);
+                    }
+                }
+            }
             frame_opt = stream.next() => {
                 match frame_opt {
                     Some(frame) => {
+                        stall_deadline = tokio::time::Instant::now() + stall_timeout;
+                        if stalled {
+                            stalled = false;
+                            tracing::info!(track_sid = %track_sid, "video stream resumed");
+                            if let Some(on_health) = &on_health {
+                                on_health(&track_sid, StreamHealth::Resumed);
+                            }
+                        }
+                        let (frame, superseded) = take_latest(&mut stream, frame);
+                        stats.frames_received(1 + superseded);
+                        stats.frames_dropped(superseded);
+                        if superseded > 0 {
+                            tracing::trace!(track_sid = %track_sid, superseded, "renderer behind, skipped stale frames");
+                        }
+                        if paused.load(Ordering::Relaxed) {
+                            *last_frame.lock().unwrap_or_else(|e| e.into_inner()) = Some(frame);
+                            continue;
+                        }
+                        let render_start = Instant::now();
+                        if let (Some(min_interval), Some(last)) = (min_render_interval(), last_render)
+                            && render_start.duration_since(last) < min_interval
+                        {
+                            stats.frames_dropped(1);
+                            *last_frame.lock().unwrap_or_else(|e| e.into_inner()) = Some(frame);
+                            continue;
+                        }
+                        last_render = Some(render_start);
+                        let slot = surface.lock().unwrap_or_else(|e| e.into_inner());
+
                         // --- Android ---
                         #[cfg(target_os = "android")]
                         {
+                            if slot.generation != android_surface_generation {
+                                // The EGL surface is bound to the old window.
+                                android_surface_generation = slot.generation;
+                                android_renderer = android::AndroidRenderer::new();
+                            }
                             android_frame_count += 1;
                             if android_frame_count == 1 || android_frame_count % 100 == 0 {
                                 android_log(&format!("VISIO VIDEO: frame #{android_frame_count} track={track_sid} {}x{}", frame.buffer.width(), frame.buffer.height()));
                             }
-                            android::render_frame(&frame, surface.0, &track_sid);
+                            android_renderer.render(&frame, slot.surface.0, &track_sid, profile.allow_downscale);
+                            stats.frame_rendered(render_start.elapsed(), Instant::now());
                         }
 
                         // --- iOS ---
                         #[cfg(target_os = "ios")]
                         {
-                            ios::render_frame(&frame, surface.0, &track_sid);
+                            ios::render_frame(&frame, slot.surface.0, &track_sid);
+                            stats.frame_rendered(render_start.elapsed(), Instant::now());
                         }
 
                         // --- Desktop (macOS / Linux / Windows) ---
                         #[cfg(any(target_os = "macos", target_os = "linux", target_os = "windows"))]
                         {
-                            frame_count += 1;
-                            if frame_count == 1 {
+                            if frame_count == 0 {
                                 tracing::info!(track_sid = %track_sid, width = frame.buffer.width(), height = frame.buffer.height(), "first video frame received");
                             }
-                            // Throttle: render every 3rd frame (~10 fps at 30 fps input).
-                            if frame_count % 3 == 0 {
-                                desktop::render_frame(&frame, surface.0, &track_sid);
+                            // Skipped stale frames count towards the throttle.
+                            frame_count += 1 + superseded;
+                            if frame_count >= next_render_at {
+                                desktop::render_frame(&frame, slot.surface.0, &track_sid, profile.jpeg_quality);
+                                stats.frame_rendered(render_start.elapsed(), Instant::now());
+                                next_render_at = frame_count + profile.frame_divisor;
+                            } else {
+                                stats.frames_dropped(1);
                             }
                         }
+
+                        drop(slot);
+                        *last_frame.lock().unwrap_or_else(|e| e.into_inner()) = Some(frame);
                     }
                     None => {
                         #[cfg(target_os = "android")]
@@ -287,9 +631,7 @@ pub unsafe extern "C" fn visio_video_attach_surface(
 ///
 /// Returns 0 on success, -1 on invalid arguments.
 #[unsafe(no_mangle)]
-pub unsafe extern "C" fn visio_video_detach_surface(
-    track_sid: *const c_char,
-) -> i32 {
+pub unsafe extern "C" fn visio_video_detach_surface(track_sid: *const c_char) -> i32 {
     if track_sid.is_null() {
         return -1;
     }
@@ -303,3 +645,72 @@ pub unsafe extern "C" fn visio_video_detach_surface(
     stop_track_renderer(&sid);
     0
 }
+
+/// Swap the surface of a running renderer without restarting it
+/// (see `replace_surface`).
+///
+/// # Safety
+/// - `track_sid` must be a valid null-terminated C string.
+/// - `surface` must be a valid platform surface handle, as for
+///   `visio_video_attach_surface`.
+/// - `previous` must be null or point to writable storage for a pointer; on
+///   success it receives the surface that was replaced.
+///
+/// Returns 0 on success, -1 on invalid arguments, -2 if no renderer is
+/// running for the track.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn visio_video_replace_surface(
+    track_sid: *const c_char,
+    surface: *mut c_void,
+    previous: *mut *mut c_void,
+) -> i32 {
+    if track_sid.is_null() || surface.is_null() {
+        return -1;
+    }
+
+    let sid = match unsafe { CStr::from_ptr(track_sid) }.to_str() {
+        Ok(s) => s.to_owned(),
+        Err(_) => return -1,
+    };
+
+    match replace_surface(&sid, surface) {
+        Some(old) => {
+            if !previous.is_null() {
+                unsafe { *previous = old };
+            }
+            0
+        }
+        None => -2,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn take_latest_keeps_most_recent_queued_frame() {
+        let mut stream = futures_util::stream::iter(1..=5);
+        let first = stream.next().await.unwrap();
+        assert_eq!(take_latest(&mut stream, first), (5, 4));
+
+        // Nothing queued: the frame is rendered as is.
+        let mut empty = futures_util::stream::pending::<u32>();
+        assert_eq!(take_latest(&mut empty, 7), (7, 0));
+    }
+
+    #[test]
+    fn replace_surface_without_renderer_keeps_caller_surface() {
+        let mut surface = 0u8;
+        let ptr = &mut surface as *mut u8 as *mut c_void;
+        assert_eq!(replace_surface("TR_missing", ptr), None);
+    }
+
+    #[test]
+    fn max_render_fps_sets_min_interval() {
+        set_max_render_fps(Some(20));
+        assert_eq!(min_render_interval(), Some(Duration::from_millis(50)));
+        set_max_render_fps(None);
+        assert_eq!(min_render_interval(), None);
+    }
+}
@@ -0,0 +1,123 @@
+//! Still captures of remote video tracks.
+//!
+//! Every renderer keeps a reference to the last frame it received (the
+//! native buffer is refcounted, nothing is copied). A snapshot converts
+//! that frame to RGB, applies its rotation and encodes it as PNG.
+
+use livekit::webrtc::prelude::{BoxVideoFrame, VideoBuffer, VideoRotation};
+
+/// Convert I420 planes to packed RGB (BT.601, full range).
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn i420_to_rgb(
+    y_data: &[u8],
+    stride_y: u32,
+    u_data: &[u8],
+    stride_u: u32,
+    v_data: &[u8],
+    stride_v: u32,
+    width: u32,
+    height: u32,
+) -> Vec<u8> {
+    let w = width as usize;
+    let h = height as usize;
+    let mut rgb = vec![0u8; w * h * 3];
+
+    for row in 0..h {
+        for col in 0..w {
+            let y_idx = row * stride_y as usize + col;
+            let u_idx = (row / 2) * stride_u as usize + (col / 2);
+            let v_idx = (row / 2) * stride_v as usize + (col / 2);
+
+            let y = y_data[y_idx] as f32;
+            let u = u_data[u_idx] as f32 - 128.0;
+            let v = v_data[v_idx] as f32 - 128.0;
+
+            let out_idx = (row * w + col) * 3;
+            rgb[out_idx] = (y + 1.402 * v).clamp(0.0, 255.0) as u8;
+            rgb[out_idx + 1] = (y - 0.344136 * u - 0.714136 * v).clamp(0.0, 255.0) as u8;
+            rgb[out_idx + 2] = (y + 1.772 * u).clamp(0.0, 255.0) as u8;
+        }
+    }
+    rgb
+}
+
+/// Rotate packed RGB clockwise by `degrees` (0, 90, 180 or 270).
+/// Returns the pixels with the new width and height.
+fn rotate_rgb(rgb: Vec<u8>, width: u32, height: u32, degrees: u32) -> (Vec<u8>, u32, u32) {
+    let (w, h) = (width as usize, height as usize);
+    let (out_w, out_h) = match degrees {
+        90 | 270 => (h, w),
+        180 => (w, h),
+        _ => return (rgb, width, height),
+    };
+    let mut out = vec![0u8; rgb.len()];
+    for row in 0..h {
+        for col in 0..w {
+            let (x, y) = match degrees {
+                90 => (h - 1 - row, col),
+                180 => (w - 1 - col, h - 1 - row),
+                _ => (row, w - 1 - col),
+            };
+            let src = (row * w + col) * 3;
+            let dst = (y * out_w + x) * 3;
+            out[dst..dst + 3].copy_from_slice(&rgb[src..src + 3]);
+        }
+    }
+    (out, out_w as u32, out_h as u32)
+}
+
+fn encode_png(rgb: &[u8], width: u32, height: u32) -> Result<Vec<u8>, png::EncodingError> {
+    let mut out = Vec::new();
+    let mut encoder = png::Encoder::new(&mut out, width, height);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.write_header()?.write_image_data(rgb)?;
+    Ok(out)
+}
+
+/// Encode `frame` as an upright PNG.
+pub(crate) fn frame_to_png(frame: &BoxVideoFrame) -> Result<Vec<u8>, png::EncodingError> {
+    let i420 = frame.buffer.to_i420();
+    let (y_data, u_data, v_data) = i420.data();
+    let (stride_y, stride_u, stride_v) = i420.strides();
+    let (width, height) = (i420.width(), i420.height());
+    let rgb = i420_to_rgb(
+        y_data, stride_y, u_data, stride_u, v_data, stride_v, width, height,
+    );
+    let degrees = match frame.rotation {
+        VideoRotation::VideoRotation0 => 0,
+        VideoRotation::VideoRotation90 => 90,
+        VideoRotation::VideoRotation180 => 180,
+        VideoRotation::VideoRotation270 => 270,
+    };
+    let (rgb, width, height) = rotate_rgb(rgb, width, height, degrees);
+    encode_png(&rgb, width, height)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rotates_and_encodes_rgb() {
+        // 2x1 image: red, blue.
+        let rgb = vec![255, 0, 0, 0, 0, 255];
+        let (rotated, w, h) = rotate_rgb(rgb.clone(), 2, 1, 90);
+        assert_eq!((w, h), (1, 2));
+        assert_eq!(rotated, rgb);
+        let (rotated, _, _) = rotate_rgb(rgb.clone(), 2, 1, 180);
+        assert_eq!(rotated, vec![0, 0, 255, 255, 0, 0]);
+        let (rotated, w, h) = rotate_rgb(rgb.clone(), 2, 1, 270);
+        assert_eq!((w, h), (1, 2));
+        assert_eq!(rotated, vec![0, 0, 255, 255, 0, 0]);
+
+        // Grey I420 pixel converts to grey RGB.
+        assert_eq!(
+            i420_to_rgb(&[128], 1, &[128], 1, &[128], 1, 1, 1),
+            vec![128; 3]
+        );
+
+        let png = encode_png(&rgb, 2, 1).unwrap();
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+    }
+}
@@ -0,0 +1,103 @@
+//! Per-renderer frame statistics.
+//!
+//! Each `TrackRenderer` owns a `StatsRecorder` updated by its frame loop.
+//! `renderer_stats(track_sid)` returns a snapshot, so render performance
+//! (e.g. the desktop JPEG path) can be measured on real devices.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Window over which `RendererStats::fps` is computed.
+const FPS_WINDOW: Duration = Duration::from_secs(1);
+
+/// Snapshot of a renderer's counters.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RendererStats {
+    /// Frames pulled from the video stream.
+    pub frames_received: u64,
+    /// Frames handed to the platform renderer.
+    pub frames_rendered: u64,
+    /// Frames received but not rendered (throttled or superseded).
+    pub frames_dropped: u64,
+    /// Average time spent converting and delivering a rendered frame.
+    pub avg_render_ms: f64,
+    /// Rendered frames per second over the last complete window.
+    pub fps: f64,
+}
+
+#[derive(Default)]
+struct State {
+    stats: RendererStats,
+    total_render: Duration,
+    window_start: Option<Instant>,
+    window_frames: u64,
+}
+
+#[derive(Default)]
+pub(crate) struct StatsRecorder {
+    state: Mutex<State>,
+}
+
+impl StatsRecorder {
+    fn with_state<T>(&self, f: impl FnOnce(&mut State) -> T) -> T {
+        f(&mut self.state.lock().unwrap_or_else(|e| e.into_inner()))
+    }
+
+    pub(crate) fn frames_received(&self, count: u64) {
+        self.with_state(|s| s.stats.frames_received += count);
+    }
+
+    pub(crate) fn frames_dropped(&self, count: u64) {
+        self.with_state(|s| s.stats.frames_dropped += count);
+    }
+
+    pub(crate) fn frame_rendered(&self, took: Duration, now: Instant) {
+        self.with_state(|s| {
+            s.stats.frames_rendered += 1;
+            s.total_render += took;
+            s.stats.avg_render_ms =
+                s.total_render.as_secs_f64() * 1000.0 / s.stats.frames_rendered as f64;
+
+            let start = *s.window_start.get_or_insert(now);
+            s.window_frames += 1;
+            let elapsed = now.duration_since(start);
+            if elapsed >= FPS_WINDOW {
+                s.stats.fps = s.window_frames as f64 / elapsed.as_secs_f64();
+                s.window_start = Some(now);
+                s.window_frames = 0;
+            }
+        });
+    }
+
+    pub(crate) fn snapshot(&self) -> RendererStats {
+        self.with_state(|s| s.stats.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_frames_and_averages_render_time() {
+        let stats = StatsRecorder::default();
+        let t0 = Instant::now();
+        for i in 0..21u64 {
+            stats.frames_received(1);
+            if i % 2 == 0 {
+                let at = t0 + Duration::from_millis(i * 50);
+                stats.frame_rendered(Duration::from_millis(4 + i % 4), at);
+            } else {
+                stats.frames_dropped(1);
+            }
+        }
+        let snap = stats.snapshot();
+        assert_eq!(snap.frames_received, 21);
+        assert_eq!(snap.frames_rendered, 11);
+        assert_eq!(snap.frames_dropped, 10);
+        // Render times alternate 4 ms / 6 ms, starting and ending on 4 ms.
+        assert!((snap.avg_render_ms - 54.0 / 11.0).abs() < 1e-9);
+        // 11 frames over the first second (0..=1000 ms).
+        assert!((snap.fps - 11.0).abs() < 1e-9);
+    }
+}
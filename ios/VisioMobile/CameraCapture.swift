@@ -11,8 +11,15 @@ final class CameraCapture: NSObject, AVCaptureVideoDataOutputSampleBufferDelegat
     private var frameCount: UInt64 = 0
     private var currentPosition: AVCaptureDevice.Position = .front
     private var currentInput: AVCaptureDeviceInput?
+    // Torch and zoom requested through VisioClient camera commands
+    private var torchEnabled = false
+    private var zoomFactor: CGFloat = 1
+    // Target format from VisioClient; nil keeps the VGA default
+    private var constraints: CaptureConstraints?
 
-    func start() {
+    /// Start capturing from the camera with unique ID `preferredCameraId`,
+    /// falling back to the front camera when it is unset or unavailable.
+    func start(preferredCameraId: String? = nil) {
         // Configure and start on the camera queue (Apple warns against
         // calling startRunning() on the main queue).
         queue.async { [self] in
@@ -30,10 +37,13 @@ final class CameraCapture: NSObject, AVCaptureVideoDataOutputSampleBufferDelegat
             }
 
             session.beginConfiguration()
-            session.sessionPreset = .vga640x480
+            session.sessionPreset = sessionPreset()
 
-            // Try front camera first, then any camera.
-            var device = AVCaptureDevice.default(.builtInWideAngleCamera, for: .video, position: .front)
+            // Try the preferred camera, then front camera, then any camera.
+            var device = preferredCameraId.flatMap { AVCaptureDevice(uniqueID: $0) }
+            if device == nil {
+                device = AVCaptureDevice.default(.builtInWideAngleCamera, for: .video, position: .front)
+            }
             if device == nil {
                 NSLog("CameraCapture: no front camera, trying any position")
                 device = AVCaptureDevice.default(.builtInWideAngleCamera, for: .video, position: .unspecified)
@@ -72,10 +82,16 @@ final class CameraCapture: NSObject, AVCaptureVideoDataOutputSampleBufferDelegat
 
             session.commitConfiguration()
             session.startRunning()
+            configureDevice()
             NSLog("CameraCapture: session started, isRunning=%d", session.isRunning ? 1 : 0)
         }
     }
 
+    /// Unique ID of the built-in front or back camera.
+    static func cameraId(front: Bool) -> String? {
+        AVCaptureDevice.default(.builtInWideAngleCamera, for: .video, position: front ? .front : .back)?.uniqueID
+    }
+
     func switchCamera(toFront: Bool) {
         queue.async { [self] in
             let newPosition: AVCaptureDevice.Position = toFront ? .front : .back
@@ -103,10 +119,98 @@ final class CameraCapture: NSObject, AVCaptureVideoDataOutputSampleBufferDelegat
                 currentPosition = newPosition
             }
             session.commitConfiguration()
+            configureDevice()
             NSLog("CameraCapture: switched to %@ camera", toFront ? "front" : "back")
         }
     }
 
+    /// Apply a torch, zoom or focus command. Commands received before the
+    /// session starts are applied when it does.
+    func apply(_ command: CameraCommand) {
+        queue.async { [self] in
+            switch command {
+            case .setTorch(let enabled):
+                torchEnabled = enabled
+                configureDevice()
+            case .setZoom(let factor):
+                zoomFactor = CGFloat(factor)
+                configureDevice()
+            case .setFocusPoint(let x, let y):
+                // The point is given on the upright (portrait) image; the
+                // device expects landscape sensor coordinates.
+                configureDevice(focusPoint: CGPoint(x: CGFloat(y), y: CGFloat(1 - x)))
+            }
+        }
+    }
+
+    /// Apply new capture constraints: resolution, frame rate and low-light
+    /// mode change on the running session.
+    func apply(constraints newConstraints: CaptureConstraints) {
+        queue.async { [self] in
+            constraints = newConstraints
+            let preset = sessionPreset()
+            if session.sessionPreset != preset, session.canSetSessionPreset(preset) {
+                session.beginConfiguration()
+                session.sessionPreset = preset
+                session.commitConfiguration()
+            }
+            configureDevice()
+        }
+    }
+
+    private func sessionPreset() -> AVCaptureSession.Preset {
+        guard let constraints else { return .vga640x480 }
+        if constraints.height >= 720 { return .hd1280x720 }
+        if constraints.height >= 480 { return .vga640x480 }
+        return .cif352x288
+    }
+
+    /// Push torch, zoom and the optional focus point to the current device.
+    /// Must run on `queue`.
+    private func configureDevice(focusPoint: CGPoint? = nil) {
+        guard let device = currentInput?.device else { return }
+        do {
+            try device.lockForConfiguration()
+        } catch {
+            NSLog("CameraCapture: cannot configure device: %@", error.localizedDescription)
+            return
+        }
+        defer { device.unlockForConfiguration() }
+
+        if device.hasTorch, device.isTorchModeSupported(.on) {
+            device.torchMode = torchEnabled ? .on : .off
+        }
+        device.videoZoomFactor = min(max(zoomFactor, 1), device.activeFormat.videoMaxZoomFactor)
+        if let constraints {
+            // Cap the frame rate; in low-light mode let it drop to half
+            // for longer exposures.
+            let maxFps = Double(constraints.maxFps)
+            let minFps = constraints.lowLightBoost ? maxFps / 2 : maxFps
+            let ranges = device.activeFormat.videoSupportedFrameRateRanges
+            if ranges.contains(where: { $0.minFrameRate <= minFps && maxFps <= $0.maxFrameRate }) {
+                device.activeVideoMinFrameDuration = CMTime(value: 1, timescale: CMTimeScale(maxFps))
+                device.activeVideoMaxFrameDuration = CMTime(value: 1, timescale: CMTimeScale(minFps))
+            }
+            if device.isLowLightBoostSupported {
+                device.automaticallyEnablesLowLightBoostWhenAvailable = constraints.lowLightBoost
+            }
+        }
+        if let focusPoint {
+            if device.isFocusPointOfInterestSupported {
+                device.focusPointOfInterest = focusPoint
+                if device.isFocusModeSupported(.continuousAutoFocus) {
+                    device.focusMode = .continuousAutoFocus
+                }
+            }
+            if device.isExposurePointOfInterestSupported {
+                device.exposurePointOfInterest = focusPoint
+                if device.isExposureModeSupported(.continuousAutoExposure) {
+                    device.exposureMode = .continuousAutoExposure
+                }
+            }
+        }
+    }
+
     func isFront() -> Bool {
         return currentPosition == .front
     }
@@ -122,6 +226,9 @@ final class CameraCapture: NSObject, AVCaptureVideoDataOutputSampleBufferDelegat
 
     func captureOutput(_ output: AVCaptureOutput, didOutput sampleBuffer: CMSampleBuffer, from connection: AVCaptureConnection) {
         guard let pixelBuffer = CMSampleBufferGetImageBuffer(sampleBuffer) else { return }
+        // Presentation time is on the host clock (mach_absolute_time)
+        let pts = CMSampleBufferGetPresentationTimeStamp(sampleBuffer)
+        let timestampNs = pts.isValid ? Int64(CMTimeGetSeconds(pts) * 1_000_000_000) : 0
 
         CVPixelBufferLockBaseAddress(pixelBuffer, .readOnly)
         defer { CVPixelBufferUnlockBaseAddress(pixelBuffer, .readOnly) }
@@ -169,7 +276,8 @@ final class CameraCapture: NSObject, AVCaptureVideoDataOutputSampleBufferDelegat
                     yPtr, UInt32(yStride),
                     uPtr, UInt32(chromaW),
                     vPtr, UInt32(chromaW),
-                    UInt32(width), UInt32(height)
+                    UInt32(width), UInt32(height),
+                    timestampNs
                 )
             }
         }
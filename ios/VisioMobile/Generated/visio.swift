@@ -419,6 +419,38 @@ fileprivate struct FfiConverterUInt8: FfiConverterPrimitive {
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterUInt16: FfiConverterPrimitive {
+    typealias FfiType = UInt16
+    typealias SwiftType = UInt16
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> UInt16 {
+        return try lift(readInt(&buf))
+    }
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterInt16: FfiConverterPrimitive {
+    typealias FfiType = Int16
+    typealias SwiftType = Int16
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Int16 {
+        return try lift(readInt(&buf))
+    }
+
+    public static func write(_ value: Int16, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
@@ -435,6 +467,22 @@ fileprivate struct FfiConverterUInt32: FfiConverterPrimitive {
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterInt32: FfiConverterPrimitive {
+    typealias FfiType = Int32
+    typealias SwiftType = Int32
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Int32 {
+        return try lift(readInt(&buf))
+    }
+
+    public static func write(_ value: Int32, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
@@ -451,6 +499,54 @@ fileprivate struct FfiConverterUInt64: FfiConverterPrimitive {
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterInt64: FfiConverterPrimitive {
+    typealias FfiType = Int64
+    typealias SwiftType = Int64
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Int64 {
+        return try lift(readInt(&buf))
+    }
+
+    public static func write(_ value: Int64, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(value))
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterFloat: FfiConverterPrimitive {
+    typealias FfiType = Float
+    typealias SwiftType = Float
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Float {
+        return try lift(readFloat(&buf))
+    }
+
+    public static func write(_ value: Float, into buf: inout [UInt8]) {
+        writeFloat(&buf, lower(value))
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterDouble: FfiConverterPrimitive {
+    typealias FfiType = Double
+    typealias SwiftType = Double
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Double {
+        return try lift(readDouble(&buf))
+    }
+
+    public static func write(_ value: Double, into buf: inout [UInt8]) {
+        writeDouble(&buf, lower(value))
+    }
+}
+
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
@@ -516,68 +612,268 @@ fileprivate struct FfiConverterString: FfiConverter {
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterData: FfiConverterRustBuffer {
+    typealias SwiftType = Data
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Data {
+        let len: Int32 = try readInt(&buf)
+        return Data(try readBytes(&buf, count: Int(len)))
+    }
+
+    public static func write(_ value: Data, into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        writeBytes(&buf, value)
+    }
+}
+
 
 
 
 public protocol VisioClientProtocol: AnyObject, Sendable {
     
-    func activeSpeakers()  -> [String]
+    func activeSpeakerHoldMs()  -> UInt64
+    
+    func activeSpeakers(roomId: String?)  -> [String]
+    
+    func addCameraCommandListener(listener: CameraCommandListener) 
     
     func addListener(listener: VisioEventListener) 
     
-    func chatMessages()  -> [ChatMessage]
+    func addRoomListener(listener: RoomEventListener) 
+    
+    func audioPreset(roomId: String?)  -> AudioPreset
+    
+    func audioPublishOptions(roomId: String?)  -> AudioPublishOptions
+    
+    func captureSnapshot(trackSid: String) throws  -> Data
+    
+    func chatMessageBody(text: String, roomId: String?)  -> [MessageSpan]
+    
+    func chatMessages(roomId: String?)  -> [ChatMessage]
+    
+    func chatUnread(roomId: String?)  -> ChatUnread
+    
+    func clearCache() 
+    
+    func closePoll(pollId: String, roomId: String?) throws  -> Poll
+    
+    func connect(meetUrl: String, username: String?, roomId: String?) throws 
+    
+    func connectAsync(meetUrl: String, username: String?, roomId: String?) async throws 
+    
+    func connectTimeouts()  -> ConnectTimeouts
+    
+    func connectWithOptions(meetUrl: String, options: JoinOptions, roomId: String?) throws 
+    
+    func connectWithOptionsAsync(meetUrl: String, options: JoinOptions, roomId: String?) async throws 
+    
+    func connectionState(roomId: String?)  -> ConnectionState
+    
+    func createPoll(question: String, options: [String], roomId: String?) throws  -> Poll
+    
+    func disconnect(roomId: String?) 
+    
+    func disconnectAsync(roomId: String?) async 
+    
+    func dominantSpeaker(roomId: String?)  -> String?
+    
+    func endCall(roomId: String?) 
     
-    func connect(meetUrl: String, username: String?) throws 
+    func exportChat(format: ChatExportFormat, language: String, utcOffsetMinutes: Int32, roomId: String?) throws  -> Data
     
-    func connectionState()  -> ConnectionState
+    func exportJournal(roomId: String?)  -> String
     
-    func disconnect() 
+    func fetchAvatar(url: String) throws  -> Data
+    
+    func getAvSyncStats()  -> AvSyncStats
     
     func getBackgroundMode()  -> String
     
+    func getCachedAvatar(url: String)  -> Data?
+    
+    func getCachedRoomInfo(meetUrl: String)  -> RoomInfo?
+    
+    func getCameraControlState()  -> CameraControlState
+    
+    func getCaptureConstraints(roomId: String?)  -> CaptureConstraints
+    
+    func getExtra(key: String)  -> String?
+    
+    func getFeatureFlags(roomId: String?)  -> FeatureFlags
+    
+    func getInviteInfo(roomId: String?) throws  -> InviteInfo
+    
+    func getLastCallSummary(roomId: String?)  -> CallSummary?
+    
+    func getLocalPermissions(roomId: String?)  -> LocalPermissions?
+    
     func getMeetInstances()  -> [String]
     
+    func getNegotiatedCodecs(roomId: String?) throws  -> NegotiatedCodecs
+    
+    func getParticipantAttributes(participantSid: String, roomId: String?)  -> [String: String]?
+    
+    func getQualityHistory(trackSid: String, roomId: String?)  -> [QualitySample]
+    
+    func getRecordingStatus(roomId: String?)  -> RecordingStatus
+    
+    func getRendererStats(trackSid: String)  -> RendererStats?
+    
     func getSettings()  -> Settings
     
-    func isCameraEnabled()  -> Bool
+    func getTimeRemaining(roomId: String?)  -> UInt64?
+    
+    func getVideoStats(trackSid: String, roomId: String?)  -> VideoStats?
     
-    func isHandRaised()  -> Bool
+    func getWhiteboardSession(roomId: String?) throws  -> WhiteboardSession
     
-    func isMicrophoneEnabled()  -> Bool
+    func handleMediaButton(button: MediaButton, roomId: String?) throws  -> Bool
+    
+    func inviteParticipants(emails: [String], roomId: String?) throws 
+    
+    func isCameraEnabled(roomId: String?)  -> Bool
+    
+    func isHandRaised(roomId: String?)  -> Bool
+    
+    func isMicrophoneEnabled(roomId: String?)  -> Bool
+    
+    func isOutputMuted()  -> Bool
+    
+    func isRoomLocked(roomId: String?)  -> Bool
+    
+    func isVideoPrivacyEnabled()  -> Bool
+    
+    func join(meetUrl: String, username: String?) throws  -> String
+    
+    func joinLeaveConfig()  -> JoinLeaveConfig
+    
+    func joinOptions()  -> JoinOptions
+    
+    func layoutOrder(roomId: String?)  -> [String]
+    
+    func leave(roomId: String) throws 
     
     func loadBackgroundImage(id: UInt8, jpegPath: String) throws 
     
     func loadBlurModel(modelPath: String) throws 
     
-    func lowerHand() throws 
+    func lockRoom(locked: Bool, roomId: String?) throws 
+    
+    func lowBandwidthAudioOptions()  -> AudioPublishOptions
+    
+    func lowerHand(roomId: String?) throws 
+    
+    func markChatReadUpTo(id: String, roomId: String?)  -> Bool
+    
+    func outputVolume()  -> Float
     
-    func participants()  -> [ParticipantInfo]
+    func participantSnapshot(participantSid: String, size: UInt32, roomId: String?) throws  -> Data
     
-    func raiseHand() throws 
+    func participants(roomId: String?)  -> [ParticipantInfo]
     
-    func reconnect() throws 
+    func pendingMedia(roomId: String?)  -> PendingMedia
     
-    func sendChatMessage(text: String) throws  -> ChatMessage
+    func permissionState(permission: DevicePermission)  -> PermissionState
     
-    func sendReaction(emoji: String) throws 
+    func pinParticipant(participantSid: String, roomId: String?) 
+    
+    func pinnedParticipants(roomId: String?)  -> [String]
+    
+    func polls(roomId: String?)  -> [Poll]
+    
+    func powerMode()  -> PowerMode
+    
+    func presence(roomId: String?)  -> Presence
+    
+    func publishData(topic: String, payload: Data, reliable: Bool, roomId: String?) throws 
+    
+    func pushDeviceCheckAudio(samples: [Int16]) 
+    
+    func queueMediaUntilConnected()  -> Bool
+    
+    func raiseHand(roomId: String?) throws 
+    
+    func reconnect(roomId: String?) throws 
+    
+    func reportActivity(roomId: String?) 
+    
+    func reportIncomingCall(room: String, displayName: String, roomId: String?) throws  -> SystemCall
+    
+    func reportOutgoingCall(room: String, displayName: String, roomId: String?) throws  -> SystemCall
+    
+    func reportThermalState(state: ThermalState) 
+    
+    func roomIds()  -> [String]
+    
+    func runDeviceCheck(windowMs: UInt32, speakerError: String?, checkCamera: Bool)  -> DeviceCheckReport
+    
+    func saveChatExport(format: ChatExportFormat, language: String, utcOffsetMinutes: Int32, path: String, roomId: String?) throws 
+    
+    func saveSnapshot(trackSid: String, path: String) throws 
+    
+    func sendChatMessage(text: String, roomId: String?) throws  -> ChatMessage
+    
+    func sendChatMessageAsync(text: String, roomId: String?) async throws  -> ChatMessage
+    
+    func sendReaction(emoji: String, roomId: String?) throws 
+    
+    func setActiveSpeakerHold(holdMs: UInt64) 
+    
+    func setAudioBitrate(bps: UInt32, roomId: String?) throws 
+    
+    func setAudioDucking(enabled: Bool, level: Float) 
+    
+    func setAudioPreset(preset: AudioPreset, roomId: String?) throws 
+    
+    func setAudioPublishOptions(options: AudioPublishOptions, roomId: String?) throws 
+    
+    func setAutoGain(enabled: Bool, targetLufs: Float) 
     
     func setBackgroundMode(mode: String) 
     
-    func setCameraEnabled(enabled: Bool) throws 
+    func setCameraEnabled(enabled: Bool, roomId: String?) throws 
     
     func setCameraEnabledOnJoin(enabled: Bool) 
     
-    func setChatOpen(`open`: Bool) 
+    func setChatFormatting(enabled: Bool, roomId: String?) 
+    
+    func setChatOpen(`open`: Bool, roomId: String?) 
+    
+    func setConnectTimeouts(timeouts: ConnectTimeouts) 
     
     func setDisplayName(name: String?) 
     
+    func setExtra(key: String, value: String?) 
+    
+    func setFeedbackDiagnosticsEnabled(enabled: Bool) 
+    
+    func setFocusPoint(x: Float, y: Float) 
+    
+    func setHandAutoLower(enabled: Bool, delaySecs: UInt32) 
+    
+    func setIdlePolicy(aloneTimeoutMins: UInt32, autoLeaveWhenAlone: Bool, inactivityTimeoutMins: UInt32) 
+    
+    func setJoinLeaveConfig(config: JoinLeaveConfig) 
+    
     func setLanguage(lang: String?) 
     
+    func setLocalAttributes(attributes: [String: String], roomId: String?) throws 
+    
+    func setLowLightBoost(enabled: Bool, roomId: String?) 
+    
+    func setMaxChatMessageBytes(maxBytes: UInt64, roomId: String?) 
+    
     func setMeetInstances(instances: [String]) 
     
+    func setMetrics(enabled: Bool, endpoint: String?) 
+    
     func setMicEnabledOnJoin(enabled: Bool) 
     
-    func setMicrophoneEnabled(enabled: Bool) throws 
+    func setMicrophoneEnabled(enabled: Bool, roomId: String?) throws 
     
     func setNotificationHandRaised(enabled: Bool) 
     
@@ -585,16 +881,88 @@ public protocol VisioClientProtocol: AnyObject, Sendable {
     
     func setNotificationParticipantJoin(enabled: Bool) 
     
+    func setOutputMuted(muted: Bool) 
+    
+    func setOutputVolume(volume: Float) 
+    
+    func setPermissionState(permission: DevicePermission, state: PermissionState) 
+    
+    func setPowerMode(mode: PowerMode) 
+    
+    func setPreferredCameraId(id: String?) 
+    
+    func setPreferredMicrophoneId(id: String?) 
+    
+    func setPreferredSpeakerId(id: String?) 
+    
+    func setPresence(presence: Presence, roomId: String?) throws 
+    
+    func setQualityPolicy(enabled: Bool, delaySecs: UInt32, pauseRemoteVideo: Bool) 
+    
+    func setQueueMediaUntilConnected(enabled: Bool) 
+    
+    func setRtcConfig(config: RtcConfigOverride) throws 
+    
     func setTheme(theme: String) 
     
+    func setTorchEnabled(enabled: Bool) 
+    
+    func setVideoCodec(codec: VideoCodec, roomId: String?) 
+    
+    func setVideoContentHint(hint: VideoContentHint, roomId: String?) 
+    
+    func setVideoOverlay(text: String?, corner: OverlayCorner) 
+    
+    func setVideoOverlayImage(pngPath: String?) throws 
+    
+    func setVideoPrivacy(enabled: Bool, roomId: String?) throws 
+    
+    func setVideoPrivacyStyle(style: VideoPrivacyStyle) 
+    
+    func setVisibleTracks(trackSids: [String], roomId: String?) 
+    
+    func setZoom(factor: Float) 
+    
+    func shutdown() 
+    
+    func startLocalRecording(path: String, options: LocalRecordingOptions, roomId: String?) throws 
+    
+    func startRecording(mode: RecordingMode, roomId: String?) throws 
+    
     func startVideoRenderer(trackSid: String) 
     
+    func stopLocalRecording(roomId: String?) throws  -> UInt64
+    
+    func stopRecording(roomId: String?) throws 
+    
     func stopVideoRenderer(trackSid: String) 
     
-    func unreadCount()  -> UInt32
+    func submitFeedback(rating: UInt8, comments: String?, attachLogs: Bool, roomId: String?) throws 
+    
+    func subscribeDataTopic(topic: String, roomId: String?) throws 
+    
+    func switchRoom(meetUrl: String, username: String?, roomId: String?) throws 
+    
+    func systemCall(roomId: String?)  -> SystemCall?
+    
+    func translate(key: String, args: [String: String])  -> String
+    
+    func unpinParticipant(participantSid: String, roomId: String?) 
+    
+    func unreadCount(roomId: String?)  -> UInt32
+    
+    func unsubscribeDataTopic(topic: String, roomId: String?) 
     
     func validateRoom(url: String, username: String?)  -> RoomValidationResult
     
+    func validateRoomAsync(url: String, username: String?) async  -> RoomValidationResult
+    
+    func videoCodec(roomId: String?)  -> VideoCodec
+    
+    func videoContentHint(roomId: String?)  -> VideoContentHint
+    
+    func votePoll(pollId: String, option: UInt32, roomId: String?) throws  -> Poll
+    
 }
 open class VisioClient: VisioClientProtocol, @unchecked Sendable {
     fileprivate let pointer: UnsafeMutableRawPointer!
@@ -656,719 +1024,8182 @@ public convenience init(dataDir: String) {
     
 
     
-open func activeSpeakers() -> [String]  {
-    return try!  FfiConverterSequenceString.lift(try! rustCall() {
-    uniffi_visio_ffi_fn_method_visioclient_active_speakers(self.uniffiClonePointer(),$0
+open func activeSpeakerHoldMs() -> UInt64  {
+    return try!  FfiConverterUInt64.lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_active_speaker_hold_ms(self.uniffiClonePointer(),$0
     )
 })
 }
     
-open func addListener(listener: VisioEventListener)  {try! rustCall() {
-    uniffi_visio_ffi_fn_method_visioclient_add_listener(self.uniffiClonePointer(),
-        FfiConverterCallbackInterfaceVisioEventListener_lower(listener),$0
-    )
-}
-}
-    
-open func chatMessages() -> [ChatMessage]  {
-    return try!  FfiConverterSequenceTypeChatMessage.lift(try! rustCall() {
-    uniffi_visio_ffi_fn_method_visioclient_chat_messages(self.uniffiClonePointer(),$0
+open func activeSpeakers(roomId: String? = nil) -> [String]  {
+    return try!  FfiConverterSequenceString.lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_active_speakers(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(roomId),$0
     )
 })
 }
     
-open func connect(meetUrl: String, username: String?)throws   {try rustCallWithError(FfiConverterTypeVisioError_lift) {
-    uniffi_visio_ffi_fn_method_visioclient_connect(self.uniffiClonePointer(),
-        FfiConverterString.lower(meetUrl),
-        FfiConverterOptionString.lower(username),$0
+open func addCameraCommandListener(listener: CameraCommandListener)  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_add_camera_command_listener(self.uniffiClonePointer(),
+        FfiConverterCallbackInterfaceCameraCommandListener_lower(listener),$0
     )
 }
 }
     
-open func connectionState() -> ConnectionState  {
-    return try!  FfiConverterTypeConnectionState_lift(try! rustCall() {
-    uniffi_visio_ffi_fn_method_visioclient_connection_state(self.uniffiClonePointer(),$0
+open func addListener(listener: VisioEventListener)  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_add_listener(self.uniffiClonePointer(),
+        FfiConverterCallbackInterfaceVisioEventListener_lower(listener),$0
     )
-})
+}
 }
     
-open func disconnect()  {try! rustCall() {
-    uniffi_visio_ffi_fn_method_visioclient_disconnect(self.uniffiClonePointer(),$0
+open func addRoomListener(listener: RoomEventListener)  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_add_room_listener(self.uniffiClonePointer(),
+        FfiConverterCallbackInterfaceRoomEventListener_lower(listener),$0
     )
 }
 }
     
-open func getBackgroundMode() -> String  {
-    return try!  FfiConverterString.lift(try! rustCall() {
-    uniffi_visio_ffi_fn_method_visioclient_get_background_mode(self.uniffiClonePointer(),$0
+open func audioPreset(roomId: String? = nil) -> AudioPreset  {
+    return try!  FfiConverterTypeAudioPreset_lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_audio_preset(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(roomId),$0
     )
 })
 }
     
-open func getMeetInstances() -> [String]  {
-    return try!  FfiConverterSequenceString.lift(try! rustCall() {
-    uniffi_visio_ffi_fn_method_visioclient_get_meet_instances(self.uniffiClonePointer(),$0
+open func audioPublishOptions(roomId: String? = nil) -> AudioPublishOptions  {
+    return try!  FfiConverterTypeAudioPublishOptions_lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_audio_publish_options(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(roomId),$0
     )
 })
 }
     
-open func getSettings() -> Settings  {
-    return try!  FfiConverterTypeSettings_lift(try! rustCall() {
-    uniffi_visio_ffi_fn_method_visioclient_get_settings(self.uniffiClonePointer(),$0
+open func captureSnapshot(trackSid: String)throws  -> Data  {
+    return try  FfiConverterData.lift(try rustCallWithError(FfiConverterTypeVisioError_lift) {
+    uniffi_visio_ffi_fn_method_visioclient_capture_snapshot(self.uniffiClonePointer(),
+        FfiConverterString.lower(trackSid),$0
     )
 })
 }
     
-open func isCameraEnabled() -> Bool  {
-    return try!  FfiConverterBool.lift(try! rustCall() {
-    uniffi_visio_ffi_fn_method_visioclient_is_camera_enabled(self.uniffiClonePointer(),$0
+open func chatMessageBody(text: String, roomId: String? = nil) -> [MessageSpan]  {
+    return try!  FfiConverterSequenceTypeMessageSpan.lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_chat_message_body(self.uniffiClonePointer(),
+        FfiConverterString.lower(text),
+        FfiConverterOptionString.lower(roomId),$0
     )
 })
 }
     
-open func isHandRaised() -> Bool  {
-    return try!  FfiConverterBool.lift(try! rustCall() {
-    uniffi_visio_ffi_fn_method_visioclient_is_hand_raised(self.uniffiClonePointer(),$0
+open func chatMessages(roomId: String? = nil) -> [ChatMessage]  {
+    return try!  FfiConverterSequenceTypeChatMessage.lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_chat_messages(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(roomId),$0
     )
 })
 }
     
-open func isMicrophoneEnabled() -> Bool  {
-    return try!  FfiConverterBool.lift(try! rustCall() {
-    uniffi_visio_ffi_fn_method_visioclient_is_microphone_enabled(self.uniffiClonePointer(),$0
+open func chatUnread(roomId: String? = nil) -> ChatUnread  {
+    return try!  FfiConverterTypeChatUnread_lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_chat_unread(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(roomId),$0
     )
 })
 }
     
-open func loadBackgroundImage(id: UInt8, jpegPath: String)throws   {try rustCallWithError(FfiConverterTypeVisioError_lift) {
-    uniffi_visio_ffi_fn_method_visioclient_load_background_image(self.uniffiClonePointer(),
-        FfiConverterUInt8.lower(id),
-        FfiConverterString.lower(jpegPath),$0
+open func clearCache()  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_clear_cache(self.uniffiClonePointer(),$0
     )
 }
 }
     
-open func loadBlurModel(modelPath: String)throws   {try rustCallWithError(FfiConverterTypeVisioError_lift) {
-    uniffi_visio_ffi_fn_method_visioclient_load_blur_model(self.uniffiClonePointer(),
-        FfiConverterString.lower(modelPath),$0
+open func closePoll(pollId: String, roomId: String? = nil)throws  -> Poll  {
+    return try  FfiConverterTypePoll_lift(try rustCallWithError(FfiConverterTypeVisioError_lift) {
+    uniffi_visio_ffi_fn_method_visioclient_close_poll(self.uniffiClonePointer(),
+        FfiConverterString.lower(pollId),
+        FfiConverterOptionString.lower(roomId),$0
     )
-}
+})
 }
     
-open func lowerHand()throws   {try rustCallWithError(FfiConverterTypeVisioError_lift) {
-    uniffi_visio_ffi_fn_method_visioclient_lower_hand(self.uniffiClonePointer(),$0
+open func connect(meetUrl: String, username: String?, roomId: String? = nil)throws   {try rustCallWithError(FfiConverterTypeVisioError_lift) {
+    uniffi_visio_ffi_fn_method_visioclient_connect(self.uniffiClonePointer(),
+        FfiConverterString.lower(meetUrl),
+        FfiConverterOptionString.lower(username),
+        FfiConverterOptionString.lower(roomId),$0
     )
 }
 }
     
-open func participants() -> [ParticipantInfo]  {
-    return try!  FfiConverterSequenceTypeParticipantInfo.lift(try! rustCall() {
-    uniffi_visio_ffi_fn_method_visioclient_participants(self.uniffiClonePointer(),$0
+open func connectAsync(meetUrl: String, username: String?, roomId: String? = nil)async throws   {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_visio_ffi_fn_method_visioclient_connect_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(meetUrl),FfiConverterOptionString.lower(username),FfiConverterOptionString.lower(roomId)
+                )
+            },
+            pollFunc: ffi_visio_ffi_rust_future_poll_void,
+            completeFunc: ffi_visio_ffi_rust_future_complete_void,
+            freeFunc: ffi_visio_ffi_rust_future_free_void,
+            liftFunc: { $0 },
+            errorHandler: FfiConverterTypeVisioError_lift
+        )
+}
+    
+open func connectTimeouts() -> ConnectTimeouts  {
+    return try!  FfiConverterTypeConnectTimeouts_lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_connect_timeouts(self.uniffiClonePointer(),$0
     )
 })
 }
     
-open func raiseHand()throws   {try rustCallWithError(FfiConverterTypeVisioError_lift) {
-    uniffi_visio_ffi_fn_method_visioclient_raise_hand(self.uniffiClonePointer(),$0
+open func connectWithOptions(meetUrl: String, options: JoinOptions, roomId: String? = nil)throws   {try rustCallWithError(FfiConverterTypeVisioError_lift) {
+    uniffi_visio_ffi_fn_method_visioclient_connect_with_options(self.uniffiClonePointer(),
+        FfiConverterString.lower(meetUrl),
+        FfiConverterTypeJoinOptions_lower(options),
+        FfiConverterOptionString.lower(roomId),$0
     )
 }
 }
     
-open func reconnect()throws   {try rustCallWithError(FfiConverterTypeVisioError_lift) {
-    uniffi_visio_ffi_fn_method_visioclient_reconnect(self.uniffiClonePointer(),$0
-    )
-}
+open func connectWithOptionsAsync(meetUrl: String, options: JoinOptions, roomId: String? = nil)async throws   {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_visio_ffi_fn_method_visioclient_connect_with_options_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(meetUrl),FfiConverterTypeJoinOptions_lower(options),FfiConverterOptionString.lower(roomId)
+                )
+            },
+            pollFunc: ffi_visio_ffi_rust_future_poll_void,
+            completeFunc: ffi_visio_ffi_rust_future_complete_void,
+            freeFunc: ffi_visio_ffi_rust_future_free_void,
+            liftFunc: { $0 },
+            errorHandler: FfiConverterTypeVisioError_lift
+        )
 }
     
-open func sendChatMessage(text: String)throws  -> ChatMessage  {
-    return try  FfiConverterTypeChatMessage_lift(try rustCallWithError(FfiConverterTypeVisioError_lift) {
-    uniffi_visio_ffi_fn_method_visioclient_send_chat_message(self.uniffiClonePointer(),
-        FfiConverterString.lower(text),$0
+open func connectionState(roomId: String? = nil) -> ConnectionState  {
+    return try!  FfiConverterTypeConnectionState_lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_connection_state(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(roomId),$0
     )
 })
 }
     
-open func sendReaction(emoji: String)throws   {try rustCallWithError(FfiConverterTypeVisioError_lift) {
-    uniffi_visio_ffi_fn_method_visioclient_send_reaction(self.uniffiClonePointer(),
-        FfiConverterString.lower(emoji),$0
+open func createPoll(question: String, options: [String], roomId: String? = nil)throws  -> Poll  {
+    return try  FfiConverterTypePoll_lift(try rustCallWithError(FfiConverterTypeVisioError_lift) {
+    uniffi_visio_ffi_fn_method_visioclient_create_poll(self.uniffiClonePointer(),
+        FfiConverterString.lower(question),
+        FfiConverterSequenceString.lower(options),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+})
+}
+    
+open func disconnect(roomId: String? = nil)  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_disconnect(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(roomId),$0
     )
 }
 }
     
-open func setBackgroundMode(mode: String)  {try! rustCall() {
-    uniffi_visio_ffi_fn_method_visioclient_set_background_mode(self.uniffiClonePointer(),
-        FfiConverterString.lower(mode),$0
+open func disconnectAsync(roomId: String? = nil)async   {
+    return
+        try!  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_visio_ffi_fn_method_visioclient_disconnect_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterOptionString.lower(roomId)
+                )
+            },
+            pollFunc: ffi_visio_ffi_rust_future_poll_void,
+            completeFunc: ffi_visio_ffi_rust_future_complete_void,
+            freeFunc: ffi_visio_ffi_rust_future_free_void,
+            liftFunc: { $0 },
+            errorHandler: nil
+            
+        )
+}
+    
+open func dominantSpeaker(roomId: String? = nil) -> String?  {
+    return try!  FfiConverterOptionString.lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_dominant_speaker(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+})
+}
+    
+open func endCall(roomId: String? = nil)  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_end_call(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(roomId),$0
     )
 }
 }
     
-open func setCameraEnabled(enabled: Bool)throws   {try rustCallWithError(FfiConverterTypeVisioError_lift) {
-    uniffi_visio_ffi_fn_method_visioclient_set_camera_enabled(self.uniffiClonePointer(),
-        FfiConverterBool.lower(enabled),$0
+open func exportChat(format: ChatExportFormat, language: String, utcOffsetMinutes: Int32, roomId: String? = nil)throws  -> Data  {
+    return try  FfiConverterData.lift(try rustCallWithError(FfiConverterTypeVisioError_lift) {
+    uniffi_visio_ffi_fn_method_visioclient_export_chat(self.uniffiClonePointer(),
+        FfiConverterTypeChatExportFormat_lower(format),
+        FfiConverterString.lower(language),
+        FfiConverterInt32.lower(utcOffsetMinutes),
+        FfiConverterOptionString.lower(roomId),$0
     )
+})
 }
+    
+open func exportJournal(roomId: String? = nil) -> String  {
+    return try!  FfiConverterString.lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_export_journal(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+})
 }
     
-open func setCameraEnabledOnJoin(enabled: Bool)  {try! rustCall() {
-    uniffi_visio_ffi_fn_method_visioclient_set_camera_enabled_on_join(self.uniffiClonePointer(),
-        FfiConverterBool.lower(enabled),$0
+open func fetchAvatar(url: String)throws  -> Data  {
+    return try  FfiConverterData.lift(try rustCallWithError(FfiConverterTypeVisioError_lift) {
+    uniffi_visio_ffi_fn_method_visioclient_fetch_avatar(self.uniffiClonePointer(),
+        FfiConverterString.lower(url),$0
     )
+})
 }
+    
+open func getAvSyncStats() -> AvSyncStats  {
+    return try!  FfiConverterTypeAvSyncStats_lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_get_av_sync_stats(self.uniffiClonePointer(),$0
+    )
+})
 }
     
-open func setChatOpen(`open`: Bool)  {try! rustCall() {
-    uniffi_visio_ffi_fn_method_visioclient_set_chat_open(self.uniffiClonePointer(),
-        FfiConverterBool.lower(`open`),$0
+open func getBackgroundMode() -> String  {
+    return try!  FfiConverterString.lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_get_background_mode(self.uniffiClonePointer(),$0
     )
+})
 }
+    
+open func getCachedAvatar(url: String) -> Data?  {
+    return try!  FfiConverterOptionData.lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_get_cached_avatar(self.uniffiClonePointer(),
+        FfiConverterString.lower(url),$0
+    )
+})
 }
     
-open func setDisplayName(name: String?)  {try! rustCall() {
-    uniffi_visio_ffi_fn_method_visioclient_set_display_name(self.uniffiClonePointer(),
-        FfiConverterOptionString.lower(name),$0
+open func getCachedRoomInfo(meetUrl: String) -> RoomInfo?  {
+    return try!  FfiConverterOptionTypeRoomInfo.lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_get_cached_room_info(self.uniffiClonePointer(),
+        FfiConverterString.lower(meetUrl),$0
     )
+})
 }
+    
+open func getCameraControlState() -> CameraControlState  {
+    return try!  FfiConverterTypeCameraControlState_lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_get_camera_control_state(self.uniffiClonePointer(),$0
+    )
+})
 }
     
-open func setLanguage(lang: String?)  {try! rustCall() {
-    uniffi_visio_ffi_fn_method_visioclient_set_language(self.uniffiClonePointer(),
-        FfiConverterOptionString.lower(lang),$0
+open func getCaptureConstraints(roomId: String? = nil) -> CaptureConstraints  {
+    return try!  FfiConverterTypeCaptureConstraints_lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_get_capture_constraints(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(roomId),$0
     )
+})
 }
+    
+open func getExtra(key: String) -> String?  {
+    return try!  FfiConverterOptionString.lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_get_extra(self.uniffiClonePointer(),
+        FfiConverterString.lower(key),$0
+    )
+})
 }
     
-open func setMeetInstances(instances: [String])  {try! rustCall() {
-    uniffi_visio_ffi_fn_method_visioclient_set_meet_instances(self.uniffiClonePointer(),
-        FfiConverterSequenceString.lower(instances),$0
+open func getFeatureFlags(roomId: String? = nil) -> FeatureFlags  {
+    return try!  FfiConverterTypeFeatureFlags_lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_get_feature_flags(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(roomId),$0
     )
+})
 }
+    
+open func getInviteInfo(roomId: String? = nil)throws  -> InviteInfo  {
+    return try  FfiConverterTypeInviteInfo_lift(try rustCallWithError(FfiConverterTypeVisioError_lift) {
+    uniffi_visio_ffi_fn_method_visioclient_get_invite_info(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+})
 }
     
-open func setMicEnabledOnJoin(enabled: Bool)  {try! rustCall() {
-    uniffi_visio_ffi_fn_method_visioclient_set_mic_enabled_on_join(self.uniffiClonePointer(),
-        FfiConverterBool.lower(enabled),$0
+open func getLastCallSummary(roomId: String? = nil) -> CallSummary?  {
+    return try!  FfiConverterOptionTypeCallSummary.lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_get_last_call_summary(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(roomId),$0
     )
+})
 }
+    
+open func getLocalPermissions(roomId: String? = nil) -> LocalPermissions?  {
+    return try!  FfiConverterOptionTypeLocalPermissions.lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_get_local_permissions(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+})
 }
     
-open func setMicrophoneEnabled(enabled: Bool)throws   {try rustCallWithError(FfiConverterTypeVisioError_lift) {
-    uniffi_visio_ffi_fn_method_visioclient_set_microphone_enabled(self.uniffiClonePointer(),
-        FfiConverterBool.lower(enabled),$0
+open func getMeetInstances() -> [String]  {
+    return try!  FfiConverterSequenceString.lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_get_meet_instances(self.uniffiClonePointer(),$0
     )
+})
 }
+    
+open func getNegotiatedCodecs(roomId: String? = nil)throws  -> NegotiatedCodecs  {
+    return try  FfiConverterTypeNegotiatedCodecs_lift(try rustCallWithError(FfiConverterTypeVisioError_lift) {
+    uniffi_visio_ffi_fn_method_visioclient_get_negotiated_codecs(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+})
 }
     
-open func setNotificationHandRaised(enabled: Bool)  {try! rustCall() {
-    uniffi_visio_ffi_fn_method_visioclient_set_notification_hand_raised(self.uniffiClonePointer(),
-        FfiConverterBool.lower(enabled),$0
+open func getParticipantAttributes(participantSid: String, roomId: String? = nil) -> [String: String]?  {
+    return try!  FfiConverterOptionDictionaryStringString.lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_get_participant_attributes(self.uniffiClonePointer(),
+        FfiConverterString.lower(participantSid),
+        FfiConverterOptionString.lower(roomId),$0
     )
+})
 }
+    
+open func getQualityHistory(trackSid: String, roomId: String? = nil) -> [QualitySample]  {
+    return try!  FfiConverterSequenceTypeQualitySample.lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_get_quality_history(self.uniffiClonePointer(),
+        FfiConverterString.lower(trackSid),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+})
 }
     
-open func setNotificationMessageReceived(enabled: Bool)  {try! rustCall() {
-    uniffi_visio_ffi_fn_method_visioclient_set_notification_message_received(self.uniffiClonePointer(),
-        FfiConverterBool.lower(enabled),$0
+open func getRecordingStatus(roomId: String? = nil) -> RecordingStatus  {
+    return try!  FfiConverterTypeRecordingStatus_lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_get_recording_status(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(roomId),$0
     )
+})
 }
+    
+open func getRendererStats(trackSid: String) -> RendererStats?  {
+    return try!  FfiConverterOptionTypeRendererStats.lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_get_renderer_stats(self.uniffiClonePointer(),
+        FfiConverterString.lower(trackSid),$0
+    )
+})
 }
     
-open func setNotificationParticipantJoin(enabled: Bool)  {try! rustCall() {
-    uniffi_visio_ffi_fn_method_visioclient_set_notification_participant_join(self.uniffiClonePointer(),
-        FfiConverterBool.lower(enabled),$0
+open func getSettings() -> Settings  {
+    return try!  FfiConverterTypeSettings_lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_get_settings(self.uniffiClonePointer(),$0
     )
+})
 }
+    
+open func getTimeRemaining(roomId: String? = nil) -> UInt64?  {
+    return try!  FfiConverterOptionUInt64.lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_get_time_remaining(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+})
 }
     
-open func setTheme(theme: String)  {try! rustCall() {
-    uniffi_visio_ffi_fn_method_visioclient_set_theme(self.uniffiClonePointer(),
-        FfiConverterString.lower(theme),$0
+open func getVideoStats(trackSid: String, roomId: String? = nil) -> VideoStats?  {
+    return try!  FfiConverterOptionTypeVideoStats.lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_get_video_stats(self.uniffiClonePointer(),
+        FfiConverterString.lower(trackSid),
+        FfiConverterOptionString.lower(roomId),$0
     )
+})
 }
+    
+open func getWhiteboardSession(roomId: String? = nil)throws  -> WhiteboardSession  {
+    return try  FfiConverterTypeWhiteboardSession_lift(try rustCallWithError(FfiConverterTypeVisioError_lift) {
+    uniffi_visio_ffi_fn_method_visioclient_get_whiteboard_session(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+})
 }
     
-open func startVideoRenderer(trackSid: String)  {try! rustCall() {
-    uniffi_visio_ffi_fn_method_visioclient_start_video_renderer(self.uniffiClonePointer(),
-        FfiConverterString.lower(trackSid),$0
+open func handleMediaButton(button: MediaButton, roomId: String? = nil)throws  -> Bool  {
+    return try  FfiConverterBool.lift(try rustCallWithError(FfiConverterTypeVisioError_lift) {
+    uniffi_visio_ffi_fn_method_visioclient_handle_media_button(self.uniffiClonePointer(),
+        FfiConverterTypeMediaButton_lower(button),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+})
+}
+    
+open func inviteParticipants(emails: [String], roomId: String? = nil)throws   {try rustCallWithError(FfiConverterTypeVisioError_lift) {
+    uniffi_visio_ffi_fn_method_visioclient_invite_participants(self.uniffiClonePointer(),
+        FfiConverterSequenceString.lower(emails),
+        FfiConverterOptionString.lower(roomId),$0
     )
 }
 }
     
-open func stopVideoRenderer(trackSid: String)  {try! rustCall() {
-    uniffi_visio_ffi_fn_method_visioclient_stop_video_renderer(self.uniffiClonePointer(),
-        FfiConverterString.lower(trackSid),$0
+open func isCameraEnabled(roomId: String? = nil) -> Bool  {
+    return try!  FfiConverterBool.lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_is_camera_enabled(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(roomId),$0
     )
+})
 }
+    
+open func isHandRaised(roomId: String? = nil) -> Bool  {
+    return try!  FfiConverterBool.lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_is_hand_raised(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+})
 }
     
-open func unreadCount() -> UInt32  {
-    return try!  FfiConverterUInt32.lift(try! rustCall() {
-    uniffi_visio_ffi_fn_method_visioclient_unread_count(self.uniffiClonePointer(),$0
+open func isMicrophoneEnabled(roomId: String? = nil) -> Bool  {
+    return try!  FfiConverterBool.lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_is_microphone_enabled(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(roomId),$0
     )
 })
 }
     
-open func validateRoom(url: String, username: String?) -> RoomValidationResult  {
-    return try!  FfiConverterTypeRoomValidationResult_lift(try! rustCall() {
-    uniffi_visio_ffi_fn_method_visioclient_validate_room(self.uniffiClonePointer(),
-        FfiConverterString.lower(url),
+open func isOutputMuted() -> Bool  {
+    return try!  FfiConverterBool.lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_is_output_muted(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func isRoomLocked(roomId: String? = nil) -> Bool  {
+    return try!  FfiConverterBool.lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_is_room_locked(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+})
+}
+    
+open func isVideoPrivacyEnabled() -> Bool  {
+    return try!  FfiConverterBool.lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_is_video_privacy_enabled(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func join(meetUrl: String, username: String?)throws  -> String  {
+    return try  FfiConverterString.lift(try rustCallWithError(FfiConverterTypeVisioError_lift) {
+    uniffi_visio_ffi_fn_method_visioclient_join(self.uniffiClonePointer(),
+        FfiConverterString.lower(meetUrl),
         FfiConverterOptionString.lower(username),$0
     )
 })
 }
     
-
+open func joinLeaveConfig() -> JoinLeaveConfig  {
+    return try!  FfiConverterTypeJoinLeaveConfig_lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_join_leave_config(self.uniffiClonePointer(),$0
+    )
+})
 }
-
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public struct FfiConverterTypeVisioClient: FfiConverter {
-
-    typealias FfiType = UnsafeMutableRawPointer
-    typealias SwiftType = VisioClient
-
-    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> VisioClient {
-        return VisioClient(unsafeFromRawPointer: pointer)
-    }
-
-    public static func lower(_ value: VisioClient) -> UnsafeMutableRawPointer {
-        return value.uniffiClonePointer()
-    }
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> VisioClient {
-        let v: UInt64 = try readInt(&buf)
-        // The Rust code won't compile if a pointer won't fit in a UInt64.
-        // We have to go via `UInt` because that's the thing that's the size of a pointer.
-        let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
-        if (ptr == nil) {
-            throw UniffiInternalError.unexpectedNullPointer
-        }
-        return try lift(ptr!)
-    }
-
-    public static func write(_ value: VisioClient, into buf: inout [UInt8]) {
-        // This fiddling is because `Int` is the thing that's the same size as a pointer.
-        // The Rust code won't compile if a pointer won't fit in a `UInt64`.
-        writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
-    }
+    
+open func joinOptions() -> JoinOptions  {
+    return try!  FfiConverterTypeJoinOptions_lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_join_options(self.uniffiClonePointer(),$0
+    )
+})
 }
-
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public func FfiConverterTypeVisioClient_lift(_ pointer: UnsafeMutableRawPointer) throws -> VisioClient {
-    return try FfiConverterTypeVisioClient.lift(pointer)
+    
+open func layoutOrder(roomId: String? = nil) -> [String]  {
+    return try!  FfiConverterSequenceString.lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_layout_order(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+})
 }
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public func FfiConverterTypeVisioClient_lower(_ value: VisioClient) -> UnsafeMutableRawPointer {
-    return FfiConverterTypeVisioClient.lower(value)
+    
+open func leave(roomId: String)throws   {try rustCallWithError(FfiConverterTypeVisioError_lift) {
+    uniffi_visio_ffi_fn_method_visioclient_leave(self.uniffiClonePointer(),
+        FfiConverterString.lower(roomId),$0
+    )
 }
-
-
-
-
-public struct ChatMessage {
-    public var id: String
-    public var senderSid: String
-    public var senderName: String
-    public var text: String
-    public var timestampMs: UInt64
-
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(id: String, senderSid: String, senderName: String, text: String, timestampMs: UInt64) {
-        self.id = id
-        self.senderSid = senderSid
-        self.senderName = senderName
-        self.text = text
-        self.timestampMs = timestampMs
-    }
 }
-
-#if compiler(>=6)
-extension ChatMessage: Sendable {}
-#endif
-
-
-extension ChatMessage: Equatable, Hashable {
-    public static func ==(lhs: ChatMessage, rhs: ChatMessage) -> Bool {
-        if lhs.id != rhs.id {
-            return false
-        }
-        if lhs.senderSid != rhs.senderSid {
-            return false
-        }
-        if lhs.senderName != rhs.senderName {
-            return false
-        }
-        if lhs.text != rhs.text {
-            return false
-        }
-        if lhs.timestampMs != rhs.timestampMs {
-            return false
-        }
-        return true
-    }
-
-    public func hash(into hasher: inout Hasher) {
-        hasher.combine(id)
-        hasher.combine(senderSid)
-        hasher.combine(senderName)
-        hasher.combine(text)
-        hasher.combine(timestampMs)
-    }
+    
+open func loadBackgroundImage(id: UInt8, jpegPath: String)throws   {try rustCallWithError(FfiConverterTypeVisioError_lift) {
+    uniffi_visio_ffi_fn_method_visioclient_load_background_image(self.uniffiClonePointer(),
+        FfiConverterUInt8.lower(id),
+        FfiConverterString.lower(jpegPath),$0
+    )
 }
-
-
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public struct FfiConverterTypeChatMessage: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ChatMessage {
-        return
-            try ChatMessage(
-                id: FfiConverterString.read(from: &buf), 
-                senderSid: FfiConverterString.read(from: &buf), 
-                senderName: FfiConverterString.read(from: &buf), 
-                text: FfiConverterString.read(from: &buf), 
-                timestampMs: FfiConverterUInt64.read(from: &buf)
-        )
-    }
-
-    public static func write(_ value: ChatMessage, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.id, into: &buf)
-        FfiConverterString.write(value.senderSid, into: &buf)
-        FfiConverterString.write(value.senderName, into: &buf)
-        FfiConverterString.write(value.text, into: &buf)
-        FfiConverterUInt64.write(value.timestampMs, into: &buf)
-    }
 }
-
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public func FfiConverterTypeChatMessage_lift(_ buf: RustBuffer) throws -> ChatMessage {
-    return try FfiConverterTypeChatMessage.lift(buf)
+    
+open func loadBlurModel(modelPath: String)throws   {try rustCallWithError(FfiConverterTypeVisioError_lift) {
+    uniffi_visio_ffi_fn_method_visioclient_load_blur_model(self.uniffiClonePointer(),
+        FfiConverterString.lower(modelPath),$0
+    )
 }
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public func FfiConverterTypeChatMessage_lower(_ value: ChatMessage) -> RustBuffer {
-    return FfiConverterTypeChatMessage.lower(value)
 }
-
-
-public struct ParticipantInfo {
-    public var sid: String
-    public var identity: String
-    public var name: String?
-    public var isMuted: Bool
-    public var hasVideo: Bool
-    public var videoTrackSid: String?
-    public var connectionQuality: ConnectionQuality
-
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(sid: String, identity: String, name: String?, isMuted: Bool, hasVideo: Bool, videoTrackSid: String?, connectionQuality: ConnectionQuality) {
-        self.sid = sid
-        self.identity = identity
-        self.name = name
-        self.isMuted = isMuted
-        self.hasVideo = hasVideo
-        self.videoTrackSid = videoTrackSid
-        self.connectionQuality = connectionQuality
-    }
+    
+open func lockRoom(locked: Bool, roomId: String? = nil)throws   {try rustCallWithError(FfiConverterTypeVisioError_lift) {
+    uniffi_visio_ffi_fn_method_visioclient_lock_room(self.uniffiClonePointer(),
+        FfiConverterBool.lower(locked),
+        FfiConverterOptionString.lower(roomId),$0
+    )
 }
-
-#if compiler(>=6)
-extension ParticipantInfo: Sendable {}
-#endif
-
-
-extension ParticipantInfo: Equatable, Hashable {
-    public static func ==(lhs: ParticipantInfo, rhs: ParticipantInfo) -> Bool {
-        if lhs.sid != rhs.sid {
-            return false
-        }
-        if lhs.identity != rhs.identity {
-            return false
-        }
-        if lhs.name != rhs.name {
-            return false
-        }
-        if lhs.isMuted != rhs.isMuted {
-            return false
-        }
-        if lhs.hasVideo != rhs.hasVideo {
-            return false
-        }
-        if lhs.videoTrackSid != rhs.videoTrackSid {
-            return false
-        }
-        if lhs.connectionQuality != rhs.connectionQuality {
-            return false
-        }
-        return true
-    }
-
-    public func hash(into hasher: inout Hasher) {
-        hasher.combine(sid)
-        hasher.combine(identity)
-        hasher.combine(name)
-        hasher.combine(isMuted)
-        hasher.combine(hasVideo)
-        hasher.combine(videoTrackSid)
-        hasher.combine(connectionQuality)
-    }
 }
-
-
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public struct FfiConverterTypeParticipantInfo: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ParticipantInfo {
-        return
-            try ParticipantInfo(
-                sid: FfiConverterString.read(from: &buf), 
-                identity: FfiConverterString.read(from: &buf), 
-                name: FfiConverterOptionString.read(from: &buf), 
-                isMuted: FfiConverterBool.read(from: &buf), 
-                hasVideo: FfiConverterBool.read(from: &buf), 
-                videoTrackSid: FfiConverterOptionString.read(from: &buf), 
-                connectionQuality: FfiConverterTypeConnectionQuality.read(from: &buf)
-        )
-    }
-
-    public static func write(_ value: ParticipantInfo, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.sid, into: &buf)
-        FfiConverterString.write(value.identity, into: &buf)
-        FfiConverterOptionString.write(value.name, into: &buf)
-        FfiConverterBool.write(value.isMuted, into: &buf)
-        FfiConverterBool.write(value.hasVideo, into: &buf)
-        FfiConverterOptionString.write(value.videoTrackSid, into: &buf)
-        FfiConverterTypeConnectionQuality.write(value.connectionQuality, into: &buf)
-    }
+    
+open func lowBandwidthAudioOptions() -> AudioPublishOptions  {
+    return try!  FfiConverterTypeAudioPublishOptions_lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_low_bandwidth_audio_options(self.uniffiClonePointer(),$0
+    )
+})
 }
-
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public func FfiConverterTypeParticipantInfo_lift(_ buf: RustBuffer) throws -> ParticipantInfo {
-    return try FfiConverterTypeParticipantInfo.lift(buf)
+    
+open func lowerHand(roomId: String? = nil)throws   {try rustCallWithError(FfiConverterTypeVisioError_lift) {
+    uniffi_visio_ffi_fn_method_visioclient_lower_hand(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(roomId),$0
+    )
 }
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public func FfiConverterTypeParticipantInfo_lower(_ value: ParticipantInfo) -> RustBuffer {
-    return FfiConverterTypeParticipantInfo.lower(value)
 }
-
-
-public struct Settings {
-    public var displayName: String?
-    public var language: String?
-    public var micEnabledOnJoin: Bool
-    public var cameraEnabledOnJoin: Bool
-    public var theme: String
-    public var meetInstances: [String]
-    public var notificationParticipantJoin: Bool
-    public var notificationHandRaised: Bool
-    public var notificationMessageReceived: Bool
-
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(displayName: String?, language: String?, micEnabledOnJoin: Bool, cameraEnabledOnJoin: Bool, theme: String, meetInstances: [String], notificationParticipantJoin: Bool, notificationHandRaised: Bool, notificationMessageReceived: Bool) {
-        self.displayName = displayName
-        self.language = language
-        self.micEnabledOnJoin = micEnabledOnJoin
-        self.cameraEnabledOnJoin = cameraEnabledOnJoin
-        self.theme = theme
-        self.meetInstances = meetInstances
-        self.notificationParticipantJoin = notificationParticipantJoin
-        self.notificationHandRaised = notificationHandRaised
-        self.notificationMessageReceived = notificationMessageReceived
-    }
+    
+open func markChatReadUpTo(id: String, roomId: String? = nil) -> Bool  {
+    return try!  FfiConverterBool.lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_mark_chat_read_up_to(self.uniffiClonePointer(),
+        FfiConverterString.lower(id),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+})
 }
-
-#if compiler(>=6)
-extension Settings: Sendable {}
-#endif
-
-
-extension Settings: Equatable, Hashable {
-    public static func ==(lhs: Settings, rhs: Settings) -> Bool {
-        if lhs.displayName != rhs.displayName {
-            return false
-        }
-        if lhs.language != rhs.language {
-            return false
-        }
-        if lhs.micEnabledOnJoin != rhs.micEnabledOnJoin {
-            return false
-        }
-        if lhs.cameraEnabledOnJoin != rhs.cameraEnabledOnJoin {
-            return false
-        }
-        if lhs.theme != rhs.theme {
-            return false
-        }
-        if lhs.meetInstances != rhs.meetInstances {
-            return false
-        }
-        if lhs.notificationParticipantJoin != rhs.notificationParticipantJoin {
-            return false
-        }
-        if lhs.notificationHandRaised != rhs.notificationHandRaised {
-            return false
-        }
-        if lhs.notificationMessageReceived != rhs.notificationMessageReceived {
-            return false
-        }
-        return true
-    }
-
-    public func hash(into hasher: inout Hasher) {
-        hasher.combine(displayName)
-        hasher.combine(language)
-        hasher.combine(micEnabledOnJoin)
-        hasher.combine(cameraEnabledOnJoin)
-        hasher.combine(theme)
-        hasher.combine(meetInstances)
-        hasher.combine(notificationParticipantJoin)
-        hasher.combine(notificationHandRaised)
-        hasher.combine(notificationMessageReceived)
+    
+open func outputVolume() -> Float  {
+    return try!  FfiConverterFloat.lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_output_volume(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func participantSnapshot(participantSid: String, size: UInt32, roomId: String? = nil)throws  -> Data  {
+    return try  FfiConverterData.lift(try rustCallWithError(FfiConverterTypeVisioError_lift) {
+    uniffi_visio_ffi_fn_method_visioclient_participant_snapshot(self.uniffiClonePointer(),
+        FfiConverterString.lower(participantSid),
+        FfiConverterUInt32.lower(size),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+})
+}
+    
+open func participants(roomId: String? = nil) -> [ParticipantInfo]  {
+    return try!  FfiConverterSequenceTypeParticipantInfo.lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_participants(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+})
+}
+    
+open func pendingMedia(roomId: String? = nil) -> PendingMedia  {
+    return try!  FfiConverterTypePendingMedia_lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_pending_media(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+})
+}
+    
+open func permissionState(permission: DevicePermission) -> PermissionState  {
+    return try!  FfiConverterTypePermissionState_lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_permission_state(self.uniffiClonePointer(),
+        FfiConverterTypeDevicePermission_lower(permission),$0
+    )
+})
+}
+    
+open func pinParticipant(participantSid: String, roomId: String? = nil)  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_pin_participant(self.uniffiClonePointer(),
+        FfiConverterString.lower(participantSid),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+}
+}
+    
+open func pinnedParticipants(roomId: String? = nil) -> [String]  {
+    return try!  FfiConverterSequenceString.lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_pinned_participants(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+})
+}
+    
+open func polls(roomId: String? = nil) -> [Poll]  {
+    return try!  FfiConverterSequenceTypePoll.lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_polls(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+})
+}
+    
+open func powerMode() -> PowerMode  {
+    return try!  FfiConverterTypePowerMode_lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_power_mode(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func presence(roomId: String? = nil) -> Presence  {
+    return try!  FfiConverterTypePresence_lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_presence(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+})
+}
+    
+open func publishData(topic: String, payload: Data, reliable: Bool, roomId: String? = nil)throws   {try rustCallWithError(FfiConverterTypeVisioError_lift) {
+    uniffi_visio_ffi_fn_method_visioclient_publish_data(self.uniffiClonePointer(),
+        FfiConverterString.lower(topic),
+        FfiConverterData.lower(payload),
+        FfiConverterBool.lower(reliable),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+}
+}
+    
+open func pushDeviceCheckAudio(samples: [Int16])  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_push_device_check_audio(self.uniffiClonePointer(),
+        FfiConverterSequenceInt16.lower(samples),$0
+    )
+}
+}
+    
+open func queueMediaUntilConnected() -> Bool  {
+    return try!  FfiConverterBool.lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_queue_media_until_connected(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func raiseHand(roomId: String? = nil)throws   {try rustCallWithError(FfiConverterTypeVisioError_lift) {
+    uniffi_visio_ffi_fn_method_visioclient_raise_hand(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+}
+}
+    
+open func reconnect(roomId: String? = nil)throws   {try rustCallWithError(FfiConverterTypeVisioError_lift) {
+    uniffi_visio_ffi_fn_method_visioclient_reconnect(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+}
+}
+    
+open func reportActivity(roomId: String? = nil)  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_report_activity(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+}
+}
+    
+open func reportIncomingCall(room: String, displayName: String, roomId: String? = nil)throws  -> SystemCall  {
+    return try  FfiConverterTypeSystemCall_lift(try rustCallWithError(FfiConverterTypeVisioError_lift) {
+    uniffi_visio_ffi_fn_method_visioclient_report_incoming_call(self.uniffiClonePointer(),
+        FfiConverterString.lower(room),
+        FfiConverterString.lower(displayName),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+})
+}
+    
+open func reportOutgoingCall(room: String, displayName: String, roomId: String? = nil)throws  -> SystemCall  {
+    return try  FfiConverterTypeSystemCall_lift(try rustCallWithError(FfiConverterTypeVisioError_lift) {
+    uniffi_visio_ffi_fn_method_visioclient_report_outgoing_call(self.uniffiClonePointer(),
+        FfiConverterString.lower(room),
+        FfiConverterString.lower(displayName),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+})
+}
+    
+open func reportThermalState(state: ThermalState)  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_report_thermal_state(self.uniffiClonePointer(),
+        FfiConverterTypeThermalState_lower(state),$0
+    )
+}
+}
+    
+open func roomIds() -> [String]  {
+    return try!  FfiConverterSequenceString.lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_room_ids(self.uniffiClonePointer(),$0
+    )
+})
+}
+    
+open func runDeviceCheck(windowMs: UInt32, speakerError: String?, checkCamera: Bool) -> DeviceCheckReport  {
+    return try!  FfiConverterTypeDeviceCheckReport_lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_run_device_check(self.uniffiClonePointer(),
+        FfiConverterUInt32.lower(windowMs),
+        FfiConverterOptionString.lower(speakerError),
+        FfiConverterBool.lower(checkCamera),$0
+    )
+})
+}
+    
+open func saveChatExport(format: ChatExportFormat, language: String, utcOffsetMinutes: Int32, path: String, roomId: String? = nil)throws   {try rustCallWithError(FfiConverterTypeVisioError_lift) {
+    uniffi_visio_ffi_fn_method_visioclient_save_chat_export(self.uniffiClonePointer(),
+        FfiConverterTypeChatExportFormat_lower(format),
+        FfiConverterString.lower(language),
+        FfiConverterInt32.lower(utcOffsetMinutes),
+        FfiConverterString.lower(path),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+}
+}
+    
+open func saveSnapshot(trackSid: String, path: String)throws   {try rustCallWithError(FfiConverterTypeVisioError_lift) {
+    uniffi_visio_ffi_fn_method_visioclient_save_snapshot(self.uniffiClonePointer(),
+        FfiConverterString.lower(trackSid),
+        FfiConverterString.lower(path),$0
+    )
+}
+}
+    
+open func sendChatMessage(text: String, roomId: String? = nil)throws  -> ChatMessage  {
+    return try  FfiConverterTypeChatMessage_lift(try rustCallWithError(FfiConverterTypeVisioError_lift) {
+    uniffi_visio_ffi_fn_method_visioclient_send_chat_message(self.uniffiClonePointer(),
+        FfiConverterString.lower(text),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+})
+}
+    
+open func sendChatMessageAsync(text: String, roomId: String? = nil)async throws  -> ChatMessage  {
+    return
+        try  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_visio_ffi_fn_method_visioclient_send_chat_message_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(text),FfiConverterOptionString.lower(roomId)
+                )
+            },
+            pollFunc: ffi_visio_ffi_rust_future_poll_rust_buffer,
+            completeFunc: ffi_visio_ffi_rust_future_complete_rust_buffer,
+            freeFunc: ffi_visio_ffi_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterTypeChatMessage_lift,
+            errorHandler: FfiConverterTypeVisioError_lift
+        )
+}
+    
+open func sendReaction(emoji: String, roomId: String? = nil)throws   {try rustCallWithError(FfiConverterTypeVisioError_lift) {
+    uniffi_visio_ffi_fn_method_visioclient_send_reaction(self.uniffiClonePointer(),
+        FfiConverterString.lower(emoji),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+}
+}
+    
+open func setActiveSpeakerHold(holdMs: UInt64)  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_set_active_speaker_hold(self.uniffiClonePointer(),
+        FfiConverterUInt64.lower(holdMs),$0
+    )
+}
+}
+    
+open func setAudioBitrate(bps: UInt32, roomId: String? = nil)throws   {try rustCallWithError(FfiConverterTypeVisioError_lift) {
+    uniffi_visio_ffi_fn_method_visioclient_set_audio_bitrate(self.uniffiClonePointer(),
+        FfiConverterUInt32.lower(bps),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+}
+}
+    
+open func setAudioDucking(enabled: Bool, level: Float)  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_set_audio_ducking(self.uniffiClonePointer(),
+        FfiConverterBool.lower(enabled),
+        FfiConverterFloat.lower(level),$0
+    )
+}
+}
+    
+open func setAudioPreset(preset: AudioPreset, roomId: String? = nil)throws   {try rustCallWithError(FfiConverterTypeVisioError_lift) {
+    uniffi_visio_ffi_fn_method_visioclient_set_audio_preset(self.uniffiClonePointer(),
+        FfiConverterTypeAudioPreset_lower(preset),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+}
+}
+    
+open func setAudioPublishOptions(options: AudioPublishOptions, roomId: String? = nil)throws   {try rustCallWithError(FfiConverterTypeVisioError_lift) {
+    uniffi_visio_ffi_fn_method_visioclient_set_audio_publish_options(self.uniffiClonePointer(),
+        FfiConverterTypeAudioPublishOptions_lower(options),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+}
+}
+    
+open func setAutoGain(enabled: Bool, targetLufs: Float)  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_set_auto_gain(self.uniffiClonePointer(),
+        FfiConverterBool.lower(enabled),
+        FfiConverterFloat.lower(targetLufs),$0
+    )
+}
+}
+    
+open func setBackgroundMode(mode: String)  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_set_background_mode(self.uniffiClonePointer(),
+        FfiConverterString.lower(mode),$0
+    )
+}
+}
+    
+open func setCameraEnabled(enabled: Bool, roomId: String? = nil)throws   {try rustCallWithError(FfiConverterTypeVisioError_lift) {
+    uniffi_visio_ffi_fn_method_visioclient_set_camera_enabled(self.uniffiClonePointer(),
+        FfiConverterBool.lower(enabled),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+}
+}
+    
+open func setCameraEnabledOnJoin(enabled: Bool)  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_set_camera_enabled_on_join(self.uniffiClonePointer(),
+        FfiConverterBool.lower(enabled),$0
+    )
+}
+}
+    
+open func setChatFormatting(enabled: Bool, roomId: String? = nil)  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_set_chat_formatting(self.uniffiClonePointer(),
+        FfiConverterBool.lower(enabled),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+}
+}
+    
+open func setChatOpen(`open`: Bool, roomId: String? = nil)  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_set_chat_open(self.uniffiClonePointer(),
+        FfiConverterBool.lower(`open`),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+}
+}
+    
+open func setConnectTimeouts(timeouts: ConnectTimeouts)  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_set_connect_timeouts(self.uniffiClonePointer(),
+        FfiConverterTypeConnectTimeouts_lower(timeouts),$0
+    )
+}
+}
+    
+open func setDisplayName(name: String?)  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_set_display_name(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(name),$0
+    )
+}
+}
+    
+open func setExtra(key: String, value: String?)  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_set_extra(self.uniffiClonePointer(),
+        FfiConverterString.lower(key),
+        FfiConverterOptionString.lower(value),$0
+    )
+}
+}
+    
+open func setFeedbackDiagnosticsEnabled(enabled: Bool)  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_set_feedback_diagnostics_enabled(self.uniffiClonePointer(),
+        FfiConverterBool.lower(enabled),$0
+    )
+}
+}
+    
+open func setFocusPoint(x: Float, y: Float)  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_set_focus_point(self.uniffiClonePointer(),
+        FfiConverterFloat.lower(x),
+        FfiConverterFloat.lower(y),$0
+    )
+}
+}
+    
+open func setHandAutoLower(enabled: Bool, delaySecs: UInt32)  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_set_hand_auto_lower(self.uniffiClonePointer(),
+        FfiConverterBool.lower(enabled),
+        FfiConverterUInt32.lower(delaySecs),$0
+    )
+}
+}
+    
+open func setIdlePolicy(aloneTimeoutMins: UInt32, autoLeaveWhenAlone: Bool, inactivityTimeoutMins: UInt32)  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_set_idle_policy(self.uniffiClonePointer(),
+        FfiConverterUInt32.lower(aloneTimeoutMins),
+        FfiConverterBool.lower(autoLeaveWhenAlone),
+        FfiConverterUInt32.lower(inactivityTimeoutMins),$0
+    )
+}
+}
+    
+open func setJoinLeaveConfig(config: JoinLeaveConfig)  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_set_join_leave_config(self.uniffiClonePointer(),
+        FfiConverterTypeJoinLeaveConfig_lower(config),$0
+    )
+}
+}
+    
+open func setLanguage(lang: String?)  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_set_language(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(lang),$0
+    )
+}
+}
+    
+open func setLocalAttributes(attributes: [String: String], roomId: String? = nil)throws   {try rustCallWithError(FfiConverterTypeVisioError_lift) {
+    uniffi_visio_ffi_fn_method_visioclient_set_local_attributes(self.uniffiClonePointer(),
+        FfiConverterDictionaryStringString.lower(attributes),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+}
+}
+    
+open func setLowLightBoost(enabled: Bool, roomId: String? = nil)  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_set_low_light_boost(self.uniffiClonePointer(),
+        FfiConverterBool.lower(enabled),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+}
+}
+    
+open func setMaxChatMessageBytes(maxBytes: UInt64, roomId: String? = nil)  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_set_max_chat_message_bytes(self.uniffiClonePointer(),
+        FfiConverterUInt64.lower(maxBytes),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+}
+}
+    
+open func setMeetInstances(instances: [String])  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_set_meet_instances(self.uniffiClonePointer(),
+        FfiConverterSequenceString.lower(instances),$0
+    )
+}
+}
+    
+open func setMetrics(enabled: Bool, endpoint: String?)  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_set_metrics(self.uniffiClonePointer(),
+        FfiConverterBool.lower(enabled),
+        FfiConverterOptionString.lower(endpoint),$0
+    )
+}
+}
+    
+open func setMicEnabledOnJoin(enabled: Bool)  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_set_mic_enabled_on_join(self.uniffiClonePointer(),
+        FfiConverterBool.lower(enabled),$0
+    )
+}
+}
+    
+open func setMicrophoneEnabled(enabled: Bool, roomId: String? = nil)throws   {try rustCallWithError(FfiConverterTypeVisioError_lift) {
+    uniffi_visio_ffi_fn_method_visioclient_set_microphone_enabled(self.uniffiClonePointer(),
+        FfiConverterBool.lower(enabled),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+}
+}
+    
+open func setNotificationHandRaised(enabled: Bool)  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_set_notification_hand_raised(self.uniffiClonePointer(),
+        FfiConverterBool.lower(enabled),$0
+    )
+}
+}
+    
+open func setNotificationMessageReceived(enabled: Bool)  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_set_notification_message_received(self.uniffiClonePointer(),
+        FfiConverterBool.lower(enabled),$0
+    )
+}
+}
+    
+open func setNotificationParticipantJoin(enabled: Bool)  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_set_notification_participant_join(self.uniffiClonePointer(),
+        FfiConverterBool.lower(enabled),$0
+    )
+}
+}
+    
+open func setOutputMuted(muted: Bool)  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_set_output_muted(self.uniffiClonePointer(),
+        FfiConverterBool.lower(muted),$0
+    )
+}
+}
+    
+open func setOutputVolume(volume: Float)  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_set_output_volume(self.uniffiClonePointer(),
+        FfiConverterFloat.lower(volume),$0
+    )
+}
+}
+    
+open func setPermissionState(permission: DevicePermission, state: PermissionState)  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_set_permission_state(self.uniffiClonePointer(),
+        FfiConverterTypeDevicePermission_lower(permission),
+        FfiConverterTypePermissionState_lower(state),$0
+    )
+}
+}
+    
+open func setPowerMode(mode: PowerMode)  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_set_power_mode(self.uniffiClonePointer(),
+        FfiConverterTypePowerMode_lower(mode),$0
+    )
+}
+}
+    
+open func setPreferredCameraId(id: String?)  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_set_preferred_camera_id(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(id),$0
+    )
+}
+}
+    
+open func setPreferredMicrophoneId(id: String?)  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_set_preferred_microphone_id(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(id),$0
+    )
+}
+}
+    
+open func setPreferredSpeakerId(id: String?)  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_set_preferred_speaker_id(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(id),$0
+    )
+}
+}
+    
+open func setPresence(presence: Presence, roomId: String? = nil)throws   {try rustCallWithError(FfiConverterTypeVisioError_lift) {
+    uniffi_visio_ffi_fn_method_visioclient_set_presence(self.uniffiClonePointer(),
+        FfiConverterTypePresence_lower(presence),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+}
+}
+    
+open func setQualityPolicy(enabled: Bool, delaySecs: UInt32, pauseRemoteVideo: Bool)  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_set_quality_policy(self.uniffiClonePointer(),
+        FfiConverterBool.lower(enabled),
+        FfiConverterUInt32.lower(delaySecs),
+        FfiConverterBool.lower(pauseRemoteVideo),$0
+    )
+}
+}
+    
+open func setQueueMediaUntilConnected(enabled: Bool)  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_set_queue_media_until_connected(self.uniffiClonePointer(),
+        FfiConverterBool.lower(enabled),$0
+    )
+}
+}
+    
+open func setRtcConfig(config: RtcConfigOverride)throws   {try rustCallWithError(FfiConverterTypeVisioError_lift) {
+    uniffi_visio_ffi_fn_method_visioclient_set_rtc_config(self.uniffiClonePointer(),
+        FfiConverterTypeRtcConfigOverride_lower(config),$0
+    )
+}
+}
+    
+open func setTheme(theme: String)  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_set_theme(self.uniffiClonePointer(),
+        FfiConverterString.lower(theme),$0
+    )
+}
+}
+    
+open func setTorchEnabled(enabled: Bool)  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_set_torch_enabled(self.uniffiClonePointer(),
+        FfiConverterBool.lower(enabled),$0
+    )
+}
+}
+    
+open func setVideoCodec(codec: VideoCodec, roomId: String? = nil)  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_set_video_codec(self.uniffiClonePointer(),
+        FfiConverterTypeVideoCodec_lower(codec),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+}
+}
+    
+open func setVideoContentHint(hint: VideoContentHint, roomId: String? = nil)  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_set_video_content_hint(self.uniffiClonePointer(),
+        FfiConverterTypeVideoContentHint_lower(hint),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+}
+}
+    
+open func setVideoOverlay(text: String?, corner: OverlayCorner)  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_set_video_overlay(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(text),
+        FfiConverterTypeOverlayCorner_lower(corner),$0
+    )
+}
+}
+    
+open func setVideoOverlayImage(pngPath: String?)throws   {try rustCallWithError(FfiConverterTypeVisioError_lift) {
+    uniffi_visio_ffi_fn_method_visioclient_set_video_overlay_image(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(pngPath),$0
+    )
+}
+}
+    
+open func setVideoPrivacy(enabled: Bool, roomId: String? = nil)throws   {try rustCallWithError(FfiConverterTypeVisioError_lift) {
+    uniffi_visio_ffi_fn_method_visioclient_set_video_privacy(self.uniffiClonePointer(),
+        FfiConverterBool.lower(enabled),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+}
+}
+    
+open func setVideoPrivacyStyle(style: VideoPrivacyStyle)  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_set_video_privacy_style(self.uniffiClonePointer(),
+        FfiConverterTypeVideoPrivacyStyle_lower(style),$0
+    )
+}
+}
+    
+open func setVisibleTracks(trackSids: [String], roomId: String? = nil)  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_set_visible_tracks(self.uniffiClonePointer(),
+        FfiConverterSequenceString.lower(trackSids),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+}
+}
+    
+open func setZoom(factor: Float)  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_set_zoom(self.uniffiClonePointer(),
+        FfiConverterFloat.lower(factor),$0
+    )
+}
+}
+    
+open func shutdown()  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_shutdown(self.uniffiClonePointer(),$0
+    )
+}
+}
+    
+open func startLocalRecording(path: String, options: LocalRecordingOptions, roomId: String? = nil)throws   {try rustCallWithError(FfiConverterTypeVisioError_lift) {
+    uniffi_visio_ffi_fn_method_visioclient_start_local_recording(self.uniffiClonePointer(),
+        FfiConverterString.lower(path),
+        FfiConverterTypeLocalRecordingOptions_lower(options),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+}
+}
+    
+open func startRecording(mode: RecordingMode, roomId: String? = nil)throws   {try rustCallWithError(FfiConverterTypeVisioError_lift) {
+    uniffi_visio_ffi_fn_method_visioclient_start_recording(self.uniffiClonePointer(),
+        FfiConverterTypeRecordingMode_lower(mode),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+}
+}
+    
+open func startVideoRenderer(trackSid: String)  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_start_video_renderer(self.uniffiClonePointer(),
+        FfiConverterString.lower(trackSid),$0
+    )
+}
+}
+    
+open func stopLocalRecording(roomId: String? = nil)throws  -> UInt64  {
+    return try  FfiConverterUInt64.lift(try rustCallWithError(FfiConverterTypeVisioError_lift) {
+    uniffi_visio_ffi_fn_method_visioclient_stop_local_recording(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+})
+}
+    
+open func stopRecording(roomId: String? = nil)throws   {try rustCallWithError(FfiConverterTypeVisioError_lift) {
+    uniffi_visio_ffi_fn_method_visioclient_stop_recording(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+}
+}
+    
+open func stopVideoRenderer(trackSid: String)  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_stop_video_renderer(self.uniffiClonePointer(),
+        FfiConverterString.lower(trackSid),$0
+    )
+}
+}
+    
+open func submitFeedback(rating: UInt8, comments: String?, attachLogs: Bool, roomId: String? = nil)throws   {try rustCallWithError(FfiConverterTypeVisioError_lift) {
+    uniffi_visio_ffi_fn_method_visioclient_submit_feedback(self.uniffiClonePointer(),
+        FfiConverterUInt8.lower(rating),
+        FfiConverterOptionString.lower(comments),
+        FfiConverterBool.lower(attachLogs),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+}
+}
+    
+open func subscribeDataTopic(topic: String, roomId: String? = nil)throws   {try rustCallWithError(FfiConverterTypeVisioError_lift) {
+    uniffi_visio_ffi_fn_method_visioclient_subscribe_data_topic(self.uniffiClonePointer(),
+        FfiConverterString.lower(topic),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+}
+}
+    
+open func switchRoom(meetUrl: String, username: String?, roomId: String? = nil)throws   {try rustCallWithError(FfiConverterTypeVisioError_lift) {
+    uniffi_visio_ffi_fn_method_visioclient_switch_room(self.uniffiClonePointer(),
+        FfiConverterString.lower(meetUrl),
+        FfiConverterOptionString.lower(username),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+}
+}
+    
+open func systemCall(roomId: String? = nil) -> SystemCall?  {
+    return try!  FfiConverterOptionTypeSystemCall.lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_system_call(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+})
+}
+    
+open func translate(key: String, args: [String: String]) -> String  {
+    return try!  FfiConverterString.lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_translate(self.uniffiClonePointer(),
+        FfiConverterString.lower(key),
+        FfiConverterDictionaryStringString.lower(args),$0
+    )
+})
+}
+    
+open func unpinParticipant(participantSid: String, roomId: String? = nil)  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_unpin_participant(self.uniffiClonePointer(),
+        FfiConverterString.lower(participantSid),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+}
+}
+    
+open func unreadCount(roomId: String? = nil) -> UInt32  {
+    return try!  FfiConverterUInt32.lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_unread_count(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+})
+}
+    
+open func unsubscribeDataTopic(topic: String, roomId: String? = nil)  {try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_unsubscribe_data_topic(self.uniffiClonePointer(),
+        FfiConverterString.lower(topic),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+}
+}
+    
+open func validateRoom(url: String, username: String?) -> RoomValidationResult  {
+    return try!  FfiConverterTypeRoomValidationResult_lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_validate_room(self.uniffiClonePointer(),
+        FfiConverterString.lower(url),
+        FfiConverterOptionString.lower(username),$0
+    )
+})
+}
+    
+open func validateRoomAsync(url: String, username: String?)async  -> RoomValidationResult  {
+    return
+        try!  await uniffiRustCallAsync(
+            rustFutureFunc: {
+                uniffi_visio_ffi_fn_method_visioclient_validate_room_async(
+                    self.uniffiClonePointer(),
+                    FfiConverterString.lower(url),FfiConverterOptionString.lower(username)
+                )
+            },
+            pollFunc: ffi_visio_ffi_rust_future_poll_rust_buffer,
+            completeFunc: ffi_visio_ffi_rust_future_complete_rust_buffer,
+            freeFunc: ffi_visio_ffi_rust_future_free_rust_buffer,
+            liftFunc: FfiConverterTypeRoomValidationResult_lift,
+            errorHandler: nil
+            
+        )
+}
+    
+open func videoCodec(roomId: String? = nil) -> VideoCodec  {
+    return try!  FfiConverterTypeVideoCodec_lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_video_codec(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+})
+}
+    
+open func videoContentHint(roomId: String? = nil) -> VideoContentHint  {
+    return try!  FfiConverterTypeVideoContentHint_lift(try! rustCall() {
+    uniffi_visio_ffi_fn_method_visioclient_video_content_hint(self.uniffiClonePointer(),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+})
+}
+    
+open func votePoll(pollId: String, option: UInt32, roomId: String? = nil)throws  -> Poll  {
+    return try  FfiConverterTypePoll_lift(try rustCallWithError(FfiConverterTypeVisioError_lift) {
+    uniffi_visio_ffi_fn_method_visioclient_vote_poll(self.uniffiClonePointer(),
+        FfiConverterString.lower(pollId),
+        FfiConverterUInt32.lower(option),
+        FfiConverterOptionString.lower(roomId),$0
+    )
+})
+}
+    
+
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeVisioClient: FfiConverter {
+
+    typealias FfiType = UnsafeMutableRawPointer
+    typealias SwiftType = VisioClient
+
+    public static func lift(_ pointer: UnsafeMutableRawPointer) throws -> VisioClient {
+        return VisioClient(unsafeFromRawPointer: pointer)
+    }
+
+    public static func lower(_ value: VisioClient) -> UnsafeMutableRawPointer {
+        return value.uniffiClonePointer()
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> VisioClient {
+        let v: UInt64 = try readInt(&buf)
+        // The Rust code won't compile if a pointer won't fit in a UInt64.
+        // We have to go via `UInt` because that's the thing that's the size of a pointer.
+        let ptr = UnsafeMutableRawPointer(bitPattern: UInt(truncatingIfNeeded: v))
+        if (ptr == nil) {
+            throw UniffiInternalError.unexpectedNullPointer
+        }
+        return try lift(ptr!)
+    }
+
+    public static func write(_ value: VisioClient, into buf: inout [UInt8]) {
+        // This fiddling is because `Int` is the thing that's the same size as a pointer.
+        // The Rust code won't compile if a pointer won't fit in a `UInt64`.
+        writeInt(&buf, UInt64(bitPattern: Int64(Int(bitPattern: lower(value)))))
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeVisioClient_lift(_ pointer: UnsafeMutableRawPointer) throws -> VisioClient {
+    return try FfiConverterTypeVisioClient.lift(pointer)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeVisioClient_lower(_ value: VisioClient) -> UnsafeMutableRawPointer {
+    return FfiConverterTypeVisioClient.lower(value)
+}
+
+
+
+
+public struct AudioPublishOptions {
+    public var maxBitrateBps: UInt32?
+    public var dtx: Bool
+    public var red: Bool
+    public var stereo: Bool
+    public var voiceProcessing: Bool
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(maxBitrateBps: UInt32?, dtx: Bool, red: Bool, stereo: Bool, voiceProcessing: Bool) {
+        self.maxBitrateBps = maxBitrateBps
+        self.dtx = dtx
+        self.red = red
+        self.stereo = stereo
+        self.voiceProcessing = voiceProcessing
+    }
+}
+
+#if compiler(>=6)
+extension AudioPublishOptions: Sendable {}
+#endif
+
+
+extension AudioPublishOptions: Equatable, Hashable {
+    public static func ==(lhs: AudioPublishOptions, rhs: AudioPublishOptions) -> Bool {
+        if lhs.maxBitrateBps != rhs.maxBitrateBps {
+            return false
+        }
+        if lhs.dtx != rhs.dtx {
+            return false
+        }
+        if lhs.red != rhs.red {
+            return false
+        }
+        if lhs.stereo != rhs.stereo {
+            return false
+        }
+        if lhs.voiceProcessing != rhs.voiceProcessing {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(maxBitrateBps)
+        hasher.combine(dtx)
+        hasher.combine(red)
+        hasher.combine(stereo)
+        hasher.combine(voiceProcessing)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeAudioPublishOptions: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> AudioPublishOptions {
+        return
+            try AudioPublishOptions(
+                maxBitrateBps: FfiConverterOptionUInt32.read(from: &buf), 
+                dtx: FfiConverterBool.read(from: &buf), 
+                red: FfiConverterBool.read(from: &buf), 
+                stereo: FfiConverterBool.read(from: &buf), 
+                voiceProcessing: FfiConverterBool.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: AudioPublishOptions, into buf: inout [UInt8]) {
+        FfiConverterOptionUInt32.write(value.maxBitrateBps, into: &buf)
+        FfiConverterBool.write(value.dtx, into: &buf)
+        FfiConverterBool.write(value.red, into: &buf)
+        FfiConverterBool.write(value.stereo, into: &buf)
+        FfiConverterBool.write(value.voiceProcessing, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeAudioPublishOptions_lift(_ buf: RustBuffer) throws -> AudioPublishOptions {
+    return try FfiConverterTypeAudioPublishOptions.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeAudioPublishOptions_lower(_ value: AudioPublishOptions) -> RustBuffer {
+    return FfiConverterTypeAudioPublishOptions.lower(value)
+}
+
+
+public struct AvSyncStats {
+    public var audioDelayMs: Double?
+    public var videoDelayMs: Double?
+    public var skewMs: Double?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(audioDelayMs: Double?, videoDelayMs: Double?, skewMs: Double?) {
+        self.audioDelayMs = audioDelayMs
+        self.videoDelayMs = videoDelayMs
+        self.skewMs = skewMs
+    }
+}
+
+#if compiler(>=6)
+extension AvSyncStats: Sendable {}
+#endif
+
+
+extension AvSyncStats: Equatable, Hashable {
+    public static func ==(lhs: AvSyncStats, rhs: AvSyncStats) -> Bool {
+        if lhs.audioDelayMs != rhs.audioDelayMs {
+            return false
+        }
+        if lhs.videoDelayMs != rhs.videoDelayMs {
+            return false
+        }
+        if lhs.skewMs != rhs.skewMs {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(audioDelayMs)
+        hasher.combine(videoDelayMs)
+        hasher.combine(skewMs)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeAvSyncStats: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> AvSyncStats {
+        return
+            try AvSyncStats(
+                audioDelayMs: FfiConverterOptionDouble.read(from: &buf), 
+                videoDelayMs: FfiConverterOptionDouble.read(from: &buf), 
+                skewMs: FfiConverterOptionDouble.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: AvSyncStats, into buf: inout [UInt8]) {
+        FfiConverterOptionDouble.write(value.audioDelayMs, into: &buf)
+        FfiConverterOptionDouble.write(value.videoDelayMs, into: &buf)
+        FfiConverterOptionDouble.write(value.skewMs, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeAvSyncStats_lift(_ buf: RustBuffer) throws -> AvSyncStats {
+    return try FfiConverterTypeAvSyncStats.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeAvSyncStats_lower(_ value: AvSyncStats) -> RustBuffer {
+    return FfiConverterTypeAvSyncStats.lower(value)
+}
+
+
+public struct CallSummary {
+    public var endedAtMs: Int64
+    public var durationSecs: UInt64
+    public var peakParticipants: UInt32
+    public var reconnectCount: UInt32
+    public var averageQuality: ConnectionQuality?
+    public var errorCount: UInt32
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(endedAtMs: Int64, durationSecs: UInt64, peakParticipants: UInt32, reconnectCount: UInt32, averageQuality: ConnectionQuality?, errorCount: UInt32) {
+        self.endedAtMs = endedAtMs
+        self.durationSecs = durationSecs
+        self.peakParticipants = peakParticipants
+        self.reconnectCount = reconnectCount
+        self.averageQuality = averageQuality
+        self.errorCount = errorCount
+    }
+}
+
+#if compiler(>=6)
+extension CallSummary: Sendable {}
+#endif
+
+
+extension CallSummary: Equatable, Hashable {
+    public static func ==(lhs: CallSummary, rhs: CallSummary) -> Bool {
+        if lhs.endedAtMs != rhs.endedAtMs {
+            return false
+        }
+        if lhs.durationSecs != rhs.durationSecs {
+            return false
+        }
+        if lhs.peakParticipants != rhs.peakParticipants {
+            return false
+        }
+        if lhs.reconnectCount != rhs.reconnectCount {
+            return false
+        }
+        if lhs.averageQuality != rhs.averageQuality {
+            return false
+        }
+        if lhs.errorCount != rhs.errorCount {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(endedAtMs)
+        hasher.combine(durationSecs)
+        hasher.combine(peakParticipants)
+        hasher.combine(reconnectCount)
+        hasher.combine(averageQuality)
+        hasher.combine(errorCount)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeCallSummary: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CallSummary {
+        return
+            try CallSummary(
+                endedAtMs: FfiConverterInt64.read(from: &buf), 
+                durationSecs: FfiConverterUInt64.read(from: &buf), 
+                peakParticipants: FfiConverterUInt32.read(from: &buf), 
+                reconnectCount: FfiConverterUInt32.read(from: &buf), 
+                averageQuality: FfiConverterOptionTypeConnectionQuality.read(from: &buf), 
+                errorCount: FfiConverterUInt32.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: CallSummary, into buf: inout [UInt8]) {
+        FfiConverterInt64.write(value.endedAtMs, into: &buf)
+        FfiConverterUInt64.write(value.durationSecs, into: &buf)
+        FfiConverterUInt32.write(value.peakParticipants, into: &buf)
+        FfiConverterUInt32.write(value.reconnectCount, into: &buf)
+        FfiConverterOptionTypeConnectionQuality.write(value.averageQuality, into: &buf)
+        FfiConverterUInt32.write(value.errorCount, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCallSummary_lift(_ buf: RustBuffer) throws -> CallSummary {
+    return try FfiConverterTypeCallSummary.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCallSummary_lower(_ value: CallSummary) -> RustBuffer {
+    return FfiConverterTypeCallSummary.lower(value)
+}
+
+
+public struct CameraControlState {
+    public var torchEnabled: Bool
+    public var zoomFactor: Float
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(torchEnabled: Bool, zoomFactor: Float) {
+        self.torchEnabled = torchEnabled
+        self.zoomFactor = zoomFactor
+    }
+}
+
+#if compiler(>=6)
+extension CameraControlState: Sendable {}
+#endif
+
+
+extension CameraControlState: Equatable, Hashable {
+    public static func ==(lhs: CameraControlState, rhs: CameraControlState) -> Bool {
+        if lhs.torchEnabled != rhs.torchEnabled {
+            return false
+        }
+        if lhs.zoomFactor != rhs.zoomFactor {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(torchEnabled)
+        hasher.combine(zoomFactor)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeCameraControlState: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CameraControlState {
+        return
+            try CameraControlState(
+                torchEnabled: FfiConverterBool.read(from: &buf), 
+                zoomFactor: FfiConverterFloat.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: CameraControlState, into buf: inout [UInt8]) {
+        FfiConverterBool.write(value.torchEnabled, into: &buf)
+        FfiConverterFloat.write(value.zoomFactor, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCameraControlState_lift(_ buf: RustBuffer) throws -> CameraControlState {
+    return try FfiConverterTypeCameraControlState.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCameraControlState_lower(_ value: CameraControlState) -> RustBuffer {
+    return FfiConverterTypeCameraControlState.lower(value)
+}
+
+
+public struct CaptureConstraints {
+    public var width: UInt32
+    public var height: UInt32
+    public var maxFps: UInt32
+    public var lowLightBoost: Bool
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(width: UInt32, height: UInt32, maxFps: UInt32, lowLightBoost: Bool) {
+        self.width = width
+        self.height = height
+        self.maxFps = maxFps
+        self.lowLightBoost = lowLightBoost
+    }
+}
+
+#if compiler(>=6)
+extension CaptureConstraints: Sendable {}
+#endif
+
+
+extension CaptureConstraints: Equatable, Hashable {
+    public static func ==(lhs: CaptureConstraints, rhs: CaptureConstraints) -> Bool {
+        if lhs.width != rhs.width {
+            return false
+        }
+        if lhs.height != rhs.height {
+            return false
+        }
+        if lhs.maxFps != rhs.maxFps {
+            return false
+        }
+        if lhs.lowLightBoost != rhs.lowLightBoost {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(width)
+        hasher.combine(height)
+        hasher.combine(maxFps)
+        hasher.combine(lowLightBoost)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeCaptureConstraints: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CaptureConstraints {
+        return
+            try CaptureConstraints(
+                width: FfiConverterUInt32.read(from: &buf), 
+                height: FfiConverterUInt32.read(from: &buf), 
+                maxFps: FfiConverterUInt32.read(from: &buf), 
+                lowLightBoost: FfiConverterBool.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: CaptureConstraints, into buf: inout [UInt8]) {
+        FfiConverterUInt32.write(value.width, into: &buf)
+        FfiConverterUInt32.write(value.height, into: &buf)
+        FfiConverterUInt32.write(value.maxFps, into: &buf)
+        FfiConverterBool.write(value.lowLightBoost, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCaptureConstraints_lift(_ buf: RustBuffer) throws -> CaptureConstraints {
+    return try FfiConverterTypeCaptureConstraints.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCaptureConstraints_lower(_ value: CaptureConstraints) -> RustBuffer {
+    return FfiConverterTypeCaptureConstraints.lower(value)
+}
+
+
+public struct ChatMessage {
+    public var id: String
+    public var senderSid: String
+    public var senderName: String
+    public var text: String
+    public var timestampMs: UInt64
+    public var kind: ChatMessageKind
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(id: String, senderSid: String, senderName: String, text: String, timestampMs: UInt64, kind: ChatMessageKind) {
+        self.id = id
+        self.senderSid = senderSid
+        self.senderName = senderName
+        self.text = text
+        self.timestampMs = timestampMs
+        self.kind = kind
+    }
+}
+
+#if compiler(>=6)
+extension ChatMessage: Sendable {}
+#endif
+
+
+extension ChatMessage: Equatable, Hashable {
+    public static func ==(lhs: ChatMessage, rhs: ChatMessage) -> Bool {
+        if lhs.id != rhs.id {
+            return false
+        }
+        if lhs.senderSid != rhs.senderSid {
+            return false
+        }
+        if lhs.senderName != rhs.senderName {
+            return false
+        }
+        if lhs.text != rhs.text {
+            return false
+        }
+        if lhs.timestampMs != rhs.timestampMs {
+            return false
+        }
+        if lhs.kind != rhs.kind {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(id)
+        hasher.combine(senderSid)
+        hasher.combine(senderName)
+        hasher.combine(text)
+        hasher.combine(timestampMs)
+        hasher.combine(kind)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeChatMessage: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ChatMessage {
+        return
+            try ChatMessage(
+                id: FfiConverterString.read(from: &buf), 
+                senderSid: FfiConverterString.read(from: &buf), 
+                senderName: FfiConverterString.read(from: &buf), 
+                text: FfiConverterString.read(from: &buf), 
+                timestampMs: FfiConverterUInt64.read(from: &buf), 
+                kind: FfiConverterTypeChatMessageKind.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ChatMessage, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.id, into: &buf)
+        FfiConverterString.write(value.senderSid, into: &buf)
+        FfiConverterString.write(value.senderName, into: &buf)
+        FfiConverterString.write(value.text, into: &buf)
+        FfiConverterUInt64.write(value.timestampMs, into: &buf)
+        FfiConverterTypeChatMessageKind.write(value.kind, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeChatMessage_lift(_ buf: RustBuffer) throws -> ChatMessage {
+    return try FfiConverterTypeChatMessage.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeChatMessage_lower(_ value: ChatMessage) -> RustBuffer {
+    return FfiConverterTypeChatMessage.lower(value)
+}
+
+
+public struct ChatUnread {
+    public var firstUnreadId: String?
+    public var normal: UInt32
+    public var mention: UInt32
+    public var system: UInt32
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(firstUnreadId: String?, normal: UInt32, mention: UInt32, system: UInt32) {
+        self.firstUnreadId = firstUnreadId
+        self.normal = normal
+        self.mention = mention
+        self.system = system
+    }
+}
+
+#if compiler(>=6)
+extension ChatUnread: Sendable {}
+#endif
+
+
+extension ChatUnread: Equatable, Hashable {
+    public static func ==(lhs: ChatUnread, rhs: ChatUnread) -> Bool {
+        if lhs.firstUnreadId != rhs.firstUnreadId {
+            return false
+        }
+        if lhs.normal != rhs.normal {
+            return false
+        }
+        if lhs.mention != rhs.mention {
+            return false
+        }
+        if lhs.system != rhs.system {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(firstUnreadId)
+        hasher.combine(normal)
+        hasher.combine(mention)
+        hasher.combine(system)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeChatUnread: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ChatUnread {
+        return
+            try ChatUnread(
+                firstUnreadId: FfiConverterOptionString.read(from: &buf), 
+                normal: FfiConverterUInt32.read(from: &buf), 
+                mention: FfiConverterUInt32.read(from: &buf), 
+                system: FfiConverterUInt32.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ChatUnread, into buf: inout [UInt8]) {
+        FfiConverterOptionString.write(value.firstUnreadId, into: &buf)
+        FfiConverterUInt32.write(value.normal, into: &buf)
+        FfiConverterUInt32.write(value.mention, into: &buf)
+        FfiConverterUInt32.write(value.system, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeChatUnread_lift(_ buf: RustBuffer) throws -> ChatUnread {
+    return try FfiConverterTypeChatUnread.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeChatUnread_lower(_ value: ChatUnread) -> RustBuffer {
+    return FfiConverterTypeChatUnread.lower(value)
+}
+
+
+public struct ConnectTimeouts {
+    public var meetApiMs: UInt64
+    public var livekitMs: UInt64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(meetApiMs: UInt64, livekitMs: UInt64) {
+        self.meetApiMs = meetApiMs
+        self.livekitMs = livekitMs
+    }
+}
+
+#if compiler(>=6)
+extension ConnectTimeouts: Sendable {}
+#endif
+
+
+extension ConnectTimeouts: Equatable, Hashable {
+    public static func ==(lhs: ConnectTimeouts, rhs: ConnectTimeouts) -> Bool {
+        if lhs.meetApiMs != rhs.meetApiMs {
+            return false
+        }
+        if lhs.livekitMs != rhs.livekitMs {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(meetApiMs)
+        hasher.combine(livekitMs)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeConnectTimeouts: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ConnectTimeouts {
+        return
+            try ConnectTimeouts(
+                meetApiMs: FfiConverterUInt64.read(from: &buf), 
+                livekitMs: FfiConverterUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ConnectTimeouts, into buf: inout [UInt8]) {
+        FfiConverterUInt64.write(value.meetApiMs, into: &buf)
+        FfiConverterUInt64.write(value.livekitMs, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeConnectTimeouts_lift(_ buf: RustBuffer) throws -> ConnectTimeouts {
+    return try FfiConverterTypeConnectTimeouts.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeConnectTimeouts_lower(_ value: ConnectTimeouts) -> RustBuffer {
+    return FfiConverterTypeConnectTimeouts.lower(value)
+}
+
+
+public struct ConnectivityDiagnosis {
+    public var host: String
+    public var port: UInt16
+    public var dns: CheckResult
+    public var ipv4Addresses: [String]
+    public var ipv6Addresses: [String]
+    public var tcpIpv4: CheckResult
+    public var tcpIpv6: CheckResult
+    public var tls: CheckResult
+    public var websocket: CheckResult
+    public var captivePortal: Bool
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(host: String, port: UInt16, dns: CheckResult, ipv4Addresses: [String], ipv6Addresses: [String], tcpIpv4: CheckResult, tcpIpv6: CheckResult, tls: CheckResult, websocket: CheckResult, captivePortal: Bool) {
+        self.host = host
+        self.port = port
+        self.dns = dns
+        self.ipv4Addresses = ipv4Addresses
+        self.ipv6Addresses = ipv6Addresses
+        self.tcpIpv4 = tcpIpv4
+        self.tcpIpv6 = tcpIpv6
+        self.tls = tls
+        self.websocket = websocket
+        self.captivePortal = captivePortal
+    }
+}
+
+#if compiler(>=6)
+extension ConnectivityDiagnosis: Sendable {}
+#endif
+
+
+extension ConnectivityDiagnosis: Equatable, Hashable {
+    public static func ==(lhs: ConnectivityDiagnosis, rhs: ConnectivityDiagnosis) -> Bool {
+        if lhs.host != rhs.host {
+            return false
+        }
+        if lhs.port != rhs.port {
+            return false
+        }
+        if lhs.dns != rhs.dns {
+            return false
+        }
+        if lhs.ipv4Addresses != rhs.ipv4Addresses {
+            return false
+        }
+        if lhs.ipv6Addresses != rhs.ipv6Addresses {
+            return false
+        }
+        if lhs.tcpIpv4 != rhs.tcpIpv4 {
+            return false
+        }
+        if lhs.tcpIpv6 != rhs.tcpIpv6 {
+            return false
+        }
+        if lhs.tls != rhs.tls {
+            return false
+        }
+        if lhs.websocket != rhs.websocket {
+            return false
+        }
+        if lhs.captivePortal != rhs.captivePortal {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(host)
+        hasher.combine(port)
+        hasher.combine(dns)
+        hasher.combine(ipv4Addresses)
+        hasher.combine(ipv6Addresses)
+        hasher.combine(tcpIpv4)
+        hasher.combine(tcpIpv6)
+        hasher.combine(tls)
+        hasher.combine(websocket)
+        hasher.combine(captivePortal)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeConnectivityDiagnosis: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ConnectivityDiagnosis {
+        return
+            try ConnectivityDiagnosis(
+                host: FfiConverterString.read(from: &buf), 
+                port: FfiConverterUInt16.read(from: &buf), 
+                dns: FfiConverterTypeCheckResult.read(from: &buf), 
+                ipv4Addresses: FfiConverterSequenceString.read(from: &buf), 
+                ipv6Addresses: FfiConverterSequenceString.read(from: &buf), 
+                tcpIpv4: FfiConverterTypeCheckResult.read(from: &buf), 
+                tcpIpv6: FfiConverterTypeCheckResult.read(from: &buf), 
+                tls: FfiConverterTypeCheckResult.read(from: &buf), 
+                websocket: FfiConverterTypeCheckResult.read(from: &buf), 
+                captivePortal: FfiConverterBool.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ConnectivityDiagnosis, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.host, into: &buf)
+        FfiConverterUInt16.write(value.port, into: &buf)
+        FfiConverterTypeCheckResult.write(value.dns, into: &buf)
+        FfiConverterSequenceString.write(value.ipv4Addresses, into: &buf)
+        FfiConverterSequenceString.write(value.ipv6Addresses, into: &buf)
+        FfiConverterTypeCheckResult.write(value.tcpIpv4, into: &buf)
+        FfiConverterTypeCheckResult.write(value.tcpIpv6, into: &buf)
+        FfiConverterTypeCheckResult.write(value.tls, into: &buf)
+        FfiConverterTypeCheckResult.write(value.websocket, into: &buf)
+        FfiConverterBool.write(value.captivePortal, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeConnectivityDiagnosis_lift(_ buf: RustBuffer) throws -> ConnectivityDiagnosis {
+    return try FfiConverterTypeConnectivityDiagnosis.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeConnectivityDiagnosis_lower(_ value: ConnectivityDiagnosis) -> RustBuffer {
+    return FfiConverterTypeConnectivityDiagnosis.lower(value)
+}
+
+
+public struct DeviceCheckReport {
+    public var microphone: CheckResult
+    public var microphoneLevel: Float
+    public var camera: CheckResult
+    public var cameraFrames: UInt32
+    public var speaker: CheckResult
+    public var hasProblem: Bool
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(microphone: CheckResult, microphoneLevel: Float, camera: CheckResult, cameraFrames: UInt32, speaker: CheckResult, hasProblem: Bool) {
+        self.microphone = microphone
+        self.microphoneLevel = microphoneLevel
+        self.camera = camera
+        self.cameraFrames = cameraFrames
+        self.speaker = speaker
+        self.hasProblem = hasProblem
+    }
+}
+
+#if compiler(>=6)
+extension DeviceCheckReport: Sendable {}
+#endif
+
+
+extension DeviceCheckReport: Equatable, Hashable {
+    public static func ==(lhs: DeviceCheckReport, rhs: DeviceCheckReport) -> Bool {
+        if lhs.microphone != rhs.microphone {
+            return false
+        }
+        if lhs.microphoneLevel != rhs.microphoneLevel {
+            return false
+        }
+        if lhs.camera != rhs.camera {
+            return false
+        }
+        if lhs.cameraFrames != rhs.cameraFrames {
+            return false
+        }
+        if lhs.speaker != rhs.speaker {
+            return false
+        }
+        if lhs.hasProblem != rhs.hasProblem {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(microphone)
+        hasher.combine(microphoneLevel)
+        hasher.combine(camera)
+        hasher.combine(cameraFrames)
+        hasher.combine(speaker)
+        hasher.combine(hasProblem)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeDeviceCheckReport: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DeviceCheckReport {
+        return
+            try DeviceCheckReport(
+                microphone: FfiConverterTypeCheckResult.read(from: &buf), 
+                microphoneLevel: FfiConverterFloat.read(from: &buf), 
+                camera: FfiConverterTypeCheckResult.read(from: &buf), 
+                cameraFrames: FfiConverterUInt32.read(from: &buf), 
+                speaker: FfiConverterTypeCheckResult.read(from: &buf), 
+                hasProblem: FfiConverterBool.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: DeviceCheckReport, into buf: inout [UInt8]) {
+        FfiConverterTypeCheckResult.write(value.microphone, into: &buf)
+        FfiConverterFloat.write(value.microphoneLevel, into: &buf)
+        FfiConverterTypeCheckResult.write(value.camera, into: &buf)
+        FfiConverterUInt32.write(value.cameraFrames, into: &buf)
+        FfiConverterTypeCheckResult.write(value.speaker, into: &buf)
+        FfiConverterBool.write(value.hasProblem, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDeviceCheckReport_lift(_ buf: RustBuffer) throws -> DeviceCheckReport {
+    return try FfiConverterTypeDeviceCheckReport.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDeviceCheckReport_lower(_ value: DeviceCheckReport) -> RustBuffer {
+    return FfiConverterTypeDeviceCheckReport.lower(value)
+}
+
+
+public struct DialInInfo {
+    public var phoneNumber: String
+    public var pinCode: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(phoneNumber: String, pinCode: String) {
+        self.phoneNumber = phoneNumber
+        self.pinCode = pinCode
+    }
+}
+
+#if compiler(>=6)
+extension DialInInfo: Sendable {}
+#endif
+
+
+extension DialInInfo: Equatable, Hashable {
+    public static func ==(lhs: DialInInfo, rhs: DialInInfo) -> Bool {
+        if lhs.phoneNumber != rhs.phoneNumber {
+            return false
+        }
+        if lhs.pinCode != rhs.pinCode {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(phoneNumber)
+        hasher.combine(pinCode)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeDialInInfo: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DialInInfo {
+        return
+            try DialInInfo(
+                phoneNumber: FfiConverterString.read(from: &buf), 
+                pinCode: FfiConverterString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: DialInInfo, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.phoneNumber, into: &buf)
+        FfiConverterString.write(value.pinCode, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDialInInfo_lift(_ buf: RustBuffer) throws -> DialInInfo {
+    return try FfiConverterTypeDialInInfo.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDialInInfo_lower(_ value: DialInInfo) -> RustBuffer {
+    return FfiConverterTypeDialInInfo.lower(value)
+}
+
+
+public struct FeatureFlags {
+    public var chat: Bool
+    public var reactions: Bool
+    public var recording: Bool
+    public var transcription: Bool
+    public var dialIn: Bool
+    public var subtitles: Bool
+    public var whiteboard: Bool
+    public var watermark: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(chat: Bool, reactions: Bool, recording: Bool, transcription: Bool, dialIn: Bool, subtitles: Bool, whiteboard: Bool, watermark: String?) {
+        self.chat = chat
+        self.reactions = reactions
+        self.recording = recording
+        self.transcription = transcription
+        self.dialIn = dialIn
+        self.subtitles = subtitles
+        self.whiteboard = whiteboard
+        self.watermark = watermark
+    }
+}
+
+#if compiler(>=6)
+extension FeatureFlags: Sendable {}
+#endif
+
+
+extension FeatureFlags: Equatable, Hashable {
+    public static func ==(lhs: FeatureFlags, rhs: FeatureFlags) -> Bool {
+        if lhs.chat != rhs.chat {
+            return false
+        }
+        if lhs.reactions != rhs.reactions {
+            return false
+        }
+        if lhs.recording != rhs.recording {
+            return false
+        }
+        if lhs.transcription != rhs.transcription {
+            return false
+        }
+        if lhs.dialIn != rhs.dialIn {
+            return false
+        }
+        if lhs.subtitles != rhs.subtitles {
+            return false
+        }
+        if lhs.whiteboard != rhs.whiteboard {
+            return false
+        }
+        if lhs.watermark != rhs.watermark {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(chat)
+        hasher.combine(reactions)
+        hasher.combine(recording)
+        hasher.combine(transcription)
+        hasher.combine(dialIn)
+        hasher.combine(subtitles)
+        hasher.combine(whiteboard)
+        hasher.combine(watermark)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeFeatureFlags: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> FeatureFlags {
+        return
+            try FeatureFlags(
+                chat: FfiConverterBool.read(from: &buf), 
+                reactions: FfiConverterBool.read(from: &buf), 
+                recording: FfiConverterBool.read(from: &buf), 
+                transcription: FfiConverterBool.read(from: &buf), 
+                dialIn: FfiConverterBool.read(from: &buf), 
+                subtitles: FfiConverterBool.read(from: &buf), 
+                whiteboard: FfiConverterBool.read(from: &buf), 
+                watermark: FfiConverterOptionString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: FeatureFlags, into buf: inout [UInt8]) {
+        FfiConverterBool.write(value.chat, into: &buf)
+        FfiConverterBool.write(value.reactions, into: &buf)
+        FfiConverterBool.write(value.recording, into: &buf)
+        FfiConverterBool.write(value.transcription, into: &buf)
+        FfiConverterBool.write(value.dialIn, into: &buf)
+        FfiConverterBool.write(value.subtitles, into: &buf)
+        FfiConverterBool.write(value.whiteboard, into: &buf)
+        FfiConverterOptionString.write(value.watermark, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFeatureFlags_lift(_ buf: RustBuffer) throws -> FeatureFlags {
+    return try FfiConverterTypeFeatureFlags.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeFeatureFlags_lower(_ value: FeatureFlags) -> RustBuffer {
+    return FfiConverterTypeFeatureFlags.lower(value)
+}
+
+
+public struct IceServerConfig {
+    public var urls: [String]
+    public var username: String
+    public var credential: String
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(urls: [String], username: String, credential: String) {
+        self.urls = urls
+        self.username = username
+        self.credential = credential
+    }
+}
+
+#if compiler(>=6)
+extension IceServerConfig: Sendable {}
+#endif
+
+
+extension IceServerConfig: Equatable, Hashable {
+    public static func ==(lhs: IceServerConfig, rhs: IceServerConfig) -> Bool {
+        if lhs.urls != rhs.urls {
+            return false
+        }
+        if lhs.username != rhs.username {
+            return false
+        }
+        if lhs.credential != rhs.credential {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(urls)
+        hasher.combine(username)
+        hasher.combine(credential)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeIceServerConfig: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> IceServerConfig {
+        return
+            try IceServerConfig(
+                urls: FfiConverterSequenceString.read(from: &buf), 
+                username: FfiConverterString.read(from: &buf), 
+                credential: FfiConverterString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: IceServerConfig, into buf: inout [UInt8]) {
+        FfiConverterSequenceString.write(value.urls, into: &buf)
+        FfiConverterString.write(value.username, into: &buf)
+        FfiConverterString.write(value.credential, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeIceServerConfig_lift(_ buf: RustBuffer) throws -> IceServerConfig {
+    return try FfiConverterTypeIceServerConfig.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeIceServerConfig_lower(_ value: IceServerConfig) -> RustBuffer {
+    return FfiConverterTypeIceServerConfig.lower(value)
+}
+
+
+public struct InviteInfo {
+    public var url: String
+    public var dialIn: DialInInfo?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(url: String, dialIn: DialInInfo?) {
+        self.url = url
+        self.dialIn = dialIn
+    }
+}
+
+#if compiler(>=6)
+extension InviteInfo: Sendable {}
+#endif
+
+
+extension InviteInfo: Equatable, Hashable {
+    public static func ==(lhs: InviteInfo, rhs: InviteInfo) -> Bool {
+        if lhs.url != rhs.url {
+            return false
+        }
+        if lhs.dialIn != rhs.dialIn {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(url)
+        hasher.combine(dialIn)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeInviteInfo: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> InviteInfo {
+        return
+            try InviteInfo(
+                url: FfiConverterString.read(from: &buf), 
+                dialIn: FfiConverterOptionTypeDialInInfo.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: InviteInfo, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.url, into: &buf)
+        FfiConverterOptionTypeDialInInfo.write(value.dialIn, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeInviteInfo_lift(_ buf: RustBuffer) throws -> InviteInfo {
+    return try FfiConverterTypeInviteInfo.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeInviteInfo_lower(_ value: InviteInfo) -> RustBuffer {
+    return FfiConverterTypeInviteInfo.lower(value)
+}
+
+
+public struct JoinLeaveConfig {
+    public var mode: JoinLeaveMode
+    public var windowMs: UInt64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(mode: JoinLeaveMode, windowMs: UInt64) {
+        self.mode = mode
+        self.windowMs = windowMs
+    }
+}
+
+#if compiler(>=6)
+extension JoinLeaveConfig: Sendable {}
+#endif
+
+
+extension JoinLeaveConfig: Equatable, Hashable {
+    public static func ==(lhs: JoinLeaveConfig, rhs: JoinLeaveConfig) -> Bool {
+        if lhs.mode != rhs.mode {
+            return false
+        }
+        if lhs.windowMs != rhs.windowMs {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(mode)
+        hasher.combine(windowMs)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeJoinLeaveConfig: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> JoinLeaveConfig {
+        return
+            try JoinLeaveConfig(
+                mode: FfiConverterTypeJoinLeaveMode.read(from: &buf), 
+                windowMs: FfiConverterUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: JoinLeaveConfig, into buf: inout [UInt8]) {
+        FfiConverterTypeJoinLeaveMode.write(value.mode, into: &buf)
+        FfiConverterUInt64.write(value.windowMs, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeJoinLeaveConfig_lift(_ buf: RustBuffer) throws -> JoinLeaveConfig {
+    return try FfiConverterTypeJoinLeaveConfig.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeJoinLeaveConfig_lower(_ value: JoinLeaveConfig) -> RustBuffer {
+    return FfiConverterTypeJoinLeaveConfig.lower(value)
+}
+
+
+public struct JoinOptions {
+    public var username: String?
+    public var displayName: String?
+    public var micOn: Bool
+    public var cameraOn: Bool
+    public var audioOnly: Bool
+    public var autoSubscribe: Bool
+    public var rtcOverrides: RtcConfigOverride?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(username: String? = nil, displayName: String? = nil, micOn: Bool = false, cameraOn: Bool = false, audioOnly: Bool = false, autoSubscribe: Bool = true, rtcOverrides: RtcConfigOverride? = nil) {
+        self.username = username
+        self.displayName = displayName
+        self.micOn = micOn
+        self.cameraOn = cameraOn
+        self.audioOnly = audioOnly
+        self.autoSubscribe = autoSubscribe
+        self.rtcOverrides = rtcOverrides
+    }
+}
+
+#if compiler(>=6)
+extension JoinOptions: Sendable {}
+#endif
+
+
+extension JoinOptions: Equatable, Hashable {
+    public static func ==(lhs: JoinOptions, rhs: JoinOptions) -> Bool {
+        if lhs.username != rhs.username {
+            return false
+        }
+        if lhs.displayName != rhs.displayName {
+            return false
+        }
+        if lhs.micOn != rhs.micOn {
+            return false
+        }
+        if lhs.cameraOn != rhs.cameraOn {
+            return false
+        }
+        if lhs.audioOnly != rhs.audioOnly {
+            return false
+        }
+        if lhs.autoSubscribe != rhs.autoSubscribe {
+            return false
+        }
+        if lhs.rtcOverrides != rhs.rtcOverrides {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(username)
+        hasher.combine(displayName)
+        hasher.combine(micOn)
+        hasher.combine(cameraOn)
+        hasher.combine(audioOnly)
+        hasher.combine(autoSubscribe)
+        hasher.combine(rtcOverrides)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeJoinOptions: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> JoinOptions {
+        return
+            try JoinOptions(
+                username: FfiConverterOptionString.read(from: &buf), 
+                displayName: FfiConverterOptionString.read(from: &buf), 
+                micOn: FfiConverterBool.read(from: &buf), 
+                cameraOn: FfiConverterBool.read(from: &buf), 
+                audioOnly: FfiConverterBool.read(from: &buf), 
+                autoSubscribe: FfiConverterBool.read(from: &buf), 
+                rtcOverrides: FfiConverterOptionTypeRtcConfigOverride.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: JoinOptions, into buf: inout [UInt8]) {
+        FfiConverterOptionString.write(value.username, into: &buf)
+        FfiConverterOptionString.write(value.displayName, into: &buf)
+        FfiConverterBool.write(value.micOn, into: &buf)
+        FfiConverterBool.write(value.cameraOn, into: &buf)
+        FfiConverterBool.write(value.audioOnly, into: &buf)
+        FfiConverterBool.write(value.autoSubscribe, into: &buf)
+        FfiConverterOptionTypeRtcConfigOverride.write(value.rtcOverrides, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeJoinOptions_lift(_ buf: RustBuffer) throws -> JoinOptions {
+    return try FfiConverterTypeJoinOptions.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeJoinOptions_lower(_ value: JoinOptions) -> RustBuffer {
+    return FfiConverterTypeJoinOptions.lower(value)
+}
+
+
+public struct LayoutOptions {
+    public var mode: LayoutMode
+    public var width: Float
+    public var height: Float
+    public var participantSids: [String]
+    public var spotlightSid: String?
+    public var tileAspectRatio: Float
+    public var gap: Float
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(mode: LayoutMode, width: Float, height: Float, participantSids: [String], spotlightSid: String?, tileAspectRatio: Float = Float(1.7777778), gap: Float = Float(8.0)) {
+        self.mode = mode
+        self.width = width
+        self.height = height
+        self.participantSids = participantSids
+        self.spotlightSid = spotlightSid
+        self.tileAspectRatio = tileAspectRatio
+        self.gap = gap
+    }
+}
+
+#if compiler(>=6)
+extension LayoutOptions: Sendable {}
+#endif
+
+
+extension LayoutOptions: Equatable, Hashable {
+    public static func ==(lhs: LayoutOptions, rhs: LayoutOptions) -> Bool {
+        if lhs.mode != rhs.mode {
+            return false
+        }
+        if lhs.width != rhs.width {
+            return false
+        }
+        if lhs.height != rhs.height {
+            return false
+        }
+        if lhs.participantSids != rhs.participantSids {
+            return false
+        }
+        if lhs.spotlightSid != rhs.spotlightSid {
+            return false
+        }
+        if lhs.tileAspectRatio != rhs.tileAspectRatio {
+            return false
+        }
+        if lhs.gap != rhs.gap {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(mode)
+        hasher.combine(width)
+        hasher.combine(height)
+        hasher.combine(participantSids)
+        hasher.combine(spotlightSid)
+        hasher.combine(tileAspectRatio)
+        hasher.combine(gap)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeLayoutOptions: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> LayoutOptions {
+        return
+            try LayoutOptions(
+                mode: FfiConverterTypeLayoutMode.read(from: &buf), 
+                width: FfiConverterFloat.read(from: &buf), 
+                height: FfiConverterFloat.read(from: &buf), 
+                participantSids: FfiConverterSequenceString.read(from: &buf), 
+                spotlightSid: FfiConverterOptionString.read(from: &buf), 
+                tileAspectRatio: FfiConverterFloat.read(from: &buf), 
+                gap: FfiConverterFloat.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: LayoutOptions, into buf: inout [UInt8]) {
+        FfiConverterTypeLayoutMode.write(value.mode, into: &buf)
+        FfiConverterFloat.write(value.width, into: &buf)
+        FfiConverterFloat.write(value.height, into: &buf)
+        FfiConverterSequenceString.write(value.participantSids, into: &buf)
+        FfiConverterOptionString.write(value.spotlightSid, into: &buf)
+        FfiConverterFloat.write(value.tileAspectRatio, into: &buf)
+        FfiConverterFloat.write(value.gap, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeLayoutOptions_lift(_ buf: RustBuffer) throws -> LayoutOptions {
+    return try FfiConverterTypeLayoutOptions.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeLayoutOptions_lower(_ value: LayoutOptions) -> RustBuffer {
+    return FfiConverterTypeLayoutOptions.lower(value)
+}
+
+
+public struct LocalPermissions {
+    public var accessLevel: String?
+    public var canPublish: Bool
+    public var canSubscribe: Bool
+    public var canPublishData: Bool
+    public var canPublishSources: [String]
+    public var canUpdateMetadata: Bool
+    public var isAdmin: Bool
+    public var canPublishMicrophone: Bool
+    public var canPublishCamera: Bool
+    public var canPublishScreenShare: Bool
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(accessLevel: String?, canPublish: Bool, canSubscribe: Bool, canPublishData: Bool, canPublishSources: [String], canUpdateMetadata: Bool, isAdmin: Bool, canPublishMicrophone: Bool, canPublishCamera: Bool, canPublishScreenShare: Bool) {
+        self.accessLevel = accessLevel
+        self.canPublish = canPublish
+        self.canSubscribe = canSubscribe
+        self.canPublishData = canPublishData
+        self.canPublishSources = canPublishSources
+        self.canUpdateMetadata = canUpdateMetadata
+        self.isAdmin = isAdmin
+        self.canPublishMicrophone = canPublishMicrophone
+        self.canPublishCamera = canPublishCamera
+        self.canPublishScreenShare = canPublishScreenShare
+    }
+}
+
+#if compiler(>=6)
+extension LocalPermissions: Sendable {}
+#endif
+
+
+extension LocalPermissions: Equatable, Hashable {
+    public static func ==(lhs: LocalPermissions, rhs: LocalPermissions) -> Bool {
+        if lhs.accessLevel != rhs.accessLevel {
+            return false
+        }
+        if lhs.canPublish != rhs.canPublish {
+            return false
+        }
+        if lhs.canSubscribe != rhs.canSubscribe {
+            return false
+        }
+        if lhs.canPublishData != rhs.canPublishData {
+            return false
+        }
+        if lhs.canPublishSources != rhs.canPublishSources {
+            return false
+        }
+        if lhs.canUpdateMetadata != rhs.canUpdateMetadata {
+            return false
+        }
+        if lhs.isAdmin != rhs.isAdmin {
+            return false
+        }
+        if lhs.canPublishMicrophone != rhs.canPublishMicrophone {
+            return false
+        }
+        if lhs.canPublishCamera != rhs.canPublishCamera {
+            return false
+        }
+        if lhs.canPublishScreenShare != rhs.canPublishScreenShare {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(accessLevel)
+        hasher.combine(canPublish)
+        hasher.combine(canSubscribe)
+        hasher.combine(canPublishData)
+        hasher.combine(canPublishSources)
+        hasher.combine(canUpdateMetadata)
+        hasher.combine(isAdmin)
+        hasher.combine(canPublishMicrophone)
+        hasher.combine(canPublishCamera)
+        hasher.combine(canPublishScreenShare)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeLocalPermissions: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> LocalPermissions {
+        return
+            try LocalPermissions(
+                accessLevel: FfiConverterOptionString.read(from: &buf), 
+                canPublish: FfiConverterBool.read(from: &buf), 
+                canSubscribe: FfiConverterBool.read(from: &buf), 
+                canPublishData: FfiConverterBool.read(from: &buf), 
+                canPublishSources: FfiConverterSequenceString.read(from: &buf), 
+                canUpdateMetadata: FfiConverterBool.read(from: &buf), 
+                isAdmin: FfiConverterBool.read(from: &buf), 
+                canPublishMicrophone: FfiConverterBool.read(from: &buf), 
+                canPublishCamera: FfiConverterBool.read(from: &buf), 
+                canPublishScreenShare: FfiConverterBool.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: LocalPermissions, into buf: inout [UInt8]) {
+        FfiConverterOptionString.write(value.accessLevel, into: &buf)
+        FfiConverterBool.write(value.canPublish, into: &buf)
+        FfiConverterBool.write(value.canSubscribe, into: &buf)
+        FfiConverterBool.write(value.canPublishData, into: &buf)
+        FfiConverterSequenceString.write(value.canPublishSources, into: &buf)
+        FfiConverterBool.write(value.canUpdateMetadata, into: &buf)
+        FfiConverterBool.write(value.isAdmin, into: &buf)
+        FfiConverterBool.write(value.canPublishMicrophone, into: &buf)
+        FfiConverterBool.write(value.canPublishCamera, into: &buf)
+        FfiConverterBool.write(value.canPublishScreenShare, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeLocalPermissions_lift(_ buf: RustBuffer) throws -> LocalPermissions {
+    return try FfiConverterTypeLocalPermissions.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeLocalPermissions_lower(_ value: LocalPermissions) -> RustBuffer {
+    return FfiConverterTypeLocalPermissions.lower(value)
+}
+
+
+public struct LocalRecordingOptions {
+    public var maxSizeBytes: UInt64?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(maxSizeBytes: UInt64?) {
+        self.maxSizeBytes = maxSizeBytes
+    }
+}
+
+#if compiler(>=6)
+extension LocalRecordingOptions: Sendable {}
+#endif
+
+
+extension LocalRecordingOptions: Equatable, Hashable {
+    public static func ==(lhs: LocalRecordingOptions, rhs: LocalRecordingOptions) -> Bool {
+        if lhs.maxSizeBytes != rhs.maxSizeBytes {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(maxSizeBytes)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeLocalRecordingOptions: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> LocalRecordingOptions {
+        return
+            try LocalRecordingOptions(
+                maxSizeBytes: FfiConverterOptionUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: LocalRecordingOptions, into buf: inout [UInt8]) {
+        FfiConverterOptionUInt64.write(value.maxSizeBytes, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeLocalRecordingOptions_lift(_ buf: RustBuffer) throws -> LocalRecordingOptions {
+    return try FfiConverterTypeLocalRecordingOptions.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeLocalRecordingOptions_lower(_ value: LocalRecordingOptions) -> RustBuffer {
+    return FfiConverterTypeLocalRecordingOptions.lower(value)
+}
+
+
+public struct MessageSpan {
+    public var kind: SpanKind
+    public var text: String
+    public var url: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(kind: SpanKind, text: String, url: String?) {
+        self.kind = kind
+        self.text = text
+        self.url = url
+    }
+}
+
+#if compiler(>=6)
+extension MessageSpan: Sendable {}
+#endif
+
+
+extension MessageSpan: Equatable, Hashable {
+    public static func ==(lhs: MessageSpan, rhs: MessageSpan) -> Bool {
+        if lhs.kind != rhs.kind {
+            return false
+        }
+        if lhs.text != rhs.text {
+            return false
+        }
+        if lhs.url != rhs.url {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(kind)
+        hasher.combine(text)
+        hasher.combine(url)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeMessageSpan: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> MessageSpan {
+        return
+            try MessageSpan(
+                kind: FfiConverterTypeSpanKind.read(from: &buf), 
+                text: FfiConverterString.read(from: &buf), 
+                url: FfiConverterOptionString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: MessageSpan, into buf: inout [UInt8]) {
+        FfiConverterTypeSpanKind.write(value.kind, into: &buf)
+        FfiConverterString.write(value.text, into: &buf)
+        FfiConverterOptionString.write(value.url, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeMessageSpan_lift(_ buf: RustBuffer) throws -> MessageSpan {
+    return try FfiConverterTypeMessageSpan.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeMessageSpan_lower(_ value: MessageSpan) -> RustBuffer {
+    return FfiConverterTypeMessageSpan.lower(value)
+}
+
+
+public struct NegotiatedCodecs {
+    public var sent: [String]
+    public var received: [String]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(sent: [String], received: [String]) {
+        self.sent = sent
+        self.received = received
+    }
+}
+
+#if compiler(>=6)
+extension NegotiatedCodecs: Sendable {}
+#endif
+
+
+extension NegotiatedCodecs: Equatable, Hashable {
+    public static func ==(lhs: NegotiatedCodecs, rhs: NegotiatedCodecs) -> Bool {
+        if lhs.sent != rhs.sent {
+            return false
+        }
+        if lhs.received != rhs.received {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(sent)
+        hasher.combine(received)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeNegotiatedCodecs: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> NegotiatedCodecs {
+        return
+            try NegotiatedCodecs(
+                sent: FfiConverterSequenceString.read(from: &buf), 
+                received: FfiConverterSequenceString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: NegotiatedCodecs, into buf: inout [UInt8]) {
+        FfiConverterSequenceString.write(value.sent, into: &buf)
+        FfiConverterSequenceString.write(value.received, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeNegotiatedCodecs_lift(_ buf: RustBuffer) throws -> NegotiatedCodecs {
+    return try FfiConverterTypeNegotiatedCodecs.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeNegotiatedCodecs_lower(_ value: NegotiatedCodecs) -> RustBuffer {
+    return FfiConverterTypeNegotiatedCodecs.lower(value)
+}
+
+
+public struct ParticipantInfo {
+    public var sid: String
+    public var identity: String
+    public var name: String?
+    public var isMuted: Bool
+    public var hasVideo: Bool
+    public var videoTrackSid: String?
+    public var connectionQuality: ConnectionQuality
+    public var presence: Presence
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(sid: String, identity: String, name: String?, isMuted: Bool, hasVideo: Bool, videoTrackSid: String?, connectionQuality: ConnectionQuality, presence: Presence) {
+        self.sid = sid
+        self.identity = identity
+        self.name = name
+        self.isMuted = isMuted
+        self.hasVideo = hasVideo
+        self.videoTrackSid = videoTrackSid
+        self.connectionQuality = connectionQuality
+        self.presence = presence
+    }
+}
+
+#if compiler(>=6)
+extension ParticipantInfo: Sendable {}
+#endif
+
+
+extension ParticipantInfo: Equatable, Hashable {
+    public static func ==(lhs: ParticipantInfo, rhs: ParticipantInfo) -> Bool {
+        if lhs.sid != rhs.sid {
+            return false
+        }
+        if lhs.identity != rhs.identity {
+            return false
+        }
+        if lhs.name != rhs.name {
+            return false
+        }
+        if lhs.isMuted != rhs.isMuted {
+            return false
+        }
+        if lhs.hasVideo != rhs.hasVideo {
+            return false
+        }
+        if lhs.videoTrackSid != rhs.videoTrackSid {
+            return false
+        }
+        if lhs.connectionQuality != rhs.connectionQuality {
+            return false
+        }
+        if lhs.presence != rhs.presence {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(sid)
+        hasher.combine(identity)
+        hasher.combine(name)
+        hasher.combine(isMuted)
+        hasher.combine(hasVideo)
+        hasher.combine(videoTrackSid)
+        hasher.combine(connectionQuality)
+        hasher.combine(presence)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeParticipantInfo: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ParticipantInfo {
+        return
+            try ParticipantInfo(
+                sid: FfiConverterString.read(from: &buf), 
+                identity: FfiConverterString.read(from: &buf), 
+                name: FfiConverterOptionString.read(from: &buf), 
+                isMuted: FfiConverterBool.read(from: &buf), 
+                hasVideo: FfiConverterBool.read(from: &buf), 
+                videoTrackSid: FfiConverterOptionString.read(from: &buf), 
+                connectionQuality: FfiConverterTypeConnectionQuality.read(from: &buf), 
+                presence: FfiConverterTypePresence.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: ParticipantInfo, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.sid, into: &buf)
+        FfiConverterString.write(value.identity, into: &buf)
+        FfiConverterOptionString.write(value.name, into: &buf)
+        FfiConverterBool.write(value.isMuted, into: &buf)
+        FfiConverterBool.write(value.hasVideo, into: &buf)
+        FfiConverterOptionString.write(value.videoTrackSid, into: &buf)
+        FfiConverterTypeConnectionQuality.write(value.connectionQuality, into: &buf)
+        FfiConverterTypePresence.write(value.presence, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeParticipantInfo_lift(_ buf: RustBuffer) throws -> ParticipantInfo {
+    return try FfiConverterTypeParticipantInfo.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeParticipantInfo_lower(_ value: ParticipantInfo) -> RustBuffer {
+    return FfiConverterTypeParticipantInfo.lower(value)
+}
+
+
+public struct PendingMedia {
+    public var microphone: Bool?
+    public var camera: Bool?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(microphone: Bool?, camera: Bool?) {
+        self.microphone = microphone
+        self.camera = camera
+    }
+}
+
+#if compiler(>=6)
+extension PendingMedia: Sendable {}
+#endif
+
+
+extension PendingMedia: Equatable, Hashable {
+    public static func ==(lhs: PendingMedia, rhs: PendingMedia) -> Bool {
+        if lhs.microphone != rhs.microphone {
+            return false
+        }
+        if lhs.camera != rhs.camera {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(microphone)
+        hasher.combine(camera)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypePendingMedia: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> PendingMedia {
+        return
+            try PendingMedia(
+                microphone: FfiConverterOptionBool.read(from: &buf), 
+                camera: FfiConverterOptionBool.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: PendingMedia, into buf: inout [UInt8]) {
+        FfiConverterOptionBool.write(value.microphone, into: &buf)
+        FfiConverterOptionBool.write(value.camera, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePendingMedia_lift(_ buf: RustBuffer) throws -> PendingMedia {
+    return try FfiConverterTypePendingMedia.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePendingMedia_lower(_ value: PendingMedia) -> RustBuffer {
+    return FfiConverterTypePendingMedia.lower(value)
+}
+
+
+public struct Poll {
+    public var id: String
+    public var question: String
+    public var options: [String]
+    public var counts: [UInt32]
+    public var creatorSid: String
+    public var localVote: UInt32?
+    public var ended: Bool
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(id: String, question: String, options: [String], counts: [UInt32], creatorSid: String, localVote: UInt32?, ended: Bool) {
+        self.id = id
+        self.question = question
+        self.options = options
+        self.counts = counts
+        self.creatorSid = creatorSid
+        self.localVote = localVote
+        self.ended = ended
+    }
+}
+
+#if compiler(>=6)
+extension Poll: Sendable {}
+#endif
+
+
+extension Poll: Equatable, Hashable {
+    public static func ==(lhs: Poll, rhs: Poll) -> Bool {
+        if lhs.id != rhs.id {
+            return false
+        }
+        if lhs.question != rhs.question {
+            return false
+        }
+        if lhs.options != rhs.options {
+            return false
+        }
+        if lhs.counts != rhs.counts {
+            return false
+        }
+        if lhs.creatorSid != rhs.creatorSid {
+            return false
+        }
+        if lhs.localVote != rhs.localVote {
+            return false
+        }
+        if lhs.ended != rhs.ended {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(id)
+        hasher.combine(question)
+        hasher.combine(options)
+        hasher.combine(counts)
+        hasher.combine(creatorSid)
+        hasher.combine(localVote)
+        hasher.combine(ended)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypePoll: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Poll {
+        return
+            try Poll(
+                id: FfiConverterString.read(from: &buf), 
+                question: FfiConverterString.read(from: &buf), 
+                options: FfiConverterSequenceString.read(from: &buf), 
+                counts: FfiConverterSequenceUInt32.read(from: &buf), 
+                creatorSid: FfiConverterString.read(from: &buf), 
+                localVote: FfiConverterOptionUInt32.read(from: &buf), 
+                ended: FfiConverterBool.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: Poll, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.id, into: &buf)
+        FfiConverterString.write(value.question, into: &buf)
+        FfiConverterSequenceString.write(value.options, into: &buf)
+        FfiConverterSequenceUInt32.write(value.counts, into: &buf)
+        FfiConverterString.write(value.creatorSid, into: &buf)
+        FfiConverterOptionUInt32.write(value.localVote, into: &buf)
+        FfiConverterBool.write(value.ended, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePoll_lift(_ buf: RustBuffer) throws -> Poll {
+    return try FfiConverterTypePoll.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePoll_lower(_ value: Poll) -> RustBuffer {
+    return FfiConverterTypePoll.lower(value)
+}
+
+
+public struct QualitySample {
+    public var timestampMs: UInt64
+    public var bitrateKbps: UInt32
+    public var packetLossPct: Double
+    public var quality: ConnectionQuality
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(timestampMs: UInt64, bitrateKbps: UInt32, packetLossPct: Double, quality: ConnectionQuality) {
+        self.timestampMs = timestampMs
+        self.bitrateKbps = bitrateKbps
+        self.packetLossPct = packetLossPct
+        self.quality = quality
+    }
+}
+
+#if compiler(>=6)
+extension QualitySample: Sendable {}
+#endif
+
+
+extension QualitySample: Equatable, Hashable {
+    public static func ==(lhs: QualitySample, rhs: QualitySample) -> Bool {
+        if lhs.timestampMs != rhs.timestampMs {
+            return false
+        }
+        if lhs.bitrateKbps != rhs.bitrateKbps {
+            return false
+        }
+        if lhs.packetLossPct != rhs.packetLossPct {
+            return false
+        }
+        if lhs.quality != rhs.quality {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(timestampMs)
+        hasher.combine(bitrateKbps)
+        hasher.combine(packetLossPct)
+        hasher.combine(quality)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeQualitySample: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> QualitySample {
+        return
+            try QualitySample(
+                timestampMs: FfiConverterUInt64.read(from: &buf), 
+                bitrateKbps: FfiConverterUInt32.read(from: &buf), 
+                packetLossPct: FfiConverterDouble.read(from: &buf), 
+                quality: FfiConverterTypeConnectionQuality.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: QualitySample, into buf: inout [UInt8]) {
+        FfiConverterUInt64.write(value.timestampMs, into: &buf)
+        FfiConverterUInt32.write(value.bitrateKbps, into: &buf)
+        FfiConverterDouble.write(value.packetLossPct, into: &buf)
+        FfiConverterTypeConnectionQuality.write(value.quality, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeQualitySample_lift(_ buf: RustBuffer) throws -> QualitySample {
+    return try FfiConverterTypeQualitySample.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeQualitySample_lower(_ value: QualitySample) -> RustBuffer {
+    return FfiConverterTypeQualitySample.lower(value)
+}
+
+
+public struct RendererStats {
+    public var framesReceived: UInt64
+    public var framesRendered: UInt64
+    public var framesDropped: UInt64
+    public var avgRenderMs: Double
+    public var fps: Double
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(framesReceived: UInt64, framesRendered: UInt64, framesDropped: UInt64, avgRenderMs: Double, fps: Double) {
+        self.framesReceived = framesReceived
+        self.framesRendered = framesRendered
+        self.framesDropped = framesDropped
+        self.avgRenderMs = avgRenderMs
+        self.fps = fps
+    }
+}
+
+#if compiler(>=6)
+extension RendererStats: Sendable {}
+#endif
+
+
+extension RendererStats: Equatable, Hashable {
+    public static func ==(lhs: RendererStats, rhs: RendererStats) -> Bool {
+        if lhs.framesReceived != rhs.framesReceived {
+            return false
+        }
+        if lhs.framesRendered != rhs.framesRendered {
+            return false
+        }
+        if lhs.framesDropped != rhs.framesDropped {
+            return false
+        }
+        if lhs.avgRenderMs != rhs.avgRenderMs {
+            return false
+        }
+        if lhs.fps != rhs.fps {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(framesReceived)
+        hasher.combine(framesRendered)
+        hasher.combine(framesDropped)
+        hasher.combine(avgRenderMs)
+        hasher.combine(fps)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRendererStats: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RendererStats {
+        return
+            try RendererStats(
+                framesReceived: FfiConverterUInt64.read(from: &buf), 
+                framesRendered: FfiConverterUInt64.read(from: &buf), 
+                framesDropped: FfiConverterUInt64.read(from: &buf), 
+                avgRenderMs: FfiConverterDouble.read(from: &buf), 
+                fps: FfiConverterDouble.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: RendererStats, into buf: inout [UInt8]) {
+        FfiConverterUInt64.write(value.framesReceived, into: &buf)
+        FfiConverterUInt64.write(value.framesRendered, into: &buf)
+        FfiConverterUInt64.write(value.framesDropped, into: &buf)
+        FfiConverterDouble.write(value.avgRenderMs, into: &buf)
+        FfiConverterDouble.write(value.fps, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRendererStats_lift(_ buf: RustBuffer) throws -> RendererStats {
+    return try FfiConverterTypeRendererStats.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRendererStats_lower(_ value: RendererStats) -> RustBuffer {
+    return FfiConverterTypeRendererStats.lower(value)
+}
+
+
+public struct RestreamStatus {
+    public var state: RestreamState
+    public var videoBitrateKbps: UInt32
+    public var error: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(state: RestreamState, videoBitrateKbps: UInt32, error: String?) {
+        self.state = state
+        self.videoBitrateKbps = videoBitrateKbps
+        self.error = error
+    }
+}
+
+#if compiler(>=6)
+extension RestreamStatus: Sendable {}
+#endif
+
+
+extension RestreamStatus: Equatable, Hashable {
+    public static func ==(lhs: RestreamStatus, rhs: RestreamStatus) -> Bool {
+        if lhs.state != rhs.state {
+            return false
+        }
+        if lhs.videoBitrateKbps != rhs.videoBitrateKbps {
+            return false
+        }
+        if lhs.error != rhs.error {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(state)
+        hasher.combine(videoBitrateKbps)
+        hasher.combine(error)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRestreamStatus: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RestreamStatus {
+        return
+            try RestreamStatus(
+                state: FfiConverterTypeRestreamState.read(from: &buf), 
+                videoBitrateKbps: FfiConverterUInt32.read(from: &buf), 
+                error: FfiConverterOptionString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: RestreamStatus, into buf: inout [UInt8]) {
+        FfiConverterTypeRestreamState.write(value.state, into: &buf)
+        FfiConverterUInt32.write(value.videoBitrateKbps, into: &buf)
+        FfiConverterOptionString.write(value.error, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRestreamStatus_lift(_ buf: RustBuffer) throws -> RestreamStatus {
+    return try FfiConverterTypeRestreamStatus.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRestreamStatus_lower(_ value: RestreamStatus) -> RustBuffer {
+    return FfiConverterTypeRestreamStatus.lower(value)
+}
+
+
+public struct RoomInfo {
+    public var roomId: String?
+    public var accessLevel: String?
+    public var pinCode: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(roomId: String?, accessLevel: String?, pinCode: String?) {
+        self.roomId = roomId
+        self.accessLevel = accessLevel
+        self.pinCode = pinCode
+    }
+}
+
+#if compiler(>=6)
+extension RoomInfo: Sendable {}
+#endif
+
+
+extension RoomInfo: Equatable, Hashable {
+    public static func ==(lhs: RoomInfo, rhs: RoomInfo) -> Bool {
+        if lhs.roomId != rhs.roomId {
+            return false
+        }
+        if lhs.accessLevel != rhs.accessLevel {
+            return false
+        }
+        if lhs.pinCode != rhs.pinCode {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(roomId)
+        hasher.combine(accessLevel)
+        hasher.combine(pinCode)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRoomInfo: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RoomInfo {
+        return
+            try RoomInfo(
+                roomId: FfiConverterOptionString.read(from: &buf), 
+                accessLevel: FfiConverterOptionString.read(from: &buf), 
+                pinCode: FfiConverterOptionString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: RoomInfo, into buf: inout [UInt8]) {
+        FfiConverterOptionString.write(value.roomId, into: &buf)
+        FfiConverterOptionString.write(value.accessLevel, into: &buf)
+        FfiConverterOptionString.write(value.pinCode, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRoomInfo_lift(_ buf: RustBuffer) throws -> RoomInfo {
+    return try FfiConverterTypeRoomInfo.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRoomInfo_lower(_ value: RoomInfo) -> RustBuffer {
+    return FfiConverterTypeRoomInfo.lower(value)
+}
+
+
+public struct RtcConfigOverride {
+    public var iceServers: [IceServerConfig]
+    public var relayOnly: Bool
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(iceServers: [IceServerConfig], relayOnly: Bool) {
+        self.iceServers = iceServers
+        self.relayOnly = relayOnly
+    }
+}
+
+#if compiler(>=6)
+extension RtcConfigOverride: Sendable {}
+#endif
+
+
+extension RtcConfigOverride: Equatable, Hashable {
+    public static func ==(lhs: RtcConfigOverride, rhs: RtcConfigOverride) -> Bool {
+        if lhs.iceServers != rhs.iceServers {
+            return false
+        }
+        if lhs.relayOnly != rhs.relayOnly {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(iceServers)
+        hasher.combine(relayOnly)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRtcConfigOverride: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RtcConfigOverride {
+        return
+            try RtcConfigOverride(
+                iceServers: FfiConverterSequenceTypeIceServerConfig.read(from: &buf), 
+                relayOnly: FfiConverterBool.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: RtcConfigOverride, into buf: inout [UInt8]) {
+        FfiConverterSequenceTypeIceServerConfig.write(value.iceServers, into: &buf)
+        FfiConverterBool.write(value.relayOnly, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRtcConfigOverride_lift(_ buf: RustBuffer) throws -> RtcConfigOverride {
+    return try FfiConverterTypeRtcConfigOverride.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRtcConfigOverride_lower(_ value: RtcConfigOverride) -> RustBuffer {
+    return FfiConverterTypeRtcConfigOverride.lower(value)
+}
+
+
+public struct SelfTestReport {
+    public var webrtc: CheckResult
+    public var runtime: CheckResult
+    public var dataDir: CheckResult
+    public var hasProblem: Bool
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(webrtc: CheckResult, runtime: CheckResult, dataDir: CheckResult, hasProblem: Bool) {
+        self.webrtc = webrtc
+        self.runtime = runtime
+        self.dataDir = dataDir
+        self.hasProblem = hasProblem
+    }
+}
+
+#if compiler(>=6)
+extension SelfTestReport: Sendable {}
+#endif
+
+
+extension SelfTestReport: Equatable, Hashable {
+    public static func ==(lhs: SelfTestReport, rhs: SelfTestReport) -> Bool {
+        if lhs.webrtc != rhs.webrtc {
+            return false
+        }
+        if lhs.runtime != rhs.runtime {
+            return false
+        }
+        if lhs.dataDir != rhs.dataDir {
+            return false
+        }
+        if lhs.hasProblem != rhs.hasProblem {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(webrtc)
+        hasher.combine(runtime)
+        hasher.combine(dataDir)
+        hasher.combine(hasProblem)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSelfTestReport: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SelfTestReport {
+        return
+            try SelfTestReport(
+                webrtc: FfiConverterTypeCheckResult.read(from: &buf), 
+                runtime: FfiConverterTypeCheckResult.read(from: &buf), 
+                dataDir: FfiConverterTypeCheckResult.read(from: &buf), 
+                hasProblem: FfiConverterBool.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: SelfTestReport, into buf: inout [UInt8]) {
+        FfiConverterTypeCheckResult.write(value.webrtc, into: &buf)
+        FfiConverterTypeCheckResult.write(value.runtime, into: &buf)
+        FfiConverterTypeCheckResult.write(value.dataDir, into: &buf)
+        FfiConverterBool.write(value.hasProblem, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSelfTestReport_lift(_ buf: RustBuffer) throws -> SelfTestReport {
+    return try FfiConverterTypeSelfTestReport.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSelfTestReport_lower(_ value: SelfTestReport) -> RustBuffer {
+    return FfiConverterTypeSelfTestReport.lower(value)
+}
+
+
+public struct Settings {
+    public var displayName: String?
+    public var language: String?
+    public var micEnabledOnJoin: Bool
+    public var cameraEnabledOnJoin: Bool
+    public var theme: String
+    public var meetInstances: [String]
+    public var notificationParticipantJoin: Bool
+    public var notificationHandRaised: Bool
+    public var notificationMessageReceived: Bool
+    public var videoOverlayText: String?
+    public var videoOverlayCorner: OverlayCorner
+    public var videoPrivacyStyle: VideoPrivacyStyle
+    public var handAutoLowerEnabled: Bool
+    public var handAutoLowerDelaySecs: UInt32
+    public var metricsEnabled: Bool
+    public var metricsEndpoint: String?
+    public var feedbackDiagnosticsEnabled: Bool
+    public var rtcConfig: RtcConfigOverride
+    public var preferredMicrophoneId: String?
+    public var preferredCameraId: String?
+    public var preferredSpeakerId: String?
+    public var audioDuckingEnabled: Bool
+    public var audioDuckingLevel: Float
+    public var autoGainEnabled: Bool
+    public var autoGainTargetLufs: Float
+    public var audioPreset: AudioPreset
+    public var aloneTimeoutMins: UInt32
+    public var autoLeaveWhenAlone: Bool
+    public var inactivityTimeoutMins: UInt32
+    public var qualityPolicyEnabled: Bool
+    public var qualityPolicyDelaySecs: UInt32
+    public var qualityPolicyPauseRemoteVideo: Bool
+    public var extras: [String: String]
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(displayName: String?, language: String?, micEnabledOnJoin: Bool, cameraEnabledOnJoin: Bool, theme: String, meetInstances: [String], notificationParticipantJoin: Bool, notificationHandRaised: Bool, notificationMessageReceived: Bool, videoOverlayText: String?, videoOverlayCorner: OverlayCorner, videoPrivacyStyle: VideoPrivacyStyle, handAutoLowerEnabled: Bool, handAutoLowerDelaySecs: UInt32, metricsEnabled: Bool, metricsEndpoint: String?, feedbackDiagnosticsEnabled: Bool, rtcConfig: RtcConfigOverride, preferredMicrophoneId: String?, preferredCameraId: String?, preferredSpeakerId: String?, audioDuckingEnabled: Bool, audioDuckingLevel: Float, autoGainEnabled: Bool, autoGainTargetLufs: Float, audioPreset: AudioPreset, aloneTimeoutMins: UInt32, autoLeaveWhenAlone: Bool, inactivityTimeoutMins: UInt32, qualityPolicyEnabled: Bool, qualityPolicyDelaySecs: UInt32, qualityPolicyPauseRemoteVideo: Bool, extras: [String: String]) {
+        self.displayName = displayName
+        self.language = language
+        self.micEnabledOnJoin = micEnabledOnJoin
+        self.cameraEnabledOnJoin = cameraEnabledOnJoin
+        self.theme = theme
+        self.meetInstances = meetInstances
+        self.notificationParticipantJoin = notificationParticipantJoin
+        self.notificationHandRaised = notificationHandRaised
+        self.notificationMessageReceived = notificationMessageReceived
+        self.videoOverlayText = videoOverlayText
+        self.videoOverlayCorner = videoOverlayCorner
+        self.videoPrivacyStyle = videoPrivacyStyle
+        self.handAutoLowerEnabled = handAutoLowerEnabled
+        self.handAutoLowerDelaySecs = handAutoLowerDelaySecs
+        self.metricsEnabled = metricsEnabled
+        self.metricsEndpoint = metricsEndpoint
+        self.feedbackDiagnosticsEnabled = feedbackDiagnosticsEnabled
+        self.rtcConfig = rtcConfig
+        self.preferredMicrophoneId = preferredMicrophoneId
+        self.preferredCameraId = preferredCameraId
+        self.preferredSpeakerId = preferredSpeakerId
+        self.audioDuckingEnabled = audioDuckingEnabled
+        self.audioDuckingLevel = audioDuckingLevel
+        self.autoGainEnabled = autoGainEnabled
+        self.autoGainTargetLufs = autoGainTargetLufs
+        self.audioPreset = audioPreset
+        self.aloneTimeoutMins = aloneTimeoutMins
+        self.autoLeaveWhenAlone = autoLeaveWhenAlone
+        self.inactivityTimeoutMins = inactivityTimeoutMins
+        self.qualityPolicyEnabled = qualityPolicyEnabled
+        self.qualityPolicyDelaySecs = qualityPolicyDelaySecs
+        self.qualityPolicyPauseRemoteVideo = qualityPolicyPauseRemoteVideo
+        self.extras = extras
+    }
+}
+
+#if compiler(>=6)
+extension Settings: Sendable {}
+#endif
+
+
+extension Settings: Equatable, Hashable {
+    public static func ==(lhs: Settings, rhs: Settings) -> Bool {
+        if lhs.displayName != rhs.displayName {
+            return false
+        }
+        if lhs.language != rhs.language {
+            return false
+        }
+        if lhs.micEnabledOnJoin != rhs.micEnabledOnJoin {
+            return false
+        }
+        if lhs.cameraEnabledOnJoin != rhs.cameraEnabledOnJoin {
+            return false
+        }
+        if lhs.theme != rhs.theme {
+            return false
+        }
+        if lhs.meetInstances != rhs.meetInstances {
+            return false
+        }
+        if lhs.notificationParticipantJoin != rhs.notificationParticipantJoin {
+            return false
+        }
+        if lhs.notificationHandRaised != rhs.notificationHandRaised {
+            return false
+        }
+        if lhs.notificationMessageReceived != rhs.notificationMessageReceived {
+            return false
+        }
+        if lhs.videoOverlayText != rhs.videoOverlayText {
+            return false
+        }
+        if lhs.videoOverlayCorner != rhs.videoOverlayCorner {
+            return false
+        }
+        if lhs.videoPrivacyStyle != rhs.videoPrivacyStyle {
+            return false
+        }
+        if lhs.handAutoLowerEnabled != rhs.handAutoLowerEnabled {
+            return false
+        }
+        if lhs.handAutoLowerDelaySecs != rhs.handAutoLowerDelaySecs {
+            return false
+        }
+        if lhs.metricsEnabled != rhs.metricsEnabled {
+            return false
+        }
+        if lhs.metricsEndpoint != rhs.metricsEndpoint {
+            return false
+        }
+        if lhs.feedbackDiagnosticsEnabled != rhs.feedbackDiagnosticsEnabled {
+            return false
+        }
+        if lhs.rtcConfig != rhs.rtcConfig {
+            return false
+        }
+        if lhs.preferredMicrophoneId != rhs.preferredMicrophoneId {
+            return false
+        }
+        if lhs.preferredCameraId != rhs.preferredCameraId {
+            return false
+        }
+        if lhs.preferredSpeakerId != rhs.preferredSpeakerId {
+            return false
+        }
+        if lhs.audioDuckingEnabled != rhs.audioDuckingEnabled {
+            return false
+        }
+        if lhs.audioDuckingLevel != rhs.audioDuckingLevel {
+            return false
+        }
+        if lhs.autoGainEnabled != rhs.autoGainEnabled {
+            return false
+        }
+        if lhs.autoGainTargetLufs != rhs.autoGainTargetLufs {
+            return false
+        }
+        if lhs.audioPreset != rhs.audioPreset {
+            return false
+        }
+        if lhs.aloneTimeoutMins != rhs.aloneTimeoutMins {
+            return false
+        }
+        if lhs.autoLeaveWhenAlone != rhs.autoLeaveWhenAlone {
+            return false
+        }
+        if lhs.inactivityTimeoutMins != rhs.inactivityTimeoutMins {
+            return false
+        }
+        if lhs.qualityPolicyEnabled != rhs.qualityPolicyEnabled {
+            return false
+        }
+        if lhs.qualityPolicyDelaySecs != rhs.qualityPolicyDelaySecs {
+            return false
+        }
+        if lhs.qualityPolicyPauseRemoteVideo != rhs.qualityPolicyPauseRemoteVideo {
+            return false
+        }
+        if lhs.extras != rhs.extras {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(displayName)
+        hasher.combine(language)
+        hasher.combine(micEnabledOnJoin)
+        hasher.combine(cameraEnabledOnJoin)
+        hasher.combine(theme)
+        hasher.combine(meetInstances)
+        hasher.combine(notificationParticipantJoin)
+        hasher.combine(notificationHandRaised)
+        hasher.combine(notificationMessageReceived)
+        hasher.combine(videoOverlayText)
+        hasher.combine(videoOverlayCorner)
+        hasher.combine(videoPrivacyStyle)
+        hasher.combine(handAutoLowerEnabled)
+        hasher.combine(handAutoLowerDelaySecs)
+        hasher.combine(metricsEnabled)
+        hasher.combine(metricsEndpoint)
+        hasher.combine(feedbackDiagnosticsEnabled)
+        hasher.combine(rtcConfig)
+        hasher.combine(preferredMicrophoneId)
+        hasher.combine(preferredCameraId)
+        hasher.combine(preferredSpeakerId)
+        hasher.combine(audioDuckingEnabled)
+        hasher.combine(audioDuckingLevel)
+        hasher.combine(autoGainEnabled)
+        hasher.combine(autoGainTargetLufs)
+        hasher.combine(audioPreset)
+        hasher.combine(aloneTimeoutMins)
+        hasher.combine(autoLeaveWhenAlone)
+        hasher.combine(inactivityTimeoutMins)
+        hasher.combine(qualityPolicyEnabled)
+        hasher.combine(qualityPolicyDelaySecs)
+        hasher.combine(qualityPolicyPauseRemoteVideo)
+        hasher.combine(extras)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSettings: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Settings {
+        return
+            try Settings(
+                displayName: FfiConverterOptionString.read(from: &buf), 
+                language: FfiConverterOptionString.read(from: &buf), 
+                micEnabledOnJoin: FfiConverterBool.read(from: &buf), 
+                cameraEnabledOnJoin: FfiConverterBool.read(from: &buf), 
+                theme: FfiConverterString.read(from: &buf), 
+                meetInstances: FfiConverterSequenceString.read(from: &buf), 
+                notificationParticipantJoin: FfiConverterBool.read(from: &buf), 
+                notificationHandRaised: FfiConverterBool.read(from: &buf), 
+                notificationMessageReceived: FfiConverterBool.read(from: &buf), 
+                videoOverlayText: FfiConverterOptionString.read(from: &buf), 
+                videoOverlayCorner: FfiConverterTypeOverlayCorner.read(from: &buf), 
+                videoPrivacyStyle: FfiConverterTypeVideoPrivacyStyle.read(from: &buf), 
+                handAutoLowerEnabled: FfiConverterBool.read(from: &buf), 
+                handAutoLowerDelaySecs: FfiConverterUInt32.read(from: &buf), 
+                metricsEnabled: FfiConverterBool.read(from: &buf), 
+                metricsEndpoint: FfiConverterOptionString.read(from: &buf), 
+                feedbackDiagnosticsEnabled: FfiConverterBool.read(from: &buf), 
+                rtcConfig: FfiConverterTypeRtcConfigOverride.read(from: &buf), 
+                preferredMicrophoneId: FfiConverterOptionString.read(from: &buf), 
+                preferredCameraId: FfiConverterOptionString.read(from: &buf), 
+                preferredSpeakerId: FfiConverterOptionString.read(from: &buf), 
+                audioDuckingEnabled: FfiConverterBool.read(from: &buf), 
+                audioDuckingLevel: FfiConverterFloat.read(from: &buf), 
+                autoGainEnabled: FfiConverterBool.read(from: &buf), 
+                autoGainTargetLufs: FfiConverterFloat.read(from: &buf), 
+                audioPreset: FfiConverterTypeAudioPreset.read(from: &buf), 
+                aloneTimeoutMins: FfiConverterUInt32.read(from: &buf), 
+                autoLeaveWhenAlone: FfiConverterBool.read(from: &buf), 
+                inactivityTimeoutMins: FfiConverterUInt32.read(from: &buf), 
+                qualityPolicyEnabled: FfiConverterBool.read(from: &buf), 
+                qualityPolicyDelaySecs: FfiConverterUInt32.read(from: &buf), 
+                qualityPolicyPauseRemoteVideo: FfiConverterBool.read(from: &buf), 
+                extras: FfiConverterDictionaryStringString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: Settings, into buf: inout [UInt8]) {
+        FfiConverterOptionString.write(value.displayName, into: &buf)
+        FfiConverterOptionString.write(value.language, into: &buf)
+        FfiConverterBool.write(value.micEnabledOnJoin, into: &buf)
+        FfiConverterBool.write(value.cameraEnabledOnJoin, into: &buf)
+        FfiConverterString.write(value.theme, into: &buf)
+        FfiConverterSequenceString.write(value.meetInstances, into: &buf)
+        FfiConverterBool.write(value.notificationParticipantJoin, into: &buf)
+        FfiConverterBool.write(value.notificationHandRaised, into: &buf)
+        FfiConverterBool.write(value.notificationMessageReceived, into: &buf)
+        FfiConverterOptionString.write(value.videoOverlayText, into: &buf)
+        FfiConverterTypeOverlayCorner.write(value.videoOverlayCorner, into: &buf)
+        FfiConverterTypeVideoPrivacyStyle.write(value.videoPrivacyStyle, into: &buf)
+        FfiConverterBool.write(value.handAutoLowerEnabled, into: &buf)
+        FfiConverterUInt32.write(value.handAutoLowerDelaySecs, into: &buf)
+        FfiConverterBool.write(value.metricsEnabled, into: &buf)
+        FfiConverterOptionString.write(value.metricsEndpoint, into: &buf)
+        FfiConverterBool.write(value.feedbackDiagnosticsEnabled, into: &buf)
+        FfiConverterTypeRtcConfigOverride.write(value.rtcConfig, into: &buf)
+        FfiConverterOptionString.write(value.preferredMicrophoneId, into: &buf)
+        FfiConverterOptionString.write(value.preferredCameraId, into: &buf)
+        FfiConverterOptionString.write(value.preferredSpeakerId, into: &buf)
+        FfiConverterBool.write(value.audioDuckingEnabled, into: &buf)
+        FfiConverterFloat.write(value.audioDuckingLevel, into: &buf)
+        FfiConverterBool.write(value.autoGainEnabled, into: &buf)
+        FfiConverterFloat.write(value.autoGainTargetLufs, into: &buf)
+        FfiConverterTypeAudioPreset.write(value.audioPreset, into: &buf)
+        FfiConverterUInt32.write(value.aloneTimeoutMins, into: &buf)
+        FfiConverterBool.write(value.autoLeaveWhenAlone, into: &buf)
+        FfiConverterUInt32.write(value.inactivityTimeoutMins, into: &buf)
+        FfiConverterBool.write(value.qualityPolicyEnabled, into: &buf)
+        FfiConverterUInt32.write(value.qualityPolicyDelaySecs, into: &buf)
+        FfiConverterBool.write(value.qualityPolicyPauseRemoteVideo, into: &buf)
+        FfiConverterDictionaryStringString.write(value.extras, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSettings_lift(_ buf: RustBuffer) throws -> Settings {
+    return try FfiConverterTypeSettings.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSettings_lower(_ value: Settings) -> RustBuffer {
+    return FfiConverterTypeSettings.lower(value)
+}
+
+
+public struct SystemCall {
+    public var callId: String
+    public var room: String
+    public var displayName: String
+    public var direction: CallDirection
+    public var state: SystemCallState
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(callId: String, room: String, displayName: String, direction: CallDirection, state: SystemCallState) {
+        self.callId = callId
+        self.room = room
+        self.displayName = displayName
+        self.direction = direction
+        self.state = state
+    }
+}
+
+#if compiler(>=6)
+extension SystemCall: Sendable {}
+#endif
+
+
+extension SystemCall: Equatable, Hashable {
+    public static func ==(lhs: SystemCall, rhs: SystemCall) -> Bool {
+        if lhs.callId != rhs.callId {
+            return false
+        }
+        if lhs.room != rhs.room {
+            return false
+        }
+        if lhs.displayName != rhs.displayName {
+            return false
+        }
+        if lhs.direction != rhs.direction {
+            return false
+        }
+        if lhs.state != rhs.state {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(callId)
+        hasher.combine(room)
+        hasher.combine(displayName)
+        hasher.combine(direction)
+        hasher.combine(state)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSystemCall: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SystemCall {
+        return
+            try SystemCall(
+                callId: FfiConverterString.read(from: &buf), 
+                room: FfiConverterString.read(from: &buf), 
+                displayName: FfiConverterString.read(from: &buf), 
+                direction: FfiConverterTypeCallDirection.read(from: &buf), 
+                state: FfiConverterTypeSystemCallState.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: SystemCall, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.callId, into: &buf)
+        FfiConverterString.write(value.room, into: &buf)
+        FfiConverterString.write(value.displayName, into: &buf)
+        FfiConverterTypeCallDirection.write(value.direction, into: &buf)
+        FfiConverterTypeSystemCallState.write(value.state, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSystemCall_lift(_ buf: RustBuffer) throws -> SystemCall {
+    return try FfiConverterTypeSystemCall.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSystemCall_lower(_ value: SystemCall) -> RustBuffer {
+    return FfiConverterTypeSystemCall.lower(value)
+}
+
+
+public struct TileRect {
+    public var participantSid: String
+    public var x: Float
+    public var y: Float
+    public var width: Float
+    public var height: Float
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(participantSid: String, x: Float, y: Float, width: Float, height: Float) {
+        self.participantSid = participantSid
+        self.x = x
+        self.y = y
+        self.width = width
+        self.height = height
+    }
+}
+
+#if compiler(>=6)
+extension TileRect: Sendable {}
+#endif
+
+
+extension TileRect: Equatable, Hashable {
+    public static func ==(lhs: TileRect, rhs: TileRect) -> Bool {
+        if lhs.participantSid != rhs.participantSid {
+            return false
+        }
+        if lhs.x != rhs.x {
+            return false
+        }
+        if lhs.y != rhs.y {
+            return false
+        }
+        if lhs.width != rhs.width {
+            return false
+        }
+        if lhs.height != rhs.height {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(participantSid)
+        hasher.combine(x)
+        hasher.combine(y)
+        hasher.combine(width)
+        hasher.combine(height)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTileRect: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TileRect {
+        return
+            try TileRect(
+                participantSid: FfiConverterString.read(from: &buf), 
+                x: FfiConverterFloat.read(from: &buf), 
+                y: FfiConverterFloat.read(from: &buf), 
+                width: FfiConverterFloat.read(from: &buf), 
+                height: FfiConverterFloat.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: TileRect, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.participantSid, into: &buf)
+        FfiConverterFloat.write(value.x, into: &buf)
+        FfiConverterFloat.write(value.y, into: &buf)
+        FfiConverterFloat.write(value.width, into: &buf)
+        FfiConverterFloat.write(value.height, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTileRect_lift(_ buf: RustBuffer) throws -> TileRect {
+    return try FfiConverterTypeTileRect.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTileRect_lower(_ value: TileRect) -> RustBuffer {
+    return FfiConverterTypeTileRect.lower(value)
+}
+
+
+public struct TrackInfo {
+    public var sid: String
+    public var participantSid: String
+    public var kind: TrackKind
+    public var source: TrackSource
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(sid: String, participantSid: String, kind: TrackKind, source: TrackSource) {
+        self.sid = sid
+        self.participantSid = participantSid
+        self.kind = kind
+        self.source = source
+    }
+}
+
+#if compiler(>=6)
+extension TrackInfo: Sendable {}
+#endif
+
+
+extension TrackInfo: Equatable, Hashable {
+    public static func ==(lhs: TrackInfo, rhs: TrackInfo) -> Bool {
+        if lhs.sid != rhs.sid {
+            return false
+        }
+        if lhs.participantSid != rhs.participantSid {
+            return false
+        }
+        if lhs.kind != rhs.kind {
+            return false
+        }
+        if lhs.source != rhs.source {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(sid)
+        hasher.combine(participantSid)
+        hasher.combine(kind)
+        hasher.combine(source)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTrackInfo: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TrackInfo {
+        return
+            try TrackInfo(
+                sid: FfiConverterString.read(from: &buf), 
+                participantSid: FfiConverterString.read(from: &buf), 
+                kind: FfiConverterTypeTrackKind.read(from: &buf), 
+                source: FfiConverterTypeTrackSource.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: TrackInfo, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.sid, into: &buf)
+        FfiConverterString.write(value.participantSid, into: &buf)
+        FfiConverterTypeTrackKind.write(value.kind, into: &buf)
+        FfiConverterTypeTrackSource.write(value.source, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTrackInfo_lift(_ buf: RustBuffer) throws -> TrackInfo {
+    return try FfiConverterTypeTrackInfo.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTrackInfo_lower(_ value: TrackInfo) -> RustBuffer {
+    return FfiConverterTypeTrackInfo.lower(value)
+}
+
+
+public struct VideoStats {
+    public var width: UInt32
+    public var height: UInt32
+    public var isHd: Bool
+    public var fps: Double
+    public var avgDecodeMs: Double
+    public var freezeCount: UInt32
+    public var renderFps: Double
+    public var framesDropped: UInt64
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(width: UInt32, height: UInt32, isHd: Bool, fps: Double, avgDecodeMs: Double, freezeCount: UInt32, renderFps: Double, framesDropped: UInt64) {
+        self.width = width
+        self.height = height
+        self.isHd = isHd
+        self.fps = fps
+        self.avgDecodeMs = avgDecodeMs
+        self.freezeCount = freezeCount
+        self.renderFps = renderFps
+        self.framesDropped = framesDropped
+    }
+}
+
+#if compiler(>=6)
+extension VideoStats: Sendable {}
+#endif
+
+
+extension VideoStats: Equatable, Hashable {
+    public static func ==(lhs: VideoStats, rhs: VideoStats) -> Bool {
+        if lhs.width != rhs.width {
+            return false
+        }
+        if lhs.height != rhs.height {
+            return false
+        }
+        if lhs.isHd != rhs.isHd {
+            return false
+        }
+        if lhs.fps != rhs.fps {
+            return false
+        }
+        if lhs.avgDecodeMs != rhs.avgDecodeMs {
+            return false
+        }
+        if lhs.freezeCount != rhs.freezeCount {
+            return false
+        }
+        if lhs.renderFps != rhs.renderFps {
+            return false
+        }
+        if lhs.framesDropped != rhs.framesDropped {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(width)
+        hasher.combine(height)
+        hasher.combine(isHd)
+        hasher.combine(fps)
+        hasher.combine(avgDecodeMs)
+        hasher.combine(freezeCount)
+        hasher.combine(renderFps)
+        hasher.combine(framesDropped)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeVideoStats: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> VideoStats {
+        return
+            try VideoStats(
+                width: FfiConverterUInt32.read(from: &buf), 
+                height: FfiConverterUInt32.read(from: &buf), 
+                isHd: FfiConverterBool.read(from: &buf), 
+                fps: FfiConverterDouble.read(from: &buf), 
+                avgDecodeMs: FfiConverterDouble.read(from: &buf), 
+                freezeCount: FfiConverterUInt32.read(from: &buf), 
+                renderFps: FfiConverterDouble.read(from: &buf), 
+                framesDropped: FfiConverterUInt64.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: VideoStats, into buf: inout [UInt8]) {
+        FfiConverterUInt32.write(value.width, into: &buf)
+        FfiConverterUInt32.write(value.height, into: &buf)
+        FfiConverterBool.write(value.isHd, into: &buf)
+        FfiConverterDouble.write(value.fps, into: &buf)
+        FfiConverterDouble.write(value.avgDecodeMs, into: &buf)
+        FfiConverterUInt32.write(value.freezeCount, into: &buf)
+        FfiConverterDouble.write(value.renderFps, into: &buf)
+        FfiConverterUInt64.write(value.framesDropped, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeVideoStats_lift(_ buf: RustBuffer) throws -> VideoStats {
+    return try FfiConverterTypeVideoStats.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeVideoStats_lower(_ value: VideoStats) -> RustBuffer {
+    return FfiConverterTypeVideoStats.lower(value)
+}
+
+
+public struct WhiteboardSession {
+    public var url: String
+    public var token: String?
+
+    // Default memberwise initializers are never public by default, so we
+    // declare one manually.
+    public init(url: String, token: String?) {
+        self.url = url
+        self.token = token
+    }
+}
+
+#if compiler(>=6)
+extension WhiteboardSession: Sendable {}
+#endif
+
+
+extension WhiteboardSession: Equatable, Hashable {
+    public static func ==(lhs: WhiteboardSession, rhs: WhiteboardSession) -> Bool {
+        if lhs.url != rhs.url {
+            return false
+        }
+        if lhs.token != rhs.token {
+            return false
+        }
+        return true
+    }
+
+    public func hash(into hasher: inout Hasher) {
+        hasher.combine(url)
+        hasher.combine(token)
+    }
+}
+
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeWhiteboardSession: FfiConverterRustBuffer {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> WhiteboardSession {
+        return
+            try WhiteboardSession(
+                url: FfiConverterString.read(from: &buf), 
+                token: FfiConverterOptionString.read(from: &buf)
+        )
+    }
+
+    public static func write(_ value: WhiteboardSession, into buf: inout [UInt8]) {
+        FfiConverterString.write(value.url, into: &buf)
+        FfiConverterOptionString.write(value.token, into: &buf)
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeWhiteboardSession_lift(_ buf: RustBuffer) throws -> WhiteboardSession {
+    return try FfiConverterTypeWhiteboardSession.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeWhiteboardSession_lower(_ value: WhiteboardSession) -> RustBuffer {
+    return FfiConverterTypeWhiteboardSession.lower(value)
+}
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum AudioPreset {
+    
+    case voice
+    case music
+}
+
+
+#if compiler(>=6)
+extension AudioPreset: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeAudioPreset: FfiConverterRustBuffer {
+    typealias SwiftType = AudioPreset
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> AudioPreset {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .voice
+        
+        case 2: return .music
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: AudioPreset, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .voice:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .music:
+            writeInt(&buf, Int32(2))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeAudioPreset_lift(_ buf: RustBuffer) throws -> AudioPreset {
+    return try FfiConverterTypeAudioPreset.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeAudioPreset_lower(_ value: AudioPreset) -> RustBuffer {
+    return FfiConverterTypeAudioPreset.lower(value)
+}
+
+
+extension AudioPreset: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum CallDirection {
+    
+    case incoming
+    case outgoing
+}
+
+
+#if compiler(>=6)
+extension CallDirection: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeCallDirection: FfiConverterRustBuffer {
+    typealias SwiftType = CallDirection
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CallDirection {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .incoming
+        
+        case 2: return .outgoing
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: CallDirection, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .incoming:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .outgoing:
+            writeInt(&buf, Int32(2))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCallDirection_lift(_ buf: RustBuffer) throws -> CallDirection {
+    return try FfiConverterTypeCallDirection.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCallDirection_lower(_ value: CallDirection) -> RustBuffer {
+    return FfiConverterTypeCallDirection.lower(value)
+}
+
+
+extension CallDirection: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum CameraCommand {
+    
+    case setTorch(enabled: Bool
+    )
+    case setZoom(factor: Float
+    )
+    case setFocusPoint(x: Float, y: Float
+    )
+}
+
+
+#if compiler(>=6)
+extension CameraCommand: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeCameraCommand: FfiConverterRustBuffer {
+    typealias SwiftType = CameraCommand
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CameraCommand {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .setTorch(enabled: try FfiConverterBool.read(from: &buf)
+        )
+        
+        case 2: return .setZoom(factor: try FfiConverterFloat.read(from: &buf)
+        )
+        
+        case 3: return .setFocusPoint(x: try FfiConverterFloat.read(from: &buf), y: try FfiConverterFloat.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: CameraCommand, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case let .setTorch(enabled):
+            writeInt(&buf, Int32(1))
+            FfiConverterBool.write(enabled, into: &buf)
+            
+        
+        case let .setZoom(factor):
+            writeInt(&buf, Int32(2))
+            FfiConverterFloat.write(factor, into: &buf)
+            
+        
+        case let .setFocusPoint(x,y):
+            writeInt(&buf, Int32(3))
+            FfiConverterFloat.write(x, into: &buf)
+            FfiConverterFloat.write(y, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCameraCommand_lift(_ buf: RustBuffer) throws -> CameraCommand {
+    return try FfiConverterTypeCameraCommand.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCameraCommand_lower(_ value: CameraCommand) -> RustBuffer {
+    return FfiConverterTypeCameraCommand.lower(value)
+}
+
+
+extension CameraCommand: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum ChatExportFormat {
+    
+    case txt
+    case json
+    case csv
+}
+
+
+#if compiler(>=6)
+extension ChatExportFormat: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeChatExportFormat: FfiConverterRustBuffer {
+    typealias SwiftType = ChatExportFormat
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ChatExportFormat {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .txt
+        
+        case 2: return .json
+        
+        case 3: return .csv
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: ChatExportFormat, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .txt:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .json:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .csv:
+            writeInt(&buf, Int32(3))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeChatExportFormat_lift(_ buf: RustBuffer) throws -> ChatExportFormat {
+    return try FfiConverterTypeChatExportFormat.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeChatExportFormat_lower(_ value: ChatExportFormat) -> RustBuffer {
+    return FfiConverterTypeChatExportFormat.lower(value)
+}
+
+
+extension ChatExportFormat: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum ChatMessageKind {
+    
+    case text
+    case participantJoined
+    case participantLeft
+    case recordingStarted
+    case handRaised
+}
+
+
+#if compiler(>=6)
+extension ChatMessageKind: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeChatMessageKind: FfiConverterRustBuffer {
+    typealias SwiftType = ChatMessageKind
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ChatMessageKind {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .text
+        
+        case 2: return .participantJoined
+        
+        case 3: return .participantLeft
+        
+        case 4: return .recordingStarted
+        
+        case 5: return .handRaised
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: ChatMessageKind, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .text:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .participantJoined:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .participantLeft:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .recordingStarted:
+            writeInt(&buf, Int32(4))
+        
+        
+        case .handRaised:
+            writeInt(&buf, Int32(5))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeChatMessageKind_lift(_ buf: RustBuffer) throws -> ChatMessageKind {
+    return try FfiConverterTypeChatMessageKind.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeChatMessageKind_lower(_ value: ChatMessageKind) -> RustBuffer {
+    return FfiConverterTypeChatMessageKind.lower(value)
+}
+
+
+extension ChatMessageKind: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum CheckResult {
+    
+    case passed
+    case failed(reason: String
+    )
+    case skipped
+}
+
+
+#if compiler(>=6)
+extension CheckResult: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeCheckResult: FfiConverterRustBuffer {
+    typealias SwiftType = CheckResult
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> CheckResult {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .passed
+        
+        case 2: return .failed(reason: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 3: return .skipped
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: CheckResult, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .passed:
+            writeInt(&buf, Int32(1))
+        
+        
+        case let .failed(reason):
+            writeInt(&buf, Int32(2))
+            FfiConverterString.write(reason, into: &buf)
+            
+        
+        case .skipped:
+            writeInt(&buf, Int32(3))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCheckResult_lift(_ buf: RustBuffer) throws -> CheckResult {
+    return try FfiConverterTypeCheckResult.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeCheckResult_lower(_ value: CheckResult) -> RustBuffer {
+    return FfiConverterTypeCheckResult.lower(value)
+}
+
+
+extension CheckResult: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum ConnectStep {
+    
+    case tokenRequested
+    case tokenReceived
+    case signalConnected
+    case mediaConnected
+}
+
+
+#if compiler(>=6)
+extension ConnectStep: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeConnectStep: FfiConverterRustBuffer {
+    typealias SwiftType = ConnectStep
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ConnectStep {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .tokenRequested
+        
+        case 2: return .tokenReceived
+        
+        case 3: return .signalConnected
+        
+        case 4: return .mediaConnected
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: ConnectStep, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .tokenRequested:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .tokenReceived:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .signalConnected:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .mediaConnected:
+            writeInt(&buf, Int32(4))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeConnectStep_lift(_ buf: RustBuffer) throws -> ConnectStep {
+    return try FfiConverterTypeConnectStep.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeConnectStep_lower(_ value: ConnectStep) -> RustBuffer {
+    return FfiConverterTypeConnectStep.lower(value)
+}
+
+
+extension ConnectStep: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum ConnectionQuality {
+    
+    case excellent
+    case good
+    case poor
+    case lost
+}
+
+
+#if compiler(>=6)
+extension ConnectionQuality: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeConnectionQuality: FfiConverterRustBuffer {
+    typealias SwiftType = ConnectionQuality
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ConnectionQuality {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .excellent
+        
+        case 2: return .good
+        
+        case 3: return .poor
+        
+        case 4: return .lost
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: ConnectionQuality, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .excellent:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .good:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .poor:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .lost:
+            writeInt(&buf, Int32(4))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeConnectionQuality_lift(_ buf: RustBuffer) throws -> ConnectionQuality {
+    return try FfiConverterTypeConnectionQuality.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeConnectionQuality_lower(_ value: ConnectionQuality) -> RustBuffer {
+    return FfiConverterTypeConnectionQuality.lower(value)
+}
+
+
+extension ConnectionQuality: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum ConnectionState {
+    
+    case disconnected
+    case connecting
+    case connected
+    case reconnecting(attempt: UInt32
+    )
+}
+
+
+#if compiler(>=6)
+extension ConnectionState: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeConnectionState: FfiConverterRustBuffer {
+    typealias SwiftType = ConnectionState
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ConnectionState {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .disconnected
+        
+        case 2: return .connecting
+        
+        case 3: return .connected
+        
+        case 4: return .reconnecting(attempt: try FfiConverterUInt32.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: ConnectionState, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .disconnected:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .connecting:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .connected:
+            writeInt(&buf, Int32(3))
+        
+        
+        case let .reconnecting(attempt):
+            writeInt(&buf, Int32(4))
+            FfiConverterUInt32.write(attempt, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeConnectionState_lift(_ buf: RustBuffer) throws -> ConnectionState {
+    return try FfiConverterTypeConnectionState.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeConnectionState_lower(_ value: ConnectionState) -> RustBuffer {
+    return FfiConverterTypeConnectionState.lower(value)
+}
+
+
+extension ConnectionState: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum DeepLink {
+    
+    case joinRoom(meetUrl: String, instance: String, slug: String, displayName: String?
+    )
+    case openSettings
+}
+
+
+#if compiler(>=6)
+extension DeepLink: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeDeepLink: FfiConverterRustBuffer {
+    typealias SwiftType = DeepLink
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DeepLink {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .joinRoom(meetUrl: try FfiConverterString.read(from: &buf), instance: try FfiConverterString.read(from: &buf), slug: try FfiConverterString.read(from: &buf), displayName: try FfiConverterOptionString.read(from: &buf)
+        )
+        
+        case 2: return .openSettings
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: DeepLink, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case let .joinRoom(meetUrl,instance,slug,displayName):
+            writeInt(&buf, Int32(1))
+            FfiConverterString.write(meetUrl, into: &buf)
+            FfiConverterString.write(instance, into: &buf)
+            FfiConverterString.write(slug, into: &buf)
+            FfiConverterOptionString.write(displayName, into: &buf)
+            
+        
+        case .openSettings:
+            writeInt(&buf, Int32(2))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDeepLink_lift(_ buf: RustBuffer) throws -> DeepLink {
+    return try FfiConverterTypeDeepLink.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDeepLink_lower(_ value: DeepLink) -> RustBuffer {
+    return FfiConverterTypeDeepLink.lower(value)
+}
+
+
+extension DeepLink: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum DevicePermission {
+    
+    case camera
+    case microphone
+    case screenCapture
+    case notifications
+}
+
+
+#if compiler(>=6)
+extension DevicePermission: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeDevicePermission: FfiConverterRustBuffer {
+    typealias SwiftType = DevicePermission
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> DevicePermission {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .camera
+        
+        case 2: return .microphone
+        
+        case 3: return .screenCapture
+        
+        case 4: return .notifications
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: DevicePermission, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .camera:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .microphone:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .screenCapture:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .notifications:
+            writeInt(&buf, Int32(4))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDevicePermission_lift(_ buf: RustBuffer) throws -> DevicePermission {
+    return try FfiConverterTypeDevicePermission.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeDevicePermission_lower(_ value: DevicePermission) -> RustBuffer {
+    return FfiConverterTypeDevicePermission.lower(value)
+}
+
+
+extension DevicePermission: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum JoinLeaveMode {
+    
+    case individual
+    case batched
+    case countOnly
+}
+
+
+#if compiler(>=6)
+extension JoinLeaveMode: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeJoinLeaveMode: FfiConverterRustBuffer {
+    typealias SwiftType = JoinLeaveMode
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> JoinLeaveMode {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .individual
+        
+        case 2: return .batched
+        
+        case 3: return .countOnly
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: JoinLeaveMode, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .individual:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .batched:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .countOnly:
+            writeInt(&buf, Int32(3))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeJoinLeaveMode_lift(_ buf: RustBuffer) throws -> JoinLeaveMode {
+    return try FfiConverterTypeJoinLeaveMode.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeJoinLeaveMode_lower(_ value: JoinLeaveMode) -> RustBuffer {
+    return FfiConverterTypeJoinLeaveMode.lower(value)
+}
+
+
+extension JoinLeaveMode: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum LayoutMode {
+    
+    case grid
+    case speaker
+    case sidebar
+}
+
+
+#if compiler(>=6)
+extension LayoutMode: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeLayoutMode: FfiConverterRustBuffer {
+    typealias SwiftType = LayoutMode
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> LayoutMode {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .grid
+        
+        case 2: return .speaker
+        
+        case 3: return .sidebar
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: LayoutMode, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .grid:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .speaker:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .sidebar:
+            writeInt(&buf, Int32(3))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeLayoutMode_lift(_ buf: RustBuffer) throws -> LayoutMode {
+    return try FfiConverterTypeLayoutMode.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeLayoutMode_lower(_ value: LayoutMode) -> RustBuffer {
+    return FfiConverterTypeLayoutMode.lower(value)
+}
+
+
+extension LayoutMode: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum MediaButton {
+    
+    case playPause
+    case hangup
+}
+
+
+#if compiler(>=6)
+extension MediaButton: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeMediaButton: FfiConverterRustBuffer {
+    typealias SwiftType = MediaButton
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> MediaButton {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .playPause
+        
+        case 2: return .hangup
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: MediaButton, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .playPause:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .hangup:
+            writeInt(&buf, Int32(2))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeMediaButton_lift(_ buf: RustBuffer) throws -> MediaButton {
+    return try FfiConverterTypeMediaButton.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeMediaButton_lower(_ value: MediaButton) -> RustBuffer {
+    return FfiConverterTypeMediaButton.lower(value)
+}
+
+
+extension MediaButton: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum OverlayCorner {
+    
+    case topLeft
+    case topRight
+    case bottomLeft
+    case bottomRight
+}
+
+
+#if compiler(>=6)
+extension OverlayCorner: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeOverlayCorner: FfiConverterRustBuffer {
+    typealias SwiftType = OverlayCorner
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> OverlayCorner {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .topLeft
+        
+        case 2: return .topRight
+        
+        case 3: return .bottomLeft
+        
+        case 4: return .bottomRight
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: OverlayCorner, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .topLeft:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .topRight:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .bottomLeft:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .bottomRight:
+            writeInt(&buf, Int32(4))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeOverlayCorner_lift(_ buf: RustBuffer) throws -> OverlayCorner {
+    return try FfiConverterTypeOverlayCorner.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeOverlayCorner_lower(_ value: OverlayCorner) -> RustBuffer {
+    return FfiConverterTypeOverlayCorner.lower(value)
+}
+
+
+extension OverlayCorner: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum PermissionState {
+    
+    case unknown
+    case granted
+    case denied
+}
+
+
+#if compiler(>=6)
+extension PermissionState: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypePermissionState: FfiConverterRustBuffer {
+    typealias SwiftType = PermissionState
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> PermissionState {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .unknown
+        
+        case 2: return .granted
+        
+        case 3: return .denied
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: PermissionState, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .unknown:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .granted:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .denied:
+            writeInt(&buf, Int32(3))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePermissionState_lift(_ buf: RustBuffer) throws -> PermissionState {
+    return try FfiConverterTypePermissionState.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePermissionState_lower(_ value: PermissionState) -> RustBuffer {
+    return FfiConverterTypePermissionState.lower(value)
+}
+
+
+extension PermissionState: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum PowerMode {
+    
+    case normal
+    case saver
+}
+
+
+#if compiler(>=6)
+extension PowerMode: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypePowerMode: FfiConverterRustBuffer {
+    typealias SwiftType = PowerMode
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> PowerMode {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .normal
+        
+        case 2: return .saver
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: PowerMode, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .normal:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .saver:
+            writeInt(&buf, Int32(2))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePowerMode_lift(_ buf: RustBuffer) throws -> PowerMode {
+    return try FfiConverterTypePowerMode.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePowerMode_lower(_ value: PowerMode) -> RustBuffer {
+    return FfiConverterTypePowerMode.lower(value)
+}
+
+
+extension PowerMode: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum Presence {
+    
+    case available
+    case away
+    case beRightBack
+    case doNotDisturb
+}
+
+
+#if compiler(>=6)
+extension Presence: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypePresence: FfiConverterRustBuffer {
+    typealias SwiftType = Presence
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Presence {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .available
+        
+        case 2: return .away
+        
+        case 3: return .beRightBack
+        
+        case 4: return .doNotDisturb
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: Presence, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .available:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .away:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .beRightBack:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .doNotDisturb:
+            writeInt(&buf, Int32(4))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePresence_lift(_ buf: RustBuffer) throws -> Presence {
+    return try FfiConverterTypePresence.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypePresence_lower(_ value: Presence) -> RustBuffer {
+    return FfiConverterTypePresence.lower(value)
+}
+
+
+extension Presence: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum QualityReductionReason {
+    
+    case thermal(state: ThermalState
+    )
+}
+
+
+#if compiler(>=6)
+extension QualityReductionReason: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeQualityReductionReason: FfiConverterRustBuffer {
+    typealias SwiftType = QualityReductionReason
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> QualityReductionReason {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .thermal(state: try FfiConverterTypeThermalState.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: QualityReductionReason, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case let .thermal(state):
+            writeInt(&buf, Int32(1))
+            FfiConverterTypeThermalState.write(state, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeQualityReductionReason_lift(_ buf: RustBuffer) throws -> QualityReductionReason {
+    return try FfiConverterTypeQualityReductionReason.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeQualityReductionReason_lower(_ value: QualityReductionReason) -> RustBuffer {
+    return FfiConverterTypeQualityReductionReason.lower(value)
+}
+
+
+extension QualityReductionReason: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum RecordingMode {
+    
+    case screenRecording
+    case transcript
+}
+
+
+#if compiler(>=6)
+extension RecordingMode: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRecordingMode: FfiConverterRustBuffer {
+    typealias SwiftType = RecordingMode
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RecordingMode {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .screenRecording
+        
+        case 2: return .transcript
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: RecordingMode, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .screenRecording:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .transcript:
+            writeInt(&buf, Int32(2))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRecordingMode_lift(_ buf: RustBuffer) throws -> RecordingMode {
+    return try FfiConverterTypeRecordingMode.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRecordingMode_lower(_ value: RecordingMode) -> RustBuffer {
+    return FfiConverterTypeRecordingMode.lower(value)
+}
+
+
+extension RecordingMode: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum RecordingStatus {
+    
+    case stopped
+    case starting
+    case recording
+    case stopping
+}
+
+
+#if compiler(>=6)
+extension RecordingStatus: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRecordingStatus: FfiConverterRustBuffer {
+    typealias SwiftType = RecordingStatus
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RecordingStatus {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .stopped
+        
+        case 2: return .starting
+        
+        case 3: return .recording
+        
+        case 4: return .stopping
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: RecordingStatus, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .stopped:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .starting:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .recording:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .stopping:
+            writeInt(&buf, Int32(4))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRecordingStatus_lift(_ buf: RustBuffer) throws -> RecordingStatus {
+    return try FfiConverterTypeRecordingStatus.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRecordingStatus_lower(_ value: RecordingStatus) -> RustBuffer {
+    return FfiConverterTypeRecordingStatus.lower(value)
+}
+
+
+extension RecordingStatus: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum RestreamState {
+    
+    case stopped
+    case connecting
+    case live
+    case failed
+}
+
+
+#if compiler(>=6)
+extension RestreamState: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRestreamState: FfiConverterRustBuffer {
+    typealias SwiftType = RestreamState
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RestreamState {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .stopped
+        
+        case 2: return .connecting
+        
+        case 3: return .live
+        
+        case 4: return .failed
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: RestreamState, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .stopped:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .connecting:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .live:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .failed:
+            writeInt(&buf, Int32(4))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRestreamState_lift(_ buf: RustBuffer) throws -> RestreamState {
+    return try FfiConverterTypeRestreamState.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRestreamState_lower(_ value: RestreamState) -> RustBuffer {
+    return FfiConverterTypeRestreamState.lower(value)
+}
+
+
+extension RestreamState: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum RoomValidationResult {
+    
+    case valid(livekitUrl: String, token: String
+    )
+    case notFound
+    case invalidFormat(message: String
+    )
+    case networkError(message: String
+    )
+}
+
+
+#if compiler(>=6)
+extension RoomValidationResult: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeRoomValidationResult: FfiConverterRustBuffer {
+    typealias SwiftType = RoomValidationResult
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RoomValidationResult {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .valid(livekitUrl: try FfiConverterString.read(from: &buf), token: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 2: return .notFound
+        
+        case 3: return .invalidFormat(message: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 4: return .networkError(message: try FfiConverterString.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: RoomValidationResult, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case let .valid(livekitUrl,token):
+            writeInt(&buf, Int32(1))
+            FfiConverterString.write(livekitUrl, into: &buf)
+            FfiConverterString.write(token, into: &buf)
+            
+        
+        case .notFound:
+            writeInt(&buf, Int32(2))
+        
+        
+        case let .invalidFormat(message):
+            writeInt(&buf, Int32(3))
+            FfiConverterString.write(message, into: &buf)
+            
+        
+        case let .networkError(message):
+            writeInt(&buf, Int32(4))
+            FfiConverterString.write(message, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRoomValidationResult_lift(_ buf: RustBuffer) throws -> RoomValidationResult {
+    return try FfiConverterTypeRoomValidationResult.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeRoomValidationResult_lower(_ value: RoomValidationResult) -> RustBuffer {
+    return FfiConverterTypeRoomValidationResult.lower(value)
+}
+
+
+extension RoomValidationResult: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum SpanKind {
+    
+    case text
+    case bold
+    case link
+}
+
+
+#if compiler(>=6)
+extension SpanKind: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSpanKind: FfiConverterRustBuffer {
+    typealias SwiftType = SpanKind
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SpanKind {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .text
+        
+        case 2: return .bold
+        
+        case 3: return .link
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: SpanKind, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .text:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .bold:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .link:
+            writeInt(&buf, Int32(3))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSpanKind_lift(_ buf: RustBuffer) throws -> SpanKind {
+    return try FfiConverterTypeSpanKind.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSpanKind_lower(_ value: SpanKind) -> RustBuffer {
+    return FfiConverterTypeSpanKind.lower(value)
+}
+
+
+extension SpanKind: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum SystemCallState {
+    
+    case connecting
+    case connected
+    case endedLocally
+    case endedRemotely
+    case failed
+}
+
+
+#if compiler(>=6)
+extension SystemCallState: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeSystemCallState: FfiConverterRustBuffer {
+    typealias SwiftType = SystemCallState
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SystemCallState {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .connecting
+        
+        case 2: return .connected
+        
+        case 3: return .endedLocally
+        
+        case 4: return .endedRemotely
+        
+        case 5: return .failed
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: SystemCallState, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .connecting:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .connected:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .endedLocally:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .endedRemotely:
+            writeInt(&buf, Int32(4))
+        
+        
+        case .failed:
+            writeInt(&buf, Int32(5))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSystemCallState_lift(_ buf: RustBuffer) throws -> SystemCallState {
+    return try FfiConverterTypeSystemCallState.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeSystemCallState_lower(_ value: SystemCallState) -> RustBuffer {
+    return FfiConverterTypeSystemCallState.lower(value)
+}
+
+
+extension SystemCallState: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum ThermalState {
+    
+    case nominal
+    case fair
+    case serious
+    case critical
+}
+
+
+#if compiler(>=6)
+extension ThermalState: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeThermalState: FfiConverterRustBuffer {
+    typealias SwiftType = ThermalState
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ThermalState {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .nominal
+        
+        case 2: return .fair
+        
+        case 3: return .serious
+        
+        case 4: return .critical
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: ThermalState, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .nominal:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .fair:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .serious:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .critical:
+            writeInt(&buf, Int32(4))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeThermalState_lift(_ buf: RustBuffer) throws -> ThermalState {
+    return try FfiConverterTypeThermalState.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeThermalState_lower(_ value: ThermalState) -> RustBuffer {
+    return FfiConverterTypeThermalState.lower(value)
+}
+
+
+extension ThermalState: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum TrackKind {
+    
+    case audio
+    case video
+}
+
+
+#if compiler(>=6)
+extension TrackKind: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTrackKind: FfiConverterRustBuffer {
+    typealias SwiftType = TrackKind
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TrackKind {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .audio
+        
+        case 2: return .video
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: TrackKind, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .audio:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .video:
+            writeInt(&buf, Int32(2))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTrackKind_lift(_ buf: RustBuffer) throws -> TrackKind {
+    return try FfiConverterTypeTrackKind.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTrackKind_lower(_ value: TrackKind) -> RustBuffer {
+    return FfiConverterTypeTrackKind.lower(value)
+}
+
+
+extension TrackKind: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum TrackSource {
+    
+    case microphone
+    case camera
+    case screenShare
+    case screenShareAudio
+    case unknown
+}
+
+
+#if compiler(>=6)
+extension TrackSource: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeTrackSource: FfiConverterRustBuffer {
+    typealias SwiftType = TrackSource
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TrackSource {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .microphone
+        
+        case 2: return .camera
+        
+        case 3: return .screenShare
+        
+        case 4: return .screenShareAudio
+        
+        case 5: return .unknown
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: TrackSource, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .microphone:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .camera:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .screenShare:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .screenShareAudio:
+            writeInt(&buf, Int32(4))
+        
+        
+        case .unknown:
+            writeInt(&buf, Int32(5))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTrackSource_lift(_ buf: RustBuffer) throws -> TrackSource {
+    return try FfiConverterTypeTrackSource.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeTrackSource_lower(_ value: TrackSource) -> RustBuffer {
+    return FfiConverterTypeTrackSource.lower(value)
+}
+
+
+extension TrackSource: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum VideoCodec {
+    
+    case vp8
+    case vp9
+    case h264
+    case av1
+}
+
+
+#if compiler(>=6)
+extension VideoCodec: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeVideoCodec: FfiConverterRustBuffer {
+    typealias SwiftType = VideoCodec
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> VideoCodec {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .vp8
+        
+        case 2: return .vp9
+        
+        case 3: return .h264
+        
+        case 4: return .av1
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: VideoCodec, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .vp8:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .vp9:
+            writeInt(&buf, Int32(2))
+        
+        
+        case .h264:
+            writeInt(&buf, Int32(3))
+        
+        
+        case .av1:
+            writeInt(&buf, Int32(4))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeVideoCodec_lift(_ buf: RustBuffer) throws -> VideoCodec {
+    return try FfiConverterTypeVideoCodec.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeVideoCodec_lower(_ value: VideoCodec) -> RustBuffer {
+    return FfiConverterTypeVideoCodec.lower(value)
+}
+
+
+extension VideoCodec: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum VideoContentHint {
+    
+    case motion
+    case detail
+}
+
+
+#if compiler(>=6)
+extension VideoContentHint: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeVideoContentHint: FfiConverterRustBuffer {
+    typealias SwiftType = VideoContentHint
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> VideoContentHint {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .motion
+        
+        case 2: return .detail
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: VideoContentHint, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .motion:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .detail:
+            writeInt(&buf, Int32(2))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeVideoContentHint_lift(_ buf: RustBuffer) throws -> VideoContentHint {
+    return try FfiConverterTypeVideoContentHint.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeVideoContentHint_lower(_ value: VideoContentHint) -> RustBuffer {
+    return FfiConverterTypeVideoContentHint.lower(value)
+}
+
+
+extension VideoContentHint: Equatable, Hashable {}
+
+
+
+
+
+
+
+public enum VisioError: Swift.Error {
+
+    
+    
+    case Connection(code: String, userMessageKey: String, developerDetail: String?
+    )
+    case Room(code: String, userMessageKey: String, developerDetail: String?
+    )
+    case Auth(code: String, userMessageKey: String, developerDetail: String?
+    )
+    case Http(code: String, userMessageKey: String, developerDetail: String?
+    )
+    case InvalidUrl(code: String, userMessageKey: String, developerDetail: String?
+    )
+    case Timeout(code: String, userMessageKey: String, developerDetail: String?
+    )
+    case Unreachable(code: String, userMessageKey: String, developerDetail: String?, diagnosis: ConnectivityDiagnosis
+    )
+    case PermissionDenied(code: String, userMessageKey: String, developerDetail: String?, permission: DevicePermission
+    )
+    case PublishNotAllowed(code: String, userMessageKey: String, developerDetail: String?, trackSource: TrackSource
+    )
+    case MessageTooLarge(code: String, userMessageKey: String, developerDetail: String?, size: UInt64, max: UInt64
+    )
+    case Generic(code: String, userMessageKey: String, developerDetail: String?
+    )
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeVisioError: FfiConverterRustBuffer {
+    typealias SwiftType = VisioError
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> VisioError {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+
+        
+
+        
+        case 1: return .Connection(
+            code: try FfiConverterString.read(from: &buf), 
+            userMessageKey: try FfiConverterString.read(from: &buf), 
+            developerDetail: try FfiConverterOptionString.read(from: &buf)
+            )
+        case 2: return .Room(
+            code: try FfiConverterString.read(from: &buf), 
+            userMessageKey: try FfiConverterString.read(from: &buf), 
+            developerDetail: try FfiConverterOptionString.read(from: &buf)
+            )
+        case 3: return .Auth(
+            code: try FfiConverterString.read(from: &buf), 
+            userMessageKey: try FfiConverterString.read(from: &buf), 
+            developerDetail: try FfiConverterOptionString.read(from: &buf)
+            )
+        case 4: return .Http(
+            code: try FfiConverterString.read(from: &buf), 
+            userMessageKey: try FfiConverterString.read(from: &buf), 
+            developerDetail: try FfiConverterOptionString.read(from: &buf)
+            )
+        case 5: return .InvalidUrl(
+            code: try FfiConverterString.read(from: &buf), 
+            userMessageKey: try FfiConverterString.read(from: &buf), 
+            developerDetail: try FfiConverterOptionString.read(from: &buf)
+            )
+        case 6: return .Timeout(
+            code: try FfiConverterString.read(from: &buf), 
+            userMessageKey: try FfiConverterString.read(from: &buf), 
+            developerDetail: try FfiConverterOptionString.read(from: &buf)
+            )
+        case 7: return .Unreachable(
+            code: try FfiConverterString.read(from: &buf), 
+            userMessageKey: try FfiConverterString.read(from: &buf), 
+            developerDetail: try FfiConverterOptionString.read(from: &buf), 
+            diagnosis: try FfiConverterTypeConnectivityDiagnosis.read(from: &buf)
+            )
+        case 8: return .PermissionDenied(
+            code: try FfiConverterString.read(from: &buf), 
+            userMessageKey: try FfiConverterString.read(from: &buf), 
+            developerDetail: try FfiConverterOptionString.read(from: &buf), 
+            permission: try FfiConverterTypeDevicePermission.read(from: &buf)
+            )
+        case 9: return .PublishNotAllowed(
+            code: try FfiConverterString.read(from: &buf), 
+            userMessageKey: try FfiConverterString.read(from: &buf), 
+            developerDetail: try FfiConverterOptionString.read(from: &buf), 
+            trackSource: try FfiConverterTypeTrackSource.read(from: &buf)
+            )
+        case 10: return .MessageTooLarge(
+            code: try FfiConverterString.read(from: &buf), 
+            userMessageKey: try FfiConverterString.read(from: &buf), 
+            developerDetail: try FfiConverterOptionString.read(from: &buf), 
+            size: try FfiConverterUInt64.read(from: &buf), 
+            max: try FfiConverterUInt64.read(from: &buf)
+            )
+        case 11: return .Generic(
+            code: try FfiConverterString.read(from: &buf), 
+            userMessageKey: try FfiConverterString.read(from: &buf), 
+            developerDetail: try FfiConverterOptionString.read(from: &buf)
+            )
+
+         default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: VisioError, into buf: inout [UInt8]) {
+        switch value {
+
+        
+
+        
+        
+        case let .Connection(code,userMessageKey,developerDetail):
+            writeInt(&buf, Int32(1))
+            FfiConverterString.write(code, into: &buf)
+            FfiConverterString.write(userMessageKey, into: &buf)
+            FfiConverterOptionString.write(developerDetail, into: &buf)
+            
+        
+        case let .Room(code,userMessageKey,developerDetail):
+            writeInt(&buf, Int32(2))
+            FfiConverterString.write(code, into: &buf)
+            FfiConverterString.write(userMessageKey, into: &buf)
+            FfiConverterOptionString.write(developerDetail, into: &buf)
+            
+        
+        case let .Auth(code,userMessageKey,developerDetail):
+            writeInt(&buf, Int32(3))
+            FfiConverterString.write(code, into: &buf)
+            FfiConverterString.write(userMessageKey, into: &buf)
+            FfiConverterOptionString.write(developerDetail, into: &buf)
+            
+        
+        case let .Http(code,userMessageKey,developerDetail):
+            writeInt(&buf, Int32(4))
+            FfiConverterString.write(code, into: &buf)
+            FfiConverterString.write(userMessageKey, into: &buf)
+            FfiConverterOptionString.write(developerDetail, into: &buf)
+            
+        
+        case let .InvalidUrl(code,userMessageKey,developerDetail):
+            writeInt(&buf, Int32(5))
+            FfiConverterString.write(code, into: &buf)
+            FfiConverterString.write(userMessageKey, into: &buf)
+            FfiConverterOptionString.write(developerDetail, into: &buf)
+            
+        
+        case let .Timeout(code,userMessageKey,developerDetail):
+            writeInt(&buf, Int32(6))
+            FfiConverterString.write(code, into: &buf)
+            FfiConverterString.write(userMessageKey, into: &buf)
+            FfiConverterOptionString.write(developerDetail, into: &buf)
+            
+        
+        case let .Unreachable(code,userMessageKey,developerDetail,diagnosis):
+            writeInt(&buf, Int32(7))
+            FfiConverterString.write(code, into: &buf)
+            FfiConverterString.write(userMessageKey, into: &buf)
+            FfiConverterOptionString.write(developerDetail, into: &buf)
+            FfiConverterTypeConnectivityDiagnosis.write(diagnosis, into: &buf)
+            
+        
+        case let .PermissionDenied(code,userMessageKey,developerDetail,permission):
+            writeInt(&buf, Int32(8))
+            FfiConverterString.write(code, into: &buf)
+            FfiConverterString.write(userMessageKey, into: &buf)
+            FfiConverterOptionString.write(developerDetail, into: &buf)
+            FfiConverterTypeDevicePermission.write(permission, into: &buf)
+            
+        
+        case let .PublishNotAllowed(code,userMessageKey,developerDetail,trackSource):
+            writeInt(&buf, Int32(9))
+            FfiConverterString.write(code, into: &buf)
+            FfiConverterString.write(userMessageKey, into: &buf)
+            FfiConverterOptionString.write(developerDetail, into: &buf)
+            FfiConverterTypeTrackSource.write(trackSource, into: &buf)
+            
+        
+        case let .MessageTooLarge(code,userMessageKey,developerDetail,size,max):
+            writeInt(&buf, Int32(10))
+            FfiConverterString.write(code, into: &buf)
+            FfiConverterString.write(userMessageKey, into: &buf)
+            FfiConverterOptionString.write(developerDetail, into: &buf)
+            FfiConverterUInt64.write(size, into: &buf)
+            FfiConverterUInt64.write(max, into: &buf)
+            
+        
+        case let .Generic(code,userMessageKey,developerDetail):
+            writeInt(&buf, Int32(11))
+            FfiConverterString.write(code, into: &buf)
+            FfiConverterString.write(userMessageKey, into: &buf)
+            FfiConverterOptionString.write(developerDetail, into: &buf)
+            
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeVisioError_lift(_ buf: RustBuffer) throws -> VisioError {
+    return try FfiConverterTypeVisioError.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeVisioError_lower(_ value: VisioError) -> RustBuffer {
+    return FfiConverterTypeVisioError.lower(value)
+}
+
+
+extension VisioError: Equatable, Hashable {}
+
+
+
+
+extension VisioError: Foundation.LocalizedError {
+    public var errorDescription: String? {
+        String(reflecting: self)
+    }
+}
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum VideoPrivacyStyle {
+    
+    case blur
+    case card
+}
+
+
+#if compiler(>=6)
+extension VideoPrivacyStyle: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeVideoPrivacyStyle: FfiConverterRustBuffer {
+    typealias SwiftType = VideoPrivacyStyle
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> VideoPrivacyStyle {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .blur
+        
+        case 2: return .card
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: VideoPrivacyStyle, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case .blur:
+            writeInt(&buf, Int32(1))
+        
+        
+        case .card:
+            writeInt(&buf, Int32(2))
+        
+        }
+    }
+}
+
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeVideoPrivacyStyle_lift(_ buf: RustBuffer) throws -> VideoPrivacyStyle {
+    return try FfiConverterTypeVideoPrivacyStyle.lift(buf)
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public func FfiConverterTypeVideoPrivacyStyle_lower(_ value: VideoPrivacyStyle) -> RustBuffer {
+    return FfiConverterTypeVideoPrivacyStyle.lower(value)
+}
+
+
+extension VideoPrivacyStyle: Equatable, Hashable {}
+
+
+
+
+
+
+// Note that we don't yet support `indirect` for enums.
+// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+
+public enum VisioEvent {
+    
+    case connectionStateChanged(state: ConnectionState
+    )
+    case participantJoined(info: ParticipantInfo
+    )
+    case participantLeft(participantSid: String
+    )
+    case trackSubscribed(info: TrackInfo
+    )
+    case trackUnsubscribed(trackSid: String
+    )
+    case trackMuted(participantSid: String, source: TrackSource
+    )
+    case trackUnmuted(participantSid: String, source: TrackSource
+    )
+    case activeSpeakersChanged(participantSids: [String]
+    )
+    case layoutOrderChanged(participantSids: [String]
+    )
+    case connectionQualityChanged(participantSid: String, quality: ConnectionQuality
+    )
+    case chatMessageReceived(message: ChatMessage
+    )
+    case handRaisedChanged(participantSid: String, raised: Bool, position: UInt32
+    )
+    case unreadCountChanged(count: UInt32
+    )
+    case handAutoLowered
+    case reactionReceived(participantSid: String, participantName: String, emoji: String
+    )
+    case connectionLost
+    case roomSwitched(meetUrl: String
+    )
+    case connectProgress(step: ConnectStep
+    )
+    case participantBatchUpdate(joined: [ParticipantInfo], left: [String], joinedCount: UInt32, leftCount: UInt32
+    )
+    case trackStalled(trackSid: String
+    )
+    case trackResumed(trackSid: String
+    )
+    case permissionsChanged(permissions: LocalPermissions
+    )
+    case waitingForHost
+    case entryGranted
+    case entryDenied
+    case roomLockedChanged(locked: Bool
+    )
+    case featureFlagsChanged(flags: FeatureFlags
+    )
+    case recordingStatusChanged(status: RecordingStatus
+    )
+    case localRecordingProgress(durationMs: UInt64, sizeBytes: UInt64
+    )
+    case restreamStatusChanged(status: RestreamStatus
+    )
+    case sourceReady(source: TrackSource, handle: UInt64
+    )
+    case captureConstraintsChanged(constraints: CaptureConstraints
+    )
+    case dataMessageReceived(topic: String, senderSid: String, payload: Data
+    )
+    case pollStarted(poll: Poll
+    )
+    case pollUpdated(poll: Poll
+    )
+    case pollEnded(poll: Poll
+    )
+    case whiteboardAvailable
+    case powerModeChanged(mode: PowerMode
+    )
+    case qualityReduced(reason: QualityReductionReason, constraints: CaptureConstraints
+    )
+    case qualityRestored
+    case devicePermissionChanged(permission: DevicePermission, state: PermissionState
+    )
+    case settingsChanged(keys: [String]
+    )
+    case systemCallChanged(call: SystemCall
+    )
+    case mediaButtonHandled(button: MediaButton
+    )
+    case meetingEndingSoon(minutesLeft: UInt32
+    )
+    case meetingEnded
+    case aloneInRoom(minutes: UInt32, autoLeave: Bool
+    )
+    case inactivityDetected(minutes: UInt32
+    )
+}
+
+
+#if compiler(>=6)
+extension VisioEvent: Sendable {}
+#endif
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+public struct FfiConverterTypeVisioEvent: FfiConverterRustBuffer {
+    typealias SwiftType = VisioEvent
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> VisioEvent {
+        let variant: Int32 = try readInt(&buf)
+        switch variant {
+        
+        case 1: return .connectionStateChanged(state: try FfiConverterTypeConnectionState.read(from: &buf)
+        )
+        
+        case 2: return .participantJoined(info: try FfiConverterTypeParticipantInfo.read(from: &buf)
+        )
+        
+        case 3: return .participantLeft(participantSid: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 4: return .trackSubscribed(info: try FfiConverterTypeTrackInfo.read(from: &buf)
+        )
+        
+        case 5: return .trackUnsubscribed(trackSid: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 6: return .trackMuted(participantSid: try FfiConverterString.read(from: &buf), source: try FfiConverterTypeTrackSource.read(from: &buf)
+        )
+        
+        case 7: return .trackUnmuted(participantSid: try FfiConverterString.read(from: &buf), source: try FfiConverterTypeTrackSource.read(from: &buf)
+        )
+        
+        case 8: return .activeSpeakersChanged(participantSids: try FfiConverterSequenceString.read(from: &buf)
+        )
+        
+        case 9: return .layoutOrderChanged(participantSids: try FfiConverterSequenceString.read(from: &buf)
+        )
+        
+        case 10: return .connectionQualityChanged(participantSid: try FfiConverterString.read(from: &buf), quality: try FfiConverterTypeConnectionQuality.read(from: &buf)
+        )
+        
+        case 11: return .chatMessageReceived(message: try FfiConverterTypeChatMessage.read(from: &buf)
+        )
+        
+        case 12: return .handRaisedChanged(participantSid: try FfiConverterString.read(from: &buf), raised: try FfiConverterBool.read(from: &buf), position: try FfiConverterUInt32.read(from: &buf)
+        )
+        
+        case 13: return .unreadCountChanged(count: try FfiConverterUInt32.read(from: &buf)
+        )
+        
+        case 14: return .handAutoLowered
+        
+        case 15: return .reactionReceived(participantSid: try FfiConverterString.read(from: &buf), participantName: try FfiConverterString.read(from: &buf), emoji: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 16: return .connectionLost
+        
+        case 17: return .roomSwitched(meetUrl: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 18: return .connectProgress(step: try FfiConverterTypeConnectStep.read(from: &buf)
+        )
+        
+        case 19: return .participantBatchUpdate(joined: try FfiConverterSequenceTypeParticipantInfo.read(from: &buf), left: try FfiConverterSequenceString.read(from: &buf), joinedCount: try FfiConverterUInt32.read(from: &buf), leftCount: try FfiConverterUInt32.read(from: &buf)
+        )
+        
+        case 20: return .trackStalled(trackSid: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 21: return .trackResumed(trackSid: try FfiConverterString.read(from: &buf)
+        )
+        
+        case 22: return .permissionsChanged(permissions: try FfiConverterTypeLocalPermissions.read(from: &buf)
+        )
+        
+        case 23: return .waitingForHost
+        
+        case 24: return .entryGranted
+        
+        case 25: return .entryDenied
+        
+        case 26: return .roomLockedChanged(locked: try FfiConverterBool.read(from: &buf)
+        )
+        
+        case 27: return .featureFlagsChanged(flags: try FfiConverterTypeFeatureFlags.read(from: &buf)
+        )
+        
+        case 28: return .recordingStatusChanged(status: try FfiConverterTypeRecordingStatus.read(from: &buf)
+        )
+        
+        case 29: return .localRecordingProgress(durationMs: try FfiConverterUInt64.read(from: &buf), sizeBytes: try FfiConverterUInt64.read(from: &buf)
+        )
+        
+        case 30: return .restreamStatusChanged(status: try FfiConverterTypeRestreamStatus.read(from: &buf)
+        )
+        
+        case 31: return .sourceReady(source: try FfiConverterTypeTrackSource.read(from: &buf), handle: try FfiConverterUInt64.read(from: &buf)
+        )
+        
+        case 32: return .captureConstraintsChanged(constraints: try FfiConverterTypeCaptureConstraints.read(from: &buf)
+        )
+        
+        case 33: return .dataMessageReceived(topic: try FfiConverterString.read(from: &buf), senderSid: try FfiConverterString.read(from: &buf), payload: try FfiConverterData.read(from: &buf)
+        )
+        
+        case 34: return .pollStarted(poll: try FfiConverterTypePoll.read(from: &buf)
+        )
+        
+        case 35: return .pollUpdated(poll: try FfiConverterTypePoll.read(from: &buf)
+        )
+        
+        case 36: return .pollEnded(poll: try FfiConverterTypePoll.read(from: &buf)
+        )
+        
+        case 37: return .whiteboardAvailable
+        
+        case 38: return .powerModeChanged(mode: try FfiConverterTypePowerMode.read(from: &buf)
+        )
+        
+        case 39: return .qualityReduced(reason: try FfiConverterTypeQualityReductionReason.read(from: &buf), constraints: try FfiConverterTypeCaptureConstraints.read(from: &buf)
+        )
+        
+        case 40: return .qualityRestored
+        
+        case 41: return .devicePermissionChanged(permission: try FfiConverterTypeDevicePermission.read(from: &buf), state: try FfiConverterTypePermissionState.read(from: &buf)
+        )
+        
+        case 42: return .settingsChanged(keys: try FfiConverterSequenceString.read(from: &buf)
+        )
+        
+        case 43: return .systemCallChanged(call: try FfiConverterTypeSystemCall.read(from: &buf)
+        )
+        
+        case 44: return .mediaButtonHandled(button: try FfiConverterTypeMediaButton.read(from: &buf)
+        )
+        
+        case 45: return .meetingEndingSoon(minutesLeft: try FfiConverterUInt32.read(from: &buf)
+        )
+        
+        case 46: return .meetingEnded
+        
+        case 47: return .aloneInRoom(minutes: try FfiConverterUInt32.read(from: &buf), autoLeave: try FfiConverterBool.read(from: &buf)
+        )
+        
+        case 48: return .inactivityDetected(minutes: try FfiConverterUInt32.read(from: &buf)
+        )
+        
+        default: throw UniffiInternalError.unexpectedEnumCase
+        }
+    }
+
+    public static func write(_ value: VisioEvent, into buf: inout [UInt8]) {
+        switch value {
+        
+        
+        case let .connectionStateChanged(state):
+            writeInt(&buf, Int32(1))
+            FfiConverterTypeConnectionState.write(state, into: &buf)
+            
+        
+        case let .participantJoined(info):
+            writeInt(&buf, Int32(2))
+            FfiConverterTypeParticipantInfo.write(info, into: &buf)
+            
+        
+        case let .participantLeft(participantSid):
+            writeInt(&buf, Int32(3))
+            FfiConverterString.write(participantSid, into: &buf)
+            
+        
+        case let .trackSubscribed(info):
+            writeInt(&buf, Int32(4))
+            FfiConverterTypeTrackInfo.write(info, into: &buf)
+            
+        
+        case let .trackUnsubscribed(trackSid):
+            writeInt(&buf, Int32(5))
+            FfiConverterString.write(trackSid, into: &buf)
+            
+        
+        case let .trackMuted(participantSid,source):
+            writeInt(&buf, Int32(6))
+            FfiConverterString.write(participantSid, into: &buf)
+            FfiConverterTypeTrackSource.write(source, into: &buf)
+            
+        
+        case let .trackUnmuted(participantSid,source):
+            writeInt(&buf, Int32(7))
+            FfiConverterString.write(participantSid, into: &buf)
+            FfiConverterTypeTrackSource.write(source, into: &buf)
+            
+        
+        case let .activeSpeakersChanged(participantSids):
+            writeInt(&buf, Int32(8))
+            FfiConverterSequenceString.write(participantSids, into: &buf)
+            
+        
+        case let .layoutOrderChanged(participantSids):
+            writeInt(&buf, Int32(9))
+            FfiConverterSequenceString.write(participantSids, into: &buf)
+            
+        
+        case let .connectionQualityChanged(participantSid,quality):
+            writeInt(&buf, Int32(10))
+            FfiConverterString.write(participantSid, into: &buf)
+            FfiConverterTypeConnectionQuality.write(quality, into: &buf)
+            
+        
+        case let .chatMessageReceived(message):
+            writeInt(&buf, Int32(11))
+            FfiConverterTypeChatMessage.write(message, into: &buf)
+            
+        
+        case let .handRaisedChanged(participantSid,raised,position):
+            writeInt(&buf, Int32(12))
+            FfiConverterString.write(participantSid, into: &buf)
+            FfiConverterBool.write(raised, into: &buf)
+            FfiConverterUInt32.write(position, into: &buf)
+            
+        
+        case let .unreadCountChanged(count):
+            writeInt(&buf, Int32(13))
+            FfiConverterUInt32.write(count, into: &buf)
+            
+        
+        case .handAutoLowered:
+            writeInt(&buf, Int32(14))
+        
+        
+        case let .reactionReceived(participantSid,participantName,emoji):
+            writeInt(&buf, Int32(15))
+            FfiConverterString.write(participantSid, into: &buf)
+            FfiConverterString.write(participantName, into: &buf)
+            FfiConverterString.write(emoji, into: &buf)
+            
+        
+        case .connectionLost:
+            writeInt(&buf, Int32(16))
+        
+        
+        case let .roomSwitched(meetUrl):
+            writeInt(&buf, Int32(17))
+            FfiConverterString.write(meetUrl, into: &buf)
+            
+        
+        case let .connectProgress(step):
+            writeInt(&buf, Int32(18))
+            FfiConverterTypeConnectStep.write(step, into: &buf)
+            
+        
+        case let .participantBatchUpdate(joined,left,joinedCount,leftCount):
+            writeInt(&buf, Int32(19))
+            FfiConverterSequenceTypeParticipantInfo.write(joined, into: &buf)
+            FfiConverterSequenceString.write(left, into: &buf)
+            FfiConverterUInt32.write(joinedCount, into: &buf)
+            FfiConverterUInt32.write(leftCount, into: &buf)
+            
+        
+        case let .trackStalled(trackSid):
+            writeInt(&buf, Int32(20))
+            FfiConverterString.write(trackSid, into: &buf)
+            
+        
+        case let .trackResumed(trackSid):
+            writeInt(&buf, Int32(21))
+            FfiConverterString.write(trackSid, into: &buf)
+            
+        
+        case let .permissionsChanged(permissions):
+            writeInt(&buf, Int32(22))
+            FfiConverterTypeLocalPermissions.write(permissions, into: &buf)
+            
+        
+        case .waitingForHost:
+            writeInt(&buf, Int32(23))
+        
+        
+        case .entryGranted:
+            writeInt(&buf, Int32(24))
+        
+        
+        case .entryDenied:
+            writeInt(&buf, Int32(25))
+        
+        
+        case let .roomLockedChanged(locked):
+            writeInt(&buf, Int32(26))
+            FfiConverterBool.write(locked, into: &buf)
+            
+        
+        case let .featureFlagsChanged(flags):
+            writeInt(&buf, Int32(27))
+            FfiConverterTypeFeatureFlags.write(flags, into: &buf)
+            
+        
+        case let .recordingStatusChanged(status):
+            writeInt(&buf, Int32(28))
+            FfiConverterTypeRecordingStatus.write(status, into: &buf)
+            
+        
+        case let .localRecordingProgress(durationMs,sizeBytes):
+            writeInt(&buf, Int32(29))
+            FfiConverterUInt64.write(durationMs, into: &buf)
+            FfiConverterUInt64.write(sizeBytes, into: &buf)
+            
+        
+        case let .restreamStatusChanged(status):
+            writeInt(&buf, Int32(30))
+            FfiConverterTypeRestreamStatus.write(status, into: &buf)
+            
+        
+        case let .sourceReady(source,handle):
+            writeInt(&buf, Int32(31))
+            FfiConverterTypeTrackSource.write(source, into: &buf)
+            FfiConverterUInt64.write(handle, into: &buf)
+            
+        
+        case let .captureConstraintsChanged(constraints):
+            writeInt(&buf, Int32(32))
+            FfiConverterTypeCaptureConstraints.write(constraints, into: &buf)
+            
+        
+        case let .dataMessageReceived(topic,senderSid,payload):
+            writeInt(&buf, Int32(33))
+            FfiConverterString.write(topic, into: &buf)
+            FfiConverterString.write(senderSid, into: &buf)
+            FfiConverterData.write(payload, into: &buf)
+            
+        
+        case let .pollStarted(poll):
+            writeInt(&buf, Int32(34))
+            FfiConverterTypePoll.write(poll, into: &buf)
+            
+        
+        case let .pollUpdated(poll):
+            writeInt(&buf, Int32(35))
+            FfiConverterTypePoll.write(poll, into: &buf)
+            
+        
+        case let .pollEnded(poll):
+            writeInt(&buf, Int32(36))
+            FfiConverterTypePoll.write(poll, into: &buf)
+            
+        
+        case .whiteboardAvailable:
+            writeInt(&buf, Int32(37))
+        
+        
+        case let .powerModeChanged(mode):
+            writeInt(&buf, Int32(38))
+            FfiConverterTypePowerMode.write(mode, into: &buf)
+            
+        
+        case let .qualityReduced(reason,constraints):
+            writeInt(&buf, Int32(39))
+            FfiConverterTypeQualityReductionReason.write(reason, into: &buf)
+            FfiConverterTypeCaptureConstraints.write(constraints, into: &buf)
+            
+        
+        case .qualityRestored:
+            writeInt(&buf, Int32(40))
+        
+        
+        case let .devicePermissionChanged(permission,state):
+            writeInt(&buf, Int32(41))
+            FfiConverterTypeDevicePermission.write(permission, into: &buf)
+            FfiConverterTypePermissionState.write(state, into: &buf)
+            
+        
+        case let .settingsChanged(keys):
+            writeInt(&buf, Int32(42))
+            FfiConverterSequenceString.write(keys, into: &buf)
+            
+        
+        case let .systemCallChanged(call):
+            writeInt(&buf, Int32(43))
+            FfiConverterTypeSystemCall.write(call, into: &buf)
+            
+        
+        case let .mediaButtonHandled(button):
+            writeInt(&buf, Int32(44))
+            FfiConverterTypeMediaButton.write(button, into: &buf)
+            
+        
+        case let .meetingEndingSoon(minutesLeft):
+            writeInt(&buf, Int32(45))
+            FfiConverterUInt32.write(minutesLeft, into: &buf)
+            
+        
+        case .meetingEnded:
+            writeInt(&buf, Int32(46))
+        
+        
+        case let .aloneInRoom(minutes,autoLeave):
+            writeInt(&buf, Int32(47))
+            FfiConverterUInt32.write(minutes, into: &buf)
+            FfiConverterBool.write(autoLeave, into: &buf)
+            
+        
+        case let .inactivityDetected(minutes):
+            writeInt(&buf, Int32(48))
+            FfiConverterUInt32.write(minutes, into: &buf)
+            
+        }
     }
 }
 
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeSettings: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> Settings {
-        return
-            try Settings(
-                displayName: FfiConverterOptionString.read(from: &buf), 
-                language: FfiConverterOptionString.read(from: &buf), 
-                micEnabledOnJoin: FfiConverterBool.read(from: &buf), 
-                cameraEnabledOnJoin: FfiConverterBool.read(from: &buf), 
-                theme: FfiConverterString.read(from: &buf), 
-                meetInstances: FfiConverterSequenceString.read(from: &buf), 
-                notificationParticipantJoin: FfiConverterBool.read(from: &buf), 
-                notificationHandRaised: FfiConverterBool.read(from: &buf), 
-                notificationMessageReceived: FfiConverterBool.read(from: &buf)
-        )
-    }
-
-    public static func write(_ value: Settings, into buf: inout [UInt8]) {
-        FfiConverterOptionString.write(value.displayName, into: &buf)
-        FfiConverterOptionString.write(value.language, into: &buf)
-        FfiConverterBool.write(value.micEnabledOnJoin, into: &buf)
-        FfiConverterBool.write(value.cameraEnabledOnJoin, into: &buf)
-        FfiConverterString.write(value.theme, into: &buf)
-        FfiConverterSequenceString.write(value.meetInstances, into: &buf)
-        FfiConverterBool.write(value.notificationParticipantJoin, into: &buf)
-        FfiConverterBool.write(value.notificationHandRaised, into: &buf)
-        FfiConverterBool.write(value.notificationMessageReceived, into: &buf)
-    }
+public func FfiConverterTypeVisioEvent_lift(_ buf: RustBuffer) throws -> VisioEvent {
+    return try FfiConverterTypeVisioEvent.lift(buf)
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeSettings_lift(_ buf: RustBuffer) throws -> Settings {
-    return try FfiConverterTypeSettings.lift(buf)
+public func FfiConverterTypeVisioEvent_lower(_ value: VisioEvent) -> RustBuffer {
+    return FfiConverterTypeVisioEvent.lower(value)
 }
 
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public func FfiConverterTypeSettings_lower(_ value: Settings) -> RustBuffer {
-    return FfiConverterTypeSettings.lower(value)
-}
 
+extension VisioEvent: Equatable, Hashable {}
 
-public struct TrackInfo {
-    public var sid: String
-    public var participantSid: String
-    public var kind: TrackKind
-    public var source: TrackSource
 
-    // Default memberwise initializers are never public by default, so we
-    // declare one manually.
-    public init(sid: String, participantSid: String, kind: TrackKind, source: TrackSource) {
-        self.sid = sid
-        self.participantSid = participantSid
-        self.kind = kind
-        self.source = source
-    }
+
+
+
+
+
+
+
+public protocol CameraCommandListener: AnyObject, Sendable {
+    
+    func onCameraCommand(command: CameraCommand) 
+    
 }
 
-#if compiler(>=6)
-extension TrackInfo: Sendable {}
-#endif
 
+// Put the implementation in a struct so we don't pollute the top-level namespace
+fileprivate struct UniffiCallbackInterfaceCameraCommandListener {
 
-extension TrackInfo: Equatable, Hashable {
-    public static func ==(lhs: TrackInfo, rhs: TrackInfo) -> Bool {
-        if lhs.sid != rhs.sid {
-            return false
-        }
-        if lhs.participantSid != rhs.participantSid {
-            return false
-        }
-        if lhs.kind != rhs.kind {
-            return false
-        }
-        if lhs.source != rhs.source {
-            return false
-        }
-        return true
-    }
+    // Create the VTable using a series of closures.
+    // Swift automatically converts these into C callback functions.
+    //
+    // This creates 1-element array, since this seems to be the only way to construct a const
+    // pointer that we can pass to the Rust code.
+    static let vtable: [UniffiVTableCallbackInterfaceCameraCommandListener] = [UniffiVTableCallbackInterfaceCameraCommandListener(
+        onCameraCommand: { (
+            uniffiHandle: UInt64,
+            command: RustBuffer,
+            uniffiOutReturn: UnsafeMutableRawPointer,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> () in
+                guard let uniffiObj = try? FfiConverterCallbackInterfaceCameraCommandListener.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.onCameraCommand(
+                     command: try FfiConverterTypeCameraCommand_lift(command)
+                )
+            }
 
-    public func hash(into hasher: inout Hasher) {
-        hasher.combine(sid)
-        hasher.combine(participantSid)
-        hasher.combine(kind)
-        hasher.combine(source)
-    }
+            
+            let writeReturn = { () }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        },
+        uniffiFree: { (uniffiHandle: UInt64) -> () in
+            let result = try? FfiConverterCallbackInterfaceCameraCommandListener.handleMap.remove(handle: uniffiHandle)
+            if result == nil {
+                print("Uniffi callback interface CameraCommandListener: handle missing in uniffiFree")
+            }
+        }
+    )]
 }
 
+private func uniffiCallbackInitCameraCommandListener() {
+    uniffi_visio_ffi_fn_init_callback_vtable_cameracommandlistener(UniffiCallbackInterfaceCameraCommandListener.vtable)
+}
 
+// FfiConverter protocol for callback interfaces
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterCallbackInterfaceCameraCommandListener {
+    fileprivate static let handleMap = UniffiHandleMap<CameraCommandListener>()
+}
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeTrackInfo: FfiConverterRustBuffer {
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TrackInfo {
-        return
-            try TrackInfo(
-                sid: FfiConverterString.read(from: &buf), 
-                participantSid: FfiConverterString.read(from: &buf), 
-                kind: FfiConverterTypeTrackKind.read(from: &buf), 
-                source: FfiConverterTypeTrackSource.read(from: &buf)
-        )
+extension FfiConverterCallbackInterfaceCameraCommandListener : FfiConverter {
+    typealias SwiftType = CameraCommandListener
+    typealias FfiType = UInt64
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func lift(_ handle: UInt64) throws -> SwiftType {
+        try handleMap.get(handle: handle)
     }
 
-    public static func write(_ value: TrackInfo, into buf: inout [UInt8]) {
-        FfiConverterString.write(value.sid, into: &buf)
-        FfiConverterString.write(value.participantSid, into: &buf)
-        FfiConverterTypeTrackKind.write(value.kind, into: &buf)
-        FfiConverterTypeTrackSource.write(value.source, into: &buf)
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func lower(_ v: SwiftType) -> UInt64 {
+        return handleMap.insert(obj: v)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func write(_ v: SwiftType, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(v))
     }
 }
 
@@ -1376,75 +9207,117 @@ public struct FfiConverterTypeTrackInfo: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeTrackInfo_lift(_ buf: RustBuffer) throws -> TrackInfo {
-    return try FfiConverterTypeTrackInfo.lift(buf)
+public func FfiConverterCallbackInterfaceCameraCommandListener_lift(_ handle: UInt64) throws -> CameraCommandListener {
+    return try FfiConverterCallbackInterfaceCameraCommandListener.lift(handle)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeTrackInfo_lower(_ value: TrackInfo) -> RustBuffer {
-    return FfiConverterTypeTrackInfo.lower(value)
+public func FfiConverterCallbackInterfaceCameraCommandListener_lower(_ v: CameraCommandListener) -> UInt64 {
+    return FfiConverterCallbackInterfaceCameraCommandListener.lower(v)
 }
 
-// Note that we don't yet support `indirect` for enums.
-// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
 
-public enum ConnectionQuality {
+
+
+public protocol RoomEventListener: AnyObject, Sendable {
+    
+    func onRoomEvent(roomId: String, event: VisioEvent) 
     
-    case excellent
-    case good
-    case poor
-    case lost
 }
 
 
-#if compiler(>=6)
-extension ConnectionQuality: Sendable {}
+// Put the implementation in a struct so we don't pollute the top-level namespace
+fileprivate struct UniffiCallbackInterfaceRoomEventListener {
+
+    // Create the VTable using a series of closures.
+    // Swift automatically converts these into C callback functions.
+    //
+    // This creates 1-element array, since this seems to be the only way to construct a const
+    // pointer that we can pass to the Rust code.
+    static let vtable: [UniffiVTableCallbackInterfaceRoomEventListener] = [UniffiVTableCallbackInterfaceRoomEventListener(
+        onRoomEvent: { (
+            uniffiHandle: UInt64,
+            roomId: RustBuffer,
+            event: RustBuffer,
+            uniffiOutReturn: UnsafeMutableRawPointer,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> () in
+                guard let uniffiObj = try? FfiConverterCallbackInterfaceRoomEventListener.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.onRoomEvent(
+                     roomId: try FfiConverterString.lift(roomId),
+                     event: try FfiConverterTypeVisioEvent_lift(event)
+                )
+            }
+
+            
+            let writeReturn = { () }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        },
+        uniffiFree: { (uniffiHandle: UInt64) -> () in
+            let result = try? FfiConverterCallbackInterfaceRoomEventListener.handleMap.remove(handle: uniffiHandle)
+            if result == nil {
+                print("Uniffi callback interface RoomEventListener: handle missing in uniffiFree")
+            }
+        }
+    )]
+}
+
+private func uniffiCallbackInitRoomEventListener() {
+    uniffi_visio_ffi_fn_init_callback_vtable_roomeventlistener(UniffiCallbackInterfaceRoomEventListener.vtable)
+}
+
+// FfiConverter protocol for callback interfaces
+#if swift(>=5.8)
+@_documentation(visibility: private)
 #endif
+fileprivate struct FfiConverterCallbackInterfaceRoomEventListener {
+    fileprivate static let handleMap = UniffiHandleMap<RoomEventListener>()
+}
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeConnectionQuality: FfiConverterRustBuffer {
-    typealias SwiftType = ConnectionQuality
+extension FfiConverterCallbackInterfaceRoomEventListener : FfiConverter {
+    typealias SwiftType = RoomEventListener
+    typealias FfiType = UInt64
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ConnectionQuality {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-        
-        case 1: return .excellent
-        
-        case 2: return .good
-        
-        case 3: return .poor
-        
-        case 4: return .lost
-        
-        default: throw UniffiInternalError.unexpectedEnumCase
-        }
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func lift(_ handle: UInt64) throws -> SwiftType {
+        try handleMap.get(handle: handle)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func lower(_ v: SwiftType) -> UInt64 {
+        return handleMap.insert(obj: v)
     }
 
-    public static func write(_ value: ConnectionQuality, into buf: inout [UInt8]) {
-        switch value {
-        
-        
-        case .excellent:
-            writeInt(&buf, Int32(1))
-        
-        
-        case .good:
-            writeInt(&buf, Int32(2))
-        
-        
-        case .poor:
-            writeInt(&buf, Int32(3))
-        
-        
-        case .lost:
-            writeInt(&buf, Int32(4))
-        
-        }
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func write(_ v: SwiftType, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(v))
     }
 }
 
@@ -1452,86 +9325,115 @@ public struct FfiConverterTypeConnectionQuality: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeConnectionQuality_lift(_ buf: RustBuffer) throws -> ConnectionQuality {
-    return try FfiConverterTypeConnectionQuality.lift(buf)
+public func FfiConverterCallbackInterfaceRoomEventListener_lift(_ handle: UInt64) throws -> RoomEventListener {
+    return try FfiConverterCallbackInterfaceRoomEventListener.lift(handle)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeConnectionQuality_lower(_ value: ConnectionQuality) -> RustBuffer {
-    return FfiConverterTypeConnectionQuality.lower(value)
+public func FfiConverterCallbackInterfaceRoomEventListener_lower(_ v: RoomEventListener) -> UInt64 {
+    return FfiConverterCallbackInterfaceRoomEventListener.lower(v)
 }
 
 
-extension ConnectionQuality: Equatable, Hashable {}
-
 
 
+public protocol VisioEventListener: AnyObject, Sendable {
+    
+    func onEvent(event: VisioEvent) 
+    
+}
 
 
+// Put the implementation in a struct so we don't pollute the top-level namespace
+fileprivate struct UniffiCallbackInterfaceVisioEventListener {
 
-// Note that we don't yet support `indirect` for enums.
-// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+    // Create the VTable using a series of closures.
+    // Swift automatically converts these into C callback functions.
+    //
+    // This creates 1-element array, since this seems to be the only way to construct a const
+    // pointer that we can pass to the Rust code.
+    static let vtable: [UniffiVTableCallbackInterfaceVisioEventListener] = [UniffiVTableCallbackInterfaceVisioEventListener(
+        onEvent: { (
+            uniffiHandle: UInt64,
+            event: RustBuffer,
+            uniffiOutReturn: UnsafeMutableRawPointer,
+            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
+        ) in
+            let makeCall = {
+                () throws -> () in
+                guard let uniffiObj = try? FfiConverterCallbackInterfaceVisioEventListener.handleMap.get(handle: uniffiHandle) else {
+                    throw UniffiInternalError.unexpectedStaleHandle
+                }
+                return uniffiObj.onEvent(
+                     event: try FfiConverterTypeVisioEvent_lift(event)
+                )
+            }
 
-public enum ConnectionState {
-    
-    case disconnected
-    case connecting
-    case connected
-    case reconnecting(attempt: UInt32
-    )
+            
+            let writeReturn = { () }
+            uniffiTraitInterfaceCall(
+                callStatus: uniffiCallStatus,
+                makeCall: makeCall,
+                writeReturn: writeReturn
+            )
+        },
+        uniffiFree: { (uniffiHandle: UInt64) -> () in
+            let result = try? FfiConverterCallbackInterfaceVisioEventListener.handleMap.remove(handle: uniffiHandle)
+            if result == nil {
+                print("Uniffi callback interface VisioEventListener: handle missing in uniffiFree")
+            }
+        }
+    )]
 }
 
+private func uniffiCallbackInitVisioEventListener() {
+    uniffi_visio_ffi_fn_init_callback_vtable_visioeventlistener(UniffiCallbackInterfaceVisioEventListener.vtable)
+}
 
-#if compiler(>=6)
-extension ConnectionState: Sendable {}
+// FfiConverter protocol for callback interfaces
+#if swift(>=5.8)
+@_documentation(visibility: private)
 #endif
+fileprivate struct FfiConverterCallbackInterfaceVisioEventListener {
+    fileprivate static let handleMap = UniffiHandleMap<VisioEventListener>()
+}
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeConnectionState: FfiConverterRustBuffer {
-    typealias SwiftType = ConnectionState
+extension FfiConverterCallbackInterfaceVisioEventListener : FfiConverter {
+    typealias SwiftType = VisioEventListener
+    typealias FfiType = UInt64
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> ConnectionState {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-        
-        case 1: return .disconnected
-        
-        case 2: return .connecting
-        
-        case 3: return .connected
-        
-        case 4: return .reconnecting(attempt: try FfiConverterUInt32.read(from: &buf)
-        )
-        
-        default: throw UniffiInternalError.unexpectedEnumCase
-        }
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func lift(_ handle: UInt64) throws -> SwiftType {
+        try handleMap.get(handle: handle)
     }
 
-    public static func write(_ value: ConnectionState, into buf: inout [UInt8]) {
-        switch value {
-        
-        
-        case .disconnected:
-            writeInt(&buf, Int32(1))
-        
-        
-        case .connecting:
-            writeInt(&buf, Int32(2))
-        
-        
-        case .connected:
-            writeInt(&buf, Int32(3))
-        
-        
-        case let .reconnecting(attempt):
-            writeInt(&buf, Int32(4))
-            FfiConverterUInt32.write(attempt, into: &buf)
-            
-        }
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        let handle: UInt64 = try readInt(&buf)
+        return try lift(handle)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func lower(_ v: SwiftType) -> UInt64 {
+        return handleMap.insert(obj: v)
+    }
+
+#if swift(>=5.8)
+    @_documentation(visibility: private)
+#endif
+    public static func write(_ v: SwiftType, into buf: inout [UInt8]) {
+        writeInt(&buf, lower(v))
     }
 }
 
@@ -1539,922 +9441,1244 @@ public struct FfiConverterTypeConnectionState: FfiConverterRustBuffer {
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeConnectionState_lift(_ buf: RustBuffer) throws -> ConnectionState {
-    return try FfiConverterTypeConnectionState.lift(buf)
+public func FfiConverterCallbackInterfaceVisioEventListener_lift(_ handle: UInt64) throws -> VisioEventListener {
+    return try FfiConverterCallbackInterfaceVisioEventListener.lift(handle)
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeConnectionState_lower(_ value: ConnectionState) -> RustBuffer {
-    return FfiConverterTypeConnectionState.lower(value)
+public func FfiConverterCallbackInterfaceVisioEventListener_lower(_ v: VisioEventListener) -> UInt64 {
+    return FfiConverterCallbackInterfaceVisioEventListener.lower(v)
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionUInt32: FfiConverterRustBuffer {
+    typealias SwiftType = UInt32?
 
-extension ConnectionState: Equatable, Hashable {}
-
-
-
-
-
-
-// Note that we don't yet support `indirect` for enums.
-// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterUInt32.write(value, into: &buf)
+    }
 
-public enum RoomValidationResult {
-    
-    case valid(livekitUrl: String, token: String
-    )
-    case notFound
-    case invalidFormat(message: String
-    )
-    case networkError(message: String
-    )
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterUInt32.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
-
-#if compiler(>=6)
-extension RoomValidationResult: Sendable {}
-#endif
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeRoomValidationResult: FfiConverterRustBuffer {
-    typealias SwiftType = RoomValidationResult
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> RoomValidationResult {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-        
-        case 1: return .valid(livekitUrl: try FfiConverterString.read(from: &buf), token: try FfiConverterString.read(from: &buf)
-        )
-        
-        case 2: return .notFound
-        
-        case 3: return .invalidFormat(message: try FfiConverterString.read(from: &buf)
-        )
-        
-        case 4: return .networkError(message: try FfiConverterString.read(from: &buf)
-        )
-        
-        default: throw UniffiInternalError.unexpectedEnumCase
-        }
-    }
+fileprivate struct FfiConverterOptionUInt64: FfiConverterRustBuffer {
+    typealias SwiftType = UInt64?
 
-    public static func write(_ value: RoomValidationResult, into buf: inout [UInt8]) {
-        switch value {
-        
-        
-        case let .valid(livekitUrl,token):
-            writeInt(&buf, Int32(1))
-            FfiConverterString.write(livekitUrl, into: &buf)
-            FfiConverterString.write(token, into: &buf)
-            
-        
-        case .notFound:
-            writeInt(&buf, Int32(2))
-        
-        
-        case let .invalidFormat(message):
-            writeInt(&buf, Int32(3))
-            FfiConverterString.write(message, into: &buf)
-            
-        
-        case let .networkError(message):
-            writeInt(&buf, Int32(4))
-            FfiConverterString.write(message, into: &buf)
-            
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
         }
+        writeInt(&buf, Int8(1))
+        FfiConverterUInt64.write(value, into: &buf)
     }
-}
 
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterUInt64.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeRoomValidationResult_lift(_ buf: RustBuffer) throws -> RoomValidationResult {
-    return try FfiConverterTypeRoomValidationResult.lift(buf)
+fileprivate struct FfiConverterOptionInt64: FfiConverterRustBuffer {
+    typealias SwiftType = Int64?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterInt64.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterInt64.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeRoomValidationResult_lower(_ value: RoomValidationResult) -> RustBuffer {
-    return FfiConverterTypeRoomValidationResult.lower(value)
-}
-
-
-extension RoomValidationResult: Equatable, Hashable {}
-
-
+fileprivate struct FfiConverterOptionDouble: FfiConverterRustBuffer {
+    typealias SwiftType = Double?
 
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterDouble.write(value, into: &buf)
+    }
 
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterDouble.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionBool: FfiConverterRustBuffer {
+    typealias SwiftType = Bool?
 
-// Note that we don't yet support `indirect` for enums.
-// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterBool.write(value, into: &buf)
+    }
 
-public enum TrackKind {
-    
-    case audio
-    case video
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterBool.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
-
-#if compiler(>=6)
-extension TrackKind: Sendable {}
-#endif
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeTrackKind: FfiConverterRustBuffer {
-    typealias SwiftType = TrackKind
+fileprivate struct FfiConverterOptionString: FfiConverterRustBuffer {
+    typealias SwiftType = String?
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TrackKind {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-        
-        case 1: return .audio
-        
-        case 2: return .video
-        
-        default: throw UniffiInternalError.unexpectedEnumCase
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
         }
+        writeInt(&buf, Int8(1))
+        FfiConverterString.write(value, into: &buf)
     }
 
-    public static func write(_ value: TrackKind, into buf: inout [UInt8]) {
-        switch value {
-        
-        
-        case .audio:
-            writeInt(&buf, Int32(1))
-        
-        
-        case .video:
-            writeInt(&buf, Int32(2))
-        
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterString.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeTrackKind_lift(_ buf: RustBuffer) throws -> TrackKind {
-    return try FfiConverterTypeTrackKind.lift(buf)
+fileprivate struct FfiConverterOptionTypeCallSummary: FfiConverterRustBuffer {
+    typealias SwiftType = CallSummary?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeCallSummary.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeCallSummary.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeTrackKind_lower(_ value: TrackKind) -> RustBuffer {
-    return FfiConverterTypeTrackKind.lower(value)
-}
-
+fileprivate struct FfiConverterOptionTypeConnectionQuality: FfiConverterRustBuffer {
+    typealias SwiftType = ConnectionQuality?
 
-extension TrackKind: Equatable, Hashable {}
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeConnectionQuality.write(value, into: &buf)
+    }
 
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeConnectionQuality.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeDialInInfo: FfiConverterRustBuffer {
+    typealias SwiftType = DialInInfo?
 
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeDialInInfo.write(value, into: &buf)
+    }
 
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeDialInInfo.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeLocalPermissions: FfiConverterRustBuffer {
+    typealias SwiftType = LocalPermissions?
 
-// Note that we don't yet support `indirect` for enums.
-// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeLocalPermissions.write(value, into: &buf)
+    }
 
-public enum TrackSource {
-    
-    case microphone
-    case camera
-    case screenShare
-    case unknown
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeLocalPermissions.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
-
-#if compiler(>=6)
-extension TrackSource: Sendable {}
-#endif
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeTrackSource: FfiConverterRustBuffer {
-    typealias SwiftType = TrackSource
+fileprivate struct FfiConverterOptionTypeRendererStats: FfiConverterRustBuffer {
+    typealias SwiftType = RendererStats?
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> TrackSource {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-        
-        case 1: return .microphone
-        
-        case 2: return .camera
-        
-        case 3: return .screenShare
-        
-        case 4: return .unknown
-        
-        default: throw UniffiInternalError.unexpectedEnumCase
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
         }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeRendererStats.write(value, into: &buf)
     }
 
-    public static func write(_ value: TrackSource, into buf: inout [UInt8]) {
-        switch value {
-        
-        
-        case .microphone:
-            writeInt(&buf, Int32(1))
-        
-        
-        case .camera:
-            writeInt(&buf, Int32(2))
-        
-        
-        case .screenShare:
-            writeInt(&buf, Int32(3))
-        
-        
-        case .unknown:
-            writeInt(&buf, Int32(4))
-        
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeRendererStats.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeTrackSource_lift(_ buf: RustBuffer) throws -> TrackSource {
-    return try FfiConverterTypeTrackSource.lift(buf)
+fileprivate struct FfiConverterOptionTypeRtcConfigOverride: FfiConverterRustBuffer {
+    typealias SwiftType = RtcConfigOverride?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeRtcConfigOverride.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeRtcConfigOverride.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeTrackSource_lower(_ value: TrackSource) -> RustBuffer {
-    return FfiConverterTypeTrackSource.lower(value)
-}
-
-
-extension TrackSource: Equatable, Hashable {}
-
+fileprivate struct FfiConverterOptionTypeSystemCall: FfiConverterRustBuffer {
+    typealias SwiftType = SystemCall?
 
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeSystemCall.write(value, into: &buf)
+    }
 
-
-
-
-
-public enum VisioError: Swift.Error {
-
-    
-    
-    case Connection(msg: String
-    )
-    case Room(msg: String
-    )
-    case Auth(msg: String
-    )
-    case Http(msg: String
-    )
-    case InvalidUrl(msg: String
-    )
-    case Generic(msg: String
-    )
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeSystemCall.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeVisioError: FfiConverterRustBuffer {
-    typealias SwiftType = VisioError
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> VisioError {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-
-        
-
-        
-        case 1: return .Connection(
-            msg: try FfiConverterString.read(from: &buf)
-            )
-        case 2: return .Room(
-            msg: try FfiConverterString.read(from: &buf)
-            )
-        case 3: return .Auth(
-            msg: try FfiConverterString.read(from: &buf)
-            )
-        case 4: return .Http(
-            msg: try FfiConverterString.read(from: &buf)
-            )
-        case 5: return .InvalidUrl(
-            msg: try FfiConverterString.read(from: &buf)
-            )
-        case 6: return .Generic(
-            msg: try FfiConverterString.read(from: &buf)
-            )
+fileprivate struct FfiConverterOptionTypeDeepLink: FfiConverterRustBuffer {
+    typealias SwiftType = DeepLink?
 
-         default: throw UniffiInternalError.unexpectedEnumCase
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
         }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeDeepLink.write(value, into: &buf)
     }
 
-    public static func write(_ value: VisioError, into buf: inout [UInt8]) {
-        switch value {
-
-        
-
-        
-        
-        case let .Connection(msg):
-            writeInt(&buf, Int32(1))
-            FfiConverterString.write(msg, into: &buf)
-            
-        
-        case let .Room(msg):
-            writeInt(&buf, Int32(2))
-            FfiConverterString.write(msg, into: &buf)
-            
-        
-        case let .Auth(msg):
-            writeInt(&buf, Int32(3))
-            FfiConverterString.write(msg, into: &buf)
-            
-        
-        case let .Http(msg):
-            writeInt(&buf, Int32(4))
-            FfiConverterString.write(msg, into: &buf)
-            
-        
-        case let .InvalidUrl(msg):
-            writeInt(&buf, Int32(5))
-            FfiConverterString.write(msg, into: &buf)
-            
-        
-        case let .Generic(msg):
-            writeInt(&buf, Int32(6))
-            FfiConverterString.write(msg, into: &buf)
-            
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeDeepLink.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
         }
     }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeVisioError_lift(_ buf: RustBuffer) throws -> VisioError {
-    return try FfiConverterTypeVisioError.lift(buf)
+fileprivate struct FfiConverterOptionData: FfiConverterRustBuffer {
+    typealias SwiftType = Data?
+
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterData.write(value, into: &buf)
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterData.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeVisioError_lower(_ value: VisioError) -> RustBuffer {
-    return FfiConverterTypeVisioError.lower(value)
-}
+fileprivate struct FfiConverterOptionTypeRoomInfo: FfiConverterRustBuffer {
+    typealias SwiftType = RoomInfo?
 
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeRoomInfo.write(value, into: &buf)
+    }
 
-extension VisioError: Equatable, Hashable {}
-
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeRoomInfo.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
+}
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionTypeVideoStats: FfiConverterRustBuffer {
+    typealias SwiftType = VideoStats?
 
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterTypeVideoStats.write(value, into: &buf)
+    }
 
-extension VisioError: Foundation.LocalizedError {
-    public var errorDescription: String? {
-        String(reflecting: self)
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterTypeVideoStats.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
     }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterOptionDictionaryStringString: FfiConverterRustBuffer {
+    typealias SwiftType = [String: String]?
 
+    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
+        guard let value = value else {
+            writeInt(&buf, Int8(0))
+            return
+        }
+        writeInt(&buf, Int8(1))
+        FfiConverterDictionaryStringString.write(value, into: &buf)
+    }
 
-
-// Note that we don't yet support `indirect` for enums.
-// See https://github.com/mozilla/uniffi-rs/issues/396 for further discussion.
-
-public enum VisioEvent {
-    
-    case connectionStateChanged(state: ConnectionState
-    )
-    case participantJoined(info: ParticipantInfo
-    )
-    case participantLeft(participantSid: String
-    )
-    case trackSubscribed(info: TrackInfo
-    )
-    case trackUnsubscribed(trackSid: String
-    )
-    case trackMuted(participantSid: String, source: TrackSource
-    )
-    case trackUnmuted(participantSid: String, source: TrackSource
-    )
-    case activeSpeakersChanged(participantSids: [String]
-    )
-    case connectionQualityChanged(participantSid: String, quality: ConnectionQuality
-    )
-    case chatMessageReceived(message: ChatMessage
-    )
-    case handRaisedChanged(participantSid: String, raised: Bool, position: UInt32
-    )
-    case unreadCountChanged(count: UInt32
-    )
-    case reactionReceived(participantSid: String, participantName: String, emoji: String
-    )
-    case connectionLost
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
+        switch try readInt(&buf) as Int8 {
+        case 0: return nil
+        case 1: return try FfiConverterDictionaryStringString.read(from: &buf)
+        default: throw UniffiInternalError.unexpectedOptionalTag
+        }
+    }
 }
 
-
-#if compiler(>=6)
-extension VisioEvent: Sendable {}
-#endif
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public struct FfiConverterTypeVisioEvent: FfiConverterRustBuffer {
-    typealias SwiftType = VisioEvent
+fileprivate struct FfiConverterSequenceInt16: FfiConverterRustBuffer {
+    typealias SwiftType = [Int16]
 
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> VisioEvent {
-        let variant: Int32 = try readInt(&buf)
-        switch variant {
-        
-        case 1: return .connectionStateChanged(state: try FfiConverterTypeConnectionState.read(from: &buf)
-        )
-        
-        case 2: return .participantJoined(info: try FfiConverterTypeParticipantInfo.read(from: &buf)
-        )
-        
-        case 3: return .participantLeft(participantSid: try FfiConverterString.read(from: &buf)
-        )
-        
-        case 4: return .trackSubscribed(info: try FfiConverterTypeTrackInfo.read(from: &buf)
-        )
-        
-        case 5: return .trackUnsubscribed(trackSid: try FfiConverterString.read(from: &buf)
-        )
-        
-        case 6: return .trackMuted(participantSid: try FfiConverterString.read(from: &buf), source: try FfiConverterTypeTrackSource.read(from: &buf)
-        )
-        
-        case 7: return .trackUnmuted(participantSid: try FfiConverterString.read(from: &buf), source: try FfiConverterTypeTrackSource.read(from: &buf)
-        )
-        
-        case 8: return .activeSpeakersChanged(participantSids: try FfiConverterSequenceString.read(from: &buf)
-        )
-        
-        case 9: return .connectionQualityChanged(participantSid: try FfiConverterString.read(from: &buf), quality: try FfiConverterTypeConnectionQuality.read(from: &buf)
-        )
-        
-        case 10: return .chatMessageReceived(message: try FfiConverterTypeChatMessage.read(from: &buf)
-        )
-        
-        case 11: return .handRaisedChanged(participantSid: try FfiConverterString.read(from: &buf), raised: try FfiConverterBool.read(from: &buf), position: try FfiConverterUInt32.read(from: &buf)
-        )
-        
-        case 12: return .unreadCountChanged(count: try FfiConverterUInt32.read(from: &buf)
-        )
-        
-        case 13: return .reactionReceived(participantSid: try FfiConverterString.read(from: &buf), participantName: try FfiConverterString.read(from: &buf), emoji: try FfiConverterString.read(from: &buf)
-        )
-        
-        case 14: return .connectionLost
-        
-        default: throw UniffiInternalError.unexpectedEnumCase
+    public static func write(_ value: [Int16], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterInt16.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [Int16] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [Int16]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterInt16.read(from: &buf))
+        }
+        return seq
+    }
+}
+
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceUInt32: FfiConverterRustBuffer {
+    typealias SwiftType = [UInt32]
+
+    public static func write(_ value: [UInt32], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterUInt32.write(item, into: &buf)
         }
     }
 
-    public static func write(_ value: VisioEvent, into buf: inout [UInt8]) {
-        switch value {
-        
-        
-        case let .connectionStateChanged(state):
-            writeInt(&buf, Int32(1))
-            FfiConverterTypeConnectionState.write(state, into: &buf)
-            
-        
-        case let .participantJoined(info):
-            writeInt(&buf, Int32(2))
-            FfiConverterTypeParticipantInfo.write(info, into: &buf)
-            
-        
-        case let .participantLeft(participantSid):
-            writeInt(&buf, Int32(3))
-            FfiConverterString.write(participantSid, into: &buf)
-            
-        
-        case let .trackSubscribed(info):
-            writeInt(&buf, Int32(4))
-            FfiConverterTypeTrackInfo.write(info, into: &buf)
-            
-        
-        case let .trackUnsubscribed(trackSid):
-            writeInt(&buf, Int32(5))
-            FfiConverterString.write(trackSid, into: &buf)
-            
-        
-        case let .trackMuted(participantSid,source):
-            writeInt(&buf, Int32(6))
-            FfiConverterString.write(participantSid, into: &buf)
-            FfiConverterTypeTrackSource.write(source, into: &buf)
-            
-        
-        case let .trackUnmuted(participantSid,source):
-            writeInt(&buf, Int32(7))
-            FfiConverterString.write(participantSid, into: &buf)
-            FfiConverterTypeTrackSource.write(source, into: &buf)
-            
-        
-        case let .activeSpeakersChanged(participantSids):
-            writeInt(&buf, Int32(8))
-            FfiConverterSequenceString.write(participantSids, into: &buf)
-            
-        
-        case let .connectionQualityChanged(participantSid,quality):
-            writeInt(&buf, Int32(9))
-            FfiConverterString.write(participantSid, into: &buf)
-            FfiConverterTypeConnectionQuality.write(quality, into: &buf)
-            
-        
-        case let .chatMessageReceived(message):
-            writeInt(&buf, Int32(10))
-            FfiConverterTypeChatMessage.write(message, into: &buf)
-            
-        
-        case let .handRaisedChanged(participantSid,raised,position):
-            writeInt(&buf, Int32(11))
-            FfiConverterString.write(participantSid, into: &buf)
-            FfiConverterBool.write(raised, into: &buf)
-            FfiConverterUInt32.write(position, into: &buf)
-            
-        
-        case let .unreadCountChanged(count):
-            writeInt(&buf, Int32(12))
-            FfiConverterUInt32.write(count, into: &buf)
-            
-        
-        case let .reactionReceived(participantSid,participantName,emoji):
-            writeInt(&buf, Int32(13))
-            FfiConverterString.write(participantSid, into: &buf)
-            FfiConverterString.write(participantName, into: &buf)
-            FfiConverterString.write(emoji, into: &buf)
-            
-        
-        case .connectionLost:
-            writeInt(&buf, Int32(14))
-        
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [UInt32] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [UInt32]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterUInt32.read(from: &buf))
         }
+        return seq
     }
 }
 
-
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeVisioEvent_lift(_ buf: RustBuffer) throws -> VisioEvent {
-    return try FfiConverterTypeVisioEvent.lift(buf)
+fileprivate struct FfiConverterSequenceString: FfiConverterRustBuffer {
+    typealias SwiftType = [String]
+
+    public static func write(_ value: [String], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterString.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [String] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [String]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterString.read(from: &buf))
+        }
+        return seq
+    }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-public func FfiConverterTypeVisioEvent_lower(_ value: VisioEvent) -> RustBuffer {
-    return FfiConverterTypeVisioEvent.lower(value)
+fileprivate struct FfiConverterSequenceTypeChatMessage: FfiConverterRustBuffer {
+    typealias SwiftType = [ChatMessage]
+
+    public static func write(_ value: [ChatMessage], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeChatMessage.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [ChatMessage] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [ChatMessage]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeChatMessage.read(from: &buf))
+        }
+        return seq
+    }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeIceServerConfig: FfiConverterRustBuffer {
+    typealias SwiftType = [IceServerConfig]
 
-extension VisioEvent: Equatable, Hashable {}
+    public static func write(_ value: [IceServerConfig], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeIceServerConfig.write(item, into: &buf)
+        }
+    }
 
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [IceServerConfig] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [IceServerConfig]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeIceServerConfig.read(from: &buf))
+        }
+        return seq
+    }
+}
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeMessageSpan: FfiConverterRustBuffer {
+    typealias SwiftType = [MessageSpan]
 
+    public static func write(_ value: [MessageSpan], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeMessageSpan.write(item, into: &buf)
+        }
+    }
 
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [MessageSpan] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [MessageSpan]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeMessageSpan.read(from: &buf))
+        }
+        return seq
+    }
+}
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypeParticipantInfo: FfiConverterRustBuffer {
+    typealias SwiftType = [ParticipantInfo]
 
+    public static func write(_ value: [ParticipantInfo], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeParticipantInfo.write(item, into: &buf)
+        }
+    }
 
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [ParticipantInfo] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [ParticipantInfo]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeParticipantInfo.read(from: &buf))
+        }
+        return seq
+    }
+}
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterSequenceTypePoll: FfiConverterRustBuffer {
+    typealias SwiftType = [Poll]
 
-public protocol VisioEventListener: AnyObject, Sendable {
-    
-    func onEvent(event: VisioEvent) 
-    
+    public static func write(_ value: [Poll], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypePoll.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [Poll] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [Poll]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypePoll.read(from: &buf))
+        }
+        return seq
+    }
 }
 
+#if swift(>=5.8)
+@_documentation(visibility: private)
+#endif
+fileprivate struct FfiConverterDictionaryStringString: FfiConverterRustBuffer {
+    public static func write(_ value: [String: String], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for (key, value) in value {
+            FfiConverterString.write(key, into: &buf)
+            FfiConverterString.write(value, into: &buf)
+        }
+    }
 
-// Put the implementation in a struct so we don't pollute the top-level namespace
-fileprivate struct UniffiCallbackInterfaceVisioEventListener {
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [String: String] {
+        let len: Int32 = try readInt(&buf)
+        var dict = [String: String]()
+        dict.reserveCapacity(Int(len))
+        for _ in 0..<len {
+            let key = try FfiConverterString.read(from: &buf)
+            let value = try FfiConverterString.read(from: &buf)
+            dict[key] = value
+        }
+        return dict
+    }
+}
+private let UNIFFI_RUST_FUTURE_POLL_READY: Int8 = 0
+private let UNIFFI_RUST_FUTURE_POLL_MAYBE_READY: Int8 = 1
 
-    // Create the VTable using a series of closures.
-    // Swift automatically converts these into C callback functions.
-    //
-    // This creates 1-element array, since this seems to be the only way to construct a const
-    // pointer that we can pass to the Rust code.
-    static let vtable: [UniffiVTableCallbackInterfaceVisioEventListener] = [UniffiVTableCallbackInterfaceVisioEventListener(
-        onEvent: { (
-            uniffiHandle: UInt64,
-            event: RustBuffer,
-            uniffiOutReturn: UnsafeMutableRawPointer,
-            uniffiCallStatus: UnsafeMutablePointer<RustCallStatus>
-        ) in
-            let makeCall = {
-                () throws -> () in
-                guard let uniffiObj = try? FfiConverterCallbackInterfaceVisioEventListener.handleMap.get(handle: uniffiHandle) else {
-                    throw UniffiInternalError.unexpectedStaleHandle
-                }
-                return uniffiObj.onEvent(
-                     event: try FfiConverterTypeVisioEvent_lift(event)
-                )
-            }
+fileprivate let uniffiContinuationHandleMap = UniffiHandleMap<UnsafeContinuation<Int8, Never>>()
 
-            
-            let writeReturn = { () }
-            uniffiTraitInterfaceCall(
-                callStatus: uniffiCallStatus,
-                makeCall: makeCall,
-                writeReturn: writeReturn
+fileprivate func uniffiRustCallAsync<F, T>(
+    rustFutureFunc: () -> UInt64,
+    pollFunc: (UInt64, @escaping UniffiRustFutureContinuationCallback, UInt64) -> (),
+    completeFunc: (UInt64, UnsafeMutablePointer<RustCallStatus>) -> F,
+    freeFunc: (UInt64) -> (),
+    liftFunc: (F) throws -> T,
+    errorHandler: ((RustBuffer) throws -> Swift.Error)?
+) async throws -> T {
+    // Make sure to call the ensure init function since future creation doesn't have a
+    // RustCallStatus param, so doesn't use makeRustCall()
+    uniffiEnsureVisioFfiInitialized()
+    let rustFuture = rustFutureFunc()
+    defer {
+        freeFunc(rustFuture)
+    }
+    var pollResult: Int8;
+    repeat {
+        pollResult = await withUnsafeContinuation {
+            pollFunc(
+                rustFuture,
+                uniffiFutureContinuationCallback,
+                uniffiContinuationHandleMap.insert(obj: $0)
             )
-        },
-        uniffiFree: { (uniffiHandle: UInt64) -> () in
-            let result = try? FfiConverterCallbackInterfaceVisioEventListener.handleMap.remove(handle: uniffiHandle)
-            if result == nil {
-                print("Uniffi callback interface VisioEventListener: handle missing in uniffiFree")
-            }
         }
-    )]
-}
+    } while pollResult != UNIFFI_RUST_FUTURE_POLL_READY
 
-private func uniffiCallbackInitVisioEventListener() {
-    uniffi_visio_ffi_fn_init_callback_vtable_visioeventlistener(UniffiCallbackInterfaceVisioEventListener.vtable)
+    return try liftFunc(makeRustCall(
+        { completeFunc(rustFuture, $0) },
+        errorHandler: errorHandler
+    ))
 }
 
-// FfiConverter protocol for callback interfaces
+// Callback handlers for an async calls.  These are invoked by Rust when the future is ready.  They
+// lift the return value or error and resume the suspended function.
+fileprivate func uniffiFutureContinuationCallback(handle: UInt64, pollResult: Int8) {
+    if let continuation = try? uniffiContinuationHandleMap.remove(handle: handle) {
+        continuation.resume(returning: pollResult)
+    } else {
+        print("uniffiFutureContinuationCallback invalid handle")
+    }
+}
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-fileprivate struct FfiConverterCallbackInterfaceVisioEventListener {
-    fileprivate static let handleMap = UniffiHandleMap<VisioEventListener>()
+fileprivate struct FfiConverterSequenceTypeQualitySample: FfiConverterRustBuffer {
+    typealias SwiftType = [QualitySample]
+
+    public static func write(_ value: [QualitySample], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeQualitySample.write(item, into: &buf)
+        }
+    }
+
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [QualitySample] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [QualitySample]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeQualitySample.read(from: &buf))
+        }
+        return seq
+    }
 }
 
 #if swift(>=5.8)
 @_documentation(visibility: private)
 #endif
-extension FfiConverterCallbackInterfaceVisioEventListener : FfiConverter {
-    typealias SwiftType = VisioEventListener
-    typealias FfiType = UInt64
+fileprivate struct FfiConverterSequenceTypeTileRect: FfiConverterRustBuffer {
+    typealias SwiftType = [TileRect]
 
-#if swift(>=5.8)
-    @_documentation(visibility: private)
-#endif
-    public static func lift(_ handle: UInt64) throws -> SwiftType {
-        try handleMap.get(handle: handle)
+    public static func write(_ value: [TileRect], into buf: inout [UInt8]) {
+        let len = Int32(value.count)
+        writeInt(&buf, len)
+        for item in value {
+            FfiConverterTypeTileRect.write(item, into: &buf)
+        }
     }
 
-#if swift(>=5.8)
-    @_documentation(visibility: private)
-#endif
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
-        let handle: UInt64 = try readInt(&buf)
-        return try lift(handle)
+    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [TileRect] {
+        let len: Int32 = try readInt(&buf)
+        var seq = [TileRect]()
+        seq.reserveCapacity(Int(len))
+        for _ in 0 ..< len {
+            seq.append(try FfiConverterTypeTileRect.read(from: &buf))
+        }
+        return seq
     }
+}
 
-#if swift(>=5.8)
-    @_documentation(visibility: private)
-#endif
-    public static func lower(_ v: SwiftType) -> UInt64 {
-        return handleMap.insert(obj: v)
+public func buildInviteText(info: InviteInfo, language: String, startsAtMs: Int64?, utcOffsetMinutes: Int32) -> String  {
+    return try!  FfiConverterString.lift(try! rustCall() {
+    uniffi_visio_ffi_fn_func_build_invite_text(
+        FfiConverterTypeInviteInfo_lower(info),
+        FfiConverterString.lower(language),
+        FfiConverterOptionInt64.lower(startsAtMs),
+        FfiConverterInt32.lower(utcOffsetMinutes),$0
+    )
+})
+}
+public func computeLayout(options: LayoutOptions) -> [TileRect]  {
+    return try!  FfiConverterSequenceTypeTileRect.lift(try! rustCall() {
+    uniffi_visio_ffi_fn_func_compute_layout(
+        FfiConverterTypeLayoutOptions_lower(options),$0
+    )
+})
+}
+public func generateAvatar(name: String, size: UInt32)throws  -> Data  {
+    return try  FfiConverterData.lift(try rustCallWithError(FfiConverterTypeVisioError_lift) {
+    uniffi_visio_ffi_fn_func_generate_avatar(
+        FfiConverterString.lower(name),
+        FfiConverterUInt32.lower(size),$0
+    )
+})
+}
+public func generateRoomQr(url: String, size: UInt32)throws  -> Data  {
+    return try  FfiConverterData.lift(try rustCallWithError(FfiConverterTypeVisioError_lift) {
+    uniffi_visio_ffi_fn_func_generate_room_qr(
+        FfiConverterString.lower(url),
+        FfiConverterUInt32.lower(size),$0
+    )
+})
+}
+public func initLogging()  {try! rustCall() {
+    uniffi_visio_ffi_fn_func_init_logging($0
+    )
+}
+}
+public func parseDeepLink(uri: String) -> DeepLink?  {
+    return try!  FfiConverterOptionTypeDeepLink.lift(try! rustCall() {
+    uniffi_visio_ffi_fn_func_parse_deep_link(
+        FfiConverterString.lower(uri),$0
+    )
+})
+}
+public func selfTest(dataDir: String) -> SelfTestReport  {
+    return try!  FfiConverterTypeSelfTestReport_lift(try! rustCall() {
+    uniffi_visio_ffi_fn_func_self_test(
+        FfiConverterString.lower(dataDir),$0
+    )
+})
+}
+public func setFileLogging(dataDir: String?)throws   {try rustCallWithError(FfiConverterTypeVisioError_lift) {
+    uniffi_visio_ffi_fn_func_set_file_logging(
+        FfiConverterOptionString.lower(dataDir),$0
+    )
+}
+}
+public func setLogLevel(level: String)throws   {try rustCallWithError(FfiConverterTypeVisioError_lift) {
+    uniffi_visio_ffi_fn_func_set_log_level(
+        FfiConverterString.lower(level),$0
+    )
+}
+}
+public func setLogTargets(filter: String)throws   {try rustCallWithError(FfiConverterTypeVisioError_lift) {
+    uniffi_visio_ffi_fn_func_set_log_targets(
+        FfiConverterString.lower(filter),$0
+    )
+}
+}
+
+private enum InitializationResult {
+    case ok
+    case contractVersionMismatch
+    case apiChecksumMismatch
+}
+// Use a global variable to perform the versioning checks. Swift ensures that
+// the code inside is only computed once.
+private let initializationResult: InitializationResult = {
+    // Get the bindings contract version from our ComponentInterface
+    let bindings_contract_version = 29
+    // Get the scaffolding contract version by calling the into the dylib
+    let scaffolding_contract_version = ffi_visio_ffi_uniffi_contract_version()
+    if bindings_contract_version != scaffolding_contract_version {
+        return InitializationResult.contractVersionMismatch
+    }
+    if (uniffi_visio_ffi_checksum_func_build_invite_text() != 31949) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_func_compute_layout() != 49806) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_func_generate_avatar() != 38660) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_func_generate_room_qr() != 5305) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_func_init_logging() != 52772) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_func_parse_deep_link() != 64830) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_func_self_test() != 39470) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_func_set_file_logging() != 50732) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_func_set_log_level() != 56850) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_func_set_log_targets() != 44016) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_active_speaker_hold_ms() != 56278) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_active_speakers() != 52633) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_add_camera_command_listener() != 22806) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_add_listener() != 29296) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_add_room_listener() != 13333) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_audio_preset() != 58772) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_audio_publish_options() != 30697) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_capture_snapshot() != 25143) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_chat_message_body() != 63978) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_chat_messages() != 46177) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_chat_unread() != 23975) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_clear_cache() != 17805) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_close_poll() != 65303) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_connect() != 63819) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_connect_async() != 56115) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_connect_timeouts() != 29099) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_connect_with_options() != 35202) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_connect_with_options_async() != 32224) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_connection_state() != 45301) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_create_poll() != 49254) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_disconnect() != 26842) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_disconnect_async() != 32858) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_dominant_speaker() != 19757) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_end_call() != 22879) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_export_chat() != 50241) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_export_journal() != 51781) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_fetch_avatar() != 17505) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_get_av_sync_stats() != 51266) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_get_background_mode() != 47158) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_get_cached_avatar() != 64281) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_get_cached_room_info() != 61232) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_get_camera_control_state() != 30043) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_get_capture_constraints() != 5941) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_get_extra() != 39988) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_get_feature_flags() != 29569) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_get_invite_info() != 50077) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_get_last_call_summary() != 23427) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_get_local_permissions() != 48565) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_get_meet_instances() != 1312) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_get_negotiated_codecs() != 37475) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_get_participant_attributes() != 30081) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_get_quality_history() != 32765) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_get_recording_status() != 15791) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_get_renderer_stats() != 64201) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_get_settings() != 24786) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_get_time_remaining() != 27812) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_get_video_stats() != 42193) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_get_whiteboard_session() != 63516) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_handle_media_button() != 52222) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_invite_participants() != 62360) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_is_camera_enabled() != 48358) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_is_hand_raised() != 47530) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_is_microphone_enabled() != 61728) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_is_output_muted() != 27422) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_is_room_locked() != 14608) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_is_video_privacy_enabled() != 33820) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_join() != 58182) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_join_leave_config() != 7399) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_join_options() != 332) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_layout_order() != 2674) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_leave() != 21232) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_load_background_image() != 6782) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_load_blur_model() != 27369) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_lock_room() != 59538) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_low_bandwidth_audio_options() != 35871) {
+        return InitializationResult.apiChecksumMismatch
     }
-
-#if swift(>=5.8)
-    @_documentation(visibility: private)
-#endif
-    public static func write(_ v: SwiftType, into buf: inout [UInt8]) {
-        writeInt(&buf, lower(v))
+    if (uniffi_visio_ffi_checksum_method_visioclient_lower_hand() != 7287) {
+        return InitializationResult.apiChecksumMismatch
     }
-}
-
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public func FfiConverterCallbackInterfaceVisioEventListener_lift(_ handle: UInt64) throws -> VisioEventListener {
-    return try FfiConverterCallbackInterfaceVisioEventListener.lift(handle)
-}
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-public func FfiConverterCallbackInterfaceVisioEventListener_lower(_ v: VisioEventListener) -> UInt64 {
-    return FfiConverterCallbackInterfaceVisioEventListener.lower(v)
-}
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-fileprivate struct FfiConverterOptionString: FfiConverterRustBuffer {
-    typealias SwiftType = String?
-
-    public static func write(_ value: SwiftType, into buf: inout [UInt8]) {
-        guard let value = value else {
-            writeInt(&buf, Int8(0))
-            return
-        }
-        writeInt(&buf, Int8(1))
-        FfiConverterString.write(value, into: &buf)
+    if (uniffi_visio_ffi_checksum_method_visioclient_mark_chat_read_up_to() != 53189) {
+        return InitializationResult.apiChecksumMismatch
     }
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> SwiftType {
-        switch try readInt(&buf) as Int8 {
-        case 0: return nil
-        case 1: return try FfiConverterString.read(from: &buf)
-        default: throw UniffiInternalError.unexpectedOptionalTag
-        }
+    if (uniffi_visio_ffi_checksum_method_visioclient_output_volume() != 60520) {
+        return InitializationResult.apiChecksumMismatch
     }
-}
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-fileprivate struct FfiConverterSequenceString: FfiConverterRustBuffer {
-    typealias SwiftType = [String]
-
-    public static func write(_ value: [String], into buf: inout [UInt8]) {
-        let len = Int32(value.count)
-        writeInt(&buf, len)
-        for item in value {
-            FfiConverterString.write(item, into: &buf)
-        }
+    if (uniffi_visio_ffi_checksum_method_visioclient_participant_snapshot() != 37064) {
+        return InitializationResult.apiChecksumMismatch
     }
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [String] {
-        let len: Int32 = try readInt(&buf)
-        var seq = [String]()
-        seq.reserveCapacity(Int(len))
-        for _ in 0 ..< len {
-            seq.append(try FfiConverterString.read(from: &buf))
-        }
-        return seq
+    if (uniffi_visio_ffi_checksum_method_visioclient_participants() != 42457) {
+        return InitializationResult.apiChecksumMismatch
     }
-}
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-fileprivate struct FfiConverterSequenceTypeChatMessage: FfiConverterRustBuffer {
-    typealias SwiftType = [ChatMessage]
-
-    public static func write(_ value: [ChatMessage], into buf: inout [UInt8]) {
-        let len = Int32(value.count)
-        writeInt(&buf, len)
-        for item in value {
-            FfiConverterTypeChatMessage.write(item, into: &buf)
-        }
+    if (uniffi_visio_ffi_checksum_method_visioclient_pending_media() != 64201) {
+        return InitializationResult.apiChecksumMismatch
     }
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [ChatMessage] {
-        let len: Int32 = try readInt(&buf)
-        var seq = [ChatMessage]()
-        seq.reserveCapacity(Int(len))
-        for _ in 0 ..< len {
-            seq.append(try FfiConverterTypeChatMessage.read(from: &buf))
-        }
-        return seq
+    if (uniffi_visio_ffi_checksum_method_visioclient_permission_state() != 48794) {
+        return InitializationResult.apiChecksumMismatch
     }
-}
-
-#if swift(>=5.8)
-@_documentation(visibility: private)
-#endif
-fileprivate struct FfiConverterSequenceTypeParticipantInfo: FfiConverterRustBuffer {
-    typealias SwiftType = [ParticipantInfo]
-
-    public static func write(_ value: [ParticipantInfo], into buf: inout [UInt8]) {
-        let len = Int32(value.count)
-        writeInt(&buf, len)
-        for item in value {
-            FfiConverterTypeParticipantInfo.write(item, into: &buf)
-        }
+    if (uniffi_visio_ffi_checksum_method_visioclient_pin_participant() != 53817) {
+        return InitializationResult.apiChecksumMismatch
     }
-
-    public static func read(from buf: inout (data: Data, offset: Data.Index)) throws -> [ParticipantInfo] {
-        let len: Int32 = try readInt(&buf)
-        var seq = [ParticipantInfo]()
-        seq.reserveCapacity(Int(len))
-        for _ in 0 ..< len {
-            seq.append(try FfiConverterTypeParticipantInfo.read(from: &buf))
-        }
-        return seq
+    if (uniffi_visio_ffi_checksum_method_visioclient_pinned_participants() != 603) {
+        return InitializationResult.apiChecksumMismatch
     }
-}
-public func initLogging()  {try! rustCall() {
-    uniffi_visio_ffi_fn_func_init_logging($0
-    )
-}
-}
-
-private enum InitializationResult {
-    case ok
-    case contractVersionMismatch
-    case apiChecksumMismatch
-}
-// Use a global variable to perform the versioning checks. Swift ensures that
-// the code inside is only computed once.
-private let initializationResult: InitializationResult = {
-    // Get the bindings contract version from our ComponentInterface
-    let bindings_contract_version = 29
-    // Get the scaffolding contract version by calling the into the dylib
-    let scaffolding_contract_version = ffi_visio_ffi_uniffi_contract_version()
-    if bindings_contract_version != scaffolding_contract_version {
-        return InitializationResult.contractVersionMismatch
+    if (uniffi_visio_ffi_checksum_method_visioclient_polls() != 56289) {
+        return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_visio_ffi_checksum_func_init_logging() != 52772) {
+    if (uniffi_visio_ffi_checksum_method_visioclient_power_mode() != 55997) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_visio_ffi_checksum_method_visioclient_active_speakers() != 15815) {
+    if (uniffi_visio_ffi_checksum_method_visioclient_presence() != 51109) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_visio_ffi_checksum_method_visioclient_add_listener() != 29296) {
+    if (uniffi_visio_ffi_checksum_method_visioclient_publish_data() != 44554) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_visio_ffi_checksum_method_visioclient_chat_messages() != 48857) {
+    if (uniffi_visio_ffi_checksum_method_visioclient_push_device_check_audio() != 15255) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_visio_ffi_checksum_method_visioclient_connect() != 34638) {
+    if (uniffi_visio_ffi_checksum_method_visioclient_queue_media_until_connected() != 20093) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_visio_ffi_checksum_method_visioclient_connection_state() != 20987) {
+    if (uniffi_visio_ffi_checksum_method_visioclient_raise_hand() != 32971) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_visio_ffi_checksum_method_visioclient_disconnect() != 52651) {
+    if (uniffi_visio_ffi_checksum_method_visioclient_reconnect() != 16195) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_visio_ffi_checksum_method_visioclient_get_background_mode() != 47158) {
+    if (uniffi_visio_ffi_checksum_method_visioclient_report_activity() != 57244) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_visio_ffi_checksum_method_visioclient_get_meet_instances() != 1312) {
+    if (uniffi_visio_ffi_checksum_method_visioclient_report_incoming_call() != 35253) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_visio_ffi_checksum_method_visioclient_get_settings() != 24786) {
+    if (uniffi_visio_ffi_checksum_method_visioclient_report_outgoing_call() != 12042) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_visio_ffi_checksum_method_visioclient_is_camera_enabled() != 23394) {
+    if (uniffi_visio_ffi_checksum_method_visioclient_report_thermal_state() != 2138) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_visio_ffi_checksum_method_visioclient_is_hand_raised() != 47789) {
+    if (uniffi_visio_ffi_checksum_method_visioclient_room_ids() != 24953) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_visio_ffi_checksum_method_visioclient_is_microphone_enabled() != 33466) {
+    if (uniffi_visio_ffi_checksum_method_visioclient_run_device_check() != 1873) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_visio_ffi_checksum_method_visioclient_load_background_image() != 6782) {
+    if (uniffi_visio_ffi_checksum_method_visioclient_save_chat_export() != 5679) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_visio_ffi_checksum_method_visioclient_load_blur_model() != 27369) {
+    if (uniffi_visio_ffi_checksum_method_visioclient_save_snapshot() != 19008) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_send_chat_message() != 37968) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_visio_ffi_checksum_method_visioclient_lower_hand() != 53728) {
+    if (uniffi_visio_ffi_checksum_method_visioclient_send_chat_message_async() != 4030) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_visio_ffi_checksum_method_visioclient_participants() != 38029) {
+    if (uniffi_visio_ffi_checksum_method_visioclient_send_reaction() != 54833) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_visio_ffi_checksum_method_visioclient_raise_hand() != 37998) {
+    if (uniffi_visio_ffi_checksum_method_visioclient_set_active_speaker_hold() != 13561) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_visio_ffi_checksum_method_visioclient_reconnect() != 64546) {
+    if (uniffi_visio_ffi_checksum_method_visioclient_set_audio_bitrate() != 29389) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_visio_ffi_checksum_method_visioclient_send_chat_message() != 33280) {
+    if (uniffi_visio_ffi_checksum_method_visioclient_set_audio_ducking() != 18478) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_visio_ffi_checksum_method_visioclient_send_reaction() != 6155) {
+    if (uniffi_visio_ffi_checksum_method_visioclient_set_audio_preset() != 18999) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_set_audio_publish_options() != 18143) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_set_auto_gain() != 22726) {
         return InitializationResult.apiChecksumMismatch
     }
     if (uniffi_visio_ffi_checksum_method_visioclient_set_background_mode() != 59805) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_visio_ffi_checksum_method_visioclient_set_camera_enabled() != 34139) {
+    if (uniffi_visio_ffi_checksum_method_visioclient_set_camera_enabled() != 47766) {
         return InitializationResult.apiChecksumMismatch
     }
     if (uniffi_visio_ffi_checksum_method_visioclient_set_camera_enabled_on_join() != 27341) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_visio_ffi_checksum_method_visioclient_set_chat_open() != 417) {
+    if (uniffi_visio_ffi_checksum_method_visioclient_set_chat_formatting() != 16734) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_set_chat_open() != 35691) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_set_connect_timeouts() != 30963) {
         return InitializationResult.apiChecksumMismatch
     }
     if (uniffi_visio_ffi_checksum_method_visioclient_set_display_name() != 36622) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_visio_ffi_checksum_method_visioclient_set_extra() != 11128) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_set_feedback_diagnostics_enabled() != 55365) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_set_focus_point() != 2886) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_set_hand_auto_lower() != 23157) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_set_idle_policy() != 65052) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_set_join_leave_config() != 42885) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_visio_ffi_checksum_method_visioclient_set_language() != 63924) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_visio_ffi_checksum_method_visioclient_set_local_attributes() != 39326) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_set_low_light_boost() != 36466) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_set_max_chat_message_bytes() != 7435) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_visio_ffi_checksum_method_visioclient_set_meet_instances() != 55021) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_visio_ffi_checksum_method_visioclient_set_metrics() != 47984) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_visio_ffi_checksum_method_visioclient_set_mic_enabled_on_join() != 39099) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_visio_ffi_checksum_method_visioclient_set_microphone_enabled() != 607) {
+    if (uniffi_visio_ffi_checksum_method_visioclient_set_microphone_enabled() != 33604) {
         return InitializationResult.apiChecksumMismatch
     }
     if (uniffi_visio_ffi_checksum_method_visioclient_set_notification_hand_raised() != 14515) {
@@ -2466,28 +10690,144 @@ private let initializationResult: InitializationResult = {
     if (uniffi_visio_ffi_checksum_method_visioclient_set_notification_participant_join() != 47125) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_visio_ffi_checksum_method_visioclient_set_output_muted() != 41588) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_set_output_volume() != 51289) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_set_permission_state() != 25913) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_set_power_mode() != 48151) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_set_preferred_camera_id() != 53988) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_set_preferred_microphone_id() != 46946) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_set_preferred_speaker_id() != 15132) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_set_presence() != 7320) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_set_quality_policy() != 22235) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_set_queue_media_until_connected() != 44876) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_set_rtc_config() != 62861) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_visio_ffi_checksum_method_visioclient_set_theme() != 58689) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_visio_ffi_checksum_method_visioclient_set_torch_enabled() != 10794) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_set_video_codec() != 59518) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_set_video_content_hint() != 18156) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_set_video_overlay() != 26606) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_set_video_overlay_image() != 9285) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_set_video_privacy() != 16984) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_set_video_privacy_style() != 631) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_set_visible_tracks() != 11481) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_set_zoom() != 29183) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_shutdown() != 57026) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_start_local_recording() != 21407) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_start_recording() != 45086) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_visio_ffi_checksum_method_visioclient_start_video_renderer() != 53000) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_visio_ffi_checksum_method_visioclient_stop_local_recording() != 41854) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_stop_recording() != 34780) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_visio_ffi_checksum_method_visioclient_stop_video_renderer() != 45318) {
         return InitializationResult.apiChecksumMismatch
     }
-    if (uniffi_visio_ffi_checksum_method_visioclient_unread_count() != 7178) {
+    if (uniffi_visio_ffi_checksum_method_visioclient_submit_feedback() != 62496) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_subscribe_data_topic() != 28748) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_switch_room() != 27731) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_system_call() != 49060) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_translate() != 41541) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_unpin_participant() != 12125) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_unread_count() != 57030) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_unsubscribe_data_topic() != 12220) {
         return InitializationResult.apiChecksumMismatch
     }
     if (uniffi_visio_ffi_checksum_method_visioclient_validate_room() != 14512) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_visio_ffi_checksum_method_visioclient_validate_room_async() != 62440) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_video_codec() != 55435) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_video_content_hint() != 34928) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_visioclient_vote_poll() != 56668) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_visio_ffi_checksum_constructor_visioclient_new() != 10250) {
         return InitializationResult.apiChecksumMismatch
     }
+    if (uniffi_visio_ffi_checksum_method_cameracommandlistener_on_camera_command() != 50347) {
+        return InitializationResult.apiChecksumMismatch
+    }
+    if (uniffi_visio_ffi_checksum_method_roomeventlistener_on_room_event() != 13925) {
+        return InitializationResult.apiChecksumMismatch
+    }
     if (uniffi_visio_ffi_checksum_method_visioeventlistener_on_event() != 7818) {
         return InitializationResult.apiChecksumMismatch
     }
 
+    uniffiCallbackInitCameraCommandListener()
+    uniffiCallbackInitRoomEventListener()
     uniffiCallbackInitVisioEventListener()
     return InitializationResult.ok
 }()
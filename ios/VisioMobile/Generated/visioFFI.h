@@ -250,6 +250,20 @@ typedef struct UniffiForeignFutureStructVoid {
 typedef void (*UniffiForeignFutureCompleteVoid)(uint64_t, UniffiForeignFutureStructVoid
     );
 
+#endif
+#ifndef UNIFFI_FFIDEF_CALLBACK_INTERFACE_CAMERA_COMMAND_LISTENER_METHOD0
+#define UNIFFI_FFIDEF_CALLBACK_INTERFACE_CAMERA_COMMAND_LISTENER_METHOD0
+typedef void (*UniffiCallbackInterfaceCameraCommandListenerMethod0)(uint64_t, RustBuffer, void* _Nonnull, 
+        RustCallStatus *_Nonnull uniffiCallStatus
+    );
+
+#endif
+#ifndef UNIFFI_FFIDEF_CALLBACK_INTERFACE_ROOM_EVENT_LISTENER_METHOD0
+#define UNIFFI_FFIDEF_CALLBACK_INTERFACE_ROOM_EVENT_LISTENER_METHOD0
+typedef void (*UniffiCallbackInterfaceRoomEventListenerMethod0)(uint64_t, RustBuffer, RustBuffer, void* _Nonnull, 
+        RustCallStatus *_Nonnull uniffiCallStatus
+    );
+
 #endif
 #ifndef UNIFFI_FFIDEF_CALLBACK_INTERFACE_VISIO_EVENT_LISTENER_METHOD0
 #define UNIFFI_FFIDEF_CALLBACK_INTERFACE_VISIO_EVENT_LISTENER_METHOD0
@@ -257,6 +271,22 @@ typedef void (*UniffiCallbackInterfaceVisioEventListenerMethod0)(uint64_t, RustB
         RustCallStatus *_Nonnull uniffiCallStatus
     );
 
+#endif
+#ifndef UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_CAMERA_COMMAND_LISTENER
+#define UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_CAMERA_COMMAND_LISTENER
+typedef struct UniffiVTableCallbackInterfaceCameraCommandListener {
+    UniffiCallbackInterfaceCameraCommandListenerMethod0 _Nonnull onCameraCommand;
+    UniffiCallbackInterfaceFree _Nonnull uniffiFree;
+} UniffiVTableCallbackInterfaceCameraCommandListener;
+
+#endif
+#ifndef UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_ROOM_EVENT_LISTENER
+#define UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_ROOM_EVENT_LISTENER
+typedef struct UniffiVTableCallbackInterfaceRoomEventListener {
+    UniffiCallbackInterfaceRoomEventListenerMethod0 _Nonnull onRoomEvent;
+    UniffiCallbackInterfaceFree _Nonnull uniffiFree;
+} UniffiVTableCallbackInterfaceRoomEventListener;
+
 #endif
 #ifndef UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_VISIO_EVENT_LISTENER
 #define UNIFFI_FFIDEF_V_TABLE_CALLBACK_INTERFACE_VISIO_EVENT_LISTENER
@@ -281,9 +311,19 @@ void uniffi_visio_ffi_fn_free_visioclient(void*_Nonnull ptr, RustCallStatus *_No
 void*_Nonnull uniffi_visio_ffi_fn_constructor_visioclient_new(RustBuffer data_dir, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_ACTIVE_SPEAKER_HOLD_MS
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_ACTIVE_SPEAKER_HOLD_MS
+uint64_t uniffi_visio_ffi_fn_method_visioclient_active_speaker_hold_ms(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_ACTIVE_SPEAKERS
 #define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_ACTIVE_SPEAKERS
-RustBuffer uniffi_visio_ffi_fn_method_visioclient_active_speakers(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_active_speakers(void*_Nonnull ptr, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_ADD_CAMERA_COMMAND_LISTENER
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_ADD_CAMERA_COMMAND_LISTENER
+void uniffi_visio_ffi_fn_method_visioclient_add_camera_command_listener(void*_Nonnull ptr, uint64_t listener, RustCallStatus *_Nonnull out_status
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_ADD_LISTENER
@@ -291,24 +331,124 @@ RustBuffer uniffi_visio_ffi_fn_method_visioclient_active_speakers(void*_Nonnull
 void uniffi_visio_ffi_fn_method_visioclient_add_listener(void*_Nonnull ptr, uint64_t listener, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_ADD_ROOM_LISTENER
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_ADD_ROOM_LISTENER
+void uniffi_visio_ffi_fn_method_visioclient_add_room_listener(void*_Nonnull ptr, uint64_t listener, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_AUDIO_PRESET
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_AUDIO_PRESET
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_audio_preset(void*_Nonnull ptr, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_AUDIO_PUBLISH_OPTIONS
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_AUDIO_PUBLISH_OPTIONS
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_audio_publish_options(void*_Nonnull ptr, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_CAPTURE_SNAPSHOT
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_CAPTURE_SNAPSHOT
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_capture_snapshot(void*_Nonnull ptr, RustBuffer track_sid, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_CHAT_MESSAGES
 #define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_CHAT_MESSAGES
-RustBuffer uniffi_visio_ffi_fn_method_visioclient_chat_messages(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_chat_messages(void*_Nonnull ptr, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_CHAT_MESSAGE_BODY
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_CHAT_MESSAGE_BODY
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_chat_message_body(void*_Nonnull ptr, RustBuffer text, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_CHAT_UNREAD
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_CHAT_UNREAD
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_chat_unread(void*_Nonnull ptr, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_CLEAR_CACHE
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_CLEAR_CACHE
+void uniffi_visio_ffi_fn_method_visioclient_clear_cache(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_CLOSE_POLL
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_CLOSE_POLL
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_close_poll(void*_Nonnull ptr, RustBuffer poll_id, RustBuffer room_id, RustCallStatus *_Nonnull out_status
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_CONNECT
 #define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_CONNECT
-void uniffi_visio_ffi_fn_method_visioclient_connect(void*_Nonnull ptr, RustBuffer meet_url, RustBuffer username, RustCallStatus *_Nonnull out_status
+void uniffi_visio_ffi_fn_method_visioclient_connect(void*_Nonnull ptr, RustBuffer meet_url, RustBuffer username, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_CONNECT_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_CONNECT_ASYNC
+uint64_t uniffi_visio_ffi_fn_method_visioclient_connect_async(void*_Nonnull ptr, RustBuffer meet_url, RustBuffer username, RustBuffer room_id
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_CONNECT_TIMEOUTS
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_CONNECT_TIMEOUTS
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_connect_timeouts(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_CONNECT_WITH_OPTIONS
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_CONNECT_WITH_OPTIONS
+void uniffi_visio_ffi_fn_method_visioclient_connect_with_options(void*_Nonnull ptr, RustBuffer meet_url, RustBuffer options, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_CONNECT_WITH_OPTIONS_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_CONNECT_WITH_OPTIONS_ASYNC
+uint64_t uniffi_visio_ffi_fn_method_visioclient_connect_with_options_async(void*_Nonnull ptr, RustBuffer meet_url, RustBuffer options, RustBuffer room_id
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_CONNECTION_STATE
 #define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_CONNECTION_STATE
-RustBuffer uniffi_visio_ffi_fn_method_visioclient_connection_state(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_connection_state(void*_Nonnull ptr, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_CREATE_POLL
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_CREATE_POLL
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_create_poll(void*_Nonnull ptr, RustBuffer question, RustBuffer options, RustBuffer room_id, RustCallStatus *_Nonnull out_status
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_DISCONNECT
 #define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_DISCONNECT
-void uniffi_visio_ffi_fn_method_visioclient_disconnect(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+void uniffi_visio_ffi_fn_method_visioclient_disconnect(void*_Nonnull ptr, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_DISCONNECT_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_DISCONNECT_ASYNC
+uint64_t uniffi_visio_ffi_fn_method_visioclient_disconnect_async(void*_Nonnull ptr, RustBuffer room_id
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_DOMINANT_SPEAKER
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_DOMINANT_SPEAKER
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_dominant_speaker(void*_Nonnull ptr, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_END_CALL
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_END_CALL
+void uniffi_visio_ffi_fn_method_visioclient_end_call(void*_Nonnull ptr, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_EXPORT_CHAT
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_EXPORT_CHAT
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_export_chat(void*_Nonnull ptr, RustBuffer format, RustBuffer language, int32_t utc_offset_minutes, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_EXPORT_JOURNAL
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_EXPORT_JOURNAL
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_export_journal(void*_Nonnull ptr, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_FETCH_AVATAR
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_FETCH_AVATAR
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_fetch_avatar(void*_Nonnull ptr, RustBuffer url, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_GET_AV_SYNC_STATS
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_GET_AV_SYNC_STATS
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_get_av_sync_stats(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_GET_BACKGROUND_MODE
@@ -316,29 +456,164 @@ void uniffi_visio_ffi_fn_method_visioclient_disconnect(void*_Nonnull ptr, RustCa
 RustBuffer uniffi_visio_ffi_fn_method_visioclient_get_background_mode(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_GET_CACHED_AVATAR
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_GET_CACHED_AVATAR
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_get_cached_avatar(void*_Nonnull ptr, RustBuffer url, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_GET_CACHED_ROOM_INFO
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_GET_CACHED_ROOM_INFO
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_get_cached_room_info(void*_Nonnull ptr, RustBuffer meet_url, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_GET_CAMERA_CONTROL_STATE
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_GET_CAMERA_CONTROL_STATE
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_get_camera_control_state(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_GET_CAPTURE_CONSTRAINTS
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_GET_CAPTURE_CONSTRAINTS
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_get_capture_constraints(void*_Nonnull ptr, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_GET_EXTRA
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_GET_EXTRA
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_get_extra(void*_Nonnull ptr, RustBuffer key, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_GET_FEATURE_FLAGS
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_GET_FEATURE_FLAGS
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_get_feature_flags(void*_Nonnull ptr, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_GET_INVITE_INFO
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_GET_INVITE_INFO
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_get_invite_info(void*_Nonnull ptr, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_GET_LAST_CALL_SUMMARY
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_GET_LAST_CALL_SUMMARY
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_get_last_call_summary(void*_Nonnull ptr, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_GET_LOCAL_PERMISSIONS
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_GET_LOCAL_PERMISSIONS
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_get_local_permissions(void*_Nonnull ptr, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_GET_MEET_INSTANCES
 #define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_GET_MEET_INSTANCES
 RustBuffer uniffi_visio_ffi_fn_method_visioclient_get_meet_instances(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_GET_NEGOTIATED_CODECS
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_GET_NEGOTIATED_CODECS
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_get_negotiated_codecs(void*_Nonnull ptr, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_GET_PARTICIPANT_ATTRIBUTES
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_GET_PARTICIPANT_ATTRIBUTES
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_get_participant_attributes(void*_Nonnull ptr, RustBuffer participant_sid, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_GET_QUALITY_HISTORY
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_GET_QUALITY_HISTORY
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_get_quality_history(void*_Nonnull ptr, RustBuffer track_sid, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_GET_RECORDING_STATUS
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_GET_RECORDING_STATUS
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_get_recording_status(void*_Nonnull ptr, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_GET_RENDERER_STATS
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_GET_RENDERER_STATS
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_get_renderer_stats(void*_Nonnull ptr, RustBuffer track_sid, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_GET_SETTINGS
 #define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_GET_SETTINGS
 RustBuffer uniffi_visio_ffi_fn_method_visioclient_get_settings(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_GET_TIME_REMAINING
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_GET_TIME_REMAINING
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_get_time_remaining(void*_Nonnull ptr, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_GET_VIDEO_STATS
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_GET_VIDEO_STATS
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_get_video_stats(void*_Nonnull ptr, RustBuffer track_sid, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_GET_WHITEBOARD_SESSION
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_GET_WHITEBOARD_SESSION
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_get_whiteboard_session(void*_Nonnull ptr, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_HANDLE_MEDIA_BUTTON
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_HANDLE_MEDIA_BUTTON
+int8_t uniffi_visio_ffi_fn_method_visioclient_handle_media_button(void*_Nonnull ptr, RustBuffer button, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_INVITE_PARTICIPANTS
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_INVITE_PARTICIPANTS
+void uniffi_visio_ffi_fn_method_visioclient_invite_participants(void*_Nonnull ptr, RustBuffer emails, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_IS_CAMERA_ENABLED
 #define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_IS_CAMERA_ENABLED
-int8_t uniffi_visio_ffi_fn_method_visioclient_is_camera_enabled(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+int8_t uniffi_visio_ffi_fn_method_visioclient_is_camera_enabled(void*_Nonnull ptr, RustBuffer room_id, RustCallStatus *_Nonnull out_status
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_IS_HAND_RAISED
 #define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_IS_HAND_RAISED
-int8_t uniffi_visio_ffi_fn_method_visioclient_is_hand_raised(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+int8_t uniffi_visio_ffi_fn_method_visioclient_is_hand_raised(void*_Nonnull ptr, RustBuffer room_id, RustCallStatus *_Nonnull out_status
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_IS_MICROPHONE_ENABLED
 #define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_IS_MICROPHONE_ENABLED
-int8_t uniffi_visio_ffi_fn_method_visioclient_is_microphone_enabled(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+int8_t uniffi_visio_ffi_fn_method_visioclient_is_microphone_enabled(void*_Nonnull ptr, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_IS_OUTPUT_MUTED
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_IS_OUTPUT_MUTED
+int8_t uniffi_visio_ffi_fn_method_visioclient_is_output_muted(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_IS_ROOM_LOCKED
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_IS_ROOM_LOCKED
+int8_t uniffi_visio_ffi_fn_method_visioclient_is_room_locked(void*_Nonnull ptr, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_IS_VIDEO_PRIVACY_ENABLED
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_IS_VIDEO_PRIVACY_ENABLED
+int8_t uniffi_visio_ffi_fn_method_visioclient_is_video_privacy_enabled(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_JOIN
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_JOIN
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_join(void*_Nonnull ptr, RustBuffer meet_url, RustBuffer username, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_JOIN_LEAVE_CONFIG
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_JOIN_LEAVE_CONFIG
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_join_leave_config(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_JOIN_OPTIONS
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_JOIN_OPTIONS
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_join_options(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_LAYOUT_ORDER
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_LAYOUT_ORDER
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_layout_order(void*_Nonnull ptr, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_LEAVE
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_LEAVE
+void uniffi_visio_ffi_fn_method_visioclient_leave(void*_Nonnull ptr, RustBuffer room_id, RustCallStatus *_Nonnull out_status
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_LOAD_BACKGROUND_IMAGE
@@ -351,34 +626,184 @@ void uniffi_visio_ffi_fn_method_visioclient_load_background_image(void*_Nonnull
 void uniffi_visio_ffi_fn_method_visioclient_load_blur_model(void*_Nonnull ptr, RustBuffer model_path, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_LOCK_ROOM
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_LOCK_ROOM
+void uniffi_visio_ffi_fn_method_visioclient_lock_room(void*_Nonnull ptr, int8_t locked, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_LOWER_HAND
 #define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_LOWER_HAND
-void uniffi_visio_ffi_fn_method_visioclient_lower_hand(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+void uniffi_visio_ffi_fn_method_visioclient_lower_hand(void*_Nonnull ptr, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_LOW_BANDWIDTH_AUDIO_OPTIONS
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_LOW_BANDWIDTH_AUDIO_OPTIONS
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_low_bandwidth_audio_options(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_MARK_CHAT_READ_UP_TO
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_MARK_CHAT_READ_UP_TO
+int8_t uniffi_visio_ffi_fn_method_visioclient_mark_chat_read_up_to(void*_Nonnull ptr, RustBuffer id, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_OUTPUT_VOLUME
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_OUTPUT_VOLUME
+float uniffi_visio_ffi_fn_method_visioclient_output_volume(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_PARTICIPANTS
 #define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_PARTICIPANTS
-RustBuffer uniffi_visio_ffi_fn_method_visioclient_participants(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_participants(void*_Nonnull ptr, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_PARTICIPANT_SNAPSHOT
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_PARTICIPANT_SNAPSHOT
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_participant_snapshot(void*_Nonnull ptr, RustBuffer participant_sid, uint32_t size, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_PENDING_MEDIA
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_PENDING_MEDIA
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_pending_media(void*_Nonnull ptr, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_PERMISSION_STATE
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_PERMISSION_STATE
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_permission_state(void*_Nonnull ptr, RustBuffer permission, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_PIN_PARTICIPANT
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_PIN_PARTICIPANT
+void uniffi_visio_ffi_fn_method_visioclient_pin_participant(void*_Nonnull ptr, RustBuffer participant_sid, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_PINNED_PARTICIPANTS
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_PINNED_PARTICIPANTS
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_pinned_participants(void*_Nonnull ptr, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_POLLS
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_POLLS
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_polls(void*_Nonnull ptr, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_POWER_MODE
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_POWER_MODE
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_power_mode(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_PRESENCE
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_PRESENCE
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_presence(void*_Nonnull ptr, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_PUBLISH_DATA
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_PUBLISH_DATA
+void uniffi_visio_ffi_fn_method_visioclient_publish_data(void*_Nonnull ptr, RustBuffer topic, RustBuffer payload, int8_t reliable, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_PUSH_DEVICE_CHECK_AUDIO
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_PUSH_DEVICE_CHECK_AUDIO
+void uniffi_visio_ffi_fn_method_visioclient_push_device_check_audio(void*_Nonnull ptr, RustBuffer samples, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_QUEUE_MEDIA_UNTIL_CONNECTED
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_QUEUE_MEDIA_UNTIL_CONNECTED
+int8_t uniffi_visio_ffi_fn_method_visioclient_queue_media_until_connected(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_RAISE_HAND
 #define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_RAISE_HAND
-void uniffi_visio_ffi_fn_method_visioclient_raise_hand(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+void uniffi_visio_ffi_fn_method_visioclient_raise_hand(void*_Nonnull ptr, RustBuffer room_id, RustCallStatus *_Nonnull out_status
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_RECONNECT
 #define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_RECONNECT
-void uniffi_visio_ffi_fn_method_visioclient_reconnect(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+void uniffi_visio_ffi_fn_method_visioclient_reconnect(void*_Nonnull ptr, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_REPORT_ACTIVITY
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_REPORT_ACTIVITY
+void uniffi_visio_ffi_fn_method_visioclient_report_activity(void*_Nonnull ptr, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_REPORT_INCOMING_CALL
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_REPORT_INCOMING_CALL
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_report_incoming_call(void*_Nonnull ptr, RustBuffer room, RustBuffer display_name, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_REPORT_OUTGOING_CALL
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_REPORT_OUTGOING_CALL
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_report_outgoing_call(void*_Nonnull ptr, RustBuffer room, RustBuffer display_name, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_REPORT_THERMAL_STATE
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_REPORT_THERMAL_STATE
+void uniffi_visio_ffi_fn_method_visioclient_report_thermal_state(void*_Nonnull ptr, RustBuffer state, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_ROOM_IDS
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_ROOM_IDS
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_room_ids(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_RUN_DEVICE_CHECK
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_RUN_DEVICE_CHECK
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_run_device_check(void*_Nonnull ptr, uint32_t window_ms, RustBuffer speaker_error, int8_t check_camera, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SAVE_CHAT_EXPORT
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SAVE_CHAT_EXPORT
+void uniffi_visio_ffi_fn_method_visioclient_save_chat_export(void*_Nonnull ptr, RustBuffer format, RustBuffer language, int32_t utc_offset_minutes, RustBuffer path, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SAVE_SNAPSHOT
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SAVE_SNAPSHOT
+void uniffi_visio_ffi_fn_method_visioclient_save_snapshot(void*_Nonnull ptr, RustBuffer track_sid, RustBuffer path, RustCallStatus *_Nonnull out_status
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SEND_CHAT_MESSAGE
 #define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SEND_CHAT_MESSAGE
-RustBuffer uniffi_visio_ffi_fn_method_visioclient_send_chat_message(void*_Nonnull ptr, RustBuffer text, RustCallStatus *_Nonnull out_status
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_send_chat_message(void*_Nonnull ptr, RustBuffer text, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SEND_CHAT_MESSAGE_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SEND_CHAT_MESSAGE_ASYNC
+uint64_t uniffi_visio_ffi_fn_method_visioclient_send_chat_message_async(void*_Nonnull ptr, RustBuffer text, RustBuffer room_id
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SEND_REACTION
 #define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SEND_REACTION
-void uniffi_visio_ffi_fn_method_visioclient_send_reaction(void*_Nonnull ptr, RustBuffer emoji, RustCallStatus *_Nonnull out_status
+void uniffi_visio_ffi_fn_method_visioclient_send_reaction(void*_Nonnull ptr, RustBuffer emoji, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_ACTIVE_SPEAKER_HOLD
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_ACTIVE_SPEAKER_HOLD
+void uniffi_visio_ffi_fn_method_visioclient_set_active_speaker_hold(void*_Nonnull ptr, uint64_t hold_ms, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_AUDIO_BITRATE
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_AUDIO_BITRATE
+void uniffi_visio_ffi_fn_method_visioclient_set_audio_bitrate(void*_Nonnull ptr, uint32_t bps, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_AUDIO_DUCKING
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_AUDIO_DUCKING
+void uniffi_visio_ffi_fn_method_visioclient_set_audio_ducking(void*_Nonnull ptr, int8_t enabled, float level, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_AUDIO_PRESET
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_AUDIO_PRESET
+void uniffi_visio_ffi_fn_method_visioclient_set_audio_preset(void*_Nonnull ptr, RustBuffer preset, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_AUDIO_PUBLISH_OPTIONS
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_AUDIO_PUBLISH_OPTIONS
+void uniffi_visio_ffi_fn_method_visioclient_set_audio_publish_options(void*_Nonnull ptr, RustBuffer options, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_AUTO_GAIN
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_AUTO_GAIN
+void uniffi_visio_ffi_fn_method_visioclient_set_auto_gain(void*_Nonnull ptr, int8_t enabled, float target_lufs, RustCallStatus *_Nonnull out_status
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_BACKGROUND_MODE
@@ -388,7 +813,7 @@ void uniffi_visio_ffi_fn_method_visioclient_set_background_mode(void*_Nonnull pt
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_CAMERA_ENABLED
 #define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_CAMERA_ENABLED
-void uniffi_visio_ffi_fn_method_visioclient_set_camera_enabled(void*_Nonnull ptr, int8_t enabled, RustCallStatus *_Nonnull out_status
+void uniffi_visio_ffi_fn_method_visioclient_set_camera_enabled(void*_Nonnull ptr, int8_t enabled, RustBuffer room_id, RustCallStatus *_Nonnull out_status
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_CAMERA_ENABLED_ON_JOIN
@@ -396,9 +821,19 @@ void uniffi_visio_ffi_fn_method_visioclient_set_camera_enabled(void*_Nonnull ptr
 void uniffi_visio_ffi_fn_method_visioclient_set_camera_enabled_on_join(void*_Nonnull ptr, int8_t enabled, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_CHAT_FORMATTING
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_CHAT_FORMATTING
+void uniffi_visio_ffi_fn_method_visioclient_set_chat_formatting(void*_Nonnull ptr, int8_t enabled, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_CHAT_OPEN
 #define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_CHAT_OPEN
-void uniffi_visio_ffi_fn_method_visioclient_set_chat_open(void*_Nonnull ptr, int8_t open, RustCallStatus *_Nonnull out_status
+void uniffi_visio_ffi_fn_method_visioclient_set_chat_open(void*_Nonnull ptr, int8_t open, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_CONNECT_TIMEOUTS
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_CONNECT_TIMEOUTS
+void uniffi_visio_ffi_fn_method_visioclient_set_connect_timeouts(void*_Nonnull ptr, RustBuffer timeouts, RustCallStatus *_Nonnull out_status
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_DISPLAY_NAME
@@ -406,16 +841,66 @@ void uniffi_visio_ffi_fn_method_visioclient_set_chat_open(void*_Nonnull ptr, int
 void uniffi_visio_ffi_fn_method_visioclient_set_display_name(void*_Nonnull ptr, RustBuffer name, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_EXTRA
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_EXTRA
+void uniffi_visio_ffi_fn_method_visioclient_set_extra(void*_Nonnull ptr, RustBuffer key, RustBuffer value, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_FEEDBACK_DIAGNOSTICS_ENABLED
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_FEEDBACK_DIAGNOSTICS_ENABLED
+void uniffi_visio_ffi_fn_method_visioclient_set_feedback_diagnostics_enabled(void*_Nonnull ptr, int8_t enabled, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_FOCUS_POINT
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_FOCUS_POINT
+void uniffi_visio_ffi_fn_method_visioclient_set_focus_point(void*_Nonnull ptr, float x, float y, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_HAND_AUTO_LOWER
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_HAND_AUTO_LOWER
+void uniffi_visio_ffi_fn_method_visioclient_set_hand_auto_lower(void*_Nonnull ptr, int8_t enabled, uint32_t delay_secs, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_IDLE_POLICY
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_IDLE_POLICY
+void uniffi_visio_ffi_fn_method_visioclient_set_idle_policy(void*_Nonnull ptr, uint32_t alone_timeout_mins, int8_t auto_leave_when_alone, uint32_t inactivity_timeout_mins, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_JOIN_LEAVE_CONFIG
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_JOIN_LEAVE_CONFIG
+void uniffi_visio_ffi_fn_method_visioclient_set_join_leave_config(void*_Nonnull ptr, RustBuffer config, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_LANGUAGE
 #define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_LANGUAGE
 void uniffi_visio_ffi_fn_method_visioclient_set_language(void*_Nonnull ptr, RustBuffer lang, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_LOCAL_ATTRIBUTES
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_LOCAL_ATTRIBUTES
+void uniffi_visio_ffi_fn_method_visioclient_set_local_attributes(void*_Nonnull ptr, RustBuffer attributes, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_LOW_LIGHT_BOOST
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_LOW_LIGHT_BOOST
+void uniffi_visio_ffi_fn_method_visioclient_set_low_light_boost(void*_Nonnull ptr, int8_t enabled, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_MAX_CHAT_MESSAGE_BYTES
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_MAX_CHAT_MESSAGE_BYTES
+void uniffi_visio_ffi_fn_method_visioclient_set_max_chat_message_bytes(void*_Nonnull ptr, uint64_t max_bytes, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_MEET_INSTANCES
 #define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_MEET_INSTANCES
 void uniffi_visio_ffi_fn_method_visioclient_set_meet_instances(void*_Nonnull ptr, RustBuffer instances, RustCallStatus *_Nonnull out_status
 );
 #endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_METRICS
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_METRICS
+void uniffi_visio_ffi_fn_method_visioclient_set_metrics(void*_Nonnull ptr, int8_t enabled, RustBuffer endpoint, RustCallStatus *_Nonnull out_status
+);
+#endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_MIC_ENABLED_ON_JOIN
 #define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_MIC_ENABLED_ON_JOIN
 void uniffi_visio_ffi_fn_method_visioclient_set_mic_enabled_on_join(void*_Nonnull ptr, int8_t enabled, RustCallStatus *_Nonnull out_status
@@ -423,7 +908,7 @@ void uniffi_visio_ffi_fn_method_visioclient_set_mic_enabled_on_join(void*_Nonnul
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_MICROPHONE_ENABLED
 #define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_MICROPHONE_ENABLED
-void uniffi_visio_ffi_fn_method_visioclient_set_microphone_enabled(void*_Nonnull ptr, int8_t enabled, RustCallStatus *_Nonnull out_status
+void uniffi_visio_ffi_fn_method_visioclient_set_microphone_enabled(void*_Nonnull ptr, int8_t enabled, RustBuffer room_id, RustCallStatus *_Nonnull out_status
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_NOTIFICATION_HAND_RAISED
@@ -441,100 +926,335 @@ void uniffi_visio_ffi_fn_method_visioclient_set_notification_message_received(vo
 void uniffi_visio_ffi_fn_method_visioclient_set_notification_participant_join(void*_Nonnull ptr, int8_t enabled, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_THEME
-#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_THEME
-void uniffi_visio_ffi_fn_method_visioclient_set_theme(void*_Nonnull ptr, RustBuffer theme, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_OUTPUT_MUTED
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_OUTPUT_MUTED
+void uniffi_visio_ffi_fn_method_visioclient_set_output_muted(void*_Nonnull ptr, int8_t muted, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_START_VIDEO_RENDERER
-#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_START_VIDEO_RENDERER
-void uniffi_visio_ffi_fn_method_visioclient_start_video_renderer(void*_Nonnull ptr, RustBuffer track_sid, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_OUTPUT_VOLUME
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_OUTPUT_VOLUME
+void uniffi_visio_ffi_fn_method_visioclient_set_output_volume(void*_Nonnull ptr, float volume, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_STOP_VIDEO_RENDERER
-#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_STOP_VIDEO_RENDERER
-void uniffi_visio_ffi_fn_method_visioclient_stop_video_renderer(void*_Nonnull ptr, RustBuffer track_sid, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_PERMISSION_STATE
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_PERMISSION_STATE
+void uniffi_visio_ffi_fn_method_visioclient_set_permission_state(void*_Nonnull ptr, RustBuffer permission, RustBuffer state, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_UNREAD_COUNT
-#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_UNREAD_COUNT
-uint32_t uniffi_visio_ffi_fn_method_visioclient_unread_count(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_POWER_MODE
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_POWER_MODE
+void uniffi_visio_ffi_fn_method_visioclient_set_power_mode(void*_Nonnull ptr, RustBuffer mode, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_VALIDATE_ROOM
-#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_VALIDATE_ROOM
-RustBuffer uniffi_visio_ffi_fn_method_visioclient_validate_room(void*_Nonnull ptr, RustBuffer url, RustBuffer username, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_PREFERRED_CAMERA_ID
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_PREFERRED_CAMERA_ID
+void uniffi_visio_ffi_fn_method_visioclient_set_preferred_camera_id(void*_Nonnull ptr, RustBuffer id, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_INIT_CALLBACK_VTABLE_VISIOEVENTLISTENER
-#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_INIT_CALLBACK_VTABLE_VISIOEVENTLISTENER
-void uniffi_visio_ffi_fn_init_callback_vtable_visioeventlistener(const UniffiVTableCallbackInterfaceVisioEventListener* _Nonnull vtable
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_PREFERRED_MICROPHONE_ID
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_PREFERRED_MICROPHONE_ID
+void uniffi_visio_ffi_fn_method_visioclient_set_preferred_microphone_id(void*_Nonnull ptr, RustBuffer id, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_FUNC_INIT_LOGGING
-#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_FUNC_INIT_LOGGING
-void uniffi_visio_ffi_fn_func_init_logging(RustCallStatus *_Nonnull out_status
-    
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_PREFERRED_SPEAKER_ID
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_PREFERRED_SPEAKER_ID
+void uniffi_visio_ffi_fn_method_visioclient_set_preferred_speaker_id(void*_Nonnull ptr, RustBuffer id, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_VISIO_FFI_RUSTBUFFER_ALLOC
-#define UNIFFI_FFIDEF_FFI_VISIO_FFI_RUSTBUFFER_ALLOC
-RustBuffer ffi_visio_ffi_rustbuffer_alloc(uint64_t size, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_PRESENCE
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_PRESENCE
+void uniffi_visio_ffi_fn_method_visioclient_set_presence(void*_Nonnull ptr, RustBuffer presence, RustBuffer room_id, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_VISIO_FFI_RUSTBUFFER_FROM_BYTES
-#define UNIFFI_FFIDEF_FFI_VISIO_FFI_RUSTBUFFER_FROM_BYTES
-RustBuffer ffi_visio_ffi_rustbuffer_from_bytes(ForeignBytes bytes, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_QUALITY_POLICY
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_QUALITY_POLICY
+void uniffi_visio_ffi_fn_method_visioclient_set_quality_policy(void*_Nonnull ptr, int8_t enabled, uint32_t delay_secs, int8_t pause_remote_video, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_VISIO_FFI_RUSTBUFFER_FREE
-#define UNIFFI_FFIDEF_FFI_VISIO_FFI_RUSTBUFFER_FREE
-void ffi_visio_ffi_rustbuffer_free(RustBuffer buf, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_QUEUE_MEDIA_UNTIL_CONNECTED
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_QUEUE_MEDIA_UNTIL_CONNECTED
+void uniffi_visio_ffi_fn_method_visioclient_set_queue_media_until_connected(void*_Nonnull ptr, int8_t enabled, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_VISIO_FFI_RUSTBUFFER_RESERVE
-#define UNIFFI_FFIDEF_FFI_VISIO_FFI_RUSTBUFFER_RESERVE
-RustBuffer ffi_visio_ffi_rustbuffer_reserve(RustBuffer buf, uint64_t additional, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_RTC_CONFIG
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_RTC_CONFIG
+void uniffi_visio_ffi_fn_method_visioclient_set_rtc_config(void*_Nonnull ptr, RustBuffer config, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_VISIO_FFI_RUST_FUTURE_POLL_U8
-#define UNIFFI_FFIDEF_FFI_VISIO_FFI_RUST_FUTURE_POLL_U8
-void ffi_visio_ffi_rust_future_poll_u8(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_THEME
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_THEME
+void uniffi_visio_ffi_fn_method_visioclient_set_theme(void*_Nonnull ptr, RustBuffer theme, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_VISIO_FFI_RUST_FUTURE_CANCEL_U8
-#define UNIFFI_FFIDEF_FFI_VISIO_FFI_RUST_FUTURE_CANCEL_U8
-void ffi_visio_ffi_rust_future_cancel_u8(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_TORCH_ENABLED
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_TORCH_ENABLED
+void uniffi_visio_ffi_fn_method_visioclient_set_torch_enabled(void*_Nonnull ptr, int8_t enabled, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_VISIO_FFI_RUST_FUTURE_FREE_U8
-#define UNIFFI_FFIDEF_FFI_VISIO_FFI_RUST_FUTURE_FREE_U8
-void ffi_visio_ffi_rust_future_free_u8(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_VIDEO_CODEC
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_VIDEO_CODEC
+void uniffi_visio_ffi_fn_method_visioclient_set_video_codec(void*_Nonnull ptr, RustBuffer codec, RustBuffer room_id, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_VISIO_FFI_RUST_FUTURE_COMPLETE_U8
-#define UNIFFI_FFIDEF_FFI_VISIO_FFI_RUST_FUTURE_COMPLETE_U8
-uint8_t ffi_visio_ffi_rust_future_complete_u8(uint64_t handle, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_VIDEO_CONTENT_HINT
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_VIDEO_CONTENT_HINT
+void uniffi_visio_ffi_fn_method_visioclient_set_video_content_hint(void*_Nonnull ptr, RustBuffer hint, RustBuffer room_id, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_VISIO_FFI_RUST_FUTURE_POLL_I8
-#define UNIFFI_FFIDEF_FFI_VISIO_FFI_RUST_FUTURE_POLL_I8
-void ffi_visio_ffi_rust_future_poll_i8(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_VIDEO_OVERLAY
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_VIDEO_OVERLAY
+void uniffi_visio_ffi_fn_method_visioclient_set_video_overlay(void*_Nonnull ptr, RustBuffer text, RustBuffer corner, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_VISIO_FFI_RUST_FUTURE_CANCEL_I8
-#define UNIFFI_FFIDEF_FFI_VISIO_FFI_RUST_FUTURE_CANCEL_I8
-void ffi_visio_ffi_rust_future_cancel_i8(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_VIDEO_OVERLAY_IMAGE
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_VIDEO_OVERLAY_IMAGE
+void uniffi_visio_ffi_fn_method_visioclient_set_video_overlay_image(void*_Nonnull ptr, RustBuffer png_path, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_VISIO_FFI_RUST_FUTURE_FREE_I8
-#define UNIFFI_FFIDEF_FFI_VISIO_FFI_RUST_FUTURE_FREE_I8
-void ffi_visio_ffi_rust_future_free_i8(uint64_t handle
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_VIDEO_PRIVACY
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_VIDEO_PRIVACY
+void uniffi_visio_ffi_fn_method_visioclient_set_video_privacy(void*_Nonnull ptr, int8_t enabled, RustBuffer room_id, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_FFI_VISIO_FFI_RUST_FUTURE_COMPLETE_I8
-#define UNIFFI_FFIDEF_FFI_VISIO_FFI_RUST_FUTURE_COMPLETE_I8
-int8_t ffi_visio_ffi_rust_future_complete_i8(uint64_t handle, RustCallStatus *_Nonnull out_status
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_VIDEO_PRIVACY_STYLE
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_VIDEO_PRIVACY_STYLE
+void uniffi_visio_ffi_fn_method_visioclient_set_video_privacy_style(void*_Nonnull ptr, RustBuffer style, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_VISIBLE_TRACKS
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_VISIBLE_TRACKS
+void uniffi_visio_ffi_fn_method_visioclient_set_visible_tracks(void*_Nonnull ptr, RustBuffer track_sids, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_ZOOM
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SET_ZOOM
+void uniffi_visio_ffi_fn_method_visioclient_set_zoom(void*_Nonnull ptr, float factor, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SHUTDOWN
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SHUTDOWN
+void uniffi_visio_ffi_fn_method_visioclient_shutdown(void*_Nonnull ptr, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_START_LOCAL_RECORDING
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_START_LOCAL_RECORDING
+void uniffi_visio_ffi_fn_method_visioclient_start_local_recording(void*_Nonnull ptr, RustBuffer path, RustBuffer options, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_START_RECORDING
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_START_RECORDING
+void uniffi_visio_ffi_fn_method_visioclient_start_recording(void*_Nonnull ptr, RustBuffer mode, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_START_VIDEO_RENDERER
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_START_VIDEO_RENDERER
+void uniffi_visio_ffi_fn_method_visioclient_start_video_renderer(void*_Nonnull ptr, RustBuffer track_sid, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_STOP_LOCAL_RECORDING
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_STOP_LOCAL_RECORDING
+uint64_t uniffi_visio_ffi_fn_method_visioclient_stop_local_recording(void*_Nonnull ptr, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_STOP_RECORDING
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_STOP_RECORDING
+void uniffi_visio_ffi_fn_method_visioclient_stop_recording(void*_Nonnull ptr, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_STOP_VIDEO_RENDERER
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_STOP_VIDEO_RENDERER
+void uniffi_visio_ffi_fn_method_visioclient_stop_video_renderer(void*_Nonnull ptr, RustBuffer track_sid, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SUBMIT_FEEDBACK
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SUBMIT_FEEDBACK
+void uniffi_visio_ffi_fn_method_visioclient_submit_feedback(void*_Nonnull ptr, uint8_t rating, RustBuffer comments, int8_t attach_logs, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SUBSCRIBE_DATA_TOPIC
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SUBSCRIBE_DATA_TOPIC
+void uniffi_visio_ffi_fn_method_visioclient_subscribe_data_topic(void*_Nonnull ptr, RustBuffer topic, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SWITCH_ROOM
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SWITCH_ROOM
+void uniffi_visio_ffi_fn_method_visioclient_switch_room(void*_Nonnull ptr, RustBuffer meet_url, RustBuffer username, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SYSTEM_CALL
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_SYSTEM_CALL
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_system_call(void*_Nonnull ptr, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_TRANSLATE
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_TRANSLATE
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_translate(void*_Nonnull ptr, RustBuffer key, RustBuffer args, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_UNPIN_PARTICIPANT
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_UNPIN_PARTICIPANT
+void uniffi_visio_ffi_fn_method_visioclient_unpin_participant(void*_Nonnull ptr, RustBuffer participant_sid, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_UNREAD_COUNT
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_UNREAD_COUNT
+uint32_t uniffi_visio_ffi_fn_method_visioclient_unread_count(void*_Nonnull ptr, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_UNSUBSCRIBE_DATA_TOPIC
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_UNSUBSCRIBE_DATA_TOPIC
+void uniffi_visio_ffi_fn_method_visioclient_unsubscribe_data_topic(void*_Nonnull ptr, RustBuffer topic, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_VALIDATE_ROOM
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_VALIDATE_ROOM
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_validate_room(void*_Nonnull ptr, RustBuffer url, RustBuffer username, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_VALIDATE_ROOM_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_VALIDATE_ROOM_ASYNC
+uint64_t uniffi_visio_ffi_fn_method_visioclient_validate_room_async(void*_Nonnull ptr, RustBuffer url, RustBuffer username
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_VIDEO_CODEC
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_VIDEO_CODEC
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_video_codec(void*_Nonnull ptr, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_VIDEO_CONTENT_HINT
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_VIDEO_CONTENT_HINT
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_video_content_hint(void*_Nonnull ptr, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_VOTE_POLL
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_METHOD_VISIOCLIENT_VOTE_POLL
+RustBuffer uniffi_visio_ffi_fn_method_visioclient_vote_poll(void*_Nonnull ptr, RustBuffer poll_id, uint32_t option, RustBuffer room_id, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_INIT_CALLBACK_VTABLE_CAMERACOMMANDLISTENER
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_INIT_CALLBACK_VTABLE_CAMERACOMMANDLISTENER
+void uniffi_visio_ffi_fn_init_callback_vtable_cameracommandlistener(const UniffiVTableCallbackInterfaceCameraCommandListener* _Nonnull vtable
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_INIT_CALLBACK_VTABLE_ROOMEVENTLISTENER
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_INIT_CALLBACK_VTABLE_ROOMEVENTLISTENER
+void uniffi_visio_ffi_fn_init_callback_vtable_roomeventlistener(const UniffiVTableCallbackInterfaceRoomEventListener* _Nonnull vtable
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_INIT_CALLBACK_VTABLE_VISIOEVENTLISTENER
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_INIT_CALLBACK_VTABLE_VISIOEVENTLISTENER
+void uniffi_visio_ffi_fn_init_callback_vtable_visioeventlistener(const UniffiVTableCallbackInterfaceVisioEventListener* _Nonnull vtable
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_FUNC_BUILD_INVITE_TEXT
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_FUNC_BUILD_INVITE_TEXT
+RustBuffer uniffi_visio_ffi_fn_func_build_invite_text(RustBuffer info, RustBuffer language, RustBuffer starts_at_ms, int32_t utc_offset_minutes, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_FUNC_COMPUTE_LAYOUT
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_FUNC_COMPUTE_LAYOUT
+RustBuffer uniffi_visio_ffi_fn_func_compute_layout(RustBuffer options, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_FUNC_GENERATE_AVATAR
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_FUNC_GENERATE_AVATAR
+RustBuffer uniffi_visio_ffi_fn_func_generate_avatar(RustBuffer name, uint32_t size, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_FUNC_GENERATE_ROOM_QR
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_FUNC_GENERATE_ROOM_QR
+RustBuffer uniffi_visio_ffi_fn_func_generate_room_qr(RustBuffer url, uint32_t size, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_FUNC_INIT_LOGGING
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_FUNC_INIT_LOGGING
+void uniffi_visio_ffi_fn_func_init_logging(RustCallStatus *_Nonnull out_status
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_FUNC_PARSE_DEEP_LINK
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_FUNC_PARSE_DEEP_LINK
+RustBuffer uniffi_visio_ffi_fn_func_parse_deep_link(RustBuffer uri, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_FUNC_SELF_TEST
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_FUNC_SELF_TEST
+RustBuffer uniffi_visio_ffi_fn_func_self_test(RustBuffer data_dir, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_FUNC_SET_FILE_LOGGING
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_FUNC_SET_FILE_LOGGING
+void uniffi_visio_ffi_fn_func_set_file_logging(RustBuffer data_dir, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_FUNC_SET_LOG_LEVEL
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_FUNC_SET_LOG_LEVEL
+void uniffi_visio_ffi_fn_func_set_log_level(RustBuffer level, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_FUNC_SET_LOG_TARGETS
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_FN_FUNC_SET_LOG_TARGETS
+void uniffi_visio_ffi_fn_func_set_log_targets(RustBuffer filter, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_VISIO_FFI_RUSTBUFFER_ALLOC
+#define UNIFFI_FFIDEF_FFI_VISIO_FFI_RUSTBUFFER_ALLOC
+RustBuffer ffi_visio_ffi_rustbuffer_alloc(uint64_t size, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_VISIO_FFI_RUSTBUFFER_FROM_BYTES
+#define UNIFFI_FFIDEF_FFI_VISIO_FFI_RUSTBUFFER_FROM_BYTES
+RustBuffer ffi_visio_ffi_rustbuffer_from_bytes(ForeignBytes bytes, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_VISIO_FFI_RUSTBUFFER_FREE
+#define UNIFFI_FFIDEF_FFI_VISIO_FFI_RUSTBUFFER_FREE
+void ffi_visio_ffi_rustbuffer_free(RustBuffer buf, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_VISIO_FFI_RUSTBUFFER_RESERVE
+#define UNIFFI_FFIDEF_FFI_VISIO_FFI_RUSTBUFFER_RESERVE
+RustBuffer ffi_visio_ffi_rustbuffer_reserve(RustBuffer buf, uint64_t additional, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_VISIO_FFI_RUST_FUTURE_POLL_U8
+#define UNIFFI_FFIDEF_FFI_VISIO_FFI_RUST_FUTURE_POLL_U8
+void ffi_visio_ffi_rust_future_poll_u8(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_VISIO_FFI_RUST_FUTURE_CANCEL_U8
+#define UNIFFI_FFIDEF_FFI_VISIO_FFI_RUST_FUTURE_CANCEL_U8
+void ffi_visio_ffi_rust_future_cancel_u8(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_VISIO_FFI_RUST_FUTURE_FREE_U8
+#define UNIFFI_FFIDEF_FFI_VISIO_FFI_RUST_FUTURE_FREE_U8
+void ffi_visio_ffi_rust_future_free_u8(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_VISIO_FFI_RUST_FUTURE_COMPLETE_U8
+#define UNIFFI_FFIDEF_FFI_VISIO_FFI_RUST_FUTURE_COMPLETE_U8
+uint8_t ffi_visio_ffi_rust_future_complete_u8(uint64_t handle, RustCallStatus *_Nonnull out_status
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_VISIO_FFI_RUST_FUTURE_POLL_I8
+#define UNIFFI_FFIDEF_FFI_VISIO_FFI_RUST_FUTURE_POLL_I8
+void ffi_visio_ffi_rust_future_poll_i8(uint64_t handle, UniffiRustFutureContinuationCallback _Nonnull callback, uint64_t callback_data
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_VISIO_FFI_RUST_FUTURE_CANCEL_I8
+#define UNIFFI_FFIDEF_FFI_VISIO_FFI_RUST_FUTURE_CANCEL_I8
+void ffi_visio_ffi_rust_future_cancel_i8(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_VISIO_FFI_RUST_FUTURE_FREE_I8
+#define UNIFFI_FFIDEF_FFI_VISIO_FFI_RUST_FUTURE_FREE_I8
+void ffi_visio_ffi_rust_future_free_i8(uint64_t handle
+);
+#endif
+#ifndef UNIFFI_FFIDEF_FFI_VISIO_FFI_RUST_FUTURE_COMPLETE_I8
+#define UNIFFI_FFIDEF_FFI_VISIO_FFI_RUST_FUTURE_COMPLETE_I8
+int8_t ffi_visio_ffi_rust_future_complete_i8(uint64_t handle, RustCallStatus *_Nonnull out_status
 );
 #endif
 #ifndef UNIFFI_FFIDEF_FFI_VISIO_FFI_RUST_FUTURE_POLL_U16
@@ -757,183 +1477,783 @@ void ffi_visio_ffi_rust_future_free_void(uint64_t handle
 void ffi_visio_ffi_rust_future_complete_void(uint64_t handle, RustCallStatus *_Nonnull out_status
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_FUNC_INIT_LOGGING
-#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_FUNC_INIT_LOGGING
-uint16_t uniffi_visio_ffi_checksum_func_init_logging(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_FUNC_BUILD_INVITE_TEXT
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_FUNC_BUILD_INVITE_TEXT
+uint16_t uniffi_visio_ffi_checksum_func_build_invite_text(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_ACTIVE_SPEAKERS
-#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_ACTIVE_SPEAKERS
-uint16_t uniffi_visio_ffi_checksum_method_visioclient_active_speakers(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_FUNC_COMPUTE_LAYOUT
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_FUNC_COMPUTE_LAYOUT
+uint16_t uniffi_visio_ffi_checksum_func_compute_layout(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_ADD_LISTENER
-#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_ADD_LISTENER
-uint16_t uniffi_visio_ffi_checksum_method_visioclient_add_listener(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_FUNC_GENERATE_AVATAR
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_FUNC_GENERATE_AVATAR
+uint16_t uniffi_visio_ffi_checksum_func_generate_avatar(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_CHAT_MESSAGES
-#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_CHAT_MESSAGES
-uint16_t uniffi_visio_ffi_checksum_method_visioclient_chat_messages(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_FUNC_GENERATE_ROOM_QR
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_FUNC_GENERATE_ROOM_QR
+uint16_t uniffi_visio_ffi_checksum_func_generate_room_qr(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_CONNECT
-#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_CONNECT
-uint16_t uniffi_visio_ffi_checksum_method_visioclient_connect(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_FUNC_INIT_LOGGING
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_FUNC_INIT_LOGGING
+uint16_t uniffi_visio_ffi_checksum_func_init_logging(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_CONNECTION_STATE
-#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_CONNECTION_STATE
-uint16_t uniffi_visio_ffi_checksum_method_visioclient_connection_state(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_FUNC_PARSE_DEEP_LINK
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_FUNC_PARSE_DEEP_LINK
+uint16_t uniffi_visio_ffi_checksum_func_parse_deep_link(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_DISCONNECT
-#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_DISCONNECT
-uint16_t uniffi_visio_ffi_checksum_method_visioclient_disconnect(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_FUNC_SELF_TEST
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_FUNC_SELF_TEST
+uint16_t uniffi_visio_ffi_checksum_func_self_test(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_GET_BACKGROUND_MODE
-#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_GET_BACKGROUND_MODE
-uint16_t uniffi_visio_ffi_checksum_method_visioclient_get_background_mode(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_FUNC_SET_FILE_LOGGING
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_FUNC_SET_FILE_LOGGING
+uint16_t uniffi_visio_ffi_checksum_func_set_file_logging(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_GET_MEET_INSTANCES
-#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_GET_MEET_INSTANCES
-uint16_t uniffi_visio_ffi_checksum_method_visioclient_get_meet_instances(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_FUNC_SET_LOG_LEVEL
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_FUNC_SET_LOG_LEVEL
+uint16_t uniffi_visio_ffi_checksum_func_set_log_level(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_GET_SETTINGS
-#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_GET_SETTINGS
-uint16_t uniffi_visio_ffi_checksum_method_visioclient_get_settings(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_FUNC_SET_LOG_TARGETS
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_FUNC_SET_LOG_TARGETS
+uint16_t uniffi_visio_ffi_checksum_func_set_log_targets(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_IS_CAMERA_ENABLED
-#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_IS_CAMERA_ENABLED
-uint16_t uniffi_visio_ffi_checksum_method_visioclient_is_camera_enabled(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_ACTIVE_SPEAKER_HOLD_MS
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_ACTIVE_SPEAKER_HOLD_MS
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_active_speaker_hold_ms(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_IS_HAND_RAISED
-#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_IS_HAND_RAISED
-uint16_t uniffi_visio_ffi_checksum_method_visioclient_is_hand_raised(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_ACTIVE_SPEAKERS
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_ACTIVE_SPEAKERS
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_active_speakers(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_IS_MICROPHONE_ENABLED
-#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_IS_MICROPHONE_ENABLED
-uint16_t uniffi_visio_ffi_checksum_method_visioclient_is_microphone_enabled(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_ADD_CAMERA_COMMAND_LISTENER
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_ADD_CAMERA_COMMAND_LISTENER
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_add_camera_command_listener(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_LOAD_BACKGROUND_IMAGE
-#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_LOAD_BACKGROUND_IMAGE
-uint16_t uniffi_visio_ffi_checksum_method_visioclient_load_background_image(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_ADD_LISTENER
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_ADD_LISTENER
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_add_listener(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_LOAD_BLUR_MODEL
-#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_LOAD_BLUR_MODEL
-uint16_t uniffi_visio_ffi_checksum_method_visioclient_load_blur_model(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_ADD_ROOM_LISTENER
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_ADD_ROOM_LISTENER
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_add_room_listener(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_LOWER_HAND
-#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_LOWER_HAND
-uint16_t uniffi_visio_ffi_checksum_method_visioclient_lower_hand(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_AUDIO_PRESET
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_AUDIO_PRESET
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_audio_preset(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_PARTICIPANTS
-#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_PARTICIPANTS
-uint16_t uniffi_visio_ffi_checksum_method_visioclient_participants(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_AUDIO_PUBLISH_OPTIONS
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_AUDIO_PUBLISH_OPTIONS
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_audio_publish_options(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_RAISE_HAND
-#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_RAISE_HAND
-uint16_t uniffi_visio_ffi_checksum_method_visioclient_raise_hand(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_CAPTURE_SNAPSHOT
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_CAPTURE_SNAPSHOT
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_capture_snapshot(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_RECONNECT
-#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_RECONNECT
-uint16_t uniffi_visio_ffi_checksum_method_visioclient_reconnect(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_CHAT_MESSAGES
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_CHAT_MESSAGES
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_chat_messages(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SEND_CHAT_MESSAGE
-#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SEND_CHAT_MESSAGE
-uint16_t uniffi_visio_ffi_checksum_method_visioclient_send_chat_message(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_CHAT_MESSAGE_BODY
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_CHAT_MESSAGE_BODY
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_chat_message_body(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SEND_REACTION
-#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SEND_REACTION
-uint16_t uniffi_visio_ffi_checksum_method_visioclient_send_reaction(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_CHAT_UNREAD
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_CHAT_UNREAD
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_chat_unread(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_BACKGROUND_MODE
-#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_BACKGROUND_MODE
-uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_background_mode(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_CLEAR_CACHE
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_CLEAR_CACHE
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_clear_cache(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_CAMERA_ENABLED
-#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_CAMERA_ENABLED
-uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_camera_enabled(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_CLOSE_POLL
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_CLOSE_POLL
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_close_poll(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_CAMERA_ENABLED_ON_JOIN
-#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_CAMERA_ENABLED_ON_JOIN
-uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_camera_enabled_on_join(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_CONNECT
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_CONNECT
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_connect(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_CHAT_OPEN
-#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_CHAT_OPEN
-uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_chat_open(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_CONNECT_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_CONNECT_ASYNC
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_connect_async(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_DISPLAY_NAME
-#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_DISPLAY_NAME
-uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_display_name(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_CONNECT_TIMEOUTS
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_CONNECT_TIMEOUTS
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_connect_timeouts(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_LANGUAGE
-#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_LANGUAGE
-uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_language(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_CONNECT_WITH_OPTIONS
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_CONNECT_WITH_OPTIONS
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_connect_with_options(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_MEET_INSTANCES
-#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_MEET_INSTANCES
-uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_meet_instances(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_CONNECT_WITH_OPTIONS_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_CONNECT_WITH_OPTIONS_ASYNC
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_connect_with_options_async(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_MIC_ENABLED_ON_JOIN
-#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_MIC_ENABLED_ON_JOIN
-uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_mic_enabled_on_join(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_CONNECTION_STATE
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_CONNECTION_STATE
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_connection_state(void
     
 );
 #endif
-#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_MICROPHONE_ENABLED
-#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_MICROPHONE_ENABLED
-uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_microphone_enabled(void
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_CREATE_POLL
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_CREATE_POLL
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_create_poll(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_DISCONNECT
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_DISCONNECT
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_disconnect(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_DISCONNECT_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_DISCONNECT_ASYNC
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_disconnect_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_DOMINANT_SPEAKER
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_DOMINANT_SPEAKER
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_dominant_speaker(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_END_CALL
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_END_CALL
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_end_call(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_EXPORT_CHAT
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_EXPORT_CHAT
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_export_chat(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_EXPORT_JOURNAL
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_EXPORT_JOURNAL
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_export_journal(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_FETCH_AVATAR
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_FETCH_AVATAR
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_fetch_avatar(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_GET_AV_SYNC_STATS
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_GET_AV_SYNC_STATS
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_get_av_sync_stats(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_GET_BACKGROUND_MODE
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_GET_BACKGROUND_MODE
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_get_background_mode(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_GET_CACHED_AVATAR
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_GET_CACHED_AVATAR
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_get_cached_avatar(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_GET_CACHED_ROOM_INFO
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_GET_CACHED_ROOM_INFO
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_get_cached_room_info(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_GET_CAMERA_CONTROL_STATE
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_GET_CAMERA_CONTROL_STATE
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_get_camera_control_state(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_GET_CAPTURE_CONSTRAINTS
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_GET_CAPTURE_CONSTRAINTS
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_get_capture_constraints(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_GET_EXTRA
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_GET_EXTRA
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_get_extra(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_GET_FEATURE_FLAGS
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_GET_FEATURE_FLAGS
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_get_feature_flags(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_GET_INVITE_INFO
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_GET_INVITE_INFO
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_get_invite_info(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_GET_LAST_CALL_SUMMARY
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_GET_LAST_CALL_SUMMARY
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_get_last_call_summary(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_GET_LOCAL_PERMISSIONS
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_GET_LOCAL_PERMISSIONS
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_get_local_permissions(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_GET_MEET_INSTANCES
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_GET_MEET_INSTANCES
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_get_meet_instances(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_GET_NEGOTIATED_CODECS
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_GET_NEGOTIATED_CODECS
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_get_negotiated_codecs(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_GET_PARTICIPANT_ATTRIBUTES
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_GET_PARTICIPANT_ATTRIBUTES
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_get_participant_attributes(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_GET_QUALITY_HISTORY
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_GET_QUALITY_HISTORY
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_get_quality_history(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_GET_RECORDING_STATUS
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_GET_RECORDING_STATUS
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_get_recording_status(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_GET_RENDERER_STATS
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_GET_RENDERER_STATS
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_get_renderer_stats(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_GET_SETTINGS
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_GET_SETTINGS
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_get_settings(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_GET_TIME_REMAINING
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_GET_TIME_REMAINING
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_get_time_remaining(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_GET_VIDEO_STATS
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_GET_VIDEO_STATS
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_get_video_stats(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_GET_WHITEBOARD_SESSION
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_GET_WHITEBOARD_SESSION
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_get_whiteboard_session(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_HANDLE_MEDIA_BUTTON
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_HANDLE_MEDIA_BUTTON
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_handle_media_button(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_INVITE_PARTICIPANTS
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_INVITE_PARTICIPANTS
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_invite_participants(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_IS_CAMERA_ENABLED
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_IS_CAMERA_ENABLED
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_is_camera_enabled(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_IS_HAND_RAISED
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_IS_HAND_RAISED
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_is_hand_raised(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_IS_MICROPHONE_ENABLED
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_IS_MICROPHONE_ENABLED
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_is_microphone_enabled(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_IS_OUTPUT_MUTED
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_IS_OUTPUT_MUTED
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_is_output_muted(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_IS_ROOM_LOCKED
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_IS_ROOM_LOCKED
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_is_room_locked(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_IS_VIDEO_PRIVACY_ENABLED
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_IS_VIDEO_PRIVACY_ENABLED
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_is_video_privacy_enabled(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_JOIN
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_JOIN
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_join(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_JOIN_LEAVE_CONFIG
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_JOIN_LEAVE_CONFIG
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_join_leave_config(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_JOIN_OPTIONS
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_JOIN_OPTIONS
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_join_options(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_LAYOUT_ORDER
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_LAYOUT_ORDER
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_layout_order(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_LEAVE
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_LEAVE
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_leave(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_LOAD_BACKGROUND_IMAGE
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_LOAD_BACKGROUND_IMAGE
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_load_background_image(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_LOAD_BLUR_MODEL
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_LOAD_BLUR_MODEL
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_load_blur_model(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_LOCK_ROOM
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_LOCK_ROOM
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_lock_room(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_LOWER_HAND
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_LOWER_HAND
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_lower_hand(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_LOW_BANDWIDTH_AUDIO_OPTIONS
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_LOW_BANDWIDTH_AUDIO_OPTIONS
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_low_bandwidth_audio_options(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_MARK_CHAT_READ_UP_TO
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_MARK_CHAT_READ_UP_TO
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_mark_chat_read_up_to(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_OUTPUT_VOLUME
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_OUTPUT_VOLUME
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_output_volume(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_PARTICIPANTS
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_PARTICIPANTS
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_participants(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_PARTICIPANT_SNAPSHOT
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_PARTICIPANT_SNAPSHOT
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_participant_snapshot(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_PENDING_MEDIA
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_PENDING_MEDIA
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_pending_media(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_PERMISSION_STATE
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_PERMISSION_STATE
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_permission_state(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_PIN_PARTICIPANT
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_PIN_PARTICIPANT
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_pin_participant(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_PINNED_PARTICIPANTS
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_PINNED_PARTICIPANTS
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_pinned_participants(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_POLLS
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_POLLS
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_polls(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_POWER_MODE
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_POWER_MODE
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_power_mode(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_PRESENCE
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_PRESENCE
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_presence(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_PUBLISH_DATA
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_PUBLISH_DATA
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_publish_data(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_PUSH_DEVICE_CHECK_AUDIO
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_PUSH_DEVICE_CHECK_AUDIO
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_push_device_check_audio(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_QUEUE_MEDIA_UNTIL_CONNECTED
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_QUEUE_MEDIA_UNTIL_CONNECTED
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_queue_media_until_connected(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_RAISE_HAND
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_RAISE_HAND
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_raise_hand(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_RECONNECT
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_RECONNECT
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_reconnect(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_REPORT_ACTIVITY
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_REPORT_ACTIVITY
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_report_activity(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_REPORT_INCOMING_CALL
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_REPORT_INCOMING_CALL
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_report_incoming_call(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_REPORT_OUTGOING_CALL
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_REPORT_OUTGOING_CALL
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_report_outgoing_call(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_REPORT_THERMAL_STATE
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_REPORT_THERMAL_STATE
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_report_thermal_state(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_ROOM_IDS
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_ROOM_IDS
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_room_ids(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_RUN_DEVICE_CHECK
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_RUN_DEVICE_CHECK
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_run_device_check(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SAVE_CHAT_EXPORT
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SAVE_CHAT_EXPORT
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_save_chat_export(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SAVE_SNAPSHOT
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SAVE_SNAPSHOT
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_save_snapshot(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SEND_CHAT_MESSAGE
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SEND_CHAT_MESSAGE
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_send_chat_message(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SEND_CHAT_MESSAGE_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SEND_CHAT_MESSAGE_ASYNC
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_send_chat_message_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SEND_REACTION
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SEND_REACTION
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_send_reaction(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_ACTIVE_SPEAKER_HOLD
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_ACTIVE_SPEAKER_HOLD
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_active_speaker_hold(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_AUDIO_BITRATE
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_AUDIO_BITRATE
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_audio_bitrate(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_AUDIO_DUCKING
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_AUDIO_DUCKING
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_audio_ducking(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_AUDIO_PRESET
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_AUDIO_PRESET
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_audio_preset(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_AUDIO_PUBLISH_OPTIONS
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_AUDIO_PUBLISH_OPTIONS
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_audio_publish_options(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_AUTO_GAIN
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_AUTO_GAIN
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_auto_gain(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_BACKGROUND_MODE
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_BACKGROUND_MODE
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_background_mode(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_CAMERA_ENABLED
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_CAMERA_ENABLED
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_camera_enabled(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_CAMERA_ENABLED_ON_JOIN
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_CAMERA_ENABLED_ON_JOIN
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_camera_enabled_on_join(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_CHAT_FORMATTING
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_CHAT_FORMATTING
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_chat_formatting(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_CHAT_OPEN
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_CHAT_OPEN
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_chat_open(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_CONNECT_TIMEOUTS
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_CONNECT_TIMEOUTS
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_connect_timeouts(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_DISPLAY_NAME
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_DISPLAY_NAME
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_display_name(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_EXTRA
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_EXTRA
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_extra(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_FEEDBACK_DIAGNOSTICS_ENABLED
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_FEEDBACK_DIAGNOSTICS_ENABLED
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_feedback_diagnostics_enabled(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_FOCUS_POINT
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_FOCUS_POINT
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_focus_point(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_HAND_AUTO_LOWER
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_HAND_AUTO_LOWER
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_hand_auto_lower(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_IDLE_POLICY
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_IDLE_POLICY
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_idle_policy(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_JOIN_LEAVE_CONFIG
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_JOIN_LEAVE_CONFIG
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_join_leave_config(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_LANGUAGE
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_LANGUAGE
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_language(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_LOCAL_ATTRIBUTES
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_LOCAL_ATTRIBUTES
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_local_attributes(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_LOW_LIGHT_BOOST
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_LOW_LIGHT_BOOST
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_low_light_boost(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_MAX_CHAT_MESSAGE_BYTES
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_MAX_CHAT_MESSAGE_BYTES
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_max_chat_message_bytes(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_MEET_INSTANCES
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_MEET_INSTANCES
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_meet_instances(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_METRICS
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_METRICS
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_metrics(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_MIC_ENABLED_ON_JOIN
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_MIC_ENABLED_ON_JOIN
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_mic_enabled_on_join(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_MICROPHONE_ENABLED
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_MICROPHONE_ENABLED
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_microphone_enabled(void
     
 );
 #endif
@@ -953,42 +2273,270 @@ uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_notification_message_r
 #define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_NOTIFICATION_PARTICIPANT_JOIN
 uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_notification_participant_join(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_OUTPUT_MUTED
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_OUTPUT_MUTED
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_output_muted(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_OUTPUT_VOLUME
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_OUTPUT_VOLUME
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_output_volume(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_PERMISSION_STATE
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_PERMISSION_STATE
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_permission_state(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_POWER_MODE
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_POWER_MODE
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_power_mode(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_PREFERRED_CAMERA_ID
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_PREFERRED_CAMERA_ID
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_preferred_camera_id(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_PREFERRED_MICROPHONE_ID
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_PREFERRED_MICROPHONE_ID
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_preferred_microphone_id(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_PREFERRED_SPEAKER_ID
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_PREFERRED_SPEAKER_ID
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_preferred_speaker_id(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_PRESENCE
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_PRESENCE
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_presence(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_QUALITY_POLICY
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_QUALITY_POLICY
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_quality_policy(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_QUEUE_MEDIA_UNTIL_CONNECTED
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_QUEUE_MEDIA_UNTIL_CONNECTED
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_queue_media_until_connected(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_RTC_CONFIG
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_RTC_CONFIG
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_rtc_config(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_THEME
 #define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_THEME
 uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_theme(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_TORCH_ENABLED
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_TORCH_ENABLED
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_torch_enabled(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_VIDEO_CODEC
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_VIDEO_CODEC
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_video_codec(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_VIDEO_CONTENT_HINT
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_VIDEO_CONTENT_HINT
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_video_content_hint(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_VIDEO_OVERLAY
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_VIDEO_OVERLAY
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_video_overlay(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_VIDEO_OVERLAY_IMAGE
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_VIDEO_OVERLAY_IMAGE
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_video_overlay_image(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_VIDEO_PRIVACY
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_VIDEO_PRIVACY
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_video_privacy(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_VIDEO_PRIVACY_STYLE
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_VIDEO_PRIVACY_STYLE
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_video_privacy_style(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_VISIBLE_TRACKS
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_VISIBLE_TRACKS
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_visible_tracks(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_ZOOM
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SET_ZOOM
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_set_zoom(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SHUTDOWN
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SHUTDOWN
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_shutdown(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_START_LOCAL_RECORDING
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_START_LOCAL_RECORDING
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_start_local_recording(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_START_RECORDING
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_START_RECORDING
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_start_recording(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_START_VIDEO_RENDERER
 #define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_START_VIDEO_RENDERER
 uint16_t uniffi_visio_ffi_checksum_method_visioclient_start_video_renderer(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_STOP_LOCAL_RECORDING
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_STOP_LOCAL_RECORDING
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_stop_local_recording(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_STOP_RECORDING
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_STOP_RECORDING
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_stop_recording(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_STOP_VIDEO_RENDERER
 #define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_STOP_VIDEO_RENDERER
 uint16_t uniffi_visio_ffi_checksum_method_visioclient_stop_video_renderer(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SUBMIT_FEEDBACK
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SUBMIT_FEEDBACK
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_submit_feedback(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SUBSCRIBE_DATA_TOPIC
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SUBSCRIBE_DATA_TOPIC
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_subscribe_data_topic(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SWITCH_ROOM
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SWITCH_ROOM
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_switch_room(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SYSTEM_CALL
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_SYSTEM_CALL
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_system_call(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_TRANSLATE
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_TRANSLATE
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_translate(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_UNPIN_PARTICIPANT
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_UNPIN_PARTICIPANT
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_unpin_participant(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_UNREAD_COUNT
 #define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_UNREAD_COUNT
 uint16_t uniffi_visio_ffi_checksum_method_visioclient_unread_count(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_UNSUBSCRIBE_DATA_TOPIC
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_UNSUBSCRIBE_DATA_TOPIC
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_unsubscribe_data_topic(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_VALIDATE_ROOM
 #define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_VALIDATE_ROOM
 uint16_t uniffi_visio_ffi_checksum_method_visioclient_validate_room(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_VALIDATE_ROOM_ASYNC
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_VALIDATE_ROOM_ASYNC
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_validate_room_async(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_VIDEO_CODEC
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_VIDEO_CODEC
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_video_codec(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_VIDEO_CONTENT_HINT
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_VIDEO_CONTENT_HINT
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_video_content_hint(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_VOTE_POLL
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOCLIENT_VOTE_POLL
+uint16_t uniffi_visio_ffi_checksum_method_visioclient_vote_poll(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_CONSTRUCTOR_VISIOCLIENT_NEW
 #define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_CONSTRUCTOR_VISIOCLIENT_NEW
 uint16_t uniffi_visio_ffi_checksum_constructor_visioclient_new(void
     
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_CAMERACOMMANDLISTENER_ON_CAMERA_COMMAND
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_CAMERACOMMANDLISTENER_ON_CAMERA_COMMAND
+uint16_t uniffi_visio_ffi_checksum_method_cameracommandlistener_on_camera_command(void
+    
+);
+#endif
+#ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_ROOMEVENTLISTENER_ON_ROOM_EVENT
+#define UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_ROOMEVENTLISTENER_ON_ROOM_EVENT
+uint16_t uniffi_visio_ffi_checksum_method_roomeventlistener_on_room_event(void
+    
 );
 #endif
 #ifndef UNIFFI_FFIDEF_UNIFFI_VISIO_FFI_CHECKSUM_METHOD_VISIOEVENTLISTENER_ON_EVENT
@@ -1,6 +1,7 @@
 #ifndef visio_native_h
 #define visio_native_h
 
+#include <stdbool.h>
 #include <stdint.h>
 
 // Audio playout — pull decoded remote audio samples (i16 PCM, 48kHz mono).
@@ -17,12 +18,56 @@ typedef void (*VisioIosFrameCallback)(
 );
 void visio_video_set_ios_callback(VisioIosFrameCallback callback, void *user_data);
 
+// Pixel buffer provider — Rust writes NV12 directly into locked pooled
+// CVPixelBuffers. Falls back to the frame callback when acquire returns false.
+typedef struct {
+    uint8_t *y_ptr;
+    uint32_t y_stride;
+    uint8_t *uv_ptr;
+    uint32_t uv_stride;
+    void *handle;
+} VisioNv12Buffer;
+typedef bool (*VisioIosAcquireBuffer)(
+    uint32_t width, uint32_t height,
+    const char *track_sid, void *user_data,
+    VisioNv12Buffer *out
+);
+typedef void (*VisioIosSubmitBuffer)(
+    void *handle, const char *track_sid,
+    bool written, void *user_data
+);
+void visio_video_set_ios_pixel_buffer_provider(
+    VisioIosAcquireBuffer acquire,
+    VisioIosSubmitBuffer submit,
+    void *user_data
+);
+
 // Camera capture — push I420 frame from AVCaptureSession into LiveKit
 void visio_push_ios_camera_frame(
     const uint8_t *y_ptr, uint32_t y_stride,
     const uint8_t *u_ptr, uint32_t u_stride,
     const uint8_t *v_ptr, uint32_t v_stride,
-    uint32_t width, uint32_t height
+    uint32_t width, uint32_t height,
+    int64_t timestamp_ns
+);
+
+// External capture — platform-agnostic frame push into the sources published
+// by the client's default room. Returns 0 when pushed, -1 on invalid
+// arguments, -2 when the camera/microphone is not published.
+#define VISIO_PIXEL_FORMAT_I420 0 // planes: Y, U, V
+#define VISIO_PIXEL_FORMAT_NV12 1 // planes: Y, interleaved UV
+#define VISIO_PIXEL_FORMAT_RGBA 2 // planes: packed RGBA
+int32_t visio_push_video_frame(
+    const void *client, uint32_t format,
+    uint32_t width, uint32_t height,
+    const uint8_t *const planes[3], const uint32_t strides[3],
+    uint32_t rotation_degrees, int64_t timestamp_ns
+);
+// Interleaved i16 PCM at 48 kHz; several channels are mixed down to mono.
+int32_t visio_push_audio_frame(
+    const void *client, const int16_t *samples,
+    uint32_t samples_per_channel, uint32_t sample_rate,
+    uint32_t num_channels, int64_t timestamp_ns
 );
 
 #endif /* visio_native_h */
@@ -4,10 +4,12 @@ import AVFoundation
 /// Manages CallKit integration for system call UI (green bar, Dynamic Island, lock screen controls).
 ///
 /// Flow:
-/// 1. `connect()` -> `reportCallStarted()` -> iOS shows call indicator
-/// 2. Incoming phone call -> `performSetHeldCallAction` -> auto-mute mic
-/// 3. `disconnect()` -> `reportCallEnded()` -> indicator removed
-/// 4. Lock screen: native mute/hangup buttons -> actions relayed to VisioManager
+/// 1. `connect()` reports the call to the core -> `SystemCallChanged(.connecting)`
+///    -> `update(call:)` starts it -> iOS shows call indicator
+/// 2. `.connected` -> call marked connected
+/// 3. Incoming phone call -> `performSetHeldCallAction` -> auto-mute mic
+/// 4. `disconnect()` / server ends the call / failure -> `update(call:)` ends it
+/// 5. Lock screen: native mute/hangup buttons -> actions relayed to VisioManager
 class CallKitManager: NSObject, CXProviderDelegate {
 
     static let shared = CallKitManager()
@@ -29,22 +31,45 @@ class CallKitManager: NSObject, CXProviderDelegate {
 
     // MARK: - Public API
 
+    /// Bridge a `SystemCallChanged` event from the core to CallKit.
+    func update(call: SystemCall) {
+        guard let uuid = UUID(uuidString: call.callId) else { return }
+        switch call.state {
+        case .connecting:
+            if call.direction == .incoming {
+                reportIncomingCall(uuid: uuid, call: call)
+            } else {
+                reportCallStarted(uuid: uuid, call: call)
+            }
+        case .connected:
+            if call.direction == .outgoing {
+                provider.reportOutgoingCall(with: uuid, connectedAt: Date())
+            }
+        case .endedLocally:
+            reportCallEnded()
+        case .endedRemotely:
+            reportCallEnded(uuid: uuid, reason: .remoteEnded)
+        case .failed:
+            reportCallEnded(uuid: uuid, reason: .failed)
+        }
+    }
+
     /// Report that an outgoing call has started (user joined a room).
-    func reportCallStarted(roomName: String) {
-        let uuid = UUID()
+    private func reportCallStarted(uuid: UUID, call: SystemCall) {
         currentCallUUID = uuid
 
-        let handle = CXHandle(type: .generic, value: roomName)
+        let handle = CXHandle(type: .generic, value: call.room)
         let action = CXStartCallAction(call: uuid, handle: handle)
         action.isVideo = true
+        action.contactIdentifier = call.displayName
 
         let transaction = CXTransaction(action: action)
         callController.request(transaction) { [weak self] error in
             if let error {
                 NSLog("CallKitManager: start call failed: \(error.localizedDescription)")
             } else {
-                self?.provider.reportOutgoingCall(with: uuid, connectedAt: Date())
-                NSLog("CallKitManager: call started for room '\(roomName)'")
+                self?.provider.reportOutgoingCall(with: uuid, startedConnectingAt: Date())
+                NSLog("CallKitManager: call started for room '\(call.room)'")
             }
         }
 
@@ -52,8 +77,30 @@ class CallKitManager: NSObject, CXProviderDelegate {
         configureAudioSession()
     }
 
-    /// Report that the call has ended.
-    func reportCallEnded() {
+    /// Report a call joined from an invitation.
+    private func reportIncomingCall(uuid: UUID, call: SystemCall) {
+        currentCallUUID = uuid
+
+        let update = CXCallUpdate()
+        update.remoteHandle = CXHandle(type: .generic, value: call.room)
+        update.localizedCallerName = call.displayName
+        update.hasVideo = true
+        provider.reportNewIncomingCall(with: uuid, update: update) { error in
+            if let error {
+                NSLog("CallKitManager: incoming call failed: \(error.localizedDescription)")
+            }
+        }
+    }
+
+    /// The call ended on its own: tell CallKit why.
+    private func reportCallEnded(uuid: UUID, reason: CXCallEndedReason) {
+        guard uuid == currentCallUUID else { return }
+        provider.reportCall(with: uuid, endedAt: Date(), reason: reason)
+        currentCallUUID = nil
+    }
+
+    /// Report that the user ended the call.
+    private func reportCallEnded() {
         guard let uuid = currentCallUUID else { return }
         let action = CXEndCallAction(call: uuid)
         let transaction = CXTransaction(action: action)
@@ -81,8 +128,8 @@ class CallKitManager: NSObject, CXProviderDelegate {
 
     func provider(_ provider: CXProvider, perform action: CXEndCallAction) {
         // System ended the call (user tapped end on lock screen / Dynamic Island)
-        VisioManager.shared.disconnect()
         currentCallUUID = nil
+        VisioManager.shared.disconnect()
         action.fulfill()
     }
 
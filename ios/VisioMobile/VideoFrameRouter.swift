@@ -7,11 +7,17 @@ import CoreVideo
 /// The Rust `visio_video` crate calls `visio_video_set_ios_callback` once at startup.
 /// Each frame arrives with a `track_sid`; this singleton looks up the registered
 /// VideoDisplayView and enqueues a CMSampleBuffer for display.
+///
+/// With the pixel buffer provider registered, Rust instead writes NV12 directly
+/// into pooled CVPixelBuffers (`acquireBuffer` / `submitBuffer`), avoiding the
+/// copy done by `deliverFrame`.
 final class VideoFrameRouter {
     static let shared = VideoFrameRouter()
 
     /// Registered views, keyed by track SID.
     private var views: [String: VideoDisplayView] = [:]
+    /// NV12 pixel buffer pools, keyed by frame size.
+    private var pools: [String: CVPixelBufferPool] = [:]
     private let lock = NSLock()
 
     private init() {}
@@ -58,6 +64,78 @@ final class VideoFrameRouter {
         }
     }
 
+    // MARK: - Pixel buffer provider
+
+    /// Called from the Rust acquire callback: lock a pooled NV12 buffer for Rust to fill.
+    func acquireBuffer(
+        width: UInt32, height: UInt32,
+        trackSid: String,
+        out: UnsafeMutablePointer<VisioNv12Buffer>
+    ) -> Bool {
+        lock.lock()
+        let pool = views[trackSid] != nil
+            ? pixelBufferPool(width: Int(width), height: Int(height))
+            : nil
+        lock.unlock()
+        guard let pool else { return false }
+
+        var pixelBuffer: CVPixelBuffer?
+        guard CVPixelBufferPoolCreatePixelBuffer(kCFAllocatorDefault, pool, &pixelBuffer) == kCVReturnSuccess,
+              let pb = pixelBuffer else { return false }
+
+        CVPixelBufferLockBaseAddress(pb, [])
+        guard let yDst = CVPixelBufferGetBaseAddressOfPlane(pb, 0),
+              let uvDst = CVPixelBufferGetBaseAddressOfPlane(pb, 1) else {
+            CVPixelBufferUnlockBaseAddress(pb, [])
+            return false
+        }
+        out.pointee = VisioNv12Buffer(
+            y_ptr: yDst.assumingMemoryBound(to: UInt8.self),
+            y_stride: UInt32(CVPixelBufferGetBytesPerRowOfPlane(pb, 0)),
+            uv_ptr: uvDst.assumingMemoryBound(to: UInt8.self),
+            uv_stride: UInt32(CVPixelBufferGetBytesPerRowOfPlane(pb, 1)),
+            handle: Unmanaged.passRetained(pb).toOpaque()
+        )
+        return true
+    }
+
+    /// Called from the Rust submit callback once the buffer has been filled (or abandoned).
+    func submitBuffer(handle: UnsafeMutableRawPointer, trackSid: String, written: Bool) {
+        let pb = Unmanaged<CVPixelBuffer>.fromOpaque(handle).takeRetainedValue()
+        CVPixelBufferUnlockBaseAddress(pb, [])
+        guard written else { return }
+
+        lock.lock()
+        let view = views[trackSid]
+        lock.unlock()
+
+        guard let view, let sampleBuffer = createSampleBuffer(from: pb) else { return }
+
+        DispatchQueue.main.async {
+            view.enqueueSampleBuffer(sampleBuffer)
+        }
+    }
+
+    /// Must be called with `lock` held.
+    private func pixelBufferPool(width: Int, height: Int) -> CVPixelBufferPool? {
+        let key = "\(width)x\(height)"
+        if let pool = pools[key] { return pool }
+        // Sizes change with simulcast layers; don't keep stale pools around.
+        if pools.count >= 8 { pools.removeAll() }
+
+        let attributes: [CFString: Any] = [
+            kCVPixelBufferPixelFormatTypeKey: kCVPixelFormatType_420YpCbCr8BiPlanarVideoRange,
+            kCVPixelBufferWidthKey: width,
+            kCVPixelBufferHeightKey: height,
+            kCVPixelBufferIOSurfacePropertiesKey: [:] as CFDictionary,
+        ]
+        var pool: CVPixelBufferPool?
+        guard CVPixelBufferPoolCreate(kCFAllocatorDefault, nil, attributes as CFDictionary, &pool) == kCVReturnSuccess,
+              let pool else { return nil }
+        pools[key] = pool
+        return pool
+    }
+
     // MARK: - Pixel buffer creation
 
     /// Convert I420 (Y + U + V planar) to NV12 (Y + interleaved UV).
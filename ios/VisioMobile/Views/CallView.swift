@@ -102,7 +102,6 @@ struct CallView: View {
             let name = displayName.isEmpty ? nil : displayName
             manager.connect(url: roomURL, username: name)
             manager.startAudioPlayout()
-            CallKitManager.shared.reportCallStarted(roomName: roomURL)
             UIApplication.shared.isIdleTimerDisabled = true
             PiPManager.shared.setup()
         }
@@ -416,7 +415,6 @@ struct CallView: View {
                 // Hangup
                 Button {
                     manager.disconnect()
-                    CallKitManager.shared.reportCallEnded()
                     dismiss()
                 } label: {
                     Image(systemName: "phone.down.fill")
@@ -99,7 +99,7 @@ struct HomeView: View {
                     var foundValid = false
                     for url in urlsToTry {
                         guard !Task.isCancelled else { return }
-                        let result = manager.client.validateRoom(url: url, username: uname)
+                        let result = await manager.client.validateRoomAsync(url: url, username: uname)
                         if case .valid = result {
                             roomStatus = "valid"
                             resolvedRoomURL = url
@@ -166,9 +166,18 @@ struct HomeView: View {
             meetInstances = manager.client.getMeetInstances()
         }
         .onChange(of: manager.pendingDeepLink) { newValue in
-            if let link = newValue {
-                roomURL = link
+            switch newValue {
+            case .joinRoom(let meetUrl, _, _, let name):
+                roomURL = meetUrl
+                if let name {
+                    displayName = name
+                }
+                manager.pendingDeepLink = nil
+            case .openSettings:
+                showSettings = true
                 manager.pendingDeepLink = nil
+            case nil:
+                break
             }
         }
     }
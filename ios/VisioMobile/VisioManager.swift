@@ -1,5 +1,9 @@
+import AVFoundation
+import Combine
 import Foundation
+import MediaPlayer
 import SwiftUI
+import UserNotifications
 import visioFFI
 
 /// Central state manager for the Visio app, backed by UniFFI-generated VisioClient.
@@ -15,6 +19,7 @@ class VisioManager: ObservableObject {
     @Published var connectionState: ConnectionState = .disconnected
     @Published var participants: [ParticipantInfo] = []
     @Published var activeSpeakers: [String] = []
+    @Published var layoutOrder: [String] = []
     @Published var chatMessages: [ChatMessage] = []
     @Published var isMicEnabled: Bool = false
     @Published var isCameraEnabled: Bool = false
@@ -23,14 +28,51 @@ class VisioManager: ObservableObject {
     @Published var unreadCount: Int = 0
     @Published var errorMessage: String?
     @Published var videoTrackSids: [String] = []
+    @Published var stalledTrackSids: Set<String> = []
+    @Published var localPermissions: LocalPermissions? = nil
+    /// Knocking on a restricted room, waiting for the host to let us in.
+    @Published var isWaitingForHost: Bool = false
+    /// Features of the Meet instance (nil until read after connecting).
+    @Published var featureFlags: FeatureFlags? = nil
+    /// The instance offers a collaborative whiteboard for the current room.
+    @Published var whiteboardAvailable: Bool = false
+    /// Current room only admits participants through the lobby.
+    @Published var isRoomLocked: Bool = false
+    /// Cloud recording of the current room.
+    @Published var recordingStatus: RecordingStatus = .stopped
+    /// Polls of the room, oldest first.
+    @Published var polls: [Poll] = []
+    /// Custom app messages on topics subscribed with `subscribeDataTopic`.
+    let dataMessages = PassthroughSubject<(topic: String, senderSid: String, payload: Data), Never>()
+    /// Target format of the camera capture, lowered on a poor connection.
+    @Published var captureConstraints: CaptureConstraints? = nil
+    /// Battery saver: lower capture and rendering rates, no self-view.
+    @Published var powerMode: PowerMode = .normal
+    /// Why video quality is currently lowered (nil when it is not).
+    @Published var qualityReduction: QualityReductionReason? = nil
+    /// OS permissions as reported to the core, for permission badges.
+    @Published var devicePermissions: [DevicePermission: PermissionState] = [:]
+    /// Duration of the running local recording (nil when not recording).
+    @Published var localRecordingMs: UInt64? = nil
+    /// Set when the host refused our entry request.
+    @Published var entryDeniedAt: Date? = nil
     @Published var isChatOpen: Bool = false
     @Published var currentLang: String = "fr"
     @Published var currentTheme: String = "light"
     @Published var displayName: String = ""
-    @Published var pendingDeepLink: String? = nil
+    /// Link opened from outside the app, consumed by `HomeView`.
+    @Published var pendingDeepLink: DeepLink? = nil
     @Published var isFrontCamera: Bool = true
     @Published var backgroundMode: String = "off"
     @Published var reactions: [ReactionData] = []
+    @Published var connectStep: ConnectStep? = nil
+    /// Set when the local hand was lowered because the user kept speaking.
+    @Published var handAutoLoweredAt: Date? = nil
+    /// Minutes left before the meeting's scheduled end once warned, 0 when over.
+    @Published var meetingMinutesLeft: Int? = nil
+    /// Idle checks of the core: alone in the room, or no activity for a while.
+    @Published var aloneInRoom: Bool = false
+    @Published var inactivityPrompt: Bool = false
 
     // MARK: - Private
 
@@ -52,6 +94,13 @@ class VisioManager: ObservableObject {
         }
         client = VisioClient(dataDir: documentsDir.path)
         client.addListener(listener: self)
+        client.addCameraCommandListener(listener: CameraCommandForwarder(manager: self))
+
+        // Headset play/pause button; the core ignores it outside a call.
+        MPRemoteCommandCenter.shared().togglePlayPauseCommand.addTarget { [weak self] _ in
+            self?.handleMediaButton(.playPause)
+            return .success
+        }
 
         // Load persisted settings
         let settings = client.getSettings()
@@ -72,6 +121,23 @@ class VisioManager: ObservableObject {
             )
         }, nil)
 
+        // Let Rust write NV12 straight into pooled pixel buffers (one copy less per frame).
+        visio_video_set_ios_pixel_buffer_provider({ width, height, trackSidCStr, userData, out in
+            guard let trackSidCStr, let out else { return false }
+            return VideoFrameRouter.shared.acquireBuffer(
+                width: width, height: height,
+                trackSid: String(cString: trackSidCStr),
+                out: out
+            )
+        }, { handle, trackSidCStr, written, userData in
+            guard let handle, let trackSidCStr else { return }
+            VideoFrameRouter.shared.submitBuffer(
+                handle: handle,
+                trackSid: String(cString: trackSidCStr),
+                written: written
+            )
+        }, nil)
+
         // Load ONNX segmentation model for background blur
         if let modelUrl = Bundle.main.url(forResource: "selfie_segmentation", withExtension: "onnx") {
             do {
@@ -83,6 +149,69 @@ class VisioManager: ObservableObject {
         } else {
             NSLog("VisioManager: selfie_segmentation.onnx not found in bundle")
         }
+
+        // Step video quality down as the device heats up.
+        reportThermalState()
+        NotificationCenter.default.addObserver(
+            forName: ProcessInfo.thermalStateDidChangeNotification,
+            object: nil,
+            queue: nil
+        ) { [weak self] _ in
+            self?.reportThermalState()
+        }
+
+        // Permissions may change in the Settings app while in background.
+        reportPermissions()
+        NotificationCenter.default.addObserver(
+            forName: UIApplication.didBecomeActiveNotification,
+            object: nil,
+            queue: nil
+        ) { [weak self] _ in
+            self?.reportPermissions()
+        }
+    }
+
+    /// Report camera, microphone and notification permissions to the core,
+    /// which refuses to publish a denied device.
+    func reportPermissions() {
+        client.setPermissionState(
+            permission: .camera,
+            state: permissionState(AVCaptureDevice.authorizationStatus(for: .video))
+        )
+        client.setPermissionState(
+            permission: .microphone,
+            state: permissionState(AVCaptureDevice.authorizationStatus(for: .audio))
+        )
+        UNUserNotificationCenter.current().getNotificationSettings { [weak self] settings in
+            let state: PermissionState
+            switch settings.authorizationStatus {
+            case .notDetermined: state = .unknown
+            case .denied: state = .denied
+            default: state = .granted
+            }
+            self?.client.setPermissionState(permission: .notifications, state: state)
+        }
+    }
+
+    private func permissionState(_ status: AVAuthorizationStatus) -> PermissionState {
+        switch status {
+        case .authorized: return .granted
+        case .denied, .restricted: return .denied
+        case .notDetermined: return .unknown
+        @unknown default: return .unknown
+        }
+    }
+
+    private func reportThermalState() {
+        let state: ThermalState
+        switch ProcessInfo.processInfo.thermalState {
+        case .nominal: state = .nominal
+        case .fair: state = .fair
+        case .serious: state = .serious
+        case .critical: state = .critical
+        @unknown default: state = .nominal
+        }
+        client.reportThermalState(state: state)
     }
 
     // MARK: - Public API
@@ -91,18 +220,13 @@ class VisioManager: ObservableObject {
         DispatchQueue.global(qos: .userInitiated).async { [weak self] in
             guard let self else { return }
             do {
-                let settings = self.client.getSettings()
+                // Shown by CallKit, which follows the call through
+                // `systemCallChanged` events.
+                _ = try self.client.reportOutgoingCall(room: url, displayName: username ?? url)
+                // The core applies the mic/camera-on-join settings while
+                // connecting.
                 try self.client.connect(meetUrl: url, username: username)
 
-                // Apply mic-on-join setting
-                if settings.micEnabledOnJoin {
-                    try self.client.setMicrophoneEnabled(enabled: true)
-                }
-                // Apply camera-on-join setting
-                if settings.cameraEnabledOnJoin {
-                    try self.client.setCameraEnabled(enabled: true)
-                }
-
                 // Sync state after connection + track publish
                 let parts = self.client.participants()
                 let mic = self.client.isMicrophoneEnabled()
@@ -120,14 +244,15 @@ class VisioManager: ObservableObject {
                     self.errorMessage = nil
                     // Start camera capture if camera was enabled on join
                     if cam {
-                        let capture = CameraCapture()
-                        capture.start()
-                        self.cameraCapture = capture
+                        self.cameraCapture = self.makeCameraCapture(
+                            preferredCameraId: self.client.getSettings().preferredCameraId
+                        )
                     }
                 }
             } catch {
+                NSLog("VisioManager: connection failed: \(error)")
                 DispatchQueue.main.async {
-                    self.errorMessage = "Connection failed: \(error.localizedDescription)"
+                    self.errorMessage = Strings.error(error, lang: self.currentLang)
                 }
             }
         }
@@ -151,6 +276,20 @@ class VisioManager: ObservableObject {
                 self.connectionState = .disconnected
                 self.participants = []
                 self.activeSpeakers = []
+                self.layoutOrder = []
+                self.stalledTrackSids = []
+                self.localPermissions = nil
+                self.isWaitingForHost = false
+                self.isRoomLocked = false
+                self.recordingStatus = .stopped
+                self.captureConstraints = nil
+                self.polls = []
+                self.localRecordingMs = nil
+                self.featureFlags = nil
+                self.whiteboardAvailable = false
+                self.meetingMinutesLeft = nil
+                self.aloneInRoom = false
+                self.inactivityPrompt = false
                 self.chatMessages = []
                 self.isMicEnabled = false
                 self.isCameraEnabled = false
@@ -161,6 +300,7 @@ class VisioManager: ObservableObject {
                 self.videoTrackSids = []
                 self.isChatOpen = false
                 self.reactions = []
+                self.connectStep = nil
             }
         }
     }
@@ -186,6 +326,26 @@ class VisioManager: ObservableObject {
         }
     }
 
+    /// Forward a headset button press (remote command). The core debounces
+    /// it, toggles the mic or hangs up, and reports it with
+    /// `mediaButtonHandled`.
+    func handleMediaButton(_ button: MediaButton) {
+        DispatchQueue.global(qos: .userInitiated).async { [weak self] in
+            guard let self else { return }
+            do {
+                _ = try self.client.handleMediaButton(button: button)
+            } catch {
+                NSLog("VisioManager: media button failed: \(error)")
+            }
+        }
+    }
+
+    /// Answer the inactivity prompt: the user is still there.
+    func stillHere() {
+        inactivityPrompt = false
+        client.reportActivity()
+    }
+
     func toggleCamera() {
         let newValue = !isCameraEnabled
         DispatchQueue.global(qos: .userInitiated).async { [weak self] in
@@ -195,9 +355,9 @@ class VisioManager: ObservableObject {
                 DispatchQueue.main.async {
                     self.isCameraEnabled = newValue
                     if newValue {
-                        let capture = CameraCapture()
-                        capture.start()
-                        self.cameraCapture = capture
+                        self.cameraCapture = self.makeCameraCapture(
+                            preferredCameraId: self.client.getSettings().preferredCameraId
+                        )
                     } else {
                         self.cameraCapture?.stop()
                         self.cameraCapture = nil
@@ -242,6 +402,118 @@ class VisioManager: ObservableObject {
         }
     }
 
+    func inviteParticipants(_ emails: [String]) {
+        DispatchQueue.global(qos: .userInitiated).async { [weak self] in
+            guard let self else { return }
+            do {
+                try self.client.inviteParticipants(emails: emails)
+            } catch {
+                DispatchQueue.main.async {
+                    self.errorMessage = "Invitation failed: \(error.localizedDescription)"
+                }
+            }
+        }
+    }
+
+    /// URL and credentials to open the whiteboard in a web view; nil if unavailable.
+    func getWhiteboardSession(completion: @escaping (WhiteboardSession?) -> Void) {
+        DispatchQueue.global(qos: .userInitiated).async { [weak self] in
+            guard let self else { return }
+            do {
+                let session = try self.client.getWhiteboardSession()
+                DispatchQueue.main.async { completion(session) }
+            } catch {
+                DispatchQueue.main.async {
+                    self.errorMessage = "Whiteboard failed: \(error.localizedDescription)"
+                    completion(nil)
+                }
+            }
+        }
+    }
+
+    func setRoomLocked(_ locked: Bool) {
+        DispatchQueue.global(qos: .userInitiated).async { [weak self] in
+            guard let self else { return }
+            do {
+                try self.client.lockRoom(locked: locked)
+            } catch {
+                DispatchQueue.main.async {
+                    self.errorMessage = "Room lock failed: \(error.localizedDescription)"
+                }
+            }
+        }
+    }
+
+    func startRecording(_ mode: RecordingMode) {
+        DispatchQueue.global(qos: .userInitiated).async { [weak self] in
+            guard let self else { return }
+            do {
+                try self.client.startRecording(mode: mode)
+            } catch {
+                DispatchQueue.main.async {
+                    self.errorMessage = "Start recording failed: \(error.localizedDescription)"
+                }
+            }
+        }
+    }
+
+    /// Record the call audio to a WAV file in Documents/recordings.
+    func startLocalRecording() {
+        DispatchQueue.global(qos: .userInitiated).async { [weak self] in
+            guard let self else { return }
+            let dir = FileManager.default.urls(for: .documentDirectory, in: .userDomainMask).first
+                ?? FileManager.default.temporaryDirectory
+            let file = dir.appendingPathComponent("recordings")
+                .appendingPathComponent("visio-\(Int(Date().timeIntervalSince1970)).wav")
+            do {
+                try self.client.startLocalRecording(
+                    path: file.path, options: LocalRecordingOptions(maxSizeBytes: nil))
+                DispatchQueue.main.async { self.localRecordingMs = 0 }
+            } catch {
+                DispatchQueue.main.async {
+                    self.errorMessage = "Local recording failed: \(error.localizedDescription)"
+                }
+            }
+        }
+    }
+
+    func stopLocalRecording() {
+        DispatchQueue.global(qos: .userInitiated).async { [weak self] in
+            guard let self else { return }
+            _ = try? self.client.stopLocalRecording()
+            DispatchQueue.main.async { self.localRecordingMs = nil }
+        }
+    }
+
+    /// Save the last frame of a video track as PNG in Documents/snapshots.
+    func saveSnapshot(trackSid: String) -> URL? {
+        let docs = FileManager.default.urls(for: .documentDirectory, in: .userDomainMask).first
+            ?? FileManager.default.temporaryDirectory
+        let dir = docs.appendingPathComponent("snapshots")
+        try? FileManager.default.createDirectory(at: dir, withIntermediateDirectories: true)
+        let file = dir.appendingPathComponent("visio-\(Int(Date().timeIntervalSince1970)).png")
+        do {
+            try client.saveSnapshot(trackSid: trackSid, path: file.path)
+            return file
+        } catch {
+            errorMessage = "Snapshot failed: \(error.localizedDescription)"
+            return nil
+        }
+    }
+
+    func stopRecording() {
+        DispatchQueue.global(qos: .userInitiated).async { [weak self] in
+            guard let self else { return }
+            do {
+                try self.client.stopRecording()
+            } catch {
+                DispatchQueue.main.async {
+                    self.errorMessage = "Stop recording failed: \(error.localizedDescription)"
+                }
+            }
+        }
+    }
+
     func setChatOpen(_ open: Bool) {
         isChatOpen = open
         client.setChatOpen(open: open)
@@ -303,6 +575,139 @@ class VisioManager: ObservableObject {
     func switchCamera(toFront: Bool) {
         cameraCapture?.switchCamera(toFront: toFront)
         isFrontCamera = toFront
+        if let id = CameraCapture.cameraId(front: toFront) {
+            client.setPreferredCameraId(id: id)
+        }
+    }
+
+    func setTorchEnabled(_ enabled: Bool) {
+        client.setTorchEnabled(enabled: enabled)
+    }
+
+    func setZoom(_ factor: Float) {
+        client.setZoom(factor: factor)
+    }
+
+    func createPoll(question: String, options: [String]) {
+        DispatchQueue.global(qos: .userInitiated).async { [weak self] in
+            do {
+                _ = try self?.client.createPoll(question: question, options: options)
+            } catch {
+                NSLog("VisioManager: create poll failed: %@", error.localizedDescription)
+            }
+        }
+    }
+
+    func votePoll(pollId: String, option: UInt32) {
+        DispatchQueue.global(qos: .userInitiated).async { [weak self] in
+            do {
+                _ = try self?.client.votePoll(pollId: pollId, option: option)
+            } catch {
+                NSLog("VisioManager: vote failed: %@", error.localizedDescription)
+            }
+        }
+    }
+
+    func closePoll(pollId: String) {
+        DispatchQueue.global(qos: .userInitiated).async { [weak self] in
+            do {
+                _ = try self?.client.closePoll(pollId: pollId)
+            } catch {
+                NSLog("VisioManager: close poll failed: %@", error.localizedDescription)
+            }
+        }
+    }
+
+    func publishData(topic: String, payload: Data, reliable: Bool = true) {
+        DispatchQueue.global(qos: .userInitiated).async { [weak self] in
+            do {
+                try self?.client.publishData(topic: topic, payload: payload, reliable: reliable)
+            } catch {
+                NSLog("VisioManager: publish on %@ failed: %@", topic, error.localizedDescription)
+            }
+        }
+    }
+
+    func subscribeDataTopic(_ topic: String) throws {
+        try client.subscribeDataTopic(topic: topic)
+    }
+
+    func unsubscribeDataTopic(_ topic: String) {
+        client.unsubscribeDataTopic(topic: topic)
+    }
+
+    /// Let the camera lower its frame rate for better exposure in dim light.
+    func setLowLightBoost(_ enabled: Bool) {
+        client.setLowLightBoost(enabled: enabled)
+    }
+
+    /// Switch battery saver mode on or off for all rooms and renderers.
+    func setPowerMode(_ mode: PowerMode) {
+        client.setPowerMode(mode: mode)
+    }
+
+    /// Focus on a point of the self-view, normalized to 0..1.
+    func setFocusPoint(x: Float, y: Float) {
+        client.setFocusPoint(x: x, y: y)
+    }
+
+    /// Pre-join device check: tap the microphone (and start the camera with
+    /// `checkCamera`) for a moment and report a silent mic, a camera sending
+    /// no frames or a missing audio output. A camera already running for a
+    /// call is left as it is.
+    func runDeviceCheck(checkCamera: Bool, completion: @escaping (DeviceCheckReport) -> Void) {
+        let startedCamera = checkCamera && cameraCapture == nil
+        if startedCamera {
+            cameraCapture = makeCameraCapture(preferredCameraId: client.getSettings().preferredCameraId)
+        }
+        // The microphone goes through WebRTC in calls: feed the check from a tap.
+        let engine = AVAudioEngine()
+        let client = self.client
+        engine.inputNode.installTap(onBus: 0, bufferSize: 4800, format: nil) { buffer, _ in
+            guard let channel = buffer.floatChannelData?[0] else { return }
+            let samples = (0..<Int(buffer.frameLength)).map {
+                Int16(max(-1, min(1, channel[$0])) * 32767)
+            }
+            client.pushDeviceCheckAudio(samples: samples)
+        }
+        do {
+            try engine.start()
+        } catch {
+            NSLog("VisioManager: device check microphone failed: \(error)")
+        }
+        let speakerError = AVAudioSession.sharedInstance().currentRoute.outputs.isEmpty
+            ? "no audio output" : nil
+        DispatchQueue.global(qos: .userInitiated).async { [weak self] in
+            let report = client.runDeviceCheck(
+                windowMs: 1500, speakerError: speakerError, checkCamera: checkCamera)
+            engine.inputNode.removeTap(onBus: 0)
+            engine.stop()
+            DispatchQueue.main.async {
+                if startedCamera {
+                    self?.cameraCapture?.stop()
+                    self?.cameraCapture = nil
+                }
+                completion(report)
+            }
+        }
+    }
+
+    /// Start a capture with the last requested torch and zoom applied.
+    private func makeCameraCapture(preferredCameraId: String? = nil) -> CameraCapture {
+        let capture = CameraCapture()
+        let controls = client.getCameraControlState()
+        capture.apply(.setTorch(enabled: controls.torchEnabled))
+        capture.apply(.setZoom(factor: controls.zoomFactor))
+        capture.apply(constraints: client.getCaptureConstraints())
+        capture.start(preferredCameraId: preferredCameraId)
+        return capture
+    }
+
+    /// Camera commands arrive on a Rust thread.
+    fileprivate func handleCameraCommand(_ command: CameraCommand) {
+        DispatchQueue.main.async { [weak self] in
+            self?.cameraCapture?.apply(command)
+        }
     }
 
     func setNotificationParticipantJoin(_ enabled: Bool) {
@@ -329,9 +734,7 @@ class VisioManager: ObservableObject {
         switch connectionState {
         case .connected:
             if isCameraEnabled {
-                let capture = CameraCapture()
-                capture.start()
-                cameraCapture = capture
+                cameraCapture = makeCameraCapture()
             }
         case .disconnected:
             DispatchQueue.global(qos: .userInitiated).async { [weak self] in
@@ -386,6 +789,23 @@ extension VisioManager: VisioEventListener {
                 self.participants.removeAll { $0.sid == sid }
                 self.handRaisedMap.removeValue(forKey: sid)
 
+            case .participantBatchUpdate(let joined, let left, let joinedCount, let leftCount):
+                if joined.count == Int(joinedCount) && left.count == Int(leftCount) {
+                    let leftSet = Set(left)
+                    self.participants.removeAll { leftSet.contains($0.sid) }
+                    for sid in left { self.handRaisedMap.removeValue(forKey: sid) }
+                    for info in joined {
+                        if let idx = self.participants.firstIndex(where: { $0.sid == info.sid }) {
+                            self.participants[idx] = info
+                        } else {
+                            self.participants.append(info)
+                        }
+                    }
+                } else {
+                    // Count-only mode: lists are omitted, re-read from the client.
+                    self.participants = self.client.participants()
+                }
+
             case .trackMuted(let sid, _):
                 if let idx = self.participants.firstIndex(where: { $0.sid == sid }) {
                     var p = self.participants[idx]
@@ -402,6 +822,8 @@ extension VisioManager: VisioEventListener {
 
             case .activeSpeakersChanged(let sids):
                 self.activeSpeakers = sids
+            case .layoutOrderChanged(let sids):
+                self.layoutOrder = sids
 
             case .connectionQualityChanged(let sid, let quality):
                 if let idx = self.participants.firstIndex(where: { $0.sid == sid }) {
@@ -428,6 +850,7 @@ extension VisioManager: VisioEventListener {
 
             case .trackUnsubscribed(let trackSid):
                 self.videoTrackSids.removeAll { $0 == trackSid }
+                self.stalledTrackSids.remove(trackSid)
                 VideoFrameRouter.shared.unregister(trackSid: trackSid)
                 DispatchQueue.global(qos: .userInitiated).async { [weak self] in
                     self?.client.stopVideoRenderer(trackSid: trackSid)
@@ -469,11 +892,149 @@ extension VisioManager: VisioEventListener {
                         }
                     }
                 }
+
+            case .handAutoLowered:
+                self.isHandRaised = false
+                self.handAutoLoweredAt = Date()
+
+            case .connectProgress(let step):
+                self.connectStep = step
+
+            case .roomSwitched:
+                self.chatMessages = []
+                self.handRaisedMap = [:]
+                self.isHandRaised = false
+                self.reactions = []
+
+            case .trackStalled(let trackSid):
+                self.stalledTrackSids.insert(trackSid)
+
+            case .trackResumed(let trackSid):
+                self.stalledTrackSids.remove(trackSid)
+
+            case .permissionsChanged(let permissions):
+                self.localPermissions = permissions
+
+            case .waitingForHost:
+                self.isWaitingForHost = true
+
+            case .entryGranted:
+                self.isWaitingForHost = false
+
+            case .entryDenied:
+                self.isWaitingForHost = false
+                self.entryDeniedAt = Date()
+
+            case .featureFlagsChanged(let flags):
+                self.featureFlags = flags
+
+            case .roomLockedChanged(let locked):
+                self.isRoomLocked = locked
+
+            case .recordingStatusChanged(let status):
+                self.recordingStatus = status
+
+            case .sourceReady:
+                // Capture pushes into the core registry, which now points
+                // at the new publication: nothing to re-wire here.
+                break
+
+            case .captureConstraintsChanged(let constraints):
+                self.captureConstraints = constraints
+                self.cameraCapture?.apply(constraints: constraints)
+
+            case .pollStarted(let poll), .pollUpdated(let poll), .pollEnded(let poll):
+                if let index = self.polls.firstIndex(where: { $0.id == poll.id }) {
+                    self.polls[index] = poll
+                } else {
+                    self.polls.append(poll)
+                }
+
+            case .whiteboardAvailable:
+                self.whiteboardAvailable = true
+
+            case .powerModeChanged(let mode):
+                self.powerMode = mode
+                // The self-view tile is hidden in battery saver mode.
+                self.participants = self.client.participants()
+
+            case .qualityReduced(let reason, _):
+                self.qualityReduction = reason
+
+            case .qualityRestored:
+                self.qualityReduction = nil
+
+            case .devicePermissionChanged(let permission, let state):
+                self.devicePermissions[permission] = state
+
+            case .dataMessageReceived(let topic, let senderSid, let payload):
+                self.dataMessages.send((topic: topic, senderSid: senderSid, payload: payload))
+
+            case .localRecordingProgress(let durationMs, _):
+                if self.localRecordingMs != nil {
+                    self.localRecordingMs = durationMs
+                }
+
+            case .restreamStatusChanged:
+                // Restreaming needs ffmpeg: only the desktop app starts it.
+                break
+
+            case .settingsChanged(let keys):
+                // Changed elsewhere (e.g. a deep link): refresh published settings
+                let settings = self.client.getSettings()
+                if keys.contains("language") { self.currentLang = settings.language ?? "fr" }
+                if keys.contains("theme") { self.currentTheme = settings.theme ?? "light" }
+                if keys.contains("display_name") { self.displayName = settings.displayName ?? "" }
+
+            case .meetingEndingSoon(let minutesLeft):
+                self.meetingMinutesLeft = Int(minutesLeft)
+
+            case .meetingEnded:
+                self.meetingMinutesLeft = 0
+
+            case .aloneInRoom(_, let autoLeave):
+                if autoLeave {
+                    self.disconnect()
+                } else {
+                    self.aloneInRoom = true
+                }
+
+            case .inactivityDetected:
+                self.inactivityPrompt = true
+
+            case .systemCallChanged(let call):
+                CallKitManager.shared.update(call: call)
+
+            case .mediaButtonHandled(let button):
+                switch button {
+                case .playPause:
+                    DispatchQueue.global(qos: .userInitiated).async {
+                        let mic = self.client.isMicrophoneEnabled()
+                        DispatchQueue.main.async { self.isMicEnabled = mic }
+                    }
+                case .hangup:
+                    self.disconnect()
+                }
             }
         }
     }
 }
 
+// MARK: - CameraCommandListener
+
+/// Forwards camera commands without retaining the manager.
+private final class CameraCommandForwarder: CameraCommandListener {
+    private weak var manager: VisioManager?
+
+    init(manager: VisioManager) {
+        self.manager = manager
+    }
+
+    func onCameraCommand(command: CameraCommand) {
+        manager?.handleCameraCommand(command)
+    }
+}
+
 // MARK: - Reaction Data
 
 struct ReactionData: Identifiable {
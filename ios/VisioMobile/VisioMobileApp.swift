@@ -18,15 +18,12 @@ struct VisioMobileApp: App {
             .environmentObject(manager)
             .preferredColorScheme(manager.currentTheme == "dark" ? .dark : .light)
             .onOpenURL { url in
-                guard url.scheme == "visio",
-                      let host = url.host,
-                      let slug = url.pathComponents.dropFirst().first
-                else { return }
-
-                let instances = manager.client.getMeetInstances()
-                if instances.contains(host) {
-                    manager.pendingDeepLink = "https://\(host)/\(slug)"
+                guard let link = parseDeepLink(uri: url.absoluteString) else { return }
+                if case .joinRoom(_, let instance, _, _) = link,
+                   !manager.client.getMeetInstances().contains(instance) {
+                    return
                 }
+                manager.pendingDeepLink = link
             }
             .onChange(of: scenePhase) { phase in
                 switch phase {
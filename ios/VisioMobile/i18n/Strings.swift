@@ -27,7 +27,20 @@ enum Strings {
         return translations[lang]?[key] ?? translations["en"]?[key] ?? key
     }
 
-    static func detectSystemLang() -> String {
+    /// Message for users of an error raised by the core; details belong in the log.
+    static func error(_ error: Error, lang: String) -> String {
+        guard let error = error as? VisioError else { return t("error.generic", lang: lang) }
+        switch error {
+        case .Connection(_, let key, _), .Room(_, let key, _), .Auth(_, let key, _),
+             .Http(_, let key, _), .InvalidUrl(_, let key, _), .Timeout(_, let key, _),
+             .Unreachable(_, let key, _, _), .PermissionDenied(_, let key, _, _),
+             .PublishNotAllowed(_, let key, _, _), .MessageTooLarge(_, let key, _, _, _),
+             .Generic(_, let key, _):
+            return t(key, lang: lang)
+        }
+    }
+
+        static func detectSystemLang() -> String {
         let sysLang = Locale.current.language.languageCode?.identifier ?? "en"
         return supportedLangs.contains(sysLang) ? sysLang : "en"
     }